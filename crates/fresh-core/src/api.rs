@@ -1155,6 +1155,11 @@ pub struct EditorStateSnapshot {
     /// attached" without having to track state across editor restarts.
     #[serde(default)]
     pub authority_label: String,
+    /// Newer release reported by the update checker (e.g. `"0.3.1"`), or
+    /// `None` when the check is disabled, still running, or found nothing
+    /// newer. Read via `editor.getUpdateAvailable()`.
+    #[serde(default)]
+    pub update_available: Option<String>,
     /// Current Workspace Trust level for the active project: `"restricted"`,
     /// `"trusted"`, or `"blocked"`. Empty when trust state is unavailable
     /// (e.g. the default local authority before a guarded one is installed).
@@ -1344,6 +1349,7 @@ impl EditorStateSnapshot {
             windows: Vec::new(),
             active_window_id: WindowId(1),
             authority_label: String::new(),
+            update_available: None,
             workspace_trust_level: String::new(),
             env_active: false,
             detected_env: String::new(),
@@ -2755,6 +2761,11 @@ pub enum PluginCommand {
         window_id: Option<WindowId>,
     },
 
+    /// Switch the active window to the project rooted at `path`, exactly
+    /// like confirming the Switch Project prompt. Ignored (with a status
+    /// message) when `path` is not a directory.
+    SwitchProject { path: PathBuf },
+
     /// Insert text at the current cursor position in the active buffer
     InsertAtCursor { text: String },
 
@@ -2815,6 +2826,44 @@ pub enum PluginCommand {
     /// Kill a background process by ID
     KillBackgroundProcess { process_id: u64 },
 
+    /// Start a job: a background process listed in the editor's running
+    /// jobs while it runs. Streams output like `SpawnBackgroundProcess`,
+    /// and can take stdin and report progress.
+    SpawnJob {
+        /// Plugin that started the job
+        plugin: String,
+        /// Unique ID for this job, shared with its process (generated by
+        /// plugin runtime)
+        job_id: u64,
+        /// Command to execute
+        command: String,
+        /// Arguments to pass
+        args: Vec<String>,
+        /// Working directory (optional)
+        cwd: Option<String>,
+        /// Name shown in the running jobs list
+        title: String,
+        /// Pipe stdin so the plugin can write to it
+        stdin: bool,
+        /// Callback ID to call when the job exits
+        callback_id: JsCallbackId,
+    },
+
+    /// Write text to a job's stdin
+    WriteJobStdin { job_id: u64, data: String },
+
+    /// Close a job's stdin, signalling end of input
+    CloseJobStdin { job_id: u64 },
+
+    /// Update the progress shown for a running job
+    SetJobProgress {
+        job_id: u64,
+        /// What the job is doing now
+        message: Option<String>,
+        /// Completion percentage, 0-100
+        percent: Option<u8>,
+    },
+
     /// Wait for a process to complete and get its result
     /// Used with processes started via SpawnProcess
     SpawnProcessWait {
@@ -3095,6 +3144,32 @@ pub enum PluginCommand {
         epoch: Option<u64>,
     },
 
+    /// Mark a byte range read-only. User edits that would change it are
+    /// refused, with `hint` (or a generic message) shown in the status bar.
+    /// Plugin edits (`InsertText`, `DeleteRange`) are not affected, so the
+    /// owner can still rewrite the text. Used for prompt text embedded in
+    /// a buffer, generated sections, and aggregated multi-file edit buffers.
+    AddReadOnlyRegion {
+        buffer_id: BufferId,
+        /// Namespace for bulk removal (shared with overlay namespace system)
+        namespace: OverlayNamespace,
+        /// Byte range to protect
+        start: usize,
+        end: usize,
+        /// Status-bar message shown when an edit is refused
+        hint: Option<String>,
+        /// Hook epoch the range was computed against (auto-stamped); the editor
+        /// remaps it forward before anchoring. `None` = verbatim.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        epoch: Option<u64>,
+    },
+
+    /// Clear all read-only regions in a namespace
+    ClearReadOnlyRegions {
+        buffer_id: BufferId,
+        namespace: OverlayNamespace,
+    },
+
     /// Add a collapsed fold range. Hides the byte range
     /// `[start, end)` from rendering — the line containing `start - 1`
     /// (the fold's "header") stays visible while the lines covered by
@@ -4717,6 +4792,25 @@ pub struct BackgroundProcessResult {
     pub exit_code: i32,
 }
 
+/// Options for spawnJob
+#[derive(Debug, Clone, Default, Serialize, Deserialize, TS)]
+#[serde(deny_unknown_fields)]
+#[ts(export)]
+pub struct JobOptions {
+    /// Working directory (defaults to editor cwd)
+    #[serde(default)]
+    #[ts(optional)]
+    pub cwd: Option<String>,
+    /// Name shown in the running jobs list (defaults to the command line)
+    #[serde(default)]
+    #[ts(optional)]
+    pub title: Option<String>,
+    /// Pipe stdin so the job can be written to with `write()` (default: false)
+    #[serde(default)]
+    #[ts(optional)]
+    pub stdin: Option<bool>,
+}
+
 /// A single match from project-wide grep
 #[derive(Debug, Clone, Serialize, Deserialize, TS)]
 #[serde(rename_all = "camelCase")]
@@ -5337,6 +5431,7 @@ mod fromjs_impls {
         ProcessLimitsPackConfig,
         CreateTerminalOptions,
         CreateWindowWithTerminalOptions,
+        JobOptions,
     );
 
     impl<'js> rquickjs::IntoJs<'js> for TextPropertiesAtCursor {
@@ -6728,6 +6823,7 @@ mod tests {
             plugin_name: String::new(),
             custom_contexts: Vec::new(),
             terminal_bypass: false,
+            context_menu: false,
         }
     }
 
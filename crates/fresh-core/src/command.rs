@@ -35,6 +35,11 @@ pub struct Command {
     /// keyboard capture is on, matching the existing UX.
     #[serde(default)]
     pub terminal_bypass: bool,
+    /// When `true`, the command is also listed in the editor's right-click
+    /// context menu (below the built-in entries) whenever its custom
+    /// contexts are active. Default `false` — palette only.
+    #[serde(default)]
+    pub context_menu: bool,
 }
 
 /// A single suggestion item for autocomplete
@@ -35,6 +35,14 @@ pub enum HookArgs {
     /// fires once per refresh, not once per file.
     AfterFileExplorerChange { path: PathBuf },
 
+    /// A watched repo's checked-out branch changed (`git checkout`,
+    /// `git switch`, ...). Fires after the editor has reloaded open
+    /// buffers for the new branch, so plugins that derive state from the
+    /// working tree (diff gutters, branch labels) refresh in the same
+    /// pass. `branch` is the new branch name, or the short commit id for
+    /// a detached HEAD.
+    GitHeadChanged { branch: String },
+
     /// A buffer was closed
     BufferClosed { buffer_id: BufferId },
 
@@ -292,6 +300,14 @@ pub enum HookArgs {
         content_x: u16,
         /// Content area Y offset
         content_y: u16,
+        /// Buffer under the mouse (None outside any buffer panel)
+        buffer_id: Option<u64>,
+        /// 0-indexed buffer row (line number) under the mouse, accounting
+        /// for scroll. None outside any buffer.
+        buffer_row: Option<u32>,
+        /// 0-indexed byte column inside the buffer row. None outside any
+        /// buffer.
+        buffer_col: Option<u32>,
     },
 
     /// LSP server request (server -> client)
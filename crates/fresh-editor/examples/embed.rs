@@ -0,0 +1,65 @@
+//! A minimal ratatui app that embeds the editor below its own title bar.
+//!
+//!   cargo run -p fresh-editor --example embed -- [FILE]
+//!
+//! The app owns the terminal; the editor only draws into the area it is
+//! given. Ctrl+Q (the editor's quit binding) closes the app.
+
+use crossterm::event::{self, DisableMouseCapture, EnableMouseCapture};
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use crossterm::ExecutableCommand;
+use fresh::embed::{EmbedOptions, EmbeddedEditor};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::Style;
+use ratatui::widgets::Paragraph;
+use ratatui::Terminal;
+use std::io::stdout;
+use std::time::Duration;
+
+fn main() -> anyhow::Result<()> {
+    let mut editor = EmbeddedEditor::new(EmbedOptions::new())?;
+    if let Some(path) = std::env::args().nth(1) {
+        editor.open_file(std::path::Path::new(&path))?;
+    }
+
+    enable_raw_mode()?;
+    stdout().execute(EnterAlternateScreen)?;
+    stdout().execute(EnableMouseCapture)?;
+    let result = run(&mut editor);
+    stdout().execute(DisableMouseCapture)?;
+    stdout().execute(LeaveAlternateScreen)?;
+    disable_raw_mode()?;
+    result
+}
+
+fn run(editor: &mut EmbeddedEditor) -> anyhow::Result<()> {
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+    let mut needs_render = true;
+    while !editor.should_quit() {
+        if needs_render {
+            terminal.draw(|frame| {
+                let [title, body] = Layout::vertical([Constraint::Length(1), Constraint::Fill(1)])
+                    .areas(frame.area());
+                frame.render_widget(
+                    Paragraph::new(" Host application — Ctrl+Q to quit")
+                        .style(Style::new().reversed()),
+                    title,
+                );
+                editor.render(frame, body);
+            })?;
+            needs_render = false;
+        }
+        if event::poll(Duration::from_millis(50))? {
+            // Resizes re-render at the new size; the editor follows the
+            // area it is given.
+            let event = event::read()?;
+            needs_render |= matches!(event, event::Event::Resize(..));
+            needs_render |= editor.handle_event(event)?;
+        }
+        needs_render |= editor.tick()?;
+    }
+    Ok(())
+}
@@ -0,0 +1,19 @@
+//! Random event sequences against EditorState, checked by
+//! `fresh::event_fuzz` (cursor/marker/line-count invariants and a full
+//! undo/redo round trip).
+//!
+//! Run from `crates/fresh-editor`:
+//!   cargo +nightly fuzz run event_model
+//!
+//! A crash input replays through the same decoder in the proptest suite
+//! (`tests/event_fuzz_tests.rs`) via `fresh::event_fuzz::run`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Err(e) = fresh::event_fuzz::run(data) {
+        panic!("{e}");
+    }
+});
@@ -4,7 +4,8 @@ const editor = getEditor();
 // ═════════════════════════════════════════════════════════════════════
 //   DASHBOARD PLUGIN
 //
-//   Shows a TUI dashboard with weather, git, GitHub PRs, and disk
+//   Shows a TUI dashboard with quick actions, recent projects and
+//   files, the update notice, weather, git, GitHub PRs, and disk
 //   stats when there's no real work open — either at startup or
 //   after the user closes the last file buffer (instead of the
 //   default untitled scratch).
@@ -164,7 +165,7 @@ export type DashboardApi = {
      *  Returns true if at least one section was removed. */
     removeSection(name: string): boolean;
     /** Remove every registered section, including the bundled
-     *  built-ins (git, disk). */
+     *  built-ins (start, recent, git, disk). */
     clearAllSections(): void;
     /** Toggle the ambient auto-open behaviour for this session.
      *  Equivalent to setting `plugins.dashboard.auto-open` in the
@@ -1443,6 +1444,147 @@ const diskRefresh: SectionRefresh = async (ctx) => {
     }
 };
 
+// ── Start screen ───────────────────────────────────────────────────────
+//
+// The `start` and `recent` sections turn the dashboard into a welcome
+// screen: the update notice and a handful of quick actions first, then
+// the projects and files the user worked on last. Every row is a click
+// target, so Tab / j / k walk them and Return runs the focused one.
+
+const QUICK_ACTIONS: { label: string; action: string; hint: string }[] = [
+    { label: "Open File", action: "open", hint: "pick a file to edit" },
+    { label: "Open Folder", action: "switch_project", hint: "switch to another project" },
+    { label: "Calibrate Input", action: "calibrate_input", hint: "check the keys your terminal sends" },
+    { label: "Keybinding Editor", action: "open_keybinding_editor", hint: "view and change key bindings" },
+];
+
+const startRefresh: SectionRefresh = async (ctx) => {
+    // Set by the editor's release checker once it has found a newer
+    // release; the row runs the same Update Fresh action as the
+    // status-bar indicator.
+    const update = editor.getUpdateAvailable();
+    if (update) {
+        ctx.text("    ");
+        ctx.text(`Update available: v${update}`, {
+            color: "warn",
+            bold: true,
+            onClick: () => editor.executeAction("update_fresh"),
+        });
+        ctx.newline();
+    }
+    for (const qa of QUICK_ACTIONS) {
+        ctx.text("    ");
+        ctx.text(qa.label, {
+            color: "accent",
+            onClick: () => editor.executeAction(qa.action),
+        });
+        ctx.text(pad("", 20 - qa.label.length) + qa.hint, { color: "muted" });
+        ctx.newline();
+    }
+};
+
+const RECENT_LIMIT = 5;
+
+type RecentEntry = { path: string; savedAt: number };
+
+// Recent projects and files are read from the editor's own session
+// store, so they are whatever Fresh last restored from: one workspace
+// file per project root under `workspaces/`, one cursor/scroll state
+// file per edited file under `file_states/`. Parsing every workspace on
+// each 5s tick would be wasteful, so the lists are cached until the
+// dashboard is reopened.
+let recentCache: { projects: RecentEntry[]; files: RecentEntry[] } | null = null;
+
+// Inverse of `encode_path_for_filename` in src/workspace.rs: `_` stands
+// for a path separator and `%XX` for an escaped byte. The leading
+// separator is dropped by the encoder, so it is put back here (except
+// in front of a Windows drive letter).
+function decodeStateFilename(encoded: string): string {
+    if (encoded === "root") return "/";
+    let path = encoded.replace(/_/g, "/");
+    try {
+        path = decodeURIComponent(path);
+    } catch {
+        // Keep the undecoded name rather than dropping the entry.
+    }
+    return /^[A-Za-z]:/.test(path) ? path : "/" + path;
+}
+
+function newestFirst(entries: RecentEntry[]): RecentEntry[] {
+    return entries.sort((a, b) => b.savedAt - a.savedAt).slice(0, RECENT_LIMIT);
+}
+
+function loadRecent(): { projects: RecentEntry[]; files: RecentEntry[] } {
+    const dataDir = editor.getDataDir();
+    const projects: RecentEntry[] = [];
+    const seenRoots = new Set<string>();
+    const wsDir = editor.pathJoin(dataDir, "workspaces");
+    if (editor.fileExists(wsDir)) {
+        for (const e of editor.readDir(wsDir)) {
+            if (!e.is_file || !e.name.endsWith(".json")) continue;
+            const raw = editor.readFile(editor.pathJoin(wsDir, e.name));
+            if (raw === null) continue;
+            try {
+                const ws = JSON.parse(raw) as { working_dir?: string; saved_at?: number };
+                const root = ws.working_dir;
+                if (!root || seenRoots.has(root) || !editor.fileExists(root)) continue;
+                seenRoots.add(root);
+                projects.push({ path: root, savedAt: ws.saved_at ?? 0 });
+            } catch {
+                // A half-written workspace file is skipped, not fatal.
+            }
+        }
+    }
+    const files: RecentEntry[] = [];
+    const fsDir = editor.pathJoin(dataDir, "file_states");
+    if (editor.fileExists(fsDir)) {
+        for (const e of editor.readDir(fsDir)) {
+            if (!e.is_file || !e.name.endsWith(".json")) continue;
+            const path = decodeStateFilename(e.name.slice(0, -".json".length));
+            if (!editor.fileExists(path)) continue;
+            const raw = editor.readFile(editor.pathJoin(fsDir, e.name));
+            if (raw === null) continue;
+            try {
+                const st = JSON.parse(raw) as { saved_at?: number };
+                files.push({ path, savedAt: st.saved_at ?? 0 });
+            } catch {
+                // See above.
+            }
+        }
+    }
+    return { projects: newestFirst(projects), files: newestFirst(files) };
+}
+
+// Show `path` relative to HOME as `~/…` so long roots fit the frame.
+function tildify(path: string): string {
+    const home = editor.getEnv("HOME");
+    if (home && (path === home || path.startsWith(home + "/"))) {
+        return "~" + path.slice(home.length);
+    }
+    return path;
+}
+
+function recentRow(ctx: DashboardContext, label: string, path: string, onClick: () => void) {
+    ctx.text("    " + pad(label, 10), { color: "muted" });
+    ctx.text(tildify(path), { color: "value", onClick });
+    ctx.newline();
+}
+
+const recentRefresh: SectionRefresh = async (ctx) => {
+    if (recentCache === null) recentCache = loadRecent();
+    const { projects, files } = recentCache;
+    if (projects.length === 0 && files.length === 0) {
+        ctx.kv("recent", "nothing yet", "muted");
+        return;
+    }
+    projects.forEach((p, i) => {
+        recentRow(ctx, i === 0 ? "projects" : "", p.path, () => editor.switchProject(p.path));
+    });
+    files.forEach((f, i) => {
+        recentRow(ctx, i === 0 ? "files" : "", f.path, () => editor.openFile(f.path, null, null));
+    });
+};
+
 // ── Lifecycle ──────────────────────────────────────────────────────────
 
 // Fire-and-forget: refresh every 5s while the dashboard remains the
@@ -1488,6 +1630,7 @@ function bootstrapDashboard(bufferId: number) {
     // stale-data banner.
     fetchToken++;
     const myToken = fetchToken;
+    recentCache = null;
     for (const entry of registeredSections) {
         entry.draw = loadingDraw();
         void refreshSection(entry, myToken);
@@ -1757,9 +1900,12 @@ registerHandler(
 // change to the context contract surfaces here first.
 //
 // `weather` and `github` are opt-in — they hit the network on every
-// refresh, so we only register `git` and `disk` by default. Users
-// wire the others up from init.ts via the exported plugin API; see
-// the init.ts starter template for a ready-to-paste example.
+// refresh, so we only register `start`, `recent`, `git` and `disk` by
+// default. Users wire the others up from init.ts via the exported
+// plugin API; see the init.ts starter template for a ready-to-paste
+// example.
+registerSection("start", startRefresh);
+registerSection("recent", recentRefresh);
 registerSection("git", gitRefresh);
 registerSection("disk", diskRefresh);
 
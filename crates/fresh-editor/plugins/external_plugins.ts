@@ -0,0 +1,433 @@
+/// <reference path="./lib/fresh.d.ts" />
+const editor = getEditor();
+
+/**
+ * External Plugins - plugins written in any language, run as child processes
+ *
+ * Each `*.json` manifest in `<config dir>/external-plugins/` describes one
+ * plugin:
+ *
+ *   { "name": "shout", "command": "python3", "args": ["shout.py"], "cwd": "..." }
+ *
+ * The plugin is started as a job and speaks JSON-RPC 2.0 over stdio, one
+ * message per line. The editor sends an `initialize` request when the
+ * process starts, then notifications as the user interacts with what the
+ * plugin registered:
+ *
+ *   command/execute  { command }          a registered command or key ran
+ *   popup/result     { popup, action }    a popup button was picked
+ *   shutdown         {}                   the plugin is being stopped
+ *
+ * The plugin calls back with requests (see `METHODS` below) to read and edit
+ * buffers, register commands and keybindings, and show popups and panels.
+ * Everything a plugin registered goes away when its process exits.
+ *
+ * "External Plugins: Manage" lists the plugins and starts or stops the one
+ * picked. WASM modules aren't loaded directly; a WASM plugin runs through
+ * this protocol under a standalone runtime (`"command": "wasmtime"`).
+ */
+
+const MANIFEST_DIR = editor.pathJoin(editor.getConfigDir(), "external-plugins");
+const MANAGE_PROMPT = "external-plugins-manage";
+const KEY_LAYER = "external-plugins";
+const PANEL_MODE = "external-plugin-panel";
+
+/** JSON-RPC error codes used in responses */
+const METHOD_NOT_FOUND = -32601;
+const INVALID_PARAMS = -32602;
+const INTERNAL_ERROR = -32603;
+
+interface Manifest {
+  name: string;
+  command: string;
+  args: string[];
+  cwd?: string;
+}
+
+type PluginState = "stopped" | "starting" | "running" | "exited";
+
+interface ExternalPlugin {
+  manifest: Manifest;
+  state: PluginState;
+  job: JobHandle | null;
+  /** Registered command id -> palette name */
+  commands: Map<string, string>;
+  /** Key -> command id */
+  keys: Map<string, string>;
+  /** Panel id -> virtual buffer */
+  panels: Map<string, number>;
+}
+
+class RpcError extends Error {
+  code: number;
+  constructor(code: number, message: string) {
+    super(message);
+    this.code = code;
+  }
+}
+
+const plugins = new Map<string, ExternalPlugin>();
+
+// =============================================================================
+// Manifests
+// =============================================================================
+
+function parseManifest(text: string): Manifest {
+  const raw = JSON.parse(text);
+  if (typeof raw.name !== "string" || !raw.name) throw new Error("missing \"name\"");
+  if (typeof raw.command !== "string" || !raw.command) throw new Error("missing \"command\"");
+  const args = Array.isArray(raw.args) ? raw.args.map(String) : [];
+  const cwd = typeof raw.cwd === "string" ? raw.cwd : undefined;
+  return { name: raw.name, command: raw.command, args, cwd };
+}
+
+/** Pick up manifests added since the last scan. Known plugins are kept as is. */
+function scanManifests(): void {
+  if (!editor.fileExists(MANIFEST_DIR)) return;
+  for (const entry of editor.readDir(MANIFEST_DIR)) {
+    if (!entry.is_file || !entry.name.endsWith(".json")) continue;
+    const file = editor.pathJoin(MANIFEST_DIR, entry.name);
+    const text = editor.readFile(file);
+    if (text === null) continue;
+    let manifest: Manifest;
+    try {
+      manifest = parseManifest(text);
+    } catch (e) {
+      editor.setStatus(editor.t("status.bad_manifest", { file: entry.name, error: String(e) }));
+      continue;
+    }
+    if (!plugins.has(manifest.name)) {
+      plugins.set(manifest.name, {
+        manifest,
+        state: "stopped",
+        job: null,
+        commands: new Map(),
+        keys: new Map(),
+        panels: new Map(),
+      });
+    }
+  }
+}
+
+// =============================================================================
+// Process lifecycle
+// =============================================================================
+
+let nextRequestId = 1;
+
+function send(plugin: ExternalPlugin, message: Record<string, unknown>): void {
+  plugin.job?.write(JSON.stringify({ jsonrpc: "2.0", ...message }) + "\n");
+}
+
+function notify(plugin: ExternalPlugin, method: string, params: Record<string, unknown>): void {
+  send(plugin, { method, params });
+}
+
+function startPlugin(plugin: ExternalPlugin): void {
+  if (plugin.job) return;
+  const { name, command, args, cwd } = plugin.manifest;
+  const job = editor.spawnJob(command, args, {
+    title: name,
+    stdin: true,
+    cwd: cwd ?? MANIFEST_DIR,
+  });
+  plugin.job = job;
+  plugin.state = "starting";
+
+  job.onStdout((line) => {
+    void onMessage(plugin, line);
+  });
+  job.onStderr((line) => editor.debug(`[${name}] ${line}`));
+  job.then((result) => {
+    if (plugin.job !== job) return;
+    plugin.job = null;
+    plugin.state = "exited";
+    releaseRegistrations(plugin);
+    editor.setStatus(
+      editor.t("status.exited", { name, code: String(result.exit_code) }),
+    );
+  });
+
+  send(plugin, {
+    id: nextRequestId++,
+    method: "initialize",
+    params: { protocolVersion: 1, cwd: editor.getCwd(), name },
+  });
+}
+
+function stopPlugin(plugin: ExternalPlugin): void {
+  const job = plugin.job;
+  if (!job) return;
+  notify(plugin, "shutdown", {});
+  job.closeStdin();
+  plugin.job = null;
+  plugin.state = "stopped";
+  releaseRegistrations(plugin);
+  void job.kill();
+  editor.setStatus(editor.t("status.stopped", { name: plugin.manifest.name }));
+}
+
+/** Drop the commands, keys and panels a plugin registered. */
+function releaseRegistrations(plugin: ExternalPlugin): void {
+  for (const name of plugin.commands.values()) {
+    editor.unregisterCommand(name);
+  }
+  plugin.commands.clear();
+  if (plugin.keys.size > 0) {
+    plugin.keys.clear();
+    refreshKeyLayer();
+  }
+  for (const bufferId of plugin.panels.values()) {
+    editor.closeBuffer(bufferId);
+  }
+  plugin.panels.clear();
+}
+
+// =============================================================================
+// Messages from the plugin
+// =============================================================================
+
+async function onMessage(plugin: ExternalPlugin, line: string): Promise<void> {
+  if (!line.trim()) return;
+  let message: { id?: number | string; method?: string; params?: unknown; result?: unknown; error?: unknown };
+  try {
+    message = JSON.parse(line);
+  } catch (_e) {
+    editor.debug(`[${plugin.manifest.name}] not JSON-RPC: ${line}`);
+    return;
+  }
+
+  // A response: the only request the editor sends is `initialize`.
+  if (message.method === undefined) {
+    if (plugin.state === "starting" && message.error === undefined) {
+      plugin.state = "running";
+      editor.setStatus(editor.t("status.started", { name: plugin.manifest.name }));
+    }
+    return;
+  }
+
+  const params = (message.params ?? {}) as Record<string, unknown>;
+  try {
+    const result = await dispatch(plugin, message.method, params);
+    if (message.id !== undefined) send(plugin, { id: message.id, result: result ?? null });
+  } catch (e) {
+    if (message.id === undefined) return;
+    const code = e instanceof RpcError ? e.code : INTERNAL_ERROR;
+    send(plugin, { id: message.id, error: { code, message: String((e as Error).message ?? e) } });
+  }
+}
+
+function num(params: Record<string, unknown>, key: string): number {
+  const value = params[key];
+  if (typeof value !== "number") throw new RpcError(INVALID_PARAMS, `"${key}" must be a number`);
+  return value;
+}
+
+function str(params: Record<string, unknown>, key: string): string {
+  const value = params[key];
+  if (typeof value !== "string") throw new RpcError(INVALID_PARAMS, `"${key}" must be a string`);
+  return value;
+}
+
+/** `bufferId` param, defaulting to the active buffer */
+function bufferOf(params: Record<string, unknown>): number {
+  return typeof params.bufferId === "number" ? params.bufferId : editor.getActiveBufferId();
+}
+
+type Method = (plugin: ExternalPlugin, params: Record<string, unknown>) => unknown;
+
+const METHODS: Record<string, Method> = {
+  "buffer/active": () => {
+    const bufferId = editor.getActiveBufferId();
+    return { bufferId, path: editor.getBufferPath(bufferId) || null };
+  },
+  "buffer/getText": (_plugin, params) => {
+    const bufferId = bufferOf(params);
+    const start = typeof params.start === "number" ? params.start : 0;
+    const end = typeof params.end === "number" ? params.end : editor.getBufferLength(bufferId);
+    return editor.getBufferText(bufferId, start, end);
+  },
+  "buffer/length": (_plugin, params) => editor.getBufferLength(bufferOf(params)),
+  "buffer/cursor": () => editor.getCursorPosition(),
+  "buffer/insert": (_plugin, params) =>
+    editor.insertText(bufferOf(params), num(params, "position"), str(params, "text")),
+  "buffer/delete": (_plugin, params) =>
+    editor.deleteRange(bufferOf(params), num(params, "start"), num(params, "end")),
+  "editor/setStatus": (_plugin, params) => editor.setStatus(str(params, "message")),
+  "commands/register": (plugin, params) => registerCommand(plugin, params),
+  "keybindings/register": (plugin, params) => {
+    const key = str(params, "key");
+    const command = str(params, "command");
+    if (!plugin.commands.has(command)) {
+      throw new RpcError(INVALID_PARAMS, `unknown command "${command}"`);
+    }
+    plugin.keys.set(key, command);
+    refreshKeyLayer();
+    return true;
+  },
+  "ui/showPopup": (plugin, params) =>
+    editor.showActionPopup({
+      id: `${popupPrefix(plugin)}${str(params, "id")}`,
+      title: str(params, "title"),
+      message: typeof params.message === "string" ? params.message : "",
+      actions: (Array.isArray(params.actions) ? params.actions : []).map((a: Record<string, unknown>) => ({
+        id: String(a.id),
+        label: String(a.label ?? a.id),
+      })),
+    }),
+  "ui/showPanel": (plugin, params) => showPanel(plugin, params),
+};
+
+function dispatch(plugin: ExternalPlugin, method: string, params: Record<string, unknown>): unknown {
+  const handler = METHODS[method];
+  if (!handler) throw new RpcError(METHOD_NOT_FOUND, `unknown method "${method}"`);
+  return handler(plugin, params);
+}
+
+// =============================================================================
+// Commands and keybindings
+// =============================================================================
+
+/** Handler names are looked up as JS identifiers, so keep them to word characters. */
+function handlerName(plugin: ExternalPlugin, command: string): string {
+  const ident = (s: string) => s.replace(/\W/g, "_");
+  return `external_plugin_${ident(plugin.manifest.name)}__${ident(command)}`;
+}
+
+function registerCommand(plugin: ExternalPlugin, params: Record<string, unknown>): boolean {
+  const id = str(params, "id");
+  const name = str(params, "name");
+  const description = typeof params.description === "string" ? params.description : "";
+  const handler = handlerName(plugin, id);
+  registerHandler(handler, () => {
+    if (plugin.commands.has(id)) notify(plugin, "command/execute", { command: id });
+  });
+  const previous = plugin.commands.get(id);
+  if (previous !== undefined && previous !== name) editor.unregisterCommand(previous);
+  plugin.commands.set(id, name);
+  editor.registerCommand(name, description, handler, null);
+  return true;
+}
+
+/**
+ * Rebuild the key layer from every running plugin's keys. The layer is a
+ * mode switched on as a custom context rather than as the editor mode, so
+ * it never displaces vi mode, markdown source and the like; a key the
+ * active mode binds still goes to that mode.
+ */
+function refreshKeyLayer(): void {
+  const bindings: string[][] = [];
+  for (const plugin of plugins.values()) {
+    for (const [key, command] of plugin.keys) {
+      bindings.push([key, handlerName(plugin, command)]);
+    }
+  }
+  editor.defineMode(KEY_LAYER, bindings, false);
+  editor.setContext(KEY_LAYER, bindings.length > 0);
+}
+
+// =============================================================================
+// Popups and panels
+// =============================================================================
+
+function popupPrefix(plugin: ExternalPlugin): string {
+  return `external-plugin:${plugin.manifest.name}:`;
+}
+
+editor.on("action_popup_result", (data) => {
+  for (const plugin of plugins.values()) {
+    const prefix = popupPrefix(plugin);
+    if (data.popup_id.startsWith(prefix)) {
+      notify(plugin, "popup/result", {
+        popup: data.popup_id.slice(prefix.length),
+        action: data.action_id,
+      });
+      return;
+    }
+  }
+});
+
+editor.defineMode(PANEL_MODE, [["q", "external_plugin_close_panel"]], true);
+
+async function showPanel(plugin: ExternalPlugin, params: Record<string, unknown>): Promise<number> {
+  const id = str(params, "id");
+  const lines = Array.isArray(params.lines) ? params.lines.map(String) : [];
+  const entries = lines.map((text) => ({ text: text + "\n" }));
+  const existing = plugin.panels.get(id);
+  if (existing !== undefined) {
+    editor.setVirtualBufferContent(existing, entries);
+    return existing;
+  }
+  const title = typeof params.title === "string" ? params.title : id;
+  const result = await editor.createVirtualBufferInSplit({
+    name: `*${title}*`,
+    mode: PANEL_MODE,
+    readOnly: true,
+    entries,
+    ratio: 0.7,
+    direction: "horizontal",
+    panelId: `${popupPrefix(plugin)}${id}`,
+    showLineNumbers: false,
+    editingDisabled: true,
+  });
+  if (result === null) throw new RpcError(INTERNAL_ERROR, "failed to open panel");
+  plugin.panels.set(id, result.bufferId);
+  return result.bufferId;
+}
+
+function external_plugin_close_panel(): void {
+  editor.closeBuffer(editor.getActiveBufferId());
+}
+registerHandler("external_plugin_close_panel", external_plugin_close_panel);
+
+editor.on("buffer_closed", (data) => {
+  for (const plugin of plugins.values()) {
+    for (const [id, bufferId] of plugin.panels) {
+      if (bufferId === data.buffer_id) plugin.panels.delete(id);
+    }
+  }
+});
+
+// =============================================================================
+// Plugin manager
+// =============================================================================
+
+function external_plugins_manage(): void {
+  scanManifests();
+  if (plugins.size === 0) {
+    editor.setStatus(editor.t("status.no_plugins", { dir: MANIFEST_DIR }));
+    return;
+  }
+  const suggestions: PromptSuggestion[] = [...plugins.values()].map((plugin) => ({
+    text: plugin.manifest.name,
+    description: editor.t(`state.${plugin.state}`),
+    value: plugin.manifest.name,
+  }));
+  editor.startPrompt(editor.t("prompt.manage"), MANAGE_PROMPT);
+  editor.setPromptSuggestions(suggestions);
+}
+registerHandler("external_plugins_manage", external_plugins_manage);
+
+editor.on("prompt_confirmed", (data) => {
+  if (data.prompt_type !== MANAGE_PROMPT) return;
+  const plugin = plugins.get(data.input.trim());
+  if (!plugin) return;
+  if (plugin.job) {
+    stopPlugin(plugin);
+  } else {
+    startPlugin(plugin);
+  }
+});
+
+editor.registerCommand(
+  "%cmd.manage",
+  "%cmd.manage_desc",
+  "external_plugins_manage",
+  null,
+);
+
+// Start every plugin that has a manifest.
+scanManifests();
+for (const plugin of plugins.values()) {
+  startPlugin(plugin);
+}
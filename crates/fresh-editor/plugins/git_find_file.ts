@@ -25,7 +25,8 @@ const finder = new Finder<GitFile>(editor, {
     label: file.rel,
     location: { file: file.abs, line: 1, column: 1 },
   }),
-  preview: false, // No preview for file finder
+  // The floating overlay draws its own preview pane (see `prompt` below).
+  preview: false,
   maxResults: 100,
 });
 
@@ -67,6 +68,9 @@ function start_git_find_file() : void {
       load: loadGitFiles,
       // Uses built-in fuzzy filter by default
     },
+    // The overlay previews the selected file; on remote workspaces only
+    // its head is fetched.
+    floatingOverlay: true,
   });
 }
 registerHandler("start_git_find_file", start_git_find_file);
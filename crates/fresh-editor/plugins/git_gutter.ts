@@ -444,6 +444,14 @@ editor.on("after_file_save", (args) => {
 
   return true;
 });
+editor.on("git_head_changed", () => {
+  // A branch switch changes what every open file is diffed against
+  for (const bufferId of bufferStates.keys()) {
+    updateGitGutter(bufferId);
+  }
+
+  return true;
+});
 editor.on("buffer_closed", (args) => {
   bufferStates.delete(args.buffer_id);
   return true;
@@ -131,6 +131,74 @@ editor.on("path_changed", async (args) => {
   await refreshForActiveBuffer();
 });
 
+// === Branch switcher ===
+//
+// Clicking the branch segment (or running "Git: Switch Branch") lists the
+// local branches, most recently committed first, and checks out the one
+// picked. The HEAD watcher above then refreshes the label.
+
+const SWITCH_BRANCH_PROMPT = "git-switch-branch";
+
+async function git_switch_branch(): Promise<void> {
+  const cwd = gitCwdCandidate(editor);
+  const result = await editor.spawnProcess(
+    "git",
+    ["for-each-ref", "--sort=-committerdate", "--format=%(refname:short)", "refs/heads"],
+    cwd,
+  );
+  if (result.exit_code !== 0) {
+    editor.setStatus(editor.t("status.not_in_git"));
+    return;
+  }
+  const branches = result.stdout
+    .split("\n")
+    .map((line) => line.trim())
+    .filter((line) => line.length > 0);
+  if (branches.length === 0) {
+    editor.setStatus(editor.t("status.no_branches"));
+    return;
+  }
+  const suggestions: PromptSuggestion[] = branches.map((branch) => ({
+    text: branch,
+    description: branch === lastDetectedBranch ? editor.t("prompt.current_branch") : undefined,
+    value: branch,
+  }));
+  editor.startPrompt(editor.t("prompt.switch_branch"), SWITCH_BRANCH_PROMPT);
+  editor.setPromptSuggestions(suggestions);
+}
+registerHandler("git_switch_branch", git_switch_branch);
+
+editor.on("prompt_confirmed", async (data) => {
+  if (data.prompt_type !== SWITCH_BRANCH_PROMPT) return;
+  const branch = data.input.trim();
+  if (!branch || branch === lastDetectedBranch) return;
+  const result = await editor.spawnProcess(
+    "git",
+    ["checkout", branch],
+    gitCwdCandidate(editor),
+  );
+  if (result.exit_code === 0) {
+    editor.setStatus(editor.t("status.switched_branch", { branch }));
+    await refreshForActiveBuffer();
+  } else {
+    editor.setStatus(
+      editor.t("status.switch_branch_failed", { branch, error: result.stderr.trim() }),
+    );
+  }
+});
+
+editor.on("status_bar_token_clicked", async (data) => {
+  if (data.plugin_name !== "git_statusbar" || data.token_name !== GIT_BRANCH) return;
+  await git_switch_branch();
+});
+
+editor.registerCommand(
+  "%cmd.switch_branch",
+  "%cmd.switch_branch_desc",
+  "git_switch_branch",
+  null,
+);
+
 // Kick off the first detection at load time so the status bar populates
 // before any user event fires.
 refreshForActiveBuffer();
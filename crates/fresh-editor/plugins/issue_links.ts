@@ -0,0 +1,377 @@
+/// <reference path="./lib/fresh.d.ts" />
+
+import { git, resolveGitRepoForPath } from "./lib/git_repo.ts";
+
+const editor = getEditor();
+
+/**
+ * Issue Links Plugin - GitHub/GitLab issue and PR references as links
+ *
+ * `#1234` and `org/repo#123` inside comments (or anywhere in a commit
+ * message buffer) are underlined and carry their URL, so terminals with
+ * OSC 8 support open them on click. The URL comes from the `origin`
+ * remote of the file's repository: `#1234` points at that repository,
+ * `org/repo#123` at another one on the same host.
+ *
+ * Hovering a reference, or moving the cursor onto it, shows it in the
+ * status bar. With `fetchTitles` on, the issue title is fetched from the
+ * host's API and shown instead; titles are cached for the session and
+ * requests are spaced out (see `FETCH_INTERVAL_MS`).
+ *
+ * "Issue Links: Open Reference" opens the reference under the cursor in
+ * the browser.
+ */
+
+// =============================================================================
+// Settings
+// =============================================================================
+
+editor.defineConfigString("urlTemplate", {
+  default: "",
+  description:
+    "Issue URL template with {host}, {repo} and {number}. Empty picks GitHub or GitLab style from the remote host.",
+});
+editor.defineConfigBoolean("fetchTitles", {
+  default: false,
+  description: "Fetch issue titles from the GitHub/GitLab API for hover previews. Sends requests to the remote host.",
+});
+
+interface IssueLinkSettings {
+  urlTemplate: string;
+  fetchTitles: boolean;
+}
+
+function settings(): IssueLinkSettings {
+  const cfg = (editor.getPluginConfig() ?? {}) as Partial<IssueLinkSettings>;
+  return {
+    urlTemplate: cfg.urlTemplate ?? "",
+    fetchTitles: cfg.fetchTitles ?? false,
+  };
+}
+
+// =============================================================================
+// Types and State
+// =============================================================================
+
+/** Host and `owner/name` path of a git remote */
+interface Remote {
+  host: string;
+  repo: string;
+}
+
+interface IssueRef {
+  /** Byte columns of the reference within its line */
+  start: number;
+  end: number;
+  /** `#12` or `org/repo#12`, as written */
+  label: string;
+  repo: string;
+  number: string;
+  url: string;
+}
+
+const NAMESPACE = "issue-links";
+const COMMIT_MESSAGE_FILES = ["COMMIT_EDITMSG", "MERGE_MSG", "SQUASH_MSG", "TAG_EDITMSG"];
+/** At most one title request per interval, one at a time */
+const FETCH_INTERVAL_MS = 2000;
+/** Pause after the API reports a rate limit */
+const RATE_LIMIT_BACKOFF_MS = 10 * 60 * 1000;
+
+// Remote per buffer; `null` once resolved to "no usable remote".
+const bufferRemotes: Map<number, Remote | null> = new Map();
+const resolving: Set<number> = new Set();
+
+// Fetched titles by issue URL; `null` when the lookup failed.
+const titles: Map<string, string | null> = new Map();
+let fetchInFlight = false;
+let nextFetchAt = 0;
+
+// Last reference shown in the status bar, so hovering along it doesn't
+// rewrite the status on every mouse move.
+let shownRef: string | null = null;
+
+// =============================================================================
+// Remote resolution
+// =============================================================================
+
+/** Parse `https://host/owner/repo.git`, `ssh://git@host:22/owner/repo` or `git@host:owner/repo` */
+function parseRemote(url: string): Remote | null {
+  const trimmed = url.trim().replace(/\/$/, "").replace(/\.git$/, "");
+  const m =
+    trimmed.match(/^[\w+.-]+:\/\/(?:[^@/]+@)?([^/:]+)(?::\d+)?\/(.+)$/) ??
+    trimmed.match(/^(?:[^@/]+@)?([^/:]+):(.+)$/);
+  if (!m || !m[2].includes("/")) return null;
+  return { host: m[1], repo: m[2] };
+}
+
+async function resolveRemote(bufferId: number): Promise<void> {
+  if (resolving.has(bufferId)) return;
+  const path = editor.getBufferPath(bufferId);
+  if (!path) {
+    bufferRemotes.set(bufferId, null);
+    return;
+  }
+  resolving.add(bufferId);
+  try {
+    const repo = await resolveGitRepoForPath(editor, path);
+    let remote: Remote | null = null;
+    if (repo) {
+      const result = await git(editor, repo, ["remote", "get-url", "origin"]);
+      if (result.exit_code === 0) remote = parseRemote(result.stdout);
+    }
+    bufferRemotes.set(bufferId, remote);
+    if (remote) editor.refreshLines(bufferId);
+  } finally {
+    resolving.delete(bufferId);
+  }
+}
+
+function issueUrl(remote: Remote, repo: string, number: string): string {
+  let template = settings().urlTemplate;
+  if (!template) {
+    template = remote.host.includes("gitlab")
+      ? "https://{host}/{repo}/-/issues/{number}"
+      : "https://{host}/{repo}/issues/{number}";
+  }
+  return template
+    .replace(/\{host\}/g, remote.host)
+    .replace(/\{repo\}/g, repo)
+    .replace(/\{number\}/g, number);
+}
+
+// =============================================================================
+// Reference detection
+// =============================================================================
+
+interface CommentSyntax {
+  prefix: string | null;
+  blockStart: string | null;
+  blockEnd: string | null;
+}
+
+/** Comment syntax of a buffer, `"all"` for commit messages, `null` when it has none. */
+function commentSyntax(bufferId: number): CommentSyntax | "all" | null {
+  const path = editor.getBufferPath(bufferId);
+  if (path && COMMIT_MESSAGE_FILES.includes(editor.pathBasename(path))) return "all";
+  const info = editor.getBufferInfo(bufferId);
+  if (!info) return null;
+  const config = editor.getConfig() as {
+    languages?: Record<string, {
+      comment_prefix?: string | null;
+      block_comment_start?: string | null;
+      block_comment_end?: string | null;
+    }>;
+  };
+  const lang = config.languages?.[info.language];
+  if (!lang || (!lang.comment_prefix && !lang.block_comment_start)) return null;
+  return {
+    prefix: lang.comment_prefix ?? null,
+    blockStart: lang.block_comment_start ?? null,
+    blockEnd: lang.block_comment_end ?? null,
+  };
+}
+
+/**
+ * Character ranges of `line` that are comment text. Line-local: a line
+ * comment runs to the end of the line, a block comment to its end marker,
+ * and a line starting with `*` or holding only a block end is taken as the
+ * inside of a block comment. Strings containing comment markers can fool it.
+ */
+function commentRanges(line: string, syntax: CommentSyntax): [number, number][] {
+  const ranges: [number, number][] = [];
+  const { prefix, blockStart, blockEnd } = syntax;
+  let from = 0;
+  const trimmed = line.trimStart();
+  if (blockEnd && (trimmed.startsWith("*") || (line.includes(blockEnd) && !(blockStart && line.includes(blockStart))))) {
+    const end = line.indexOf(blockEnd);
+    from = end >= 0 ? end + blockEnd.length : line.length;
+    ranges.push([0, from]);
+  }
+  while (from < line.length) {
+    const lineAt = prefix ? line.indexOf(prefix, from) : -1;
+    const blockAt = blockStart ? line.indexOf(blockStart, from) : -1;
+    if (lineAt >= 0 && (blockAt < 0 || lineAt < blockAt)) {
+      ranges.push([lineAt + prefix!.length, line.length]);
+      break;
+    }
+    if (blockAt < 0) break;
+    const bodyStart = blockAt + blockStart!.length;
+    const end = blockEnd ? line.indexOf(blockEnd, bodyStart) : -1;
+    ranges.push([bodyStart, end >= 0 ? end : line.length]);
+    from = end >= 0 ? end + blockEnd!.length : line.length;
+  }
+  return ranges;
+}
+
+const REF_PATTERN = /([A-Za-z0-9_.-]+\/[A-Za-z0-9_.-]+)?#(\d+)(?!\w)/g;
+
+function findRefs(line: string, syntax: CommentSyntax | "all", remote: Remote): IssueRef[] {
+  const ranges: [number, number][] = syntax === "all" ? [[0, line.length]] : commentRanges(line, syntax);
+  const refs: IssueRef[] = [];
+  for (const [from, to] of ranges) {
+    const text = line.slice(from, to);
+    for (const m of text.matchAll(REF_PATTERN)) {
+      const index = from + m.index!;
+      // `abc#1` or `path/to/x#1` is not a reference.
+      if (index > 0 && /[\w/#&]/.test(line[index - 1])) continue;
+      const repo = m[1] ?? remote.repo;
+      const start = editor.utf8ByteLength(line.slice(0, index));
+      refs.push({
+        start,
+        end: start + editor.utf8ByteLength(m[0]),
+        label: m[0],
+        repo,
+        number: m[2],
+        url: issueUrl(remote, repo, m[2]),
+      });
+    }
+  }
+  return refs;
+}
+
+/** The reference at byte column `col` of line `line` in the active buffer */
+async function refAt(bufferId: number, line: number, col: number): Promise<IssueRef | null> {
+  const remote = bufferRemotes.get(bufferId);
+  const syntax = commentSyntax(bufferId);
+  if (!remote || !syntax || bufferId !== editor.getActiveBufferId()) return null;
+  const start = await editor.getLineStartPosition(line);
+  const end = await editor.getLineEndPosition(line);
+  if (start === null || end === null) return null;
+  const text = await editor.getBufferText(bufferId, start, end);
+  return findRefs(text, syntax, remote).find((r) => r.start <= col && col < r.end) ?? null;
+}
+
+// =============================================================================
+// Decoration
+// =============================================================================
+
+editor.on("lines_changed", (data) => {
+  const remote = bufferRemotes.get(data.buffer_id);
+  if (remote === undefined) {
+    void resolveRemote(data.buffer_id);
+    return;
+  }
+  if (remote === null) return;
+  const syntax = commentSyntax(data.buffer_id);
+  if (!syntax) return;
+  for (const line of data.lines) {
+    editor.clearOverlaysInRangeForNamespace(data.buffer_id, NAMESPACE, line.byte_start, line.byte_end);
+    for (const ref of findRefs(line.content, syntax, remote)) {
+      editor.addOverlay(data.buffer_id, NAMESPACE, line.byte_start + ref.start, line.byte_start + ref.end, {
+        underline: true,
+        url: ref.url,
+      });
+    }
+  }
+});
+
+editor.on("buffer_closed", (data) => {
+  bufferRemotes.delete(data.buffer_id);
+});
+
+// =============================================================================
+// Previews
+// =============================================================================
+
+function apiUrl(host: string, repo: string, number: string): string {
+  if (host.includes("gitlab")) {
+    return `https://${host}/api/v4/projects/${encodeURIComponent(repo)}/issues/${number}`;
+  }
+  const base = host === "github.com" ? "https://api.github.com" : `https://${host}/api/v3`;
+  return `${base}/repos/${repo}/issues/${number}`;
+}
+
+/** Fetch the title of `ref` if allowed right now; shows it when it arrives. */
+async function fetchTitle(ref: IssueRef, host: string): Promise<void> {
+  if (fetchInFlight || Date.now() < nextFetchAt) return;
+  fetchInFlight = true;
+  nextFetchAt = Date.now() + FETCH_INTERVAL_MS;
+  const target = editor.pathJoin(editor.getTempDir(), "fresh-issue-links.json");
+  try {
+    const result = await editor.httpFetch(apiUrl(host, ref.repo, ref.number), target);
+    if (result.exit_code === 403 || result.exit_code === 429) {
+      nextFetchAt = Date.now() + RATE_LIMIT_BACKOFF_MS;
+      editor.setStatus(editor.t("status.rate_limited"));
+      return;
+    }
+    let title: string | null = null;
+    if (result.exit_code === 0) {
+      try {
+        const body = JSON.parse(editor.readFile(target) ?? "{}");
+        if (typeof body.title === "string") title = body.title;
+      } catch (_e) {
+        // Not JSON: treat as a failed lookup.
+      }
+    }
+    titles.set(ref.url, title);
+    if (shownRef === ref.url) showPreview(ref, host);
+  } finally {
+    fetchInFlight = false;
+  }
+}
+
+function showPreview(ref: IssueRef, host: string): void {
+  shownRef = ref.url;
+  const title = titles.get(ref.url);
+  if (title) {
+    editor.setStatus(editor.t("status.preview", { ref: ref.label, title }));
+    return;
+  }
+  editor.setStatus(editor.t("status.link", { ref: ref.label, url: ref.url }));
+  if (title === undefined && settings().fetchTitles) void fetchTitle(ref, host);
+}
+
+async function preview(bufferId: number, line: number, col: number): Promise<void> {
+  const ref = await refAt(bufferId, line, col);
+  if (!ref) {
+    shownRef = null;
+    return;
+  }
+  if (ref.url === shownRef) return;
+  showPreview(ref, bufferRemotes.get(bufferId)!.host);
+}
+
+editor.on("mouse_move", (data) => {
+  if (data.buffer_id === null || data.buffer_row === null || data.buffer_col === null) return;
+  void preview(data.buffer_id, data.buffer_row, data.buffer_col);
+});
+
+editor.on("cursor_moved", async (data) => {
+  // `line` is 1-indexed here.
+  const line = data.line - 1;
+  const start = await editor.getLineStartPosition(line);
+  if (start === null) return;
+  await preview(data.buffer_id, line, data.new_position - start);
+});
+
+// =============================================================================
+// Commands
+// =============================================================================
+
+async function issue_links_open(): Promise<void> {
+  const cursor = editor.getPrimaryCursor();
+  if (!cursor || cursor.line === null) return;
+  const start = await editor.getLineStartPosition(cursor.line);
+  const ref = start === null ? null : await refAt(editor.getActiveBufferId(), cursor.line, cursor.position - start);
+  if (!ref) {
+    editor.setStatus(editor.t("status.no_reference"));
+    return;
+  }
+  // Only one of the two helpers exists on a given platform.
+  editor.spawnProcess("xdg-open", [ref.url]);
+  editor.spawnProcess("open", [ref.url]);
+  editor.setStatus(editor.t("status.opening", { url: ref.url }));
+}
+registerHandler("issue_links_open", issue_links_open);
+
+editor.registerCommand(
+  "%cmd.open",
+  "%cmd.open_desc",
+  "issue_links_open",
+  null,
+);
+
+// Pick up buffers that are already open.
+for (const buffer of editor.listBuffers()) {
+  void resolveRemote(buffer.id);
+}
@@ -908,6 +908,20 @@ type CreateTerminalOptions = {
 	*/
 	commandAllowlist?: Array<string>;
 };
+type JobOptions = {
+	/**
+	* Working directory (defaults to editor cwd)
+	*/
+	cwd?: string;
+	/**
+	* Name shown in the running jobs list (defaults to the command line)
+	*/
+	title?: string;
+	/**
+	* Pipe stdin so the job can be written to with `write()` (default: false)
+	*/
+	stdin?: boolean;
+};
 type CursorInfo = {
 	/**
 	* Byte position of the cursor
@@ -2195,9 +2209,13 @@ interface EditorAPI {
 	* virtual mode (from `defineMode()`) matches. This is for plugin-defined
 	* contexts only (e.g. `"tour-active"`, `"review-mode"`), not built-in
 	* editor modes.
+	* 
+	* Pass `{ contextMenu: true }` in `options` to also list the command in
+	* the editor's right-click context menu.
 	*/
 	registerCommand(name: string, description: string, handlerName: string, context?: string | null, options?: {
 		terminalBypass?: boolean;
+		contextMenu?: boolean;
 	} | null): boolean;
 	/**
 	* Unregister a command by name
@@ -2205,6 +2223,10 @@ interface EditorAPI {
 	unregisterCommand(name: string): boolean;
 	/**
 	* Set a context (for keybinding conditions)
+	* 
+	* A context named after a mode from `defineMode()` also acts as a key
+	* layer: its bindings apply alongside the active editor mode, which
+	* keeps any key it binds itself.
 	*/
 	setContext(name: string, active: boolean): boolean;
 	/**
@@ -2382,6 +2404,11 @@ interface EditorAPI {
 	*/
 	openFileInBackground(path: string, windowId?: number): boolean;
 	/**
+	* Switch the active window to the project rooted at `path`, the
+	* same as confirming the Switch Project prompt with that directory.
+	*/
+	switchProject(path: string): boolean;
+	/**
 	* Open a file in a specific split
 	*/
 	openFileInSplit(splitId: number, path: string, line: number, column: number): boolean;
@@ -2481,6 +2508,11 @@ interface EditorAPI {
 	*/
 	getAuthorityLabel(): string;
 	/**
+	* Newer Fresh release reported by the update checker (e.g.
+	* `"0.3.1"`), or `null` when there is none to offer.
+	*/
+	getUpdateAvailable(): string | null;
+	/**
 	* Current Workspace Trust level for the active project: `"restricted"`,
 	* `"trusted"`, or `"blocked"` (empty when unavailable). Exposed to JS as
 	* `editor.workspaceTrustLevel()`. Plugins that run repo-controlled work
@@ -2954,6 +2986,17 @@ interface EditorAPI {
 	*/
 	clearConcealsInRangeForNamespace(bufferId: number, namespace: string, start: number, end: number): boolean;
 	/**
+	* Mark bytes [start, end) read-only: user edits that would change them
+	* are refused, showing `hint` (or a generic message) in the status bar.
+	* Edits made through `insertText` / `deleteRange` still apply, so the
+	* plugin can keep rewriting the text it protects.
+	*/
+	addReadOnlyRegion(bufferId: number, namespace: string, start: number, end: number, hint?: string): boolean;
+	/**
+	* Clear all read-only regions in a namespace
+	*/
+	clearReadOnlyRegions(bufferId: number, namespace: string): boolean;
+	/**
 	* Add a collapsed fold range. Hides bytes [start, end) from
 	* rendering — the line containing `start - 1` (the fold "header")
 	* stays visible, while subsequent lines covered by the range are
@@ -3763,6 +3806,11 @@ interface EditorAPI {
 	*/
 	killBackgroundProcess(processId: number): boolean;
 	/**
+	* Update the progress shown for a running job in the running jobs
+	* list: a short message and an optional completion percentage (0-100)
+	*/
+	setJobProgress(jobId: number, message: string, percent?: number): boolean;
+	/**
 	* Create a new terminal in a split (async, returns TerminalResult)
 	*/
 	createTerminal(opts?: CreateTerminalOptions): Promise<TerminalResult>;
@@ -3917,6 +3965,14 @@ interface HookEventMap {
 	after_file_explorer_change: {
 		path: string;
 	};
+	/**
+	* Fired when a watched repo's checked-out branch changes, after open
+	* buffers have been reloaded for it. `branch` is the new branch name,
+	* or the short commit id for a detached HEAD.
+	*/
+	git_head_changed: {
+		branch: string;
+	};
 	// ── text edits ───────────────────────────────────────────────────────────
 	before_insert: {
 		buffer_id: number;
@@ -4036,6 +4092,12 @@ interface HookEventMap {
 		row: number;
 		content_x: number;
 		content_y: number;
+		/** Buffer under the mouse, or `null` outside any buffer panel. */
+		buffer_id: number | null;
+		/** 0-indexed buffer row under the mouse, accounting for scroll. */
+		buffer_row: number | null;
+		/** 0-indexed byte column inside the buffer row. */
+		buffer_col: number | null;
 	};
 	mouse_scroll: {
 		buffer_id: number;
@@ -4216,4 +4278,33 @@ interface EditorAPI {
 	* This is an async runtime binding (not a direct #[qjs] method).
 	*/
 	createBufferGroup(name: string, mode: string, layout: unknown): Promise<BufferGroupResult>;
+	/**
+	* Start a job: a background process listed in the editor's running
+	* jobs (the `{jobs}` status-bar element) until it exits. Output lines
+	* arrive through `onStdout` / `onStderr`; the handle resolves with the
+	* exit code once the process exits or is killed (`-1`). A non-zero exit
+	* is reported in the status bar.
+	*
+	* ```ts
+	* const job = editor.spawnJob("cargo", ["clippy"], { title: "clippy" });
+	* job.onStderr((line) => editor.setJobProgress(job.jobId, line.trim()));
+	* const { exit_code } = await job;
+	* ```
+	*/
+	spawnJob(command: string, args: string[], options?: JobOptions): JobHandle;
+}
+/** Handle for a job started with `editor.spawnJob` */
+interface JobHandle extends ProcessHandle<BackgroundProcessResult> {
+	/** Job ID, also the process ID passed to `onProcessStdout` hooks */
+	readonly jobId: number;
+	/** Call `handler` with each line the job writes to stdout */
+	onStdout(handler: (line: string) => void): JobHandle;
+	/** Call `handler` with each line the job writes to stderr */
+	onStderr(handler: (line: string) => void): JobHandle;
+	/** Write to the job's stdin (needs `stdin: true`) */
+	write(data: string): boolean;
+	/** Close the job's stdin, signalling end of input */
+	closeStdin(): boolean;
+	/** Shorthand for `editor.setJobProgress(jobId, message, percent)` */
+	progress(message: string, percent?: number): boolean;
 }
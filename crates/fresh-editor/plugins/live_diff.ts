@@ -22,6 +22,11 @@ const editor = getEditor();
  *   - Live Diff: Toggle                — disable/enable for the active buffer
  *   - Live Diff: Refresh               — re-fetch reference and recompute
  *   - Live Diff: Set Default Mode...   — pick the default for new buffers
+ *
+ * "Live Diff: Highlight Unsaved Words" is a softer per-buffer view of the
+ * same pipeline: it diffs against the file on disk and only emphasises the
+ * changed words (plus gutter glyphs) — no line backgrounds and no virtual
+ * deletion lines — so what a save would write stays visible while typing.
  */
 
 // =============================================================================
@@ -173,6 +178,12 @@ interface BufferDiffState {
    * setting; `false` forces it off. Set by `Live Diff: Toggle (Buffer)`.
    */
   override: boolean | null;
+  /**
+   * Soft word-level view of unsaved changes, set by `Live Diff: Highlight
+   * Unsaved Words`. Enables live-diff for the buffer and, while set,
+   * overrides `mode` with the disk reference.
+   */
+  soft: boolean;
   /**
    * Last buffer text we ran the diff against. `lines_changed` fires for
    * viewport scrolls too — comparing the text catches those cheaply and
@@ -240,11 +251,25 @@ function storeOverride(bufferId: number, override: boolean | null): void {
   editor.setViewState(bufferId, "live_diff.override", override);
 }
 
+function getStoredSoft(bufferId: number): boolean {
+  return editor.getViewState(bufferId, "live_diff.soft") === true;
+}
+
+function storeSoft(bufferId: number, soft: boolean): void {
+  editor.setViewState(bufferId, "live_diff.soft", soft);
+}
+
 function isEnabledForBuffer(state: BufferDiffState): boolean {
+  if (state.soft) return true;
   if (state.override !== null) return state.override;
   return isGlobalEnabled();
 }
 
+/** The reference actually diffed against: soft mode always uses disk. */
+function referenceMode(state: BufferDiffState): DiffMode {
+  return state.soft ? { kind: "disk" } : state.mode;
+}
+
 // =============================================================================
 // Reference loading
 // =============================================================================
@@ -306,13 +331,14 @@ async function resolveDefaultBranch(filePath: string): Promise<string> {
 }
 
 async function loadReference(state: BufferDiffState): Promise<string | null> {
-  switch (state.mode.kind) {
+  const mode = referenceMode(state);
+  switch (mode.kind) {
     case "head":
       return await loadHeadRef(state.filePath);
     case "disk":
       return loadDiskRef(state.filePath);
     case "branch":
-      return await loadBranchRef(state.filePath, state.mode.ref);
+      return await loadBranchRef(state.filePath, mode.ref);
   }
 }
 
@@ -824,6 +850,10 @@ function computeLineByteStarts(lines: string[]): number[] {
 }
 
 function renderHunks(state: BufferDiffState, newLines: string[]): void {
+  if (state.soft) {
+    renderSoftHunks(state, newLines);
+    return;
+  }
   const bid = state.bufferId;
   clearDecorations(bid);
 
@@ -982,6 +1012,68 @@ function renderHunks(state: BufferDiffState, newLines: string[]): void {
   }
 }
 
+const WORD_EMPHASIS = {
+  bold: true,
+  underline: true,
+  italic: false,
+  strikethrough: false,
+  extendToLineEnd: false,
+};
+
+/**
+ * Soft style: emphasise the changed words of each new-side line (the whole
+ * text of an added line with no word pairing) and mark lines in the gutter.
+ * Deletions have no new-side text to emphasise, so their `-` glyph goes on
+ * the line that now follows them.
+ */
+function renderSoftHunks(state: BufferDiffState, newLines: string[]): void {
+  const bid = state.bufferId;
+  clearDecorations(bid);
+
+  const lineStarts = computeLineByteStarts(newLines);
+  const lineCount = newLines.length;
+  const glyphLines: Record<HunkKind, number[]> = { added: [], modified: [], removed: [] };
+  const marked = new Set<number>();
+
+  for (const h of state.hunks) {
+    if (h.kind === "removed") continue;
+    for (let i = 0; i < h.newCount; i++) {
+      const line = h.newStart + i;
+      if (line >= lineCount) break;
+      glyphLines[h.kind].push(line);
+      marked.add(line);
+      const lineByteStart = lineStarts[line];
+      const ranges = h.wordRanges?.[i] ?? [
+        { start: 0, end: editor.utf8ByteLength(newLines[line]) },
+      ];
+      for (const r of ranges) {
+        if (r.end <= r.start) continue;
+        editor.addOverlay(
+          bid,
+          NS_OVERLAY,
+          lineByteStart + r.start,
+          lineByteStart + r.end,
+          WORD_EMPHASIS,
+        );
+      }
+    }
+  }
+  for (const h of state.hunks) {
+    if (h.kind !== "removed" || lineCount === 0) continue;
+    const line = Math.min(h.newStart, lineCount - 1);
+    if (!marked.has(line)) {
+      glyphLines.removed.push(line);
+      marked.add(line);
+    }
+  }
+
+  for (const kind of ["added", "modified", "removed"] as HunkKind[]) {
+    if (glyphLines[kind].length === 0) continue;
+    const [r, g, b] = GUTTER_COLORS[kind];
+    editor.setLineIndicators(bid, glyphLines[kind], NS_GUTTER, SYMBOLS[kind], r, g, b, PRIORITY);
+  }
+}
+
 // =============================================================================
 // Recompute pipeline
 // =============================================================================
@@ -1150,6 +1242,7 @@ function ensureState(bufferId: number): BufferDiffState | null {
     reloadRef: false,
     pendingToken: 0,
     override: getStoredOverride(bufferId),
+    soft: getStoredSoft(bufferId),
     lastBufferText: null,
     lastHunksKey: "",
   };
@@ -1237,6 +1330,25 @@ function live_diff_toggle_global(): void {
 }
 registerHandler("live_diff_toggle_global", live_diff_toggle_global);
 
+/**
+ * Toggle the soft word-level view of unsaved changes for the active buffer.
+ * Turning it off returns the buffer to its own live-diff mode and toggle.
+ */
+function live_diff_toggle_soft(): void {
+  const bid = editor.getActiveBufferId();
+  const state = ensureState(bid);
+  if (!state) {
+    editor.setStatus(editor.t("status.no_file"));
+    return;
+  }
+  state.soft = !state.soft;
+  storeSoft(bid, state.soft);
+  dropReference(state);
+  syncBufferToEnabledState(state);
+  editor.setStatus(editor.t(state.soft ? "status.soft_enabled" : "status.soft_disabled"));
+}
+registerHandler("live_diff_toggle_soft", live_diff_toggle_soft);
+
 async function live_diff_vs_head(): Promise<void> {
   await setMode(editor.getActiveBufferId(), { kind: "head" });
   editor.setStatus(editor.t("status.mode_head"));
@@ -1385,7 +1497,7 @@ async function ensureHeadWatch(): Promise<void> {
 // committed ref, so a HEAD move doesn't affect it.
 function refreshGitReferences(): void {
   for (const state of states.values()) {
-    if (state.mode.kind === "disk") continue;
+    if (referenceMode(state).kind === "disk") continue;
     if (!isEnabledForBuffer(state)) continue;
     // Request a reference re-fetch (HEAD may have moved) via the flag the
     // recompute consumes under its mutex — NOT by mutating `oldText`/
@@ -1466,7 +1578,7 @@ editor.on("after_file_save", (args) => {
   if (!state) return true;
   // Save changes the file path (save-as) and invalidates the disk-mode reference.
   state.filePath = args.path;
-  if (state.mode.kind === "disk") {
+  if (referenceMode(state).kind === "disk") {
     dropReference(state);
   }
   recompute(args.buffer_id).catch((e) => editor.error(`live-diff: ${e}`));
@@ -1486,6 +1598,7 @@ editor.registerCommand("%cmd.toggle_global", "%cmd.toggle_global_desc", "live_di
 editor.registerCommand("%cmd.toggle_buffer", "%cmd.toggle_buffer_desc", "live_diff_toggle_buffer", null);
 editor.registerCommand("%cmd.vs_head", "%cmd.vs_head_desc", "live_diff_vs_head", null);
 editor.registerCommand("%cmd.vs_disk", "%cmd.vs_disk_desc", "live_diff_vs_disk", null);
+editor.registerCommand("%cmd.toggle_soft", "%cmd.toggle_soft_desc", "live_diff_toggle_soft", null);
 editor.registerCommand("%cmd.vs_branch", "%cmd.vs_branch_desc", "live_diff_vs_branch", null);
 editor.registerCommand("%cmd.vs_default_branch", "%cmd.vs_default_branch_desc", "live_diff_vs_default_branch", null);
 editor.registerCommand("%cmd.refresh", "%cmd.refresh_desc", "live_diff_refresh", null);
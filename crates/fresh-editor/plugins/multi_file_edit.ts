@@ -0,0 +1,203 @@
+/// <reference path="./lib/fresh.d.ts" />
+const editor = getEditor();
+
+/**
+ * Multi-File Edit Plugin
+ *
+ * "Multi-File Edit: Search" greps the project and gathers every matching
+ * line into one editable *Multi-File Edit* buffer, under a header per file.
+ * The headers and line-number gutters are read-only regions, so only the
+ * line text itself can change. "Multi-File Edit: Apply Changes" (Ctrl+S in
+ * the buffer) writes each changed line back to its file; a line whose file
+ * changed since the search is skipped rather than overwritten.
+ */
+
+const BUFFER_NAME = "*Multi-File Edit*";
+const MODE = "multi-file-edit";
+const NAMESPACE = "multi-file-edit";
+const GUTTER_END = "│ ";
+const MAX_RESULTS = 1000;
+
+/** One matching line of a file */
+interface Row {
+  file: string;
+  /** 1-indexed line number */
+  line: number;
+  /** Byte offset of the start of the line in the file */
+  start: number;
+  /** Line text as last read or written, without its line ending */
+  text: string;
+}
+
+let bufferId: number | null = null;
+/** The row on each buffer line; `null` for file headers and separators */
+let rows: (Row | null)[] = [];
+
+function relativePath(filePath: string): string {
+  const cwd = editor.getCwd();
+  return filePath.startsWith(cwd + "/") ? filePath.slice(cwd.length + 1) : filePath;
+}
+
+/** Matching lines grouped by file, one row per line however many matches it has */
+function collectRows(matches: GrepMatch[]): Map<string, Row[]> {
+  const byFile = new Map<string, Row[]>();
+  for (const m of matches) {
+    const list = byFile.get(m.file) ?? [];
+    if (list.some((r) => r.line === m.line)) continue;
+    // `column` is a byte column, so the line starts `column - 1` bytes earlier.
+    const text = m.context.endsWith("\r") ? m.context.slice(0, -1) : m.context;
+    list.push({ file: m.file, line: m.line, start: m.byteOffset - (m.column - 1), text });
+    byFile.set(m.file, list);
+  }
+  return byFile;
+}
+
+function render(id: number, byFile: Map<string, Row[]>): void {
+  const entries: TextPropertyEntry[] = [];
+  /** Byte ranges of the line texts: the only editable parts */
+  const editable: [number, number][] = [];
+  let offset = 0;
+  const push = (text: string, style?: Partial<OverlayOptions>): void => {
+    entries.push(style ? { text, style } : { text });
+    offset += editor.utf8ByteLength(text);
+  };
+
+  rows = [];
+  byFile.forEach((list, file) => {
+    if (rows.length > 0) {
+      push("\n");
+      rows.push(null);
+    }
+    push(relativePath(file) + "\n", { bold: true, fg: "syntax.keyword" });
+    rows.push(null);
+    const width = String(list[list.length - 1].line).length;
+    for (const row of list) {
+      push(String(row.line).padStart(width) + GUTTER_END, { fg: "editor.line_number_fg" });
+      const start = offset;
+      push(row.text);
+      editable.push([start, offset]);
+      push("\n");
+      rows.push(row);
+    }
+  });
+
+  editor.clearReadOnlyRegions(id, NAMESPACE);
+  editor.setVirtualBufferContent(id, entries);
+  const hint = editor.t("status.read_only");
+  let from = 0;
+  for (const [start, end] of editable) {
+    editor.addReadOnlyRegion(id, NAMESPACE, from, start, hint);
+    from = end;
+  }
+  editor.addReadOnlyRegion(id, NAMESPACE, from, offset, hint);
+}
+
+async function multi_file_edit_search(): Promise<void> {
+  const pattern = await editor.prompt(editor.t("prompt.search"), "");
+  if (!pattern) return;
+  const matches = await editor.grepProject(pattern, true, null, MAX_RESULTS, null);
+  if (matches.length === 0) {
+    editor.setStatus(editor.t("status.no_matches", { pattern }));
+    return;
+  }
+
+  if (bufferId === null) {
+    const res = await editor.createVirtualBuffer({
+      name: BUFFER_NAME,
+      mode: MODE,
+      readOnly: false,
+      showLineNumbers: false,
+      showCursors: true,
+      editingDisabled: false,
+    });
+    bufferId = res.bufferId;
+  }
+  editor.showBuffer(bufferId);
+  const byFile = collectRows(matches);
+  render(bufferId, byFile);
+  editor.setStatus(
+    editor.t("status.found", { lines: String(rows.filter((r) => r).length), files: String(byFile.size) }),
+  );
+}
+registerHandler("multi_file_edit_search", multi_file_edit_search);
+
+/** The file's current text for `row`, to check it hasn't moved under us */
+async function currentLine(row: Row, openBuffer: number): Promise<string | undefined> {
+  if (openBuffer > 0) {
+    return editor.getBufferText(openBuffer, row.start, row.start + editor.utf8ByteLength(row.text));
+  }
+  return editor.readFile(row.file)?.split("\n")[row.line - 1]?.replace(/\r$/, "");
+}
+
+async function multi_file_edit_apply(): Promise<void> {
+  const id = bufferId;
+  if (id === null) return;
+  const lines = (await editor.getBufferText(id, 0, editor.getBufferLength(id))).split("\n");
+  if (lines[lines.length - 1] === "") lines.pop();
+  if (lines.length !== rows.length) {
+    editor.setStatus(editor.t("status.layout_changed"));
+    return;
+  }
+
+  const changes = new Map<string, { row: Row; text: string }[]>();
+  rows.forEach((row, i) => {
+    if (!row) return;
+    const text = lines[i].slice(lines[i].indexOf(GUTTER_END) + GUTTER_END.length);
+    if (text === row.text) return;
+    const list = changes.get(row.file) ?? [];
+    list.push({ row, text });
+    changes.set(row.file, list);
+  });
+  if (changes.size === 0) {
+    editor.setStatus(editor.t("status.no_changes"));
+    return;
+  }
+
+  let written = 0;
+  let stale = 0;
+  for (const [file, list] of changes) {
+    const openBuffer = editor.findBufferByPath(file);
+    // Bottom-up, so each replacement leaves the offsets above it valid.
+    list.sort((a, b) => b.row.start - a.row.start);
+    for (const change of list) {
+      if ((await currentLine(change.row, openBuffer)) !== change.row.text) {
+        stale++;
+        continue;
+      }
+      const oldLength = editor.utf8ByteLength(change.row.text);
+      await editor.replaceInFile(file, [[change.row.start, oldLength]], change.text, openBuffer);
+      // Rows further down the file moved by the change in length.
+      const delta = editor.utf8ByteLength(change.text) - oldLength;
+      for (const row of rows) {
+        if (row && row.file === file && row.start > change.row.start) row.start += delta;
+      }
+      change.row.text = change.text;
+      written++;
+    }
+  }
+
+  editor.setStatus(
+    stale > 0
+      ? editor.t("status.applied_with_stale", { lines: String(written), stale: String(stale) })
+      : editor.t("status.applied", { lines: String(written), files: String(changes.size) }),
+  );
+}
+registerHandler("multi_file_edit_apply", multi_file_edit_apply);
+
+editor.on("buffer_closed", (data) => {
+  if (data.buffer_id === bufferId) {
+    bufferId = null;
+    rows = [];
+  }
+});
+
+editor.defineMode(
+  MODE,
+  [["C-s", "multi_file_edit_apply"]],
+  false, // readOnly
+  false, // allowTextInput: typing falls through to ordinary editing
+  true, // inheritNormalBindings
+);
+
+editor.registerCommand("%cmd.search", "%cmd.search_desc", "multi_file_edit_search", null);
+editor.registerCommand("%cmd.apply", "%cmd.apply_desc", "multi_file_edit_apply", null);
@@ -0,0 +1,569 @@
+/// <reference path="./lib/fresh.d.ts" />
+const editor = getEditor();
+
+/**
+ * Show Assembly Plugin - Compiler Explorer-style assembly view
+ *
+ * Compiles the current Rust, C or C++ file to assembly in the background
+ * and opens the result beside the source:
+ * - Directives, debug sections and unused local labels are filtered out
+ * - Symbols are demangled (Rust legacy symbols built in, C++ via c++filt)
+ * - Each assembly line keeps the source line it came from (from `.loc`),
+ *   so moving in either pane highlights the matching lines in the other
+ * - Enter jumps to the source line, 'r' recompiles, 'q' closes
+ * - Saving the source recompiles while the view is open
+ *
+ * The saved file is compiled on its own (rustc `--crate-type=lib`, cc `-S`),
+ * so code that only builds as part of a crate or with extra include paths
+ * needs the matching flags in the plugin settings.
+ */
+
+// =============================================================================
+// Settings
+// =============================================================================
+
+editor.defineConfigString("rustCompiler", {
+  default: "rustc",
+  description: "Compiler used for Rust files.",
+});
+editor.defineConfigStringArray("rustFlags", {
+  default: ["--edition=2021", "-C", "opt-level=2"],
+  description: "Extra rustc flags, e.g. \"-C\", \"target-cpu=native\" or \"-C\", \"llvm-args=-x86-asm-syntax=intel\".",
+});
+editor.defineConfigString("cCompiler", {
+  default: "cc",
+  description: "Compiler used for C files.",
+});
+editor.defineConfigStringArray("cFlags", {
+  default: ["-O2"],
+  description: "Extra C compiler flags, e.g. \"-O3\", \"-march=native\" or \"-masm=intel\".",
+});
+editor.defineConfigString("cppCompiler", {
+  default: "c++",
+  description: "Compiler used for C++ files.",
+});
+editor.defineConfigStringArray("cppFlags", {
+  default: ["-O2"],
+  description: "Extra C++ compiler flags.",
+});
+editor.defineConfigString("demangler", {
+  default: "c++filt",
+  description: "Demangler for C++ symbols. Leave empty to keep them mangled.",
+});
+
+interface AsmSettings {
+  rustCompiler: string;
+  rustFlags: string[];
+  cCompiler: string;
+  cFlags: string[];
+  cppCompiler: string;
+  cppFlags: string[];
+  demangler: string;
+}
+
+function settings(): AsmSettings {
+  const cfg = (editor.getPluginConfig() ?? {}) as Partial<AsmSettings>;
+  return {
+    rustCompiler: cfg.rustCompiler || "rustc",
+    rustFlags: cfg.rustFlags ?? ["--edition=2021", "-C", "opt-level=2"],
+    cCompiler: cfg.cCompiler || "cc",
+    cFlags: cfg.cFlags ?? ["-O2"],
+    cppCompiler: cfg.cppCompiler || "c++",
+    cppFlags: cfg.cppFlags ?? ["-O2"],
+    demangler: cfg.demangler ?? "c++filt",
+  };
+}
+
+// =============================================================================
+// Types and State
+// =============================================================================
+
+type SourceLanguage = "rust" | "c" | "cpp";
+
+interface AsmLine {
+  text: string;
+  sourceLine: number | null; // 1-based line in the source file
+}
+
+/** The open assembly view. There is at most one, tied to one source file. */
+interface AsmView {
+  bufferId: number;
+  splitId: number | null;
+  sourceBufferId: number;
+  sourceSplitId: number;
+  sourcePath: string;
+  lines: AsmLine[];
+  asmLineOffsets: number[];    // Byte offset of each assembly line start, plus the end
+  sourceLineOffsets: number[]; // Same for the compiled (saved) source
+}
+
+const PANEL_ID = "show-assembly";
+const NAMESPACE = "asm-view";
+const HIGHLIGHT_BG = "editor.selection_bg";
+
+let view: AsmView | null = null;
+
+editor.defineMode(
+  "asm-view",
+  [
+    ["Enter", "asm_view_goto_source"],
+    ["r", "asm_view_recompile"],
+    ["q", "asm_view_close"],
+    ["Escape", "asm_view_close"],
+  ],
+  true // read-only
+);
+
+function languageOf(path: string): SourceLanguage | null {
+  switch (editor.pathExtname(path).toLowerCase()) {
+    case ".rs":
+      return "rust";
+    case ".c":
+      return "c";
+    case ".cc":
+    case ".cpp":
+    case ".cxx":
+    case ".c++":
+      return "cpp";
+    default:
+      return null;
+  }
+}
+
+// =============================================================================
+// Compilation
+// =============================================================================
+
+/**
+ * Compiler command line that writes assembly with line info to stdout.
+ * The file is passed by basename and compiled from its own directory so
+ * C compilers record it under that name in `.file`.
+ */
+function compileCommand(language: SourceLanguage, fileName: string): [string, string[]] {
+  const s = settings();
+  switch (language) {
+    case "rust":
+      // `link-dead-code` keeps small `pub fn`s that rustc would otherwise
+      // leave to be inlined by downstream crates (and so never emit).
+      return [s.rustCompiler, [
+        "--crate-type=lib", "--emit=asm=-",
+        "-C", "debuginfo=1", "-C", "codegen-units=1", "-C", "link-dead-code",
+        ...s.rustFlags, fileName,
+      ]];
+    case "c":
+      return [s.cCompiler, ["-S", "-g", "-fno-asynchronous-unwind-tables", ...s.cFlags, "-o", "-", fileName]];
+    case "cpp":
+      return [s.cppCompiler, ["-S", "-g", "-fno-asynchronous-unwind-tables", ...s.cppFlags, "-o", "-", fileName]];
+  }
+}
+
+async function compile(path: string, language: SourceLanguage): Promise<{ asm: string } | { error: string }> {
+  const [command, args] = compileCommand(language, editor.pathBasename(path));
+  let result: SpawnResult;
+  try {
+    result = await editor.spawnProcess(command, args, editor.pathDirname(path));
+  } catch (e) {
+    return { error: `${command}: ${e}` };
+  }
+  if (result.exit_code !== 0) {
+    const firstError = result.stderr.split("\n").find((l) => l.trim() !== "") ?? "";
+    return { error: firstError || `${command} exited with ${result.exit_code}` };
+  }
+  return { asm: result.stdout };
+}
+
+// =============================================================================
+// Assembly Parsing
+// =============================================================================
+
+// Local labels: `.LBB0_3`, `.L5`, `.Ltmp2`, `.Lfunc_end0` (ELF) and
+// `LBB0_3`, `Ltmp2` (Mach-O).
+const LOCAL_LABEL = /^\.?L[A-Za-z_]*\d+(_\d+)?$/;
+const FILE_DIRECTIVE = /^\.file\s+(\d+)\s+"([^"]*)"(?:\s+"([^"]*)")?/;
+const LOC_DIRECTIVE = /^\.loc\s+(\d+)\s+(\d+)/;
+
+function isTextSection(directive: string): boolean {
+  if (directive === ".text") return true;
+  const m = directive.match(/^\.section\s+"?([^",\s]+)/);
+  return m !== null && (m[1].startsWith(".text") || m[1].includes("__text"));
+}
+
+/** Does a `.file` entry name the compiled source? */
+function isSourceFile(sourcePath: string, dir: string, name: string): boolean {
+  const file = (dir && !name.startsWith("/") ? `${dir}/${name}` : name).replace(/\\/g, "/");
+  const source = sourcePath.replace(/\\/g, "/");
+  return file === source || source.endsWith("/" + file);
+}
+
+/** Pad the mnemonic to a fixed column and drop trailing assembler comments. */
+function formatInstruction(line: string): string {
+  const trimmed = line.trim().replace(/\s+(#|\/\/)\s.*$/, "");
+  const m = trimmed.match(/^(\S+)\s+(.*)$/);
+  if (!m) return "        " + trimmed;
+  return "        " + m[1].padEnd(8) + m[2].replace(/\t/g, " ");
+}
+
+/**
+ * Reduce compiler assembly to labels and instructions, each tagged with
+ * the source line from the preceding `.loc` directive.
+ */
+function parseAssembly(asm: string, sourcePath: string): AsmLine[] {
+  const sourceFiles = new Set<string>();
+  const raw: Array<{ label: string | null; text: string; sourceLine: number | null }> = [];
+  let inText = false;
+  let sourceLine: number | null = null;
+
+  for (const line of asm.split("\n")) {
+    const trimmed = line.trim();
+    if (trimmed === "") continue;
+
+    if (trimmed.startsWith(".")) {
+      const file = trimmed.match(FILE_DIRECTIVE);
+      if (file) {
+        const [dir, name] = file[3] !== undefined ? [file[2], file[3]] : ["", file[2]];
+        if (isSourceFile(sourcePath, dir, name)) sourceFiles.add(file[1]);
+        continue;
+      }
+      const loc = trimmed.match(LOC_DIRECTIVE);
+      if (loc) {
+        const lineNo = parseInt(loc[2], 10);
+        sourceLine = sourceFiles.has(loc[1]) && lineNo > 0 ? lineNo : null;
+        continue;
+      }
+      if (/^\.(text|data|bss|section)\b/.test(trimmed)) {
+        inText = isTextSection(trimmed);
+        continue;
+      }
+    }
+
+    const label = line.match(/^([^\s#;:][^\s:]*):/);
+    if (label) {
+      if (inText) raw.push({ label: label[1], text: label[1] + ":", sourceLine: null });
+      continue;
+    }
+    if (!inText || !/^\s/.test(line) || /^[.#;@]|^\/\//.test(trimmed)) continue;
+    raw.push({ label: null, text: formatInstruction(line), sourceLine });
+  }
+
+  // Keep local labels only when an instruction refers to them (branch
+  // targets); the rest are debug-info and CFI bookkeeping.
+  const referenced = new Set<string>();
+  for (const item of raw) {
+    if (item.label === null) {
+      for (const token of item.text.match(/[.\w$]+/g) ?? []) referenced.add(token);
+    }
+  }
+  return raw
+    .filter((item) => item.label === null || !LOCAL_LABEL.test(item.label) || referenced.has(item.label))
+    .map((item) => ({ text: item.text, sourceLine: item.sourceLine }));
+}
+
+// =============================================================================
+// Demangling
+// =============================================================================
+
+const RUST_ESCAPES: Record<string, string> = {
+  SP: "@", BP: "*", RF: "&", LT: "<", GT: ">", LP: "(", RP: ")", C: ",",
+};
+
+function unescapeRustIdent(ident: string): string {
+  return ident
+    .replace(/^_\$/, "$")
+    .replace(/\$([A-Z]{1,2})\$/g, (m, code: string) => RUST_ESCAPES[code] ?? m)
+    .replace(/\$u([0-9a-f]{2,6})\$/g, (_m, hex: string) => String.fromCharCode(parseInt(hex, 16)))
+    .replace(/\.\./g, "::");
+}
+
+/**
+ * Demangle a legacy Rust symbol (`_ZN4core3ptr8drop17h0123456789abcdefE`),
+ * dropping the trailing hash. Returns null for anything else, including
+ * C++ symbols, which share the `_ZN` prefix but have no hash segment.
+ */
+function demangleRust(symbol: string): string | null {
+  const body = symbol.replace(/^_?_ZN/, "");
+  if (body === symbol) return null;
+  const parts: string[] = [];
+  let i = 0;
+  while (i < body.length && body[i] !== "E") {
+    const digits = body.slice(i).match(/^\d+/);
+    if (!digits) return null;
+    i += digits[0].length;
+    const len = parseInt(digits[0], 10);
+    if (i + len > body.length) return null;
+    parts.push(body.slice(i, i + len));
+    i += len;
+  }
+  if (i !== body.length - 1 || parts.length < 2 || !/^h[0-9a-f]{16}$/.test(parts[parts.length - 1])) {
+    return null;
+  }
+  return parts.slice(0, -1).map(unescapeRustIdent).join("::");
+}
+
+const MANGLED_SYMBOL = /_?_Z[A-Za-z0-9_$.]+/g;
+
+/** Demangle every symbol in the listing, in place. */
+async function demangle(lines: AsmLine[], cwd: string): Promise<void> {
+  const names = new Map<string, string>();
+  const pending: string[] = [];
+  for (const line of lines) {
+    for (const symbol of line.text.match(MANGLED_SYMBOL) ?? []) {
+      if (names.has(symbol)) continue;
+      const rust = demangleRust(symbol);
+      names.set(symbol, rust ?? symbol);
+      if (rust === null) pending.push(symbol);
+    }
+  }
+
+  const demangler = settings().demangler;
+  if (pending.length > 0 && demangler !== "") {
+    try {
+      const result = await editor.spawnProcess(demangler, pending, cwd);
+      const out = result.stdout.split("\n");
+      if (result.exit_code === 0 && out.length >= pending.length) {
+        pending.forEach((symbol, i) => names.set(symbol, out[i]));
+      }
+    } catch {
+      // No demangler installed: C++ symbols stay mangled.
+    }
+  }
+
+  for (const line of lines) {
+    line.text = line.text.replace(MANGLED_SYMBOL, (s) => names.get(s) ?? s);
+  }
+}
+
+// =============================================================================
+// View
+// =============================================================================
+
+function lineOffsets(lines: string[]): number[] {
+  const offsets = [0];
+  let pos = 0;
+  for (const line of lines) {
+    pos += editor.utf8ByteLength(line) + 1;
+    offsets.push(pos);
+  }
+  return offsets;
+}
+
+function buildEntries(lines: AsmLine[]): TextPropertyEntry[] {
+  return lines.map((line) => ({
+    text: line.text + "\n",
+    properties: { sourceLine: line.sourceLine },
+  }));
+}
+
+/** Asm line indices (0-based) generated from a source line. */
+function asmLinesFor(v: AsmView, sourceLine: number): number[] {
+  const result: number[] = [];
+  v.lines.forEach((line, i) => {
+    if (line.sourceLine === sourceLine) result.push(i);
+  });
+  return result;
+}
+
+function highlight(bufferId: number, offsets: number[], lines: number[]): void {
+  editor.clearNamespace(bufferId, NAMESPACE);
+  for (const i of lines) {
+    if (i + 1 >= offsets.length) continue;
+    editor.addOverlay(bufferId, NAMESPACE, offsets[i], offsets[i + 1], {
+      bg: HIGHLIGHT_BG,
+      extendToLineEnd: true,
+    });
+  }
+}
+
+function clearHighlights(v: AsmView): void {
+  editor.clearNamespace(v.bufferId, NAMESPACE);
+  editor.clearNamespace(v.sourceBufferId, NAMESPACE);
+}
+
+/** Highlight the assembly for a source line and scroll it into view. */
+function revealSourceLine(v: AsmView, sourceLine: number): void {
+  const asmLines = asmLinesFor(v, sourceLine);
+  highlight(v.bufferId, v.asmLineOffsets, asmLines);
+  highlight(v.sourceBufferId, v.sourceLineOffsets, asmLines.length > 0 ? [sourceLine - 1] : []);
+  if (asmLines.length > 0) {
+    editor.scrollBufferToLine(v.bufferId, asmLines[0]);
+  }
+}
+
+function closeView(): void {
+  if (!view) return;
+  const v = view;
+  view = null;
+  clearHighlights(v);
+  editor.closeBuffer(v.bufferId);
+  if (v.splitId !== null) {
+    editor.closeSplit(v.splitId);
+  }
+}
+
+async function showAssembly(sourceBufferId: number, sourceSplitId: number, sourceLine: number | null): Promise<void> {
+  const path = editor.getBufferPath(sourceBufferId);
+  const language = path ? languageOf(path) : null;
+  if (!path || !language) {
+    editor.setStatus(editor.t("status.unsupported"));
+    return;
+  }
+
+  editor.setStatus(editor.t("status.compiling", { file: editor.pathBasename(path) }));
+  const compiled = await compile(path, language);
+  if ("error" in compiled) {
+    editor.setStatus(editor.t("status.failed", { error: compiled.error }));
+    return;
+  }
+
+  const lines = parseAssembly(compiled.asm, path);
+  if (lines.length === 0) {
+    editor.setStatus(editor.t("status.no_output"));
+    return;
+  }
+  await demangle(lines, editor.pathDirname(path));
+
+  const source = editor.readFile(editor.authorityPath(path)) ?? "";
+  const texts = lines.map((l) => l.text);
+
+  // The panel is tied to one source; switching files reopens it so its
+  // name (and so its syntax highlighting) follows the new source.
+  if (view && view.sourcePath !== path) {
+    closeView();
+  }
+
+  let bufferId: number;
+  let splitId: number | null;
+  if (view) {
+    editor.setVirtualBufferContent(view.bufferId, buildEntries(lines));
+    bufferId = view.bufferId;
+    splitId = view.splitId;
+  } else {
+    const stem = editor.pathBasename(path).replace(/\.[^.]*$/, "");
+    const result = await editor.createVirtualBufferInSplit({
+      name: `*Assembly:${stem}.s*`,
+      mode: "asm-view",
+      readOnly: true,
+      entries: buildEntries(lines),
+      ratio: 0.5,
+      direction: "vertical",
+      panelId: PANEL_ID,
+      showLineNumbers: false,
+      editingDisabled: true,
+    });
+    if (result === null) {
+      editor.setStatus(editor.t("status.failed_open"));
+      return;
+    }
+    bufferId = result.bufferId;
+    splitId = result.splitId ?? null;
+  }
+
+  view = {
+    bufferId,
+    splitId,
+    sourceBufferId,
+    sourceSplitId,
+    sourcePath: path,
+    lines,
+    asmLineOffsets: lineOffsets(texts),
+    sourceLineOffsets: lineOffsets(source.split("\n")),
+  };
+
+  if (sourceLine !== null) {
+    revealSourceLine(view, sourceLine);
+  }
+  editor.setStatus(editor.t("status.ready", { count: String(lines.length) }));
+}
+
+// =============================================================================
+// Commands and Handlers
+// =============================================================================
+
+async function show_assembly(): Promise<void> {
+  const bufferId = editor.getActiveBufferId();
+  if (view && bufferId === view.bufferId) {
+    await asm_view_recompile();
+    return;
+  }
+  const cursorLine = editor.getPrimaryCursor()?.line;
+  await showAssembly(bufferId, editor.getActiveSplitId(), cursorLine != null ? cursorLine + 1 : null);
+}
+registerHandler("show_assembly", show_assembly);
+
+async function asm_view_recompile(): Promise<void> {
+  if (!view) return;
+  await showAssembly(view.sourceBufferId, view.sourceSplitId, null);
+}
+registerHandler("asm_view_recompile", asm_view_recompile);
+
+function asm_view_goto_source(): void {
+  if (!view) return;
+  const props = editor.getTextPropertiesAtCursor(view.bufferId);
+  const sourceLine = props.length > 0 ? props[0].sourceLine : null;
+  if (typeof sourceLine !== "number") {
+    editor.setStatus(editor.t("status.no_source_line"));
+    return;
+  }
+  editor.openFileInSplit(view.sourceSplitId, view.sourcePath, sourceLine, 1);
+}
+registerHandler("asm_view_goto_source", asm_view_goto_source);
+
+function asm_view_close(): void {
+  closeView();
+}
+registerHandler("asm_view_close", asm_view_close);
+
+/** Keep the two panes' highlights in step with whichever cursor moved. */
+function on_asm_cursor_moved(data: { buffer_id: number; line: number; text_properties: Record<string, unknown>[] }): void {
+  if (!view) return;
+  if (data.buffer_id === view.bufferId) {
+    const sourceLine = data.text_properties.length > 0 ? data.text_properties[0].sourceLine : null;
+    if (typeof sourceLine !== "number") {
+      clearHighlights(view);
+      return;
+    }
+    highlight(view.sourceBufferId, view.sourceLineOffsets, [sourceLine - 1]);
+    highlight(view.bufferId, view.asmLineOffsets, asmLinesFor(view, sourceLine));
+    editor.scrollToLineCenter(view.sourceSplitId, view.sourceBufferId, sourceLine - 1);
+  } else if (data.buffer_id === view.sourceBufferId) {
+    revealSourceLine(view, data.line);
+  }
+}
+registerHandler("on_asm_cursor_moved", on_asm_cursor_moved);
+editor.on("cursor_moved", on_asm_cursor_moved);
+
+function on_asm_source_saved(data: { buffer_id: number }): void {
+  if (view && data.buffer_id === view.sourceBufferId) {
+    void asm_view_recompile();
+  }
+}
+registerHandler("on_asm_source_saved", on_asm_source_saved);
+editor.on("after_file_save", on_asm_source_saved);
+
+function on_asm_buffer_closed(data: { buffer_id: number }): void {
+  if (view && (data.buffer_id === view.bufferId || data.buffer_id === view.sourceBufferId)) {
+    const closedView = data.buffer_id === view.bufferId;
+    const v = view;
+    view = null;
+    if (!closedView) {
+      editor.closeBuffer(v.bufferId);
+    }
+  }
+}
+registerHandler("on_asm_buffer_closed", on_asm_buffer_closed);
+editor.on("buffer_closed", on_asm_buffer_closed);
+
+editor.registerCommand(
+  "%cmd.show_assembly",
+  "%cmd.show_assembly_desc",
+  "show_assembly",
+  null
+);
+
+editor.registerCommand(
+  "%cmd.asm_view_close",
+  "%cmd.asm_view_close_desc",
+  "asm_view_close",
+  "asm-view"
+);
+
+editor.debug("Show Assembly plugin initialized");
@@ -0,0 +1,269 @@
+/// <reference path="./lib/fresh.d.ts" />
+const editor = getEditor();
+
+/**
+ * Tutor Plugin - an interactive tutorial (freshtutor)
+ *
+ * "Tutor: Start Tutorial" opens an editable *Tutor* buffer holding one
+ * lesson at a time: moving around, multiple cursors, search and the
+ * command palette. Each lesson ends with an exercise below a divider
+ * line. While the tutor is the active buffer, the exercise is checked
+ * against the buffer text and the cursors a few times a second; once it
+ * passes, the next lesson replaces it.
+ *
+ * The lesson reached is saved as a checkpoint (plugin global state), so
+ * starting the tutorial again resumes there. "Tutor: Restart Tutorial"
+ * goes back to the first lesson.
+ */
+
+// =============================================================================
+// Lessons
+// =============================================================================
+
+/** What an exercise check gets to look at */
+interface ExerciseState {
+  /** Buffer text below the divider */
+  text: string;
+  /** Offset of the primary cursor into `text` */
+  primary: number;
+  /** Offsets of all cursors into `text` */
+  cursors: number[];
+}
+
+interface Lesson {
+  title: string;
+  /** Instructions shown above the divider */
+  body: () => string[];
+  /** Initial exercise text */
+  exercise: string;
+  passed: (state: ExerciseState) => boolean;
+}
+
+const DIVIDER = "-".repeat(64);
+const STATE_KEY = "lesson";
+
+/** Key bound to `action`, for quoting in instructions */
+function key(action: string): string {
+  return editor.getKeybindingLabel(action, "normal") ?? editor.t("text.unbound");
+}
+
+/** The line of `text` that contains offset `pos` */
+function lineAt(text: string, pos: number): string {
+  const start = text.lastIndexOf("\n", pos - 1) + 1;
+  const end = text.indexOf("\n", pos);
+  return text.slice(start, end === -1 ? text.length : end);
+}
+
+const FRUIT = ["pear", "apple", "plum", "cherry"];
+
+const LESSONS: Lesson[] = [
+  {
+    title: "Moving around",
+    body: () => [
+      "The arrow keys move the cursor one character or line at a time,",
+      "Home and End jump to the start and end of a line, PageUp and",
+      "PageDown move a screen at a time.",
+      "",
+      `Go to Line (${key("goto_line")}) jumps straight to a line number.`,
+      "",
+      "Exercise: put the cursor on the line that says TARGET.",
+    ],
+    exercise:
+      Array.from({ length: 12 }, () => "  . . . . . . . .\n").join("") +
+      "  >>> TARGET <<<\n" +
+      "  . . . . . . . .\n",
+    passed: ({ text, primary }) => lineAt(text, primary).includes("TARGET"),
+  },
+  {
+    title: "Multiple cursors",
+    body: () => [
+      `Select a word and press ${key("add_cursor_next_match")} to add a cursor at its`,
+      "next occurrence. Everything you type goes to every cursor; Esc",
+      "goes back to a single cursor.",
+      "",
+      "Exercise: change all three `red` below to `blue` at once. Select",
+      `the first red, press ${key("add_cursor_next_match")} twice, then type blue.`,
+    ],
+    exercise: "color = red\nborder = red\nshadow = red\n",
+    passed: ({ text, cursors }) =>
+      cursors.length >= 3 &&
+      !text.includes("red") &&
+      (text.match(/= blue$/gm) ?? []).length === 3,
+  },
+  {
+    title: "Search",
+    body: () => [
+      `Search (${key("search")}) finds text in the buffer: type what you are`,
+      "looking for and press Enter to jump to it. Searching again moves",
+      "on to the next match.",
+      "",
+      "Exercise: find the needle in the haystack below.",
+    ],
+    exercise:
+      Array.from({ length: 6 }, () => "hay hay hay hay hay hay hay hay\n").join("") +
+      "hay hay hay hay needle hay hay hay\n" +
+      "hay hay hay hay hay hay hay hay\n",
+    passed: ({ text, primary }) => {
+      const at = text.indexOf("needle");
+      return at !== -1 && primary >= at && primary <= at + "needle".length;
+    },
+  },
+  {
+    title: "The command palette",
+    body: () => [
+      `The command palette (${key("command_palette")}) runs any command by name.`,
+      "Start typing to filter the list, Enter runs the highlighted",
+      "command. Key bindings are shown next to each command.",
+      "",
+      "Exercise: select the four fruit lines below (Shift+Down) and run",
+      "Sort Lines from the palette.",
+    ],
+    exercise: FRUIT.map((f) => f + "\n").join(""),
+    passed: ({ text }) => {
+      const lines = text.split("\n").filter((l) => l.trim().length > 0);
+      return lines.join(",") === [...FRUIT].sort().join(",");
+    },
+  },
+];
+
+const FINISHED = [
+  "That's the tour: you can move around, edit with several cursors,",
+  "search, and find any command in the palette.",
+  "",
+  "Next: Help > Keyboard Shortcuts lists every key binding, and the",
+  "Keybinding Editor changes them. Close this buffer when you are done.",
+];
+
+// =============================================================================
+// State
+// =============================================================================
+
+let tutorBufferId: number | null = null;
+let lessonIndex = 0;
+/** Byte offset of the exercise in the tutor buffer */
+let exerciseStart = 0;
+/**
+ * How often the exercise is checked. Polling rather than hooks: search
+ * jumps, palette commands and multi-cursor typing don't all report
+ * through the edit and cursor hooks.
+ */
+const CHECK_INTERVAL_MS = 250;
+
+function savedLesson(): number {
+  const saved = editor.getGlobalState(STATE_KEY);
+  return typeof saved === "number" && saved >= 0 && saved < LESSONS.length ? saved : 0;
+}
+
+function showLesson(index: number): void {
+  if (tutorBufferId === null) return;
+  lessonIndex = index;
+  editor.setGlobalState(STATE_KEY, index);
+
+  const done = index >= LESSONS.length;
+  const header = done
+    ? editor.t("text.finished_header")
+    : editor.t("text.lesson_header", {
+        n: String(index + 1),
+        total: String(LESSONS.length),
+        title: LESSONS[index].title,
+      });
+  const body = done ? FINISHED : LESSONS[index].body();
+  const intro = body.join("\n") + "\n\n";
+
+  const entries: TextPropertyEntry[] = [
+    { text: header + "\n\n", style: { bold: true, fg: "syntax.keyword" } },
+    { text: intro },
+  ];
+  exerciseStart = editor.utf8ByteLength(header + "\n\n" + intro);
+  if (!done) {
+    entries.push({ text: DIVIDER + "\n", style: { fg: "editor.line_number_fg" } });
+    entries.push({ text: LESSONS[index].exercise });
+    exerciseStart += editor.utf8ByteLength(DIVIDER + "\n");
+  }
+
+  editor.setVirtualBufferContent(tutorBufferId, entries);
+  // Cursors added in the multiple-cursors lesson would otherwise carry
+  // over into the next one.
+  editor.executeAction("remove_secondary_cursors");
+  editor.setBufferCursor(tutorBufferId, exerciseStart);
+}
+
+async function checkExercise(): Promise<void> {
+  const bufferId = tutorBufferId;
+  if (bufferId === null || lessonIndex >= LESSONS.length) return;
+  if (editor.getActiveBufferId() !== bufferId) return;
+  const info = editor.getBufferInfo(bufferId);
+  if (!info) return;
+
+  const full = await editor.getBufferText(bufferId, 0, info.length);
+  const divider = full.indexOf(DIVIDER + "\n");
+  if (divider === -1) return;
+  const textStart = divider + DIVIDER.length + 1;
+  const startByte = editor.utf8ByteLength(full.slice(0, textStart));
+  const text = full.slice(textStart);
+  const primary = editor.getPrimaryCursor();
+  if (!primary || primary.position < startByte) return;
+  const cursors = editor.getAllCursors().map((c) => c.position - startByte);
+
+  // The buffer may have moved on while the text was being fetched.
+  if (tutorBufferId !== bufferId) return;
+  const lesson = LESSONS[lessonIndex];
+  if (!lesson.passed({ text, primary: primary.position - startByte, cursors })) return;
+
+  const next = lessonIndex + 1;
+  editor.setStatus(
+    next < LESSONS.length
+      ? editor.t("status.lesson_done", { n: String(lessonIndex + 1), next: String(next + 1) })
+      : editor.t("status.finished"),
+  );
+  showLesson(next);
+}
+
+// Runs until the tutor buffer is closed. Checks are sequential, so a
+// lesson can't be passed twice.
+async function checkLoop(bufferId: number): Promise<void> {
+  while (tutorBufferId === bufferId) {
+    await editor.delay(CHECK_INTERVAL_MS);
+    await checkExercise();
+  }
+}
+
+// =============================================================================
+// Commands
+// =============================================================================
+
+async function openTutor(index: number): Promise<void> {
+  if (tutorBufferId !== null) {
+    editor.showBuffer(tutorBufferId);
+    showLesson(index);
+    return;
+  }
+  const res = await editor.createVirtualBuffer({
+    name: "*Tutor*",
+    readOnly: false,
+    showLineNumbers: true,
+    showCursors: true,
+    editingDisabled: false,
+  });
+  tutorBufferId = res.bufferId;
+  editor.showBuffer(tutorBufferId);
+  showLesson(index);
+  void checkLoop(tutorBufferId);
+}
+
+async function tutor_start(): Promise<void> {
+  await openTutor(savedLesson());
+}
+registerHandler("tutor_start", tutor_start);
+
+async function tutor_restart(): Promise<void> {
+  await openTutor(0);
+}
+registerHandler("tutor_restart", tutor_restart);
+
+editor.on("buffer_closed", (data) => {
+  if (data.buffer_id === tutorBufferId) tutorBufferId = null;
+});
+
+editor.registerCommand("%cmd.start", "%cmd.start_desc", "tutor_start", null);
+editor.registerCommand("%cmd.restart", "%cmd.restart_desc", "tutor_restart", null);
@@ -2135,10 +2135,20 @@ registerHandler("vi_find_prev", vi_find_prev);
 
 // Center view
 function vi_center_cursor() : void {
-  editor.executeAction("center_cursor");
+  editor.executeAction("recenter");
 }
 registerHandler("vi_center_cursor", vi_center_cursor);
 
+function vi_cursor_to_top() : void {
+  editor.executeAction("scroll_cursor_to_top");
+}
+registerHandler("vi_cursor_to_top", vi_cursor_to_top);
+
+function vi_cursor_to_bottom() : void {
+  editor.executeAction("scroll_cursor_to_bottom");
+}
+registerHandler("vi_cursor_to_bottom", vi_cursor_to_bottom);
+
 // Half page movements
 function vi_half_page_down() : void {
   // Approximate half page with multiple down movements
@@ -3429,6 +3439,8 @@ editor.defineMode("vi-normal", [
   ["C-u", "vi_half_page_up"],
   ["%", "vi_matching_bracket"],
   ["z z", "vi_center_cursor"],
+  ["z t", "vi_cursor_to_top"],
+  ["z b", "vi_cursor_to_bottom"],
   ["{", "vi_paragraph_up"],
   ["}", "vi_paragraph_down"],
 
@@ -3786,6 +3798,8 @@ const navCommands = [
   ["vi_half_page_down", "half_page_down"],
   ["vi_half_page_up", "half_page_up"],
   ["vi_center_cursor", "center_cursor"],
+  ["vi_cursor_to_top", "cursor_to_top"],
+  ["vi_cursor_to_bottom", "cursor_to_bottom"],
   ["vi_search_forward", "search_forward"],
   ["vi_search_backward", "search_backward"],
   ["vi_find_next", "find_next"],
@@ -0,0 +1,213 @@
+//! Abbreviations: typed keys expanded on a word boundary.
+//!
+//! The table is `editor.abbreviations` with the buffer language's
+//! `languages.<id>.abbreviations` on top. Typing a character
+//! that can't be part of a word checks whether the text before the cursor
+//! ends with a key. A plain expansion (`teh` → `the`) replaces the key once
+//! the typed character is in, as an undo step of its own, so Undo brings
+//! back exactly what was typed. An expansion with tab stops (`;fn` → a
+//! function template) takes the place of the typed character and starts a
+//! snippet session on its first tab stop.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::model::event::{CursorId, Event};
+use crate::primitives::snippet::{expand_snippet_with, ExpandedSnippet};
+use crate::services::completion::provider::is_word_char_for_lang;
+
+use super::buffer_config_resolve;
+use super::Editor;
+
+/// Undo log description of an expansion.
+const DESCRIPTION: &str = "Expand abbreviation";
+
+/// An abbreviation found before the cursors.
+struct AbbreviationMatch {
+    /// The key's range at each cursor where it ends.
+    replacements: Vec<(CursorId, Range<usize>)>,
+    /// The configured expansion, in snippet syntax.
+    value: String,
+}
+
+impl Editor {
+    /// Expand a template abbreviation ending at the cursors in place of
+    /// typing `c`. Returns true when it did, so `c` must not be inserted.
+    pub(super) fn expand_template_abbreviation(&mut self, c: char) -> bool {
+        let Some(found) = self.abbreviation_at_cursors(c, 0) else {
+            return false;
+        };
+        if is_plain(&self.expand_abbreviation_value(&found.value)) {
+            return false;
+        }
+        self.insert_snippet_at_cursors(found.replacements, &found.value, true, DESCRIPTION);
+        true
+    }
+
+    /// Expand a plain abbreviation ending just before the `c` each cursor
+    /// has just typed.
+    pub(super) fn expand_abbreviation(&mut self, c: char) {
+        let Some(found) = self.abbreviation_at_cursors(c, c.len_utf8()) else {
+            return;
+        };
+        let expanded = self.expand_abbreviation_value(&found.value);
+        if !is_plain(&expanded) {
+            return;
+        }
+
+        // Descending order keeps each range valid while the later ones apply.
+        let mut ranges: Vec<Range<usize>> =
+            found.replacements.into_iter().map(|(_, r)| r).collect();
+        ranges.sort_by_key(|range| std::cmp::Reverse(range.start));
+        let cursor_id = CursorId::UNDO_SENTINEL;
+        let mut events = Vec::with_capacity(ranges.len() * 2);
+        for range in ranges {
+            let deleted_text = self
+                .active_state_mut()
+                .get_text_range(range.start, range.end);
+            events.push(Event::Delete {
+                range: range.clone(),
+                deleted_text,
+                cursor_id,
+            });
+            events.push(Event::Insert {
+                position: range.start,
+                text: expanded.text.clone(),
+                cursor_id,
+            });
+        }
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, DESCRIPTION.to_string()) {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+    }
+
+    /// The abbreviation ending `offset` bytes before the primary cursor,
+    /// with the range it covers at every cursor where the same key ends.
+    /// `None` unless `c` is a word boundary and no cursor has a selection.
+    fn abbreviation_at_cursors(&mut self, c: char, offset: usize) -> Option<AbbreviationMatch> {
+        let state = self.active_state();
+        let word_chars = state.buffer_settings.word_characters.clone();
+        if is_word_char_for_lang(c, &word_chars) {
+            return None;
+        }
+        let table = buffer_config_resolve::abbreviations(&state.language, &self.config);
+        let window = table.keys().map(|key| key.len()).max()? + 4;
+        let table: HashMap<String, String> = table
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+
+        let cursors: Vec<(CursorId, usize)> = self
+            .active_cursors()
+            .iter()
+            .map(|(id, cursor)| (id, cursor.position))
+            .collect();
+        if self
+            .active_cursors()
+            .iter()
+            .any(|(_, cursor)| cursor.selection_range().is_some())
+        {
+            return None;
+        }
+        let primary = self.active_cursors().primary_id();
+
+        let mut primary_key: Option<&str> = None;
+        let mut replacements = Vec::new();
+        for (cursor_id, position) in cursors {
+            let Some(end) = position.checked_sub(offset) else {
+                continue;
+            };
+            let state = self.active_state_mut();
+            if offset > 0 && state.get_text_range(end, position) != c.to_string() {
+                continue;
+            }
+            let before = state.get_text_range(end.saturating_sub(window), end);
+            let before = before.rsplit('\n').next().unwrap_or_default();
+            let Some(key) = key_at_end(before, &table, &word_chars) else {
+                continue;
+            };
+            if cursor_id == primary {
+                primary_key = Some(key);
+            }
+            replacements.push((cursor_id, key.to_string(), end - key.len()..end));
+        }
+
+        let key = primary_key?;
+        let value = table.get(key)?.clone();
+        let replacements = replacements
+            .into_iter()
+            .filter(|(_, k, _)| k == key)
+            .map(|(cursor_id, _, range)| (cursor_id, range))
+            .collect();
+        Some(AbbreviationMatch {
+            replacements,
+            value,
+        })
+    }
+
+    fn expand_abbreviation_value(&mut self, value: &str) -> ExpandedSnippet {
+        let variables = self.snippet_variables();
+        expand_snippet_with(value, |name| variables.get(name).cloned())
+    }
+}
+
+/// Whether an expansion is plain text, with no tab stops or final cursor
+/// placement of its own.
+fn is_plain(expanded: &ExpandedSnippet) -> bool {
+    expanded.tabstops.is_empty() && expanded.cursor_offset == expanded.text.len()
+}
+
+/// The longest key of `table` that `text` ends with and that starts on a
+/// word boundary: at the start of `text`, after a character that can't be
+/// part of a word, or with a first character that can't be one itself
+/// (so `;fn` expands right after an identifier).
+fn key_at_end<'a>(
+    text: &str,
+    table: &'a HashMap<String, String>,
+    word_chars: &str,
+) -> Option<&'a str> {
+    let is_word = |c: char| is_word_char_for_lang(c, word_chars);
+    table
+        .keys()
+        .filter(|key| !key.is_empty() && text.ends_with(key.as_str()))
+        .filter(|key| {
+            let starts_word = key.chars().next().is_some_and(is_word);
+            let preceded_by_word = text[..text.len() - key.len()]
+                .chars()
+                .next_back()
+                .is_some_and(is_word);
+            !(starts_word && preceded_by_word)
+        })
+        .max_by_key(|key| key.len())
+        .map(String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn keys_match_on_word_boundaries() {
+        let table = table(&[("teh", "the"), (";fn", "fn"), ("btw", "by the way")]);
+        assert_eq!(key_at_end("teh", &table, ""), Some("teh"));
+        assert_eq!(key_at_end("I saw (teh", &table, ""), Some("teh"));
+        assert_eq!(key_at_end("xteh", &table, ""), None);
+        assert_eq!(key_at_end("tehx", &table, ""), None);
+        assert_eq!(key_at_end("value;fn", &table, ""), Some(";fn"));
+        assert_eq!(key_at_end("", &table, ""), None);
+    }
+
+    #[test]
+    fn longest_key_wins() {
+        let table = table(&[("fn", "function"), (";fn", "template")]);
+        assert_eq!(key_at_end("a ;fn", &table, ""), Some(";fn"));
+        assert_eq!(key_at_end("a fn", &table, ""), Some("fn"));
+    }
+}
@@ -133,6 +133,7 @@ impl Window {
             .expect("active window must have a populated split layout");
         let active_split = mgr.active_split();
         let previous_target = vs.get(&active_split).map(|vs| vs.active_target());
+        let previous_buffer = self.active_buffer();
 
         // Atomic pane-buffer update: tree + SVS in lockstep.
         self.set_pane_buffer(active_split, buffer_id);
@@ -150,6 +151,9 @@ impl Window {
             }
         }
 
+        self.touch_buffer_mru(previous_buffer);
+        self.touch_buffer_mru(buffer_id);
+
         self.sync_terminal_mode_flags();
 
         // Window resize events only resize terminals that are currently the
@@ -353,6 +357,8 @@ impl Window {
                     view_state.add_buffer(buffer_id);
                     view_state.push_focus(crate::view::split::TabTarget::Buffer(previous_buffer));
                 }
+                self.touch_buffer_mru(previous_buffer);
+                self.touch_buffer_mru(buffer_id);
                 // Note: We don't sync file explorer here to avoid flicker during split focus changes.
                 // File explorer syncs when explicitly focused via focus_file_explorer().
             }
@@ -143,6 +143,26 @@ impl Editor {
                 } => {
                     self.handle_lsp_diagnostics(uri, diagnostics, server_name);
                 }
+                AsyncMessage::SchemaDiagnostics {
+                    window,
+                    buffer_id,
+                    generation,
+                    uri,
+                    diagnostics,
+                } => {
+                    self.handle_schema_diagnostics(window, buffer_id, generation, uri, diagnostics);
+                }
+                AsyncMessage::LinterDiagnostics {
+                    window,
+                    linter,
+                    files,
+                    workspace,
+                } => {
+                    self.handle_linter_diagnostics(window, linter, files, workspace);
+                }
+                AsyncMessage::FileOpenedForHooks { buffer_id } => {
+                    self.run_event_hooks(crate::config::HookEvent::Open, buffer_id);
+                }
                 AsyncMessage::LspInitialized {
                     language,
                     server_name,
@@ -220,11 +240,10 @@ impl Editor {
                 } => {
                     self.handle_lsp_code_action_resolved(action);
                 }
-                AsyncMessage::LspCompletionResolved {
-                    request_id: _,
-                    item,
-                } => {
-                    if let Ok(resolved) = item {
+                AsyncMessage::LspCompletionResolved { request_id, item } => {
+                    if self.is_completion_docs_request(request_id) {
+                        self.handle_completion_docs_resolved(request_id, item.ok());
+                    } else if let Ok(resolved) = item {
                         self.handle_completion_resolved(resolved);
                     }
                 }
@@ -317,6 +336,13 @@ impl Editor {
                 AsyncMessage::FileExplorerInitialized { window, view } => {
                     self.handle_file_explorer_initialized(window, view);
                 }
+                AsyncMessage::TagsGenerated {
+                    window,
+                    manual,
+                    error,
+                } => {
+                    self.handle_tags_generated(window, manual, error);
+                }
                 AsyncMessage::FileExplorerInitFailed { window } => {
                     if let Some(win) = self.windows.get_mut(&window) {
                         win.file_explorer_init_failed();
@@ -453,6 +479,11 @@ impl Editor {
                 } => {
                     self.handle_quick_open_files_loaded(cwd, files, complete);
                 }
+                AsyncMessage::FilePreviewLoaded { path } => {
+                    // Nothing to apply: the render this message triggers
+                    // picks the preview up from the loader's cache.
+                    tracing::trace!("File preview loaded: {}", path.display());
+                }
                 AsyncMessage::PluginsDirLoaded {
                     dir,
                     errors,
@@ -520,11 +551,16 @@ impl Editor {
 
         // Poll periodic update checker for new results
         if let Some(ref mut checker) = self.update_checker {
-            // Poll for results but don't act on them - just cache
-            let _ = checker.poll_result();
+            // Poll for results but don't act on them - just cache. A failed
+            // check (no network, proxy trouble) only goes to the log; the
+            // next attempt is tomorrow's daily check, not a retry.
+            if let Some(Err(e)) = checker.poll_result() {
+                tracing::warn!("Update check failed: {}", e);
+            }
         }
 
-        // Poll for file changes (auto-revert) and file tree changes
+        // Poll for file changes (auto-revert), file tree changes and edits to
+        // user theme and config files (hot reload)
         let file_changes = {
             let _s = tracing::info_span!("poll_file_changes").entered();
             self.poll_file_changes()
@@ -533,9 +569,28 @@ impl Editor {
             let _s = tracing::info_span!("poll_file_tree_changes").entered();
             self.poll_file_tree_changes()
         };
+        let theme_changes = {
+            let _s = tracing::info_span!("poll_theme_changes").entered();
+            self.poll_theme_changes()
+        };
+        let config_changes = {
+            let _s = tracing::info_span!("poll_config_changes").entered();
+            self.poll_config_changes()
+        };
+        let editor_log_changes = {
+            let _s = tracing::info_span!("poll_editor_log").entered();
+            self.poll_editor_log()
+        };
 
         // Trigger render if any async messages, plugin commands were processed, or plugin requested render
-        needs_render || processed_any_commands || plugin_render || file_changes || tree_changes
+        needs_render
+            || processed_any_commands
+            || plugin_render
+            || file_changes
+            || tree_changes
+            || theme_changes
+            || config_changes
+            || editor_log_changes
     }
 
     /// Handle a server's `initialize` response: record capabilities and kick off
@@ -664,7 +719,7 @@ impl Editor {
             }
             Err(e) => {
                 tracing::warn!("codeAction/resolve failed: {}", e);
-                self.set_status_message(format!("Code action resolve failed: {e}"));
+                self.set_status_error(format!("Code action resolve failed: {e}"));
             }
         }
     }
@@ -694,12 +749,18 @@ impl Editor {
                     .resolve_callback(JsCallbackId::from(callback_id), "null".to_string());
             }
             PluginAsyncMessage::ProcessStdout { process_id, data } => {
+                if self.log_hook_output(process_id, &data) {
+                    return;
+                }
                 self.plugin_manager.read().unwrap().run_hook(
                     "onProcessStdout",
                     crate::services::plugins::hooks::HookArgs::ProcessOutput { process_id, data },
                 );
             }
             PluginAsyncMessage::ProcessStderr { process_id, data } => {
+                if self.log_hook_output(process_id, &data) {
+                    return;
+                }
                 self.plugin_manager.read().unwrap().run_hook(
                     "onProcessStderr",
                     crate::services::plugins::hooks::HookArgs::ProcessOutput { process_id, data },
@@ -711,14 +772,16 @@ impl Editor {
                 exit_code,
             } => {
                 self.background_process_handles.remove(&process_id);
-                let result = fresh_core::api::BackgroundProcessResult {
-                    process_id,
-                    exit_code,
-                };
-                self.plugin_manager.read().unwrap().resolve_callback(
-                    JsCallbackId::from(callback_id),
-                    serde_json::to_string(&result).unwrap(),
-                );
+                if self.finish_job(process_id, exit_code) {
+                    let result = fresh_core::api::BackgroundProcessResult {
+                        process_id,
+                        exit_code,
+                    };
+                    self.plugin_manager.read().unwrap().resolve_callback(
+                        JsCallbackId::from(callback_id),
+                        serde_json::to_string(&result).unwrap(),
+                    );
+                }
             }
             PluginAsyncMessage::LspResponse {
                 language: _,
@@ -1338,7 +1401,7 @@ impl Editor {
                 // on the SHELL's own status line (messages are per-window;
                 // the user may be looking at another workspace by now).
                 if self.dormant_remote.contains_key(&window_id) {
-                    w.set_status_message(format!("Connection failed: {reason}"));
+                    w.set_status_error(format!("Connection failed: {reason}"));
                 }
             } else if self.dormant_remote.contains_key(&window_id) {
                 // A dive-triggered connect of a dormant session failed. The
@@ -1351,11 +1414,11 @@ impl Editor {
                 // failure message is posted *after* the activation so the
                 // switch machinery can't clear it off the status line.
                 self.activate_failed_dormant_placeholder(window_id, reason.clone());
-                self.set_status_message(format!("Connection failed: {reason}"));
+                self.set_status_error(format!("Connection failed: {reason}"));
             } else {
                 // The session was closed while the connect was in flight —
                 // nothing to attach the failure to; just surface it.
-                self.set_status_message(format!("Connection failed: {reason}"));
+                self.set_status_error(format!("Connection failed: {reason}"));
             }
         }
         self.reject_remote_attach(request_id, error);
@@ -1408,9 +1471,11 @@ impl Editor {
                 .get_mut(&buf_id)
             {
                 let first_line = state.buffer.first_line_lossy();
+                let modeline_region = state.buffer.modeline_region_lossy();
                 let detected = crate::primitives::detected_language::DetectedLanguage::from_path(
                     &path,
                     first_line.as_deref(),
+                    modeline_region.as_deref(),
                     &self.grammar_registry,
                     &self.config.languages,
                 );
@@ -25,7 +25,7 @@ use serde_json::Value;
 use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
-use super::types::{LspMessageEntry, LspProgressInfo};
+use super::types::{LspMessageEntry, LspProgressInfo, MessageSeverity};
 use super::Editor;
 
 // =============================================================================
@@ -86,26 +86,25 @@ impl Editor {
             .collect()
     }
 
-    /// Apply diagnostics to a buffer identified by URI.
+    /// Apply diagnostics to a buffer of `window_id` identified by URI.
     /// Returns `(buffer_id, actually_updated)` if buffer was found, None otherwise.
     /// `actually_updated` is false when the DIAG CACHE determined no overlay changes were needed.
     fn apply_diagnostics_to_buffer(
         &mut self,
+        window_id: fresh_core::WindowId,
         uri: &str,
         diagnostics: &[Diagnostic],
     ) -> Option<(BufferId, bool)> {
-        let buffer_id = self.find_buffer_by_uri(uri)?;
-        let state = self
-            .windows
-            .get_mut(&self.active_window)
-            .map(|w| &mut w.buffers)
-            .expect("active window present")
-            .get_mut(&buffer_id)?;
-        let updated = crate::services::lsp::diagnostics::apply_diagnostics_to_state_cached(
-            state,
-            diagnostics,
-            &self.theme.read().unwrap(),
-        );
+        let window = self.windows.get_mut(&window_id)?;
+        let buffer_id = window.buffer_id_for_uri(uri)?;
+        let state = window.buffers.get_mut(&buffer_id)?;
+        let updated =
+            crate::services::lsp::diagnostics::apply_diagnostics_to_state_cached_with_style(
+                state,
+                diagnostics,
+                &self.theme.read().unwrap(),
+                self.terminal_capabilities.renders_styled_underlines(),
+            );
         Some((buffer_id, updated))
     }
 }
@@ -120,14 +119,14 @@ impl Editor {
     /// carry it forward across later edits (#2602).
     fn anchor_diagnostics(
         &self,
+        window_id: fresh_core::WindowId,
         uri: &str,
         diagnostics: Vec<Diagnostic>,
     ) -> Vec<AnchoredDiagnostic> {
-        let state = self.find_buffer_by_uri(uri).and_then(|id| {
-            self.windows
-                .get(&self.active_window)
-                .and_then(|w| w.buffers.get(&id))
-        });
+        let state = self
+            .windows
+            .get(&window_id)
+            .and_then(|w| w.buffer_id_for_uri(uri).and_then(|id| w.buffers.get(&id)));
         diagnostics
             .into_iter()
             .map(|d| AnchoredDiagnostic::capture(d, state))
@@ -137,15 +136,26 @@ impl Editor {
     /// Materialise the merged push + pull view (positions mapped to the buffer's
     /// current version) and rebuild the overlays from it.
     fn merge_and_apply_diagnostics(&mut self, uri: &str) {
-        let merged = self.active_window_mut().recompute_merged_diagnostics(uri);
+        self.merge_and_apply_diagnostics_in(self.active_window, uri);
+    }
 
-        if let Some((buffer_id, updated)) = self.apply_diagnostics_to_buffer(uri, &merged) {
+    /// [`Self::merge_and_apply_diagnostics`] for the buffers of `window_id`.
+    fn merge_and_apply_diagnostics_in(&mut self, window_id: fresh_core::WindowId, uri: &str) {
+        let Some(window) = self.windows.get_mut(&window_id) else {
+            return;
+        };
+        let merged = window.recompute_merged_diagnostics(uri);
+
+        if let Some((buffer_id, updated)) =
+            self.apply_diagnostics_to_buffer(window_id, uri, &merged)
+        {
             if updated {
                 tracing::info!(
                     "Applied {} diagnostics to buffer {:?} (overlays updated)",
                     merged.len(),
                     buffer_id
                 );
+                self.run_event_hooks(crate::config::HookEvent::Diagnostics, buffer_id);
             } else {
                 tracing::debug!(
                     "Diagnostics unchanged for buffer {:?} ({} diagnostics, cache hit)",
@@ -196,9 +206,36 @@ impl Editor {
             uri
         );
 
-        let anchored = self.anchor_diagnostics(&uri, diagnostics);
-        let server_map = self
-            .active_window_mut()
+        self.store_push_diagnostics(uri, server_name, diagnostics);
+    }
+
+    /// Store one source's diagnostics for `uri` (replacing its previous set)
+    /// and re-apply the merged result. Shared by LSP push diagnostics and
+    /// in-process producers such as schema validation.
+    pub(super) fn store_push_diagnostics(
+        &mut self,
+        uri: String,
+        server_name: String,
+        diagnostics: Vec<Diagnostic>,
+    ) {
+        self.store_push_diagnostics_in(self.active_window, uri, server_name, diagnostics);
+    }
+
+    /// [`Self::store_push_diagnostics`] into `window_id`, for background
+    /// producers whose window may no longer be the active one. Does nothing
+    /// if the window has closed.
+    pub(super) fn store_push_diagnostics_in(
+        &mut self,
+        window_id: fresh_core::WindowId,
+        uri: String,
+        server_name: String,
+        diagnostics: Vec<Diagnostic>,
+    ) {
+        let anchored = self.anchor_diagnostics(window_id, &uri, diagnostics);
+        let Some(window) = self.windows.get_mut(&window_id) else {
+            return;
+        };
+        let server_map = window
             .stored_push_diagnostics
             .entry(uri.clone())
             .or_default();
@@ -206,15 +243,49 @@ impl Editor {
             server_map.remove(&server_name);
             // Clean up empty outer entry
             if server_map.is_empty() {
-                self.active_window_mut()
-                    .stored_push_diagnostics
-                    .remove(&uri);
+                window.stored_push_diagnostics.remove(&uri);
             }
         } else {
             server_map.insert(server_name, anchored);
         }
 
-        self.merge_and_apply_diagnostics(&uri);
+        self.merge_and_apply_diagnostics_in(window_id, &uri);
+    }
+
+    /// Store a linter run's results under the linter's own key, next to the
+    /// language servers' diagnostics for the same files, in the window that
+    /// ran the linter.
+    pub(super) fn handle_linter_diagnostics(
+        &mut self,
+        window_id: fresh_core::WindowId,
+        linter: String,
+        files: Vec<(String, Vec<Diagnostic>)>,
+        workspace: bool,
+    ) {
+        let key = format!(
+            "{}{}",
+            crate::services::linters::DIAGNOSTIC_KEY_PREFIX,
+            linter
+        );
+        let Some(window) = self.windows.get(&window_id) else {
+            return;
+        };
+        if workspace {
+            let stale: Vec<String> = window
+                .stored_push_diagnostics
+                .iter()
+                .filter(|(uri, servers)| {
+                    servers.contains_key(&key) && !files.iter().any(|(u, _)| u == *uri)
+                })
+                .map(|(uri, _)| uri.clone())
+                .collect();
+            for uri in stale {
+                self.store_push_diagnostics_in(window_id, uri, key.clone(), Vec::new());
+            }
+        }
+        for (uri, diagnostics) in files {
+            self.store_push_diagnostics_in(window_id, uri, key.clone(), diagnostics);
+        }
     }
 
     /// Handle LSP pulled diagnostics (pull model — native RA diagnostics, LSP 3.17+)
@@ -261,7 +332,7 @@ impl Editor {
             server_name
         );
 
-        let anchored = self.anchor_diagnostics(&uri, diagnostics);
+        let anchored = self.anchor_diagnostics(self.active_window, &uri, diagnostics);
         let server_map = self
             .active_window_mut()
             .stored_pull_diagnostics
@@ -1095,6 +1166,14 @@ impl Editor {
             self.active_window_mut().lsp_window_messages.remove(0);
         }
 
+        let severity = match message_type {
+            LspMessageType::Error => MessageSeverity::Error,
+            LspMessageType::Warning => MessageSeverity::Warning,
+            LspMessageType::Info | LspMessageType::Log => MessageSeverity::Info,
+        };
+        self.active_window_mut()
+            .record_message(severity, &format!("LSP ({language})"), &message);
+
         // Show important messages in status bar
         match message_type {
             LspMessageType::Error | LspMessageType::Warning => {
@@ -1577,6 +1656,8 @@ impl Editor {
             | Pc::SpawnProcess { .. }
             | Pc::SpawnBackgroundProcess { .. }
             | Pc::KillBackgroundProcess { .. }
+            | Pc::WriteJobStdin { .. }
+            | Pc::CloseJobStdin { .. }
             | Pc::SpawnProcessWait { .. }
             | Pc::HttpFetch { .. }
             | Pc::WatchPath { .. }
@@ -0,0 +1,768 @@
+//! Benchmark mode (`fresh --bench`)
+//!
+//! Runs scripted operation sequences against a headless editor and reports
+//! wall-clock and allocation statistics, so regressions in the buffer and
+//! render layers show up as numbers rather than "it feels slower".
+//!
+//! Corpora are generated from a fixed seed rather than shipped as files: the
+//! same `corpus=`, `lines=` and `seed=` options always produce byte-identical
+//! input, and the report carries a fingerprint of the corpus so two runs can
+//! be checked for comparability.
+//!
+//! Every iteration builds a fresh editor (plugins off, isolated state
+//! directories, default keymap) so iterations don't warm each other's caches.
+//! Only the scenario body is timed; setup such as opening the file for the
+//! scroll scenario is not.
+
+use super::Editor;
+use crate::config::Config;
+use crate::config_io::DirectoryContext;
+use crate::input::keybindings::Action;
+use crate::model::filesystem::{FileSystem, StdFileSystem};
+use anyhow::{bail, Context, Result as AnyhowResult};
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// ============================================================================
+// Allocation counting
+// ============================================================================
+
+static COUNTING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// Global allocator wrapper that counts allocations while a benchmark
+/// iteration is being measured.
+///
+/// Outside a measurement the only overhead is one relaxed atomic load per
+/// allocation. Counts are process-wide, so work done on background threads
+/// during a scenario is included.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    #[inline]
+    fn record(size: usize) {
+        if COUNTING.load(Ordering::Relaxed) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+// SAFETY: every call is forwarded unchanged to the system allocator.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::record(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::record(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::record(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+/// Allocation counters captured over one measured region.
+#[derive(Debug, Clone, Copy, Default)]
+struct AllocStats {
+    count: u64,
+    bytes: u64,
+}
+
+fn start_counting() {
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    ALLOCATED_BYTES.store(0, Ordering::Relaxed);
+    COUNTING.store(true, Ordering::SeqCst);
+}
+
+fn stop_counting() -> AllocStats {
+    COUNTING.store(false, Ordering::SeqCst);
+    AllocStats {
+        count: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+    }
+}
+
+/// Whether [`CountingAllocator`] is the process's global allocator. The
+/// library can be linked into binaries that don't install it, in which case
+/// allocation columns are omitted rather than reported as zero.
+fn allocator_installed() -> bool {
+    start_counting();
+    drop(std::hint::black_box(Box::new(0u64)));
+    stop_counting().count > 0
+}
+
+// ============================================================================
+// Corpora
+// ============================================================================
+
+/// Kind of synthetic file a benchmark runs against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorpusKind {
+    /// Rust source: short, indented lines that exercise syntax highlighting.
+    Code,
+    /// Log output: long single lines with little structure.
+    Log,
+    /// Markdown prose: wrapped paragraphs separated by blank lines.
+    Prose,
+}
+
+impl CorpusKind {
+    pub const ALL: [CorpusKind; 3] = [CorpusKind::Code, CorpusKind::Log, CorpusKind::Prose];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            CorpusKind::Code => "code",
+            CorpusKind::Log => "log",
+            CorpusKind::Prose => "prose",
+        }
+    }
+
+    fn file_name(self) -> &'static str {
+        match self {
+            CorpusKind::Code => "corpus.rs",
+            CorpusKind::Log => "corpus.log",
+            CorpusKind::Prose => "corpus.md",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+/// xorshift64* — tiny, seedable and stable across platforms and releases,
+/// which is all corpus generation needs.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed.max(1))
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.below(items.len())]
+    }
+}
+
+const WORDS: &[&str] = &[
+    "buffer", "cursor", "render", "line", "width", "offset", "chunk", "piece", "tree", "marker",
+    "split", "view", "theme", "token", "scope", "range", "event", "state", "layout", "frame",
+    "query", "match", "index", "cache", "the", "a", "of", "to", "and", "in", "with", "from",
+];
+
+const TYPES: &[&str] = &[
+    "u32",
+    "usize",
+    "i64",
+    "&str",
+    "String",
+    "Vec<u8>",
+    "Option<usize>",
+];
+
+/// Generate `lines` lines of the given corpus kind. The output depends only
+/// on the arguments.
+pub fn generate_corpus(kind: CorpusKind, lines: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut out: Vec<String> = Vec::with_capacity(lines + 16);
+    match kind {
+        CorpusKind::Code => {
+            let mut n = 0;
+            while out.len() < lines {
+                let name = format!("{}_{}_{}", rng.pick(WORDS), rng.pick(WORDS), n);
+                out.push(format!(
+                    "/// Computes the {} {} for a {}.",
+                    rng.pick(WORDS),
+                    rng.pick(WORDS),
+                    rng.pick(WORDS)
+                ));
+                out.push(format!(
+                    "pub fn {}(input: {}, limit: {}) -> Result<{}, String> {{",
+                    name,
+                    rng.pick(TYPES),
+                    rng.pick(TYPES),
+                    rng.pick(TYPES)
+                ));
+                for _ in 0..(2 + rng.below(5)) {
+                    out.push(format!(
+                        "    let {} = limit.wrapping_mul({}) + {};",
+                        rng.pick(WORDS),
+                        rng.below(1000),
+                        rng.below(100)
+                    ));
+                }
+                out.push(format!("    if input.len() > {} {{", rng.below(4096)));
+                out.push(format!(
+                    "        return Err(format!(\"{} {} exceeds {{}}\", limit));",
+                    rng.pick(WORDS),
+                    rng.pick(WORDS)
+                ));
+                out.push("    }".to_string());
+                out.push(format!("    Ok({}::default())", rng.pick(TYPES)));
+                out.push("}".to_string());
+                out.push(String::new());
+                n += 1;
+            }
+        }
+        CorpusKind::Log => {
+            let levels = ["INFO", "INFO", "INFO", "DEBUG", "WARN", "ERROR"];
+            for i in 0..lines {
+                let mut line = format!(
+                    "2024-01-01T{:02}:{:02}:{:02}.{:03}Z {:5} service={} request={:016x}",
+                    (i / 3_600_000) % 24,
+                    (i / 60_000) % 60,
+                    (i / 1000) % 60,
+                    i % 1000,
+                    levels[rng.below(levels.len())],
+                    rng.pick(WORDS),
+                    rng.next()
+                );
+                for _ in 0..(10 + rng.below(30)) {
+                    line.push(' ');
+                    line.push_str(rng.pick(WORDS));
+                }
+                out.push(line);
+            }
+        }
+        CorpusKind::Prose => {
+            let mut section = 0;
+            while out.len() < lines {
+                section += 1;
+                out.push(format!("## Section {} — {}", section, rng.pick(WORDS)));
+                out.push(String::new());
+                for _ in 0..(1 + rng.below(4)) {
+                    let mut line = String::new();
+                    for _ in 0..(20 + rng.below(80)) {
+                        let word = rng.pick(WORDS);
+                        if line.len() + word.len() + 1 > 72 {
+                            out.push(std::mem::take(&mut line));
+                        }
+                        if !line.is_empty() {
+                            line.push(' ');
+                        }
+                        line.push_str(word);
+                    }
+                    line.push('.');
+                    out.push(line);
+                    out.push(String::new());
+                }
+            }
+        }
+    }
+    out.truncate(lines);
+    let mut text = out.join("\n");
+    text.push('\n');
+    text
+}
+
+/// FNV-1a hash of the corpus, reported so runs can be checked for identical
+/// input.
+fn fingerprint(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+// ============================================================================
+// Scenarios
+// ============================================================================
+
+/// A headless editor plus the in-memory terminal it renders into.
+struct Session {
+    editor: Editor,
+    terminal: Terminal<TestBackend>,
+    corpus: PathBuf,
+}
+
+impl Session {
+    fn new(dir: &Path, corpus: &Path, width: u16, height: u16) -> AnyhowResult<Self> {
+        let editor = Editor::with_working_dir(
+            Config::default(),
+            width,
+            height,
+            Some(dir.to_path_buf()),
+            DirectoryContext::for_testing(&dir.join("state")),
+            false,
+            crate::view::color_support::ColorCapability::TrueColor,
+            Arc::new(StdFileSystem) as Arc<dyn FileSystem + Send + Sync>,
+        )?;
+        Ok(Self {
+            editor,
+            terminal: Terminal::new(TestBackend::new(width, height))?,
+            corpus: corpus.to_path_buf(),
+        })
+    }
+
+    fn render(&mut self) -> AnyhowResult<()> {
+        self.editor.process_async_messages();
+        let editor = &mut self.editor;
+        self.terminal.draw(|frame| editor.render(frame))?;
+        Ok(())
+    }
+
+    fn open(&mut self) -> AnyhowResult<()> {
+        self.editor.open_file(&self.corpus.clone())?;
+        Ok(())
+    }
+
+    fn key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> AnyhowResult<()> {
+        self.editor.handle_key(code, modifiers)
+    }
+
+    fn action(&mut self, action: Action) -> AnyhowResult<()> {
+        self.editor.handle_action(action)
+    }
+
+    fn type_text(&mut self, text: &str) -> AnyhowResult<()> {
+        for c in text.chars() {
+            self.key(KeyCode::Char(c), KeyModifiers::NONE)?;
+        }
+        Ok(())
+    }
+}
+
+type Step = fn(&mut Session) -> AnyhowResult<()>;
+
+/// A named operation sequence. `setup` runs untimed before each measured
+/// `run`.
+pub struct Scenario {
+    pub name: &'static str,
+    pub description: &'static str,
+    setup: Step,
+    run: Step,
+}
+
+fn open_and_render(s: &mut Session) -> AnyhowResult<()> {
+    s.open()?;
+    s.render()
+}
+
+fn nothing(_: &mut Session) -> AnyhowResult<()> {
+    Ok(())
+}
+
+/// The built-in scenarios, in the order they run by default.
+pub const SCENARIOS: &[Scenario] = &[
+    Scenario {
+        name: "open",
+        description: "Open the corpus and draw the first frame",
+        setup: nothing,
+        run: open_and_render,
+    },
+    Scenario {
+        name: "scroll",
+        description: "Page through the first 200 screens, then jump to the end and back",
+        setup: open_and_render,
+        run: |s| {
+            for _ in 0..200 {
+                s.action(Action::MovePageDown)?;
+                s.render()?;
+            }
+            s.action(Action::MoveDocumentEnd)?;
+            s.render()?;
+            s.action(Action::MoveDocumentStart)?;
+            s.render()
+        },
+    },
+    Scenario {
+        name: "type",
+        description: "Type 2,000 characters mid-file, redrawing every 10 keystrokes",
+        setup: |s| {
+            open_and_render(s)?;
+            for _ in 0..50 {
+                s.action(Action::MovePageDown)?;
+            }
+            s.render()
+        },
+        run: |s| {
+            let line = "let value = compute(input, 42); ";
+            for i in 0..2000 {
+                let c = line.as_bytes()[i % line.len()] as char;
+                if i % 80 == 79 {
+                    s.action(Action::InsertNewline)?;
+                } else {
+                    s.key(KeyCode::Char(c), KeyModifiers::NONE)?;
+                }
+                if i % 10 == 9 {
+                    s.render()?;
+                }
+            }
+            s.render()
+        },
+    },
+    Scenario {
+        name: "mass-edit",
+        description: "Toggle a comment on every line, then undo and redo it",
+        setup: open_and_render,
+        run: |s| {
+            s.action(Action::SelectAll)?;
+            s.action(Action::ToggleComment)?;
+            s.render()?;
+            s.action(Action::Undo)?;
+            s.render()?;
+            s.action(Action::Redo)?;
+            s.render()
+        },
+    },
+    Scenario {
+        name: "search",
+        description: "Search for a common word and step through 200 matches",
+        setup: open_and_render,
+        run: |s| {
+            s.action(Action::Search)?;
+            s.type_text("buffer")?;
+            s.key(KeyCode::Enter, KeyModifiers::NONE)?;
+            s.render()?;
+            for _ in 0..200 {
+                s.action(Action::FindNext)?;
+                s.render()?;
+            }
+            Ok(())
+        },
+    },
+    Scenario {
+        name: "resize",
+        description: "Redraw 100 frames while alternating the terminal width",
+        setup: open_and_render,
+        run: |s| {
+            for i in 0..100u16 {
+                let width = 100 + (i % 2) * 20;
+                s.editor.resize(width, 40);
+                s.terminal.backend_mut().resize(width, 40);
+                s.render()?;
+            }
+            Ok(())
+        },
+    },
+];
+
+// ============================================================================
+// Running and reporting
+// ============================================================================
+
+/// Options parsed from the `--bench=` list.
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    /// Scenario names to run; empty runs all of them.
+    pub scenarios: Vec<String>,
+    pub corpus: CorpusKind,
+    pub lines: usize,
+    pub seed: u64,
+    pub iterations: usize,
+    pub json: bool,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            scenarios: Vec::new(),
+            corpus: CorpusKind::Code,
+            lines: 100_000,
+            seed: 0x5eed,
+            iterations: 5,
+            json: false,
+        }
+    }
+}
+
+impl BenchOptions {
+    /// Parse the comma-separated `--bench=` list: scenario names plus
+    /// `corpus=`, `lines=`, `seed=`, `iterations=` and `json`.
+    pub fn parse(args: &[String]) -> AnyhowResult<Self> {
+        fn number<T: std::str::FromStr>(key: &str, value: &str) -> AnyhowResult<T> {
+            value
+                .parse()
+                .map_err(|_| anyhow::anyhow!("{key}= needs a number, got '{value}'"))
+        }
+
+        let mut options = Self::default();
+        for arg in args.iter().filter(|a| !a.is_empty()) {
+            match arg.split_once('=') {
+                Some(("corpus", name)) => {
+                    options.corpus = CorpusKind::from_name(name).with_context(|| {
+                        format!("unknown corpus '{name}' (expected code, log or prose)")
+                    })?;
+                }
+                Some(("lines", n)) => options.lines = number("lines", n)?,
+                Some(("seed", n)) => options.seed = number("seed", n)?,
+                Some(("iterations", n)) => options.iterations = number("iterations", n)?,
+                Some((key, _)) => bail!("unknown --bench option '{key}'"),
+                None if arg == "json" => options.json = true,
+                None => {
+                    let name = arg.as_str();
+                    if !SCENARIOS.iter().any(|s| s.name == name) {
+                        let known: Vec<_> = SCENARIOS.iter().map(|s| s.name).collect();
+                        bail!(
+                            "unknown scenario '{name}' (expected one of: {})",
+                            known.join(", ")
+                        );
+                    }
+                    options.scenarios.push(name.to_string());
+                }
+            }
+        }
+        if options.iterations == 0 {
+            bail!("iterations must be at least 1");
+        }
+        Ok(options)
+    }
+}
+
+/// Measurements for one scenario across all iterations.
+#[derive(Debug, Clone)]
+pub struct ScenarioResult {
+    pub name: &'static str,
+    pub times: Vec<Duration>,
+    /// Per-iteration allocation count and bytes, when the counting allocator
+    /// is installed.
+    pub allocations: Option<Vec<(u64, u64)>>,
+}
+
+impl ScenarioResult {
+    fn sorted_ms(&self) -> Vec<f64> {
+        let mut ms: Vec<f64> = self.times.iter().map(|t| t.as_secs_f64() * 1e3).collect();
+        ms.sort_by(f64::total_cmp);
+        ms
+    }
+
+    pub fn min_ms(&self) -> f64 {
+        self.sorted_ms()[0]
+    }
+
+    pub fn median_ms(&self) -> f64 {
+        median(&self.sorted_ms())
+    }
+
+    pub fn max_ms(&self) -> f64 {
+        *self.sorted_ms().last().unwrap_or(&0.0)
+    }
+
+    /// Median allocation count and bytes per iteration.
+    pub fn median_allocations(&self) -> Option<(f64, f64)> {
+        let allocations = self.allocations.as_ref()?;
+        let mut counts: Vec<f64> = allocations.iter().map(|a| a.0 as f64).collect();
+        let mut bytes: Vec<f64> = allocations.iter().map(|a| a.1 as f64).collect();
+        counts.sort_by(f64::total_cmp);
+        bytes.sort_by(f64::total_cmp);
+        Some((median(&counts), median(&bytes)))
+    }
+}
+
+fn median(sorted: &[f64]) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        n if n % 2 == 1 => sorted[n / 2],
+        n => (sorted[n / 2 - 1] + sorted[n / 2]) / 2.0,
+    }
+}
+
+/// Run the selected scenarios, calling `on_result` as each one finishes.
+pub fn run_scenarios(
+    options: &BenchOptions,
+    mut on_result: impl FnMut(&ScenarioResult),
+) -> AnyhowResult<Vec<ScenarioResult>> {
+    static RUNS: AtomicU64 = AtomicU64::new(0);
+    let root = std::env::temp_dir().join(format!(
+        "fresh-bench-{}-{}",
+        std::process::id(),
+        RUNS.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::create_dir_all(&root)?;
+    let result = run_in(&root, options, &mut on_result);
+    std::fs::remove_dir_all(&root).ok();
+    result
+}
+
+fn run_in(
+    root: &Path,
+    options: &BenchOptions,
+    on_result: &mut impl FnMut(&ScenarioResult),
+) -> AnyhowResult<Vec<ScenarioResult>> {
+    let corpus = root.join(options.corpus.file_name());
+    std::fs::write(
+        &corpus,
+        generate_corpus(options.corpus, options.lines, options.seed),
+    )?;
+    let count_allocations = allocator_installed();
+
+    let mut results = Vec::new();
+    for scenario in SCENARIOS {
+        if !options.scenarios.is_empty() && !options.scenarios.iter().any(|n| n == scenario.name) {
+            continue;
+        }
+        let mut result = ScenarioResult {
+            name: scenario.name,
+            times: Vec::with_capacity(options.iterations),
+            allocations: count_allocations.then(Vec::new),
+        };
+        for iteration in 0..options.iterations {
+            let dir = root.join(format!("{}-{}", scenario.name, iteration));
+            std::fs::create_dir_all(&dir)?;
+            let mut session = Session::new(&dir, &corpus, 120, 40)?;
+            (scenario.setup)(&mut session)
+                .with_context(|| format!("setting up scenario '{}'", scenario.name))?;
+
+            start_counting();
+            let start = Instant::now();
+            let outcome = (scenario.run)(&mut session);
+            let elapsed = start.elapsed();
+            let allocs = stop_counting();
+            outcome.with_context(|| format!("running scenario '{}'", scenario.name))?;
+
+            result.times.push(elapsed);
+            if let Some(allocations) = result.allocations.as_mut() {
+                allocations.push((allocs.count, allocs.bytes));
+            }
+            drop(session);
+        }
+        on_result(&result);
+        results.push(result);
+    }
+    Ok(results)
+}
+
+/// Entry point for `fresh --bench`: run and print the report to stdout.
+pub fn run(args: &[String]) -> AnyhowResult<()> {
+    let options = BenchOptions::parse(args)?;
+    let corpus = generate_corpus(options.corpus, options.lines, options.seed);
+    let corpus_bytes = corpus.len();
+    let corpus_fingerprint = format!("{:016x}", fingerprint(&corpus));
+    drop(corpus);
+
+    if options.json {
+        let results = run_scenarios(&options, |_| {})?;
+        let report = serde_json::json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "corpus": {
+                "kind": options.corpus.name(),
+                "lines": options.lines,
+                "bytes": corpus_bytes,
+                "seed": options.seed,
+                "fingerprint": corpus_fingerprint,
+            },
+            "iterations": options.iterations,
+            "scenarios": results.iter().map(|r| {
+                let allocs = r.median_allocations();
+                serde_json::json!({
+                    "name": r.name,
+                    "min_ms": r.min_ms(),
+                    "median_ms": r.median_ms(),
+                    "max_ms": r.max_ms(),
+                    "allocations": allocs.map(|a| a.0),
+                    "allocated_bytes": allocs.map(|a| a.1),
+                })
+            }).collect::<Vec<_>>(),
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!("fresh {} benchmark", env!("CARGO_PKG_VERSION"));
+    println!(
+        "{} corpus, {} lines, {} bytes, seed {}, fingerprint {}",
+        options.corpus.name(),
+        options.lines,
+        corpus_bytes,
+        options.seed,
+        corpus_fingerprint
+    );
+    println!("{} iterations per scenario\n", options.iterations);
+    println!(
+        "{:<10} {:>10} {:>10} {:>10} {:>12} {:>12}",
+        "scenario", "min ms", "median ms", "max ms", "allocs", "alloc MiB"
+    );
+    run_scenarios(&options, |r| {
+        let (allocs, mib) = match r.median_allocations() {
+            Some((count, bytes)) => (
+                format!("{count:.0}"),
+                format!("{:.1}", bytes / (1024.0 * 1024.0)),
+            ),
+            None => ("-".to_string(), "-".to_string()),
+        };
+        println!(
+            "{:<10} {:>10.2} {:>10.2} {:>10.2} {:>12} {:>12}",
+            r.name,
+            r.min_ms(),
+            r.median_ms(),
+            r.max_ms(),
+            allocs,
+            mib
+        );
+    })?;
+    // Each editor set the terminal cursor colour from its theme.
+    crate::view::theme::Theme::reset_terminal_cursor_color();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_corpus_is_reproducible() {
+        for kind in CorpusKind::ALL {
+            let a = generate_corpus(kind, 500, 7);
+            assert_eq!(a, generate_corpus(kind, 500, 7));
+            assert_ne!(a, generate_corpus(kind, 500, 8));
+            assert_eq!(a.lines().count(), 500);
+        }
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let args: Vec<String> = ["scroll", "corpus=log", "iterations=2", "json"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let options = BenchOptions::parse(&args).unwrap();
+        assert_eq!(options.scenarios, vec!["scroll".to_string()]);
+        assert_eq!(options.corpus, CorpusKind::Log);
+        assert_eq!(options.iterations, 2);
+        assert!(options.json);
+
+        assert!(BenchOptions::parse(&["nope".to_string()]).is_err());
+        assert!(BenchOptions::parse(&["lines=".to_string()]).is_err());
+        assert!(BenchOptions::parse(&["--lines".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_every_scenario_runs() {
+        let options = BenchOptions {
+            lines: 300,
+            iterations: 1,
+            ..Default::default()
+        };
+        let results = run_scenarios(&options, |_| {}).unwrap();
+        let names: Vec<_> = results.iter().map(|r| r.name).collect();
+        let expected: Vec<_> = SCENARIOS.iter().map(|s| s.name).collect();
+        assert_eq!(names, expected);
+        assert!(results.iter().all(|r| r.times.len() == 1));
+    }
+}
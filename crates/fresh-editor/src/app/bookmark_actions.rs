@@ -9,13 +9,56 @@
 //! still fire plugin hooks via `Editor.plugin_manager`. Once
 //! plugin-hook firing is available on `Window`, `jump_to_bookmark`
 //! becomes a Window method too.
+//!
+//! Line bookmarks follow the same split: toggling one on the cursor line is
+//! a Window mutation, while stepping between them and picking one from the
+//! bookmarks panel jump the cursor and so live on `Editor`.
+//!
+//! Named marks split the same way: setting one is a Window mutation, going
+//! to one and the marks panel are on `Editor`.
 
 use rust_i18n::t;
 
-use crate::model::event::Event;
+use crate::app::bookmarks::{is_mark_key, LineBookmark, Mark, LINE_BOOKMARK_NAMESPACE};
+use crate::model::event::{BufferId, Event};
+use crate::model::marker::MarkerId;
+use crate::view::margin::LineIndicator;
+use crate::view::prompt::PromptType;
 
 use super::Editor;
 
+/// Gutter symbol of a line bookmark.
+const LINE_BOOKMARK_SYMBOL: &str = "◆";
+
+/// Gutter colour of a line bookmark (amber).
+const LINE_BOOKMARK_COLOR: ratatui::style::Color = ratatui::style::Color::Rgb(255, 193, 7);
+
+/// Above the git gutter (10) and diff-since-saved (5) indicators.
+const LINE_BOOKMARK_PRIORITY: i32 = 20;
+
+/// Characters of a line shown in the bookmarks panel before it is cut off.
+const PREVIEW_CHARS: usize = 60;
+
+/// A line bookmark resolved to its current position.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedLineBookmark {
+    pub buffer_id: BufferId,
+    pub marker_id: MarkerId,
+    pub position: usize,
+    pub line: usize,
+    pub label: Option<String>,
+}
+
+/// A named mark resolved to its current position.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedMark {
+    pub key: char,
+    pub buffer_id: BufferId,
+    pub marker_id: MarkerId,
+    pub position: usize,
+    pub line: usize,
+}
+
 impl crate::app::window::Window {
     /// Set bookmark at the active buffer's primary cursor position.
     pub fn set_bookmark(&mut self, key: char) {
@@ -69,6 +112,158 @@ impl crate::app::window::Window {
     }
 }
 
+impl crate::app::window::Window {
+    /// Add a line bookmark at the start of `line` in `buffer_id`: a gutter
+    /// indicator whose marker anchors the bookmark.
+    pub(crate) fn add_line_bookmark(
+        &mut self,
+        buffer_id: BufferId,
+        position: usize,
+        label: Option<String>,
+    ) -> Option<MarkerId> {
+        let state = self.buffers.get_mut(&buffer_id)?;
+        let line = state
+            .buffer
+            .get_line_number(position.min(state.buffer.len()));
+        let line_start = state.buffer.line_start_offset(line).unwrap_or(0);
+        let marker_id = state.margins.set_line_indicator(
+            line_start,
+            LINE_BOOKMARK_NAMESPACE.to_string(),
+            LineIndicator::new(
+                LINE_BOOKMARK_SYMBOL,
+                LINE_BOOKMARK_COLOR,
+                LINE_BOOKMARK_PRIORITY,
+            ),
+        );
+        self.bookmarks.add_line(LineBookmark {
+            buffer_id,
+            marker_id,
+            label,
+        });
+        Some(marker_id)
+    }
+
+    /// Line bookmarks with their current position and line, sorted by
+    /// buffer and position. Bookmarks whose buffer has closed are skipped.
+    pub(crate) fn resolved_line_bookmarks(&self) -> Vec<ResolvedLineBookmark> {
+        let mut resolved: Vec<ResolvedLineBookmark> = self
+            .bookmarks
+            .lines()
+            .iter()
+            .filter_map(|b| {
+                let state = self.buffers.get(&b.buffer_id)?;
+                let position = state.margins.get_indicator_position(b.marker_id)?;
+                Some(ResolvedLineBookmark {
+                    buffer_id: b.buffer_id,
+                    marker_id: b.marker_id,
+                    position,
+                    line: state.buffer.get_line_number(position),
+                    label: b.label.clone(),
+                })
+            })
+            .collect();
+        resolved.sort_by_key(|b| (b.buffer_id.0, b.position));
+        resolved
+    }
+
+    /// The line bookmark on the primary cursor's line, if any.
+    pub(crate) fn line_bookmark_at_cursor(&self) -> Option<ResolvedLineBookmark> {
+        let buffer_id = self.active_buffer();
+        let position = self.active_cursors().primary().position;
+        let line = self.active_state().buffer.get_line_number(position);
+        self.resolved_line_bookmarks()
+            .into_iter()
+            .find(|b| b.buffer_id == buffer_id && b.line == line)
+    }
+
+    /// Toggle a line bookmark on the primary cursor's line.
+    pub fn toggle_line_bookmark(&mut self) {
+        if let Some(existing) = self.line_bookmark_at_cursor() {
+            self.remove_line_bookmark(existing.buffer_id, existing.marker_id);
+            self.set_status_message(t!("bookmark.line_removed").to_string());
+            return;
+        }
+        let buffer_id = self.active_buffer();
+        let position = self.active_cursors().primary().position;
+        self.add_line_bookmark(buffer_id, position, None);
+        self.set_status_message(t!("bookmark.line_added").to_string());
+    }
+
+    /// Remove a line bookmark and its gutter indicator.
+    pub(crate) fn remove_line_bookmark(&mut self, buffer_id: BufferId, marker_id: MarkerId) {
+        self.bookmarks.remove_line(buffer_id, marker_id);
+        if let Some(state) = self.buffers.get_mut(&buffer_id) {
+            state
+                .margins
+                .remove_line_indicator(marker_id, LINE_BOOKMARK_NAMESPACE);
+        }
+    }
+}
+
+impl crate::app::window::Window {
+    /// Set mark `key` at the primary cursor. Lowercase marks belong to the
+    /// active buffer; uppercase marks are global, so setting one moves it
+    /// here from whichever buffer held it.
+    pub fn set_named_mark(&mut self, key: char) {
+        if !is_mark_key(key) {
+            self.set_status_message(t!("mark.invalid", key = key).to_string());
+            return;
+        }
+        let buffer_id = self.active_buffer();
+        let position = self.active_cursors().primary().position;
+        // Left gravity: text typed at the mark goes after it.
+        let marker_id = self
+            .active_state_mut()
+            .marker_list
+            .create_left_gravity(position);
+        let replaced = self.bookmarks.set_mark(Mark {
+            key,
+            buffer_id,
+            marker_id,
+        });
+        if let Some(old) = replaced {
+            if let Some(state) = self.buffers.get_mut(&old.buffer_id) {
+                state.marker_list.delete(old.marker_id);
+            }
+        }
+        self.set_status_message(t!("mark.set", key = key).to_string());
+    }
+
+    /// Marks with their current position, lowercase marks of the active
+    /// buffer first, then global marks, then other buffers' local marks,
+    /// each group by key.
+    pub(crate) fn resolved_marks(&self) -> Vec<ResolvedMark> {
+        let active = self.active_buffer();
+        let mut resolved: Vec<ResolvedMark> = self
+            .bookmarks
+            .marks()
+            .iter()
+            .filter_map(|m| {
+                let state = self.buffers.get(&m.buffer_id)?;
+                let position = state.marker_list.get_position(m.marker_id)?;
+                Some(ResolvedMark {
+                    key: m.key,
+                    buffer_id: m.buffer_id,
+                    marker_id: m.marker_id,
+                    position,
+                    line: state.buffer.get_line_number(position),
+                })
+            })
+            .collect();
+        resolved.sort_by_key(|m| {
+            let group = if m.key.is_ascii_uppercase() {
+                1
+            } else if m.buffer_id == active {
+                0
+            } else {
+                2
+            };
+            (group, m.key, m.buffer_id.0)
+        });
+        resolved
+    }
+}
+
 impl Editor {
     /// Jump to a bookmark.
     ///
@@ -83,28 +278,37 @@ impl Editor {
             return;
         };
 
-        // Switch to the buffer if needed, or forget the bookmark if it's gone.
-        if bookmark.buffer_id != self.active_buffer() {
-            if self
+        // Forget the bookmark if its buffer is gone.
+        if !self.jump_to_buffer_position(bookmark.buffer_id, bookmark.position) {
+            self.set_status_message(t!("bookmark.buffer_gone", key = key).to_string());
+            self.active_window_mut().bookmarks.remove(key);
+            return;
+        }
+        self.set_status_message(t!("bookmark.jumped", key = key).to_string());
+    }
+
+    /// Switch to `buffer_id` if needed and move the primary cursor to
+    /// `position`, scrolling it into view. Returns `false` when the buffer
+    /// no longer exists.
+    fn jump_to_buffer_position(&mut self, buffer_id: BufferId, position: usize) -> bool {
+        if buffer_id != self.active_buffer() {
+            if !self
                 .windows
                 .get(&self.active_window)
                 .map(|w| &w.buffers)
                 .expect("active window present")
-                .contains_key(&bookmark.buffer_id)
+                .contains_key(&buffer_id)
             {
-                self.set_active_buffer(bookmark.buffer_id);
-            } else {
-                self.set_status_message(t!("bookmark.buffer_gone", key = key).to_string());
-                self.active_window_mut().bookmarks.remove(key);
-                return;
+                return false;
             }
+            self.set_active_buffer(buffer_id);
         }
 
         // Move cursor to bookmark position
         let cursor = *self.active_cursors().primary();
         let cursor_id = self.active_cursors().primary_id();
         let state = self.active_state_mut();
-        let new_pos = bookmark.position.min(state.buffer.len());
+        let new_pos = position.min(state.buffer.len());
 
         let event = Event::MoveCursor {
             cursor_id,
@@ -123,6 +327,250 @@ impl Editor {
         // buffer that's already visible (#1689).
         self.active_window_mut()
             .ensure_active_cursor_visible_for_navigation(true);
-        self.set_status_message(t!("bookmark.jumped", key = key).to_string());
+        true
+    }
+
+    /// Jump to the next (`forward`) or previous line bookmark after the
+    /// cursor, across all open buffers, wrapping around at the ends.
+    pub(super) fn goto_line_bookmark(&mut self, forward: bool) {
+        let bookmarks = self.active_window().resolved_line_bookmarks();
+        if bookmarks.is_empty() {
+            self.set_status_message(t!("bookmark.none_line").to_string());
+            return;
+        }
+        let here = (
+            self.active_buffer().0,
+            self.active_state()
+                .buffer
+                .get_line_number(self.active_cursors().primary().position),
+        );
+        let target = if forward {
+            bookmarks
+                .iter()
+                .find(|b| (b.buffer_id.0, b.line) > here)
+                .or_else(|| bookmarks.first())
+        } else {
+            bookmarks
+                .iter()
+                .rev()
+                .find(|b| (b.buffer_id.0, b.line) < here)
+                .or_else(|| bookmarks.last())
+        };
+        if let Some(target) = target.cloned() {
+            self.jump_to_line_bookmark(&target);
+        }
+    }
+
+    fn jump_to_line_bookmark(&mut self, bookmark: &ResolvedLineBookmark) {
+        if self.jump_to_buffer_position(bookmark.buffer_id, bookmark.position) {
+            let name = self.get_buffer_display_name(bookmark.buffer_id);
+            let location = format!("{}:{}", name, bookmark.line + 1);
+            let message = match &bookmark.label {
+                Some(label) => format!("{location} {label}"),
+                None => location,
+            };
+            self.set_status_message(t!("bookmark.line_jumped", location = message).to_string());
+        }
+    }
+
+    /// Prompt for the label of the line bookmark on the cursor line,
+    /// bookmarking the line first if needed.
+    pub(super) fn prompt_label_line_bookmark(&mut self) {
+        let existing = self.active_window().line_bookmark_at_cursor();
+        let label = existing.as_ref().and_then(|b| b.label.clone());
+        if existing.is_none() {
+            let buffer_id = self.active_buffer();
+            let position = self.active_cursors().primary().position;
+            self.active_window_mut()
+                .add_line_bookmark(buffer_id, position, None);
+        }
+        self.start_prompt_with_initial_text(
+            t!("bookmark.label_prompt").to_string(),
+            PromptType::LineBookmarkLabel,
+            label.unwrap_or_default(),
+        );
+    }
+
+    /// Apply the label typed into the bookmark label prompt. An empty label
+    /// clears it.
+    pub(super) fn set_line_bookmark_label(&mut self, label: &str) {
+        let Some(bookmark) = self.active_window().line_bookmark_at_cursor() else {
+            return;
+        };
+        let label = label.trim();
+        let label = (!label.is_empty()).then(|| label.to_string());
+        self.active_window_mut().bookmarks.set_line_label(
+            bookmark.buffer_id,
+            bookmark.marker_id,
+            label,
+        );
+    }
+
+    /// Show the bookmarks panel: every line bookmark across open files with
+    /// its location, label and line text. The bookmark on the cursor line
+    /// is selected.
+    pub(super) fn show_line_bookmarks(&mut self) {
+        let bookmarks = self.active_window().resolved_line_bookmarks();
+        if bookmarks.is_empty() {
+            self.set_status_message(t!("bookmark.none_line").to_string());
+            return;
+        }
+        let current = self
+            .active_window()
+            .line_bookmark_at_cursor()
+            .map(|b| b.marker_id);
+
+        let mut selected = 0;
+        let mut items = Vec::new();
+        for bookmark in &bookmarks {
+            let Some(state) = self.buffers().get(&bookmark.buffer_id) else {
+                continue;
+            };
+            let text = state
+                .buffer
+                .get_line(bookmark.line)
+                .map(|bytes| preview(&String::from_utf8_lossy(&bytes)))
+                .unwrap_or_default();
+            let detail = match &bookmark.label {
+                Some(label) => format!("{label} — {text}"),
+                None => text,
+            };
+            let name = self.get_buffer_display_name(bookmark.buffer_id);
+            if Some(bookmark.marker_id) == current {
+                selected = items.len();
+            }
+            items.push(
+                crate::view::popup::PopupListItem::new(format!("{}:{}", name, bookmark.line + 1))
+                    .with_icon(LINE_BOOKMARK_SYMBOL.to_string())
+                    .with_detail(detail)
+                    .with_data(format!("{}:{}", bookmark.buffer_id.0, bookmark.marker_id.0)),
+            );
+        }
+
+        let theme = self.theme.read().unwrap();
+        let mut popup = crate::view::popup::Popup::list(items, &theme);
+        popup.select_index(selected);
+        popup.title = Some(t!("bookmark.panel_title").to_string());
+        popup.position = crate::view::popup::PopupPosition::Centered;
+        popup.width = 90;
+        popup.max_height = 15;
+        popup.resolver = crate::view::popup::PopupResolver::LineBookmarks;
+        // Explicitly invoked: the user is about to pick, so take the keys.
+        popup.focused = true;
+        drop(theme);
+        self.active_state_mut().popups.show(popup);
+    }
+
+    /// Jump to the bookmark picked in the bookmarks panel, identified by the
+    /// item's `"<buffer_id>:<marker_id>"` data.
+    pub(super) fn jump_to_picked_line_bookmark(&mut self, data: &str) {
+        let Some((buffer, marker)) = data.split_once(':') else {
+            return;
+        };
+        let (Ok(buffer), Ok(marker)) = (buffer.parse::<usize>(), marker.parse::<u64>()) else {
+            return;
+        };
+        let target = self
+            .active_window()
+            .resolved_line_bookmarks()
+            .into_iter()
+            .find(|b| b.buffer_id == BufferId(buffer) && b.marker_id == MarkerId(marker));
+        if let Some(target) = target {
+            self.jump_to_line_bookmark(&target);
+        }
+    }
+}
+
+impl Editor {
+    /// Go to mark `key`: a lowercase mark of the active buffer, or a global
+    /// uppercase mark in whichever buffer holds it.
+    pub(super) fn goto_named_mark(&mut self, key: char) {
+        if !is_mark_key(key) {
+            self.set_status_message(t!("mark.invalid", key = key).to_string());
+            return;
+        }
+        let buffer_id = self.active_buffer();
+        let target = self
+            .active_window()
+            .resolved_marks()
+            .into_iter()
+            .find(|m| m.key == key && (key.is_ascii_uppercase() || m.buffer_id == buffer_id));
+        let Some(target) = target else {
+            self.set_status_message(t!("mark.not_set", key = key).to_string());
+            return;
+        };
+        if self.jump_to_buffer_position(target.buffer_id, target.position) {
+            self.set_status_message(t!("mark.jumped", key = key).to_string());
+        }
+    }
+
+    /// Show the marks panel: every mark with its location and line text.
+    pub(super) fn show_named_marks(&mut self) {
+        let marks = self.active_window().resolved_marks();
+        if marks.is_empty() {
+            self.set_status_message(t!("mark.none").to_string());
+            return;
+        }
+
+        let mut items = Vec::new();
+        for mark in &marks {
+            let Some(state) = self.buffers().get(&mark.buffer_id) else {
+                continue;
+            };
+            let text = state
+                .buffer
+                .get_line(mark.line)
+                .map(|bytes| preview(&String::from_utf8_lossy(&bytes)))
+                .unwrap_or_default();
+            let name = self.get_buffer_display_name(mark.buffer_id);
+            items.push(
+                crate::view::popup::PopupListItem::new(format!("{}:{}", name, mark.line + 1))
+                    .with_icon(mark.key.to_string())
+                    .with_detail(text)
+                    .with_data(format!("{}:{}", mark.buffer_id.0, mark.marker_id.0)),
+            );
+        }
+
+        let theme = self.theme.read().unwrap();
+        let mut popup = crate::view::popup::Popup::list(items, &theme);
+        popup.title = Some(t!("mark.panel_title").to_string());
+        popup.position = crate::view::popup::PopupPosition::Centered;
+        popup.width = 90;
+        popup.max_height = 15;
+        popup.resolver = crate::view::popup::PopupResolver::NamedMarks;
+        popup.focused = true;
+        drop(theme);
+        self.active_state_mut().popups.show(popup);
+    }
+
+    /// Jump to the mark picked in the marks panel, identified by the item's
+    /// `"<buffer_id>:<marker_id>"` data.
+    pub(super) fn jump_to_picked_mark(&mut self, data: &str) {
+        let Some((buffer, marker)) = data.split_once(':') else {
+            return;
+        };
+        let (Ok(buffer), Ok(marker)) = (buffer.parse::<usize>(), marker.parse::<u64>()) else {
+            return;
+        };
+        let target = self
+            .active_window()
+            .resolved_marks()
+            .into_iter()
+            .find(|m| m.buffer_id == BufferId(buffer) && m.marker_id == MarkerId(marker));
+        if let Some(target) = target {
+            self.jump_to_buffer_position(target.buffer_id, target.position);
+        }
+    }
+}
+
+/// One-line preview of a bookmarked line: trimmed and cut off at
+/// [`PREVIEW_CHARS`].
+fn preview(line: &str) -> String {
+    let line = line.trim();
+    if line.chars().count() > PREVIEW_CHARS {
+        let cut: String = line.chars().take(PREVIEW_CHARS - 1).collect();
+        format!("{cut}…")
+    } else {
+        line.to_string()
     }
 }
@@ -4,10 +4,20 @@
 //! register. The state is deliberately minimal: lookup, insert, remove,
 //! iterate. Cross-cutting work — checking that a bookmark's target buffer
 //! still exists, jumping the cursor, rendering a list — lives on `Editor`.
+//!
+//! Line bookmarks (toggled on the cursor line, shown in the gutter) are
+//! kept here too. They have no register; each is anchored by the marker of
+//! its gutter indicator, so its position follows edits to the buffer.
+//!
+//! Named marks (`a`–`z` per buffer, `A`–`Z` global) are anchored by a
+//! marker in their buffer's `marker_list` for the same reason.
 
 use std::collections::HashMap;
 
 use crate::model::event::BufferId;
+use crate::model::marker::MarkerId;
+
+pub(crate) use crate::view::margin::LINE_BOOKMARK_NAMESPACE;
 
 /// A bookmark: a position within a specific buffer.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -16,10 +26,36 @@ pub(crate) struct Bookmark {
     pub position: usize,
 }
 
-/// Owner of the register -> bookmark map.
+/// A line bookmark: the line holding its gutter indicator's marker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct LineBookmark {
+    pub buffer_id: BufferId,
+    /// Marker of the indicator in the buffer's `margins`; its position is
+    /// the bookmark's position.
+    pub marker_id: MarkerId,
+    pub label: Option<String>,
+}
+
+/// A named mark: a marker in a buffer's `marker_list`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Mark {
+    pub key: char,
+    pub buffer_id: BufferId,
+    pub marker_id: MarkerId,
+}
+
+/// Whether `key` names a mark: a lowercase letter (local to its buffer) or
+/// an uppercase letter (global).
+pub(crate) fn is_mark_key(key: char) -> bool {
+    key.is_ascii_alphabetic()
+}
+
+/// Owner of the register -> bookmark map, the line bookmarks and the marks.
 #[derive(Debug, Default)]
 pub(crate) struct BookmarkState {
     bookmarks: HashMap<char, Bookmark>,
+    lines: Vec<LineBookmark>,
+    marks: Vec<Mark>,
 }
 
 impl BookmarkState {
@@ -48,6 +84,95 @@ impl BookmarkState {
     pub(crate) fn iter(&self) -> impl Iterator<Item = (char, Bookmark)> + '_ {
         self.bookmarks.iter().map(|(k, v)| (*k, *v))
     }
+
+    /// Move the register bookmarks in `buffer_id` to `f(position)`.
+    pub(crate) fn remap_positions(
+        &mut self,
+        buffer_id: BufferId,
+        mut f: impl FnMut(usize) -> usize,
+    ) {
+        for bookmark in self.bookmarks.values_mut() {
+            if bookmark.buffer_id == buffer_id {
+                bookmark.position = f(bookmark.position);
+            }
+        }
+    }
+
+    /// Add a line bookmark.
+    pub(crate) fn add_line(&mut self, bookmark: LineBookmark) {
+        self.lines.push(bookmark);
+    }
+
+    /// Remove the line bookmark anchored by `marker_id` in `buffer_id`.
+    /// Returns the removed bookmark.
+    pub(crate) fn remove_line(
+        &mut self,
+        buffer_id: BufferId,
+        marker_id: MarkerId,
+    ) -> Option<LineBookmark> {
+        let index = self
+            .lines
+            .iter()
+            .position(|b| b.buffer_id == buffer_id && b.marker_id == marker_id)?;
+        Some(self.lines.remove(index))
+    }
+
+    /// Line bookmarks in the order they were added.
+    pub(crate) fn lines(&self) -> &[LineBookmark] {
+        &self.lines
+    }
+
+    /// Set or clear (`None`) the label of a line bookmark.
+    pub(crate) fn set_line_label(
+        &mut self,
+        buffer_id: BufferId,
+        marker_id: MarkerId,
+        label: Option<String>,
+    ) {
+        if let Some(bookmark) = self
+            .lines
+            .iter_mut()
+            .find(|b| b.buffer_id == buffer_id && b.marker_id == marker_id)
+        {
+            bookmark.label = label;
+        }
+    }
+
+    /// Forget line bookmarks whose buffer is no longer open.
+    pub(crate) fn retain_lines(&mut self, mut keep: impl FnMut(BufferId) -> bool) {
+        self.lines.retain(|b| keep(b.buffer_id));
+    }
+
+    /// Store a mark, replacing the one it shadows: the same lowercase key in
+    /// the same buffer, or the same uppercase key anywhere. Returns the
+    /// replaced mark so the caller can release its marker.
+    pub(crate) fn set_mark(&mut self, mark: Mark) -> Option<Mark> {
+        let replaced = self.mark(mark.key, mark.buffer_id);
+        if let Some(old) = replaced {
+            self.marks.retain(|m| *m != old);
+        }
+        self.marks.push(mark);
+        replaced
+    }
+
+    /// The mark `key` as seen from `buffer_id`: lowercase keys only match
+    /// marks in that buffer, uppercase keys match in any buffer.
+    pub(crate) fn mark(&self, key: char, buffer_id: BufferId) -> Option<Mark> {
+        self.marks
+            .iter()
+            .find(|m| m.key == key && (key.is_ascii_uppercase() || m.buffer_id == buffer_id))
+            .copied()
+    }
+
+    /// All marks, in the order they were set.
+    pub(crate) fn marks(&self) -> &[Mark] {
+        &self.marks
+    }
+
+    /// Forget marks whose buffer is no longer open.
+    pub(crate) fn retain_marks(&mut self, mut keep: impl FnMut(BufferId) -> bool) {
+        self.marks.retain(|m| keep(m.buffer_id));
+    }
 }
 
 #[cfg(test)]
@@ -107,4 +232,55 @@ mod tests {
         seen.sort_by_key(|(k, _)| *k);
         assert_eq!(seen, vec![('a', bm(1, 10)), ('b', bm(2, 20))]);
     }
+
+    fn line(buffer: usize, marker: u64) -> LineBookmark {
+        LineBookmark {
+            buffer_id: BufferId(buffer),
+            marker_id: MarkerId(marker),
+            label: None,
+        }
+    }
+
+    #[test]
+    fn line_bookmarks_add_label_and_remove() {
+        let mut s = BookmarkState::default();
+        s.add_line(line(1, 5));
+        s.add_line(line(2, 5));
+        s.set_line_label(BufferId(2), MarkerId(5), Some("entry point".into()));
+        assert_eq!(s.lines()[1].label.as_deref(), Some("entry point"));
+
+        assert_eq!(s.remove_line(BufferId(1), MarkerId(5)), Some(line(1, 5)));
+        assert_eq!(s.remove_line(BufferId(1), MarkerId(5)), None);
+        assert_eq!(s.lines().len(), 1);
+
+        s.retain_lines(|id| id != BufferId(2));
+        assert!(s.lines().is_empty());
+    }
+
+    fn mark(key: char, buffer: usize, marker: u64) -> Mark {
+        Mark {
+            key,
+            buffer_id: BufferId(buffer),
+            marker_id: MarkerId(marker),
+        }
+    }
+
+    #[test]
+    fn lowercase_marks_are_per_buffer_and_uppercase_are_global() {
+        let mut s = BookmarkState::default();
+        assert_eq!(s.set_mark(mark('a', 1, 10)), None);
+        assert_eq!(s.set_mark(mark('a', 2, 20)), None);
+        assert_eq!(s.mark('a', BufferId(1)), Some(mark('a', 1, 10)));
+        assert_eq!(s.mark('a', BufferId(2)), Some(mark('a', 2, 20)));
+        assert_eq!(s.mark('a', BufferId(3)), None);
+
+        assert_eq!(s.set_mark(mark('A', 1, 30)), None);
+        assert_eq!(s.mark('A', BufferId(2)), Some(mark('A', 1, 30)));
+        assert_eq!(s.set_mark(mark('A', 2, 40)), Some(mark('A', 1, 30)));
+        assert_eq!(s.mark('A', BufferId(1)), Some(mark('A', 2, 40)));
+        assert_eq!(s.marks().len(), 3);
+
+        s.retain_marks(|b| b != BufferId(2));
+        assert_eq!(s.marks(), &[mark('a', 1, 10)]);
+    }
 }
@@ -0,0 +1,97 @@
+//! One coordinated refresh when a repo's checked-out branch changes.
+//!
+//! The file-tree poller reads each watched repo's `HEAD` alongside its
+//! `.git/index` (see `poll_file_tree_changes`). A `git checkout` or
+//! `git switch` makes everything derived from the working tree stale at
+//! once, so rather than letting each feature notice on its own schedule,
+//! a changed `HEAD` refreshes them together:
+//! - quick open's cached file list is dropped, so the next search relists;
+//! - the explorer's git decorations rescan (`after_file_explorer_change`);
+//! - open buffers are re-stat'ed right away, and the unmodified ones that
+//!   opted into auto-revert reload — only files the checkout rewrote;
+//! - once they have, linters re-run on every open file and the
+//!   `git_head_changed` hook lets plugins refresh diff gutters.
+//!
+//! The status bar reports the refresh as it starts and when it finishes.
+
+use super::Editor;
+use rust_i18n::t;
+
+impl Editor {
+    /// Start the refresh for a branch switch; `head` is the new contents of
+    /// the repo's `HEAD`. Finished by [`Self::finish_branch_switch_refresh`]
+    /// when the open-buffer poll it starts comes back.
+    pub(super) fn begin_branch_switch_refresh(&mut self, head: &str) {
+        let branch = branch_name(head);
+        tracing::info!("Branch switched to {}, refreshing", branch);
+
+        self.quick_open_registry.clear_file_cache();
+        let cwd = self.working_dir().to_path_buf();
+        self.notify_file_explorer_change(&cwd);
+
+        // A poll already in flight may have stat'ed files before the
+        // checkout rewrote them; drop it and start one now.
+        let files = self.auto_revert_files();
+        self.active_window_mut().pending_file_poll_rx = None;
+        self.active_window_mut().branch_refresh = Some(branch.clone());
+        if files.is_empty() {
+            self.finish_branch_switch_refresh(0);
+            return;
+        }
+        self.set_status_message(
+            t!("status.branch_switch_refreshing", branch = &branch).to_string(),
+        );
+        self.spawn_file_poll(files);
+    }
+
+    /// Finish the pending branch-switch refresh, once its open-buffer poll
+    /// has reloaded `reloaded` files. No-op when none is pending.
+    pub(super) fn finish_branch_switch_refresh(&mut self, reloaded: usize) {
+        let Some(branch) = self.active_window_mut().branch_refresh.take() else {
+            return;
+        };
+        self.active_window_mut().lint_open_buffers();
+        self.plugin_manager.read().unwrap().run_hook(
+            "git_head_changed",
+            crate::services::plugins::hooks::HookArgs::GitHeadChanged {
+                branch: branch.clone(),
+            },
+        );
+        self.set_status_message(
+            t!(
+                "status.branch_switch_refreshed",
+                branch = &branch,
+                count = reloaded
+            )
+            .to_string(),
+        );
+    }
+}
+
+/// Name of the branch `head` (the contents of a `HEAD` file) points at, or
+/// the short commit id for a detached HEAD.
+fn branch_name(head: &str) -> String {
+    let head = head.trim();
+    match head.strip_prefix("ref: ") {
+        Some(reference) => reference
+            .strip_prefix("refs/heads/")
+            .unwrap_or(reference)
+            .to_string(),
+        None => head.chars().take(7).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::branch_name;
+
+    #[test]
+    fn branch_name_reads_symbolic_and_detached_heads() {
+        assert_eq!(branch_name("ref: refs/heads/main\n"), "main");
+        assert_eq!(branch_name("ref: refs/heads/feature/x"), "feature/x");
+        assert_eq!(
+            branch_name("3f786850e387550fdab836ed7e6dc881de23001b\n"),
+            "3f78685"
+        );
+    }
+}
@@ -11,6 +11,7 @@
 
 use rust_i18n::t;
 
+use crate::input::position_history::PositionEntry;
 use crate::model::event::{BufferId, Event, LeafId};
 use crate::view::prompt::PromptType;
 
@@ -80,6 +81,9 @@ impl Editor {
         // pending entry that points at a half-torn-down buffer.
         self.cancel_pending_pastes_for_buffer(id);
 
+        // A narrowed buffer closed without Widen gives its region back.
+        self.release_narrowing(id);
+
         // Clear preview tracking if we're closing the current preview buffer.
         // This keeps `preview` from pointing at a freed buffer id.
         if let Some((_, preview_id)) = self.active_window().preview {
@@ -95,6 +99,7 @@ impl Editor {
 
         // Save file state before closing (for per-file session persistence)
         self.active_window().save_file_state_on_close(id);
+        self.active_window_mut().remember_closed_buffer(id);
 
         // Delete recovery data for explicitly closed buffers (including unnamed)
         if let Err(e) = self.delete_buffer_recovery(id) {
@@ -429,8 +434,8 @@ impl Editor {
 
     /// Remove every trace of a now-closed buffer from the active window's
     /// per-buffer maps: the buffer registry, cross-window attachments, event
-    /// logs, semantic-token bookkeeping, the panel-id mapping, and each
-    /// split's open-buffers / focus-history lists.
+    /// logs, semantic-token bookkeeping, line bookmarks, the panel-id
+    /// mapping, and each split's open-buffers / focus-history lists.
     fn purge_buffer_state(&mut self, id: BufferId) {
         self.windows
             .get_mut(&self.active_window)
@@ -442,6 +447,12 @@ impl Editor {
         self.active_window_mut().seen_byte_ranges.remove(&id);
         self.active_window_mut().buffer_metadata.remove(&id);
         self.active_window_mut().status_bar_values.remove(&id);
+        self.active_window_mut()
+            .bookmarks
+            .retain_lines(|buffer_id| buffer_id != id);
+        self.active_window_mut()
+            .bookmarks
+            .retain_marks(|buffer_id| buffer_id != id);
         if let Some((request_id, _, _)) = self
             .active_window_mut()
             .semantic_tokens_in_flight
@@ -689,7 +700,7 @@ impl Editor {
                 return true;
             }
             if let Err(e) = self.close_buffer(buffer_id) {
-                self.set_status_message(t!("file.cannot_close", error = e.to_string()).to_string());
+                self.set_status_error(t!("file.cannot_close", error = e.to_string()).to_string());
             } else {
                 self.set_status_message(t!("buffer.tab_closed").to_string());
             }
@@ -896,6 +907,44 @@ impl Editor {
         self.set_batch_close_status_message(closed, skipped_modified);
     }
 
+    /// Close the tabs in a split whose buffers have no unsaved changes
+    pub fn close_saved_tabs_in_split(&mut self, split_id: LeafId) {
+        let split_tabs = self
+            .windows
+            .get(&self.active_window)
+            .and_then(|w| w.buffers.splits())
+            .map(|(_, vs)| vs)
+            .expect("active window must have a populated split layout")
+            .get(&split_id)
+            .map(|vs| vs.buffer_tab_ids_vec())
+            .unwrap_or_default();
+
+        // Modified buffers are left alone rather than counted as skipped:
+        // keeping them is the point of this operation.
+        let tabs_to_close: Vec<_> = split_tabs
+            .into_iter()
+            .filter(|id| {
+                !self
+                    .buffers()
+                    .get(id)
+                    .is_some_and(|state| state.buffer.is_modified())
+            })
+            .collect();
+
+        let mut closed = 0;
+        let mut skipped_modified = 0;
+        for buffer_id in tabs_to_close {
+            if self.close_tab_in_split_silent(buffer_id, split_id) {
+                closed += 1;
+            } else {
+                skipped_modified += 1;
+            }
+        }
+
+        self.reseat_tab_scroll_for_split(split_id);
+        self.set_batch_close_status_message(closed, skipped_modified);
+    }
+
     /// Close all tabs in a split
     pub fn close_all_tabs_in_split(&mut self, split_id: LeafId) {
         // Get the split's open buffers
@@ -1208,51 +1257,8 @@ impl Editor {
         }
 
         // Navigate to the previous position
-        if let Some(entry) = self.active_window_mut().position_history.back() {
-            let target_buffer = entry.buffer_id;
-            let target_position = entry.position;
-            let target_anchor = entry.anchor;
-
-            // Switch to the target buffer
-            if self
-                .windows
-                .get(&self.active_window)
-                .map(|w| &w.buffers)
-                .expect("active window present")
-                .contains_key(&target_buffer)
-            {
-                self.set_active_buffer(target_buffer);
-
-                // Move cursor to the saved position
-                let cursors = self.active_cursors();
-                let cursor_id = cursors.primary_id();
-                let old_position = cursors.primary().position;
-                let old_anchor = cursors.primary().anchor;
-                let old_sticky_column = cursors.primary().sticky_column;
-                let event = Event::MoveCursor {
-                    cursor_id,
-                    old_position,
-                    new_position: target_position,
-                    old_anchor,
-                    new_anchor: target_anchor,
-                    old_sticky_column,
-                    new_sticky_column: None, // Reset sticky column for navigation
-                };
-                let split_id = self
-                    .windows
-                    .get(&self.active_window)
-                    .and_then(|w| w.buffers.splits())
-                    .map(|(mgr, _)| mgr)
-                    .expect("active window must have a populated split layout")
-                    .active_split();
-                self.active_window_mut()
-                    .apply_event_to_buffer(target_buffer, split_id, &event);
-                // Position-history entries can land anywhere in the buffer;
-                // the viewport must scroll to the restored cursor or the user
-                // sees the same page after Ctrl+- / Ctrl+= (#1689).
-                self.active_window_mut()
-                    .ensure_active_cursor_visible_for_navigation(true);
-            }
+        if let Some(entry) = self.active_window_mut().position_history.back().cloned() {
+            self.restore_position_entry(&entry);
         }
 
         // Clear the flag
@@ -1264,57 +1270,64 @@ impl Editor {
         // Set flag to prevent recording this navigation movement
         self.active_window_mut().in_navigation = true;
 
-        if let Some(entry) = self.active_window_mut().position_history.forward() {
-            let target_buffer = entry.buffer_id;
-            let target_position = entry.position;
-            let target_anchor = entry.anchor;
-
-            // Switch to the target buffer
-            if self
-                .windows
-                .get(&self.active_window)
-                .map(|w| &w.buffers)
-                .expect("active window present")
-                .contains_key(&target_buffer)
-            {
-                self.set_active_buffer(target_buffer);
-
-                // Move cursor to the saved position
-                let cursors = self.active_cursors();
-                let cursor_id = cursors.primary_id();
-                let old_position = cursors.primary().position;
-                let old_anchor = cursors.primary().anchor;
-                let old_sticky_column = cursors.primary().sticky_column;
-                let event = Event::MoveCursor {
-                    cursor_id,
-                    old_position,
-                    new_position: target_position,
-                    old_anchor,
-                    new_anchor: target_anchor,
-                    old_sticky_column,
-                    new_sticky_column: None, // Reset sticky column for navigation
-                };
-                let split_id = self
-                    .windows
-                    .get(&self.active_window)
-                    .and_then(|w| w.buffers.splits())
-                    .map(|(mgr, _)| mgr)
-                    .expect("active window must have a populated split layout")
-                    .active_split();
-                self.active_window_mut()
-                    .apply_event_to_buffer(target_buffer, split_id, &event);
-                // Position-history entries can land anywhere in the buffer;
-                // the viewport must scroll to the restored cursor or the user
-                // sees the same page after Ctrl+- / Ctrl+= (#1689).
-                self.active_window_mut()
-                    .ensure_active_cursor_visible_for_navigation(true);
-            }
+        if let Some(entry) = self.active_window_mut().position_history.forward().cloned() {
+            self.restore_position_entry(&entry);
         }
 
         // Clear the flag
         self.active_window_mut().in_navigation = false;
     }
 
+    /// Switch to a position history entry's buffer and move the cursor to
+    /// it. Entries whose buffer has since closed are skipped.
+    pub(super) fn restore_position_entry(&mut self, entry: &PositionEntry) {
+        let target_buffer = entry.buffer_id;
+        let target_position = entry.position;
+        let target_anchor = entry.anchor;
+
+        // Switch to the target buffer
+        if !self
+            .windows
+            .get(&self.active_window)
+            .map(|w| &w.buffers)
+            .expect("active window present")
+            .contains_key(&target_buffer)
+        {
+            return;
+        }
+        self.set_active_buffer(target_buffer);
+
+        // Move cursor to the saved position
+        let cursors = self.active_cursors();
+        let cursor_id = cursors.primary_id();
+        let old_position = cursors.primary().position;
+        let old_anchor = cursors.primary().anchor;
+        let old_sticky_column = cursors.primary().sticky_column;
+        let event = Event::MoveCursor {
+            cursor_id,
+            old_position,
+            new_position: target_position,
+            old_anchor,
+            new_anchor: target_anchor,
+            old_sticky_column,
+            new_sticky_column: None, // Reset sticky column for navigation
+        };
+        let split_id = self
+            .windows
+            .get(&self.active_window)
+            .and_then(|w| w.buffers.splits())
+            .map(|(mgr, _)| mgr)
+            .expect("active window must have a populated split layout")
+            .active_split();
+        self.active_window_mut()
+            .apply_event_to_buffer(target_buffer, split_id, &event);
+        // Position-history entries can land anywhere in the buffer;
+        // the viewport must scroll to the restored cursor or the user
+        // sees the same page after Ctrl+- / Ctrl+= (#1689).
+        self.active_window_mut()
+            .ensure_active_cursor_visible_for_navigation(true);
+    }
+
     /// Retain a closed terminal's rendered backing file so its scrollback
     /// stays searchable (Universal Search "Terminals" scope). Renames it to
     /// a unique `<stem>-closed-<epoch_ms>.txt` so a future terminal that
@@ -8,6 +8,8 @@
 //! free functions so both the orchestrator on `Editor` and any future
 //! `BufferRegistry` subsystem can call them without tangled coupling.
 
+use std::collections::HashMap;
+
 use crate::config::Config;
 
 /// Effective `line_wrap` for a buffer with the given language.
@@ -51,6 +53,27 @@ pub(crate) fn page_view(language: &str, config: &Config) -> Option<Option<usize>
     }
 }
 
+/// Effective abbreviation table for a buffer with the given language: the
+/// global `editor.abbreviations` with the language's own entries layered on
+/// top.
+pub(crate) fn abbreviations<'a>(language: &str, config: &'a Config) -> HashMap<&'a str, &'a str> {
+    let mut table: HashMap<&str, &str> = config
+        .editor
+        .abbreviations
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect();
+    if let Some(lang_config) = config.languages.get(language) {
+        table.extend(
+            lang_config
+                .abbreviations
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str())),
+        );
+    }
+    table
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,4 +159,25 @@ mod tests {
         config.editor.page_width = Some(80);
         assert_eq!(page_view("markdown", &config), Some(Some(80)));
     }
+
+    #[test]
+    fn abbreviations_language_entries_override_global() {
+        let mut lang = LanguageConfig::default();
+        lang.abbreviations
+            .insert("fn".to_string(), "function".to_string());
+        let mut config = config_with("javascript", lang);
+        config
+            .editor
+            .abbreviations
+            .insert("teh".to_string(), "the".to_string());
+        config
+            .editor
+            .abbreviations
+            .insert("fn".to_string(), "footnote".to_string());
+
+        let table = abbreviations("javascript", &config);
+        assert_eq!(table.get("teh"), Some(&"the"));
+        assert_eq!(table.get("fn"), Some(&"function"));
+        assert_eq!(abbreviations("rust", &config).get("fn"), Some(&"footnote"));
+    }
 }
@@ -0,0 +1,250 @@
+//! The editor content context menu: right-click in a buffer (or the Open
+//! Context Menu action) pops a menu of editing, navigation and
+//! plugin-contributed actions for that buffer.
+//!
+//! The menu shares the geometry / navigation / hit-test core with the tab,
+//! new-tab, close-split and file-explorer menus (see
+//! [`super::types::ContextMenu`]); this module only decides *what* it lists
+//! and *where* it opens.
+
+use rust_i18n::t;
+
+use super::types::{BufferContextMenu, BufferContextMenuItem};
+use super::Editor;
+use crate::input::keybindings::Action;
+use crate::model::event::{BufferId, Event, LeafId};
+
+impl Editor {
+    /// Open the content context menu for a right-click at `(col, row)`.
+    ///
+    /// Returns `false` when the position isn't over a buffer's content area,
+    /// or the buffer there has no menu (live terminals, composite views and
+    /// fixed plugin panels keep their own click handling).
+    ///
+    /// Like other editors, the click focuses the split and moves the cursor
+    /// to the clicked position — unless it landed inside the selection, so
+    /// "select, right-click, Copy" acts on that selection.
+    pub(super) fn open_buffer_context_menu_at(&mut self, col: u16, row: u16) -> bool {
+        let hit = self
+            .active_layout()
+            .split_areas
+            .iter()
+            .find(|(_, _, rect, ..)| {
+                col >= rect.x
+                    && col < rect.x + rect.width
+                    && row >= rect.y
+                    && row < rect.y + rect.height
+            })
+            .map(|(split_id, buffer_id, rect, ..)| (*split_id, *buffer_id, *rect));
+        let Some((split_id, buffer_id, content_rect)) = hit else {
+            return false;
+        };
+        if !self.buffer_has_context_menu(buffer_id) {
+            return false;
+        }
+
+        self.focus_split(split_id, buffer_id);
+        self.active_window_mut().key_context = crate::input::keybindings::KeyContext::Normal;
+        if let Some(position) =
+            self.content_position_at(split_id, buffer_id, content_rect, col, row)
+        {
+            self.move_cursor_for_context_click(position);
+        }
+
+        self.show_buffer_context_menu(buffer_id, split_id, col, row.saturating_add(1));
+        true
+    }
+
+    /// Open the content context menu just below the primary cursor of the
+    /// active split — the keyboard path (`Menu` key).
+    pub(super) fn open_buffer_context_menu_at_cursor(&mut self) {
+        let buffer_id = self.active_buffer();
+        let split_id = self.effective_active_split();
+        if !self.buffer_has_context_menu(buffer_id) {
+            return;
+        }
+
+        let area = self
+            .active_layout()
+            .split_areas
+            .iter()
+            .find(|(sid, bid, ..)| *sid == split_id && *bid == buffer_id)
+            .map(|(_, _, rect, ..)| *rect);
+        let Some(content_rect) = area else {
+            return;
+        };
+        let gutter_width = self
+            .buffers()
+            .get(&buffer_id)
+            .map(|s| s.margins.left_total_width() as u16)
+            .unwrap_or(0);
+        let Some((viewport, cursor, compose_width)) = self
+            .windows
+            .get(&self.active_window)
+            .and_then(|w| w.buffers.splits())
+            .and_then(|(_, vs)| vs.get(&split_id))
+            .map(|vs| (vs.viewport.clone(), *vs.cursors.primary(), vs.compose_width))
+        else {
+            return;
+        };
+        let Some(state) = self.active_window_mut().buffers.get_mut(&buffer_id) else {
+            return;
+        };
+        let (rel_col, rel_row) = viewport.cursor_screen_position(&mut state.buffer, &cursor);
+        let adjusted_rect =
+            super::click_geometry::adjust_content_rect_for_compose(content_rect, compose_width);
+        let col = adjusted_rect.x + gutter_width + rel_col;
+        let row = content_rect.y + rel_row;
+
+        self.show_buffer_context_menu(buffer_id, split_id, col, row.saturating_add(1));
+    }
+
+    /// Run the action of a chosen content context menu item. The menu has
+    /// already been dismissed by the caller.
+    pub(super) fn execute_buffer_context_menu_action(
+        &mut self,
+        action: Action,
+    ) -> anyhow::Result<()> {
+        self.handle_action(action)
+    }
+
+    /// Whether a buffer offers the content context menu at all.
+    fn buffer_has_context_menu(&self, buffer_id: BufferId) -> bool {
+        let window = self.active_window();
+        let fixed_panel = window.is_non_scrollable_buffer(buffer_id)
+            && self.widget_registry.panels_for_buffer(buffer_id).is_empty();
+        !(window.is_terminal_buffer(buffer_id)
+            || window.is_composite_buffer(buffer_id)
+            || fixed_panel)
+    }
+
+    fn show_buffer_context_menu(&mut self, buffer_id: BufferId, split_id: LeafId, x: u16, y: u16) {
+        let items = self.buffer_context_menu_items();
+        let window = self.active_window_mut();
+        window.close_context_menus();
+        window.buffer_context_menu = Some(BufferContextMenu::new(buffer_id, split_id, x, y, items));
+    }
+
+    /// The items for the active buffer, in display order: clipboard entries,
+    /// then LSP navigation when the buffer's language has a server, then the
+    /// plugin commands registered with `contextMenu: true` whose custom
+    /// contexts are active.
+    fn buffer_context_menu_items(&self) -> Vec<BufferContextMenuItem> {
+        let editable = !self.active_window().is_editing_disabled();
+        let mut items = Vec::new();
+        if editable {
+            items.push(BufferContextMenuItem::new(t!("menu.edit.cut"), Action::Cut));
+        }
+        items.push(BufferContextMenuItem::new(
+            t!("menu.edit.copy"),
+            Action::Copy,
+        ));
+        if editable {
+            items.push(BufferContextMenuItem::new(
+                t!("menu.edit.paste"),
+                Action::Paste,
+            ));
+        }
+        items.push(BufferContextMenuItem::new(
+            t!("menu.edit.select_all"),
+            Action::SelectAll,
+        ));
+
+        if self.active_window().is_lsp_available() {
+            items.push(BufferContextMenuItem::new(
+                t!("menu.lsp.goto_definition"),
+                Action::LspGotoDefinition,
+            ));
+            items.push(BufferContextMenuItem::new(
+                t!("menu.lsp.find_references"),
+                Action::LspReferences,
+            ));
+            if editable {
+                items.push(BufferContextMenuItem::new(
+                    t!("menu.lsp.rename_symbol"),
+                    Action::LspRename,
+                ));
+                items.push(BufferContextMenuItem::new(
+                    t!("menu.lsp.code_actions"),
+                    Action::LspCodeActions,
+                ));
+            }
+        }
+
+        let buffer_mode = self
+            .active_window()
+            .buffer_metadata
+            .get(&self.active_buffer())
+            .and_then(|m| m.virtual_mode());
+        let plugin_items = self
+            .command_registry
+            .read()
+            .unwrap()
+            .context_menu_commands(&self.active_window().active_custom_contexts, buffer_mode)
+            .into_iter()
+            .map(|cmd| BufferContextMenuItem::new(cmd.get_localized_name(), cmd.action));
+        items.extend(plugin_items);
+        items
+    }
+
+    /// Byte position under a screen cell of a split's content area.
+    pub(super) fn content_position_at(
+        &self,
+        split_id: LeafId,
+        buffer_id: BufferId,
+        content_rect: ratatui::layout::Rect,
+        col: u16,
+        row: u16,
+    ) -> Option<usize> {
+        let view_state = self
+            .windows
+            .get(&self.active_window)
+            .and_then(|w| w.buffers.splits())
+            .and_then(|(_, vs)| vs.get(&split_id))?;
+        let gutter_width = self
+            .buffers()
+            .get(&buffer_id)
+            .map(|s| s.margins.left_total_width() as u16)?;
+        let cached_mappings = self
+            .active_layout()
+            .view_line_mappings
+            .get(&split_id)
+            .cloned();
+        super::click_geometry::screen_to_buffer_position(
+            col,
+            row,
+            content_rect,
+            gutter_width,
+            &cached_mappings,
+            view_state.viewport.top_byte,
+            true,
+            view_state.compose_width,
+        )
+    }
+
+    /// Move the primary cursor to a right-clicked position, keeping the
+    /// selection when the click landed inside it.
+    fn move_cursor_for_context_click(&mut self, position: usize) {
+        let cursors = self.active_cursors();
+        let primary_id = cursors.primary_id();
+        let cursor = *cursors.primary();
+        if cursor
+            .selection_range()
+            .is_some_and(|range| range.contains(&position))
+        {
+            return;
+        }
+        let event = Event::MoveCursor {
+            cursor_id: primary_id,
+            old_position: cursor.position,
+            new_position: position,
+            old_anchor: cursor.anchor,
+            new_anchor: None,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column: None,
+        };
+        self.active_event_log_mut().append(event.clone());
+        self.apply_event_to_active_buffer(&event);
+        self.track_cursor_movement(&event);
+    }
+}
@@ -699,6 +699,8 @@ impl super::Editor {
                     wrap_column,
                     rulers: cfg.rulers,
                     scroll_offset: cfg.scroll_offset,
+                    scroll_past_end: cfg.scroll_past_end,
+                    typewriter_scrolling: cfg.typewriter_scrolling,
                 });
                 // Match the panel-buffer presentation set in
                 // `build_group_layout` (no line numbers, no current-
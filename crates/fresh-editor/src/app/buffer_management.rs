@@ -639,6 +639,8 @@ impl Editor {
                 wrap_column,
                 rulers: self.config.editor.rulers.clone(),
                 scroll_offset: self.config.editor.scroll_offset,
+                scroll_past_end: self.config.editor.scroll_past_end,
+                typewriter_scrolling: self.config.editor.typewriter_scrolling,
             });
         }
 
@@ -0,0 +1,268 @@
+//! Buffer picker: every open buffer in most-recently-used order.
+//!
+//! The order comes from [`Window::buffer_mru`], which the focus paths in
+//! `active_focus.rs` update whenever the active buffer changes. While the
+//! picker is open, the highlighted buffer is shown in the other split
+//! without becoming a tab there; [`BufferPickerPreview`] remembers what that
+//! split showed so cancelling or confirming puts it back.
+
+use rust_i18n::t;
+
+use super::window::Window;
+use super::Editor;
+use crate::input::commands::Suggestion;
+use crate::model::event::{BufferId, LeafId};
+use crate::view::prompt::{Prompt, PromptType};
+
+/// Upper bound on [`Window::buffer_mru`]; older entries fall off the front.
+const MAX_BUFFER_MRU: usize = 100;
+
+/// A buffer temporarily shown in another split by the picker.
+#[derive(Debug, Clone)]
+pub(crate) struct BufferPickerPreview {
+    /// Split the preview is shown in.
+    leaf: LeafId,
+    /// Buffer that split showed before the preview.
+    original: BufferId,
+    /// Buffer currently previewed.
+    shown: BufferId,
+    /// The split had no view state for `shown` before the preview, so the
+    /// one created for it is dropped on restore.
+    created_state: bool,
+}
+
+impl Window {
+    /// Move `buffer_id` to the most-recent end of the MRU list.
+    pub(super) fn touch_buffer_mru(&mut self, buffer_id: BufferId) {
+        self.buffer_mru.retain(|&id| id != buffer_id);
+        self.buffer_mru.push(buffer_id);
+        if self.buffer_mru.len() > MAX_BUFFER_MRU {
+            self.buffer_mru.remove(0);
+        }
+    }
+
+    /// Show `buffer_id` in the split the user was last in (other than the
+    /// active one) without adding a tab for it. No-op with a single split.
+    fn show_buffer_picker_preview(&mut self, buffer_id: BufferId) {
+        if self
+            .buffer_picker_preview
+            .as_ref()
+            .is_some_and(|preview| preview.shown == buffer_id)
+        {
+            return;
+        }
+        self.end_buffer_picker_preview();
+        if buffer_id == self.active_buffer() {
+            return;
+        }
+
+        let Some((mgr, vs_map)) = self.buffers.splits_mut() else {
+            return;
+        };
+        let active = mgr.active_split();
+        let can_preview = |leaf: LeafId| {
+            leaf != active
+                && mgr.leaf_role(leaf).is_none()
+                && vs_map
+                    .get(&leaf)
+                    .is_some_and(|vs| vs.active_group_tab.is_none())
+        };
+        let Some(leaf) = mgr.last_focused_where(can_preview).or_else(|| {
+            mgr.root()
+                .leaf_split_ids()
+                .into_iter()
+                .find(|leaf| can_preview(*leaf))
+        }) else {
+            return;
+        };
+        let Some(original) = mgr.buffer_for_split(leaf) else {
+            return;
+        };
+        if original == buffer_id {
+            return;
+        }
+        let Some(view_state) = vs_map.get_mut(&leaf) else {
+            return;
+        };
+        let created_state = view_state.buffer_state(buffer_id).is_none();
+        mgr.set_split_buffer(leaf, buffer_id);
+        view_state.switch_buffer(buffer_id);
+        self.buffer_picker_preview = Some(BufferPickerPreview {
+            leaf,
+            original,
+            shown: buffer_id,
+            created_state,
+        });
+    }
+
+    /// Put back what the preview split showed before the picker opened.
+    pub(super) fn end_buffer_picker_preview(&mut self) {
+        let Some(preview) = self.buffer_picker_preview.take() else {
+            return;
+        };
+        let Some((mgr, vs_map)) = self.buffers.splits_mut() else {
+            return;
+        };
+        if !mgr.root().leaf_split_ids().contains(&preview.leaf) {
+            return;
+        }
+        mgr.set_split_buffer(preview.leaf, preview.original);
+        if let Some(view_state) = vs_map.get_mut(&preview.leaf) {
+            view_state.switch_buffer(preview.original);
+            if preview.created_state {
+                view_state.remove_buffer_state(preview.shown);
+            }
+        }
+    }
+}
+
+impl Editor {
+    /// Open the buffer picker. The previously active buffer is preselected,
+    /// so Enter right away toggles between the two most recent buffers.
+    pub(super) fn start_buffer_picker(&mut self) {
+        self.active_window_mut().end_buffer_picker_preview();
+        let suggestions = self.buffer_picker_suggestions();
+        let selected = if suggestions.len() > 1 { 1 } else { 0 };
+        let mut prompt = Prompt::with_suggestions(
+            t!("prompt.switch_buffer").to_string(),
+            PromptType::SwitchBuffer,
+            suggestions,
+        );
+        if !prompt.suggestions.is_empty() {
+            prompt.selected_suggestion = Some(selected);
+        }
+        self.active_window_mut().prompt = Some(prompt);
+        self.preview_buffer_from_picker();
+    }
+
+    /// Open buffers, most recently active first, then any never-activated
+    /// buffers in opening order. Mirrors the tab bar in what it leaves out.
+    fn buffer_picker_order(&self) -> Vec<BufferId> {
+        let window = self.active_window();
+        let listed = |id: &BufferId| {
+            window.buffers.contains_key(id)
+                && !window
+                    .buffer_metadata
+                    .get(id)
+                    .is_some_and(|m| m.hidden_from_tabs || m.synthetic_placeholder)
+        };
+        let active = self.active_buffer();
+        let mut order: Vec<BufferId> = std::iter::once(active)
+            .chain(window.buffer_mru.iter().rev().copied())
+            .filter(listed)
+            .collect();
+        let mut rest: Vec<BufferId> = window
+            .buffers
+            .iter()
+            .map(|(&id, _)| id)
+            .filter(|id| listed(id) && !order.contains(id))
+            .collect();
+        rest.sort_by_key(|id| id.0);
+        order.extend(rest);
+        let mut seen = std::collections::HashSet::new();
+        order.retain(|id| seen.insert(*id));
+        order
+    }
+
+    fn buffer_picker_suggestions(&self) -> Vec<Suggestion> {
+        let active = self.active_buffer();
+        self.buffer_picker_order()
+            .into_iter()
+            .map(|buffer_id| {
+                let name = self
+                    .active_window()
+                    .buffer_metadata
+                    .get(&buffer_id)
+                    .map(|m| m.display_name.clone())
+                    .unwrap_or_else(|| format!("Buffer {:?}", buffer_id));
+                let modified = self
+                    .buffers()
+                    .get(&buffer_id)
+                    .is_some_and(|b| b.buffer.is_modified());
+                let description = match (buffer_id == active, modified) {
+                    (true, true) => Some(t!("buffer_picker.current_modified").to_string()),
+                    (true, false) => Some(t!("buffer_picker.current").to_string()),
+                    (false, true) => Some(t!("buffer_picker.modified").to_string()),
+                    (false, false) => None,
+                };
+                Suggestion {
+                    description_spans: None,
+                    text: name,
+                    description,
+                    value: Some(buffer_id.0.to_string()),
+                    disabled: false,
+                    keybinding: None,
+                    source: None,
+                }
+            })
+            .collect()
+    }
+
+    /// Buffer of the picker's highlighted row, if the picker is open.
+    fn buffer_picker_selection(&self) -> Option<BufferId> {
+        let prompt = self.active_window().prompt.as_ref()?;
+        if !matches!(prompt.prompt_type, PromptType::SwitchBuffer) {
+            return None;
+        }
+        let suggestion = prompt.suggestions.get(prompt.selected_suggestion?)?;
+        let id = BufferId(suggestion.value.as_deref()?.parse().ok()?);
+        self.buffers().contains_key(&id).then_some(id)
+    }
+
+    /// Show the highlighted buffer in the other split, or drop the preview
+    /// when nothing is highlighted.
+    pub(super) fn preview_buffer_from_picker(&mut self) {
+        match self.buffer_picker_selection() {
+            Some(buffer_id) => self
+                .active_window_mut()
+                .show_buffer_picker_preview(buffer_id),
+            None => self.active_window_mut().end_buffer_picker_preview(),
+        }
+    }
+
+    /// Close the highlighted buffer and refresh the list in place. A buffer
+    /// with unsaved changes hands over to the usual save/discard question,
+    /// which replaces the picker.
+    pub(super) fn close_buffer_from_picker(&mut self) {
+        let Some(buffer_id) = self.buffer_picker_selection() else {
+            return;
+        };
+        self.active_window_mut().end_buffer_picker_preview();
+        let modified = self
+            .buffers()
+            .get(&buffer_id)
+            .is_some_and(|state| state.buffer.is_modified());
+        if modified {
+            self.active_window_mut().prompt = None;
+            let name = self.get_buffer_display_name(buffer_id);
+            self.start_prompt(
+                t!(
+                    "prompt.buffer_modified",
+                    name = name,
+                    save_key = t!("prompt.key.save").to_string(),
+                    discard_key = t!("prompt.key.discard").to_string(),
+                    cancel_key = t!("prompt.key.cancel").to_string()
+                )
+                .to_string(),
+                PromptType::ConfirmCloseBuffer { buffer_id },
+            );
+            return;
+        }
+        if let Err(e) = self.close_buffer(buffer_id) {
+            self.set_status_error(t!("file.cannot_close", error = e.to_string()).to_string());
+            return;
+        }
+        self.set_status_message(t!("buffer.closed").to_string());
+
+        let suggestions = self.buffer_picker_suggestions();
+        if let Some(prompt) = self.active_window_mut().prompt.as_mut() {
+            let selected = prompt.selected_suggestion.unwrap_or(0);
+            prompt.original_suggestions = Some(suggestions);
+            prompt.filter_suggestions(false);
+            if !prompt.suggestions.is_empty() {
+                prompt.selected_suggestion = Some(selected.min(prompt.suggestions.len() - 1));
+            }
+        }
+        self.preview_buffer_from_picker();
+    }
+}
@@ -0,0 +1,141 @@
+//! Serve Buffer: a read-only live view of one buffer over HTTP.
+//!
+//! [`Editor::serve_buffer`] starts a [`BufferShareServer`] for the active
+//! buffer; each tick [`Editor::publish_shared_buffer`] re-renders the buffer
+//! to highlighted HTML when it changed and hands it to the server, which the
+//! browser page picks up by polling.
+
+use std::time::{Duration, Instant};
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::model::event::BufferId;
+use crate::services::buffer_share::BufferShareServer;
+use crate::services::styled_html::render_styled_html;
+
+/// Minimum time between two renders of the shared buffer, so typing in a
+/// large file doesn't re-highlight it on every keystroke.
+const PUBLISH_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Buffers larger than this are served as plain text: highlighting the whole
+/// file for every published change would stall the editor.
+const MAX_HIGHLIGHT_BYTES: usize = 1024 * 1024;
+
+/// The buffer being served and the server showing it.
+pub(crate) struct BufferShare {
+    server: BufferShareServer,
+    window: fresh_core::WindowId,
+    buffer_id: BufferId,
+    /// Buffer version of the last published render.
+    published_version: Option<u64>,
+    last_publish: Option<Instant>,
+}
+
+impl Editor {
+    /// Serve the active buffer. If another buffer is already being served,
+    /// the running server switches to this one and keeps its address.
+    pub fn serve_buffer(&mut self) {
+        let buffer_id = self.active_buffer();
+        let window = self.active_window;
+        if self.active_window().is_composite_buffer(buffer_id) {
+            self.set_status_message(t!("share.unsupported_buffer").to_string());
+            return;
+        }
+        if let Some(share) = &mut self.buffer_share {
+            share.window = window;
+            share.buffer_id = buffer_id;
+            share.published_version = None;
+            share.last_publish = None;
+        } else {
+            let address = self.config().editor.serve_buffer_address.clone();
+            match BufferShareServer::start(&address) {
+                Ok(server) => {
+                    self.buffer_share = Some(BufferShare {
+                        server,
+                        window,
+                        buffer_id,
+                        published_version: None,
+                        last_publish: None,
+                    });
+                }
+                Err(e) => {
+                    self.set_status_message(
+                        t!("share.failed", address = address, error = e.to_string()).to_string(),
+                    );
+                    return;
+                }
+            }
+        }
+        self.publish_shared_buffer();
+        let url = self
+            .buffer_share
+            .as_ref()
+            .map(|share| share.server.url())
+            .unwrap_or_default();
+        let name = self.get_buffer_display_name(buffer_id);
+        self.set_status_message(t!("share.serving", name = name, url = url).to_string());
+    }
+
+    /// Stop the Serve Buffer server, if one is running.
+    pub fn stop_serving_buffer(&mut self) {
+        if self.buffer_share.take().is_some() {
+            self.set_status_message(t!("share.stopped").to_string());
+        } else {
+            self.set_status_message(t!("share.not_serving").to_string());
+        }
+    }
+
+    /// URL of the running Serve Buffer server.
+    pub fn buffer_share_url(&self) -> Option<String> {
+        self.buffer_share.as_ref().map(|share| share.server.url())
+    }
+
+    /// Re-render the served buffer if it changed since the last publish.
+    /// Stops serving once the buffer is closed. Called every tick.
+    pub(crate) fn publish_shared_buffer(&mut self) {
+        let Some(share) = &self.buffer_share else {
+            return;
+        };
+        if share
+            .last_publish
+            .is_some_and(|at| at.elapsed() < PUBLISH_INTERVAL)
+        {
+            return;
+        }
+        let (window, buffer_id, published_version) =
+            (share.window, share.buffer_id, share.published_version);
+
+        let Some(win) = self.windows.get_mut(&window) else {
+            self.buffer_share = None;
+            return;
+        };
+        let Some(state) = win.buffers.get_mut(&buffer_id) else {
+            self.buffer_share = None;
+            self.set_status_message(t!("share.buffer_closed").to_string());
+            return;
+        };
+        let version = state.buffer.version();
+        if published_version == Some(version) {
+            return;
+        }
+        let theme = self.theme.read().unwrap();
+        let text = state.buffer.to_string().unwrap_or_default();
+        let spans = if text.len() <= MAX_HIGHLIGHT_BYTES {
+            state
+                .highlighter
+                .highlight_viewport(&state.buffer, 0, text.len(), &theme, 0)
+        } else {
+            Vec::new()
+        };
+        let html = render_styled_html(&text, &spans, &theme);
+        drop(theme);
+
+        let title = self.get_buffer_display_name(buffer_id);
+        if let Some(share) = &mut self.buffer_share {
+            share.server.publish(&title, html);
+            share.published_version = Some(version);
+            share.last_publish = Some(Instant::now());
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Evaluate Selection: a small calculator over the selected text.
+//!
+//! The expression language lives in [`crate::primitives::calc`]. The popup
+//! form shows the primary selection's result; the replace and append forms
+//! rewrite every selection as one undoable edit, and refuse to change
+//! anything if one of them doesn't evaluate.
+
+use rust_i18n::t;
+
+use crate::model::event::{CursorId, Event};
+use crate::primitives::calc::{evaluate, Evaluation};
+
+use super::Editor;
+
+/// What Evaluate Selection does with the result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum CalcOutput {
+    /// Show it in a popup.
+    Popup,
+    /// Replace the expression with the number.
+    Replace,
+    /// Keep the expression and add ` = <result>` after it.
+    Append,
+}
+
+impl Editor {
+    pub(super) fn evaluate_selection(&mut self, output: CalcOutput) {
+        let primary = self.active_cursors().primary_id();
+        let ranges: Vec<_> = self
+            .active_cursors()
+            .iter()
+            .filter(|(id, _)| output != CalcOutput::Popup || *id == primary)
+            .filter_map(|(_, cursor)| cursor.selection_range())
+            .filter(|range| !range.is_empty())
+            .collect();
+        if ranges.is_empty() {
+            self.set_status_message(t!("calc.no_selection").to_string());
+            return;
+        }
+
+        let mut results = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            let text = self
+                .active_state_mut()
+                .get_text_range(range.start, range.end);
+            match evaluate(&text) {
+                Ok(result) => results.push((range, text, result)),
+                Err(error) => {
+                    self.set_status_message(t!("calc.error", error = error).to_string());
+                    return;
+                }
+            }
+        }
+
+        if output == CalcOutput::Popup {
+            if let Some((_, _, result)) = results.pop() {
+                self.show_calc_popup(&result);
+            }
+            return;
+        }
+
+        // Descending order keeps each range valid while the later ones apply.
+        results.sort_by_key(|(range, _, _)| std::cmp::Reverse(range.start));
+        let cursor_id = CursorId::UNDO_SENTINEL;
+        let mut events = Vec::with_capacity(results.len() * 2);
+        for (range, text, result) in results {
+            if output == CalcOutput::Append {
+                events.push(Event::Insert {
+                    position: range.end,
+                    text: format!(" = {result}"),
+                    cursor_id,
+                });
+                continue;
+            }
+            events.push(Event::Delete {
+                range: range.clone(),
+                deleted_text: text,
+                cursor_id,
+            });
+            events.push(Event::Insert {
+                position: range.start,
+                text: result.value.to_string(),
+                cursor_id,
+            });
+        }
+        if let Some(bulk_edit) =
+            self.apply_events_as_bulk_edit(events, "Evaluate selection".to_string())
+        {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+    }
+
+    fn show_calc_popup(&mut self, result: &Evaluation) {
+        use crate::view::popup::{Popup, PopupPosition};
+        use ratatui::style::Style;
+
+        let mut lines = vec![t!("calc.value", value = result).to_string()];
+        if let Some(hex) = result.hex() {
+            lines.push(t!("calc.hex", value = hex).to_string());
+        }
+        if let Some(binary) = result.binary() {
+            lines.push(t!("calc.binary", value = binary).to_string());
+        }
+
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0) as u16 + 4;
+        let popup = {
+            let theme = self.theme.read().unwrap();
+            let mut p = Popup::text(lines, &theme).with_title(t!("calc.title").to_string());
+            p.transient = true;
+            p.position = PopupPosition::BelowCursor;
+            p.width = width.clamp(24, 80);
+            p.max_height = 6;
+            p.border_style = Style::default().fg(theme.popup_border_fg);
+            p.background_style = Style::default().bg(theme.popup_bg);
+            p
+        };
+        let popups = &mut self.active_state_mut().popups;
+        while popups.top().is_some_and(|p| p.transient) {
+            popups.hide();
+        }
+        popups.show(popup);
+    }
+}
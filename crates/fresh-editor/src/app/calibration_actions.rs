@@ -4,7 +4,10 @@
 
 use super::calibration_wizard::{CalibrationWizard, WizardAction};
 use super::Editor;
+use crate::input::commands::Suggestion;
 use crate::input::handler::InputResult;
+use crate::input::key_translator::{current_terminal, KeyTranslator};
+use crate::view::prompt::{Prompt, PromptType};
 use crossterm::event::KeyEvent;
 use rust_i18n::t;
 
@@ -19,17 +22,39 @@ impl Editor {
             self.set_status_message(t!("calibration.tui_only").to_string());
             return;
         }
-        self.calibration_wizard = Some(CalibrationWizard::new());
+        let mut wizard =
+            CalibrationWizard::new().with_custom_keys(&self.config.editor.calibration_keys);
+        wizard.keyboard_protocol = self.terminal_capabilities.keyboard;
+        self.calibration_wizard = Some(wizard);
         self.set_status_message(t!("calibration.started").to_string());
     }
 
-    /// Save calibration and close wizard
+    /// Save calibration and close wizard. The result becomes the profile for
+    /// the current terminal when Fresh can tell which terminal that is.
     pub fn save_calibration(&mut self, wizard: CalibrationWizard) {
         let translator = wizard.build_translator();
         let count = translator.len();
+        let config_dir = &self.dir_context.config_dir;
 
         // Save to config file
-        if let Err(e) = translator.save_to_config_dir(&self.dir_context.config_dir) {
+        let (result, message) = match current_terminal() {
+            Some(terminal) => {
+                let profile = KeyTranslator::profile_name_for(&terminal);
+                (
+                    translator.save_profile(config_dir, &profile, &terminal),
+                    t!(
+                        "calibration.saved_profile",
+                        count = count,
+                        profile = profile
+                    ),
+                )
+            }
+            None => (
+                translator.save_to_config_dir(config_dir),
+                t!("calibration.saved", count = count),
+            ),
+        };
+        if let Err(e) = result {
             tracing::error!("Failed to save key calibration: {}", e);
             self.set_status_message(
                 t!("calibration.save_error", error = e.to_string()).to_string(),
@@ -40,7 +65,85 @@ impl Editor {
         // Update the active translator
         self.key_translator = translator;
 
-        self.set_status_message(t!("calibration.saved", count = count).to_string());
+        self.set_status_message(message.to_string());
+    }
+
+    /// Start the prompt that picks the calibration profile for this terminal
+    pub(super) fn start_select_calibration_profile_prompt(&mut self) {
+        let Some(terminal) = current_terminal() else {
+            self.set_status_message(t!("calibration.no_terminal").to_string());
+            return;
+        };
+        let profiles = KeyTranslator::list_profiles(&self.dir_context.config_dir);
+        if profiles.is_empty() {
+            self.set_status_message(t!("calibration.no_profiles").to_string());
+            return;
+        }
+
+        let current_index = profiles
+            .iter()
+            .position(|p| p.terminals.contains(&terminal))
+            .unwrap_or(0);
+        let suggestions: Vec<Suggestion> = profiles
+            .iter()
+            .map(|profile| {
+                let mut description =
+                    t!("calibration.profile_keys", count = profile.translations).to_string();
+                if !profile.terminals.is_empty() {
+                    description.push_str(&format!(" · {}", profile.terminals.join(", ")));
+                }
+                if profile.terminals.contains(&terminal) {
+                    description.push_str(" (current)");
+                }
+                Suggestion {
+                    description_spans: None,
+                    text: profile.name.clone(),
+                    description: Some(description),
+                    value: Some(profile.name.clone()),
+                    disabled: false,
+                    keybinding: None,
+                    source: None,
+                }
+            })
+            .collect();
+
+        self.active_window_mut().prompt = Some(Prompt::with_suggestions(
+            t!("calibration.select_profile_prompt", terminal = terminal).to_string(),
+            PromptType::SelectCalibrationProfile,
+            suggestions,
+        ));
+        if let Some(prompt) = self.active_window_mut().prompt.as_mut() {
+            prompt.selected_suggestion = Some(current_index);
+        }
+    }
+
+    /// Use the named calibration profile for this terminal from now on
+    pub(super) fn apply_calibration_profile(&mut self, name: &str) {
+        let Some(terminal) = current_terminal() else {
+            return;
+        };
+        if name.is_empty() {
+            return;
+        }
+        match KeyTranslator::assign_profile(&self.dir_context.config_dir, name, &terminal) {
+            Ok(translator) => {
+                self.key_translator = translator;
+                self.set_status_message(
+                    t!(
+                        "calibration.profile_selected",
+                        profile = name,
+                        terminal = terminal
+                    )
+                    .to_string(),
+                );
+            }
+            Err(e) => {
+                tracing::warn!("Failed to switch calibration profile: {}", e);
+                self.set_status_message(
+                    t!("calibration.save_error", error = e.to_string()).to_string(),
+                );
+            }
+        }
     }
 
     /// Handle input when calibration wizard is active
@@ -52,7 +155,10 @@ impl Editor {
         };
 
         // Handle the key based on current state
-        let action = if wizard.has_pending_confirmation() {
+        let action = if wizard.is_diagnostic() {
+            wizard.record_diagnostic(&crossterm::event::Event::Key(*event));
+            WizardAction::Continue
+        } else if wizard.has_pending_confirmation() {
             wizard.handle_confirmation_key(*event)
         } else if wizard.is_verify_phase() {
             wizard.handle_verify_key(*event)
@@ -94,6 +200,34 @@ impl Editor {
         InputResult::Consumed
     }
 
+    /// Feed a raw terminal event to the calibration wizard's event inspector.
+    /// Returns true if the inspector is open and took the event; it sees
+    /// events before key translation and dead-key composition.
+    pub(crate) fn record_calibration_diagnostic(
+        &mut self,
+        event: &crossterm::event::Event,
+    ) -> bool {
+        use crossterm::event::Event;
+
+        if !matches!(
+            event,
+            Event::Key(_) | Event::Paste(_) | Event::FocusGained | Event::FocusLost
+        ) {
+            return false;
+        }
+        let Some(wizard) = self.calibration_wizard.as_mut() else {
+            return false;
+        };
+        if !wizard.is_diagnostic() {
+            return false;
+        }
+        wizard.record_diagnostic(event);
+        if let Some(msg) = wizard.status_message.take() {
+            self.set_status_message(msg);
+        }
+        true
+    }
+
     /// Check if calibration wizard is active
     pub fn is_calibration_active(&self) -> bool {
         self.calibration_wizard.is_some()
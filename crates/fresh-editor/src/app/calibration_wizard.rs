@@ -7,10 +7,18 @@
 //! The wizard operates in two phases:
 //! 1. Capture Phase: User presses each target key, wizard records what the terminal sends
 //! 2. Verify Phase: User can test their mappings work correctly before saving
+//!
+//! From either phase, `i` opens the event inspector: a log of every raw event
+//! the terminal delivers and what dead-key composition makes of it. Pressing
+//! Esc twice returns to the wizard.
 
+use crate::input::dead_keys::DeadKeyComposer;
 use crate::input::key_translator::{KeyEventKey, KeyTranslator};
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::input::keybindings::KeybindingResolver;
+use crate::services::terminal_modes::KeyboardProtocol;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use rust_i18n::t;
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
 /// What the user's key SHOULD produce (the expected/normalized key)
@@ -35,7 +43,7 @@ impl ExpectedKey {
 #[derive(Debug, Clone)]
 pub struct CalibrationTarget {
     /// Display name for the key (e.g., "BACKSPACE", "CTRL+LEFT")
-    pub name: &'static str,
+    pub name: Cow<'static, str>,
     /// What Fresh expects to receive (the normalized key)
     pub expected: ExpectedKey,
 }
@@ -44,7 +52,7 @@ pub struct CalibrationTarget {
 #[derive(Debug, Clone)]
 pub struct CalibrationGroup {
     /// Group name (e.g., "Basic Editing", "Line Navigation")
-    pub name: &'static str,
+    pub name: Cow<'static, str>,
     /// Keys in this group
     pub targets: Vec<CalibrationTarget>,
 }
@@ -54,91 +62,91 @@ pub fn calibration_groups() -> Vec<CalibrationGroup> {
     vec![
         // Group 1: Basic Editing (4 keys)
         CalibrationGroup {
-            name: "Basic Editing",
+            name: "Basic Editing".into(),
             targets: vec![
                 CalibrationTarget {
-                    name: "BACKSPACE",
+                    name: "BACKSPACE".into(),
                     expected: ExpectedKey::new(KeyCode::Backspace, KeyModifiers::NONE),
                 },
                 CalibrationTarget {
-                    name: "DELETE",
+                    name: "DELETE".into(),
                     expected: ExpectedKey::new(KeyCode::Delete, KeyModifiers::NONE),
                 },
                 CalibrationTarget {
-                    name: "TAB",
+                    name: "TAB".into(),
                     expected: ExpectedKey::new(KeyCode::Tab, KeyModifiers::NONE),
                 },
                 CalibrationTarget {
-                    name: "SHIFT+TAB",
+                    name: "SHIFT+TAB".into(),
                     expected: ExpectedKey::new(KeyCode::BackTab, KeyModifiers::SHIFT),
                 },
             ],
         },
         // Group 2: Line Navigation (4 keys)
         CalibrationGroup {
-            name: "Line Navigation",
+            name: "Line Navigation".into(),
             targets: vec![
                 CalibrationTarget {
-                    name: "HOME",
+                    name: "HOME".into(),
                     expected: ExpectedKey::new(KeyCode::Home, KeyModifiers::NONE),
                 },
                 CalibrationTarget {
-                    name: "END",
+                    name: "END".into(),
                     expected: ExpectedKey::new(KeyCode::End, KeyModifiers::NONE),
                 },
                 CalibrationTarget {
-                    name: "SHIFT+HOME",
+                    name: "SHIFT+HOME".into(),
                     expected: ExpectedKey::new(KeyCode::Home, KeyModifiers::SHIFT),
                 },
                 CalibrationTarget {
-                    name: "SHIFT+END",
+                    name: "SHIFT+END".into(),
                     expected: ExpectedKey::new(KeyCode::End, KeyModifiers::SHIFT),
                 },
             ],
         },
         // Group 3: Word Navigation (8 keys)
         CalibrationGroup {
-            name: "Word Navigation",
+            name: "Word Navigation".into(),
             targets: vec![
                 CalibrationTarget {
-                    name: "ALT+LEFT",
+                    name: "ALT+LEFT".into(),
                     expected: ExpectedKey::new(KeyCode::Left, KeyModifiers::ALT),
                 },
                 CalibrationTarget {
-                    name: "ALT+RIGHT",
+                    name: "ALT+RIGHT".into(),
                     expected: ExpectedKey::new(KeyCode::Right, KeyModifiers::ALT),
                 },
                 CalibrationTarget {
-                    name: "ALT+SHIFT+LEFT",
+                    name: "ALT+SHIFT+LEFT".into(),
                     expected: ExpectedKey::new(
                         KeyCode::Left,
                         KeyModifiers::ALT.union(KeyModifiers::SHIFT),
                     ),
                 },
                 CalibrationTarget {
-                    name: "ALT+SHIFT+RIGHT",
+                    name: "ALT+SHIFT+RIGHT".into(),
                     expected: ExpectedKey::new(
                         KeyCode::Right,
                         KeyModifiers::ALT.union(KeyModifiers::SHIFT),
                     ),
                 },
                 CalibrationTarget {
-                    name: "CTRL+LEFT",
+                    name: "CTRL+LEFT".into(),
                     expected: ExpectedKey::new(KeyCode::Left, KeyModifiers::CONTROL),
                 },
                 CalibrationTarget {
-                    name: "CTRL+RIGHT",
+                    name: "CTRL+RIGHT".into(),
                     expected: ExpectedKey::new(KeyCode::Right, KeyModifiers::CONTROL),
                 },
                 CalibrationTarget {
-                    name: "CTRL+SHIFT+LEFT",
+                    name: "CTRL+SHIFT+LEFT".into(),
                     expected: ExpectedKey::new(
                         KeyCode::Left,
                         KeyModifiers::CONTROL.union(KeyModifiers::SHIFT),
                     ),
                 },
                 CalibrationTarget {
-                    name: "CTRL+SHIFT+RIGHT",
+                    name: "CTRL+SHIFT+RIGHT".into(),
                     expected: ExpectedKey::new(
                         KeyCode::Right,
                         KeyModifiers::CONTROL.union(KeyModifiers::SHIFT),
@@ -148,44 +156,44 @@ pub fn calibration_groups() -> Vec<CalibrationGroup> {
         },
         // Group 4: Document Navigation (4 keys)
         CalibrationGroup {
-            name: "Document Navigation",
+            name: "Document Navigation".into(),
             targets: vec![
                 CalibrationTarget {
-                    name: "PAGE UP",
+                    name: "PAGE UP".into(),
                     expected: ExpectedKey::new(KeyCode::PageUp, KeyModifiers::NONE),
                 },
                 CalibrationTarget {
-                    name: "PAGE DOWN",
+                    name: "PAGE DOWN".into(),
                     expected: ExpectedKey::new(KeyCode::PageDown, KeyModifiers::NONE),
                 },
                 CalibrationTarget {
-                    name: "CTRL+HOME",
+                    name: "CTRL+HOME".into(),
                     expected: ExpectedKey::new(KeyCode::Home, KeyModifiers::CONTROL),
                 },
                 CalibrationTarget {
-                    name: "CTRL+END",
+                    name: "CTRL+END".into(),
                     expected: ExpectedKey::new(KeyCode::End, KeyModifiers::CONTROL),
                 },
             ],
         },
         // Group 5: Emacs-Style Navigation (4 keys)
         CalibrationGroup {
-            name: "Emacs-Style",
+            name: "Emacs-Style".into(),
             targets: vec![
                 CalibrationTarget {
-                    name: "CTRL+A",
+                    name: "CTRL+A".into(),
                     expected: ExpectedKey::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
                 },
                 CalibrationTarget {
-                    name: "CTRL+E",
+                    name: "CTRL+E".into(),
                     expected: ExpectedKey::new(KeyCode::Char('e'), KeyModifiers::CONTROL),
                 },
                 CalibrationTarget {
-                    name: "CTRL+K",
+                    name: "CTRL+K".into(),
                     expected: ExpectedKey::new(KeyCode::Char('k'), KeyModifiers::CONTROL),
                 },
                 CalibrationTarget {
-                    name: "CTRL+Y",
+                    name: "CTRL+Y".into(),
                     expected: ExpectedKey::new(KeyCode::Char('y'), KeyModifiers::CONTROL),
                 },
             ],
@@ -193,6 +201,38 @@ pub fn calibration_groups() -> Vec<CalibrationGroup> {
     ]
 }
 
+/// Parse a key written like the `editor.calibration_keys` entries: a key
+/// name with optional modifiers, joined by `+` (e.g. "F5", "ctrl+enter").
+fn parse_calibration_key(spec: &str) -> Option<ExpectedKey> {
+    let mut parts: Vec<String> = spec.split('+').map(|p| p.trim().to_string()).collect();
+    let key = parts.pop()?;
+    let code = KeybindingResolver::parse_key_public(&key)?;
+    let modifiers = KeybindingResolver::parse_modifiers_public(&parts);
+    Some(ExpectedKey::new(code, modifiers))
+}
+
+/// Build the group of keys the user added with `editor.calibration_keys`.
+/// Entries that don't parse are skipped with a warning.
+pub fn custom_group(keys: &[String]) -> Option<CalibrationGroup> {
+    let targets: Vec<CalibrationTarget> = keys
+        .iter()
+        .filter_map(|spec| match parse_calibration_key(spec) {
+            Some(expected) => Some(CalibrationTarget {
+                name: spec.trim().to_uppercase().into(),
+                expected,
+            }),
+            None => {
+                tracing::warn!("Ignoring unknown calibration key '{}'", spec);
+                None
+            }
+        })
+        .collect();
+    (!targets.is_empty()).then(|| CalibrationGroup {
+        name: "Custom Keys".into(),
+        targets,
+    })
+}
+
 /// Current step in the calibration wizard
 #[derive(Debug, Clone)]
 pub enum CalibrationStep {
@@ -256,6 +296,29 @@ pub enum PendingConfirmation {
     Restart,
 }
 
+/// Most events kept in the inspector log
+const MAX_DIAGNOSTIC_EVENTS: usize = 200;
+
+/// One event seen by the inspector
+#[derive(Debug, Clone)]
+pub struct DiagnosticEntry {
+    /// The raw event as the terminal delivered it
+    pub event: String,
+    /// What dead-key composition does with it, if anything notable
+    pub effect: Option<String>,
+}
+
+/// Event inspector state: the log, plus a composer of its own so the
+/// effect column matches what typing in a buffer would do
+#[derive(Debug, Default)]
+pub struct DiagnosticLog {
+    /// Recorded events, oldest first
+    pub entries: Vec<DiagnosticEntry>,
+    composer: DeadKeyComposer,
+    /// Whether the previous event was a bare Esc press
+    last_was_esc: bool,
+}
+
 /// The calibration wizard state machine
 #[derive(Debug)]
 pub struct CalibrationWizard {
@@ -277,6 +340,10 @@ pub struct CalibrationWizard {
     pub status_message: Option<String>,
     /// Pending confirmation dialog
     pub pending_confirmation: PendingConfirmation,
+    /// Event inspector, while it is open
+    pub diagnostic: Option<DiagnosticLog>,
+    /// Whether the terminal speaks the kitty keyboard protocol
+    pub keyboard_protocol: KeyboardProtocol,
 }
 
 impl CalibrationWizard {
@@ -298,7 +365,19 @@ impl CalibrationWizard {
             verified: HashSet::new(),
             status_message: None,
             pending_confirmation: PendingConfirmation::None,
+            diagnostic: None,
+            keyboard_protocol: KeyboardProtocol::Unknown,
+        }
+    }
+
+    /// Append the keys from `editor.calibration_keys` as a last group
+    pub fn with_custom_keys(mut self, keys: &[String]) -> Self {
+        if let Some(group) = custom_group(keys) {
+            self.key_statuses
+                .extend(std::iter::repeat_n(KeyStatus::Pending, group.targets.len()));
+            self.groups.push(group);
         }
+        self
     }
 
     /// Check if a confirmation dialog is pending
@@ -441,6 +520,10 @@ impl CalibrationWizard {
                     self.pending_confirmation = PendingConfirmation::Abort;
                     return WizardAction::ShowConfirmation;
                 }
+                KeyCode::Char('i') => {
+                    self.start_diagnostic();
+                    return WizardAction::Continue;
+                }
                 KeyCode::Char('y') | KeyCode::Char('n') | KeyCode::Char('r') => {
                     // Reserved for verification phase
                     self.status_message = Some(t!("calibration.reserved_key").to_string());
@@ -507,6 +590,10 @@ impl CalibrationWizard {
                     }
                     return WizardAction::Continue;
                 }
+                KeyCode::Char('i') => {
+                    self.start_diagnostic();
+                    return WizardAction::Continue;
+                }
                 _ => {}
             }
         }
@@ -676,6 +763,99 @@ impl CalibrationWizard {
         self.status_message = Some(t!("calibration.restarted").to_string());
     }
 
+    /// Check if the event inspector is open
+    pub fn is_diagnostic(&self) -> bool {
+        self.diagnostic.is_some()
+    }
+
+    /// Open the event inspector with an empty log
+    pub fn start_diagnostic(&mut self) {
+        self.diagnostic = Some(DiagnosticLog::default());
+    }
+
+    /// Record a raw input event in the inspector. Two Esc presses in a row
+    /// close it again.
+    pub fn record_diagnostic(&mut self, event: &Event) {
+        let Some(log) = self.diagnostic.as_mut() else {
+            return;
+        };
+
+        let entry = match event {
+            Event::Key(key) => {
+                let mut text = format!(
+                    "{:?} {}",
+                    key.kind,
+                    super::event_debug::format_key_event(key)
+                );
+                if let KeyCode::Char(c) = key.code {
+                    text.push_str(&format!(" U+{:04X}", c as u32));
+                }
+                if !key.state.is_empty() {
+                    text.push_str(&format!(" {:?}", key.state));
+                }
+                let effect = (key.kind == KeyEventKind::Press)
+                    .then(|| Self::describe_composition(&mut log.composer, *key))
+                    .flatten();
+
+                if key.kind == KeyEventKind::Press {
+                    let is_esc = key.code == KeyCode::Esc && key.modifiers.is_empty();
+                    if is_esc && log.last_was_esc {
+                        self.diagnostic = None;
+                        self.status_message = Some(t!("calibration.diagnostic_closed").to_string());
+                        return;
+                    }
+                    log.last_was_esc = is_esc;
+                }
+                DiagnosticEntry {
+                    event: text,
+                    effect,
+                }
+            }
+            Event::Paste(text) => {
+                let preview: String = text.chars().take(40).collect();
+                DiagnosticEntry {
+                    event: format!("Paste {:?} ({} bytes)", preview, text.len()),
+                    effect: None,
+                }
+            }
+            Event::FocusGained => DiagnosticEntry {
+                event: "FocusGained".to_string(),
+                effect: None,
+            },
+            Event::FocusLost => DiagnosticEntry {
+                event: "FocusLost".to_string(),
+                effect: None,
+            },
+            Event::Mouse(_) | Event::Resize(..) => return,
+        };
+
+        if log.entries.len() == MAX_DIAGNOSTIC_EVENTS {
+            log.entries.remove(0);
+        }
+        log.entries.push(entry);
+    }
+
+    /// Describe what dead-key composition does with a key press, when it
+    /// does more than pass the key through
+    fn describe_composition(composer: &mut DeadKeyComposer, key: KeyEvent) -> Option<String> {
+        let had_pending = composer.pending().is_some();
+        let keys = composer.feed(key);
+        if keys.is_empty() {
+            return Some(match composer.pending() {
+                Some(accent) => t!("calibration.diag_dead_key", accent = accent).to_string(),
+                None => t!("calibration.diag_cancelled").to_string(),
+            });
+        }
+        if !had_pending {
+            return None;
+        }
+        let typed: Vec<String> = keys
+            .iter()
+            .map(super::event_debug::format_key_event)
+            .collect();
+        Some(t!("calibration.diag_becomes", keys = typed.join(", ")).to_string())
+    }
+
     /// Check if we're in verify phase
     pub fn is_verify_phase(&self) -> bool {
         matches!(self.step, CalibrationStep::Verify)
@@ -752,6 +932,30 @@ mod tests {
         assert_eq!(total, 24); // 4 + 4 + 8 + 4 + 4 = 24 keys
     }
 
+    #[test]
+    fn test_custom_keys_are_appended() {
+        let keys = [
+            "F5".to_string(),
+            "ctrl+enter".to_string(),
+            "nope".to_string(),
+        ];
+        let mut wizard = CalibrationWizard::new().with_custom_keys(&keys);
+        assert_eq!(wizard.current_step_info(), (1, 26));
+
+        let custom = wizard.groups().last().unwrap();
+        assert_eq!(custom.targets[1].name, "CTRL+ENTER");
+        assert_eq!(custom.targets[1].expected.code, KeyCode::Enter);
+        assert_eq!(custom.targets[1].expected.modifiers, KeyModifiers::CONTROL);
+
+        // Skip the built-in groups, then capture F5 as sent by the terminal
+        for _ in 0..5 {
+            wizard.handle_capture_key(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE));
+        }
+        let raw = KeyEvent::new(KeyCode::Char('5'), KeyModifiers::ALT);
+        wizard.handle_capture_key(raw);
+        assert_eq!(wizard.build_translator().translate(raw).code, KeyCode::F(5));
+    }
+
     #[test]
     fn test_skip_key() {
         let mut wizard = CalibrationWizard::new();
@@ -990,4 +1194,41 @@ mod tests {
         let translated = translator.translate(raw);
         assert_eq!(translated.code, KeyCode::Backspace);
     }
+
+    #[test]
+    fn test_inspector_logs_events_and_composition() {
+        let mut wizard = CalibrationWizard::new();
+        wizard.handle_capture_key(KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE));
+        assert!(wizard.is_diagnostic());
+
+        wizard.record_diagnostic(&Event::Key(KeyEvent::new(
+            KeyCode::Char('\u{b4}'),
+            KeyModifiers::NONE,
+        )));
+        wizard.record_diagnostic(&Event::Key(KeyEvent::new(
+            KeyCode::Char('e'),
+            KeyModifiers::NONE,
+        )));
+
+        let entries = &wizard.diagnostic.as_ref().unwrap().entries;
+        assert_eq!(entries.len(), 2);
+        assert!(entries[0].event.contains("U+00B4"));
+        assert!(entries[0].effect.is_some());
+        assert!(entries[1].effect.as_deref().unwrap().contains('é'));
+
+        // The inspector does not touch calibration progress
+        assert_eq!(*wizard.key_status(0), KeyStatus::Pending);
+    }
+
+    #[test]
+    fn test_inspector_closes_on_double_escape() {
+        let mut wizard = CalibrationWizard::new();
+        wizard.start_diagnostic();
+        let esc = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        wizard.record_diagnostic(&esc);
+        assert!(wizard.is_diagnostic());
+        wizard.record_diagnostic(&esc);
+        assert!(!wizard.is_diagnostic());
+    }
 }
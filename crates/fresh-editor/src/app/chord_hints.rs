@@ -0,0 +1,93 @@
+//! Pending chord bookkeeping: the `editor.chord_timeout_ms` deadline and
+//! the hint popup listing what the next key can do.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::layout::Rect;
+use ratatui::Frame;
+
+use crate::input::keybindings::{format_keybinding, KeyContext, KeybindingResolver};
+use crate::view::chord_hints::{render_chord_hints, ChordHint};
+use crate::view::theme::Theme;
+
+use super::Editor;
+
+impl Editor {
+    /// Add a key to the pending chord, resolved in `context`.
+    pub(super) fn push_chord_key(
+        &mut self,
+        code: KeyCode,
+        modifiers: KeyModifiers,
+        context: KeyContext,
+    ) {
+        let now = self.time_source.now();
+        let window = self.active_window_mut();
+        window.chord_state.push((code, modifiers));
+        window.chord_context = Some(context);
+        window.chord_last_key_at = Some(now);
+    }
+
+    /// Drop the pending chord, if any.
+    pub(super) fn clear_chord(&mut self) {
+        let window = self.active_window_mut();
+        window.chord_state.clear();
+        window.chord_context = None;
+        window.chord_last_key_at = None;
+    }
+
+    /// Abandon a pending chord once `editor.chord_timeout_ms` has passed
+    /// since its last key (called from the main loop). Returns true if a
+    /// chord was dropped (requires re-render).
+    pub fn check_chord_timeout(&mut self) -> bool {
+        let timeout_ms = self.config.editor.chord_timeout_ms;
+        if timeout_ms == 0 {
+            return false;
+        }
+        let Some(last_key_at) = self.active_window().chord_last_key_at else {
+            return false;
+        };
+        if self.time_source.elapsed_since(last_key_at)
+            < std::time::Duration::from_millis(timeout_ms)
+        {
+            return false;
+        }
+        tracing::debug!("Chord timed out after {}ms", timeout_ms);
+        self.clear_chord();
+        true
+    }
+
+    /// Draw the chord hint popup at the bottom of `area` while a chord is
+    /// pending and `editor.show_chord_hints` is on.
+    pub(super) fn render_chord_hints(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        keybindings: &KeybindingResolver,
+        theme: &Theme,
+    ) {
+        let window = self.active_window();
+        if !self.config.editor.show_chord_hints || window.chord_state.is_empty() {
+            return;
+        }
+        let Some(context) = &window.chord_context else {
+            return;
+        };
+        let hints: Vec<ChordHint> = keybindings
+            .chord_continuations(&window.chord_state, context)
+            .into_iter()
+            .map(|(keys, action)| ChordHint {
+                keys: format_keys(&keys),
+                description: KeybindingResolver::format_action(&action),
+            })
+            .collect();
+        let prefix = format_keys(&window.chord_state);
+        render_chord_hints(frame, area, &prefix, &hints, theme);
+    }
+}
+
+/// Format a key sequence the way the status bar's chord element does.
+fn format_keys(keys: &[(KeyCode, KeyModifiers)]) -> String {
+    keys.iter()
+        .map(|(code, modifiers)| format_keybinding(code, modifiers))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -128,6 +128,76 @@ impl Editor {
         None
     }
 
+    /// Buffer row and byte column under screen cell (`col`, `row`) of a
+    /// split's content area, for the plugin mouse hooks. `(None, None)`
+    /// when the cell maps to no buffer position.
+    pub(super) fn buffer_row_col_at(
+        &self,
+        col: u16,
+        row: u16,
+        split_id: crate::model::event::LeafId,
+        buffer_id: BufferId,
+        content_rect: ratatui::layout::Rect,
+    ) -> (Option<u32>, Option<u32>) {
+        let cached_mappings = self
+            .active_layout()
+            .view_line_mappings
+            .get(&split_id)
+            .cloned();
+        let fallback = self
+            .windows
+            .get(&self.active_window)
+            .and_then(|w| w.buffers.splits())
+            .map(|(_, vs)| vs)
+            .expect("active window must have a populated split layout")
+            .get(&split_id)
+            .map(|vs| vs.viewport.top_byte)
+            .unwrap_or(0);
+        let compose_width = self
+            .windows
+            .get(&self.active_window)
+            .and_then(|w| w.buffers.splits())
+            .map(|(_, vs)| vs)
+            .expect("active window must have a populated split layout")
+            .get(&split_id)
+            .and_then(|vs| vs.compose_width);
+        let gutter_width = self
+            .buffers()
+            .get(&buffer_id)
+            .map(|s| s.margins.left_total_width() as u16)
+            .unwrap_or(0);
+        let target = super::click_geometry::screen_to_buffer_position(
+            col,
+            row,
+            content_rect,
+            gutter_width,
+            &cached_mappings,
+            fallback,
+            true,
+            compose_width,
+        );
+        match target {
+            Some(byte_pos) => {
+                let state = self
+                    .windows
+                    .get(&self.active_window)
+                    .map(|w| &w.buffers)
+                    .expect("active window present")
+                    .get(&buffer_id);
+                if let Some(s) = state {
+                    let (line, col_b) = s.buffer.position_to_line_col(byte_pos);
+                    (
+                        Some(line.min(u32::MAX as usize) as u32),
+                        Some(col_b.min(u32::MAX as usize) as u32),
+                    )
+                } else {
+                    (None, None)
+                }
+            }
+            None => (None, None),
+        }
+    }
+
     /// Handle click in editor content area
     pub(super) fn handle_editor_click(
         &mut self,
@@ -151,65 +221,8 @@ impl Editor {
         // and the mouse_click hook need them, and the cost (a single
         // `screen_to_buffer_position` call) is non-trivial — share the
         // result.
-        let (mc_buffer_row, mc_buffer_col) = {
-            let cached_mappings = self
-                .active_layout()
-                .view_line_mappings
-                .get(&split_id)
-                .cloned();
-            let fallback = self
-                .windows
-                .get(&self.active_window)
-                .and_then(|w| w.buffers.splits())
-                .map(|(_, vs)| vs)
-                .expect("active window must have a populated split layout")
-                .get(&split_id)
-                .map(|vs| vs.viewport.top_byte)
-                .unwrap_or(0);
-            let compose_width = self
-                .windows
-                .get(&self.active_window)
-                .and_then(|w| w.buffers.splits())
-                .map(|(_, vs)| vs)
-                .expect("active window must have a populated split layout")
-                .get(&split_id)
-                .and_then(|vs| vs.compose_width);
-            let gutter_width = self
-                .buffers()
-                .get(&buffer_id)
-                .map(|s| s.margins.left_total_width() as u16)
-                .unwrap_or(0);
-            let target = super::click_geometry::screen_to_buffer_position(
-                col,
-                row,
-                content_rect,
-                gutter_width,
-                &cached_mappings,
-                fallback,
-                true,
-                compose_width,
-            );
-            match target {
-                Some(byte_pos) => {
-                    let state = self
-                        .windows
-                        .get(&self.active_window)
-                        .map(|w| &w.buffers)
-                        .expect("active window present")
-                        .get(&buffer_id);
-                    if let Some(s) = state {
-                        let (line, col_b) = s.buffer.position_to_line_col(byte_pos);
-                        (
-                            Some(line.min(u32::MAX as usize) as u32),
-                            Some(col_b.min(u32::MAX as usize) as u32),
-                        )
-                    } else {
-                        (None, None)
-                    }
-                }
-                None => (None, None),
-            }
-        };
+        let (mc_buffer_row, mc_buffer_col) =
+            self.buffer_row_col_at(col, row, split_id, buffer_id, content_rect);
 
         // Widget hit-test: if the click landed on a Toggle/Button
         // inside a mounted widget panel, fire the semantic
@@ -506,6 +519,23 @@ impl Editor {
             return Ok(());
         }
 
+        // A plain press inside the selection may be the start of dragging
+        // it elsewhere: keep the selection and decide on release (see
+        // `text_drag`).
+        if !extend_click && virtual_lines_below == 0 {
+            if let Some(range) = self.draggable_selection_at(target_position) {
+                self.active_window_mut().mouse_state.dragging_text =
+                    Some(super::types::TextDragState::new(
+                        buffer_id,
+                        split_id,
+                        range,
+                        target_position,
+                        (col, row),
+                    ));
+                return Ok(());
+            }
+        }
+
         // Move cursor to clicked position (respect shift for selection)
         // Both modifiers supported since some terminals intercept shift+click.
         let extend_selection =
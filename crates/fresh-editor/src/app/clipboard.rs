@@ -14,7 +14,7 @@ use crate::input::multi_cursor::{
     add_cursor_above, add_cursor_at_next_match, add_cursor_below, line_end_positions_in_selection,
     AddCursorResult,
 };
-use crate::model::buffer_position::byte_to_2d;
+use crate::model::buffer_position::{byte_to_2d, display_columns_to_bytes, display_width};
 use crate::model::cursor::Cursor;
 use crate::model::event::{BufferId, CursorId, Event};
 use crate::primitives::ansi::strip_ansi_codes;
@@ -22,6 +22,7 @@ use crate::primitives::word_navigation::{
     find_vi_word_end, find_word_start_left, find_word_start_right,
 };
 use crate::services::async_bridge::AsyncMessage;
+use crate::view::prompt::PromptType;
 use crate::view::virtual_text::{VirtualTextId, VirtualTextPosition};
 
 use super::Editor;
@@ -227,6 +228,7 @@ impl Editor {
             .buffer_settings
             .virtual_space
             .block_beyond_eol();
+        let tab_size = self.active_state().buffer_settings.tab_size;
         let block_infos: Vec<_> = self
             .active_cursors()
             .iter()
@@ -250,7 +252,7 @@ impl Editor {
             // Get current cursor position as 2D
             let mut cursor_2d = {
                 let state = self.active_state();
-                byte_to_2d(&state.buffer, cursor_byte)
+                byte_to_2d(&state.buffer, cursor_byte, tab_size)
             };
             if block_virtual {
                 if let Some(sticky) = sticky {
@@ -286,20 +288,23 @@ impl Editor {
                     // Extract the column range from this line
                     // Remove trailing newline for column calculation
                     let content_without_newline = line_content.trim_end_matches(&['\n', '\r'][..]);
-                    let chars: Vec<char> = content_without_newline.chars().collect();
 
-                    // Extract characters from min_col to max_col (exclusive)
-                    let mut extracted: String = chars
-                        .iter()
-                        .skip(min_col)
-                        .take(max_col.saturating_sub(min_col))
-                        .collect();
+                    // Extract the graphemes covering display columns min_col
+                    // to max_col (exclusive)
+                    let range = display_columns_to_bytes(
+                        content_without_newline,
+                        min_col,
+                        max_col,
+                        tab_size,
+                    );
+                    let mut extracted = content_without_newline[range].to_string();
 
                     // Virtual space: block copies are true rectangles — pad
                     // short lines out to the rectangle's width.
                     if block_virtual {
                         let want = max_col.saturating_sub(min_col);
-                        let have = extracted.chars().count();
+                        let have = display_width(content_without_newline, tab_size)
+                            .saturating_sub(min_col);
                         if have < want {
                             extracted.extend(std::iter::repeat_n(' ', want - have));
                         }
@@ -1218,6 +1223,49 @@ impl Editor {
         true
     }
 
+    /// Deliver a terminal-initiated bracketed paste (`Event::Paste`).
+    ///
+    /// A focused floating panel / dock text field takes it first (see
+    /// [`Self::paste_bracketed_into_focused_panel`]); otherwise it goes
+    /// through [`Self::paste_text`] to the prompt, terminal or buffer.
+    /// The whole paste arrives as one string, so in a buffer it lands as a
+    /// single insert: one undo step, and no auto-indent or auto-close
+    /// applied to the pasted lines the way they would be if each
+    /// character were typed.
+    pub fn handle_bracketed_paste(&mut self, text: String) {
+        if !self.paste_bracketed_into_focused_panel(&text) {
+            self.paste_text(text);
+        }
+    }
+
+    /// Paste text into the focused live terminal, asking first when it
+    /// spans more lines than `terminal.paste_confirm_lines` — a stray
+    /// multi-line paste into a shell would otherwise run every line.
+    pub(crate) fn paste_into_terminal(&mut self, text: String) {
+        let threshold = self.config.terminal.paste_confirm_lines;
+        let line_count = text.trim_end_matches('\n').split('\n').count();
+        if threshold > 0 && line_count > threshold {
+            let first_line = text.lines().next().unwrap_or_default();
+            let preview: String = first_line.chars().take(40).collect();
+            let preview = if preview.len() < first_line.len() {
+                format!("{}…", preview)
+            } else {
+                preview
+            };
+            self.start_prompt(
+                t!(
+                    "terminal.paste_confirm",
+                    count = line_count,
+                    preview = preview
+                )
+                .to_string(),
+                PromptType::ConfirmTerminalPaste { text },
+            );
+            return;
+        }
+        self.active_window_mut().send_terminal_paste(&text);
+    }
+
     /// Paste text directly into the editor
     ///
     /// Handles:
@@ -1250,8 +1298,7 @@ impl Editor {
 
         // If the focused split is a live terminal, send paste to its PTY
         if self.active_window().focused_terminal_live() {
-            self.active_window_mut()
-                .send_terminal_input(normalized.as_bytes());
+            self.paste_into_terminal(normalized);
             return;
         }
 
@@ -1379,7 +1426,17 @@ impl Editor {
             self.log_and_apply_event(&event);
         }
 
-        self.active_window_mut().status_message = Some(t!("clipboard.pasted").to_string());
+        let state = crate::app::PasteCycleState {
+            buffer_id: self.active_buffer(),
+            history_index: self
+                .clipboard
+                .history()
+                .position(|entry| entry == paste_text),
+            undo_index: self.active_event_log().current_index(),
+        };
+        let window = self.active_window_mut();
+        window.paste_cycle_state = Some(state);
+        window.status_message = Some(t!("clipboard.pasted").to_string());
     }
 
     /// Set clipboard content for testing purposes
@@ -1449,30 +1506,87 @@ impl Editor {
     /// buffer id is unknown, a status message is shown and the clipboard is
     /// left untouched.
     pub fn copy_buffer_path(&mut self, buffer_id: crate::model::event::BufferId, relative: bool) {
-        let path = self
-            .buffers()
-            .get(&buffer_id)
-            .and_then(|state| state.buffer.file_path().map(|p| p.to_path_buf()));
-        let Some(path) = path else {
+        let Some(path_str) = self.buffer_path_for_copy(buffer_id, relative) else {
             self.active_window_mut().status_message =
                 Some(t!("clipboard.no_file_path").to_string());
             return;
         };
 
-        let path_str = if relative {
-            path.strip_prefix(self.working_dir())
-                .unwrap_or(&path)
-                .to_string_lossy()
-                .into_owned()
-        } else {
-            path.to_string_lossy().into_owned()
-        };
-
         self.clipboard.copy(path_str.clone());
         self.active_window_mut().status_message =
             Some(t!("clipboard.copied_path", path = &path_str).to_string());
     }
 
+    /// Copy a buffer's workspace-relative path followed by the line of its
+    /// primary cursor in `split_id`, e.g. `src/main.rs:42`.
+    ///
+    /// The text between path and line comes from
+    /// `clipboard.path_line_separator`, so the result can match what an
+    /// issue tracker or terminal tool expects (`#L42`, ` +42`, …).
+    pub fn copy_buffer_path_with_line(
+        &mut self,
+        buffer_id: crate::model::event::BufferId,
+        split_id: crate::model::event::LeafId,
+    ) {
+        let Some(path_str) = self.buffer_path_for_copy(buffer_id, true) else {
+            self.active_window_mut().status_message =
+                Some(t!("clipboard.no_file_path").to_string());
+            return;
+        };
+        let position = self
+            .windows
+            .get(&self.active_window)
+            .and_then(|w| w.buffers.splits())
+            .and_then(|(_, vs)| vs.get(&split_id))
+            .and_then(|vs| {
+                if vs.active_buffer == buffer_id {
+                    Some(&vs.cursors)
+                } else {
+                    vs.keyed_states.get(&buffer_id).map(|bs| &bs.cursors)
+                }
+            })
+            .map(|cursors| cursors.primary().position)
+            .unwrap_or(0);
+        let line = self
+            .buffers()
+            .get(&buffer_id)
+            .map(|state| state.buffer.get_line_number(position) + 1)
+            .unwrap_or(1);
+
+        let text = format!(
+            "{}{}{}",
+            path_str, self.config.clipboard.path_line_separator, line
+        );
+        self.clipboard.copy(text.clone());
+        self.active_window_mut().status_message =
+            Some(t!("clipboard.copied_path", path = &text).to_string());
+    }
+
+    /// Copy the active buffer's path and cursor line. See
+    /// [`Self::copy_buffer_path_with_line`].
+    pub fn copy_active_buffer_path_with_line(&mut self) {
+        let buffer_id = self.active_buffer();
+        let split_id = self.effective_active_split();
+        self.copy_buffer_path_with_line(buffer_id, split_id);
+    }
+
+    /// A buffer's file path as copy commands render it: relative to the
+    /// workspace root when `relative` is set and the file lives inside it,
+    /// absolute otherwise. `None` for buffers without a file.
+    fn buffer_path_for_copy(
+        &self,
+        buffer_id: crate::model::event::BufferId,
+        relative: bool,
+    ) -> Option<String> {
+        let path = self.buffers().get(&buffer_id)?.buffer.file_path()?;
+        let path = if relative {
+            path.strip_prefix(self.working_dir()).unwrap_or(path)
+        } else {
+            path
+        };
+        Some(path.to_string_lossy().into_owned())
+    }
+
     /// Copy the active buffer's file path. See [`Self::copy_buffer_path`].
     pub fn copy_active_buffer_path(&mut self, relative: bool) {
         let buffer_id = self.active_buffer();
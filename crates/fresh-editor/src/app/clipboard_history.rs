@@ -0,0 +1,271 @@
+//! Clipboard history: paste an older copy from a searchable picker, or
+//! cycle the text just pasted back through the history in place (Emacs
+//! `yank-pop`).
+//!
+//! The picker is a prompt: typing filters the entries by their full text,
+//! `Enter` pastes the selected one as it was copied, `Alt+I` pastes it
+//! re-indented to the cursor's line and `Alt+K` pins or unpins it. Pinned
+//! entries are listed first and never fall off the end of the history.
+//!
+//! The history ring itself lives in [`crate::services::clipboard`]; every
+//! copy and cut lands there.
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::input::commands::Suggestion;
+use crate::view::prompt::{Prompt, PromptType};
+
+/// Characters of an entry shown in the picker before it is cut off.
+const PREVIEW_CHARS: usize = 70;
+
+impl Editor {
+    /// Apply the clipboard settings, including whether the history is
+    /// saved across sessions.
+    pub(super) fn apply_clipboard_config(&mut self) {
+        self.clipboard.apply_config(&self.config.clipboard);
+        let history_file = self
+            .config
+            .clipboard
+            .persist_history
+            .then(|| self.dir_context.clipboard_history_path());
+        self.clipboard.set_history_file(history_file);
+    }
+
+    /// Show the clipboard history picker.
+    pub(super) fn show_clipboard_history(&mut self) {
+        if self.active_window().is_editing_disabled() {
+            self.set_status_message(t!("buffer.editing_disabled").to_string());
+            return;
+        }
+        if self.clipboard.history_len() == 0 {
+            self.set_status_message(t!("clipboard.history_empty").to_string());
+            return;
+        }
+
+        let suggestions = self.clipboard_history_suggestions("");
+        self.start_prompt_with_suggestions(
+            format!("{}: ", t!("clipboard.history_title")),
+            PromptType::ClipboardHistory,
+            suggestions,
+        );
+        self.set_status_message(t!("clipboard.history_hint").to_string());
+    }
+
+    /// Refilter the picker after its query changed.
+    pub(super) fn update_clipboard_history_suggestions(&mut self, query: &str) {
+        let suggestions = self.clipboard_history_suggestions(query);
+        if let Some(prompt) = self.active_window_mut().prompt.as_mut() {
+            prompt.selected_suggestion = (!suggestions.is_empty()).then_some(0);
+            prompt.suggestions = suggestions;
+            prompt.scroll_offset = 0;
+        }
+    }
+
+    /// Pin or unpin the entry selected in the picker, keeping it selected.
+    pub(super) fn toggle_clipboard_history_pin(&mut self) {
+        let Some(index) = self.selected_clipboard_history_entry() else {
+            return;
+        };
+        let Some(pinned) = self.clipboard.toggle_pin(index) else {
+            return;
+        };
+        let query = self
+            .active_window()
+            .prompt
+            .as_ref()
+            .map(|p| p.input.clone())
+            .unwrap_or_default();
+        self.update_clipboard_history_suggestions(&query);
+        let value = index.to_string();
+        if let Some(prompt) = self.active_window_mut().prompt.as_mut() {
+            if let Some(row) = prompt
+                .suggestions
+                .iter()
+                .position(|s| s.get_value() == value)
+            {
+                prompt.selected_suggestion = Some(row);
+                prompt.ensure_selected_visible();
+            }
+        }
+        self.set_status_message(if pinned {
+            t!("clipboard.history_pinned_status").to_string()
+        } else {
+            t!("clipboard.history_unpinned_status").to_string()
+        });
+    }
+
+    /// Close the picker and paste the selected entry re-indented to match
+    /// the line the cursor is on.
+    pub(super) fn paste_clipboard_history_entry_indented(&mut self) {
+        let Some(index) = self.selected_clipboard_history_entry() else {
+            return;
+        };
+        let Some(text) = self.clipboard.history_entry(index).map(str::to_string) else {
+            return;
+        };
+        self.cancel_prompt();
+        self.clipboard.copy(text.clone());
+        let indent = self.cursor_line_indent();
+        self.paste_text(reindent(&text, &indent));
+    }
+
+    /// History index of the entry selected in the picker, if the picker is
+    /// open and has a selection.
+    fn selected_clipboard_history_entry(&self) -> Option<usize> {
+        self.active_window()
+            .prompt
+            .as_ref()
+            .filter(|p| p.prompt_type == PromptType::ClipboardHistory)
+            .and_then(Prompt::selected_value)
+            .and_then(|value| value.parse().ok())
+    }
+
+    /// Picker rows for the entries containing every word of `query`
+    /// (ignoring case): pinned entries first, then the rest newest first.
+    fn clipboard_history_suggestions(&self, query: &str) -> Vec<Suggestion> {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        let (pinned, unpinned): (Vec<_>, Vec<_>) = self
+            .clipboard
+            .history()
+            .enumerate()
+            .filter(|(_, entry)| {
+                let entry = entry.to_lowercase();
+                terms.iter().all(|term| entry.contains(term.as_str()))
+            })
+            .partition(|(i, _)| self.clipboard.is_pinned(*i));
+
+        pinned
+            .into_iter()
+            .map(|entry| (entry, true))
+            .chain(unpinned.into_iter().map(|entry| (entry, false)))
+            .map(|((i, entry), pinned)| {
+                let mut details = Vec::new();
+                if pinned {
+                    details.push(t!("clipboard.history_pinned").to_string());
+                }
+                let lines = entry.lines().count();
+                if lines > 1 {
+                    details.push(t!("clipboard.history_lines", count = lines).to_string());
+                }
+                let suggestion = Suggestion::new(preview(entry)).with_value(i.to_string());
+                if details.is_empty() {
+                    suggestion
+                } else {
+                    suggestion.with_description(details.join(" · "))
+                }
+            })
+            .collect()
+    }
+
+    /// Leading whitespace of the line the primary cursor is on.
+    fn cursor_line_indent(&self) -> String {
+        let state = self.active_state();
+        let position = self.active_cursors().primary().position;
+        state
+            .buffer
+            .offset_to_position(position)
+            .and_then(|pos| state.buffer.get_line(pos.line))
+            .map(|bytes| {
+                String::from_utf8_lossy(&bytes)
+                    .chars()
+                    .take_while(|c| *c == ' ' || *c == '\t')
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Paste history entry `index` and make it the current clipboard, so a
+    /// plain paste afterwards repeats it.
+    pub(super) fn paste_clipboard_history_entry(&mut self, index: usize) {
+        let Some(text) = self.clipboard.history_entry(index).map(str::to_string) else {
+            return;
+        };
+        self.clipboard.copy(text.clone());
+        self.paste_text(text);
+    }
+
+    /// Replace the text just pasted with the next older history entry,
+    /// wrapping around to the newest after the oldest.
+    pub(super) fn cycle_paste_history(&mut self) {
+        let len = self.clipboard.history_len();
+        let current = self
+            .active_window_mut()
+            .paste_cycle_state
+            .take()
+            .filter(|state| {
+                state.buffer_id == self.active_buffer()
+                    && state.undo_index == self.active_event_log().current_index()
+            });
+        let Some(state) = current.filter(|_| len > 0) else {
+            self.set_status_message(t!("clipboard.no_paste_to_cycle").to_string());
+            return;
+        };
+
+        let next = state.history_index.map_or(0, |i| (i + 1) % len);
+        let Some(text) = self.clipboard.history_entry(next).map(str::to_string) else {
+            return;
+        };
+        // A paste is a single undo step, so undoing it restores the text
+        // and selections it replaced before pasting the next entry.
+        self.handle_undo();
+        self.paste_text(text);
+    }
+}
+
+/// One-line preview of a history entry: its non-blank lines, trimmed and
+/// joined with `⏎`, cut off at [`PREVIEW_CHARS`].
+fn preview(entry: &str) -> String {
+    let line = entry
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ⏎ ");
+    if line.chars().count() > PREVIEW_CHARS {
+        let cut: String = line.chars().take(PREVIEW_CHARS - 1).collect();
+        format!("{cut}…")
+    } else {
+        line
+    }
+}
+
+/// Shift `text` so its least-indented line sits at `indent`.
+///
+/// The first line is pasted at the cursor, which already sits after the
+/// line's indentation, so it only loses the common indentation; later lines
+/// get `indent` in its place. Blank lines are left empty.
+fn reindent(text: &str, indent: &str) -> String {
+    let text = text.replace("\r\n", "\n");
+    let common = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| &line[..line.len() - line.trim_start().len()])
+        .reduce(|a, b| {
+            let len = a
+                .chars()
+                .zip(b.chars())
+                .take_while(|(x, y)| x == y)
+                .map(|(x, _)| x.len_utf8())
+                .sum();
+            &a[..len]
+        })
+        .unwrap_or("");
+
+    text.split('\n')
+        .enumerate()
+        .map(|(i, line)| {
+            if line.trim().is_empty() {
+                String::new()
+            } else {
+                let body = line.strip_prefix(common).unwrap_or(line.trim_start());
+                if i == 0 {
+                    body.to_string()
+                } else {
+                    format!("{indent}{body}")
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,93 @@
+//! Reopen Closed Tab: bring back the most recently closed file.
+//!
+//! `close_buffer_internal` records each file-backed buffer it closes on
+//! [`Window::closed_buffers`] together with its primary cursor, so reopening
+//! lands where the user left off. Unnamed, virtual and terminal buffers have
+//! nothing on disk to reopen and aren't recorded.
+
+use std::path::PathBuf;
+
+use rust_i18n::t;
+
+use super::window::Window;
+use super::Editor;
+use crate::model::event::BufferId;
+
+/// Upper bound on [`Window::closed_buffers`]; the oldest entries fall off.
+const MAX_CLOSED_BUFFERS: usize = 50;
+
+/// A file closed in this session, newest last on the stack.
+#[derive(Debug, Clone)]
+pub(crate) struct ClosedBuffer {
+    path: PathBuf,
+    /// Byte offset of the primary cursor when the buffer was closed.
+    position: usize,
+}
+
+impl Window {
+    /// Push `buffer_id` onto the closed-buffer stack if it is backed by a
+    /// file. Called before the buffer's state is torn down.
+    pub(super) fn remember_closed_buffer(&mut self, buffer_id: BufferId) {
+        if self.terminal_buffers.contains_key(&buffer_id) {
+            return;
+        }
+        let Some(path) = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .filter(|meta| !meta.hidden_from_tabs && !meta.synthetic_placeholder)
+            .and_then(|meta| meta.file_path())
+            .filter(|path| !path.as_os_str().is_empty())
+            .cloned()
+        else {
+            return;
+        };
+        // Prefer the cursor the user last saw: the active split's view of
+        // the buffer, else any split that has it open.
+        let position = self
+            .buffers
+            .splits()
+            .and_then(|(mgr, view_states)| {
+                view_states
+                    .get(&mgr.active_split())
+                    .filter(|vs| vs.has_buffer(buffer_id))
+                    .or_else(|| view_states.values().find(|vs| vs.has_buffer(buffer_id)))
+            })
+            .and_then(|vs| vs.keyed_states.get(&buffer_id))
+            .map(|state| state.cursors.primary().position)
+            .unwrap_or(0);
+
+        self.closed_buffers.retain(|closed| closed.path != path);
+        self.closed_buffers.push(ClosedBuffer { path, position });
+        if self.closed_buffers.len() > MAX_CLOSED_BUFFERS {
+            self.closed_buffers.remove(0);
+        }
+    }
+}
+
+impl Editor {
+    /// Reopen the most recently closed file and put the cursor back where
+    /// it was. Files deleted since are skipped.
+    pub fn reopen_closed_buffer(&mut self) {
+        while let Some(closed) = self.active_window_mut().closed_buffers.pop() {
+            if !closed.path.exists() {
+                continue;
+            }
+            match self.open_file(&closed.path) {
+                Ok(_) => {
+                    self.goto_byte_offset(closed.position);
+                    self.active_window_mut()
+                        .ensure_active_cursor_visible_for_navigation(true);
+                    let name = self.get_buffer_display_name(self.active_buffer());
+                    self.set_status_message(t!("buffer.reopened", name = name).to_string());
+                }
+                Err(e) => {
+                    self.set_status_message(
+                        t!("file.error_opening", error = e.to_string()).to_string(),
+                    );
+                }
+            }
+            return;
+        }
+        self.set_status_message(t!("buffer.nothing_to_reopen").to_string());
+    }
+}
@@ -0,0 +1,245 @@
+//! Documentation panel beside the completion popup.
+//!
+//! With `editor.completion_documentation` on, the selected LSP completion
+//! item's detail and documentation are shown in a markdown panel attached
+//! to the popup. Servers often leave documentation out of the completion
+//! list to keep it small; the first time such an item is selected it is
+//! sent to `completionItem/resolve` and the resolved item replaces the
+//! stored one (so accepting it later also sees resolved
+//! `additionalTextEdits`). The panel scrolls on its own with
+//! `completion_docs_scroll_down` / `completion_docs_scroll_up` or the mouse
+//! wheel, leaving the list selection alone.
+
+use super::lsp_requests::space_doc_paragraphs;
+use super::{CompletionDocs, Editor};
+use crate::view::popup::{Popup, PopupKind, PopupPosition};
+
+/// Width of the documentation panel, borders included.
+const PANEL_WIDTH: u16 = 50;
+/// Height cap of the documentation panel, borders included.
+const PANEL_MAX_HEIGHT: u16 = 15;
+
+/// The markdown shown for a completion item: its detail as a code block,
+/// then its documentation. `None` when it has neither.
+fn completion_docs_markdown(item: &lsp_types::CompletionItem, language: &str) -> Option<String> {
+    let detail = item.detail.as_deref().filter(|d| !d.trim().is_empty());
+    let documentation = item
+        .documentation
+        .as_ref()
+        .map(|doc| match doc {
+            lsp_types::Documentation::String(s) => space_doc_paragraphs(s),
+            lsp_types::Documentation::MarkupContent(m)
+                if m.kind == lsp_types::MarkupKind::PlainText =>
+            {
+                space_doc_paragraphs(&m.value)
+            }
+            lsp_types::Documentation::MarkupContent(m) => m.value.clone(),
+        })
+        .filter(|d| !d.trim().is_empty());
+
+    let mut markdown = String::new();
+    if let Some(detail) = detail {
+        markdown.push_str(&format!("```{}\n{}\n```\n", language, detail));
+    }
+    if let Some(documentation) = documentation {
+        if !markdown.is_empty() {
+            markdown.push_str("\n---\n\n");
+        }
+        markdown.push_str(&documentation);
+    }
+    (!markdown.is_empty()).then_some(markdown)
+}
+
+impl Editor {
+    /// Label of the selected item when the active buffer's topmost popup is
+    /// the completion popup.
+    fn selected_completion_label(&self) -> Option<String> {
+        let popup = self.active_state().popups.top()?;
+        if popup.kind != PopupKind::Completion {
+            return None;
+        }
+        popup.selected_item().map(|item| item.text.clone())
+    }
+
+    /// Forget the documentation state of the previous completion popup.
+    /// Called when a new set of completion items arrives.
+    pub(crate) fn reset_completion_docs(&mut self) {
+        let window = self.active_window_mut();
+        window.completion_docs = None;
+        window.pending_completion_docs_request = None;
+        window.resolved_completion_labels.clear();
+    }
+
+    /// Bring the documentation panel in line with the completion popup's
+    /// selection: keep it if it already describes the selected item, show
+    /// the item's documentation when it is known, or resolve the item.
+    pub(crate) fn update_completion_docs(&mut self) {
+        if !self.config.editor.completion_documentation {
+            return;
+        }
+        let Some(label) = self.selected_completion_label() else {
+            self.active_window_mut().completion_docs = None;
+            return;
+        };
+        if self
+            .active_window()
+            .completion_docs
+            .as_ref()
+            .is_some_and(|docs| docs.label == label)
+        {
+            return;
+        }
+        self.active_window_mut().completion_docs = None;
+
+        let Some(item) = self
+            .active_window()
+            .completion_items
+            .as_ref()
+            .and_then(|items| items.iter().find(|item| item.label == label))
+            .cloned()
+        else {
+            return;
+        };
+
+        let needs_resolve = item.documentation.is_none()
+            && !self
+                .active_window()
+                .resolved_completion_labels
+                .contains(&label)
+            && self.active_window().server_supports_completion_resolve();
+        if !needs_resolve {
+            self.show_completion_docs(&item);
+            return;
+        }
+        if self
+            .active_window()
+            .pending_completion_docs_request
+            .as_ref()
+            .is_some_and(|(_, pending)| *pending == label)
+        {
+            return;
+        }
+        if let Some(request_id) = self.active_window_mut().send_completion_resolve(item) {
+            self.active_window_mut().pending_completion_docs_request = Some((request_id, label));
+        }
+    }
+
+    /// Whether `request_id` is the documentation panel's resolve request.
+    pub(crate) fn is_completion_docs_request(&self, request_id: u64) -> bool {
+        self.active_window()
+            .pending_completion_docs_request
+            .as_ref()
+            .is_some_and(|(id, _)| *id == request_id)
+    }
+
+    /// Handle the response to the documentation panel's resolve request.
+    /// `None` means the request failed; the item is then shown as it was.
+    pub(crate) fn handle_completion_docs_resolved(
+        &mut self,
+        request_id: u64,
+        resolved: Option<lsp_types::CompletionItem>,
+    ) {
+        let Some((_, label)) = self
+            .active_window_mut()
+            .pending_completion_docs_request
+            .take_if(|(id, _)| *id == request_id)
+        else {
+            return;
+        };
+        let window = self.active_window_mut();
+        window.resolved_completion_labels.insert(label.clone());
+        let Some(items) = window.completion_items.as_mut() else {
+            return;
+        };
+        let Some(stored) = items.iter_mut().find(|item| item.label == label) else {
+            return;
+        };
+        // Servers echo the item back; anything else is not a resolution of it.
+        if let Some(resolved) = resolved.filter(|resolved| resolved.label == label) {
+            *stored = resolved;
+        }
+        let item = stored.clone();
+        if self.selected_completion_label().as_deref() == Some(label.as_str()) {
+            self.show_completion_docs(&item);
+        }
+    }
+
+    /// Show `item`'s documentation beside the completion popup, if it has any.
+    fn show_completion_docs(&mut self, item: &lsp_types::CompletionItem) {
+        let language = self.active_state().language.clone();
+        let Some(markdown) = completion_docs_markdown(item, &language) else {
+            return;
+        };
+        let mut panel = {
+            let theme = self.theme();
+            Popup::markdown(&markdown, &theme, Some(&self.grammar_registry))
+        };
+        panel.position = PopupPosition::BelowCursor;
+        panel.width = PANEL_WIDTH;
+        panel.max_height = PANEL_MAX_HEIGHT;
+        self.active_window_mut().completion_docs = Some(CompletionDocs {
+            label: item.label.clone(),
+            panel,
+        });
+    }
+
+    /// Scroll the documentation panel by `delta` lines without touching the
+    /// completion list. Returns false when no panel is shown.
+    pub(crate) fn scroll_completion_docs(&mut self, delta: i32) -> bool {
+        match self.active_window_mut().completion_docs.as_mut() {
+            Some(docs) => {
+                docs.panel.scroll_by(delta);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Scroll the documentation panel by a page (`forward`: down).
+    pub(crate) fn page_completion_docs(&mut self, forward: bool) {
+        let page = i32::from(PANEL_MAX_HEIGHT.saturating_sub(2)).max(1);
+        self.scroll_completion_docs(if forward { page } else { -page });
+    }
+
+    /// The panel to draw beside the completion popup: only while it still
+    /// describes the popup's selected item.
+    pub(crate) fn visible_completion_docs(&self) -> Option<&Popup> {
+        let docs = self.active_window().completion_docs.as_ref()?;
+        (self.selected_completion_label().as_deref() == Some(docs.label.as_str()))
+            .then_some(&docs.panel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn docs_markdown_combines_detail_and_documentation() {
+        let item = lsp_types::CompletionItem {
+            label: "len".to_string(),
+            detail: Some("fn len(&self) -> usize".to_string()),
+            documentation: Some(lsp_types::Documentation::MarkupContent(
+                lsp_types::MarkupContent {
+                    kind: lsp_types::MarkupKind::Markdown,
+                    value: "Returns the **length**.".to_string(),
+                },
+            )),
+            ..Default::default()
+        };
+        assert_eq!(
+            completion_docs_markdown(&item, "rust").unwrap(),
+            "```rust\nfn len(&self) -> usize\n```\n\n---\n\nReturns the **length**."
+        );
+    }
+
+    #[test]
+    fn docs_markdown_is_none_without_detail_or_documentation() {
+        let item = lsp_types::CompletionItem {
+            label: "len".to_string(),
+            detail: Some("  ".to_string()),
+            ..Default::default()
+        };
+        assert!(completion_docs_markdown(&item, "rust").is_none());
+    }
+}
@@ -23,6 +23,7 @@ use crate::model::composite_buffer::{CompositeBuffer, CompositeLayout, LineAlign
 use crate::model::event::{BufferId, LeafId};
 use crate::view::composite_view::CompositeViewState;
 use anyhow::Result as AnyhowResult;
+use std::path::Path;
 use unicode_segmentation::UnicodeSegmentation;
 
 /// Information about the current cursor line needed for movement operations
@@ -546,6 +547,60 @@ impl Editor {
         buffer_id
     }
 
+    /// Open `old` and `new` side by side in a diff view (`fresh --diff`).
+    /// Both files also stay open as ordinary buffers.
+    pub fn open_diff_view(&mut self, old: &Path, new: &Path) -> AnyhowResult<BufferId> {
+        use crate::model::composite_buffer::{DiffHunk, PaneStyle};
+        use crate::model::line_diff::diff_hunks;
+
+        let old_id = self.open_file(old)?;
+        let new_id = self.open_file(new)?;
+        let text = |editor: &Self, id: BufferId| {
+            editor
+                .buffers()
+                .get(&id)
+                .and_then(|state| state.buffer.to_string())
+                .unwrap_or_default()
+        };
+        let old_text = text(self, old_id);
+        let new_text = text(self, new_id);
+        let old_lines: Vec<&[u8]> = old_text.as_bytes().split(|&b| b == b'\n').collect();
+        let new_lines: Vec<&[u8]> = new_text.as_bytes().split(|&b| b == b'\n').collect();
+        let hunks: Vec<DiffHunk> = diff_hunks(&old_lines, &new_lines)
+            .into_iter()
+            .map(|(o, n)| DiffHunk::new(o.start, o.len(), n.start, n.len()))
+            .collect();
+
+        let label = |path: &Path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string())
+        };
+        let sources = vec![
+            SourcePane::new(old_id, label(old), false).with_style(PaneStyle::old_diff()),
+            SourcePane::new(new_id, label(new), false).with_style(PaneStyle::new_diff()),
+        ];
+        let layout = CompositeLayout::SideBySide {
+            ratios: vec![0.5, 0.5],
+            show_separator: true,
+        };
+        let name = format!("*Diff: {} ↔ {}*", label(old), label(new));
+        let buffer_id =
+            self.create_composite_buffer(name, "diff-view".to_string(), layout, sources);
+
+        let alignment = LineAlignment::from_hunks(&hunks, old_lines.len(), new_lines.len());
+        let window = self.active_window_mut();
+        window.set_composite_alignment(buffer_id, alignment);
+        if !hunks.is_empty() {
+            if let Some(composite) = window.composite_buffers.get_mut(&buffer_id) {
+                composite.initial_focus_hunk = Some(0);
+            }
+        }
+
+        self.set_active_buffer(buffer_id);
+        Ok(buffer_id)
+    }
+
     // `set_composite_alignment`, `close_composite_buffer`,
     // `composite_focus_next`, `composite_focus_prev` moved to
     // `impl Window` above. Editor callers reach them via
@@ -0,0 +1,131 @@
+//! Config rescue mode: what happens when the config doesn't load.
+//!
+//! A config file that fails to parse used to leave the editor on defaults
+//! with nothing but a log line. Instead, [`Editor::enter_config_rescue`]
+//! opens the broken file with the parse error as a diagnostic on the
+//! offending line while the editor keeps running on default settings (and
+//! so the default keybindings). Saving the file re-checks it; once it loads,
+//! Reload Config applies it and leaves rescue mode.
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::config_io::{ConfigLoadFailure, ConfigResolver};
+use crate::model::event::BufferId;
+
+/// Diagnostic source for config load errors.
+const CONFIG_DIAGNOSTIC_SOURCE: &str = "config";
+
+/// The config failure the editor is running without, and where its
+/// diagnostic was published.
+pub(crate) struct ConfigRescue {
+    failure: ConfigLoadFailure,
+    /// URI of the buffer carrying the error diagnostic, if the file could
+    /// be opened.
+    uri: Option<String>,
+}
+
+impl Editor {
+    /// Start rescue mode for `failure`: open the broken file and mark the
+    /// error in it. Calling it again replaces the previous failure.
+    pub fn enter_config_rescue(&mut self, failure: ConfigLoadFailure) {
+        let buffer_id = match self.open_file(&failure.path) {
+            Ok(buffer_id) => Some(buffer_id),
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to open broken config {}: {}",
+                    failure.path.display(),
+                    e
+                );
+                None
+            }
+        };
+        self.show_config_failure(failure, buffer_id);
+    }
+
+    /// Whether the editor is running on defaults because the config failed
+    /// to load.
+    pub fn in_config_rescue(&self) -> bool {
+        self.config_rescue.is_some()
+    }
+
+    fn show_config_failure(&mut self, failure: ConfigLoadFailure, buffer_id: Option<BufferId>) {
+        self.clear_config_rescue_diagnostic();
+        let uri = buffer_id
+            .and_then(|id| self.active_window().buffer_metadata.get(&id))
+            .and_then(|meta| meta.file_uri())
+            .map(|uri| uri.as_str().to_string());
+        if let Some(uri) = &uri {
+            let (line, column) = failure
+                .position
+                .map(|(line, column)| (line.saturating_sub(1), column.saturating_sub(1)))
+                .unwrap_or((0, 0));
+            let start = lsp_types::Position::new(line as u32, column as u32);
+            let end = lsp_types::Position::new(line as u32, column as u32 + 1);
+            let diagnostic = lsp_types::Diagnostic {
+                range: lsp_types::Range { start, end },
+                severity: Some(lsp_types::DiagnosticSeverity::ERROR),
+                source: Some(CONFIG_DIAGNOSTIC_SOURCE.to_string()),
+                message: t!("config.rescue_diagnostic", error = failure.message).to_string(),
+                ..Default::default()
+            };
+            self.store_push_diagnostics(
+                uri.clone(),
+                CONFIG_DIAGNOSTIC_SOURCE.to_string(),
+                vec![diagnostic],
+            );
+        }
+        self.set_status_message(
+            t!(
+                "config.rescue",
+                path = failure.path.display().to_string(),
+                error = failure.message
+            )
+            .to_string(),
+        );
+        self.config_rescue = Some(ConfigRescue { failure, uri });
+    }
+
+    fn clear_config_rescue_diagnostic(&mut self) {
+        if let Some(uri) = self.config_rescue.as_mut().and_then(|r| r.uri.take()) {
+            self.store_push_diagnostics(uri, CONFIG_DIAGNOSTIC_SOURCE.to_string(), Vec::new());
+        }
+    }
+
+    /// After a save of the broken config file, check the config again:
+    /// clear the error and point at Reload Config once it loads, or show the
+    /// error it has now.
+    pub(super) fn recheck_config_rescue(&mut self, buffer_id: BufferId) {
+        let Some(rescue) = &self.config_rescue else {
+            return;
+        };
+        let rescue_path = rescue.failure.path.clone();
+        let saved_uri = self
+            .active_window()
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|meta| meta.file_uri())
+            .map(|uri| uri.as_str().to_string());
+        if rescue.uri.is_none() || rescue.uri != saved_uri {
+            return;
+        }
+        let resolver =
+            ConfigResolver::new(self.dir_context.clone(), self.working_dir().to_path_buf());
+        match resolver.resolve_or_failure() {
+            Ok(_) => {
+                self.clear_config_rescue_diagnostic();
+                self.set_status_message(t!("config.rescue_fixed").to_string());
+            }
+            Err(failure) if failure.path == rescue_path => {
+                self.show_config_failure(failure, Some(buffer_id));
+            }
+            Err(failure) => self.enter_config_rescue(failure),
+        }
+    }
+
+    /// Leave rescue mode after the config loaded again.
+    pub(super) fn end_config_rescue(&mut self) {
+        self.clear_config_rescue_diagnostic();
+        self.config_rescue = None;
+    }
+}
@@ -0,0 +1,149 @@
+//! Hot reload of the config files.
+//!
+//! The user config (plus its platform variant) and the project config are
+//! polled on the auto-revert interval. When one of them is written — from a
+//! fresh buffer or by another program — the layers are resolved again and,
+//! if that changes the effective settings, applied the same way Reload
+//! Config does. A file that no longer loads keeps the current settings and
+//! reports the error in a popup.
+
+use std::path::PathBuf;
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::{Instant, SystemTime};
+
+use rust_i18n::t;
+
+use crate::config::Config;
+use crate::config_io::ConfigResolver;
+
+use super::Editor;
+
+/// `(path, mtime, size)` of every watched config file, in watch order.
+/// Missing files are recorded as `None` so creating or deleting one counts
+/// as a change.
+type ConfigFingerprint = Vec<(PathBuf, Option<(Option<SystemTime>, u64)>)>;
+
+/// Polling state for the config layer files.
+#[derive(Default)]
+pub(super) struct ConfigFileWatch {
+    /// When the last poll was launched (`None` until the first poll).
+    last_poll: Option<Instant>,
+    /// Snapshot the current config was loaded from. The first poll takes
+    /// it synchronously, so an edit made right after startup is still seen
+    /// as a change.
+    fingerprint: Option<ConfigFingerprint>,
+    /// Result of an in-flight background poll.
+    pending_rx: Option<Receiver<ConfigFingerprint>>,
+}
+
+impl Editor {
+    /// Poll the config files for changes (called from main loop).
+    ///
+    /// Like [`Editor::poll_theme_changes`], the files are stat'ed on a
+    /// background thread. Returns true if the config was reloaded or an
+    /// error was reported (requires re-render).
+    pub fn poll_config_changes(&mut self) -> bool {
+        let mut changed = false;
+        if let Some(ref rx) = self.config_file_watch.pending_rx {
+            match rx.try_recv() {
+                Ok(fingerprint) => {
+                    self.config_file_watch.pending_rx = None;
+                    changed = self.process_config_poll_result(fingerprint);
+                }
+                Err(TryRecvError::Empty) => return false,
+                Err(TryRecvError::Disconnected) => {
+                    self.config_file_watch.pending_rx = None;
+                }
+            }
+        }
+
+        let poll_interval =
+            std::time::Duration::from_millis(self.config.editor.auto_revert_poll_interval_ms);
+        if let Some(last_poll) = self.config_file_watch.last_poll {
+            if self.time_source.elapsed_since(last_poll) < poll_interval {
+                return changed;
+            }
+        }
+        self.config_file_watch.last_poll = Some(self.time_source.now());
+
+        let paths = self.watched_config_paths();
+        if self.config_file_watch.fingerprint.is_none() {
+            self.config_file_watch.fingerprint = Some(fingerprint_files(paths));
+            return changed;
+        }
+        let (tx, rx) = std::sync::mpsc::channel();
+        std::thread::Builder::new()
+            .name("poll-config-changes".to_string())
+            .spawn(move || {
+                let fingerprint = fingerprint_files(paths);
+                // Receiver is gone if the editor is shutting down.
+                if tx.send(fingerprint).is_err() {}
+            })
+            .ok();
+        self.config_file_watch.pending_rx = Some(rx);
+
+        changed
+    }
+
+    /// The layer files a hot reload reacts to. The session layer is left
+    /// out: only the editor itself writes it.
+    fn watched_config_paths(&self) -> Vec<PathBuf> {
+        let resolver =
+            ConfigResolver::new(self.dir_context.clone(), self.working_dir().to_path_buf());
+        let mut paths = vec![resolver.user_config_path()];
+        paths.extend(resolver.user_platform_config_path());
+        paths.push(resolver.project_config_path());
+        paths
+    }
+
+    /// Compare a fresh snapshot against the last one and reload on change.
+    fn process_config_poll_result(&mut self, fingerprint: ConfigFingerprint) -> bool {
+        if self.config_file_watch.fingerprint.as_ref() == Some(&fingerprint) {
+            return false;
+        }
+        self.config_file_watch.fingerprint = Some(fingerprint);
+
+        let resolver =
+            ConfigResolver::new(self.dir_context.clone(), self.working_dir().to_path_buf());
+        match resolver.resolve_or_failure() {
+            Ok(config) => {
+                // Saves from the Settings UI and settings toggles land here
+                // too; they already match what the editor runs on.
+                if !self.in_config_rescue() && same_settings(&config, &self.config) {
+                    return false;
+                }
+                self.reload_config();
+                tracing::info!("Config reloaded after a change on disk");
+                self.set_status_message(t!("config.reloaded").to_string());
+                true
+            }
+            Err(failure) => {
+                tracing::warn!("Not reloading changed config: {}", failure.error);
+                self.show_config_reload_error_popup(&failure);
+                true
+            }
+        }
+    }
+}
+
+/// Whether two configs hold the same settings. `Config` has no
+/// `PartialEq`, so compare the serialized forms.
+fn same_settings(a: &Config, b: &Config) -> bool {
+    match (serde_json::to_value(a), serde_json::to_value(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Stat every path in `paths`.
+fn fingerprint_files(paths: Vec<PathBuf>) -> ConfigFingerprint {
+    paths
+        .into_iter()
+        .map(|path| {
+            let stat = std::fs::metadata(&path)
+                .ok()
+                .map(|m| (m.modified().ok(), m.len()));
+            (path, stat)
+        })
+        .collect()
+}
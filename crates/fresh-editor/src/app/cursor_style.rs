@@ -0,0 +1,52 @@
+//! Terminal cursor style per context on `Editor`.
+//!
+//! The cursor shape follows what the primary cursor is doing — typing,
+//! selecting, or sitting in a modal normal mode — as configured by the
+//! `cursor_style*` settings. The style is recomputed every frame and only
+//! written to the terminal when it changes.
+
+use crate::config::{CursorContext, CursorStyle};
+
+use super::Editor;
+
+impl Editor {
+    /// What the primary cursor is doing, for picking its style. A prompt
+    /// counts as editing, since the cursor is in its input line.
+    pub fn cursor_context(&self) -> CursorContext {
+        if self.is_prompting() {
+            return CursorContext::Editing;
+        }
+        let normal_mode = self
+            .active_window()
+            .editor_mode
+            .as_deref()
+            .is_some_and(|mode| self.mode_registry.is_read_only(mode));
+        if normal_mode {
+            return CursorContext::NormalMode;
+        }
+        let primary = self.active_cursors().primary();
+        let has_selection = primary.block_anchor.is_some()
+            || primary.selection_range().is_some_and(|r| !r.is_empty());
+        if has_selection {
+            CursorContext::Selection
+        } else {
+            CursorContext::Editing
+        }
+    }
+
+    /// The terminal cursor style for the current context.
+    pub fn cursor_style(&self) -> CursorStyle {
+        self.config.editor.cursor_style_for(self.cursor_context())
+    }
+
+    /// The cursor style to send to the terminal, when it differs from the
+    /// one sent last. Call once per frame.
+    pub fn take_cursor_style_change(&mut self) -> Option<CursorStyle> {
+        let style = self.cursor_style();
+        if self.applied_cursor_style == Some(style) {
+            return None;
+        }
+        self.applied_cursor_style = Some(style);
+        Some(style)
+    }
+}
@@ -7,13 +7,20 @@
 use super::{DabbrevCycleState, Editor};
 use crate::model::event::Event;
 use crate::services::completion::dabbrev::DabbrevProvider;
+use crate::services::completion::project_words::MAX_FILE_BYTES;
 use crate::services::completion::provider::{
-    CompletionContext, CompletionProvider, OtherBufferSlice, ProviderResult,
+    CompletionContext, CompletionProvider, OtherBufferSlice, ProjectWord, ProviderResult,
 };
 
 /// Scan radius for other-buffer slices during dabbrev.
 const OTHER_BUFFER_SCAN_RADIUS: usize = 64 * 1024; // 64 KB
 
+/// Project-index words handed to the buffer-word provider per request.
+const MAX_PROJECT_WORDS: usize = 100;
+
+/// Tags-file names handed to the buffer-word provider per request.
+const MAX_TAG_WORDS: usize = 100;
+
 impl Editor {
     /// Handle the DabbrevExpand action (Alt+/).
     ///
@@ -123,6 +130,8 @@ impl Editor {
             word_chars_extra,
             prefix_has_uppercase: prefix_has_upper,
             other_buffers,
+            project_words: Vec::new(),
+            tag_words: Vec::new(),
         };
 
         let provider = DabbrevProvider::new();
@@ -204,6 +213,85 @@ impl Editor {
         slices
     }
 
+    /// Words from the project word index matching `prefix`, nearest to the
+    /// active file first. The first call for a project starts indexing it in
+    /// the background, so early requests may come back empty.
+    fn collect_project_words(&self, prefix: &str, prefix_has_uppercase: bool) -> Vec<ProjectWord> {
+        if !self.config.editor.project_word_completion {
+            return Vec::new();
+        }
+        let window = self.active_window();
+        window.project_words.ensure_built(
+            window.authority().filesystem.clone(),
+            self.local_filesystem.clone(),
+            &window.root,
+            self.dir_context
+                .project_state_dir(&window.root)
+                .join("word_index.json"),
+            true,
+        );
+        let from = self.project_relative_path(self.active_buffer());
+        window.project_words.matching(
+            prefix,
+            prefix_has_uppercase,
+            from.as_deref(),
+            MAX_PROJECT_WORDS,
+        )
+    }
+
+    /// Names from the project's tags file matching `prefix`.
+    fn collect_tag_words(&mut self, prefix: &str, prefix_has_uppercase: bool) -> Vec<String> {
+        if !self.config.editor.tag_word_completion {
+            return Vec::new();
+        }
+        self.active_window_mut().tag_index().names_matching(
+            prefix,
+            prefix_has_uppercase,
+            MAX_TAG_WORDS,
+        )
+    }
+
+    /// Fold a just-saved buffer into the project word index.
+    pub(crate) fn update_project_words_for_saved(
+        &mut self,
+        buffer_id: crate::model::event::BufferId,
+    ) {
+        let Some(rel_path) = self.project_relative_path(buffer_id) else {
+            return;
+        };
+        let window = self.active_window();
+        let Some(state) = window.buffers.get(&buffer_id) else {
+            return;
+        };
+        let Some(path) = state.buffer.file_path() else {
+            return;
+        };
+        if state.buffer.len() as u64 > MAX_FILE_BYTES {
+            return;
+        }
+        let text = state.buffer.slice_bytes(0..state.buffer.len());
+        window.project_words.update_file(
+            window.authority().filesystem.as_ref(),
+            path,
+            &rel_path,
+            &String::from_utf8_lossy(&text),
+        );
+    }
+
+    /// `buffer_id`'s file path relative to the window root, `/`-separated
+    /// like the paths in the project word index.
+    fn project_relative_path(&self, buffer_id: crate::model::event::BufferId) -> Option<String> {
+        let window = self.active_window();
+        let path = window.buffers.get(&buffer_id)?.buffer.file_path()?;
+        let rel = path.strip_prefix(&window.root).ok()?;
+        Some(
+            rel.components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+        )
+    }
+
     /// Reset the dabbrev cycling session. Called when any non-dabbrev action
     /// is taken (typing, moving cursor, etc.).
     pub(crate) fn reset_dabbrev_state(&mut self) {
@@ -212,8 +300,8 @@ impl Editor {
 
     /// Run the `CompletionService` (buffer-words + dabbrev providers) and
     /// return results as `PopupListItemData` items suitable for the
-    /// completion popup. The items use icon `"w"` to visually distinguish
-    /// them from LSP results.
+    /// completion popup. Word items use icon `"w"` and user snippets `"s"`
+    /// to visually distinguish them from LSP results.
     ///
     /// Returns an empty vec if the prefix is empty or no candidates match.
     pub(crate) fn get_buffer_completion_popup_items(
@@ -266,6 +354,8 @@ impl Editor {
         let viewport_bottom_byte = (viewport_top_byte + 8192).min(buffer_len);
 
         let prefix_has_upper = prefix.chars().any(|c| c.is_uppercase());
+        let project_words = self.collect_project_words(&prefix, prefix_has_upper);
+        let tag_words = self.collect_tag_words(&prefix, prefix_has_upper);
 
         let ctx = CompletionContext {
             prefix,
@@ -276,10 +366,12 @@ impl Editor {
             scan_range,
             viewport_top_byte,
             viewport_bottom_byte,
-            language_id: None,
+            language_id: Some(self.active_state().language.clone()),
             word_chars_extra,
             prefix_has_uppercase: prefix_has_upper,
             other_buffers,
+            project_words,
+            tag_words,
         };
 
         let candidates = self
@@ -292,8 +384,9 @@ impl Editor {
             .map(|c| PopupListItemData {
                 text: c.label.clone(),
                 detail: c.detail.clone(),
-                icon: Some("w".to_string()),
+                icon: c.icon.or(Some("w".to_string())),
                 data: c.insert_text.or(Some(c.label)),
+                match_positions: Vec::new(),
             })
             .collect()
     }
@@ -52,6 +52,7 @@ impl Editor {
     /// Also propagates the new `Arc` to every window's
     /// `resources.config`, so window-scoped reads see the swap.
     pub fn set_config(&mut self, new_config: Config) {
+        crate::services::http::set_offline(new_config.offline);
         self.config = Arc::new(new_config);
         self.sync_windows_config();
     }
@@ -296,7 +297,7 @@ impl Editor {
 
     /// The earliest wall-clock deadline at which the main event loop
     /// needs to wake up and re-render, *purely because of internal
-    /// time-driven UI elements* (animations, the LSP status-bar
+    /// time-driven UI elements* (animations, smooth scrolling, the LSP status-bar
     /// spinner). Returns `None` when no time-driven UI is in flight —
     /// the loop can sleep until the next user / async event without
     /// missing a frame.
@@ -323,15 +324,22 @@ impl Editor {
         // exactly when the timeout needs to fire, so a hung clipboard
         // owner can't block the UI past `PASTE_ASYNC_DEADLINE`.
         let paste_deadline = self.next_paste_deadline();
+        // A smooth wheel scroll needs a frame for each of its steps.
+        let smooth_scroll_deadline = self.active_window().smooth_scroll_deadline();
         // Note: the terminal-title poll deadline is intentionally NOT folded
         // in here. This deadline path caps the loop's wait to one frame
         // (~16ms) for smooth animation, which would turn the ~1s title poll
         // into a 60Hz busy loop. The loop's existing 50ms idle poll is fine
         // granularity to notice `terminal_titles_need_poll` going true.
-        [lsp_progress_deadline, anim_deadline, paste_deadline]
-            .into_iter()
-            .flatten()
-            .min()
+        [
+            lsp_progress_deadline,
+            anim_deadline,
+            paste_deadline,
+            smooth_scroll_deadline,
+        ]
+        .into_iter()
+        .flatten()
+        .min()
     }
 
     /// Earliest time a terminal tab needs its foreground-process title
@@ -1475,7 +1483,7 @@ impl Editor {
         }
 
         // Trigger the completion request
-        self.request_completion();
+        self.request_completion(None);
 
         true
     }
@@ -604,11 +604,21 @@ impl Editor {
             // Trivial defaults (no external dependencies):
             remote_reconnect_forwarders: std::collections::HashSet::new(),
             remote_connected_cache: HashMap::new(),
+            file_preview: None,
             materialize_pending: std::collections::HashSet::new(),
             grammar_reload_pending: false,
             grammar_build_in_progress: false,
             pending_grammar_callbacks: Vec::new(),
             expanded_menus_cache: crate::view::ui::ExpandedMenusCache::default(),
+            session_theme: None,
+            pending_screen_dump: None,
+            screenshot_on_exit: None,
+            no_upgrade_check: false,
+            last_rendered_frame: None,
+            theme_dir_watch: super::theme_reload::ThemeDirWatch::new(
+                &parts.dir_context.themes_dir(),
+            ),
+            config_file_watch: Default::default(),
             ansi_background: None,
             ansi_background_path: None,
             background_fade: crate::primitives::ansi_background::DEFAULT_BACKGROUND_FADE,
@@ -622,6 +632,9 @@ impl Editor {
             software_cursor_only: false,
             session_name: None,
             pending_escape_sequences: Vec::new(),
+            applied_cursor_style: None,
+            perf_hud: Default::default(),
+            startup_profile: None,
             restart_with_dir: None,
             last_window_title: None,
             mode_registry: ModeRegistry::new(),
@@ -630,6 +643,14 @@ impl Editor {
             remote_indicator_override: None,
             menus: crate::config::MenuConfig::translated(),
             background_process_handles: HashMap::new(),
+            jobs: std::collections::BTreeMap::new(),
+            next_hook_job: 0,
+            buffer_share: None,
+            config_rescue: None,
+            hook_active_buffer: None,
+            idle_hooks: HashMap::new(),
+            #[cfg(feature = "scripting")]
+            scripts: Default::default(),
             host_process_handles: HashMap::new(),
             status_bar_token_registry: Mutex::new(HashMap::new()),
             plugin_schemas: std::sync::Arc::new(std::sync::RwLock::new(parts.plugin_schemas)),
@@ -640,12 +661,16 @@ impl Editor {
             plugin_render_requested: false,
             full_redraw_requested: false,
             suppress_chrome_cells: false,
+            ime_preedit: None,
+            dead_keys: Default::default(),
             suspend_requested: false,
             plugin_global_state: parts.plugin_global_state,
             // Boot-loaded state came *from* disk — nothing is dirty yet.
             plugin_global_dirty: HashMap::new(),
             warning_log: None,
             status_log_path: None,
+            editor_log_path: None,
+            editor_log_tail: None,
             self_update_phase: crate::services::release_checker::SelfUpdatePhase::default(),
             self_update_terminal: None,
             self_update_output: None,
@@ -656,6 +681,12 @@ impl Editor {
             preview_window_id: None,
             settings_state: None,
             calibration_wizard: None,
+            terminal_capabilities: crate::services::terminal_probe::TerminalCapabilities::unprobed(
+                parts.color_capability,
+            ),
+            image_placement: None,
+            shown_image: None,
+            presentation: None,
             // event_debug moved to Window
             keybinding_editor: None,
             stdin_stream: stdin_stream::StdinStream::default(),
@@ -1084,6 +1115,8 @@ impl Editor {
             wrap_column: config.editor.wrap_column,
             rulers: config.editor.rulers.clone(),
             scroll_offset: config.editor.scroll_offset,
+            scroll_past_end: config.editor.scroll_past_end,
+            typewriter_scrolling: config.editor.typewriter_scrolling,
         });
         split_view_states.insert(initial_split_id, initial_view_state);
 
@@ -1188,24 +1221,38 @@ impl Editor {
         // Extract config values before moving config into the struct
         let recovery_enabled = config.editor.recovery_enabled;
         let check_for_updates = config.check_for_updates;
-
-        // Start periodic update checker if enabled (also sends daily telemetry)
-        let update_checker = if check_for_updates {
+        // Telemetry only goes out once the user has said yes to it.
+        let send_telemetry = config.telemetry == Some(true);
+        crate::services::http::set_offline(config.offline);
+
+        // Start periodic update checker if enabled (the daily telemetry ping
+        // rides along with it, or goes out on its own when checks are off)
+        let update_checker = if config.offline {
+            tracing::debug!("Offline mode, skipping update check");
+            None
+        } else if check_for_updates {
             tracing::debug!("Update checking enabled, starting periodic checker");
             Some(
                 crate::services::release_checker::start_periodic_update_check(
                     crate::services::release_checker::DEFAULT_RELEASES_URL,
+                    send_telemetry,
                     time_source.clone(),
                     dir_context.data_dir.clone(),
                 ),
             )
         } else {
             tracing::debug!("Update checking disabled by config");
+            if send_telemetry {
+                crate::services::telemetry::start_daily_ping(
+                    time_source.clone(),
+                    dir_context.data_dir.clone(),
+                );
+            }
             None
         };
 
         // Cache raw user config at startup (to avoid re-reading file every frame)
-        let user_config_raw = Config::read_user_config_raw(&working_dir);
+        let user_config_raw = Config::read_user_config_raw(&dir_context, &working_dir);
 
         // Wrap config in Arc and pre-seed the snapshot mirror + JSON cache.
         // Doing this at construction means the strong count of the live
@@ -1469,7 +1516,7 @@ impl Editor {
 
         t.phase("editor_struct_assembly");
         // Apply clipboard configuration
-        editor.clipboard.apply_config(&editor.config.clipboard);
+        editor.apply_clipboard_config();
 
         // Seed splits/buffers for every persisted inactive window so they
         // render in preview surfaces (Orchestrator's WindowEmbed) before the
@@ -1519,6 +1566,8 @@ impl Editor {
                 );
             }
         }
+        #[cfg(feature = "scripting")]
+        editor.load_user_scripts(false);
         t.phase("post_struct_hooks");
         t.finish();
         Ok(editor)
@@ -1784,7 +1833,7 @@ impl Editor {
                     .write()
                     .unwrap()
                     .reload_from_config(&self.config);
-                self.clipboard.apply_config(&self.config.clipboard);
+                self.apply_clipboard_config();
                 {
                     let cfg = self.config.editor.clone();
                     let win = self.active_window_mut();
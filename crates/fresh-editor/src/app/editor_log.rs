@@ -0,0 +1,440 @@
+//! Show Editor Log: the editor's own tracing log in a read-only panel.
+//!
+//! The panel tails the log file main.rs hands over with
+//! [`Editor::set_editor_log_path`]: it starts with the last megabyte and
+//! picks up new lines every half second while it is open. Lines are
+//! colored by level, and `m` in the panel filters them by module (the
+//! tracing target, e.g. `fresh::services::lsp`).
+
+use std::collections::VecDeque;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use rust_i18n::t;
+
+use crate::app::Editor;
+use crate::input::buffer_mode::BufferMode;
+use crate::input::commands::Suggestion;
+use crate::input::keybindings::{Action, KeyContext};
+use crate::model::event::BufferId;
+use crate::primitives::ansi::strip_ansi_codes;
+use crate::primitives::text_property::TextPropertyEntry;
+use crate::view::prompt::{Prompt, PromptType};
+use fresh_core::api::{OverlayColorSpec, OverlayOptions};
+
+/// Display name of the panel buffer.
+const EDITOR_LOG_BUFFER_NAME: &str = "*Editor Log*";
+
+/// Mode carried on the panel buffer, for its `m` / `q` bindings.
+const EDITOR_LOG_MODE: &str = "editor-log";
+
+/// How much of an existing log the panel starts with.
+const INITIAL_TAIL_BYTES: u64 = 1024 * 1024;
+
+/// Lines kept in the panel; older ones scroll off.
+const MAX_LOG_LINES: usize = 5000;
+
+/// How often an open panel checks the file for new lines. Rendering the
+/// panel logs too, so polling on every loop would keep it busy.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A log line's level, as tracing's formatter writes it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    fn parse(word: &str) -> Option<Self> {
+        match word {
+            "TRACE" => Some(Self::Trace),
+            "DEBUG" => Some(Self::Debug),
+            "INFO" => Some(Self::Info),
+            "WARN" => Some(Self::Warn),
+            "ERROR" => Some(Self::Error),
+            _ => None,
+        }
+    }
+
+    fn theme_key(self) -> Option<&'static str> {
+        match self {
+            Self::Trace | Self::Debug => Some("editor.line_number_fg"),
+            Self::Info => None,
+            Self::Warn => Some("diagnostic.warning_fg"),
+            Self::Error => Some("diagnostic.error_fg"),
+        }
+    }
+}
+
+/// One line of the log with its level and module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct LogLine {
+    level: Option<LogLevel>,
+    module: String,
+    text: String,
+}
+
+impl LogLine {
+    /// Parse a line of tracing's default format,
+    /// `<timestamp> <LEVEL> [spans: ]<module>: <message>`. A line that
+    /// doesn't start that way continues a multi-line message and takes
+    /// the level and module of the line before it.
+    fn parse(raw: &str, previous: Option<&LogLine>) -> Self {
+        let text = strip_ansi_codes(raw);
+        let mut words = text.split_whitespace();
+        let level = words.nth(1).and_then(LogLevel::parse);
+        let Some(level) = level else {
+            return Self {
+                level: previous.and_then(|p| p.level),
+                module: previous.map(|p| p.module.clone()).unwrap_or_default(),
+                text,
+            };
+        };
+        let rest = text
+            .trim_start()
+            .split_once(char::is_whitespace)
+            .map(|(_, rest)| rest.trim_start())
+            .and_then(|rest| rest.split_once(char::is_whitespace))
+            .map(|(_, rest)| rest)
+            .unwrap_or_default();
+        // Spans come before the module; the module is the first segment
+        // that is a path (`a::b`), or failing that a bare identifier.
+        let is_ident = |s: &str| {
+            !s.is_empty()
+                && s.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+        };
+        let segments: Vec<&str> = rest.split(": ").map(str::trim).collect();
+        let candidates = &segments[..segments.len().saturating_sub(1)];
+        let module = candidates
+            .iter()
+            .find(|s| s.contains("::") && is_ident(s))
+            .or_else(|| candidates.iter().find(|s| is_ident(s)))
+            .map(|s| s.to_string())
+            .unwrap_or_default();
+        Self {
+            level: Some(level),
+            module,
+            text,
+        }
+    }
+}
+
+/// What the open panel has read of the log file.
+pub(super) struct EditorLogTail {
+    /// Byte offset read up to.
+    offset: u64,
+    /// Bytes after the last newline, completed by the next read.
+    partial: Vec<u8>,
+    lines: VecDeque<LogLine>,
+    /// Only lines whose module contains this are shown; empty shows all.
+    module_filter: String,
+    last_poll: Instant,
+}
+
+impl EditorLogTail {
+    fn new(path: &PathBuf) -> std::io::Result<Self> {
+        let len = std::fs::metadata(path)?.len();
+        let mut tail = Self {
+            offset: len.saturating_sub(INITIAL_TAIL_BYTES),
+            partial: Vec::new(),
+            lines: VecDeque::new(),
+            module_filter: String::new(),
+            last_poll: Instant::now(),
+        };
+        let starts_mid_line = tail.offset > 0;
+        tail.read_new(path)?;
+        if starts_mid_line {
+            tail.lines.pop_front();
+        }
+        Ok(tail)
+    }
+
+    /// Read what was appended since the last read. Returns whether any
+    /// complete lines came in.
+    fn read_new(&mut self, path: &PathBuf) -> std::io::Result<bool> {
+        let mut file = std::fs::File::open(path)?;
+        let len = file.metadata()?.len();
+        if len < self.offset {
+            // Truncated or replaced: start over.
+            self.offset = 0;
+            self.partial.clear();
+        }
+        if len == self.offset {
+            return Ok(false);
+        }
+        file.seek(SeekFrom::Start(self.offset))?;
+        let mut bytes = std::mem::take(&mut self.partial);
+        let read = file.take(len - self.offset).read_to_end(&mut bytes)?;
+        self.offset += read as u64;
+
+        let complete = match bytes.iter().rposition(|&b| b == b'\n') {
+            Some(end) => {
+                self.partial = bytes.split_off(end + 1);
+                bytes
+            }
+            None => {
+                self.partial = bytes;
+                return Ok(false);
+            }
+        };
+        for raw in String::from_utf8_lossy(&complete).lines() {
+            let line = LogLine::parse(raw, self.lines.back());
+            if self.lines.len() == MAX_LOG_LINES {
+                self.lines.pop_front();
+            }
+            self.lines.push_back(line);
+        }
+        Ok(true)
+    }
+
+    fn entries(&self, path: &std::path::Path) -> Vec<TextPropertyEntry> {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let header = if self.module_filter.is_empty() {
+            t!("editor_log.header", file = name)
+        } else {
+            t!(
+                "editor_log.header_filtered",
+                file = name,
+                module = self.module_filter
+            )
+        };
+        let mut entries = vec![TextPropertyEntry::text(format!("{header}\n\n"))];
+        for line in self
+            .lines
+            .iter()
+            .filter(|line| line.module.contains(&self.module_filter))
+        {
+            let mut entry = TextPropertyEntry::text(format!("{}\n", line.text));
+            entry.style = line
+                .level
+                .and_then(LogLevel::theme_key)
+                .map(|key| OverlayOptions {
+                    fg: Some(OverlayColorSpec::ThemeKey(key.to_string())),
+                    ..Default::default()
+                });
+            entries.push(entry);
+        }
+        entries
+    }
+
+    /// Modules seen in the log, for the filter prompt.
+    fn modules(&self) -> Vec<String> {
+        let mut modules: Vec<String> = self
+            .lines
+            .iter()
+            .filter(|line| !line.module.is_empty())
+            .map(|line| line.module.clone())
+            .collect();
+        modules.sort();
+        modules.dedup();
+        modules
+    }
+}
+
+impl Editor {
+    /// Set the editor's tracing log file, shown by **Show Editor Log**.
+    pub fn set_editor_log_path(&mut self, path: PathBuf) {
+        self.editor_log_path = Some(path);
+    }
+
+    /// Open the *Editor Log* panel, or switch to it.
+    pub(super) fn show_editor_log(&mut self) {
+        let Some(path) = self.editor_log_path.clone() else {
+            self.set_status_message(t!("editor_log.unavailable").to_string());
+            return;
+        };
+        if self.editor_log_buffer().is_none() || self.editor_log_tail.is_none() {
+            match EditorLogTail::new(&path) {
+                Ok(tail) => self.editor_log_tail = Some(tail),
+                Err(e) => {
+                    self.set_status_error(
+                        t!("editor_log.read_failed", error = e.to_string()).to_string(),
+                    );
+                    return;
+                }
+            }
+        }
+        self.ensure_editor_log_mode_registered();
+        let buffer_id = match self.editor_log_buffer() {
+            Some(id) => id,
+            None => {
+                let window = self.active_window_mut();
+                let id = window.create_virtual_buffer(
+                    EDITOR_LOG_BUFFER_NAME.to_string(),
+                    EDITOR_LOG_MODE.to_string(),
+                    true,
+                );
+                if let Some(state) = window.buffers.get_mut(&id) {
+                    state.editing_disabled = true;
+                    state.margins.configure_for_line_numbers(false);
+                }
+                id
+            }
+        };
+        self.refresh_editor_log(buffer_id);
+        self.set_active_buffer(buffer_id);
+        let len = self.active_state().buffer.len();
+        self.goto_byte_offset(len);
+    }
+
+    /// Pick up new log lines while the panel is open. Returns true when
+    /// the panel changed.
+    pub fn poll_editor_log(&mut self) -> bool {
+        let Some(tail) = self.editor_log_tail.as_mut() else {
+            return false;
+        };
+        if tail.last_poll.elapsed() < POLL_INTERVAL {
+            return false;
+        }
+        tail.last_poll = Instant::now();
+        let Some(buffer_id) = self.editor_log_buffer() else {
+            self.editor_log_tail = None;
+            return false;
+        };
+        let (Some(path), Some(tail)) =
+            (self.editor_log_path.as_ref(), self.editor_log_tail.as_mut())
+        else {
+            return false;
+        };
+        match tail.read_new(path) {
+            Ok(true) => {}
+            Ok(false) => return false,
+            Err(e) => {
+                tracing::debug!("Failed to read the editor log: {}", e);
+                return false;
+            }
+        }
+        // Keep following the end if that's where the cursor was.
+        let following = self.active_buffer() == buffer_id
+            && self.active_cursors().primary().position == self.active_state().buffer.len();
+        self.refresh_editor_log(buffer_id);
+        if following {
+            let len = self.active_state().buffer.len();
+            self.goto_byte_offset(len);
+        }
+        true
+    }
+
+    /// Prompt for the module to filter the panel by.
+    pub(super) fn start_editor_log_filter_prompt(&mut self) {
+        let Some(tail) = self.editor_log_tail.as_ref() else {
+            self.set_status_message(t!("editor_log.not_open").to_string());
+            return;
+        };
+        let suggestions = tail.modules().into_iter().map(Suggestion::new).collect();
+        let current = tail.module_filter.clone();
+        let mut prompt = Prompt::with_suggestions(
+            t!("editor_log.filter_prompt").to_string(),
+            PromptType::FilterEditorLog,
+            suggestions,
+        );
+        // Confirm what is typed, so an empty input clears the filter.
+        prompt.sync_input_on_navigate = true;
+        prompt.set_input(current);
+        self.active_window_mut().prompt = Some(prompt);
+    }
+
+    /// Show only lines whose module contains `module`; empty shows all.
+    pub(super) fn set_editor_log_filter(&mut self, module: &str) {
+        let Some(tail) = self.editor_log_tail.as_mut() else {
+            return;
+        };
+        tail.module_filter = module.trim().to_string();
+        if let Some(buffer_id) = self.editor_log_buffer() {
+            self.refresh_editor_log(buffer_id);
+        }
+    }
+
+    fn editor_log_buffer(&self) -> Option<BufferId> {
+        self.active_window()
+            .buffer_metadata
+            .iter()
+            .find(|(_, meta)| meta.virtual_mode() == Some(EDITOR_LOG_MODE))
+            .map(|(id, _)| *id)
+    }
+
+    fn refresh_editor_log(&mut self, buffer_id: BufferId) {
+        let (Some(path), Some(tail)) = (&self.editor_log_path, &self.editor_log_tail) else {
+            return;
+        };
+        let entries = tail.entries(path);
+        if let Err(e) = self.set_virtual_buffer_content(buffer_id, entries) {
+            tracing::debug!("Failed to refresh the editor log panel: {}", e);
+        }
+    }
+
+    /// Bind `m` (filter by module) and `q` (close) in the panel.
+    /// Idempotent, like `ensure_help_panel_mode_registered`.
+    fn ensure_editor_log_mode_registered(&mut self) {
+        let mode_ctx = KeyContext::Mode(EDITOR_LOG_MODE.to_string());
+        {
+            let mut kb = self.keybindings.write().unwrap();
+            kb.clear_plugin_defaults_for_mode(EDITOR_LOG_MODE);
+            kb.set_mode_inherits_normal_bindings(EDITOR_LOG_MODE, true);
+            kb.load_plugin_default(
+                mode_ctx.clone(),
+                KeyCode::Char('m'),
+                KeyModifiers::NONE,
+                Action::FilterEditorLog,
+            );
+            kb.load_plugin_default(
+                mode_ctx,
+                KeyCode::Char('q'),
+                KeyModifiers::NONE,
+                Action::CloseTab,
+            );
+        }
+        self.mode_registry.register(
+            BufferMode::new(EDITOR_LOG_MODE)
+                .with_read_only(true)
+                .with_inherit_normal_bindings(true),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_level_and_module() {
+        let line = LogLine::parse(
+            "2026-01-02T03:04:05.678901Z  WARN fresh::services::lsp::manager: server exited",
+            None,
+        );
+        assert_eq!(line.level, Some(LogLevel::Warn));
+        assert_eq!(line.module, "fresh::services::lsp::manager");
+
+        let with_span = LogLine::parse(
+            "2026-01-02T03:04:05.678901Z DEBUG render{frame=3}: fresh::app::render: drew",
+            None,
+        );
+        assert_eq!(with_span.level, Some(LogLevel::Debug));
+        assert_eq!(with_span.module, "fresh::app::render");
+    }
+
+    #[test]
+    fn strips_colors_and_continues_multi_line_messages() {
+        let first = LogLine::parse(
+            "\x1b[2m2026-01-02T03:04:05Z\x1b[0m \x1b[31mERROR\x1b[0m \x1b[2mfresh::main\x1b[0m\x1b[2m:\x1b[0m boom",
+            None,
+        );
+        assert_eq!(first.level, Some(LogLevel::Error));
+        assert_eq!(first.module, "fresh::main");
+        assert!(!first.text.contains('\x1b'));
+
+        let next = LogLine::parse("    at src/main.rs:10", Some(&first));
+        assert_eq!(next.level, Some(LogLevel::Error));
+        assert_eq!(next.module, "fresh::main");
+    }
+}
@@ -3,7 +3,8 @@
 //! Every buffer mutation in this editor flows through one of:
 //!
 //! - `log_and_apply_event` — the canonical single-event path that logs
-//!   to the EventLog and applies the event.
+//!   to the EventLog and applies the event. User edits that would change
+//!   a read-only region (`crate::model::read_only_regions`) stop here.
 //! - `apply_event_to_active_buffer` — apply without logging, used by
 //!   replay paths.
 //! - `apply_events_as_bulk_edit` — batched multi-event application
@@ -17,8 +18,10 @@
 //! since they're entirely per-window concerns.
 
 use lsp_types::TextDocumentContentChangeEvent;
+use rust_i18n::t;
 
 use crate::model::event::Event;
+use crate::view::viewport::Viewport;
 
 use super::types::EventLineInfo;
 use super::Editor;
@@ -28,7 +31,12 @@ impl Editor {
     /// Log an event and apply it to the active buffer.
     /// For Delete events, captures displaced marker positions before applying
     /// so undo can restore them to their exact original positions.
+    /// Edits touching a plugin-marked read-only region are refused here,
+    /// before anything is logged.
     pub fn log_and_apply_event(&mut self, event: &Event) {
+        if event.modifies_buffer() && self.refuse_read_only_edit(event) {
+            return;
+        }
         // Capture displaced markers before the event is applied
         if let Event::Delete { range, .. } = event {
             let displaced = self.active_state().capture_displaced_markers(range);
@@ -43,6 +51,22 @@ impl Editor {
         self.apply_event_to_active_buffer(event);
     }
 
+    /// Refuse `event` if it would change a read-only region of the active
+    /// buffer, showing the region's hint in the status bar. Returns true
+    /// when refused.
+    fn refuse_read_only_edit(&mut self, event: &Event) -> bool {
+        let state = self.active_state();
+        let Some(region) = state.read_only_regions.blocking(event, &state.marker_list) else {
+            return false;
+        };
+        let message = region
+            .hint
+            .clone()
+            .unwrap_or_else(|| t!("buffer.read_only_region").to_string());
+        self.set_status_message(message);
+        true
+    }
+
     /// Apply an event to the active buffer with all cross-cutting concerns.
     /// This is the centralized method that automatically handles:
     /// - Event application to buffer
@@ -63,7 +87,18 @@ impl Editor {
                 return;
             }
             Event::Recenter => {
-                self.active_window_mut().handle_recenter_event();
+                self.active_window_mut()
+                    .handle_recenter_event(Viewport::center_on_position);
+                return;
+            }
+            Event::ScrollCursorToTop => {
+                self.active_window_mut()
+                    .handle_recenter_event(Viewport::top_on_position);
+                return;
+            }
+            Event::ScrollCursorToBottom => {
+                self.active_window_mut()
+                    .handle_recenter_event(Viewport::bottom_on_position);
                 return;
             }
             _ => {}
@@ -134,6 +169,9 @@ impl Editor {
                 win.invalidate_layouts_for_buffer(buf);
                 win.schedule_semantic_tokens_full_refresh(buf);
                 win.schedule_folding_ranges_refresh(buf);
+                win.schedule_schema_validation(buf);
+                win.schedule_idle_lint(buf);
+                self.schedule_idle_hooks(buf);
             }
             Event::Batch { events, .. } => {
                 let has_edits = events
@@ -145,6 +183,9 @@ impl Editor {
                     win.invalidate_layouts_for_buffer(buf);
                     win.schedule_semantic_tokens_full_refresh(buf);
                     win.schedule_folding_ranges_refresh(buf);
+                    win.schedule_schema_validation(buf);
+                    win.schedule_idle_lint(buf);
+                    self.schedule_idle_hooks(buf);
                 }
             }
             _ => {}
@@ -162,8 +203,11 @@ impl Editor {
         // highlighted match — or typing against its boundary so a
         // whole-word `\b` rule no longer holds — would leave a stale
         // highlight on text that no longer matches. We skip during
-        // interactive replace, which manages its own highlight lifecycle.
-        if self.active_window().interactive_replace_state.is_none() {
+        // interactive replace, which manages its own highlight lifecycle,
+        // and when highlights aren't kept past the search prompt.
+        if self.active_window().interactive_replace_state.is_none()
+            && self.config.editor.keep_search_highlights
+        {
             let search_bg = self.theme.read().unwrap().search_match_bg;
             let search_fg = self.theme.read().unwrap().search_match_fg;
             match event {
@@ -266,6 +310,12 @@ impl Editor {
             return None;
         }
 
+        // All or nothing: one event touching a read-only region refuses
+        // the whole edit.
+        if events.iter().any(|e| self.refuse_read_only_edit(e)) {
+            return None;
+        }
+
         // Multi-cursor edits and code-action rewrites go through this path
         // (not `apply_event_to_active_buffer`). Promote any preview tab
         // here too so the invariant "edited buffer is never preview"
@@ -300,6 +350,17 @@ impl Editor {
             .map(|(id, c)| (id, c.position, c.anchor))
             .collect();
 
+        let spans: Vec<(usize, usize)> = events
+            .iter()
+            .filter_map(|e| match e {
+                Event::Insert { position, .. } => Some((*position, *position)),
+                Event::Delete { range, .. } => Some((range.start, range.end)),
+                _ => None,
+            })
+            .collect();
+        self.active_window_mut()
+            .expand_folds_touched_by_edits(active_buf, split_id, &spans);
+
         let state = self
             .windows
             .get_mut(&self.active_window)
@@ -566,6 +627,7 @@ impl Editor {
         let win = self.active_window_mut();
         win.invalidate_layouts_for_buffer(buf);
         win.adjust_other_split_cursors_for_event(&bulk_edit);
+        win.prune_stale_folds(buf);
         // Note: Do NOT clear search overlays - markers track through edits for F3/Shift+F3
 
         // Notify LSP of the change using full document replacement.
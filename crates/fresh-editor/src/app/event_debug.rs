@@ -41,7 +41,7 @@ impl RecordedEvent {
 }
 
 /// Format a key event for display
-fn format_key_event(event: &KeyEvent) -> String {
+pub(crate) fn format_key_event(event: &KeyEvent) -> String {
     let mut parts = Vec::new();
 
     // Build modifier string
@@ -0,0 +1,308 @@
+//! Config hooks: commands and editor actions run on editor events.
+//!
+//! Each entry of the `hooks` config attaches a command, an action, or both
+//! to an event — a file opened, saved, switched to, left idle after edits,
+//! or given new diagnostics — for files matching its `file_match` globs.
+//! Commands run as jobs (see [`super::jobs`]): they show in the `{jobs}`
+//! status-bar element, can be cancelled from the jobs menu, and a non-zero
+//! exit is reported in the status bar. Their output goes to the messages
+//! log rather than to the plugins' process hooks.
+//!
+//! Saves call [`Editor::run_event_hooks`] directly, and so does applying a
+//! changed set of diagnostics. Opens happen inside a window, which posts
+//! [`AsyncMessage::FileOpenedForHooks`]. Buffer switches and idle pauses
+//! are noticed by [`Editor::check_event_hooks`] on each tick, so every path
+//! that changes the active buffer counts.
+//!
+//! [`AsyncMessage::FileOpenedForHooks`]: crate::services::async_bridge::AsyncMessage::FileOpenedForHooks
+
+use std::time::Duration;
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::config::{EventHookConfig, HookEvent};
+use crate::input::keybindings::Action;
+use crate::services::json_schema::catalog::pattern_matches;
+use crate::services::workspace_trust::SpawnDecision;
+use fresh_core::BufferId;
+
+/// How long edits must pause before the `idle` hooks run.
+const HOOK_IDLE_MS: u64 = 1000;
+
+/// Job ids for jobs the editor starts itself (hook commands, language
+/// server installs) start here, well clear of the low-numbered ids the
+/// plugin runtime hands out for its own jobs.
+const HOOK_JOB_ID_BASE: u64 = 1 << 62;
+
+/// Plugin name recorded on the jobs hook commands run as.
+pub(super) const HOOK_JOB_PLUGIN: &str = "hooks";
+
+/// Whether `hook` runs for `event` on the file at `path`.
+fn hook_applies(hook: &EventHookConfig, event: HookEvent, path: &str) -> bool {
+    hook.enabled
+        && hook.event == event
+        && (hook.file_match.is_empty() || hook.file_match.iter().any(|p| pattern_matches(p, path)))
+}
+
+/// `arg` with its placeholders filled in: `$FILE` / `%file` with the file
+/// path and `$LINE` / `%line` with the 1-based cursor line.
+fn expand_placeholders(arg: &str, path: &str, line: usize) -> String {
+    let line = line.to_string();
+    arg.replace("$FILE", path)
+        .replace("%file", path)
+        .replace("$LINE", &line)
+        .replace("%line", &line)
+}
+
+impl Editor {
+    /// Run the hooks configured for `event` whose globs match the buffer's
+    /// file, after the scripts' handlers for it. Buffers without a file
+    /// never match a config hook.
+    pub(crate) fn run_event_hooks(&mut self, event: HookEvent, buffer_id: BufferId) {
+        #[cfg(feature = "scripting")]
+        if let Some(hook) = super::scripts::script_hook(event) {
+            let path = self
+                .buffers()
+                .get(&buffer_id)
+                .and_then(|state| state.buffer.file_path())
+                .map(|p| p.to_string_lossy().to_string())
+                .unwrap_or_default();
+            self.run_script_hooks(hook, buffer_id, &path);
+        }
+        if self.config().hooks.is_empty() {
+            return;
+        }
+        let Some(path) = self
+            .buffers()
+            .get(&buffer_id)
+            .and_then(|state| state.buffer.file_path())
+            .filter(|p| !p.as_os_str().is_empty())
+            .map(|p| p.to_string_lossy().to_string())
+        else {
+            return;
+        };
+        let hooks: Vec<EventHookConfig> = self
+            .config()
+            .hooks
+            .iter()
+            .filter(|hook| hook_applies(hook, event, &path))
+            .cloned()
+            .collect();
+        let line = self.hook_cursor_line(buffer_id);
+        for hook in hooks {
+            if let Some(action) = &hook.action {
+                self.run_hook_action(action, buffer_id);
+            }
+            if !hook.command.is_empty() {
+                self.run_hook_command(&hook, &path, line);
+            }
+        }
+    }
+
+    /// Run an editor action for a hook on `buffer_id`. Actions work on the
+    /// active buffer, so a hook fired for a buffer in the background (Save
+    /// All, diagnostics, an idle timer) skips its action rather than
+    /// applying it to whatever buffer has focus.
+    fn run_hook_action(&mut self, name: &str, buffer_id: BufferId) {
+        if buffer_id != self.active_buffer() {
+            tracing::debug!(
+                "hook action '{}' skipped: {:?} is not the active buffer",
+                name,
+                buffer_id
+            );
+            return;
+        }
+        let Some(action) = Action::from_str(name, &Default::default()) else {
+            self.set_status_message(t!("hooks.unknown_action", action = name).to_string());
+            return;
+        };
+        if let Err(e) = self.handle_action(action) {
+            tracing::warn!("hook action '{}' failed: {}", name, e);
+        }
+    }
+
+    /// 1-based line of the buffer's primary cursor. A buffer in the
+    /// background (a file saved by "Save All") uses the cursor of a split
+    /// that shows it, and line 1 when no split does.
+    fn hook_cursor_line(&self, buffer_id: BufferId) -> usize {
+        let position = if buffer_id == self.active_buffer() {
+            self.active_cursors().primary().position
+        } else {
+            self.active_window()
+                .buffers
+                .splits()
+                .and_then(|(_, view_states)| {
+                    view_states
+                        .values()
+                        .find_map(|vs| vs.buffer_state(buffer_id))
+                })
+                .map_or(0, |bs| bs.cursors.primary().position)
+        };
+        self.buffers()
+            .get(&buffer_id)
+            .map_or(0, |state| state.buffer.get_line_number(position))
+            + 1
+    }
+
+    /// Start the hook's command as a job, with the placeholders in its
+    /// arguments filled in. Workspace Trust applies as it does to any spawn.
+    fn run_hook_command(&mut self, hook: &EventHookConfig, path: &str, line: usize) {
+        let args: Vec<String> = hook
+            .args
+            .iter()
+            .map(|a| expand_placeholders(a, path, line))
+            .collect();
+        let cwd = self.working_dir().to_string_lossy().to_string();
+        if let SpawnDecision::Deny(reason) = self
+            .authority()
+            .workspace_trust
+            .decide(&hook.command, Some(&cwd))
+        {
+            self.set_status_message(
+                t!("hooks.denied", command = hook.command, reason = reason).to_string(),
+            );
+            return;
+        }
+        let title = hook.title.clone().unwrap_or_else(|| {
+            std::iter::once(hook.command.as_str())
+                .chain(args.iter().map(String::as_str))
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+        let job_id = self.next_editor_job_id();
+        self.handle_spawn_job(
+            HOOK_JOB_PLUGIN.to_string(),
+            job_id,
+            hook.command.clone(),
+            args,
+            Some(cwd),
+            title,
+            false,
+            None,
+        );
+    }
+
+    /// Write a line of a hook command's output to the messages log, under
+    /// the hook's title. Returns `false` when `job_id` isn't a hook's job.
+    pub(super) fn log_hook_output(&mut self, job_id: u64, data: &str) -> bool {
+        let Some(job) = self
+            .jobs
+            .get(&job_id)
+            .filter(|job| job.plugin == HOOK_JOB_PLUGIN)
+        else {
+            return false;
+        };
+        tracing::info!(target: "status", "{}: {}", job.title, data.trim_end());
+        true
+    }
+
+    /// Allocate the id for a job the editor starts on its own behalf.
+    pub(super) fn next_editor_job_id(&mut self) -> u64 {
+        let job_id = HOOK_JOB_ID_BASE + self.next_hook_job;
+        self.next_hook_job += 1;
+        job_id
+    }
+
+    /// Start the idle countdown for a buffer that was just edited. No-op
+    /// unless an `idle` hook is configured.
+    pub(crate) fn schedule_idle_hooks(&mut self, buffer_id: BufferId) {
+        if !self
+            .config()
+            .hooks
+            .iter()
+            .any(|h| h.enabled && h.event == HookEvent::Idle)
+        {
+            return;
+        }
+        self.idle_hooks.insert(
+            buffer_id,
+            self.time_source.now() + Duration::from_millis(HOOK_IDLE_MS),
+        );
+    }
+
+    /// Run the `buffer_switch` hooks when the active buffer changed since
+    /// the last tick, and the `idle` hooks of buffers whose edits paused.
+    pub(crate) fn check_event_hooks(&mut self) {
+        let active = self.active_buffer();
+        if let Some(previous) = self.hook_active_buffer.replace(active) {
+            if previous != active {
+                self.run_event_hooks(HookEvent::BufferSwitch, active);
+            }
+        }
+
+        if self.idle_hooks.is_empty() {
+            return;
+        }
+        let now = self.time_source.now();
+        let due: Vec<BufferId> = self
+            .idle_hooks
+            .iter()
+            .filter(|(_, at)| now >= **at)
+            .map(|(id, _)| *id)
+            .collect();
+        for buffer_id in due {
+            self.idle_hooks.remove(&buffer_id);
+            self.run_event_hooks(HookEvent::Idle, buffer_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hook(event: HookEvent, file_match: &[&str]) -> EventHookConfig {
+        EventHookConfig {
+            event,
+            file_match: file_match.iter().map(|p| p.to_string()).collect(),
+            command: "true".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn hook_applies_to_its_event_and_matching_files() {
+        let save = hook(HookEvent::Save, &["schema.graphql"]);
+        assert!(hook_applies(
+            &save,
+            HookEvent::Save,
+            "/p/api/schema.graphql"
+        ));
+        assert!(!hook_applies(
+            &save,
+            HookEvent::Open,
+            "/p/api/schema.graphql"
+        ));
+        assert!(!hook_applies(
+            &save,
+            HookEvent::Save,
+            "/p/api/query.graphql"
+        ));
+
+        let nested = hook(HookEvent::Save, &["src/**/*.rs"]);
+        assert!(hook_applies(&nested, HookEvent::Save, "/p/src/app/mod.rs"));
+        assert!(!hook_applies(&nested, HookEvent::Save, "/p/tests/mod.rs"));
+    }
+
+    #[test]
+    fn placeholders_expand_in_both_spellings() {
+        assert_eq!(
+            expand_placeholders("$FILE:$LINE", "/p/main.rs", 12),
+            "/p/main.rs:12"
+        );
+        assert_eq!(
+            expand_placeholders("--at=%file:%line", "/p/main.rs", 3),
+            "--at=/p/main.rs:3"
+        );
+        assert_eq!(expand_placeholders("-v", "/p/main.rs", 3), "-v");
+    }
+
+    #[test]
+    fn hook_without_globs_matches_every_file_unless_disabled() {
+        let mut any = hook(HookEvent::Idle, &[]);
+        assert!(hook_applies(&any, HookEvent::Idle, "/p/README.md"));
+        any.enabled = false;
+        assert!(!hook_applies(&any, HookEvent::Idle, "/p/README.md"));
+    }
+}
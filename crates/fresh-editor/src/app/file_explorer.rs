@@ -1574,7 +1574,7 @@ impl Editor {
             })
             .collect();
 
-        let joined = rendered.join("\n");
+        let joined = rendered.join(&self.config.clipboard.path_list_separator);
         self.clipboard.copy(joined.clone());
 
         let msg = if rendered.len() == 1 {
@@ -1609,7 +1609,7 @@ impl crate::app::window::Window {
                 Ok(home) => home,
                 Err(e) => {
                     tracing::error!("Failed to get remote home directory: {}", e);
-                    self.set_status_message(format!("Failed to get remote home: {}", e));
+                    self.set_status_error(format!("Failed to get remote home: {}", e));
                     return;
                 }
             }
@@ -463,7 +463,7 @@ impl Editor {
 
         // Open the file - this will create an unsaved buffer with the path set
         if let Err(e) = self.open_file(&path) {
-            self.set_status_message(t!("file.error_opening", error = e.to_string()).to_string());
+            self.set_status_error(t!("file.error_opening", error = e.to_string()).to_string());
         } else {
             self.set_status_message(
                 t!("file.created_new", path = path.display().to_string()).to_string(),
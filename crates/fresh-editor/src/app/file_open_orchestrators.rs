@@ -148,10 +148,13 @@ impl Editor {
             .get(&self.active_buffer())
             .and_then(|s| s.buffer.file_path())
             .is_some();
+        let ids_before = self.buffers().ids();
 
         let buffer_id = self
             .active_window_mut()
             .open_file_no_focus_with_kind(path, kind)?;
+        let freshly_loaded = !ids_before.contains(&buffer_id)
+            || (buffer_id == self.active_buffer() && !active_had_path);
 
         // Check if this was an already-open buffer or a new one
         // For already-open buffers, just switch to them
@@ -207,22 +210,43 @@ impl Editor {
             .map(|m| m.display_name.clone())
             .unwrap_or_else(|| path.display().to_string());
 
-        // Check if buffer is binary for status message
-        let is_binary = self
+        // Check if buffer is binary or has mixed line endings for status message
+        let (is_binary, mixed_line_ending) = self
             .buffers()
             .get(&buffer_id)
-            .map(|s| s.buffer.is_binary())
-            .unwrap_or(false);
+            .map(|s| {
+                let buffer = &s.buffer;
+                (
+                    buffer.is_binary(),
+                    buffer
+                        .has_mixed_line_endings()
+                        .then(|| buffer.line_ending()),
+                )
+            })
+            .unwrap_or((false, None));
 
         // Show appropriate status message for binary vs regular files
         if is_binary {
             self.active_window_mut().status_message =
                 Some(t!("buffer.opened_binary", name = display_name).to_string());
+        } else if let Some(ending) = mixed_line_ending {
+            self.active_window_mut().status_message = Some(
+                t!(
+                    "buffer.opened_mixed_line_endings",
+                    name = display_name,
+                    ending = ending.display_name()
+                )
+                .to_string(),
+            );
         } else {
             self.active_window_mut().status_message =
                 Some(t!("buffer.opened", name = display_name).to_string());
         }
 
+        if freshly_loaded {
+            self.offer_indentation_fix(buffer_id);
+        }
+
         Ok(buffer_id)
     }
 
@@ -353,12 +377,14 @@ impl Editor {
             },
         )?;
         let first_line = buffer.first_line_lossy();
+        let modeline_region = buffer.modeline_region_lossy();
         // Create editor state with the buffer
         // Use display_path for language detection (glob patterns match user-visible paths)
         let detected =
             crate::primitives::detected_language::DetectedLanguage::from_path_with_fallback(
                 &display_path,
                 first_line.as_deref(),
+                modeline_region.as_deref(),
                 &self.grammar_registry,
                 &self.config.languages,
                 self.config.default_language.as_deref(),
@@ -414,6 +440,8 @@ impl Editor {
                 wrap_column,
                 rulers: self.config.editor.rulers.clone(),
                 scroll_offset: self.config.editor.scroll_offset,
+                scroll_past_end: self.config.editor.scroll_past_end,
+                typewriter_scrolling: self.config.editor.typewriter_scrolling,
             });
         }
 
@@ -543,12 +571,14 @@ impl Editor {
             Arc::clone(&self.authority().filesystem),
         )?;
         let first_line = buffer.first_line_lossy();
+        let modeline_region = buffer.modeline_region_lossy();
         // Create editor state with the buffer
         // Use display_path for language detection (glob patterns match user-visible paths)
         let detected =
             crate::primitives::detected_language::DetectedLanguage::from_path_with_fallback(
                 &display_path,
                 first_line.as_deref(),
+                modeline_region.as_deref(),
                 &self.grammar_registry,
                 &self.config.languages,
                 self.config.default_language.as_deref(),
@@ -604,6 +634,8 @@ impl Editor {
                 wrap_column,
                 rulers: self.config.editor.rulers.clone(),
                 scroll_offset: self.config.editor.scroll_offset,
+                scroll_past_end: self.config.editor.scroll_past_end,
+                typewriter_scrolling: self.config.editor.typewriter_scrolling,
             });
         }
 
@@ -785,10 +817,12 @@ impl Editor {
         // extension is what matters; the directory tree is
         // container-side and won't match host-relative globs anyway).
         let first_line = buffer.first_line_lossy();
+        let modeline_region = buffer.modeline_region_lossy();
         let detected =
             crate::primitives::detected_language::DetectedLanguage::from_path_with_fallback(
                 &container_path,
                 first_line.as_deref(),
+                modeline_region.as_deref(),
                 &self.grammar_registry,
                 &self.config.languages,
                 self.config.default_language.as_deref(),
@@ -851,6 +885,8 @@ impl Editor {
                 wrap_column,
                 rulers: self.config.editor.rulers.clone(),
                 scroll_offset: self.config.editor.scroll_offset,
+                scroll_past_end: self.config.editor.scroll_past_end,
+                typewriter_scrolling: self.config.editor.typewriter_scrolling,
             });
         }
 
@@ -1056,10 +1092,12 @@ impl crate::app::window::Window {
                 Arc::clone(&self.authority().filesystem),
             )?;
             let first_line = buffer.first_line_lossy();
+            let modeline_region = buffer.modeline_region_lossy();
             let detected =
                 crate::primitives::detected_language::DetectedLanguage::from_path_with_fallback(
                     &display_path,
                     first_line.as_deref(),
+                    modeline_region.as_deref(),
                     &self.resources.grammar_registry,
                     &self.resources.config.languages,
                     self.resources.config.default_language.as_deref(),
@@ -1105,6 +1143,22 @@ impl crate::app::window::Window {
             state.editing_disabled = true;
         }
 
+        // Generated sources (`@generated`, `DO NOT EDIT` headers) are
+        // overwritten by their generator, so open them read-only too.
+        let is_generated = !is_binary && self.resources.config.editor.auto_read_only && {
+            use super::types::BufferMetadata;
+            let head_len = state
+                .buffer
+                .len()
+                .min(BufferMetadata::GENERATED_HEADER_BYTES);
+            state
+                .buffer
+                .get_text_range_mut(0, head_len)
+                .is_ok_and(|head| {
+                    BufferMetadata::is_generated_header(&String::from_utf8_lossy(&head))
+                })
+        };
+
         // Apply the global + per-language buffer settings (whitespace
         // visibility, tabs, auto-close/surround, guides, …).
         // Uses the buffer's stored language (already set by
@@ -1162,8 +1216,11 @@ impl crate::app::window::Window {
         {
             metadata.read_only = true;
         }
+        if is_generated {
+            metadata.read_only = true;
+        }
 
-        // Mark read-only files (library, binary, or filesystem-readonly) as editing-disabled
+        // Mark read-only files (library, binary, generated, or filesystem-readonly) as editing-disabled
         if metadata.read_only {
             if let Some(state) = self.buffers.get_mut(&buffer_id) {
                 state.editing_disabled = true;
@@ -1203,6 +1260,8 @@ impl crate::app::window::Window {
                 wrap_column,
                 rulers: cfg.rulers,
                 scroll_offset: cfg.scroll_offset,
+                scroll_past_end: cfg.scroll_past_end,
+                typewriter_scrolling: cfg.typewriter_scrolling,
             });
             // Auto-activate page view if configured for this language
             if let Some(page_width) = page_view {
@@ -1226,6 +1285,9 @@ impl crate::app::window::Window {
         // Track file for auto-revert and conflict detection
         self.watch_file(path);
 
+        self.schedule_schema_validation(buffer_id);
+        self.schedule_idle_lint(buffer_id);
+
         // Fire AfterFileOpen hook for plugins — but not for preview opens
         // (file-explorer browse, live-grep overlay). A preview is "just
         // looking": firing this hook lets plugins raise intrusive UI (e.g.
@@ -1243,14 +1305,25 @@ impl crate::app::window::Window {
         Ok(buffer_id)
     }
 
-    /// Fire the `after_file_open` plugin hook for `buffer_id`. Single site so
+    /// Fire the `after_file_open` plugin hook for `buffer_id`, and queue the
+    /// config `open` hooks and the scripts' `on_open` handlers. Single site so
     /// both the commit-time open path and the escalation (promote) path raise
-    /// it identically.
+    /// them identically.
     pub(crate) fn run_after_file_open_hook(&self, buffer_id: BufferId, path: std::path::PathBuf) {
         self.resources.plugin_manager.read().unwrap().run_hook(
             "after_file_open",
             crate::services::plugins::hooks::HookArgs::AfterFileOpen { buffer_id, path },
         );
+        #[cfg(not(feature = "scripting"))]
+        if self.resources.config.hooks.is_empty() {
+            return;
+        }
+        // Receiver may be dropped if the editor is shutting down
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = self
+            .bridge
+            .sender()
+            .send(crate::services::async_bridge::AsyncMessage::FileOpenedForHooks { buffer_id });
     }
 
     /// Fire the deferred `after_file_open` hook for a buffer that was opened
@@ -11,6 +11,10 @@ use rust_i18n::t;
 
 use super::Editor;
 
+/// Wait id reserved for `fresh --wait` in direct mode: the editor quits once
+/// the buffer opened with it is closed. Nested-client waits count up from 1.
+pub const CLI_WAIT_ID: u64 = u64::MAX;
+
 impl Editor {
     /// Queue a file to be opened after the TUI starts.
     ///
@@ -131,8 +135,26 @@ impl Editor {
     }
 
     /// Take and return completed wait IDs (for --wait support).
+    ///
+    /// [`CLI_WAIT_ID`] is left in place for [`Self::take_cli_wait_completed`].
     pub fn take_completed_waits(&mut self) -> Vec<u64> {
-        std::mem::take(&mut self.active_window_mut().completed_waits)
+        let waits = &mut self.active_window_mut().completed_waits;
+        let cli_waiting = waits.contains(&CLI_WAIT_ID);
+        let mut completed = std::mem::take(waits);
+        if cli_waiting {
+            completed.retain(|id| *id != CLI_WAIT_ID);
+            waits.push(CLI_WAIT_ID);
+        }
+        completed
+    }
+
+    /// Whether the buffer opened by `fresh --wait` has been closed. Reports
+    /// the completion once.
+    pub fn take_cli_wait_completed(&mut self) -> bool {
+        let waits = &mut self.active_window_mut().completed_waits;
+        let before = waits.len();
+        waits.retain(|id| *id != CLI_WAIT_ID);
+        waits.len() != before
     }
 
     /// Remove wait tracking for a given wait_id (e.g., when waiting client disconnects).
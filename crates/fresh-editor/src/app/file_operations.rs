@@ -48,8 +48,16 @@ impl Editor {
             Err(e) => {
                 if let Some(sudo_info) = e.downcast_ref::<SudoSaveRequired>() {
                     let info = sudo_info.clone();
+                    let command = self
+                        .config
+                        .editor
+                        .elevation_command
+                        .split_whitespace()
+                        .next()
+                        .unwrap_or("sudo")
+                        .to_string();
                     self.start_prompt(
-                        t!("prompt.sudo_save_confirm").to_string(),
+                        t!("prompt.sudo_save_confirm", command = command).to_string(),
                         PromptType::ConfirmSudoSave { info },
                     );
                     Ok(())
@@ -107,10 +115,12 @@ impl Editor {
             {
                 if state.language == "text" {
                     let first_line = state.buffer.first_line_lossy();
+                    let modeline_region = state.buffer.modeline_region_lossy();
                     let detected =
                         crate::primitives::detected_language::DetectedLanguage::from_path(
                             p,
                             first_line.as_deref(),
+                            modeline_region.as_deref(),
                             &self.grammar_registry,
                             &self.config.languages,
                         );
@@ -154,6 +164,17 @@ impl Editor {
 
         // Notify LSP of save
         self.active_window_mut().notify_lsp_save_buffer(buffer_id);
+        // Re-validate: a save-as can change which schema applies.
+        self.active_window_mut()
+            .schedule_schema_validation(buffer_id);
+        self.active_window_mut().lint_on_save(buffer_id);
+        self.recheck_config_rescue(buffer_id);
+        self.update_project_words_for_saved(buffer_id);
+        self.generate_tags_on_save();
+
+        if let Some(ref p) = path {
+            self.record_local_history(buffer_id, p);
+        }
 
         // Delete recovery file (buffer is now saved)
         if let Err(e) = self.delete_buffer_recovery(buffer_id) {
@@ -180,6 +201,7 @@ impl Editor {
                 },
             );
         }
+        self.run_event_hooks(crate::config::HookEvent::Save, buffer_id);
 
         // Run on-save actions (formatters, linters, etc.)
         // Note: run_on_save_actions also assumes active_buffer internally.
@@ -280,6 +302,7 @@ impl Editor {
     }
 
     /// Collect ids of modified unnamed (no on-disk path) buffers in tab order.
+    /// Scratch buffers are left out — the workspace file keeps them.
     ///
     /// Used by the "save and quit" flow to walk a Save-As prompt over each
     /// unnamed buffer before the editor actually exits.
@@ -299,7 +322,12 @@ impl Editor {
                 .file_path()
                 .map(|p| p.as_os_str().is_empty())
                 .unwrap_or(true);
-            if is_unnamed {
+            let is_scratch = self
+                .active_window()
+                .buffer_metadata
+                .get(id)
+                .is_some_and(|meta| meta.scratch);
+            if is_unnamed && !is_scratch {
                 out.push(*id);
             }
         }
@@ -474,25 +502,6 @@ impl Editor {
             return Ok(false);
         }
 
-        // Save scroll position (from SplitViewState) and cursor positions before reloading
-        let active_split = self
-            .windows
-            .get(&self.active_window)
-            .and_then(|w| w.buffers.splits())
-            .map(|(mgr, _)| mgr)
-            .expect("active window must have a populated split layout")
-            .active_split();
-        let (old_top_byte, old_left_column) = self
-            .windows
-            .get(&self.active_window)
-            .and_then(|w| w.buffers.splits())
-            .map(|(_, vs)| vs)
-            .expect("active window must have a populated split layout")
-            .get(&active_split)
-            .map(|vs| (vs.viewport.top_byte, vs.viewport.left_column))
-            .unwrap_or((0, 0));
-        let old_cursors = self.active_cursors().clone();
-
         // Preserve user settings before reloading
         let old_buffer_settings = self.active_state().buffer_settings.clone();
         let old_editing_disabled = self.active_state().editing_disabled;
@@ -508,61 +517,16 @@ impl Editor {
             std::sync::Arc::clone(&self.authority().filesystem),
         )?;
 
-        // Restore cursor positions (clamped to valid range for new file size)
-        let new_file_size = new_state.buffer.len();
-        let mut restored_cursors = old_cursors;
-        restored_cursors.map(|cursor| {
-            cursor.position = cursor.position.min(new_file_size);
-            // Clear selection since the content may have changed
-            cursor.clear_selection();
-        });
         // Restore user settings (tab size, indentation, etc.)
         new_state.buffer_settings = old_buffer_settings;
         new_state.editing_disabled = old_editing_disabled;
         // Line number visibility is in per-split BufferViewState (survives buffer replacement)
 
-        // Replace the current buffer with the new state
+        // Replace the buffer, carrying cursors, selections, scroll position,
+        // folds and bookmarks through the diff of old and new content.
         let buffer_id = self.active_buffer();
-        if let Some(state) = self
-            .windows
-            .get_mut(&self.active_window)
-            .map(|w| &mut w.buffers)
-            .expect("active window present")
-            .get_mut(&buffer_id)
-        {
-            *state = new_state;
-            // Note: line_wrap_enabled is now in SplitViewState.viewport
-        }
-
-        // Restore cursor positions in SplitViewState (clamped to valid range for new file size)
-        let active_split = self
-            .windows
-            .get(&self.active_window)
-            .and_then(|w| w.buffers.splits())
-            .map(|(mgr, _)| mgr)
-            .expect("active window must have a populated split layout")
-            .active_split();
-        if let Some(view_state) = self
-            .windows
-            .get_mut(&self.active_window)
-            .and_then(|w| w.split_view_states_mut())
-            .expect("active window must have a populated split layout")
-            .get_mut(&active_split)
-        {
-            view_state.cursors = restored_cursors;
-        }
-
-        // Restore scroll position in SplitViewState (clamped to valid range for new file size)
-        if let Some(view_state) = self
-            .windows
-            .get_mut(&self.active_window)
-            .and_then(|w| w.split_view_states_mut())
-            .expect("active window must have a populated split layout")
-            .get_mut(&active_split)
-        {
-            view_state.viewport.top_byte = old_top_byte.min(new_file_size);
-            view_state.viewport.left_column = old_left_column;
-        }
+        self.active_window_mut()
+            .replace_state_on_reload(buffer_id, new_state);
 
         // Clear the undo/redo history for this buffer
         if let Some(event_log) = self.active_window_mut().event_logs.get_mut(&buffer_id) {
@@ -629,7 +593,12 @@ impl Editor {
             match rx.try_recv() {
                 Ok(results) => {
                     self.active_window_mut().pending_file_poll_rx = None;
-                    any_changed = self.process_file_poll_results(results);
+                    let reloaded = self.process_file_poll_results(results);
+                    any_changed = reloaded > 0;
+                    if self.active_window().branch_refresh.is_some() {
+                        self.finish_branch_switch_refresh(reloaded);
+                        any_changed = true;
+                    }
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => {
                     // Still in progress — don't block, don't start another
@@ -638,6 +607,7 @@ impl Editor {
                 Err(std::sync::mpsc::TryRecvError::Disconnected) => {
                     // Background task panicked or was dropped
                     self.active_window_mut().pending_file_poll_rx = None;
+                    self.finish_branch_switch_refresh(0);
                 }
             }
         }
@@ -657,26 +627,32 @@ impl Editor {
         }
         self.active_window_mut().last_auto_revert_poll = self.time_source.now();
 
-        // Collect paths of open files that need checking. Auto-revert is a
-        // per-buffer property, so only poll files whose buffer opts in — this
-        // keeps terminal backing files (append-streamed by the PTY reader)
-        // out of the poll set entirely, so their constant growth never fires
-        // a reload or redraw (fresh#2608).
-        let files_to_check: Vec<PathBuf> = {
-            let window = self.active_window();
-            window
-                .buffers
-                .iter()
-                .filter(|(id, _)| window.buffer_auto_revert_enabled(**id))
-                .filter_map(|(_, state)| state.buffer.file_path().map(PathBuf::from))
-                .collect()
-        };
-
-        if files_to_check.is_empty() {
-            return any_changed;
+        let files_to_check = self.auto_revert_files();
+        if !files_to_check.is_empty() {
+            self.spawn_file_poll(files_to_check);
         }
 
-        // Spawn background metadata checks
+        any_changed
+    }
+
+    /// Paths of the open files auto-revert polls. Auto-revert is a
+    /// per-buffer property, so only files whose buffer opts in — this keeps
+    /// terminal backing files (append-streamed by the PTY reader) out of the
+    /// poll set entirely, so their constant growth never fires a reload or
+    /// redraw (fresh#2608).
+    pub(super) fn auto_revert_files(&self) -> Vec<PathBuf> {
+        let window = self.active_window();
+        window
+            .buffers
+            .iter()
+            .filter(|(id, _)| window.buffer_auto_revert_enabled(**id))
+            .filter_map(|(_, state)| state.buffer.file_path().map(PathBuf::from))
+            .collect()
+    }
+
+    /// Stat `files_to_check` on a background thread; `poll_file_changes`
+    /// picks up the results.
+    pub(super) fn spawn_file_poll(&mut self, files_to_check: Vec<PathBuf>) {
         let (tx, rx) = std::sync::mpsc::channel();
         let fs = self.authority().filesystem.clone();
         std::thread::Builder::new()
@@ -695,16 +671,15 @@ impl Editor {
             })
             .ok();
         self.active_window_mut().pending_file_poll_rx = Some(rx);
-
-        any_changed
     }
 
-    /// Process results from a background file poll
+    /// Process results from a background file poll. Returns how many files
+    /// changed on disk and were handled.
     fn process_file_poll_results(
         &mut self,
         results: Vec<(PathBuf, Option<std::time::SystemTime>)>,
-    ) -> bool {
-        let mut any_changed = false;
+    ) -> usize {
+        let mut changed = 0;
         for (path, mtime_opt) in results {
             let Some(current_mtime) = mtime_opt else {
                 continue;
@@ -714,7 +689,7 @@ impl Editor {
                 if current_mtime != stored_mtime {
                     let path_str = path.display().to_string();
                     if self.handle_async_file_changed(path_str) {
-                        any_changed = true;
+                        changed += 1;
                     }
                 }
             } else {
@@ -722,7 +697,7 @@ impl Editor {
                 self.file_mod_times_mut().insert(path, current_mtime);
             }
         }
-        any_changed
+        changed
     }
 
     /// Poll for file tree changes (called from main loop)
@@ -740,9 +715,10 @@ impl Editor {
         let mut dir_poll_pending = false;
         if let Some(ref rx) = self.active_window_mut().pending_dir_poll_rx {
             match rx.try_recv() {
-                Ok((dir_results, git_index_mtimes)) => {
+                Ok((dir_results, git_index_mtimes, git_heads)) => {
                     self.active_window_mut().pending_dir_poll_rx = None;
-                    any_refreshed = self.process_dir_poll_results(dir_results, git_index_mtimes);
+                    any_refreshed =
+                        self.process_dir_poll_results(dir_results, git_index_mtimes, git_heads);
                 }
                 Err(std::sync::mpsc::TryRecvError::Empty) => {
                     dir_poll_pending = true;
@@ -784,18 +760,20 @@ impl Editor {
         // (see `git_index.rs`) so the first poll never blocks on `git`.
         self.spawn_git_index_resolution();
 
-        // Get file explorer reference
-        let Some(explorer) = self.file_explorer() else {
-            return any_refreshed;
-        };
-
-        // Collect expanded directories (node_id, path)
-        let expanded_dirs: Vec<(NodeId, PathBuf)> = explorer
-            .tree()
-            .all_nodes()
-            .filter(|node| node.is_dir() && node.is_expanded())
-            .map(|node| (node.id, node.entry.path.clone()))
-            .collect();
+        // Collect the explorer's expanded directories (node_id, path). The
+        // git indexes and HEADs below are watched with or without it, so a
+        // branch switch is noticed even when the explorer was never opened.
+        let expanded_dirs: Vec<(NodeId, PathBuf)> = self
+            .file_explorer()
+            .map(|explorer| {
+                explorer
+                    .tree()
+                    .all_nodes()
+                    .filter(|node| node.is_dir() && node.is_expanded())
+                    .map(|node| (node.id, node.entry.path.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
 
         // The watch set is stored explicitly (populated once above), not
         // re-derived from `dir_mod_times` by pattern-matching `.git/index`.
@@ -806,7 +784,8 @@ impl Editor {
             return any_refreshed;
         }
 
-        // Spawn background metadata checks (directories + git indexes)
+        // Spawn background metadata checks (directories + git indexes), and
+        // read each repo's `HEAD` (next to its index) to spot branch switches
         let (tx, rx) = std::sync::mpsc::channel();
         let fs = self.authority().filesystem.clone();
         std::thread::Builder::new()
@@ -820,6 +799,15 @@ impl Editor {
                     })
                     .collect();
 
+                let git_heads: Vec<(PathBuf, String)> = git_index_paths
+                    .iter()
+                    .filter_map(|index| {
+                        let path = index.with_file_name("HEAD");
+                        let head = fs.read_file(&path).ok()?;
+                        Some((path, String::from_utf8_lossy(&head).trim().to_string()))
+                    })
+                    .collect();
+
                 let git_index_mtimes: Vec<(PathBuf, std::time::SystemTime)> = git_index_paths
                     .into_iter()
                     .filter_map(|path| {
@@ -829,7 +817,7 @@ impl Editor {
                     .collect();
 
                 // Receiver may have been dropped during shutdown — that's fine.
-                if tx.send((results, git_index_mtimes)).is_err() {}
+                if tx.send((results, git_index_mtimes, git_heads)).is_err() {}
             })
             .ok();
         self.active_window_mut().pending_dir_poll_rx = Some(rx);
@@ -846,6 +834,7 @@ impl Editor {
             Option<std::time::SystemTime>,
         )>,
         git_index_mtimes: Vec<(PathBuf, std::time::SystemTime)>,
+        git_heads: Vec<(PathBuf, String)>,
     ) -> bool {
         let mut dirs_to_refresh: Vec<(crate::view::file_tree::NodeId, PathBuf)> = Vec::new();
 
@@ -883,7 +872,20 @@ impl Editor {
             }
         }
 
-        if dirs_to_refresh.is_empty() && !git_index_changed {
+        // A `HEAD` whose contents changed since the last poll means the
+        // checked-out branch switched (the first sighting only records it).
+        let mut switched_head = None;
+        for (path, head) in git_heads {
+            let previous = self
+                .active_window_mut()
+                .git_heads
+                .insert(path, head.clone());
+            if previous.is_some_and(|previous| previous != head) {
+                switched_head = Some(head);
+            }
+        }
+
+        if dirs_to_refresh.is_empty() && !git_index_changed && switched_head.is_none() {
             return false;
         }
 
@@ -903,8 +905,11 @@ impl Editor {
         // External git operations (commit, pull, checkout, …) update
         // `.git/index` without touching expanded directories. Fire the
         // explorer-change hook so git_explorer rescans and clears stale
-        // status badges (#1431).
-        if git_index_changed {
+        // status badges (#1431). A branch switch does that as part of
+        // refreshing everything else the checkout made stale.
+        if let Some(head) = switched_head {
+            self.begin_branch_switch_refresh(&head);
+        } else if git_index_changed {
             let cwd = self.working_dir().to_path_buf();
             self.notify_file_explorer_change(&cwd);
         }
@@ -1132,31 +1137,15 @@ impl Editor {
     /// Revert a specific buffer by ID without affecting the active viewport.
     ///
     /// This is used for auto-reverting background buffers that aren't currently
-    /// visible in the active split. It reloads the buffer content and updates
-    /// cursors (clamped to valid positions), but does NOT touch any viewport state.
+    /// visible in the active split. It reloads the buffer content and maps each
+    /// split's cursors and scroll position through the change, but does not
+    /// scroll to reveal the cursor.
     pub(crate) fn revert_buffer_by_id(
         &mut self,
         buffer_id: BufferId,
         path: &Path,
     ) -> anyhow::Result<()> {
         // Preserve user settings before reloading
-        // TODO: Consider moving line numbers to SplitViewState (per-view setting)
-        // Get cursors from split view states for this buffer (find any split showing it)
-        let old_cursors = self
-            .windows
-            .get(&self.active_window)
-            .and_then(|w| w.buffers.splits())
-            .map(|(_, vs)| vs)
-            .expect("active window must have a populated split layout")
-            .values()
-            .find_map(|vs| {
-                if vs.keyed_states.contains_key(&buffer_id) {
-                    vs.keyed_states.get(&buffer_id).map(|bs| bs.cursors.clone())
-                } else {
-                    None
-                }
-            })
-            .unwrap_or_default();
         let (old_buffer_settings, old_editing_disabled) = self
             .buffers()
             .get(&buffer_id)
@@ -1185,43 +1174,15 @@ impl Editor {
             fs,
         )?;
 
-        // Get the new file size for clamping
-        let new_file_size = new_state.buffer.len();
-
-        // Restore cursor positions (clamped to valid range for new file size)
-        let mut restored_cursors = old_cursors;
-        restored_cursors.map(|cursor| {
-            cursor.position = cursor.position.min(new_file_size);
-            cursor.clear_selection();
-        });
         // Restore user settings (tab size, indentation, etc.)
         new_state.buffer_settings = old_buffer_settings;
         new_state.editing_disabled = old_editing_disabled;
         // Line number visibility is in per-split BufferViewState (survives buffer replacement)
 
-        // Replace the buffer content
-        if let Some(state) = self
-            .windows
-            .get_mut(&self.active_window)
-            .map(|w| &mut w.buffers)
-            .expect("active window present")
-            .get_mut(&buffer_id)
-        {
-            *state = new_state;
-        }
-
-        // Restore cursors in any split view states that have this buffer
-        for vs in self
-            .windows
-            .get_mut(&self.active_window)
-            .and_then(|w| w.split_view_states_mut())
-            .expect("active window must have a populated split layout")
-            .values_mut()
-        {
-            if let Some(buf_state) = vs.keyed_states.get_mut(&buffer_id) {
-                buf_state.cursors = restored_cursors.clone();
-            }
-        }
+        // Replace the buffer content, mapping every split's cursors, scroll
+        // position and folds through the diff of old and new content.
+        self.active_window_mut()
+            .replace_state_on_reload(buffer_id, new_state);
 
         // Clear the undo/redo history for this buffer
         if let Some(event_log) = self.active_window_mut().event_logs.get_mut(&buffer_id) {
@@ -1341,8 +1302,8 @@ impl Editor {
                         tracing::info!("Auto-reverted file: {:?}", path);
                     }
                 } else {
-                    // Use revert_buffer_by_id() which doesn't touch any viewport
-                    // This prevents corrupting the active split's viewport state
+                    // Use revert_buffer_by_id() which only remaps this buffer's
+                    // own view states, leaving the active split's viewport alone
                     if let Err(e) = self.revert_buffer_by_id(buffer_id, &path) {
                         tracing::error!("Failed to auto-revert background file {:?}: {}", path, e);
                     } else {
@@ -1589,19 +1550,58 @@ impl crate::app::window::Window {
                     "LSP for {} not auto-starting (auto_start=false). Click the LSP indicator to start manually.",
                     language
                 );
+                self.suggest_known_lsp_servers(&language, false);
             }
             LspSpawnResult::NotConfigured => {
                 tracing::debug!("No LSP server configured for language: {}", language);
+                self.suggest_known_lsp_servers(&language, true);
             }
             LspSpawnResult::Disabled => {
                 tracing::debug!("LSP disabled in config for language: {}", language);
             }
             LspSpawnResult::Failed => {
                 tracing::warn!("Failed to spawn LSP client for language: {}", language);
+                self.suggest_known_lsp_servers(&language, false);
             }
         }
     }
 
+    /// Point the user at a well-known language server for `language` when
+    /// none of the configured ones can run: one found on PATH is offered
+    /// for enabling from the LSP menu; with `suggest_install` (no server
+    /// configured at all) the platform's install command is shown instead
+    /// when none is found. Shown once per language per session.
+    fn suggest_known_lsp_servers(&mut self, language: &str, suggest_install: bool) {
+        use crate::services::lsp::known_servers::detect_servers;
+
+        if self.lsp_detection_notified.contains(language)
+            || self.is_lsp_language_user_dismissed(language)
+        {
+            return;
+        }
+        let configured = self.lsp.get_configs(language).unwrap_or(&[]);
+        let detection = detect_servers(language, configured, |cmd| {
+            self.lsp.command_exists_via_authority(cmd)
+        });
+        let message = if let Some(server) = detection.found.first() {
+            t!(
+                "lsp.server_detected",
+                command = server.command,
+                language = language
+            )
+        } else if let Some(server) = detection.missing.first().filter(|_| suggest_install) {
+            t!(
+                "lsp.server_install_suggestion",
+                language = language,
+                install = server.install_command()
+            )
+        } else {
+            return;
+        };
+        self.lsp_detection_notified.insert(language.to_string());
+        self.set_status_message(message.to_string());
+    }
+
     /// Record a file's modification time (called when opening files).
     /// Window-local: records into this window's own `file_mod_times`.
     pub(crate) fn watch_file(&mut self, path: &Path) {
@@ -0,0 +1,115 @@
+//! Input-method (IME) composition.
+//!
+//! While an IME composes (CJK candidate selection, dead-key accents), the
+//! frontend reports the uncommitted pre-edit text with
+//! [`Editor::set_ime_preedit`]. It is painted underlined at the caret of the
+//! active split — over the buffer cells, never into the buffer — and the
+//! caret sits at its end, so the IME's candidate window follows it. The
+//! commit arrives through [`Editor::commit_ime_text`] and is typed in
+//! character by character, like key presses — into the focused prompt,
+//! panel field, terminal or buffer.
+//!
+//! Terminals have no pre-edit events, but a dead key held back by the
+//! [`DeadKeyComposer`](crate::input::dead_keys::DeadKeyComposer) is shown
+//! the same way until the key after it resolves it.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::style::{Modifier, Style};
+use ratatui::Frame;
+
+use super::Editor;
+
+impl Editor {
+    /// Replace the pre-edit text; an empty string ends the composition.
+    pub fn set_ime_preedit(&mut self, text: &str) {
+        self.ime_preedit = (!text.is_empty()).then(|| text.to_string());
+    }
+
+    /// The pre-edit text currently shown at the caret, if composing.
+    pub fn ime_preedit(&self) -> Option<&str> {
+        self.ime_preedit.as_deref()
+    }
+
+    /// End the composition and type the committed text. Each character
+    /// takes the path of a typed key, so auto-pairing and abbreviations
+    /// apply; the buffer's undo group keeps the commit one undo step.
+    pub fn commit_ime_text(&mut self, text: &str) {
+        self.ime_preedit = None;
+        if text.is_empty() {
+            return;
+        }
+        let group_buffer = self.active_buffer();
+        if let Some(log) = self.active_window_mut().event_logs.get_mut(&group_buffer) {
+            log.begin_undo_group();
+        }
+        for c in text.chars() {
+            if let Err(e) = self.handle_key(KeyCode::Char(c), KeyModifiers::NONE) {
+                tracing::warn!("IME commit failed: {}", e);
+            }
+        }
+        if let Some(log) = self.active_window_mut().event_logs.get_mut(&group_buffer) {
+            log.end_undo_group();
+        }
+    }
+
+    /// Run a terminal key press through the dead-key composer, returning
+    /// the keys to handle in its place. Bypassed while the calibration
+    /// wizard or event debug dialog is open: they need the raw keys.
+    pub(super) fn compose_dead_key(&mut self, key: KeyEvent) -> Vec<KeyEvent> {
+        if !self.config().editor.compose_dead_keys
+            || self.calibration_wizard.is_some()
+            || self.active_window().is_event_debug_active()
+        {
+            return vec![key];
+        }
+        let was_pending = self.dead_keys.pending().is_some();
+        let keys = self.dead_keys.feed(key);
+        let pending = self.dead_keys.pending();
+        if was_pending || pending.is_some() {
+            self.ime_preedit = pending.map(String::from);
+        }
+        keys
+    }
+
+    /// Paint the pre-edit text at the caret cell `(x, y)`, clipped to the
+    /// split it falls in, and return where the caret goes after it.
+    pub(super) fn render_ime_preedit(&self, frame: &mut Frame, x: u16, y: u16) -> (u16, u16) {
+        if self.ime_preedit.is_none() {
+            return (x, y);
+        }
+        let right = self
+            .active_layout()
+            .split_areas
+            .iter()
+            .map(|(_, _, rect, ..)| *rect)
+            .find(|rect| {
+                x >= rect.x && x < rect.x + rect.width && y >= rect.y && y < rect.y + rect.height
+            })
+            .map_or(frame.area().right(), |rect| rect.right());
+        self.render_ime_preedit_within(frame, x, y, right)
+    }
+
+    /// Paint the pre-edit text at the caret cell `(x, y)` of a prompt or
+    /// other input, stopping before column `right`.
+    pub(super) fn render_ime_preedit_within(
+        &self,
+        frame: &mut Frame,
+        x: u16,
+        y: u16,
+        right: u16,
+    ) -> (u16, u16) {
+        let Some(text) = self.ime_preedit.as_deref() else {
+            return (x, y);
+        };
+        let theme = self.theme();
+        let style = Style::default()
+            .fg(theme.editor_fg)
+            .bg(theme.editor_bg)
+            .add_modifier(Modifier::UNDERLINED);
+        let (end, _) =
+            frame
+                .buffer_mut()
+                .set_stringn(x, y, text, right.saturating_sub(x) as usize, style);
+        (end.min(right.saturating_sub(1)), y)
+    }
+}
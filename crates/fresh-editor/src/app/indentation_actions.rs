@@ -0,0 +1,224 @@
+//! Indentation anomaly detection and normalization.
+//!
+//! When a file opens with mixed tabs and spaces or inconsistent indent
+//! widths (see [`crate::primitives::indent_normalize`]), an unfocused popup
+//! offers **Convert To Spaces** / **Convert To Tabs**, each row previewing
+//! the first line it would change. The conversions are also commands, and
+//! are recorded as whole-buffer transforms so **Revert Last Transform** can
+//! back them out after later edits.
+
+use rust_i18n::t;
+
+use crate::model::event::{BufferId, CursorId, Event};
+use crate::primitives::indent_normalize::{
+    analyze_indentation, normalize_indentation, IndentReport, IndentStyle,
+};
+
+use super::Editor;
+
+/// Characters of line content shown after the indentation in a preview.
+const PREVIEW_CHARS: usize = 8;
+
+impl Editor {
+    /// Offer to normalize the indentation of the just-opened, active
+    /// `buffer_id` if it mixes tabs and spaces or uses inconsistent widths.
+    pub(super) fn offer_indentation_fix(&mut self, buffer_id: BufferId) {
+        if self.active_buffer() != buffer_id {
+            return;
+        }
+        let state = self.active_state();
+        if state.editing_disabled || state.buffer.is_binary() {
+            return;
+        }
+        let tab_size = state.buffer_settings.tab_size;
+        let Some(text) = state.buffer.to_string() else {
+            return;
+        };
+        let report = analyze_indentation(&text, tab_size);
+        if !report.is_anomalous() {
+            return;
+        }
+
+        let mut items = Vec::new();
+        for (style, data) in [(IndentStyle::Spaces, "spaces"), (IndentStyle::Tabs, "tabs")] {
+            let converted = normalize_indentation(&text, style, tab_size);
+            let Some(preview) = first_change_preview(&text, &converted) else {
+                continue;
+            };
+            items.push(
+                crate::view::popup::PopupListItem::new(style_label(style, tab_size))
+                    .with_detail(preview)
+                    .with_data(data.to_string()),
+            );
+        }
+        if items.is_empty() {
+            return;
+        }
+        items.push(
+            crate::view::popup::PopupListItem::new(t!("indentation.keep").to_string())
+                .with_data("keep".to_string()),
+        );
+
+        let (popup_bg, popup_border_fg) = {
+            let theme = self.theme();
+            (theme.popup_bg, theme.popup_border_fg)
+        };
+        let popup = crate::view::popup::Popup {
+            kind: crate::view::popup::PopupKind::List,
+            title: Some(t!("indentation.title").to_string()),
+            description: Some(describe_report(&report)),
+            transient: false,
+            content: crate::view::popup::PopupContent::List { items, selected: 0 },
+            position: crate::view::popup::PopupPosition::BottomRight,
+            width: 76,
+            max_height: 10,
+            bordered: true,
+            border_style: ratatui::style::Style::default().fg(popup_border_fg),
+            background_style: ratatui::style::Style::default().bg(popup_bg),
+            scroll_offset: 0,
+            text_selection: None,
+            accept_key_hint: None,
+            resolver: crate::view::popup::PopupResolver::IndentationFix,
+            // A notice, not a question that blocks editing: it only takes
+            // the keyboard when the user focuses it.
+            focused: false,
+            focus_key_hint: self.popup_focus_key_hint(),
+        };
+        self.active_state_mut().popups.show(popup);
+    }
+
+    /// Dispatch the row chosen in the indentation popup.
+    pub(super) fn handle_indentation_fix_action(&mut self, action_key: &str) {
+        match action_key {
+            "spaces" => self.convert_indentation(IndentStyle::Spaces),
+            "tabs" => self.convert_indentation(IndentStyle::Tabs),
+            _ => {}
+        }
+    }
+
+    /// Rewrite the active buffer's indentation to `style` as a single
+    /// undoable edit, keeping every line's visual column, and make new
+    /// indentation follow it.
+    pub(super) fn convert_indentation(&mut self, style: IndentStyle) {
+        let state = self.active_state();
+        let tab_size = state.buffer_settings.tab_size;
+        let Some(text) = state.buffer.to_string() else {
+            self.set_status_message(t!("error.buffer_not_loaded").to_string());
+            return;
+        };
+        let converted = normalize_indentation(&text, style, tab_size);
+
+        // Only the indentation of changed lines is replaced, so markers on
+        // the rest of each line stay put. No cursor is tied to the edits;
+        // every cursor shifts with the text around it.
+        let cursor_id = CursorId::UNDO_SENTINEL;
+        let mut events = Vec::new();
+        let mut line_start = 0;
+        for (old, new) in text.split('\n').zip(converted.split('\n')) {
+            if old != new {
+                let old_indent = old.len() - old.trim_start_matches([' ', '\t']).len();
+                let new_indent = new.len() - new.trim_start_matches([' ', '\t']).len();
+                events.push(Event::Delete {
+                    range: line_start..line_start + old_indent,
+                    deleted_text: old[..old_indent].to_string(),
+                    cursor_id,
+                });
+                events.push(Event::Insert {
+                    position: line_start,
+                    text: new[..new_indent].to_string(),
+                    cursor_id,
+                });
+            }
+            line_start += old.len() + 1;
+        }
+        let count = events.len() / 2;
+        let label = style_label(style, tab_size);
+        if count > 0 {
+            // apply_events_as_bulk_edit wants the edits in descending order.
+            let events = events
+                .chunks(2)
+                .rev()
+                .flat_map(|pair| pair.iter().cloned())
+                .collect();
+            if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, label.clone()) {
+                self.active_event_log_mut().append(bulk_edit);
+            }
+            let buffer_id = self.active_buffer();
+            self.record_transform(buffer_id, label.clone(), text);
+        }
+        self.active_state_mut().buffer_settings.use_tabs = style == IndentStyle::Tabs;
+        self.set_status_message(
+            t!("indentation.converted", count = count, style = label).to_string(),
+        );
+    }
+}
+
+/// "Convert To Spaces (4)" / "Convert To Tabs".
+fn style_label(style: IndentStyle, tab_size: usize) -> String {
+    match style {
+        IndentStyle::Spaces => t!("indentation.to_spaces", size = tab_size).to_string(),
+        IndentStyle::Tabs => t!("indentation.to_tabs").to_string(),
+    }
+}
+
+fn describe_report(report: &IndentReport) -> String {
+    let mut parts = Vec::new();
+    if report.has_mixed_indentation() {
+        parts.push(
+            t!(
+                "indentation.mixed",
+                tabs = report.tab_lines + report.mixed_lines,
+                spaces = report.space_lines
+            )
+            .to_string(),
+        );
+    }
+    if report.has_inconsistent_widths() {
+        let widths: Vec<String> = report.space_steps.iter().map(|w| w.to_string()).collect();
+        parts.push(t!("indentation.inconsistent", widths = widths.join(", ")).to_string());
+    }
+    parts.join(" ")
+}
+
+/// How many lines differ between `before` and `after`, and the first one
+/// with its whitespace made visible: `3 line(s) · L5 →→foo ⇒ ········foo`.
+fn first_change_preview(before: &str, after: &str) -> Option<String> {
+    let mut changes = before
+        .split('\n')
+        .zip(after.split('\n'))
+        .enumerate()
+        .filter(|(_, (b, a))| b != a);
+    let (line, (old, new)) = changes.next()?;
+    let count = changes.count() + 1;
+    Some(
+        t!(
+            "indentation.preview",
+            count = count,
+            line = line + 1,
+            before = show_indent(old),
+            after = show_indent(new)
+        )
+        .to_string(),
+    )
+}
+
+/// `line`'s indentation with tabs as `→` and spaces as `·`, followed by the
+/// start of its content.
+fn show_indent(line: &str) -> String {
+    let mut shown = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            '\t' => shown.push('→'),
+            ' ' => shown.push('·'),
+            _ => break,
+        }
+        chars.next();
+    }
+    let content: String = chars.by_ref().take(PREVIEW_CHARS).collect();
+    shown.push_str(content.trim_end());
+    if chars.next().is_some() {
+        shown.push('…');
+    }
+    shown
+}
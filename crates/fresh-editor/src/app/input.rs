@@ -206,8 +206,9 @@ impl Editor {
     }
 
     /// Resolve a key event against `KeyContext::Completion` when the topmost
-    /// visible popup is a completion popup. Only `CompletionAccept` and
-    /// `CompletionDismiss` are recognised here — every other key falls
+    /// visible popup is a completion popup. Only `CompletionAccept`,
+    /// `CompletionDismiss`, `SmartTab` and the documentation-panel scroll
+    /// actions are recognised here — every other key falls
     /// through to the popup's own handler so type-to-filter, navigation, and
     /// the "any other key dismisses + passthrough" behaviours stay intact.
     pub(crate) fn resolve_completion_popup_action(
@@ -215,17 +216,8 @@ impl Editor {
         event: &crossterm::event::KeyEvent,
     ) -> Option<crate::input::keybindings::Action> {
         use crate::input::keybindings::{Action, KeyContext};
-        use crate::view::popup::PopupKind;
-
-        let topmost_kind = if self.global_popups.is_visible() {
-            self.global_popups.top().map(|p| p.kind)
-        } else if self.active_state().popups.is_visible() {
-            self.active_state().popups.top().map(|p| p.kind)
-        } else {
-            None
-        };
 
-        if topmost_kind != Some(PopupKind::Completion) {
+        if !self.completion_popup_is_topmost() {
             return None;
         }
 
@@ -235,11 +227,31 @@ impl Editor {
             .unwrap()
             .resolve_in_context_only(event, KeyContext::Completion)
         {
-            Some(action @ (Action::CompletionAccept | Action::CompletionDismiss)) => Some(action),
+            Some(
+                action @ (Action::CompletionAccept
+                | Action::CompletionDismiss
+                | Action::CompletionDocsScrollDown
+                | Action::CompletionDocsScrollUp
+                | Action::SmartTab),
+            ) => Some(action),
             _ => None,
         }
     }
 
+    /// Whether the topmost visible popup is a completion popup.
+    pub(crate) fn completion_popup_is_topmost(&self) -> bool {
+        use crate::view::popup::PopupKind;
+
+        let topmost_kind = if self.global_popups.is_visible() {
+            self.global_popups.top().map(|p| p.kind)
+        } else if self.active_state().popups.is_visible() {
+            self.active_state().popups.top().map(|p| p.kind)
+        } else {
+            None
+        };
+        topmost_kind == Some(PopupKind::Completion)
+    }
+
     /// Build the editor's overlay stack, ordered top-first (highest
     /// keyboard-focus precedence first), ending with the always-present
     /// editor base layer.
@@ -276,9 +288,9 @@ impl Editor {
                 blocks_terminal_input: true,
             });
         }
-        // Keybinding editor and calibration wizard install their own
-        // input dispatchers (see `input_dispatch.rs`), so they are
-        // transparent to `KeyContext`-driven keybinding resolution
+        // Keybinding editor, calibration wizard and presentation mode
+        // install their own input dispatchers (see `input_dispatch.rs`), so
+        // they are transparent to `KeyContext`-driven keybinding resolution
         // (`key_context: None`) — but they fully own the keyboard while
         // present and block PTY routing.
         if self.keybinding_editor.is_some() {
@@ -297,6 +309,14 @@ impl Editor {
                 blocks_terminal_input: true,
             });
         }
+        if self.presentation.is_some() {
+            layers.push(Layer {
+                kind: LayerKind::Presentation,
+                owns_keyboard: true,
+                key_context: None,
+                blocks_terminal_input: true,
+            });
+        }
         // The workspace-trust prompt is a `global_popups` entry with its
         // own modal z-band, key handler and mouse handler. When it's the
         // top of the global stack it takes the place of the generic
@@ -404,6 +424,14 @@ impl Editor {
                 blocks_terminal_input: true,
             });
         }
+        if self.active_window().buffer_context_menu.is_some() {
+            layers.push(Layer {
+                kind: LayerKind::BufferContextMenu,
+                owns_keyboard: true,
+                key_context: None,
+                blocks_terminal_input: true,
+            });
+        }
         // The centered widget modal (picker / new-session form / plugin
         // overlay) owns the keyboard when focused. It resolves as `Normal`
         // regardless of the underlying buffer's (possibly stale) context so
@@ -465,6 +493,21 @@ impl Editor {
             .expect("editor base layer always owns the keyboard")
     }
 
+    /// Editor state that conditional `when` clauses (`language:…`,
+    /// `filetype:…`, `popup`) are evaluated against.
+    pub fn when_state(&self) -> crate::input::when_clause::WhenState {
+        let state = self.active_state();
+        crate::input::when_clause::WhenState {
+            language: state.language.clone(),
+            file_type: state
+                .buffer
+                .file_path()
+                .and_then(|p| p.extension())
+                .map(|ext| ext.to_string_lossy().to_lowercase()),
+            popup_visible: self.global_popups.is_visible() || state.popups.is_visible(),
+        }
+    }
+
     /// Handle a key event and return whether it was handled
     /// This is the central key handling logic used by both main.rs and tests
     pub fn handle_key(
@@ -485,6 +528,10 @@ impl Editor {
         // Create key event for dispatch methods
         let key_event = crossterm::event::KeyEvent::new(code, modifiers);
 
+        // A key press stops a smooth wheel scroll where it is, so it can't
+        // drag the viewport away from a cursor the key just moved.
+        self.active_window_mut().smooth_scroll = None;
+
         // Diagnostic for the "dock visible, buffer won't accept keys" wedge
         // (#2234, item 4): while the dock is mounted, record its host-side focus
         // plus the active window's key context for *every* key, before any
@@ -709,6 +756,7 @@ impl Editor {
                 let key_event = crossterm::event::KeyEvent::new(code, modifiers);
 
                 // Mode chord resolution (via KeybindingResolver)
+                let when_state = self.when_state();
                 let (chord_result, resolved_action) = {
                     let keybindings = self.keybindings.read().unwrap();
                     let chord_result = keybindings.resolve_chord(
@@ -716,24 +764,25 @@ impl Editor {
                         &key_event,
                         mode_ctx.clone(),
                     );
-                    let resolved = keybindings.resolve(&key_event, mode_ctx);
+                    let resolved =
+                        keybindings.resolve_in_state(&key_event, mode_ctx.clone(), &when_state);
                     (chord_result, resolved)
                 };
                 match chord_result {
                     crate::input::keybindings::ChordResolution::Complete(action) => {
                         tracing::debug!("Mode chord resolved to action: {:?}", action);
-                        self.active_window_mut().chord_state.clear();
+                        self.clear_chord();
                         return self.handle_action(action);
                     }
                     crate::input::keybindings::ChordResolution::Partial => {
                         tracing::debug!("Potential chord prefix in mode '{}'", mode_name);
-                        self.active_window_mut().chord_state.push((code, modifiers));
+                        self.push_chord_key(code, modifiers, mode_ctx);
                         return Ok(());
                     }
                     crate::input::keybindings::ChordResolution::NoMatch => {
                         if !self.active_window_mut().chord_state.is_empty() {
                             tracing::debug!("Chord sequence abandoned in mode, clearing state");
-                            self.active_window_mut().chord_state.clear();
+                            self.clear_chord();
                         }
                     }
                 }
@@ -744,6 +793,12 @@ impl Editor {
                 }
             }
 
+            // Plugin key layers get the keys the mode leaves unbound, before
+            // text-input or read-only modes swallow them.
+            if let Some(result) = self.handle_key_layer_key(code, modifiers) {
+                return result;
+            }
+
             // Handle unbound keys for modes that want to capture input.
             //
             // Buffer-local modes with allow_text_input (e.g. search-replace-list)
@@ -883,6 +938,7 @@ impl Editor {
 
         // Check for chord sequence matches first
         let key_event = crossterm::event::KeyEvent::new(code, modifiers);
+        let when_state = self.when_state();
         let (chord_result, action) = {
             let keybindings = self.keybindings.read().unwrap();
             let chord_result = keybindings.resolve_chord(
@@ -890,7 +946,7 @@ impl Editor {
                 &key_event,
                 context.clone(),
             );
-            let action = keybindings.resolve(&key_event, context.clone());
+            let action = keybindings.resolve_in_state(&key_event, context.clone(), &when_state);
             (chord_result, action)
         };
 
@@ -898,20 +954,20 @@ impl Editor {
             crate::input::keybindings::ChordResolution::Complete(action) => {
                 // Complete chord match - execute action and clear chord state
                 tracing::debug!("Complete chord match -> Action: {:?}", action);
-                self.active_window_mut().chord_state.clear();
+                self.clear_chord();
                 return self.handle_action(action);
             }
             crate::input::keybindings::ChordResolution::Partial => {
                 // Partial match - add to chord state and wait for more keys
                 tracing::debug!("Partial chord match - waiting for next key");
-                self.active_window_mut().chord_state.push((code, modifiers));
+                self.push_chord_key(code, modifiers, context);
                 return Ok(());
             }
             crate::input::keybindings::ChordResolution::NoMatch => {
                 // No chord match - clear state and try regular resolution
                 if !self.active_window_mut().chord_state.is_empty() {
                     tracing::debug!("Chord sequence abandoned, clearing state");
-                    self.active_window_mut().chord_state.clear();
+                    self.clear_chord();
                 }
             }
         }
@@ -942,6 +998,65 @@ impl Editor {
         self.handle_action(action)
     }
 
+    /// Resolve a key against plugin key layers: defined modes switched on
+    /// as a custom context (`editor.setContext(name, true)`) instead of as
+    /// the editor mode, so they coexist with whatever mode is active. Only
+    /// keys a layer binds itself are claimed. Returns `None` to let the key
+    /// continue to normal resolution.
+    fn handle_key_layer_key(
+        &mut self,
+        code: KeyCode,
+        modifiers: KeyModifiers,
+    ) -> Option<AnyhowResult<()>> {
+        use crate::input::keybindings::{ChordResolution, KeyContext};
+
+        let window = self.active_window();
+        let layers: Vec<KeyContext> = match &window.chord_context {
+            // A pending chord continues only in the layer that started it
+            Some(KeyContext::Mode(name)) if window.active_custom_contexts.contains(name) => {
+                vec![KeyContext::Mode(name.clone())]
+            }
+            Some(_) => return None,
+            None => {
+                let mut names: Vec<&String> = window
+                    .active_custom_contexts
+                    .iter()
+                    .filter(|name| self.mode_registry.has_mode(name))
+                    .collect();
+                names.sort();
+                names
+                    .into_iter()
+                    .map(|name| KeyContext::Mode(name.clone()))
+                    .collect()
+            }
+        };
+
+        let key_event = crossterm::event::KeyEvent::new(code, modifiers);
+        for layer in layers {
+            let resolution = self.keybindings.read().unwrap().resolve_layer(
+                &self.active_window().chord_state,
+                &key_event,
+                &layer,
+            );
+            match resolution {
+                ChordResolution::Complete(action) => {
+                    self.clear_chord();
+                    return Some(self.handle_action(action));
+                }
+                ChordResolution::Partial => {
+                    self.push_chord_key(code, modifiers, layer);
+                    return Some(Ok(()));
+                }
+                ChordResolution::NoMatch => {}
+            }
+        }
+        if !self.active_window().chord_state.is_empty() {
+            tracing::debug!("Chord sequence abandoned in key layer, clearing state");
+            self.clear_chord();
+        }
+        None
+    }
+
     /// Handle an action (for normal mode and command execution).
     /// Used by the app module internally and by the GUI module for native menu dispatch.
     /// Change the current workspace's trust level, persist it, and report it.
@@ -1010,6 +1125,15 @@ impl Editor {
             self.reset_dabbrev_state();
         }
 
+        // While a snippet is being filled in, Tab / Shift+Tab move between
+        // its tab stops and Escape ends it.
+        match action {
+            Action::InsertTab if self.snippet_tabstop_step(true) => return Ok(()),
+            Action::DedentSelection if self.snippet_tabstop_step(false) => return Ok(()),
+            Action::RemoveSecondaryCursors => self.end_snippet_session(),
+            _ => {}
+        }
+
         match action {
             Action::Quit => self.quit(),
             Action::ForceQuit => {
@@ -1131,6 +1255,7 @@ impl Editor {
             Action::New => {
                 self.new_buffer();
             }
+            Action::NewScratchBuffer => self.start_new_scratch_buffer_prompt(),
             Action::Close | Action::CloseTab => {
                 // Both Close and CloseTab use close_tab() which handles:
                 // - Closing the split if this is the last buffer and there are other splits
@@ -1138,6 +1263,23 @@ impl Editor {
                 // - Properly closing the buffer
                 self.close_tab();
             }
+            Action::CloseOtherTabs => {
+                let split_id = self.split_manager().active_split();
+                self.close_other_tabs_in_split(self.active_buffer(), split_id);
+            }
+            Action::CloseTabsToRight => {
+                let split_id = self.split_manager().active_split();
+                self.close_tabs_to_right_in_split(self.active_buffer(), split_id);
+            }
+            Action::CloseSavedTabs => {
+                let split_id = self.split_manager().active_split();
+                self.close_saved_tabs_in_split(split_id);
+            }
+            Action::ReopenClosedTab => self.reopen_closed_buffer(),
+            Action::KeepPreviewTab => {
+                self.active_window_mut()
+                    .promote_active_buffer_from_preview();
+            }
             Action::Revert => {
                 // Check if buffer has unsaved changes - prompt for confirmation
                 if self.active_state().buffer.is_modified() {
@@ -1167,6 +1309,9 @@ impl Editor {
             Action::OpenUpdateLog => {
                 self.show_self_update_output();
             }
+            Action::ToggleTelemetry => self.toggle_telemetry(),
+            Action::ToggleUpdateCheck => self.toggle_update_check(),
+            Action::ShowTelemetryData => self.show_telemetry_data(),
             Action::UpdateFresh => {
                 // Once an update is running or finished, the indicator's job is
                 // to surface the update terminal, not to re-offer the update —
@@ -1187,9 +1332,10 @@ impl Editor {
                 } else {
                     // An update is available — offer it via the popup regardless
                     // of install method. Choosing "Update" opens the local update
-                    // terminal (`fresh --cmd update --yes`); for unknown/source
-                    // installs that terminal just prints the releases page and
-                    // exits, but the UX stays consistent with every other state.
+                    // terminal (`fresh --cmd update --yes`); a hand-placed binary
+                    // is swapped in place there, while source installs just get
+                    // the releases page, but the UX stays consistent with every
+                    // other state.
                     let version = self.latest_version().unwrap_or("").to_string();
                     self.show_update_popup(&version);
                 }
@@ -1240,6 +1386,14 @@ impl Editor {
                     }
                 }
             }
+            Action::RevertLastTransform => {
+                if self.refuse_if_editing_disabled() {
+                    return Ok(());
+                }
+                self.revert_last_transform();
+            }
+            Action::FileHistory => self.show_file_history(),
+            Action::FileHistoryShowDiff => self.show_file_history_diff(),
             Action::Copy => {
                 // Editor-level popups take precedence over everything, including the file explorer.
                 let popup = self
@@ -1311,6 +1465,9 @@ impl Editor {
             Action::CopyWithTheme(theme) => self.copy_selection_with_theme(&theme),
             Action::CopyFilePath => self.copy_active_buffer_path(false),
             Action::CopyRelativeFilePath => self.copy_active_buffer_path(true),
+            Action::CopyFilePathWithLine => self.copy_active_buffer_path_with_line(),
+            Action::ServeBuffer => self.serve_buffer(),
+            Action::StopServingBuffer => self.stop_serving_buffer(),
             Action::Cut => {
                 if self.active_window_mut().key_context
                     == crate::input::keybindings::KeyContext::FileExplorer
@@ -1360,6 +1517,10 @@ impl Editor {
                 }
                 self.paste()
             }
+            Action::PasteFromHistory => self.show_clipboard_history(),
+            Action::CyclePasteHistory => self.cycle_paste_history(),
+            Action::ClipboardHistoryTogglePin => self.toggle_clipboard_history_pin(),
+            Action::ClipboardHistoryPasteIndented => self.paste_clipboard_history_entry_indented(),
             Action::SelectAll => {
                 // Focused widget Text wins over the buffer's
                 // select-all. SelectAll on the buffer is then
@@ -1397,6 +1558,18 @@ impl Editor {
             Action::ShowStatusLog => {
                 self.open_status_log();
             }
+            Action::ShowMessages => {
+                self.show_messages();
+            }
+            Action::CycleMessageFilter => {
+                self.cycle_message_filter();
+            }
+            Action::ShowEditorLog => {
+                self.show_editor_log();
+            }
+            Action::FilterEditorLog => {
+                self.start_editor_log_filter_prompt();
+            }
             Action::ShowLspStatus => {
                 self.show_lsp_status_popup();
             }
@@ -1406,6 +1579,9 @@ impl Editor {
             Action::ShowReadOnlyMenu => {
                 self.show_read_only_popup();
             }
+            Action::ShowJobs => {
+                self.show_jobs_popup();
+            }
             Action::ClearWarnings => {
                 self.active_window_mut().clear_warnings();
             }
@@ -1641,6 +1817,11 @@ impl Editor {
             Action::TogglePageView => {
                 self.active_window_mut().handle_toggle_page_view();
             }
+            Action::StartPresentation => self.open_presentation(),
+            Action::MarkdownPreview => self.show_markdown_preview(),
+            Action::PreviewImage => self.preview_image(),
+            Action::ExpandSelectionToNode => self.expand_selection_to_node(),
+            Action::ShrinkSelection => self.shrink_selection(),
             Action::SetPageWidth => {
                 let active_split = self
                     .windows
@@ -1690,7 +1871,7 @@ impl Editor {
                 );
             }
             Action::LspCompletion => {
-                self.request_completion();
+                self.request_completion(None);
             }
             Action::DabbrevExpand => {
                 if self.refuse_if_editing_disabled() {
@@ -1698,9 +1879,20 @@ impl Editor {
                 }
                 self.dabbrev_expand();
             }
+            Action::InsertSnippet => {
+                if !self.refuse_if_editing_disabled() {
+                    self.start_insert_snippet_prompt();
+                }
+            }
             Action::LspGotoDefinition => {
                 self.request_goto_definition()?;
             }
+            Action::GotoTag => {
+                self.start_goto_tag_prompt();
+            }
+            Action::GenerateTags => {
+                self.generate_tags(true);
+            }
             Action::LspRename => {
                 self.start_rename()?;
             }
@@ -1734,15 +1926,49 @@ impl Editor {
             Action::DumpConfig => {
                 self.dump_config();
             }
+            Action::ReloadConfig => {
+                self.reload_config();
+                if !self.in_config_rescue() {
+                    self.set_status_message(t!("config.reloaded").to_string());
+                }
+            }
+            Action::DumpScreen => {
+                self.start_dump_screen_prompt();
+            }
             Action::RedrawScreen => {
                 self.request_full_redraw();
             }
             Action::SelectTheme => {
-                self.start_select_theme_prompt();
+                self.start_select_theme_prompt(true);
+            }
+            Action::PreviewThemes => {
+                self.start_select_theme_prompt(false);
             }
             Action::InspectThemeAtCursor => {
                 self.inspect_theme_at_cursor();
             }
+            Action::DescribeChar => {
+                self.describe_char_at_cursor();
+            }
+            Action::EvaluateSelection => {
+                self.evaluate_selection(super::calc_actions::CalcOutput::Popup);
+            }
+            Action::EvaluateSelectionReplace => {
+                if !self.refuse_if_editing_disabled() {
+                    self.evaluate_selection(super::calc_actions::CalcOutput::Replace);
+                }
+            }
+            Action::EvaluateSelectionAppend => {
+                if !self.refuse_if_editing_disabled() {
+                    self.evaluate_selection(super::calc_actions::CalcOutput::Append);
+                }
+            }
+            Action::NarrowToSelection => {
+                self.narrow_to_selection();
+            }
+            Action::Widen => {
+                self.widen();
+            }
             Action::SelectKeybindingMap => {
                 self.start_select_keybinding_map_prompt();
             }
@@ -1826,6 +2052,7 @@ impl Editor {
             Action::PrevBuffer => self.prev_buffer(),
             Action::SwitchToPreviousTab => self.switch_to_previous_tab(),
             Action::SwitchToTabByName => self.start_switch_to_tab_prompt(),
+            Action::SwitchBuffer => self.start_buffer_picker(),
 
             // Tab scrolling (manual scroll - don't auto-adjust)
             Action::ScrollTabsLeft => {
@@ -1868,6 +2095,7 @@ impl Editor {
             }
             Action::NavigateBack => self.navigate_back(),
             Action::NavigateForward => self.navigate_forward(),
+            Action::NavigationHistory => self.show_navigation_history(),
             Action::SplitHorizontal => self.split_pane_horizontal(),
             Action::SplitVertical => self.split_pane_vertical(),
             Action::CloseSplit => self.close_active_split(),
@@ -1890,6 +2118,7 @@ impl Editor {
             Action::ToggleTabBar => self.active_window_mut().toggle_tab_bar(),
             Action::ToggleStatusBar => self.active_window_mut().toggle_status_bar(),
             Action::TogglePromptLine => self.active_window_mut().toggle_prompt_line(),
+            Action::ToggleZenMode => self.active_window_mut().toggle_zen_mode(),
             Action::ToggleVerticalScrollbar => self.toggle_vertical_scrollbar(),
             Action::ToggleHorizontalScrollbar => self.toggle_horizontal_scrollbar(),
             Action::ToggleLineNumbers => self.toggle_line_numbers(),
@@ -1901,6 +2130,7 @@ impl Editor {
             Action::ToggleMouseCapture => self.toggle_mouse_capture(),
             Action::ToggleMouseHover => self.toggle_mouse_hover(),
             Action::ToggleDebugHighlights => self.active_window_mut().toggle_debug_highlights(),
+            Action::TogglePerfHud => self.toggle_perf_hud(),
             // Rulers
             Action::AddRuler => {
                 self.start_prompt(t!("rulers.add_prompt").to_string(), PromptType::AddRuler);
@@ -1924,12 +2154,44 @@ impl Editor {
             Action::SetLineEnding => {
                 self.start_set_line_ending_prompt();
             }
+            Action::ConvertLineEndingsToLf => {
+                if !self.refuse_if_editing_disabled() {
+                    self.convert_line_endings(crate::model::buffer::LineEnding::LF);
+                }
+            }
+            Action::ConvertLineEndingsToCrlf => {
+                if !self.refuse_if_editing_disabled() {
+                    self.convert_line_endings(crate::model::buffer::LineEnding::CRLF);
+                }
+            }
+            Action::ConvertIndentationToSpaces => {
+                if !self.refuse_if_editing_disabled() {
+                    self.convert_indentation(
+                        crate::primitives::indent_normalize::IndentStyle::Spaces,
+                    );
+                }
+            }
+            Action::ConvertIndentationToTabs => {
+                if !self.refuse_if_editing_disabled() {
+                    self.convert_indentation(
+                        crate::primitives::indent_normalize::IndentStyle::Tabs,
+                    );
+                }
+            }
+            Action::FixEncodingArtifacts => {
+                if !self.refuse_if_editing_disabled() {
+                    self.start_fix_encoding_artifacts();
+                }
+            }
             Action::SetEncoding => {
                 self.start_set_encoding_prompt();
             }
             Action::ReloadWithEncoding => {
                 self.start_reload_with_encoding_prompt();
             }
+            Action::SaveWithEncoding => {
+                self.start_save_with_encoding_prompt();
+            }
             Action::SetLanguage => {
                 self.start_set_language_prompt();
             }
@@ -2063,6 +2325,9 @@ impl Editor {
             Action::MenuActivate => {
                 self.handle_menu_activate();
             }
+            Action::OpenContextMenu => {
+                self.open_buffer_context_menu_at_cursor();
+            }
             Action::MenuClose => {
                 self.handle_menu_close();
             }
@@ -2113,6 +2378,15 @@ impl Editor {
             Action::ToggleComment => {
                 self.toggle_comment();
             }
+            Action::ToggleBlockComment => {
+                self.toggle_block_comment();
+            }
+            Action::JoinLines => {
+                self.join_lines();
+            }
+            Action::SplitLine => {
+                self.split_line();
+            }
             Action::ToggleFold => {
                 self.active_window_mut().toggle_fold_at_cursor();
             }
@@ -2137,9 +2411,20 @@ impl Editor {
             Action::ListBookmarks => {
                 self.active_window_mut().list_bookmarks();
             }
+            Action::ToggleLineBookmark => {
+                self.active_window_mut().toggle_line_bookmark();
+            }
+            Action::NextLineBookmark => self.goto_line_bookmark(true),
+            Action::PrevLineBookmark => self.goto_line_bookmark(false),
+            Action::LabelLineBookmark => self.prompt_label_line_bookmark(),
+            Action::ShowLineBookmarks => self.show_line_bookmarks(),
+            Action::SetNamedMark(key) => self.active_window_mut().set_named_mark(key),
+            Action::GotoNamedMark(key) => self.goto_named_mark(key),
+            Action::ListNamedMarks => self.show_named_marks(),
             Action::ToggleSearchCaseSensitive if !self.active_prompt_has_search_options() => {}
             Action::ToggleSearchWholeWord if !self.active_prompt_has_search_options() => {}
             Action::ToggleSearchRegex if !self.active_prompt_has_search_options() => {}
+            Action::ToggleSearchMultiline if !self.active_prompt_has_search_options() => {}
             Action::ToggleSearchCaseSensitive => {
                 self.active_window_mut().search_case_sensitive =
                     !self.active_window().search_case_sensitive;
@@ -2174,6 +2459,16 @@ impl Editor {
                 self.set_status_message(t!("search.regex_state", state = state).to_string());
                 self.refresh_active_search();
             }
+            Action::ToggleSearchMultiline => {
+                self.active_window_mut().search_multiline = !self.active_window().search_multiline;
+                let state = if self.active_window().search_multiline {
+                    "enabled"
+                } else {
+                    "disabled"
+                };
+                self.set_status_message(t!("search.multiline_state", state = state).to_string());
+                self.refresh_active_search();
+            }
             Action::ToggleSearchConfirmEach => {
                 self.active_window_mut().search_confirm_each =
                     !self.active_window().search_confirm_each;
@@ -2243,6 +2538,15 @@ impl Editor {
                     PromptType::JumpToBookmark,
                 );
             }
+            Action::PromptSetNamedMark => {
+                self.start_prompt(t!("mark.prompt_set").to_string(), PromptType::SetNamedMark);
+            }
+            Action::PromptGotoNamedMark => {
+                self.start_prompt(
+                    t!("mark.prompt_goto").to_string(),
+                    PromptType::GotoNamedMark,
+                );
+            }
             Action::CompositeNextHunk => {
                 let buf = self.active_buffer();
                 self.active_window_mut().composite_next_hunk_active(buf);
@@ -2271,14 +2575,17 @@ impl Editor {
                         }
                     } else {
                         for event in events {
-                            self.active_event_log_mut().append(event.clone());
-                            self.apply_event_to_active_buffer(&event);
+                            self.log_and_apply_event(&event);
                         }
                     }
                 }
             }
             Action::PluginAction(action_name) => {
                 tracing::debug!("handle_action: PluginAction('{}')", action_name);
+                #[cfg(feature = "scripting")]
+                if self.run_script_command(&action_name) {
+                    return Ok(());
+                }
                 // Execute the plugin callback via TypeScript plugin thread
                 // Use non-blocking version to avoid deadlock with async plugin ops
                 #[cfg(feature = "plugins")]
@@ -2355,7 +2662,7 @@ impl Editor {
                             ));
                         }
                         Err(e) => {
-                            self.set_status_message(format!("Failed to load plugin: {}", e));
+                            self.set_status_error(format!("Failed to load plugin: {}", e));
                             tracing::error!("LoadPluginFromBuffer error: {}", e);
                         }
                     }
@@ -2403,12 +2710,12 @@ impl Editor {
                                 self.set_status_message(format!("init.ts: {}", path.display()));
                             }
                             Err(e) => {
-                                self.set_status_message(format!("init.ts: open failed: {e}"));
+                                self.set_status_error(format!("init.ts: open failed: {e}"));
                             }
                         }
                     }
                     Err(e) => {
-                        self.set_status_message(format!("init.ts: create failed: {e}"));
+                        self.set_status_error(format!("init.ts: create failed: {e}"));
                     }
                 }
             }
@@ -2435,6 +2742,14 @@ impl Editor {
                     ));
                 }
             }
+            Action::ScriptsReload => {
+                #[cfg(feature = "scripting")]
+                self.load_user_scripts(true);
+                #[cfg(not(feature = "scripting"))]
+                self.set_status_message(
+                    "Scripts not available (compiled without scripting support)".to_string(),
+                );
+            }
             Action::OpenTerminal => {
                 self.open_terminal();
             }
@@ -2487,8 +2802,7 @@ impl Editor {
                 // Paste clipboard contents into terminal as a single batch
                 if self.active_window().focused_terminal_live() {
                     if let Some(text) = self.clipboard.paste() {
-                        self.active_window_mut()
-                            .send_terminal_input(text.as_bytes());
+                        self.paste_into_terminal(text);
                     }
                 }
             }
@@ -2564,6 +2878,12 @@ impl Editor {
             Action::CalibrateInput => {
                 self.open_calibration_wizard();
             }
+            Action::SelectCalibrationProfile => {
+                self.start_select_calibration_profile_prompt();
+            }
+            Action::ShowTerminalCapabilities => {
+                self.show_terminal_capabilities_popup();
+            }
             Action::EventDebug => {
                 self.active_window_mut().open_event_debug();
             }
@@ -2627,6 +2947,9 @@ impl Editor {
             Action::CompletionDismiss => {
                 self.handle_popup_cancel();
             }
+            Action::CompletionDocsScrollDown => self.page_completion_docs(true),
+            Action::CompletionDocsScrollUp => self.page_completion_docs(false),
+            Action::SmartTab => self.smart_tab()?,
             Action::InsertChar(c) => {
                 if self.is_prompting() {
                     return self.handle_insert_char_prompt(c);
@@ -3162,6 +3485,7 @@ impl Editor {
             ) {
                 let query = prompt.input.clone();
                 self.update_search_highlights(&query);
+                self.update_incremental_match_count(&query);
             }
         } else if let Some(search_state) = &self.active_window().search_state {
             let query = search_state.query.clone();
@@ -3208,7 +3532,7 @@ impl Editor {
             .expect("active window must have a populated split layout")
             .split_root_positioned(SplitDirection::Horizontal, buffer_id, 0.7, false)
             .map_err(|e| {
-                self.set_status_message(format!("Failed to create dock for terminal: {}", e));
+                self.set_status_error(format!("Failed to create dock for terminal: {}", e));
             });
         let Ok(new_leaf) = new_leaf else {
             return Ok(());
@@ -3231,6 +3555,8 @@ impl Editor {
                 .resolve_wrap_column_for_buffer(buffer_id),
             rulers: self.config.editor.rulers.clone(),
             scroll_offset: 0,
+            scroll_past_end: false,
+            typewriter_scrolling: crate::config::TypewriterScrolling::Off,
         });
         // Terminals don't wrap — keep escape sequences intact.
         view_state.viewport.line_wrap_enabled = false;
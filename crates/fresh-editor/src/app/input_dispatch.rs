@@ -99,8 +99,9 @@ impl Editor {
     }
 
     /// Walk the overlay stack top-down and, if a *capture-all* modal
-    /// (Settings / KeybindingEditor / CalibrationWizard / Menu) is the
-    /// keyboard owner, dispatch to its handler and return its result.
+    /// (Settings / KeybindingEditor / CalibrationWizard / Presentation /
+    /// Menu) is the keyboard owner, dispatch to its handler and return its
+    /// result.
     /// Returns `None` when no such modal is up, letting the caller fall
     /// through to the Prompt / Popup blocks (which have their own
     /// fall-through semantics that don't fit a top-down kind-walk).
@@ -115,6 +116,7 @@ impl Editor {
             LayerKind::Settings
             | LayerKind::KeybindingEditor
             | LayerKind::CalibrationWizard
+            | LayerKind::Presentation
             | LayerKind::Menu => Some(l.kind),
             _ => None,
         })?;
@@ -133,6 +135,7 @@ impl Editor {
             }
             LayerKind::KeybindingEditor => self.handle_keybinding_editor_input(event),
             LayerKind::CalibrationWizard => self.handle_calibration_input(event),
+            LayerKind::Presentation => self.handle_presentation_input(event),
             LayerKind::Menu => {
                 let all_menus: Vec<crate::config::Menu> = self
                     .menus
@@ -301,6 +304,7 @@ impl Editor {
                     .popups
                     .dispatch_input(event, &mut ctx);
                 self.process_deferred_actions(ctx);
+                self.update_completion_docs();
                 // If the popup handler returned Ignored (e.g., non-word
                 // character, Ctrl+key, arrow keys), fall through to normal
                 // input handling. The deferred ClosePopup action was already
@@ -392,6 +396,12 @@ impl Editor {
                     }
                 }
             }
+            DeferredAction::PreviewBufferFromPrompt => {
+                self.preview_buffer_from_picker();
+            }
+            DeferredAction::CloseBufferFromPicker => {
+                self.close_buffer_from_picker();
+            }
             DeferredAction::PromptSelectionChanged { selected_index } => {
                 // Fire hook for plugin prompts so they can update live preview
                 let plugin_custom_type =
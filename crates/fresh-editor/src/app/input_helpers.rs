@@ -255,6 +255,11 @@ impl Editor {
         // Cancel any pending LSP requests since the text is changing
         self.active_window_mut().cancel_pending_lsp_requests();
 
+        // An abbreviation with tab stops expands in place of the character.
+        if self.expand_template_abbreviation(c) {
+            return Ok(());
+        }
+
         if let Some(events) = self
             .active_window_mut()
             .action_to_events(Action::InsertChar(c))
@@ -269,12 +274,14 @@ impl Editor {
             } else {
                 // Single cursor - apply normally
                 for event in events {
-                    self.active_event_log_mut().append(event.clone());
-                    self.apply_event_to_active_buffer(&event);
+                    self.log_and_apply_event(&event);
                 }
             }
         }
 
+        // A plain abbreviation expands after the character, as its own undo step.
+        self.expand_abbreviation(c);
+
         // Auto-trigger signature help on '(' and ','
         if c == '(' || c == ',' {
             self.request_signature_help();
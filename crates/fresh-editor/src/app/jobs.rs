@@ -0,0 +1,485 @@
+//! Jobs: background processes a plugin starts with `editor.spawnJob`.
+//!
+//! A job runs like any background process — output lines go to the
+//! `onProcessStdout` / `onProcessStderr` hooks and its exit resolves the
+//! plugin's promise — but the editor also keeps a record of it while it
+//! runs: a title and the latest progress the plugin reported. That record
+//! is the editor's task manager:
+//! - the `{jobs}` status-bar element shows the running job, or how many
+//!   are running;
+//! - the jobs menu (click the element, or "Show Running Jobs") lists them
+//!   and cancels the one selected.
+//!
+//! A job that exits non-zero is reported in the status bar, so a failing
+//! linter or deploy script is never silent.
+
+use std::collections::BTreeMap;
+
+use fresh_core::api::JsCallbackId;
+use rust_i18n::t;
+#[cfg(feature = "plugins")]
+use tokio::sync::mpsc::UnboundedSender;
+
+use super::Editor;
+
+/// A running job.
+pub(crate) struct Job {
+    /// Plugin that started it (`hooks` for a config hook's command)
+    pub plugin: String,
+    /// Name shown in the status bar and jobs menu
+    pub title: String,
+    /// Latest progress message
+    pub message: Option<String>,
+    /// Latest completion percentage, 0-100
+    pub percent: Option<u8>,
+    /// Resolves the plugin's `JobHandle` when the job ends; `None` for a
+    /// config hook's command, which nothing waits on
+    callback_id: Option<JsCallbackId>,
+    /// Feeds the process's stdin; `None` when stdin isn't piped or was closed
+    #[cfg(feature = "plugins")]
+    stdin: Option<UnboundedSender<String>>,
+}
+
+impl Job {
+    /// One line describing the job: its title, then its progress.
+    pub(crate) fn summary(&self) -> String {
+        let mut text = self.title.clone();
+        if let Some(message) = &self.message {
+            text.push_str(": ");
+            text.push_str(message);
+        }
+        if let Some(percent) = self.percent {
+            text.push_str(&format!(" {}%", percent));
+        }
+        text
+    }
+}
+
+/// Text of the `{jobs}` status-bar element: the summary of the only running
+/// job, a count when several run, empty when none do.
+pub(crate) fn compose_jobs_status(jobs: &BTreeMap<u64, Job>) -> String {
+    let mut running = jobs.values();
+    match (running.next(), running.next()) {
+        (None, _) => String::new(),
+        (Some(job), None) => format!("\u{2699} {}", job.summary()),
+        (Some(_), Some(_)) => {
+            format!("\u{2699} {}", t!("jobs.status_many", count = jobs.len()))
+        }
+    }
+}
+
+impl Editor {
+    /// Start a job for `plugin` and list it until it exits.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn handle_spawn_job(
+        &mut self,
+        plugin: String,
+        job_id: u64,
+        command: String,
+        args: Vec<String>,
+        cwd: Option<String>,
+        title: String,
+        stdin: bool,
+        callback_id: Option<JsCallbackId>,
+    ) {
+        #[cfg_attr(not(feature = "plugins"), allow(unused_variables))]
+        let (stdin_tx, stdin_rx) = if stdin {
+            let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+            (Some(tx), Some(rx))
+        } else {
+            (None, None)
+        };
+        // The process's exit carries a callback id either way; a hook job's
+        // is never resolved (see `finish_job`).
+        self.handle_spawn_background_process(
+            job_id,
+            command,
+            args,
+            cwd,
+            callback_id.unwrap_or_else(|| JsCallbackId::from(job_id)),
+            stdin_rx,
+        );
+        // The spawn rejects the callback when there is no async runtime;
+        // only list jobs that actually started.
+        if self.background_process_handles.contains_key(&job_id) {
+            self.jobs.insert(
+                job_id,
+                Job {
+                    plugin,
+                    title,
+                    message: None,
+                    percent: None,
+                    callback_id,
+                    #[cfg(feature = "plugins")]
+                    stdin: stdin_tx,
+                },
+            );
+        }
+    }
+
+    #[cfg(feature = "plugins")]
+    pub(super) fn handle_write_job_stdin(&mut self, job_id: u64, data: String) {
+        let Some(job) = self.jobs.get(&job_id) else {
+            return;
+        };
+        match &job.stdin {
+            Some(stdin) => {
+                // The writer task is gone once the process exits.
+                if stdin.send(data).is_err() {}
+            }
+            None => tracing::warn!(
+                "plugin '{}' wrote to job {} without stdin",
+                job.plugin,
+                job_id
+            ),
+        }
+    }
+
+    /// Drop the stdin sender; the writer task then closes the pipe.
+    #[cfg(feature = "plugins")]
+    pub(super) fn handle_close_job_stdin(&mut self, job_id: u64) {
+        if let Some(job) = self.jobs.get_mut(&job_id) {
+            job.stdin = None;
+        }
+    }
+
+    #[cfg(feature = "plugins")]
+    pub(super) fn handle_set_job_progress(
+        &mut self,
+        job_id: u64,
+        message: Option<String>,
+        percent: Option<u8>,
+    ) {
+        if let Some(job) = self.jobs.get_mut(&job_id) {
+            job.message = message;
+            job.percent = percent;
+        }
+    }
+
+    /// A background process exited. If it was a job, drop it from the list
+    /// and report a failure. Returns whether a plugin waits on the exit —
+    /// false only for a config hook's job.
+    pub(super) fn finish_job(&mut self, process_id: u64, exit_code: i32) -> bool {
+        let Some(job) = self.jobs.remove(&process_id) else {
+            return true;
+        };
+        if exit_code != 0 {
+            self.set_status_message(
+                t!("jobs.failed", title = job.title, code = exit_code).to_string(),
+            );
+        }
+        job.callback_id.is_some()
+    }
+
+    /// A job was killed: its process never reports an exit, so resolve the
+    /// plugin's handle here with exit code -1.
+    pub(super) fn cancel_job(&mut self, job_id: u64) {
+        let Some(callback_id) = self.jobs.remove(&job_id).and_then(|job| job.callback_id) else {
+            return;
+        };
+        let result = fresh_core::api::BackgroundProcessResult {
+            process_id: job_id,
+            exit_code: -1,
+        };
+        self.plugin_manager
+            .read()
+            .unwrap()
+            .resolve_callback(callback_id, serde_json::to_string(&result).unwrap());
+    }
+
+    /// Titles and progress of the running jobs, oldest first.
+    pub fn running_jobs(&self) -> Vec<String> {
+        self.jobs.values().map(Job::summary).collect()
+    }
+
+    /// Show the jobs menu, anchored to the status bar's `{jobs}` segment:
+    /// one row per running job (selecting it cancels the job) and a row to
+    /// close the menu. Toggles closed on a second click, mirroring the
+    /// read-only and update menus.
+    pub fn show_jobs_popup(&mut self) {
+        use crate::view::popup::{
+            Popup, PopupContent, PopupKind, PopupListItem, PopupPosition, PopupResolver,
+        };
+        use ratatui::style::Style;
+
+        if self
+            .active_state()
+            .popups
+            .top()
+            .is_some_and(|p| matches!(p.resolver, PopupResolver::Jobs))
+        {
+            self.hide_popup();
+            return;
+        }
+        if self.jobs.is_empty() {
+            self.set_status_message(t!("jobs.none").to_string());
+            return;
+        }
+        self.dismiss_menu_popups_for_prompt();
+
+        let mut items: Vec<PopupListItem> = self
+            .jobs
+            .iter()
+            .map(|(id, job)| {
+                PopupListItem::new(format!(
+                    "    {}",
+                    t!("jobs.menu.cancel", job = job.summary())
+                ))
+                .with_data(id.to_string())
+            })
+            .collect();
+        items.push(
+            PopupListItem::new(format!("    {}", t!("jobs.menu.close")))
+                .with_data("close".to_string()),
+        );
+
+        let position = self
+            .active_chrome()
+            .status_bar
+            .clickable_area(crate::view::ui::status_bar::StatusBarClickable::Jobs)
+            .map(
+                |(status_row, col_start, _)| PopupPosition::AboveStatusBarAt {
+                    x: col_start,
+                    status_row,
+                },
+            )
+            .unwrap_or(PopupPosition::BottomRight);
+
+        let popup_width = (items
+            .iter()
+            .map(|i| unicode_width::UnicodeWidthStr::width(i.text.as_str()))
+            .max()
+            .unwrap_or(24)
+            + 4) as u16;
+
+        let popup = Popup {
+            kind: PopupKind::List,
+            title: Some(t!("jobs.menu.title").to_string()),
+            description: None,
+            transient: false,
+            content: PopupContent::List { items, selected: 0 },
+            position,
+            width: popup_width.clamp(28, 70),
+            max_height: 12,
+            bordered: true,
+            border_style: Style::default().fg(self.theme.read().unwrap().popup_border_fg),
+            background_style: Style::default().bg(self.theme.read().unwrap().popup_bg),
+            scroll_offset: 0,
+            text_selection: None,
+            accept_key_hint: None,
+            resolver: PopupResolver::Jobs,
+            focused: true,
+            focus_key_hint: None,
+        };
+
+        let buffer_id = self.active_buffer();
+        if let Some(state) = self
+            .windows
+            .get_mut(&self.active_window)
+            .map(|w| &mut w.buffers)
+            .expect("active window present")
+            .get_mut(&buffer_id)
+        {
+            state.popups.show(popup);
+        }
+    }
+
+    /// Dispatch the row selected from the jobs menu: a job id cancels that
+    /// job, `"close"` is a no-op (the popup already closed).
+    pub fn handle_jobs_menu_action(&mut self, action_key: &str) {
+        match action_key.parse::<u64>() {
+            Ok(job_id) => self.handle_kill_background_process(job_id),
+            Err(_) if action_key == "close" => {}
+            Err(_) => tracing::warn!(
+                "handle_jobs_menu_action: unknown action key '{}'",
+                action_key
+            ),
+        }
+    }
+
+    /// Spawn a background process with streaming output. With `stdin`, the
+    /// process's stdin is piped and fed from that channel until it closes.
+    pub(super) fn handle_spawn_background_process(
+        &mut self,
+        process_id: u64,
+        command: String,
+        args: Vec<String>,
+        cwd: Option<String>,
+        callback_id: JsCallbackId,
+        stdin: Option<tokio::sync::mpsc::UnboundedReceiver<String>>,
+    ) {
+        // Spawn background process with streaming output via tokio
+        if let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) {
+            use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+            use tokio::process::Command as TokioCommand;
+
+            let effective_cwd = cwd.unwrap_or_else(|| {
+                std::env::current_dir()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_else(|_| ".".to_string())
+            });
+
+            let sender = bridge.sender();
+            let sender_stdout = sender.clone();
+            let sender_stderr = sender.clone();
+            let callback_id_u64 = callback_id.as_u64();
+
+            // Receiver may be dropped if editor is shutting down
+            #[allow(clippy::let_underscore_must_use)]
+            let handle = runtime.spawn(async move {
+                use crate::services::process_hidden::HideWindow;
+                let mut cmd = TokioCommand::new(&command);
+                cmd.args(&args)
+                    .current_dir(&effective_cwd)
+                    .stdout(std::process::Stdio::piped())
+                    .stderr(std::process::Stdio::piped())
+                    // Killing aborts this task; take the child down with it.
+                    .kill_on_drop(true);
+                if stdin.is_some() {
+                    cmd.stdin(std::process::Stdio::piped());
+                }
+                let mut child = match cmd.hide_window().spawn() {
+                    Ok(child) => child,
+                    Err(e) => {
+                        let _ = sender.send(crate::services::async_bridge::AsyncMessage::Plugin(
+                            fresh_core::api::PluginAsyncMessage::ProcessExit {
+                                process_id,
+                                callback_id: callback_id_u64,
+                                exit_code: -1,
+                            },
+                        ));
+                        tracing::error!("Failed to spawn background process: {}", e);
+                        return;
+                    }
+                };
+
+                // Feed stdin until the sender is dropped, then close it.
+                if let (Some(mut input), Some(mut child_stdin)) = (stdin, child.stdin.take()) {
+                    tokio::spawn(async move {
+                        while let Some(data) = input.recv().await {
+                            if child_stdin.write_all(data.as_bytes()).await.is_err() {
+                                break;
+                            }
+                        }
+                    });
+                }
+
+                // Stream stdout
+                let stdout = child.stdout.take();
+                let stderr = child.stderr.take();
+                let pid = process_id;
+                let mut readers = Vec::new();
+
+                // Spawn stdout reader
+                if let Some(stdout) = stdout {
+                    let sender = sender_stdout;
+                    readers.push(tokio::spawn(async move {
+                        let reader = BufReader::new(stdout);
+                        let mut lines = reader.lines();
+                        while let Ok(Some(line)) = lines.next_line().await {
+                            let _ =
+                                sender.send(crate::services::async_bridge::AsyncMessage::Plugin(
+                                    fresh_core::api::PluginAsyncMessage::ProcessStdout {
+                                        process_id: pid,
+                                        data: line + "\n",
+                                    },
+                                ));
+                        }
+                    }));
+                }
+
+                // Spawn stderr reader
+                if let Some(stderr) = stderr {
+                    let sender = sender_stderr;
+                    readers.push(tokio::spawn(async move {
+                        let reader = BufReader::new(stderr);
+                        let mut lines = reader.lines();
+                        while let Ok(Some(line)) = lines.next_line().await {
+                            let _ =
+                                sender.send(crate::services::async_bridge::AsyncMessage::Plugin(
+                                    fresh_core::api::PluginAsyncMessage::ProcessStderr {
+                                        process_id: pid,
+                                        data: line + "\n",
+                                    },
+                                ));
+                        }
+                    }));
+                }
+
+                // Wait for process to complete
+                let exit_code = match child.wait().await {
+                    Ok(status) => status.code().unwrap_or(-1),
+                    Err(_) => -1,
+                };
+                // Deliver the last output lines before the exit.
+                for reader in readers {
+                    let _ = reader.await;
+                }
+
+                let _ = sender.send(crate::services::async_bridge::AsyncMessage::Plugin(
+                    fresh_core::api::PluginAsyncMessage::ProcessExit {
+                        process_id,
+                        callback_id: callback_id_u64,
+                        exit_code,
+                    },
+                ));
+            });
+
+            // Store abort handle for potential kill
+            self.background_process_handles
+                .insert(process_id, handle.abort_handle());
+        } else {
+            // No runtime - reject immediately
+            self.plugin_manager
+                .read()
+                .unwrap()
+                .reject_callback(callback_id, "Async runtime not available".to_string());
+        }
+    }
+
+    /// Kill a background process or job by id.
+    pub(super) fn handle_kill_background_process(&mut self, process_id: u64) {
+        if let Some(handle) = self.background_process_handles.remove(&process_id) {
+            handle.abort();
+            tracing::debug!("Killed background process {}", process_id);
+        }
+        self.cancel_job(process_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(title: &str, message: Option<&str>, percent: Option<u8>) -> Job {
+        Job {
+            plugin: "test".to_string(),
+            title: title.to_string(),
+            message: message.map(String::from),
+            percent,
+            callback_id: None,
+            #[cfg(feature = "plugins")]
+            stdin: None,
+        }
+    }
+
+    #[test]
+    fn summary_appends_progress_to_title() {
+        assert_eq!(job("clippy", None, None).summary(), "clippy");
+        assert_eq!(
+            job("clippy", Some("Checking foo"), Some(40)).summary(),
+            "clippy: Checking foo 40%"
+        );
+        assert_eq!(job("deploy", None, Some(5)).summary(), "deploy 5%");
+    }
+
+    #[test]
+    fn status_shows_the_only_job_or_a_count() {
+        let mut jobs = BTreeMap::new();
+        assert_eq!(compose_jobs_status(&jobs), "");
+        jobs.insert(1, job("clippy", Some("Checking"), None));
+        assert_eq!(compose_jobs_status(&jobs), "\u{2699} clippy: Checking");
+        jobs.insert(2, job("deploy", None, None));
+        assert_eq!(compose_jobs_status(&jobs), "\u{2699} 2 jobs");
+    }
+}
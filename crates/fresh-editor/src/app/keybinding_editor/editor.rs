@@ -2,14 +2,19 @@
 
 use super::helpers::{format_chord_keys, key_code_to_config_name, modifiers_to_config_names};
 use super::types::*;
-use crate::config::{Config, Keybinding};
+use crate::config::{Config, Keybinding, KeymapConfig};
 use crate::input::command_registry::CommandRegistry;
 use crate::input::keybindings::{
     format_keybinding, normalize_key, Action, KeyContext, KeybindingResolver,
 };
+use crate::input::when_clause::WhenClause;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use rust_i18n::t;
 use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+/// Appended to the key of a binding that fires on key release
+const RELEASE_SUFFIX: &str = " (release)";
 
 /// The main keybinding editor state
 #[derive(Debug)]
@@ -48,6 +53,12 @@ pub struct KeybindingEditor {
 
     /// Whether help overlay is showing
     pub showing_help: bool,
+    /// Whether the diff against the built-in keymap is showing
+    pub showing_diff: bool,
+    /// First visible line of the diff overlay
+    pub diff_scroll: usize,
+    /// Export/import path dialog (None = not open)
+    pub file_dialog: Option<KeymapFileDialog>,
 
     /// Active keymap name
     pub active_keymap: String,
@@ -68,6 +79,9 @@ pub struct KeybindingEditor {
 
     /// Named keymaps info for display
     pub keymap_names: Vec<String>,
+    /// Bindings of the active keymap before any custom override, keyed by
+    /// (key_display, context) — the baseline for the diff view
+    keymap_defaults: HashMap<(String, String), ResolvedBinding>,
 
     /// Available action names (for autocomplete)
     pub available_actions: Vec<String>,
@@ -104,6 +118,12 @@ impl KeybindingEditor {
     ) -> Self {
         let bindings =
             Self::resolve_all_bindings(config, resolver, mode_registry, command_registry);
+        let keymap_defaults = config
+            .resolve_keymap(&config.active_keybinding_map)
+            .iter()
+            .filter_map(|kb| Self::keybinding_to_resolved(kb, BindingSource::Keymap))
+            .map(|b| ((b.key_display.clone(), b.context.clone()), b))
+            .collect();
         let filtered_indices: Vec<usize> = (0..bindings.len()).collect();
 
         // Collect available action names (include plugin action names from plugin defaults)
@@ -180,6 +200,9 @@ impl KeybindingEditor {
             source_filter: SourceFilter::All,
             edit_dialog: None,
             showing_help: false,
+            showing_diff: false,
+            diff_scroll: 0,
+            file_dialog: None,
             active_keymap: config.active_keybinding_map.to_string(),
             config_file_path,
             pending_adds: Vec::new(),
@@ -188,6 +211,7 @@ impl KeybindingEditor {
             showing_confirm_dialog: false,
             confirm_selection: 0,
             keymap_names,
+            keymap_defaults,
             available_actions,
             mode_contexts,
             display_rows: Vec::new(),
@@ -213,7 +237,7 @@ impl KeybindingEditor {
         // First, load bindings from the active keymap
         let map_bindings = config.resolve_keymap(&config.active_keybinding_map);
         for kb in &map_bindings {
-            if let Some(entry) = Self::keybinding_to_resolved(kb, BindingSource::Keymap, resolver) {
+            if let Some(entry) = Self::keybinding_to_resolved(kb, BindingSource::Keymap) {
                 let key = (entry.key_display.clone(), entry.context.clone());
                 let idx = bindings.len();
                 seen.insert(key, idx);
@@ -223,7 +247,7 @@ impl KeybindingEditor {
 
         // Then, load custom bindings (these override keymap bindings)
         for kb in &config.keybindings {
-            if let Some(entry) = Self::keybinding_to_resolved(kb, BindingSource::Custom, resolver) {
+            if let Some(entry) = Self::keybinding_to_resolved(kb, BindingSource::Custom) {
                 let key = (entry.key_display.clone(), entry.context.clone());
                 if let Some(&existing_idx) = seen.get(&key) {
                     // Override the existing binding
@@ -359,11 +383,7 @@ impl KeybindingEditor {
     }
 
     /// Convert a Keybinding config entry to a ResolvedBinding
-    fn keybinding_to_resolved(
-        kb: &Keybinding,
-        source: BindingSource,
-        _resolver: &KeybindingResolver,
-    ) -> Option<ResolvedBinding> {
+    fn keybinding_to_resolved(kb: &Keybinding, source: BindingSource) -> Option<ResolvedBinding> {
         let context = kb.when.as_deref().unwrap_or("normal").to_string();
 
         // Store the qualified form (e.g. `menu_open:File`) on ResolvedBinding
@@ -398,7 +418,10 @@ impl KeybindingEditor {
             // Single key binding
             let key_code = KeybindingResolver::parse_key_public(&kb.key)?;
             let modifiers = KeybindingResolver::parse_modifiers_public(&kb.modifiers);
-            let key_display = format_keybinding(&key_code, &modifiers);
+            let mut key_display = format_keybinding(&key_code, &modifiers);
+            if kb.release {
+                key_display.push_str(RELEASE_SUFFIX);
+            }
             let action_display =
                 KeybindingResolver::format_action_from_str_with_args(&kb.action, &kb.args);
             let original_config = if source == BindingSource::Custom {
@@ -512,7 +535,7 @@ impl KeybindingEditor {
         for (i, binding) in self.bindings.iter().enumerate() {
             // Apply context filter
             if let ContextFilter::Specific(ref ctx) = self.context_filter {
-                if &binding.context != ctx {
+                if !Self::context_matches_filter(&binding.context, ctx) {
                     continue;
                 }
             }
@@ -771,6 +794,16 @@ impl KeybindingEditor {
         for mode_ctx in &self.mode_contexts {
             contexts.push(ContextFilter::Specific(mode_ctx.clone()));
         }
+        // Then every compound `when` clause in use (`language:markdown`, …)
+        let mut clauses: Vec<&String> = self
+            .bindings
+            .iter()
+            .map(|b| &b.context)
+            .filter(|c| WhenClause::parse(c).is_ok_and(|clause| clause.as_context().is_none()))
+            .collect();
+        clauses.sort();
+        clauses.dedup();
+        contexts.extend(clauses.into_iter().cloned().map(ContextFilter::Specific));
 
         let current_idx = contexts
             .iter()
@@ -781,6 +814,24 @@ impl KeybindingEditor {
         self.apply_filters();
     }
 
+    /// Whether a binding's context passes the context filter `filter`. A
+    /// compound clause also shows under every context it names, so a
+    /// `language:markdown` binding is listed under `normal`.
+    fn context_matches_filter(context: &str, filter: &str) -> bool {
+        if context == filter {
+            return true;
+        }
+        match (
+            WhenClause::parse(context),
+            KeyContext::from_when_clause(filter),
+        ) {
+            (Ok(clause), Some(filter_ctx)) if clause.as_context().is_none() => {
+                clause.mentions(&filter_ctx)
+            }
+            _ => false,
+        }
+    }
+
     /// Cycle source filter
     pub fn cycle_source_filter(&mut self) {
         self.source_filter = match self.source_filter {
@@ -801,11 +852,12 @@ impl KeybindingEditor {
     pub fn open_edit_dialog(&mut self) {
         if let Some(idx) = self.selected_binding_index() {
             let binding = self.bindings[idx].clone();
-            self.edit_dialog = Some(EditBindingState::new_edit_with_modes(
-                idx,
-                &binding,
-                &self.mode_contexts,
-            ));
+            let mut dialog =
+                EditBindingState::new_edit_with_modes(idx, &binding, &self.mode_contexts);
+            if !binding.is_chord && binding.source != BindingSource::Unbound {
+                self.refresh_conflicts(&mut dialog);
+            }
+            self.edit_dialog = Some(dialog);
         }
     }
 
@@ -842,8 +894,20 @@ impl KeybindingEditor {
     /// when it was added this session, otherwise record it in `pending_removes`
     /// so the save drops it from the persisted config.
     fn delete_custom_binding(&mut self, idx: usize) -> DeleteResult {
+        let action_name = self.bindings[idx].action.clone();
+        self.forget_custom_binding(idx);
+        self.bindings.remove(idx);
+        self.has_changes = true;
+
+        self.readd_as_unbound_if_orphaned(action_name);
+        self.apply_filters();
+        DeleteResult::CustomRemoved
+    }
+
+    /// Record that the `Custom` binding at `idx` is going away, without
+    /// touching its display row.
+    fn forget_custom_binding(&mut self, idx: usize) {
         let binding = &self.bindings[idx];
-        let action_name = binding.action.clone();
 
         // Use the original config-level Keybinding if available (for bindings
         // loaded from config), otherwise reconstruct it. This avoids lossy
@@ -865,13 +929,6 @@ impl KeybindingEditor {
         } else {
             self.pending_removes.push(config_kb);
         }
-
-        self.bindings.remove(idx);
-        self.has_changes = true;
-
-        self.readd_as_unbound_if_orphaned(action_name);
-        self.apply_filters();
-        DeleteResult::CustomRemoved
     }
 
     /// Shadow a built-in keymap or plugin binding with a custom `noop` override
@@ -901,6 +958,7 @@ impl KeybindingEditor {
             } else {
                 Some(binding.context.clone())
             },
+            release: false,
         };
         self.pending_adds.push(noop_kb);
 
@@ -971,6 +1029,7 @@ impl KeybindingEditor {
             } else {
                 Some(binding.context.clone())
             },
+            release: false,
         }
     }
 
@@ -1013,6 +1072,13 @@ impl KeybindingEditor {
         // variant at runtime.
         let (bare_action, args) = Action::unqualify_action(&dialog.action_text);
 
+        // Editing a key-release binding keeps it one
+        let release = dialog
+            .editing_index
+            .and_then(|idx| self.bindings.get(idx))
+            .and_then(|b| b.original_config.as_ref())
+            .is_some_and(|kb| kb.release);
+
         let new_binding = Keybinding {
             key: key_name,
             modifiers: modifier_names,
@@ -1020,6 +1086,7 @@ impl KeybindingEditor {
             action: bare_action.clone(),
             args: args.clone(),
             when: Some(dialog.context.clone()),
+            release,
         };
 
         // Add as custom binding
@@ -1027,7 +1094,10 @@ impl KeybindingEditor {
         self.has_changes = true;
 
         // Update display
-        let key_display = format_keybinding(&key_code, &modifiers);
+        let mut key_display = format_keybinding(&key_code, &modifiers);
+        if release {
+            key_display.push_str(RELEASE_SUFFIX);
+        }
         let action_display =
             KeybindingResolver::format_action_from_str_with_args(&bare_action, &args);
 
@@ -1073,30 +1143,301 @@ impl KeybindingEditor {
         modifiers: KeyModifiers,
         context: &str,
     ) -> Vec<String> {
-        let mut conflicts = Vec::new();
-
-        for binding in &self.bindings {
-            if !binding.is_chord
-                && binding.key_code == key_code
-                && binding.modifiers == modifiers
-                && (binding.context == context
-                    || binding.context == "global"
-                    || context == "global")
+        self.conflicting_bindings(key_code, modifiers, context, None)
+            .into_iter()
+            .map(|idx| Self::conflict_label(&self.bindings[idx]))
+            .collect()
+    }
+
+    /// Indices of the bindings the given key combination would clash with,
+    /// leaving out `exclude` (the binding being edited). `noop` overrides
+    /// only disable a key, so they never clash.
+    fn conflicting_bindings(
+        &self,
+        key_code: KeyCode,
+        modifiers: KeyModifiers,
+        context: &str,
+        exclude: Option<usize>,
+    ) -> Vec<usize> {
+        self.bindings
+            .iter()
+            .enumerate()
+            .filter(|(idx, binding)| {
+                Some(*idx) != exclude
+                    && !binding.is_chord
+                    && binding.action != "noop"
+                    && !binding
+                        .original_config
+                        .as_ref()
+                        .is_some_and(|kb| kb.release)
+                    && binding.key_code == key_code
+                    && binding.modifiers == modifiers
+                    && (binding.context == context
+                        || binding.context == "global"
+                        || context == "global")
+            })
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    fn conflict_label(binding: &ResolvedBinding) -> String {
+        format!(
+            "{} ({}, {})",
+            binding.action_display,
+            binding.context,
+            match binding.source {
+                BindingSource::Custom => "custom",
+                BindingSource::Plugin => "plugin",
+                _ => "keymap",
+            }
+        )
+    }
+
+    /// Recompute the dialog's conflicts for its current key and context.
+    pub fn refresh_conflicts(&self, dialog: &mut EditBindingState) {
+        dialog.conflict_indices = match dialog.key_code {
+            Some(key_code) => self.conflicting_bindings(
+                key_code,
+                dialog.modifiers,
+                &dialog.context,
+                dialog.editing_index,
+            ),
+            None => Vec::new(),
+        };
+        dialog.conflicts = dialog
+            .conflict_indices
+            .iter()
+            .map(|&idx| Self::conflict_label(&self.bindings[idx]))
+            .collect();
+        dialog.selected_button = dialog
+            .selected_button
+            .min(dialog.button_count().saturating_sub(1));
+    }
+
+    /// Save the edit dialog after unbinding every binding it conflicts with.
+    /// Returns an error message if validation fails (nothing is unbound then).
+    pub fn apply_edit_dialog_unbinding_conflicts(&mut self) -> Option<String> {
+        if !self.edit_dialog_is_valid() {
+            return self.apply_edit_dialog();
+        }
+        let mut dialog = self.edit_dialog.take()?;
+        self.unbind_conflicts(&mut dialog);
+        self.edit_dialog = Some(dialog);
+        self.apply_edit_dialog()
+    }
+
+    /// Save the edit dialog, unbinding what it conflicts with, then open an
+    /// add dialog that moves the first conflicting action to a new key (the
+    /// next key pressed is captured).
+    pub fn apply_edit_dialog_rebinding_conflict(&mut self) -> Option<String> {
+        if !self.edit_dialog_is_valid() {
+            return self.apply_edit_dialog();
+        }
+        let displaced = self
+            .edit_dialog
+            .as_ref()
+            .and_then(|d| d.conflict_indices.first())
+            .map(|&idx| self.bindings[idx].clone());
+        if let Some(err) = self.apply_edit_dialog_unbinding_conflicts() {
+            return Some(err);
+        }
+        if let Some(displaced) = displaced {
+            let mut dialog = EditBindingState::new_add_with_modes(&self.mode_contexts);
+            dialog.action_cursor = displaced.action.len();
+            dialog.action_text = displaced.action;
+            if let Some(pos) = dialog
+                .context_options
+                .iter()
+                .position(|c| c == &displaced.context)
             {
-                conflicts.push(format!(
-                    "{} ({}, {})",
-                    binding.action_display,
-                    binding.context,
-                    match binding.source {
-                        BindingSource::Custom => "custom",
-                        BindingSource::Plugin => "plugin",
-                        _ => "keymap",
+                dialog.context_option_index = pos;
+                dialog.context = displaced.context;
+            }
+            dialog.capturing_special = true;
+            self.edit_dialog = Some(dialog);
+        }
+        None
+    }
+
+    /// Whether `apply_edit_dialog` would accept the open dialog.
+    fn edit_dialog_is_valid(&self) -> bool {
+        self.edit_dialog.as_ref().is_some_and(|d| {
+            d.key_code.is_some()
+                && !d.action_text.is_empty()
+                && self.is_valid_action(&d.action_text)
+        })
+    }
+
+    /// Unbind every binding in `dialog.conflict_indices`, keeping
+    /// `dialog.editing_index` pointing at the same row.
+    fn unbind_conflicts(&mut self, dialog: &mut EditBindingState) {
+        let mut indices = std::mem::take(&mut dialog.conflict_indices);
+        indices.sort_unstable_by(|a, b| b.cmp(a));
+        for idx in indices {
+            let binding = &self.bindings[idx];
+            let action_name = binding.action.clone();
+            match binding.source {
+                BindingSource::Custom => self.forget_custom_binding(idx),
+                // The new binding shadows a default on the same key and
+                // context by itself; anywhere else it needs a `noop`.
+                BindingSource::Keymap | BindingSource::Plugin
+                    if binding.context != dialog.context =>
+                {
+                    self.override_binding_with_noop(idx);
+                    continue;
+                }
+                BindingSource::Keymap | BindingSource::Plugin => {}
+                BindingSource::Unbound => continue,
+            }
+            self.bindings.remove(idx);
+            if let Some(editing) = dialog.editing_index.as_mut() {
+                if *editing > idx {
+                    *editing -= 1;
+                }
+            }
+            self.has_changes = true;
+            self.readd_as_unbound_if_orphaned(action_name);
+        }
+        dialog.conflicts.clear();
+    }
+
+    /// Differences between the current bindings (including unsaved edits)
+    /// and the active keymap's built-in bindings, ordered by context and key.
+    pub fn keymap_diff(&self) -> Vec<KeymapDiffEntry> {
+        let mut entries: Vec<KeymapDiffEntry> = self
+            .bindings
+            .iter()
+            .filter(|b| b.source == BindingSource::Custom)
+            .filter_map(|b| {
+                let default = self
+                    .keymap_defaults
+                    .get(&(b.key_display.clone(), b.context.clone()));
+                let kind = match default {
+                    Some(d) if d.action == b.action => return None,
+                    Some(_) if b.action == "noop" => KeymapDiffKind::Removed,
+                    Some(_) => KeymapDiffKind::Changed,
+                    // A `noop` on a key the keymap never bound changes nothing.
+                    None if b.action == "noop" => return None,
+                    None => KeymapDiffKind::Added,
+                };
+                Some(KeymapDiffEntry {
+                    action_display: if kind == KeymapDiffKind::Removed {
+                        String::new()
+                    } else {
+                        b.action_display.clone()
+                    },
+                    kind,
+                    key_display: b.key_display.clone(),
+                    context: b.context.clone(),
+                    default_action_display: default.map(|d| d.action_display.clone()),
+                })
+            })
+            .collect();
+        entries.sort_by(|a, b| {
+            a.context
+                .cmp(&b.context)
+                .then(a.key_display.cmp(&b.key_display))
+        });
+        entries
+    }
+
+    /// Open the export/import path dialog, prefilled with `keymap.json`
+    /// next to the config file.
+    pub fn open_file_dialog(&mut self, action: KeymapFileAction) {
+        let path = Path::new(&self.config_file_path).with_file_name("keymap.json");
+        self.file_dialog = Some(KeymapFileDialog {
+            action,
+            path: path.display().to_string(),
+            error: None,
+        });
+    }
+
+    /// Resolve a path typed into the file dialog: `~` is expanded and
+    /// relative paths are taken from the config file's directory.
+    pub fn resolve_keymap_file_path(&self, path: &str) -> PathBuf {
+        let path = crate::primitives::path_utils::expand_tilde(path.trim());
+        if path.is_absolute() {
+            return path;
+        }
+        Path::new(&self.config_file_path)
+            .parent()
+            .map(|dir| dir.join(&path))
+            .unwrap_or(path)
+    }
+
+    /// Write the custom bindings (including unsaved edits) to `path` as a
+    /// keymap that inherits from the active one, so it can be imported
+    /// elsewhere or dropped into `keybinding_maps`. Returns the number of
+    /// bindings written.
+    pub fn export_keymap(&self, path: &Path) -> Result<usize, String> {
+        let bindings: Vec<Keybinding> = self
+            .bindings
+            .iter()
+            .filter(|b| b.source == BindingSource::Custom)
+            .map(|b| {
+                b.original_config
+                    .clone()
+                    .unwrap_or_else(|| self.resolved_to_config_keybinding(b))
+            })
+            .collect();
+        let count = bindings.len();
+        let keymap = KeymapConfig {
+            inherits: Some(self.active_keymap.clone()),
+            bindings,
+        };
+        let json = serde_json::to_string_pretty(&keymap).map_err(|e| e.to_string())?;
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        std::fs::write(path, json).map_err(|e| e.to_string())?;
+        Ok(count)
+    }
+
+    /// Read a keymap file written by [`Self::export_keymap`] and add its
+    /// bindings as unsaved custom bindings, overriding whatever is bound to
+    /// the same key and context. Returns the number of bindings added and,
+    /// when the file was made for another keymap, that keymap's name.
+    pub fn import_keymap(&mut self, path: &Path) -> Result<(usize, Option<String>), String> {
+        let content = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let keymap: KeymapConfig = serde_json::from_str(&content).map_err(|e| e.to_string())?;
+
+        let mut added = 0;
+        for kb in keymap.bindings {
+            let Some(resolved) = Self::keybinding_to_resolved(&kb, BindingSource::Custom) else {
+                continue;
+            };
+            let existing = self.bindings.iter().position(|b| {
+                b.source != BindingSource::Unbound
+                    && b.key_display == resolved.key_display
+                    && b.context == resolved.context
+            });
+            match existing {
+                Some(idx)
+                    if self.bindings[idx].source == BindingSource::Custom
+                        && self.bindings[idx].action == resolved.action => {}
+                Some(idx) => {
+                    if self.bindings[idx].source == BindingSource::Custom {
+                        self.forget_custom_binding(idx);
                     }
-                ));
+                    let displaced = std::mem::replace(&mut self.bindings[idx], resolved);
+                    self.pending_adds.push(kb);
+                    self.readd_as_unbound_if_orphaned(displaced.action);
+                    added += 1;
+                }
+                None => {
+                    self.bindings.push(resolved);
+                    self.pending_adds.push(kb);
+                    added += 1;
+                }
             }
         }
+        if added > 0 {
+            self.has_changes = true;
+        }
+        self.apply_filters();
 
-        conflicts
+        let other_keymap = keymap.inherits.filter(|name| name != &self.active_keymap);
+        Ok((added, other_keymap))
     }
 
     /// Get the custom bindings to save to config
@@ -1232,6 +1573,160 @@ mod tests {
         );
     }
 
+    /// Open an add dialog for `key` with `action` in `context`, conflicts
+    /// already computed as the key-capture path does.
+    fn add_dialog(
+        editor: &mut KeybindingEditor,
+        key: KeyCode,
+        modifiers: KeyModifiers,
+        action: &str,
+        context: &str,
+    ) {
+        editor.open_add_dialog();
+        let mut dialog = editor.edit_dialog.take().unwrap();
+        dialog.key_code = Some(key);
+        dialog.modifiers = modifiers;
+        dialog.key_display = format_keybinding(&key, &modifiers);
+        dialog.action_text = action.to_string();
+        dialog.context = context.to_string();
+        editor.refresh_conflicts(&mut dialog);
+        editor.edit_dialog = Some(dialog);
+    }
+
+    fn bound_to(editor: &KeybindingEditor, key_display: &str, context: &str) -> Vec<String> {
+        editor
+            .bindings
+            .iter()
+            .filter(|b| b.key_display == key_display && b.context == context)
+            .map(|b| b.action.clone())
+            .collect()
+    }
+
+    #[test]
+    fn unbinding_conflicts_hands_the_key_to_the_new_binding() {
+        let mut editor = make_editor(&[]);
+        add_dialog(
+            &mut editor,
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL,
+            "quit",
+            "normal",
+        );
+        let dialog = editor.edit_dialog.as_ref().unwrap();
+        assert!(
+            dialog.conflicts.iter().any(|c| c.contains("normal")),
+            "Ctrl+S should clash with the keymap's save, got {:?}",
+            dialog.conflicts
+        );
+        assert_eq!(dialog.button_count(), 4);
+
+        assert_eq!(editor.apply_edit_dialog_unbinding_conflicts(), None);
+        assert_eq!(bound_to(&editor, "Ctrl+S", "normal"), vec!["quit"]);
+        // Same key and context: the new binding shadows the default on its
+        // own, no `noop` override is needed.
+        assert!(editor.pending_adds.iter().all(|kb| kb.action != "noop"));
+        assert!(editor
+            .bindings
+            .iter()
+            .any(|b| b.action == "save" && b.source == BindingSource::Unbound));
+
+        let diff = editor.keymap_diff();
+        let entry = diff
+            .iter()
+            .find(|e| e.key_display == "Ctrl+S" && e.context == "normal")
+            .expect("Ctrl+S should show up in the diff");
+        assert_eq!(entry.kind, KeymapDiffKind::Changed);
+        assert!(entry.default_action_display.is_some());
+    }
+
+    #[test]
+    fn rebinding_conflict_opens_a_dialog_for_the_displaced_action() {
+        let mut editor = make_editor(&[]);
+        add_dialog(
+            &mut editor,
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL,
+            "quit",
+            "normal",
+        );
+        assert_eq!(editor.apply_edit_dialog_rebinding_conflict(), None);
+
+        let dialog = editor
+            .edit_dialog
+            .as_ref()
+            .expect("an add dialog for the displaced action");
+        assert_eq!(dialog.action_text, "save");
+        assert_eq!(dialog.context, "normal");
+        assert_eq!(dialog.editing_index, None);
+        assert!(dialog.capturing_special, "the next key should be captured");
+    }
+
+    #[test]
+    fn invalid_dialog_does_not_unbind_conflicts() {
+        let mut editor = make_editor(&[]);
+        add_dialog(
+            &mut editor,
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL,
+            "no_such_action",
+            "normal",
+        );
+        assert!(editor.apply_edit_dialog_unbinding_conflicts().is_some());
+        assert!(editor.edit_dialog.is_some());
+        assert_eq!(bound_to(&editor, "Ctrl+S", "normal"), vec!["save"]);
+        assert!(!editor.has_changes);
+    }
+
+    #[test]
+    fn exported_keymap_imports_as_custom_bindings() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("shared").join("keymap.json");
+
+        let mut source = make_editor(&[]);
+        add_dialog(
+            &mut source,
+            KeyCode::Char('y'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+            "save",
+            "normal",
+        );
+        assert_eq!(source.apply_edit_dialog(), None);
+        assert_eq!(source.export_keymap(&path), Ok(1));
+
+        let mut target = make_editor(&[]);
+        assert_eq!(target.import_keymap(&path), Ok((1, None)));
+        assert!(target.has_changes);
+        assert_eq!(target.get_custom_bindings().len(), 1);
+        assert_eq!(bound_to(&target, "Ctrl+Alt+Y", "normal"), vec!["save"]);
+        let diff = target.keymap_diff();
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].kind, KeymapDiffKind::Added);
+
+        // Importing the same file again adds nothing.
+        assert_eq!(target.import_keymap(&path), Ok((0, None)));
+    }
+
+    #[test]
+    fn deleted_keymap_binding_shows_as_removed_in_diff() {
+        let mut editor = make_editor(&[]);
+        let idx = editor
+            .bindings
+            .iter()
+            .position(|b| b.action == "save" && b.key_display == "Ctrl+S")
+            .unwrap();
+        editor.selected = editor
+            .display_rows
+            .iter()
+            .position(|r| matches!(r, DisplayRow::Binding(i) if *i == idx))
+            .unwrap();
+        assert_eq!(editor.delete_selected(), DeleteResult::KeymapOverridden);
+
+        let diff = editor.keymap_diff();
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0].kind, KeymapDiffKind::Removed);
+        assert_eq!(diff[0].key_display, "Ctrl+S");
+    }
+
     #[test]
     fn qualified_action_roundtrips_through_resolved_to_config() {
         // A binding selected from the dropdown as `menu_open:File` must be
@@ -1259,4 +1754,50 @@ mod tests {
             kb.args
         );
     }
+
+    /// Compound clauses get their own filter entry and also show under the
+    /// contexts they name.
+    #[test]
+    fn context_filter_covers_compound_when_clauses() {
+        let mut config = Config::default();
+        config.keybindings.push(Keybinding {
+            key: "j".to_string(),
+            modifiers: vec!["alt".to_string()],
+            keys: vec![],
+            action: "move_document_end".to_string(),
+            args: HashMap::new(),
+            when: Some("language:markdown".to_string()),
+            release: false,
+        });
+        let resolver = KeybindingResolver::new(&config);
+        let mut editor = KeybindingEditor::new(
+            &config,
+            &resolver,
+            &ModeRegistry::new(),
+            &CommandRegistry::new(),
+            String::from("/tmp/fresh-config.toml"),
+            &[],
+        );
+        let shows_binding = |editor: &KeybindingEditor| {
+            editor
+                .filtered_indices
+                .iter()
+                .any(|&i| editor.bindings[i].context == "language:markdown")
+        };
+
+        editor.context_filter = ContextFilter::Specific("normal".to_string());
+        editor.apply_filters();
+        assert!(shows_binding(&editor));
+
+        editor.context_filter = ContextFilter::Specific("prompt".to_string());
+        editor.apply_filters();
+        assert!(!shows_binding(&editor));
+
+        let mut seen = Vec::new();
+        for _ in 0..20 {
+            editor.cycle_context_filter();
+            seen.push(editor.context_filter_display().to_string());
+        }
+        assert!(seen.iter().any(|c| c == "language:markdown"), "{:?}", seen);
+    }
 }
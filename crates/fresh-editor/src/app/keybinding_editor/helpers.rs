@@ -54,5 +54,8 @@ pub fn modifiers_to_config_names(modifiers: KeyModifiers) -> Vec<String> {
     if modifiers.contains(KeyModifiers::SUPER) {
         names.push("super".to_string());
     }
+    if modifiers.contains(KeyModifiers::HYPER) {
+        names.push("hyper".to_string());
+    }
     names
 }
@@ -91,13 +91,17 @@ pub struct EditBindingState {
     pub editing_index: Option<usize>,
     /// Detected conflicts
     pub conflicts: Vec<String>,
+    /// Indices into `bindings` of the conflicting entries, parallel to
+    /// `conflicts` (used to unbind or rebind them from the dialog)
+    pub conflict_indices: Vec<usize>,
     /// Available context options
     pub context_options: Vec<String>,
     /// Selected context option index
     pub context_option_index: usize,
     /// Whether the context dropdown is open
     pub context_dropdown_open: bool,
-    /// Selected button (0=Save, 1=Cancel)
+    /// Selected button (0=Save, 1=Cancel, and when there are conflicts
+    /// 2=Unbind Others, 3=Rebind Other)
     pub selected_button: usize,
     /// Focus area (0=key, 1=action, 2=context, 3=buttons)
     pub focus_area: usize,
@@ -145,6 +149,7 @@ impl EditBindingState {
             context: "normal".to_string(),
             editing_index: None,
             conflicts: Vec::new(),
+            conflict_indices: Vec::new(),
             context_options,
             context_option_index: 1, // default to "normal"
             context_dropdown_open: false,
@@ -169,6 +174,10 @@ impl EditBindingState {
     ) -> Self {
         let mut context_options = Self::base_context_options();
         context_options.extend(mode_contexts.iter().cloned());
+        // Keep a compound `when` clause selectable so editing doesn't drop it
+        if !context_options.contains(&binding.context) {
+            context_options.push(binding.context.clone());
+        }
         let context_option_index = context_options
             .iter()
             .position(|c| c == &binding.context)
@@ -184,6 +193,7 @@ impl EditBindingState {
             context: binding.context.clone(),
             editing_index: Some(index),
             conflicts: Vec::new(),
+            conflict_indices: Vec::new(),
             context_options,
             context_option_index,
             context_dropdown_open: false,
@@ -198,6 +208,64 @@ impl EditBindingState {
     }
 }
 
+impl EditBindingState {
+    /// Number of buttons in the button row: Save and Cancel, plus the two
+    /// conflict-resolution buttons while there are conflicts.
+    pub fn button_count(&self) -> usize {
+        if self.conflict_indices.is_empty() {
+            2
+        } else {
+            4
+        }
+    }
+}
+
+/// What the export/import dialog does with its path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeymapFileAction {
+    /// Write the custom bindings to the file
+    Export,
+    /// Read bindings from the file as custom bindings
+    Import,
+}
+
+/// State for the keymap export/import path dialog
+#[derive(Debug, Clone)]
+pub struct KeymapFileDialog {
+    /// Whether the path is exported to or imported from
+    pub action: KeymapFileAction,
+    /// The file path being edited
+    pub path: String,
+    /// Error from the last attempt (shown under the path)
+    pub error: Option<String>,
+}
+
+/// How a binding differs from the built-in keymap
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeymapDiffKind {
+    /// Key is not bound by the keymap
+    Added,
+    /// Key is bound by the keymap to a different action
+    Changed,
+    /// Keymap binding disabled with a `noop` override
+    Removed,
+}
+
+/// One line of the diff against the built-in keymap
+#[derive(Debug, Clone)]
+pub struct KeymapDiffEntry {
+    /// How the binding differs
+    pub kind: KeymapDiffKind,
+    /// Formatted key combination
+    pub key_display: String,
+    /// Context / when clause
+    pub context: String,
+    /// Human-readable action now bound (empty for `Removed`)
+    pub action_display: String,
+    /// Human-readable action the keymap binds (None for `Added`)
+    pub default_action_display: Option<String>,
+}
+
 /// Search mode for the keybinding editor
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum SearchMode {
@@ -250,6 +318,8 @@ pub struct KeybindingEditorLayout {
     pub table_first_row_y: u16,
     /// Edit dialog button areas: (save_rect, cancel_rect)
     pub dialog_buttons: Option<(Rect, Rect)>,
+    /// Edit dialog conflict button areas: (unbind_rect, rebind_rect)
+    pub dialog_conflict_buttons: Option<(Rect, Rect)>,
     /// Edit dialog key field area
     pub dialog_key_field: Option<Rect>,
     /// Edit dialog action field area
@@ -101,7 +101,7 @@ impl Editor {
         let config_value = match serde_json::to_value(&self.config.keybindings) {
             Ok(v) => v,
             Err(e) => {
-                self.set_status_message(format!("Failed to serialize keybindings: {}", e));
+                self.set_status_error(format!("Failed to serialize keybindings: {}", e));
                 return;
             }
         };
@@ -123,7 +123,7 @@ impl Editor {
                 self.set_status_message("Keybinding changes saved".to_string());
             }
             Err(e) => {
-                self.set_status_message(format!("Failed to save keybindings: {}", e));
+                self.set_status_error(format!("Failed to save keybindings: {}", e));
             }
         }
     }
@@ -160,6 +160,12 @@ impl Editor {
             // wheel to selection meant any prior scrollbar drag snapped
             // back via `ensure_visible` on the next wheel tick. Three
             // rows per tick matches the settings modal.
+            MouseEventKind::ScrollUp if editor.showing_diff => {
+                editor.diff_scroll = editor.diff_scroll.saturating_sub(3);
+            }
+            MouseEventKind::ScrollDown if editor.showing_diff => {
+                editor.diff_scroll += 3;
+            }
             MouseEventKind::ScrollUp
                 if editor.edit_dialog.is_none() && !editor.showing_confirm_dialog =>
             {
@@ -202,6 +208,12 @@ impl Editor {
                     return Ok(true);
                 }
 
+                // The diff overlay and the path dialog are keyboard-driven
+                if editor.showing_diff || editor.file_dialog.is_some() {
+                    self.keybinding_editor = Some(editor);
+                    return Ok(true);
+                }
+
                 // Handle edit dialog clicks
                 if editor.edit_dialog.is_some() {
                     // Button clicks
@@ -220,6 +232,21 @@ impl Editor {
                             return Ok(true);
                         }
                     }
+                    if let Some((unbind_r, rebind_r)) = layout.dialog_conflict_buttons {
+                        let unbind = point_in_rect(unbind_r, col, row);
+                        if unbind || point_in_rect(rebind_r, col, row) {
+                            let result = if unbind {
+                                editor.apply_edit_dialog_unbinding_conflicts()
+                            } else {
+                                editor.apply_edit_dialog_rebinding_conflict()
+                            };
+                            if let Some(err) = result {
+                                self.set_status_message(err);
+                            }
+                            self.keybinding_editor = Some(editor);
+                            return Ok(true);
+                        }
+                    }
                     // Field clicks
                     if let Some(r) = layout.dialog_key_field {
                         if point_in_rect(r, col, row) {
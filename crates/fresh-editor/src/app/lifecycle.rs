@@ -116,6 +116,10 @@ impl Editor {
     pub fn take_full_redraw_request(&mut self) -> bool {
         let requested = self.full_redraw_requested;
         self.full_redraw_requested = false;
+        if requested {
+            // The clear wipes any image; draw it again after the repaint.
+            self.shown_image = None;
+        }
         requested
     }
 
@@ -222,9 +226,11 @@ impl Editor {
 
     /// Count modified buffers that would require a save prompt on quit.
     ///
-    /// When `hot_exit` is enabled, unnamed buffers are excluded (they are
-    /// automatically recovered across restarts), but file-backed modified
-    /// buffers still trigger a prompt with a "recoverable" option.
+    /// Scratch buffers are always excluded (their content is kept in the
+    /// workspace file). When `hot_exit` is enabled, unnamed buffers are
+    /// excluded too (they are automatically recovered across restarts), but
+    /// file-backed modified buffers still trigger a prompt with a
+    /// "recoverable" option.
     /// When `auto_save_enabled` is true, file-backed buffers are excluded
     /// (they will be saved to disk on exit).
     fn count_modified_buffers_needing_prompt(&self) -> usize {
@@ -241,6 +247,9 @@ impl Editor {
                     return false;
                 }
                 if let Some(meta) = self.active_window().buffer_metadata.get(buffer_id) {
+                    if meta.scratch {
+                        return false; // kept in the workspace file
+                    }
                     if let Some(path) = meta.file_path() {
                         let is_unnamed = path.as_os_str().is_empty();
                         if is_unnamed && hot_exit {
@@ -276,14 +285,38 @@ impl Editor {
     pub fn handle_input_event(&mut self, event: crossterm::event::Event) -> anyhow::Result<bool> {
         use crossterm::event::{Event as Ev, KeyEventKind};
 
+        self.mark_input_received();
+        if self.record_calibration_diagnostic(&event) {
+            return Ok(true);
+        }
+
         match event {
-            Ev::Key(key_event) if key_event.kind == KeyEventKind::Press => {
+            Ev::Key(key_event) if key_event.kind == KeyEventKind::Release => {
+                // Only reported by terminals speaking the kitty keyboard
+                // protocol, and only requested when a binding wants them.
+                let context = self.get_key_context();
+                let action = self
+                    .keybindings
+                    .read()
+                    .unwrap()
+                    .resolve_release(&key_event, &context);
+                match action {
+                    Some(action) => {
+                        self.handle_action(action)?;
+                        Ok(true)
+                    }
+                    None => Ok(false),
+                }
+            }
+            Ev::Key(key_event) => {
                 let key_code = format!("{:?}", key_event.code);
                 let modifiers = format!("{:?}", key_event.modifiers);
                 self.active_window_mut()
                     .log_keystroke(&key_code, &modifiers);
                 let translated = self.key_translator().translate(key_event);
-                self.handle_key(translated.code, translated.modifiers)?;
+                for key in self.compose_dead_key(translated) {
+                    self.handle_key(key.code, key.modifiers)?;
+                }
                 // If `paste()` just took the async placeholder path,
                 // skip the otherwise-automatic render for this
                 // keystroke. The placeholder is sitting in the
@@ -307,14 +340,7 @@ impl Editor {
             Ev::Paste(text) => {
                 // Terminal-initiated bracketed paste — no async read
                 // needed, the terminal already harvested the clipboard.
-                // When a floating modal / dock owns the keyboard the
-                // paste belongs to its focused text field, not the
-                // buffer underneath; route there first (and let a modal
-                // with no text field focused swallow it) before falling
-                // back to the buffer/prompt paste path.
-                if !self.paste_bracketed_into_focused_panel(&text) {
-                    self.paste_text(text);
-                }
+                self.handle_bracketed_paste(text);
                 Ok(true)
             }
             Ev::FocusGained => {
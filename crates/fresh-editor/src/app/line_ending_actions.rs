@@ -0,0 +1,95 @@
+//! Whole-buffer line-ending conversion (Convert Line Endings to LF/CRLF).
+//!
+//! Unlike **Set Line Ending**, which only changes how the file is written on
+//! save, these commands rewrite the line breaks in the buffer itself, so the
+//! change is visible, covers files with mixed endings, and can be undone.
+
+use rust_i18n::t;
+
+use crate::model::buffer::LineEnding;
+use crate::model::event::{CursorId, Event};
+
+use super::Editor;
+
+impl Editor {
+    /// Rewrite every line break in the active buffer to `target` as a
+    /// single undoable edit, and make `target` the buffer's line ending.
+    pub(super) fn convert_line_endings(&mut self, target: LineEnding) {
+        let bytes = {
+            let state = self.active_state_mut();
+            let total_bytes = state.buffer.len();
+            match state.buffer.get_text_range_mut(0, total_bytes) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    tracing::warn!("Failed to load buffer for line-ending conversion: {}", e);
+                    self.set_status_message(t!("error.buffer_not_loaded").to_string());
+                    return;
+                }
+            }
+        };
+
+        // No cursor is tied to these edits: every cursor just shifts with
+        // the text around it, so it stays on the same character.
+        let cursor_id = CursorId::UNDO_SENTINEL;
+        let mut events = Vec::new();
+        let mut count = 0;
+        // Walk backwards so each edit leaves the positions of the ones
+        // still to come untouched (apply_events_as_bulk_edit wants them in
+        // descending order).
+        let mut i = bytes.len();
+        while i > 0 {
+            i -= 1;
+            let (start, end) = match bytes[i] {
+                b'\n' if i > 0 && bytes[i - 1] == b'\r' => (i - 1, i + 1),
+                b'\n' | b'\r' => (i, i + 1),
+                _ => continue,
+            };
+            let current = &bytes[start..end];
+            let wanted = target.insertion_str();
+            if current != wanted.as_bytes() {
+                // Between CRLF and LF only the `\r` is added or removed, so
+                // the `\n` that markers and line starts hang off stays put.
+                let (delete, insert) = match (current, target) {
+                    (b"\r\n", LineEnding::LF) => (Some(start..start + 1), None),
+                    (b"\n", LineEnding::CRLF) => (None, Some("\r")),
+                    _ => (Some(start..end), Some(wanted)),
+                };
+                if let Some(range) = delete {
+                    events.push(Event::Delete {
+                        deleted_text: String::from_utf8_lossy(&bytes[range.clone()]).into_owned(),
+                        range,
+                        cursor_id,
+                    });
+                }
+                if let Some(text) = insert {
+                    events.push(Event::Insert {
+                        position: start,
+                        text: text.to_string(),
+                        cursor_id,
+                    });
+                }
+                count += 1;
+            }
+            i = start;
+        }
+
+        if count > 0 {
+            let description = format!("Convert line endings to {}", target.display_name());
+            if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description) {
+                self.active_event_log_mut().append(bulk_edit);
+            }
+        }
+        self.active_state_mut()
+            .buffer
+            .set_converted_line_ending(target);
+
+        self.set_status_message(
+            t!(
+                "line_ending.converted",
+                count = count,
+                ending = target.display_name()
+            )
+            .to_string(),
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Background command-line linting (shellcheck, ruff, eslint, clippy, ...).
+//!
+//! Saves run every applicable linter; edits and opens schedule a debounced
+//! run of the `run_on_idle` ones, which lint the unsaved buffer via stdin.
+//! Each run happens on a worker thread that posts its results back as
+//! [`AsyncMessage::LinterDiagnostics`]. See [`crate::services::linters`].
+
+use super::window::Window;
+use crate::config::LinterConfig;
+use crate::services::async_bridge::AsyncMessage;
+use crate::services::linters::{self, LintRequest};
+use fresh_core::BufferId;
+
+impl Window {
+    /// Schedule an idle lint for a buffer (debounced). No-op unless one of
+    /// the buffer's linters has `run_on_idle` set.
+    pub fn schedule_idle_lint(&mut self, buffer_id: BufferId) {
+        const LINT_DEBOUNCE_MS: u64 = 1000;
+        if self.linters_for(buffer_id, true).is_empty() {
+            return;
+        }
+        let next_time =
+            std::time::Instant::now() + std::time::Duration::from_millis(LINT_DEBOUNCE_MS);
+        self.lint_debounce.insert(buffer_id, next_time);
+    }
+
+    /// Run every linter that applies to a just-saved buffer.
+    pub fn lint_on_save(&mut self, buffer_id: BufferId) {
+        // The save supersedes a pending idle run.
+        self.lint_debounce.remove(&buffer_id);
+        for (name, config) in self.linters_for(buffer_id, false) {
+            self.spawn_linter(buffer_id, name, config);
+        }
+    }
+
+    /// Run every applicable linter on every open file, e.g. after a branch
+    /// switch rewrote them. A workspace linter runs once, not per file.
+    pub fn lint_open_buffers(&mut self) {
+        let buffer_ids: Vec<BufferId> = self.buffers.iter().map(|(id, _)| *id).collect();
+        let mut workspace_run = std::collections::HashSet::new();
+        for buffer_id in buffer_ids {
+            self.lint_debounce.remove(&buffer_id);
+            for (name, config) in self.linters_for(buffer_id, false) {
+                if config.workspace && !workspace_run.insert(name.clone()) {
+                    continue;
+                }
+                self.spawn_linter(buffer_id, name, config);
+            }
+        }
+    }
+
+    /// Start every idle lint whose debounce has elapsed.
+    pub fn check_lint_timer(&mut self) {
+        if self.lint_debounce.is_empty() {
+            return;
+        }
+        let now = std::time::Instant::now();
+        let due: Vec<BufferId> = self
+            .lint_debounce
+            .iter()
+            .filter(|(_, at)| now >= **at)
+            .map(|(id, _)| *id)
+            .collect();
+        for buffer_id in due {
+            self.lint_debounce.remove(&buffer_id);
+            for (name, config) in self.linters_for(buffer_id, true) {
+                self.spawn_linter(buffer_id, name, config);
+            }
+        }
+    }
+
+    /// Enabled linters for the buffer's language. `idle` keeps only those
+    /// that can lint unsaved content. None run outside a Trusted workspace:
+    /// linters like eslint load the project's own config, which is
+    /// repo-controlled code.
+    fn linters_for(&self, buffer_id: BufferId, idle: bool) -> Vec<(String, LinterConfig)> {
+        use crate::services::workspace_trust::TrustLevel;
+        if self.authority().workspace_trust.level() != TrustLevel::Trusted {
+            return Vec::new();
+        }
+        let Some(state) = self.buffers.get(&buffer_id) else {
+            return Vec::new();
+        };
+        if state.buffer.file_path().is_none() {
+            return Vec::new();
+        }
+        let language = state.language.as_str();
+        let has_lsp = self.lsp.has_handles(language);
+        self.resources
+            .config
+            .linters
+            .iter()
+            .filter(|(_, l)| l.enabled && l.languages.iter().any(|lang| lang == language))
+            .filter(|(_, l)| !(l.only_without_lsp && has_lsp))
+            .filter(|(_, l)| !idle || (l.run_on_idle && l.stdin && !l.workspace))
+            .map(|(name, l)| (name.clone(), l.clone()))
+            .collect()
+    }
+
+    fn spawn_linter(&mut self, buffer_id: BufferId, name: String, config: LinterConfig) {
+        let Some(uri) = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_uri())
+            .map(|u| u.as_str().to_string())
+        else {
+            return;
+        };
+        let Some(state) = self.buffers.get(&buffer_id) else {
+            return;
+        };
+        let Some(path) = state.buffer.file_path().map(|p| p.to_path_buf()) else {
+            return;
+        };
+        let text = if config.stdin {
+            let Some(text) = state.buffer.to_string() else {
+                return;
+            };
+            Some(text)
+        } else {
+            None
+        };
+
+        let workspace = config.workspace;
+        let request = LintRequest {
+            name: name.clone(),
+            config,
+            path,
+            text,
+            working_dir: self.root.clone(),
+        };
+        let window = self.id;
+        let sender = self.bridge.sender();
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = std::thread::Builder::new()
+            .name(format!("lint-{}", name))
+            .spawn(move || {
+                // Presets apply whether or not the tool is installed.
+                if !crate::services::lsp::command_exists(&request.config.command) {
+                    return;
+                }
+                let results = match linters::run_linter(&request) {
+                    Ok(results) => results,
+                    Err(e) => {
+                        tracing::warn!("{}", e);
+                        return;
+                    }
+                };
+                let files = results
+                    .into_iter()
+                    .filter_map(|(path, diagnostics)| {
+                        let file_uri = if path == request.path {
+                            uri.clone()
+                        } else {
+                            fresh_core::file_uri::path_to_lsp_uri(&path)?
+                                .as_str()
+                                .to_string()
+                        };
+                        Some((file_uri, diagnostics))
+                    })
+                    .collect();
+                // Receiver gone means the editor is shutting down.
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = sender.send(AsyncMessage::LinterDiagnostics {
+                    window,
+                    linter: name,
+                    files,
+                    workspace,
+                });
+            });
+    }
+}
@@ -0,0 +1,183 @@
+//! Local file history: the copies kept at each save, and the **File
+//! History** picker over them.
+//!
+//! With `editor.local_history_enabled` on, every save of a file stores what
+//! was written (see [`crate::services::local_history`]). The picker lists
+//! the current file's versions, newest first, with how many lines each one
+//! differs from the buffer by. `Enter` restores the selected version into
+//! the buffer as a single undoable edit and `Alt+V` opens a diff from it to
+//! the buffer in a read-only view.
+
+use std::path::{Path, PathBuf};
+
+use rust_i18n::t;
+
+use super::help_actions::HELP_PANEL_MODE;
+use super::Editor;
+use crate::input::commands::Suggestion;
+use crate::model::event::BufferId;
+use crate::services::local_history::{self, HistoryVersion};
+use crate::view::prompt::{Prompt, PromptType};
+
+/// How a version's save time is shown.
+const TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+impl Editor {
+    /// Keep a copy of what was just saved from `buffer_id` to `path`, when
+    /// local history is enabled.
+    pub(super) fn record_local_history(&mut self, buffer_id: BufferId, path: &Path) {
+        if !self.config.editor.local_history_enabled {
+            return;
+        }
+        let Some(content) = self
+            .buffers()
+            .get(&buffer_id)
+            .filter(|state| !state.buffer.is_large_file())
+            .and_then(|state| state.buffer.get_all_text())
+        else {
+            return;
+        };
+        let dir = self.dir_context.local_history_dir_for(path);
+        let max_versions = self.config.editor.local_history_max_versions as usize;
+        if let Err(e) = local_history::record(&dir, &content, chrono::Local::now(), max_versions) {
+            tracing::warn!("Failed to record local history for {:?}: {}", path, e);
+        }
+    }
+
+    /// Show the saved versions of the active file.
+    pub(super) fn show_file_history(&mut self) {
+        let Some(path) = self.active_file_path() else {
+            self.set_status_message(t!("local_history.no_file").to_string());
+            return;
+        };
+        let versions = local_history::versions(&self.dir_context.local_history_dir_for(&path));
+        if versions.is_empty() {
+            let message = if self.config.editor.local_history_enabled {
+                t!("local_history.empty")
+            } else {
+                t!("local_history.disabled")
+            };
+            self.set_status_message(message.to_string());
+            return;
+        }
+
+        let current = self.active_state().buffer.to_string().unwrap_or_default();
+        let suggestions = versions
+            .iter()
+            .map(|version| {
+                let saved = read_version(&version.path);
+                let description = match local_history::change_counts(&saved, &current) {
+                    (0, 0) => t!("local_history.same_as_buffer").to_string(),
+                    (added, removed) => format!("+{} -{}", added, removed),
+                };
+                Suggestion::new(version.saved_at.format(TIME_FORMAT).to_string())
+                    .with_description(description)
+                    .with_value(version.path.display().to_string())
+            })
+            .collect();
+        self.start_prompt_with_suggestions(
+            format!("{}: ", t!("local_history.title")),
+            PromptType::FileHistory,
+            suggestions,
+        );
+        self.set_status_message(t!("local_history.hint").to_string());
+    }
+
+    /// Replace the buffer's content with the saved version at `version`.
+    pub(super) fn restore_local_history_version(&mut self, version: &Path) {
+        if self.refuse_if_editing_disabled() {
+            return;
+        }
+        let Some(version) = self.file_history_version(version) else {
+            return;
+        };
+        let content = read_version(&version.path);
+        if let Err(e) = self.replace_buffer_with_output(&content) {
+            self.set_status_message(e);
+            return;
+        }
+        let time = version.saved_at.format(TIME_FORMAT).to_string();
+        self.set_status_message(t!("local_history.restored", time = time).to_string());
+    }
+
+    /// Close the picker and open a diff from the selected version to the
+    /// buffer.
+    pub(super) fn show_file_history_diff(&mut self) {
+        let Some(version) = self
+            .active_window()
+            .prompt
+            .as_ref()
+            .filter(|p| p.prompt_type == PromptType::FileHistory)
+            .and_then(Prompt::selected_value)
+            .map(PathBuf::from)
+            .and_then(|path| self.file_history_version(&path))
+        else {
+            return;
+        };
+        self.cancel_prompt();
+
+        let name = self
+            .active_file_path()
+            .and_then(|path| path.file_name().map(|n| n.to_string_lossy().into_owned()))
+            .unwrap_or_default();
+        let time = version.saved_at.format(TIME_FORMAT).to_string();
+        let current = self.active_state().buffer.to_string().unwrap_or_default();
+        let mut diff = local_history::unified_diff(
+            &format!("{} ({})", name, time),
+            &format!("{} ({})", name, t!("local_history.buffer_label")),
+            &read_version(&version.path),
+            &current,
+        );
+        if diff.is_empty() {
+            diff = format!("{}\n", t!("local_history.no_differences"));
+        }
+
+        self.ensure_help_panel_mode_registered();
+        // The `.diff` suffix picks the diff syntax for the view.
+        let buffer_name = format!("*History of {}.diff*", name);
+        let window = self.active_window_mut();
+        let existing = window
+            .buffer_metadata
+            .iter()
+            .find(|(_, meta)| meta.display_name == buffer_name)
+            .map(|(id, _)| *id);
+        let buffer_id = existing.unwrap_or_else(|| {
+            let id = window.create_virtual_buffer(buffer_name, HELP_PANEL_MODE.to_string(), true);
+            if let Some(state) = window.buffers.get_mut(&id) {
+                state.editing_disabled = true;
+            }
+            id
+        });
+        let entries = vec![crate::primitives::text_property::TextPropertyEntry::text(
+            diff,
+        )];
+        if let Err(e) = window.set_virtual_buffer_content(buffer_id, entries) {
+            tracing::debug!("Failed to fill the file history diff: {}", e);
+        }
+        self.set_active_buffer(buffer_id);
+        self.goto_byte_offset(0);
+    }
+
+    /// The history version stored at `path` for the active file, if there
+    /// is one.
+    fn file_history_version(&self, path: &Path) -> Option<HistoryVersion> {
+        let file = self.active_file_path()?;
+        local_history::versions(&self.dir_context.local_history_dir_for(&file))
+            .into_iter()
+            .find(|version| version.path == path)
+    }
+
+    fn active_file_path(&self) -> Option<PathBuf> {
+        self.active_state()
+            .buffer
+            .file_path()
+            .map(Path::to_path_buf)
+    }
+}
+
+/// A version's content, as text. Versions that can't be read show empty.
+fn read_version(path: &Path) -> String {
+    std::fs::read(path)
+        .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+        .unwrap_or_default()
+}
@@ -403,6 +403,10 @@ impl Editor {
     /// - `enable:<language>` — restore a dismissed language's pill
     /// - `autostart:<language>/<server_name>` — flip auto_start=true for
     ///   the named server in config, save, and start it now
+    /// - `use:<language>/<command>` — configure the well-known server
+    ///   found on PATH for the language, save, and start it now
+    /// - `install:<language>/<command>` — run the well-known server's
+    ///   install command for this platform as a job
     /// - `cancel_popup` — no-op here; the row exists purely so the
     ///   user has an on-screen "Dismiss" affordance (close is handled
     ///   upstream in `handle_popup_confirm` before this is called)
@@ -432,6 +436,18 @@ impl Editor {
             // that documents the Esc shortcut. Nothing to do here.
             return;
         }
+        if let Some(target) = action_key.strip_prefix("use:") {
+            if let Some((language, command)) = target.split_once('/') {
+                self.use_known_lsp_server(language, command);
+            }
+            return;
+        }
+        if let Some(target) = action_key.strip_prefix("install:") {
+            if let Some((language, command)) = target.split_once('/') {
+                self.install_known_lsp_server(language, command);
+            }
+            return;
+        }
         if let Some(target) = action_key.strip_prefix("autostart:") {
             // Persist `auto_start = true` in config so the server
             // starts automatically on future file opens, then kick it
@@ -661,6 +677,106 @@ impl Editor {
         }
     }
 
+    /// Put the well-known server `command` in front of the configured
+    /// servers for `language` (enabled, auto-starting), persist the config,
+    /// and start it for the open buffers.
+    fn use_known_lsp_server(&mut self, language: &str, command: &str) {
+        let Some(known) = crate::services::lsp::known_servers::find_known_server(language, command)
+        else {
+            return;
+        };
+        let mut configs: Vec<crate::types::LspServerConfig> = self
+            .config()
+            .lsp
+            .get(language)
+            .map(|cfg| cfg.as_slice().to_vec())
+            .unwrap_or_default();
+        configs.retain(|c| c.command != command);
+        configs.insert(0, known.to_config());
+        self.config_mut().lsp.insert(
+            language.to_string(),
+            crate::types::LspLanguageConfig::Multi(configs.clone()),
+        );
+        self.set_lsp_config(language.to_string(), configs);
+        self.active_window_mut().undismiss_lsp_language(language);
+        if let Err(e) = self.save_config() {
+            tracing::warn!("Failed to save config after enabling {}: {}", command, e);
+        } else {
+            let config_path = self.dir_context.config_path();
+            self.emit_event(
+                "config_changed",
+                serde_json::json!({
+                    "path": config_path.to_string_lossy(),
+                }),
+            );
+        }
+
+        let file_path = self
+            .active_window()
+            .buffer_metadata
+            .get(&self.active_buffer())
+            .and_then(|meta| meta.file_path().cloned());
+        let __active_id = self.active_window;
+        if let Some(lsp) = self.windows.get_mut(&__active_id).map(|w| &mut w.lsp) {
+            let _ = lsp.manual_restart(language, file_path.as_deref());
+        }
+        self.reopen_buffers_for_language(language);
+        self.set_status_message(
+            t!(
+                "lsp.server_enabled_detected",
+                command = command,
+                language = language
+            )
+            .to_string(),
+        );
+    }
+
+    /// Run the well-known server's install command for this platform as a
+    /// job in the task runner. Workspace Trust applies as to any spawn.
+    fn install_known_lsp_server(&mut self, language: &str, command: &str) {
+        use crate::services::workspace_trust::SpawnDecision;
+
+        let Some(known) = crate::services::lsp::known_servers::find_known_server(language, command)
+            .filter(|k| k.install_is_runnable())
+        else {
+            return;
+        };
+        let install = known.install_command();
+        let (shell, flag) = if cfg!(windows) {
+            ("cmd", "/C")
+        } else {
+            ("sh", "-c")
+        };
+        let cwd = self.working_dir().to_string_lossy().to_string();
+        if let SpawnDecision::Deny(reason) =
+            self.authority().workspace_trust.decide(shell, Some(&cwd))
+        {
+            self.set_status_message(
+                t!("lsp.install_denied", command = install, reason = reason).to_string(),
+            );
+            return;
+        }
+        let job_id = self.next_editor_job_id();
+        self.handle_spawn_job(
+            "lsp".to_string(),
+            job_id,
+            shell.to_string(),
+            vec![flag.to_string(), install.to_string()],
+            Some(cwd),
+            install.to_string(),
+            false,
+            None,
+        );
+        self.set_status_message(
+            t!(
+                "lsp.server_installing",
+                command = command,
+                install = install
+            )
+            .to_string(),
+        );
+    }
+
     // `toggle_fold_at_cursor`, `toggle_fold_at_line`, and the
     // `toggle_fold_at_byte` wrapper live on `impl Window` — call them via
     // `self.active_window_mut().toggle_fold_at_*(...)`.
@@ -848,9 +964,13 @@ fn create_fold(
         }
     });
 
-    buf_state
-        .folds
-        .add(&mut state.marker_list, start_byte, end_byte, placeholder);
+    buf_state.folds.add(
+        &state.buffer,
+        &mut state.marker_list,
+        start_byte,
+        end_byte,
+        placeholder,
+    );
 
     // If the viewport top is now inside the folded range, move it to the header.
     if buf_state.viewport.top_byte >= start_byte && buf_state.viewport.top_byte < end_byte {
@@ -1329,10 +1449,12 @@ impl Editor {
                     .get_mut(&buffer_id)
                 {
                     let first_line = state.buffer.first_line_lossy();
+                    let modeline_region = state.buffer.modeline_region_lossy();
                     let detected =
                         crate::primitives::detected_language::DetectedLanguage::from_path(
                             &plugin_file,
                             first_line.as_deref(),
+                            modeline_region.as_deref(),
                             &self.grammar_registry,
                             &self.config.languages,
                         );
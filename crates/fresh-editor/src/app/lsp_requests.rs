@@ -19,6 +19,7 @@ use crate::model::event::{BufferId, Event};
 use crate::primitives::word_navigation::{find_word_end, find_word_start};
 use crate::view::prompt::{Prompt, PromptType};
 
+use crate::services::completion::lsp_ranking::rank_lsp_items;
 use crate::services::lsp::async_handler::LspHandle;
 use crate::types::LspFeature;
 
@@ -30,7 +31,7 @@ use super::{Editor, SemanticTokenRangeRequest};
 /// lines, which markdown treats as soft breaks within one paragraph. This
 /// doubles all single newlines so each line becomes its own paragraph with
 /// spacing between them.
-fn space_doc_paragraphs(text: &str) -> String {
+pub(super) fn space_doc_paragraphs(text: &str) -> String {
     text.replace("\n\n", "\x00").replace(['\n', '\x00'], "\n\n")
 }
 
@@ -144,25 +145,15 @@ impl Editor {
         let prefix = if word_start < cursor_pos {
             self.active_state_mut()
                 .get_text_range(word_start, cursor_pos)
-                .to_lowercase()
         } else {
             String::new()
         };
+        let matching = self.config.editor.completion_matching;
+        let sort = self.config.editor.completion_sort;
 
-        let matches_prefix = |item: &lsp_types::CompletionItem| -> bool {
-            prefix.is_empty()
-                || item.label.to_lowercase().starts_with(&prefix)
-                || item
-                    .filter_text
-                    .as_ref()
-                    .map(|ft| ft.to_lowercase().starts_with(&prefix))
-                    .unwrap_or(false)
-        };
-
-        let filtered_items: Vec<&lsp_types::CompletionItem> =
-            items.iter().filter(|item| matches_prefix(item)).collect();
-
-        if filtered_items.is_empty() && self.active_window().completion_items.is_none() {
+        if rank_lsp_items(&items, &prefix, matching, sort).is_empty()
+            && self.active_window().completion_items.is_none()
+        {
             tracing::debug!("No completion items match prefix '{}'", prefix);
             return Ok(());
         }
@@ -174,25 +165,22 @@ impl Editor {
                 tracing::debug!("Extended completion items, now {} total", existing.len());
             }
             None => {
+                self.reset_completion_docs();
                 self.active_window_mut().completion_items = Some(items);
             }
         }
 
         // Rebuild popup from ALL merged items (not just the new batch)
         let all_items = self.active_window_mut().completion_items.as_ref().unwrap();
-        let all_filtered: Vec<&lsp_types::CompletionItem> = all_items
-            .iter()
-            .filter(|item| matches_prefix(item))
-            .collect();
+        let all_ranked = rank_lsp_items(all_items, &prefix, matching, sort);
 
-        if all_filtered.is_empty() {
+        if all_ranked.is_empty() {
             tracing::debug!("No completion items match prefix '{}'", prefix);
             return Ok(());
         }
 
         // Build LSP popup items, then append buffer-word items below.
-        let mut all_popup_items =
-            crate::app::popup_actions::lsp_items_to_popup_items(&all_filtered);
+        let mut all_popup_items = crate::app::popup_actions::lsp_items_to_popup_items(all_ranked);
         let buffer_word_items = self.get_buffer_completion_popup_items();
         // Deduplicate: skip buffer-word items whose label already appears in LSP results.
         let lsp_labels: std::collections::HashSet<String> = all_popup_items
@@ -231,6 +219,7 @@ impl Editor {
             popup_obj.focus_key_hint = focus_hint;
             state.popups.show_or_replace(popup_obj);
         }
+        self.update_completion_docs();
 
         tracing::info!(
             "Showing completion popup with {} items",
@@ -261,7 +250,9 @@ impl Editor {
         self.active_window_mut().pending_goto_definition_request = None;
 
         if locations.is_empty() {
-            self.active_window_mut().status_message = Some(t!("lsp.no_definition").to_string());
+            if !self.goto_tag_definition() {
+                self.active_window_mut().status_message = Some(t!("lsp.no_definition").to_string());
+            }
             return Ok(());
         }
 
@@ -646,7 +637,9 @@ impl Editor {
 
     /// Request LSP completion at current cursor position.
     /// Sends completion requests to all eligible servers for merged results.
-    pub(crate) fn request_completion(&mut self) {
+    /// `trigger_character` is the trigger character that was just typed, or
+    /// `None` for explicit invocation and completion while typing a word.
+    pub(crate) fn request_completion(&mut self, trigger_character: Option<char>) {
         // A new completion request starts a fresh batch. Cancel any
         // previous in-flight completion requests so their late responses
         // are ignored (handle_completion_response drops responses whose
@@ -700,6 +693,7 @@ impl Editor {
                     uri.as_uri().clone(),
                     line as u32,
                     character as u32,
+                    trigger_character,
                 );
                 if result.is_ok() {
                     tracing::info!(
@@ -805,7 +799,7 @@ impl Editor {
             );
             // Cancel any pending scheduled trigger
             self.active_window_mut().scheduled_completion_trigger = None;
-            self.request_completion();
+            self.request_completion(Some(c));
             return;
         }
 
@@ -871,6 +865,9 @@ impl Editor {
         if sent {
             self.active_window_mut().next_lsp_request_id += 1;
             self.active_window_mut().pending_goto_definition_request = Some(request_id);
+        } else {
+            // No server for this buffer: the project's tags file may know.
+            self.goto_tag_definition();
         }
 
         Ok(())
@@ -931,11 +928,20 @@ impl Editor {
             },
         );
 
-        let sent_ids: Vec<u64> = results
+        let mut sent_ids: Vec<u64> = results
             .iter()
             .filter_map(|(id, ok)| ok.then_some(*id))
             .collect();
-        self.active_window_mut().next_lsp_request_id = base_request_id + results.len() as u64;
+        let mut next_request_id = base_request_id + results.len() as u64;
+        // JSON/YAML schemas document keys too; their answer joins the batch.
+        if self
+            .active_window()
+            .spawn_schema_hover(buffer_id, cursor_pos, next_request_id)
+        {
+            sent_ids.push(next_request_id);
+            next_request_id += 1;
+        }
+        self.active_window_mut().next_lsp_request_id = next_request_id;
 
         if !sent_ids.is_empty() {
             self.active_window_mut().hover.record_requests(
@@ -1015,11 +1021,19 @@ impl Editor {
             },
         );
 
-        let sent_ids: Vec<u64> = results
+        let mut sent_ids: Vec<u64> = results
             .iter()
             .filter_map(|(id, ok)| ok.then_some(*id))
             .collect();
-        self.active_window_mut().next_lsp_request_id = base_request_id + results.len() as u64;
+        let mut next_request_id = base_request_id + results.len() as u64;
+        if self
+            .active_window()
+            .spawn_schema_hover(buffer_id, byte_pos, next_request_id)
+        {
+            sent_ids.push(next_request_id);
+            next_request_id += 1;
+        }
+        self.active_window_mut().next_lsp_request_id = next_request_id;
 
         if !sent_ids.is_empty() {
             self.active_window_mut().hover.record_requests(
@@ -2036,6 +2050,7 @@ impl Editor {
                         icon: None,
                         data: Some(i.to_string()),
                         disabled: false,
+                        match_positions: Vec::new(),
                     }
                 })
                 .collect()
@@ -2119,16 +2134,32 @@ impl Editor {
     pub(crate) fn execute_resolved_code_action(&mut self, ca: lsp_types::CodeAction) {
         let title = ca.title.clone();
 
+        // Source actions (organize imports, fix all) rewrite the whole file,
+        // so keep the active buffer's text for Revert Last Transform.
+        let is_source_action = ca
+            .kind
+            .as_ref()
+            .is_some_and(|kind| kind.as_str().starts_with("source"));
+        let buffer_id = self.active_buffer();
+        let before = if is_source_action && ca.edit.is_some() {
+            self.active_state().buffer.to_string()
+        } else {
+            None
+        };
+
         // Apply workspace edit if present
         if let Some(edit) = ca.edit {
             match self.apply_workspace_edit(edit) {
                 Ok(n) => {
+                    if let Some(before) = before {
+                        self.record_transform(buffer_id, title.clone(), before);
+                    }
                     self.set_status_message(
                         t!("lsp.code_action_applied", title = &title, count = n).to_string(),
                     );
                 }
                 Err(e) => {
-                    self.set_status_message(format!("Code action failed: {e}"));
+                    self.set_status_error(format!("Code action failed: {e}"));
                     return;
                 }
             }
@@ -2142,7 +2173,6 @@ impl Editor {
 
     /// Send workspace/executeCommand to the LSP server
     fn send_execute_command(&mut self, cmd: lsp_types::Command) {
-        tracing::info!("Executing LSP command: {} ({})", cmd.title, cmd.command);
         self.set_status_message(
             t!(
                 "lsp.code_action_applied",
@@ -2151,6 +2181,13 @@ impl Editor {
             )
             .to_string(),
         );
+        self.execute_lsp_command(&cmd);
+    }
+
+    /// Send workspace/executeCommand for `cmd` to the active buffer's
+    /// language servers, without a status message.
+    pub(super) fn execute_lsp_command(&mut self, cmd: &lsp_types::Command) {
+        tracing::info!("Executing LSP command: {} ({})", cmd.title, cmd.command);
 
         // Get the language for this buffer to find the right LSP handle
         let language = match self
@@ -2232,7 +2269,14 @@ impl Editor {
             .map(|(id, _)| *id);
 
         if let Some(buffer_id) = buffer_id {
+            let before = self
+                .buffers()
+                .get(&buffer_id)
+                .and_then(|state| state.buffer.to_string());
             let count = self.apply_lsp_text_edits(buffer_id, edits)?;
+            if let Some(before) = before {
+                self.record_transform(buffer_id, t!("transform.format").to_string(), before);
+            }
             self.set_status_message(format!("Formatted ({} edits)", count));
             Ok(count)
         } else {
@@ -3207,7 +3251,7 @@ impl Editor {
             Ok(value) if !value.is_null() => {
                 // prepareRename succeeded — show the rename prompt
                 if let Err(e) = self.show_rename_prompt() {
-                    self.set_status_message(format!("Rename failed: {e}"));
+                    self.set_status_error(format!("Rename failed: {e}"));
                 }
             }
             Ok(_) => {
@@ -253,6 +253,9 @@ impl Editor {
             auto_revert_enabled: true,
             synthetic_placeholder: false,
             recovery_id: None,
+            scratch: false,
+            last_transform: None,
+            narrowing: None,
         };
         self.active_window_mut()
             .buffer_metadata
@@ -338,6 +341,9 @@ impl Editor {
             auto_revert_enabled: true,
             synthetic_placeholder: false,
             recovery_id: None,
+            scratch: false,
+            last_transform: None,
+            narrowing: None,
         };
         self.active_window_mut()
             .buffer_metadata
@@ -0,0 +1,155 @@
+//! The *Messages* panel: the window's [`MessageLog`] in a read-only buffer.
+//!
+//! Every status message, plugin status, LSP `window/showMessage`
+//! notification and reported error is recorded with a timestamp and a
+//! severity. **Show Messages** opens the log; while the panel is open it
+//! follows new messages. In the panel `f` steps the severity filter and
+//! `q` closes it.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use rust_i18n::t;
+
+use crate::app::types::{MessageLog, MessageSeverity};
+use crate::app::window::Window;
+use crate::app::Editor;
+use crate::input::buffer_mode::BufferMode;
+use crate::input::keybindings::{Action, KeyContext};
+use crate::model::event::BufferId;
+use crate::primitives::text_property::TextPropertyEntry;
+use fresh_core::api::{OverlayColorSpec, OverlayOptions};
+
+/// Display name of the panel buffer.
+const MESSAGES_BUFFER_NAME: &str = "*Messages*";
+
+/// Mode carried on the panel buffer, for its `f` / `q` bindings.
+const MESSAGES_PANEL_MODE: &str = "messages";
+
+impl Editor {
+    /// Open the *Messages* panel, or switch to it and refresh it.
+    pub(super) fn show_messages(&mut self) {
+        self.ensure_messages_panel_mode_registered();
+        let window = self.active_window_mut();
+        let buffer_id = match window.messages_panel_buffer() {
+            Some(id) => id,
+            None => {
+                let id = window.create_virtual_buffer(
+                    MESSAGES_BUFFER_NAME.to_string(),
+                    MESSAGES_PANEL_MODE.to_string(),
+                    true,
+                );
+                if let Some(state) = window.buffers.get_mut(&id) {
+                    state.editing_disabled = true;
+                    state.margins.configure_for_line_numbers(false);
+                }
+                id
+            }
+        };
+        window.refresh_messages_panel();
+        self.set_active_buffer(buffer_id);
+        // Newest messages are at the bottom.
+        let len = self.active_state().buffer.len();
+        self.goto_byte_offset(len);
+    }
+
+    /// Step the panel's severity filter (all → warnings → errors).
+    pub(super) fn cycle_message_filter(&mut self) {
+        let window = self.active_window_mut();
+        window.message_log.cycle_filter();
+        window.refresh_messages_panel();
+    }
+
+    /// Show `message` in the status bar and log it as an error.
+    pub fn set_status_error(&mut self, message: String) {
+        self.active_window_mut().set_status_error(message);
+    }
+
+    /// Bind `f` (filter) and `q` (close) in the panel. Idempotent, like
+    /// `ensure_help_panel_mode_registered`.
+    fn ensure_messages_panel_mode_registered(&mut self) {
+        let mode_ctx = KeyContext::Mode(MESSAGES_PANEL_MODE.to_string());
+        {
+            let mut kb = self.keybindings.write().unwrap();
+            kb.clear_plugin_defaults_for_mode(MESSAGES_PANEL_MODE);
+            kb.set_mode_inherits_normal_bindings(MESSAGES_PANEL_MODE, true);
+            kb.load_plugin_default(
+                mode_ctx.clone(),
+                KeyCode::Char('f'),
+                KeyModifiers::NONE,
+                Action::CycleMessageFilter,
+            );
+            kb.load_plugin_default(
+                mode_ctx,
+                KeyCode::Char('q'),
+                KeyModifiers::NONE,
+                Action::CloseTab,
+            );
+        }
+        self.mode_registry.register(
+            BufferMode::new(MESSAGES_PANEL_MODE)
+                .with_read_only(true)
+                .with_inherit_normal_bindings(true),
+        );
+    }
+}
+
+impl Window {
+    /// Add a message to the log, and to the panel if it is open.
+    pub(crate) fn record_message(&mut self, severity: MessageSeverity, source: &str, text: &str) {
+        self.message_log.push(severity, source, text);
+        self.refresh_messages_panel();
+    }
+
+    fn messages_panel_buffer(&self) -> Option<BufferId> {
+        self.buffer_metadata
+            .iter()
+            .find(|(_, meta)| meta.virtual_mode() == Some(MESSAGES_PANEL_MODE))
+            .map(|(id, _)| *id)
+    }
+
+    fn refresh_messages_panel(&mut self) {
+        let Some(buffer_id) = self.messages_panel_buffer() else {
+            return;
+        };
+        let entries = messages_panel_entries(&self.message_log);
+        if let Err(e) = self.set_virtual_buffer_content(buffer_id, entries) {
+            tracing::debug!("Failed to refresh the messages panel: {}", e);
+        }
+    }
+}
+
+/// The panel's lines: a header naming the filter, then one line per
+/// message, warnings and errors colored like diagnostics.
+fn messages_panel_entries(log: &MessageLog) -> Vec<TextPropertyEntry> {
+    let filter = match log.min_severity {
+        MessageSeverity::Info => t!("messages.filter_all"),
+        MessageSeverity::Warning => t!("messages.filter_warnings"),
+        MessageSeverity::Error => t!("messages.filter_errors"),
+    };
+    let mut entries = vec![TextPropertyEntry::text(format!(
+        "{}\n\n",
+        t!("messages.header", filter = filter)
+    ))];
+    for message in log.visible() {
+        let mut entry = TextPropertyEntry::text(format!(
+            "{}  {}  {}: {}\n",
+            message.time.format("%H:%M:%S"),
+            message.severity.label(),
+            message.source,
+            message.text.replace('\n', " "),
+        ));
+        let color = match message.severity {
+            MessageSeverity::Info => None,
+            MessageSeverity::Warning => Some("diagnostic.warning_fg"),
+            MessageSeverity::Error => Some("diagnostic.error_fg"),
+        };
+        entry.style = color.map(|key| OverlayOptions {
+            fg: Some(OverlayColorSpec::ThemeKey(key.to_string())),
+            ..Default::default()
+        });
+        entries.push(entry);
+    }
+    if entries.len() == 1 {
+        entries.push(TextPropertyEntry::text(t!("messages.empty").to_string()));
+    }
+    entries
+}
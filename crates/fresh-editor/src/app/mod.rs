@@ -1,26 +1,42 @@
+mod abbreviation_actions;
 mod action_events;
 mod active_focus;
 mod async_dispatch;
 mod async_messages;
+pub mod bench;
 mod bookmark_actions;
 mod bookmarks;
+mod branch_switch;
 mod buffer_close;
 mod buffer_config_resolve;
+mod buffer_context_menu;
 mod buffer_groups;
 mod buffer_management;
+mod buffer_picker;
+mod buffer_share;
+mod calc_actions;
 mod calibration_actions;
 pub mod calibration_wizard;
+mod chord_hints;
 mod click_geometry;
 mod click_handlers;
 mod clipboard;
+mod clipboard_history;
+mod closed_buffers;
+mod completion_docs;
 mod composite_buffer_actions;
+mod config_rescue;
+mod config_watch;
+mod cursor_style;
 mod dabbrev_actions;
 mod diagnostic_jumps;
 mod editor_accessors;
 mod editor_init;
+mod editor_log;
 mod event_apply;
 pub mod event_debug;
 mod event_debug_actions;
+mod event_hooks;
 mod file_explorer;
 pub mod file_open;
 mod file_open_input;
@@ -31,13 +47,19 @@ mod git_index;
 mod help;
 mod help_actions;
 mod hover;
+mod ime;
+mod indentation_actions;
 mod input;
 mod input_dispatch;
 mod input_helpers;
+mod jobs;
 pub mod keybinding_editor;
 mod keybinding_editor_actions;
 mod lifecycle;
+mod line_ending_actions;
 mod line_scan;
+mod linters;
+mod local_history_actions;
 mod lsp_actions;
 mod lsp_event_notify;
 mod lsp_requests;
@@ -47,12 +69,17 @@ mod macro_codegen;
 mod macros;
 mod menu_actions;
 mod menu_context;
+mod messages_panel;
+mod mojibake_actions;
 mod mouse_input;
+mod narrowing;
 mod navigation;
+mod navigation_history;
 mod on_save_actions;
 mod orchestrator_persistence;
 mod overlay;
 mod path_utils;
+mod perf_hud;
 #[cfg(feature = "plugins")]
 mod plugin_commands;
 #[cfg(feature = "plugins")]
@@ -60,32 +87,51 @@ mod plugin_dispatch;
 mod popup_actions;
 mod popup_dialogs;
 mod popup_overlay_actions;
+pub mod presentation;
+mod presentation_actions;
+mod preview_actions;
 mod prompt_actions;
 mod prompt_lifecycle;
 mod recovery_actions;
 mod regex_replace;
+mod reload_remap;
 pub(crate) mod render;
 mod scan_orchestrators;
+mod schema_validation;
+mod scratch_buffers;
+mod screen_dump_actions;
+#[cfg(feature = "scripting")]
+mod scripts;
 mod scroll_sync;
 mod scrollbar_input;
 mod scrollbar_math;
 mod search_ops;
 mod search_scan;
+mod selection_expand_actions;
 mod settings_actions;
 mod settings_prompts;
 mod shell_command;
 mod smart_home;
+mod smart_tab;
+mod snippet_actions;
 mod split_actions;
+pub mod startup_profile;
 mod stdin_stream;
 mod tab_drag;
+mod tag_actions;
+mod telemetry_actions;
 mod terminal;
 pub use terminal::PluginTerminalSpec;
 mod terminal_input;
 mod terminal_link;
 mod terminal_mouse;
+mod terminal_probe_actions;
+mod text_drag;
 mod text_ops;
 mod theme_inspect;
+mod theme_reload;
 mod toggle_actions;
+mod transform_revert;
 pub mod types;
 mod undo_actions;
 mod view_actions;
@@ -157,6 +203,9 @@ pub fn editor_tick(
     if editor.check_mouse_hover_timer() {
         needs_render = true;
     }
+    if editor.active_window_mut().step_smooth_scroll() {
+        needs_render = true;
+    }
     // Hide the dock's keyboard-flashed overlay scrollbar once its deadline
     // passes: the main loop's idle poll runs this tick within ~50ms of
     // expiry, so the bar vanishes without another input event.
@@ -169,7 +218,19 @@ pub fn editor_tick(
     if editor.check_completion_trigger_timer() {
         needs_render = true;
     }
+    if editor.check_chord_timeout() {
+        needs_render = true;
+    }
     editor.active_window_mut().check_diagnostic_pull_timer();
+    editor.active_window_mut().check_schema_validation_timer();
+    editor.active_window_mut().check_lint_timer();
+    editor.check_event_hooks();
+    editor.publish_shared_buffer();
+    // `fresh --wait`: the waited-on buffer was closed, so we're done.
+    if editor.take_cli_wait_completed() {
+        editor.quit();
+        needs_render = true;
+    }
     editor.check_inlay_hints_timer();
     if editor.check_warning_log() {
         needs_render = true;
@@ -244,6 +305,7 @@ pub use self::warning_domains::{
     WarningDomainRegistry, WarningLevel, WarningPopupContent,
 };
 pub use crate::model::event::BufferId;
+pub use file_open_queue::CLI_WAIT_ID;
 
 /// Decode a wire-side LSP URI to a host path. Thin wrapper over
 /// [`LspUri::to_host_path`](crate::app::types::LspUri::to_host_path)
@@ -324,6 +386,32 @@ pub struct DabbrevCycleState {
     pub index: usize,
 }
 
+/// Documentation panel shown beside the completion popup for its selected
+/// item.
+#[derive(Debug, Clone)]
+pub struct CompletionDocs {
+    /// Label of the completion item the panel describes.
+    pub label: String,
+    /// Markdown panel, scrolled independently of the completion list.
+    pub panel: crate::view::popup::Popup,
+}
+
+/// The paste that Cycle Paste Through History would replace.
+///
+/// Recorded by every buffer paste. It only counts while the paste is still
+/// the last entry in the buffer's event log, so a cycle never undoes an
+/// unrelated edit — and opening the command palette to run it doesn't
+/// break the chain the way resetting on every action would.
+#[derive(Debug, Clone)]
+pub struct PasteCycleState {
+    pub buffer_id: BufferId,
+    /// Clipboard history index of the pasted text, if it is in the history.
+    pub history_index: Option<usize>,
+    /// Event log position right after the paste; anything else logged
+    /// since then means the paste is no longer the last edit.
+    pub undo_index: usize,
+}
+
 /// Snapshot of cursor and viewport state used to restore the original position
 /// when a goto-line preview is abandoned (cancel, or the user edits the input
 /// so it no longer targets a line).
@@ -461,6 +549,28 @@ pub struct Editor {
     /// Shared theme data cache for plugin access (name → JSON value)
     theme_cache: Arc<RwLock<HashMap<String, serde_json::Value>>>,
 
+    /// Theme picked with **Preview Themes** for this session only. Takes
+    /// precedence over `config.theme` (e.g. on theme reload) until a theme
+    /// is persisted again via **Select Theme**.
+    session_theme: Option<String>,
+
+    /// Hot-reload polling state for the user themes directory.
+    theme_dir_watch: theme_reload::ThemeDirWatch,
+
+    /// Hot-reload polling state for the config layer files.
+    config_file_watch: config_watch::ConfigFileWatch,
+
+    /// Target of a **Dump Screen To File** request, written at the end of
+    /// the next render.
+    pending_screen_dump: Option<PathBuf>,
+
+    /// `--screenshot-on-exit` target; while set, every render keeps a copy
+    /// of its frame in `last_rendered_frame`.
+    screenshot_on_exit: Option<PathBuf>,
+
+    /// Most recent finished frame, kept only for `--screenshot-on-exit`.
+    last_rendered_frame: Option<ratatui::buffer::Buffer>,
+
     /// Optional ANSI background image
     ansi_background: Option<crate::primitives::ansi_background::AnsiBackground>,
 
@@ -510,6 +620,16 @@ pub struct Editor {
     /// These get prepended to the next render output
     pending_escape_sequences: Vec<u8>,
 
+    /// Terminal cursor style last handed out by `take_cursor_style_change`
+    /// (`None` until the first frame)
+    applied_cursor_style: Option<crate::config::CursorStyle>,
+
+    /// Frame timing and the performance HUD's visibility
+    perf_hud: perf_hud::PerfHud,
+
+    /// `--profile-startup` timings, finished by the first frame
+    startup_profile: Option<startup_profile::StartupProfile>,
+
     /// If set, the editor should restart with this new working directory
     /// This is used by Open Folder to do a clean context switch
     restart_with_dir: Option<PathBuf>,
@@ -570,6 +690,11 @@ pub struct Editor {
     /// press a key. Only windows with a remote authority are tracked.
     remote_connected_cache: HashMap<fresh_core::WindowId, bool>,
 
+    /// Bounded-prefix file reader for the overlay pickers' preview (Live
+    /// Grep, Find File) on remote workspaces. Created on first use and rebuilt when the active
+    /// authority's filesystem changes.
+    file_preview: Option<crate::services::file_preview::FilePreviewLoader>,
+
     /// In-flight async system-clipboard reads, keyed by `request_id`.
     /// Each entry owns an anchor (`VirtualText("▍")`) in some buffer
     /// that floats with edits via the marker tree; when the matching
@@ -842,6 +967,33 @@ pub struct Editor {
     /// Maps process_id to abort handle
     background_process_handles: HashMap<u64, tokio::task::AbortHandle>,
 
+    /// Running jobs started with `editor.spawnJob` or by a config hook, by
+    /// job id (which is also their background process id). See `jobs.rs`.
+    jobs: std::collections::BTreeMap<u64, jobs::Job>,
+
+    /// Count of jobs started by config hooks, for their job ids. See
+    /// `event_hooks.rs`.
+    next_hook_job: u64,
+
+    /// The buffer being served read-only over HTTP by the Serve Buffer
+    /// command. See `buffer_share.rs`.
+    buffer_share: Option<buffer_share::BufferShare>,
+
+    /// Set while the editor runs on defaults because the config failed to
+    /// load. See `config_rescue.rs`.
+    config_rescue: Option<config_rescue::ConfigRescue>,
+
+    /// Active buffer at the last tick, to notice buffer switches for the
+    /// `buffer_switch` config hooks.
+    hook_active_buffer: Option<BufferId>,
+
+    /// When each edited buffer's `idle` config hooks are due.
+    idle_hooks: HashMap<BufferId, std::time::Instant>,
+
+    /// User scripts from `~/.config/fresh/scripts/`. See `scripts.rs`.
+    #[cfg(feature = "scripting")]
+    scripts: crate::services::scripting::ScriptHost,
+
     /// Cancellation senders for host-side processes spawned via
     /// `spawnHostProcess`. Firing the sender (or dropping it) triggers
     /// an in-task `child.start_kill()` so the process is reaped, not
@@ -937,6 +1089,16 @@ pub struct Editor {
     /// See docs/internal/web-ui.md.
     pub(crate) suppress_chrome_cells: bool,
 
+    /// Uncommitted input-method (IME) composition text. Painted underlined
+    /// at the caret of the active split until the IME commits or cancels;
+    /// never part of the buffer. Set by frontends that see pre-edit events
+    /// (GUI, web); a terminal emulator draws its own pre-edit instead.
+    pub(crate) ime_preedit: Option<String>,
+
+    /// Dead key held back from a terminal that sends accents as separate
+    /// keys (`editor.compose_dead_keys`); shown as the pre-edit meanwhile.
+    pub(crate) dead_keys: crate::input::dead_keys::DeadKeyComposer,
+
     /// Request the event loop to suspend the process (SIGTSTP on Unix).
     /// Consumed by the outer event loop after the current action returns.
     suspend_requested: bool,
@@ -967,6 +1129,11 @@ pub struct Editor {
     /// Status message log path (for viewing full status history)
     status_log_path: Option<PathBuf>,
 
+    /// The editor's own tracing log, for **Show Editor Log**
+    editor_log_path: Option<PathBuf>,
+    /// What the open *Editor Log* panel has read of that log
+    editor_log_tail: Option<editor_log::EditorLogTail>,
+
     /// Warning domain registry for extensible warning indicators
     /// Contains LSP warnings, general warnings, and can be extended by plugins
     // `warning_domains` moved onto `Window`.
@@ -974,6 +1141,10 @@ pub struct Editor {
     /// Periodic update checker (checks for new releases every hour)
     update_checker: Option<crate::services::release_checker::PeriodicUpdateChecker>,
 
+    /// `--no-upgrade-check` was given: update checks and telemetry stay off
+    /// across config reloads, whatever the config files say.
+    no_upgrade_check: bool,
+
     /// Lifecycle of an interactive in-editor self-update, reflected by the
     /// status-bar update indicator (see `SelfUpdatePhase`).
     self_update_phase: crate::services::release_checker::SelfUpdatePhase,
@@ -1045,6 +1216,18 @@ pub struct Editor {
     /// Calibration wizard state (when calibration modal is open)
     pub(crate) calibration_wizard: Option<calibration_wizard::CalibrationWizard>,
 
+    /// What the host terminal supports, from the environment and its
+    /// answers to the startup capability query
+    pub(crate) terminal_capabilities: crate::services::terminal_probe::TerminalCapabilities,
+
+    /// Image the last frame reserved cells for, and the one last written
+    /// to the terminal (see `preview_actions`)
+    pub(crate) image_placement: Option<preview_actions::ImagePlacement>,
+    pub(crate) shown_image: Option<preview_actions::ImagePlacement>,
+
+    /// Presentation mode state (when presenting a buffer)
+    pub(crate) presentation: Option<presentation::Presentation>,
+
     // `event_debug` modal state moved to `Window` — each window has its
     // own debug overlay (the dialog records keystrokes destined for that
     // window's input pipeline, so it's logically per-window).
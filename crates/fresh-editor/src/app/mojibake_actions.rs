@@ -0,0 +1,106 @@
+//! Fix Encoding Artifacts: repair mojibake in the selection.
+//!
+//! The command finds the selections that contain mojibake (see
+//! [`crate::model::mojibake`]), previews the first repair in a confirmation
+//! prompt, and on confirmation replaces every affected selection as a single
+//! undoable edit.
+
+use rust_i18n::t;
+
+use crate::model::event::{CursorId, Event};
+use crate::model::mojibake::{repair_mojibake, EncodingFix};
+use crate::view::prompt::PromptType;
+
+use super::Editor;
+
+/// Longest text shown on either side of the preview arrow.
+const PREVIEW_CHARS: usize = 30;
+
+impl Editor {
+    /// Scan the selections for mojibake and ask before repairing it.
+    pub(super) fn start_fix_encoding_artifacts(&mut self) {
+        let ranges: Vec<_> = self
+            .active_cursors()
+            .iter()
+            .filter_map(|(_, cursor)| cursor.selection_range())
+            .filter(|range| !range.is_empty())
+            .collect();
+        if ranges.is_empty() {
+            self.set_status_message(t!("mojibake.no_selection").to_string());
+            return;
+        }
+
+        let mut fixes = Vec::new();
+        let state = self.active_state_mut();
+        for range in ranges {
+            let original = state.get_text_range(range.start, range.end);
+            if let Some(repaired) = repair_mojibake(&original) {
+                fixes.push(EncodingFix {
+                    range,
+                    original,
+                    repaired,
+                });
+            }
+        }
+        let Some(first) = fixes.first() else {
+            self.set_status_message(t!("mojibake.none_found").to_string());
+            return;
+        };
+
+        let message = t!(
+            "mojibake.confirm",
+            count = fixes.len(),
+            before = preview(&first.original),
+            after = preview(&first.repaired)
+        )
+        .to_string();
+        self.start_prompt(message, PromptType::ConfirmFixEncoding { fixes });
+    }
+
+    /// Apply the repairs confirmed in the Fix Encoding Artifacts prompt.
+    /// Selections edited since the prompt opened are skipped.
+    pub(super) fn apply_encoding_fixes(&mut self, mut fixes: Vec<EncodingFix>) {
+        let state = self.active_state_mut();
+        fixes.retain(|fix| {
+            fix.range.end <= state.buffer.len()
+                && state.get_text_range(fix.range.start, fix.range.end) == fix.original
+        });
+        // Descending order keeps each range valid while the later ones apply.
+        fixes.sort_by_key(|fix| std::cmp::Reverse(fix.range.start));
+
+        let cursor_id = CursorId::UNDO_SENTINEL;
+        let count = fixes.len();
+        let mut events = Vec::with_capacity(count * 2);
+        for fix in fixes {
+            events.push(Event::Delete {
+                range: fix.range.clone(),
+                deleted_text: fix.original,
+                cursor_id,
+            });
+            events.push(Event::Insert {
+                position: fix.range.start,
+                text: fix.repaired,
+                cursor_id,
+            });
+        }
+        if count > 0 {
+            if let Some(bulk_edit) =
+                self.apply_events_as_bulk_edit(events, "Fix encoding artifacts".to_string())
+            {
+                self.active_event_log_mut().append(bulk_edit);
+            }
+        }
+        self.set_status_message(t!("mojibake.fixed", count = count).to_string());
+    }
+}
+
+/// First line of `text`, cut to [`PREVIEW_CHARS`] characters.
+fn preview(text: &str) -> String {
+    let line = text.lines().next().unwrap_or_default();
+    let mut chars = line.chars();
+    let mut shown: String = chars.by_ref().take(PREVIEW_CHARS).collect();
+    if chars.next().is_some() || line.len() < text.trim_end().len() {
+        shown.push('…');
+    }
+    shown
+}
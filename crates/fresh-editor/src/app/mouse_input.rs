@@ -46,6 +46,7 @@ impl Editor {
             LayerKind::Settings
             | LayerKind::KeybindingEditor
             | LayerKind::CalibrationWizard
+            | LayerKind::Presentation
             | LayerKind::WorkspaceTrust
             | LayerKind::FloatingModal => Some(l.kind),
             _ => None,
@@ -57,6 +58,8 @@ impl Editor {
             // every mouse event (its UI is keyboard-driven). Swallowing
             // here matches the previous explicit `return Ok(false)`.
             LayerKind::CalibrationWizard => Ok(false),
+            // Presentation mode is read-only and paged from the keyboard.
+            LayerKind::Presentation => Ok(false),
             LayerKind::WorkspaceTrust => self.handle_workspace_trust_mouse(mouse_event),
             // The centered widget modal (orchestrator control room /
             // New-Session form) captures the whole mouse channel here —
@@ -324,6 +327,12 @@ impl Editor {
                     }
                 }
 
+                // Drop a dragged selection (or, if it never moved, treat
+                // the press as the click it was)
+                if let Some(drag) = self.active_window_mut().mouse_state.dragging_text.take() {
+                    self.finish_text_drag(drag, mouse_event.modifiers);
+                }
+
                 // Stop dragging and clear drag state
                 self.release_widget_scrollbar();
                 self.clear_active_window_drag_state();
@@ -338,16 +347,27 @@ impl Editor {
             }
             MouseEventKind::Moved => {
                 // Dispatch MouseMove hook to plugins (fire-and-forget, no blocking check)
+                if self
+                    .plugin_manager
+                    .read()
+                    .unwrap()
+                    .has_hook_handlers("mouse_move")
                 {
-                    // Find content rect for the split under the mouse
-                    let content_rect = self
+                    // Find the split under the mouse
+                    let split_area = self
                         .active_layout()
                         .split_areas
                         .iter()
                         .find(|(_, _, content_rect, _, _, _)| in_rect(col, row, *content_rect))
-                        .map(|(_, _, rect, _, _, _)| *rect);
+                        .map(|(split_id, buffer_id, rect, _, _, _)| (*split_id, *buffer_id, *rect));
 
-                    let (content_x, content_y) = content_rect.map(|r| (r.x, r.y)).unwrap_or((0, 0));
+                    let (content_x, content_y) =
+                        split_area.map(|(_, _, r)| (r.x, r.y)).unwrap_or((0, 0));
+                    let (buffer_row, buffer_col) = split_area
+                        .map(|(split_id, buffer_id, rect)| {
+                            self.buffer_row_col_at(col, row, split_id, buffer_id, rect)
+                        })
+                        .unwrap_or((None, None));
 
                     self.plugin_manager.read().unwrap().run_hook(
                         "mouse_move",
@@ -356,6 +376,9 @@ impl Editor {
                             row,
                             content_x,
                             content_y,
+                            buffer_id: split_area.map(|(_, buffer_id, _)| buffer_id.0 as u64),
+                            buffer_row,
+                            buffer_col,
                         },
                     );
                 }
@@ -414,11 +437,13 @@ impl Editor {
                 }
             }
             MouseEventKind::ScrollUp => {
-                self.handle_vertical_scroll(col, row, mouse_event.modifiers, -3)?;
+                let lines = self.config.editor.mouse_wheel_scroll_lines as i32;
+                self.handle_vertical_scroll(col, row, mouse_event.modifiers, -lines)?;
                 needs_render = true;
             }
             MouseEventKind::ScrollDown => {
-                self.handle_vertical_scroll(col, row, mouse_event.modifiers, 3)?;
+                let lines = self.config.editor.mouse_wheel_scroll_lines as i32;
+                self.handle_vertical_scroll(col, row, mouse_event.modifiers, lines)?;
                 needs_render = true;
             }
             MouseEventKind::ScrollLeft => {
@@ -447,7 +472,8 @@ impl Editor {
                     self.show_theme_info_popup(col, row)?;
                     needs_render = true;
                 } else {
-                    // Normal right-click → tab context menu
+                    // Normal right-click → tab / explorer / content
+                    // context menu
                     self.handle_right_click(col, row)?;
                     needs_render = true;
                 }
@@ -522,6 +548,13 @@ impl Editor {
             && self.handle_file_open_scroll(delta)
         {
             // file browser consumed the scroll
+        } else if self
+            .active_chrome()
+            .completion_docs_area
+            .is_some_and(|area| in_rect(col, row, area))
+            && self.scroll_completion_docs(delta)
+        {
+            // the completion documentation panel consumed the scroll
         } else if self.is_mouse_over_any_popup(col, row) {
             self.scroll_popup(delta);
         } else if self.floating_widget_panel.is_some() {
@@ -990,6 +1023,12 @@ impl Editor {
                 return true;
             }
         }
+        // The completion documentation panel belongs to the completion popup.
+        if let Some(docs) = self.active_chrome().completion_docs_area {
+            if in_rect(col, row, docs) {
+                return true;
+            }
+        }
         let layouts = popup_areas_to_layout_info(&self.active_chrome().popup_areas);
         let hit_tester = PopupHitTester::new(&layouts, &self.active_state().popups);
         hit_tester.is_over_popup(col, row)
@@ -1277,6 +1316,7 @@ impl Editor {
                     SearchOptionsHover::WholeWord => HoverTarget::SearchOptionWholeWord,
                     SearchOptionsHover::Regex => HoverTarget::SearchOptionRegex,
                     SearchOptionsHover::ConfirmEach => HoverTarget::SearchOptionConfirmEach,
+                    SearchOptionsHover::Multiline => HoverTarget::SearchOptionMultiline,
                     SearchOptionsHover::None => return None,
                 });
             }
@@ -2143,7 +2183,7 @@ impl Editor {
             if let Some(url) = link_url {
                 #[cfg(feature = "runtime")]
                 if let Err(e) = open::that(&url) {
-                    self.set_status_message(format!("Failed to open URL: {}", e));
+                    self.set_status_error(format!("Failed to open URL: {}", e));
                 } else {
                     self.set_status_message(format!("Opening: {}", url));
                 }
@@ -2423,12 +2463,27 @@ impl Editor {
             }
             C::Encoding => {
                 self.dismiss_menu_popups_for_prompt();
-                self.handle_action(Action::SetEncoding)
+                // A clean file is reopened with the picked encoding (the
+                // fix for a mis-detected file); a buffer that can't be
+                // reloaded gets the Set Encoding prompt instead.
+                let can_reload = self
+                    .buffers()
+                    .get(&self.active_buffer())
+                    .is_some_and(|s| s.buffer.file_path().is_some() && !s.buffer.is_modified());
+                if can_reload {
+                    self.handle_action(Action::ReloadWithEncoding)
+                } else {
+                    self.handle_action(Action::SetEncoding)
+                }
             }
             C::Language => {
                 self.dismiss_menu_popups_for_prompt();
                 self.handle_action(Action::SetLanguage)
             }
+            C::Diagnostics => {
+                self.dismiss_menu_popups_for_prompt();
+                self.handle_action(Action::PluginAction("show_diagnostics_panel".to_string()))
+            }
             // Owns its own toggle (second click closes the popup).
             C::Lsp => self.handle_action(Action::ShowLspStatus),
             // Owns its own toggle; clears other popups itself after the check.
@@ -2449,6 +2504,8 @@ impl Editor {
                 self.dismiss_menu_popups_for_prompt();
                 self.handle_action(Action::UpdateFresh)
             }
+            // Owns its own toggle (second click closes the jobs menu).
+            C::Jobs => self.handle_action(Action::ShowJobs),
         }
     }
 
@@ -2457,6 +2514,20 @@ impl Editor {
         if row != status_row {
             return None;
         }
+        // Elements the user gave an `on_click` action in `status_bar.segments`.
+        let segment_actions = self.active_chrome().status_bar.segment_actions.clone();
+        for (action, r, s, e) in segment_actions {
+            if row == r && col >= s && col < e {
+                let Some(action) = Action::from_str(&action, &Default::default()) else {
+                    self.set_status_error(
+                        t!("statusbar.unknown_action", action = action).to_string(),
+                    );
+                    return Some(Ok(()));
+                };
+                self.dismiss_menu_popups_for_prompt();
+                return Some(self.handle_action(action));
+            }
+        }
         // Generic click rail: one hit-test over every clickable segment drawn
         // last frame. The id→Action mapping (and each element's popup-dismiss
         // nuance) lives in `dispatch_status_bar_click`.
@@ -2506,6 +2577,9 @@ impl Editor {
             SearchOptionsHover::ConfirmEach => {
                 Some(self.handle_action(Action::ToggleSearchConfirmEach))
             }
+            SearchOptionsHover::Multiline => {
+                Some(self.handle_action(Action::ToggleSearchMultiline))
+            }
             SearchOptionsHover::None => None,
         }
     }
@@ -3017,6 +3091,12 @@ impl Editor {
             return Ok(());
         }
 
+        // If dragging a selection to drop it elsewhere
+        if self.active_window().mouse_state.dragging_text.is_some() {
+            self.handle_text_drag(col, row);
+            return Ok(());
+        }
+
         // If dragging to select text
         if self.active_window_mut().mouse_state.dragging_text_selection {
             self.handle_text_selection_drag(col, row)?;
@@ -3344,6 +3424,7 @@ impl Editor {
                 return Ok(());
             }
         }
+        self.active_window_mut().buffer_context_menu = None;
 
         if let Some(explorer_area) = self.active_layout().file_explorer_area {
             if col >= explorer_area.x
@@ -3405,8 +3486,10 @@ impl Editor {
             self.active_window_mut().tab_context_menu =
                 Some(TabContextMenu::new(buffer_id, split_id, col, row + 1));
         } else {
-            // Click outside tab - close context menu if open
+            // Click outside tab - close context menu if open, then offer the
+            // content menu if the click landed in a buffer.
             self.active_window_mut().tab_context_menu = None;
+            self.open_buffer_context_menu_at(col, row);
         }
 
         Ok(())
@@ -3474,6 +3557,9 @@ impl Editor {
             TabContextMenuItem::ExtractToNewWorkspace => {
                 self.extract_tab_to_new_workspace(buffer_id);
             }
+            TabContextMenuItem::CopyPathLine => {
+                self.copy_buffer_path_with_line(buffer_id, leaf_id);
+            }
         }
 
         Ok(())
@@ -3584,6 +3670,18 @@ impl Editor {
                     self.execute_close_split_menu_action(item, split_id);
                 }
             }
+            ContextMenuKind::Buffer => {
+                let selected = self
+                    .active_window()
+                    .buffer_context_menu
+                    .as_ref()
+                    .and_then(|m| m.highlighted_item())
+                    .map(|item| item.action.clone());
+                self.active_window_mut().close_context_menus();
+                if let Some(action) = selected {
+                    return self.execute_buffer_context_menu_action(action);
+                }
+            }
         }
         Ok(())
     }
@@ -4373,6 +4471,7 @@ impl Editor {
         ms.drag_selection_anchor = None;
         ms.drag_selection_by_words = false;
         ms.drag_selection_word_end = None;
+        ms.dragging_text = None;
         ms.terminal_drag_pending = None;
         ms.dragging_popup_scrollbar = None;
         ms.drag_start_popup_scroll = None;
@@ -0,0 +1,217 @@
+//! Narrow to Selection / Widen: edit one region of a file on its own.
+//!
+//! Narrowing copies the selected lines into a new buffer (same language,
+//! named after the region) and marks the region read-only in the source,
+//! so it can only change through the narrowed buffer. **Widen** writes the
+//! narrowed text back over the region as one undoable edit, closes the
+//! narrowed buffer and returns to the source. Closing the narrowed buffer
+//! any other way discards its edits and releases the region.
+
+use rust_i18n::t;
+
+use crate::app::types::Narrowing;
+use crate::model::event::{BufferId, CursorId, Event};
+use crate::primitives::detected_language::DetectedLanguage;
+use crate::view::folding::indent_folding::{find_line_start_byte, find_next_line_start_byte};
+use fresh_core::overlay::OverlayNamespace;
+
+use super::Editor;
+
+impl Editor {
+    /// Open the lines the primary selection touches in a narrowed buffer.
+    pub(super) fn narrow_to_selection(&mut self) {
+        let Some(selection) = self
+            .active_cursors()
+            .primary()
+            .selection_range()
+            .filter(|range| !range.is_empty())
+        else {
+            self.set_status_message(t!("narrow.no_selection").to_string());
+            return;
+        };
+
+        let source = self.active_buffer();
+        let state = self.active_state_mut();
+        let start = find_line_start_byte(&state.buffer, selection.start);
+        // A selection ending at the start of a line doesn't take that line.
+        let end = if find_line_start_byte(&state.buffer, selection.end) == selection.end {
+            selection.end
+        } else {
+            find_next_line_start_byte(&state.buffer, selection.end)
+        };
+        let probe = Event::Delete {
+            range: start..end,
+            deleted_text: String::new(),
+            cursor_id: CursorId::UNDO_SENTINEL,
+        };
+        if state
+            .read_only_regions
+            .blocking(&probe, &state.marker_list)
+            .is_some()
+        {
+            self.set_status_message(t!("narrow.read_only").to_string());
+            return;
+        }
+        let text = state.get_text_range(start, end);
+        let first_line = state.buffer.get_line_number(start) + 1;
+        let last_line = state
+            .buffer
+            .get_line_number(end.saturating_sub(1).max(start))
+            + 1;
+        let language = state.display_name.clone();
+        let source_name = self
+            .active_window()
+            .buffer_metadata
+            .get(&source)
+            .map(|meta| meta.display_name.clone())
+            .unwrap_or_default();
+        let detected = DetectedLanguage::from_syntax_name(
+            &language,
+            &self.grammar_registry,
+            &self.config.languages,
+        )
+        .unwrap_or_else(DetectedLanguage::plain_text);
+
+        let buffer_id = self.new_buffer();
+        let namespace = OverlayNamespace::from_string(format!("narrow:{}", buffer_id.0));
+        let name = t!(
+            "narrow.buffer_name",
+            name = source_name,
+            first = first_line,
+            last = last_line
+        )
+        .to_string();
+        if let Some(meta) = self.active_window_mut().buffer_metadata.get_mut(&buffer_id) {
+            meta.display_name = name;
+            meta.narrowing = Some(Narrowing {
+                source,
+                namespace: namespace.clone(),
+            });
+        }
+        let window = self.active_window_mut();
+        if let Some(state) = window.buffers.get_mut(&buffer_id) {
+            state.buffer.insert(0, &text);
+            state.buffer.clear_modified();
+            state.apply_language(detected);
+        }
+        if let Some(state) = window.buffers.get_mut(&source) {
+            state.read_only_regions.add(
+                &mut state.marker_list,
+                namespace,
+                start..end,
+                Some(t!("narrow.protected").to_string()),
+            );
+        }
+        self.refresh_buffer_config(buffer_id);
+
+        self.set_status_message(
+            t!("narrow.narrowed", first = first_line, last = last_line).to_string(),
+        );
+    }
+
+    /// Merge the active narrowed buffer (or the one narrowed from the active
+    /// buffer) back into its source.
+    pub(super) fn widen(&mut self) {
+        let active = self.active_buffer();
+        let metadata = &self.active_window().buffer_metadata;
+        let narrowed = if metadata.get(&active).is_some_and(|m| m.narrowing.is_some()) {
+            Some(active)
+        } else {
+            metadata
+                .iter()
+                .find(|(_, m)| m.narrowing.as_ref().is_some_and(|n| n.source == active))
+                .map(|(id, _)| *id)
+        };
+        let Some((narrowed, narrowing)) = narrowed.and_then(|id| {
+            metadata
+                .get(&id)
+                .and_then(|m| m.narrowing.clone())
+                .map(|n| (id, n))
+        }) else {
+            self.set_status_message(t!("narrow.not_narrowed").to_string());
+            return;
+        };
+
+        let window = self.active_window_mut();
+        let Some(range) = window.buffers.get_mut(&narrowing.source).and_then(|state| {
+            let range = state
+                .read_only_regions
+                .range_in_namespace(&narrowing.namespace, &state.marker_list)?;
+            state
+                .read_only_regions
+                .clear_namespace(&narrowing.namespace, &mut state.marker_list);
+            Some(range)
+        }) else {
+            self.set_status_message(t!("narrow.source_closed").to_string());
+            return;
+        };
+        let Some(text) = window.buffers.get_mut(&narrowed).map(|state| {
+            let len = state.buffer.len();
+            state.get_text_range(0, len)
+        }) else {
+            return;
+        };
+
+        self.set_active_buffer(narrowing.source);
+        let original = self
+            .active_state_mut()
+            .get_text_range(range.start, range.end);
+        if original != text {
+            let cursor_id = CursorId::UNDO_SENTINEL;
+            let events = vec![
+                Event::Delete {
+                    range: range.clone(),
+                    deleted_text: original,
+                    cursor_id,
+                },
+                Event::Insert {
+                    position: range.start,
+                    text,
+                    cursor_id,
+                },
+            ];
+            if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, "Widen".to_string()) {
+                self.active_event_log_mut().append(bulk_edit);
+            }
+        }
+        self.goto_byte_offset(range.start);
+        if let Err(e) = self.force_close_buffer(narrowed) {
+            tracing::warn!("Failed to close narrowed buffer: {}", e);
+        }
+        self.set_status_message(t!("narrow.widened").to_string());
+    }
+
+    /// Release the source region of a narrowed buffer that is being closed.
+    pub(super) fn release_narrowing(&mut self, buffer_id: BufferId) {
+        let window = self.active_window_mut();
+        let Some(narrowing) = window
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|meta| meta.narrowing.clone())
+        else {
+            return;
+        };
+        if let Some(state) = window.buffers.get_mut(&narrowing.source) {
+            state
+                .read_only_regions
+                .clear_namespace(&narrowing.namespace, &mut state.marker_list);
+        }
+    }
+
+    /// Status bar label for the active buffer when it is narrowed, e.g.
+    /// "Narrowed 10-50" (the region's current lines in its source).
+    pub(super) fn narrowed_status(&self, buffer_id: BufferId) -> Option<String> {
+        let window = self.active_window();
+        let narrowing = window.buffer_metadata.get(&buffer_id)?.narrowing.as_ref()?;
+        let source = window.buffers.get(&narrowing.source)?;
+        let range = source
+            .read_only_regions
+            .range_in_namespace(&narrowing.namespace, &source.marker_list)?;
+        let first = source.buffer.get_line_number(range.start) + 1;
+        let last = source
+            .buffer
+            .get_line_number(range.end.saturating_sub(1).max(range.start))
+            + 1;
+        Some(t!("narrow.status", first = first, last = last).to_string())
+    }
+}
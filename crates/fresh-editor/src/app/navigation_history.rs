@@ -0,0 +1,120 @@
+//! Navigation history picker: the position history behind Go Back / Go
+//! Forward, listed newest first with each location's file, line and text,
+//! so a jump several steps back is one pick away.
+//!
+//! The history itself lives in [`crate::input::position_history`]; large
+//! cursor jumps (search, go-to-definition, go-to-line) and buffer switches
+//! land there.
+
+use rust_i18n::t;
+
+use super::Editor;
+
+/// Characters of a line shown in the picker before it is cut off.
+const PREVIEW_CHARS: usize = 60;
+
+impl Editor {
+    /// Show the navigation history picker, with the current location
+    /// selected.
+    pub(super) fn show_navigation_history(&mut self) {
+        // The location we're leaving belongs in the list too, so it can be
+        // picked to come back — unless we're already back in history.
+        self.active_window_mut()
+            .position_history
+            .commit_pending_movement();
+        if !self.active_window().position_history.can_go_forward() {
+            let cursors = self.active_cursors();
+            let position = cursors.primary().position;
+            let anchor = cursors.primary().anchor;
+            let buffer_id = self.active_buffer();
+            let ph = &mut self.active_window_mut().position_history;
+            ph.record_movement(buffer_id, position, anchor);
+            ph.commit_pending_movement();
+        }
+
+        let history = &self.active_window().position_history;
+        if history.is_empty() {
+            self.set_status_message(t!("navigation.history_empty").to_string());
+            return;
+        }
+        let current = history.current_index();
+        let entries: Vec<_> = history.entries().iter().cloned().enumerate().collect();
+
+        let mut selected = 0;
+        let mut items = Vec::new();
+        for (index, entry) in entries.into_iter().rev() {
+            let Some(state) = self.buffers().get(&entry.buffer_id) else {
+                continue;
+            };
+            let line = state
+                .buffer
+                .offset_to_position(entry.position)
+                .map(|pos| pos.line);
+            let preview = line
+                .and_then(|line| state.buffer.get_line(line))
+                .map(|bytes| preview(&String::from_utf8_lossy(&bytes)))
+                .unwrap_or_default();
+            let name = self.get_buffer_display_name(entry.buffer_id);
+            let location = match line {
+                Some(line) => format!("{}:{}", name, line + 1),
+                None => name,
+            };
+            if Some(index) == current {
+                selected = items.len();
+            }
+            let marker = if Some(index) == current { "●" } else { " " };
+            items.push(
+                crate::view::popup::PopupListItem::new(location)
+                    .with_icon(marker.to_string())
+                    .with_detail(preview)
+                    .with_data(index.to_string()),
+            );
+        }
+        if items.is_empty() {
+            self.set_status_message(t!("navigation.history_empty").to_string());
+            return;
+        }
+
+        let theme = self.theme.read().unwrap();
+        let mut popup = crate::view::popup::Popup::list(items, &theme);
+        popup.select_index(selected);
+        popup.title = Some(t!("navigation.history_title").to_string());
+        popup.position = crate::view::popup::PopupPosition::Centered;
+        popup.width = 90;
+        popup.max_height = 15;
+        popup.resolver = crate::view::popup::PopupResolver::NavigationHistory;
+        // Explicitly invoked: the user is about to pick, so take the keys.
+        popup.focused = true;
+        drop(theme);
+        self.active_state_mut().popups.show(popup);
+    }
+
+    /// Jump to position history entry `index`, as picked from the
+    /// navigation history popup. Back and forward keep working from there.
+    pub(super) fn navigate_to_history_entry(&mut self, index: usize) {
+        self.active_window_mut().in_navigation = true;
+
+        if let Some(entry) = self
+            .active_window_mut()
+            .position_history
+            .go_to(index)
+            .cloned()
+        {
+            self.restore_position_entry(&entry);
+        }
+
+        self.active_window_mut().in_navigation = false;
+    }
+}
+
+/// One-line preview of a history location: its line, trimmed and cut off
+/// at [`PREVIEW_CHARS`].
+fn preview(line: &str) -> String {
+    let line = line.trim();
+    if line.chars().count() > PREVIEW_CHARS {
+        let cut: String = line.chars().take(PREVIEW_CHARS - 1).collect();
+        format!("{cut}…")
+    } else {
+        line.to_string()
+    }
+}
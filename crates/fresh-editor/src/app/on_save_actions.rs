@@ -37,8 +37,12 @@ impl Editor {
 
         let mut ran_any_action = false;
 
-        // Run whitespace cleanup actions first (before formatter)
-        if self.config.editor.trim_trailing_whitespace_on_save && self.trim_trailing_whitespace()? {
+        // Run whitespace cleanup actions first (before formatter).
+        // Trimming honours a per-language override of the global setting.
+        let trim_on_save =
+            crate::config::BufferConfig::resolve(&self.config, Some(&self.active_state().language))
+                .trim_trailing_whitespace_on_save;
+        if trim_on_save && self.trim_trailing_whitespace()? {
             ran_any_action = true;
         }
 
@@ -73,7 +77,7 @@ impl Editor {
             if let Some(ref formatter) = lang_config.formatter {
                 match self.run_formatter(formatter, &path) {
                     ActionResult::Success(output) => {
-                        self.replace_buffer_with_output(&output)?;
+                        self.replace_buffer_as_transform(&output, t!("transform.format"))?;
                         // Re-save after formatting
                         if let Err(e) = self.active_state_mut().buffer.save() {
                             return Err(format!("Failed to re-save after format: {}", e));
@@ -179,7 +183,7 @@ impl Editor {
 
         match self.run_formatter(&formatter, &path) {
             ActionResult::Success(output) => {
-                self.replace_buffer_with_output(&output)?;
+                self.replace_buffer_as_transform(&output, t!("transform.format"))?;
                 self.set_status_message(
                     t!(
                         "format.formatted_with",
@@ -494,8 +498,22 @@ impl Editor {
         }
     }
 
+    /// [`Self::replace_buffer_with_output`], keeping the previous text as
+    /// the buffer's last-transform snapshot for **Revert Last Transform**.
+    fn replace_buffer_as_transform(
+        &mut self,
+        output: &str,
+        label: impl Into<String>,
+    ) -> Result<(), String> {
+        let before = self.active_state().buffer.to_string().unwrap_or_default();
+        self.replace_buffer_with_output(output)?;
+        let buffer_id = self.active_buffer();
+        self.record_transform(buffer_id, label.into(), before);
+        Ok(())
+    }
+
     /// Replace the active buffer's content with new output.
-    fn replace_buffer_with_output(&mut self, output: &str) -> Result<(), String> {
+    pub(super) fn replace_buffer_with_output(&mut self, output: &str) -> Result<(), String> {
         let cursor_id = self.active_cursors().primary_id();
 
         // Get current buffer content
@@ -602,6 +620,12 @@ impl Editor {
         }
 
         self.replace_buffer_with_output(&trimmed)?;
+        let buffer_id = self.active_buffer();
+        self.record_transform(
+            buffer_id,
+            t!("transform.trim_whitespace").to_string(),
+            content,
+        );
         Ok(true)
     }
 
@@ -35,6 +35,8 @@ pub(crate) enum LayerKind {
     KeybindingEditor,
     /// The calibration wizard (`calibration_wizard`) — same as above.
     CalibrationWizard,
+    /// Presentation mode (`presentation`) — same as above.
+    Presentation,
     /// The workspace-trust prompt: a global popup whose top resolver is
     /// `PopupResolver::WorkspaceTrust`, painted in the modal z-band and
     /// dispatched by a bespoke mouse/key handler. Distinct from `Popup`
@@ -62,6 +64,10 @@ pub(crate) enum LayerKind {
     /// with a custom key dispatcher, transparent to `KeyContext` resolution but
     /// blocking PTY routing while open.
     CloseSplitMenu,
+    /// The editor content right-click menu
+    /// (`active_window().buffer_context_menu`), same treatment as the other
+    /// native context menus.
+    BufferContextMenu,
     /// The centered widget modal (`floating_widget_panel`).
     FloatingModal,
     /// The editor-global left dock (`dock`).
@@ -0,0 +1,190 @@
+//! Performance HUD and frame timing on `Editor`.
+//!
+//! Every frame records how long `render` took and, when input arrived since
+//! the previous frame, how long that input waited to be drawn. The HUD
+//! (toggled from the command palette) shows those timings alongside the
+//! active buffer's size, the in-flight LSP requests per server and the
+//! process's resident memory.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+use rust_i18n::t;
+
+use super::startup_profile::StartupProfile;
+use super::Editor;
+use crate::view::perf_hud::PerfHudRow;
+
+/// Frames kept for the average and maximum.
+const SAMPLES: usize = 120;
+/// How often the resident memory is re-read while the HUD is shown.
+const MEMORY_REFRESH: Duration = Duration::from_millis(500);
+
+/// Rolling window of recent durations.
+#[derive(Debug, Default)]
+struct Samples(VecDeque<Duration>);
+
+impl Samples {
+    fn push(&mut self, sample: Duration) {
+        if self.0.len() == SAMPLES {
+            self.0.pop_front();
+        }
+        self.0.push_back(sample);
+    }
+
+    /// The last, average and maximum sample, or `None` before the first.
+    fn summary(&self) -> Option<String> {
+        let last = *self.0.back()?;
+        let total: Duration = self.0.iter().sum();
+        let avg = total / self.0.len() as u32;
+        let max = self.0.iter().max().copied().unwrap_or_default();
+        Some(
+            t!(
+                "perf_hud.timing",
+                last = format_ms(last),
+                avg = format_ms(avg),
+                max = format_ms(max)
+            )
+            .to_string(),
+        )
+    }
+}
+
+fn format_ms(duration: Duration) -> String {
+    format!("{:.1}", duration.as_secs_f64() * 1000.0)
+}
+
+/// Frame timing and HUD state.
+#[derive(Debug, Default)]
+pub(crate) struct PerfHud {
+    visible: bool,
+    frame_times: Samples,
+    input_latencies: Samples,
+    /// When the oldest input not yet drawn arrived.
+    input_pending_since: Option<Instant>,
+    /// Resident memory in bytes and when it was read.
+    memory: Option<(Option<u64>, Instant)>,
+}
+
+impl Editor {
+    /// Show or hide the performance HUD.
+    pub fn toggle_perf_hud(&mut self) {
+        self.perf_hud.visible = !self.perf_hud.visible;
+        let key = if self.perf_hud.visible {
+            "toggle.perf_hud_shown"
+        } else {
+            "toggle.perf_hud_hidden"
+        };
+        self.set_status_message(t!(key).to_string());
+    }
+
+    /// Whether the performance HUD is shown.
+    pub fn is_perf_hud_visible(&self) -> bool {
+        self.perf_hud.visible
+    }
+
+    /// Note that an input event arrived, so the next frame can measure how
+    /// long it took to show.
+    pub fn mark_input_received(&mut self) {
+        self.perf_hud
+            .input_pending_since
+            .get_or_insert_with(Instant::now);
+    }
+
+    /// Hand the editor the startup profile to finish on its first frame.
+    pub fn set_startup_profile(&mut self, profile: StartupProfile) {
+        self.startup_profile = Some(profile);
+    }
+
+    /// Take back the startup profile, finished if a frame has been drawn.
+    pub fn take_startup_profile(&mut self) -> Option<StartupProfile> {
+        self.startup_profile.take()
+    }
+
+    /// Record a frame whose rendering started at `started`.
+    pub(crate) fn record_frame(&mut self, started: Instant) {
+        let now = Instant::now();
+        self.perf_hud.frame_times.push(now - started);
+        if let Some(since) = self.perf_hud.input_pending_since.take() {
+            self.perf_hud.input_latencies.push(now - since);
+        }
+        if let Some(profile) = self.startup_profile.as_mut() {
+            profile.finish("first frame");
+        }
+    }
+
+    /// The HUD's rows for the current state. Re-reads the resident memory
+    /// when the last reading is stale.
+    pub(crate) fn perf_hud_rows(&mut self) -> Vec<PerfHudRow> {
+        let none = || "—".to_string();
+        let memory_stale = self
+            .perf_hud
+            .memory
+            .is_none_or(|(_, read_at)| read_at.elapsed() >= MEMORY_REFRESH);
+        if memory_stale {
+            self.perf_hud.memory = Some((resident_memory_bytes(), Instant::now()));
+        }
+
+        let buffer = self.active_state().buffer.len();
+        let buffer = match self.active_state().buffer.line_count() {
+            Some(lines) => t!(
+                "perf_hud.buffer_size",
+                size = crate::app::file_open::format_size(buffer as u64),
+                lines = lines
+            )
+            .to_string(),
+            None => crate::app::file_open::format_size(buffer as u64),
+        };
+
+        let lsp = self.active_window().lsp.in_flight_requests();
+        let lsp = if lsp.is_empty() {
+            none()
+        } else {
+            lsp.iter()
+                .map(|(server, count)| format!("{server} {count}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        let memory = self
+            .perf_hud
+            .memory
+            .and_then(|(bytes, _)| bytes)
+            .map(crate::app::file_open::format_size)
+            .unwrap_or_else(none);
+
+        let rows = [
+            ("perf_hud.frame", self.perf_hud.frame_times.summary()),
+            ("perf_hud.latency", self.perf_hud.input_latencies.summary()),
+            ("perf_hud.buffer", Some(buffer)),
+            ("perf_hud.lsp", Some(lsp)),
+            ("perf_hud.memory", Some(memory)),
+        ];
+        rows.into_iter()
+            .map(|(label, value)| PerfHudRow {
+                label: t!(label).to_string(),
+                value: value.unwrap_or_else(none),
+            })
+            .collect()
+    }
+}
+
+/// The process's resident set size, where the platform exposes it cheaply.
+#[cfg(target_os = "linux")]
+fn resident_memory_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kb = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kb * 1024)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn resident_memory_bytes() -> Option<u64> {
+    None
+}
@@ -2,6 +2,7 @@
 //!
 //! This module groups plugin commands by domain for better maintainability.
 
+use crate::app::types::MessageSeverity;
 use crate::model::cursor::Cursors;
 use crate::model::event::{BufferId, ContainerId, CursorId, Event, LeafId, OverlayFace, SplitId};
 use crate::view::overlay::{OverlayHandle, OverlayNamespace};
@@ -689,6 +690,52 @@ impl Editor {
         }
     }
 
+    // ==================== Read-only Region Commands ====================
+
+    /// Handle AddReadOnlyRegion command - protect a byte range from user edits
+    pub(super) fn handle_add_read_only_region(
+        &mut self,
+        buffer_id: BufferId,
+        namespace: OverlayNamespace,
+        start: usize,
+        end: usize,
+        hint: Option<String>,
+        epoch: Option<u64>,
+    ) {
+        if let Some(state) = self
+            .windows
+            .get_mut(&self.active_window)
+            .expect("active window present")
+            .buffer_state_mut(buffer_id)
+        {
+            let (start, end) = match state.map_plugin_range(start, end, epoch) {
+                Some(r) => r,
+                None => return,
+            };
+            state
+                .read_only_regions
+                .add(&mut state.marker_list, namespace, start..end, hint);
+        }
+    }
+
+    /// Handle ClearReadOnlyRegions command
+    pub(super) fn handle_clear_read_only_regions(
+        &mut self,
+        buffer_id: BufferId,
+        namespace: OverlayNamespace,
+    ) {
+        if let Some(state) = self
+            .windows
+            .get_mut(&self.active_window)
+            .expect("active window present")
+            .buffer_state_mut(buffer_id)
+        {
+            state
+                .read_only_regions
+                .clear_namespace(&namespace, &mut state.marker_list);
+        }
+    }
+
     // ==================== Fold Commands ====================
 
     /// Handle AddFold command — register a collapsed fold range for the
@@ -1392,6 +1439,9 @@ impl Editor {
             // Interactive replace manages its own highlight lifecycle.
             return;
         }
+        if !self.config.editor.keep_search_highlights {
+            return;
+        }
         let (search_fg, search_bg) = {
             let theme = self.theme.read().unwrap();
             (theme.search_match_fg, theme.search_match_bg)
@@ -2080,13 +2130,18 @@ impl Editor {
             // Detect plugin errors and collect them for test assertions
             // Error patterns: "Plugin error", "JS error", "handler error"
             let lower = message.to_lowercase();
-            if lower.contains("plugin error")
+            let severity = if lower.contains("plugin error")
                 || lower.contains("js error")
                 || lower.contains("handler error")
                 || lower.contains("error in")
             {
                 self.active_window_mut().plugin_errors.push(message.clone());
-            }
+                MessageSeverity::Error
+            } else {
+                MessageSeverity::Info
+            };
+            self.active_window_mut()
+                .record_message(severity, "plugin", &message);
             // Clear core status message so only plugin message shows
             self.active_window_mut().status_message = None;
             self.active_window_mut().plugin_status_message = Some(message.clone());
@@ -2263,6 +2318,7 @@ impl Editor {
             custom_contexts: command.custom_contexts,
             source: CommandSource::Plugin(command.plugin_name),
             terminal_bypass: command.terminal_bypass,
+            context_menu: command.context_menu,
         };
 
         tracing::debug!(
@@ -2474,6 +2530,8 @@ impl Editor {
         // Convert LanguagePackConfig to the internal LanguageConfig format
         let lang_config = crate::config::LanguageConfig {
             comment_prefix: config.comment_prefix,
+            block_comment_start: config.block_comment_start,
+            block_comment_end: config.block_comment_end,
             auto_indent: config.auto_indent.unwrap_or(true),
             use_tabs: config.use_tabs,
             tab_size: config.tab_size,
@@ -241,6 +241,10 @@ impl Editor {
         // lockstep with the canonical seat.
         snapshot.authority_label = self.authority().display_label.clone();
 
+        // Newer release from the update checker, for the dashboard's
+        // update notice.
+        snapshot.update_available = self.latest_version().map(|v| v.to_string());
+
         // Surface the active project's Workspace Trust level so plugins that
         // run repo-controlled work can gate on it.
         snapshot.workspace_trust_level = self
@@ -575,6 +579,24 @@ impl Editor {
                 );
             }
 
+            // ==================== Read-only Region Commands ====================
+            PluginCommand::AddReadOnlyRegion {
+                buffer_id,
+                namespace,
+                start,
+                end,
+                hint,
+                epoch,
+            } => {
+                self.handle_add_read_only_region(buffer_id, namespace, start, end, hint, epoch);
+            }
+            PluginCommand::ClearReadOnlyRegions {
+                buffer_id,
+                namespace,
+            } => {
+                self.handle_clear_read_only_regions(buffer_id, namespace);
+            }
+
             PluginCommand::AddFold {
                 buffer_id,
                 start,
@@ -1053,6 +1075,16 @@ impl Editor {
             PluginCommand::OpenFileInBackground { path, window_id } => {
                 self.handle_open_file_in_background_routed(path, window_id);
             }
+            PluginCommand::SwitchProject { path } => {
+                if path.is_dir() {
+                    self.change_working_dir(path);
+                } else {
+                    self.set_status_message(
+                        rust_i18n::t!("file.not_directory", path = path.display().to_string())
+                            .to_string(),
+                    );
+                }
+            }
             PluginCommand::OpenFileAtLocation { path, line, column } => {
                 return self.handle_open_file_at_location(path, line, column);
             }
@@ -1217,13 +1249,58 @@ impl Editor {
                 cwd,
                 callback_id,
             } => {
-                self.handle_spawn_background_process(process_id, command, args, cwd, callback_id);
+                self.handle_spawn_background_process(
+                    process_id,
+                    command,
+                    args,
+                    cwd,
+                    callback_id,
+                    None,
+                );
             }
 
             PluginCommand::KillBackgroundProcess { process_id } => {
                 self.handle_kill_background_process(process_id);
             }
 
+            PluginCommand::SpawnJob {
+                plugin,
+                job_id,
+                command,
+                args,
+                cwd,
+                title,
+                stdin,
+                callback_id,
+            } => {
+                self.handle_spawn_job(
+                    plugin,
+                    job_id,
+                    command,
+                    args,
+                    cwd,
+                    title,
+                    stdin,
+                    Some(callback_id),
+                );
+            }
+
+            PluginCommand::WriteJobStdin { job_id, data } => {
+                self.handle_write_job_stdin(job_id, data);
+            }
+
+            PluginCommand::CloseJobStdin { job_id } => {
+                self.handle_close_job_stdin(job_id);
+            }
+
+            PluginCommand::SetJobProgress {
+                job_id,
+                message,
+                percent,
+            } => {
+                self.handle_set_job_progress(job_id, message, percent);
+            }
+
             // ==================== Virtual Buffer Commands (complex, kept inline) ====================
             PluginCommand::CreateVirtualBuffer {
                 name,
@@ -2464,8 +2541,18 @@ impl Editor {
 
     /// Set the global editor mode (for vi mode)
     fn handle_set_editor_mode(&mut self, mode: Option<String>) {
+        #[cfg(feature = "scripting")]
+        let changed = self.active_window().editor_mode != mode;
         self.active_window_mut().editor_mode = mode.clone();
         tracing::debug!("Set editor mode: {:?}", mode);
+        #[cfg(feature = "scripting")]
+        if changed {
+            self.run_script_hooks(
+                crate::services::scripting::ScriptHook::ModeChange,
+                self.active_buffer(),
+                mode.as_deref().unwrap_or_default(),
+            );
+        }
     }
 
     /// Normalize a plugin-supplied `BufferId`: treat id 0 as "use the active buffer".
@@ -2962,124 +3049,6 @@ impl Editor {
         }
     }
 
-    fn handle_spawn_background_process(
-        &mut self,
-        process_id: u64,
-        command: String,
-        args: Vec<String>,
-        cwd: Option<String>,
-        callback_id: JsCallbackId,
-    ) {
-        // Spawn background process with streaming output via tokio
-        if let (Some(runtime), Some(bridge)) = (&self.tokio_runtime, &self.async_bridge) {
-            use tokio::io::{AsyncBufReadExt, BufReader};
-            use tokio::process::Command as TokioCommand;
-
-            let effective_cwd = cwd.unwrap_or_else(|| {
-                std::env::current_dir()
-                    .map(|p| p.to_string_lossy().to_string())
-                    .unwrap_or_else(|_| ".".to_string())
-            });
-
-            let sender = bridge.sender();
-            let sender_stdout = sender.clone();
-            let sender_stderr = sender.clone();
-            let callback_id_u64 = callback_id.as_u64();
-
-            // Receiver may be dropped if editor is shutting down
-            #[allow(clippy::let_underscore_must_use)]
-            let handle = runtime.spawn(async move {
-                use crate::services::process_hidden::HideWindow;
-                let mut child = match TokioCommand::new(&command)
-                    .args(&args)
-                    .current_dir(&effective_cwd)
-                    .stdout(std::process::Stdio::piped())
-                    .stderr(std::process::Stdio::piped())
-                    .hide_window()
-                    .spawn()
-                {
-                    Ok(child) => child,
-                    Err(e) => {
-                        let _ = sender.send(crate::services::async_bridge::AsyncMessage::Plugin(
-                            fresh_core::api::PluginAsyncMessage::ProcessExit {
-                                process_id,
-                                callback_id: callback_id_u64,
-                                exit_code: -1,
-                            },
-                        ));
-                        tracing::error!("Failed to spawn background process: {}", e);
-                        return;
-                    }
-                };
-
-                // Stream stdout
-                let stdout = child.stdout.take();
-                let stderr = child.stderr.take();
-                let pid = process_id;
-
-                // Spawn stdout reader
-                if let Some(stdout) = stdout {
-                    let sender = sender_stdout;
-                    tokio::spawn(async move {
-                        let reader = BufReader::new(stdout);
-                        let mut lines = reader.lines();
-                        while let Ok(Some(line)) = lines.next_line().await {
-                            let _ =
-                                sender.send(crate::services::async_bridge::AsyncMessage::Plugin(
-                                    fresh_core::api::PluginAsyncMessage::ProcessStdout {
-                                        process_id: pid,
-                                        data: line + "\n",
-                                    },
-                                ));
-                        }
-                    });
-                }
-
-                // Spawn stderr reader
-                if let Some(stderr) = stderr {
-                    let sender = sender_stderr;
-                    tokio::spawn(async move {
-                        let reader = BufReader::new(stderr);
-                        let mut lines = reader.lines();
-                        while let Ok(Some(line)) = lines.next_line().await {
-                            let _ =
-                                sender.send(crate::services::async_bridge::AsyncMessage::Plugin(
-                                    fresh_core::api::PluginAsyncMessage::ProcessStderr {
-                                        process_id: pid,
-                                        data: line + "\n",
-                                    },
-                                ));
-                        }
-                    });
-                }
-
-                // Wait for process to complete
-                let exit_code = match child.wait().await {
-                    Ok(status) => status.code().unwrap_or(-1),
-                    Err(_) => -1,
-                };
-
-                let _ = sender.send(crate::services::async_bridge::AsyncMessage::Plugin(
-                    fresh_core::api::PluginAsyncMessage::ProcessExit {
-                        process_id,
-                        callback_id: callback_id_u64,
-                        exit_code,
-                    },
-                ));
-            });
-
-            // Store abort handle for potential kill
-            self.background_process_handles
-                .insert(process_id, handle.abort_handle());
-        } else {
-            // No runtime - reject immediately
-            self.plugin_manager
-                .read()
-                .unwrap()
-                .reject_callback(callback_id, "Async runtime not available".to_string());
-        }
-    }
-
     #[allow(clippy::too_many_arguments)]
     fn handle_create_virtual_buffer_with_content(
         &mut self,
@@ -3390,6 +3359,8 @@ impl Editor {
                         .resolve_wrap_column_for_buffer(buffer_id),
                     rulers: self.config.editor.rulers.clone(),
                     scroll_offset: self.config.editor.scroll_offset,
+                    scroll_past_end: self.config.editor.scroll_past_end,
+                    typewriter_scrolling: self.config.editor.typewriter_scrolling,
                 });
                 view_state.ensure_buffer_state(buffer_id).show_line_numbers = show_line_numbers;
                 self.windows
@@ -3634,6 +3605,7 @@ impl Editor {
                 detail: None,
                 icon: None,
                 data: Some(action.id.clone()),
+                match_positions: Vec::new(),
             })
             .collect();
 
@@ -4511,13 +4483,6 @@ impl Editor {
         }
     }
 
-    fn handle_kill_background_process(&mut self, process_id: u64) {
-        if let Some(handle) = self.background_process_handles.remove(&process_id) {
-            handle.abort();
-            tracing::debug!("Killed background process {}", process_id);
-        }
-    }
-
     fn handle_create_virtual_buffer(&mut self, name: String, mode: String, read_only: bool) {
         let buffer_id =
             self.active_window_mut()
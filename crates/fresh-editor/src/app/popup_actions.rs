@@ -3,9 +3,10 @@
 //! This module contains handlers for popup-related actions like confirmation and cancellation.
 
 use super::Editor;
-use crate::model::event::Event;
-use crate::primitives::snippet::{expand_snippet, is_snippet};
+use crate::model::event::CursorId;
+use crate::primitives::snippet::is_snippet;
 use crate::primitives::word_navigation::find_completion_word_start;
+use crate::services::completion::lsp_ranking::rank_lsp_items;
 use rust_i18n::t;
 
 /// Result of handling a popup confirmation.
@@ -137,6 +138,20 @@ impl Editor {
                 PopupConfirmResult::EarlyReturn
             }
 
+            Some(PopupResolver::Jobs) => {
+                let action_key = self
+                    .active_state()
+                    .popups
+                    .top()
+                    .and_then(|p| p.selected_item())
+                    .and_then(|item| item.data.clone());
+                self.hide_popup();
+                if let Some(key) = action_key {
+                    self.handle_jobs_menu_action(&key);
+                }
+                PopupConfirmResult::EarlyReturn
+            }
+
             Some(PopupResolver::Update) => {
                 let action_key = self
                     .active_state()
@@ -151,6 +166,63 @@ impl Editor {
                 PopupConfirmResult::EarlyReturn
             }
 
+            Some(PopupResolver::IndentationFix) => {
+                let action_key = self
+                    .active_state()
+                    .popups
+                    .top()
+                    .and_then(|p| p.selected_item())
+                    .and_then(|item| item.data.clone());
+                self.hide_popup();
+                if let Some(key) = action_key {
+                    self.handle_indentation_fix_action(&key);
+                }
+                PopupConfirmResult::EarlyReturn
+            }
+
+            Some(PopupResolver::NavigationHistory) => {
+                let index = self
+                    .active_state()
+                    .popups
+                    .top()
+                    .and_then(|p| p.selected_item())
+                    .and_then(|item| item.data.as_ref())
+                    .and_then(|data| data.parse::<usize>().ok());
+                self.hide_popup();
+                if let Some(index) = index {
+                    self.navigate_to_history_entry(index);
+                }
+                PopupConfirmResult::EarlyReturn
+            }
+
+            Some(PopupResolver::LineBookmarks) => {
+                let data = self
+                    .active_state()
+                    .popups
+                    .top()
+                    .and_then(|p| p.selected_item())
+                    .and_then(|item| item.data.clone());
+                self.hide_popup();
+                if let Some(data) = data {
+                    self.jump_to_picked_line_bookmark(&data);
+                }
+                PopupConfirmResult::EarlyReturn
+            }
+
+            Some(PopupResolver::NamedMarks) => {
+                let data = self
+                    .active_state()
+                    .popups
+                    .top()
+                    .and_then(|p| p.selected_item())
+                    .and_then(|item| item.data.clone());
+                self.hide_popup();
+                if let Some(data) = data {
+                    self.jump_to_picked_mark(&data);
+                }
+                PopupConfirmResult::EarlyReturn
+            }
+
             Some(PopupResolver::WorkspaceTrust) => {
                 // The trust prompt lives on the global stack; read its
                 // selection there (global-first, matching the resolver lookup).
@@ -177,13 +249,25 @@ impl Editor {
                     .top()
                     .and_then(|p| p.selected_item())
                     .map(|item| (item.text.clone(), item.data.clone()));
+                let mut command = None;
                 if let Some((label, insert_text)) = completion_info {
+                    let item = insert_text
+                        .as_deref()
+                        .and_then(|text| self.accepted_lsp_item(&label, text));
                     if let Some(text) = insert_text {
-                        self.insert_completion_text(text);
+                        self.insert_completion_text(&label, text, item.as_ref());
+                    }
+                    if let Some(item) = item {
+                        self.resolve_accepted_completion(&item);
+                        command = item.command;
                     }
-                    self.apply_completion_additional_edits(&label);
                 }
                 self.hide_popup();
+                // After hiding: the command may open a new popup
+                // (e.g. `editor.action.triggerSuggest`).
+                if let Some(command) = command {
+                    self.run_completion_command(command);
+                }
                 PopupConfirmResult::Done
             }
 
@@ -204,147 +288,160 @@ impl Editor {
         }
     }
 
-    /// Insert completion text, replacing the word prefix at *every* cursor.
-    /// If the text contains LSP snippet syntax, it will be expanded.
+    /// Insert completion text at *every* cursor, replacing the range the
+    /// LSP item's `textEdit` covers or, without one, the word prefix.
+    /// Snippet completions (see [`Self::completion_is_snippet`]) are
+    /// expanded, and one with tab stops starts a snippet session. The
+    /// item's `additionalTextEdits` (e.g. auto-imports) are applied in the
+    /// same undo step.
     ///
     /// Multi-cursor: each cursor's own word prefix is replaced, so cursors
-    /// stay in lock-step after the accept (issue #1901, accept path). All
-    /// per-cursor edits go through `apply_events_as_bulk_edit` so undo is
-    /// atomic.
-    fn insert_completion_text(&mut self, text: String) {
-        use crate::model::event::CursorId;
-
-        // Check if this is a snippet and expand it
-        let (insert_text, cursor_offset) = if is_snippet(&text) {
-            let expanded = expand_snippet(&text);
-            (expanded.text, Some(expanded.cursor_offset))
-        } else {
-            (text, None)
-        };
+    /// stay in lock-step after the accept (issue #1901, accept path). A
+    /// `textEdit` range is taken relative to the primary cursor and applied
+    /// at the same offsets around the others. All per-cursor edits go
+    /// through `apply_events_as_bulk_edit` so undo is atomic.
+    fn insert_completion_text(
+        &mut self,
+        label: &str,
+        text: String,
+        item: Option<&lsp_types::CompletionItem>,
+    ) {
+        let expand = self.completion_is_snippet(label, &text, item);
+        let extent = item.and_then(|item| self.completion_edit_extent(item));
+
+        // Each cursor replaces its own word prefix (or text-edit range).
+        let positions: Vec<(CursorId, usize)> = self
+            .active_cursors()
+            .iter()
+            .map(|(id, c)| (id, c.position))
+            .collect();
+        let buffer = &self.active_state().buffer;
+        let buffer_len = buffer.len();
+        let replacements = positions
+            .into_iter()
+            .map(|(id, pos)| match extent {
+                Some((before, after)) => (
+                    id,
+                    pos.saturating_sub(before)..(pos + after).min(buffer_len),
+                ),
+                None => (id, find_completion_word_start(buffer, pos)..pos),
+            })
+            .collect();
 
-        // Collect per-cursor data: id, current position, word_start, prefix text.
-        let cursor_data: Vec<(CursorId, usize, usize, String)> = {
-            let positions: Vec<(CursorId, usize)> = self
-                .active_cursors()
-                .iter()
-                .map(|(id, c)| (id, c.position))
-                .collect();
-            positions
-                .into_iter()
-                .map(|(id, pos)| {
-                    let word_start = {
-                        let state = self.active_state();
-                        find_completion_word_start(&state.buffer, pos)
-                    };
-                    let prefix = if word_start < pos {
-                        self.active_state_mut().get_text_range(word_start, pos)
-                    } else {
-                        String::new()
-                    };
-                    (id, pos, word_start, prefix)
-                })
-                .collect()
-        };
+        let extra_edits = item
+            .and_then(|item| item.additional_text_edits.as_ref())
+            .map(|edits| {
+                edits
+                    .iter()
+                    .map(|edit| {
+                        let start = buffer.lsp_position_to_byte(
+                            edit.range.start.line as usize,
+                            edit.range.start.character as usize,
+                        );
+                        let end = buffer.lsp_position_to_byte(
+                            edit.range.end.line as usize,
+                            edit.range.end.character as usize,
+                        );
+                        (start..end.max(start), edit.new_text.clone())
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
 
-        // Build delete+insert events. `apply_events_as_bulk_edit` sorts by
-        // descending position internally, so emission order doesn't matter.
-        let mut events: Vec<Event> = Vec::new();
-        for (cursor_id, pos, word_start, prefix) in &cursor_data {
-            if *word_start < *pos {
-                events.push(Event::Delete {
-                    range: *word_start..*pos,
-                    deleted_text: prefix.clone(),
-                    cursor_id: *cursor_id,
-                });
-            }
-            events.push(Event::Insert {
-                position: *word_start,
-                text: insert_text.clone(),
-                cursor_id: *cursor_id,
-            });
-        }
+        self.insert_snippet_with_edits(
+            replacements,
+            &text,
+            expand,
+            extra_edits,
+            "Accept completion",
+        );
+    }
 
-        if events.is_empty() {
-            return;
-        }
+    /// The stored LSP item the popup entry `label` (inserting `text`) was
+    /// built from.
+    fn accepted_lsp_item(&self, label: &str, text: &str) -> Option<lsp_types::CompletionItem> {
+        self.active_window()
+            .completion_items
+            .as_ref()?
+            .iter()
+            .find(|item| item.label == label && lsp_insert_text(item) == text)
+            .cloned()
+    }
 
-        let description = "Accept completion".to_string();
-        if cursor_data.len() > 1 || events.len() > 1 {
-            // Multi-cursor (or replacement = delete+insert): one atomic bulk edit.
-            if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description) {
-                self.active_event_log_mut().append(bulk_edit);
-            }
-        } else {
-            for event in events {
-                self.log_and_apply_event(&event);
-            }
+    /// How far the range of `item`'s `textEdit` reaches before and after
+    /// the primary cursor, in bytes. Text typed since the request extends
+    /// the range to the cursor. `None` without a usable text edit.
+    fn completion_edit_extent(&self, item: &lsp_types::CompletionItem) -> Option<(usize, usize)> {
+        let range = match item.text_edit.as_ref()? {
+            lsp_types::CompletionTextEdit::Edit(edit) => edit.range,
+            // Insert mode: replace up to the cursor, keep the rest of the word.
+            lsp_types::CompletionTextEdit::InsertAndReplace(edit) => edit.insert,
+        };
+        let buffer = &self.active_state().buffer;
+        let pos = self.active_cursors().primary().position;
+        let start =
+            buffer.lsp_position_to_byte(range.start.line as usize, range.start.character as usize);
+        let end =
+            buffer.lsp_position_to_byte(range.end.line as usize, range.end.character as usize);
+        // The range must be on the cursor's line and start at or before it.
+        if start > pos || buffer.get_line_number(start) != buffer.get_line_number(pos) {
+            return None;
         }
+        Some((pos - start, end.saturating_sub(pos)))
+    }
 
-        // Snippet placement: after the bulk edit, each cursor sits at the end
-        // of its own inserted text; the snippet's $0 sits `cursor_offset` bytes
-        // into that text. Walk each cursor back to its $0 placeholder.
-        if let Some(offset) = cursor_offset {
-            if offset != insert_text.len() {
-                let move_events: Vec<Event> = self
-                    .active_cursors()
-                    .iter()
-                    .map(|(cursor_id, cursor)| {
-                        let current = cursor.position;
-                        let target = current.saturating_sub(insert_text.len()) + offset;
-                        Event::MoveCursor {
-                            cursor_id,
-                            old_position: current,
-                            new_position: target,
-                            old_anchor: cursor.anchor,
-                            new_anchor: None,
-                            old_sticky_column: cursor.sticky_column,
-                            new_sticky_column: None,
-                        }
-                    })
-                    .collect();
-                for event in move_events {
-                    self.log_and_apply_event(&event);
-                }
-            }
+    /// Whether the accepted completion's text is a snippet. LSP items say so
+    /// with `InsertTextFormat::Snippet`; user snippets always are; anything
+    /// else (e.g. plugin providers) is expanded if it uses snippet syntax.
+    fn completion_is_snippet(
+        &self,
+        label: &str,
+        text: &str,
+        item: Option<&lsp_types::CompletionItem>,
+    ) -> bool {
+        if let Some(item) = item {
+            return item.insert_text_format == Some(lsp_types::InsertTextFormat::SNIPPET);
         }
+        is_snippet(text)
+            || self
+                .active_user_snippets()
+                .iter()
+                .any(|snippet| snippet.body == text && snippet.prefixes.iter().any(|p| p == label))
     }
 
-    /// Apply additional_text_edits from the accepted completion item (e.g. auto-imports).
-    /// If the item already has additional_text_edits, apply them directly.
-    /// If not and the server supports completionItem/resolve, send a resolve request
-    /// so the server can fill them in (the response is handled asynchronously).
-    fn apply_completion_additional_edits(&mut self, label: &str) {
-        // Find the matching CompletionItem from stored items
-        let item = self
-            .active_window_mut()
-            .completion_items
+    /// An accepted item without `additionalTextEdits` may still have some
+    /// (e.g. an auto-import) that the server only fills in on
+    /// `completionItem/resolve`; ask for them unless the item was already
+    /// resolved for the documentation panel. The response is applied
+    /// asynchronously by `handle_completion_resolved`.
+    fn resolve_accepted_completion(&mut self, item: &lsp_types::CompletionItem) {
+        let has_edits = item
+            .additional_text_edits
             .as_ref()
-            .and_then(|items| items.iter().find(|item| item.label == label).cloned());
-
-        let Some(item) = item else { return };
-
-        if let Some(edits) = &item.additional_text_edits {
-            if !edits.is_empty() {
-                tracing::info!(
-                    "Applying {} additional text edits from completion '{}'",
-                    edits.len(),
-                    label
-                );
-                let buffer_id = self.active_buffer();
-                if let Err(e) = self.apply_lsp_text_edits(buffer_id, edits.clone()) {
-                    tracing::error!("Failed to apply completion additional_text_edits: {}", e);
-                }
-                return;
-            }
+            .is_some_and(|edits| !edits.is_empty());
+        let window = self.active_window();
+        if has_edits
+            || window.resolved_completion_labels.contains(&item.label)
+            || !window.server_supports_completion_resolve()
+        {
+            return;
         }
+        tracing::info!(
+            "Completion '{}' has no additional_text_edits, sending completionItem/resolve",
+            item.label
+        );
+        self.active_window_mut()
+            .send_completion_resolve(item.clone());
+    }
 
-        // No additional_text_edits present — try resolve if server supports it
-        if self.active_window().server_supports_completion_resolve() {
-            tracing::info!(
-                "Completion '{}' has no additional_text_edits, sending completionItem/resolve",
-                label
-            );
-            self.active_window_mut().send_completion_resolve(item);
+    /// Run the `command` of an accepted completion item. The two commands
+    /// servers borrow from VS Code to re-open completion or signature help
+    /// are handled here; anything else goes to the server.
+    fn run_completion_command(&mut self, command: lsp_types::Command) {
+        match command.command.as_str() {
+            "editor.action.triggerSuggest" => self.request_completion(None),
+            "editor.action.triggerParameterHints" => self.request_signature_help(),
+            _ => self.execute_lsp_command(&command),
         }
     }
 
@@ -409,6 +506,22 @@ impl Editor {
                 self.hide_popup();
             }
 
+            Some(PopupResolver::Jobs) => {
+                self.hide_popup();
+            }
+
+            Some(PopupResolver::IndentationFix) => {
+                self.hide_popup();
+            }
+
+            Some(PopupResolver::NavigationHistory) => {
+                self.hide_popup();
+            }
+
+            Some(PopupResolver::LineBookmarks) | Some(PopupResolver::NamedMarks) => {
+                self.hide_popup();
+            }
+
             Some(PopupResolver::WorkspaceTrust) => {
                 // The trust prompt is a forced choice: there is no "undecided"
                 // outcome, so Escape does nothing. The user must pick Trust /
@@ -565,30 +678,20 @@ impl Editor {
         let prefix = if word_start < cursor_pos {
             self.active_state_mut()
                 .get_text_range(word_start, cursor_pos)
-                .to_lowercase()
         } else {
             String::new()
         };
 
-        // Filter LSP items
-        let filtered_lsp: Vec<&lsp_types::CompletionItem> = if prefix.is_empty() {
-            lsp_items.iter().collect()
-        } else {
-            lsp_items
-                .iter()
-                .filter(|item| {
-                    item.label.to_lowercase().starts_with(&prefix)
-                        || item
-                            .filter_text
-                            .as_ref()
-                            .map(|ft| ft.to_lowercase().starts_with(&prefix))
-                            .unwrap_or(false)
-                })
-                .collect()
-        };
+        // Filter and rank LSP items
+        let ranked_lsp = rank_lsp_items(
+            &lsp_items,
+            &prefix,
+            self.config.editor.completion_matching,
+            self.config.editor.completion_sort,
+        );
 
         // Build combined items: LSP first, then buffer-word results.
-        let mut all_popup_items = lsp_items_to_popup_items(&filtered_lsp);
+        let mut all_popup_items = lsp_items_to_popup_items(ranked_lsp);
         let buffer_word_items = self.get_buffer_completion_popup_items();
         let lsp_labels: std::collections::HashSet<String> = all_popup_items
             .iter()
@@ -668,14 +771,28 @@ pub(crate) fn build_completion_popup_from_items(
 }
 
 /// Convert LSP `CompletionItem`s to `PopupListItemData`s.
+/// The text an LSP completion item inserts: the new text of its `textEdit`,
+/// which the LSP specification gives precedence, else its `insertText`, else
+/// its label.
+fn lsp_insert_text(item: &lsp_types::CompletionItem) -> &str {
+    let edit_text = item.text_edit.as_ref().map(|edit| match edit {
+        lsp_types::CompletionTextEdit::Edit(edit) => edit.new_text.as_str(),
+        lsp_types::CompletionTextEdit::InsertAndReplace(edit) => edit.new_text.as_str(),
+    });
+    edit_text
+        .or(item.insert_text.as_deref())
+        .unwrap_or(&item.label)
+}
+
 pub(crate) fn lsp_items_to_popup_items(
-    items: &[&lsp_types::CompletionItem],
+    items: Vec<crate::services::completion::lsp_ranking::RankedItem<'_>>,
 ) -> Vec<crate::model::event::PopupListItemData> {
     use crate::model::event::PopupListItemData;
 
     items
-        .iter()
-        .map(|item| {
+        .into_iter()
+        .map(|ranked| {
+            let item = ranked.item;
             let icon = match item.kind {
                 Some(lsp_types::CompletionItemKind::FUNCTION)
                 | Some(lsp_types::CompletionItemKind::METHOD) => Some("λ".to_string()),
@@ -691,10 +808,8 @@ pub(crate) fn lsp_items_to_popup_items(
                 text: item.label.clone(),
                 detail: item.detail.clone(),
                 icon,
-                data: item
-                    .insert_text
-                    .clone()
-                    .or_else(|| Some(item.label.clone())),
+                data: Some(lsp_insert_text(item).to_string()),
+                match_positions: ranked.match_positions,
             }
         })
         .collect()
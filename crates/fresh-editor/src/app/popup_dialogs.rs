@@ -274,7 +274,9 @@ impl Editor {
     /// rows, then pin + present the popup.
     fn build_and_show_lsp_status_popup(&mut self, language: &str, focused: bool) {
         let servers = self.collect_lsp_status_servers(language);
-        if servers.names.is_empty() {
+        let mut detection_items = Vec::new();
+        self.push_lsp_detection_rows(language, &mut detection_items);
+        if servers.names.is_empty() && detection_items.is_empty() {
             self.active_window_mut().status_message = Some(t!("lsp.no_server_active").to_string());
             return;
         }
@@ -286,6 +288,7 @@ impl Editor {
         // to interpret it, so no separate action-key table is needed.
         let mut items: Vec<crate::view::popup::PopupListItem> = Vec::new();
         self.push_lsp_server_rows(language, &servers, &mut items);
+        items.extend(detection_items);
         self.push_lsp_footer_rows(language, &servers, &mut items);
         self.present_lsp_status_popup(language, items, focused);
     }
@@ -470,6 +473,13 @@ impl Editor {
                     crate::view::popup::PopupListItem::new(format!("    Stop {name}"))
                         .with_data(format!("stop:{language}/{name}")),
                 );
+            } else if binary_missing
+                && !authority_is_container
+                && crate::services::lsp::known_servers::known_servers(language)
+                    .any(|k| k.command == name)
+            {
+                // A well-known server: the detection rows below carry its
+                // install command (and a row to run it), so no advisory here.
             } else if binary_missing {
                 // A disabled advisory row instead of an actionable "Start" —
                 // clicking Start here would spawn, fail, and noise up the
@@ -521,6 +531,64 @@ impl Editor {
         }
     }
 
+    /// Push rows for well-known servers when no configured server for
+    /// `language` can run: "Use X" for one found on PATH, otherwise the
+    /// platform's install command for each one that isn't installed — as a
+    /// row that runs it in the task runner when it needs no privileges.
+    fn push_lsp_detection_rows(
+        &self,
+        language: &str,
+        items: &mut Vec<crate::view::popup::PopupListItem>,
+    ) {
+        use crate::services::lsp::known_servers::detect_servers;
+
+        let configured = self
+            .config
+            .lsp
+            .get(language)
+            .map(|cfg| cfg.as_slice())
+            .unwrap_or(&[]);
+        let detection = detect_servers(language, configured, |cmd| match self.lsp() {
+            Some(mgr) => mgr.command_exists_via_authority(cmd),
+            None => crate::services::lsp::command_exists(cmd),
+        });
+        if detection.is_empty() {
+            return;
+        }
+        for server in &detection.found {
+            items.push(crate::view::popup::PopupListItem::new(format!(
+                "✚ {} (found on PATH)",
+                server.command
+            )));
+            items.push(
+                crate::view::popup::PopupListItem::new(format!("    Use {}", server.command))
+                    .with_data(format!("use:{language}/{}", server.command)),
+            );
+        }
+        // Installing runs on the host; inside a container the advisory row
+        // from `push_lsp_server_rows` points at postCreateCommand instead.
+        let authority_is_container = self.authority().display_label.starts_with("Container:");
+        if !detection.found.is_empty() || authority_is_container {
+            return;
+        }
+        for server in &detection.missing {
+            // A configured server already has its own header row above.
+            if !configured.iter().any(|c| c.command == server.command) {
+                items.push(crate::view::popup::PopupListItem::new(format!(
+                    "○ {} (not installed)",
+                    server.command
+                )));
+            }
+            let label = format!("    Install: {}", server.install_command());
+            items.push(if server.install_is_runnable() {
+                crate::view::popup::PopupListItem::new(label)
+                    .with_data(format!("install:{language}/{}", server.command))
+            } else {
+                crate::view::popup::PopupListItem::new(label).disabled()
+            });
+        }
+    }
+
     /// Push the language-level footer rows — enable/disable, view log, plugin
     /// contributions, and the trailing dismiss row — into `items`.
     fn push_lsp_footer_rows(
@@ -1445,14 +1513,16 @@ impl Editor {
         };
 
         let items = vec![
-            crate::view::popup::PopupListItem::new("Trust this folder".to_string())
-                .with_detail("Allow project tooling (LSP, env managers, tasks) to run".to_string())
+            crate::view::popup::PopupListItem::new(t!("trust.popup.opt_trust_label").to_string())
+                .with_detail(t!("trust.popup.opt_trust_desc").to_string())
                 .with_data("trusted".to_string()),
-            crate::view::popup::PopupListItem::new("Keep restricted (default)".to_string())
-                .with_detail("Don't run repo-controlled code; system tools still run".to_string())
-                .with_data("restricted".to_string()),
-            crate::view::popup::PopupListItem::new("Block all execution".to_string())
-                .with_detail("No processes run at all in this workspace".to_string())
+            crate::view::popup::PopupListItem::new(
+                t!("trust.popup.opt_restrict_label").to_string(),
+            )
+            .with_detail(t!("trust.popup.opt_restrict_desc").to_string())
+            .with_data("restricted".to_string()),
+            crate::view::popup::PopupListItem::new(t!("trust.popup.opt_block_label").to_string())
+                .with_detail(t!("trust.popup.opt_block_desc").to_string())
                 .with_data("blocked".to_string()),
         ];
 
@@ -1472,7 +1542,7 @@ impl Editor {
 
         let popup = Popup {
             kind: PopupKind::List,
-            title: Some("This project can run code on your machine. Trust it?".to_string()),
+            title: Some(t!("trust.popup.title").to_string()),
             description: None,
             transient: false,
             content: PopupContent::List { items, selected },
@@ -1733,6 +1803,64 @@ impl Editor {
         }
     }
 
+    /// Show a centered popup reporting that a config file changed on disk
+    /// but doesn't load, so the current settings were kept.
+    ///
+    /// Raised by config hot reload, which has no command invocation to
+    /// answer with a status line: the edit happened in a buffer or in
+    /// another program, and the user needs to know it didn't take effect.
+    pub fn show_config_reload_error_popup(
+        &mut self,
+        failure: &crate::config_io::ConfigLoadFailure,
+    ) {
+        use crate::view::popup::{Popup, PopupPosition};
+        use ratatui::style::Style;
+
+        const WIDTH: u16 = 64;
+        let wrap_width = (WIDTH as usize).saturating_sub(4);
+
+        let location = match failure.position {
+            Some((line, column)) => format!("{}:{}:{}", failure.path.display(), line, column),
+            None => failure.path.display().to_string(),
+        };
+        let detail = t!(
+            "config.reload_failed",
+            path = location,
+            error = failure.message
+        )
+        .to_string();
+        let kept = t!("config.reload_failed_kept").to_string();
+
+        let mut lines = hard_wrap(&detail, wrap_width);
+        lines.push(String::new());
+        lines.extend(hard_wrap(&kept, wrap_width));
+
+        let popup = {
+            let theme = self.theme.read().unwrap();
+            let mut p = Popup::text(lines, &theme)
+                .with_title(t!("config.reload_failed_title").to_string())
+                .with_focused(true);
+            p.transient = false;
+            p.position = PopupPosition::Centered;
+            p.width = WIDTH;
+            p.max_height = 14;
+            p.border_style = Style::default().fg(theme.diagnostic_error_fg);
+            p.background_style = Style::default().bg(theme.popup_bg);
+            p
+        };
+
+        let buffer_id = self.active_buffer();
+        if let Some(state) = self
+            .windows
+            .get_mut(&self.active_window)
+            .map(|w| &mut w.buffers)
+            .expect("active window present")
+            .get_mut(&buffer_id)
+        {
+            state.popups.show(popup);
+        }
+    }
+
     /// Get text properties at the cursor position in the active buffer
     pub fn get_text_properties_at_cursor(
         &self,
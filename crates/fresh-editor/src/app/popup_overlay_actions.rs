@@ -268,28 +268,31 @@ impl Editor {
 
         let popup = PopupData {
             kind: PopupKindHint::List,
-            title: Some(format!("Start LSP Server: {}?", server_info)),
+            title: Some(t!("lsp.start_server", language = server_info).to_string()),
             description: None,
             transient: false,
             content: PopupContentData::List {
                 items: vec![
                     PopupListItemData {
-                        text: "Allow this time".to_string(),
-                        detail: Some("Start the LSP server for this session".to_string()),
+                        text: t!("lsp.allow_once").to_string(),
+                        detail: Some(t!("lsp.allow_once_desc").to_string()),
                         icon: None,
                         data: Some("allow_once".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
-                        text: "Always allow".to_string(),
-                        detail: Some("Always start this LSP server automatically".to_string()),
+                        text: t!("lsp.always_allow").to_string(),
+                        detail: Some(t!("lsp.always_allow_desc").to_string()),
                         icon: None,
                         data: Some("allow_always".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
-                        text: "Don't start".to_string(),
-                        detail: Some("Cancel LSP server startup".to_string()),
+                        text: t!("lsp.dont_start").to_string(),
+                        detail: Some(t!("lsp.dont_start_desc").to_string()),
                         icon: None,
                         data: Some("deny".to_string()),
+                        match_positions: Vec::new(),
                     },
                 ],
                 selected: 0,
@@ -599,6 +602,7 @@ impl Editor {
         let event = Event::PopupSelectNext;
         self.active_event_log_mut().append(event.clone());
         self.apply_event_to_active_buffer(&event);
+        self.update_completion_docs();
     }
 
     /// Navigate popup selection (previous item)
@@ -610,6 +614,7 @@ impl Editor {
         let event = Event::PopupSelectPrev;
         self.active_event_log_mut().append(event.clone());
         self.apply_event_to_active_buffer(&event);
+        self.update_completion_docs();
     }
 
     /// Navigate popup (page down)
@@ -621,6 +626,7 @@ impl Editor {
         let event = Event::PopupPageDown;
         self.active_event_log_mut().append(event.clone());
         self.apply_event_to_active_buffer(&event);
+        self.update_completion_docs();
     }
 
     /// Navigate popup (page up)
@@ -632,6 +638,7 @@ impl Editor {
         let event = Event::PopupPageUp;
         self.active_event_log_mut().append(event.clone());
         self.apply_event_to_active_buffer(&event);
+        self.update_completion_docs();
     }
 }
 
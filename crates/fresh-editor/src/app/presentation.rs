@@ -0,0 +1,159 @@
+//! Presentation mode
+//!
+//! A read-only, full-screen view of a buffer with the text drawn several
+//! times larger than the terminal font, for screen sharing and demos. The
+//! buffer is snapshotted (text plus syntax colors) when the mode opens and
+//! shown a page at a time; Space and Backspace move between pages.
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::style::Color;
+
+/// How much larger than the terminal font the text is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresentationScale {
+    /// Double width: each character is drawn as its fullwidth form.
+    Wide,
+    /// Double width and triple height: each character is drawn with the
+    /// block font in [`crate::primitives::block_font`].
+    Block,
+}
+
+/// What the editor should do after a key in presentation mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresentationAction {
+    Continue,
+    Exit,
+}
+
+/// One line of the presented buffer as runs of same-colored text.
+pub type PresentationLine = Vec<(String, Color)>;
+
+/// Presentation mode state.
+#[derive(Debug)]
+pub struct Presentation {
+    /// Name of the presented buffer, shown in the footer.
+    pub title: String,
+    /// The buffer's lines, tabs expanded.
+    pub lines: Vec<PresentationLine>,
+    pub scale: PresentationScale,
+    /// First line of the current page; always a multiple of
+    /// `lines_per_page`.
+    top_line: usize,
+    /// How many lines fit on a page, set by the renderer each frame.
+    lines_per_page: usize,
+}
+
+impl Presentation {
+    pub fn new(title: String, lines: Vec<PresentationLine>) -> Self {
+        Self {
+            title,
+            lines,
+            scale: PresentationScale::Block,
+            top_line: 0,
+            lines_per_page: 1,
+        }
+    }
+
+    /// The lines on the current page.
+    pub fn page_lines(&self) -> &[PresentationLine] {
+        let end = (self.top_line + self.lines_per_page).min(self.lines.len());
+        &self.lines[self.top_line.min(end)..end]
+    }
+
+    /// Zero-based index of the current page.
+    pub fn page(&self) -> usize {
+        self.top_line / self.lines_per_page
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.lines.len().div_ceil(self.lines_per_page).max(1)
+    }
+
+    /// Update the page size to what fits on screen, keeping the current
+    /// top line on the page that is shown.
+    pub fn set_lines_per_page(&mut self, lines_per_page: usize) {
+        self.lines_per_page = lines_per_page.max(1);
+        self.top_line -= self.top_line % self.lines_per_page;
+    }
+
+    fn go_to_page(&mut self, page: usize) {
+        self.top_line = page.min(self.page_count() - 1) * self.lines_per_page;
+    }
+
+    fn set_scale(&mut self, scale: PresentationScale) {
+        // The page size changes with the scale; the renderer realigns the
+        // top line to the new page size on the next frame.
+        self.scale = scale;
+    }
+
+    pub fn handle_key(&mut self, event: KeyEvent) -> PresentationAction {
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('q') => return PresentationAction::Exit,
+            KeyCode::Char(' ')
+            | KeyCode::Enter
+            | KeyCode::PageDown
+            | KeyCode::Right
+            | KeyCode::Down
+            | KeyCode::Char('n')
+            | KeyCode::Char('j') => self.go_to_page(self.page() + 1),
+            KeyCode::Backspace
+            | KeyCode::PageUp
+            | KeyCode::Left
+            | KeyCode::Up
+            | KeyCode::Char('p')
+            | KeyCode::Char('k') => self.go_to_page(self.page().saturating_sub(1)),
+            KeyCode::Home | KeyCode::Char('g') => self.go_to_page(0),
+            KeyCode::End | KeyCode::Char('G') => self.go_to_page(usize::MAX),
+            KeyCode::Char('+') | KeyCode::Char('=') => self.set_scale(PresentationScale::Block),
+            KeyCode::Char('-') => self.set_scale(PresentationScale::Wide),
+            _ => {}
+        }
+        PresentationAction::Continue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn presentation(lines: usize) -> Presentation {
+        let lines = (0..lines)
+            .map(|i| vec![(i.to_string(), Color::Reset)])
+            .collect();
+        let mut presentation = Presentation::new("test".into(), lines);
+        presentation.set_lines_per_page(3);
+        presentation
+    }
+
+    fn press(presentation: &mut Presentation, code: KeyCode) -> PresentationAction {
+        presentation.handle_key(KeyEvent::new(code, KeyModifiers::NONE))
+    }
+
+    #[test]
+    fn pages_forward_and_back_within_bounds() {
+        let mut p = presentation(7);
+        assert_eq!(p.page_count(), 3);
+        press(&mut p, KeyCode::Backspace);
+        assert_eq!(p.page(), 0);
+        press(&mut p, KeyCode::Char(' '));
+        press(&mut p, KeyCode::Char(' '));
+        press(&mut p, KeyCode::Char(' '));
+        assert_eq!(p.page(), 2);
+        assert_eq!(p.page_lines().len(), 1);
+        press(&mut p, KeyCode::Backspace);
+        assert_eq!(p.page(), 1);
+        assert_eq!(p.page_lines()[0][0].0, "3");
+        assert_eq!(press(&mut p, KeyCode::Esc), PresentationAction::Exit);
+    }
+
+    #[test]
+    fn resizing_keeps_the_top_line_on_screen() {
+        let mut p = presentation(10);
+        press(&mut p, KeyCode::End);
+        assert_eq!(p.page_lines()[0][0].0, "9");
+        p.set_lines_per_page(4);
+        assert_eq!(p.page(), 2);
+        assert_eq!(p.page_lines()[0][0].0, "8");
+    }
+}
@@ -0,0 +1,107 @@
+//! Presentation mode action handling
+//!
+//! Opens presentation mode on the active buffer and routes keys to it while
+//! it is up. See [`super::presentation`].
+
+use super::presentation::{Presentation, PresentationAction, PresentationLine};
+use super::Editor;
+use crate::input::handler::InputResult;
+use crossterm::event::KeyEvent;
+use ratatui::style::Color;
+use rust_i18n::t;
+
+/// Largest buffer presentation mode will snapshot. A presentation is read
+/// page by page by an audience, so anything bigger is almost certainly a
+/// mistake, and highlighting it in one pass would stall the UI.
+const MAX_PRESENTATION_BYTES: usize = 1024 * 1024;
+
+impl Editor {
+    /// Open presentation mode on the active buffer.
+    pub fn open_presentation(&mut self) {
+        // Presentation mode paints TUI cells only; on the web bridge it
+        // would be an invisible modal that owns the keyboard.
+        if self.suppress_chrome_cells {
+            self.set_status_message(t!("presentation.tui_only").to_string());
+            return;
+        }
+        let buffer_id = self.active_buffer();
+        let title = self.get_buffer_display_name(buffer_id);
+        let theme = self.theme.clone();
+        let theme = theme.read().unwrap();
+
+        let state = self.active_state_mut();
+        if state.buffer.is_binary() || state.buffer.len() > MAX_PRESENTATION_BYTES {
+            self.set_status_message(t!("presentation.unsupported").to_string());
+            return;
+        }
+        let Some(text) = state.buffer.to_string() else {
+            self.set_status_message(t!("error.buffer_not_loaded").to_string());
+            return;
+        };
+        let tab_size = state.buffer_settings.tab_size.max(1);
+        let spans = state
+            .highlighter
+            .highlight_viewport(&state.buffer, 0, text.len(), &theme, 0);
+
+        // Color of each byte, then runs of same-colored text per line.
+        let mut colors = vec![theme.editor_fg; text.len()];
+        for span in spans {
+            let end = span.range.end.min(text.len());
+            for color in colors.get_mut(span.range.start..end).into_iter().flatten() {
+                *color = span.color;
+            }
+        }
+        let mut lines = Vec::new();
+        let mut offset = 0;
+        for line in text.split('\n') {
+            lines.push(colored_line(
+                line,
+                &colors[offset..offset + line.len()],
+                tab_size,
+            ));
+            offset += line.len() + 1;
+        }
+        // A trailing newline ends the last line rather than starting a new one.
+        if text.ends_with('\n') {
+            lines.pop();
+        }
+        drop(theme);
+
+        self.presentation = Some(Presentation::new(title, lines));
+    }
+
+    /// Handle input while presentation mode is active.
+    pub fn handle_presentation_input(&mut self, event: &KeyEvent) -> InputResult {
+        let Some(presentation) = self.presentation.as_mut() else {
+            return InputResult::Ignored;
+        };
+        if presentation.handle_key(*event) == PresentationAction::Exit {
+            self.presentation = None;
+        }
+        InputResult::Consumed
+    }
+}
+
+/// Split `line` into runs of the same color, given the color of each of its
+/// bytes, expanding tabs and dropping a trailing `\r`.
+fn colored_line(line: &str, colors: &[Color], tab_size: usize) -> PresentationLine {
+    let mut runs: PresentationLine = Vec::new();
+    let mut column = 0;
+    for (index, c) in line.char_indices() {
+        let text = match c {
+            '\t' => {
+                let width = tab_size - column % tab_size;
+                " ".repeat(width)
+            }
+            '\r' if index + 1 == line.len() => break,
+            c => c.to_string(),
+        };
+        column += text.chars().count();
+        let color = colors[index];
+        match runs.last_mut() {
+            Some((run, run_color)) if *run_color == color => run.push_str(&text),
+            _ => runs.push((text, color)),
+        }
+    }
+    runs
+}
@@ -0,0 +1,281 @@
+//! Markdown and image previews
+//!
+//! "Markdown Preview" renders the active buffer in a popup: headings,
+//! lists, emphasis and syntax-highlighted code blocks. "Preview Image"
+//! shows the image linked under the cursor, or the active file when it is
+//! an image. Images are drawn by the terminal through its graphics protocol
+//! (see [`crate::services::terminal_graphics`]); terminals without one get
+//! a description of the file instead.
+
+use super::Editor;
+use crate::services::terminal_graphics::{self, PreviewImage};
+use crate::view::popup::{Popup, PopupContent, PopupPosition};
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use rust_i18n::t;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// File extensions "Preview Image" recognises in plain text.
+const IMAGE_EXTENSIONS: [&str; 4] = ["png", "jpg", "jpeg", "gif"];
+
+/// Where an image popup's picture goes on screen.
+#[derive(Debug, Clone)]
+pub(crate) struct ImagePlacement {
+    pub area: Rect,
+    pub image: Arc<PreviewImage>,
+}
+
+impl PartialEq for ImagePlacement {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area && Arc::ptr_eq(&self.image, &other.image)
+    }
+}
+
+impl Editor {
+    /// Show the active buffer rendered as markdown.
+    pub fn show_markdown_preview(&mut self) {
+        let Some(text) = self.active_state().buffer.to_string() else {
+            self.set_status_message(t!("preview.markdown_unavailable").to_string());
+            return;
+        };
+        let title = self
+            .active_state()
+            .buffer
+            .file_path()
+            .and_then(Path::file_name)
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| t!("preview.markdown_title").to_string());
+
+        let popup = {
+            let theme = self.theme.read().unwrap();
+            let mut p = Popup::markdown(&text, &theme, Some(&self.grammar_registry))
+                .with_title(title)
+                .with_focused(true);
+            p.transient = false;
+            p.position = PopupPosition::Centered;
+            p.width = (self.terminal_width.saturating_mul(4) / 5).clamp(40, 120);
+            p.max_height = self.terminal_height.saturating_sub(4).max(5);
+            p.background_style = Style::default().bg(theme.popup_bg);
+            p
+        };
+        self.active_state_mut().popups.show(popup);
+    }
+
+    /// Preview the image under the cursor, or the active file.
+    pub fn preview_image(&mut self) {
+        let Some(path) = self.image_path_at_cursor().or_else(|| {
+            self.active_state()
+                .buffer
+                .file_path()
+                .filter(|path| has_image_extension(path))
+                .map(Path::to_path_buf)
+        }) else {
+            self.set_status_message(t!("preview.no_image").to_string());
+            return;
+        };
+        let image = match self
+            .authority()
+            .filesystem
+            .read_file(&path)
+            .and_then(|data| PreviewImage::from_data(&path, data))
+        {
+            Ok(image) => image,
+            Err(e) => {
+                self.set_status_message(
+                    t!(
+                        "preview.image_error",
+                        path = path.display().to_string(),
+                        error = e.to_string()
+                    )
+                    .to_string(),
+                );
+                return;
+            }
+        };
+
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        let title = t!(
+            "preview.image_title",
+            name = name,
+            width = image.width,
+            height = image.height,
+            format = image.format.label()
+        )
+        .to_string();
+
+        let theme = self.theme.read().unwrap().clone();
+        let popup = match self.terminal_capabilities.graphics {
+            Some(protocol) if protocol.draws(image.format) => {
+                let max_cols = (self.terminal_width.saturating_mul(4) / 5).saturating_sub(2);
+                let max_rows = self.terminal_height.saturating_sub(6);
+                let (cols, rows) =
+                    image.fit(max_cols, max_rows, terminal_graphics::cell_pixel_size());
+                let mut p = Popup::text(Vec::new(), &theme);
+                p.content = PopupContent::Image {
+                    image: Arc::new(image),
+                    cols,
+                    rows,
+                };
+                // Room for the title and the close button beside it.
+                p.width = (cols + 2).max(title.chars().count() as u16 + 8);
+                p.max_height = rows + 2;
+                p
+            }
+            graphics => {
+                let reason = match graphics {
+                    Some(protocol) => t!(
+                        "preview.image_format_unsupported",
+                        format = image.format.label(),
+                        protocol = protocol.label()
+                    ),
+                    None => t!("preview.image_unsupported"),
+                };
+                let lines = vec![
+                    path.display().to_string(),
+                    String::new(),
+                    reason.to_string(),
+                ];
+                let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0) as u16;
+                let mut p = Popup::text(lines, &theme);
+                p.width = (width.max(title.chars().count() as u16 + 4) + 4).min(100);
+                p.max_height = 8;
+                p
+            }
+        };
+        let mut popup = popup.with_title(title).with_focused(true);
+        popup.transient = false;
+        popup.position = PopupPosition::Centered;
+        self.active_state_mut().popups.show(popup);
+    }
+
+    /// Record where this frame placed an image popup's picture.
+    pub(crate) fn set_image_placement(&mut self, placement: Option<ImagePlacement>) {
+        self.image_placement = placement;
+    }
+
+    /// Escape sequences to write after the frame just drawn: the image an
+    /// image popup reserved room for, or the removal of one that has gone.
+    /// `None` when nothing changed since the last call.
+    pub fn take_graphics_output(&mut self) -> Option<Vec<u8>> {
+        if self.image_placement == self.shown_image {
+            return None;
+        }
+        let protocol = self.terminal_capabilities.graphics?;
+        let mut out = Vec::new();
+        if self.shown_image.is_some() {
+            out.extend(terminal_graphics::clear(protocol));
+            if protocol != terminal_graphics::GraphicsProtocol::Kitty {
+                // Sixel and iTerm2 images are part of the cells; repaint
+                // them all, since unchanged cells aren't rewritten.
+                self.request_full_redraw();
+            }
+        }
+        if let Some(placement) = &self.image_placement {
+            if let Some(image) = terminal_graphics::encode(
+                protocol,
+                &placement.image,
+                placement.area,
+                terminal_graphics::cell_pixel_size(),
+            ) {
+                out.extend(image);
+            }
+        }
+        self.shown_image = self.image_placement.clone();
+        (!out.is_empty()).then_some(out)
+    }
+
+    /// The image a markdown link or a file path under the cursor points at.
+    fn image_path_at_cursor(&self) -> Option<PathBuf> {
+        let pos = self.active_cursors().primary().position;
+        let buffer = &self.active_state().buffer;
+        let (line, col) = buffer.position_to_line_col(pos);
+        let text = String::from_utf8_lossy(&buffer.get_line(line)?).into_owned();
+        let target = image_target_at(&text, col)?;
+        let path = PathBuf::from(target);
+        let resolved = if path.is_absolute() {
+            path
+        } else {
+            buffer
+                .file_path()
+                .and_then(Path::parent)
+                .unwrap_or_else(|| self.working_dir())
+                .join(path)
+        };
+        self.authority()
+            .filesystem
+            .is_file(&resolved)
+            .unwrap_or(false)
+            .then_some(resolved)
+    }
+}
+
+fn has_image_extension(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| IMAGE_EXTENSIONS.contains(&ext.to_ascii_lowercase().as_str()))
+}
+
+/// The link target or image-like word of `line` at byte column `col`.
+fn image_target_at(line: &str, col: usize) -> Option<&str> {
+    // A markdown link or image: the cursor anywhere from `[` to `)`.
+    let mut search = 0;
+    while let Some(found) = line[search..].find("](") {
+        let close = search + found;
+        let Some(open) = line[..close].rfind('[') else {
+            search = close + 2;
+            continue;
+        };
+        let start = if line[..open].ends_with('!') {
+            open - 1
+        } else {
+            open
+        };
+        let target_start = close + 2;
+        let Some(target_len) = line[target_start..].find(')') else {
+            break;
+        };
+        let end = target_start + target_len;
+        if (start..=end).contains(&col) {
+            let target = line[target_start..end].trim();
+            // Drop an optional title: `(path "title")`.
+            let target = target.split_whitespace().next().unwrap_or(target);
+            return Some(target.trim_start_matches('<').trim_end_matches('>'));
+        }
+        search = end;
+    }
+
+    // Otherwise a word that names an image file.
+    let is_separator = |c: char| c.is_whitespace() || "\"'`()[]<>,;".contains(c);
+    let start = line[..col.min(line.len())]
+        .rfind(is_separator)
+        .map_or(0, |i| i + 1);
+    let end = line[start..]
+        .find(is_separator)
+        .map_or(line.len(), |i| start + i);
+    let word = &line[start..end];
+    has_image_extension(Path::new(word)).then_some(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn targets_found_under_the_cursor() {
+        let line = "See ![logo](img/logo.png \"Logo\") and [docs](README.md).";
+        assert_eq!(image_target_at(line, 4), Some("img/logo.png"));
+        assert_eq!(image_target_at(line, 20), Some("img/logo.png"));
+        assert_eq!(image_target_at(line, 40), Some("README.md"));
+        assert_eq!(image_target_at(line, 0), None);
+
+        assert_eq!(
+            image_target_at("icon = \"assets/icon.PNG\"", 12),
+            Some("assets/icon.PNG")
+        );
+        assert_eq!(image_target_at("plain words here", 3), None);
+    }
+}
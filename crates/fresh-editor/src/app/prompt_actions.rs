@@ -12,6 +12,7 @@ use crate::config_io::{ConfigLayer, ConfigResolver};
 use crate::input::keybindings::Action;
 use crate::primitives::path_utils::expand_tilde;
 use crate::services::plugins::hooks::HookArgs;
+use crate::services::workspace_trust::SpawnDecision;
 use crate::view::prompt::PromptType;
 
 /// Result of handling a prompt confirmation.
@@ -30,7 +31,8 @@ pub(super) fn parse_path_line_col(input: &str) -> (String, Option<usize>, Option
 
 /// Convert a parsed goto-line target into a concrete 1-based line number,
 /// clamped into `1..=max_line`. Relative offsets are applied to `current_line`
-/// with saturating arithmetic so users can't underflow past line 1.
+/// with saturating arithmetic so users can't underflow past line 1;
+/// percentages are taken of `max_line`.
 pub(super) fn resolve_goto_line_target(
     target: crate::input::quick_open::GotoLineTarget,
     current_line: usize,
@@ -46,10 +48,33 @@ pub(super) fn resolve_goto_line_target(
                 current_line.saturating_sub(delta.unsigned_abs())
             }
         }
+        GotoLineTarget::Percent(pct) => max_line.saturating_mul(pct) / 100,
     };
     raw.clamp(1, max_line.max(1))
 }
 
+/// Resolve an encoding prompt's input to an [`Encoding`].
+///
+/// Matches the full input against display names first, which handles
+/// multi-word names like "UTF-16 LE" and "UTF-8 BOM", then the part before
+/// the parenthesis (e.g., "UTF-8" from "UTF-8 (Unicode)").
+fn parse_encoding_input(input: &str) -> Option<crate::model::buffer::Encoding> {
+    use crate::model::buffer::Encoding;
+
+    let trimmed = input.trim();
+    Encoding::all()
+        .iter()
+        .find(|enc| enc.display_name().eq_ignore_ascii_case(trimmed))
+        .copied()
+        .or_else(|| {
+            let before_paren = trimmed.split('(').next().unwrap_or(trimmed).trim();
+            Encoding::all()
+                .iter()
+                .find(|enc| enc.display_name().eq_ignore_ascii_case(before_paren))
+                .copied()
+        })
+}
+
 impl Editor {
     /// Handle prompt confirmation based on the prompt type.
     ///
@@ -139,30 +164,19 @@ impl Editor {
                 }
             }
             PromptType::GotoLine => {
-                let buffer_id = self.active_buffer();
-                if let Some(state) = self
-                    .windows
-                    .get(&self.active_window)
-                    .map(|w| &w.buffers)
-                    .expect("active window present")
-                    .get(&buffer_id)
-                {
-                    let max_line = state.buffer.line_count().unwrap_or(1);
-                    let current_line = state.primary_cursor_line_number.value() + 1;
-                    match crate::input::quick_open::parse_goto_line_input(&input) {
-                        Some(target) => {
-                            let line = resolve_goto_line_target(target, current_line, max_line);
-                            self.goto_line_col(line, None);
-                            self.set_status_message(t!("goto.jumped", line = line).to_string());
+                if let Some(position) = crate::input::quick_open::parse_goto_position(&input) {
+                    self.goto_position(position);
+                } else {
+                    match parse_path_line_col(&input) {
+                        (path, Some(line), column) if !path.is_empty() => {
+                            self.open_path_with_jump(&path, Some(line), column);
                         }
-                        None => {
+                        _ => {
                             self.set_status_message(
                                 t!("error.invalid_line", input = input.trim()).to_string(),
                             );
                         }
                     }
-                } else {
-                    self.set_status_message(t!("status.no_selection").to_string());
                 }
             }
             PromptType::GotoByteOffset => {
@@ -226,6 +240,9 @@ impl Editor {
                     self.open_file_with_jump(resolved, line, column);
                 }
             }
+            PromptType::DumpScreen => {
+                self.handle_dump_screen(&input);
+            }
             PromptType::SetBackgroundFile => {
                 if let Err(e) = self.load_ansi_background(&input) {
                     self.set_status_message(
@@ -290,6 +307,17 @@ impl Editor {
                     "Bookmark",
                 );
             }
+            PromptType::LineBookmarkLabel => {
+                self.set_line_bookmark_label(&input);
+            }
+            PromptType::SetNamedMark => match input.trim().chars().next() {
+                Some(c) => self.active_window_mut().set_named_mark(c),
+                None => self.set_status_message(t!("register.not_specified").to_string()),
+            },
+            PromptType::GotoNamedMark => match input.trim().chars().next() {
+                Some(c) => self.goto_named_mark(c),
+                None => self.set_status_message(t!("register.not_specified").to_string()),
+            },
             PromptType::Plugin { custom_type } => {
                 tracing::info!(
                     "prompt_confirmed: dispatching hook for prompt_type='{}', input='{}', selected_index={:?}",
@@ -321,6 +349,14 @@ impl Editor {
                     self.set_status_message(t!("buffer.revert_cancelled").to_string());
                 }
             }
+            PromptType::ConfirmFixEncoding { fixes } => {
+                let input_lower = input.trim().to_lowercase();
+                if input_lower == "y" || input_lower == "yes" {
+                    self.apply_encoding_fixes(fixes);
+                } else {
+                    self.set_status_message(t!("mojibake.cancelled").to_string());
+                }
+            }
             PromptType::ConfirmSaveConflict => {
                 let input_lower = input.trim().to_lowercase();
                 if input_lower == "o" || input_lower == "overwrite" {
@@ -339,8 +375,26 @@ impl Editor {
                     // Hide prompt before starting blocking command to clear the line
                     self.cancel_prompt();
 
-                    // Read temp file and write via sudo (works for both local and remote)
+                    // Read temp file and write via the configured elevation
+                    // command (works for both local and remote)
+                    let elevate: Vec<String> = self
+                        .config
+                        .editor
+                        .elevation_command
+                        .split_whitespace()
+                        .map(String::from)
+                        .collect();
+                    let command = elevate.first().map_or("sudo", String::as_str).to_string();
+                    let cwd = self.working_dir().to_string_lossy().to_string();
                     let result = (|| -> anyhow::Result<()> {
+                        // Workspace Trust applies as it does to any spawn.
+                        if let SpawnDecision::Deny(reason) = self
+                            .authority()
+                            .workspace_trust
+                            .decide(&command, Some(&cwd))
+                        {
+                            anyhow::bail!(reason);
+                        }
                         let data = self.authority().filesystem.read_file(&info.temp_path)?;
                         self.authority().filesystem.sudo_write(
                             &info.dest_path,
@@ -348,6 +402,7 @@ impl Editor {
                             info.mode,
                             info.uid,
                             info.gid,
+                            &elevate,
                         )?;
                         // Best-effort cleanup of temp file.
                         #[allow(clippy::let_underscore_must_use)]
@@ -364,21 +419,34 @@ impl Editor {
                             {
                                 tracing::warn!("Failed to finalize sudo save: {}", e);
                                 self.set_status_message(
-                                    t!("prompt.sudo_save_failed", error = e.to_string())
-                                        .to_string(),
+                                    t!(
+                                        "prompt.sudo_save_failed",
+                                        command = &command,
+                                        error = e.to_string()
+                                    )
+                                    .to_string(),
                                 );
                             } else if let Err(e) = self.finalize_save(Some(info.dest_path)) {
                                 tracing::warn!("Failed to finalize save after sudo: {}", e);
                                 self.set_status_message(
-                                    t!("prompt.sudo_save_failed", error = e.to_string())
-                                        .to_string(),
+                                    t!(
+                                        "prompt.sudo_save_failed",
+                                        command = &command,
+                                        error = e.to_string()
+                                    )
+                                    .to_string(),
                                 );
                             }
                         }
                         Err(e) => {
                             tracing::warn!("Sudo save failed: {}", e);
                             self.set_status_message(
-                                t!("prompt.sudo_save_failed", error = e.to_string()).to_string(),
+                                t!(
+                                    "prompt.sudo_save_failed",
+                                    command = &command,
+                                    error = e.to_string()
+                                )
+                                .to_string(),
                             );
                             // Best-effort cleanup of temp file.
                             #[allow(clippy::let_underscore_must_use)]
@@ -429,6 +497,17 @@ impl Editor {
             PromptType::ConfirmQuit => {
                 self.handle_confirm_quit(&input);
             }
+            PromptType::TelemetryConsent => {
+                self.handle_telemetry_consent(&input);
+            }
+            PromptType::ConfirmTerminalPaste { text } => {
+                let input_lower = input.trim().to_lowercase();
+                if input_lower == "y" || input_lower == "yes" {
+                    self.active_window_mut().send_terminal_paste(&text);
+                } else {
+                    self.set_status_message(t!("terminal.paste_cancelled").to_string());
+                }
+            }
             PromptType::LspRename {
                 original_text,
                 start_pos,
@@ -622,9 +701,12 @@ impl Editor {
             PromptType::RestartLspServer => {
                 self.handle_restart_lsp_server(&input);
             }
-            PromptType::SelectTheme { .. } => {
+            PromptType::SelectTheme { persist: true, .. } => {
                 self.apply_theme(input.trim());
             }
+            PromptType::SelectTheme { persist: false, .. } => {
+                self.apply_session_theme(input.trim());
+            }
             PromptType::SelectKeybindingMap => {
                 self.apply_keybinding_map(input.trim());
             }
@@ -634,6 +716,15 @@ impl Editor {
             PromptType::SelectLocale => {
                 self.apply_locale(input.trim());
             }
+            PromptType::SelectCalibrationProfile => {
+                self.apply_calibration_profile(input.trim());
+            }
+            PromptType::InsertSnippet { snippets } => {
+                self.insert_user_snippet(&snippets, &input);
+            }
+            PromptType::GotoTag => {
+                self.goto_picked_tag(&input);
+            }
             PromptType::CopyWithFormattingTheme => {
                 self.copy_selection_with_theme(input.trim());
             }
@@ -642,6 +733,24 @@ impl Editor {
                     self.switch_to_tab(BufferId(id));
                 }
             }
+            PromptType::SwitchBuffer => {
+                self.active_window_mut().end_buffer_picker_preview();
+                if let Ok(id) = input.trim().parse::<usize>() {
+                    self.switch_buffer(BufferId(id));
+                }
+            }
+            PromptType::ClipboardHistory => {
+                // Without a selection the input is the search query, not
+                // an entry index.
+                if let Some(index) = selected_index.and_then(|_| input.parse::<usize>().ok()) {
+                    self.paste_clipboard_history_entry(index);
+                }
+            }
+            PromptType::FileHistory => {
+                if selected_index.is_some() {
+                    self.restore_local_history_version(std::path::Path::new(&input));
+                }
+            }
             PromptType::QueryReplaceConfirm => {
                 // This is handled by InsertChar, not PromptConfirm
                 // But if somehow Enter is pressed, treat it as skip (n)
@@ -666,9 +775,20 @@ impl Editor {
             PromptType::SetEncoding => {
                 self.handle_set_encoding(&input);
             }
+            PromptType::SaveWithEncoding => {
+                if self.handle_save_with_encoding(&input) {
+                    return PromptResult::ExecuteAction(Action::Save);
+                }
+            }
+            PromptType::NewScratchBuffer => {
+                self.new_scratch_buffer(&input);
+            }
             PromptType::SetLanguage => {
                 self.handle_set_language(&input);
             }
+            PromptType::FilterEditorLog => {
+                self.set_editor_log_filter(&input);
+            }
             PromptType::ShellCommand { replace } => {
                 self.handle_shell_command(&input, replace);
             }
@@ -793,10 +913,12 @@ impl Editor {
                 {
                     if state.language == "text" {
                         let first_line = state.buffer.first_line_lossy();
+                        let modeline_region = state.buffer.modeline_region_lossy();
                         let detected =
                             crate::primitives::detected_language::DetectedLanguage::from_path(
                                 &full_path,
                                 first_line.as_deref(),
+                                modeline_region.as_deref(),
                                 &self.grammar_registry,
                                 &self.config.languages,
                             );
@@ -885,7 +1007,7 @@ impl Editor {
                 // the quit rather than silently dropping the remaining
                 // unnamed buffers.
                 self.active_window_mut().pending_quit_unnamed_save.clear();
-                self.set_status_message(t!("file.error_saving", error = e.to_string()).to_string());
+                self.set_status_error(t!("file.error_saving", error = e.to_string()).to_string());
             }
         }
     }
@@ -1092,26 +1214,7 @@ impl Editor {
 
     /// Handle SetEncoding prompt confirmation.
     fn handle_set_encoding(&mut self, input: &str) {
-        use crate::model::buffer::Encoding;
-
-        let trimmed = input.trim();
-
-        // First try to match the full input against encoding display names
-        // This handles multi-word names like "UTF-16 LE" and "UTF-8 BOM"
-        let encoding = Encoding::all()
-            .iter()
-            .find(|enc| enc.display_name().eq_ignore_ascii_case(trimmed))
-            .copied()
-            .or_else(|| {
-                // If no match, try extracting before the parenthesis (e.g., "UTF-8" from "UTF-8 (Unicode)")
-                let before_paren = trimmed.split('(').next().unwrap_or(trimmed).trim();
-                Encoding::all()
-                    .iter()
-                    .find(|enc| enc.display_name().eq_ignore_ascii_case(before_paren))
-                    .copied()
-            });
-
-        match encoding {
+        match parse_encoding_input(input) {
             Some(enc) => {
                 self.active_state_mut().buffer.set_encoding(enc);
                 self.set_status_message(format!("Encoding set to {}", enc.display_name()));
@@ -1122,31 +1225,51 @@ impl Editor {
         }
     }
 
+    /// Handle SaveWithEncoding prompt confirmation.
+    ///
+    /// Switches the buffer to the chosen encoding and returns true so the
+    /// caller runs a normal save, which converts on write. Refuses (and
+    /// returns false) when the text has characters the encoding can't
+    /// represent, rather than letting the conversion replace them.
+    fn handle_save_with_encoding(&mut self, input: &str) -> bool {
+        let Some(enc) = parse_encoding_input(input) else {
+            self.set_status_message(format!("Unknown encoding: {}", input));
+            return false;
+        };
+
+        let unencodable = match self.active_state().buffer.first_unencodable_char(enc) {
+            Ok(c) => c,
+            Err(e) => {
+                self.set_status_message(t!("file.error_saving", error = e.to_string()).to_string());
+                return false;
+            }
+        };
+        if let Some(c) = unencodable {
+            self.set_status_message(
+                t!(
+                    "file.unencodable_char",
+                    encoding = enc.display_name(),
+                    char = c.to_string(),
+                    code = format!("{:04X}", c as u32)
+                )
+                .to_string(),
+            );
+            return false;
+        }
+
+        self.active_state_mut().buffer.set_encoding(enc);
+        true
+    }
+
     /// Handle OpenFileWithEncoding prompt confirmation.
     /// Opens a file with a specific encoding (no auto-detection).
     ///
     /// For large files with non-resynchronizable encodings, shows a confirmation prompt
     /// before loading the entire file into memory.
     fn handle_open_file_with_encoding(&mut self, path: &std::path::Path, input: &str) {
-        use crate::model::buffer::Encoding;
         use crate::view::prompt::PromptType;
 
-        let trimmed = input.trim();
-
-        // Parse the encoding from input
-        let encoding = Encoding::all()
-            .iter()
-            .find(|enc| enc.display_name().eq_ignore_ascii_case(trimmed))
-            .copied()
-            .or_else(|| {
-                let before_paren = trimmed.split('(').next().unwrap_or(trimmed).trim();
-                Encoding::all()
-                    .iter()
-                    .find(|enc| enc.display_name().eq_ignore_ascii_case(before_paren))
-                    .copied()
-            });
-
-        match encoding {
+        match parse_encoding_input(input) {
             Some(enc) => {
                 // Check if this is a large file with non-resynchronizable encoding
                 // If so, show confirmation prompt before loading
@@ -1208,28 +1331,11 @@ impl Editor {
     /// Handle ReloadWithEncoding prompt confirmation.
     /// Reloads the current file with a specific encoding.
     fn handle_reload_with_encoding(&mut self, input: &str) {
-        use crate::model::buffer::Encoding;
-
-        let trimmed = input.trim();
-
-        // Parse the encoding from input
-        let encoding = Encoding::all()
-            .iter()
-            .find(|enc| enc.display_name().eq_ignore_ascii_case(trimmed))
-            .copied()
-            .or_else(|| {
-                let before_paren = trimmed.split('(').next().unwrap_or(trimmed).trim();
-                Encoding::all()
-                    .iter()
-                    .find(|enc| enc.display_name().eq_ignore_ascii_case(before_paren))
-                    .copied()
-            });
-
-        match encoding {
+        match parse_encoding_input(input) {
             Some(enc) => {
                 // Reload the file with the specified encoding
                 if let Err(e) = self.reload_with_encoding(enc) {
-                    self.set_status_message(format!("Failed to reload: {}", e));
+                    self.set_status_error(format!("Failed to reload: {}", e));
                 } else {
                     self.set_status_message(format!(
                         "Reloaded with {} encoding",
@@ -1250,7 +1356,7 @@ impl Editor {
     /// from a language that overrode them), matching what the file-open path
     /// does so a manual **Set Language** stays consistent with opening a file
     /// of that type (issue #2580).
-    fn refresh_buffer_config(&mut self, buffer_id: fresh_core::BufferId) {
+    pub(super) fn refresh_buffer_config(&mut self, buffer_id: fresh_core::BufferId) {
         if let Some(state) = self
             .windows
             .get_mut(&self.active_window)
@@ -1395,7 +1501,7 @@ impl Editor {
         } else if first_char == discard_first {
             // Discard and close
             if let Err(e) = self.force_close_buffer(buffer_id) {
-                self.set_status_message(t!("file.cannot_close", error = e.to_string()).to_string());
+                self.set_status_error(t!("file.cannot_close", error = e.to_string()).to_string());
             } else {
                 self.set_status_message(t!("buffer.changes_discarded").to_string());
             }
@@ -1684,13 +1790,7 @@ impl Editor {
         match result {
             QuickOpenResult::ExecuteAction(action) => PromptResult::ExecuteAction(action),
             QuickOpenResult::OpenFile { path, line, column } => {
-                let expanded_path = expand_tilde(&path);
-                let full_path = if expanded_path.is_absolute() {
-                    expanded_path
-                } else {
-                    self.working_dir().join(&expanded_path)
-                };
-                self.open_file_with_jump(full_path, line, column);
+                self.open_path_with_jump(&path, line, column);
                 PromptResult::Done
             }
             QuickOpenResult::ShowBuffer(buffer_id) => {
@@ -1711,7 +1811,7 @@ impl Editor {
                 }
                 PromptResult::Done
             }
-            QuickOpenResult::GotoLine(target) => {
+            QuickOpenResult::GotoLine(position) => {
                 // Large file opened in byte-offset mode: there is no line
                 // index yet, so a `:N` target can't be resolved to a byte
                 // offset. Offer the same "scan for exact line numbers?" flow
@@ -1724,22 +1824,7 @@ impl Editor {
                     );
                     return PromptResult::Done;
                 }
-                let buffer_id = self.active_buffer();
-                if let Some(state) = self
-                    .windows
-                    .get(&self.active_window)
-                    .map(|w| &w.buffers)
-                    .expect("active window present")
-                    .get(&buffer_id)
-                {
-                    let max_line = state.buffer.line_count().unwrap_or(1);
-                    let current_line = state.primary_cursor_line_number.value() + 1;
-                    let line = resolve_goto_line_target(target, current_line, max_line);
-                    self.goto_line_col(line, None);
-                    self.set_status_message(t!("goto.jumped", line = line).to_string());
-                } else {
-                    self.set_status_message(t!("status.no_selection").to_string());
-                }
+                self.goto_position(position);
                 PromptResult::Done
             }
             QuickOpenResult::None => {
@@ -1753,6 +1838,39 @@ impl Editor {
         }
     }
 
+    /// Resolve a goto position against the active buffer and move the
+    /// cursor there.
+    fn goto_position(&mut self, position: crate::input::quick_open::GotoPosition) {
+        let buffer_id = self.active_buffer();
+        let Some(state) = self
+            .windows
+            .get(&self.active_window)
+            .map(|w| &w.buffers)
+            .expect("active window present")
+            .get(&buffer_id)
+        else {
+            self.set_status_message(t!("status.no_selection").to_string());
+            return;
+        };
+        let max_line = state.buffer.line_count().unwrap_or(1);
+        let current_line = state.primary_cursor_line_number.value() + 1;
+        let line = resolve_goto_line_target(position.line, current_line, max_line);
+        self.goto_line_col(line, position.column);
+        self.set_status_message(t!("goto.jumped", line = line).to_string());
+    }
+
+    /// Open `path` (tilde-expanded, relative to the working directory) and
+    /// jump to the given position.
+    fn open_path_with_jump(&mut self, path: &str, line: Option<usize>, column: Option<usize>) {
+        let expanded_path = expand_tilde(path);
+        let full_path = if expanded_path.is_absolute() {
+            expanded_path
+        } else {
+            self.working_dir().join(&expanded_path)
+        };
+        self.open_file_with_jump(full_path, line, column);
+    }
+
     fn open_file_with_jump(
         &mut self,
         full_path: std::path::PathBuf,
@@ -1826,7 +1944,28 @@ impl Editor {
 
 #[cfg(test)]
 mod tests {
-    use super::parse_path_line_col;
+    use super::{parse_path_line_col, resolve_goto_line_target};
+    use crate::input::quick_open::GotoLineTarget;
+
+    #[test]
+    fn test_resolve_goto_line_target_percent() {
+        assert_eq!(
+            resolve_goto_line_target(GotoLineTarget::Percent(50), 1, 200),
+            100
+        );
+        assert_eq!(
+            resolve_goto_line_target(GotoLineTarget::Percent(0), 80, 200),
+            1
+        );
+        assert_eq!(
+            resolve_goto_line_target(GotoLineTarget::Percent(100), 1, 200),
+            200
+        );
+        assert_eq!(
+            resolve_goto_line_target(GotoLineTarget::Relative(-5), 3, 200),
+            1
+        );
+    }
 
     #[test]
     fn test_parse_path_line_col_empty() {
@@ -81,6 +81,7 @@ impl Editor {
                 self.get_or_create_prompt_history("search").init_at_last();
             }
             self.update_search_highlights(&text);
+            self.update_incremental_match_count(&text);
         }
     }
 
@@ -228,7 +229,8 @@ impl Editor {
                 .and_then(|m| m.virtual_mode())
                 .map(|s| s.to_string()),
             has_lsp_config,
-            relative_line_numbers: self.config.editor.relative_line_numbers,
+            relative_line_numbers: self.config.editor.effective_line_number_mode()
+                != crate::config::LineNumberMode::Absolute,
         }
     }
 
@@ -253,7 +255,7 @@ impl Editor {
         }
 
         // Live preview for the goto-line provider: if the input is ":<N>" for a
-        // valid absolute line N, jump there now so the user sees the target as
+        // valid absolute line N (or `:<N>%`), jump there now so the user sees the target as
         // they type (matches VSCode's Ctrl+P :<N> behavior). Otherwise, restore
         // the cursor to its pre-preview position.
         //
@@ -265,7 +267,7 @@ impl Editor {
         // byte; skip previewing until the confirm path offers a scan (#2597).
         let input = input.trim();
         let target = if self.active_buffer_has_line_index() {
-            Self::parse_quick_open_goto_line_target(input)
+            self.parse_quick_open_goto_line_target(input)
         } else {
             None
         };
@@ -273,12 +275,25 @@ impl Editor {
     }
 
     /// Parse a Quick Open input string for a `:<N>` goto-line preview target.
-    /// Only absolute inputs are previewed; relative inputs return `None`.
-    pub(super) fn parse_quick_open_goto_line_target(input: &str) -> Option<usize> {
+    /// Only absolute and percentage inputs are previewed; relative inputs
+    /// return `None`.
+    pub(super) fn parse_quick_open_goto_line_target(&self, input: &str) -> Option<usize> {
         let rest = input.strip_prefix(':')?;
-        match crate::input::quick_open::parse_goto_line_input(rest) {
-            Some(crate::input::quick_open::GotoLineTarget::Absolute(n)) => Some(n),
-            _ => None,
+        self.goto_line_preview_target(rest)
+    }
+
+    /// Resolve goto input to the line a live preview should show, or `None`
+    /// for relative and unparseable input.
+    fn goto_line_preview_target(&self, input: &str) -> Option<usize> {
+        use crate::input::quick_open::GotoLineTarget;
+        let position = crate::input::quick_open::parse_goto_position(input)?;
+        match position.line {
+            GotoLineTarget::Absolute(n) => Some(n),
+            GotoLineTarget::Percent(pct) => {
+                let max_line = self.active_state().buffer.line_count().unwrap_or(1);
+                Some((max_line.saturating_mul(pct) / 100).clamp(1, max_line.max(1)))
+            }
+            GotoLineTarget::Relative(_) => None,
         }
     }
 
@@ -695,6 +710,9 @@ impl Editor {
             } else {
                 (Vec::new(), None)
             };
+        if let Some(loader) = &self.file_preview {
+            loader.clear();
+        }
         // Scrub the preview's search-match overlays from the last buffer it
         // pointed at. Redundant for preview-loaded buffers (closed below),
         // but essential for buffers the user already had open so the
@@ -764,7 +782,7 @@ impl Editor {
     pub fn cancel_prompt(&mut self) {
         // Extract theme to restore if this is a SelectTheme prompt
         let theme_to_restore = if let Some(ref prompt) = self.active_window_mut().prompt {
-            if let PromptType::SelectTheme { original_theme } = &prompt.prompt_type {
+            if let PromptType::SelectTheme { original_theme, .. } = &prompt.prompt_type {
                 Some(original_theme.clone())
             } else {
                 None
@@ -899,6 +917,10 @@ impl Editor {
                     // where it was before the prompt was opened.
                     self.restore_goto_line_preview_snapshot();
                 }
+                PromptType::SwitchBuffer => {
+                    // Put back whatever the other split showed before
+                    self.active_window_mut().end_buffer_picker_preview();
+                }
                 _ => {}
             }
         }
@@ -1016,10 +1038,18 @@ impl Editor {
                     | PromptType::RestartLspServer
                     | PromptType::SelectTheme { .. }
                     | PromptType::SelectLocale
+                    | PromptType::SelectCalibrationProfile
                     | PromptType::SwitchToTab
+                    | PromptType::SwitchBuffer
+                    | PromptType::ClipboardHistory
+                    | PromptType::FileHistory
                     | PromptType::SetLanguage
+                    | PromptType::NewScratchBuffer
                     | PromptType::SetEncoding
+                    | PromptType::SaveWithEncoding
                     | PromptType::SetLineEnding
+                    | PromptType::InsertSnippet { .. }
+                    | PromptType::GotoTag
                     | PromptType::Plugin { .. }
                     // Resume re-opens Live Grep as a core-driven
                     // PromptType::LiveGrep whose suggestions carry the
@@ -1260,6 +1290,7 @@ impl Editor {
             PromptType::Search | PromptType::ReplaceSearch | PromptType::QueryReplaceSearch => {
                 // Update incremental search highlights as user types
                 self.update_search_highlights(&input);
+                self.update_incremental_match_count(&input);
                 // Reset history navigation when user types - allows Up to navigate history
                 if let Some(history) = self.active_window_mut().prompt_histories.get_mut("search") {
                     history.reset_navigation();
@@ -1281,14 +1312,11 @@ impl Editor {
                 {
                     history.reset_navigation();
                 }
-                // Live preview for absolute line numbers only. Signed
+                // Live preview for absolute and percentage lines only. Signed
                 // (`+N`/`-N`) inputs are relative, and previewing them as the
                 // user types each digit is disorienting — preview only on
                 // Enter for those.
-                let target = match crate::input::quick_open::parse_goto_line_input(input.trim()) {
-                    Some(crate::input::quick_open::GotoLineTarget::Absolute(n)) => Some(n),
-                    _ => None,
-                };
+                let target = self.goto_line_preview_target(input.trim());
                 self.apply_goto_line_preview(target);
             }
             PromptType::OpenFile | PromptType::SwitchProject | PromptType::SaveFileAs => {
@@ -1323,12 +1351,31 @@ impl Editor {
             | PromptType::StopLspServer
             | PromptType::RestartLspServer
             | PromptType::SetLanguage
+            | PromptType::NewScratchBuffer
             | PromptType::SetEncoding
-            | PromptType::SetLineEnding => {
+            | PromptType::SaveWithEncoding
+            | PromptType::SetLineEnding
+            | PromptType::InsertSnippet { .. }
+            | PromptType::SelectCalibrationProfile
+            | PromptType::FileHistory
+            | PromptType::FilterEditorLog => {
                 if let Some(prompt) = &mut self.active_window_mut().prompt {
                     prompt.filter_suggestions(false);
                 }
             }
+            PromptType::SwitchBuffer => {
+                if let Some(prompt) = &mut self.active_window_mut().prompt {
+                    prompt.filter_suggestions(false);
+                }
+                self.preview_buffer_from_picker();
+            }
+            PromptType::GotoTag => {
+                self.update_goto_tag_suggestions(&input);
+            }
+            PromptType::ClipboardHistory => {
+                // Matches on the full entry text, not just the preview row
+                self.update_clipboard_history_suggestions(&input);
+            }
             PromptType::SelectLocale => {
                 // Locale selection also matches on description (language names)
                 if let Some(prompt) = &mut self.active_window_mut().prompt {
@@ -447,7 +447,8 @@ impl Editor {
         }
 
         // Collect buffer IDs that need recovery (immutable pass).
-        // Skip composite/hidden buffers — they are not real user content.
+        // Skip composite/hidden buffers — they are not real user content —
+        // and scratch buffers, whose content lives in the workspace file.
         let buffers_needing_recovery: Vec<_> = self
             .buffers()
             .iter()
@@ -456,7 +457,7 @@ impl Editor {
                     return None;
                 }
                 if let Some(meta) = self.active_window().buffer_metadata.get(buffer_id) {
-                    if meta.hidden_from_tabs || meta.is_virtual() {
+                    if meta.hidden_from_tabs || meta.is_virtual() || meta.scratch {
                         return None;
                     }
                 }
@@ -7,6 +7,10 @@
 /// pattern matches the literal text.  That lets the caller share one code
 /// path for both regex and plain-text search.
 ///
+/// With `multiline` on, a match may span lines: `.` also matches a
+/// newline in regex mode, and a plain-text query reads `\n`, `\t` and `\r`
+/// as escapes (see [`literal_search_text`]).
+///
 /// Returns `Err` with a user-facing message when the pattern fails to
 /// compile (regex-mode only; escaped literal patterns never fail).
 pub fn build_search_regex(
@@ -14,6 +18,7 @@ pub fn build_search_regex(
     use_regex: bool,
     whole_word: bool,
     case_sensitive: bool,
+    multiline: bool,
 ) -> Result<regex::Regex, String> {
     let pattern = if use_regex {
         if whole_word {
@@ -22,7 +27,7 @@ pub fn build_search_regex(
             query.to_string()
         }
     } else {
-        let escaped = regex::escape(query);
+        let escaped = regex::escape(&literal_search_text(query, multiline));
         if whole_word {
             format!(r"\b{}\b", escaped)
         } else {
@@ -37,10 +42,23 @@ pub fn build_search_regex(
         // anchors behave the same on LF and CRLF buffers.
         .multi_line(true)
         .crlf(true)
+        .dot_matches_new_line(multiline)
         .build()
         .map_err(|e| e.to_string())
 }
 
+/// The text a plain-text query stands for. With `multiline` on, `\n`, `\t`
+/// and `\r` in the query are read as escapes so a literal search can span
+/// lines (the search prompt is a single line); otherwise the query is
+/// taken as typed.
+pub fn literal_search_text(query: &str, multiline: bool) -> std::borrow::Cow<'_, str> {
+    if multiline {
+        std::borrow::Cow::Owned(interpret_escapes(query))
+    } else {
+        std::borrow::Cow::Borrowed(query)
+    }
+}
+
 /// Build a [`regex::bytes::Regex`] from user-supplied search settings.
 /// Returns `None` when `use_regex` is false.
 pub fn build_regex(
@@ -48,6 +66,7 @@ pub fn build_regex(
     use_regex: bool,
     whole_word: bool,
     case_sensitive: bool,
+    multiline: bool,
 ) -> Option<regex::bytes::Regex> {
     if !use_regex {
         return None;
@@ -64,6 +83,7 @@ pub fn build_regex(
         // Keep `^`/`$` line-anchored in replace, matching the search path.
         .multi_line(true)
         .crlf(true)
+        .dot_matches_new_line(multiline)
         .build()
         .ok()
 }
@@ -197,41 +217,41 @@ mod tests {
 
     #[test]
     fn build_regex_returns_none_when_disabled() {
-        assert!(build_regex("foo", false, false, true).is_none());
+        assert!(build_regex("foo", false, false, true, false).is_none());
     }
 
     #[test]
     fn build_search_regex_plain_text_escapes_special_chars() {
         // Non-regex mode: "a.b" should match "a.b" literally, not "a?b".
-        let re = build_search_regex("a.b", false, false, true).unwrap();
+        let re = build_search_regex("a.b", false, false, true, false).unwrap();
         assert!(re.is_match("a.b"));
         assert!(!re.is_match("axb"));
     }
 
     #[test]
     fn build_search_regex_regex_mode_treats_dot_as_wildcard() {
-        let re = build_search_regex("a.b", true, false, true).unwrap();
+        let re = build_search_regex("a.b", true, false, true, false).unwrap();
         assert!(re.is_match("axb"));
         assert!(re.is_match("a.b"));
     }
 
     #[test]
     fn build_search_regex_whole_word_wraps_pattern() {
-        let re = build_search_regex("foo", false, true, true).unwrap();
+        let re = build_search_regex("foo", false, true, true, false).unwrap();
         assert!(re.is_match("foo bar"));
         assert!(!re.is_match("foobar"));
     }
 
     #[test]
     fn build_search_regex_case_insensitive_when_flag_off() {
-        let re = build_search_regex("Hello", false, false, false).unwrap();
+        let re = build_search_regex("Hello", false, false, false, false).unwrap();
         assert!(re.is_match("HELLO"));
         assert!(re.is_match("hello"));
     }
 
     #[test]
     fn build_search_regex_reports_invalid_pattern_in_regex_mode() {
-        let err = build_search_regex("[unclosed", true, false, true).unwrap_err();
+        let err = build_search_regex("[unclosed", true, false, true, false).unwrap_err();
         assert!(!err.is_empty());
     }
 
@@ -239,21 +259,21 @@ mod tests {
     fn build_search_regex_plain_mode_never_fails_even_on_regex_metachars() {
         // Plain-text mode escapes everything, so even a syntactically-bad
         // regex pattern compiles fine as a literal search.
-        assert!(build_search_regex("[unclosed", false, false, true).is_ok());
+        assert!(build_search_regex("[unclosed", false, false, true, false).is_ok());
     }
 
     #[test]
     fn build_search_regex_caret_anchors_every_line() {
         // Regression: `^use` must match the start of *every* line, not just
         // the start of the buffer. Without multi-line mode this finds 1.
-        let re = build_search_regex("^use", true, false, true).unwrap();
+        let re = build_search_regex("^use", true, false, true, false).unwrap();
         let text = "use a;\nuse b;\nlet use_x = 1;\nuse c;\n";
         assert_eq!(re.find_iter(text).count(), 3);
     }
 
     #[test]
     fn build_search_regex_dollar_anchors_every_line() {
-        let re = build_search_regex("foo$", true, false, true).unwrap();
+        let re = build_search_regex("foo$", true, false, true, false).unwrap();
         let text = "foo\nfoobar\nbar foo\n";
         assert_eq!(re.find_iter(text).count(), 2);
     }
@@ -261,7 +281,7 @@ mod tests {
     #[test]
     fn build_search_regex_anchors_match_crlf_lines() {
         // On CRLF buffers `$` must match before `\r\n`, so `foo$` still hits.
-        let re = build_search_regex("foo$", true, false, true).unwrap();
+        let re = build_search_regex("foo$", true, false, true, false).unwrap();
         let text = "foo\r\nbar\r\nfoo\r\n";
         assert_eq!(re.find_iter(text).count(), 2);
     }
@@ -271,45 +291,59 @@ mod tests {
         // Multi-line mode only re-anchors `^`/`$`; `.` must still stop at a
         // line boundary (no `dot_matches_new_line`). So `a.b` cannot span
         // "a\nb", but a greedy `a.*` stays within its line.
-        let re = build_search_regex("a.b", true, false, true).unwrap();
+        let re = build_search_regex("a.b", true, false, true, false).unwrap();
         assert!(!re.is_match("a\nb"));
-        let re2 = build_search_regex("a.*", true, false, true).unwrap();
+        let re2 = build_search_regex("a.*", true, false, true, false).unwrap();
         let m = re2.find("axy\nbcd").unwrap();
         assert_eq!(m.as_str(), "axy");
     }
 
+    #[test]
+    fn build_search_regex_multiline_spans_lines() {
+        // Regex mode: `.` crosses newlines.
+        let re = build_search_regex("a.b", true, false, true, true).unwrap();
+        assert!(re.is_match("a\nb"));
+        // Plain mode: `\n` in the query is a newline, other text stays literal.
+        let re = build_search_regex(r"a.\nb", false, false, true, true).unwrap();
+        assert!(re.is_match("a.\nb"));
+        assert!(!re.is_match("ax\nb"));
+        // Without multiline, the backslash is matched as typed.
+        let re = build_search_regex(r"a\nb", false, false, true, false).unwrap();
+        assert!(re.is_match(r"a\nb"));
+    }
+
     #[test]
     fn build_regex_caret_anchors_every_line() {
         // The bytes builder used for replace must anchor per line too.
-        let re = build_regex("^use", true, false, true).unwrap();
+        let re = build_regex("^use", true, false, true, false).unwrap();
         let text = b"use a;\nuse b;\nuse c;\n";
         assert_eq!(re.find_iter(text).count(), 3);
     }
 
     #[test]
     fn build_regex_basic_pattern() {
-        let re = build_regex("foo.*bar", true, false, true).unwrap();
+        let re = build_regex("foo.*bar", true, false, true, false).unwrap();
         assert!(re.is_match(b"foo123bar"));
         assert!(!re.is_match(b"baz"));
     }
 
     #[test]
     fn build_regex_case_insensitive() {
-        let re = build_regex("hello", true, false, false).unwrap();
+        let re = build_regex("hello", true, false, false, false).unwrap();
         assert!(re.is_match(b"HELLO"));
         assert!(re.is_match(b"hello"));
     }
 
     #[test]
     fn build_regex_whole_word() {
-        let re = build_regex("foo", true, true, true).unwrap();
+        let re = build_regex("foo", true, true, true, false).unwrap();
         assert!(re.is_match(b"foo bar"));
         assert!(!re.is_match(b"foobar"));
     }
 
     #[test]
     fn collect_regex_matches_literal_replacement() {
-        let re = build_regex("Sig:.*", true, false, true).unwrap();
+        let re = build_regex("Sig:.*", true, false, true, false).unwrap();
         let input = b"AAAAAA\nSig: hello\nBBBBBB\nSig: world\nCCCCCC";
         let matches = collect_regex_matches(&re, input, "");
 
@@ -322,7 +356,7 @@ mod tests {
 
     #[test]
     fn collect_regex_matches_with_capture_groups() {
-        let re = build_regex(r"(\w+)@(\w+)", true, false, true).unwrap();
+        let re = build_regex(r"(\w+)@(\w+)", true, false, true, false).unwrap();
         let input = b"alice@example bob@test";
         let matches = collect_regex_matches(&re, input, "$2=$1");
 
@@ -333,7 +367,7 @@ mod tests {
 
     #[test]
     fn expand_replacement_with_groups() {
-        let re = build_regex(r"(\d+)-(\d+)", true, false, true).unwrap();
+        let re = build_regex(r"(\d+)-(\d+)", true, false, true, false).unwrap();
         let matched = b"123-456";
         let result = expand_replacement(&re, matched, "$2/$1");
         assert_eq!(result, "456/123");
@@ -341,7 +375,7 @@ mod tests {
 
     #[test]
     fn expand_replacement_no_groups() {
-        let re = build_regex("hello", true, false, true).unwrap();
+        let re = build_regex("hello", true, false, true, false).unwrap();
         let matched = b"hello";
         let result = expand_replacement(&re, matched, "world");
         assert_eq!(result, "world");
@@ -405,7 +439,7 @@ mod tests {
 
     #[test]
     fn collect_regex_matches_expands_newline_escape() {
-        let re = build_regex(" +", true, false, true).unwrap();
+        let re = build_regex(" +", true, false, true, false).unwrap();
         let input = b"foo   bar";
         let matches = collect_regex_matches(&re, input, r"\n");
 
@@ -415,7 +449,7 @@ mod tests {
 
     #[test]
     fn collect_regex_matches_combines_escapes_and_capture_groups() {
-        let re = build_regex(r"(\w+)=(\w+)", true, false, true).unwrap();
+        let re = build_regex(r"(\w+)=(\w+)", true, false, true, false).unwrap();
         let input = b"key=value";
         let matches = collect_regex_matches(&re, input, r"$1\n$2");
 
@@ -425,7 +459,7 @@ mod tests {
 
     #[test]
     fn expand_replacement_interprets_escapes() {
-        let re = build_regex(r"(\w+)", true, false, true).unwrap();
+        let re = build_regex(r"(\w+)", true, false, true, false).unwrap();
         let result = expand_replacement(&re, b"hello", r"$1\tend");
         assert_eq!(result, "hello\tend");
     }
@@ -433,7 +467,7 @@ mod tests {
     /// Matches Python: re.sub(r'bla(bla)', r'oo\1oo', 'blablabla') == 'ooblaoobla'
     #[test]
     fn collect_regex_matches_capture_group_blabla() {
-        let re = build_regex(r"bla(bla)", true, false, true).unwrap();
+        let re = build_regex(r"bla(bla)", true, false, true, false).unwrap();
         let input = b"blablabla";
         let matches = collect_regex_matches(&re, input, "oo$1oo");
 
@@ -0,0 +1,121 @@
+//! Keep the user's place when a buffer is reloaded from disk.
+//!
+//! Revert and auto-revert (an external edit, a `git checkout`, a
+//! regenerated file) replace the buffer's [`EditorState`] with a fresh read
+//! of the file. Instead of clamping every position to the new length, the
+//! old and new text are diffed line by line ([`OffsetMap`]) and cursors,
+//! selections, scroll positions, folds, line bookmarks, marks and register
+//! bookmarks are carried through the diff. Folds, line bookmarks and marks
+//! are anchored by markers of the replaced state, so they are re-created in
+//! the new one.
+//!
+//! When either text isn't fully loaded (large-file mode) there is nothing
+//! to diff, and positions are clamped to the new length instead.
+
+use super::window::Window;
+use crate::model::event::BufferId;
+use crate::model::line_diff::OffsetMap;
+use crate::state::EditorState;
+use crate::view::folding::FoldManager;
+
+impl Window {
+    /// Replace `buffer_id`'s state with `new_state`, a fresh load of the
+    /// same file, mapping every view of the buffer through the change.
+    pub(crate) fn replace_state_on_reload(&mut self, buffer_id: BufferId, new_state: EditorState) {
+        let line_bookmarks: Vec<_> = self
+            .bookmarks
+            .lines()
+            .iter()
+            .filter(|b| b.buffer_id == buffer_id)
+            .cloned()
+            .collect();
+        let marks: Vec<_> = self
+            .bookmarks
+            .marks()
+            .iter()
+            .filter(|m| m.buffer_id == buffer_id)
+            .copied()
+            .collect();
+
+        let Some((offset_map, new_len, line_bookmarks, marks)) = self
+            .buffers
+            .with_buffer_and_view_states(buffer_id, |state, view_states| {
+                let old_state = std::mem::replace(state, new_state);
+                let new_len = state.buffer.len();
+                let offset_map = old_state
+                    .buffer
+                    .get_all_text()
+                    .zip(state.buffer.get_all_text())
+                    .map(|(old, new)| OffsetMap::new(&old, &new));
+                let map = |offset| map_offset(offset_map.as_ref(), new_len, offset);
+
+                for view in view_states
+                    .values_mut()
+                    .filter_map(|vs| vs.keyed_states.get_mut(&buffer_id))
+                {
+                    view.cursors.map(|cursor| {
+                        cursor.position = map(cursor.position);
+                        cursor.anchor = cursor.anchor.map(map);
+                        cursor.sticky_column = None;
+                        cursor.block_anchor = None;
+                    });
+                    view.viewport.top_byte = map(view.viewport.top_byte);
+
+                    let folds = view
+                        .folds
+                        .resolved_ranges(&old_state.buffer, &old_state.marker_list);
+                    view.folds = FoldManager::new();
+                    for fold in folds {
+                        view.folds.add(
+                            &state.buffer,
+                            &mut state.marker_list,
+                            map(fold.start_byte),
+                            map(fold.end_byte),
+                            fold.placeholder,
+                        );
+                    }
+                }
+
+                let line_bookmarks: Vec<_> = line_bookmarks
+                    .into_iter()
+                    .filter_map(|b| {
+                        let position = old_state.margins.get_indicator_position(b.marker_id)?;
+                        Some((b, map(position)))
+                    })
+                    .collect();
+                let marks: Vec<_> = marks
+                    .into_iter()
+                    .filter_map(|m| {
+                        let position = old_state.marker_list.get_position(m.marker_id)?;
+                        Some((m, map(position)))
+                    })
+                    .collect();
+                (offset_map, new_len, line_bookmarks, marks)
+            })
+        else {
+            return;
+        };
+
+        for (bookmark, position) in line_bookmarks {
+            self.bookmarks.remove_line(buffer_id, bookmark.marker_id);
+            self.add_line_bookmark(buffer_id, position, bookmark.label);
+        }
+        for (mut mark, position) in marks {
+            if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                mark.marker_id = state.marker_list.create_left_gravity(position);
+                self.bookmarks.set_mark(mark);
+            }
+        }
+        self.bookmarks.remap_positions(buffer_id, |offset| {
+            map_offset(offset_map.as_ref(), new_len, offset)
+        });
+    }
+}
+
+/// Map `offset` through the diff, or clamp it when there is none.
+fn map_offset(offset_map: Option<&OffsetMap>, new_len: usize, offset: usize) -> usize {
+    match offset_map {
+        Some(offset_map) => offset_map.map(offset),
+        None => offset.min(new_len),
+    }
+}
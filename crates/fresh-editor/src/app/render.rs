@@ -58,6 +58,7 @@ impl Editor {
     /// Render the editor to the terminal
     pub fn render(&mut self, frame: &mut Frame) {
         let _span = tracing::info_span!("render").entered();
+        let frame_started = std::time::Instant::now();
         let size = frame.area();
 
         self.drain_pre_layout_plugin_commands();
@@ -911,6 +912,17 @@ impl Editor {
             );
         }
 
+        // Which keys can complete a pending chord, along the bottom of the
+        // editor area.
+        if !self.suppress_chrome_cells {
+            self.render_chord_hints(
+                frame,
+                editor_content_area,
+                &keybindings_cloned,
+                &theme_clone,
+            );
+        }
+
         // Render menu bar last so dropdown appears on top of all other content
         // Update menu context with current editor state
         self.update_menu_context();
@@ -936,6 +948,18 @@ impl Editor {
         // Chrome theme-key provenance (status bar, menu, tabs, file explorer,
         // scrollbars) is now recorded during each region's own paint.
 
+        // Presentation mode covers the whole chrome area, menu bar included.
+        if !self.suppress_chrome_cells {
+            if let Some(ref mut presentation) = self.presentation {
+                crate::view::presentation::render_presentation(
+                    frame,
+                    chrome_area,
+                    presentation,
+                    &self.theme.read().unwrap(),
+                );
+            }
+        }
+
         // Render tab drag drop zone overlay if dragging a tab
         let drag_state_clone = self.active_window().mouse_state.dragging_tab.clone();
         if let Some(ref drag_state) = drag_state_clone {
@@ -957,9 +981,12 @@ impl Editor {
         //
         // When a prompt is active the prompt renderer already placed the
         // caret on the prompt line via `frame.set_cursor_position`; don't
-        // override it with the (now-irrelevant) buffer cursor.
+        // override it with the (now-irrelevant) buffer cursor. An IME
+        // composition in progress is drawn at the caret, which moves to
+        // its end.
         if let Some((cx, cy)) = pending_hardware_cursor {
             if self.active_window().prompt.is_none() && !self.cursor_obscured_by_overlay(cx, cy) {
+                let (cx, cy) = self.render_ime_preedit(frame, cx, cy);
                 frame.set_cursor_position((cx, cy));
             }
         }
@@ -969,6 +996,9 @@ impl Editor {
             frame.buffer_mut(),
             self.color_capability,
         );
+        if !self.terminal_capabilities.renders_styled_underlines() {
+            crate::view::color_support::strip_styled_underlines(frame.buffer_mut());
+        }
 
         // Frame-buffer animations run last so they mutate the final paint.
         self.active_window_mut()
@@ -985,6 +1015,18 @@ impl Editor {
             top_is_trust_modal,
             &theme_clone,
         );
+
+        // Performance HUD, above everything so it stays readable.
+        if self.is_perf_hud_visible() && !self.suppress_chrome_cells {
+            let rows = self.perf_hud_rows();
+            crate::view::perf_hud::render_perf_hud(frame, chrome_area, &rows, &theme_clone);
+        }
+
+        // Everything is painted: hand the finished frame to Dump Screen /
+        // --screenshot-on-exit.
+        self.capture_rendered_frame(frame.buffer_mut());
+
+        self.record_frame(frame_started);
     }
 
     /// Render the search-options bar into `area` when `show_search_options`
@@ -1021,6 +1063,7 @@ impl Editor {
                 Some(HoverTarget::SearchOptionWholeWord) => SearchOptionsHover::WholeWord,
                 Some(HoverTarget::SearchOptionRegex) => SearchOptionsHover::Regex,
                 Some(HoverTarget::SearchOptionConfirmEach) => SearchOptionsHover::ConfirmEach,
+                Some(HoverTarget::SearchOptionMultiline) => SearchOptionsHover::Multiline,
                 _ => SearchOptionsHover::None,
             };
 
@@ -1030,6 +1073,7 @@ impl Editor {
                 self.active_window().search_case_sensitive,
                 self.active_window().search_whole_word,
                 self.active_window().search_use_regex,
+                self.active_window().search_multiline,
                 confirm_each,
                 theme,
                 keybindings,
@@ -1054,7 +1098,7 @@ impl Editor {
         if let Some(prompt) = prompt {
             if !prompt.overlay {
                 // Use specialized renderer for file/folder open prompt to show colorized path
-                if matches!(
+                let caret = if matches!(
                     prompt.prompt_type,
                     crate::view::prompt::PromptType::OpenFile
                         | crate::view::prompt::PromptType::SwitchProject
@@ -1066,12 +1110,16 @@ impl Editor {
                             prompt,
                             file_open_state,
                             theme,
-                        );
+                        )
                     } else {
-                        StatusBarRenderer::render_prompt(frame, area, prompt, theme);
+                        StatusBarRenderer::render_prompt(frame, area, prompt, theme)
                     }
                 } else {
-                    StatusBarRenderer::render_prompt(frame, area, prompt, theme);
+                    StatusBarRenderer::render_prompt(frame, area, prompt, theme)
+                };
+                if let Some((x, y)) = caret {
+                    let caret = self.render_ime_preedit_within(frame, x, y, area.right());
+                    frame.set_cursor_position(caret);
                 }
             }
         }
@@ -1251,6 +1299,34 @@ impl Editor {
         // Store popup areas for mouse hit testing
         self.active_chrome_mut().popup_areas = popup_info.clone();
 
+        // An image popup on top gets its picture drawn over its content
+        // area once the frame is out.
+        let image_placement = self
+            .active_state()
+            .popups
+            .top()
+            .zip(popup_info.last())
+            .and_then(|(popup, (_, _, inner, ..))| match &popup.content {
+                crate::view::popup::PopupContent::Image { image, cols, rows }
+                    if !self.suppress_chrome_cells =>
+                {
+                    // Centered across the content area, which a long
+                    // title may have made wider than the image.
+                    let width = (*cols).min(inner.width);
+                    Some(super::preview_actions::ImagePlacement {
+                        area: ratatui::layout::Rect {
+                            x: inner.x + (inner.width - width) / 2,
+                            y: inner.y,
+                            width,
+                            height: (*rows).min(inner.height),
+                        },
+                        image: image.clone(),
+                    })
+                }
+                _ => None,
+            });
+        self.set_image_placement(image_placement);
+
         // Now render popups (cells only when this frontend draws chrome itself;
         // the web renders them natively from `popups_view`, but the area cache
         // above is always populated for hit-routing).
@@ -1263,6 +1339,19 @@ impl Editor {
                 }
             }
         }
+
+        // The completion documentation panel sits beside the completion
+        // popup, which is the topmost one whenever the panel is visible.
+        let docs_area = popup_info.last().and_then(|(_, popup_area, ..)| {
+            self.visible_completion_docs()
+                .and_then(|panel| panel.calculate_area_beside(*popup_area, chrome_area))
+        });
+        self.active_chrome_mut().completion_docs_area = docs_area;
+        if let (true, Some(area), Some(panel)) =
+            (draw_popups, docs_area, self.visible_completion_docs())
+        {
+            panel.render(frame, area, theme_clone);
+        }
     }
 
     /// Draw the software mouse cursor (GPM, which can't paint its own caret on
@@ -1854,6 +1943,8 @@ impl Editor {
                 .get(&active_buf)
                 .map(|m| m.read_only)
                 .unwrap_or(false);
+            let narrowed = self.narrowed_status(active_buf);
+            let editor_mode = self.active_window().editor_mode.clone();
             let is_synthetic_placeholder = self
                 .active_window()
                 .buffer_metadata
@@ -1866,6 +1957,7 @@ impl Editor {
             // Active session's trust level for the always-present `{trust}`
             // indicator — read here (Copy) before the mutable window borrow.
             let workspace_trust_level = self.authority().workspace_trust.level();
+            let jobs_status = crate::app::jobs::compose_jobs_status(&self.jobs);
             // Single window borrow, split into buffers + cursors so the
             // status-bar context can hold both.
             let __active_id = self.active_window;
@@ -1893,6 +1985,7 @@ impl Editor {
                         plugin_status_message: &plugin_status_message,
                         lsp_status: &lsp_status,
                         lsp_indicator_state,
+                        jobs_status: &jobs_status,
                         theme,
                         display_name,
                         keybindings,
@@ -1905,6 +1998,8 @@ impl Editor {
                         remote_connection: remote_connection.as_deref(),
                         session_name: session_name.as_deref(),
                         read_only: is_read_only,
+                        narrowed: narrowed.as_deref(),
+                        editor_mode: editor_mode.as_deref(),
                         remote_state_override: self.remote_indicator_override.as_ref(),
                         remote_reconnect_error: remote_reconnect_error.as_deref(),
                         remote_connecting,
@@ -1936,6 +2031,7 @@ impl Editor {
             status_bar.area = Some((area.y, area.x, area.width));
             status_bar.clickable = status_bar_layout.clickable;
             status_bar.plugin_token_areas = status_bar_layout.plugin_token_areas;
+            status_bar.segment_actions = status_bar_layout.segment_actions;
             status_bar.segments = status_bar_layout.segments;
         }
     }
@@ -2331,6 +2427,9 @@ impl Editor {
     /// was rendered this frame. Used to decide whether the hardware cursor
     /// should be shown or hidden so it does not bleed through a popup.
     fn cursor_obscured_by_overlay(&self, x: u16, y: u16) -> bool {
+        if self.presentation.is_some() {
+            return true;
+        }
         let inside = |rect: ratatui::layout::Rect| -> bool {
             x >= rect.x
                 && x < rect.x.saturating_add(rect.width)
@@ -2804,6 +2903,19 @@ impl Editor {
             .canonicalize(&abs_path)
             .unwrap_or(abs_path);
 
+        // Over a remote link, preview the head of the file from a
+        // bounded read instead of loading all of it (unless the user
+        // already has it open, in which case that buffer is free to show).
+        let remote_preview = self
+            .authority()
+            .filesystem
+            .remote_connection_info()
+            .is_some()
+            && !self
+                .buffers()
+                .iter()
+                .any(|(_, s)| s.buffer.file_path() == Some(abs_path.as_path()));
+
         // If the standalone state already targets this path, just
         // re-seed the cursor and skip the file-load roundtrip.
         let already_target = self
@@ -2811,13 +2923,15 @@ impl Editor {
             .overlay_preview_state
             .as_ref()
             .is_some_and(|st| {
-                self.windows
-                    .get(&self.active_window)
-                    .map(|w| &w.buffers)
-                    .expect("active window present")
-                    .get(&st.buffer_id)
-                    .and_then(|s| s.buffer.file_path())
-                    .is_some_and(|p| p == abs_path.as_path())
+                st.remote_previews.get(&abs_path) == Some(&st.buffer_id)
+                    || self
+                        .windows
+                        .get(&self.active_window)
+                        .map(|w| &w.buffers)
+                        .expect("active window present")
+                        .get(&st.buffer_id)
+                        .and_then(|s| s.buffer.file_path())
+                        .is_some_and(|p| p == abs_path.as_path())
             });
 
         let buffer_id = if already_target {
@@ -2826,6 +2940,16 @@ impl Editor {
                 .as_ref()
                 .unwrap()
                 .buffer_id
+        } else if remote_preview {
+            match self.remote_preview_buffer(&abs_path) {
+                Some(id) => id,
+                None => {
+                    // Still loading (or unreadable): show nothing rather
+                    // than the previous result under this one's name.
+                    self.blank_overlay_preview();
+                    return;
+                }
+            }
         } else {
             // Snapshot whether this path was already known so we can
             // tell "I just loaded it for preview" from "the user had
@@ -2957,6 +3081,8 @@ impl Editor {
                     .resolve_wrap_column_for_buffer(buffer_id),
                 rulers: self.config.editor.rulers.clone(),
                 scroll_offset: self.config.editor.scroll_offset,
+                scroll_past_end: self.config.editor.scroll_past_end,
+                typewriter_scrolling: self.config.editor.typewriter_scrolling,
             });
             let mut loaded_buffers = std::collections::HashSet::new();
             // Whether this *first* preview buffer was newly loaded.
@@ -2977,6 +3103,7 @@ impl Editor {
                     buffer_id,
                     view_state,
                     loaded_buffers,
+                    remote_previews: std::collections::HashMap::new(),
                     blanked: false,
                     centered_byte: None,
                 });
@@ -3006,6 +3133,12 @@ impl Editor {
             }
         }
 
+        if remote_preview {
+            if let Some(state) = self.active_window_mut().overlay_preview_state.as_mut() {
+                state.remote_previews.insert(abs_path.clone(), buffer_id);
+            }
+        }
+
         // Set the cursor to the match position and centre it vertically.
         let byte_offset = self
             .buffers()
@@ -3148,6 +3281,78 @@ impl Editor {
     /// Blank the Live Grep preview pane: it renders just its frame until
     /// the next selectable result. Keeps `overlay_preview_state` (and its
     /// `loaded_buffers` cleanup tracking) intact.
+    /// Buffer showing the head of `path` for the overlay preview on a
+    /// remote workspace, or `None` while the bounded read is still in
+    /// flight (a redraw follows when it lands) or if it failed.
+    fn remote_preview_buffer(&mut self, path: &std::path::Path) -> Option<BufferId> {
+        use crate::services::file_preview::FilePreviewLoader;
+
+        if let Some(&id) = self
+            .active_window()
+            .overlay_preview_state
+            .as_ref()
+            .and_then(|st| st.remote_previews.get(path))
+        {
+            if self.buffers().contains_key(&id) {
+                return Some(id);
+            }
+        }
+
+        let fs = std::sync::Arc::clone(&self.authority().filesystem);
+        let stale = self
+            .file_preview
+            .as_ref()
+            .is_none_or(|loader| !std::sync::Arc::ptr_eq(loader.filesystem(), &fs));
+        if stale {
+            let sender = self.async_bridge.as_ref().map(|bridge| bridge.sender());
+            self.file_preview = Some(FilePreviewLoader::new(fs, move |path| {
+                // A send failure means the editor is shutting down.
+                if let Some(sender) = &sender {
+                    drop(sender.send(
+                        crate::services::async_bridge::AsyncMessage::FilePreviewLoaded { path },
+                    ));
+                }
+            }));
+        }
+        let loader = self.file_preview.as_ref()?;
+        let preview = match loader.get(path) {
+            Some(result) => result.ok()?,
+            None => {
+                loader.request(path);
+                return None;
+            }
+        };
+
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        let mut text = preview.text.clone();
+        if preview.truncated {
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+            text.push_str(&t!(
+                "buffer.preview_truncated",
+                kb = crate::services::file_preview::PREVIEW_BYTES / 1024
+            ));
+        }
+        let window = self.active_window_mut();
+        let buffer_id = window.create_virtual_buffer_detached(name, "normal".to_string(), true);
+        if let Some(meta) = window.buffer_metadata.get_mut(&buffer_id) {
+            meta.hidden_from_tabs = true;
+        }
+        window
+            .set_virtual_buffer_content(
+                buffer_id,
+                vec![crate::primitives::text_property::TextPropertyEntry::text(
+                    text,
+                )],
+            )
+            .ok()?;
+        Some(buffer_id)
+    }
+
     fn blank_overlay_preview(&mut self) {
         if let Some(state) = self.active_window_mut().overlay_preview_state.as_mut() {
             state.blanked = true;
@@ -3497,7 +3702,13 @@ impl Editor {
             + str_width(&prompt.input[..prompt.cursor_pos.min(prompt.input.len())]))
             as u16;
         if draw && input_focused && cursor_x < input_row.width {
-            frame.set_cursor_position((input_row.x + cursor_x, input_row.y));
+            let caret = self.render_ime_preedit_within(
+                frame,
+                input_row.x + cursor_x,
+                input_row.y,
+                input_row.right(),
+            );
+            frame.set_cursor_position(caret);
         }
 
         // Optional toolbar row (the styled segments the plugin set
@@ -4208,7 +4419,7 @@ impl Editor {
                     false, // lsp_waiting — not relevant for layout
                     self.config.editor.estimated_line_length,
                     self.config.editor.highlight_context_bytes,
-                    self.config.editor.relative_line_numbers,
+                    crate::view::margin::GutterSettings::from_config(&self.config.editor),
                     self.config.editor.use_terminal_bg,
                     self.session_mode || !self.software_cursor_only,
                     self.software_cursor_only,
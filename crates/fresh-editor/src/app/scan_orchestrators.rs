@@ -217,7 +217,7 @@ impl Editor {
         let Some(finished) = self.active_window_mut().line_scan.take_finished() else {
             return;
         };
-        self.set_status_message(t!("goto.scan_failed", error = e.to_string()).to_string());
+        self.set_status_error(t!("goto.scan_failed", error = e.to_string()).to_string());
         if finished.open_goto_line {
             self.open_goto_line_if_active(finished.buffer_id);
         }
@@ -244,7 +244,7 @@ impl Editor {
         if let Err(e) = self.process_search_scan_batch(buffer_id) {
             tracing::warn!("Search scan error: {e}");
             self.active_window_mut().search_scan.abandon();
-            self.set_status_message(format!("Search failed: {e}"));
+            self.set_status_error(format!("Search failed: {e}"));
             return true;
         }
 
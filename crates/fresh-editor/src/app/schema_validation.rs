@@ -0,0 +1,180 @@
+//! Background JSON-schema validation of JSON/YAML buffers.
+//!
+//! Edits, opens and saves schedule a debounced validation; the main loop's
+//! tick fires due ones on a worker thread, which posts the results back as
+//! [`AsyncMessage::SchemaDiagnostics`]. Each validation is tagged with a
+//! generation so a slow, superseded run can't overwrite newer results. See
+//! [`crate::services::json_schema`].
+
+use super::window::Window;
+use super::Editor;
+use crate::services::async_bridge::AsyncMessage;
+use crate::services::json_schema::{self, document::DocumentFormat, ValidationRequest};
+use fresh_core::{BufferId, WindowId};
+
+impl Window {
+    /// Schedule a schema validation for a buffer (debounced). No-op when
+    /// `json_schemas.enabled` is off or the buffer isn't JSON/YAML.
+    pub fn schedule_schema_validation(&mut self, buffer_id: BufferId) {
+        const SCHEMA_VALIDATION_DEBOUNCE_MS: u64 = 500;
+        if !self.resources.config.json_schemas.enabled {
+            return;
+        }
+        let Some(state) = self.buffers.get(&buffer_id) else {
+            return;
+        };
+        if DocumentFormat::for_language(&state.language).is_none() {
+            return;
+        }
+        let next_time = std::time::Instant::now()
+            + std::time::Duration::from_millis(SCHEMA_VALIDATION_DEBOUNCE_MS);
+        self.schema_validation_debounce.insert(buffer_id, next_time);
+    }
+
+    /// Start every schema validation whose debounce has elapsed.
+    pub fn check_schema_validation_timer(&mut self) {
+        if self.schema_validation_debounce.is_empty() {
+            return;
+        }
+        let now = std::time::Instant::now();
+        let due: Vec<BufferId> = self
+            .schema_validation_debounce
+            .iter()
+            .filter(|(_, at)| now >= **at)
+            .map(|(id, _)| *id)
+            .collect();
+        for buffer_id in due {
+            self.schema_validation_debounce.remove(&buffer_id);
+            self.spawn_schema_validation(buffer_id);
+        }
+    }
+
+    /// Snapshot what a background schema job needs for a buffer. `None`
+    /// unless the buffer is a JSON/YAML file.
+    fn schema_request(&self, buffer_id: BufferId) -> Option<ValidationRequest> {
+        let state = self.buffers.get(&buffer_id)?;
+        let format = DocumentFormat::for_language(&state.language)?;
+        Some(ValidationRequest {
+            text: state.buffer.to_string()?,
+            path: state.buffer.file_path()?.to_path_buf(),
+            format,
+            config: self.resources.config.json_schemas.clone(),
+            working_dir: self.root.clone(),
+            schemas_dir: self.resources.dir_context.schemas_dir(),
+            trusted: self.authority().workspace_trust.level()
+                == crate::services::workspace_trust::TrustLevel::Trusted,
+        })
+    }
+
+    /// Look up the schema documentation at byte `offset` of a buffer on a
+    /// worker thread. The answer comes back as hover response `request_id`,
+    /// so it merges with the language servers' hovers. Returns false when
+    /// there is no schema to consult.
+    pub(super) fn spawn_schema_hover(
+        &self,
+        buffer_id: BufferId,
+        offset: usize,
+        request_id: u64,
+    ) -> bool {
+        if !self.resources.config.json_schemas.enabled {
+            return false;
+        }
+        let Some(request) = self.schema_request(buffer_id) else {
+            return false;
+        };
+        let sender = self.bridge.sender();
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = std::thread::Builder::new()
+            .name("json-schema-hover".to_string())
+            .spawn(move || {
+                let (contents, range) = match json_schema::hover_document(&request, offset) {
+                    Some((contents, range)) => (
+                        contents,
+                        Some((
+                            (range.start.line, range.start.character),
+                            (range.end.line, range.end.character),
+                        )),
+                    ),
+                    // An empty hover counts as "nothing here".
+                    None => (String::new(), None),
+                };
+                // Receiver gone means the editor is shutting down.
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = sender.send(AsyncMessage::LspHover {
+                    request_id,
+                    contents,
+                    is_markdown: true,
+                    range,
+                });
+            });
+        true
+    }
+
+    fn spawn_schema_validation(&mut self, buffer_id: BufferId) {
+        let Some(uri) = self
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|m| m.file_uri())
+            .map(|u| u.as_str().to_string())
+        else {
+            return;
+        };
+        let Some(request) = self.schema_request(buffer_id) else {
+            return;
+        };
+        self.next_schema_validation_generation += 1;
+        let generation = self.next_schema_validation_generation;
+        self.schema_validation_in_flight
+            .insert(buffer_id, generation);
+        let window = self.id;
+        let sender = self.bridge.sender();
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = std::thread::Builder::new()
+            .name("json-schema".to_string())
+            .spawn(move || {
+                let diagnostics = json_schema::validate_document(&request);
+                // Receiver gone means the editor is shutting down.
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = sender.send(AsyncMessage::SchemaDiagnostics {
+                    window,
+                    buffer_id,
+                    generation,
+                    uri,
+                    diagnostics,
+                });
+            });
+    }
+}
+
+impl Editor {
+    /// Store a schema validation's results in the window that started it,
+    /// unless a newer validation of the same buffer has started since.
+    pub(super) fn handle_schema_diagnostics(
+        &mut self,
+        window_id: WindowId,
+        buffer_id: BufferId,
+        generation: u64,
+        uri: String,
+        diagnostics: Vec<lsp_types::Diagnostic>,
+    ) {
+        let Some(window) = self.windows.get_mut(&window_id) else {
+            return;
+        };
+        let in_flight = &mut window.schema_validation_in_flight;
+        if in_flight.get(&buffer_id) != Some(&generation) {
+            tracing::trace!(
+                "json-schema: dropping stale results for {:?} (generation {})",
+                buffer_id,
+                generation
+            );
+            return;
+        }
+        in_flight.remove(&buffer_id);
+        self.store_push_diagnostics_in(
+            window_id,
+            uri,
+            json_schema::DIAGNOSTIC_SOURCE.to_string(),
+            diagnostics,
+        );
+    }
+}
@@ -0,0 +1,104 @@
+//! Scratch buffers: named, unnamed-on-disk buffers for throwaway notes.
+//!
+//! A scratch buffer is an ordinary unnamed buffer with
+//! [`BufferMetadata::scratch`](super::types::BufferMetadata::scratch) set.
+//! Quitting never asks for a path for it — its content is stored in the
+//! workspace file (see `Window::capture_workspace`) and comes back with the
+//! session. Saving it (Save / Save As) turns it into a normal file buffer.
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::model::event::BufferId;
+use crate::primitives::detected_language::DetectedLanguage;
+use crate::view::prompt::PromptType;
+
+impl Editor {
+    /// Open the "New scratch buffer:" language picker.
+    pub(super) fn start_new_scratch_buffer_prompt(&mut self) {
+        let (suggestions, _) = self.language_suggestions("");
+        self.active_window_mut().prompt = Some(crate::view::prompt::Prompt::with_suggestions(
+            t!("prompt.new_scratch_buffer").to_string(),
+            PromptType::NewScratchBuffer,
+            suggestions,
+        ));
+        if let Some(prompt) = self.active_window_mut().prompt.as_mut() {
+            if !prompt.suggestions.is_empty() {
+                prompt.selected_suggestion = Some(0);
+            }
+        }
+    }
+
+    /// Create a scratch buffer highlighted as `language` (a syntax name as
+    /// shown in the language picker; empty or "Plain Text" for none) and
+    /// switch to it. Returns `None` when the language is unknown.
+    pub fn new_scratch_buffer(&mut self, language: &str) -> Option<BufferId> {
+        let language = language.trim();
+        let detected = if language.is_empty()
+            || language == "Plain Text"
+            || language.eq_ignore_ascii_case("text")
+        {
+            DetectedLanguage::plain_text()
+        } else if let Some(detected) = DetectedLanguage::from_syntax_name(
+            language,
+            &self.grammar_registry,
+            &self.config.languages,
+        ) {
+            detected
+        } else {
+            self.set_status_message(
+                t!("scratch.unknown_language", language = language).to_string(),
+            );
+            return None;
+        };
+
+        let name = self.next_scratch_buffer_name();
+        let buffer_id = self.new_buffer();
+        if let Some(meta) = self.active_window_mut().buffer_metadata.get_mut(&buffer_id) {
+            meta.display_name = name.clone();
+            meta.scratch = true;
+            meta.recovery_id = Some(crate::services::recovery::generate_buffer_id());
+        }
+        let language_name = detected.display_name.clone();
+        let language_id = detected.name.clone();
+        if let Some(state) = self
+            .windows
+            .get_mut(&self.active_window)
+            .map(|w| &mut w.buffers)
+            .expect("active window present")
+            .get_mut(&buffer_id)
+        {
+            state.apply_language(detected);
+        }
+        self.refresh_buffer_config(buffer_id);
+        #[cfg(feature = "plugins")]
+        self.update_plugin_state_snapshot();
+        self.plugin_manager.read().unwrap().run_hook(
+            "language_changed",
+            crate::services::plugins::hooks::HookArgs::LanguageChanged {
+                buffer_id,
+                language: language_id,
+            },
+        );
+
+        self.set_status_message(
+            t!("scratch.created", name = name, language = language_name).to_string(),
+        );
+        Some(buffer_id)
+    }
+
+    /// First "scratch-N" name not used by an open scratch buffer.
+    fn next_scratch_buffer_name(&self) -> String {
+        let taken: std::collections::HashSet<&str> = self
+            .active_window()
+            .buffer_metadata
+            .values()
+            .filter(|meta| meta.scratch)
+            .map(|meta| meta.display_name.as_str())
+            .collect();
+        (1..)
+            .map(|n| format!("scratch-{n}"))
+            .find(|name| !taken.contains(name.as_str()))
+            .expect("unbounded range always yields a free name")
+    }
+}
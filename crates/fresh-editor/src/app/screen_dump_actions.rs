@@ -0,0 +1,94 @@
+//! Dump Screen To File and `--screenshot-on-exit`.
+//!
+//! A dump is written from the end of [`Editor::render`], once every layer
+//! (popups, prompts, modals) has been painted, so the file holds exactly
+//! what the terminal shows.
+
+use std::path::{Path, PathBuf};
+
+use ratatui::buffer::Buffer;
+use rust_i18n::t;
+
+use crate::view::prompt::PromptType;
+use crate::view::screen_dump::{dump_buffer, ScreenDumpFormat};
+
+use super::Editor;
+
+/// Pre-filled target of the Dump Screen To File prompt, relative to the
+/// working directory.
+const DEFAULT_SCREEN_DUMP_FILE: &str = "fresh-screen.txt";
+
+impl Editor {
+    /// Ask where to write the screen dump (`.ans` for ANSI colors).
+    pub(super) fn start_dump_screen_prompt(&mut self) {
+        self.start_prompt_with_initial_text(
+            t!("view.screen_dump_prompt").to_string(),
+            PromptType::DumpScreen,
+            DEFAULT_SCREEN_DUMP_FILE.to_string(),
+        );
+    }
+
+    /// Queue a dump of the next rendered frame to `input` (relative paths
+    /// resolve against the working directory).
+    pub(super) fn handle_dump_screen(&mut self, input: &str) {
+        let input = input.trim();
+        if input.is_empty() {
+            return;
+        }
+        let path = Path::new(input);
+        let path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.working_dir().join(path)
+        };
+        self.pending_screen_dump = Some(path);
+    }
+
+    /// Write a dump queued by [`Editor::handle_dump_screen`], and keep a
+    /// copy of the frame when `--screenshot-on-exit` is set. Called with
+    /// the finished frame at the end of every render.
+    pub(super) fn capture_rendered_frame(&mut self, buffer: &Buffer) {
+        if self.screenshot_on_exit.is_some() {
+            self.last_rendered_frame = Some(buffer.clone());
+        }
+        let Some(path) = self.pending_screen_dump.take() else {
+            return;
+        };
+        let dump = dump_buffer(buffer, ScreenDumpFormat::from_path(&path));
+        let result = self
+            .authority()
+            .filesystem
+            .write_file(&path, dump.as_bytes());
+        let message = match result {
+            Ok(()) => t!(
+                "view.screen_dump_written",
+                path = path.display().to_string()
+            ),
+            Err(e) => t!(
+                "view.screen_dump_failed",
+                path = path.display().to_string(),
+                error = e.to_string()
+            ),
+        };
+        self.set_status_message(message.to_string());
+    }
+
+    /// Write the last rendered frame to `path` when the editor exits
+    /// (`--screenshot-on-exit`).
+    pub fn set_screenshot_on_exit(&mut self, path: PathBuf) {
+        self.screenshot_on_exit = Some(path);
+    }
+
+    /// Write the `--screenshot-on-exit` file, if one was requested and a
+    /// frame has been rendered. Uses the local filesystem: the path comes
+    /// from the command line, not from the (possibly remote) workspace.
+    pub fn write_exit_screenshot(&mut self) -> std::io::Result<()> {
+        let (Some(path), Some(frame)) = (
+            self.screenshot_on_exit.as_ref(),
+            self.last_rendered_frame.as_ref(),
+        ) else {
+            return Ok(());
+        };
+        std::fs::write(path, dump_buffer(frame, ScreenDumpFormat::from_path(path)))
+    }
+}
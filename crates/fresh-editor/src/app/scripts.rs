@@ -0,0 +1,221 @@
+//! User scripts (see [`crate::services::scripting`]) wired into the editor.
+//!
+//! Scripts load when the editor starts and again on "Scripts: Reload".
+//! Their commands go into the palette as `script:<file>.<function>` plugin
+//! actions, which `handle_action` hands to [`Editor::run_script_command`]
+//! before the plugin runtime sees them. Event handlers run from the same
+//! places as the `hooks` config (see [`Editor::run_event_hooks`]), plus
+//! editor mode changes.
+//!
+//! A call's edits land as one bulk edit on the active buffer, so a script
+//! command undoes in a single step. Buffers that aren't active (a hook for
+//! a file saved by "Save All") are edited through the plugin command path.
+
+use rust_i18n::t;
+
+use super::Editor;
+use crate::config::HookEvent;
+use crate::input::commands::{Command, CommandSource};
+use crate::input::keybindings::Action;
+use crate::model::event::{CursorId, Event};
+use crate::services::scripting::{
+    replacement, ScriptBuffer, ScriptEffects, ScriptHook, ScriptHost, ScriptRequest, SCRIPTS_DIR,
+};
+use fresh_core::api::PluginCommand;
+use fresh_core::BufferId;
+
+/// The script hook for a config hook event, if scripts handle it.
+pub(super) fn script_hook(event: HookEvent) -> Option<ScriptHook> {
+    match event {
+        HookEvent::Open => Some(ScriptHook::Open),
+        HookEvent::Save => Some(ScriptHook::Save),
+        HookEvent::BufferSwitch => Some(ScriptHook::BufferSwitch),
+        HookEvent::Idle | HookEvent::Diagnostics => None,
+    }
+}
+
+impl Editor {
+    /// Load the scripts from the config dir, replacing the commands of any
+    /// earlier load. Failures are reported in the status bar; with
+    /// `announce`, so is a clean load.
+    pub(crate) fn load_user_scripts(&mut self, announce: bool) {
+        {
+            let registry = self.command_registry.read().unwrap();
+            for name in self.scripts.script_names() {
+                registry.unregister_by_plugin(name);
+            }
+        }
+
+        let mut host = ScriptHost::new();
+        let mut errors = host.load_dir(&self.dir_context.config_dir.join(SCRIPTS_DIR));
+        {
+            let registry = self.command_registry.read().unwrap();
+            for command in host.commands() {
+                let registered = registry.try_register(Command {
+                    name: command.name.clone(),
+                    description: command.description.clone(),
+                    action: Action::PluginAction(command.action_name()),
+                    contexts: vec![],
+                    custom_contexts: vec![],
+                    source: CommandSource::Plugin(command.script.clone()),
+                    terminal_bypass: false,
+                    context_menu: false,
+                });
+                if registered.is_err() {
+                    errors.push((
+                        command.script.clone(),
+                        t!("scripts.command_taken", command = command.name).to_string(),
+                    ));
+                }
+            }
+        }
+        let loaded = host.script_names().count();
+        self.scripts = host;
+
+        for (script, error) in &errors {
+            tracing::warn!("script {script}: {error}");
+        }
+        if let Some((script, error)) = errors.first() {
+            self.set_status_message(
+                t!("scripts.load_failed", script = script, error = error).to_string(),
+            );
+        } else if announce {
+            self.set_status_message(t!("scripts.loaded", count = loaded).to_string());
+        }
+    }
+
+    /// Run the script command registered under `action` against the active
+    /// buffer. Returns `false` when `action` isn't a script command.
+    pub(crate) fn run_script_command(&mut self, action: &str) -> bool {
+        let Some(command) = self.scripts.command_for_action(action).cloned() else {
+            return false;
+        };
+        let buffer_id = self.active_buffer();
+        let Some(buffer) = self.script_buffer(buffer_id) else {
+            return true;
+        };
+        match self.scripts.run_command(&command, buffer.clone()) {
+            Ok(effects) => {
+                self.apply_script_effects(buffer_id, &buffer.text, effects, &command.name)
+            }
+            Err(error) => self.report_script_error(&command.script, &error),
+        }
+        true
+    }
+
+    /// Run every script's handler for `hook` against `buffer_id`, passing
+    /// `arg` (a file path, or the new mode).
+    pub(crate) fn run_script_hooks(&mut self, hook: ScriptHook, buffer_id: BufferId, arg: &str) {
+        if !self.scripts.has_hook(hook) {
+            return;
+        }
+        let Some(buffer) = self.script_buffer(buffer_id) else {
+            return;
+        };
+        let (effects, errors) = self.scripts.run_hook(hook, arg, buffer.clone());
+        self.apply_script_effects(buffer_id, &buffer.text, effects, hook.function_name());
+        for (script, error) in errors {
+            self.report_script_error(&script, &error);
+        }
+    }
+
+    /// Snapshot of `buffer_id` for a script call. The cursor is the primary
+    /// cursor when the buffer is active, and 0 otherwise.
+    fn script_buffer(&mut self, buffer_id: BufferId) -> Option<ScriptBuffer> {
+        let cursor = if buffer_id == self.active_buffer() {
+            self.active_cursors().primary().position
+        } else {
+            0
+        };
+        let mode = self.active_window().editor_mode.clone().unwrap_or_default();
+        let state = self.active_window_mut().buffers.get_mut(&buffer_id)?;
+        let text = state.get_text_range(0, state.buffer.len());
+        let path = state
+            .buffer
+            .file_path()
+            .map(|p| p.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Some(ScriptBuffer {
+            text,
+            path,
+            cursor,
+            mode,
+        })
+    }
+
+    /// Apply what a call left behind: the edit that turns `old_text` into
+    /// the script's text, then the cursor move, then its requests.
+    fn apply_script_effects(
+        &mut self,
+        buffer_id: BufferId,
+        old_text: &str,
+        effects: ScriptEffects,
+        description: &str,
+    ) {
+        let mut commands = Vec::new();
+        if let Some((range, text)) = effects
+            .text
+            .as_deref()
+            .and_then(|new_text| replacement(old_text, new_text))
+        {
+            if buffer_id == self.active_buffer() {
+                // No cursor is tied to the edit: cursors shift with the text
+                // around them, as for any bulk edit.
+                let mut events = Vec::new();
+                if !range.is_empty() {
+                    events.push(Event::Delete {
+                        deleted_text: old_text[range.clone()].to_string(),
+                        range: range.clone(),
+                        cursor_id: CursorId::UNDO_SENTINEL,
+                    });
+                }
+                if !text.is_empty() {
+                    events.push(Event::Insert {
+                        position: range.start,
+                        text: text.to_string(),
+                        cursor_id: CursorId::UNDO_SENTINEL,
+                    });
+                }
+                if let Some(bulk_edit) =
+                    self.apply_events_as_bulk_edit(events, format!("Script: {description}"))
+                {
+                    self.active_event_log_mut().append(bulk_edit);
+                }
+            } else {
+                if !range.is_empty() {
+                    commands.push(PluginCommand::DeleteRange {
+                        buffer_id,
+                        range: range.clone(),
+                    });
+                }
+                if !text.is_empty() {
+                    commands.push(PluginCommand::InsertText {
+                        buffer_id,
+                        position: range.start,
+                        text: text.to_string(),
+                    });
+                }
+            }
+        }
+        if let Some(position) = effects.cursor {
+            commands.push(PluginCommand::SetBufferCursor {
+                buffer_id,
+                position,
+            });
+        }
+        commands.extend(effects.requests.into_iter().map(|request| match request {
+            ScriptRequest::SetStatus(message) => PluginCommand::SetStatus { message },
+            ScriptRequest::RunAction(action_name) => PluginCommand::ExecuteAction { action_name },
+        }));
+        for command in commands {
+            if let Err(e) = self.handle_plugin_command(command) {
+                tracing::warn!("script command failed: {e}");
+            }
+        }
+    }
+
+    fn report_script_error(&mut self, script: &str, error: &str) {
+        tracing::warn!("script {script}: {error}");
+        self.set_status_error(t!("scripts.error", script = script, error = error).to_string());
+    }
+}
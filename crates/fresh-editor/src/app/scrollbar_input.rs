@@ -5,11 +5,32 @@
 //! scrollbar (used in unified diff views). Pure scrollbar math lives in
 //! `super::scrollbar_math`; these methods do the side-effecting work of
 //! mutating viewports and split state.
+//!
+//! With `smooth_scroll` on, a wheel scroll of a buffer split is not applied
+//! at once: it is queued as a [`SmoothScroll`] and played out by
+//! [`Window::step_smooth_scroll`](crate::app::window::Window::step_smooth_scroll)
+//! from the editor tick, one eased step per frame.
+
+use std::time::{Duration, Instant};
 
 use anyhow::Result as AnyhowResult;
 
 use crate::model::event::{BufferId, LeafId};
 
+/// Time between the frames of a smooth scroll.
+const SMOOTH_SCROLL_FRAME: Duration = Duration::from_millis(16);
+
+/// A wheel scroll being played out over several frames.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SmoothScroll {
+    leaf_id: LeafId,
+    buffer_id: BufferId,
+    /// Lines still to scroll; negative scrolls up.
+    remaining: i32,
+    /// When the next step is due.
+    next_step: Instant,
+}
+
 impl crate::app::window::Window {
     /// Handle mouse wheel scroll event
     pub(super) fn handle_mouse_scroll(
@@ -101,6 +122,73 @@ impl crate::app::window::Window {
             return Ok(());
         }
 
+        if self.config().editor.smooth_scroll {
+            self.queue_smooth_scroll(target_split, buffer_id, delta);
+            return Ok(());
+        }
+        self.scroll_buffer_split(target_split, buffer_id, delta);
+
+        Ok(())
+    }
+
+    /// Add a wheel scroll to the running smooth scroll, or start a new one,
+    /// and play its first step right away. A scroll of another split or in
+    /// the other direction first finishes the running one.
+    fn queue_smooth_scroll(&mut self, leaf_id: LeafId, buffer_id: BufferId, delta: i32) {
+        let now = self.resources.time_source.now();
+        let scroll = match self.smooth_scroll.take() {
+            Some(mut running)
+                if running.leaf_id == leaf_id
+                    && running.buffer_id == buffer_id
+                    && running.remaining.signum() == delta.signum() =>
+            {
+                running.remaining += delta;
+                running
+            }
+            other => {
+                if let Some(running) = other {
+                    self.scroll_buffer_split(running.leaf_id, running.buffer_id, running.remaining);
+                }
+                SmoothScroll {
+                    leaf_id,
+                    buffer_id,
+                    remaining: delta,
+                    next_step: now,
+                }
+            }
+        };
+        self.smooth_scroll = Some(scroll);
+        self.step_smooth_scroll();
+    }
+
+    /// Play the next frame of the running smooth scroll, if it is due.
+    /// Each frame covers half the remaining distance (at least one line),
+    /// so the scroll starts fast and eases out. Returns true if the
+    /// viewport moved.
+    pub(crate) fn step_smooth_scroll(&mut self) -> bool {
+        let Some(mut scroll) = self.smooth_scroll else {
+            return false;
+        };
+        let now = self.resources.time_source.now();
+        if now < scroll.next_step {
+            return false;
+        }
+        let step = (scroll.remaining + scroll.remaining.signum()) / 2;
+        scroll.remaining -= step;
+        scroll.next_step = now + SMOOTH_SCROLL_FRAME;
+        self.smooth_scroll = (scroll.remaining != 0).then_some(scroll);
+        self.scroll_buffer_split(scroll.leaf_id, scroll.buffer_id, step);
+        true
+    }
+
+    /// When the running smooth scroll needs its next frame, if one is running.
+    pub(crate) fn smooth_scroll_deadline(&self) -> Option<Instant> {
+        self.smooth_scroll.map(|scroll| scroll.next_step)
+    }
+
+    /// Scroll a buffer split's viewport by `delta` lines, following its
+    /// view transform when a plugin has set one.
+    fn scroll_buffer_split(&mut self, target_split: LeafId, buffer_id: BufferId, delta: i32) {
         // Get view_transform tokens from SplitViewState (if any)
         let view_transform_tokens = self
             .buffers
@@ -119,8 +207,6 @@ impl crate::app::window::Window {
             view_transform_tokens,
             tab_size,
         );
-
-        Ok(())
     }
 
     /// Handle horizontal scroll (Shift+ScrollWheel or native ScrollLeft/ScrollRight)
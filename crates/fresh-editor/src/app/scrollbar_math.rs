@@ -16,24 +16,23 @@
 //! index; subsequent calls (the steady state during a drag) are pure
 //! lookups.
 
-use crate::model::buffer::Buffer;
 use crate::primitives::line_wrapping::WrapConfig;
 use crate::state::EditorState;
 use crate::view::line_wrap_cache::CacheViewMode;
 use crate::view::visual_row_index::{ensure_built, VisualRowIndexKey};
 
 /// Width estimate of the gutter, used to build the wrap config. Kept in
-/// sync with the real gutter sizing in the render path (indicator +
-/// digits + separator) — see `Viewport::gutter_width`, which uses the same
-/// formula with `MIN_LINE_NUMBER_DIGITS` as the floor.  Returns 0 when
+/// sync with the real gutter sizing in the render path (signs + digits +
+/// separator) — see `Viewport::gutter_width`, which uses the same
+/// `GutterShape` formula.  Returns 0 when
 /// `show_line_numbers` is false (compose mode etc.) — the renderer's
 /// `state.margins.left_total_width()` returns 0 there too, and any
 /// divergence makes scroll math wrap at a different column than the
 /// renderer.
-fn estimated_gutter_width(buffer: &Buffer, _show_line_numbers: bool) -> usize {
-    let line_count = buffer.line_count().unwrap_or(1);
+fn estimated_gutter_width(state: &EditorState, _show_line_numbers: bool) -> usize {
+    let line_count = state.buffer.line_count().unwrap_or(1);
     let digits = (line_count as f64).log10().floor() as usize + 1;
-    1 + digits.max(crate::view::margin::MIN_LINE_NUMBER_DIGITS) + 3
+    state.margins.left_config.shape().width(digits)
 }
 
 /// Build the `VisualRowIndexKey` scroll math uses for these viewport
@@ -72,7 +71,7 @@ fn ensure_index(
         ensure_built(state, &key);
         return;
     }
-    let gutter_width = estimated_gutter_width(&state.buffer, show_line_numbers);
+    let gutter_width = estimated_gutter_width(state, show_line_numbers);
     let wrap_config = WrapConfig::new(wrap_width, gutter_width, true, true);
     let effective_width = wrap_config
         .first_line_width
@@ -40,6 +40,64 @@ impl Editor {
             .update_search_highlights(query, search_fg, search_bg);
     }
 
+    /// Show where the query being typed stands in the buffer, as "Match 3
+    /// of 17" counting from the first match at or after the cursor. Large
+    /// files are skipped, since the count needs a full scan per keystroke.
+    pub(super) fn update_incremental_match_count(&mut self, query: &str) {
+        if query.is_empty() || self.active_state().buffer.is_large_file() {
+            return;
+        }
+        let Ok(regex) = self.active_window().build_search_regex(query) else {
+            return;
+        };
+        let search_range = self.active_window().pending_search_range.clone();
+        let cursor_pos = self.active_cursors().primary().position;
+        let buffer_content = {
+            let state = self.active_state_mut();
+            let total_bytes = state.buffer.len();
+            match state.buffer.get_text_range_mut(0, total_bytes) {
+                Ok(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+                Err(_) => return,
+            }
+        };
+        let range = search_range.unwrap_or(0..buffer_content.len());
+        let Some(search_slice) = buffer_content.get(range.clone()) else {
+            return;
+        };
+
+        let mut total = 0;
+        let mut current = None;
+        let mut capped = false;
+        for m in regex.find_iter(search_slice) {
+            if total >= SearchState::MAX_MATCHES {
+                capped = true;
+                break;
+            }
+            if current.is_none() && range.start + m.start() >= cursor_pos {
+                current = Some(total);
+            }
+            total += 1;
+        }
+
+        if total == 0 {
+            self.set_status_message(t!("search.no_occurrences", search = query).to_string());
+            return;
+        }
+        let total = if capped {
+            format!("{}+", total)
+        } else {
+            total.to_string()
+        };
+        self.set_status_message(
+            t!(
+                "search.match_of",
+                current = current.unwrap_or(0) + 1,
+                total = total
+            )
+            .to_string(),
+        );
+    }
+
     /// Perform a search and update search state.
     ///
     /// For large files (lazy-loaded buffers), this starts an incremental
@@ -179,7 +237,10 @@ impl Editor {
             capped,
         });
 
-        if is_large {
+        if !self.config.editor.keep_search_highlights {
+            // Highlights end with the prompt; F3 navigates `matches` instead.
+            self.active_window_mut().clear_search_overlays();
+        } else if is_large {
             // Large file: viewport-only overlays to avoid O(matches) memory
             self.refresh_search_overlays();
         } else {
@@ -268,10 +329,14 @@ impl Editor {
         // scrolling in check_search_overlay_refresh().
         self.active_window_mut().search_overlay_top_byte = Some(top_byte);
 
+        let keep_highlights = self.config.editor.keep_search_highlights;
         let state = self.active_state_mut();
 
         // Clear existing search overlays
         state.overlays.clear_namespace(&ns, &mut state.marker_list);
+        if !keep_highlights {
+            return;
+        }
 
         // Walk visible lines to find the visible byte range
         let visible_start = top_byte;
@@ -370,6 +435,7 @@ impl Editor {
         // Pre-snapshot per-window search settings before taking the &mut
         // borrow on self.windows below.
         let case_sensitive = self.active_window().search_case_sensitive;
+        let multiline = self.active_window().search_multiline;
         if let Some(state) = self
             .windows
             .get_mut(&self.active_window)
@@ -386,6 +452,7 @@ impl Editor {
                 .case_insensitive(!case_sensitive)
                 .multi_line(true)
                 .crlf(true)
+                .dot_matches_new_line(multiline)
                 .build()
                 .expect("regex already validated");
             let scan = state.buffer.search_scan_init(
@@ -772,6 +839,7 @@ impl Editor {
             self.active_window().search_use_regex,
             self.active_window().search_whole_word,
             self.active_window().search_case_sensitive,
+            self.active_window().search_multiline,
         )
     }
 
@@ -813,6 +881,12 @@ impl Editor {
         }
 
         let compiled_regex = self.build_replace_regex(search);
+        // Plain-text matching looks for the query's literal text, which in
+        // multiline mode has its `\n`-style escapes applied.
+        let literal = super::regex_replace::literal_search_text(
+            search,
+            self.active_window().search_multiline,
+        );
 
         // Find all matches first (before making any modifications)
         // Each match is (position, length, expanded_replacement)
@@ -844,12 +918,12 @@ impl Editor {
 
             while current_pos < buffer_len {
                 if let Some(offset) = state.buffer.find_next_in_range(
-                    search,
+                    &literal,
                     current_pos,
                     Some(current_pos..buffer_len),
                 ) {
-                    matches.push((offset, search.len(), replacement.to_string()));
-                    current_pos = offset + search.len();
+                    matches.push((offset, literal.len(), replacement.to_string()));
+                    current_pos = offset + literal.len();
                 } else {
                     break;
                 }
@@ -923,6 +997,10 @@ impl Editor {
         }
 
         let compiled_regex = self.build_replace_regex(search);
+        let literal = super::regex_replace::literal_search_text(
+            search,
+            self.active_window().search_multiline,
+        );
 
         // Find the first match lazily (don't find all matches upfront)
         let start_pos = self.active_cursors().primary().position;
@@ -951,14 +1029,20 @@ impl Editor {
             (pos, match_len)
         } else {
             let state = self.active_state();
-            let Some(pos) = state.buffer.find_next(search, start_pos) else {
+            let Some(pos) = state.buffer.find_next(&literal, start_pos) else {
                 self.set_status_message(t!("search.no_occurrences", search = search).to_string());
                 return;
             };
-            (pos, search.len())
+            (pos, literal.len())
         };
 
         // Initialize interactive replace state with just the current match
+        // Plain-text follow-up matches search for `search` as given here.
+        let search = if compiled_regex.is_some() {
+            search
+        } else {
+            &literal
+        };
         self.active_window_mut().interactive_replace_state = Some(InteractiveReplaceState {
             search: search.to_string(),
             replacement: replacement.to_string(),
@@ -0,0 +1,146 @@
+//! Expand Selection To Node / Shrink Selection
+//!
+//! Expanding grows every cursor's selection to the smallest syntax node that
+//! strictly contains it — identifier, expression, statement, function — using
+//! the tree-sitter grammar when the buffer's language has one and
+//! [`enclosing_text_range`] otherwise. Shrinking steps back through the
+//! selections the expansions started from.
+
+use super::Editor;
+use crate::model::event::Event;
+use crate::model::selection_history::SelectionSnapshot;
+use crate::primitives::enclosing_range::enclosing_text_range;
+use crate::primitives::highlighter::Language;
+use fresh_languages::tree_sitter::{Parser, Tree};
+use rust_i18n::t;
+use std::ops::Range;
+
+/// Largest buffer Expand Selection To Node will parse. Every press reparses
+/// from scratch, which stops being instant somewhere past this.
+const MAX_EXPAND_BYTES: usize = 4 * 1024 * 1024;
+
+impl Editor {
+    /// Grow each cursor's selection to the enclosing syntax node.
+    pub fn expand_selection_to_node(&mut self) {
+        let before = self.selection_snapshot();
+        let state = self.active_state_mut();
+        if state.buffer.len() > MAX_EXPAND_BYTES {
+            self.set_status_message(t!("selection.expand_too_large").to_string());
+            return;
+        }
+        let Some(text) = state.buffer.to_string() else {
+            self.set_status_message(t!("error.buffer_not_loaded").to_string());
+            return;
+        };
+        let tree = syntax_tree(state.highlighter.language(), &text);
+
+        let after: SelectionSnapshot = before
+            .iter()
+            .map(|&(cursor_id, anchor, position)| {
+                let range = anchor.map_or(position..position, |anchor| {
+                    anchor.min(position)..anchor.max(position)
+                });
+                let enclosing = tree
+                    .as_ref()
+                    .and_then(|tree| enclosing_node_range(tree, range.clone()))
+                    .or_else(|| enclosing_text_range(&text, range));
+                match enclosing {
+                    Some(range) => (cursor_id, Some(range.start), range.end),
+                    None => (cursor_id, anchor, position),
+                }
+            })
+            .collect();
+        if after == before {
+            return;
+        }
+        self.apply_selection_snapshot(&before, &after);
+        self.active_state_mut()
+            .selection_history
+            .push(before, after);
+    }
+
+    /// Undo the last Expand Selection To Node, if the selections are still
+    /// what it left.
+    pub fn shrink_selection(&mut self) {
+        let now = self.selection_snapshot();
+        let state = self.active_state_mut();
+        let Some(previous) = state.selection_history.pop(&now) else {
+            return;
+        };
+        // Another split may have edited the buffer without touching these
+        // cursors.
+        let len = state.buffer.len();
+        let previous: SelectionSnapshot = previous
+            .into_iter()
+            .map(|(cursor_id, anchor, position)| {
+                (cursor_id, anchor.map(|a| a.min(len)), position.min(len))
+            })
+            .collect();
+        self.apply_selection_snapshot(&now, &previous);
+    }
+
+    fn selection_snapshot(&self) -> SelectionSnapshot {
+        let mut snapshot: SelectionSnapshot = self
+            .active_cursors()
+            .iter()
+            .map(|(cursor_id, cursor)| (cursor_id, cursor.anchor, cursor.position))
+            .collect();
+        snapshot.sort_by_key(|(cursor_id, _, _)| cursor_id.0);
+        snapshot
+    }
+
+    /// Move the cursors from `from` to `to` as one undoable step.
+    fn apply_selection_snapshot(&mut self, from: &SelectionSnapshot, to: &SelectionSnapshot) {
+        let cursors = self.active_cursors();
+        let mut events: Vec<Event> = from
+            .iter()
+            .zip(to)
+            .filter(|(old, new)| old != new)
+            .map(
+                |(&(cursor_id, old_anchor, old_position), &(_, new_anchor, new_position))| {
+                    Event::MoveCursor {
+                        cursor_id,
+                        old_position,
+                        new_position,
+                        old_anchor,
+                        new_anchor,
+                        old_sticky_column: cursors.get(cursor_id).and_then(|c| c.sticky_column),
+                        new_sticky_column: None,
+                    }
+                },
+            )
+            .collect();
+        let event = match events.len() {
+            0 => return,
+            1 => events.remove(0),
+            _ => Event::Batch {
+                events,
+                description: "Expand selection".to_string(),
+            },
+        };
+        self.active_event_log_mut().append(event.clone());
+        self.apply_event_to_active_buffer(&event);
+    }
+}
+
+/// Parse `text` with `language`'s grammar, if this build has one.
+fn syntax_tree(language: Option<&Language>, text: &str) -> Option<Tree> {
+    let ts_language = language?.ts_language()?;
+    let mut parser = Parser::new();
+    parser.set_language(&ts_language).ok()?;
+    parser.parse(text, None)
+}
+
+/// The smallest node of `tree` that strictly contains `range`.
+fn enclosing_node_range(tree: &Tree, range: Range<usize>) -> Option<Range<usize>> {
+    let mut node = tree
+        .root_node()
+        .descendant_for_byte_range(range.start, range.end)?;
+    loop {
+        let node_range = node.byte_range();
+        if node_range.start <= range.start && node_range.end >= range.end && node_range != range {
+            return Some(node_range);
+        }
+        node = node.parent()?;
+    }
+}
@@ -123,7 +123,10 @@ impl Editor {
         self.set_config(new_config.clone());
 
         // Refresh cached raw user config for plugins
-        self.set_user_config_raw(Config::read_user_config_raw(self.working_dir()));
+        self.set_user_config_raw(Config::read_user_config_raw(
+            &self.dir_context,
+            self.working_dir(),
+        ));
 
         // Apply runtime changes
         if old_theme != self.config.theme {
@@ -195,6 +198,8 @@ impl Editor {
             view_state.show_line_numbers = self.config.editor.line_numbers;
             for buf_state in view_state.keyed_states.values_mut() {
                 buf_state.rulers = self.config.editor.rulers.clone();
+                buf_state.viewport.scroll_past_end = self.config.editor.scroll_past_end;
+                buf_state.viewport.typewriter_scrolling = self.config.editor.typewriter_scrolling;
             }
         }
 
@@ -725,7 +730,7 @@ impl Editor {
                     let load_result = self.plugin_manager.read().unwrap().load_plugin(path);
                     if let Err(e) = load_result {
                         tracing::error!("Failed to load plugin '{}': {}", name, e);
-                        self.set_status_message(format!("Failed to load plugin '{}': {}", name, e));
+                        self.set_status_error(format!("Failed to load plugin '{}': {}", name, e));
                     }
                 }
             } else {
@@ -734,7 +739,7 @@ impl Editor {
                 let unload_result = self.plugin_manager.write().unwrap().unload_plugin(&name);
                 if let Err(e) = unload_result {
                     tracing::error!("Failed to unload plugin '{}': {}", name, e);
-                    self.set_status_message(format!("Failed to unload plugin '{}': {}", name, e));
+                    self.set_status_error(format!("Failed to unload plugin '{}': {}", name, e));
                 } else {
                     // Clean up status bar tokens for this plugin
                     self.remove_plugin_status_bar_elements(&name);
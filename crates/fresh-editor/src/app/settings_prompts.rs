@@ -75,6 +75,21 @@ impl Editor {
 
     /// Start the encoding selection prompt
     pub(super) fn start_set_encoding_prompt(&mut self) {
+        self.start_encoding_prompt("Encoding: ".to_string(), PromptType::SetEncoding);
+    }
+
+    /// Start the save with encoding prompt
+    ///
+    /// Prompts user to select an encoding, then converts the buffer to it and saves.
+    pub(super) fn start_save_with_encoding_prompt(&mut self) {
+        self.start_encoding_prompt(
+            t!("file.save_with_encoding_prompt").to_string(),
+            PromptType::SaveWithEncoding,
+        );
+    }
+
+    /// Open an encoding picker with the buffer's current encoding preselected.
+    fn start_encoding_prompt(&mut self, message: String, prompt_type: PromptType) {
         use crate::model::buffer::Encoding;
 
         let current_encoding = self.active_state().buffer.encoding();
@@ -105,8 +120,8 @@ impl Editor {
             .unwrap_or(0);
 
         self.active_window_mut().prompt = Some(crate::view::prompt::Prompt::with_suggestions(
-            "Encoding: ".to_string(),
-            PromptType::SetEncoding,
+            message,
+            prompt_type,
             suggestions,
         ));
 
@@ -127,8 +142,6 @@ impl Editor {
     /// Prompts user to select an encoding, then reloads the current file with that encoding.
     /// Requires the buffer to have no unsaved modifications.
     pub(super) fn start_reload_with_encoding_prompt(&mut self) {
-        use crate::model::buffer::Encoding;
-
         // Check if buffer has a file path
         let has_file = self
             .buffers()
@@ -155,56 +168,41 @@ impl Editor {
             return;
         }
 
-        let current_encoding = self.active_state().buffer.encoding();
-
-        let suggestions: Vec<crate::input::commands::Suggestion> = Encoding::all()
-            .iter()
-            .map(|enc| {
-                let is_current = *enc == current_encoding;
-                crate::input::commands::Suggestion {
-                    description_spans: None,
-                    text: format!("{} ({})", enc.display_name(), enc.description()),
-                    description: if is_current {
-                        Some("current".to_string())
-                    } else {
-                        None
-                    },
-                    value: Some(enc.display_name().to_string()),
-                    disabled: false,
-                    keybinding: None,
-                    source: None,
-                }
-            })
-            .collect();
+        self.start_encoding_prompt(
+            "Reload with encoding: ".to_string(),
+            PromptType::ReloadWithEncoding,
+        );
+    }
 
-        let current_index = Encoding::all()
-            .iter()
-            .position(|enc| *enc == current_encoding)
-            .unwrap_or(0);
+    /// Start the language selection prompt
+    pub(super) fn start_set_language_prompt(&mut self) {
+        let current_language = self.active_state().language.clone();
+        let (suggestions, current_index) = self.language_suggestions(&current_language);
 
         self.active_window_mut().prompt = Some(crate::view::prompt::Prompt::with_suggestions(
-            "Reload with encoding: ".to_string(),
-            PromptType::ReloadWithEncoding,
+            "Language: ".to_string(),
+            PromptType::SetLanguage,
             suggestions,
         ));
 
         if let Some(prompt) = self.active_window_mut().prompt.as_mut() {
             if !prompt.suggestions.is_empty() {
                 prompt.selected_suggestion = Some(current_index);
-                let enc = Encoding::all()[current_index];
-                prompt.input = format!("{} ({})", enc.display_name(), enc.description());
-                prompt.cursor_pos = prompt.input.len();
-                prompt.selection_anchor = Some(0);
+                // Don't set input - keep it empty so typing filters the list
+                // The selected suggestion shows the current language
             }
         }
     }
 
-    /// Start the language selection prompt
-    pub(super) fn start_set_language_prompt(&mut self) {
+    /// Suggestions for a language picker: "Plain Text" followed by every
+    /// catalog language, alphabetically. Returns them with the index of
+    /// `current_language` (0 when it isn't in the list).
+    pub(super) fn language_suggestions(
+        &self,
+        current_language: &str,
+    ) -> (Vec<crate::input::commands::Suggestion>, usize) {
         use crate::input::commands::CommandSource;
 
-        let current_language = self.active_state().language.clone();
-
         // Map each catalog entry's display name to a config key (when the user
         // declared a custom key for it) so we can show the extra column.
         let mut config_key_by_display: std::collections::HashMap<String, String> =
@@ -299,34 +297,27 @@ impl Editor {
             });
         }
 
-        // Find current language index
-        let current_index = current_index_found.unwrap_or(0);
-
-        self.active_window_mut().prompt = Some(crate::view::prompt::Prompt::with_suggestions(
-            "Language: ".to_string(),
-            PromptType::SetLanguage,
-            suggestions,
-        ));
-
-        if let Some(prompt) = self.active_window_mut().prompt.as_mut() {
-            if !prompt.suggestions.is_empty() {
-                prompt.selected_suggestion = Some(current_index);
-                // Don't set input - keep it empty so typing filters the list
-                // The selected suggestion shows the current language
-            }
-        }
+        (suggestions, current_index_found.unwrap_or(0))
     }
 
-    /// Start the theme selection prompt with available themes
-    pub(super) fn start_select_theme_prompt(&mut self) {
+    /// Start the theme selection prompt with available themes.
+    ///
+    /// Every highlighted theme is live-previewed. With `persist` (Select
+    /// Theme) confirming saves the choice to config; without it (Preview
+    /// Themes) the choice only lasts for this session.
+    pub(super) fn start_select_theme_prompt(&mut self, persist: bool) {
         let available_themes = self.theme_registry.list();
         // The config may hold a portable form (`s-dark.json`, `builtin://dark`,
         // `file://${HOME}/…`) rather than a canonical registry key. Resolve
         // it so the picker can pre-highlight the current theme.
+        let current_value = self
+            .session_theme
+            .clone()
+            .unwrap_or_else(|| self.config.theme.0.clone());
         let resolved_current = self
             .theme_registry
-            .resolve_key(&self.config.theme.0)
-            .unwrap_or_else(|| self.config.theme.0.clone());
+            .resolve_key(&current_value)
+            .unwrap_or(current_value);
         let current_theme_key = resolved_current.as_str();
 
         // Find the index of the current theme (match by key first, then name)
@@ -379,10 +370,16 @@ impl Editor {
             })
             .collect();
 
+        let label = if persist {
+            "Select theme: "
+        } else {
+            "Preview theme: "
+        };
         self.active_window_mut().prompt = Some(crate::view::prompt::Prompt::with_suggestions(
-            "Select theme: ".to_string(),
+            label.to_string(),
             PromptType::SelectTheme {
                 original_theme: current_theme_key.to_string(),
+                persist,
             },
             suggestions,
         ));
@@ -459,6 +456,7 @@ impl Editor {
                     .portable_form(&resolved)
                     .unwrap_or(resolved);
                 self.config_mut().theme = to_persist.into();
+                self.session_theme = None;
 
                 // Persist to config file
                 self.save_theme_to_config();
@@ -476,6 +474,31 @@ impl Editor {
         }
     }
 
+    /// Apply a theme by key or name for this session only (Preview Themes).
+    ///
+    /// The config is left untouched, so the persisted theme comes back on
+    /// the next start.
+    pub(super) fn apply_session_theme(&mut self, key_or_name: &str) {
+        if key_or_name.is_empty() {
+            return;
+        }
+        let Some(key) = self.theme_registry.resolve_key(key_or_name) else {
+            self.set_status_message(format!("Theme '{}' not found", key_or_name));
+            return;
+        };
+        // The highlighted theme is normally already on screen from the live
+        // preview; this also covers a typed-in key that was never previewed.
+        self.preview_theme(&key);
+        self.session_theme = Some(key);
+        self.set_status_message(
+            t!(
+                "view.theme_previewing",
+                theme = self.theme.read().unwrap().name.clone()
+            )
+            .to_string(),
+        );
+    }
+
     /// Re-apply all stored diagnostics and semantic tokens with the current
     /// theme colors. Both overlay types bake RGB values at creation time, so
     /// they must be rebuilt when the theme changes.
@@ -497,10 +520,11 @@ impl Editor {
                     .expect("active window present")
                     .get_mut(&buffer_id)
                 {
-                    crate::services::lsp::diagnostics::apply_diagnostics_to_state_cached(
+                    crate::services::lsp::diagnostics::apply_diagnostics_to_state_cached_with_style(
                         state,
                         &diagnostics,
                         &self.theme.read().unwrap(),
+                        self.terminal_capabilities.renders_styled_underlines(),
                     );
                 }
             }
@@ -769,21 +793,10 @@ impl Editor {
         use crate::config::CursorStyle;
 
         if let Some(style) = CursorStyle::parse(style_name) {
-            // Update the config in memory
+            // Update the config in memory; the next frame sends the
+            // style for the current context to the terminal.
             self.config_mut().editor.cursor_style = style;
 
-            // Apply the cursor style to the terminal
-            if self.session_mode {
-                // In session mode, queue the escape sequence to be sent to the client
-                self.queue_escape_sequences(style.to_escape_sequence());
-            } else {
-                // In normal mode, write directly to stdout
-                use std::io::stdout;
-                // Best-effort cursor style change to stdout.
-                #[allow(clippy::let_underscore_must_use)]
-                let _ = crossterm::execute!(stdout(), style.to_crossterm_style());
-            }
-
             // Persist to config file
             self.save_cursor_style_to_config();
 
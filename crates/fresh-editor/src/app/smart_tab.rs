@@ -0,0 +1,166 @@
+//! Context-dependent Tab.
+//!
+//! `smart_tab` tries the behaviors listed in `editor.smart_tab` in order and
+//! runs the first one that applies: accept the open completion, expand the
+//! snippet whose prefix is before the cursor, move to the next snippet tab
+//! stop, indent the selection, or insert indentation. Leaving a behavior out
+//! of the list disables it.
+
+use anyhow::Result as AnyhowResult;
+
+use crate::config::SmartTabBehavior;
+use crate::input::keybindings::Action;
+use crate::services::completion::snippets::UserSnippet;
+
+use super::Editor;
+
+/// How far back from the cursor to look for a snippet prefix.
+const MAX_PREFIX_SCAN: usize = 256;
+
+impl Editor {
+    /// Run the first applicable `editor.smart_tab` behavior.
+    pub(super) fn smart_tab(&mut self) -> AnyhowResult<()> {
+        let completion_open = self.completion_popup_is_topmost();
+        let behaviors = self.config().editor.smart_tab.clone();
+        for behavior in behaviors {
+            if behavior == SmartTabBehavior::AcceptCompletion {
+                if completion_open {
+                    self.handle_popup_confirm();
+                    return Ok(());
+                }
+                continue;
+            }
+            if !self.smart_tab_applies(behavior) {
+                continue;
+            }
+            // Any other behavior leaves the completion list stale.
+            if completion_open {
+                self.handle_popup_cancel();
+            }
+            match behavior {
+                SmartTabBehavior::AcceptCompletion => {}
+                SmartTabBehavior::ExpandSnippet => self.expand_snippet_before_cursor(),
+                SmartTabBehavior::NextTabStop => {
+                    self.snippet_tabstop_step(true);
+                }
+                SmartTabBehavior::IndentSelection | SmartTabBehavior::InsertIndent => {
+                    self.apply_action_as_events(Action::InsertTab)?;
+                }
+            }
+            return Ok(());
+        }
+        Ok(())
+    }
+
+    /// Whether `behavior` (other than accepting a completion) would do
+    /// something right now.
+    fn smart_tab_applies(&mut self, behavior: SmartTabBehavior) -> bool {
+        let buffer_id = self.active_buffer();
+        if self.active_window().is_composite_buffer(buffer_id) {
+            // Tab switches panes in composite views; only the indent
+            // behaviors route there.
+            return matches!(
+                behavior,
+                SmartTabBehavior::IndentSelection | SmartTabBehavior::InsertIndent
+            );
+        }
+        let has_selection = self
+            .active_cursors()
+            .iter()
+            .any(|(_, cursor)| cursor.selection_range().is_some());
+        match behavior {
+            SmartTabBehavior::AcceptCompletion => false,
+            SmartTabBehavior::ExpandSnippet => {
+                !has_selection
+                    && self.active_cursors().count() == 1
+                    && !self.active_window().is_editing_disabled()
+                    && self.snippet_before_cursor().is_some()
+            }
+            SmartTabBehavior::NextTabStop => self.snippet_tabstop_pending(),
+            SmartTabBehavior::IndentSelection => has_selection,
+            SmartTabBehavior::InsertIndent => !has_selection,
+        }
+    }
+
+    /// The user snippet whose prefix ends at the cursor, with the byte
+    /// length of that prefix.
+    fn snippet_before_cursor(&mut self) -> Option<(usize, UserSnippet)> {
+        let snippets = self.active_user_snippets();
+        if snippets.is_empty() {
+            return None;
+        }
+        let position = self.active_cursors().primary().position;
+        let text = self
+            .active_state_mut()
+            .get_text_range(position.saturating_sub(MAX_PREFIX_SCAN), position);
+        let line = text.rsplit('\n').next().unwrap_or_default();
+        match_snippet_prefix(line, &snippets).map(|(len, snippet)| (len, snippet.clone()))
+    }
+
+    /// Replace the snippet prefix before the cursor with its snippet.
+    fn expand_snippet_before_cursor(&mut self) {
+        let Some((prefix_len, snippet)) = self.snippet_before_cursor() else {
+            return;
+        };
+        let primary_id = self.active_cursors().primary_id();
+        let position = self.active_cursors().primary().position;
+        let range = position - prefix_len..position;
+        self.insert_snippet_at_cursors(
+            vec![(primary_id, range)],
+            &snippet.body,
+            true,
+            "Expand snippet",
+        );
+    }
+}
+
+/// Find the snippet whose prefix `line` ends with, preferring the longest
+/// prefix. A prefix that starts with a word character must not continue a
+/// longer word, so `xfor` does not expand `for`.
+fn match_snippet_prefix<'a>(
+    line: &str,
+    snippets: &'a [UserSnippet],
+) -> Option<(usize, &'a UserSnippet)> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    snippets
+        .iter()
+        .flat_map(|snippet| snippet.prefixes.iter().map(move |p| (p, snippet)))
+        .filter(|(prefix, _)| !prefix.is_empty() && line.ends_with(prefix.as_str()))
+        .filter(|(prefix, _)| {
+            let before = line[..line.len() - prefix.len()].chars().next_back();
+            let starts_word = prefix.chars().next().is_some_and(is_word);
+            !(starts_word && before.is_some_and(is_word))
+        })
+        .max_by_key(|(prefix, _)| prefix.len())
+        .map(|(prefix, snippet)| (prefix.len(), snippet))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snippet(name: &str, prefixes: &[&str]) -> UserSnippet {
+        UserSnippet {
+            name: name.to_string(),
+            prefixes: prefixes.iter().map(|p| p.to_string()).collect(),
+            body: String::new(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn match_snippet_prefix_needs_word_boundary_and_prefers_longest() {
+        let snippets = vec![
+            snippet("For", &["for"]),
+            snippet("For each", &["foreach"]),
+            snippet("Include", &["#inc"]),
+        ];
+        let name = |line: &str| match_snippet_prefix(line, &snippets).map(|(_, s)| s.name.clone());
+
+        assert_eq!(name("    for"), Some("For".to_string()));
+        assert_eq!(name("foreach"), Some("For each".to_string()));
+        assert_eq!(name("xfor"), None);
+        assert_eq!(name("a#inc"), Some("Include".to_string()));
+        assert_eq!(name("for "), None);
+    }
+}
@@ -0,0 +1,398 @@
+//! Snippet insertion and tab-stop navigation.
+//!
+//! Snippets come from completion items (LSP items marked
+//! `InsertTextFormat::Snippet`, user snippets) and from **Insert Snippet**.
+//! Inserting one with tab stops starts a [`SnippetSession`] on the buffer:
+//! the cursors select every occurrence of `$1`, so typing fills in the
+//! placeholder and its mirrors at once; Tab and Shift+Tab move to the next
+//! and previous tab stop, and Tab past the last one lands on `$0` and ends
+//! the session, as does Escape.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use rust_i18n::t;
+
+use crate::model::event::{CursorId, Event};
+use crate::model::snippet_session::SnippetSession;
+use crate::primitives::snippet::{expand_snippet_with, ExpandedSnippet};
+use crate::services::completion::snippets::UserSnippet;
+use crate::view::prompt::PromptType;
+
+use super::Editor;
+
+impl Editor {
+    /// Replace each cursor's range with `snippet`, expanded if `expand` is
+    /// set (otherwise it is inserted verbatim). All edits form one undo step.
+    ///
+    /// With a single cursor and a snippet that has tab stops, a snippet
+    /// session starts on the first tab stop; otherwise every cursor moves to
+    /// its copy's `$0`.
+    pub(super) fn insert_snippet_at_cursors(
+        &mut self,
+        replacements: Vec<(CursorId, Range<usize>)>,
+        snippet: &str,
+        expand: bool,
+        description: &str,
+    ) {
+        self.insert_snippet_with_edits(replacements, snippet, expand, Vec::new(), description);
+    }
+
+    /// [`Self::insert_snippet_at_cursors`], also replacing each range of
+    /// `extra_edits` with its text in the same undo step — a completion's
+    /// `additionalTextEdits`, such as an auto-import. Extra edits move no
+    /// cursor; ones overlapping a cursor's range are dropped.
+    pub(super) fn insert_snippet_with_edits(
+        &mut self,
+        replacements: Vec<(CursorId, Range<usize>)>,
+        snippet: &str,
+        expand: bool,
+        extra_edits: Vec<(Range<usize>, String)>,
+        description: &str,
+    ) {
+        if replacements.is_empty() {
+            return;
+        }
+        let extra_edits: Vec<(Range<usize>, String)> = extra_edits
+            .into_iter()
+            .filter(|(edit, _)| {
+                let overlaps = replacements
+                    .iter()
+                    .any(|(_, range)| edit.start < range.end && range.start < edit.end);
+                if overlaps {
+                    tracing::warn!("Dropping extra edit {:?} overlapping the insertion", edit);
+                }
+                !overlaps
+            })
+            .collect();
+        let expanded = if expand {
+            let variables = self.snippet_variables();
+            expand_snippet_with(snippet, |name| variables.get(name).cloned())
+        } else {
+            ExpandedSnippet {
+                text: snippet.to_string(),
+                cursor_offset: snippet.len(),
+                tabstops: Vec::new(),
+            }
+        };
+        let insert_text = expanded.text.clone();
+
+        // `apply_events_as_bulk_edit` sorts by descending position
+        // internally, so emission order doesn't matter.
+        let mut events: Vec<Event> = Vec::new();
+        for (cursor_id, range) in &replacements {
+            if !range.is_empty() {
+                let deleted_text = self
+                    .active_state_mut()
+                    .get_text_range(range.start, range.end);
+                events.push(Event::Delete {
+                    range: range.clone(),
+                    deleted_text,
+                    cursor_id: *cursor_id,
+                });
+            }
+            events.push(Event::Insert {
+                position: range.start,
+                text: insert_text.clone(),
+                cursor_id: *cursor_id,
+            });
+        }
+        for (range, text) in &extra_edits {
+            if !range.is_empty() {
+                let deleted_text = self
+                    .active_state_mut()
+                    .get_text_range(range.start, range.end);
+                events.push(Event::Delete {
+                    range: range.clone(),
+                    deleted_text,
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+            if !text.is_empty() {
+                events.push(Event::Insert {
+                    position: range.start,
+                    text: text.clone(),
+                    cursor_id: CursorId::UNDO_SENTINEL,
+                });
+            }
+        }
+
+        if events.len() > 1 {
+            // Multi-cursor (or replacement = delete+insert): one atomic bulk edit.
+            if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description.to_string())
+            {
+                self.active_event_log_mut().append(bulk_edit);
+            }
+        } else {
+            for event in events {
+                self.log_and_apply_event(&event);
+            }
+        }
+
+        if let [(_, range)] = replacements.as_slice() {
+            if !expanded.tabstops.is_empty() {
+                // Extra edits before the snippet shift where it landed.
+                let shift: isize = extra_edits
+                    .iter()
+                    .filter(|(edit, _)| edit.end <= range.start)
+                    .map(|(edit, text)| text.len() as isize - edit.len() as isize)
+                    .sum();
+                let base = (range.start as isize + shift).max(0) as usize;
+                self.start_snippet_session(base, &expanded);
+                return;
+            }
+        }
+
+        // After the edit each cursor sits at the end of its own inserted
+        // text; the snippet's $0 sits `cursor_offset` bytes into that text.
+        // Walk each cursor back to its $0 placeholder.
+        if expanded.cursor_offset != insert_text.len() {
+            let move_events: Vec<Event> = self
+                .active_cursors()
+                .iter()
+                .map(|(cursor_id, cursor)| {
+                    let current = cursor.position;
+                    let target = current.saturating_sub(insert_text.len()) + expanded.cursor_offset;
+                    Event::MoveCursor {
+                        cursor_id,
+                        old_position: current,
+                        new_position: target,
+                        old_anchor: cursor.anchor,
+                        new_anchor: None,
+                        old_sticky_column: cursor.sticky_column,
+                        new_sticky_column: None,
+                    }
+                })
+                .collect();
+            for event in move_events {
+                self.log_and_apply_event(&event);
+            }
+        }
+    }
+
+    /// Values for the snippet variables Fresh knows (`$TM_FILENAME`, …).
+    pub(super) fn snippet_variables(&mut self) -> HashMap<&'static str, String> {
+        let mut variables = HashMap::new();
+        let cursor = *self.active_cursors().primary();
+        let state = self.active_state_mut();
+        if let Some(path) = state.buffer.file_path().map(|p| p.to_path_buf()) {
+            let name = |s: Option<&std::ffi::OsStr>| s.map(|s| s.to_string_lossy().into_owned());
+            if let Some(file_name) = name(path.file_name()) {
+                variables.insert("TM_FILENAME", file_name);
+            }
+            if let Some(stem) = name(path.file_stem()) {
+                variables.insert("TM_FILENAME_BASE", stem);
+            }
+            if let Some(dir) = path.parent() {
+                variables.insert("TM_DIRECTORY", dir.to_string_lossy().into_owned());
+            }
+            variables.insert("TM_FILEPATH", path.to_string_lossy().into_owned());
+        }
+        if let Some(range) = cursor.selection_range() {
+            let selected = state.get_text_range(range.start, range.end);
+            variables.insert("TM_SELECTED_TEXT", selected);
+        }
+        let line = state.buffer.get_line_number(cursor.position);
+        variables.insert("TM_LINE_INDEX", line.to_string());
+        variables.insert("TM_LINE_NUMBER", (line + 1).to_string());
+        variables
+    }
+
+    /// Track the tab stops of `expanded`, just inserted at `base`, and select
+    /// the first one.
+    fn start_snippet_session(&mut self, base: usize, expanded: &ExpandedSnippet) {
+        self.end_snippet_session();
+        let state = self.active_state_mut();
+        state.snippet_session = SnippetSession::new(&mut state.marker_list, base, expanded);
+        self.select_snippet_tabstop(0);
+    }
+
+    /// End the active buffer's snippet session, if any.
+    pub(super) fn end_snippet_session(&mut self) {
+        let state = self.active_state_mut();
+        if let Some(session) = state.snippet_session.take() {
+            session.release(&mut state.marker_list);
+        }
+    }
+
+    /// Move to the next (`forward`) or previous tab stop of the active
+    /// snippet. Returns false, leaving the key to its normal action, when no
+    /// snippet is active or the cursor has left it (which ends the session).
+    pub(super) fn snippet_tabstop_step(&mut self, forward: bool) -> bool {
+        let position = self.active_cursors().primary().position;
+        let state = self.active_state();
+        let Some(session) = &state.snippet_session else {
+            return false;
+        };
+        let inside = session.contains(&state.marker_list, position);
+        let (current, len) = (session.current(), session.len());
+        let final_position = session.final_position(&state.marker_list);
+        if !inside {
+            self.end_snippet_session();
+            return false;
+        }
+        if !forward {
+            self.select_snippet_tabstop(current.saturating_sub(1));
+        } else if current + 1 < len {
+            self.select_snippet_tabstop(current + 1);
+        } else {
+            self.end_snippet_session();
+            if let Some(position) = final_position {
+                self.set_cursor_ranges(std::slice::from_ref(&(position..position)));
+            }
+        }
+        true
+    }
+
+    /// Whether the cursor is inside an active snippet, so Tab would move to
+    /// its next tab stop.
+    pub(super) fn snippet_tabstop_pending(&self) -> bool {
+        let position = self.active_cursors().primary().position;
+        let state = self.active_state();
+        state
+            .snippet_session
+            .as_ref()
+            .is_some_and(|session| session.contains(&state.marker_list, position))
+    }
+
+    fn select_snippet_tabstop(&mut self, index: usize) {
+        let state = self.active_state_mut();
+        let Some(session) = state.snippet_session.as_mut() else {
+            return;
+        };
+        session.set_current(index);
+        let ranges = session.ranges(&state.marker_list, index);
+        if !ranges.is_empty() {
+            self.set_cursor_ranges(&ranges);
+        }
+    }
+
+    /// Put one cursor on each range, selecting it; the first range gets the
+    /// primary cursor.
+    fn set_cursor_ranges(&mut self, ranges: &[Range<usize>]) {
+        let cursors = self.active_cursors();
+        let primary_id = cursors.primary_id();
+        let mut existing: Vec<(CursorId, crate::model::cursor::Cursor)> = cursors
+            .iter()
+            .filter(|(id, _)| *id != primary_id)
+            .map(|(id, c)| (id, *c))
+            .collect();
+        existing.insert(0, (primary_id, *cursors.primary()));
+        let next_free_id = existing
+            .iter()
+            .map(|(id, _)| id.0)
+            .max()
+            .map_or(0, |m| m + 1);
+
+        let mut events = Vec::new();
+        for (i, range) in ranges.iter().enumerate() {
+            let new_anchor = (!range.is_empty()).then_some(range.start);
+            match existing.get(i) {
+                Some(&(cursor_id, cursor)) => events.push(Event::MoveCursor {
+                    cursor_id,
+                    old_position: cursor.position,
+                    new_position: range.end,
+                    old_anchor: cursor.anchor,
+                    new_anchor,
+                    old_sticky_column: cursor.sticky_column,
+                    new_sticky_column: None,
+                }),
+                None => events.push(Event::AddCursor {
+                    cursor_id: CursorId(next_free_id + i - existing.len()),
+                    position: range.end,
+                    anchor: new_anchor,
+                }),
+            }
+        }
+        for &(cursor_id, cursor) in existing.iter().skip(ranges.len()) {
+            events.push(Event::RemoveCursor {
+                cursor_id,
+                position: cursor.position,
+                anchor: cursor.anchor,
+            });
+        }
+
+        let batch = Event::Batch {
+            events,
+            description: "Snippet tab stop".to_string(),
+        };
+        self.active_event_log_mut().append(batch.clone());
+        self.apply_event_to_active_buffer(&batch);
+    }
+
+    /// User snippets for the active buffer's language.
+    pub(super) fn active_user_snippets(&self) -> Vec<UserSnippet> {
+        self.active_window()
+            .snippets
+            .snippets(&self.active_state().language)
+    }
+
+    /// Open the Insert Snippet prompt listing the user snippets for the
+    /// active buffer's language.
+    pub(super) fn start_insert_snippet_prompt(&mut self) {
+        let snippets = self.active_user_snippets();
+        if snippets.is_empty() {
+            self.set_status_message(
+                t!(
+                    "snippet.none_defined",
+                    language = self.active_state().language.clone()
+                )
+                .to_string(),
+            );
+            return;
+        }
+        let suggestions = snippets
+            .iter()
+            .enumerate()
+            .map(|(index, snippet)| crate::input::commands::Suggestion {
+                description_spans: None,
+                text: snippet.name.clone(),
+                description: Some(match &snippet.description {
+                    Some(description) => {
+                        format!("{} — {}", snippet.prefixes.join(", "), description)
+                    }
+                    None => snippet.prefixes.join(", "),
+                }),
+                value: Some(index.to_string()),
+                disabled: false,
+                keybinding: None,
+                source: None,
+            })
+            .collect();
+        self.active_window_mut().prompt = Some(crate::view::prompt::Prompt::with_suggestions(
+            t!("snippet.prompt").to_string(),
+            PromptType::InsertSnippet { snippets },
+            suggestions,
+        ));
+        if let Some(prompt) = self.active_window_mut().prompt.as_mut() {
+            prompt.selected_suggestion = Some(0);
+        }
+    }
+
+    /// Insert the snippet chosen in the Insert Snippet prompt, replacing each
+    /// cursor's selection.
+    pub(super) fn insert_user_snippet(&mut self, snippets: &[UserSnippet], input: &str) {
+        let chosen = input
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| snippets.get(index))
+            .or_else(|| snippets.iter().find(|s| s.name == input.trim()));
+        let Some(snippet) = chosen else {
+            self.set_status_message(t!("snippet.not_found", name = input.trim()).to_string());
+            return;
+        };
+        let replacements = self
+            .active_cursors()
+            .iter()
+            .map(|(id, cursor)| {
+                let range = cursor
+                    .selection_range()
+                    .unwrap_or(cursor.position..cursor.position);
+                (id, range)
+            })
+            .collect();
+        let body = snippet.body.clone();
+        self.insert_snippet_at_cursors(replacements, &body, true, "Insert snippet");
+    }
+}
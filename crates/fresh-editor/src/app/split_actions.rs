@@ -96,6 +96,8 @@ impl Editor {
                         .resolve_wrap_column_for_buffer(current_buffer_id),
                     rulers: self.config.editor.rulers.clone(),
                     scroll_offset: self.config.editor.scroll_offset,
+                    scroll_past_end: self.config.editor.scroll_past_end,
+                    typewriter_scrolling: self.config.editor.typewriter_scrolling,
                 });
 
                 // Copy keyed states from source split for OTHER buffers (not the active one).
@@ -125,6 +127,7 @@ impl Editor {
                                     .line_start_offset(end_line.saturating_add(1))
                                     .unwrap_or_else(|| state.buffer.len());
                                 buf_state.folds.add(
+                                    &state.buffer,
                                     &mut state.marker_list,
                                     start_byte,
                                     end_byte,
@@ -148,7 +151,7 @@ impl Editor {
                 self.set_status_message(msg.to_string());
             }
             Err(e) => {
-                self.set_status_message(t!("split.error", error = e.to_string()).to_string());
+                self.set_status_error(t!("split.error", error = e.to_string()).to_string());
             }
         }
 
@@ -497,7 +500,7 @@ impl Editor {
     /// Cycle through all splits and tabs in the current window in reverse order.
     pub fn prev_pane(&mut self) {
         self.cycle_pane(false);
-        self.set_status_message(t!("cmd.prev_pane").to_string());
+        self.set_status_message(t!("cmd.previous_pane").to_string());
     }
 
     fn cycle_pane(&mut self, forward: bool) {
@@ -0,0 +1,112 @@
+//! Startup timing breakdown (`fresh --profile-startup`)
+//!
+//! `main` marks the end of each startup phase as it passes it; the editor
+//! marks the first frame it draws. The breakdown is printed to stderr once
+//! the editor exits and the terminal is restored.
+
+use std::time::{Duration, Instant};
+
+/// Wall-clock durations of the startup phases, in the order they ran.
+#[derive(Debug, Clone)]
+pub struct StartupProfile {
+    started: Instant,
+    last_mark: Instant,
+    phases: Vec<(&'static str, Duration)>,
+    finished: bool,
+}
+
+impl StartupProfile {
+    /// Start timing now.
+    pub fn start() -> Self {
+        let now = Instant::now();
+        Self {
+            started: now,
+            last_mark: now,
+            phases: Vec::new(),
+            finished: false,
+        }
+    }
+
+    /// Record that `phase` ended now; it covers the time since the previous
+    /// mark. Ignored once the profile is finished.
+    pub fn mark(&mut self, phase: &'static str) {
+        if self.finished {
+            return;
+        }
+        let now = Instant::now();
+        self.phases.push((phase, now - self.last_mark));
+        self.last_mark = now;
+    }
+
+    /// Record the last phase. Later marks are ignored, so this can be called
+    /// on every frame.
+    pub fn finish(&mut self, phase: &'static str) {
+        self.mark(phase);
+        self.finished = true;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// The recorded phases and their durations.
+    pub fn phases(&self) -> &[(&'static str, Duration)] {
+        &self.phases
+    }
+
+    /// Time from `start` to the last mark.
+    pub fn total(&self) -> Duration {
+        self.last_mark - self.started
+    }
+
+    /// One line per phase plus the total, durations right-aligned in
+    /// milliseconds.
+    pub fn report(&self) -> String {
+        let label_width = self
+            .phases
+            .iter()
+            .map(|(phase, _)| phase.len())
+            .chain(std::iter::once("total".len()))
+            .max()
+            .unwrap_or(0);
+        let mut out = String::from("Startup profile:\n");
+        let rows = self
+            .phases
+            .iter()
+            .copied()
+            .chain(std::iter::once(("total", self.total())));
+        for (phase, duration) in rows {
+            out.push_str(&format!(
+                "  {phase:<label_width$}  {:>9.1} ms\n",
+                duration.as_secs_f64() * 1000.0
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marks_after_finish_are_ignored_and_report_lists_phases() {
+        let mut profile = StartupProfile::start();
+        profile.mark("parse arguments");
+        profile.finish("first frame");
+        profile.mark("later");
+        profile.finish("later");
+
+        let phases: Vec<&str> = profile.phases().iter().map(|(p, _)| *p).collect();
+        assert_eq!(phases, ["parse arguments", "first frame"]);
+        assert!(profile.is_finished());
+
+        let report = profile.report();
+        let lines: Vec<&str> = report.lines().collect();
+        assert_eq!(lines[0], "Startup profile:");
+        assert!(lines[1].starts_with("  parse arguments  "));
+        assert!(lines[2].starts_with("  first frame      "));
+        assert!(lines[3].starts_with("  total            "));
+        assert!(lines.iter().skip(1).all(|l| l.ends_with(" ms")));
+    }
+}
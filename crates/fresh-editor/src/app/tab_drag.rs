@@ -495,6 +495,8 @@ impl Editor {
                         .resolve_wrap_column_for_buffer(buffer_id),
                     rulers: self.config.editor.rulers.clone(),
                     scroll_offset: self.config.editor.scroll_offset,
+                    scroll_past_end: self.config.editor.scroll_past_end,
+                    typewriter_scrolling: self.config.editor.typewriter_scrolling,
                 });
 
                 // Copy cursor position from source split's view state
@@ -0,0 +1,250 @@
+//! Navigation with a ctags `tags` file, for projects without a language
+//! server.
+//!
+//! **Go to Definition** falls back to the tags file when no server handles
+//! the buffer or the server finds nothing; **Go to Tag** searches every tag
+//! in the project. **Generate Tags** runs `tags_command` from the project
+//! root through the active authority's process spawner, and
+//! `tags_generate_on_save` does the same in the background after each save.
+//! See [`crate::services::tags`].
+
+use rust_i18n::t;
+
+use super::window::Window;
+use super::Editor;
+use crate::primitives::word_navigation::{find_word_end, find_word_start};
+use crate::services::async_bridge::AsyncMessage;
+use crate::services::tags::{resolve_address, Tag, TagIndex, TAGS_FILE_NAME};
+use crate::view::prompt::PromptType;
+
+/// Tags listed in the Go to Tag prompt at once.
+const MAX_TAG_SUGGESTIONS: usize = 200;
+
+impl Window {
+    /// The project's tags, re-read if the tags file changed.
+    pub(crate) fn tag_index(&mut self) -> &TagIndex {
+        let fs = self.authority().filesystem.clone();
+        let path = self.root.join(TAGS_FILE_NAME);
+        self.tags.load(fs.as_ref(), &path)
+    }
+}
+
+impl Editor {
+    /// Jump to the tags-file definition of the word under the cursor. With
+    /// several definitions, opens Go to Tag on them instead. Returns false
+    /// if the tags file has none.
+    pub(super) fn goto_tag_definition(&mut self) -> bool {
+        let name = {
+            let state = self.active_state();
+            let cursor = self.active_cursors().primary().position;
+            let start = find_word_start(&state.buffer, cursor);
+            let end = find_word_end(&state.buffer, cursor);
+            String::from_utf8_lossy(&state.buffer.slice_bytes(start..end)).into_owned()
+        };
+        if name.is_empty() {
+            return false;
+        }
+        let definitions: Vec<Tag> = self
+            .active_window_mut()
+            .tag_index()
+            .definitions(&name)
+            .into_iter()
+            .cloned()
+            .collect();
+        match definitions.as_slice() {
+            [] => false,
+            [tag] => {
+                self.jump_to_tag(tag);
+                true
+            }
+            _ => {
+                self.open_goto_tag_prompt(&name);
+                true
+            }
+        }
+    }
+
+    /// Open the Go to Tag prompt.
+    pub(super) fn start_goto_tag_prompt(&mut self) {
+        if self.active_window_mut().tag_index().is_empty() {
+            self.set_status_message(t!("tags.none").to_string());
+            return;
+        }
+        self.open_goto_tag_prompt("");
+    }
+
+    fn open_goto_tag_prompt(&mut self, query: &str) {
+        let mut prompt = crate::view::prompt::Prompt::with_suggestions(
+            t!("tags.prompt").to_string(),
+            PromptType::GotoTag,
+            Vec::new(),
+        );
+        prompt.set_input(query.to_string());
+        self.active_window_mut().prompt = Some(prompt);
+        self.update_goto_tag_suggestions(query);
+    }
+
+    /// Refill the Go to Tag suggestions for the typed query. Each
+    /// suggestion's value is the tag's name, file and address,
+    /// tab-separated.
+    pub(super) fn update_goto_tag_suggestions(&mut self, query: &str) {
+        let suggestions: Vec<crate::input::commands::Suggestion> = self
+            .active_window_mut()
+            .tag_index()
+            .search(query.trim(), MAX_TAG_SUGGESTIONS)
+            .into_iter()
+            .map(|tag| crate::input::commands::Suggestion {
+                description_spans: None,
+                text: tag.name.clone(),
+                description: Some(match &tag.kind {
+                    Some(kind) => format!("{} — {}", tag.file, kind),
+                    None => tag.file.clone(),
+                }),
+                value: Some(format!("{}\t{}\t{}", tag.name, tag.file, tag.address)),
+                disabled: false,
+                keybinding: None,
+                source: None,
+            })
+            .collect();
+        if let Some(prompt) = self.active_window_mut().prompt.as_mut() {
+            prompt.suggestions = suggestions;
+            prompt.selected_suggestion = if prompt.suggestions.is_empty() {
+                None
+            } else {
+                Some(0)
+            };
+        }
+    }
+
+    /// Jump to the tag chosen in the Go to Tag prompt.
+    pub(super) fn goto_picked_tag(&mut self, value: &str) {
+        let mut fields = value.splitn(3, '\t');
+        let (Some(name), Some(file), Some(address)) = (fields.next(), fields.next(), fields.next())
+        else {
+            self.set_status_message(t!("tags.not_found", name = value.trim()).to_string());
+            return;
+        };
+        let tag = Tag {
+            name: name.to_string(),
+            file: file.to_string(),
+            address: address.to_string(),
+            kind: None,
+        };
+        self.jump_to_tag(&tag);
+    }
+
+    /// Open the tag's file and move the cursor to its line, on the tag's
+    /// name if the line contains it.
+    fn jump_to_tag(&mut self, tag: &Tag) {
+        let path = self.active_window().root.join(&tag.file);
+        if let Err(e) = self.open_file(&path) {
+            self.set_status_message(t!("file.error_opening", error = e.to_string()).to_string());
+            return;
+        }
+        let target = self.active_state().buffer.to_string().map(|text| {
+            let line = resolve_address(&tag.address, &text).unwrap_or(0);
+            let column = text
+                .lines()
+                .nth(line)
+                .and_then(|l| l.find(&tag.name))
+                .unwrap_or(0);
+            (line, column)
+        });
+        let (line, column) = match target {
+            Some(target) => target,
+            // Too large to search: only a line-number address can be used.
+            None => (resolve_address(&tag.address, "").unwrap_or(0), 0),
+        };
+        self.goto_line_col(line + 1, Some(column + 1));
+        self.set_status_message(
+            t!(
+                "lsp.jumped_to_definition",
+                path = tag.file.clone(),
+                line = line + 1
+            )
+            .to_string(),
+        );
+    }
+
+    /// Run `tags_command` from the project root in the background. A run
+    /// requested while one is going starts when it finishes. `manual` runs
+    /// report their outcome in the status bar; save-triggered ones only
+    /// report failures.
+    pub(super) fn generate_tags(&mut self, manual: bool) {
+        let Some((program, args)) = self.config.editor.tags_command.split_first() else {
+            self.set_status_message(t!("tags.no_command").to_string());
+            return;
+        };
+        let (program, args) = (program.clone(), args.to_vec());
+        let (Some(runtime), Some(bridge)) = (self.tokio_runtime.clone(), &self.async_bridge) else {
+            return;
+        };
+        let sender = bridge.sender();
+        let spawner = self.authority().process_spawner.clone();
+        let window_id = self.active_window;
+        let window = self.active_window_mut();
+        if window.tags_generating {
+            window.tags_regenerate_pending = true;
+            return;
+        }
+        window.tags_generating = true;
+        let root = window.root.to_string_lossy().into_owned();
+        runtime.spawn(async move {
+            let error = match spawner.spawn(program.clone(), args, Some(root)).await {
+                Ok(result) if result.exit_code == 0 => None,
+                Ok(result) => Some(
+                    result
+                        .stderr
+                        .lines()
+                        .find(|line| !line.trim().is_empty())
+                        .map(str::to_string)
+                        .unwrap_or_else(|| format!("{} exited with {}", program, result.exit_code)),
+                ),
+                Err(e) => Some(e.to_string()),
+            };
+            // Receiver gone means the editor is shutting down.
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = sender.send(AsyncMessage::TagsGenerated {
+                window: window_id,
+                manual,
+                error,
+            });
+        });
+        if manual {
+            self.set_status_message(t!("tags.generating").to_string());
+        }
+    }
+
+    /// Regenerate tags after a save when the project asks for it.
+    pub(super) fn generate_tags_on_save(&mut self) {
+        if self.config.editor.tags_generate_on_save {
+            self.generate_tags(false);
+        }
+    }
+
+    pub(super) fn handle_tags_generated(
+        &mut self,
+        window_id: fresh_core::WindowId,
+        manual: bool,
+        error: Option<String>,
+    ) {
+        let Some(window) = self.windows.get_mut(&window_id) else {
+            return;
+        };
+        window.tags_generating = false;
+        let rerun = std::mem::take(&mut window.tags_regenerate_pending);
+        match error {
+            Some(error) => {
+                tracing::warn!("Generating tags failed: {}", error);
+                window.status_message = Some(t!("tags.failed", error = error).to_string());
+            }
+            None if manual => {
+                window.status_message = Some(t!("tags.generated").to_string());
+            }
+            None => {}
+        }
+        if rerun && window_id == self.active_window {
+            self.generate_tags(false);
+        }
+    }
+}
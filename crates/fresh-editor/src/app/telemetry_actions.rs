@@ -0,0 +1,182 @@
+//! Telemetry consent and the privacy commands.
+//!
+//! Telemetry (`telemetry`) and the update check (`check_for_updates`) are
+//! separate switches. Nothing is sent until `telemetry` is set: on launch the
+//! editor asks once, and a dismissed question is asked again next time.
+//! **Toggle Telemetry** and **Toggle Update Check** flip and persist each
+//! switch (taking effect from the next launch, when the daily check runs),
+//! and **Show Telemetry Data** lists exactly what would be sent.
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use rust_i18n::t;
+
+use crate::app::Editor;
+use crate::config::Config;
+use crate::input::buffer_mode::BufferMode;
+use crate::input::keybindings::{Action, KeyContext};
+use crate::primitives::text_property::TextPropertyEntry;
+use crate::view::prompt::PromptType;
+
+/// Display name of the telemetry data buffer.
+const TELEMETRY_BUFFER_NAME: &str = "*Telemetry Data*";
+
+/// Mode carried on the telemetry data buffer, for its `q` binding.
+const TELEMETRY_DATA_MODE: &str = "telemetry-data";
+
+impl Editor {
+    /// Ask whether to send telemetry, unless that's already been answered.
+    /// Called once after startup; stays quiet while another prompt or popup
+    /// (such as Workspace Trust) is up, or when the network is off anyway.
+    pub fn maybe_prompt_telemetry_consent(&mut self) {
+        if self.config.telemetry.is_some() || self.config.offline || self.is_prompting() {
+            return;
+        }
+        if self.active_state().popups.top().is_some() {
+            return;
+        }
+        self.start_prompt(
+            t!("telemetry.consent_prompt").to_string(),
+            PromptType::TelemetryConsent,
+        );
+    }
+
+    /// Answer to the consent prompt: yes or no is recorded in the user
+    /// config, anything else leaves the question open.
+    pub(super) fn handle_telemetry_consent(&mut self, input: &str) {
+        match input.trim().to_lowercase().as_str() {
+            "y" | "yes" => self.set_telemetry(true),
+            "n" | "no" => self.set_telemetry(false),
+            _ => self.set_status_message(t!("telemetry.consent_deferred").to_string()),
+        }
+    }
+
+    /// Keep update checks and telemetry off for this session
+    /// (`--no-upgrade-check`), including after the config is reloaded.
+    pub fn set_no_upgrade_check(&mut self, no_upgrade_check: bool) {
+        self.no_upgrade_check = no_upgrade_check;
+    }
+
+    /// Reapply command-line overrides to a freshly loaded config.
+    pub(super) fn apply_cli_overrides(&self, config: &mut Config) {
+        if self.no_upgrade_check {
+            config.check_for_updates = false;
+            config.telemetry = Some(false);
+        }
+    }
+
+    pub(super) fn toggle_telemetry(&mut self) {
+        let enabled = self.config.telemetry != Some(true);
+        self.set_telemetry(enabled);
+    }
+
+    pub(super) fn toggle_update_check(&mut self) {
+        let enabled = !self.config.check_for_updates;
+        self.config_mut().check_for_updates = enabled;
+        self.persist_config_change("/check_for_updates", serde_json::Value::Bool(enabled));
+        let status = if enabled {
+            t!("telemetry.update_check_enabled")
+        } else {
+            t!("telemetry.update_check_disabled")
+        };
+        self.set_status_message(status.to_string());
+    }
+
+    fn set_telemetry(&mut self, enabled: bool) {
+        self.config_mut().telemetry = Some(enabled);
+        self.persist_config_change("/telemetry", serde_json::Value::Bool(enabled));
+        let status = if enabled {
+            t!("telemetry.enabled")
+        } else {
+            t!("telemetry.disabled")
+        };
+        self.set_status_message(status.to_string());
+    }
+
+    /// Open the *Telemetry Data* buffer: both switches, the exact JSON body
+    /// of the daily ping and where it goes, and what the update check sends.
+    pub(super) fn show_telemetry_data(&mut self) {
+        self.ensure_telemetry_data_mode_registered();
+        let window = self.active_window_mut();
+        let existing = window
+            .buffer_metadata
+            .iter()
+            .find(|(_, meta)| meta.virtual_mode() == Some(TELEMETRY_DATA_MODE))
+            .map(|(id, _)| *id);
+        let buffer_id = match existing {
+            Some(id) => id,
+            None => {
+                let id = window.create_virtual_buffer(
+                    TELEMETRY_BUFFER_NAME.to_string(),
+                    TELEMETRY_DATA_MODE.to_string(),
+                    true,
+                );
+                if let Some(state) = window.buffers.get_mut(&id) {
+                    state.editing_disabled = true;
+                    state.margins.configure_for_line_numbers(false);
+                }
+                id
+            }
+        };
+        let entries = self.telemetry_data_entries();
+        if let Err(e) = self.set_virtual_buffer_content(buffer_id, entries) {
+            tracing::debug!("Failed to fill the telemetry data buffer: {}", e);
+        }
+        self.set_active_buffer(buffer_id);
+    }
+
+    fn telemetry_data_entries(&self) -> Vec<TextPropertyEntry> {
+        let state = |on: Option<bool>| match on {
+            Some(true) => t!("telemetry.state_on"),
+            Some(false) => t!("telemetry.state_off"),
+            None => t!("telemetry.state_unset"),
+        };
+        let (url, body) = crate::services::telemetry::preview(
+            &self.dir_context.data_dir,
+            &t!("telemetry.data_new_id"),
+        );
+
+        let mut text = format!(
+            "{}\n\n{}\n",
+            t!("telemetry.data_header"),
+            t!(
+                "telemetry.data_settings",
+                telemetry = state(self.config.telemetry),
+                updates = state(Some(self.config.check_for_updates)),
+            )
+        );
+        if self.config.offline {
+            text.push_str(&format!("{}\n", t!("telemetry.data_offline")));
+        }
+        text.push_str(&format!(
+            "\n{}\n\n{}\n\n{}\n",
+            t!("telemetry.data_intro", url = url),
+            body,
+            t!(
+                "telemetry.data_update_check",
+                url = crate::services::release_checker::DEFAULT_RELEASES_URL
+            )
+        ));
+        vec![TextPropertyEntry::text(text)]
+    }
+
+    /// Bind `q` (close) in the telemetry data buffer. Idempotent, like
+    /// `ensure_help_panel_mode_registered`.
+    fn ensure_telemetry_data_mode_registered(&mut self) {
+        {
+            let mut kb = self.keybindings.write().unwrap();
+            kb.clear_plugin_defaults_for_mode(TELEMETRY_DATA_MODE);
+            kb.set_mode_inherits_normal_bindings(TELEMETRY_DATA_MODE, true);
+            kb.load_plugin_default(
+                KeyContext::Mode(TELEMETRY_DATA_MODE.to_string()),
+                KeyCode::Char('q'),
+                KeyModifiers::NONE,
+                Action::CloseTab,
+            );
+        }
+        self.mode_registry.register(
+            BufferMode::new(TELEMETRY_DATA_MODE)
+                .with_read_only(true)
+                .with_inherit_normal_bindings(true),
+        );
+    }
+}
@@ -587,6 +587,8 @@ impl Window {
                                     wrap_column: None,
                                     rulers,
                                     scroll_offset: 0,
+                                    scroll_past_end: false,
+                                    typewriter_scrolling: crate::config::TypewriterScrolling::Off,
                                 },
                             );
                             // Terminal buffers grid-wrap at the PTY
@@ -1247,7 +1249,7 @@ impl Editor {
         let new_leaf = match new_leaf {
             Ok(leaf) => leaf,
             Err(e) => {
-                self.set_status_message(t!("split.error", error = e.to_string()).to_string());
+                self.set_status_error(t!("split.error", error = e.to_string()).to_string());
                 return;
             }
         };
@@ -1266,6 +1268,8 @@ impl Editor {
                 .resolve_wrap_column_for_buffer(buffer_id),
             rulers: self.config.editor.rulers.clone(),
             scroll_offset: 0,
+            scroll_past_end: false,
+            typewriter_scrolling: crate::config::TypewriterScrolling::Off,
         });
         // Terminals grid-wrap at the PTY width (fresh#2649).
         view_state.viewport.line_wrap_enabled = true;
@@ -1695,6 +1699,28 @@ impl Window {
         }
     }
 
+    /// Paste text into this window's active terminal. When the program
+    /// running there enabled bracketed paste (DECSET 2004) the text is
+    /// wrapped in `ESC[200~` … `ESC[201~`, so a shell inserts it as one
+    /// edit instead of executing each line as it arrives. An embedded end
+    /// marker is stripped first so pasted content can't close the bracket
+    /// early and smuggle the rest through as typed input.
+    pub fn send_terminal_paste(&mut self, text: &str) {
+        let bracketed = self
+            .get_active_terminal_state()
+            .map(|s| s.is_bracketed_paste())
+            .unwrap_or(false);
+        if bracketed {
+            let mut data = Vec::with_capacity(text.len() + 12);
+            data.extend_from_slice(b"\x1b[200~");
+            data.extend_from_slice(text.replace("\x1b[201~", "").as_bytes());
+            data.extend_from_slice(b"\x1b[201~");
+            self.send_terminal_input(&data);
+        } else {
+            self.send_terminal_input(text.as_bytes());
+        }
+    }
+
     /// Send a key event to this window's active terminal. Picks
     /// "application cursor" vs "normal cursor" escape sequences
     /// based on the terminal's current state.
@@ -257,15 +257,17 @@ impl Window {
             compose_width,
         )?;
 
-        let pos = crate::model::buffer_position::byte_to_2d(&state.buffer, byte_pos);
-        let line_bytes = state.buffer.get_line(pos.line)?;
+        let line_number = state.buffer.get_line_number(byte_pos);
+        let line_start = state.buffer.line_start_offset(line_number).unwrap_or(0);
+        let line_bytes = state.buffer.get_line(line_number)?;
         let line = String::from_utf8_lossy(&line_bytes);
         let line = line.strip_suffix('\n').unwrap_or(&line);
-        // `pos.column` is a byte offset within the line; convert to a char
-        // column for the (char-indexed) detector.
+        // Convert the byte offset within the line to a char column for the
+        // (char-indexed) detector.
+        let byte_col = byte_pos.saturating_sub(line_start);
         let char_col = line
             .char_indices()
-            .take_while(|(b, _)| *b < pos.column)
+            .take_while(|(b, _)| *b < byte_col)
             .count();
 
         let link = crate::services::terminal::path_link::detect_link_at(line, char_col)?;
@@ -0,0 +1,90 @@
+//! Terminal capability probe handling
+//!
+//! Folds the host terminal's query replies into the editor's
+//! [`TerminalCapabilities`](crate::services::terminal_probe::TerminalCapabilities),
+//! turns off what the terminal can't do, and shows the findings.
+
+use super::Editor;
+use crate::services::terminal_probe::Support;
+use rust_i18n::t;
+
+impl Editor {
+    /// Record the host terminal's answer to the capability query, turning
+    /// off what it can't do once it has answered in full. Returns whether
+    /// anything on screen may have changed.
+    pub fn apply_terminal_reply(&mut self, reply: fresh_input_parser::TerminalReply) -> bool {
+        let was_settled = self.terminal_capabilities.settled;
+        if !self.terminal_capabilities.apply_reply(reply) {
+            return false;
+        }
+        tracing::debug!("Terminal capabilities: {:?}", self.terminal_capabilities);
+        let mut redraw = false;
+        if let Some(wizard) = self.calibration_wizard.as_mut() {
+            wizard.keyboard_protocol = self.terminal_capabilities.keyboard;
+            redraw = true;
+        }
+        if self.terminal_capabilities.settled && !was_settled {
+            redraw |= self.degrade_for_terminal();
+        }
+        redraw
+    }
+
+    /// Start over from what the environment says about the host terminal,
+    /// ahead of a fresh capability query.
+    pub fn set_terminal_capabilities(
+        &mut self,
+        capabilities: crate::services::terminal_probe::TerminalCapabilities,
+    ) {
+        self.terminal_capabilities = capabilities;
+    }
+
+    /// Mark the display as rendering every style, for front ends that draw
+    /// the cells themselves rather than through a terminal (GUI, web).
+    pub fn set_styled_underlines_supported(&mut self) {
+        self.terminal_capabilities.styled_underlines = Support::Yes;
+    }
+
+    /// Turn off features the terminal reported it doesn't support.
+    fn degrade_for_terminal(&mut self) -> bool {
+        if self.terminal_capabilities.mouse != Support::No || !self.is_mouse_enabled() {
+            return false;
+        }
+        self.set_mouse_capture(false);
+        // Best-effort: the terminal ignores mouse modes anyway.
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = crossterm::execute!(std::io::stdout(), crossterm::event::DisableMouseCapture);
+        self.set_status_message(t!("probe.mouse_disabled_status").to_string());
+        true
+    }
+
+    /// Show what the terminal supports and which features were turned off.
+    pub fn show_terminal_capabilities_popup(&mut self) {
+        use crate::view::popup::{Popup, PopupPosition};
+        use ratatui::style::Style;
+
+        let lines = self.terminal_capabilities.report_lines();
+        let popup = {
+            let theme = self.theme.read().unwrap();
+            let mut p = Popup::text(lines, &theme)
+                .with_title(t!("probe.title").to_string())
+                .with_focused(true);
+            p.transient = false;
+            p.position = PopupPosition::Centered;
+            p.width = 64;
+            p.max_height = 14;
+            p.background_style = Style::default().bg(theme.popup_bg);
+            p
+        };
+
+        let buffer_id = self.active_buffer();
+        if let Some(state) = self
+            .windows
+            .get_mut(&self.active_window)
+            .map(|w| &mut w.buffers)
+            .expect("active window present")
+            .get_mut(&buffer_id)
+        {
+            state.popups.show(popup);
+        }
+    }
+}
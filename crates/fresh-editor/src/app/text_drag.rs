@@ -0,0 +1,181 @@
+//! Drag-and-drop of selected text with the mouse.
+//!
+//! A left press inside the (single, stream) selection doesn't collapse it;
+//! it records a [`TextDragState`]. Moving the pointer tracks the byte
+//! position under it — in any split, so text can be dragged across splits
+//! and buffers — and releasing drops the text there: moved by default,
+//! copied when `Ctrl` or `Alt` is held at the drop. A release without
+//! movement is an ordinary click and puts the cursor at the press.
+//!
+//! Within one buffer the delete and insert land as a single bulk edit, so
+//! one undo puts the text back. Across buffers each side gets its own
+//! undo step. Either way the dropped text ends up selected at the drop
+//! site, with the cursor at its end.
+
+use std::ops::Range;
+
+use crossterm::event::KeyModifiers;
+
+use super::types::TextDragState;
+use super::Editor;
+use crate::model::event::{BufferId, Event, LeafId};
+
+impl Editor {
+    /// The primary selection, if a press at `position` should start
+    /// dragging it: a single cursor with a non-empty stream selection that
+    /// contains the position.
+    pub(super) fn draggable_selection_at(&self, position: usize) -> Option<Range<usize>> {
+        let cursors = self.active_cursors();
+        if cursors.count() != 1 {
+            return None;
+        }
+        let cursor = cursors.primary();
+        if cursor.has_block_selection() {
+            return None;
+        }
+        cursor
+            .selection_range()
+            .filter(|range| !range.is_empty() && range.contains(&position))
+    }
+
+    /// Track the pointer during a selection drag.
+    pub(super) fn handle_text_drag(&mut self, col: u16, row: u16) {
+        let drop_target = self.text_drop_target_at(col, row);
+        if let Some(drag) = self.active_window_mut().mouse_state.dragging_text.as_mut() {
+            drag.current_position = (col, row);
+            drag.drop_target = drop_target;
+        }
+    }
+
+    /// Complete a selection drag on mouse release.
+    pub(super) fn finish_text_drag(&mut self, drag: TextDragState, modifiers: KeyModifiers) {
+        if !drag.is_dragging() {
+            self.collapse_selection_to(drag.press_position);
+            return;
+        }
+        // Released over chrome or a buffer that can't take text: cancel,
+        // leaving the selection where it was.
+        let Some((target_split, target_buffer, drop)) = drag.drop_target else {
+            return;
+        };
+        let range = drag.range.clone();
+        let source_editable = self.buffer_is_editable(drag.source_buffer_id);
+        let copy =
+            modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) || !source_editable;
+        let same_buffer = target_buffer == drag.source_buffer_id;
+        if same_buffer && !copy && drop >= range.start && drop <= range.end {
+            // Dropped onto itself.
+            return;
+        }
+
+        let Some(text) = self
+            .active_window_mut()
+            .buffers
+            .get_mut(&drag.source_buffer_id)
+            .map(|state| state.get_text_range(range.start, range.end))
+        else {
+            return;
+        };
+
+        if !copy && !same_buffer {
+            // The source is still the active split: cut there first, as
+            // its own undo step.
+            let cursor_id = self.active_cursors().primary_id();
+            self.log_and_apply_event(&Event::Delete {
+                range: range.clone(),
+                deleted_text: text.clone(),
+                cursor_id,
+            });
+        }
+
+        self.focus_split(target_split, target_buffer);
+        self.active_window_mut().key_context = crate::input::keybindings::KeyContext::Normal;
+
+        let cursor_id = self.active_cursors().primary_id();
+        let move_within_buffer = !copy && same_buffer;
+        let mut events = Vec::new();
+        if move_within_buffer {
+            events.push(Event::Delete {
+                range: range.clone(),
+                deleted_text: text.clone(),
+                cursor_id,
+            });
+        }
+        // Bulk edits address the pre-edit buffer; the selection is
+        // placed in post-edit coordinates.
+        let dropped_start = if move_within_buffer && drop > range.end {
+            drop - range.len()
+        } else {
+            drop
+        };
+        let dropped_end = dropped_start + text.len();
+        events.push(Event::Insert {
+            position: drop,
+            text,
+            cursor_id,
+        });
+        let description = if copy { "Copy Text" } else { "Move Text" };
+        if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description.to_string()) {
+            self.active_event_log_mut().append(bulk_edit);
+        }
+        self.move_primary_cursor(dropped_end, Some(dropped_start));
+    }
+
+    /// Split, buffer and byte position under a screen cell, when that cell
+    /// is in the content area of a buffer that accepts dropped text.
+    fn text_drop_target_at(&self, col: u16, row: u16) -> Option<(LeafId, BufferId, usize)> {
+        let (split_id, buffer_id, content_rect) = self
+            .active_layout()
+            .split_areas
+            .iter()
+            .find(|(_, _, rect, ..)| {
+                col >= rect.x
+                    && col < rect.x + rect.width
+                    && row >= rect.y
+                    && row < rect.y + rect.height
+            })
+            .map(|(split_id, buffer_id, rect, ..)| (*split_id, *buffer_id, *rect))?;
+        let window = self.active_window();
+        if window.is_terminal_buffer(buffer_id)
+            || window.is_composite_buffer(buffer_id)
+            || !self.buffer_is_editable(buffer_id)
+        {
+            return None;
+        }
+        let position = self.content_position_at(split_id, buffer_id, content_rect, col, row)?;
+        Some((split_id, buffer_id, position))
+    }
+
+    fn buffer_is_editable(&self, buffer_id: BufferId) -> bool {
+        self.buffers()
+            .get(&buffer_id)
+            .is_some_and(|state| !state.editing_disabled)
+    }
+
+    /// The deferred plain click of a press that started inside the
+    /// selection: drop the selection and put the cursor at the press.
+    fn collapse_selection_to(&mut self, position: usize) {
+        self.move_primary_cursor(position, None);
+    }
+
+    fn move_primary_cursor(&mut self, position: usize, anchor: Option<usize>) {
+        let cursors = self.active_cursors();
+        let cursor_id = cursors.primary_id();
+        let cursor = *cursors.primary();
+        let new_sticky_column = self.buffers().get(&self.active_buffer()).and_then(|state| {
+            crate::primitives::display_width::visual_column_of(&state.buffer, position)
+        });
+        let event = Event::MoveCursor {
+            cursor_id,
+            old_position: cursor.position,
+            new_position: position,
+            old_anchor: cursor.anchor,
+            new_anchor: anchor,
+            old_sticky_column: cursor.sticky_column,
+            new_sticky_column,
+        };
+        self.active_event_log_mut().append(event.clone());
+        self.apply_event_to_active_buffer(&event);
+        self.track_cursor_movement(&event);
+    }
+}
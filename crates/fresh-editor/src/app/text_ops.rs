@@ -1,13 +1,22 @@
 //! Text-manipulation orchestrators on `Editor`.
 //!
-//! Smart-home, comment toggling, bracket matching — operations that read
-//! cursor + buffer state, compute a target position or edit, and apply
-//! events to the active buffer. Pure decision logic for smart-home lives
-//! in `super::smart_home`; these methods are the cross-cutting drivers.
+//! Smart-home, comment toggling, joining and splitting lines, bracket
+//! matching — operations that read cursor + buffer state, compute a target
+//! position or edit, and apply events to the active buffer. Pure decision
+//! logic for smart-home lives in `super::smart_home`; these methods are the
+//! cross-cutting drivers.
+//! Describe Character reads the same state but reports in a popup.
+
+use std::collections::BTreeMap;
 
 use rust_i18n::t;
 
-use crate::model::event::{Event, LeafId};
+use crate::model::cursor::Cursor;
+use crate::model::event::{CursorId, Event, LeafId};
+use crate::primitives::comment_toggle::{
+    map_offset, toggle_block_comment, toggle_line_comments, CommentEdit, CommentTokens, ToggleKind,
+};
+use crate::primitives::line_join::{join_lines, split_line};
 
 use super::Editor;
 
@@ -178,194 +187,397 @@ impl Editor {
         }
     }
 
-    /// Toggle comment on the current line or selection
-    pub(super) fn toggle_comment(&mut self) {
-        // Determine comment prefix from language config
-        // If no language detected or no comment prefix configured, do nothing
-        let language = &self.active_state().language;
-        let comment_prefix = self
-            .config
-            .languages
-            .get(language)
-            .and_then(|lang_config| lang_config.comment_prefix.clone());
-
-        let comment_prefix: String = match comment_prefix {
-            Some(prefix) => {
-                // Ensure there's a trailing space for consistent formatting
-                if prefix.ends_with(' ') {
-                    prefix
-                } else {
-                    format!("{} ", prefix)
-                }
-            }
-            None => return, // No comment prefix for this language, do nothing
+    /// Comment delimiters for the active buffer's language, from its
+    /// `comment_prefix` and `block_comment_start`/`block_comment_end`.
+    fn comment_tokens(&self) -> CommentTokens {
+        let Some(lang_config) = self.config.languages.get(&self.active_state().language) else {
+            return CommentTokens::default();
         };
+        let non_empty = |token: &Option<String>| {
+            token
+                .as_deref()
+                .map(str::trim)
+                .filter(|token| !token.is_empty())
+                .map(str::to_string)
+        };
+        CommentTokens {
+            line: non_empty(&lang_config.comment_prefix),
+            block: non_empty(&lang_config.block_comment_start)
+                .zip(non_empty(&lang_config.block_comment_end)),
+        }
+    }
 
-        let estimated_line_length = self.config.editor.estimated_line_length;
+    /// Toggle line comments on the lines touched by every cursor or
+    /// selection. Languages without a line comment get each line wrapped
+    /// in block comment tokens instead.
+    pub(super) fn toggle_comment(&mut self) {
+        let tokens = self.comment_tokens();
+        if tokens.is_empty() {
+            self.set_status_message(t!("comment.unsupported").to_string());
+            return;
+        }
 
-        let cursor = *self.active_cursors().primary();
-        let cursor_id = self.active_cursors().primary_id();
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let cursors: Vec<(CursorId, Cursor)> = self
+            .active_cursors()
+            .iter()
+            .map(|(id, c)| (id, *c))
+            .collect();
         let state = self.active_state_mut();
 
-        // Save original selection info to restore after edit
-        let original_anchor = cursor.anchor;
-        let original_position = cursor.position;
-        let had_selection = original_anchor.is_some();
+        // Every line a cursor touches, once. A selection ending at the start
+        // of a line does not include that line.
+        let mut lines: BTreeMap<usize, String> = BTreeMap::new();
+        for (_, cursor) in &cursors {
+            let (start, end) = cursor
+                .selection_range()
+                .map_or((cursor.position, cursor.position), |r| (r.start, r.end));
+            let mut iter = state.buffer.line_iterator(start, estimated_line_length);
+            let first_line_start = iter.current_position();
+            while let Some((line_start, content)) = iter.next_line() {
+                if line_start > first_line_start && line_start >= end {
+                    break;
+                }
+                lines.insert(
+                    line_start,
+                    content.trim_end_matches(['\n', '\r']).to_string(),
+                );
+            }
+            // The empty last line of a buffer ending in a newline.
+            lines.entry(first_line_start).or_default();
+        }
 
-        let (start_pos, end_pos) = if let Some(range) = cursor.selection_range() {
-            (range.start, range.end)
-        } else {
-            let iter = state
-                .buffer
-                .line_iterator(cursor.position, estimated_line_length);
-            let line_start = iter.current_position();
-            (line_start, cursor.position)
+        let line_refs: Vec<(usize, &str)> = lines
+            .iter()
+            .map(|(&start, line)| (start, line.as_str()))
+            .collect();
+        let Some((kind, edits)) = toggle_line_comments(&line_refs, &tokens) else {
+            return;
         };
+        let mut changed_lines: Vec<usize> = edits
+            .iter()
+            .filter_map(|edit| lines.range(..=edit.offset).next_back().map(|(&s, _)| s))
+            .collect();
+        changed_lines.dedup();
+        let count = changed_lines.len();
 
-        // Find all line starts in the range
-        let buffer_len = state.buffer.len();
-        let mut line_starts = Vec::new();
-        let mut iter = state.buffer.line_iterator(start_pos, estimated_line_length);
-        let mut current_pos = iter.current_position();
-        line_starts.push(current_pos);
-
-        while let Some((_, content)) = iter.next_line() {
-            current_pos += content.len();
-            if current_pos >= end_pos || current_pos >= buffer_len {
-                break;
-            }
-            let next_iter = state
-                .buffer
-                .line_iterator(current_pos, estimated_line_length);
-            let next_start = next_iter.current_position();
-            if next_start != *line_starts.last().unwrap() {
-                line_starts.push(next_start);
-            }
-            iter = state
-                .buffer
-                .line_iterator(current_pos, estimated_line_length);
-        }
+        let action_desc = match kind {
+            ToggleKind::Comment => "Comment",
+            ToggleKind::Uncomment => "Uncomment",
+        };
+        self.apply_comment_edits(&cursors, edits, format!("{} lines", action_desc));
+        self.set_status_message(
+            t!("lines.action", action = action_desc, count = count).to_string(),
+        );
+    }
 
-        // Determine if we should comment or uncomment
-        // If all lines are commented, uncomment; otherwise comment
-        let all_commented = line_starts.iter().all(|&line_start| {
-            let line_bytes = state
-                .buffer
-                .slice_bytes(line_start..buffer_len.min(line_start + comment_prefix.len() + 10));
-            let line_str = String::from_utf8_lossy(&line_bytes);
-            let trimmed = line_str.trim_start();
-            trimmed.starts_with(comment_prefix.trim())
-        });
+    /// Toggle a block comment around every selection, or around the
+    /// content of the cursor's line when nothing is selected. Languages
+    /// without block comments toggle line comments instead.
+    pub(super) fn toggle_block_comment(&mut self) {
+        let Some((open, close)) = self.comment_tokens().block else {
+            self.toggle_comment();
+            return;
+        };
 
-        let mut events = Vec::new();
-        // Track (edit_position, byte_delta) for calculating new cursor positions
-        // delta is positive for insertions, negative for deletions
-        let mut position_deltas: Vec<(usize, isize)> = Vec::new();
-
-        if all_commented {
-            // Uncomment: remove comment prefix from each line
-            for &line_start in line_starts.iter().rev() {
-                let line_bytes = state
-                    .buffer
-                    .slice_bytes(line_start..buffer_len.min(line_start + 100));
-                let line_str = String::from_utf8_lossy(&line_bytes);
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let cursors: Vec<(CursorId, Cursor)> = self
+            .active_cursors()
+            .iter()
+            .map(|(id, c)| (id, *c))
+            .collect();
 
-                // Find where the comment prefix starts (after leading whitespace)
-                let leading_ws: usize = line_str
-                    .chars()
-                    .take_while(|c| c.is_whitespace() && *c != '\n')
-                    .map(|c| c.len_utf8())
-                    .sum();
-                let rest = &line_str[leading_ws..];
-
-                if rest.starts_with(comment_prefix.trim()) {
-                    let remove_len = if rest.starts_with(&comment_prefix) {
-                        comment_prefix.len()
-                    } else {
-                        comment_prefix.trim().len()
-                    };
-                    let deleted_text = String::from_utf8_lossy(&state.buffer.slice_bytes(
-                        line_start + leading_ws..line_start + leading_ws + remove_len,
-                    ))
-                    .to_string();
-                    events.push(Event::Delete {
-                        range: (line_start + leading_ws)..(line_start + leading_ws + remove_len),
-                        deleted_text,
-                        cursor_id,
-                    });
-                    position_deltas.push((line_start, -(remove_len as isize)));
+        let mut ranges: Vec<(usize, usize)> = cursors
+            .iter()
+            .map(|(_, cursor)| match cursor.selection_range() {
+                Some(range) => (range.start, range.end),
+                None => {
+                    let state = self.active_state_mut();
+                    let mut iter = state
+                        .buffer
+                        .line_iterator(cursor.position, estimated_line_length);
+                    match iter.next_line() {
+                        Some((start, content)) => {
+                            (start, start + content.trim_end_matches(['\n', '\r']).len())
+                        }
+                        None => (cursor.position, cursor.position),
+                    }
                 }
+            })
+            .collect();
+        ranges.sort_unstable();
+        ranges.dedup();
+
+        let mut edits = Vec::new();
+        let mut added = 0;
+        let mut removed = 0;
+        let mut covered_to = 0;
+        for (start, end) in ranges {
+            // Overlapping ranges would produce interleaved edits.
+            if start < covered_to {
+                continue;
             }
-        } else {
-            // Comment: add comment prefix to each line
-            let prefix_len = comment_prefix.len();
-            for &line_start in line_starts.iter().rev() {
-                events.push(Event::Insert {
-                    position: line_start,
-                    text: comment_prefix.to_string(),
-                    cursor_id,
-                });
-                position_deltas.push((line_start, prefix_len as isize));
+            covered_to = end.max(start + 1);
+            let text = self.active_state_mut().get_text_range(start, end);
+            let (kind, range_edits) = toggle_block_comment(start, &text, &open, &close);
+            match kind {
+                ToggleKind::Comment => added += 1,
+                ToggleKind::Uncomment => removed += 1,
             }
+            edits.extend(range_edits);
         }
 
-        if events.is_empty() {
-            return;
-        }
-
-        let action_desc = if all_commented {
-            "Uncomment"
+        let description = if removed > 0 && added == 0 {
+            "Uncomment block"
         } else {
-            "Comment"
+            "Comment block"
         };
+        self.apply_comment_edits(&cursors, edits, description.to_string());
+        let message = if added > 0 {
+            t!("comment.block_added", count = added + removed)
+        } else {
+            t!("comment.block_removed", count = removed)
+        };
+        self.set_status_message(message.to_string());
+    }
 
-        // If there was a selection, add a MoveCursor event to restore it
-        if had_selection {
-            // Sort deltas by position ascending for calculation
-            position_deltas.sort_by_key(|(pos, _)| *pos);
-
-            // Calculate cumulative shift for a position based on edits at or before that position
-            let calc_shift = |original_pos: usize| -> isize {
-                let mut shift: isize = 0;
-                for (edit_pos, delta) in &position_deltas {
-                    if *edit_pos < original_pos {
-                        shift += delta;
+    /// Apply ascending comment `edits` as one undoable bulk edit, carrying
+    /// each cursor (and its selection, grown or shrunk by the markers)
+    /// across them.
+    fn apply_comment_edits(
+        &mut self,
+        cursors: &[(CursorId, Cursor)],
+        edits: Vec<CommentEdit>,
+        description: String,
+    ) {
+        let moves = cursors
+            .iter()
+            .map(|&(cursor_id, cursor)| {
+                let target = match cursor.anchor {
+                    Some(anchor) => {
+                        // The selection start stays in front of a marker
+                        // inserted there, so the selection covers the comment.
+                        let forward = anchor <= cursor.position;
+                        (
+                            map_offset(&edits, cursor.position, !forward),
+                            Some(map_offset(&edits, anchor, forward)),
+                        )
                     }
-                }
-                shift
-            };
-
-            let anchor_shift = calc_shift(original_anchor.unwrap_or(0));
-            let position_shift = calc_shift(original_position);
+                    None => (map_offset(&edits, cursor.position, false), None),
+                };
+                (cursor_id, cursor, target)
+            })
+            .collect();
+        self.apply_planned_edits(edits, moves, description);
+    }
 
-            let new_anchor = (original_anchor.unwrap_or(0) as isize + anchor_shift).max(0) as usize;
-            let new_position = (original_position as isize + position_shift).max(0) as usize;
+    /// Apply ascending `edits` as one undoable bulk edit, then move each
+    /// cursor to its `(position, anchor)` target in the edited text.
+    fn apply_planned_edits(
+        &mut self,
+        edits: Vec<CommentEdit>,
+        moves: Vec<(CursorId, Cursor, (usize, Option<usize>))>,
+        description: String,
+    ) {
+        if edits.is_empty() {
+            return;
+        }
+        let state = self.active_state();
+        let sentinel = CursorId::UNDO_SENTINEL;
+        let mut events = Vec::new();
+        for edit in edits.iter().rev() {
+            if edit.delete > 0 {
+                let range = edit.offset..edit.offset + edit.delete;
+                events.push(Event::Delete {
+                    deleted_text: String::from_utf8_lossy(&state.buffer.slice_bytes(range.clone()))
+                        .into_owned(),
+                    range,
+                    cursor_id: sentinel,
+                });
+            }
+            if !edit.insert.is_empty() {
+                events.push(Event::Insert {
+                    position: edit.offset,
+                    text: edit.insert.clone(),
+                    cursor_id: sentinel,
+                });
+            }
+        }
 
+        for (cursor_id, cursor, (new_position, new_anchor)) in moves {
             events.push(Event::MoveCursor {
                 cursor_id,
-                old_position: original_position,
+                old_position: cursor.position,
                 new_position,
-                old_anchor: original_anchor,
-                new_anchor: Some(new_anchor),
-                old_sticky_column: None,
+                old_anchor: cursor.anchor,
+                new_anchor,
+                old_sticky_column: cursor.sticky_column,
                 new_sticky_column: None,
             });
         }
 
-        // Use optimized bulk edit for multi-line comment toggle
-        let description = format!("{} lines", action_desc);
         if let Some(bulk_edit) = self.apply_events_as_bulk_edit(events, description) {
             self.active_event_log_mut().append(bulk_edit);
         }
+    }
 
-        self.set_status_message(
-            t!(
-                "lines.action",
-                action = action_desc,
-                count = line_starts.len()
-            )
-            .to_string(),
-        );
+    /// Join each cursor's line with the next one, or the lines a selection
+    /// touches into one. The cursor lands where the last two lines met.
+    pub(super) fn join_lines(&mut self) {
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let comment_prefix = self.comment_tokens().line;
+        let cursors: Vec<(CursorId, Cursor)> = self
+            .active_cursors()
+            .iter()
+            .map(|(id, c)| (id, *c))
+            .collect();
+        let state = self.active_state_mut();
+
+        // Per cursor, the starts of the first and last line to join: the
+        // lines a selection touches, or the cursor's line and the next one.
+        // A selection ending at the start of a line does not include that
+        // line.
+        let buffer_len = state.buffer.len();
+        let mut spans: Vec<(usize, usize, CursorId)> = Vec::new();
+        for &(cursor_id, cursor) in &cursors {
+            let (start, end) = cursor
+                .selection_range()
+                .map_or((cursor.position, cursor.position), |r| {
+                    (r.start, r.end.saturating_sub(1).max(r.start))
+                });
+            let mut iter = state.buffer.line_iterator(start, estimated_line_length);
+            let first = iter.current_position();
+            let mut last = first;
+            while let Some((line_start, content)) = iter.next_line() {
+                // The empty line after a final newline is not joined.
+                if line_start > first && line_start >= buffer_len {
+                    break;
+                }
+                last = line_start;
+                let holds_end = line_start + content.len() > end;
+                if !content.ends_with('\n') || (holds_end && line_start > first) {
+                    break;
+                }
+            }
+            spans.push((first, last, cursor_id));
+        }
+        spans.sort_unstable_by_key(|&(first, last, _)| (first, last));
+
+        // Join every group of lines once, merging groups that share lines.
+        let mut groups: Vec<(usize, usize)> = Vec::new();
+        for &(first, last, _) in spans.iter().filter(|(first, last, _)| first < last) {
+            match groups.last_mut() {
+                Some((_, group_last)) if first <= *group_last => {
+                    *group_last = (*group_last).max(last);
+                }
+                _ => groups.push((first, last)),
+            }
+        }
+        let mut edits = Vec::new();
+        // Where the break after each joined line ends up, keyed by line start.
+        let mut join_points: BTreeMap<usize, usize> = BTreeMap::new();
+        for (first, last) in groups {
+            let mut lines: Vec<(usize, String)> = Vec::new();
+            let mut iter = state.buffer.line_iterator(first, estimated_line_length);
+            while let Some((line_start, content)) = iter.next_line() {
+                if line_start > last {
+                    break;
+                }
+                lines.push((
+                    line_start,
+                    content.trim_end_matches(['\n', '\r']).to_string(),
+                ));
+            }
+            let line_refs: Vec<(usize, &str)> = lines
+                .iter()
+                .map(|(start, line)| (*start, line.as_str()))
+                .collect();
+            let group_edits = join_lines(&line_refs, comment_prefix.as_deref());
+            for ((line_start, _), edit) in lines.iter().zip(&group_edits) {
+                join_points.insert(*line_start, edit.offset);
+            }
+            edits.extend(group_edits);
+        }
+        if edits.is_empty() {
+            return;
+        }
+
+        let moves = cursors
+            .iter()
+            .map(|&(cursor_id, cursor)| {
+                // The break removed last among the cursor's own lines.
+                let join_point = spans
+                    .iter()
+                    .find(|(_, _, id)| *id == cursor_id)
+                    .and_then(|&(first, last, _)| join_points.range(first..last).next_back())
+                    .map(|(_, &offset)| offset);
+                let position = match join_point {
+                    Some(offset) => map_offset(&edits, offset, true),
+                    None => map_offset(&edits, cursor.position, false),
+                };
+                (cursor_id, cursor, (position, None))
+            })
+            .collect();
+        self.apply_planned_edits(edits, moves, "Join lines".to_string());
+    }
+
+    /// Split the line at every cursor, leaving the cursor at the end of
+    /// the first half. See [`split_line`] for the whitespace handling.
+    pub(super) fn split_line(&mut self) {
+        let estimated_line_length = self.config.editor.estimated_line_length;
+        let comment_prefix = self.comment_tokens().line;
+        let cursors: Vec<(CursorId, Cursor)> = self
+            .active_cursors()
+            .iter()
+            .map(|(id, c)| (id, *c))
+            .collect();
+        let state = self.active_state_mut();
+        let line_ending = state.buffer.line_ending().insertion_str();
+
+        let mut positions: Vec<usize> = cursors.iter().map(|(_, c)| c.position).collect();
+        positions.sort_unstable();
+        positions.dedup();
+        let mut edits: Vec<CommentEdit> = Vec::new();
+        for position in positions {
+            let mut iter = state.buffer.line_iterator(position, estimated_line_length);
+            let Some((line_start, content)) = iter.next_line() else {
+                continue;
+            };
+            let line = content.trim_end_matches(['\n', '\r']);
+            let column = (position - line_start).min(line.len());
+            let edit = split_line(
+                line_start,
+                line,
+                column,
+                line_ending,
+                comment_prefix.as_deref(),
+            );
+            // Cursors in the same run of whitespace split it once.
+            if let Some(prev) = edits.last() {
+                if edit.offset < prev.offset + prev.delete {
+                    continue;
+                }
+            }
+            edits.push(edit);
+        }
+
+        let moves = cursors
+            .iter()
+            .map(|&(cursor_id, cursor)| {
+                let split_at = edits
+                    .iter()
+                    .find(|edit| {
+                        (edit.offset..=edit.offset + edit.delete).contains(&cursor.position)
+                    })
+                    .map_or(cursor.position, |edit| edit.offset);
+                (
+                    cursor_id,
+                    cursor,
+                    (map_offset(&edits, split_at, true), None),
+                )
+            })
+            .collect();
+        self.apply_planned_edits(edits, moves, "Split line".to_string());
     }
 
     /// Go to matching bracket
@@ -527,4 +739,62 @@ impl Editor {
             self.set_status_message(t!("diagnostics.bracket_no_match").to_string());
         }
     }
+
+    /// Describe Character: show the code point, name, UTF-8 bytes and
+    /// general category of the character under the primary cursor.
+    pub(super) fn describe_char_at_cursor(&mut self) {
+        use crate::primitives::char_info::CharInfo;
+        use crate::view::popup::{Popup, PopupPosition};
+        use ratatui::style::Style;
+
+        let pos = self.active_cursors().primary().position;
+        let state = self.active_state();
+        let bytes = state
+            .buffer
+            .slice_bytes(pos..(pos + 4).min(state.buffer.len()));
+        // At most four bytes were read, so a character may be cut off at the
+        // end; only the first one matters.
+        let valid = match std::str::from_utf8(&bytes) {
+            Ok(text) => text,
+            Err(e) => std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default(),
+        };
+        let Some(ch) = valid.chars().next() else {
+            let message = match bytes.first() {
+                Some(byte) => t!("char_info.invalid_byte", byte = format!("{byte:02X}")),
+                None => t!("char_info.none"),
+            };
+            self.set_status_message(message.to_string());
+            return;
+        };
+
+        let info = CharInfo::new(ch);
+        let mut lines = vec![
+            t!("char_info.character", value = ch.escape_debug()).to_string(),
+            t!("char_info.code_point", value = info.code_point).to_string(),
+        ];
+        if let Some(name) = &info.name {
+            lines.push(t!("char_info.name", value = name).to_string());
+        }
+        lines.push(t!("char_info.utf8", value = info.utf8).to_string());
+        lines.push(t!("char_info.category", value = info.category).to_string());
+
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0) as u16 + 4;
+        let popup = {
+            let theme = self.theme.read().unwrap();
+            let mut p = Popup::text(lines, &theme).with_title(t!("char_info.title").to_string());
+            p.transient = true;
+            p.position = PopupPosition::BelowCursor;
+            p.width = width.clamp(30, 80);
+            p.max_height = 8;
+            p.border_style = Style::default().fg(theme.popup_border_fg);
+            p.background_style = Style::default().bg(theme.popup_bg);
+            p
+        };
+        // Replace a previous description rather than stacking another.
+        let popups = &mut self.active_state_mut().popups;
+        while popups.top().is_some_and(|p| p.transient) {
+            popups.hide();
+        }
+        popups.show(popup);
+    }
 }
@@ -0,0 +1,150 @@
+//! Hot reload of user theme files.
+//!
+//! The user themes directory (`~/.config/fresh/themes/`, including
+//! `packages/`) is polled on the auto-revert interval. When a theme file is
+//! added, removed or rewritten, the registry is rebuilt and the active theme
+//! re-applied, so editing a `.json` / `.toml` theme shows up without a
+//! restart.
+
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, TryRecvError};
+use std::time::{Instant, SystemTime};
+
+use rust_i18n::t;
+
+use crate::view::prompt::PromptType;
+use crate::view::theme::is_theme_file;
+
+use super::Editor;
+
+/// Sorted `(path, mtime, size)` of every theme file and package manifest
+/// under the themes directory. Any difference between two snapshots means
+/// the registry needs rebuilding.
+type ThemeDirFingerprint = Vec<(PathBuf, Option<SystemTime>, u64)>;
+
+/// Polling state for the user themes directory.
+pub(super) struct ThemeDirWatch {
+    /// When the last poll was launched (`None` until the first poll).
+    last_poll: Option<Instant>,
+    /// Snapshot the current registry was built from.
+    fingerprint: ThemeDirFingerprint,
+    /// Result of an in-flight background poll.
+    pending_rx: Option<Receiver<ThemeDirFingerprint>>,
+}
+
+impl ThemeDirWatch {
+    /// Take the baseline snapshot synchronously, right after the registry
+    /// was loaded from the same directory, so an edit made before the first
+    /// background poll is still seen as a change.
+    pub(super) fn new(themes_dir: &Path) -> Self {
+        Self {
+            last_poll: None,
+            fingerprint: fingerprint_dir(themes_dir),
+            pending_rx: None,
+        }
+    }
+}
+
+impl Editor {
+    /// Poll the user themes directory for changes (called from main loop).
+    ///
+    /// Like [`Editor::poll_file_changes`], the directory walk runs on a
+    /// background thread; this launches a poll when the interval has elapsed
+    /// and picks up the result of a prior one. Returns true if the themes
+    /// were reloaded (requires re-render).
+    pub fn poll_theme_changes(&mut self) -> bool {
+        let mut reloaded = false;
+        if let Some(ref rx) = self.theme_dir_watch.pending_rx {
+            match rx.try_recv() {
+                Ok(fingerprint) => {
+                    self.theme_dir_watch.pending_rx = None;
+                    reloaded = self.process_theme_poll_result(fingerprint);
+                }
+                Err(TryRecvError::Empty) => return false,
+                Err(TryRecvError::Disconnected) => {
+                    self.theme_dir_watch.pending_rx = None;
+                }
+            }
+        }
+
+        let poll_interval =
+            std::time::Duration::from_millis(self.config.editor.auto_revert_poll_interval_ms);
+        if let Some(last_poll) = self.theme_dir_watch.last_poll {
+            if self.time_source.elapsed_since(last_poll) < poll_interval {
+                return reloaded;
+            }
+        }
+        self.theme_dir_watch.last_poll = Some(self.time_source.now());
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let themes_dir = self.dir_context.themes_dir();
+        std::thread::Builder::new()
+            .name("poll-theme-changes".to_string())
+            .spawn(move || {
+                let fingerprint = fingerprint_dir(&themes_dir);
+                // Receiver is gone if the editor is shutting down.
+                if tx.send(fingerprint).is_err() {}
+            })
+            .ok();
+        self.theme_dir_watch.pending_rx = Some(rx);
+
+        reloaded
+    }
+
+    /// Compare a fresh snapshot against the last one and reload on change.
+    fn process_theme_poll_result(&mut self, fingerprint: ThemeDirFingerprint) -> bool {
+        if self.theme_dir_watch.fingerprint == fingerprint {
+            return false;
+        }
+
+        // A theme picker is live-previewing and will restore its original
+        // theme on cancel; reloading underneath it would fight the preview.
+        // Keep the old snapshot so the change is picked up once it closes.
+        let picking_theme = self
+            .active_window()
+            .prompt
+            .as_ref()
+            .is_some_and(|p| matches!(p.prompt_type, PromptType::SelectTheme { .. }));
+        if picking_theme {
+            return false;
+        }
+
+        self.theme_dir_watch.fingerprint = fingerprint;
+        self.reload_themes();
+        self.theme.read().unwrap().set_terminal_cursor_color();
+        self.reapply_all_overlays();
+        self.set_status_message(
+            t!(
+                "view.themes_reloaded",
+                theme = self.theme.read().unwrap().name.clone()
+            )
+            .to_string(),
+        );
+        true
+    }
+}
+
+/// Snapshot every theme file and `package.json` manifest under `dir`.
+fn fingerprint_dir(dir: &Path) -> ThemeDirFingerprint {
+    let mut fingerprint = Vec::new();
+    collect_theme_files(dir, &mut fingerprint);
+    fingerprint.sort();
+    fingerprint
+}
+
+fn collect_theme_files(dir: &Path, out: &mut ThemeDirFingerprint) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        if metadata.is_dir() {
+            collect_theme_files(&path, out);
+        } else if is_theme_file(&path) {
+            out.push((path, metadata.modified().ok(), metadata.len()));
+        }
+    }
+}
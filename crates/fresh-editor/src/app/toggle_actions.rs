@@ -605,16 +605,28 @@ impl Editor {
     /// This reloads the config from disk, applies runtime changes (theme, keybindings),
     /// and emits a config_changed event so plugins can update their state accordingly.
     /// Uses the layered config system to properly merge with defaults.
+    ///
+    /// A config that doesn't load leaves the current settings in place and
+    /// enters config rescue mode; one that loads again ends it.
     pub fn reload_config(&mut self) {
+        let (mut config, failure) =
+            Config::load_with_layers_or_failure(&self.dir_context, self.working_dir());
+        if let Some(failure) = failure {
+            self.enter_config_rescue(failure);
+            return;
+        }
+        self.end_config_rescue();
         let old_theme = self.config.theme.clone();
-        self.set_config(Config::load_with_layers(
+        let old_rulers = self.config.editor.rulers.clone();
+        self.apply_cli_overrides(&mut config);
+        self.set_config(config);
+
+        // Refresh cached raw user config for plugins
+        self.set_user_config_raw(Config::read_user_config_raw(
             &self.dir_context,
             self.working_dir(),
         ));
 
-        // Refresh cached raw user config for plugins
-        self.set_user_config_raw(Config::read_user_config_raw(self.working_dir()));
-
         // Apply theme change if needed
         if old_theme != self.config.theme {
             if let Some(theme) = self.theme_registry.get_cloned(&self.config.theme) {
@@ -634,7 +646,7 @@ impl Editor {
             .reload_from_config(&self.config);
 
         // Update clipboard configuration
-        self.clipboard.apply_config(&self.config.clipboard);
+        self.apply_clipboard_config();
 
         // Apply bar visibility changes immediately
         self.active_window_mut().menu_bar_visible = self.config.editor.show_menu_bar;
@@ -642,6 +654,24 @@ impl Editor {
         self.active_window_mut().status_bar_visible = self.config.editor.show_status_bar;
         self.active_window_mut().prompt_line_visible = self.config.editor.show_prompt_line;
 
+        // Move rulers of open buffers to the new config, leaving ones that
+        // were added or removed by hand alone
+        if old_rulers != self.config.editor.rulers {
+            let rulers = self.config.editor.rulers.clone();
+            for window in self.windows.values_mut() {
+                let Some(view_states) = window.split_view_states_mut() else {
+                    continue;
+                };
+                for buffer_view in view_states
+                    .values_mut()
+                    .flat_map(|vs| vs.keyed_states.values_mut())
+                    .filter(|bv| bv.rulers == old_rulers)
+                {
+                    buffer_view.rulers = rulers.clone();
+                }
+            }
+        }
+
         // Update LSP configs
         let __active_id = self.active_window;
         if let Some(lsp) = self.windows.get_mut(&__active_id).map(|w| &mut w.lsp) {
@@ -661,7 +691,7 @@ impl Editor {
         }
 
         // Emit event so plugins know config changed
-        let config_path = Config::find_config_path(self.working_dir());
+        let config_path = Config::find_config_path(&self.dir_context, self.working_dir());
         self.emit_event(
             "config_changed",
             serde_json::json!({
@@ -693,7 +723,11 @@ impl Editor {
         *self.theme_cache.write().unwrap() = self.theme_registry.to_json_map();
 
         // Re-apply current theme if it still exists, otherwise it might have been updated
-        if let Some(theme) = self.theme_registry.get_cloned(&self.config.theme) {
+        let current = self
+            .session_theme
+            .clone()
+            .unwrap_or_else(|| self.config.theme.0.clone());
+        if let Some(theme) = self.theme_registry.get_cloned(&current) {
             *self.theme.write().unwrap() = theme;
         }
 
@@ -0,0 +1,97 @@
+//! Revert Last Transform.
+//!
+//! Whole-buffer transforms (format, trim trailing whitespace, organize
+//! imports) leave a [`TransformSnapshot`] on the buffer. Reverting merges
+//! the pre-transform text with whatever was typed since, so a format can be
+//! backed out without also losing the edits made after it — something plain
+//! undo can't do once those edits are on the stack.
+
+use rust_i18n::t;
+
+use crate::model::event::BufferId;
+use crate::model::line_merge::{merge_lines, MergeError};
+
+use super::types::TransformSnapshot;
+use super::Editor;
+
+impl Editor {
+    /// Record that `buffer_id` was just rewritten by the transform `label`,
+    /// `before` being its text beforehand.
+    ///
+    /// Transforms that run back to back with no edit in between (trim then
+    /// format on save) collapse into one snapshot, so a revert restores the
+    /// text from before the first of them.
+    pub(super) fn record_transform(&mut self, buffer_id: BufferId, label: String, before: String) {
+        let Some(after) = self
+            .buffers()
+            .get(&buffer_id)
+            .and_then(|state| state.buffer.to_string())
+        else {
+            return;
+        };
+        if after == before {
+            return;
+        }
+        let Some(meta) = self.active_window_mut().buffer_metadata.get_mut(&buffer_id) else {
+            return;
+        };
+        let before = match meta.last_transform.take() {
+            Some(prev) if prev.after == before => prev.before,
+            _ => before,
+        };
+        meta.last_transform = Some(TransformSnapshot {
+            label,
+            before,
+            after,
+        });
+    }
+
+    /// Undo the active buffer's last whole-buffer transform, keeping any
+    /// edits made since it ran.
+    pub(super) fn revert_last_transform(&mut self) {
+        let buffer_id = self.active_buffer();
+        let Some(snapshot) = self
+            .active_window()
+            .buffer_metadata
+            .get(&buffer_id)
+            .and_then(|meta| meta.last_transform.clone())
+        else {
+            self.set_status_message(t!("transform.nothing_to_revert").to_string());
+            return;
+        };
+        let Some(current) = self.active_state().buffer.to_string() else {
+            self.set_status_message(t!("error.buffer_not_loaded").to_string());
+            return;
+        };
+
+        let merged = if current == snapshot.after {
+            Ok(snapshot.before)
+        } else {
+            merge_lines(&snapshot.after, &current, &snapshot.before)
+        };
+        let reverted = match merged {
+            Ok(text) => text,
+            Err(MergeError::Conflict) => {
+                self.set_status_message(
+                    t!("transform.revert_conflict", label = &snapshot.label).to_string(),
+                );
+                return;
+            }
+            Err(MergeError::TooLarge) => {
+                self.set_status_message(
+                    t!("transform.revert_too_large", label = &snapshot.label).to_string(),
+                );
+                return;
+            }
+        };
+
+        if let Err(e) = self.replace_buffer_with_output(&reverted) {
+            self.set_status_message(e);
+            return;
+        }
+        if let Some(meta) = self.active_window_mut().buffer_metadata.get_mut(&buffer_id) {
+            meta.last_transform = None;
+        }
+        self.set_status_message(t!("transform.reverted", label = &snapshot.label).to_string());
+    }
+}
@@ -3,6 +3,9 @@ use rust_i18n::t;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
+/// How many leading lines [`BufferMetadata::is_generated_header`] inspects.
+const GENERATED_HEADER_LINES: usize = 5;
+
 /// The kind of buffer (file-backed or virtual)
 #[derive(Debug, Clone, PartialEq)]
 pub enum BufferKind {
@@ -24,6 +27,28 @@ pub enum BufferKind {
     },
 }
 
+/// Buffer text from just before and just after a whole-buffer transform.
+///
+/// `after` is the common base for the three-way merge that reverts the
+/// transform while keeping edits made since.
+#[derive(Debug, Clone)]
+pub struct TransformSnapshot {
+    /// What the transform was, for status messages (e.g. "Format").
+    pub label: String,
+    pub before: String,
+    pub after: String,
+}
+
+/// Where a narrowed buffer's text came from (see **Narrow to Selection**).
+///
+/// The region stays read-only in `source` under `namespace` until the
+/// narrowed buffer is widened back into it or closed.
+#[derive(Debug, Clone)]
+pub struct Narrowing {
+    pub source: crate::model::event::BufferId,
+    pub namespace: fresh_core::overlay::OverlayNamespace,
+}
+
 /// Metadata associated with a buffer
 #[derive(Debug, Clone)]
 pub struct BufferMetadata {
@@ -75,6 +100,19 @@ pub struct BufferMetadata {
     /// For file-backed buffers, recovery ID is computed from the path hash.
     /// For unnamed buffers, this is generated once and reused across auto-saves.
     pub recovery_id: Option<String>,
+
+    /// Whether this is a scratch buffer: unnamed, never prompted for a path
+    /// on quit, and kept (content included) in the workspace file instead.
+    /// Cleared once the buffer is saved to a file.
+    pub scratch: bool,
+
+    /// The most recent whole-buffer transform (format, trim whitespace,
+    /// organize imports), kept for **Revert Last Transform**.
+    pub last_transform: Option<TransformSnapshot>,
+
+    /// Set on a buffer made by **Narrow to Selection**: the region of
+    /// another buffer it edits, merged back by **Widen**.
+    pub narrowing: Option<Narrowing>,
 }
 
 impl BufferMetadata {
@@ -138,6 +176,9 @@ impl BufferMetadata {
             auto_revert_enabled: true,
             synthetic_placeholder: false,
             recovery_id: None,
+            scratch: false,
+            last_transform: None,
+            narrowing: None,
         }
     }
 
@@ -159,6 +200,9 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             recovery_id: None,
+            scratch: false,
+            last_transform: None,
+            narrowing: None,
         }
     }
 
@@ -219,6 +263,9 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             recovery_id: None,
+            scratch: false,
+            last_transform: None,
+            narrowing: None,
         }
     }
 
@@ -258,6 +305,9 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             recovery_id: None,
+            scratch: false,
+            last_transform: None,
+            narrowing: None,
         }
     }
 
@@ -307,6 +357,22 @@ impl BufferMetadata {
             || PREFIX_PATTERNS.iter().any(|p| path_str.starts_with(*p))
     }
 
+    /// How many leading bytes of a file to hand to [`Self::is_generated_header`].
+    pub const GENERATED_HEADER_BYTES: usize = 1024;
+
+    /// Check whether the start of a file carries a "generated code" marker.
+    ///
+    /// Recognises the common conventions in the first few lines: `@generated`
+    /// (prost, Buck, Facebook tooling) and `DO NOT EDIT` (Go's
+    /// `// Code generated ... DO NOT EDIT.`, protoc, bindgen, ...). Such files
+    /// are rewritten by their generator, so hand edits are usually lost.
+    pub fn is_generated_header(head: &str) -> bool {
+        const MARKERS: &[&str] = &["@generated", "DO NOT EDIT"];
+        head.lines()
+            .take(GENERATED_HEADER_LINES)
+            .any(|line| MARKERS.iter().any(|m| line.contains(m)))
+    }
+
     /// Compute display name relative to working_dir when possible, otherwise absolute
     pub fn display_name_for_path(path: &Path, working_dir: &Path) -> String {
         // Canonicalize working_dir to normalize platform-specific prefixes
@@ -356,6 +422,9 @@ impl BufferMetadata {
             hidden_from_tabs: false,
             synthetic_placeholder: false,
             recovery_id: None,
+            scratch: false,
+            last_transform: None,
+            narrowing: None,
         }
     }
 
@@ -375,6 +444,9 @@ impl BufferMetadata {
             hidden_from_tabs: true,
             synthetic_placeholder: false,
             recovery_id: None,
+            scratch: false,
+            last_transform: None,
+            narrowing: None,
         }
     }
 
@@ -385,6 +457,37 @@ impl BufferMetadata {
     }
 }
 
+#[cfg(test)]
+mod is_generated_header_tests {
+    use super::*;
+
+    #[test]
+    fn go_generated_header_is_detected() {
+        assert!(BufferMetadata::is_generated_header(
+            "// Code generated by protoc-gen-go. DO NOT EDIT.\n\npackage foo\n"
+        ));
+    }
+
+    #[test]
+    fn at_generated_marker_is_detected() {
+        assert!(BufferMetadata::is_generated_header(
+            "// This file is @generated by prost-build.\npub struct Foo {}\n"
+        ));
+    }
+
+    #[test]
+    fn marker_past_the_header_is_ignored() {
+        let mut text = "fn main() {}\n".repeat(GENERATED_HEADER_LINES);
+        text.push_str("// DO NOT EDIT\n");
+        assert!(!BufferMetadata::is_generated_header(&text));
+    }
+
+    #[test]
+    fn plain_source_is_not_generated() {
+        assert!(!BufferMetadata::is_generated_header("fn main() {}\n"));
+    }
+}
+
 #[cfg(test)]
 mod is_library_path_tests {
     use super::*;
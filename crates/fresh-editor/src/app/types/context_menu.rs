@@ -1,3 +1,4 @@
+use crate::input::keybindings::Action;
 use crate::model::event::{BufferId, LeafId};
 use rust_i18n::t;
 
@@ -13,6 +14,10 @@ pub const TAB_CONTEXT_MENU_WIDTH: u16 = 28;
 /// Width of the close-split confirmation popup (fits "Close split" + padding).
 pub const CLOSE_SPLIT_MENU_WIDTH: u16 = 16;
 
+/// Minimum width of the editor content context menu. The menu widens to fit
+/// its longest label, since plugin-contributed entries can be long.
+pub const BUFFER_CONTEXT_MENU_MIN_WIDTH: u16 = 22;
+
 /// Shared geometry + navigation + hit-testing core for the native context
 /// menus.
 ///
@@ -145,6 +150,8 @@ pub enum ContextMenuKind {
     FileExplorer,
     /// The close-split confirmation popup (clicking the split's `×` button).
     CloseSplit,
+    /// The editor content right-click context menu.
+    Buffer,
 }
 
 /// Tab context menu items
@@ -167,6 +174,8 @@ pub enum TabContextMenuItem {
     /// Move the tab into its own new orchestrator workspace over the same
     /// project root (a co-tenant window)
     ExtractToNewWorkspace,
+    /// Copy the tab's workspace-relative path and its cursor line
+    CopyPathLine,
 }
 
 impl TabContextMenuItem {
@@ -183,6 +192,7 @@ impl TabContextMenuItem {
             Self::CopyRelativePath,
             Self::CopyFullPath,
             Self::ExtractToNewWorkspace,
+            Self::CopyPathLine,
         ]
     }
 
@@ -197,6 +207,7 @@ impl TabContextMenuItem {
             Self::CopyRelativePath => t!("tab.copy_relative_path").to_string(),
             Self::CopyFullPath => t!("tab.copy_full_path").to_string(),
             Self::ExtractToNewWorkspace => t!("tab.extract_to_new_workspace").to_string(),
+            Self::CopyPathLine => t!("tab.copy_path_line").to_string(),
         }
     }
 }
@@ -355,6 +366,83 @@ impl CloseSplitMenu {
     }
 }
 
+/// One entry of the editor content context menu: its label and the action
+/// it runs.
+///
+/// Unlike the other menus' fixed item enums, this list is assembled when
+/// the menu opens — LSP entries appear only when the buffer's language has
+/// a server, editing entries disappear in read-only buffers, and plugins
+/// append their own commands (`registerCommand(..., { contextMenu: true })`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BufferContextMenuItem {
+    /// Display label.
+    pub label: String,
+    /// Action dispatched through `handle_action` when the item is chosen.
+    pub action: Action,
+}
+
+impl BufferContextMenuItem {
+    pub fn new(label: impl Into<String>, action: Action) -> Self {
+        Self {
+            label: label.into(),
+            action,
+        }
+    }
+}
+
+/// State for the editor content context menu (right-click in a buffer, or
+/// the Open Context Menu action).
+#[derive(Debug, Clone)]
+pub struct BufferContextMenu {
+    /// The buffer the menu was opened over
+    pub buffer_id: BufferId,
+    /// The split showing that buffer
+    pub split_id: LeafId,
+    /// Items captured at open time, in display order.
+    pub items: Vec<BufferContextMenuItem>,
+    /// Shared geometry + navigation core (position, highlight, width, items).
+    pub menu: ContextMenu,
+}
+
+impl BufferContextMenu {
+    /// Create a menu anchored at the given screen position, wide enough for
+    /// its longest label.
+    pub fn new(
+        buffer_id: BufferId,
+        split_id: LeafId,
+        x: u16,
+        y: u16,
+        items: Vec<BufferContextMenuItem>,
+    ) -> Self {
+        let longest = items
+            .iter()
+            .map(|item| unicode_width::UnicodeWidthStr::width(item.label.as_str()))
+            .max()
+            .unwrap_or(0);
+        // One leading space, the label, and a cell of slack on each border.
+        let width = (longest as u16)
+            .saturating_add(4)
+            .max(BUFFER_CONTEXT_MENU_MIN_WIDTH);
+        let item_count = items.len();
+        Self {
+            buffer_id,
+            split_id,
+            items,
+            menu: ContextMenu::new(x, y, width, item_count),
+        }
+    }
+
+    /// The items this menu presents, in display order.
+    pub fn items(&self) -> &[BufferContextMenuItem] {
+        &self.items
+    }
+
+    /// Get the currently highlighted item.
+    pub fn highlighted_item(&self) -> Option<&BufferContextMenuItem> {
+        self.items.get(self.menu.highlighted)
+    }
+}
+
 /// File explorer context menu items
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FileExplorerContextMenuItem {
@@ -500,6 +588,33 @@ mod tests {
         assert_eq!(menu.items().len(), 2);
     }
 
+    #[test]
+    fn buffer_context_menu_widens_to_fit_longest_label() {
+        let items = vec![
+            BufferContextMenuItem::new("Copy", Action::Copy),
+            BufferContextMenuItem::new(
+                "A plugin command with a rather long name",
+                Action::PluginAction("long".to_string()),
+            ),
+        ];
+        let menu = BufferContextMenu::new(BufferId(1), LeafId(SplitId(1)), 0, 0, items);
+        assert_eq!(menu.menu.width, 44);
+        assert_eq!(menu.menu.item_count, 2);
+        assert_eq!(
+            menu.highlighted_item().map(|i| &i.action),
+            Some(&Action::Copy)
+        );
+
+        let short = BufferContextMenu::new(
+            BufferId(1),
+            LeafId(SplitId(1)),
+            0,
+            0,
+            vec![BufferContextMenuItem::new("Copy", Action::Copy)],
+        );
+        assert_eq!(short.menu.width, BUFFER_CONTEXT_MENU_MIN_WIDTH);
+    }
+
     #[test]
     fn close_split_menu_navigation_reaches_cancel() {
         let mut menu = CloseSplitMenu::new(LeafId(SplitId(1)), 0, 0);
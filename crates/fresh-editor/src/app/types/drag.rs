@@ -67,3 +67,53 @@ impl TabDragState {
         dx > 3 || dy > 3 // Threshold of 3 pixels before drag activates
     }
 }
+
+/// State for a selection being dragged to a new location (drag-and-drop
+/// text). Recorded when a left press lands inside the selection; the
+/// selection is kept instead of being replaced by the click.
+#[derive(Debug, Clone)]
+pub struct TextDragState {
+    /// The buffer the text is dragged from
+    pub source_buffer_id: BufferId,
+    /// The split the press landed in
+    pub source_split_id: LeafId,
+    /// Byte range of the dragged selection in the source buffer
+    pub range: std::ops::Range<usize>,
+    /// Byte position under the press — where the cursor goes if the
+    /// press turns out to be a plain click
+    pub press_position: usize,
+    /// Starting mouse position when drag began
+    pub start_position: (u16, u16),
+    /// Current mouse position
+    pub current_position: (u16, u16),
+    /// Split, buffer and byte position under the pointer, when it's over
+    /// a buffer's content area
+    pub drop_target: Option<(LeafId, BufferId, usize)>,
+}
+
+impl TextDragState {
+    /// Create a new text drag state
+    pub fn new(
+        source_buffer_id: BufferId,
+        source_split_id: LeafId,
+        range: std::ops::Range<usize>,
+        press_position: usize,
+        start_position: (u16, u16),
+    ) -> Self {
+        Self {
+            source_buffer_id,
+            source_split_id,
+            range,
+            press_position,
+            start_position,
+            current_position: start_position,
+            drop_target: None,
+        }
+    }
+
+    /// Whether the pointer has left the press cell — until then a release
+    /// is a plain click that collapses the selection.
+    pub fn is_dragging(&self) -> bool {
+        self.current_position != self.start_position
+    }
+}
@@ -60,6 +60,8 @@ pub enum HoverTarget {
     SearchOptionRegex,
     /// Hovering over the search options "Confirm Each" checkbox
     SearchOptionConfirmEach,
+    /// Hovering over the search options "Multiline" checkbox
+    SearchOptionMultiline,
     /// Hovering over an item (by index) in whichever native context menu is
     /// open — the tab context menu, the "+" new-tab popup, or the
     /// file-explorer context menu. Only one is ever open at a time, so a
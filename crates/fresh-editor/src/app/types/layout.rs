@@ -120,6 +120,10 @@ pub(crate) struct ChromeLayout {
     /// show a preview. The mouse-wheel handler reads this to scroll the
     /// preview (rather than the result list) when the pointer is over it.
     pub prompt_preview_area: Option<Rect>,
+    /// Screen rect of the documentation panel beside the completion popup.
+    /// `None` when no panel is shown. The mouse-wheel handler reads this to
+    /// scroll the documentation rather than the completion list.
+    pub completion_docs_area: Option<Rect>,
     /// Settings modal layout for hit testing
     pub settings_layout: Option<crate::view::settings::SettingsLayout>,
     /// Workspace-trust dialog click layout (radios + OK/Quit) for hit testing.
@@ -174,6 +178,10 @@ pub(crate) struct StatusBarChrome {
     /// See `docs/internal/trust-env-devcontainer-ux-plan.md` for the
     /// design context.
     pub plugin_token_areas: std::collections::HashMap<String, (u16, u16, u16)>,
+    /// Areas of elements with a configured `on_click` action, as
+    /// `(action, row, start_col, end_col)`. Checked first by
+    /// `handle_click_status_bar`.
+    pub segment_actions: Vec<(String, u16, u16, u16)>,
     /// Semantic status-bar model (rendered elements + text + positions), captured
     /// by the renderer so `status_view` derives the web status bar directly
     /// instead of scraping the drawn cells.
@@ -463,6 +471,12 @@ pub struct OverlayPreviewState {
     /// Buffers the user already had open are *not* in this set —
     /// dismissing the overlay never disturbs them.
     pub loaded_buffers: HashSet<BufferId>,
+    /// On remote workspaces the preview shows a read-only virtual buffer
+    /// holding the head of the file rather than the file itself. Such a
+    /// buffer has no file path, so this map is how a later frame finds the
+    /// one already built for a path. Every entry is also in
+    /// `loaded_buffers`.
+    pub remote_previews: HashMap<std::path::PathBuf, BufferId>,
     /// When true, the preview pane renders empty (just its frame). Set
     /// when the current query has no selectable result so a stale match
     /// doesn't keep showing after the result list clears. Kept as a flag
@@ -0,0 +1,108 @@
+use std::collections::VecDeque;
+
+/// How many entries a [`MessageLog`] keeps before dropping the oldest.
+const MAX_MESSAGES: usize = 1000;
+
+/// How serious a logged message is. Ordered, so a filter can keep
+/// everything at or above a level.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MessageSeverity {
+    #[default]
+    Info,
+    Warning,
+    Error,
+}
+
+impl MessageSeverity {
+    /// Fixed-width label for the *Messages* panel.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Info => "INFO ",
+            Self::Warning => "WARN ",
+            Self::Error => "ERROR",
+        }
+    }
+}
+
+/// One message as it was shown to the user.
+#[derive(Debug, Clone)]
+pub struct MessageEntry {
+    pub time: chrono::DateTime<chrono::Local>,
+    pub severity: MessageSeverity,
+    /// Where it came from: "editor", "plugin", "LSP (rust)".
+    pub source: String,
+    pub text: String,
+}
+
+/// Status messages, LSP `window/showMessage` notifications and error
+/// reports, newest last, for the *Messages* panel. Status messages replace
+/// each other in the status bar; this keeps them.
+#[derive(Debug, Clone, Default)]
+pub struct MessageLog {
+    entries: VecDeque<MessageEntry>,
+    /// Lowest severity the panel shows.
+    pub min_severity: MessageSeverity,
+}
+
+impl MessageLog {
+    pub fn push(&mut self, severity: MessageSeverity, source: &str, text: &str) {
+        if self.entries.len() == MAX_MESSAGES {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(MessageEntry {
+            time: chrono::Local::now(),
+            severity,
+            source: source.to_string(),
+            text: text.to_string(),
+        });
+    }
+
+    /// Entries that pass the severity filter, oldest first.
+    pub fn visible(&self) -> impl Iterator<Item = &MessageEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.severity >= self.min_severity)
+    }
+
+    /// Step the filter: everything → warnings and errors → errors only →
+    /// everything.
+    pub fn cycle_filter(&mut self) {
+        self.min_severity = match self.min_severity {
+            MessageSeverity::Info => MessageSeverity::Warning,
+            MessageSeverity::Warning => MessageSeverity::Error,
+            MessageSeverity::Error => MessageSeverity::Info,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filter_keeps_messages_at_or_above_the_level() {
+        let mut log = MessageLog::default();
+        log.push(MessageSeverity::Info, "editor", "saved");
+        log.push(MessageSeverity::Warning, "LSP (rust)", "slow");
+        log.push(MessageSeverity::Error, "editor", "failed");
+
+        assert_eq!(log.visible().count(), 3);
+        log.cycle_filter();
+        let texts: Vec<_> = log.visible().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, ["slow", "failed"]);
+        log.cycle_filter();
+        assert_eq!(log.visible().count(), 1);
+        log.cycle_filter();
+        assert_eq!(log.visible().count(), 3);
+    }
+
+    #[test]
+    fn oldest_messages_drop_off() {
+        let mut log = MessageLog::default();
+        for i in 0..MAX_MESSAGES + 5 {
+            log.push(MessageSeverity::Info, "editor", &i.to_string());
+        }
+        assert_eq!(log.visible().count(), MAX_MESSAGES);
+        assert_eq!(log.visible().next().unwrap().text, "5");
+    }
+}
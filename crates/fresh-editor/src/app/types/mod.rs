@@ -6,6 +6,7 @@ mod hover;
 mod layout;
 mod lsp_state;
 mod lsp_uri;
+mod message_log;
 mod mouse;
 mod search_state;
 mod theme;
@@ -16,20 +17,20 @@ pub const DEFAULT_BACKGROUND_FILE: &str = "scripts/landscape-wide.txt";
 pub use buffer_group::{BufferGroup, BufferGroupId, GroupLayoutNode};
 
 // buffer_meta re-exports
-pub use buffer_meta::{BufferKind, BufferMetadata};
+pub use buffer_meta::{BufferKind, BufferMetadata, Narrowing, TransformSnapshot};
 
 // context_menu re-exports
 pub use context_menu::FILE_EXPLORER_CONTEXT_MENU_WIDTH;
 pub use context_menu::NEW_TAB_MENU_WIDTH;
 pub use context_menu::TAB_CONTEXT_MENU_WIDTH;
 pub use context_menu::{
-    CloseSplitMenu, CloseSplitMenuItem, ContextMenu, ContextMenuHit, ContextMenuKind,
-    FileExplorerContextMenu, FileExplorerContextMenuItem, NewTabMenu, NewTabMenuItem,
-    TabContextMenu, TabContextMenuItem,
+    BufferContextMenu, BufferContextMenuItem, CloseSplitMenu, CloseSplitMenuItem, ContextMenu,
+    ContextMenuHit, ContextMenuKind, FileExplorerContextMenu, FileExplorerContextMenuItem,
+    NewTabMenu, NewTabMenuItem, TabContextMenu, TabContextMenuItem,
 };
 
 // drag re-exports
-pub use drag::{TabDragState, TabDropZone};
+pub use drag::{TabDragState, TabDropZone, TextDragState};
 
 // hover re-exports
 pub use hover::HoverTarget;
@@ -45,6 +46,9 @@ pub(crate) use lsp_state::{LspMessageEntry, LspProgressInfo};
 // lsp_uri re-exports
 pub use lsp_uri::{file_path_to_lsp_uri, file_path_to_lsp_uri_with_translation, LspUri};
 
+// message_log re-exports
+pub use message_log::{MessageEntry, MessageLog, MessageSeverity};
+
 // mouse re-exports
 pub(crate) use mouse::MouseState;
 
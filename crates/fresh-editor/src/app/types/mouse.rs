@@ -1,4 +1,4 @@
-use super::drag::TabDragState;
+use super::drag::{TabDragState, TextDragState};
 use super::hover::HoverTarget;
 use crate::config::ExplorerWidth;
 use crate::model::event::{BufferId, ContainerId, LeafId, SplitDirection};
@@ -64,6 +64,8 @@ pub struct MouseState {
     pub drag_selection_word_end: Option<usize>,
     /// Tab drag state (for drag-to-split functionality)
     pub dragging_tab: Option<TabDragState>,
+    /// Selection drag-and-drop state (press inside a selection)
+    pub dragging_text: Option<TextDragState>,
     /// Whether we're currently dragging a popup scrollbar (popup index)
     pub dragging_popup_scrollbar: Option<usize>,
     /// Initial scroll offset when starting to drag popup scrollbar
@@ -5,7 +5,7 @@
 //! editor-global state (plugin manager, mode registry, etc.); they manipulate
 //! per-window split-view-state and animations.
 
-use crate::app::window::Window;
+use crate::app::window::{Window, ZenModeRestore};
 use crate::model::event::LeafId;
 use crate::state::ViewMode;
 use rust_i18n::t;
@@ -86,6 +86,69 @@ impl Window {
         self.set_status_message(t!("view.mode", mode = mode_label).to_string());
     }
 
+    /// Toggle Zen mode: hide the menu, tab and status bars and the active
+    /// view's gutter, and center its text in a soft-wrapped column
+    /// `zen_mode_width` wide. Turning it off restores exactly what it
+    /// changed.
+    pub fn toggle_zen_mode(&mut self) {
+        if let Some(restore) = self.zen_mode.take() {
+            self.menu_bar_visible = restore.menu_bar_visible;
+            self.tab_bar_visible = restore.tab_bar_visible;
+            self.status_bar_visible = restore.status_bar_visible;
+            // The split or buffer may have been closed meanwhile.
+            if let Some(vs) = self
+                .split_view_states_mut()
+                .and_then(|states| states.get_mut(&restore.split))
+                .and_then(|split| split.keyed_states.get_mut(&restore.buffer))
+            {
+                vs.show_line_numbers = restore.show_line_numbers;
+                vs.compose_width = restore.compose_width;
+                vs.viewport.line_wrap_enabled = restore.line_wrap_enabled;
+            }
+            self.set_status_message(t!("toggle.zen_mode_disabled").to_string());
+            return;
+        }
+
+        let width = self.config().editor.zen_mode_width;
+        let (menu_bar_visible, tab_bar_visible, status_bar_visible) = (
+            self.menu_bar_visible,
+            self.tab_bar_visible,
+            self.status_bar_visible,
+        );
+        let Some(split) = self.buffers.splits().map(|(mgr, _)| mgr.active_split()) else {
+            return;
+        };
+        let Some(split_state) = self
+            .split_view_states_mut()
+            .and_then(|states| states.get_mut(&split))
+        else {
+            return;
+        };
+        let buffer = split_state.active_buffer;
+        let Some(vs) = split_state.keyed_states.get_mut(&buffer) else {
+            return;
+        };
+        let restore = ZenModeRestore {
+            menu_bar_visible,
+            tab_bar_visible,
+            status_bar_visible,
+            split,
+            buffer,
+            show_line_numbers: vs.show_line_numbers,
+            compose_width: vs.compose_width,
+            line_wrap_enabled: vs.viewport.line_wrap_enabled,
+        };
+        vs.show_line_numbers = false;
+        vs.compose_width = (width > 0).then(|| width.min(u16::MAX as usize) as u16);
+        vs.viewport.line_wrap_enabled = true;
+
+        self.zen_mode = Some(restore);
+        self.menu_bar_visible = false;
+        self.tab_bar_visible = false;
+        self.status_bar_visible = false;
+        self.set_status_message(t!("toggle.zen_mode_enabled").to_string());
+    }
+
     /// Start a horizontal slide over the given split's content area to
     /// visualize a tab switch. `direction`: +1 = the new tab is to
     /// the right of the previous one in tab order, so the new view
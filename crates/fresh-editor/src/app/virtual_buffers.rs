@@ -144,6 +144,8 @@ impl Editor {
                 wrap_column,
                 rulers: self.config.editor.rulers.clone(),
                 scroll_offset: self.config.editor.scroll_offset,
+                scroll_past_end: self.config.editor.scroll_past_end,
+                typewriter_scrolling: self.config.editor.typewriter_scrolling,
             });
         }
 
@@ -396,6 +398,8 @@ impl crate::app::window::Window {
                 wrap_column,
                 rulers: cfg.rulers.clone(),
                 scroll_offset: cfg.scroll_offset,
+                scroll_past_end: cfg.scroll_past_end,
+                typewriter_scrolling: cfg.typewriter_scrolling,
             });
         } else {
             let mut view_state =
@@ -408,6 +412,8 @@ impl crate::app::window::Window {
                 wrap_column,
                 rulers: cfg.rulers,
                 scroll_offset: cfg.scroll_offset,
+                scroll_past_end: cfg.scroll_past_end,
+                typewriter_scrolling: cfg.typewriter_scrolling,
             });
             self.split_view_states_mut()
                 .expect("active window must have a populated split layout")
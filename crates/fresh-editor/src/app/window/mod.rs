@@ -175,6 +175,20 @@ impl TerminalBuffer {
     }
 }
 
+/// The layout Zen mode replaced, put back when it is turned off.
+#[derive(Debug, Clone)]
+pub struct ZenModeRestore {
+    pub menu_bar_visible: bool,
+    pub tab_bar_visible: bool,
+    pub status_bar_visible: bool,
+    /// The split and buffer Zen mode centered, and that view's own settings.
+    pub split: LeafId,
+    pub buffer: BufferId,
+    pub show_line_numbers: bool,
+    pub compose_width: Option<u16>,
+    pub line_wrap_enabled: bool,
+}
+
 pub struct Window {
     /// Stable identifier. The base window is always `WindowId(1)`.
     pub id: WindowId,
@@ -300,11 +314,21 @@ pub struct Window {
     /// Original LSP completion items (for type-to-filter).
     pub completion_items: Option<Vec<lsp_types::CompletionItem>>,
 
+    /// Documentation panel for the selected completion item.
+    pub completion_docs: Option<crate::app::CompletionDocs>,
+    /// Pending `completionItem/resolve` request for the documentation
+    /// panel: request id and the label of the item it resolves.
+    pub pending_completion_docs_request: Option<(u64, String)>,
+    /// Labels of the completion items already resolved for this popup.
+    pub resolved_completion_labels: std::collections::HashSet<String>,
+
     /// Scheduled completion-trigger time (debounced quick-suggestions).
     pub scheduled_completion_trigger: Option<std::time::Instant>,
 
     /// Dabbrev cycling state (Alt+/ session).
     pub dabbrev_state: Option<crate::app::DabbrevCycleState>,
+    /// The paste Cycle Paste Through History would replace.
+    pub paste_cycle_state: Option<crate::app::PasteCycleState>,
 
     /// Pending LSP go-to-definition request id.
     pub pending_goto_definition_request: Option<u64>,
@@ -344,6 +368,17 @@ pub struct Window {
         std::collections::HashMap<BufferId, (u64, u64, crate::app::SemanticTokensFullRequestKind)>,
     pub semantic_tokens_full_debounce: std::collections::HashMap<BufferId, std::time::Instant>,
 
+    /// Next-allowed JSON-schema validation per buffer (see
+    /// `schedule_schema_validation`).
+    pub schema_validation_debounce: std::collections::HashMap<BufferId, std::time::Instant>,
+    /// Generation of the latest schema validation started per buffer;
+    /// results from older ones are dropped.
+    pub(crate) schema_validation_in_flight: std::collections::HashMap<BufferId, u64>,
+    pub(crate) next_schema_validation_generation: u64,
+
+    /// Next-allowed idle lint run per buffer (see `schedule_idle_lint`).
+    pub lint_debounce: std::collections::HashMap<BufferId, std::time::Instant>,
+
     /// Pending semantic-tokens-range requests + per-buffer in-flight,
     /// last-request, and last-applied tracking.
     pub(crate) pending_semantic_token_range_requests:
@@ -624,6 +659,19 @@ pub struct Window {
     /// goto-line prompt, restored on Esc.
     pub(crate) goto_line_preview: Option<crate::app::GotoLinePreviewSnapshot>,
 
+    /// Buffers in the order they were last made active, oldest first.
+    /// Feeds the buffer picker's most-recently-used ordering; may hold ids
+    /// of buffers closed since, which readers skip.
+    pub(crate) buffer_mru: Vec<BufferId>,
+
+    /// What the other split showed before the buffer picker started
+    /// previewing into it; restored when the picker closes.
+    pub(crate) buffer_picker_preview: Option<crate::app::buffer_picker::BufferPickerPreview>,
+
+    /// Files closed in this session, most recent last, for Reopen Closed
+    /// Tab.
+    pub(crate) closed_buffers: Vec<crate::app::closed_buffers::ClosedBuffer>,
+
     /// Pending plugin-issued prompt callback id (used by
     /// `editor.startPrompt` to deliver the prompt result back).
     pub pending_async_prompt_callback: Option<fresh_core::api::JsCallbackId>,
@@ -638,6 +686,7 @@ pub struct Window {
     pub search_whole_word: bool,
     pub search_use_regex: bool,
     pub search_confirm_each: bool,
+    pub search_multiline: bool,
 
     /// Scheduled (debounced) per-buffer LSP feature requests for the
     /// active window's LSP. Per-window because the LSP they target is
@@ -650,6 +699,10 @@ pub struct Window {
     /// per-window — different windows can prompt independently.
     pub user_dismissed_lsp_languages: std::collections::HashSet<String>,
 
+    /// Languages this window already suggested a detected (or installable)
+    /// language server for, so the hint shows once per session.
+    pub lsp_detection_notified: std::collections::HashSet<String>,
+
     /// Active editor mode (e.g. "search", "replace", "macro-record").
     /// Per-window because the modes drive UI affordances that belong
     /// to one window's UX flow.
@@ -670,6 +723,26 @@ pub struct Window {
     /// (notably the LSP set) are per-window.
     pub completion_service: crate::services::completion::CompletionService,
 
+    /// Parsed user snippet files, shared with the snippet completion
+    /// provider in `completion_service`.
+    pub snippets: Arc<crate::services::completion::snippets::SnippetLibrary>,
+
+    /// Persistent word index over this window's project, feeding
+    /// project-wide buffer-word completion. Per-window because it indexes
+    /// the window's root through the window's authority.
+    pub project_words: crate::services::completion::project_words::ProjectWords,
+
+    /// The project's ctags `tags` file, read on first use and re-read when
+    /// it changes. Per-window for the same reason as `project_words`.
+    pub tags: crate::services::tags::TagFile,
+
+    /// A `tags_command` run is in flight.
+    pub tags_generating: bool,
+
+    /// Another run was requested while one was in flight; it starts when
+    /// that one finishes.
+    pub tags_regenerate_pending: bool,
+
     /// Overlay namespace for LSP diagnostic overlays in this window
     /// (filter / bulk-remove key). The diagnostics it scopes are buffer
     /// overlays, and buffers are per-window, so the namespace follows.
@@ -719,6 +792,10 @@ pub struct Window {
     /// servers, on the same bounded-ring pattern as `lsp_window_messages`.
     pub(crate) lsp_log_messages: Vec<crate::app::LspMessageEntry>,
 
+    /// Status messages, LSP notifications and errors shown in this
+    /// window, kept for the *Messages* panel.
+    pub(crate) message_log: crate::app::types::MessageLog,
+
     /// Push-model diagnostics keyed by URI, then by server name. Each
     /// `publishDiagnostics` from a server replaces that server's slice
     /// for the URI; the merged view is materialised in
@@ -771,6 +848,15 @@ pub struct Window {
     /// dropped by a `.git/index` suffix match.
     pub watched_git_indexes: Vec<PathBuf>,
 
+    /// Last-seen contents of each watched repo's `HEAD` (the file next to
+    /// its index), keyed by path. A change means the checked-out branch
+    /// switched; see `branch_switch.rs`.
+    pub git_heads: HashMap<PathBuf, String>,
+
+    /// Branch a branch-switch refresh is waiting to finish for: set while
+    /// the open-buffer poll it started is in flight.
+    pub branch_refresh: Option<String>,
+
     /// Receiver for background file change poll results for this window.
     /// `Some` while a metadata poll is in flight.
     #[allow(clippy::type_complexity)]
@@ -780,6 +866,7 @@ pub struct Window {
     /// Receiver for background directory change poll results for this window.
     /// The second element carries mtimes for all watched `.git/index` files
     /// (one per repo in a monorepo, or a single entry for a normal repo).
+    /// The third carries the contents of each repo's `HEAD`.
     #[allow(clippy::type_complexity)]
     pub pending_dir_poll_rx: Option<
         std::sync::mpsc::Receiver<(
@@ -789,6 +876,7 @@ pub struct Window {
                 Option<std::time::SystemTime>,
             )>,
             Vec<(PathBuf, std::time::SystemTime)>,
+            Vec<(PathBuf, String)>,
         )>,
     >,
 
@@ -847,6 +935,11 @@ pub struct Window {
     /// Pending chord sequence for multi-key bindings (e.g. C-x C-s).
     /// Each window tracks its own in-progress chord.
     pub chord_state: Vec<(crossterm::event::KeyCode, crossterm::event::KeyModifiers)>,
+    /// Context the pending chord resolves in, so the chord hint popup
+    /// lists the bindings the next key would actually reach.
+    pub chord_context: Option<crate::input::keybindings::KeyContext>,
+    /// When the last chord key was pressed, for `editor.chord_timeout_ms`.
+    pub chord_last_key_at: Option<std::time::Instant>,
 
     /// Multi-click detection state (per-window because clicks land
     /// inside a window).
@@ -867,6 +960,9 @@ pub struct Window {
     pub status_bar_visible: bool,
     pub prompt_line_visible: bool,
 
+    /// Zen mode is on; holds the layout to restore when it is turned off.
+    pub zen_mode: Option<ZenModeRestore>,
+
     /// Timing state for auto-recovery saves and persistent auto-saves
     /// in this window.
     pub last_auto_recovery_save: std::time::Instant,
@@ -889,6 +985,9 @@ pub struct Window {
     /// `×` button). Offers "Close split" / "Cancel".
     pub close_split_menu: Option<crate::app::types::CloseSplitMenu>,
 
+    /// Editor content context menu state (right-click in a buffer).
+    pub buffer_context_menu: Option<crate::app::types::BufferContextMenu>,
+
     /// Theme inspector popup (Ctrl+Right-Click) anchored in this window.
     pub theme_info_popup: Option<crate::app::types::ThemeInfoPopup>,
 
@@ -961,6 +1060,9 @@ pub struct Window {
     /// Per-window UI animation runner.
     pub animations: crate::view::animation::AnimationRunner,
 
+    /// Mouse-wheel scroll still being played out, with `smooth_scroll` on.
+    pub(crate) smooth_scroll: Option<crate::app::scrollbar_input::SmoothScroll>,
+
     /// Plugin error log (populated when plugin status messages match
     /// error patterns; tests assert against this).
     pub plugin_errors: Vec<String>,
@@ -980,6 +1082,18 @@ pub struct Window {
     pub process_groups: ProcessGroups,
 }
 
+/// Byte spans touched by the edits in `event`, in the pre-edit coordinates
+/// of each edit; an insertion is an empty span at its position.
+fn event_edit_spans(event: &crate::model::event::Event) -> Vec<(usize, usize)> {
+    use crate::model::event::Event;
+    match event {
+        Event::Insert { position, .. } => vec![(*position, *position)],
+        Event::Delete { range, .. } => vec![(range.start, range.end)],
+        Event::Batch { events, .. } => events.iter().flat_map(event_edit_spans).collect(),
+        _ => Vec::new(),
+    }
+}
+
 /// Apply language-server configuration to a freshly-created
 /// [`LspManager`]: per-language configs, the universal (global)
 /// servers, and the Deno auto-detection override. Shared by every
@@ -995,6 +1109,7 @@ pub(crate) fn configure_lsp_servers(
 
     // Global master switch — gates auto-start of every server below.
     lsp.set_globally_enabled(config.lsp_enabled);
+    lsp.set_shared_servers(config.lsp_shared_servers);
 
     // Per-language servers from config.
     for (language, lsp_configs) in &config.lsp {
@@ -1096,6 +1211,9 @@ impl Window {
         if let Some(m) = &self.close_split_menu {
             return Some((ContextMenuKind::CloseSplit, &m.menu));
         }
+        if let Some(m) = &self.buffer_context_menu {
+            return Some((ContextMenuKind::Buffer, &m.menu));
+        }
         None
     }
 
@@ -1120,6 +1238,9 @@ impl Window {
         if let Some(m) = self.close_split_menu.as_mut() {
             return Some(&mut m.menu);
         }
+        if let Some(m) = self.buffer_context_menu.as_mut() {
+            return Some(&mut m.menu);
+        }
         None
     }
 
@@ -1158,16 +1279,24 @@ impl Window {
                 .iter()
                 .map(|i| i.label())
                 .collect(),
+            ContextMenuKind::Buffer => self
+                .buffer_context_menu
+                .as_ref()?
+                .items()
+                .iter()
+                .map(|i| i.label.clone())
+                .collect(),
         })
     }
 
-    /// Dismiss whichever native context menu is open (all three fields are
+    /// Dismiss whichever native context menu is open (every menu field is
     /// cleared unconditionally — only one is ever set).
     pub(crate) fn close_context_menus(&mut self) {
         self.tab_context_menu = None;
         self.new_tab_menu = None;
         self.file_explorer_context_menu = None;
         self.close_split_menu = None;
+        self.buffer_context_menu = None;
     }
 
     /// Apply LSP folding ranges to the named buffer's `folding_ranges`
@@ -1317,6 +1446,7 @@ impl Window {
             self.search_use_regex,
             self.search_whole_word,
             self.search_case_sensitive,
+            self.search_multiline,
         )
     }
 
@@ -1468,16 +1598,16 @@ impl Window {
 
     /// Send `completionItem/resolve` for `item` to the first LSP server
     /// (in language order) that advertises `completion_resolve` for the
-    /// active buffer's language. No-op if no server is running or no
-    /// server supports the resolve.
-    pub(crate) fn send_completion_resolve(&mut self, item: lsp_types::CompletionItem) {
-        let Some(language) = self
+    /// active buffer's language. Returns the request id, or `None` if no
+    /// server is running or no server supports the resolve.
+    pub(crate) fn send_completion_resolve(
+        &mut self,
+        item: lsp_types::CompletionItem,
+    ) -> Option<u64> {
+        let language = self
             .buffers
             .get(&self.active_buffer())
-            .map(|s| s.language.clone())
-        else {
-            return;
-        };
+            .map(|s| s.language.clone())?;
         let request_id = self.alloc_lsp_request_id();
         {
             let lsp = &mut self.lsp;
@@ -1489,11 +1619,13 @@ impl Window {
                             sh.name,
                             e
                         );
+                        return None;
                     }
-                    return;
+                    return Some(request_id);
                 }
             }
         }
+        None
     }
 
     /// Apply an event to a buffer + the cursors of a split inside this
@@ -1524,12 +1656,57 @@ impl Window {
         split_id: LeafId,
         event: &crate::model::event::Event,
     ) {
+        self.expand_folds_touched_by_edits(buffer_id, split_id, &event_edit_spans(event));
         self.buffers
             .with_buffer_and_split(buffer_id, split_id, |state, vs| {
                 if let Some(keyed) = vs.keyed_states.get_mut(&buffer_id) {
                     state.apply(&mut keyed.cursors, event);
                 }
             });
+        if event.modifies_buffer() {
+            self.prune_stale_folds(buffer_id);
+        }
+    }
+
+    /// Expand the folds of `split_id`'s view of `buffer_id` that the given
+    /// pre-edit byte spans reach into, so an edit originating in that view
+    /// never changes hidden text out of sight. Call before applying the edit.
+    pub fn expand_folds_touched_by_edits(
+        &mut self,
+        buffer_id: BufferId,
+        split_id: LeafId,
+        spans: &[(usize, usize)],
+    ) {
+        if spans.is_empty() {
+            return;
+        }
+        self.buffers
+            .with_buffer_and_split(buffer_id, split_id, |state, vs| {
+                if let Some(keyed) = vs.keyed_states.get_mut(&buffer_id) {
+                    for &(start, end) in spans {
+                        keyed
+                            .folds
+                            .expand_touched_by_edit(&mut state.marker_list, start, end);
+                    }
+                }
+            });
+    }
+
+    /// Drop folds on `buffer_id` that edits have left inconsistent (header
+    /// deleted or detached, hidden range emptied) in every view state
+    /// hosting the buffer. Folds in other views otherwise just follow their
+    /// markers, so edits inside them keep them collapsed.
+    pub fn prune_stale_folds(&mut self, buffer_id: BufferId) {
+        self.buffers
+            .with_buffer_and_view_states(buffer_id, |state, vs_map| {
+                for vs in vs_map.values_mut() {
+                    if let Some(keyed) = vs.keyed_states.get_mut(&buffer_id) {
+                        keyed
+                            .folds
+                            .prune_stale(&state.buffer, &mut state.marker_list);
+                    }
+                }
+            });
     }
 
     /// Scroll the named split's viewport so the buffer's primary cursor
@@ -1582,6 +1759,7 @@ impl Window {
                     if vs.keyed_states.contains_key(&buffer_id) {
                         let buf_state = vs.ensure_buffer_state(buffer_id);
                         buf_state.folds.add(
+                            &state.buffer,
                             &mut state.marker_list,
                             start,
                             end,
@@ -2005,6 +2183,9 @@ impl Window {
         // are borrowed here, then moved into the struct below.
         let bridge = crate::services::async_bridge::AsyncBridge::new();
         let lsp = build_window_lsp(id, &root, &authority, &resources, &bridge);
+        let snippets = Arc::new(crate::services::completion::snippets::SnippetLibrary::new(
+            resources.dir_context.snippets_dir(),
+        ));
         Self {
             id,
             label,
@@ -2035,8 +2216,12 @@ impl Window {
             next_lsp_request_id: 0,
             pending_completion_requests: std::collections::HashSet::new(),
             completion_items: None,
+            completion_docs: None,
+            pending_completion_docs_request: None,
+            resolved_completion_labels: std::collections::HashSet::new(),
             scheduled_completion_trigger: None,
             dabbrev_state: None,
+            paste_cycle_state: None,
             pending_goto_definition_request: None,
             pending_references_request: None,
             pending_references_symbol: String::new(),
@@ -2053,6 +2238,10 @@ impl Window {
             pending_semantic_token_requests: std::collections::HashMap::new(),
             semantic_tokens_in_flight: std::collections::HashMap::new(),
             semantic_tokens_full_debounce: std::collections::HashMap::new(),
+            schema_validation_debounce: std::collections::HashMap::new(),
+            schema_validation_in_flight: std::collections::HashMap::new(),
+            next_schema_validation_generation: 0,
+            lint_debounce: std::collections::HashMap::new(),
             pending_semantic_token_range_requests: std::collections::HashMap::new(),
             semantic_tokens_range_in_flight: std::collections::HashMap::new(),
             semantic_tokens_range_last_request: std::collections::HashMap::new(),
@@ -2098,19 +2287,37 @@ impl Window {
             overlay_preview_state: None,
             file_rapid_change_counts: HashMap::new(),
             goto_line_preview: None,
+            buffer_mru: Vec::new(),
+            buffer_picker_preview: None,
+            closed_buffers: Vec::new(),
             pending_async_prompt_callback: None,
             pending_quit_unnamed_save: Vec::new(),
             search_case_sensitive: true,
             search_whole_word: false,
             search_use_regex: false,
             search_confirm_each: false,
+            search_multiline: false,
             scheduled_diagnostic_pull: None,
             scheduled_inlay_hints_request: None,
             user_dismissed_lsp_languages: std::collections::HashSet::new(),
+            lsp_detection_notified: std::collections::HashSet::new(),
             editor_mode: None,
             prompt_histories: HashMap::new(),
             pending_close_buffer: None,
-            completion_service: crate::services::completion::CompletionService::new(),
+            completion_service: {
+                let mut service = crate::services::completion::CompletionService::new();
+                service.register(Box::new(
+                    crate::services::completion::snippets::SnippetProvider::new(Arc::clone(
+                        &snippets,
+                    )),
+                ));
+                service
+            },
+            snippets,
+            project_words: Default::default(),
+            tags: Default::default(),
+            tags_generating: false,
+            tags_regenerate_pending: false,
             lsp_diagnostic_namespace: crate::view::overlay::OverlayNamespace::from_string(
                 "lsp-diagnostic".to_string(),
             ),
@@ -2120,6 +2327,7 @@ impl Window {
             lsp_menu_contributions: HashMap::new(),
             lsp_window_messages: Vec::new(),
             lsp_log_messages: Vec::new(),
+            message_log: Default::default(),
             stored_push_diagnostics: HashMap::new(),
             stored_pull_diagnostics: HashMap::new(),
             stored_diagnostics: Arc::new(HashMap::new()),
@@ -2129,6 +2337,8 @@ impl Window {
             last_file_tree_poll: now,
             git_index_resolved: false,
             watched_git_indexes: Vec::new(),
+            git_heads: HashMap::new(),
+            branch_refresh: None,
             pending_git_index_rx: None,
             pending_file_poll_rx: None,
             pending_dir_poll_rx: None,
@@ -2140,6 +2350,8 @@ impl Window {
             mouse_state: crate::app::types::MouseState::default(),
             key_context: crate::input::keybindings::KeyContext::Normal,
             chord_state: Vec::new(),
+            chord_context: None,
+            chord_last_key_at: None,
             previous_click_time: None,
             previous_click_position: None,
             click_count: 0,
@@ -2150,6 +2362,7 @@ impl Window {
             tab_bar_visible: resources.config.editor.show_tab_bar,
             status_bar_visible: resources.config.editor.show_status_bar,
             prompt_line_visible: resources.config.editor.show_prompt_line,
+            zen_mode: None,
             last_auto_recovery_save: now,
             last_persistent_auto_save: now,
             warning_domains: crate::app::warning_domains::WarningDomainRegistry::default(),
@@ -2157,6 +2370,7 @@ impl Window {
             new_tab_menu: None,
             file_explorer_context_menu: None,
             close_split_menu: None,
+            buffer_context_menu: None,
             theme_info_popup: None,
             event_debug: None,
             file_open_state: None,
@@ -2179,6 +2393,7 @@ impl Window {
             search_scan: crate::app::search_scan::SearchScan::default(),
             search_overlay_top_byte: None,
             animations: crate::view::animation::AnimationRunner::default(),
+            smooth_scroll: None,
             plugin_errors: Vec::new(),
             file_explorer_clipboard: None,
             process_groups: ProcessGroups::default(),
@@ -2218,7 +2433,18 @@ impl Window {
     /// `impl Window` can post status without an `Editor` reference.
     /// Clears any plugin-supplied status (matches Editor behaviour).
     pub fn set_status_message(&mut self, message: String) {
+        self.show_status(crate::app::types::MessageSeverity::Info, message);
+    }
+
+    /// Like [`Self::set_status_message`], but the message is logged as an
+    /// error in the *Messages* panel.
+    pub fn set_status_error(&mut self, message: String) {
+        self.show_status(crate::app::types::MessageSeverity::Error, message);
+    }
+
+    fn show_status(&mut self, severity: crate::app::types::MessageSeverity, message: String) {
         tracing::info!(target: "status", "{}", message);
+        self.record_message(severity, "editor", &message);
         self.plugin_status_message = None;
         self.status_message = Some(message);
     }
@@ -3226,10 +3452,12 @@ impl Window {
             std::sync::Arc::clone(&self.resources.local_filesystem),
         )?;
         let first_line = buffer.first_line_lossy();
+        let modeline_region = buffer.modeline_region_lossy();
         let detected =
             crate::primitives::detected_language::DetectedLanguage::from_path_with_fallback(
                 &display_path,
                 first_line.as_deref(),
+                modeline_region.as_deref(),
                 &self.resources.grammar_registry,
                 &self.config().languages,
                 self.config().default_language.as_deref(),
@@ -3272,6 +3500,8 @@ impl Window {
                 wrap_column,
                 rulers: cfg.rulers,
                 scroll_offset: cfg.scroll_offset,
+                scroll_past_end: cfg.scroll_past_end,
+                typewriter_scrolling: cfg.typewriter_scrolling,
             });
         }
 
@@ -3754,7 +3984,7 @@ impl Window {
 
     /// The open buffer showing `uri`, if any. Diagnostics are keyed by URI but
     /// only an open buffer has a `CoordMap`/marker tree to map positions with.
-    fn buffer_id_for_uri(&self, uri: &str) -> Option<BufferId> {
+    pub(crate) fn buffer_id_for_uri(&self, uri: &str) -> Option<BufferId> {
         self.buffer_metadata
             .iter()
             .find_map(|(id, meta)| meta.file_uri().filter(|u| u.as_str() == uri).map(|_| *id))
@@ -4027,8 +4257,13 @@ impl Window {
         }
     }
 
-    /// Handle a `Recenter` event using the active split's viewport.
-    pub(crate) fn handle_recenter_event(&mut self) {
+    /// Handle a `Recenter`, `ScrollCursorToTop` or `ScrollCursorToBottom`
+    /// event: `place` scrolls the active split's viewport (and those synced
+    /// with it) around the cursor.
+    pub(crate) fn handle_recenter_event(
+        &mut self,
+        place: fn(&mut crate::view::viewport::Viewport, &mut crate::model::buffer::Buffer, usize),
+    ) {
         let Some((mgr, vs_map)) = self.buffers.splits() else {
             return;
         };
@@ -4051,11 +4286,11 @@ impl Window {
                 .with_buffer_and_split(buffer_id, split_id, |state, view_state| {
                     let buffer = &mut state.buffer;
                     let cursor_pos = view_state.cursors.primary().position;
-                    // `center_on_position` counts real visual rows, so a
-                    // recenter in a wrapped document doesn't under-scroll
-                    // and leave the cursor below the viewport (each logical
-                    // line above the cursor can span many rows).
-                    view_state.viewport.center_on_position(buffer, cursor_pos);
+                    // `place` counts real visual rows, so a recenter in a
+                    // wrapped document doesn't under-scroll and leave the
+                    // cursor below the viewport (each logical line above
+                    // the cursor can span many rows).
+                    place(&mut view_state.viewport, buffer, cursor_pos);
                     view_state.viewport.set_skip_ensure_visible();
                 });
         }
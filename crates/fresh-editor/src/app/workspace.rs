@@ -33,13 +33,14 @@ use crate::services::terminal::TerminalId;
 use crate::state::ViewMode;
 use crate::view::split::{SplitNode, SplitViewState};
 use crate::workspace::{
-    FileExplorerState, PersistedFileWorkspace, SearchOptions, SerializedBookmark, SerializedCursor,
-    SerializedFileState, SerializedFoldRange, SerializedScroll, SerializedSplitDirection,
-    SerializedSplitNode, SerializedSplitViewState, SerializedTabRef, SerializedTerminalWorkspace,
-    SerializedViewMode, UnnamedBufferRef, Workspace, WorkspaceConfigOverrides, WorkspaceError,
-    WorkspaceHistories, WORKSPACE_VERSION,
+    FileExplorerState, PersistedFileWorkspace, ScratchBufferRef, SearchOptions, SerializedBookmark,
+    SerializedCursor, SerializedFileState, SerializedFoldRange, SerializedLineBookmark,
+    SerializedScroll, SerializedSplitDirection, SerializedSplitNode, SerializedSplitViewState,
+    SerializedTabRef, SerializedTerminalWorkspace, SerializedViewMode, UnnamedBufferRef, Workspace,
+    WorkspaceConfigOverrides, WorkspaceError, WorkspaceHistories, WORKSPACE_VERSION,
 };
 
+use super::bookmark_actions::ResolvedLineBookmark;
 use super::bookmarks::{Bookmark, BookmarkState};
 use super::Editor;
 
@@ -740,6 +741,21 @@ impl crate::app::window::Window {
         }
     }
 
+    /// Re-create line bookmarks (and their gutter markers) from the saved
+    /// workspace, resolving file paths to buffer IDs.
+    fn restore_line_bookmarks_from_workspace(
+        &mut self,
+        bookmarks: &[SerializedLineBookmark],
+        path_to_buffer: &HashMap<PathBuf, BufferId>,
+    ) {
+        for bookmark in bookmarks {
+            let Some(&buffer_id) = path_to_buffer.get(&bookmark.file_path) else {
+                continue;
+            };
+            self.add_line_bookmark(buffer_id, bookmark.position, bookmark.label.clone());
+        }
+    }
+
     /// Drop the initial empty unnamed buffer if it is no longer referenced by any
     /// split after the workspace has been applied.
     fn clean_orphaned_buffers(&mut self) {
@@ -1171,6 +1187,8 @@ impl crate::app::window::Window {
                             wrap_column: self.resolve_wrap_column_for_buffer(second_buffer_id),
                             rulers: self.resources.config.editor.rulers.clone(),
                             scroll_offset: self.resources.config.editor.scroll_offset,
+                            scroll_past_end: self.resources.config.editor.scroll_past_end,
+                            typewriter_scrolling: self.resources.config.editor.typewriter_scrolling,
                         });
                         self.buffers
                             .split_view_states_mut()
@@ -1446,6 +1464,7 @@ impl crate::app::window::Window {
                                 .line_start_offset(end_line.saturating_add(1))
                                 .unwrap_or_else(|| state.buffer.len());
                             buf_state.folds.add(
+                                &state.buffer,
                                 &mut state.marker_list,
                                 start_byte,
                                 end_byte,
@@ -1526,6 +1545,7 @@ impl crate::app::window::Window {
         self.search_whole_word = opts.whole_word;
         self.search_use_regex = opts.use_regex;
         self.search_confirm_each = opts.confirm_each;
+        self.search_multiline = opts.multiline;
     }
 
     fn restore_prompt_histories(&mut self, histories: &WorkspaceHistories) {
@@ -2004,6 +2024,40 @@ impl crate::app::window::Window {
         unnamed_buffer_map
     }
 
+    /// Recreate this window's scratch buffers from the content stored in
+    /// the workspace file, adding each to `buffer_map` under its
+    /// `recovery_id` so the split-layout restore can place its tabs.
+    /// Unlike unnamed buffers these don't depend on `hot_exit`.
+    fn restore_scratch_buffers(
+        &mut self,
+        scratch_buffers: &[ScratchBufferRef],
+        buffer_map: &mut HashMap<String, BufferId>,
+    ) {
+        for scratch in scratch_buffers {
+            let buffer_id = self.create_unnamed_recovery_buffer(
+                &scratch.content,
+                scratch.recovery_id.clone(),
+                scratch.display_name.clone(),
+            );
+            if let Some(meta) = self.buffer_metadata.get_mut(&buffer_id) {
+                meta.scratch = true;
+            }
+            let detected = crate::primitives::detected_language::DetectedLanguage::from_syntax_name(
+                &scratch.language,
+                &self.resources.grammar_registry,
+                &self.resources.config.languages,
+            );
+            if let Some(state) = self.buffers.get_mut(&buffer_id) {
+                if let Some(detected) = detected {
+                    state.apply_language(detected);
+                }
+                state.apply_buffer_config(&self.resources.config);
+                state.buffer.set_modified(!scratch.content.is_empty());
+            }
+            buffer_map.insert(scratch.recovery_id.clone(), buffer_id);
+        }
+    }
+
     /// Replay hot-exit recovery data onto this window's file-backed
     /// buffers that were modified when the editor last exited (via the
     /// shared recovery service in `self.resources`).
@@ -2179,7 +2233,8 @@ impl crate::app::window::Window {
 
         // Unnamed-buffer recovery must precede the split layout (the tree
         // references those buffers).
-        let unnamed_buffer_map = self.restore_unnamed_buffers(&workspace.unnamed_buffers);
+        let mut unnamed_buffer_map = self.restore_unnamed_buffers(&workspace.unnamed_buffers);
+        self.restore_scratch_buffers(&workspace.scratch_buffers, &mut unnamed_buffer_map);
 
         let mut path_to_buffer = self.open_workspace_files(&workspace.split_states);
         self.restore_external_files(&workspace.external_files, &mut path_to_buffer);
@@ -2206,6 +2261,7 @@ impl crate::app::window::Window {
         }
 
         self.restore_bookmarks_from_workspace(&workspace.bookmarks, &path_to_buffer);
+        self.restore_line_bookmarks_from_workspace(&workspace.line_bookmarks, &path_to_buffer);
         self.clean_orphaned_buffers();
         self.log_restore_summary(session_name);
 
@@ -2409,9 +2465,15 @@ impl crate::app::window::Window {
             whole_word: self.search_whole_word,
             use_regex: self.search_use_regex,
             confirm_each: self.search_confirm_each,
+            multiline: self.search_multiline,
         };
 
         let bookmarks = serialize_bookmarks(&self.bookmarks, &self.buffer_metadata, &self.root);
+        let line_bookmarks = serialize_line_bookmarks(
+            self.resolved_line_bookmarks(),
+            &self.buffer_metadata,
+            &self.root,
+        );
 
         let external_files: Vec<PathBuf> = self
             .buffer_metadata
@@ -2444,7 +2506,7 @@ impl crate::app::window::Window {
                     if !path.as_os_str().is_empty() {
                         return None;
                     }
-                    if meta.hidden_from_tabs || meta.is_virtual() {
+                    if meta.hidden_from_tabs || meta.is_virtual() || meta.scratch {
                         return None;
                     }
                     let state = self.buffers.get(buffer_id)?;
@@ -2462,6 +2524,21 @@ impl crate::app::window::Window {
             Vec::new()
         };
 
+        let scratch_buffers: Vec<ScratchBufferRef> = self
+            .buffer_metadata
+            .iter()
+            .filter(|(_, meta)| meta.scratch)
+            .filter_map(|(buffer_id, meta)| {
+                let state = self.buffers.get(buffer_id)?;
+                Some(ScratchBufferRef {
+                    recovery_id: meta.recovery_id.clone()?,
+                    display_name: meta.display_name.clone(),
+                    language: state.display_name.clone(),
+                    content: state.buffer.to_string()?,
+                })
+            })
+            .collect();
+
         Workspace {
             version: WORKSPACE_VERSION,
             working_dir: self.root.clone(),
@@ -2473,10 +2550,12 @@ impl crate::app::window::Window {
             histories,
             search_options,
             bookmarks,
+            line_bookmarks,
             terminals,
             external_files,
             read_only_files,
             unnamed_buffers,
+            scratch_buffers,
             plugin_global_state: HashMap::new(),
             saved_at: std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
@@ -2855,6 +2934,27 @@ fn serialize_split_view_state(
     }
 }
 
+/// Line bookmarks in files under the working directory, at their current
+/// (edit-tracked) positions.
+fn serialize_line_bookmarks(
+    bookmarks: Vec<ResolvedLineBookmark>,
+    buffer_metadata: &HashMap<BufferId, super::types::BufferMetadata>,
+    working_dir: &Path,
+) -> Vec<SerializedLineBookmark> {
+    bookmarks
+        .into_iter()
+        .filter_map(|bookmark| {
+            let abs_path = buffer_metadata.get(&bookmark.buffer_id)?.file_path()?;
+            let rel_path = abs_path.strip_prefix(working_dir).ok()?;
+            Some(SerializedLineBookmark {
+                file_path: rel_path.to_path_buf(),
+                position: bookmark.position,
+                label: bookmark.label,
+            })
+        })
+        .collect()
+}
+
 fn serialize_bookmarks(
     bookmarks: &BookmarkState,
     buffer_metadata: &HashMap<BufferId, super::types::BufferMetadata>,
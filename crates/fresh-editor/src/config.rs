@@ -202,6 +202,17 @@ impl CursorStyle {
         }
     }
 
+    /// The non-blinking variant of this style. `Default` is left alone,
+    /// since the terminal decides whether it blinks.
+    pub fn steady(self) -> Self {
+        match self {
+            Self::BlinkingBlock => Self::SteadyBlock,
+            Self::BlinkingBar => Self::SteadyBar,
+            Self::BlinkingUnderline => Self::SteadyUnderline,
+            other => other,
+        }
+    }
+
     /// Convert to string representation
     pub fn as_str(self) -> &'static str {
         match self {
@@ -230,6 +241,29 @@ impl JsonSchema for CursorStyle {
     }
 }
 
+/// What the primary cursor is doing, which picks its terminal cursor style.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorContext {
+    /// Typing text: `cursor_style`.
+    Editing,
+    /// A selection is active: `cursor_style_selection`.
+    Selection,
+    /// A modal editor mode that takes no text input, such as vi normal
+    /// mode: `cursor_style_normal_mode`.
+    NormalMode,
+}
+
+/// How secondary cursors are drawn when there are several
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SecondaryCursorStyle {
+    /// Reverse-video block, like the primary cursor
+    #[default]
+    Block,
+    /// Underlined character
+    Underline,
+}
+
 /// Indentation guide rendering mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -378,6 +412,148 @@ impl JsonSchema for VirtualSpaceMode {
     }
 }
 
+/// How the gutter numbers lines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineNumberMode {
+    /// Line numbers count from the top of the buffer
+    #[default]
+    Absolute,
+    /// Distance from the cursor line, which shows 0
+    Relative,
+    /// Distance from the cursor line, which shows its absolute number
+    Hybrid,
+}
+
+impl JsonSchema for LineNumberMode {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("LineNumberMode")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "How the gutter numbers lines",
+            "type": "string",
+            "enum": ["absolute", "relative", "hybrid"],
+            "default": "absolute"
+        })
+    }
+}
+
+/// One column of the gutter, placed in the order listed in
+/// `editor.gutter_components`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GutterComponent {
+    /// LSP diagnostic markers
+    Diagnostics,
+    /// Line bookmarks
+    Bookmarks,
+    /// Change markers from git, live diff, unsaved edits and other plugins
+    Git,
+    /// Fold arrows
+    Fold,
+    /// Line numbers
+    LineNumbers,
+}
+
+impl GutterComponent {
+    /// Whether this component is a one-cell sign rather than line numbers.
+    pub fn is_sign(self) -> bool {
+        self != Self::LineNumbers
+    }
+}
+
+/// Default `editor.gutter_components`: one sign column where diagnostics
+/// beat bookmarks, git changes and fold arrows, then the line numbers.
+pub const DEFAULT_GUTTER_COMPONENTS: &[GutterComponent] = &[
+    GutterComponent::Diagnostics,
+    GutterComponent::Bookmarks,
+    GutterComponent::Git,
+    GutterComponent::Fold,
+    GutterComponent::LineNumbers,
+];
+
+/// Where typewriter scrolling holds the cursor line on screen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TypewriterScrolling {
+    /// The cursor moves freely within the `scroll_offset` margins
+    #[default]
+    Off,
+    /// The cursor line stays in the vertical middle of the viewport
+    Center,
+    /// The cursor line stays `scroll_offset` rows below the viewport top
+    Offset,
+}
+
+impl JsonSchema for TypewriterScrolling {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("TypewriterScrolling")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Where typewriter scrolling holds the cursor line on screen",
+            "type": "string",
+            "enum": ["off", "center", "offset"],
+            "default": "off"
+        })
+    }
+}
+
+/// How typed text filters the completion popup.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompletionMatching {
+    /// Typed characters must appear in order, not necessarily adjacent
+    #[default]
+    Fuzzy,
+    /// Items must start with the typed text
+    Prefix,
+}
+
+impl JsonSchema for CompletionMatching {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("CompletionMatching")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "How typed text filters the completion popup",
+            "type": "string",
+            "enum": ["fuzzy", "prefix"],
+            "default": "fuzzy"
+        })
+    }
+}
+
+/// Order of the items in the completion popup.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompletionSort {
+    /// Best match first; the server's `sortText` breaks ties
+    #[default]
+    Score,
+    /// The server's `sortText` order, ignoring match quality
+    Server,
+}
+
+impl JsonSchema for CompletionSort {
+    fn schema_name() -> Cow<'static, str> {
+        Cow::Borrowed("CompletionSort")
+    }
+
+    fn json_schema(_gen: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        schemars::json_schema!({
+            "description": "Order of the items in the completion popup",
+            "type": "string",
+            "enum": ["score", "server"],
+            "default": "score"
+        })
+    }
+}
+
 impl PartialEq<KeybindingMapName> for str {
     fn eq(&self, other: &KeybindingMapName) -> bool {
         self == other.0
@@ -416,10 +592,15 @@ pub struct Config {
     pub locale: LocaleName,
 
     /// Check for new versions on startup (default: true).
-    /// When enabled, also sends basic anonymous telemetry (version, OS, terminal type).
     #[serde(default = "default_true")]
     pub check_for_updates: bool,
 
+    /// Send basic anonymous telemetry (version, OS, terminal type) once a day.
+    /// `null` until answered at the consent prompt shown on launch; nothing is
+    /// sent before that. Independent of `check_for_updates`.
+    #[serde(default)]
+    pub telemetry: Option<bool>,
+
     /// Offer an interactive in-editor update when a new version is detected
     /// (default: true). When on, clicking the status-bar update indicator (or
     /// the "Update fresh" command) prompts to update now; confirming runs the
@@ -430,6 +611,12 @@ pub struct Config {
     #[serde(default = "default_true")]
     pub self_update: bool,
 
+    /// Never use the network (default: false), for air-gapped machines. No
+    /// update check or telemetry is sent, and plugin downloads fail right
+    /// away instead of waiting for a timeout.
+    #[serde(default)]
+    pub offline: bool,
+
     /// Editor behavior settings (indentation, line numbers, wrapping, etc.)
     #[serde(default)]
     pub editor: EditorConfig,
@@ -485,6 +672,13 @@ pub struct Config {
     #[serde(default = "default_true")]
     pub lsp_enabled: bool,
 
+    /// Share language servers between Fresh instances. Servers are started
+    /// through a background LSP proxy, so a second editor on the same
+    /// project attaches to the running server (e.g. one rust-analyzer per
+    /// workspace) instead of starting its own. Applies to local workspaces.
+    #[serde(default)]
+    pub lsp_shared_servers: bool,
+
     /// LSP server configurations by language.
     /// Each language maps to one or more server configs (multi-LSP support).
     /// Accepts both single-object and array forms for backwards compatibility.
@@ -515,6 +709,24 @@ pub struct Config {
     /// Environment auto-activation detectors (venv / direnv / mise / …).
     #[serde(default)]
     pub env: EnvConfig,
+
+    /// JSON/YAML schema validation
+    #[serde(default)]
+    pub json_schemas: JsonSchemaConfig,
+
+    /// Command-line linters whose output is reported as diagnostics, keyed
+    /// by linter name. Presets ship for shellcheck, ruff, eslint and
+    /// cargo clippy; override a preset's fields or add your own.
+    #[serde(default = "Config::default_linters")]
+    #[schemars(default = "default_linters_schema")]
+    pub linters: HashMap<String, LinterConfig>,
+
+    /// Commands and editor actions run when a file is opened, saved,
+    /// switched to or left idle — e.g. regenerate code whenever
+    /// `schema.graphql` is saved. Commands run as jobs; a failing one is
+    /// reported in the status bar.
+    #[serde(default)]
+    pub hooks: Vec<EventHookConfig>,
 }
 
 /// Environment-detection configuration: the single source of truth for which
@@ -664,6 +876,12 @@ fn default_keybinding_map_schema() -> KeybindingMapName {
     KeybindingMapName("default".to_string())
 }
 
+/// Schema-stable default for `linters`, sorted by name so the generated
+/// JSON Schema does not depend on `HashMap` iteration order.
+fn default_linters_schema() -> std::collections::BTreeMap<String, LinterConfig> {
+    Config::default_linters().into_iter().collect()
+}
+
 fn default_theme_name() -> ThemeName {
     ThemeName("high-contrast".to_string())
 }
@@ -680,6 +898,9 @@ pub struct WhitespaceVisibility {
     pub tabs_leading: bool,
     pub tabs_inner: bool,
     pub tabs_trailing: bool,
+    pub line_endings: bool,
+    /// Background highlight on trailing whitespace (not an indicator glyph).
+    pub highlight_trailing: bool,
 }
 
 impl Default for WhitespaceVisibility {
@@ -692,6 +913,8 @@ impl Default for WhitespaceVisibility {
             tabs_leading: true,
             tabs_inner: true,
             tabs_trailing: true,
+            line_endings: false,
+            highlight_trailing: false,
         }
     }
 }
@@ -700,14 +923,7 @@ impl WhitespaceVisibility {
     /// Resolve from EditorConfig flat fields (applying master toggle)
     pub fn from_editor_config(editor: &EditorConfig) -> Self {
         if !editor.whitespace_show {
-            return Self {
-                spaces_leading: false,
-                spaces_inner: false,
-                spaces_trailing: false,
-                tabs_leading: false,
-                tabs_inner: false,
-                tabs_trailing: false,
-            };
+            return Self::hidden();
         }
         Self {
             spaces_leading: editor.whitespace_spaces_leading,
@@ -716,6 +932,8 @@ impl WhitespaceVisibility {
             tabs_leading: editor.whitespace_tabs_leading,
             tabs_inner: editor.whitespace_tabs_inner,
             tabs_trailing: editor.whitespace_tabs_trailing,
+            line_endings: editor.whitespace_line_endings,
+            highlight_trailing: editor.whitespace_highlight_trailing,
         }
     }
 
@@ -740,9 +958,10 @@ impl WhitespaceVisibility {
         self.tabs_leading || self.tabs_inner || self.tabs_trailing
     }
 
-    /// Returns true if any indicator (space or tab) is enabled
+    /// Returns true if any indicator (space, tab, line ending) or the
+    /// trailing-whitespace highlight is enabled
     pub fn any_visible(&self) -> bool {
-        self.any_spaces() || self.any_tabs()
+        self.any_spaces() || self.any_tabs() || self.line_endings || self.highlight_trailing
     }
 
     /// All indicators disabled — the "hidden" state of the master toggle.
@@ -754,6 +973,8 @@ impl WhitespaceVisibility {
             tabs_leading: false,
             tabs_inner: false,
             tabs_trailing: false,
+            line_endings: false,
+            highlight_trailing: false,
         }
     }
 
@@ -780,7 +1001,9 @@ impl WhitespaceVisibility {
 ///
 /// Elements are specified as strings in the config:
 /// - `"{filename}"` — file path with session/remote prefix, modified and read-only indicators
+/// - `"{mode}"` — editor mode set by a modal-editing plugin (e.g. `vi-normal`), hidden when none
 /// - `"{read_only}"` — persistent `[RO]` indicator, shown only while the buffer is read-only
+/// - `"{narrowed}"` — the source lines of a narrowed buffer, shown only while narrowed
 /// - `"{cursor}"` — cursor position as `Ln 1, Col 1`
 /// - `"{cursor:compact}"` — cursor position as `1:1`
 /// - `"{diagnostics}"` — error/warning/info counts (e.g. `E:1 W:2`)
@@ -791,6 +1014,7 @@ impl WhitespaceVisibility {
 /// - `"{encoding}"` — file encoding (e.g. UTF-8)
 /// - `"{language}"` — detected language name
 /// - `"{lsp}"` — LSP server status indicator
+/// - `"{jobs}"` — running plugin jobs and their progress
 /// - `"{warnings}"` — general warning badge
 /// - `"{update}"` — update available indicator
 /// - `"{palette}"` — command palette shortcut hint
@@ -801,10 +1025,15 @@ impl WhitespaceVisibility {
 pub enum StatusBarElement {
     /// File path with session/remote prefix, modified/read-only indicators
     Filename,
+    /// Editor mode set by a plugin (e.g. `vi-normal`); hidden when none is set
+    Mode,
     /// Persistent `[RO]` read-only indicator. Renders only while the active
     /// buffer is read-only, as a steady status segment independent of the
     /// `{filename}` element (which is omitted from the default layout).
     ReadOnly,
+    /// Source lines of a narrowed buffer (e.g. `Narrowed 10-50`). Renders
+    /// only while the active buffer was made by **Narrow to Selection**.
+    Narrowed,
     /// Cursor position (default format: `Ln 1, Col 1`)
     Cursor,
     /// Cursor position (compact format: `1:1`)
@@ -825,6 +1054,9 @@ pub enum StatusBarElement {
     Language,
     /// LSP server status
     Lsp,
+    /// Running plugin jobs (`editor.spawnJob`) and their progress. Hidden
+    /// when nothing runs; clickable (opens the jobs menu).
+    Jobs,
     /// General warning badge
     Warnings,
     /// Update available indicator
@@ -857,7 +1089,9 @@ impl TryFrom<String> for StatusBarElement {
             .unwrap_or(&s);
         match inner {
             "filename" => Ok(Self::Filename),
+            "mode" => Ok(Self::Mode),
             "read_only" => Ok(Self::ReadOnly),
+            "narrowed" => Ok(Self::Narrowed),
             "cursor" => Ok(Self::Cursor),
             "cursor:compact" => Ok(Self::CursorCompact),
             "diagnostics" => Ok(Self::Diagnostics),
@@ -868,6 +1102,7 @@ impl TryFrom<String> for StatusBarElement {
             "encoding" => Ok(Self::Encoding),
             "language" => Ok(Self::Language),
             "lsp" => Ok(Self::Lsp),
+            "jobs" => Ok(Self::Jobs),
             "warnings" => Ok(Self::Warnings),
             "update" => Ok(Self::Update),
             "palette" => Ok(Self::Palette),
@@ -890,7 +1125,9 @@ impl From<StatusBarElement> for String {
     fn from(e: StatusBarElement) -> String {
         match e {
             StatusBarElement::Filename => "{filename}".to_string(),
+            StatusBarElement::Mode => "{mode}".to_string(),
             StatusBarElement::ReadOnly => "{read_only}".to_string(),
+            StatusBarElement::Narrowed => "{narrowed}".to_string(),
             StatusBarElement::Cursor => "{cursor}".to_string(),
             StatusBarElement::CursorCompact => "{cursor:compact}".to_string(),
             StatusBarElement::Diagnostics => "{diagnostics}".to_string(),
@@ -901,6 +1138,7 @@ impl From<StatusBarElement> for String {
             StatusBarElement::Encoding => "{encoding}".to_string(),
             StatusBarElement::Language => "{language}".to_string(),
             StatusBarElement::Lsp => "{lsp}".to_string(),
+            StatusBarElement::Jobs => "{jobs}".to_string(),
             StatusBarElement::Warnings => "{warnings}".to_string(),
             StatusBarElement::Update => "{update}".to_string(),
             StatusBarElement::Palette => "{palette}".to_string(),
@@ -921,7 +1159,9 @@ impl schemars::JsonSchema for StatusBarElement {
             "type": "string",
             "x-dual-list-options": [
                 {"value": "{filename}", "name": "Filename"},
+                {"value": "{mode}", "name": "Mode"},
                 {"value": "{read_only}", "name": "Read-Only"},
+                {"value": "{narrowed}", "name": "Narrowed"},
                 {"value": "{cursor}", "name": "Cursor"},
                 {"value": "{cursor:compact}", "name": "Cursor (compact)"},
                 {"value": "{diagnostics}", "name": "Diagnostics"},
@@ -932,6 +1172,7 @@ impl schemars::JsonSchema for StatusBarElement {
                 {"value": "{encoding}", "name": "Encoding"},
                 {"value": "{language}", "name": "Language"},
                 {"value": "{lsp}", "name": "LSP"},
+                {"value": "{jobs}", "name": "Jobs"},
                 {"value": "{warnings}", "name": "Warnings"},
                 {"value": "{update}", "name": "Update"},
                 {"value": "{palette}", "name": "Palette"},
@@ -970,9 +1211,11 @@ fn default_status_bar_right() -> Vec<StatusBarElement> {
         // standing home even though `{filename}` (its other host) is omitted
         // from the default layout.
         StatusBarElement::ReadOnly,
+        StatusBarElement::Narrowed,
         StatusBarElement::LineEnding,
         StatusBarElement::Encoding,
         StatusBarElement::Language,
+        StatusBarElement::Jobs,
         StatusBarElement::Lsp,
         StatusBarElement::Warnings,
         StatusBarElement::Update,
@@ -1003,7 +1246,7 @@ pub struct StatusBarConfig {
     pub left: Vec<StatusBarElement>,
 
     /// Elements shown on the right side of the status bar.
-    /// Default: ["{read_only}", "{line_ending}", "{encoding}", "{language}", "{lsp}", "{warnings}", "{update}", "{palette}"]
+    /// Default: ["{read_only}", "{narrowed}", "{line_ending}", "{encoding}", "{language}", "{jobs}", "{lsp}", "{warnings}", "{update}", "{palette}"]
     #[serde(default = "default_status_bar_right")]
     #[schemars(extend("x-section" = "Status Bar", "x-dual-list-sibling" = "/editor/status_bar/left", "x-dynamically-extendable-status-bar-elements" = true))]
     pub right: Vec<StatusBarElement>,
@@ -1016,6 +1259,56 @@ pub struct StatusBarConfig {
     #[serde(default = "default_status_bar_separator")]
     #[schemars(extend("x-section" = "Status Bar"))]
     pub separator: String,
+
+    /// Per-element styling and click actions, keyed by the element as it
+    /// appears in `left` / `right` (e.g. `"{clock}"` or `"{git:branch}"`).
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Status Bar"))]
+    pub segments: HashMap<String, StatusBarSegment>,
+}
+
+/// Styling and click action for one status bar element.
+///
+/// Example config:
+/// ```json
+/// {
+///   "status_bar": {
+///     "segments": {
+///       "{language}": { "fg": "#88c0d0", "bold": true },
+///       "{clock}": { "bg": "ui.status_palette_bg", "on_click": "show_messages" }
+///     }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct StatusBarSegment {
+    /// Foreground color: a theme key (e.g. `"ui.status_lsp_on_fg"`) or a
+    /// `"#rrggbb"` hex color. Unset keeps the element's own color.
+    #[serde(default)]
+    pub fg: Option<String>,
+
+    /// Background color, in the same forms as `fg`.
+    #[serde(default)]
+    pub bg: Option<String>,
+
+    /// Draw the element in bold.
+    #[serde(default)]
+    pub bold: bool,
+
+    /// Action run when the element is clicked (e.g. `"show_messages"`),
+    /// replacing the element's own click behavior.
+    #[serde(default)]
+    pub on_click: Option<String>,
+}
+
+impl StatusBarConfig {
+    /// The `segments` entry for an element, if any.
+    pub fn segment(&self, element: &StatusBarElement) -> Option<&StatusBarSegment> {
+        if self.segments.is_empty() {
+            return None;
+        }
+        self.segments.get(&String::from(element.clone()))
+    }
 }
 
 fn default_status_bar_separator() -> String {
@@ -1049,6 +1342,7 @@ impl Default for StatusBarConfig {
             left: default_status_bar_left(),
             right: default_status_bar_right(),
             separator: default_status_bar_separator(),
+            segments: HashMap::new(),
         }
     }
 }
@@ -1083,9 +1377,40 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub relative_line_numbers: bool,
 
-    /// Highlight the line containing the cursor
+    /// How line numbers count. "absolute": from the top of the buffer.
+    /// "relative": distance from the cursor line, which shows 0.
+    /// "hybrid": relative, but the cursor line shows its absolute number.
+    /// `relative_line_numbers` selects "hybrid" while this is "absolute".
+    /// Default: "absolute"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Display"))]
+    pub line_number_mode: LineNumberMode,
+
+    /// Minimum number of digits reserved for line numbers. The column
+    /// still grows for longer buffers.
+    /// Default: 2
+    #[serde(default = "default_line_number_min_width")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub line_number_min_width: usize,
+
+    /// Gutter columns, left to right. Remove an entry to hide it.
+    /// Default: ["diagnostics", "bookmarks", "git", "fold", "line_numbers"]
+    #[serde(default = "default_gutter_components")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub gutter_components: Vec<GutterComponent>,
+
+    /// Draw the diagnostics, bookmarks, git and fold signs in one shared
+    /// column, where the earliest entry in `gutter_components` with a sign
+    /// on the line wins. When off, each gets a column of its own.
+    /// Default: true
     #[serde(default = "default_true")]
     #[schemars(extend("x-section" = "Display"))]
+    pub gutter_shared_signs: bool,
+
+    /// Highlight the line containing the cursor with a subtle background
+    /// across the full content width. Also accepted as `cursorline`.
+    #[serde(default = "default_true", alias = "cursorline")]
+    #[schemars(extend("x-section" = "Display"))]
     pub highlight_current_line: bool,
 
     /// Highlight all occurrences of the word under the cursor
@@ -1132,6 +1457,13 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub page_width: Option<usize>,
 
+    /// Width of the centered text column in Zen mode (in columns).
+    /// Set to `0` to use the full viewport width.
+    /// Default: 80
+    #[serde(default = "default_zen_mode_width")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub zen_mode_width: usize,
+
     /// Enable syntax highlighting for code files
     #[serde(default = "default_true")]
     #[schemars(extend("x-section" = "Display"))]
@@ -1268,6 +1600,35 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Display"))]
     pub cursor_style: CursorStyle,
 
+    /// Cursor style while text is selected. `null` uses `cursor_style`.
+    /// For example, a "steady_bar" `cursor_style` with a "steady_block"
+    /// selection style gives a bar while typing and a block over selections.
+    /// Default: null
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Display"))]
+    pub cursor_style_selection: Option<CursorStyle>,
+
+    /// Cursor style in a modal editor mode that takes no text input, such
+    /// as vi normal mode. `null` uses `cursor_style`.
+    /// Default: null
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Display"))]
+    pub cursor_style_normal_mode: Option<CursorStyle>,
+
+    /// Whether the cursor blinks. When off, blinking cursor styles are
+    /// shown steady.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Display"))]
+    pub cursor_blink: bool,
+
+    /// How secondary cursors are drawn with multiple cursors: "block"
+    /// (reverse video, like the primary cursor) or "underline".
+    /// Default: "block"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Display"))]
+    pub secondary_cursor_style: SecondaryCursorStyle,
+
     /// Vertical ruler lines at specific column positions.
     /// Draws subtle vertical lines to help with line length conventions.
     /// Example: [80, 120] draws rulers at columns 80 and 120.
@@ -1361,6 +1722,20 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Whitespace"))]
     pub whitespace_tabs_trailing: bool,
 
+    /// Show a line-ending indicator (↵) at the end of every line.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Whitespace"))]
+    pub whitespace_line_endings: bool,
+
+    /// Highlight trailing whitespace with the theme's `trailing_whitespace_bg`
+    /// color, independently of the indicator glyphs above. Like the indicators,
+    /// it is hidden while the whitespace master toggle is off.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Whitespace"))]
+    pub whitespace_highlight_trailing: bool,
+
     // ===== Editing =====
     /// Whether pressing Tab inserts a tab character instead of spaces.
     /// This is the global default; individual languages can override it
@@ -1410,11 +1785,36 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Editing"))]
     pub virtual_space: VirtualSpaceMode,
 
+    /// What Tab does, in priority order: the first behavior that applies
+    /// wins. Remove an entry to disable it.
+    /// Default: ["accept_completion", "expand_snippet", "next_tab_stop",
+    /// "indent_selection", "insert_indent"]
+    #[serde(default = "default_smart_tab")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub smart_tab: Vec<SmartTabBehavior>,
+
     /// Minimum lines to keep visible above/below cursor when scrolling
     #[serde(default = "default_scroll_offset")]
     #[schemars(extend("x-section" = "Editing"))]
     pub scroll_offset: usize,
 
+    /// Allow scrolling past the last line, until it reaches the top of the
+    /// viewport. When disabled, the last line stops at the bottom.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub scroll_past_end: bool,
+
+    /// Typewriter scrolling: keep the cursor line at a fixed screen row
+    /// while typing and navigating, scrolling the text instead.
+    /// "center" holds it in the middle of the viewport, "offset" holds it
+    /// `scroll_offset` rows below the top. The last lines of the buffer
+    /// may scroll above the bottom edge to reach that row.
+    /// Default: "off"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub typewriter_scrolling: TypewriterScrolling,
+
     /// Default line ending format for new files.
     /// Files loaded from disk will use their detected line ending format.
     /// Options: "lf" (Unix/Linux/macOS), "crlf" (Windows), "cr" (Classic Mac)
@@ -1435,10 +1835,20 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Editing"))]
     pub ensure_final_newline_on_save: bool,
 
+    /// Abbreviations expanded as you type: typing the key followed by a
+    /// space or punctuation replaces it with the value (e.g. `"teh": "the"`).
+    /// Values use snippet syntax, so `";fn"` can expand to a template with
+    /// tab stops. `languages.<id>.abbreviations` adds or overrides entries
+    /// for one language.
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub abbreviations: HashMap<String, String>,
+
     /// Automatically open files in read-only mode when they are not
     /// writable on disk (filesystem permissions) or live in a
     /// library/vendor directory (rustup toolchains, node_modules,
-    /// /usr/include, /nix/store, ...). When disabled, such files open
+    /// /usr/include, /nix/store, ...), or are generated code (an
+    /// `@generated` or `DO NOT EDIT` header). When disabled, such files open
     /// editable; saving may still fail unless permissions allow it.
     /// Binary files always open read-only regardless of this setting.
     /// Default: true
@@ -1446,6 +1856,26 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Editing"))]
     pub auto_read_only: bool,
 
+    /// Command used to save a file you lack permission to write, after
+    /// confirming the prompt shown when a save is denied. It runs as
+    /// `<command> tee <file>`, then `<command> chmod` and `<command> chown`
+    /// to keep the file's mode and owner. Arguments are separated by
+    /// spaces, e.g. "doas" or "sudo -A". Only read from your user config;
+    /// a project's `.fresh/` config cannot set it.
+    /// Default: "sudo"
+    #[serde(default = "default_elevation_command")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub elevation_command: String,
+
+    /// Keep search matches highlighted after the search prompt closes, until
+    /// **Clear Search** is run or a new search starts. When disabled, the
+    /// highlights disappear as soon as the search is confirmed; Find Next
+    /// and Find Previous still step through the matches.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Editing"))]
+    pub keep_search_highlights: bool,
+
     // ===== Bracket Matching =====
     /// Highlight matching bracket pairs when cursor is on a bracket.
     /// Default: true
@@ -1495,6 +1925,66 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Completion"))]
     pub suggest_on_trigger_characters: bool,
 
+    /// Offer words from the whole project in buffer-word completion, not
+    /// only from open buffers. Words come from a persistent per-project
+    /// index built in the background on first use; candidates from files
+    /// closer to the current one rank higher.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Completion"))]
+    pub project_word_completion: bool,
+
+    /// Offer names from the project's ctags `tags` file (at the project
+    /// root) in buffer-word completion, ranked below words from open buffers
+    /// and the project word index. The file is read again when it changes.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Completion"))]
+    pub tag_word_completion: bool,
+
+    /// How typed text filters the completion popup. "fuzzy": the typed
+    /// characters must appear in order anywhere in the item (`gtv` finds
+    /// `get_text_value`), and matched characters are highlighted. "prefix":
+    /// items must start with the typed text.
+    /// Default: "fuzzy"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Completion"))]
+    pub completion_matching: CompletionMatching,
+
+    /// Order of LSP items in the completion popup. "score": best match
+    /// first, with the server's `sortText` breaking ties. "server": the
+    /// server's `sortText` order regardless of how well the item matches.
+    /// Default: "score"
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Completion"))]
+    pub completion_sort: CompletionSort,
+
+    /// Show documentation for the selected completion item in a panel
+    /// beside the completion popup. Documentation the server leaves out
+    /// of the completion list is fetched with `completionItem/resolve`
+    /// when an item is first selected.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Completion"))]
+    pub completion_documentation: bool,
+
+    // ===== Tags =====
+    /// Command that writes the project's ctags `tags` file, run from the
+    /// project root by Generate Tags and after saves when
+    /// `tags_generate_on_save` is on. The first element is the program.
+    /// Default: ["ctags", "-R", "."]
+    #[serde(default = "default_tags_command")]
+    #[schemars(extend("x-section" = "Tags"))]
+    pub tags_command: Vec<String>,
+
+    /// Regenerate the project's tags file in the background after each
+    /// save. Usually set in the project's own config, for projects that use
+    /// tags for navigation.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Tags"))]
+    pub tags_generate_on_save: bool,
+
     // ===== LSP =====
     /// Whether to enable LSP inlay hints (type hints, parameter hints, etc.)
     #[serde(default = "default_true")]
@@ -1544,6 +2034,19 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Mouse"))]
     pub double_click_time_ms: u64,
 
+    /// Number of lines one mouse-wheel notch scrolls.
+    /// Default: 3
+    #[serde(default = "default_mouse_wheel_scroll_lines")]
+    #[schemars(extend("x-section" = "Mouse"))]
+    pub mouse_wheel_scroll_lines: usize,
+
+    /// Animate mouse-wheel scrolling over several frames instead of
+    /// jumping the whole distance at once.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Mouse"))]
+    pub smooth_scroll: bool,
+
     /// Whether to enable persistent auto-save (save to original file on disk).
     /// When enabled, modified buffers are saved to their original file path
     /// at a configurable interval.
@@ -1643,6 +2146,23 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Recovery"))]
     pub auto_revert_poll_interval_ms: u64,
 
+    /// Whether to keep a local history of saved files. When enabled, every
+    /// save also stores a timestamped copy of the file in the data
+    /// directory, so the **File History** command can show how it looked
+    /// at earlier saves and restore it, independent of version control.
+    /// Default: false
+    #[serde(default = "default_false")]
+    #[schemars(extend("x-section" = "Recovery"))]
+    pub local_history_enabled: bool,
+
+    /// How many saved versions local history keeps per file. The oldest
+    /// copies are removed once a file has more than this.
+    /// Only effective when local_history_enabled is true.
+    /// Default: 50
+    #[serde(default = "default_local_history_max_versions")]
+    #[schemars(extend("x-section" = "Recovery"))]
+    pub local_history_max_versions: u32,
+
     // ===== Keyboard =====
     /// Enable keyboard enhancement: disambiguate escape codes using CSI-u sequences.
     /// This allows unambiguous reading of Escape and modified keys.
@@ -1677,6 +2197,36 @@ pub struct EditorConfig {
     #[schemars(extend("x-section" = "Keyboard"))]
     pub keyboard_report_all_keys_as_escape_codes: bool,
 
+    /// Compose dead keys the terminal passes through as spacing accents.
+    /// A non-ASCII accent such as ´ or ¨ is held until the next key and
+    /// combined with it (´ then e types é) instead of being typed on its own.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Keyboard"))]
+    pub compose_dead_keys: bool,
+
+    /// Milliseconds a multi-key chord (e.g. Ctrl+X Ctrl+S) waits for its
+    /// next key before it is abandoned. 0 waits until the next key press.
+    /// Default: 0
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Keyboard"))]
+    pub chord_timeout_ms: u64,
+
+    /// After the first key of a chord, show a popup listing the keys that
+    /// can follow it and the action each one runs.
+    /// Default: true
+    #[serde(default = "default_true")]
+    #[schemars(extend("x-section" = "Keyboard"))]
+    pub show_chord_hints: bool,
+
+    /// Extra keys the Calibrate Keyboard wizard asks for after its built-in
+    /// groups, written as a key with optional modifiers joined by `+`.
+    /// Example: ["F5", "ctrl+enter", "ctrl+shift+f12"]
+    /// Default: []
+    #[serde(default)]
+    #[schemars(extend("x-section" = "Keyboard"))]
+    pub calibration_keys: Vec<String>,
+
     // ===== Performance =====
     /// Maximum time in milliseconds for syntax highlighting per frame
     #[serde(default = "default_highlight_timeout")]
@@ -1728,6 +2278,14 @@ pub struct EditorConfig {
     #[serde(default = "default_file_tree_poll_interval")]
     #[schemars(extend("x-section" = "Performance"))]
     pub file_tree_poll_interval_ms: u64,
+
+    /// Address the Serve Buffer command listens on. The default only
+    /// accepts connections from this machine, on a free port; use e.g.
+    /// "0.0.0.0:8138" to let others on your network follow along.
+    /// Default: "127.0.0.1:0"
+    #[serde(default = "default_serve_buffer_address")]
+    #[schemars(extend("x-section" = "Web Sharing"))]
+    pub serve_buffer_address: String,
 }
 
 fn default_tab_size() -> usize {
@@ -1774,6 +2332,10 @@ fn default_screensaver_idle_minutes() -> u32 {
     5
 }
 
+fn default_tags_command() -> Vec<String> {
+    vec!["ctags".to_string(), "-R".to_string(), ".".to_string()]
+}
+
 fn default_false() -> bool {
     false
 }
@@ -1786,6 +2348,18 @@ fn default_scroll_offset() -> usize {
     3
 }
 
+fn default_line_number_min_width() -> usize {
+    crate::view::margin::MIN_LINE_NUMBER_DIGITS
+}
+
+fn default_gutter_components() -> Vec<GutterComponent> {
+    DEFAULT_GUTTER_COMPONENTS.to_vec()
+}
+
+fn default_mouse_wheel_scroll_lines() -> usize {
+    3
+}
+
 fn default_highlight_timeout() -> u64 {
     5
 }
@@ -1826,10 +2400,50 @@ fn default_auto_revert_poll_interval() -> u64 {
     2000 // 2 seconds between file mtime checks
 }
 
+fn default_local_history_max_versions() -> u32 {
+    50
+}
+
 fn default_file_tree_poll_interval() -> u64 {
     3000 // 3 seconds between directory mtime checks
 }
 
+fn default_serve_buffer_address() -> String {
+    "127.0.0.1:0".to_string()
+}
+
+fn default_elevation_command() -> String {
+    "sudo".to_string()
+}
+
+impl EditorConfig {
+    /// The line number mode in effect: `line_number_mode`, or "hybrid"
+    /// when only the older `relative_line_numbers` switch is on.
+    pub fn effective_line_number_mode(&self) -> LineNumberMode {
+        if self.line_number_mode == LineNumberMode::Absolute && self.relative_line_numbers {
+            LineNumberMode::Hybrid
+        } else {
+            self.line_number_mode
+        }
+    }
+
+    /// The terminal cursor style for `context`, with blinking turned off
+    /// when `cursor_blink` is.
+    pub fn cursor_style_for(&self, context: CursorContext) -> CursorStyle {
+        let style = match context {
+            CursorContext::Editing => None,
+            CursorContext::Selection => self.cursor_style_selection,
+            CursorContext::NormalMode => self.cursor_style_normal_mode,
+        }
+        .unwrap_or(self.cursor_style);
+        if self.cursor_blink {
+            style
+        } else {
+            style.steady()
+        }
+    }
+}
+
 impl Default for EditorConfig {
     fn default() -> Self {
         Self {
@@ -1839,11 +2453,18 @@ impl Default for EditorConfig {
             auto_close: true,
             auto_surround: true,
             virtual_space: VirtualSpaceMode::default(),
+            smart_tab: default_smart_tab(),
             animations: true,
             cursor_jump_animation: true,
             line_numbers: true,
             relative_line_numbers: false,
+            line_number_mode: LineNumberMode::default(),
+            line_number_min_width: default_line_number_min_width(),
+            gutter_components: default_gutter_components(),
+            gutter_shared_signs: true,
             scroll_offset: default_scroll_offset(),
+            scroll_past_end: false,
+            typewriter_scrolling: TypewriterScrolling::default(),
             syntax_highlighting: true,
             highlight_current_line: true,
             highlight_occurrences: true,
@@ -1853,6 +2474,7 @@ impl Default for EditorConfig {
             wrap_indent: true,
             wrap_column: None,
             page_width: default_page_width(),
+            zen_mode_width: default_zen_mode_width(),
             highlight_timeout_ms: default_highlight_timeout(),
             snapshot_interval: default_snapshot_interval(),
             large_file_threshold_bytes: default_large_file_threshold(),
@@ -1873,24 +2495,47 @@ impl Default for EditorConfig {
             mouse_hover_enabled: default_mouse_hover_enabled(),
             mouse_hover_delay_ms: default_mouse_hover_delay(),
             double_click_time_ms: default_double_click_time(),
+            mouse_wheel_scroll_lines: default_mouse_wheel_scroll_lines(),
+            smooth_scroll: false,
             auto_revert_poll_interval_ms: default_auto_revert_poll_interval(),
+            local_history_enabled: false,
+            local_history_max_versions: default_local_history_max_versions(),
             read_concurrency: default_read_concurrency(),
             file_tree_poll_interval_ms: default_file_tree_poll_interval(),
+            serve_buffer_address: default_serve_buffer_address(),
             default_line_ending: LineEndingOption::default(),
             trim_trailing_whitespace_on_save: false,
             ensure_final_newline_on_save: false,
+            abbreviations: HashMap::new(),
             auto_read_only: true,
+            elevation_command: default_elevation_command(),
+            keep_search_highlights: true,
             highlight_matching_brackets: true,
             rainbow_brackets: true,
             cursor_style: CursorStyle::default(),
+            cursor_style_selection: None,
+            cursor_style_normal_mode: None,
+            cursor_blink: true,
+            secondary_cursor_style: SecondaryCursorStyle::default(),
             keyboard_disambiguate_escape_codes: true,
             keyboard_report_event_types: false,
             keyboard_report_alternate_keys: true,
             keyboard_report_all_keys_as_escape_codes: false,
+            compose_dead_keys: true,
+            chord_timeout_ms: 0,
+            show_chord_hints: true,
+            calibration_keys: Vec::new(),
             completion_popup_auto_show: false,
             quick_suggestions: true,
             quick_suggestions_delay_ms: default_quick_suggestions_delay(),
             suggest_on_trigger_characters: true,
+            project_word_completion: true,
+            tag_word_completion: false,
+            completion_matching: CompletionMatching::default(),
+            completion_sort: CompletionSort::default(),
+            completion_documentation: true,
+            tags_command: default_tags_command(),
+            tags_generate_on_save: false,
             show_menu_bar: true,
             screensaver_enabled: false,
             screensaver_idle_minutes: default_screensaver_idle_minutes(),
@@ -1917,6 +2562,8 @@ impl Default for EditorConfig {
             whitespace_tabs_leading: true,
             whitespace_tabs_inner: true,
             whitespace_tabs_trailing: true,
+            whitespace_line_endings: false,
+            whitespace_highlight_trailing: false,
         }
     }
 }
@@ -2268,6 +2915,32 @@ pub struct ClipboardConfig {
     /// Disable this if you don't have a display server or it causes issues
     #[serde(default = "default_true")]
     pub use_system_clipboard: bool,
+
+    /// Text placed between the file path and the line number by
+    /// "Copy Path:Line" (default: ":")
+    /// For example "#L" produces the line anchors code hosts link to
+    #[serde(default = "default_path_line_separator")]
+    pub path_line_separator: String,
+
+    /// Text placed between paths when several are copied at once, as from a
+    /// multi-selection in the file explorer (default: a newline)
+    /// Set it to " " to paste the paths as arguments on a shell command line
+    #[serde(default = "default_path_list_separator")]
+    pub path_list_separator: String,
+
+    /// Keep the clipboard history across sessions (default: false)
+    /// Copies are saved unencrypted in the data directory, so anything
+    /// copied — passwords included — ends up on disk
+    #[serde(default)]
+    pub persist_history: bool,
+}
+
+fn default_path_line_separator() -> String {
+    ":".to_string()
+}
+
+fn default_path_list_separator() -> String {
+    "\n".to_string()
 }
 
 impl Default for ClipboardConfig {
@@ -2275,6 +2948,9 @@ impl Default for ClipboardConfig {
         Self {
             use_osc52: true,
             use_system_clipboard: true,
+            path_line_separator: default_path_line_separator(),
+            path_list_separator: default_path_list_separator(),
+            persist_history: false,
         }
     }
 }
@@ -2346,6 +3022,17 @@ pub struct TerminalConfig {
     /// with no Shift bypass.
     #[serde(default)]
     pub mouse_forwarding: TerminalMouseForwarding,
+
+    /// Ask for confirmation, showing the line count and the start of the
+    /// text, before pasting more than this many lines into a live
+    /// terminal. Pastes are still sent bracketed (`ESC[200~`) to programs
+    /// that enable bracketed paste. Set to `0` to never ask (default: 10)
+    #[serde(default = "default_paste_confirm_lines")]
+    pub paste_confirm_lines: usize,
+}
+
+fn default_paste_confirm_lines() -> usize {
+    10
 }
 
 /// Mouse-forwarding policy for programs running inside the terminal
@@ -2370,6 +3057,7 @@ impl Default for TerminalConfig {
             resume_agents: true,
             mouse_drag_selects: true,
             mouse_forwarding: TerminalMouseForwarding::default(),
+            paste_confirm_lines: default_paste_confirm_lines(),
         }
     }
 }
@@ -2424,6 +3112,238 @@ impl Default for PackagesConfig {
     }
 }
 
+/// JSON/YAML schema validation settings
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct JsonSchemaConfig {
+    /// Validate JSON and YAML buffers against their schema in the background
+    /// and report violations as diagnostics (default: true).
+    /// The schema comes from the document's `$schema` key, then from
+    /// `associations`, then from a built-in catalog of well-known files
+    /// (package.json, tsconfig.json, GitHub workflows, docker-compose, ...).
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Download schemas referenced by an http(s) URL (default: true).
+    /// Downloaded schemas are cached in the data directory. When off, only
+    /// local schema files are used. A URL named by the document itself is
+    /// only downloaded in a trusted workspace.
+    #[serde(default = "default_true")]
+    pub download: bool,
+
+    /// File pattern → schema associations, checked before the built-in
+    /// catalog. `schema` may be a URL, an absolute path, or a path relative
+    /// to the working directory.
+    #[serde(default)]
+    pub associations: Vec<SchemaAssociation>,
+}
+
+impl Default for JsonSchemaConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            download: true,
+            associations: Vec::new(),
+        }
+    }
+}
+
+/// Maps files to a JSON schema
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[schemars(extend("x-display-field" = "/schema"))]
+pub struct SchemaAssociation {
+    /// Glob patterns selecting the files (e.g. "*.tasks.json",
+    /// "deploy/**/*.yaml"). Patterns without a `/` match the file name.
+    pub file_match: Vec<String>,
+
+    /// Schema location: URL, absolute path, or path relative to the
+    /// working directory.
+    pub schema: String,
+}
+
+/// A command-line linter run on save (and optionally while idle), whose
+/// output is parsed by `pattern` and reported as diagnostics.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[schemars(extend("x-display-field" = "/command"))]
+pub struct LinterConfig {
+    /// Whether this linter runs at all (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Languages the linter applies to (e.g. ["bash"]). Ignored for
+    /// `workspace` linters, which run after saving a file of any of these
+    /// languages.
+    #[serde(default)]
+    pub languages: Vec<String>,
+
+    /// The linter command to run (e.g. "shellcheck")
+    pub command: String,
+
+    /// Arguments to pass to the linter
+    /// Use "$FILE" to include the file path
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    /// Pass the buffer content via stdin instead of linting the file on
+    /// disk (default: false). Required for `run_on_idle`.
+    #[serde(default)]
+    pub stdin: bool,
+
+    /// Problem matcher: a regular expression applied to every line of the
+    /// linter's output (stdout and stderr). Named groups: `line` (required),
+    /// `column`, `end_line`, `end_column`, `severity`, `code`, `message`,
+    /// and `file` (used by `workspace` linters to place results).
+    /// Lines and columns are 1-based.
+    pub pattern: String,
+
+    /// Severity for matches without a recognisable `severity` group
+    #[serde(default)]
+    pub default_severity: LintSeverity,
+
+    /// Also lint while typing, after edits pause (default: false).
+    /// Only takes effect together with `stdin`.
+    #[serde(default)]
+    pub run_on_idle: bool,
+
+    /// The linter checks the whole project rather than one file (e.g.
+    /// `cargo clippy`). Its results replace all of its previous results,
+    /// across every file it reports on.
+    #[serde(default)]
+    pub workspace: bool,
+
+    /// Skip the linter while a language server is running for the
+    /// buffer's language (default: true), so the two don't report the
+    /// same problems twice.
+    #[serde(default = "default_true")]
+    pub only_without_lsp: bool,
+
+    /// Timeout in milliseconds (default: 10000)
+    #[serde(default = "default_on_save_timeout")]
+    pub timeout_ms: u64,
+}
+
+impl Default for LinterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            languages: Vec::new(),
+            command: String::new(),
+            args: Vec::new(),
+            stdin: false,
+            pattern: String::new(),
+            default_severity: LintSeverity::default(),
+            run_on_idle: false,
+            workspace: false,
+            only_without_lsp: true,
+            timeout_ms: default_on_save_timeout(),
+        }
+    }
+}
+
+/// A command or editor action run when an editor event fires for a
+/// matching file. Set `command`, `action`, or both (the action runs first).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[schemars(extend("x-display-field" = "/event"))]
+pub struct EventHookConfig {
+    /// Whether this hook runs at all (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// The event that runs the hook
+    #[serde(default)]
+    pub event: HookEvent,
+
+    /// Glob patterns selecting the files (e.g. "schema.graphql",
+    /// "src/**/*.rs"). Patterns without a `/` match the file name.
+    /// Empty matches every file.
+    #[serde(default)]
+    pub file_match: Vec<String>,
+
+    /// Command to run as a job, in the working directory
+    #[serde(default)]
+    pub command: String,
+
+    /// Arguments to pass to the command
+    /// Use "$FILE" or "%file" for the file path, "$LINE" or "%line" for the cursor line
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    /// Name shown in the jobs indicator (defaults to the command line)
+    #[serde(default)]
+    pub title: Option<String>,
+
+    /// Editor action to run in the active buffer (e.g. "format_buffer")
+    #[serde(default)]
+    pub action: Option<String>,
+}
+
+impl Default for EventHookConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            event: HookEvent::default(),
+            file_match: Vec::new(),
+            command: String::new(),
+            args: Vec::new(),
+            title: None,
+            action: None,
+        }
+    }
+}
+
+/// Editor event a hook runs on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEvent {
+    /// A file was opened
+    Open,
+    /// A file was saved
+    #[default]
+    Save,
+    /// A different buffer became active
+    BufferSwitch,
+    /// Edits to the buffer paused for a second
+    Idle,
+    /// The file's diagnostics from a language server or linter changed
+    Diagnostics,
+}
+
+/// One thing Tab can do, tried in the order listed in `editor.smart_tab`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SmartTabBehavior {
+    /// Accept the selected item when the completion popup is open
+    AcceptCompletion,
+    /// Expand a user snippet whose prefix is the word before the cursor
+    ExpandSnippet,
+    /// Move to the next tab stop of the snippet being filled in
+    NextTabStop,
+    /// Indent the selected lines when there is a selection
+    IndentSelection,
+    /// Insert indentation at the cursor
+    InsertIndent,
+}
+
+fn default_smart_tab() -> Vec<SmartTabBehavior> {
+    vec![
+        SmartTabBehavior::AcceptCompletion,
+        SmartTabBehavior::ExpandSnippet,
+        SmartTabBehavior::NextTabStop,
+        SmartTabBehavior::IndentSelection,
+        SmartTabBehavior::InsertIndent,
+    ]
+}
+
+/// Diagnostic severity for linter results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    Error,
+    #[default]
+    Warning,
+    Info,
+    Hint,
+}
+
 // Re-export PluginConfig from fresh-core for shared type usage
 pub use fresh_core::config::PluginConfig;
 
@@ -2488,9 +3408,16 @@ pub struct Keybinding {
     #[serde(default)]
     pub args: HashMap<String, serde_json::Value>,
 
-    /// Optional condition (e.g., "mode == insert")
+    /// Optional condition: a context (e.g., "prompt", "mode:vi-normal"),
+    /// `language:<id>` / `filetype:<ext>`, combined with `&&`, `||`, `!`
     #[serde(default)]
     pub when: Option<String>,
+
+    /// Fire when the key is released instead of pressed. Needs a terminal
+    /// with the kitty keyboard protocol; release events are requested
+    /// automatically when any binding sets this.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub release: bool,
 }
 
 /// Keymap configuration (for built-in and user-defined keymaps)
@@ -2567,6 +3494,10 @@ fn default_page_width() -> Option<usize> {
     Some(80)
 }
 
+fn default_zen_mode_width() -> usize {
+    80
+}
+
 /// Language-specific configuration
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[schemars(extend("x-display-field" = "/grammar"))]
@@ -2587,6 +3518,15 @@ pub struct LanguageConfig {
     #[serde(default)]
     pub comment_prefix: Option<String>,
 
+    /// Block comment start marker (e.g., "/*"). Toggle Comment falls back to
+    /// block comments when the language has no line comment prefix.
+    #[serde(default)]
+    pub block_comment_start: Option<String>,
+
+    /// Block comment end marker (e.g., "*/")
+    #[serde(default)]
+    pub block_comment_end: Option<String>,
+
     /// Whether to auto-indent
     #[serde(default = "default_true")]
     pub auto_indent: bool,
@@ -2692,6 +3632,19 @@ pub struct LanguageConfig {
     /// them back for plain text.
     #[serde(default)]
     pub indentation_guide: Option<bool>,
+
+    /// Whether to remove trailing whitespace when saving files of this language.
+    /// If not specified (`null`), falls back to the global
+    /// `editor.trim_trailing_whitespace_on_save` setting. Useful for Markdown,
+    /// where two trailing spaces are a hard line break.
+    #[serde(default)]
+    pub trim_trailing_whitespace_on_save: Option<bool>,
+
+    /// Abbreviations for this language, on top of the global
+    /// `editor.abbreviations` table. An entry here wins over a global one with the
+    /// same key.
+    #[serde(default)]
+    pub abbreviations: HashMap<String, String>,
 }
 
 /// User-overridable auto-indentation rules for a language.
@@ -2833,6 +3786,9 @@ pub struct BufferConfig {
     /// guides are a source-code aid, and undetected/plain-text files rarely
     /// have meaningful indentation nesting.
     pub indentation_guide: bool,
+
+    /// Whether to remove trailing whitespace on save
+    pub trim_trailing_whitespace_on_save: bool,
 }
 
 impl BufferConfig {
@@ -2865,6 +3821,7 @@ impl BufferConfig {
             textmate_grammar: None,
             word_characters: String::new(),
             indentation_guide: language_id.is_none_or(|id| id != "text"),
+            trim_trailing_whitespace_on_save: editor.trim_trailing_whitespace_on_save,
         };
 
         // Apply language-specific overrides if available.
@@ -2945,6 +3902,11 @@ impl BufferConfig {
             if let Some(ref wc) = lang_config.word_characters {
                 config.word_characters = wc.clone();
             }
+
+            // Trim trailing whitespace on save: language override (only if explicitly set)
+            if let Some(trim) = lang_config.trim_trailing_whitespace_on_save {
+                config.trim_trailing_whitespace_on_save = trim;
+            }
         }
 
         config
@@ -3065,7 +4027,9 @@ impl Default for Config {
             theme: default_theme_name(),
             locale: LocaleName::default(),
             check_for_updates: true,
+            telemetry: None,
             self_update: true,
+            offline: false,
             editor: EditorConfig::default(),
             file_explorer: FileExplorerConfig::default(),
             file_browser: FileBrowserConfig::default(),
@@ -3077,12 +4041,16 @@ impl Default for Config {
             languages: Self::default_languages(),
             default_language: None,
             lsp_enabled: true,
+            lsp_shared_servers: false,
             lsp: Self::default_lsp_config(),
             universal_lsp: Self::default_universal_lsp_config(),
             warnings: WarningsConfig::default(),
             plugins: HashMap::new(),
             packages: PackagesConfig::default(),
             env: EnvConfig::default(),
+            json_schemas: JsonSchemaConfig::default(),
+            linters: Self::default_linters(),
+            hooks: Vec::new(),
         }
     }
 }
@@ -3158,6 +4126,13 @@ impl MenuConfig {
                         when: Some(context_keys::HAS_BUFFER.to_string()),
                         checkbox: None,
                     },
+                    MenuItem::Action {
+                        label: t!("menu.file.save_with_encoding").to_string(),
+                        action: "save_with_encoding".to_string(),
+                        args: HashMap::new(),
+                        when: Some(context_keys::HAS_BUFFER.to_string()),
+                        checkbox: None,
+                    },
                     MenuItem::Separator { separator: true },
                     MenuItem::Action {
                         label: t!("menu.file.close_buffer").to_string(),
@@ -3592,6 +4567,20 @@ impl MenuConfig {
                         when: None,
                         checkbox: None,
                     },
+                    MenuItem::Action {
+                        label: t!("menu.selection.expand_selection_to_node").to_string(),
+                        action: "expand_selection_to_node".to_string(),
+                        args: HashMap::new(),
+                        when: None,
+                        checkbox: None,
+                    },
+                    MenuItem::Action {
+                        label: t!("menu.selection.shrink_selection").to_string(),
+                        action: "shrink_selection".to_string(),
+                        args: HashMap::new(),
+                        when: None,
+                        checkbox: None,
+                    },
                     MenuItem::Separator { separator: true },
                     MenuItem::Action {
                         label: t!("menu.selection.add_cursor_above").to_string(),
@@ -4079,6 +5068,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "rust".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4100,6 +5091,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4111,6 +5104,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "javascript".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4132,6 +5127,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4143,6 +5140,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "typescript".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4164,6 +5163,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4175,6 +5176,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "python".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4200,6 +5203,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4211,6 +5216,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "gdscript".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4227,6 +5234,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4238,6 +5247,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "c".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4259,6 +5270,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4277,6 +5290,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "cpp".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4298,6 +5313,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4309,6 +5326,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "C#".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4325,6 +5344,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4351,6 +5372,8 @@ impl Config {
                 ],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4367,6 +5390,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4378,6 +5403,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "fish".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4394,6 +5421,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4409,6 +5438,8 @@ impl Config {
                 ],
                 grammar: "Makefile".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -4425,6 +5456,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4436,6 +5469,8 @@ impl Config {
                 filenames: vec!["Dockerfile".to_string(), "Containerfile".to_string()],
                 grammar: "dockerfile".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4452,6 +5487,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4463,6 +5500,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "json".to_string(),
                 comment_prefix: None,
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4484,6 +5523,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4520,6 +5561,8 @@ impl Config {
                 ],
                 grammar: "jsonc".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4541,6 +5584,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4552,6 +5597,8 @@ impl Config {
                 filenames: vec!["Cargo.lock".to_string()],
                 grammar: "toml".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4568,6 +5615,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4579,6 +5628,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "yaml".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4600,6 +5651,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4611,6 +5664,8 @@ impl Config {
                 filenames: vec!["README".to_string()],
                 grammar: "markdown".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("<!--".to_string()),
+                block_comment_end: Some("-->".to_string()),
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -4627,6 +5682,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4639,6 +5696,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "go".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4660,6 +5719,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4671,6 +5732,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "odin".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4687,6 +5750,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4698,6 +5763,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "zig".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4714,6 +5781,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4725,6 +5794,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "c3".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4741,6 +5812,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4752,6 +5825,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "slang".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4768,6 +5843,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4793,6 +5870,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "glsl".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4809,6 +5888,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4825,6 +5906,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "hlsl".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4841,6 +5924,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4852,6 +5937,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "wgsl".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4868,6 +5955,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4879,6 +5968,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Metal".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4895,6 +5986,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4906,6 +5999,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "CUDA".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4922,6 +6017,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4933,6 +6030,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "HIP".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4949,6 +6048,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4969,6 +6070,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Fortran".to_string(),
                 comment_prefix: Some("!".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -4985,6 +6088,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -4996,6 +6101,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "MLIR".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5012,6 +6119,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5023,6 +6132,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "LLVM IR".to_string(),
                 comment_prefix: Some(";".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5039,6 +6150,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5050,6 +6163,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "java".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5066,6 +6181,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5084,6 +6201,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "latex".to_string(),
                 comment_prefix: Some("%".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5100,6 +6219,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5111,6 +6232,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "go".to_string(), // Templ uses Go-like syntax
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5127,6 +6250,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5138,6 +6263,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Smali".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5154,6 +6281,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5165,6 +6294,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Gettext PO".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -5181,6 +6312,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5192,6 +6325,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "M4".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5208,6 +6343,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5219,6 +6356,8 @@ impl Config {
                 filenames: vec!["project.pbxproj".to_string()],
                 grammar: "Xcode Project".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5235,6 +6374,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5247,6 +6388,8 @@ impl Config {
                 filenames: vec!["git-rebase-todo".to_string()],
                 grammar: "Git Rebase Todo".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -5263,6 +6406,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5279,6 +6424,8 @@ impl Config {
                 ],
                 grammar: "Git Commit Message".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -5295,6 +6442,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5311,6 +6460,8 @@ impl Config {
                 ],
                 grammar: "Gitignore".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -5327,6 +6478,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5338,6 +6491,8 @@ impl Config {
                 filenames: vec![".gitconfig".to_string(), ".gitmodules".to_string()],
                 grammar: "Git Config".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5354,6 +6509,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5365,6 +6522,8 @@ impl Config {
                 filenames: vec![".gitattributes".to_string()],
                 grammar: "Git Attributes".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -5381,6 +6540,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5392,6 +6553,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Typst".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5408,6 +6571,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5423,6 +6588,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Kotlin".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5439,6 +6606,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5450,6 +6619,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Swift".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5466,6 +6637,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5477,6 +6650,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Scala".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5493,6 +6668,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5504,6 +6681,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Dart".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5520,6 +6699,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5531,6 +6712,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Elixir".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5547,6 +6730,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5558,6 +6743,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Erlang".to_string(),
                 comment_prefix: Some("%".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5574,6 +6761,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5585,6 +6774,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Haskell".to_string(),
                 comment_prefix: Some("--".to_string()),
+                block_comment_start: Some("{-".to_string()),
+                block_comment_end: Some("-}".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5601,6 +6792,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5612,6 +6805,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "OCaml".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("(*".to_string()),
+                block_comment_end: Some("*)".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5628,6 +6823,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5644,6 +6841,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Clojure".to_string(),
                 comment_prefix: Some(";".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5660,6 +6859,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5671,6 +6872,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "R".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5687,6 +6890,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5698,6 +6903,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Julia".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: Some("#=".to_string()),
+                block_comment_end: Some("=#".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5714,6 +6921,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5725,6 +6934,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Perl".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5741,6 +6952,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5752,6 +6965,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Nim".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: Some("#[".to_string()),
+                block_comment_end: Some("]#".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5768,6 +6983,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5779,6 +6996,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Gleam".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5795,6 +7014,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5811,6 +7032,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Racket".to_string(),
                 comment_prefix: Some(";".to_string()),
+                block_comment_start: Some("#|".to_string()),
+                block_comment_end: Some("|#".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5827,6 +7050,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5838,6 +7063,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "FSharp".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("(*".to_string()),
+                block_comment_end: Some("*)".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5854,6 +7081,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5865,6 +7094,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Nix".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5881,6 +7112,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5892,6 +7125,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Nushell".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5908,6 +7143,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5919,6 +7156,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Solidity".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5935,6 +7174,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5946,6 +7187,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Verilog".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5962,6 +7205,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -5978,6 +7223,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "SystemVerilog".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -5994,6 +7241,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6005,6 +7254,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "VHDL".to_string(),
                 comment_prefix: Some("--".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6021,6 +7272,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6035,6 +7288,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Assembly".to_string(),
                 comment_prefix: Some(";".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6051,6 +7306,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6064,6 +7321,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Assembly".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6080,6 +7339,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6095,6 +7356,8 @@ impl Config {
                 ],
                 grammar: "Ruby".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6111,6 +7374,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6122,6 +7387,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "PHP".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6138,6 +7405,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6149,6 +7418,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Lua".to_string(),
                 comment_prefix: Some("--".to_string()),
+                block_comment_start: Some("--[[".to_string()),
+                block_comment_end: Some("]]".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6165,6 +7436,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6176,6 +7449,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "HTML".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("<!--".to_string()),
+                block_comment_end: Some("-->".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6192,6 +7467,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6203,6 +7480,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "CSS".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6219,6 +7498,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6230,6 +7511,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "SQL".to_string(),
                 comment_prefix: Some("--".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6246,6 +7529,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6257,6 +7542,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "GraphQL".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6273,6 +7560,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6284,6 +7573,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Protocol Buffers".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6300,6 +7591,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6311,6 +7604,8 @@ impl Config {
                 filenames: vec!["CMakeLists.txt".to_string()],
                 grammar: "CMake".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: Some("#[[".to_string()),
+                block_comment_end: Some("]]".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6327,6 +7622,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6338,6 +7635,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "HCL".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6354,6 +7653,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6365,6 +7666,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Vue".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("<!--".to_string()),
+                block_comment_end: Some("-->".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6381,6 +7684,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6392,6 +7697,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Svelte".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("<!--".to_string()),
+                block_comment_end: Some("-->".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6408,6 +7715,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6419,6 +7728,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "Astro".to_string(),
                 comment_prefix: None,
+                block_comment_start: Some("<!--".to_string()),
+                block_comment_end: Some("-->".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6435,6 +7746,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6448,6 +7761,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "SCSS".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6464,6 +7779,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6475,6 +7792,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "LESS".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6491,6 +7810,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6502,6 +7823,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "PowerShell".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: Some("<#".to_string()),
+                block_comment_end: Some("#>".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6518,6 +7841,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6529,6 +7854,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "KDL".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6545,6 +7872,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6563,6 +7892,8 @@ impl Config {
                 ],
                 grammar: "Starlark".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6579,6 +7910,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6594,6 +7927,8 @@ impl Config {
                 ],
                 grammar: "Justfile".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6610,6 +7945,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6621,6 +7958,8 @@ impl Config {
                 filenames: vec!["Earthfile".to_string()],
                 grammar: "Earthfile".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6637,6 +7976,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6648,6 +7989,8 @@ impl Config {
                 filenames: vec!["go.mod".to_string(), "go.sum".to_string()],
                 grammar: "Go Module".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6664,6 +8007,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6675,6 +8020,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "V".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: Some("/*".to_string()),
+                block_comment_end: Some("*/".to_string()),
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6691,6 +8038,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6702,6 +8051,8 @@ impl Config {
                 filenames: vec![],
                 grammar: "INI".to_string(),
                 comment_prefix: Some(";".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: false,
                 auto_close: None,
                 auto_surround: None,
@@ -6718,6 +8069,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6729,6 +8082,8 @@ impl Config {
                 filenames: vec!["hyprland.conf".to_string()],
                 grammar: "Hyprlang".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -6745,6 +8100,8 @@ impl Config {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -6819,6 +8176,81 @@ impl Config {
         universal
     }
 
+    /// Create the built-in linter presets. Each only runs when its command
+    /// is on `$PATH`; clippy is off by default since it builds the project.
+    fn default_linters() -> HashMap<String, LinterConfig> {
+        let linter =
+            |languages: &[&str], command: &str, args: &[&str], pattern: &str| LinterConfig {
+                enabled: true,
+                languages: languages.iter().map(|l| l.to_string()).collect(),
+                command: command.to_string(),
+                args: args.iter().map(|a| a.to_string()).collect(),
+                stdin: true,
+                pattern: pattern.to_string(),
+                default_severity: LintSeverity::Warning,
+                run_on_idle: true,
+                workspace: false,
+                only_without_lsp: true,
+                timeout_ms: default_on_save_timeout(),
+            };
+
+        let mut linters = HashMap::new();
+        // shellcheck has no language server of its own, but bash-language-server
+        // runs it internally — hence only_without_lsp.
+        linters.insert(
+            "shellcheck".to_string(),
+            linter(
+                &["bash"],
+                "shellcheck",
+                &["--format=gcc", "-"],
+                r"^[^:]+:(?P<line>\d+):(?P<column>\d+): (?P<severity>\w+): (?P<message>.*?)(?: \[(?P<code>SC\d+)\])?$",
+            ),
+        );
+        linters.insert(
+            "ruff".to_string(),
+            linter(
+                &["python"],
+                "ruff",
+                &[
+                    "check",
+                    "--output-format=concise",
+                    "--quiet",
+                    "--stdin-filename",
+                    "$FILE",
+                    "-",
+                ],
+                r"^.+?:(?P<line>\d+):(?P<column>\d+): (?P<code>[A-Za-z]+\d*):? (?:\[\*\] )?(?P<message>.*)$",
+            ),
+        );
+        // The stylish formatter is the one every eslint version ships.
+        linters.insert(
+            "eslint".to_string(),
+            linter(
+                &["javascript", "typescript"],
+                "eslint",
+                &["--format=stylish", "--stdin", "--stdin-filename", "$FILE"],
+                r"^\s+(?P<line>\d+):(?P<column>\d+)\s+(?P<severity>error|warning)\s+(?P<message>.+?)(?:\s{2,}(?P<code>[\w@/-]+))?$",
+            ),
+        );
+        linters.insert(
+            "clippy".to_string(),
+            LinterConfig {
+                enabled: false,
+                stdin: false,
+                run_on_idle: false,
+                workspace: true,
+                timeout_ms: 120_000,
+                ..linter(
+                    &["rust"],
+                    "cargo",
+                    &["clippy", "--quiet", "--message-format=short"],
+                    r"^(?P<file>[^\s:][^:]*):(?P<line>\d+):(?P<column>\d+): (?P<severity>error|warning)(?:\[(?P<code>[^\]]+)\])?: (?P<message>.*)$",
+                )
+            },
+        );
+        linters
+    }
+
     /// Create empty universal LSP configurations for WASM builds
     #[cfg(not(feature = "runtime"))]
     fn default_universal_lsp_config() -> HashMap<String, LspLanguageConfig> {
@@ -8087,6 +9519,20 @@ impl Config {
             ));
         }
 
+        // Validate line number column width
+        if self.editor.line_number_min_width > 20 {
+            return Err(ConfigError::ValidationError(
+                "line_number_min_width must be <= 20".to_string(),
+            ));
+        }
+
+        // Validate mouse wheel step
+        if self.editor.mouse_wheel_scroll_lines == 0 || self.editor.mouse_wheel_scroll_lines > 100 {
+            return Err(ConfigError::ValidationError(
+                "mouse_wheel_scroll_lines must be between 1 and 100".to_string(),
+            ));
+        }
+
         // Validate keybindings
         for binding in &self.keybindings {
             if binding.key.is_empty() {
@@ -8106,7 +9552,7 @@ impl Config {
 }
 
 /// Configuration error types
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ConfigError {
     IoError(String),
     ParseError(String),
@@ -8148,10 +9594,51 @@ pub(crate) fn parse_config_jsonc(contents: &str) -> Result<serde_json::Value, Co
     })
 }
 
+/// The JSONC syntax error in `contents`, if any, as the parser's message
+/// and its 1-based line and column.
+pub(crate) fn config_syntax_error(contents: &str) -> Option<(String, usize, usize)> {
+    let e = jsonc_parser::parse_to_serde_value::<serde_json::Value>(contents, &Default::default())
+        .err()?;
+    Some((e.kind().to_string(), e.line_display(), e.column_display()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cursor_style_for_context_falls_back_and_honors_blink() {
+        let mut config = EditorConfig {
+            cursor_style: CursorStyle::BlinkingBar,
+            cursor_style_selection: Some(CursorStyle::BlinkingBlock),
+            ..EditorConfig::default()
+        };
+        assert_eq!(
+            config.cursor_style_for(CursorContext::Editing),
+            CursorStyle::BlinkingBar
+        );
+        assert_eq!(
+            config.cursor_style_for(CursorContext::Selection),
+            CursorStyle::BlinkingBlock
+        );
+        // No normal-mode style: falls back to `cursor_style`.
+        assert_eq!(
+            config.cursor_style_for(CursorContext::NormalMode),
+            CursorStyle::BlinkingBar
+        );
+
+        config.cursor_blink = false;
+        assert_eq!(
+            config.cursor_style_for(CursorContext::Selection),
+            CursorStyle::SteadyBlock
+        );
+        config.cursor_style = CursorStyle::Default;
+        assert_eq!(
+            config.cursor_style_for(CursorContext::Editing),
+            CursorStyle::Default
+        );
+    }
+
     #[test]
     fn test_file_explorer_width_default_is_percent_30() {
         let cfg = FileExplorerConfig::default();
@@ -8810,6 +10297,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "go".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -8831,6 +10320,8 @@ mod tests {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
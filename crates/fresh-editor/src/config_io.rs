@@ -422,6 +422,18 @@ impl ConfigLayer {
     }
 }
 
+/// A config layer file that failed to load. Carries enough to point the
+/// user at the problem: the file, the parser's message and, for syntax
+/// errors, the 1-based line and column.
+#[derive(Debug, Clone)]
+pub struct ConfigLoadFailure {
+    pub path: PathBuf,
+    pub error: ConfigError,
+    /// The error without the path prefix, for showing inside the file.
+    pub message: String,
+    pub position: Option<(usize, usize)>,
+}
+
 /// Manages loading and merging of all configuration layers.
 ///
 /// Resolution order: System → User → Project → Session
@@ -447,23 +459,34 @@ impl ConfigResolver {
     ///
     /// Each layer fills in values missing from higher precedence layers.
     pub fn resolve(&self) -> Result<Config, ConfigError> {
+        self.resolve_or_failure().map_err(|failure| failure.error)
+    }
+
+    /// Like [`resolve`](Self::resolve), but a failure names the layer file
+    /// that broke and, for syntax errors, where in it.
+    pub fn resolve_or_failure(&self) -> Result<Config, ConfigLoadFailure> {
         // Start with highest precedence layer (Session)
-        let mut merged = self.load_session_layer()?.unwrap_or_default();
+        // Both workspace layers live in the project's `.fresh/` directory.
+        let mut merged = Self::read_layer(&self.session_config_path())?.unwrap_or_default();
+        merged.clear_user_only_settings();
 
         // Merge in Project layer (fills missing values)
-        if let Some(project_partial) = self.load_project_layer()? {
+        if let Some(mut project_partial) = Self::read_layer(&self.project_config_path())? {
             tracing::debug!("Loaded project config layer");
+            project_partial.clear_user_only_settings();
             merged.merge_from(&project_partial);
         }
 
         // Merge in User Platform layer (e.g., config_linux.json)
-        if let Some(platform_partial) = self.load_user_platform_layer()? {
-            tracing::debug!("Loaded user platform config layer");
-            merged.merge_from(&platform_partial);
+        if let Some(path) = self.user_platform_config_path() {
+            if let Some(platform_partial) = Self::read_layer(&path)? {
+                tracing::debug!("Loaded user platform config layer");
+                merged.merge_from(&platform_partial);
+            }
         }
 
         // Merge in User layer (fills remaining missing values)
-        if let Some(user_partial) = self.load_user_layer()? {
+        if let Some(user_partial) = Self::read_layer(&self.user_config_path())? {
             tracing::debug!("Loaded user config layer");
             merged.merge_from(&user_partial);
         }
@@ -547,23 +570,59 @@ impl ConfigResolver {
 
     /// Load a layer from a specific path, applying migrations if needed.
     fn load_layer_from_path(&self, path: &Path) -> Result<Option<PartialConfig>, ConfigError> {
+        Self::read_layer(path).map_err(|failure| failure.error)
+    }
+
+    /// Read, migrate and deserialize one layer file; `Ok(None)` if it
+    /// doesn't exist.
+    fn read_layer(path: &Path) -> Result<Option<PartialConfig>, ConfigLoadFailure> {
         if !path.exists() {
             return Ok(None);
         }
+        let fail = |error: ConfigError, message: String, position: Option<(usize, usize)>| {
+            ConfigLoadFailure {
+                path: path.to_path_buf(),
+                error,
+                message,
+                position,
+            }
+        };
 
-        let content = std::fs::read_to_string(path)
-            .map_err(|e| ConfigError::IoError(format!("{}: {}", path.display(), e)))?;
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            fail(
+                ConfigError::IoError(format!("{}: {}", path.display(), e)),
+                e.to_string(),
+                None,
+            )
+        })?;
 
         // Parse as raw JSONC first (comments/trailing commas tolerated)
-        let value: Value = crate::config::parse_config_jsonc(&content)
-            .map_err(|e| ConfigError::ParseError(format!("{}: {}", path.display(), e)))?;
+        let value: Value = crate::config::parse_config_jsonc(&content).map_err(|e| {
+            let (message, position) = match crate::config::config_syntax_error(&content) {
+                Some((message, line, column)) => (message, Some((line, column))),
+                None => (e.to_string(), None),
+            };
+            fail(
+                ConfigError::ParseError(format!("{}: {}", path.display(), e)),
+                message,
+                position,
+            )
+        })?;
 
         // Apply migrations
-        let migrated = migrate_config(value)?;
+        let migrated = migrate_config(value).map_err(|e| {
+            let message = e.to_string();
+            fail(e, message, None)
+        })?;
 
         // Now deserialize to PartialConfig
-        let partial: PartialConfig = serde_json::from_value(migrated)
-            .map_err(|e| ConfigError::ParseError(format!("{}: {}", path.display(), e)))?;
+        let partial: PartialConfig = serde_json::from_value(migrated).map_err(|e| {
+            fail(
+                ConfigError::ParseError(format!("{}: {}", path.display(), e)),
+                e.to_string(),
+                None,
+            )
+        })?;
 
         Ok(Some(partial))
     }
@@ -840,57 +899,36 @@ fn diff_partial_config(current: &PartialConfig, parent: &PartialConfig) -> Parti
 }
 
 impl Config {
-    /// Get the system config file paths (without local/working directory).
-    ///
-    /// On macOS, prioritizes `~/.config/fresh/config.json` if it exists.
-    /// Then checks the standard system config directory.
-    fn system_config_paths() -> Vec<PathBuf> {
-        let mut paths = Vec::with_capacity(2);
-
-        // macOS: Prioritize ~/.config/fresh/config.json
-        #[cfg(target_os = "macos")]
-        if let Some(home) = dirs::home_dir() {
-            let path = home.join(".config").join("fresh").join(Config::FILENAME);
-            if path.exists() {
-                paths.push(path);
-            }
-        }
-
-        // Standard system paths (XDG on Linux, AppSupport on macOS, Roaming on Windows)
-        if let Some(config_dir) = dirs::config_dir() {
-            let path = config_dir.join("fresh").join(Config::FILENAME);
-            if !paths.contains(&path) && path.exists() {
-                paths.push(path);
-            }
-        }
-
-        paths
-    }
-
     /// Get all config search paths, checking local (working directory) first.
     ///
     /// Search order:
     /// 1. `{working_dir}/config.json` (project-local config)
-    /// 2. System config paths (see `system_config_paths()`)
+    /// 2. The user config in `dir_context` (the portable `config/` directory
+    ///    in portable mode)
     ///
     /// Only returns paths that exist on disk.
-    fn config_search_paths(working_dir: &Path) -> Vec<PathBuf> {
+    fn config_search_paths(dir_context: &DirectoryContext, working_dir: &Path) -> Vec<PathBuf> {
         let local = Self::local_config_path(working_dir);
-        let mut paths = Vec::with_capacity(3);
+        let mut paths = Vec::with_capacity(2);
 
         if local.exists() {
             paths.push(local);
         }
 
-        paths.extend(Self::system_config_paths());
+        let user = dir_context.config_path();
+        if user.exists() {
+            paths.push(user);
+        }
         paths
     }
 
     /// Find the first existing config file, checking local directory first.
     ///
     /// Returns `None` if no config file exists anywhere.
-    pub fn find_config_path(working_dir: &Path) -> Option<PathBuf> {
-        Self::config_search_paths(working_dir).into_iter().next()
+    pub fn find_config_path(dir_context: &DirectoryContext, working_dir: &Path) -> Option<PathBuf> {
+        Self::config_search_paths(dir_context, working_dir)
+            .into_iter()
+            .next()
     }
 
     /// Load configuration using the 4-level layer system.
@@ -898,15 +936,28 @@ impl Config {
     /// Merges layers in precedence order: Session > Project > User > System
     /// Falls back to defaults for any unspecified values.
     pub fn load_with_layers(dir_context: &DirectoryContext, working_dir: &Path) -> Self {
+        Self::load_with_layers_or_failure(dir_context, working_dir).0
+    }
+
+    /// [`load_with_layers`](Self::load_with_layers) that also hands back
+    /// why the defaults were used, so the editor can start in config rescue
+    /// mode instead of silently ignoring the user's settings.
+    pub fn load_with_layers_or_failure(
+        dir_context: &DirectoryContext,
+        working_dir: &Path,
+    ) -> (Self, Option<ConfigLoadFailure>) {
         let resolver = ConfigResolver::new(dir_context.clone(), working_dir.to_path_buf());
-        match resolver.resolve() {
+        match resolver.resolve_or_failure() {
             Ok(config) => {
                 tracing::info!("Loaded layered config for {}", working_dir.display());
-                config
+                (config, None)
             }
-            Err(e) => {
-                tracing::warn!("Failed to load layered config: {}, using defaults", e);
-                Self::default()
+            Err(failure) => {
+                tracing::warn!(
+                    "Failed to load layered config: {}, using defaults",
+                    failure.error
+                );
+                (Self::default(), Some(failure))
             }
         }
     }
@@ -917,9 +968,12 @@ impl Config {
     /// with defaults). Useful for plugins that need to distinguish between
     /// user-set values and defaults.
     ///
-    /// Checks working directory first, then system paths.
-    pub fn read_user_config_raw(working_dir: &Path) -> serde_json::Value {
-        for path in Self::config_search_paths(working_dir) {
+    /// Checks working directory first, then the user config directory.
+    pub fn read_user_config_raw(
+        dir_context: &DirectoryContext,
+        working_dir: &Path,
+    ) -> serde_json::Value {
+        for path in Self::config_search_paths(dir_context, working_dir) {
             if let Ok(contents) = std::fs::read_to_string(&path) {
                 match crate::config::parse_config_jsonc(&contents) {
                     Ok(value) => return value,
@@ -986,6 +1040,39 @@ fn is_empty_diff(value: &serde_json::Value) -> bool {
     }
 }
 
+/// Environment variable that turns on portable mode; `--portable <dir>`
+/// sets it so child processes (the daemon, plugins' tools) inherit it.
+pub const PORTABLE_HOME_ENV: &str = "FRESH_HOME";
+
+/// Root directory of portable mode, if it's on.
+///
+/// In portable mode every path the editor reads or writes for itself —
+/// config, data (sessions, recovery, history), logs and caches — lives
+/// under this directory instead of the platform's per-user locations, so
+/// the editor can run from a USB stick or a locked-down account without
+/// touching `$HOME`. Read once from [`PORTABLE_HOME_ENV`].
+pub fn portable_home() -> Option<&'static Path> {
+    static PORTABLE_HOME: std::sync::OnceLock<Option<PathBuf>> = std::sync::OnceLock::new();
+    PORTABLE_HOME
+        .get_or_init(|| {
+            let dir = PathBuf::from(std::env::var_os(PORTABLE_HOME_ENV)?);
+            if dir.as_os_str().is_empty() {
+                return None;
+            }
+            Some(std::path::absolute(&dir).unwrap_or(dir))
+        })
+        .as_deref()
+}
+
+/// The editor's cache directory: `<portable home>/cache` in portable mode,
+/// otherwise `fresh` under the platform cache directory.
+pub fn cache_dir() -> Option<PathBuf> {
+    match portable_home() {
+        Some(root) => Some(root.join("cache")),
+        None => dirs::cache_dir().map(|p| p.join("fresh")),
+    }
+}
+
 /// Directory paths for editor state and configuration
 ///
 /// This struct holds all directory paths that the editor needs.
@@ -1020,6 +1107,10 @@ impl DirectoryContext {
     /// Create a DirectoryContext from the system directories
     /// This should ONLY be called from main()
     pub fn from_system() -> std::io::Result<Self> {
+        if let Some(root) = portable_home() {
+            return Ok(Self::for_portable(root));
+        }
+
         let data_dir = dirs::data_dir()
             .ok_or_else(|| {
                 std::io::Error::new(
@@ -1045,6 +1136,20 @@ impl DirectoryContext {
         })
     }
 
+    /// Create a DirectoryContext for portable mode: config and data live in
+    /// `config/` and `data/` under `root`. The home, documents and downloads
+    /// entries are only file-dialog shortcuts, so they still point at the
+    /// user's folders.
+    pub fn for_portable(root: &std::path::Path) -> Self {
+        Self {
+            data_dir: root.join("data"),
+            config_dir: root.join("config"),
+            home_dir: dirs::home_dir(),
+            documents_dir: dirs::document_dir(),
+            downloads_dir: dirs::download_dir(),
+        }
+    }
+
     /// Create a DirectoryContext for testing with a temp directory
     /// All paths point to subdirectories within the provided temp_dir
     pub fn for_testing(temp_dir: &std::path::Path) -> Self {
@@ -1114,6 +1219,29 @@ impl DirectoryContext {
         self.prompt_history_path("goto_line")
     }
 
+    /// Get the clipboard history file, used when `clipboard.persist_history`
+    /// is enabled
+    pub fn clipboard_history_path(&self) -> std::path::PathBuf {
+        self.data_dir.join("clipboard_history.json")
+    }
+
+    /// Get the local history root, used when `editor.local_history_enabled`
+    /// is on
+    pub fn local_history_dir(&self) -> std::path::PathBuf {
+        self.data_dir.join("local_history")
+    }
+
+    /// Get the local history directory holding the saved versions of `file`
+    pub fn local_history_dir_for(&self, file: &std::path::Path) -> std::path::PathBuf {
+        let encoded = crate::workspace::encode_path_for_filename(file);
+        self.local_history_dir().join(encoded)
+    }
+
+    /// Get the cache directory for downloaded JSON schemas
+    pub fn schemas_dir(&self) -> std::path::PathBuf {
+        self.data_dir.join("schemas")
+    }
+
     /// Get the terminals root directory
     pub fn terminals_dir(&self) -> std::path::PathBuf {
         self.data_dir.join("terminals")
@@ -1150,6 +1278,11 @@ impl DirectoryContext {
         self.config_dir.join("grammars")
     }
 
+    /// Get the user snippets directory path
+    pub fn snippets_dir(&self) -> std::path::PathBuf {
+        self.config_dir.join("snippets")
+    }
+
     /// Get the plugins directory path
     pub fn plugins_dir(&self) -> std::path::PathBuf {
         self.config_dir.join("plugins")
@@ -1245,6 +1378,31 @@ mod tests {
         drop(temp);
     }
 
+    #[test]
+    fn resolver_reports_broken_layer_with_position() {
+        let (temp, resolver) = create_test_resolver();
+
+        let user_config_path = resolver.user_config_path();
+        std::fs::create_dir_all(user_config_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &user_config_path,
+            "{\n  \"editor\": {\n    \"tab_size\": 2,\n    \"line_numbers\": fals\n  }\n}\n",
+        )
+        .unwrap();
+
+        let failure = resolver.resolve_or_failure().unwrap_err();
+        assert_eq!(failure.path, user_config_path);
+        assert_eq!(failure.position.map(|(line, _)| line), Some(4));
+        assert!(!failure
+            .message
+            .contains(&*user_config_path.to_string_lossy()));
+        assert!(matches!(
+            resolver.resolve(),
+            Err(ConfigError::ParseError(_))
+        ));
+        drop(temp);
+    }
+
     #[test]
     fn resolver_loads_project_layer_with_comments() {
         // The project `.fresh/config.json` layer must also tolerate comments.
@@ -1732,6 +1890,75 @@ mod tests {
         drop(temp);
     }
 
+    #[test]
+    fn resolver_ignores_elevation_command_from_workspace_layers() {
+        let (temp, resolver) = create_test_resolver();
+
+        let user_config_path = resolver.user_config_path();
+        std::fs::create_dir_all(user_config_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &user_config_path,
+            r#"{"editor": {"elevation_command": "doas"}}"#,
+        )
+        .unwrap();
+
+        // A project must not choose the program run with elevated rights
+        let project_config_path = resolver.project_config_path();
+        std::fs::create_dir_all(project_config_path.parent().unwrap()).unwrap();
+        std::fs::write(
+            &project_config_path,
+            r#"{"editor": {"elevation_command": "./evil", "tab_size": 8}}"#,
+        )
+        .unwrap();
+        std::fs::write(
+            resolver.session_config_path(),
+            r#"{"editor": {"elevation_command": "./evil"}}"#,
+        )
+        .unwrap();
+
+        let config = resolver.resolve().unwrap();
+        assert_eq!(config.editor.elevation_command, "doas");
+        assert_eq!(config.editor.tab_size, 8); // Other project settings apply
+        drop(temp);
+    }
+
+    #[test]
+    fn read_user_config_raw_uses_directory_context() {
+        let temp = TempDir::new().unwrap();
+        let dir_context = DirectoryContext::for_portable(temp.path());
+        let working_dir = temp.path().join("project");
+        std::fs::create_dir_all(&working_dir).unwrap();
+        std::fs::create_dir_all(&dir_context.config_dir).unwrap();
+        std::fs::write(dir_context.config_path(), r#"{"theme": "portable"}"#).unwrap();
+
+        let raw = Config::read_user_config_raw(&dir_context, &working_dir);
+        assert_eq!(raw["theme"], "portable");
+        assert_eq!(
+            Config::find_config_path(&dir_context, &working_dir),
+            Some(dir_context.config_path())
+        );
+    }
+
+    #[test]
+    fn resolver_ignores_telemetry_from_project_layer() {
+        let (temp, resolver) = create_test_resolver();
+
+        // Consent given in a repository's config is not the user's consent
+        let project_config_path = resolver.project_config_path();
+        std::fs::create_dir_all(project_config_path.parent().unwrap()).unwrap();
+        std::fs::write(&project_config_path, r#"{"telemetry": true}"#).unwrap();
+
+        let config = resolver.resolve().unwrap();
+        assert_eq!(config.telemetry, None);
+
+        let user_config_path = resolver.user_config_path();
+        std::fs::create_dir_all(user_config_path.parent().unwrap()).unwrap();
+        std::fs::write(&user_config_path, r#"{"telemetry": false}"#).unwrap();
+        let config = resolver.resolve().unwrap();
+        assert_eq!(config.telemetry, Some(false));
+        drop(temp);
+    }
+
     #[test]
     fn resolver_session_overrides_all() {
         let (temp, resolver) = create_test_resolver();
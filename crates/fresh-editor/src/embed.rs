@@ -0,0 +1,325 @@
+//! Embedding the editor in another ratatui application.
+//!
+//! [`EmbeddedEditor`] wraps an [`Editor`] so it behaves like a widget: the
+//! host owns the terminal and its event loop, and the editor draws into
+//! whatever area of the host's [`Frame`] it is given. Nothing here touches
+//! the process's terminal — no raw mode, alternate screen, mouse capture or
+//! keyboard enhancement flags; those stay with the host.
+//!
+//! ```no_run
+//! use fresh::embed::{EmbedOptions, EmbeddedEditor};
+//! use ratatui::layout::Rect;
+//!
+//! # fn draw(frame: &mut ratatui::Frame, event: crossterm::event::Event) -> anyhow::Result<()> {
+//! let mut editor = EmbeddedEditor::new(EmbedOptions::new())?;
+//! editor.open_file(std::path::Path::new("notes.md"))?;
+//!
+//! // In the host's event loop:
+//! editor.handle_event(event)?;
+//! editor.tick()?;
+//! let area = Rect::new(0, 1, 80, 20);
+//! editor.render(frame, area);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Each call to [`EmbeddedEditor::render`] lays the editor out for the size
+//! of `area`, so resizing is just rendering into a different area. Mouse
+//! events are taken in the host's coordinates and translated into the
+//! area's.
+
+use crate::app::Editor;
+use crate::config::Config;
+use crate::config_io::DirectoryContext;
+use crate::model::event::BufferId;
+use crate::model::filesystem::{FileSystem, StdFileSystem};
+use crate::view::color_support::ColorCapability;
+use anyhow::{Context, Result as AnyhowResult};
+use crossterm::event::Event;
+use ratatui::backend::{Backend, ClearType, WindowSize};
+use ratatui::buffer::Cell;
+use ratatui::layout::{Position, Rect, Size};
+use ratatui::{Frame, Terminal};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Size the editor is laid out for before its first render.
+const INITIAL_SIZE: Size = Size {
+    width: 80,
+    height: 24,
+};
+
+/// How to build an [`EmbeddedEditor`].
+///
+/// Defaults: the user's config and state directories, their config file
+/// layered over the defaults, the current directory as the working
+/// directory, plugins off and true color.
+#[derive(Default)]
+pub struct EmbedOptions {
+    config: Option<Config>,
+    working_dir: Option<PathBuf>,
+    dir_context: Option<DirectoryContext>,
+    plugins: bool,
+    color_capability: Option<ColorCapability>,
+}
+
+impl EmbedOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use `config` instead of loading the user's config file.
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Resolve relative paths, and run LSP servers and plugins, in `dir`.
+    pub fn with_working_dir(mut self, dir: PathBuf) -> Self {
+        self.working_dir = Some(dir);
+        self
+    }
+
+    /// Keep config, recovery files and sessions in `dir_context` instead of
+    /// the user's directories, e.g. to isolate the embedded editor from a
+    /// standalone `fresh`.
+    pub fn with_dir_context(mut self, dir_context: DirectoryContext) -> Self {
+        self.dir_context = Some(dir_context);
+        self
+    }
+
+    /// Run the plugin runtime and the user's `init.ts`.
+    pub fn with_plugins(mut self, enabled: bool) -> Self {
+        self.plugins = enabled;
+        self
+    }
+
+    /// Downsample colors for a terminal without true color support.
+    pub fn with_color_capability(mut self, capability: ColorCapability) -> Self {
+        self.color_capability = Some(capability);
+        self
+    }
+}
+
+/// An [`Editor`] that renders into an area of a host application's frame.
+pub struct EmbeddedEditor {
+    editor: Editor,
+    /// Off-screen terminal the editor draws a whole frame into, copied into
+    /// the host's frame by [`Self::render`].
+    terminal: Terminal<OffscreenBackend>,
+    /// Area of the host's frame the editor was last rendered into.
+    area: Rect,
+}
+
+impl EmbeddedEditor {
+    /// Build an editor from `options`.
+    pub fn new(options: EmbedOptions) -> AnyhowResult<Self> {
+        let dir_context = match options.dir_context {
+            Some(dir_context) => dir_context,
+            None => DirectoryContext::from_system()
+                .context("Failed to determine config and state directories")?,
+        };
+        let working_dir = match options.working_dir {
+            Some(dir) => dir,
+            None => std::env::current_dir().context("Failed to determine working directory")?,
+        };
+        let config = options
+            .config
+            .unwrap_or_else(|| Config::load_with_layers(&dir_context, &working_dir));
+        let filesystem: Arc<dyn FileSystem + Send + Sync> = Arc::new(StdFileSystem);
+
+        let mut editor = Editor::with_working_dir(
+            config,
+            INITIAL_SIZE.width,
+            INITIAL_SIZE.height,
+            Some(working_dir),
+            dir_context,
+            options.plugins,
+            options
+                .color_capability
+                .unwrap_or(ColorCapability::TrueColor),
+            filesystem,
+        )
+        .context("Failed to create editor instance")?;
+        if options.plugins {
+            // Same boot as `fresh`: the user's init.ts, then the
+            // plugins-loaded hook. Both complete on the plugin thread and
+            // are picked up by `tick`.
+            editor.load_init_script_async(true);
+            editor.fire_plugins_loaded_hook();
+        }
+        Ok(Self::from_editor(editor))
+    }
+
+    /// Wrap an already-built editor. It is resized to the area it is
+    /// rendered into.
+    pub fn from_editor(editor: Editor) -> Self {
+        let terminal = Terminal::new(OffscreenBackend::new(INITIAL_SIZE))
+            .expect("the off-screen backend cannot fail");
+        Self {
+            editor,
+            terminal,
+            area: Rect::ZERO,
+        }
+    }
+
+    /// The wrapped editor, for anything this type doesn't cover.
+    pub fn editor(&self) -> &Editor {
+        &self.editor
+    }
+
+    pub fn editor_mut(&mut self) -> &mut Editor {
+        &mut self.editor
+    }
+
+    /// Unwrap the editor.
+    pub fn into_editor(self) -> Editor {
+        self.editor
+    }
+
+    /// Area of the host's frame the editor was last rendered into.
+    pub fn area(&self) -> Rect {
+        self.area
+    }
+
+    /// Open `path` in a new tab, or switch to it if it is already open.
+    pub fn open_file(&mut self, path: &Path) -> AnyhowResult<BufferId> {
+        self.editor.open_file(path)
+    }
+
+    /// Feed an input event to the editor. Mouse events are in the host's
+    /// coordinates; those outside the editor's area are ignored. Resize
+    /// events are ignored too — the editor follows the area it is rendered
+    /// into. Returns whether the editor needs to be redrawn.
+    pub fn handle_event(&mut self, event: Event) -> AnyhowResult<bool> {
+        match event {
+            Event::Mouse(mut mouse) => {
+                if !self.area.contains(Position::new(mouse.column, mouse.row)) {
+                    return Ok(false);
+                }
+                mouse.column -= self.area.x;
+                mouse.row -= self.area.y;
+                self.editor.handle_mouse(mouse)
+            }
+            Event::Resize(..) => Ok(false),
+            event => self.editor.handle_input_event(event),
+        }
+    }
+
+    /// Process background work: LSP responses, file watching, plugin
+    /// commands, pending file opens. Call it on every turn of the host's
+    /// event loop, like `fresh` does between input polls. Returns whether
+    /// the editor needs to be redrawn.
+    pub fn tick(&mut self) -> AnyhowResult<bool> {
+        crate::app::editor_tick(&mut self.editor, || Ok(()))
+    }
+
+    /// Whether the user asked the editor to quit. The host decides what
+    /// that means — close the pane, exit, or ignore it.
+    pub fn should_quit(&self) -> bool {
+        self.editor.should_quit()
+    }
+
+    /// Draw the editor into `area` of `frame`, placing the terminal cursor
+    /// if the editor shows one.
+    pub fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let area = area.intersection(frame.area());
+        if area.is_empty() {
+            return;
+        }
+        if area.as_size() != self.terminal.backend().size {
+            self.terminal.backend_mut().size = area.as_size();
+            self.editor.resize(area.width, area.height);
+        }
+        self.area = area;
+
+        let editor = &mut self.editor;
+        let Ok(completed) = self.terminal.draw(|frame| editor.render(frame)) else {
+            return;
+        };
+        let target = frame.buffer_mut();
+        for y in 0..area.height {
+            for x in 0..area.width {
+                target[(area.x + x, area.y + y)] = completed.buffer[(x, y)].clone();
+            }
+        }
+
+        let backend = self.terminal.backend();
+        if backend.cursor_visible {
+            let cursor = backend.cursor;
+            frame.set_cursor_position((area.x + cursor.x, area.y + cursor.y));
+        }
+    }
+}
+
+/// A [`Backend`] that discards output and only records the screen size and
+/// the cursor: [`EmbeddedEditor::render`] reads the finished frame from
+/// [`Terminal::draw`] instead.
+struct OffscreenBackend {
+    size: Size,
+    cursor: Position,
+    cursor_visible: bool,
+}
+
+impl OffscreenBackend {
+    fn new(size: Size) -> Self {
+        Self {
+            size,
+            cursor: Position::ORIGIN,
+            cursor_visible: false,
+        }
+    }
+}
+
+impl Backend for OffscreenBackend {
+    type Error = std::io::Error;
+
+    fn draw<'a, I>(&mut self, _content: I) -> std::io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        Ok(())
+    }
+
+    fn hide_cursor(&mut self) -> std::io::Result<()> {
+        self.cursor_visible = false;
+        Ok(())
+    }
+
+    fn show_cursor(&mut self) -> std::io::Result<()> {
+        self.cursor_visible = true;
+        Ok(())
+    }
+
+    fn get_cursor_position(&mut self) -> std::io::Result<Position> {
+        Ok(self.cursor)
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> std::io::Result<()> {
+        self.cursor = position.into();
+        Ok(())
+    }
+
+    fn clear(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn clear_region(&mut self, _clear_type: ClearType) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn size(&self) -> std::io::Result<Size> {
+        Ok(self.size)
+    }
+
+    fn window_size(&mut self) -> std::io::Result<WindowSize> {
+        Ok(WindowSize {
+            columns_rows: self.size,
+            pixels: Size::default(),
+        })
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,446 @@
+//! Deterministic fuzz/property hooks for the event model.
+//!
+//! Drives an [`EditorState`] through a sequence of valid events — inserts,
+//! deletes, cursor moves, cursor add/remove, undo and redo — decoded from an
+//! arbitrary byte string, and checks the invariants every editing path relies
+//! on after each step:
+//!
+//! - cursors and selection anchors lie inside the buffer, on char boundaries;
+//! - markers (both the main marker list and margin indicators) lie inside the
+//!   buffer;
+//! - the piece tree's line count agrees with the text;
+//! - undoing the whole history restores the initial text and every marker,
+//!   and redoing it all again restores the text at the end of the history.
+//!
+//! The same bytes always decode to the same operations, so a failing input
+//! from `cargo fuzz` (see `crates/fresh-editor/fuzz/`) or a shrunk proptest
+//! case replays exactly. Nothing here is reachable from production code.
+
+use std::sync::Arc;
+
+use ratatui::style::Color;
+
+use crate::model::cursor::Cursors;
+use crate::model::event::{CursorId, Event, EventLog};
+use crate::model::filesystem::StdFileSystem;
+use crate::model::marker::MarkerId;
+use crate::state::EditorState;
+use crate::view::margin::LineIndicator;
+
+/// Text the buffer starts from: several lines, indentation and multi-byte
+/// characters, so positions have to be snapped to char boundaries.
+const INITIAL_TEXT: &str = "fn main() {\n    println!(\"héllo → wörld\");\n}\n\nlet x = 1;\n";
+
+/// Characters inserted text is drawn from.
+const ALPHABET: &[&str] = &["a", "Z", " ", "\t", "\n", "é", "→", "😀", "{", "}"];
+
+/// Every how many chars of [`INITIAL_TEXT`] a marker of each kind is placed.
+const MARKER_SPACING: usize = 5;
+
+/// One step of a fuzz run. Positions are raw values that are mapped into the
+/// current buffer (modulo its length, then snapped back to a char boundary)
+/// when the step is applied, so every decoded sequence is valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FuzzOp {
+    Insert {
+        at: usize,
+        text: String,
+    },
+    Delete {
+        at: usize,
+        len: usize,
+    },
+    MoveCursor {
+        to: usize,
+        select_from: Option<usize>,
+    },
+    AddCursor {
+        at: usize,
+    },
+    /// Remove the `nth` secondary cursor (the primary is never removed, so
+    /// which cursor becomes primary stays deterministic).
+    RemoveCursor {
+        nth: usize,
+    },
+    Undo,
+    Redo,
+}
+
+/// Decode a byte string into operations. Decoding stops when the input runs
+/// out; a truncated final operation is dropped.
+pub fn decode_ops(data: &[u8]) -> Vec<FuzzOp> {
+    let mut bytes = data.iter().copied();
+    let mut ops = Vec::new();
+    while let Some(op) = decode_op(&mut bytes) {
+        ops.push(op);
+    }
+    ops
+}
+
+fn decode_op(bytes: &mut impl Iterator<Item = u8>) -> Option<FuzzOp> {
+    let op = match bytes.next()? % 10 {
+        // Inserts and deletes are weighted up so buffers keep changing.
+        0..=2 => {
+            let at = read_position(bytes)?;
+            let len = usize::from(bytes.next()? % 6) + 1;
+            let mut text = String::new();
+            for _ in 0..len {
+                text.push_str(ALPHABET[usize::from(bytes.next()?) % ALPHABET.len()]);
+            }
+            FuzzOp::Insert { at, text }
+        }
+        3 | 4 => FuzzOp::Delete {
+            at: read_position(bytes)?,
+            len: usize::from(bytes.next()? % 12) + 1,
+        },
+        5 => {
+            let to = read_position(bytes)?;
+            let select_from = match bytes.next()? {
+                b if b % 2 == 0 => None,
+                _ => Some(read_position(bytes)?),
+            };
+            FuzzOp::MoveCursor { to, select_from }
+        }
+        6 => FuzzOp::AddCursor {
+            at: read_position(bytes)?,
+        },
+        7 => FuzzOp::RemoveCursor {
+            nth: usize::from(bytes.next()?),
+        },
+        8 => FuzzOp::Undo,
+        _ => FuzzOp::Redo,
+    };
+    Some(op)
+}
+
+fn read_position(bytes: &mut impl Iterator<Item = u8>) -> Option<usize> {
+    Some(usize::from(u16::from_le_bytes([
+        bytes.next()?,
+        bytes.next()?,
+    ])))
+}
+
+/// Expand `seed` into `len` pseudo-random bytes (splitmix64), for runs that
+/// want a reproducible input without storing it.
+pub fn seeded_input(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed;
+    let mut out = Vec::with_capacity(len + 8);
+    while out.len() < len {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        out.extend_from_slice(&(z ^ (z >> 31)).to_le_bytes());
+    }
+    out.truncate(len);
+    out
+}
+
+/// Decode `data`, apply every operation and check all invariants, including
+/// the full undo/redo round trip at the end. This is the fuzz entry point.
+pub fn run(data: &[u8]) -> Result<(), String> {
+    let mut fuzzer = EventFuzzer::new();
+    for (i, op) in decode_ops(data).iter().enumerate() {
+        fuzzer.apply(op);
+        fuzzer
+            .check_invariants()
+            .map_err(|e| format!("after op {i} ({op:?}): {e}"))?;
+    }
+    fuzzer.check_round_trip()
+}
+
+/// An [`EditorState`] with its cursors, event log and a fixed set of markers,
+/// driven the way the editor drives it (displaced markers are recorded on
+/// deletes and restored on undo).
+pub struct EventFuzzer {
+    state: EditorState,
+    cursors: Cursors,
+    log: EventLog,
+    /// Markers in the main marker list, with their initial positions.
+    markers: Vec<(MarkerId, usize)>,
+    /// Margin indicator markers, with their initial positions.
+    indicators: Vec<(MarkerId, usize)>,
+}
+
+impl Default for EventFuzzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventFuzzer {
+    /// Start from [`INITIAL_TEXT`] with markers spread across it. Loading
+    /// the text is not part of the undo history.
+    pub fn new() -> Self {
+        let mut state = EditorState::new(
+            80,
+            24,
+            crate::config::LARGE_FILE_THRESHOLD_BYTES as usize,
+            Arc::new(StdFileSystem),
+        );
+        let mut cursors = Cursors::new();
+        state.apply(
+            &mut cursors,
+            &Event::Insert {
+                position: 0,
+                text: INITIAL_TEXT.to_string(),
+                cursor_id: CursorId::UNDO_SENTINEL,
+            },
+        );
+
+        let mut markers = Vec::new();
+        let mut indicators = Vec::new();
+        for (i, (pos, _)) in INITIAL_TEXT.char_indices().enumerate() {
+            if i % MARKER_SPACING == 0 {
+                markers.push((state.marker_list.create(pos, false), pos));
+                let indicator = LineIndicator::new("●", Color::Red, 10);
+                let id = state
+                    .margins
+                    .set_line_indicator(pos, format!("fuzz-{i}"), indicator);
+                indicators.push((id, pos));
+            }
+        }
+
+        Self {
+            state,
+            cursors,
+            log: EventLog::new(),
+            markers,
+            indicators,
+        }
+    }
+
+    /// The current buffer text.
+    pub fn text(&self) -> String {
+        self.state.buffer.to_string().unwrap_or_default()
+    }
+
+    /// Apply one operation, logging it like the editor does.
+    pub fn apply(&mut self, op: &FuzzOp) {
+        let text = self.text();
+        let snap = |raw: usize| floor_char_boundary(&text, raw % (text.len() + 1));
+        let primary = self.cursors.primary_id();
+        match op {
+            FuzzOp::Insert { at, text } => self.log_and_apply(Event::Insert {
+                position: snap(*at),
+                text: text.clone(),
+                cursor_id: primary,
+            }),
+            FuzzOp::Delete { at, len } => {
+                let start = snap(*at);
+                let end = ceil_char_boundary(&text, (start + len).min(text.len()));
+                if start < end {
+                    self.log_and_apply(Event::Delete {
+                        range: start..end,
+                        deleted_text: text[start..end].to_string(),
+                        cursor_id: primary,
+                    });
+                }
+            }
+            FuzzOp::MoveCursor { to, select_from } => {
+                let cursor = *self.cursors.primary();
+                self.log_and_apply(Event::MoveCursor {
+                    cursor_id: primary,
+                    old_position: cursor.position,
+                    new_position: snap(*to),
+                    old_anchor: cursor.anchor,
+                    new_anchor: select_from.map(snap),
+                    old_sticky_column: cursor.sticky_column,
+                    new_sticky_column: None,
+                });
+            }
+            FuzzOp::AddCursor { at } => {
+                let next_id = self.cursors.ids().iter().map(|id| id.0).max().unwrap_or(0) + 1;
+                self.log_and_apply(Event::AddCursor {
+                    cursor_id: CursorId(next_id),
+                    position: snap(*at),
+                    anchor: None,
+                });
+            }
+            FuzzOp::RemoveCursor { nth } => {
+                let mut secondary: Vec<CursorId> = self
+                    .cursors
+                    .ids()
+                    .into_iter()
+                    .filter(|id| *id != primary)
+                    .collect();
+                if secondary.is_empty() {
+                    return;
+                }
+                secondary.sort_by_key(|id| id.0);
+                let id = secondary[nth % secondary.len()];
+                if let Some(cursor) = self.cursors.get(id).copied() {
+                    self.log_and_apply(Event::RemoveCursor {
+                        cursor_id: id,
+                        position: cursor.position,
+                        anchor: cursor.anchor,
+                    });
+                }
+            }
+            FuzzOp::Undo => {
+                self.undo();
+            }
+            FuzzOp::Redo => {
+                self.redo();
+            }
+        }
+    }
+
+    /// Check the per-step invariants.
+    pub fn check_invariants(&self) -> Result<(), String> {
+        let text = self
+            .state
+            .buffer
+            .to_string()
+            .ok_or("buffer text is not loaded")?;
+        let len = text.len();
+        if self.state.buffer.len() != len {
+            return Err(format!(
+                "buffer length {} but text has {len} bytes",
+                self.state.buffer.len()
+            ));
+        }
+
+        for (id, cursor) in self.cursors.iter() {
+            for (what, pos) in [
+                ("position", Some(cursor.position)),
+                ("anchor", cursor.anchor),
+            ] {
+                let Some(pos) = pos else { continue };
+                if pos > len || !text.is_char_boundary(pos) {
+                    return Err(format!(
+                        "cursor {id:?} {what} {pos} is not a char boundary in {len} bytes"
+                    ));
+                }
+            }
+        }
+
+        for (id, _) in &self.markers {
+            match self.state.marker_list.get_position(*id) {
+                Some(pos) if pos <= len => {}
+                other => return Err(format!("marker {id:?} at {other:?} in {len} bytes")),
+            }
+        }
+        for (id, _) in &self.indicators {
+            match self.state.margins.get_indicator_position(*id) {
+                Some(pos) if pos <= len => {}
+                other => return Err(format!("indicator {id:?} at {other:?} in {len} bytes")),
+            }
+        }
+
+        let lines = text.matches('\n').count() + 1;
+        if let Some(count) = self.state.buffer.line_count() {
+            if count != lines {
+                return Err(format!("line count {count} but text has {lines} lines"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Redo to the end of the history, then undo all of it and check the
+    /// initial text and marker positions come back, then redo all of it and
+    /// check the text matches the end of the history again. Leaves the state
+    /// at the end of the history.
+    pub fn check_round_trip(&mut self) -> Result<(), String> {
+        self.redo_all("redo to end")?;
+        let end = self.text();
+
+        let mut steps = 0;
+        while self.undo() {
+            steps += 1;
+            self.check_invariants()
+                .map_err(|e| format!("after undo {steps}: {e}"))?;
+        }
+
+        let text = self.text();
+        if text != INITIAL_TEXT {
+            return Err(format!("undoing everything left {text:?}"));
+        }
+        for (id, initial) in &self.markers {
+            let pos = self.state.marker_list.get_position(*id);
+            if pos != Some(*initial) {
+                return Err(format!(
+                    "marker {id:?} at {pos:?} after undo, was {initial}"
+                ));
+            }
+        }
+        for (id, initial) in &self.indicators {
+            let pos = self.state.margins.get_indicator_position(*id);
+            if pos != Some(*initial) {
+                return Err(format!(
+                    "indicator {id:?} at {pos:?} after undo, was {initial}"
+                ));
+            }
+        }
+
+        self.redo_all("redo")?;
+        let text = self.text();
+        if text != end {
+            return Err(format!(
+                "redoing everything gave {text:?}, expected {end:?}"
+            ));
+        }
+        Ok(())
+    }
+
+    fn redo_all(&mut self, what: &str) -> Result<(), String> {
+        let mut steps = 0;
+        while self.redo() {
+            steps += 1;
+            self.check_invariants()
+                .map_err(|e| format!("after {what} {steps}: {e}"))?;
+        }
+        Ok(())
+    }
+
+    fn log_and_apply(&mut self, event: Event) {
+        let displaced = match &event {
+            Event::Delete { range, .. } => self.state.capture_displaced_markers(range),
+            _ => Vec::new(),
+        };
+        self.log.append(event.clone());
+        if !displaced.is_empty() {
+            self.log.set_displaced_markers_on_last(displaced);
+        }
+        self.state.apply(&mut self.cursors, &event);
+    }
+
+    /// Undo one step; returns false when there was nothing to undo.
+    fn undo(&mut self) -> bool {
+        if !self.log.can_undo() {
+            return false;
+        }
+        for (event, displaced) in self.log.undo() {
+            self.state.apply(&mut self.cursors, &event);
+            if !displaced.is_empty() {
+                self.state.restore_displaced_markers(&displaced);
+            }
+        }
+        true
+    }
+
+    /// Redo one step; returns false when there was nothing to redo.
+    fn redo(&mut self) -> bool {
+        if !self.log.can_redo() {
+            return false;
+        }
+        for event in self.log.redo() {
+            self.state.apply(&mut self.cursors, &event);
+        }
+        true
+    }
+}
+
+fn floor_char_boundary(text: &str, mut pos: usize) -> usize {
+    while !text.is_char_boundary(pos) {
+        pos -= 1;
+    }
+    pos
+}
+
+fn ceil_char_boundary(text: &str, mut pos: usize) -> usize {
+    while !text.is_char_boundary(pos) {
+        pos += 1;
+    }
+    pos
+}
@@ -124,6 +124,7 @@ pub fn run_gui(
 
         // ratatui-wgpu does not render a hardware cursor.
         editor.set_software_cursor_only(true);
+        editor.set_styled_underlines_supported();
 
         let workspace_enabled = !no_session_flag && file_locations.is_empty();
 
@@ -206,6 +207,14 @@ impl GuiApplication for EditorApp {
         self.editor.handle_mouse(mouse)
     }
 
+    fn on_ime_preedit(&mut self, text: &str) {
+        self.editor.set_ime_preedit(text);
+    }
+
+    fn on_ime_commit(&mut self, text: &str) {
+        self.editor.commit_ime_text(text);
+    }
+
     fn render(&mut self, frame: &mut ratatui::Frame) {
         self.editor.render(frame);
     }
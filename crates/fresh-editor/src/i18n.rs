@@ -234,6 +234,11 @@ pub fn suspend_unsupported_message() -> String {
     rust_i18n::t!("status.suspend_unsupported").to_string()
 }
 
+/// Status line shown when a file named on the command line can't be opened.
+pub fn error_opening_file_message(error: &str) -> String {
+    rust_i18n::t!("file.error_opening", error = error).to_string()
+}
+
 /// Translate `key` using the runtime backend, with English fallback.
 ///
 /// Unlike `rust_i18n::t!` (a macro that takes a literal key inside the
@@ -374,6 +379,109 @@ mod tests {
         }
     }
 
+    /// Every locale must use the same `%{...}` placeholders as English for
+    /// each key: a dropped one loses information, an extra one is shown
+    /// literally since nothing fills it in.
+    #[test]
+    fn test_all_locales_use_english_placeholders() {
+        use std::fs;
+        use std::path::Path;
+
+        fn placeholders(s: &str) -> HashSet<&str> {
+            s.split("%{")
+                .skip(1)
+                .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+                .collect()
+        }
+
+        let locales_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("locales");
+        let read = |locale: &str| -> serde_json::Map<String, serde_json::Value> {
+            let content = fs::read_to_string(locales_dir.join(format!("{}.json", locale)))
+                .unwrap_or_else(|_| panic!("Failed to read {}.json", locale));
+            serde_json::from_str(&content)
+                .unwrap_or_else(|_| panic!("Failed to parse {}.json", locale))
+        };
+        let en = read("en");
+
+        let mut mismatched = Vec::new();
+        for locale in available_locales() {
+            if locale == "en" {
+                continue;
+            }
+            for (key, value) in read(locale) {
+                let (Some(translated), Some(english)) =
+                    (value.as_str(), en.get(&key).and_then(|v| v.as_str()))
+                else {
+                    continue;
+                };
+                if placeholders(translated) != placeholders(english) {
+                    mismatched.push(format!("{}: {}", locale, key));
+                }
+            }
+        }
+        mismatched.sort();
+        assert!(
+            mismatched.is_empty(),
+            "Translations with placeholders differing from English: {:?}",
+            mismatched
+        );
+    }
+
+    /// Every key passed to `t!` as a literal in the editor's sources must
+    /// exist in the English locale; a missing one shows the raw key.
+    #[test]
+    fn test_source_translation_keys_exist() {
+        use std::fs;
+        use std::path::Path;
+
+        fn collect(dir: &Path, files: &mut Vec<std::path::PathBuf>) {
+            for entry in fs::read_dir(dir).unwrap().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    collect(&path, files);
+                } else if path.extension().is_some_and(|ext| ext == "rs") {
+                    files.push(path);
+                }
+            }
+        }
+
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let en_content =
+            fs::read_to_string(root.join("locales/en.json")).expect("Failed to read en.json");
+        let en: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(&en_content).expect("Failed to parse en.json");
+
+        let mut files = Vec::new();
+        collect(&root.join("src"), &mut files);
+        let mut missing = Vec::new();
+        for file in files {
+            let source = fs::read_to_string(&file).unwrap();
+            for (pos, _) in source.match_indices("t!(") {
+                let preceded_by_ident = source[..pos]
+                    .chars()
+                    .next_back()
+                    .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '!' | '"'));
+                if preceded_by_ident {
+                    continue;
+                }
+                let Some(rest) = source[pos + 3..].trim_start().strip_prefix('"') else {
+                    continue;
+                };
+                let Some((key, _)) = rest.split_once('"') else {
+                    continue;
+                };
+                if !en.contains_key(key) {
+                    missing.push(format!("{}: {}", file.display(), key));
+                }
+            }
+        }
+        assert!(
+            missing.is_empty(),
+            "Translation keys used in source but missing from en.json: {:?}",
+            missing
+        );
+    }
+
     #[test]
     fn t_returns_translation_for_active_locale() {
         let saved = current_locale();
@@ -3,7 +3,9 @@
 use crate::input::keybindings::Action;
 use crate::input::line_move::{move_lines, LineMoveDirection};
 use crate::model::buffer::{Buffer, LineEnding};
-use crate::model::buffer_position::{byte_to_2d, pos_2d_to_byte};
+use crate::model::buffer_position::{
+    byte_to_2d, display_column, display_columns_to_bytes, display_width, line_text, pos_2d_to_byte,
+};
 use crate::model::cursor::{Cursor, Cursors, Position2D, SelectionMode};
 use crate::model::event::{CursorId, Event};
 use crate::model::virtual_space::{
@@ -12,6 +14,8 @@ use crate::model::virtual_space::{
 use crate::primitives::display_width::{byte_offset_at_visual_column, str_width};
 use crate::primitives::highlighter::HighlightCategory;
 use crate::primitives::indent_pattern::PatternIndentCalculator;
+use crate::primitives::text_escape;
+use crate::primitives::text_transform::{self, SortOrder};
 use crate::primitives::word_navigation::{
     find_vi_word_end, find_word_end, find_word_end_right, find_word_start, find_word_start_left,
     find_word_start_right,
@@ -419,8 +423,9 @@ fn block_select_action(
 
     let vs_mode = state.buffer_settings.virtual_space;
     let block_virtual = vs_mode.block_beyond_eol();
+    let tab_size = state.buffer_settings.tab_size;
     for (cursor_id, cursor) in cursors.iter() {
-        let mut current_2d = byte_to_2d(&state.buffer, cursor.position);
+        let mut current_2d = byte_to_2d(&state.buffer, cursor.position, tab_size);
         if block_virtual {
             if cursor.selection_mode == SelectionMode::Block {
                 // In block mode the sticky column carries the block column,
@@ -442,26 +447,52 @@ fn block_select_action(
             cursor.block_anchor.unwrap_or(current_2d)
         };
 
-        // Calculate new 2D position based on direction
+        // Calculate new 2D position based on direction. Left and Right step
+        // over a whole grapheme cluster, so the column moves by its display
+        // width (two for a CJK character or emoji).
         let new_2d = match direction {
-            BlockDirection::Left => Position2D {
-                line: current_2d.line,
-                column: current_2d.column.saturating_sub(1),
-            },
-            BlockDirection::Right => {
-                // Get current line length to bound the column
-                let line_content = state.buffer.get_line(current_2d.line).unwrap_or_default();
-                let line_len = if line_content.last() == Some(&b'\n') {
-                    line_content.len().saturating_sub(1)
+            BlockDirection::Left => {
+                let text = line_text(&state.buffer, current_2d.line);
+                let line_width = display_width(&text, tab_size);
+                let column = if current_2d.column > line_width {
+                    current_2d.column - 1
                 } else {
-                    line_content.len()
+                    let byte = display_columns_to_bytes(
+                        &text,
+                        current_2d.column,
+                        current_2d.column + 1,
+                        tab_size,
+                    )
+                    .start;
+                    let prev = crate::primitives::grapheme::prev_grapheme_boundary(&text, byte);
+                    display_column(&text, prev, tab_size)
                 };
-                // With virtual space for block selections, the rectangle may
-                // grow past the line end (true rectangles).
-                let column = if block_virtual {
-                    current_2d.column + 1
+                Position2D {
+                    line: current_2d.line,
+                    column,
+                }
+            }
+            BlockDirection::Right => {
+                let text = line_text(&state.buffer, current_2d.line);
+                let line_width = display_width(&text, tab_size);
+                let byte = display_columns_to_bytes(
+                    &text,
+                    current_2d.column,
+                    current_2d.column + 1,
+                    tab_size,
+                )
+                .start;
+                let next = crate::primitives::grapheme::next_grapheme_boundary(&text, byte);
+                let column = if current_2d.column >= line_width {
+                    // With virtual space for block selections, the rectangle
+                    // may grow past the line end (true rectangles).
+                    if block_virtual {
+                        current_2d.column + 1
+                    } else {
+                        line_width
+                    }
                 } else {
-                    (current_2d.column + 1).min(line_len)
+                    display_column(&text, next, tab_size)
                 };
                 Position2D {
                     line: current_2d.line,
@@ -491,10 +522,10 @@ fn block_select_action(
         };
 
         // Convert new 2D position back to byte offset
-        let new_byte_pos = pos_2d_to_byte(&state.buffer, new_2d);
+        let new_byte_pos = pos_2d_to_byte(&state.buffer, new_2d, tab_size);
 
         // Store the byte anchor for the event system (for undo/redo compatibility)
-        let byte_anchor = pos_2d_to_byte(&state.buffer, block_anchor);
+        let byte_anchor = pos_2d_to_byte(&state.buffer, block_anchor, tab_size);
 
         events.push(Event::MoveCursor {
             cursor_id,
@@ -517,7 +548,7 @@ fn block_select_action(
     let buffer_ref = &state.buffer;
     cursors.map(|cursor| {
         if cursor.selection_mode != SelectionMode::Block || cursor.block_anchor.is_none() {
-            let mut current_2d = byte_to_2d(buffer_ref, cursor.position);
+            let mut current_2d = byte_to_2d(buffer_ref, cursor.position, tab_size);
             // Anchor the rectangle at the cursor's on-screen (possibly
             // virtual) column, matching the entry handling above.
             if block_virtual {
@@ -550,11 +581,12 @@ fn convert_block_selection_to_cursors(
 
     // Check if any cursor has a block selection
     let block_virtual = state.buffer_settings.virtual_space.block_beyond_eol();
+    let tab_size = state.buffer_settings.tab_size;
     let block_info: Option<(CursorId, Position2D, Position2D)> =
         cursors.iter().find_map(|(cursor_id, cursor)| {
             if cursor.has_block_selection() {
                 let block_anchor = cursor.block_anchor?;
-                let mut cursor_2d = byte_to_2d(&state.buffer, cursor.position);
+                let mut cursor_2d = byte_to_2d(&state.buffer, cursor.position, tab_size);
                 // The block column (sticky) may extend past the clipped byte
                 // column when virtual space is enabled for block selections.
                 if block_virtual {
@@ -587,30 +619,20 @@ fn convert_block_selection_to_cursors(
 
     for line in min_line..=max_line {
         let line_start = state.buffer.line_start_offset(line).unwrap_or(0);
-        let line_content = state.buffer.get_line(line).unwrap_or_default();
+        let text = line_text(&state.buffer, line);
+        let line_width = display_width(&text, tab_size);
 
-        // Calculate line length excluding newline
-        let line_len = if line_content.last() == Some(&b'\n') {
-            line_content.len().saturating_sub(1)
-        } else {
-            line_content.len()
-        };
-
-        if block_virtual && min_col > line_len {
-            let line_end = line_start + line_len;
-            paddings.push((cursor_positions.len(), min_col - line_len));
+        if block_virtual && min_col > line_width {
+            let line_end = line_start + text.len();
+            paddings.push((cursor_positions.len(), min_col - line_width));
             cursor_positions.push((line_end, line_end));
             continue;
         }
 
-        // Clamp columns to actual line length
-        let actual_min_col = min_col.min(line_len);
-        let actual_max_col = max_col.min(line_len);
-
-        let anchor = line_start + actual_min_col;
-        let position = line_start + actual_max_col;
-
-        cursor_positions.push((position, anchor));
+        // The graphemes the rectangle covers on this line; a wide character
+        // straddling an edge is taken whole. Columns past the line end clamp.
+        let bytes = display_columns_to_bytes(&text, min_col, max_col, tab_size);
+        cursor_positions.push((line_start + bytes.end, line_start + bytes.start));
     }
 
     // Update the primary cursor to have a normal selection on the first line
@@ -1218,9 +1240,43 @@ fn transform_case<F>(
         })
         .filter(|(_, start, end)| start < end)
         .collect();
+    replace_ranges(state, events, &mut selections, transform);
+}
+
+/// Transform selected text only; cursors without a selection are left alone.
+fn transform_selections<F>(
+    state: &mut EditorState,
+    cursors: &mut Cursors,
+    events: &mut Vec<Event>,
+    transform: F,
+) where
+    F: Fn(&str) -> String,
+{
+    let mut selections: Vec<_> = cursors
+        .iter()
+        .filter_map(|(cursor_id, cursor)| {
+            cursor
+                .selection_range()
+                .map(|range| (cursor_id, range.start, range.end))
+        })
+        .filter(|(_, start, end)| start < end)
+        .collect();
+    replace_ranges(state, events, &mut selections, transform);
+}
+
+/// Replace each `(cursor, start, end)` range with `transform` of its text,
+/// processing ranges in reverse order to avoid position shifts.
+fn replace_ranges<F>(
+    state: &mut EditorState,
+    events: &mut Vec<Event>,
+    selections: &mut [(CursorId, usize, usize)],
+    transform: F,
+) where
+    F: Fn(&str) -> String,
+{
     selections.sort_by_key(|(_, start, _)| std::cmp::Reverse(*start));
 
-    for (cursor_id, start, end) in selections {
+    for &mut (cursor_id, start, end) in selections {
         let text = state.get_text_range(start, end);
         let transformed = transform(&text);
         if transformed != text {
@@ -2391,8 +2447,16 @@ fn handle_toggle_case(state: &mut EditorState, cursors: &Cursors, events: &mut V
     }
 }
 
-fn handle_sort_lines(state: &mut EditorState, cursors: &Cursors, events: &mut Vec<Event>) {
-    // Sort selected lines alphabetically
+/// Replace the lines of every selection with `transform` of them.
+/// Selections spanning a single line are left alone.
+fn handle_line_transform<F>(
+    state: &mut EditorState,
+    cursors: &Cursors,
+    events: &mut Vec<Event>,
+    transform: F,
+) where
+    F: Fn(&[&str]) -> Vec<String>,
+{
     // Process cursors in reverse order to avoid position shifts
     let line_ending = state.buffer.line_ending().insertion_str();
     let mut selections: Vec<_> = cursors
@@ -2404,18 +2468,17 @@ fn handle_sort_lines(state: &mut EditorState, cursors: &Cursors, events: &mut Ve
     for (cursor_id, range) in selections {
         let text = state.get_text_range(range.start, range.end);
         // Split into lines, preserving the original line ending style
-        let mut lines: Vec<&str> = text.lines().collect();
+        let lines: Vec<&str> = text.lines().collect();
         // Check if original text ends with a newline
         let ends_with_newline = text.ends_with('\n') || text.ends_with("\r\n");
 
         if lines.len() > 1 {
-            lines.sort();
-            let mut sorted_text = lines.join(line_ending);
+            let mut new_text = transform(&lines).join(line_ending);
             if ends_with_newline {
-                sorted_text.push_str(line_ending);
+                new_text.push_str(line_ending);
             }
 
-            if sorted_text != text {
+            if new_text != text {
                 events.push(Event::Delete {
                     range: range.clone(),
                     deleted_text: text,
@@ -2423,7 +2486,7 @@ fn handle_sort_lines(state: &mut EditorState, cursors: &Cursors, events: &mut Ve
                 });
                 events.push(Event::Insert {
                     position: range.start,
-                    text: sorted_text,
+                    text: new_text,
                     cursor_id,
                 });
             }
@@ -2431,6 +2494,56 @@ fn handle_sort_lines(state: &mut EditorState, cursors: &Cursors, events: &mut Ve
     }
 }
 
+/// Number the selected lines, or insert a number at each cursor without a
+/// selection. Numbering runs through the cursors in document order.
+fn handle_number_lines(state: &mut EditorState, cursors: &Cursors, events: &mut Vec<Event>) {
+    let line_ending = state.buffer.line_ending().insertion_str();
+    let mut targets: Vec<_> = cursors
+        .iter()
+        .map(|(cursor_id, cursor)| {
+            let range = cursor
+                .selection_range()
+                .unwrap_or(cursor.position..cursor.position);
+            (cursor_id, range)
+        })
+        .collect();
+    targets.sort_by_key(|(_, range)| range.start);
+
+    let mut next = 1;
+    let mut replacements = Vec::with_capacity(targets.len());
+    for (cursor_id, range) in targets {
+        let text = state.get_text_range(range.start, range.end);
+        let new_text = if text.is_empty() {
+            next += 1;
+            (next - 1).to_string()
+        } else {
+            let lines: Vec<&str> = text.lines().collect();
+            let mut numbered = text_transform::number_lines(&lines, next).join(line_ending);
+            if text.ends_with('\n') {
+                numbered.push_str(line_ending);
+            }
+            next += lines.len();
+            numbered
+        };
+        replacements.push((cursor_id, range, text, new_text));
+    }
+
+    for (cursor_id, range, text, new_text) in replacements.into_iter().rev() {
+        if !text.is_empty() {
+            events.push(Event::Delete {
+                range: range.clone(),
+                deleted_text: text,
+                cursor_id,
+            });
+        }
+        events.push(Event::Insert {
+            position: range.start,
+            text: new_text,
+            cursor_id,
+        });
+    }
+}
+
 fn handle_duplicate_line(
     state: &mut EditorState,
     cursors: &Cursors,
@@ -3069,8 +3182,84 @@ pub fn action_to_events(
             handle_toggle_case(state, cursors, &mut events);
         }
 
-        Action::SortLines => {
-            handle_sort_lines(state, cursors, &mut events);
+        Action::ToTitleCase => {
+            transform_case(state, cursors, &mut events, text_transform::to_title_case);
+        }
+
+        Action::ToCamelCase => {
+            transform_case(state, cursors, &mut events, text_transform::to_camel_case);
+        }
+
+        Action::ToSnakeCase => {
+            transform_case(state, cursors, &mut events, text_transform::to_snake_case);
+        }
+
+        Action::ToKebabCase => {
+            transform_case(state, cursors, &mut events, text_transform::to_kebab_case);
+        }
+
+        Action::SortLines | Action::SortLinesDescending | Action::SortLinesUnique => {
+            let order = match action {
+                Action::SortLinesDescending => SortOrder::Descending,
+                Action::SortLinesUnique => SortOrder::Unique,
+                _ => SortOrder::Ascending,
+            };
+            handle_line_transform(state, cursors, &mut events, |lines| {
+                text_transform::sort_lines(lines, order)
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect()
+            });
+        }
+
+        Action::ReverseLines => {
+            handle_line_transform(state, cursors, &mut events, |lines| {
+                text_transform::reverse_lines(lines)
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect()
+            });
+        }
+
+        Action::NumberLines => {
+            handle_number_lines(state, cursors, &mut events);
+        }
+
+        Action::EscapeUnicode => {
+            transform_selections(state, cursors, &mut events, text_escape::escape_unicode);
+        }
+
+        Action::UnescapeUnicode => {
+            transform_selections(state, cursors, &mut events, text_escape::unescape_unicode);
+        }
+
+        Action::EncodeHtmlEntities => {
+            transform_selections(
+                state,
+                cursors,
+                &mut events,
+                text_escape::encode_html_entities,
+            );
+        }
+
+        Action::DecodeHtmlEntities => {
+            transform_selections(
+                state,
+                cursors,
+                &mut events,
+                text_escape::decode_html_entities,
+            );
+        }
+
+        Action::UrlEncode => {
+            transform_selections(state, cursors, &mut events, text_escape::url_encode);
+        }
+
+        Action::UrlDecode => {
+            // Selections that don't decode to valid UTF-8 are left untouched.
+            transform_selections(state, cursors, &mut events, |s| {
+                text_escape::url_decode(s).unwrap_or_else(|| s.to_string())
+            });
         }
 
         Action::OpenLine => {
@@ -3087,6 +3276,14 @@ pub fn action_to_events(
             events.push(Event::Recenter);
         }
 
+        Action::ScrollCursorToTop => {
+            events.push(Event::ScrollCursorToTop);
+        }
+
+        Action::ScrollCursorToBottom => {
+            events.push(Event::ScrollCursorToBottom);
+        }
+
         Action::SetMark => {
             // Set the selection anchor at the current cursor position
             // This starts a selection that extends as the cursor moves
@@ -3135,16 +3332,24 @@ pub fn action_to_events(
         | Action::Open
         | Action::SwitchProject
         | Action::New
+        | Action::NewScratchBuffer
         | Action::Close
         | Action::CloseTab
+        | Action::CloseOtherTabs
+        | Action::CloseTabsToRight
+        | Action::CloseSavedTabs
+        | Action::ReopenClosedTab
+        | Action::KeepPreviewTab
         | Action::GotoLine
         | Action::ScanLineIndex
         | Action::NextBuffer
         | Action::PrevBuffer
         | Action::SwitchToPreviousTab
         | Action::SwitchToTabByName
+        | Action::SwitchBuffer
         | Action::NavigateBack
         | Action::NavigateForward
+        | Action::NavigationHistory
         | Action::SplitHorizontal
         | Action::SplitVertical
         | Action::CloseSplit
@@ -3159,8 +3364,15 @@ pub fn action_to_events(
         | Action::CopyWithTheme(_)
         | Action::CopyFilePath
         | Action::CopyRelativeFilePath
+        | Action::CopyFilePathWithLine
+        | Action::ServeBuffer
+        | Action::StopServingBuffer
         | Action::Cut
         | Action::Paste
+        | Action::PasteFromHistory
+        | Action::CyclePasteHistory
+        | Action::ClipboardHistoryTogglePin
+        | Action::ClipboardHistoryPasteIndented
         | Action::YankWordForward
         | Action::YankWordBackward
         | Action::YankToLineEnd
@@ -3186,6 +3398,11 @@ pub fn action_to_events(
         | Action::ToggleReadOnly
         | Action::TogglePageView
         | Action::SetPageWidth
+        | Action::StartPresentation
+        | Action::MarkdownPreview
+        | Action::PreviewImage
+        | Action::ExpandSelectionToNode
+        | Action::ShrinkSelection
         | Action::IncreaseSplitSize
         | Action::DecreaseSplitSize
         | Action::ToggleMaximizeSplit
@@ -3197,22 +3414,42 @@ pub fn action_to_events(
         | Action::ShowKeyboardShortcuts
         | Action::ShowWarnings
         | Action::ShowStatusLog
+        | Action::ShowMessages
+        | Action::CycleMessageFilter
+        | Action::ShowEditorLog
+        | Action::FilterEditorLog
         | Action::ShowLspStatus
         | Action::ShowRemoteIndicatorMenu
         | Action::ShowReadOnlyMenu
+        | Action::ShowJobs
         | Action::ClearWarnings
         | Action::SmartHome
         | Action::ToggleComment
+        | Action::ToggleBlockComment
+        | Action::JoinLines
+        | Action::SplitLine
         | Action::DabbrevExpand
+        | Action::InsertSnippet
         | Action::ToggleFold
         | Action::SetBookmark(_)
         | Action::JumpToBookmark(_)
         | Action::ClearBookmark(_)
         | Action::ListBookmarks
+        | Action::ToggleLineBookmark
+        | Action::NextLineBookmark
+        | Action::PrevLineBookmark
+        | Action::LabelLineBookmark
+        | Action::ShowLineBookmarks
+        | Action::SetNamedMark(_)
+        | Action::GotoNamedMark(_)
+        | Action::PromptSetNamedMark
+        | Action::PromptGotoNamedMark
+        | Action::ListNamedMarks
         | Action::ToggleSearchCaseSensitive
         | Action::ToggleSearchWholeWord
         | Action::ToggleSearchRegex
         | Action::ToggleSearchConfirmEach
+        | Action::ToggleSearchMultiline
         | Action::StartMacroRecording
         | Action::StopMacroRecording
         | Action::PlayMacro(_)
@@ -3266,12 +3503,16 @@ pub fn action_to_events(
         | Action::PopupFocus
         | Action::CompletionAccept
         | Action::CompletionDismiss
+        | Action::CompletionDocsScrollDown
+        | Action::CompletionDocsScrollUp
+        | Action::SmartTab
         | Action::ToggleFileExplorer
         | Action::ToggleFileExplorerSide
         | Action::ToggleMenuBar
         | Action::ToggleTabBar
         | Action::ToggleStatusBar
         | Action::TogglePromptLine
+        | Action::ToggleZenMode
         | Action::ToggleVerticalScrollbar
         | Action::ToggleHorizontalScrollbar
         | Action::FocusFileExplorer
@@ -3318,6 +3559,8 @@ pub fn action_to_events(
         | Action::LspToggleForBuffer
         | Action::ToggleInlayHints
         | Action::ToggleMouseHover
+        | Action::GotoTag
+        | Action::GenerateTags
         | Action::ToggleLineNumbers
         | Action::ToggleLineNumbersCurrentBuffer
         | Action::ToggleLineWrapCurrentBuffer
@@ -3326,6 +3569,8 @@ pub fn action_to_events(
         | Action::ToggleScrollSync
         | Action::ToggleMouseCapture
         | Action::DumpConfig
+        | Action::ReloadConfig
+        | Action::DumpScreen
         | Action::RedrawScreen
         | Action::Search
         | Action::FindInSelection
@@ -3337,6 +3582,7 @@ pub fn action_to_events(
         | Action::Replace
         | Action::QueryReplace
         | Action::MenuActivate
+        | Action::OpenContextMenu
         | Action::MenuClose
         | Action::MenuLeft
         | Action::MenuRight
@@ -3350,7 +3596,15 @@ pub fn action_to_events(
         | Action::ScrollTabsLeft
         | Action::ScrollTabsRight
         | Action::InspectThemeAtCursor
+        | Action::DescribeChar
+        | Action::EvaluateSelection
+        | Action::EvaluateSelectionReplace
+        | Action::EvaluateSelectionAppend
+        | Action::NarrowToSelection
+        | Action::Widen
+        | Action::FixEncodingArtifacts
         | Action::SelectTheme
+        | Action::PreviewThemes
         | Action::SelectKeybindingMap
         | Action::SelectCursorStyle
         | Action::SelectLocale
@@ -3358,9 +3612,15 @@ pub fn action_to_events(
         | Action::ToggleAutoRevert
         | Action::UpdateFresh
         | Action::OpenUpdateLog
+        | Action::ToggleTelemetry
+        | Action::ToggleUpdateCheck
+        | Action::ShowTelemetryData
         | Action::FormatBuffer
         | Action::TrimTrailingWhitespace
         | Action::EnsureFinalNewline
+        | Action::RevertLastTransform
+        | Action::FileHistory
+        | Action::FileHistoryShowDiff
         | Action::OpenTerminal
         | Action::OpenTerminalRight
         | Action::OpenTerminalBelow
@@ -3383,23 +3643,32 @@ pub fn action_to_events(
         | Action::SettingsInherit
         | Action::SetTabSize
         | Action::SetLineEnding
+        | Action::ConvertLineEndingsToLf
+        | Action::ConvertLineEndingsToCrlf
+        | Action::ConvertIndentationToSpaces
+        | Action::ConvertIndentationToTabs
         | Action::SetEncoding
         | Action::ReloadWithEncoding
+        | Action::SaveWithEncoding
         | Action::SetLanguage
         | Action::ToggleIndentationStyle
         | Action::ToggleTabIndicators
         | Action::ToggleWhitespaceIndicators
         | Action::ToggleDebugHighlights
+        | Action::TogglePerfHud
         | Action::ResetBufferSettings
         | Action::ShellCommand
         | Action::ShellCommandReplace
         | Action::CalibrateInput
+        | Action::SelectCalibrationProfile
+        | Action::ShowTerminalCapabilities
         | Action::EventDebug
         | Action::SuspendProcess
         | Action::LoadPluginFromBuffer
         | Action::InitReload
         | Action::InitEdit
         | Action::InitCheck
+        | Action::ScriptsReload
         | Action::OpenKeybindingEditor
         | Action::AddRuler
         | Action::RemoveRuler
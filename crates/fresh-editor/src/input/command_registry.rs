@@ -66,6 +66,30 @@ impl CommandRegistry {
         })
     }
 
+    /// Plugin commands registered for the editor content context menu
+    /// (`contextMenu: true`) whose custom contexts are satisfied, in
+    /// registration order. A custom context counts as active when it's in
+    /// `active_custom_contexts` or names the focused buffer's mode — the
+    /// same visibility rule the command palette applies.
+    pub fn context_menu_commands(
+        &self,
+        active_custom_contexts: &std::collections::HashSet<String>,
+        active_buffer_mode: Option<&str>,
+    ) -> Vec<Command> {
+        let plugins = self.plugin_commands.read().unwrap();
+        plugins
+            .iter()
+            .filter(|cmd| cmd.context_menu)
+            .filter(|cmd| {
+                cmd.custom_contexts.iter().all(|ctx| {
+                    active_custom_contexts.contains(ctx)
+                        || active_buffer_mode.is_some_and(|mode| mode == ctx)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Record that a command was used (for history/sorting)
     ///
     /// This moves the command to the front of the history list.
@@ -406,6 +430,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         };
 
         registry.register(custom_command.clone());
@@ -428,6 +453,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         };
 
         registry.register(custom_command);
@@ -437,6 +463,48 @@ mod tests {
         assert_eq!(registry.plugin_command_count(), 0);
     }
 
+    #[test]
+    fn test_context_menu_commands_respect_flag_and_contexts() {
+        let registry = CommandRegistry::new();
+        let plugin_command =
+            |name: &str, context_menu: bool, custom_contexts: Vec<String>| Command {
+                name: name.to_string(),
+                description: String::new(),
+                action: Action::PluginAction(name.to_string()),
+                contexts: vec![],
+                custom_contexts,
+                source: CommandSource::Plugin("test".to_string()),
+                terminal_bypass: false,
+                context_menu,
+            };
+        registry.register(plugin_command("Palette Only", false, vec![]));
+        registry.register(plugin_command("Always", true, vec![]));
+        registry.register(plugin_command(
+            "In Review",
+            true,
+            vec!["review-mode".to_string()],
+        ));
+
+        let none = std::collections::HashSet::new();
+        let names = |cmds: Vec<Command>| cmds.into_iter().map(|c| c.name).collect::<Vec<_>>();
+        assert_eq!(
+            names(registry.context_menu_commands(&none, None)),
+            ["Always"]
+        );
+
+        let review: std::collections::HashSet<String> =
+            ["review-mode".to_string()].into_iter().collect();
+        assert_eq!(
+            names(registry.context_menu_commands(&review, None)),
+            ["Always", "In Review"]
+        );
+        // The focused buffer's mode satisfies the context too.
+        assert_eq!(
+            names(registry.context_menu_commands(&none, Some("review-mode"))),
+            ["Always", "In Review"]
+        );
+    }
+
     #[test]
     fn test_register_replaces_existing() {
         let registry = CommandRegistry::new();
@@ -449,6 +517,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         };
 
         let command2 = Command {
@@ -459,6 +528,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         };
 
         registry.register(command1);
@@ -483,6 +553,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         });
 
         registry.register(Command {
@@ -493,6 +564,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         });
 
         registry.register(Command {
@@ -503,6 +575,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         });
 
         assert_eq!(registry.plugin_command_count(), 3);
@@ -531,6 +604,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         });
 
         let empty_contexts = std::collections::HashSet::new();
@@ -567,6 +641,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         });
 
         registry.register(Command {
@@ -577,6 +652,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         });
 
         // In normal context, "Popup Only" should be disabled
@@ -622,6 +698,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         });
 
         registry.register(Command {
@@ -632,6 +709,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         });
 
         let all = registry.get_all();
@@ -656,6 +734,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         });
 
         // Should now find the custom version
@@ -760,6 +839,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         });
 
         registry.register(Command {
@@ -770,6 +850,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Builtin,
             terminal_bypass: false,
+            context_menu: false,
         });
 
         // Use one built-in command
@@ -867,6 +948,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Plugin("plugin-a".to_string()),
             terminal_bypass: false,
+            context_menu: false,
         };
 
         let cmd_b = Command {
@@ -877,6 +959,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Plugin("plugin-b".to_string()),
             terminal_bypass: false,
+            context_menu: false,
         };
 
         // First registration succeeds
@@ -905,6 +988,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Plugin("plugin-a".to_string()),
             terminal_bypass: false,
+            context_menu: false,
         };
 
         let cmd2 = Command {
@@ -915,6 +999,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Plugin("plugin-a".to_string()),
             terminal_bypass: false,
+            context_menu: false,
         };
 
         assert!(registry.try_register(cmd1).is_ok());
@@ -938,6 +1023,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Plugin("plugin-a".to_string()),
             terminal_bypass: false,
+            context_menu: false,
         };
 
         let cmd_b = Command {
@@ -948,6 +1034,7 @@ mod tests {
             custom_contexts: vec![],
             source: CommandSource::Plugin("plugin-b".to_string()),
             terminal_bypass: false,
+            context_menu: false,
         };
 
         // Plugin A registers
@@ -40,6 +40,11 @@ pub struct Command {
     /// flag stays `false` for them and they still bypass the
     /// existing way.
     pub terminal_bypass: bool,
+    /// When `true`, the command also appears in the editor content area's
+    /// right-click menu. Plugins set this via
+    /// `editor.registerCommand(..., { contextMenu: true })`; built-in
+    /// entries of that menu are fixed and don't use the flag.
+    pub context_menu: bool,
 }
 
 impl Command {
@@ -216,6 +221,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.new_scratch_buffer",
+        desc_key: "cmd.new_scratch_buffer_desc",
+        action: || Action::NewScratchBuffer,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.close_buffer",
         desc_key: "cmd.close_buffer_desc",
@@ -230,6 +242,41 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.close_other_tabs",
+        desc_key: "cmd.close_other_tabs_desc",
+        action: || Action::CloseOtherTabs,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.close_tabs_to_right",
+        desc_key: "cmd.close_tabs_to_right_desc",
+        action: || Action::CloseTabsToRight,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.close_saved_tabs",
+        desc_key: "cmd.close_saved_tabs_desc",
+        action: || Action::CloseSavedTabs,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.reopen_closed_tab",
+        desc_key: "cmd.reopen_closed_tab_desc",
+        action: || Action::ReopenClosedTab,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.keep_preview_tab",
+        desc_key: "cmd.keep_preview_tab_desc",
+        action: || Action::KeepPreviewTab,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.revert_file",
         desc_key: "cmd.revert_file_desc",
@@ -265,6 +312,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.revert_last_transform",
+        desc_key: "cmd.revert_last_transform_desc",
+        action: || Action::RevertLastTransform,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.file_history",
+        desc_key: "cmd.file_history_desc",
+        action: || Action::FileHistory,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.quit",
         desc_key: "cmd.quit_desc",
@@ -337,6 +398,34 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.copy_file_path_with_line",
+        desc_key: "cmd.copy_file_path_with_line_desc",
+        action: || Action::CopyFilePathWithLine,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.serve_buffer",
+        desc_key: "cmd.serve_buffer_desc",
+        action: || Action::ServeBuffer,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.stop_serving_buffer",
+        desc_key: "cmd.stop_serving_buffer_desc",
+        action: || Action::StopServingBuffer,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.open_context_menu",
+        desc_key: "cmd.open_context_menu_desc",
+        action: || Action::OpenContextMenu,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.cut",
         desc_key: "cmd.cut_desc",
@@ -351,6 +440,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.paste_from_history",
+        desc_key: "cmd.paste_from_history_desc",
+        action: || Action::PasteFromHistory,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.cycle_paste_history",
+        desc_key: "cmd.cycle_paste_history_desc",
+        action: || Action::CyclePasteHistory,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.delete_line",
         desc_key: "cmd.delete_line_desc",
@@ -400,6 +503,34 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.transform_title_case",
+        desc_key: "cmd.transform_title_case_desc",
+        action: || Action::ToTitleCase,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.transform_camel_case",
+        desc_key: "cmd.transform_camel_case_desc",
+        action: || Action::ToCamelCase,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.transform_snake_case",
+        desc_key: "cmd.transform_snake_case_desc",
+        action: || Action::ToSnakeCase,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.transform_kebab_case",
+        desc_key: "cmd.transform_kebab_case_desc",
+        action: || Action::ToKebabCase,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.sort_lines",
         desc_key: "cmd.sort_lines_desc",
@@ -407,6 +538,118 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.sort_lines_descending",
+        desc_key: "cmd.sort_lines_descending_desc",
+        action: || Action::SortLinesDescending,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.sort_lines_unique",
+        desc_key: "cmd.sort_lines_unique_desc",
+        action: || Action::SortLinesUnique,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.reverse_lines",
+        desc_key: "cmd.reverse_lines_desc",
+        action: || Action::ReverseLines,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.number_lines",
+        desc_key: "cmd.number_lines_desc",
+        action: || Action::NumberLines,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.escape_unicode",
+        desc_key: "cmd.escape_unicode_desc",
+        action: || Action::EscapeUnicode,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.unescape_unicode",
+        desc_key: "cmd.unescape_unicode_desc",
+        action: || Action::UnescapeUnicode,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.encode_html_entities",
+        desc_key: "cmd.encode_html_entities_desc",
+        action: || Action::EncodeHtmlEntities,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.decode_html_entities",
+        desc_key: "cmd.decode_html_entities_desc",
+        action: || Action::DecodeHtmlEntities,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.url_encode",
+        desc_key: "cmd.url_encode_desc",
+        action: || Action::UrlEncode,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.url_decode",
+        desc_key: "cmd.url_decode_desc",
+        action: || Action::UrlDecode,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.describe_char",
+        desc_key: "cmd.describe_char_desc",
+        action: || Action::DescribeChar,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.evaluate_selection",
+        desc_key: "cmd.evaluate_selection_desc",
+        action: || Action::EvaluateSelection,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.evaluate_selection_replace",
+        desc_key: "cmd.evaluate_selection_replace_desc",
+        action: || Action::EvaluateSelectionReplace,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.evaluate_selection_append",
+        desc_key: "cmd.evaluate_selection_append_desc",
+        action: || Action::EvaluateSelectionAppend,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.narrow_to_selection",
+        desc_key: "cmd.narrow_to_selection_desc",
+        action: || Action::NarrowToSelection,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.widen",
+        desc_key: "cmd.widen_desc",
+        action: || Action::Widen,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.open_line",
         desc_key: "cmd.open_line_desc",
@@ -428,6 +671,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.scroll_cursor_to_top",
+        desc_key: "cmd.scroll_cursor_to_top_desc",
+        action: || Action::ScrollCursorToTop,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.scroll_cursor_to_bottom",
+        desc_key: "cmd.scroll_cursor_to_bottom_desc",
+        action: || Action::ScrollCursorToBottom,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.set_mark",
         desc_key: "cmd.set_mark_desc",
@@ -478,6 +735,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.expand_selection_to_node",
+        desc_key: "cmd.expand_selection_to_node_desc",
+        action: || Action::ExpandSelectionToNode,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.shrink_selection",
+        desc_key: "cmd.shrink_selection_desc",
+        action: || Action::ShrinkSelection,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Multi-cursor
     CommandDef {
         name_key: "cmd.add_cursor_above",
@@ -543,6 +814,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.switch_buffer",
+        desc_key: "cmd.switch_buffer_desc",
+        action: || Action::SwitchBuffer,
+        contexts: &[Normal, Terminal],
+        custom_contexts: &[],
+    },
     // Split operations
     CommandDef {
         name_key: "cmd.split_horizontal",
@@ -694,6 +972,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.debug_toggle_perf_hud",
+        desc_key: "cmd.debug_toggle_perf_hud_desc",
+        action: || Action::TogglePerfHud,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     // Rulers
     CommandDef {
         name_key: "cmd.add_ruler",
@@ -724,6 +1009,41 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.convert_line_endings_to_lf",
+        desc_key: "cmd.convert_line_endings_to_lf_desc",
+        action: || Action::ConvertLineEndingsToLf,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.convert_line_endings_to_crlf",
+        desc_key: "cmd.convert_line_endings_to_crlf_desc",
+        action: || Action::ConvertLineEndingsToCrlf,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.convert_indentation_to_spaces",
+        desc_key: "cmd.convert_indentation_to_spaces_desc",
+        action: || Action::ConvertIndentationToSpaces,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.convert_indentation_to_tabs",
+        desc_key: "cmd.convert_indentation_to_tabs_desc",
+        action: || Action::ConvertIndentationToTabs,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.fix_encoding_artifacts",
+        desc_key: "cmd.fix_encoding_artifacts_desc",
+        action: || Action::FixEncodingArtifacts,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.set_encoding",
         desc_key: "cmd.set_encoding_desc",
@@ -738,6 +1058,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.save_with_encoding",
+        desc_key: "cmd.save_with_encoding_desc",
+        action: || Action::SaveWithEncoding,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.set_language",
         desc_key: "cmd.set_language_desc",
@@ -851,6 +1178,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal, FileExplorer, Terminal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_zen_mode",
+        desc_key: "cmd.toggle_zen_mode_desc",
+        action: || Action::ToggleZenMode,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_vertical_scrollbar",
         desc_key: "cmd.toggle_vertical_scrollbar_desc",
@@ -971,6 +1305,27 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.start_presentation",
+        desc_key: "cmd.start_presentation_desc",
+        action: || Action::StartPresentation,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.markdown_preview",
+        desc_key: "cmd.markdown_preview_desc",
+        action: || Action::MarkdownPreview,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.preview_image",
+        desc_key: "cmd.preview_image_desc",
+        action: || Action::PreviewImage,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_read_only",
         desc_key: "cmd.toggle_read_only_desc",
@@ -1085,6 +1440,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.insert_snippet",
+        desc_key: "cmd.insert_snippet_desc",
+        action: || Action::InsertSnippet,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.goto_definition",
         desc_key: "cmd.goto_definition_desc",
@@ -1092,6 +1454,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.goto_tag",
+        desc_key: "cmd.goto_tag_desc",
+        action: || Action::GotoTag,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.generate_tags",
+        desc_key: "cmd.generate_tags_desc",
+        action: || Action::GenerateTags,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.show_hover_info",
         desc_key: "cmd.show_hover_info_desc",
@@ -1169,6 +1545,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.navigation_history",
+        desc_key: "cmd.navigation_history_desc",
+        action: || Action::NavigationHistory,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Smart editing
     CommandDef {
         name_key: "cmd.toggle_comment",
@@ -1177,6 +1560,27 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_block_comment",
+        desc_key: "cmd.toggle_block_comment_desc",
+        action: || Action::ToggleBlockComment,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.join_lines",
+        desc_key: "cmd.join_lines_desc",
+        action: || Action::JoinLines,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.split_line",
+        desc_key: "cmd.split_line_desc",
+        action: || Action::SplitLine,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.dedent_selection",
         desc_key: "cmd.dedent_selection_desc",
@@ -1285,6 +1689,62 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_bookmark",
+        desc_key: "cmd.toggle_bookmark_desc",
+        action: || Action::ToggleLineBookmark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.next_bookmark",
+        desc_key: "cmd.next_bookmark_desc",
+        action: || Action::NextLineBookmark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.prev_bookmark",
+        desc_key: "cmd.prev_bookmark_desc",
+        action: || Action::PrevLineBookmark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.label_bookmark",
+        desc_key: "cmd.label_bookmark_desc",
+        action: || Action::LabelLineBookmark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.show_bookmarks",
+        desc_key: "cmd.show_bookmarks_desc",
+        action: || Action::ShowLineBookmarks,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.set_named_mark",
+        desc_key: "cmd.set_named_mark_desc",
+        action: || Action::PromptSetNamedMark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.goto_named_mark",
+        desc_key: "cmd.goto_named_mark_desc",
+        action: || Action::PromptGotoNamedMark,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.list_named_marks",
+        desc_key: "cmd.list_named_marks_desc",
+        action: || Action::ListNamedMarks,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Help
     CommandDef {
         name_key: "cmd.show_manual",
@@ -1307,6 +1767,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.show_messages",
+        desc_key: "cmd.show_messages_desc",
+        action: || Action::ShowMessages,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.show_editor_log",
+        desc_key: "cmd.show_editor_log_desc",
+        action: || Action::ShowEditorLog,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.show_lsp_status",
         desc_key: "cmd.show_lsp_status_desc",
@@ -1314,6 +1788,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.show_jobs",
+        desc_key: "cmd.show_jobs_desc",
+        action: || Action::ShowJobs,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.update_fresh",
         desc_key: "cmd.update_fresh_desc",
@@ -1328,6 +1809,27 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.toggle_telemetry",
+        desc_key: "cmd.toggle_telemetry_desc",
+        action: || Action::ToggleTelemetry,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.toggle_update_check",
+        desc_key: "cmd.toggle_update_check_desc",
+        action: || Action::ToggleUpdateCheck,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.show_telemetry_data",
+        desc_key: "cmd.show_telemetry_data_desc",
+        action: || Action::ShowTelemetryData,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.show_remote_indicator_menu",
         desc_key: "cmd.show_remote_indicator_menu_desc",
@@ -1350,6 +1852,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.reload_config",
+        desc_key: "cmd.reload_config_desc",
+        action: || Action::ReloadConfig,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.redraw_screen",
         desc_key: "cmd.redraw_screen_desc",
@@ -1357,6 +1866,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.dump_screen",
+        desc_key: "cmd.dump_screen_desc",
+        action: || Action::DumpScreen,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     CommandDef {
         name_key: "cmd.toggle_inlay_hints",
         desc_key: "cmd.toggle_inlay_hints_desc",
@@ -1372,6 +1888,13 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.preview_themes",
+        desc_key: "cmd.preview_themes_desc",
+        action: || Action::PreviewThemes,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     // Theme inspection
     CommandDef {
         name_key: "cmd.inspect_theme_at_cursor",
@@ -1428,6 +1951,20 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[],
         custom_contexts: &[],
     },
+    CommandDef {
+        name_key: "cmd.select_calibration_profile",
+        desc_key: "cmd.select_calibration_profile_desc",
+        action: || Action::SelectCalibrationProfile,
+        contexts: &[],
+        custom_contexts: &[],
+    },
+    CommandDef {
+        name_key: "cmd.show_terminal_capabilities",
+        desc_key: "cmd.show_terminal_capabilities_desc",
+        action: || Action::ShowTerminalCapabilities,
+        contexts: &[],
+        custom_contexts: &[],
+    },
     // Terminal commands
     CommandDef {
         name_key: "cmd.open_terminal",
@@ -1539,6 +2076,14 @@ static COMMAND_DEFS: &[CommandDef] = &[
         contexts: &[Normal],
         custom_contexts: &[],
     },
+    // User scripts
+    CommandDef {
+        name_key: "cmd.scripts_reload",
+        desc_key: "cmd.scripts_reload_desc",
+        action: || Action::ScriptsReload,
+        contexts: &[Normal],
+        custom_contexts: &[],
+    },
     // Live Grep (issue #1796) — `cmd.live_grep` itself is registered
     // by the live_grep plugin (palette title is plugin-controlled);
     // these are the editor-side actions that should also be palette-
@@ -1590,6 +2135,7 @@ pub fn get_all_commands() -> Vec<Command> {
             // path; the plugin-driven `terminal_bypass` flag isn't
             // wired into them.
             terminal_bypass: false,
+            context_menu: false,
         })
         .collect()
 }
@@ -0,0 +1,204 @@
+//! Dead-key composition for terminals that pass dead keys through
+//!
+//! Most terminals compose dead keys themselves and send only the finished
+//! character. Some (Windows consoles in particular) also send the dead key
+//! as its spacing accent — `´` then `é`, or `´` then a bare `e` — which
+//! would otherwise land in the buffer as an extra character.
+//!
+//! The composer sits after key translation and before keybinding
+//! resolution:
+//! 1. A spacing accent (`´ ¨ ˆ ˜ ¸ ˇ …`) is held back instead of typed.
+//! 2. The next key resolves it: a base letter composes (`´` + `e` → `é`),
+//!    a character that already carries the accent replaces it, Space (or
+//!    the same accent again) types the accent itself, Esc cancels, and
+//!    anything else types the accent followed by that key.
+//!
+//! ASCII accents (`` ` ^ ~ ' " ``) are never held: they are ordinary
+//! characters in code, and holding them would delay every one typed.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Spacing accents treated as dead keys, with the letters each composes
+/// with and the results, position by position.
+const DEAD_KEYS: &[(char, &str, &str)] = &[
+    // acute
+    ('\u{b4}', "aeiouyAEIOUYcCnNsSzZ", "áéíóúýÁÉÍÓÚÝćĆńŃśŚźŹ"),
+    // diaeresis
+    ('\u{a8}', "aeiouyAEIOUY", "äëïöüÿÄËÏÖÜŸ"),
+    // circumflex
+    ('\u{2c6}', "aeiouAEIOU", "âêîôûÂÊÎÔÛ"),
+    // tilde
+    ('\u{2dc}', "anoANO", "ãñõÃÑÕ"),
+    // cedilla
+    ('\u{b8}', "cCsS", "çÇşŞ"),
+    // caron
+    ('\u{2c7}', "cCsSzZeErRnN", "čČšŠžŽěĚřŘňŇ"),
+    // breve
+    ('\u{2d8}', "aAgG", "ăĂğĞ"),
+    // ring above
+    ('\u{2da}', "aAuU", "åÅůŮ"),
+    // ogonek
+    ('\u{2db}', "aAeE", "ąĄęĘ"),
+    // double acute
+    ('\u{2dd}', "oOuU", "őŐűŰ"),
+    // dot above
+    ('\u{2d9}', "zZeE", "żŻėĖ"),
+    // macron
+    ('\u{af}', "aeiouAEIOU", "āēīōūĀĒĪŌŪ"),
+];
+
+/// Whether `c` is a spacing accent the composer holds back.
+pub fn is_dead_key(c: char) -> bool {
+    DEAD_KEYS.iter().any(|(accent, ..)| *accent == c)
+}
+
+/// `accent` applied to `base`, if that letter takes it.
+pub fn compose(accent: char, base: char) -> Option<char> {
+    let (_, bases, composed) = DEAD_KEYS.iter().find(|(a, ..)| *a == accent)?;
+    let index = bases.chars().position(|b| b == base)?;
+    composed.chars().nth(index)
+}
+
+/// Whether `c` is a letter already composed with `accent`.
+fn carries_accent(accent: char, c: char) -> bool {
+    DEAD_KEYS
+        .iter()
+        .find(|(a, ..)| *a == accent)
+        .is_some_and(|(_, _, composed)| composed.contains(c))
+}
+
+/// A key that types text: a character with at most Shift held.
+fn typed_char(event: &KeyEvent) -> Option<char> {
+    match event.code {
+        KeyCode::Char(c) if (event.modifiers - KeyModifiers::SHIFT).is_empty() => Some(c),
+        _ => None,
+    }
+}
+
+/// Holds a dead key until the key after it decides what it becomes.
+#[derive(Debug, Clone, Default)]
+pub struct DeadKeyComposer {
+    pending: Option<char>,
+}
+
+impl DeadKeyComposer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The accent waiting for the next key, if any.
+    pub fn pending(&self) -> Option<char> {
+        self.pending
+    }
+
+    /// Feed one key press; returns the keys to deliver in its place (none
+    /// while an accent is held).
+    pub fn feed(&mut self, event: KeyEvent) -> Vec<KeyEvent> {
+        let typed = typed_char(&event);
+        let Some(accent) = self.pending.take() else {
+            if let Some(c) = typed.filter(|c| is_dead_key(*c)) {
+                self.pending = Some(c);
+                return Vec::new();
+            }
+            return vec![event];
+        };
+
+        let accent_key = KeyEvent::new(KeyCode::Char(accent), KeyModifiers::NONE);
+        match typed {
+            Some(c) => {
+                if let Some(composed) = compose(accent, c) {
+                    vec![KeyEvent::new(KeyCode::Char(composed), KeyModifiers::NONE)]
+                } else if carries_accent(accent, c) {
+                    // The terminal composed it too; drop the extra accent.
+                    vec![event]
+                } else if c == ' ' || c == accent {
+                    vec![accent_key]
+                } else if is_dead_key(c) {
+                    self.pending = Some(c);
+                    vec![accent_key]
+                } else {
+                    vec![accent_key, event]
+                }
+            }
+            None if event.code == KeyCode::Esc && event.modifiers.is_empty() => Vec::new(),
+            None => vec![accent_key, event],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(c: char) -> KeyEvent {
+        KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+    }
+
+    fn typed(composer: &mut DeadKeyComposer, keys: &[KeyEvent]) -> String {
+        keys.iter()
+            .flat_map(|k| composer.feed(*k))
+            .map(|k| match k.code {
+                KeyCode::Char(c) => c.to_string(),
+                other => format!("<{:?}>", other),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_accent_then_letter_composes() {
+        let mut composer = DeadKeyComposer::new();
+        assert!(composer.feed(key('´')).is_empty());
+        assert_eq!(composer.pending(), Some('´'));
+        assert_eq!(typed(&mut composer, &[key('e')]), "é");
+        assert_eq!(composer.pending(), None);
+    }
+
+    #[test]
+    fn test_shifted_letter_composes() {
+        let mut composer = DeadKeyComposer::new();
+        let shifted_u = KeyEvent::new(KeyCode::Char('U'), KeyModifiers::SHIFT);
+        assert_eq!(typed(&mut composer, &[key('¨'), shifted_u]), "Ü");
+    }
+
+    #[test]
+    fn test_precomposed_letter_drops_the_accent() {
+        let mut composer = DeadKeyComposer::new();
+        assert_eq!(typed(&mut composer, &[key('´'), key('é')]), "é");
+    }
+
+    #[test]
+    fn test_space_or_repeat_types_the_accent() {
+        let mut composer = DeadKeyComposer::new();
+        assert_eq!(typed(&mut composer, &[key('´'), key(' ')]), "´");
+        assert_eq!(typed(&mut composer, &[key('´'), key('´')]), "´");
+    }
+
+    #[test]
+    fn test_unrelated_key_types_both() {
+        let mut composer = DeadKeyComposer::new();
+        assert_eq!(typed(&mut composer, &[key('´'), key('x')]), "´x");
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        assert_eq!(typed(&mut composer, &[key('ˇ'), enter]), "ˇ<Enter>");
+    }
+
+    #[test]
+    fn test_escape_cancels() {
+        let mut composer = DeadKeyComposer::new();
+        let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE);
+        assert_eq!(typed(&mut composer, &[key('´'), esc, key('e')]), "e");
+    }
+
+    #[test]
+    fn test_ascii_accents_pass_through() {
+        let mut composer = DeadKeyComposer::new();
+        assert_eq!(typed(&mut composer, &[key('^'), key('e')]), "^e");
+        assert_eq!(typed(&mut composer, &[key('~'), key('n')]), "~n");
+    }
+
+    #[test]
+    fn test_ctrl_chord_is_not_a_dead_key() {
+        let mut composer = DeadKeyComposer::new();
+        let ctrl = KeyEvent::new(KeyCode::Char('´'), KeyModifiers::CONTROL);
+        assert_eq!(composer.feed(ctrl), vec![ctrl]);
+    }
+}
@@ -143,6 +143,10 @@ pub enum DeferredAction {
     PromptHistoryNext,
     /// Preview theme from the current prompt input (for SelectTheme)
     PreviewThemeFromPrompt,
+    /// Preview the selected buffer in the other split (for SwitchBuffer)
+    PreviewBufferFromPrompt,
+    /// Close the selected buffer without leaving the picker (for SwitchBuffer)
+    CloseBufferFromPicker,
     /// Notify plugin that prompt selection changed (for live preview in Live Grep, etc.)
     PromptSelectionChanged {
         selected_index: usize,
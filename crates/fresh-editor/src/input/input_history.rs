@@ -384,7 +384,11 @@ impl InputHistory {
 /// Get the data directory for Fresh editor state
 /// Returns $XDG_DATA_HOME/fresh or ~/.local/share/fresh on Linux
 /// Returns ~/Library/Application Support/fresh on macOS
+/// Returns `<portable home>/data` in portable mode
 pub fn get_data_dir() -> std::io::Result<std::path::PathBuf> {
+    if let Some(root) = crate::config_io::portable_home() {
+        return Ok(crate::config_io::DirectoryContext::for_portable(root).data_dir);
+    }
     let data_dir = dirs::data_dir().ok_or_else(|| {
         std::io::Error::new(
             std::io::ErrorKind::NotFound,
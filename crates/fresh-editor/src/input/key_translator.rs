@@ -9,11 +9,15 @@
 //! 3. KeybindingResolver.resolve(normalized) → Action
 //!
 //! This keeps calibration separate from keymap customization.
+//!
+//! Calibrations can be kept per terminal: each profile under
+//! `key_calibrations/` lists the terminals it is for, and the one listing the
+//! terminal Fresh runs in is loaded instead of `key_calibration.json`.
 
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 /// Key translation table that normalizes terminal input
 ///
@@ -185,10 +189,34 @@ struct CalibrationFile {
     /// Format description
     #[serde(rename = "_format")]
     format: String,
+    /// Terminals this profile is loaded for (see [`current_terminal`])
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    terminals: Vec<String>,
     /// Translation mappings
     translations: Vec<TranslationEntry>,
 }
 
+/// A saved calibration profile, as listed by [`KeyTranslator::list_profiles`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CalibrationProfile {
+    /// File stem under `key_calibrations/`
+    pub name: String,
+    /// Terminals the profile is loaded for
+    pub terminals: Vec<String>,
+    /// Number of key translations in it
+    pub translations: usize,
+}
+
+/// Identify the terminal Fresh runs in: `$TERM_PROGRAM` when the terminal
+/// sets it (iTerm.app, WezTerm, vscode, …), otherwise `$TERM`.
+pub fn current_terminal() -> Option<String> {
+    ["TERM_PROGRAM", "TERM"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .map(|value| value.trim().to_string())
+        .find(|value| !value.is_empty() && value != "dumb")
+}
+
 /// A single translation entry in the calibration file
 #[derive(Debug, Serialize, Deserialize)]
 struct TranslationEntry {
@@ -262,14 +290,7 @@ impl KeyTranslator {
             return Ok(Self::new());
         }
 
-        let content = std::fs::read_to_string(path)?;
-        let file: CalibrationFile = serde_json::from_str(&content).map_err(|e| {
-            std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                format!("Invalid calibration file: {}", e),
-            )
-        })?;
-
+        let file = Self::read_file(path)?;
         let mut translator = Self::new();
         for entry in file.translations {
             translator.translations.insert(entry.raw, entry.expected);
@@ -286,6 +307,20 @@ impl KeyTranslator {
 
     /// Save translations to a JSON file
     pub fn save_to_file(&self, path: &Path) -> Result<(), std::io::Error> {
+        self.write_file(path, Vec::new())
+    }
+
+    fn read_file(path: &Path) -> Result<CalibrationFile, std::io::Error> {
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Invalid calibration file: {}", e),
+            )
+        })
+    }
+
+    fn write_file(&self, path: &Path, terminals: Vec<String>) -> Result<(), std::io::Error> {
         // Ensure parent directory exists
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
@@ -303,6 +338,7 @@ impl KeyTranslator {
         let file = CalibrationFile {
             comment: "Generated by 'Calibrate Input Keys' wizard".to_string(),
             format: "raw_key → expected_key".to_string(),
+            terminals,
             translations: entries,
         };
 
@@ -323,10 +359,18 @@ impl KeyTranslator {
         config_dir.join("key_calibration.json")
     }
 
-    /// Load from the config directory.
+    /// Load from the config directory: the profile for the current
+    /// terminal if there is one, `key_calibration.json` otherwise.
     pub fn load_from_config_dir(config_dir: &std::path::Path) -> Result<Self, std::io::Error> {
-        let path = Self::calibration_path(config_dir);
-        Self::load_from_file(&path)
+        let profile = current_terminal().and_then(|terminal| {
+            Self::list_profiles(config_dir)
+                .into_iter()
+                .find(|p| p.terminals.contains(&terminal))
+        });
+        match profile {
+            Some(profile) => Self::load_from_file(&Self::profile_path(config_dir, &profile.name)),
+            None => Self::load_from_file(&Self::calibration_path(config_dir)),
+        }
     }
 
     /// Save to the config directory.
@@ -334,6 +378,112 @@ impl KeyTranslator {
         let path = Self::calibration_path(config_dir);
         self.save_to_file(&path)
     }
+
+    /// Directory holding the per-terminal calibration profiles.
+    pub fn profiles_dir(config_dir: &Path) -> PathBuf {
+        config_dir.join("key_calibrations")
+    }
+
+    /// Path of the named profile.
+    pub fn profile_path(config_dir: &Path, name: &str) -> PathBuf {
+        Self::profiles_dir(config_dir).join(format!("{}.json", name))
+    }
+
+    /// Profile name for a terminal, safe to use as a file name.
+    pub fn profile_name_for(terminal: &str) -> String {
+        terminal
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || "-_.".contains(c) {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect()
+    }
+
+    /// All saved profiles, sorted by name. Unreadable files are skipped.
+    pub fn list_profiles(config_dir: &Path) -> Vec<CalibrationProfile> {
+        let Ok(entries) = std::fs::read_dir(Self::profiles_dir(config_dir)) else {
+            return Vec::new();
+        };
+        let mut profiles: Vec<CalibrationProfile> = entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if path.extension()? != "json" {
+                    return None;
+                }
+                let name = path.file_stem()?.to_str()?.to_string();
+                match Self::read_file(&path) {
+                    Ok(file) => Some(CalibrationProfile {
+                        name,
+                        terminals: file.terminals,
+                        translations: file.translations.len(),
+                    }),
+                    Err(e) => {
+                        tracing::warn!("Skipping calibration profile {}: {}", path.display(), e);
+                        None
+                    }
+                }
+            })
+            .collect();
+        profiles.sort_by(|a, b| a.name.cmp(&b.name));
+        profiles
+    }
+
+    /// Save as the named profile and make it the one loaded for `terminal`.
+    pub fn save_profile(
+        &self,
+        config_dir: &Path,
+        name: &str,
+        terminal: &str,
+    ) -> Result<(), std::io::Error> {
+        let path = Self::profile_path(config_dir, name);
+        let mut terminals = if path.exists() {
+            Self::read_file(&path)?.terminals
+        } else {
+            Vec::new()
+        };
+        if !terminals.iter().any(|t| t == terminal) {
+            terminals.push(terminal.to_string());
+        }
+        self.write_file(&path, terminals)?;
+        Self::release_terminal(config_dir, terminal, name)
+    }
+
+    /// Make the named profile the one loaded for `terminal`, and load it.
+    pub fn assign_profile(
+        config_dir: &Path,
+        name: &str,
+        terminal: &str,
+    ) -> Result<Self, std::io::Error> {
+        let path = Self::profile_path(config_dir, name);
+        let translator = Self::load_from_file(&path)?;
+        translator.save_profile(config_dir, name, terminal)?;
+        Ok(translator)
+    }
+
+    /// Drop `terminal` from every profile other than `keep`.
+    fn release_terminal(
+        config_dir: &Path,
+        terminal: &str,
+        keep: &str,
+    ) -> Result<(), std::io::Error> {
+        for profile in Self::list_profiles(config_dir) {
+            if profile.name == keep || !profile.terminals.iter().any(|t| t == terminal) {
+                continue;
+            }
+            let path = Self::profile_path(config_dir, &profile.name);
+            let terminals = profile
+                .terminals
+                .into_iter()
+                .filter(|t| t != terminal)
+                .collect();
+            Self::load_from_file(&path)?.write_file(&path, terminals)?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -419,4 +569,52 @@ mod tests {
         assert_eq!(original.code, restored.code);
         assert_eq!(original.modifiers, restored.modifiers);
     }
+
+    #[test]
+    fn test_profiles_are_assigned_per_terminal() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_dir = dir.path();
+
+        let mut translator = KeyTranslator::new();
+        translator.add_translation(
+            KeyEvent::new(KeyCode::Char('\x7f'), KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE),
+        );
+        translator
+            .save_profile(config_dir, "laptop", "xterm-kitty")
+            .unwrap();
+        KeyTranslator::new()
+            .save_profile(config_dir, "plain", "WezTerm")
+            .unwrap();
+
+        let profiles = KeyTranslator::list_profiles(config_dir);
+        assert_eq!(
+            profiles,
+            vec![
+                CalibrationProfile {
+                    name: "laptop".to_string(),
+                    terminals: vec!["xterm-kitty".to_string()],
+                    translations: 1,
+                },
+                CalibrationProfile {
+                    name: "plain".to_string(),
+                    terminals: vec!["WezTerm".to_string()],
+                    translations: 0,
+                },
+            ]
+        );
+
+        // A terminal belongs to one profile at a time
+        let loaded = KeyTranslator::assign_profile(config_dir, "laptop", "WezTerm").unwrap();
+        assert_eq!(loaded.len(), 1);
+        let profiles = KeyTranslator::list_profiles(config_dir);
+        assert_eq!(profiles[0].terminals, vec!["xterm-kitty", "WezTerm"]);
+        assert!(profiles[1].terminals.is_empty());
+    }
+
+    #[test]
+    fn test_profile_name_for_terminal() {
+        assert_eq!(KeyTranslator::profile_name_for("iTerm.app"), "iTerm.app");
+        assert_eq!(KeyTranslator::profile_name_for("my term/1"), "my-term-1");
+    }
 }
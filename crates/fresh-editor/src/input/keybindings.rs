@@ -1,4 +1,5 @@
 use crate::config::Config;
+use crate::input::when_clause::{WhenClause, WhenState};
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use rust_i18n::t;
 use std::collections::HashMap;
@@ -103,6 +104,14 @@ pub fn format_keybinding(keycode: &KeyCode, modifiers: &KeyModifiers) -> String
 
     let use_plus = !use_macos_symbols();
 
+    // Hyper only reaches the editor through the kitty keyboard protocol and
+    // has no native symbol
+    if modifiers.contains(KeyModifiers::HYPER) {
+        result.push_str("Hyper");
+        if use_plus {
+            result.push('+');
+        }
+    }
     if modifiers.contains(KeyModifiers::SUPER) {
         result.push_str(super_label);
         if use_plus {
@@ -142,6 +151,7 @@ pub fn format_keybinding(keycode: &KeyCode, modifiers: &KeyModifiers) -> String
         KeyCode::End => result.push_str("End"),
         KeyCode::PageUp => result.push_str("PgUp"),
         KeyCode::PageDown => result.push_str("PgDn"),
+        KeyCode::Menu => result.push_str("Menu"),
         KeyCode::Char(' ') => result.push_str("Space"),
         KeyCode::Char(c) => result.push_str(&c.to_uppercase().to_string()),
         KeyCode::F(n) => result.push_str(&format!("F{}", n)),
@@ -367,6 +377,7 @@ pub enum Action {
     InsertChar(char),
     InsertNewline,
     InsertTab,
+    SmartTab,
 
     // Basic movement
     MoveLeft,
@@ -413,6 +424,8 @@ pub enum Action {
     SelectWord,
     SelectLine,
     ExpandSelection,
+    ExpandSelectionToNode,
+    ShrinkSelection,
 
     // Block/rectangular selection (column-wise)
     BlockSelectLeft,
@@ -435,6 +448,10 @@ pub enum Action {
 
     // View
     Recenter,
+    /// Scroll so the cursor's line is at the top of the view (vim `zt`)
+    ScrollCursorToTop,
+    /// Scroll so the cursor's line is at the bottom of the view (vim `zb`)
+    ScrollCursorToBottom,
 
     // Selection
     SetMark,
@@ -446,11 +463,27 @@ pub enum Action {
     CopyWithTheme(String),
     Cut,
     Paste,
+    /// Pick a recent copy from the clipboard history and paste it.
+    PasteFromHistory,
+    /// Replace the text just pasted with the next older clipboard history
+    /// entry (Emacs `yank-pop`).
+    CyclePasteHistory,
+    /// Pin or unpin the entry selected in the clipboard history picker.
+    ClipboardHistoryTogglePin,
+    /// Paste the entry selected in the clipboard history picker,
+    /// re-indented to the cursor's line.
+    ClipboardHistoryPasteIndented,
     /// Copy the absolute filesystem path of the active buffer's file to the clipboard.
     CopyFilePath,
     /// Copy the active buffer's file path relative to the workspace root, falling
     /// back to the absolute path if the file lives outside the workspace.
     CopyRelativeFilePath,
+    /// Copy the active buffer's workspace-relative path and the cursor's line
+    /// number, joined by `clipboard.path_line_separator`.
+    CopyFilePathWithLine,
+    /// Serve the active buffer read-only over HTTP.
+    ServeBuffer,
+    StopServingBuffer,
 
     // Vi-style yank (copy without selection, then restore cursor)
     YankWordForward,
@@ -473,8 +506,15 @@ pub enum Action {
     Open,
     SwitchProject,
     New,
+    /// Create a scratch buffer after picking its language.
+    NewScratchBuffer,
     Close,
     CloseTab,
+    CloseOtherTabs,
+    CloseTabsToRight,
+    CloseSavedTabs,
+    ReopenClosedTab,
+    KeepPreviewTab,
     Quit,
     ForceQuit,
     Detach,
@@ -483,6 +523,9 @@ pub enum Action {
     FormatBuffer,
     TrimTrailingWhitespace,
     EnsureFinalNewline,
+    RevertLastTransform,
+    FileHistory,
+    FileHistoryShowDiff,
 
     // Navigation
     GotoLine,
@@ -495,7 +538,11 @@ pub enum Action {
     SmartHome,
     DedentSelection,
     ToggleComment,
+    ToggleBlockComment,
+    JoinLines,
+    SplitLine,
     DabbrevExpand,
+    InsertSnippet, // Pick a user snippet for the buffer's language and insert it
     ToggleFold,
 
     // Bookmarks
@@ -503,12 +550,25 @@ pub enum Action {
     JumpToBookmark(char),
     ClearBookmark(char),
     ListBookmarks,
+    ToggleLineBookmark,
+    NextLineBookmark,
+    PrevLineBookmark,
+    LabelLineBookmark,
+    ShowLineBookmarks,
+
+    // Named marks (a-z per buffer, A-Z global)
+    SetNamedMark(char),
+    GotoNamedMark(char),
+    PromptSetNamedMark,
+    PromptGotoNamedMark,
+    ListNamedMarks,
 
     // Search options
     ToggleSearchCaseSensitive,
     ToggleSearchWholeWord,
     ToggleSearchRegex,
     ToggleSearchConfirmEach,
+    ToggleSearchMultiline,
 
     // Macros
     StartMacroRecording,
@@ -542,13 +602,29 @@ pub enum Action {
     ShowKeyboardShortcuts,
     ShowWarnings,
     ShowStatusLog,
+    /// Open the *Messages* panel of past status messages and errors.
+    ShowMessages,
+    /// Step the *Messages* panel's severity filter.
+    CycleMessageFilter,
+    /// Open the editor's own tracing log in the *Editor Log* panel.
+    ShowEditorLog,
+    /// Filter the *Editor Log* panel by module.
+    FilterEditorLog,
     ShowLspStatus,
     ShowRemoteIndicatorMenu,
     ShowReadOnlyMenu,
+    /// List running plugin jobs, offering to cancel them.
+    ShowJobs,
     /// Offer/confirm an in-editor update when a new version is available.
     UpdateFresh,
     /// Open the background self-update log (always the local copy).
     OpenUpdateLog,
+    /// Turn the daily anonymous telemetry ping on or off (persisted).
+    ToggleTelemetry,
+    /// Turn the startup update check on or off (persisted).
+    ToggleUpdateCheck,
+    /// Show exactly what the telemetry ping would send.
+    ShowTelemetryData,
     ClearWarnings,
     CommandPalette, // Alias for QuickOpen — kept for keymap/plugin compatibility
     /// Quick Open - unified prompt with prefix-based provider routing
@@ -579,8 +655,12 @@ pub enum Action {
     ToggleReadOnly,
     TogglePageView,
     SetPageWidth,
+    StartPresentation,
+    MarkdownPreview,
+    PreviewImage,
     InspectThemeAtCursor,
     SelectTheme,
+    PreviewThemes,
     SelectKeybindingMap,
     SelectCursorStyle,
     SelectLocale,
@@ -590,6 +670,7 @@ pub enum Action {
     PrevBuffer,
     SwitchToPreviousTab,
     SwitchToTabByName,
+    SwitchBuffer,
 
     // Tab scrolling
     ScrollTabsLeft,
@@ -598,6 +679,7 @@ pub enum Action {
     // Position history navigation
     NavigateBack,
     NavigateForward,
+    NavigationHistory,
 
     // Split view operations
     SplitHorizontal,
@@ -670,6 +752,9 @@ pub enum Action {
     // Completion popup actions (override generic Popup keys for PopupKind::Completion)
     CompletionAccept,
     CompletionDismiss,
+    /// Scroll the documentation panel beside the completion popup.
+    CompletionDocsScrollDown,
+    CompletionDocsScrollUp,
 
     // File explorer operations
     ToggleFileExplorer,
@@ -683,6 +768,8 @@ pub enum Action {
     ToggleStatusBar,
     // Prompt line visibility
     TogglePromptLine,
+    // Distraction-free layout: no chrome, centered soft-wrapped text
+    ToggleZenMode,
     // Scrollbar visibility
     ToggleVerticalScrollbar,
     ToggleHorizontalScrollbar,
@@ -734,6 +821,10 @@ pub enum Action {
     ToggleInlayHints,
     ToggleMouseHover,
 
+    // Tags (ctags)
+    GotoTag,      // Search the project's tags file and jump to a symbol
+    GenerateTags, // Run `tags_command` to rebuild the tags file
+
     // View toggles
     ToggleLineNumbers,
     /// Toggle line-number visibility for the current buffer only (per-buffer
@@ -751,14 +842,20 @@ pub enum Action {
     ToggleScrollSync,
     ToggleMouseCapture,
     ToggleDebugHighlights, // Debug mode: show highlight/overlay byte ranges
+    TogglePerfHud,         // Frame timing and resource counters overlay
     SetBackground,
     SetBackgroundBlend,
 
     // Buffer settings (per-buffer overrides)
     SetTabSize,
     SetLineEnding,
+    ConvertLineEndingsToLf,
+    ConvertLineEndingsToCrlf,
+    ConvertIndentationToSpaces,
+    ConvertIndentationToTabs,
     SetEncoding,
     ReloadWithEncoding,
+    SaveWithEncoding,
     SetLanguage,
     ToggleIndentationStyle,
     ToggleTabIndicators,
@@ -769,6 +866,8 @@ pub enum Action {
 
     // Config operations
     DumpConfig,
+    ReloadConfig,
+    DumpScreen,
 
     // Force a full terminal clear + redraw (fixes display corruption from external output)
     RedrawScreen,
@@ -793,6 +892,9 @@ pub enum Action {
     MenuDown,         // Navigate to next item in menu
     MenuExecute,      // Execute selected menu item (Enter)
     MenuOpen(String), // Open a specific menu by name (e.g., "File", "Edit")
+    /// Open the editor content context menu at the cursor (the keyboard
+    /// counterpart of right-clicking in the buffer).
+    OpenContextMenu,
 
     // Keybinding map switching
     SwitchKeybindingMap(String), // Switch to a named keybinding map (e.g., "default", "emacs", "vscode")
@@ -832,10 +934,43 @@ pub enum Action {
     ToUpperCase, // Convert selection to uppercase
     ToLowerCase, // Convert selection to lowercase
     ToggleCase,  // Toggle case of character under cursor (vim ~)
-    SortLines,   // Sort selected lines alphabetically
+    ToTitleCase, // Capitalize each word of the selection
+    ToCamelCase, // Convert selection to camelCase
+    ToSnakeCase, // Convert selection to snake_case
+    ToKebabCase, // Convert selection to kebab-case
+
+    // Line transforms on the selection
+    SortLines,           // Sort selected lines alphabetically
+    SortLinesDescending, // Sort selected lines in reverse alphabetical order
+    SortLinesUnique,     // Sort selected lines and drop duplicates
+    ReverseLines,        // Reverse the order of the selected lines
+    NumberLines,         // Number the selected lines, or insert a number at each cursor
+
+    // Escape conversions on the selection
+    EscapeUnicode,      // Non-ASCII characters -> \u{XXXX} escapes
+    UnescapeUnicode,    // \u{XXXX} / \uXXXX escapes -> characters
+    EncodeHtmlEntities, // Special and non-ASCII characters -> HTML entities
+    DecodeHtmlEntities, // HTML entities -> characters
+    UrlEncode,          // Percent-encode the selection
+    UrlDecode,          // Decode %XX sequences in the selection
+    DescribeChar,       // Show code point, name, UTF-8 bytes and category of the char under cursor
+
+    // Calculator
+    EvaluateSelection, // Show the value of the selected expression in a popup
+    EvaluateSelectionReplace, // Replace the selected expression with its value
+    EvaluateSelectionAppend, // Append " = <value>" after the selected expression
+
+    // Narrowing
+    NarrowToSelection, // Edit the selected lines on their own in a narrowed buffer
+    Widen,             // Merge a narrowed buffer back into its file
+
+    // Encoding repair
+    FixEncodingArtifacts, // Repair mojibake (UTF-8 read as Windows-1252) in the selection
 
     // Input calibration
-    CalibrateInput, // Open the input calibration wizard
+    CalibrateInput,           // Open the input calibration wizard
+    SelectCalibrationProfile, // Pick the calibration profile for this terminal
+    ShowTerminalCapabilities, // Show what the terminal supports
 
     // Event debug
     EventDebug, // Open the event debug dialog
@@ -850,9 +985,10 @@ pub enum Action {
     LoadPluginFromBuffer, // Load current buffer as a plugin
 
     // User init.ts (design M4, M5, M6)
-    InitReload, // Reload ~/.config/fresh/init.ts via the existing plugin pipeline
-    InitEdit,   // Open ~/.config/fresh/init.ts (creates from template if missing)
-    InitCheck,  // Syntax-check ~/.config/fresh/init.ts via oxc
+    InitReload,    // Reload ~/.config/fresh/init.ts via the existing plugin pipeline
+    InitEdit,      // Open ~/.config/fresh/init.ts (creates from template if missing)
+    InitCheck,     // Syntax-check ~/.config/fresh/init.ts via oxc
+    ScriptsReload, // Reload the .rhai scripts in ~/.config/fresh/scripts/
 
     // Composite buffer (side-by-side diff) hunk navigation
     CompositeNextHunk, // Navigate to the next hunk in a composite diff view
@@ -944,6 +1080,7 @@ impl Action {
         simple {
             "insert_newline" => InsertNewline,
             "insert_tab" => InsertTab,
+            "smart_tab" => SmartTab,
 
             "move_left" => MoveLeft,
             "move_right" => MoveRight,
@@ -988,6 +1125,8 @@ impl Action {
             "select_word" => SelectWord,
             "select_line" => SelectLine,
             "expand_selection" => ExpandSelection,
+            "expand_selection_to_node" => ExpandSelectionToNode,
+            "shrink_selection" => ShrinkSelection,
 
             "block_select_left" => BlockSelectLeft,
             "block_select_right" => BlockSelectRight,
@@ -1006,6 +1145,8 @@ impl Action {
             "open_line" => OpenLine,
             "duplicate_line" => DuplicateLine,
             "recenter" => Recenter,
+            "scroll_cursor_to_top" => ScrollCursorToTop,
+            "scroll_cursor_to_bottom" => ScrollCursorToBottom,
             "set_mark" => SetMark,
             "cancel_mark" => CancelMark,
             "clear_mark" => ClearMark,
@@ -1013,8 +1154,15 @@ impl Action {
             "copy" => Copy,
             "cut" => Cut,
             "paste" => Paste,
+            "paste_from_history" => PasteFromHistory,
+            "cycle_paste_history" => CyclePasteHistory,
+            "clipboard_history_toggle_pin" => ClipboardHistoryTogglePin,
+            "clipboard_history_paste_indented" => ClipboardHistoryPasteIndented,
             "copy_file_path" => CopyFilePath,
             "copy_relative_file_path" => CopyRelativeFilePath,
+            "copy_file_path_with_line" => CopyFilePathWithLine,
+            "serve_buffer" => ServeBuffer,
+            "stop_serving_buffer" => StopServingBuffer,
 
             "yank_word_forward" => YankWordForward,
             "yank_word_backward" => YankWordBackward,
@@ -1034,8 +1182,14 @@ impl Action {
             "open" => Open,
             "switch_project" => SwitchProject,
             "new" => New,
+            "new_scratch_buffer" => NewScratchBuffer,
             "close" => Close,
             "close_tab" => CloseTab,
+            "close_other_tabs" => CloseOtherTabs,
+            "close_tabs_to_right" => CloseTabsToRight,
+            "close_saved_tabs" => CloseSavedTabs,
+            "reopen_closed_tab" => ReopenClosedTab,
+            "keep_preview_tab" => KeepPreviewTab,
             "quit" => Quit,
             "force_quit" => ForceQuit,
             "detach" => Detach,
@@ -1044,6 +1198,9 @@ impl Action {
             "format_buffer" => FormatBuffer,
             "trim_trailing_whitespace" => TrimTrailingWhitespace,
             "ensure_final_newline" => EnsureFinalNewline,
+            "revert_last_transform" => RevertLastTransform,
+            "file_history" => FileHistory,
+            "file_history_show_diff" => FileHistoryShowDiff,
             "goto_line" => GotoLine,
             "scan_line_index" => ScanLineIndex,
             "goto_matching_bracket" => GoToMatchingBracket,
@@ -1053,15 +1210,28 @@ impl Action {
             "smart_home" => SmartHome,
             "dedent_selection" => DedentSelection,
             "toggle_comment" => ToggleComment,
+            "toggle_block_comment" => ToggleBlockComment,
+            "join_lines" => JoinLines,
+            "split_line" => SplitLine,
             "dabbrev_expand" => DabbrevExpand,
+            "insert_snippet" => InsertSnippet,
             "toggle_fold" => ToggleFold,
 
             "list_bookmarks" => ListBookmarks,
+            "toggle_bookmark" => ToggleLineBookmark,
+            "next_bookmark" => NextLineBookmark,
+            "prev_bookmark" => PrevLineBookmark,
+            "label_bookmark" => LabelLineBookmark,
+            "show_bookmarks" => ShowLineBookmarks,
+            "prompt_set_named_mark" => PromptSetNamedMark,
+            "prompt_goto_named_mark" => PromptGotoNamedMark,
+            "list_named_marks" => ListNamedMarks,
 
             "toggle_search_case_sensitive" => ToggleSearchCaseSensitive,
             "toggle_search_whole_word" => ToggleSearchWholeWord,
             "toggle_search_regex" => ToggleSearchRegex,
             "toggle_search_confirm_each" => ToggleSearchConfirmEach,
+            "toggle_search_multiline" => ToggleSearchMultiline,
 
             "start_macro_recording" => StartMacroRecording,
             "stop_macro_recording" => StopMacroRecording,
@@ -1084,11 +1254,19 @@ impl Action {
             "keyboard_shortcuts" => ShowKeyboardShortcuts,
             "show_warnings" => ShowWarnings,
             "show_status_log" => ShowStatusLog,
+            "show_messages" => ShowMessages,
+            "cycle_message_filter" => CycleMessageFilter,
+            "show_editor_log" => ShowEditorLog,
+            "filter_editor_log" => FilterEditorLog,
             "show_lsp_status" => ShowLspStatus,
             "show_remote_indicator_menu" => ShowRemoteIndicatorMenu,
             "show_read_only_menu" => ShowReadOnlyMenu,
+            "show_jobs" => ShowJobs,
             "update_fresh" => UpdateFresh,
             "open_update_log" => OpenUpdateLog,
+            "toggle_telemetry" => ToggleTelemetry,
+            "toggle_update_check" => ToggleUpdateCheck,
+            "show_telemetry_data" => ShowTelemetryData,
             "clear_warnings" => ClearWarnings,
             "command_palette" => CommandPalette,
             "quick_open" => QuickOpen,
@@ -1105,16 +1283,21 @@ impl Action {
             "toggle_read_only" => ToggleReadOnly,
             "toggle_page_view" => TogglePageView,
             "set_page_width" => SetPageWidth,
+            "start_presentation" => StartPresentation,
+            "markdown_preview" => MarkdownPreview,
+            "preview_image" => PreviewImage,
 
             "next_buffer" => NextBuffer,
             "prev_buffer" => PrevBuffer,
             "switch_to_previous_tab" => SwitchToPreviousTab,
             "switch_to_tab_by_name" => SwitchToTabByName,
+            "switch_buffer" => SwitchBuffer,
             "scroll_tabs_left" => ScrollTabsLeft,
             "scroll_tabs_right" => ScrollTabsRight,
 
             "navigate_back" => NavigateBack,
             "navigate_forward" => NavigateForward,
+            "navigation_history" => NavigationHistory,
 
             "split_horizontal" => SplitHorizontal,
             "split_vertical" => SplitVertical,
@@ -1171,6 +1354,8 @@ impl Action {
 
             "completion_accept" => CompletionAccept,
             "completion_dismiss" => CompletionDismiss,
+            "completion_docs_scroll_down" => CompletionDocsScrollDown,
+            "completion_docs_scroll_up" => CompletionDocsScrollUp,
 
             "toggle_file_explorer" => ToggleFileExplorer,
             "toggle_file_explorer_side" => ToggleFileExplorerSide,
@@ -1178,6 +1363,7 @@ impl Action {
             "toggle_tab_bar" => ToggleTabBar,
             "toggle_status_bar" => ToggleStatusBar,
             "toggle_prompt_line" => TogglePromptLine,
+            "toggle_zen_mode" => ToggleZenMode,
             "toggle_vertical_scrollbar" => ToggleVerticalScrollbar,
             "toggle_horizontal_scrollbar" => ToggleHorizontalScrollbar,
             "focus_file_explorer" => FocusFileExplorer,
@@ -1223,6 +1409,8 @@ impl Action {
             "lsp_toggle_for_buffer" => LspToggleForBuffer,
             "toggle_inlay_hints" => ToggleInlayHints,
             "toggle_mouse_hover" => ToggleMouseHover,
+            "goto_tag" => GotoTag,
+            "generate_tags" => GenerateTags,
 
             "toggle_line_numbers" => ToggleLineNumbers,
             "toggle_line_numbers_current_buffer" => ToggleLineNumbersCurrentBuffer,
@@ -1232,18 +1420,25 @@ impl Action {
             "toggle_scroll_sync" => ToggleScrollSync,
             "toggle_mouse_capture" => ToggleMouseCapture,
             "toggle_debug_highlights" => ToggleDebugHighlights,
+            "toggle_perf_hud" => TogglePerfHud,
             "set_background" => SetBackground,
             "set_background_blend" => SetBackgroundBlend,
             "inspect_theme_at_cursor" => InspectThemeAtCursor,
             "select_theme" => SelectTheme,
+            "preview_themes" => PreviewThemes,
             "select_keybinding_map" => SelectKeybindingMap,
             "select_cursor_style" => SelectCursorStyle,
             "select_locale" => SelectLocale,
 
             "set_tab_size" => SetTabSize,
             "set_line_ending" => SetLineEnding,
+            "convert_line_endings_to_lf" => ConvertLineEndingsToLf,
+            "convert_line_endings_to_crlf" => ConvertLineEndingsToCrlf,
+            "convert_indentation_to_spaces" => ConvertIndentationToSpaces,
+            "convert_indentation_to_tabs" => ConvertIndentationToTabs,
             "set_encoding" => SetEncoding,
             "reload_with_encoding" => ReloadWithEncoding,
+            "save_with_encoding" => SaveWithEncoding,
             "set_language" => SetLanguage,
             "toggle_indentation_style" => ToggleIndentationStyle,
             "toggle_tab_indicators" => ToggleTabIndicators,
@@ -1253,6 +1448,8 @@ impl Action {
             "remove_ruler" => RemoveRuler,
 
             "dump_config" => DumpConfig,
+            "reload_config" => ReloadConfig,
+            "dump_screen" => DumpScreen,
             "redraw_screen" => RedrawScreen,
 
             "search" => Search,
@@ -1272,6 +1469,7 @@ impl Action {
             "menu_up" => MenuUp,
             "menu_down" => MenuDown,
             "menu_execute" => MenuExecute,
+            "open_context_menu" => OpenContextMenu,
 
             "open_terminal" => OpenTerminal,
             "open_terminal_right" => OpenTerminalRight,
@@ -1289,15 +1487,40 @@ impl Action {
             "to_upper_case" => ToUpperCase,
             "to_lower_case" => ToLowerCase,
             "toggle_case" => ToggleCase,
+            "to_title_case" => ToTitleCase,
+            "to_camel_case" => ToCamelCase,
+            "to_snake_case" => ToSnakeCase,
+            "to_kebab_case" => ToKebabCase,
             "sort_lines" => SortLines,
+            "sort_lines_descending" => SortLinesDescending,
+            "sort_lines_unique" => SortLinesUnique,
+            "reverse_lines" => ReverseLines,
+            "number_lines" => NumberLines,
+
+            "escape_unicode" => EscapeUnicode,
+            "unescape_unicode" => UnescapeUnicode,
+            "encode_html_entities" => EncodeHtmlEntities,
+            "decode_html_entities" => DecodeHtmlEntities,
+            "url_encode" => UrlEncode,
+            "url_decode" => UrlDecode,
+            "describe_char" => DescribeChar,
+            "evaluate_selection" => EvaluateSelection,
+            "evaluate_selection_replace" => EvaluateSelectionReplace,
+            "evaluate_selection_append" => EvaluateSelectionAppend,
+            "narrow_to_selection" => NarrowToSelection,
+            "widen" => Widen,
+            "fix_encoding_artifacts" => FixEncodingArtifacts,
 
             "calibrate_input" => CalibrateInput,
+            "select_calibration_profile" => SelectCalibrationProfile,
+            "show_terminal_capabilities" => ShowTerminalCapabilities,
             "event_debug" => EventDebug,
             "suspend_process" => SuspendProcess,
             "load_plugin_from_buffer" => LoadPluginFromBuffer,
             "init_reload" => InitReload,
             "init_edit" => InitEdit,
             "init_check" => InitCheck,
+            "scripts_reload" => ScriptsReload,
             "open_keybinding_editor" => OpenKeybindingEditor,
 
             "composite_next_hunk" => CompositeNextHunk,
@@ -1336,6 +1559,8 @@ impl Action {
             "set_bookmark" => SetBookmark,
             "jump_to_bookmark" => JumpToBookmark,
             "clear_bookmark" => ClearBookmark,
+            "set_named_mark" => SetNamedMark,
+            "goto_named_mark" => GotoNamedMark,
             "play_macro" => PlayMacro,
             "toggle_macro_recording" => ToggleMacroRecording,
             "show_macro" => ShowMacro,
@@ -1420,6 +1645,8 @@ impl Action {
             | Self::SetBookmark(c)
             | Self::JumpToBookmark(c)
             | Self::ClearBookmark(c)
+            | Self::SetNamedMark(c)
+            | Self::GotoNamedMark(c)
             | Self::PlayMacro(c)
             | Self::ToggleMacroRecording(c)
             | Self::ShowMacro(c) => {
@@ -1513,6 +1740,8 @@ impl Action {
                 | Action::SelectWord
                 | Action::SelectLine
                 | Action::ExpandSelection
+                | Action::ExpandSelectionToNode
+                | Action::ShrinkSelection
                 // Block selection
                 | Action::BlockSelectLeft
                 | Action::BlockSelectRight
@@ -1522,6 +1751,7 @@ impl Action {
                 | Action::InsertChar(_)
                 | Action::InsertNewline
                 | Action::InsertTab
+                | Action::SmartTab
                 | Action::DeleteBackward
                 | Action::DeleteForward
                 | Action::DeleteWordBackward
@@ -1537,6 +1767,7 @@ impl Action {
                 // Clipboard editing (but not Copy)
                 | Action::Cut
                 | Action::Paste
+                | Action::CyclePasteHistory
                 // Undo/Redo
                 | Action::Undo
                 | Action::Redo
@@ -1551,6 +1782,7 @@ impl Action {
             Action::InsertChar(_)
                 | Action::InsertNewline
                 | Action::InsertTab
+                | Action::SmartTab
                 | Action::DeleteBackward
                 | Action::DeleteForward
                 | Action::DeleteWordBackward
@@ -1581,6 +1813,44 @@ pub enum ChordResolution {
     NoMatch,
 }
 
+/// Where a binding from config applies, as given by its `when` clause
+enum BindingScope {
+    /// A single context, stored in the per-context maps
+    Context(KeyContext),
+    /// A compound clause (`language:markdown`, `normal && !popup`, …),
+    /// checked against the editor state at resolve time
+    Conditional(WhenClause),
+}
+
+impl BindingScope {
+    fn of(binding: &crate::config::Keybinding) -> Self {
+        let Some(when) = binding.when.as_deref() else {
+            return Self::Context(KeyContext::Normal);
+        };
+        match WhenClause::parse(when) {
+            Ok(WhenClause::Context(context)) => Self::Context(context),
+            Ok(clause) => Self::Conditional(clause),
+            Err(e) => {
+                tracing::warn!(
+                    "Invalid when clause {:?} for {}: {}; using normal",
+                    when,
+                    binding.action,
+                    e
+                );
+                Self::Context(KeyContext::Normal)
+            }
+        }
+    }
+}
+
+/// A single-key binding guarded by a compound `when` clause
+#[derive(Debug, Clone)]
+struct ConditionalBinding {
+    clause: WhenClause,
+    key: (KeyCode, KeyModifiers),
+    action: Action,
+}
+
 /// Resolves key events to actions based on configuration
 #[derive(Clone)]
 pub struct KeybindingResolver {
@@ -1609,6 +1879,16 @@ pub struct KeybindingResolver {
     /// bindings (motion, selection, copy). Populated by `defineMode` when
     /// `inheritNormalBindings: true`.
     inheriting_modes: std::collections::HashSet<String>,
+
+    /// Custom bindings with a compound `when` clause, in config order
+    conditional_bindings: Vec<ConditionalBinding>,
+
+    /// Keymap bindings with a compound `when` clause, in keymap order
+    default_conditional_bindings: Vec<ConditionalBinding>,
+
+    /// Bindings that fire when the key is released (`"release": true`).
+    /// Custom entries are loaded after the keymap's and replace them.
+    release_bindings: HashMap<KeyContext, HashMap<(KeyCode, KeyModifiers), Action>>,
 }
 
 impl KeybindingResolver {
@@ -1622,6 +1902,9 @@ impl KeybindingResolver {
             default_chord_bindings: HashMap::new(),
             plugin_chord_defaults: HashMap::new(),
             inheriting_modes: std::collections::HashSet::new(),
+            conditional_bindings: Vec::new(),
+            default_conditional_bindings: Vec::new(),
+            release_bindings: HashMap::new(),
         };
 
         // Load bindings from the active keymap (with inheritance resolution) into default_bindings
@@ -1663,12 +1946,19 @@ impl KeybindingResolver {
     fn load_default_bindings_from_vec(&mut self, bindings: &[crate::config::Keybinding]) {
         for binding in bindings {
             // Determine context from "when" clause
-            let context = if let Some(ref when) = binding.when {
-                KeyContext::from_when_clause(when).unwrap_or(KeyContext::Normal)
-            } else {
-                KeyContext::Normal
+            let context = match BindingScope::of(binding) {
+                BindingScope::Context(context) => context,
+                BindingScope::Conditional(clause) => {
+                    self.load_conditional(binding, clause, true);
+                    continue;
+                }
             };
 
+            if binding.release {
+                self.load_release(binding, context);
+                continue;
+            }
+
             if let Some(action) = Action::from_str(&binding.action, &binding.args) {
                 // Check if this is a chord binding (has keys field)
                 if !binding.keys.is_empty() {
@@ -1755,12 +2045,19 @@ impl KeybindingResolver {
     fn load_bindings_from_vec(&mut self, bindings: &[crate::config::Keybinding]) {
         for binding in bindings {
             // Determine context from "when" clause
-            let context = if let Some(ref when) = binding.when {
-                KeyContext::from_when_clause(when).unwrap_or(KeyContext::Normal)
-            } else {
-                KeyContext::Normal
+            let context = match BindingScope::of(binding) {
+                BindingScope::Context(context) => context,
+                BindingScope::Conditional(clause) => {
+                    self.load_conditional(binding, clause, false);
+                    continue;
+                }
             };
 
+            if binding.release {
+                self.load_release(binding, context);
+                continue;
+            }
+
             if let Some(action) = Action::from_str(&binding.action, &binding.args) {
                 // Check if this is a chord binding (has keys field)
                 if !binding.keys.is_empty() {
@@ -1795,6 +2092,62 @@ impl KeybindingResolver {
         }
     }
 
+    /// Load a binding that fires on key release. Only single keys in a plain
+    /// context can be released; anything else is skipped.
+    fn load_release(&mut self, binding: &crate::config::Keybinding, context: KeyContext) {
+        if !binding.keys.is_empty() {
+            tracing::warn!(
+                "Chord binding for {} ignored: chords can't fire on release",
+                binding.action
+            );
+            return;
+        }
+        let (Some(action), Some(key_code)) = (
+            Action::from_str(&binding.action, &binding.args),
+            Self::parse_key(&binding.key),
+        ) else {
+            return;
+        };
+        self.release_bindings.entry(context).or_default().insert(
+            (key_code, Self::parse_modifiers(&binding.modifiers)),
+            action,
+        );
+    }
+
+    /// Load a binding with a compound `when` clause. Chords are keyed by
+    /// context alone, so a conditional chord is skipped.
+    fn load_conditional(
+        &mut self,
+        binding: &crate::config::Keybinding,
+        clause: WhenClause,
+        is_default: bool,
+    ) {
+        if !binding.keys.is_empty() || binding.release {
+            tracing::warn!(
+                "Chord or release binding for {} ignored: when clause {:?} is not a single context",
+                binding.action,
+                binding.when.as_deref().unwrap_or_default()
+            );
+            return;
+        }
+        let Some(action) = Action::from_str(&binding.action, &binding.args) else {
+            return;
+        };
+        let Some(key_code) = Self::parse_key(&binding.key) else {
+            return;
+        };
+        let conditional = ConditionalBinding {
+            clause,
+            key: (key_code, Self::parse_modifiers(&binding.modifiers)),
+            action,
+        };
+        if is_default {
+            self.default_conditional_bindings.push(conditional);
+        } else {
+            self.conditional_bindings.push(conditional);
+        }
+    }
+
     /// Load a plugin default binding (for mode bindings registered via defineMode)
     pub fn load_plugin_default(
         &mut self,
@@ -1996,6 +2349,198 @@ impl KeybindingResolver {
         }
     }
 
+    /// Resolve a key against only the bindings `context` itself declares,
+    /// chords first and then single keys. Unlike [`resolve`](Self::resolve)
+    /// this never falls back to global or parent-context bindings, so a
+    /// plugin key layer only claims the keys it binds.
+    pub fn resolve_layer(
+        &self,
+        chord_state: &[(KeyCode, KeyModifiers)],
+        event: &KeyEvent,
+        context: &KeyContext,
+    ) -> ChordResolution {
+        let mut full_sequence: Vec<(KeyCode, KeyModifiers)> = chord_state
+            .iter()
+            .map(|(c, m)| normalize_key(*c, *m))
+            .collect();
+        full_sequence.push(normalize_key(event.code, event.modifiers));
+
+        let mut has_partial_match = false;
+        for context_chords in [
+            &self.chord_bindings,
+            &self.default_chord_bindings,
+            &self.plugin_chord_defaults,
+        ]
+        .into_iter()
+        .filter_map(|map| map.get(context))
+        {
+            if let Some(action) = context_chords.get(&full_sequence) {
+                return ChordResolution::Complete(action.clone());
+            }
+            has_partial_match |= context_chords
+                .keys()
+                .any(|seq| seq.len() > full_sequence.len() && seq.starts_with(&full_sequence));
+        }
+        if has_partial_match {
+            ChordResolution::Partial
+        } else if chord_state.is_empty() && self.binds_in(&full_sequence[0], context) {
+            ChordResolution::Complete(self.resolve(event, context.clone()))
+        } else {
+            ChordResolution::NoMatch
+        }
+    }
+
+    /// List the ways a pending chord can be completed, for the chord hint
+    /// popup: the keys still to press after `chord_state` and the action
+    /// they run. Sources are consulted in the same priority order as
+    /// [`resolve_chord`](Self::resolve_chord), so a sequence bound in more
+    /// than one place is listed with the action that would actually run.
+    /// Sorted by the remaining keys.
+    pub fn chord_continuations(
+        &self,
+        chord_state: &[(KeyCode, KeyModifiers)],
+        context: &KeyContext,
+    ) -> Vec<(Vec<(KeyCode, KeyModifiers)>, Action)> {
+        let prefix: Vec<(KeyCode, KeyModifiers)> = chord_state
+            .iter()
+            .map(|(c, m)| normalize_key(*c, *m))
+            .collect();
+
+        let search_order = [
+            (&self.chord_bindings, &KeyContext::Global),
+            (&self.default_chord_bindings, &KeyContext::Global),
+            (&self.chord_bindings, context),
+            (&self.default_chord_bindings, context),
+            (&self.plugin_chord_defaults, context),
+        ];
+
+        let mut seen = std::collections::HashSet::new();
+        let mut continuations = Vec::new();
+        for (binding_map, bind_context) in search_order {
+            let Some(context_chords) = binding_map.get(bind_context) else {
+                continue;
+            };
+            for (chord_seq, action) in context_chords {
+                if chord_seq.len() <= prefix.len() || chord_seq[..prefix.len()] != prefix[..] {
+                    continue;
+                }
+                let rest = chord_seq[prefix.len()..].to_vec();
+                if seen.insert(rest.clone()) && *action != Action::None {
+                    continuations.push((rest, action.clone()));
+                }
+            }
+        }
+
+        continuations.sort_by_cached_key(|(keys, _)| {
+            keys.iter()
+                .map(|(code, modifiers)| format_keybinding(code, modifiers))
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+        continuations
+    }
+
+    /// Resolve a key release to the action bound to it, if any. Releases
+    /// have no fallthrough: the binding must be in `context` or `global`.
+    pub fn resolve_release(&self, event: &KeyEvent, context: &KeyContext) -> Option<Action> {
+        let norm = normalize_key(event.code, event.modifiers);
+        [context, &KeyContext::Global]
+            .into_iter()
+            .find_map(|ctx| self.release_bindings.get(ctx)?.get(&norm))
+            .filter(|action| **action != Action::None)
+            .cloned()
+    }
+
+    /// Resolve a key event in the given context, also considering bindings
+    /// whose `when` clause depends on editor state (buffer language, file
+    /// type, a visible popup).
+    ///
+    /// A matching custom conditional binding wins over everything; a
+    /// matching keymap conditional binding wins over the keymap's plain
+    /// bindings but not over a custom binding for the same key. Later
+    /// entries win over earlier ones, as with plain bindings. Other contexts
+    /// fall through to a `normal`-scoped conditional the same way they fall
+    /// through to a plain Normal binding, and a matching one shadows the
+    /// plain Normal binding it overrides.
+    pub fn resolve_in_state(
+        &self,
+        event: &KeyEvent,
+        context: KeyContext,
+        state: &WhenState,
+    ) -> Action {
+        let norm = normalize_key(event.code, event.modifiers);
+        if let Some(action) = self.resolve_conditional(&norm, &context, state) {
+            return action;
+        }
+        if context == KeyContext::Normal || self.binds_in(&norm, &context) {
+            return self.resolve(event, context);
+        }
+        let Some(action) = self.resolve_conditional(&norm, &KeyContext::Normal, state) else {
+            return self.resolve(event, context);
+        };
+
+        let full_fallthrough = context.allows_normal_fallthrough()
+            || matches!(&context, KeyContext::Mode(name) if self.inheriting_modes.contains(name));
+        if full_fallthrough
+            || Self::is_application_wide_action(&action)
+            || (context.allows_ui_fallthrough() && Self::is_terminal_ui_action(&action))
+        {
+            return action;
+        }
+        let fallback = self.resolve(event, context);
+        let from_normal = [&self.bindings, &self.default_bindings].iter().any(|map| {
+            map.get(&KeyContext::Normal)
+                .and_then(|m| m.get(&norm))
+                .is_some_and(|a| *a == fallback)
+        });
+        if from_normal {
+            Action::None
+        } else {
+            fallback
+        }
+    }
+
+    /// The conditional binding for `key` whose clause holds, if any
+    fn resolve_conditional(
+        &self,
+        key: &(KeyCode, KeyModifiers),
+        context: &KeyContext,
+        state: &WhenState,
+    ) -> Option<Action> {
+        let find = |list: &[ConditionalBinding]| {
+            list.iter()
+                .rev()
+                .find(|b| b.key == *key && b.clause.matches(context, state))
+                .map(|b| b.action.clone())
+        };
+
+        if let Some(action) = find(&self.conditional_bindings) {
+            tracing::trace!("  -> Found in custom conditional bindings: {:?}", action);
+            return Some(action);
+        }
+        let has_custom = std::iter::once(KeyContext::Global)
+            .chain(Some(context.clone()))
+            .chain(context.parent_context())
+            .any(|ctx| self.bindings.get(&ctx).is_some_and(|m| m.contains_key(key)));
+        if has_custom {
+            return None;
+        }
+        let action = find(&self.default_conditional_bindings)?;
+        tracing::trace!("  -> Found in default conditional bindings: {:?}", action);
+        Some(action)
+    }
+
+    /// Whether `context` itself (custom, keymap or plugin) binds `key`
+    fn binds_in(&self, key: &(KeyCode, KeyModifiers), context: &KeyContext) -> bool {
+        [
+            &self.bindings,
+            &self.default_bindings,
+            &self.plugin_defaults,
+        ]
+        .iter()
+        .any(|map| map.get(context).is_some_and(|m| m.contains_key(key)))
+    }
+
     /// Resolve a key event to an action in the given context
     pub fn resolve(&self, event: &KeyEvent, context: KeyContext) -> Action {
         // Normalize key for lookups (e.g., BackTab+SHIFT → BackTab, Char('T')+SHIFT → Char('t')+SHIFT)
@@ -2418,6 +2963,7 @@ impl KeybindingResolver {
             "end" => Some(KeyCode::End),
             "pageup" => Some(KeyCode::PageUp),
             "pagedown" => Some(KeyCode::PageDown),
+            "menu" => Some(KeyCode::Menu),
 
             s if s.len() == 1 => s.chars().next().map(KeyCode::Char),
             // Handle function keys like "f1", "f2", ..., "f12"
@@ -2435,6 +2981,7 @@ impl KeybindingResolver {
                 "shift" => result |= KeyModifiers::SHIFT,
                 "alt" => result |= KeyModifiers::ALT,
                 "super" | "cmd" | "command" | "meta" => result |= KeyModifiers::SUPER,
+                "hyper" => result |= KeyModifiers::HYPER,
                 _ => {}
             }
         }
@@ -2503,6 +3050,7 @@ impl KeybindingResolver {
             Action::InsertChar(c) => t!("action.insert_char", char = c),
             Action::InsertNewline => t!("action.insert_newline"),
             Action::InsertTab => t!("action.insert_tab"),
+            Action::SmartTab => t!("action.smart_tab"),
             Action::MoveLeft => t!("action.move_left"),
             Action::MoveRight => t!("action.move_right"),
             Action::MoveUp => t!("action.move_up"),
@@ -2545,6 +3093,8 @@ impl KeybindingResolver {
             Action::SelectWord => t!("action.select_word"),
             Action::SelectLine => t!("action.select_line"),
             Action::ExpandSelection => t!("action.expand_selection"),
+            Action::ExpandSelectionToNode => t!("action.expand_selection_to_node"),
+            Action::ShrinkSelection => t!("action.shrink_selection"),
             Action::BlockSelectLeft => t!("action.block_select_left"),
             Action::BlockSelectRight => t!("action.block_select_right"),
             Action::BlockSelectUp => t!("action.block_select_up"),
@@ -2561,6 +3111,8 @@ impl KeybindingResolver {
             Action::OpenLine => t!("action.open_line"),
             Action::DuplicateLine => t!("action.duplicate_line"),
             Action::Recenter => t!("action.recenter"),
+            Action::ScrollCursorToTop => t!("action.scroll_cursor_to_top"),
+            Action::ScrollCursorToBottom => t!("action.scroll_cursor_to_bottom"),
             Action::SetMark => t!("action.set_mark"),
             Action::CancelMark => t!("action.cancel_mark"),
             Action::ClearMark => t!("action.clear_mark"),
@@ -2569,8 +3121,17 @@ impl KeybindingResolver {
             Action::CopyWithTheme(theme) => t!("action.copy_with_theme", theme = theme),
             Action::Cut => t!("action.cut"),
             Action::Paste => t!("action.paste"),
+            Action::PasteFromHistory => t!("action.paste_from_history"),
+            Action::CyclePasteHistory => t!("action.cycle_paste_history"),
+            Action::ClipboardHistoryTogglePin => t!("action.clipboard_history_toggle_pin"),
+            Action::ClipboardHistoryPasteIndented => {
+                t!("action.clipboard_history_paste_indented")
+            }
             Action::CopyFilePath => t!("action.copy_file_path"),
             Action::CopyRelativeFilePath => t!("action.copy_relative_file_path"),
+            Action::CopyFilePathWithLine => t!("action.copy_file_path_with_line"),
+            Action::ServeBuffer => t!("action.serve_buffer"),
+            Action::StopServingBuffer => t!("action.stop_serving_buffer"),
             Action::YankWordForward => t!("action.yank_word_forward"),
             Action::YankWordBackward => t!("action.yank_word_backward"),
             Action::YankToLineEnd => t!("action.yank_to_line_end"),
@@ -2587,8 +3148,14 @@ impl KeybindingResolver {
             Action::Open => t!("action.open"),
             Action::SwitchProject => t!("action.switch_project"),
             Action::New => t!("action.new"),
+            Action::NewScratchBuffer => t!("action.new_scratch_buffer"),
             Action::Close => t!("action.close"),
             Action::CloseTab => t!("action.close_tab"),
+            Action::CloseOtherTabs => t!("action.close_other_tabs"),
+            Action::CloseTabsToRight => t!("action.close_tabs_to_right"),
+            Action::CloseSavedTabs => t!("action.close_saved_tabs"),
+            Action::ReopenClosedTab => t!("action.reopen_closed_tab"),
+            Action::KeepPreviewTab => t!("action.keep_preview_tab"),
             Action::Quit => t!("action.quit"),
             Action::ForceQuit => t!("action.force_quit"),
             Action::Detach => t!("action.detach"),
@@ -2597,6 +3164,9 @@ impl KeybindingResolver {
             Action::FormatBuffer => t!("action.format_buffer"),
             Action::TrimTrailingWhitespace => t!("action.trim_trailing_whitespace"),
             Action::EnsureFinalNewline => t!("action.ensure_final_newline"),
+            Action::RevertLastTransform => t!("action.revert_last_transform"),
+            Action::FileHistory => t!("action.file_history"),
+            Action::FileHistoryShowDiff => t!("action.file_history_show_diff"),
             Action::GotoLine => t!("action.goto_line"),
             Action::ScanLineIndex => t!("action.scan_line_index"),
             Action::GoToMatchingBracket => t!("action.goto_matching_bracket"),
@@ -2605,16 +3175,31 @@ impl KeybindingResolver {
             Action::SmartHome => t!("action.smart_home"),
             Action::DedentSelection => t!("action.dedent_selection"),
             Action::ToggleComment => t!("action.toggle_comment"),
+            Action::ToggleBlockComment => t!("action.toggle_block_comment"),
+            Action::JoinLines => t!("action.join_lines"),
+            Action::SplitLine => t!("action.split_line"),
             Action::DabbrevExpand => std::borrow::Cow::Borrowed("Expand abbreviation (dabbrev)"),
+            Action::InsertSnippet => t!("action.insert_snippet"),
             Action::ToggleFold => t!("action.toggle_fold"),
             Action::SetBookmark(c) => t!("action.set_bookmark", key = c),
             Action::JumpToBookmark(c) => t!("action.jump_to_bookmark", key = c),
             Action::ClearBookmark(c) => t!("action.clear_bookmark", key = c),
             Action::ListBookmarks => t!("action.list_bookmarks"),
+            Action::ToggleLineBookmark => t!("action.toggle_bookmark"),
+            Action::NextLineBookmark => t!("action.next_bookmark"),
+            Action::PrevLineBookmark => t!("action.prev_bookmark"),
+            Action::LabelLineBookmark => t!("action.label_bookmark"),
+            Action::ShowLineBookmarks => t!("action.show_bookmarks"),
+            Action::SetNamedMark(c) => t!("action.set_named_mark", key = c),
+            Action::GotoNamedMark(c) => t!("action.goto_named_mark", key = c),
+            Action::PromptSetNamedMark => t!("action.prompt_set_named_mark"),
+            Action::PromptGotoNamedMark => t!("action.prompt_goto_named_mark"),
+            Action::ListNamedMarks => t!("action.list_named_marks"),
             Action::ToggleSearchCaseSensitive => t!("action.toggle_search_case_sensitive"),
             Action::ToggleSearchWholeWord => t!("action.toggle_search_whole_word"),
             Action::ToggleSearchRegex => t!("action.toggle_search_regex"),
             Action::ToggleSearchConfirmEach => t!("action.toggle_search_confirm_each"),
+            Action::ToggleSearchMultiline => t!("action.toggle_search_multiline"),
             Action::StartMacroRecording => t!("action.start_macro_recording"),
             Action::StopMacroRecording => t!("action.stop_macro_recording"),
             Action::PlayMacro(c) => t!("action.play_macro", key = c),
@@ -2636,11 +3221,19 @@ impl KeybindingResolver {
             Action::ShowKeyboardShortcuts => t!("action.show_keyboard_shortcuts"),
             Action::ShowWarnings => t!("action.show_warnings"),
             Action::ShowStatusLog => t!("action.show_status_log"),
+            Action::ShowMessages => t!("action.show_messages"),
+            Action::CycleMessageFilter => t!("action.cycle_message_filter"),
+            Action::ShowEditorLog => t!("action.show_editor_log"),
+            Action::FilterEditorLog => t!("action.filter_editor_log"),
             Action::ShowLspStatus => t!("action.show_lsp_status"),
             Action::ShowRemoteIndicatorMenu => t!("action.show_remote_indicator_menu"),
             Action::ShowReadOnlyMenu => t!("action.show_read_only_menu"),
+            Action::ShowJobs => t!("action.show_jobs"),
             Action::UpdateFresh => t!("action.update_fresh"),
             Action::OpenUpdateLog => t!("action.open_update_log"),
+            Action::ToggleTelemetry => t!("action.toggle_telemetry"),
+            Action::ToggleUpdateCheck => t!("action.toggle_update_check"),
+            Action::ShowTelemetryData => t!("action.show_telemetry_data"),
             Action::ClearWarnings => t!("action.clear_warnings"),
             Action::CommandPalette => t!("action.command_palette"),
             Action::QuickOpen => t!("action.quick_open"),
@@ -2658,10 +3251,14 @@ impl KeybindingResolver {
             Action::ToggleReadOnly => t!("action.toggle_read_only"),
             Action::TogglePageView => t!("action.toggle_page_view"),
             Action::SetPageWidth => t!("action.set_page_width"),
+            Action::StartPresentation => t!("action.start_presentation"),
+            Action::MarkdownPreview => t!("action.markdown_preview"),
+            Action::PreviewImage => t!("action.preview_image"),
             Action::NextBuffer => t!("action.next_buffer"),
             Action::PrevBuffer => t!("action.prev_buffer"),
             Action::NavigateBack => t!("action.navigate_back"),
             Action::NavigateForward => t!("action.navigate_forward"),
+            Action::NavigationHistory => t!("action.navigation_history"),
             Action::SplitHorizontal => t!("action.split_horizontal"),
             Action::SplitVertical => t!("action.split_vertical"),
             Action::CloseSplit => t!("action.close_split"),
@@ -2719,12 +3316,15 @@ impl KeybindingResolver {
             Action::PopupFocus => t!("action.popup_focus"),
             Action::CompletionAccept => t!("action.completion_accept"),
             Action::CompletionDismiss => t!("action.completion_dismiss"),
+            Action::CompletionDocsScrollDown => t!("action.completion_docs_scroll_down"),
+            Action::CompletionDocsScrollUp => t!("action.completion_docs_scroll_up"),
             Action::ToggleFileExplorer => t!("action.toggle_file_explorer"),
             Action::ToggleFileExplorerSide => t!("action.toggle_file_explorer_side"),
             Action::ToggleMenuBar => t!("action.toggle_menu_bar"),
             Action::ToggleTabBar => t!("action.toggle_tab_bar"),
             Action::ToggleStatusBar => t!("action.toggle_status_bar"),
             Action::TogglePromptLine => t!("action.toggle_prompt_line"),
+            Action::ToggleZenMode => t!("action.toggle_zen_mode"),
             Action::ToggleVerticalScrollbar => t!("action.toggle_vertical_scrollbar"),
             Action::ToggleHorizontalScrollbar => t!("action.toggle_horizontal_scrollbar"),
             Action::FocusFileExplorer => t!("action.focus_file_explorer"),
@@ -2771,6 +3371,8 @@ impl KeybindingResolver {
             Action::LspToggleForBuffer => t!("action.lsp_toggle_for_buffer"),
             Action::ToggleInlayHints => t!("action.toggle_inlay_hints"),
             Action::ToggleMouseHover => t!("action.toggle_mouse_hover"),
+            Action::GotoTag => t!("action.goto_tag"),
+            Action::GenerateTags => t!("action.generate_tags"),
             Action::ToggleLineNumbers => t!("action.toggle_line_numbers"),
             Action::ToggleLineNumbersCurrentBuffer => {
                 t!("action.toggle_line_numbers_current_buffer")
@@ -2783,20 +3385,28 @@ impl KeybindingResolver {
             Action::ToggleScrollSync => t!("action.toggle_scroll_sync"),
             Action::ToggleMouseCapture => t!("action.toggle_mouse_capture"),
             Action::ToggleDebugHighlights => t!("action.toggle_debug_highlights"),
+            Action::TogglePerfHud => t!("action.toggle_perf_hud"),
             Action::SetBackground => t!("action.set_background"),
             Action::SetBackgroundBlend => t!("action.set_background_blend"),
             Action::AddRuler => t!("action.add_ruler"),
             Action::RemoveRuler => t!("action.remove_ruler"),
             Action::SetTabSize => t!("action.set_tab_size"),
             Action::SetLineEnding => t!("action.set_line_ending"),
+            Action::ConvertLineEndingsToLf => t!("action.convert_line_endings_to_lf"),
+            Action::ConvertLineEndingsToCrlf => t!("action.convert_line_endings_to_crlf"),
+            Action::ConvertIndentationToSpaces => t!("action.convert_indentation_to_spaces"),
+            Action::ConvertIndentationToTabs => t!("action.convert_indentation_to_tabs"),
             Action::SetEncoding => t!("action.set_encoding"),
             Action::ReloadWithEncoding => t!("action.reload_with_encoding"),
+            Action::SaveWithEncoding => t!("action.save_with_encoding"),
             Action::SetLanguage => t!("action.set_language"),
             Action::ToggleIndentationStyle => t!("action.toggle_indentation_style"),
             Action::ToggleTabIndicators => t!("action.toggle_tab_indicators"),
             Action::ToggleWhitespaceIndicators => t!("action.toggle_whitespace_indicators"),
             Action::ResetBufferSettings => t!("action.reset_buffer_settings"),
             Action::DumpConfig => t!("action.dump_config"),
+            Action::ReloadConfig => t!("action.reload_config"),
+            Action::DumpScreen => t!("action.dump_screen"),
             Action::RedrawScreen => t!("action.redraw_screen"),
             Action::Search => t!("action.search"),
             Action::FindInSelection => t!("action.find_in_selection"),
@@ -2808,6 +3418,7 @@ impl KeybindingResolver {
             Action::Replace => t!("action.replace"),
             Action::QueryReplace => t!("action.query_replace"),
             Action::MenuActivate => t!("action.menu_activate"),
+            Action::OpenContextMenu => t!("action.open_context_menu"),
             Action::MenuClose => t!("action.menu_close"),
             Action::MenuLeft => t!("action.menu_left"),
             Action::MenuRight => t!("action.menu_right"),
@@ -2820,11 +3431,13 @@ impl KeybindingResolver {
             Action::ScrollTabsLeft => t!("action.scroll_tabs_left"),
             Action::ScrollTabsRight => t!("action.scroll_tabs_right"),
             Action::SelectTheme => t!("action.select_theme"),
+            Action::PreviewThemes => t!("action.preview_themes"),
             Action::SelectKeybindingMap => t!("action.select_keybinding_map"),
             Action::SelectCursorStyle => t!("action.select_cursor_style"),
             Action::SelectLocale => t!("action.select_locale"),
             Action::SwitchToPreviousTab => t!("action.switch_to_previous_tab"),
             Action::SwitchToTabByName => t!("action.switch_to_tab_by_name"),
+            Action::SwitchBuffer => t!("action.switch_buffer"),
             Action::OpenTerminal => t!("action.open_terminal"),
             Action::OpenTerminalRight => t!("action.open_terminal_right"),
             Action::OpenTerminalBelow => t!("action.open_terminal_below"),
@@ -2850,14 +3463,38 @@ impl KeybindingResolver {
             Action::ToUpperCase => t!("action.to_uppercase"),
             Action::ToLowerCase => t!("action.to_lowercase"),
             Action::ToggleCase => t!("action.to_uppercase"),
+            Action::ToTitleCase => t!("action.to_title_case"),
+            Action::ToCamelCase => t!("action.to_camel_case"),
+            Action::ToSnakeCase => t!("action.to_snake_case"),
+            Action::ToKebabCase => t!("action.to_kebab_case"),
             Action::SortLines => t!("action.sort_lines"),
+            Action::SortLinesDescending => t!("action.sort_lines_descending"),
+            Action::SortLinesUnique => t!("action.sort_lines_unique"),
+            Action::ReverseLines => t!("action.reverse_lines"),
+            Action::NumberLines => t!("action.number_lines"),
+            Action::EscapeUnicode => t!("action.escape_unicode"),
+            Action::UnescapeUnicode => t!("action.unescape_unicode"),
+            Action::EncodeHtmlEntities => t!("action.encode_html_entities"),
+            Action::DecodeHtmlEntities => t!("action.decode_html_entities"),
+            Action::UrlEncode => t!("action.url_encode"),
+            Action::UrlDecode => t!("action.url_decode"),
+            Action::DescribeChar => t!("action.describe_char"),
+            Action::EvaluateSelection => t!("action.evaluate_selection"),
+            Action::EvaluateSelectionReplace => t!("action.evaluate_selection_replace"),
+            Action::EvaluateSelectionAppend => t!("action.evaluate_selection_append"),
+            Action::NarrowToSelection => t!("action.narrow_to_selection"),
+            Action::Widen => t!("action.widen"),
+            Action::FixEncodingArtifacts => t!("action.fix_encoding_artifacts"),
             Action::CalibrateInput => t!("action.calibrate_input"),
+            Action::SelectCalibrationProfile => t!("action.select_calibration_profile"),
+            Action::ShowTerminalCapabilities => t!("action.show_terminal_capabilities"),
             Action::EventDebug => t!("action.event_debug"),
             Action::SuspendProcess => t!("action.suspend_process"),
             Action::LoadPluginFromBuffer => "Load Plugin from Buffer".into(),
             Action::InitReload => "Reload init.ts".into(),
             Action::InitEdit => "Edit init.ts".into(),
             Action::InitCheck => "Check init.ts".into(),
+            Action::ScriptsReload => "Reload scripts".into(),
             Action::OpenKeybindingEditor => "Keybinding Editor".into(),
             Action::CompositeNextHunk => t!("action.composite_next_hunk"),
             Action::CompositePrevHunk => t!("action.composite_prev_hunk"),
@@ -3030,10 +3667,9 @@ impl KeybindingResolver {
                 let modifiers = Self::parse_modifiers(&binding.modifiers);
                 if let Some(action) = Action::from_str(&binding.action, &binding.args) {
                     // Determine context from "when" clause
-                    let context = if let Some(ref when) = binding.when {
-                        KeyContext::from_when_clause(when).unwrap_or(KeyContext::Normal)
-                    } else {
-                        KeyContext::Normal
+                    let context = match BindingScope::of(binding) {
+                        BindingScope::Context(context) => context,
+                        BindingScope::Conditional(_) => continue,
                     };
 
                     self.bindings
@@ -3335,6 +3971,7 @@ mod tests {
             action: "save".to_string(),
             args: HashMap::new(),
             when: Some("normal".to_string()),
+            release: false,
         });
         let resolver = KeybindingResolver::new(&config);
 
@@ -3379,6 +4016,7 @@ mod tests {
             action: "save".to_string(),
             args: HashMap::new(),
             when: Some("normal".to_string()),
+            release: false,
         });
         let resolver = KeybindingResolver::new(&config);
 
@@ -3699,6 +4337,11 @@ mod tests {
             resolver.resolve(&alt_r, KeyContext::SearchPrompt),
             Action::ToggleSearchRegex,
         );
+        let alt_n = KeyEvent::new(KeyCode::Char('n'), KeyModifiers::ALT);
+        assert_eq!(
+            resolver.resolve(&alt_n, KeyContext::SearchPrompt),
+            Action::ToggleSearchMultiline,
+        );
 
         // SearchPrompt inherits all generic editing/navigation keys from its
         // parent Prompt context (Enter confirms, Esc cancels, text types).
@@ -3752,6 +4395,7 @@ mod tests {
             action: "quit".to_string(), // Override Esc in popup context to quit
             args: HashMap::new(),
             when: Some("popup".to_string()),
+            release: false,
         });
 
         let resolver = KeybindingResolver::new(&config);
@@ -3808,6 +4452,7 @@ mod tests {
             action: "command_palette".to_string(),
             args: HashMap::new(),
             when: None, // Default to normal context
+            release: false,
         });
 
         let resolver = KeybindingResolver::new(&config);
@@ -3862,6 +4507,7 @@ mod tests {
             action: "command_palette".to_string(),
             args: HashMap::new(),
             when: None,
+            release: false,
         });
         let resolver = KeybindingResolver::new(&config);
 
@@ -3894,6 +4540,7 @@ mod tests {
             action: "command_palette".to_string(),
             args: HashMap::new(),
             when: Some("global".to_string()),
+            release: false,
         });
         let resolver = KeybindingResolver::new(&config);
 
@@ -4343,4 +4990,178 @@ mod tests {
             "inheriting-modes membership must survive reload_from_config"
         );
     }
+
+    /// A key layer claims its own single keys and chords, and nothing it
+    /// would only reach through Global or Normal fallbacks.
+    #[test]
+    fn test_resolve_layer_only_claims_own_bindings() {
+        let config = Config::default();
+        let mut resolver = KeybindingResolver::new(&config);
+        let layer = KeyContext::Mode("test-plugin-layer".to_string());
+        let alt_l = (KeyCode::Char('l'), KeyModifiers::ALT);
+        let ctrl_c = (KeyCode::Char('c'), KeyModifiers::CONTROL);
+        let ctrl_k = (KeyCode::Char('k'), KeyModifiers::CONTROL);
+        let single = Action::PluginAction("test-plugin.single".to_string());
+        let chord = Action::PluginAction("test-plugin.chord".to_string());
+        resolver.load_plugin_default(layer.clone(), alt_l.0, alt_l.1, single.clone());
+        resolver.load_plugin_chord_default(layer.clone(), vec![ctrl_c, ctrl_k], chord.clone());
+        resolver.set_mode_inherits_normal_bindings("test-plugin-layer", true);
+
+        let event = |(code, modifiers): (KeyCode, KeyModifiers)| KeyEvent::new(code, modifiers);
+        assert_eq!(
+            resolver.resolve_layer(&[], &event(alt_l), &layer),
+            ChordResolution::Complete(single)
+        );
+        assert_eq!(
+            resolver.resolve_layer(&[], &event(ctrl_c), &layer),
+            ChordResolution::Partial
+        );
+        assert_eq!(
+            resolver.resolve_layer(&[ctrl_c], &event(ctrl_k), &layer),
+            ChordResolution::Complete(chord)
+        );
+        // Bound in Normal (and inherited by the mode) but not by the layer
+        let left = KeyEvent::new(KeyCode::Left, KeyModifiers::NONE);
+        assert_eq!(
+            resolver.resolve_layer(&[], &left, &layer),
+            ChordResolution::NoMatch
+        );
+    }
+
+    /// Chord hints list what can follow the pending prefix, with a custom
+    /// binding shadowing the keymap's for the same sequence.
+    #[test]
+    fn test_chord_continuations_lists_next_keys() {
+        let config = Config::default();
+        let mut resolver = KeybindingResolver::new(&config);
+        let mode_ctx = KeyContext::Mode("test-plugin-mode".to_string());
+        let g = (KeyCode::Char('g'), KeyModifiers::NONE);
+        let d = (KeyCode::Char('d'), KeyModifiers::NONE);
+        let top = Action::PluginAction("test-plugin.top".to_string());
+        let definition = Action::PluginAction("test-plugin.definition".to_string());
+        resolver.load_plugin_chord_default(mode_ctx.clone(), vec![g, g], top.clone());
+        resolver.load_plugin_chord_default(mode_ctx.clone(), vec![g, d], definition.clone());
+        resolver.load_plugin_chord_default(
+            mode_ctx.clone(),
+            vec![(KeyCode::Char('z'), KeyModifiers::NONE), g],
+            Action::MoveLeft,
+        );
+
+        assert_eq!(
+            resolver.chord_continuations(&[g], &mode_ctx),
+            vec![(vec![d], definition), (vec![g], top)]
+        );
+        assert!(resolver.chord_continuations(&[d], &mode_ctx).is_empty());
+    }
+
+    /// Bindings with a compound `when` clause only fire while the editor
+    /// state satisfies it, and otherwise leave the plain bindings in charge.
+    #[test]
+    fn test_conditional_when_clause_resolution() {
+        use crate::config::Keybinding;
+
+        let mut config = Config::default();
+        for (key, action, when) in [
+            ("b", "save", "language:markdown"),
+            ("esc", "quit", "normal && !popup"),
+        ] {
+            config.keybindings.push(Keybinding {
+                key: key.to_string(),
+                modifiers: if key == "b" {
+                    vec!["ctrl".to_string()]
+                } else {
+                    vec![]
+                },
+                keys: vec![],
+                action: action.to_string(),
+                args: HashMap::new(),
+                when: Some(when.to_string()),
+                release: false,
+            });
+        }
+        let resolver = KeybindingResolver::new(&config);
+        let markdown = WhenState {
+            language: "markdown".to_string(),
+            file_type: Some("md".to_string()),
+            popup_visible: false,
+        };
+        let rust = WhenState {
+            language: "rust".to_string(),
+            ..markdown.clone()
+        };
+
+        let ctrl_b = KeyEvent::new(KeyCode::Char('b'), KeyModifiers::CONTROL);
+        assert_eq!(
+            resolver.resolve_in_state(&ctrl_b, KeyContext::Normal, &markdown),
+            Action::Save
+        );
+        assert_eq!(
+            resolver.resolve_in_state(&ctrl_b, KeyContext::Normal, &rust),
+            resolver.resolve(&ctrl_b, KeyContext::Normal)
+        );
+        // Other contexts fall through to it like to a plain Normal binding:
+        // `save` is application-wide, so it reaches prompts and plugin modes.
+        assert_eq!(
+            resolver.resolve_in_state(&ctrl_b, KeyContext::Prompt, &markdown),
+            Action::Save
+        );
+        assert_eq!(
+            resolver.resolve_in_state(&ctrl_b, KeyContext::Prompt, &rust),
+            resolver.resolve(&ctrl_b, KeyContext::Prompt)
+        );
+        assert_eq!(
+            resolver.resolve_in_state(
+                &ctrl_b,
+                KeyContext::Mode("markdown-source".to_string()),
+                &markdown
+            ),
+            Action::Save
+        );
+
+        let esc = KeyEvent::new(KeyCode::Esc, KeyModifiers::empty());
+        assert_eq!(
+            resolver.resolve_in_state(&esc, KeyContext::Normal, &rust),
+            Action::Quit
+        );
+        let with_popup = WhenState {
+            popup_visible: true,
+            ..rust
+        };
+        assert_eq!(
+            resolver.resolve_in_state(&esc, KeyContext::Normal, &with_popup),
+            Action::RemoveSecondaryCursors
+        );
+    }
+
+    #[test]
+    fn test_release_bindings_only_fire_on_release() {
+        use crate::config::Keybinding;
+
+        let mut config = Config::default();
+        config.keybindings.push(Keybinding {
+            key: "k".to_string(),
+            modifiers: vec!["ctrl".to_string(), "shift".to_string()],
+            keys: vec![],
+            action: "toggle_line_numbers".to_string(),
+            args: HashMap::new(),
+            when: Some("normal".to_string()),
+            release: true,
+        });
+        let resolver = KeybindingResolver::new(&config);
+        let key = KeyEvent::new(
+            KeyCode::Char('K'),
+            KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        );
+
+        assert_eq!(
+            resolver.resolve_release(&key, &KeyContext::Normal),
+            Some(Action::ToggleLineNumbers)
+        );
+        assert_eq!(resolver.resolve_release(&key, &KeyContext::Prompt), None);
+        // The press keeps whatever the keymap binds it to
+        assert_ne!(
+            resolver.resolve(&key, KeyContext::Normal),
+            Action::ToggleLineNumbers
+        );
+    }
 }
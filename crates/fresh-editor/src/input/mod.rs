@@ -7,6 +7,7 @@ pub mod buffer_mode;
 pub mod command_registry;
 pub mod commands;
 pub mod composite_router;
+pub mod dead_keys;
 pub mod fuzzy;
 pub mod handler;
 pub mod input_history;
@@ -16,6 +17,7 @@ mod line_move;
 pub mod multi_cursor;
 pub mod position_history;
 pub mod quick_open;
+pub mod when_clause;
 
 #[cfg(test)]
 pub mod tests_language_features;
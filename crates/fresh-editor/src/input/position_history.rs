@@ -252,6 +252,23 @@ impl PositionHistory {
     pub fn current_index(&self) -> Option<usize> {
         self.current_index
     }
+
+    /// All entries, oldest first
+    pub fn entries(&self) -> &[PositionEntry] {
+        &self.entries
+    }
+
+    /// Jump straight to the entry at `index`
+    ///
+    /// Like `back()`/`forward()` this moves the current index without
+    /// truncating, so the entries after it stay reachable with `forward()`.
+    /// Returns None if `index` is out of range.
+    pub fn go_to(&mut self, index: usize) -> Option<&PositionEntry> {
+        self.commit_pending_movement();
+        let entry = self.entries.get(index)?;
+        self.current_index = Some(index);
+        Some(entry)
+    }
 }
 
 impl Default for PositionHistory {
@@ -431,6 +448,26 @@ mod tests {
         assert_eq!(history.current(), Some(&make_entry(1, 20)));
     }
 
+    #[test]
+    fn test_go_to_keeps_entries_on_both_sides() {
+        let mut history = PositionHistory::new();
+
+        history.push(make_entry(1, 10));
+        history.push(make_entry(1, 20));
+        history.push(make_entry(2, 30));
+
+        assert_eq!(history.go_to(0), Some(&make_entry(1, 10)));
+        assert!(!history.can_go_back());
+        assert_eq!(history.forward(), Some(&make_entry(1, 20)));
+
+        assert_eq!(history.go_to(2), Some(&make_entry(2, 30)));
+        assert_eq!(history.back(), Some(&make_entry(1, 20)));
+
+        assert_eq!(history.go_to(3), None);
+        assert_eq!(history.current(), Some(&make_entry(1, 20)));
+        assert_eq!(history.entries().len(), 3);
+    }
+
     #[test]
     fn test_clear() {
         let mut history = PositionHistory::new();
@@ -32,8 +32,8 @@ pub enum QuickOpenResult {
     },
     /// Show a buffer by ID
     ShowBuffer(usize),
-    /// Go to a line in the current buffer
-    GotoLine(GotoLineTarget),
+    /// Go to a line (and optional column) in the current buffer
+    GotoLine(GotoPosition),
     /// Do nothing (provider handled it internally)
     None,
     /// Show an error message
@@ -49,6 +49,15 @@ pub enum GotoLineTarget {
     Absolute(usize),
     /// Signed offset from the current cursor line (input had `+`/`-` prefix).
     Relative(isize),
+    /// Percentage of the buffer's line count, `0..=100` (input had `%` suffix).
+    Percent(usize),
+}
+
+/// A parsed goto target: a line plus an optional 1-based column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GotoPosition {
+    pub line: GotoLineTarget,
+    pub column: Option<usize>,
 }
 
 /// Parse a goto-line input string.
@@ -56,7 +65,8 @@ pub enum GotoLineTarget {
 /// - `"500"` → `Absolute(500)`
 /// - `"+3"` → `Relative(3)`
 /// - `"-3"` → `Relative(-3)`
-/// - `"0"`, `"+0"`, `"-0"`, `""`, `"abc"` → `None`
+/// - `"50%"` → `Percent(50)`
+/// - `"0"`, `"+0"`, `"-0"`, `"101%"`, `""`, `"abc"` → `None`
 ///
 /// Whitespace around the input is ignored. The leading-sign convention is
 /// independent of any display setting: the user's literal input decides.
@@ -65,6 +75,16 @@ pub fn parse_goto_line_input(input: &str) -> Option<GotoLineTarget> {
     if trimmed.is_empty() {
         return None;
     }
+    if let Some(pct) = trimmed.strip_suffix('%') {
+        if !pct.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        return pct
+            .parse::<usize>()
+            .ok()
+            .filter(|&p| p <= 100)
+            .map(GotoLineTarget::Percent);
+    }
     if let Some(rest) = trimmed
         .strip_prefix('+')
         .or_else(|| trimmed.strip_prefix('-'))
@@ -87,6 +107,28 @@ pub fn parse_goto_line_input(input: &str) -> Option<GotoLineTarget> {
     }
 }
 
+/// Parse a goto input of the form `[:]LINE[:COL]`, where `LINE` is anything
+/// [`parse_goto_line_input`] accepts.
+///
+/// - `"120:4"` → line `Absolute(120)`, column `Some(4)`
+/// - `":+10"` → line `Relative(10)`, no column
+/// - `"50%"` → line `Percent(50)`, no column
+///
+/// Returns `None` for a zero or non-numeric column, so `file:line:col` input
+/// falls through to [`parse_path_line_col`].
+pub fn parse_goto_position(input: &str) -> Option<GotoPosition> {
+    let trimmed = input.trim();
+    let trimmed = trimmed.strip_prefix(':').unwrap_or(trimmed);
+    let (line_part, column) = match trimmed.split_once(':') {
+        Some((line_part, col_part)) => {
+            let col = col_part.trim().parse::<usize>().ok().filter(|&c| c > 0)?;
+            (line_part, Some(col))
+        }
+        None => (trimmed, None),
+    };
+    parse_goto_line_input(line_part).map(|line| GotoPosition { line, column })
+}
+
 /// Context provided to providers when generating suggestions
 #[derive(Debug, Clone)]
 pub struct QuickOpenContext {
@@ -254,6 +296,17 @@ impl QuickOpenRegistry {
         }
     }
 
+    /// Drop the file provider's cached file list so the next file search
+    /// relists the project (e.g. after a branch switch).
+    pub fn clear_file_cache(&self) {
+        for provider in self.providers.values() {
+            if let Some(fp) = provider.as_any().downcast_ref::<FileProvider>() {
+                fp.clear_cache();
+                return;
+            }
+        }
+    }
+
     /// Get the provider for a given input
     ///
     /// Returns (provider, query_without_prefix)
@@ -364,6 +417,59 @@ mod tests {
         assert_eq!(parse_goto_line_input("3a"), None);
     }
 
+    #[test]
+    fn parse_goto_line_input_percent() {
+        assert_eq!(
+            parse_goto_line_input("50%"),
+            Some(GotoLineTarget::Percent(50))
+        );
+        assert_eq!(
+            parse_goto_line_input("0%"),
+            Some(GotoLineTarget::Percent(0))
+        );
+        assert_eq!(
+            parse_goto_line_input("100%"),
+            Some(GotoLineTarget::Percent(100))
+        );
+        assert_eq!(parse_goto_line_input("101%"), None);
+        assert_eq!(parse_goto_line_input("+5%"), None);
+        assert_eq!(parse_goto_line_input("%"), None);
+    }
+
+    #[test]
+    fn parse_goto_position_line_and_column() {
+        assert_eq!(
+            parse_goto_position("120:4"),
+            Some(GotoPosition {
+                line: GotoLineTarget::Absolute(120),
+                column: Some(4),
+            })
+        );
+        assert_eq!(
+            parse_goto_position(":+10"),
+            Some(GotoPosition {
+                line: GotoLineTarget::Relative(10),
+                column: None,
+            })
+        );
+        assert_eq!(
+            parse_goto_position(":-5:2"),
+            Some(GotoPosition {
+                line: GotoLineTarget::Relative(-5),
+                column: Some(2),
+            })
+        );
+        assert_eq!(
+            parse_goto_position(":50%"),
+            Some(GotoPosition {
+                line: GotoLineTarget::Percent(50),
+                column: None,
+            })
+        );
+        assert_eq!(parse_goto_position("12:0"), None);
+        assert_eq!(parse_goto_position("src/main.rs:120:4"), None);
+    }
+
     #[test]
     fn test_provider_routing() {
         let mut registry = QuickOpenRegistry::new();
@@ -7,7 +7,7 @@
 //! - GotoLineProvider: Go to a specific line (prefix: ":")
 
 use super::{
-    parse_goto_line_input, GotoLineTarget, QuickOpenContext, QuickOpenProvider, QuickOpenResult,
+    parse_goto_position, GotoLineTarget, QuickOpenContext, QuickOpenProvider, QuickOpenResult,
 };
 use crate::input::commands::Suggestion;
 use crate::input::fuzzy::FuzzyMatcher;
@@ -225,25 +225,41 @@ impl QuickOpenProvider for GotoLineProvider {
             ];
         }
 
-        match parse_goto_line_input(query) {
-            Some(target) => {
-                let label = match target {
-                    GotoLineTarget::Absolute(n) => {
-                        t!("quick_open.goto_line", line = n.to_string()).to_string()
-                    }
-                    GotoLineTarget::Relative(d) => {
-                        // Format with explicit sign so "+3" reads back as "+3", not "3".
-                        t!("quick_open.goto_line", line = format!("{:+}", d)).to_string()
-                    }
+        match parse_goto_position(query) {
+            Some(position) => {
+                let mut line = match position.line {
+                    GotoLineTarget::Absolute(n) => n.to_string(),
+                    // Format with explicit sign so "+3" reads back as "+3", not "3".
+                    GotoLineTarget::Relative(d) => format!("{:+}", d),
+                    GotoLineTarget::Percent(p) => format!("{}%", p),
                 };
-                vec![Suggestion::new(label)
+                if let Some(col) = position.column {
+                    line.push_str(&format!(":{}", col));
+                }
+                vec![
+                    Suggestion::new(t!("quick_open.goto_line", line = line).to_string())
+                        .with_description(t!("quick_open.press_enter").to_string())
+                        .with_value(query.to_string()),
+                ]
+            }
+            None => match super::parse_path_line_col(query) {
+                // `:file:line[:col]` opens the file at that position.
+                (path, Some(line), column) if !path.is_empty() => {
+                    let location = match column {
+                        Some(col) => format!("{}:{}", line, col),
+                        None => line.to_string(),
+                    };
+                    vec![Suggestion::new(
+                        t!("quick_open.goto_file_line", path = path, line = location).to_string(),
+                    )
                     .with_description(t!("quick_open.press_enter").to_string())
                     .with_value(query.to_string())]
-            }
-            None => vec![
-                Suggestion::disabled(t!("quick_open.invalid_line").to_string())
-                    .with_description(query.to_string()),
-            ],
+                }
+                _ => vec![
+                    Suggestion::disabled(t!("quick_open.invalid_line").to_string())
+                        .with_description(query.to_string()),
+                ],
+            },
         }
     }
 
@@ -253,11 +269,20 @@ impl QuickOpenProvider for GotoLineProvider {
         _query: &str,
         _context: &QuickOpenContext,
     ) -> QuickOpenResult {
-        suggestion
-            .and_then(|s| s.value.as_deref())
-            .and_then(parse_goto_line_input)
-            .map(QuickOpenResult::GotoLine)
-            .unwrap_or(QuickOpenResult::None)
+        let Some(value) = suggestion.and_then(|s| s.value.as_deref()) else {
+            return QuickOpenResult::None;
+        };
+        if let Some(position) = parse_goto_position(value) {
+            return QuickOpenResult::GotoLine(position);
+        }
+        match super::parse_path_line_col(value) {
+            (path, Some(line), column) if !path.is_empty() => QuickOpenResult::OpenFile {
+                path,
+                line: Some(line),
+                column,
+            },
+            _ => QuickOpenResult::None,
+        }
     }
 
     fn as_any(&self) -> &dyn std::any::Any {
@@ -1009,7 +1034,7 @@ impl QuickOpenProvider for FileProvider {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::input::quick_open::BufferInfo;
+    use crate::input::quick_open::{BufferInfo, GotoPosition};
 
     fn make_test_context(cwd: &str) -> QuickOpenContext {
         QuickOpenContext {
@@ -1137,7 +1162,10 @@ mod tests {
         let suggestions = provider.suggestions("42", &context);
         let result = provider.on_select(suggestions.first(), "42", &context);
         match result {
-            QuickOpenResult::GotoLine(GotoLineTarget::Absolute(line)) => assert_eq!(line, 42),
+            QuickOpenResult::GotoLine(GotoPosition {
+                line: GotoLineTarget::Absolute(line),
+                column: None,
+            }) => assert_eq!(line, 42),
             other => panic!("expected absolute GotoLine result, got {:?}", other),
         }
     }
@@ -1160,13 +1188,19 @@ mod tests {
 
             let suggestions = provider.suggestions("+3", &context);
             match provider.on_select(suggestions.first(), "+3", &context) {
-                QuickOpenResult::GotoLine(GotoLineTarget::Relative(d)) => assert_eq!(d, 3),
+                QuickOpenResult::GotoLine(GotoPosition {
+                    line: GotoLineTarget::Relative(d),
+                    ..
+                }) => assert_eq!(d, 3),
                 other => panic!("expected relative GotoLine, got {:?}", other),
             }
 
             let suggestions = provider.suggestions("-7", &context);
             match provider.on_select(suggestions.first(), "-7", &context) {
-                QuickOpenResult::GotoLine(GotoLineTarget::Relative(d)) => assert_eq!(d, -7),
+                QuickOpenResult::GotoLine(GotoPosition {
+                    line: GotoLineTarget::Relative(d),
+                    ..
+                }) => assert_eq!(d, -7),
                 other => panic!("expected relative GotoLine, got {:?}", other),
             }
 
@@ -1178,6 +1212,44 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_goto_line_column_percent_and_file_targets() {
+        let provider = GotoLineProvider::new();
+        let context = make_test_context("/tmp");
+
+        let suggestions = provider.suggestions("12:5", &context);
+        match provider.on_select(suggestions.first(), "12:5", &context) {
+            QuickOpenResult::GotoLine(position) => assert_eq!(
+                position,
+                GotoPosition {
+                    line: GotoLineTarget::Absolute(12),
+                    column: Some(5),
+                }
+            ),
+            other => panic!("expected GotoLine with column, got {:?}", other),
+        }
+
+        let suggestions = provider.suggestions("50%", &context);
+        match provider.on_select(suggestions.first(), "50%", &context) {
+            QuickOpenResult::GotoLine(GotoPosition {
+                line: GotoLineTarget::Percent(50),
+                column: None,
+            }) => {}
+            other => panic!("expected percent GotoLine, got {:?}", other),
+        }
+
+        let suggestions = provider.suggestions("src/main.rs:120:4", &context);
+        assert!(!suggestions[0].disabled);
+        match provider.on_select(suggestions.first(), "src/main.rs:120:4", &context) {
+            QuickOpenResult::OpenFile { path, line, column } => {
+                assert_eq!(path, "src/main.rs");
+                assert_eq!(line, Some(120));
+                assert_eq!(column, Some(4));
+            }
+            other => panic!("expected OpenFile, got {:?}", other),
+        }
+    }
+
     /// Unsigned input is always interpreted as absolute — independent of the
     /// `relative_line_numbers` display setting.
     #[test]
@@ -1192,7 +1264,10 @@ mod tests {
             assert_eq!(suggestions.len(), 1);
             assert!(!suggestions[0].disabled);
             match provider.on_select(suggestions.first(), "42", &context) {
-                QuickOpenResult::GotoLine(GotoLineTarget::Absolute(n)) => assert_eq!(n, 42),
+                QuickOpenResult::GotoLine(GotoPosition {
+                    line: GotoLineTarget::Absolute(n),
+                    ..
+                }) => assert_eq!(n, 42),
                 other => panic!("expected absolute GotoLine, got {:?}", other),
             }
         }
@@ -0,0 +1,276 @@
+//! Conditional `when` clauses for keybindings.
+//!
+//! A plain `when` names a single [`KeyContext`] and lands in the resolver's
+//! per-context maps. Anything richer parses into a [`WhenClause`] that is
+//! checked against the editor state each time a key is resolved:
+//!
+//! - `language:markdown` — the active buffer's language id
+//! - `filetype:md` — the active buffer's file extension
+//! - `normal`, `prompt`, `mode:vi-normal`, … — the context the key is
+//!   resolved in; `popup` also holds while a popup is showing over the buffer
+//! - `!`, `&&`, `||` and parentheses combine conditions, e.g.
+//!   `normal && !popup` or `language:rust || language:toml`
+//!
+//! A clause that names no context applies in the `normal` context, the same
+//! as a binding without `when`.
+
+use super::keybindings::KeyContext;
+
+/// A parsed conditional `when` clause
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WhenClause {
+    /// The key is resolved in this context
+    Context(KeyContext),
+    /// The active buffer has this language id
+    Language(String),
+    /// The active buffer's file has this extension
+    FileType(String),
+    Not(Box<WhenClause>),
+    And(Box<WhenClause>, Box<WhenClause>),
+    Or(Box<WhenClause>, Box<WhenClause>),
+}
+
+/// Editor state a [`WhenClause`] is evaluated against, besides the context
+#[derive(Debug, Clone, Default)]
+pub struct WhenState {
+    /// Language id of the active buffer (e.g. "markdown")
+    pub language: String,
+    /// Lowercased extension of the active buffer's file, without the dot
+    pub file_type: Option<String>,
+    /// Whether a popup is showing, focused or not
+    pub popup_visible: bool,
+}
+
+impl WhenClause {
+    /// Parse a `when` string. Clauses that name no context are scoped to
+    /// `normal`.
+    pub fn parse(when: &str) -> Result<Self, String> {
+        let tokens = tokenize(when)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let clause = parser.parse_or()?;
+        if let Some(token) = parser.tokens.get(parser.pos) {
+            return Err(format!("unexpected '{}'", token));
+        }
+        Ok(if clause.names_context() {
+            clause
+        } else {
+            Self::And(
+                Box::new(Self::Context(KeyContext::Normal)),
+                Box::new(clause),
+            )
+        })
+    }
+
+    /// The context, when the clause is nothing but a single context name.
+    pub fn as_context(&self) -> Option<&KeyContext> {
+        match self {
+            Self::Context(context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Whether the clause holds for a key resolved in `context`.
+    pub fn matches(&self, context: &KeyContext, state: &WhenState) -> bool {
+        match self {
+            Self::Context(KeyContext::Global) => true,
+            Self::Context(KeyContext::Popup) => {
+                *context == KeyContext::Popup || state.popup_visible
+            }
+            Self::Context(c) => c == context,
+            Self::Language(language) => state.language.eq_ignore_ascii_case(language),
+            Self::FileType(ext) => state.file_type.as_deref() == Some(ext.as_str()),
+            Self::Not(inner) => !inner.matches(context, state),
+            Self::And(a, b) => a.matches(context, state) && b.matches(context, state),
+            Self::Or(a, b) => a.matches(context, state) || b.matches(context, state),
+        }
+    }
+
+    /// Whether the clause names `context` anywhere, negated or not.
+    pub fn mentions(&self, context: &KeyContext) -> bool {
+        match self {
+            Self::Context(c) => c == context,
+            Self::Language(_) | Self::FileType(_) => false,
+            Self::Not(inner) => inner.mentions(context),
+            Self::And(a, b) | Self::Or(a, b) => a.mentions(context) || b.mentions(context),
+        }
+    }
+
+    fn names_context(&self) -> bool {
+        match self {
+            Self::Context(_) => true,
+            Self::Language(_) | Self::FileType(_) => false,
+            Self::Not(inner) => inner.names_context(),
+            Self::And(a, b) | Self::Or(a, b) => a.names_context() || b.names_context(),
+        }
+    }
+}
+
+/// Split a clause into operators, parentheses and condition names.
+fn tokenize(when: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = when.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '(' | ')' | '!' => {
+                tokens.push(c.to_string());
+                chars.next();
+            }
+            '&' | '|' => {
+                chars.next();
+                if chars.next() != Some(c) {
+                    return Err(format!("expected '{}{}'", c, c));
+                }
+                tokens.push(format!("{}{}", c, c));
+            }
+            _ => {
+                let mut name = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || "()!&|".contains(c) {
+                        break;
+                    }
+                    name.push(c);
+                    chars.next();
+                }
+                tokens.push(name);
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<String>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next_is(&self, token: &str) -> bool {
+        self.tokens.get(self.pos).is_some_and(|t| t == token)
+    }
+
+    fn parse_or(&mut self) -> Result<WhenClause, String> {
+        let mut clause = self.parse_and()?;
+        while self.next_is("||") {
+            self.pos += 1;
+            clause = WhenClause::Or(Box::new(clause), Box::new(self.parse_and()?));
+        }
+        Ok(clause)
+    }
+
+    fn parse_and(&mut self) -> Result<WhenClause, String> {
+        let mut clause = self.parse_unary()?;
+        while self.next_is("&&") {
+            self.pos += 1;
+            clause = WhenClause::And(Box::new(clause), Box::new(self.parse_unary()?));
+        }
+        Ok(clause)
+    }
+
+    fn parse_unary(&mut self) -> Result<WhenClause, String> {
+        let Some(token) = self.tokens.get(self.pos).cloned() else {
+            return Err("unexpected end of clause".to_string());
+        };
+        self.pos += 1;
+        match token.as_str() {
+            "!" => Ok(WhenClause::Not(Box::new(self.parse_unary()?))),
+            "(" => {
+                let clause = self.parse_or()?;
+                if !self.next_is(")") {
+                    return Err("missing ')'".to_string());
+                }
+                self.pos += 1;
+                Ok(clause)
+            }
+            ")" | "&&" | "||" => Err(format!("unexpected '{}'", token)),
+            name => parse_condition(name),
+        }
+    }
+}
+
+fn parse_condition(name: &str) -> Result<WhenClause, String> {
+    if let Some(language) = name.strip_prefix("language:") {
+        if language.is_empty() {
+            return Err("missing language after 'language:'".to_string());
+        }
+        return Ok(WhenClause::Language(language.to_string()));
+    }
+    if let Some(ext) = name.strip_prefix("filetype:") {
+        let ext = ext.trim_start_matches('.');
+        if ext.is_empty() {
+            return Err("missing extension after 'filetype:'".to_string());
+        }
+        return Ok(WhenClause::FileType(ext.to_lowercase()));
+    }
+    KeyContext::from_when_clause(name)
+        .map(WhenClause::Context)
+        .ok_or_else(|| format!("unknown condition '{}'", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(language: &str, file_type: Option<&str>, popup_visible: bool) -> WhenState {
+        WhenState {
+            language: language.to_string(),
+            file_type: file_type.map(String::from),
+            popup_visible,
+        }
+    }
+
+    #[test]
+    fn plain_context_parses_to_that_context() {
+        let clause = WhenClause::parse("prompt").unwrap();
+        assert_eq!(clause.as_context(), Some(&KeyContext::Prompt));
+        let clause = WhenClause::parse("mode:vi-normal").unwrap();
+        assert_eq!(
+            clause.as_context(),
+            Some(&KeyContext::Mode("vi-normal".to_string()))
+        );
+    }
+
+    #[test]
+    fn language_clause_is_scoped_to_normal() {
+        let clause = WhenClause::parse("language:markdown").unwrap();
+        assert_eq!(clause.as_context(), None);
+        let md = state("markdown", Some("md"), false);
+        assert!(clause.matches(&KeyContext::Normal, &md));
+        assert!(!clause.matches(&KeyContext::Prompt, &md));
+        assert!(!clause.matches(&KeyContext::Normal, &state("rust", None, false)));
+    }
+
+    #[test]
+    fn operators_combine_conditions() {
+        let clause = WhenClause::parse("normal && !popup").unwrap();
+        assert!(clause.matches(&KeyContext::Normal, &state("text", None, false)));
+        assert!(!clause.matches(&KeyContext::Normal, &state("text", None, true)));
+        assert!(!clause.matches(&KeyContext::Popup, &state("text", None, false)));
+
+        let clause = WhenClause::parse("(language:rust || filetype:.TOML) && !popup").unwrap();
+        assert!(clause.matches(&KeyContext::Normal, &state("rust", Some("rs"), false)));
+        assert!(clause.matches(&KeyContext::Normal, &state("text", Some("toml"), false)));
+        assert!(!clause.matches(&KeyContext::Normal, &state("python", Some("py"), false)));
+    }
+
+    #[test]
+    fn malformed_clauses_are_rejected() {
+        for when in [
+            "",
+            "normal &&",
+            "normal & popup",
+            "(normal",
+            "normal)",
+            "language:",
+            "unknown",
+        ] {
+            assert!(
+                WhenClause::parse(when).is_err(),
+                "{:?} should not parse",
+                when
+            );
+        }
+    }
+}
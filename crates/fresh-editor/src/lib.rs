@@ -41,6 +41,10 @@ pub mod input;
 #[cfg(feature = "runtime")]
 pub mod services;
 
+// Embedding the editor as a widget in other ratatui applications
+#[cfg(feature = "runtime")]
+pub mod embed;
+
 // Session persistence (client-server architecture)
 #[cfg(feature = "runtime")]
 pub mod client;
@@ -76,3 +80,10 @@ pub mod wasm;
 // docs/internal/e2e-test-migration-design.md.
 #[cfg(feature = "runtime")]
 pub mod test_api;
+
+// Deterministic fuzz/property hooks for the event model, shared by the
+// proptest suite and the `cargo fuzz` target in `fuzz/`. Test-only, like
+// test_api above.
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod event_fuzz;
@@ -13,6 +13,7 @@ use crossterm::event::{
 use fresh::input::key_translator::KeyTranslator;
 #[cfg(target_os = "linux")]
 use fresh::services::gpm::{gpm_to_crossterm, GpmClient};
+use fresh::services::terminal_backend::TerminalBackend;
 use fresh::services::terminal_modes::{self, KeyboardConfig, TerminalModes};
 use fresh::services::tracing_setup;
 use fresh::{
@@ -22,11 +23,18 @@ use fresh::{
 };
 use ratatui::Terminal;
 use std::{
-    io::{self, stdout},
+    io::{self, stdout, Write},
     path::{Path, PathBuf},
     time::Duration,
 };
 
+// Counts allocations for `fresh --bench`; otherwise a thin pass-through to
+// the system allocator. Only built with the `bench` feature, so release
+// binaries keep the plain system allocator.
+#[cfg(feature = "bench")]
+#[global_allocator]
+static GLOBAL: fresh::app::bench::CountingAllocator = fresh::app::bench::CountingAllocator;
+
 /// English `before_help` banner. Always shown above the auto-generated help so
 /// `--locale` is discoverable even when the editor is otherwise running in a
 /// language the user can't read. Keep this short, plain ASCII, and English —
@@ -63,6 +71,14 @@ struct Cli {
     #[arg(long)]
     stdin: bool,
 
+    /// Quit once the (last) file's buffer is closed, for use as $EDITOR
+    #[arg(long)]
+    wait: bool,
+
+    /// Open two files side by side in a diff view
+    #[arg(long, num_args = 2, value_names = ["OLD", "NEW"], conflicts_with = "stdin")]
+    diff: Option<Vec<PathBuf>>,
+
     /// Disable plugin loading
     #[arg(long)]
     no_plugins: bool,
@@ -83,10 +99,19 @@ struct Cli {
     #[arg(long, value_name = "PATH")]
     log_file: Option<PathBuf>,
 
+    /// Portable mode: keep config, data, logs and caches under DIR instead
+    /// of the home directory (same as setting FRESH_HOME)
+    #[arg(long, value_name = "DIR")]
+    portable: Option<PathBuf>,
+
     /// Enable event logging to the specified file
     #[arg(long, value_name = "LOG_FILE")]
     event_log: Option<PathBuf>,
 
+    /// Save a snapshot of the last screen to PATH on exit (`.ans` for ANSI colors)
+    #[arg(long, value_name = "PATH")]
+    screenshot_on_exit: Option<PathBuf>,
+
     /// Don't restore previous workspace (only hot-exit content — unsaved
     /// modified files and unnamed buffers with content — is still restored
     /// so in-progress work is not lost)
@@ -107,6 +132,26 @@ struct Cli {
     #[arg(long, value_name = "LOCALE")]
     locale: Option<String>,
 
+    /// Run performance benchmarks against a generated corpus and print timings
+    /// Scenarios: open, scroll, type, mass-edit, search, resize
+    /// Options: corpus=(code|log|prose), lines=N, seed=N, iterations=N, json
+    #[arg(
+        long,
+        value_name = "SCENARIO,OPTION",
+        num_args = 0..=1,
+        require_equals = true,
+        value_delimiter = ','
+    )]
+    bench: Option<Vec<String>>,
+
+    /// Check what the terminal supports and print the findings
+    #[arg(long)]
+    doctor: bool,
+
+    /// Print how long each startup phase took, once the editor exits
+    #[arg(long)]
+    profile_startup: bool,
+
     // === Hidden internal flags ===
     /// Start as a daemon server (internal)
     #[arg(long, hide = true)]
@@ -123,6 +168,17 @@ struct Cli {
     #[arg(long, hide = true, value_name = "URL")]
     ssh_url: Option<String>,
 
+    /// Run the headless LSP proxy that shares language servers between
+    /// instances (internal, started by `--lsp-proxy-client`)
+    #[arg(long, hide = true)]
+    lsp_proxy: bool,
+
+    /// Relay stdio to a shared language server through the LSP proxy
+    /// (internal, used when `lsp_shared_servers` is on). The server's
+    /// command line follows `--` in FILES.
+    #[arg(long, hide = true)]
+    lsp_proxy_client: bool,
+
     // === Deprecated flags from pre-subcommand CLI (hidden, with warnings) ===
     /// [deprecated: use `fresh config show`]
     #[arg(long, hide = true)]
@@ -159,12 +215,17 @@ struct Cli {
 struct Args {
     files: Vec<String>,
     stdin: bool,
+    /// `--wait`: quit once the (last) CLI file's buffer is closed.
+    wait: bool,
+    /// `--diff OLD NEW`: open the two files in a diff view.
+    diff: Option<(PathBuf, PathBuf)>,
     no_plugins: bool,
     no_init: bool,
     safe: bool,
     config: Option<PathBuf>,
     log_file: Option<PathBuf>,
     event_log: Option<PathBuf>,
+    screenshot_on_exit: Option<PathBuf>,
     no_session: bool,
     /// Force workspace restore even if `editor.restore_previous_session`
     /// is disabled in the config.
@@ -464,15 +525,25 @@ impl From<Cli> for Args {
         let no_plugins = cli.no_plugins || safe;
         let no_init = cli.no_init || safe;
 
+        let diff = cli
+            .diff
+            .and_then(|paths| match <[PathBuf; 2]>::try_from(paths) {
+                Ok([old, new]) => Some((old, new)),
+                Err(_) => None,
+            });
+
         Args {
             files,
             stdin: cli.stdin,
+            wait: cli.wait,
+            diff,
             no_plugins,
             no_init,
             safe,
             config: cli.config,
             log_file: cli.log_file,
             event_log: cli.event_log,
+            screenshot_on_exit: cli.screenshot_on_exit,
             no_session: cli.no_restore,
             force_restore: cli.restore,
             no_upgrade_check: cli.no_upgrade_check,
@@ -549,8 +620,11 @@ struct IterationOutcome {
 
 struct SetupState {
     config: config::Config,
+    /// Why the layered config didn't load, if it didn't; `config` then
+    /// holds the defaults and the editor starts in config rescue mode.
+    config_failure: Option<fresh::config_io::ConfigLoadFailure>,
     tracing_handles: Option<TracingHandles>,
-    terminal: Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>,
+    terminal: Terminal<TerminalBackend<io::Stdout>>,
     terminal_size: (u16, u16),
     file_locations: Vec<FileLocation>,
     show_file_explorer: bool,
@@ -838,7 +912,8 @@ fn handle_first_run_setup(
     // the launch as a focused "open these files" invocation: skip the full
     // session restore but still recover hot-exit content. `--restore` (force)
     // is a deliberate user override that wins.
-    let cli_has_file_args = file_locations.iter().any(|loc| !loc.path.is_dir());
+    let cli_has_file_args =
+        args.diff.is_some() || file_locations.iter().any(|loc| !loc.path.is_dir());
     let cli_overrides_restore = cli_has_file_args
         && editor
             .config()
@@ -919,12 +994,16 @@ fn handle_first_run_setup(
     // Queue CLI files to be opened after the TUI starts
     // This ensures they go through the same code path as interactive file opens,
     // with consistent error handling (e.g., encoding confirmation prompts in the UI)
+    // With --wait, only the last file carries the wait (it's the one left
+    // active), mirroring the daemon server.
+    let last_file = file_locations.iter().rposition(|loc| !loc.path.is_dir());
     let mut has_cli_files = false;
-    for loc in file_locations {
+    for (i, loc) in file_locations.iter().enumerate() {
         if loc.path.is_dir() {
             continue;
         }
         tracing::info!("[SYNTAX DEBUG] Queueing CLI file for open: {:?}", loc.path);
+        let wait_id = (args.wait && Some(i) == last_file).then_some(fresh::app::CLI_WAIT_ID);
         editor.queue_file_open(
             loc.path.clone(),
             loc.line,
@@ -932,11 +1011,17 @@ fn handle_first_run_setup(
             loc.end_line,
             loc.end_column,
             loc.message.clone(),
-            None,
+            wait_id,
         );
         has_cli_files = true;
     }
 
+    if let Some((old, new)) = &args.diff {
+        if let Err(e) = editor.open_diff_view(old, new) {
+            editor.set_status_message(fresh::i18n::error_opening_file_message(&e.to_string()));
+        }
+    }
+
     // Schedule hot exit recovery for CLI-opened files (not covered by workspace restore)
     if has_cli_files {
         editor.schedule_hot_exit_recovery();
@@ -1729,10 +1814,10 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
         workspace_trust.level() == fresh::services::workspace_trust::TrustLevel::Trusted,
     );
 
-    let mut config = if let Some(config_path) = &args.config {
+    let (mut config, config_failure) = if let Some(config_path) = &args.config {
         // Explicit config file overrides layered system
         match config::Config::load_from_file(config_path) {
-            Ok(cfg) => cfg,
+            Ok(cfg) => (cfg, None),
             Err(e) => {
                 eprintln!(
                     "Error: Failed to load config from {}: {}",
@@ -1743,7 +1828,7 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
             }
         }
     } else {
-        config::Config::load_with_layers(&dir_context, &effective_working_dir)
+        config::Config::load_with_layers_or_failure(&dir_context, &effective_working_dir)
     };
 
     tracing::info!("Config loaded");
@@ -1752,6 +1837,7 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
     // CLI flag overrides config
     if args.no_upgrade_check {
         config.check_for_updates = false;
+        config.telemetry = Some(false);
     }
 
     // Initialize i18n with locale: CLI arg > config > environment
@@ -1761,12 +1847,7 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
 
     // Enable terminal modes (raw mode, alternate screen, mouse capture, etc.)
     // This checks support for each mode and tracks what was enabled
-    let keyboard_config = KeyboardConfig {
-        disambiguate_escape_codes: config.editor.keyboard_disambiguate_escape_codes,
-        report_event_types: config.editor.keyboard_report_event_types,
-        report_alternate_keys: config.editor.keyboard_report_alternate_keys,
-        report_all_keys_as_escape_codes: config.editor.keyboard_report_all_keys_as_escape_codes,
-    };
+    let keyboard_config = KeyboardConfig::from_config(&config);
     tracing::info!("Enabling terminal modes...");
     let terminal_modes = TerminalModes::enable(Some(&keyboard_config))?;
     tracing::info!("Terminal modes enabled");
@@ -1798,7 +1879,7 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
     tracing::info!("Set cursor style to {:?}", config.editor.cursor_style);
 
     tracing::info!("Initializing terminal backend...");
-    let backend = ratatui::backend::CrosstermBackend::new(stdout());
+    let backend = TerminalBackend::new(stdout());
     let mut terminal = Terminal::new(backend)?;
     terminal.clear()?;
     tracing::info!("Terminal backend ready");
@@ -1824,6 +1905,7 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
     tracing::info!("Key translator loaded, returning SetupState");
     Ok(SetupState {
         config,
+        config_failure,
         tracing_handles,
         terminal,
         terminal_size: (size.width, size.height),
@@ -1844,7 +1926,7 @@ fn initialize_app(args: &Args) -> AnyhowResult<SetupState> {
 fn run_editor_iteration(
     editor: &mut Editor,
     workspace_enabled: bool,
-    terminal: &mut Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>,
+    terminal: &mut Terminal<TerminalBackend<io::Stdout>>,
     key_translator: &KeyTranslator,
     #[cfg(target_os = "linux")] gpm_client: &Option<GpmClient>,
     terminal_modes: &mut TerminalModes,
@@ -3106,8 +3188,9 @@ fn client_handshake(conn: &fresh::server::ipc::ClientConnection) -> AnyhowResult
 fn try_forward_nested(args: &Args) -> Option<AnyhowResult<()>> {
     // Only plain interactive file/dir opens are forwarded. Subcommands,
     // --server and --attach are already handled before we get here;
-    // --stdin pipes content into a real editor and can't be forwarded.
-    if args.server || args.attach || args.stdin || args.files.is_empty() {
+    // --stdin pipes content into a real editor and can't be forwarded, and
+    // --diff builds its view here.
+    if args.server || args.attach || args.stdin || args.diff.is_some() || args.files.is_empty() {
         return None;
     }
 
@@ -4135,18 +4218,27 @@ fn build_localized_cli_command() -> clap::Command {
         .mut_arg("files", |a| a.help(t("cli.arg.files")))
         .mut_arg("attach", |a| a.help(t("cli.arg.attach")))
         .mut_arg("stdin", |a| a.help(t("cli.arg.stdin")))
+        .mut_arg("wait", |a| a.help(t("cli.arg.wait")))
+        .mut_arg("diff", |a| a.help(t("cli.arg.diff")))
         .mut_arg("no_plugins", |a| a.help(t("cli.arg.no_plugins")))
         .mut_arg("no_init", |a| a.help(t("cli.arg.no_init")))
         .mut_arg("safe", |a| a.help(t("cli.arg.safe")))
         .mut_arg("config", |a| a.help(t("cli.arg.config")))
         .mut_arg("log_file", |a| a.help(t("cli.arg.log_file")))
+        .mut_arg("portable", |a| a.help(t("cli.arg.portable")))
         .mut_arg("event_log", |a| a.help(t("cli.arg.event_log")))
+        .mut_arg("screenshot_on_exit", |a| {
+            a.help(t("cli.arg.screenshot_on_exit"))
+        })
         .mut_arg("no_restore", |a| a.help(t("cli.arg.no_restore")))
         .mut_arg("restore", |a| a.help(t("cli.arg.restore")))
         .mut_arg("no_upgrade_check", |a| {
             a.help(t("cli.arg.no_upgrade_check"))
         })
-        .mut_arg("locale", |a| a.help(t("cli.arg.locale")));
+        .mut_arg("locale", |a| a.help(t("cli.arg.locale")))
+        .mut_arg("bench", |a| a.help(t("cli.arg.bench")))
+        .mut_arg("doctor", |a| a.help(t("cli.arg.doctor")))
+        .mut_arg("profile_startup", |a| a.help(t("cli.arg.profile_startup")));
 
     #[cfg(feature = "gui")]
     let cmd = cmd.mut_arg("gui", |a| a.help(t("cli.arg.gui")));
@@ -4154,7 +4246,32 @@ fn build_localized_cli_command() -> clap::Command {
     cmd
 }
 
+/// Turn on portable mode from `--portable <dir>` or an inherited
+/// `FRESH_HOME`. The root is made absolute, created, and written back to
+/// `FRESH_HOME` so the daemon and other child processes resolve the same
+/// directory regardless of their working directory.
+fn enable_portable_mode(flag: Option<&Path>) -> AnyhowResult<()> {
+    use fresh::config_io::PORTABLE_HOME_ENV;
+
+    let Some(dir) = flag.map(Path::to_path_buf).or_else(|| {
+        std::env::var_os(PORTABLE_HOME_ENV)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+    }) else {
+        return Ok(());
+    };
+    let dir = std::path::absolute(&dir).unwrap_or(dir);
+    std::fs::create_dir_all(&dir)
+        .with_context(|| format!("Failed to create portable directory {}", dir.display()))?;
+    std::env::set_var(PORTABLE_HOME_ENV, &dir);
+    Ok(())
+}
+
 fn real_main() -> AnyhowResult<()> {
+    // Started before anything else so `--profile-startup` covers argument
+    // parsing too; dropped unless the flag is given.
+    let startup_started = fresh::app::startup_profile::StartupProfile::start();
+
     // Early check, otherwise terminal check would fail.
     #[cfg(all(windows, feature = "gui"))]
     let console_available = unsafe { AttachConsole(ATTACH_PARENT_PROCESS) != 0 };
@@ -4180,9 +4297,41 @@ fn real_main() -> AnyhowResult<()> {
         Err(e) => e.exit(),
     };
 
+    let mut startup_profile = cli.profile_startup.then_some(startup_started);
+    if let Some(profile) = startup_profile.as_mut() {
+        profile.mark("parse arguments");
+    }
+
     // Print deprecation warnings for old flags
     print_deprecation_warnings(&cli);
 
+    // Portable mode has to be settled before anything resolves a config,
+    // data, log or cache path.
+    enable_portable_mode(cli.portable.as_deref())?;
+
+    // `--bench` drives a headless editor and never touches the terminal.
+    if let Some(bench_args) = &cli.bench {
+        if let Err(e) = fresh::app::bench::run(bench_args) {
+            eprintln!("fresh --bench: {e:#}");
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // `--doctor` only queries the terminal and prints what it found.
+    if cli.doctor {
+        return fresh::services::terminal_probe::run_doctor();
+    }
+
+    // The LSP proxy and its relay are headless and speak LSP on stdio or
+    // sockets, so they skip all terminal setup.
+    if cli.lsp_proxy {
+        return Ok(fresh::server::lsp_proxy::run_proxy()?);
+    }
+    if cli.lsp_proxy_client {
+        return Ok(fresh::server::lsp_proxy::run_relay(&cli.files)?);
+    }
+
     // Agent command-channel verbs (`cmd`, `split`, `workspace`) run against a
     // live editor and never spawn a daemon, so handle them here — before the
     // `Args` conversion, whose slice match would otherwise reject them as
@@ -4236,6 +4385,7 @@ fn real_main() -> AnyhowResult<()> {
 
     let SetupState {
         config,
+        mut config_failure,
         mut tracing_handles,
         mut terminal,
         terminal_size,
@@ -4253,6 +4403,9 @@ fn real_main() -> AnyhowResult<()> {
         authority: startup_authority,
         _remote_session: remote_session,
     } = initialize_app(&args).context("Failed to initialize application")?;
+    if let Some(profile) = startup_profile.as_mut() {
+        profile.mark("load config and set up terminal");
+    }
 
     let mut current_working_dir = initial_working_dir;
     let (terminal_width, terminal_height) = terminal_size;
@@ -4286,6 +4439,7 @@ fn real_main() -> AnyhowResult<()> {
     // "status log not available" for every status message after the
     // restart.
     let status_log_path: Option<PathBuf> = tracing_handles.as_ref().map(|h| h.status.path.clone());
+    let editor_log_path: Option<PathBuf> = tracing_handles.as_ref().map(|h| h.log_path.clone());
 
     // Warning-log channel survives across restarts the same way,
     // except the `Receiver<()>` is single-consumer and can't be
@@ -4306,6 +4460,9 @@ fn real_main() -> AnyhowResult<()> {
         tracing::warn!("Local control socket unavailable: {}", e);
     }
 
+    // `--profile-startup` timings, printed once the terminal is restored.
+    let mut finished_startup_profile = None;
+
     // Main editor loop - supports restarting with a new working directory
     // Returns (loop_result, last_update_result) tuple
     let (result, last_update_result) = loop {
@@ -4353,6 +4510,9 @@ fn real_main() -> AnyhowResult<()> {
         )
         .context("Failed to create editor instance")?;
         tracing::info!("Editor instance created");
+        if let Some(profile) = startup_profile.as_mut() {
+            profile.mark("create editor");
+        }
 
         // Orchestrator cross-restart persistence is now loaded by
         // `Editor::with_options` before construction — it reads
@@ -4377,6 +4537,9 @@ fn real_main() -> AnyhowResult<()> {
         // plugins_loaded lifecycle hook so init.ts `on("plugins_loaded",
         // fn)` callbacks can configure plugins via getPluginApi.
         editor.fire_plugins_loaded_hook();
+        if let Some(profile) = startup_profile.as_mut() {
+            profile.mark("queue plugins and init script");
+        }
 
         #[cfg(target_os = "linux")]
         if gpm_client.is_some() {
@@ -4388,6 +4551,16 @@ fn real_main() -> AnyhowResult<()> {
         // `TerminalModes::enable` turned on above (#2504). Re-applied on every
         // editor instance (authority-swap restarts rebuild the editor).
         editor.set_mouse_capture(terminal_modes.mouse_capture_enabled());
+        editor.set_no_upgrade_check(args.no_upgrade_check);
+
+        // Ask the terminal what it supports. Unlike crossterm's probe this
+        // doesn't wait: the replies reach the editor through the event loop.
+        // Asked again for every editor instance, since a rebuilt editor
+        // starts over.
+        editor.set_terminal_capabilities(
+            fresh::services::terminal_probe::TerminalCapabilities::detect(color_capability),
+        );
+        fresh::services::terminal_modes::query_capabilities();
 
         // Re-wire the tracing log paths into every editor instance,
         // not just the first. Status-bar click → open log, warning
@@ -4396,6 +4569,9 @@ fn real_main() -> AnyhowResult<()> {
         if let Some(p) = status_log_path.as_ref() {
             editor.set_status_log_path(p.clone());
         }
+        if let Some(p) = editor_log_path.as_ref() {
+            editor.set_editor_log_path(p.clone());
+        }
         if let Some((rx, p)) = warning_log_slot.take() {
             editor.set_warning_log(rx, p);
         }
@@ -4442,6 +4618,12 @@ fn real_main() -> AnyhowResult<()> {
         // would race the file in and open on top of it.
         editor.process_pending_file_opens();
 
+        // A config that failed to load opens on top of the CLI files so
+        // its parse error is the first thing the user sees.
+        if let Some(failure) = config_failure.take() {
+            editor.enter_config_rescue(failure);
+        }
+
         // Workspace restored, initial buffers opened, recovery session up —
         // fire the `ready` lifecycle hook (design M2, §3.3 phase 3) before
         // handing off to the event loop.
@@ -4453,6 +4635,22 @@ fn real_main() -> AnyhowResult<()> {
         // the mandatory open-time gate, so the secondary button is Quit
         // (`cancellable = false`).
         editor.maybe_prompt_workspace_trust(false);
+        // Ask about telemetry once per launch until it's answered (stays
+        // quiet if the trust prompt is up).
+        if first_run {
+            editor.maybe_prompt_telemetry_consent();
+        }
+
+        if let Some(path) = &args.screenshot_on_exit {
+            editor.set_screenshot_on_exit(path.clone());
+        }
+
+        // The first frame finishes the startup profile; only the first
+        // editor instance is profiled.
+        if let Some(mut profile) = startup_profile.take() {
+            profile.mark("open files and run startup hooks");
+            editor.set_startup_profile(profile);
+        }
 
         let iteration = run_editor_iteration(
             &mut editor,
@@ -4506,6 +4704,9 @@ fn real_main() -> AnyhowResult<()> {
         // Pluck the warning-log channel back out of the soon-to-be-
         // dropped editor so the next iteration can re-bind it.
         warning_log_slot = editor.take_warning_log();
+        if let Some(profile) = editor.take_startup_profile() {
+            finished_startup_profile = Some(profile);
+        }
 
         // Persist every session before a restart rebuilds the editor from
         // disk. Quit already saves (after the loop); the restart branch did
@@ -4517,6 +4718,8 @@ fn real_main() -> AnyhowResult<()> {
             if let Err(e) = editor.save_all_windows_workspaces() {
                 tracing::warn!("Failed to save sessions before restart: {e}");
             }
+        } else if let Err(e) = editor.write_exit_screenshot() {
+            tracing::warn!("Failed to write exit screenshot: {}", e);
         }
 
         drop(editor);
@@ -4548,6 +4751,10 @@ fn real_main() -> AnyhowResult<()> {
     #[cfg(windows)]
     let _ = fresh_winterm::restore_console_mode(original_console_mode);
 
+    if let Some(profile) = finished_startup_profile {
+        eprint!("{}", profile.report());
+    }
+
     // Check for updates after terminal is restored (using cached result)
     if let Some(update_result) = last_update_result {
         if update_result.update_available {
@@ -4590,15 +4797,7 @@ fn handle_suspend_request(
 ) -> AnyhowResult<()> {
     #[cfg(unix)]
     {
-        let keyboard_config = KeyboardConfig {
-            disambiguate_escape_codes: editor.config().editor.keyboard_disambiguate_escape_codes,
-            report_event_types: editor.config().editor.keyboard_report_event_types,
-            report_alternate_keys: editor.config().editor.keyboard_report_alternate_keys,
-            report_all_keys_as_escape_codes: editor
-                .config()
-                .editor
-                .keyboard_report_all_keys_as_escape_codes,
-        };
+        let keyboard_config = KeyboardConfig::from_config(editor.config());
         terminal_modes::suspend_and_resume(terminal_modes, Some(&keyboard_config))
             .context("Failed to suspend process")?;
         editor.request_full_redraw();
@@ -4616,7 +4815,7 @@ fn handle_suspend_request(
 #[cfg(target_os = "linux")]
 fn run_event_loop(
     editor: &mut Editor,
-    terminal: &mut Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>,
+    terminal: &mut Terminal<TerminalBackend<io::Stdout>>,
     workspace_enabled: bool,
     key_translator: &KeyTranslator,
     gpm_client: &Option<GpmClient>,
@@ -4640,7 +4839,7 @@ fn run_event_loop(
 #[cfg(windows)]
 fn run_event_loop(
     editor: &mut Editor,
-    terminal: &mut Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>,
+    terminal: &mut Terminal<TerminalBackend<io::Stdout>>,
     workspace_enabled: bool,
     key_translator: &KeyTranslator,
     terminal_modes: &mut TerminalModes,
@@ -4697,6 +4896,9 @@ fn run_event_loop(
                         for ev in parsed {
                             event_buffer.push_back(ev);
                         }
+                        fresh::services::terminal_modes::push_terminal_replies(
+                            input_parser.take_replies(),
+                        );
                         got_any = true;
                     }
                     Some(VtInputEvent::Resize) => {
@@ -4740,7 +4942,7 @@ fn run_event_loop(
 #[cfg(not(any(target_os = "linux", windows)))]
 fn run_event_loop(
     editor: &mut Editor,
-    terminal: &mut Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>,
+    terminal: &mut Terminal<TerminalBackend<io::Stdout>>,
     workspace_enabled: bool,
     key_translator: &KeyTranslator,
     terminal_modes: &mut TerminalModes,
@@ -4806,7 +5008,7 @@ fn safe_event_read() -> std::io::Result<Option<CrosstermEvent>> {
 
 fn run_event_loop_common<F>(
     editor: &mut Editor,
-    terminal: &mut Terminal<ratatui::backend::CrosstermBackend<io::Stdout>>,
+    terminal: &mut Terminal<TerminalBackend<io::Stdout>>,
     workspace_enabled: bool,
     _key_translator: &KeyTranslator,
     terminal_modes: &mut TerminalModes,
@@ -4919,6 +5121,12 @@ where
                 use crossterm::ExecutableCommand;
                 stdout().execute(crossterm::terminal::BeginSynchronizedUpdate)?;
                 terminal.draw(|frame| editor.render(frame))?;
+                if let Some(style) = editor.take_cursor_style_change() {
+                    stdout().execute(style.to_crossterm_style())?;
+                }
+                if let Some(graphics) = editor.take_graphics_output() {
+                    stdout().write_all(&graphics)?;
+                }
                 stdout().execute(crossterm::terminal::EndSynchronizedUpdate)?;
             }
             tracing::info!(target: "paste_timing", "render: {}ms (paste_pending={})", r0.elapsed().as_millis(), was_paste_pending);
@@ -4948,6 +5156,14 @@ where
             poll_event(timeout)?
         };
 
+        // Replies to terminal queries (keyboard protocol) arrive as input
+        // but aren't events; hand them to the editor directly.
+        for reply in fresh::services::terminal_modes::take_terminal_replies() {
+            if editor.apply_terminal_reply(reply) {
+                needs_render = true;
+            }
+        }
+
         let Some(event) = event else {
             // No input this cycle. If the editor has been idle long enough,
             // start the wave-animation screensaver (it renders on the next
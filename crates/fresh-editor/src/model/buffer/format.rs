@@ -86,6 +86,9 @@ pub struct BufferFormat {
     /// so save never rewrites its raw bytes and content round-trips
     /// byte-for-byte (issue #2736 vs. content-preservation invariants).
     line_endings_normalized: bool,
+    /// Whether the content had more than one kind of line break when it
+    /// was loaded. `line_ending` is then only the dominant kind.
+    mixed_line_endings: bool,
 }
 
 impl BufferFormat {
@@ -106,6 +109,7 @@ impl BufferFormat {
             encoding,
             original_encoding: encoding,
             line_endings_normalized,
+            mixed_line_endings: false,
         }
     }
 
@@ -139,6 +143,26 @@ impl BufferFormat {
         self.line_endings_normalized = le == LineEnding::CR;
     }
 
+    /// Record that the content itself was rewritten to use `le` throughout
+    /// (e.g. by a convert-line-endings edit). Unlike
+    /// [`set_line_ending`](Self::set_line_ending) this schedules no
+    /// conversion on save: the bytes are already right, and undoing the
+    /// edit must bring the old bytes back unchanged.
+    pub fn set_converted_line_ending(&mut self, le: LineEnding) {
+        self.line_ending = le;
+        self.original_line_ending = le;
+        self.line_endings_normalized = false;
+        self.mixed_line_endings = false;
+    }
+
+    pub fn mixed_line_endings(&self) -> bool {
+        self.mixed_line_endings
+    }
+
+    pub fn set_mixed_line_endings(&mut self, mixed: bool) {
+        self.mixed_line_endings = mixed;
+    }
+
     pub fn set_encoding(&mut self, e: Encoding) {
         self.encoding = e;
     }
@@ -167,6 +191,10 @@ impl BufferFormat {
     /// Called after a successful save to make the current values the
     /// new "original" baseline.
     pub(super) fn promote_current_to_original(&mut self) {
+        // A save that changed the line ending rewrote every break.
+        if self.line_ending_changed_since_load() {
+            self.mixed_line_endings = false;
+        }
         self.original_line_ending = self.line_ending;
         self.original_encoding = self.encoding;
     }
@@ -174,47 +202,64 @@ impl BufferFormat {
 
 // ---------- free helpers (Rule 4 in the refactor plan) ----------
 
-/// Detect the line ending format from a sample of bytes
-///
-/// Uses majority voting: counts CRLF, LF-only, and CR-only
-/// occurrences and returns the most common format.
-pub fn detect_line_ending(bytes: &[u8]) -> LineEnding {
-    // Only check the first 8KB for line ending detection (same as binary detection)
-    let check_len = bytes.len().min(8 * 1024);
-    let sample = &bytes[..check_len];
-
-    let mut crlf_count = 0;
-    let mut lf_only_count = 0;
-    let mut cr_only_count = 0;
+/// Number of each kind of line break in a run of bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LineEndingCounts {
+    pub lf: usize,
+    pub crlf: usize,
+    pub cr: usize,
+}
 
-    let mut i = 0;
-    while i < sample.len() {
-        if sample[i] == b'\r' {
-            // Check if this is CRLF
-            if i + 1 < sample.len() && sample[i + 1] == b'\n' {
-                crlf_count += 1;
-                i += 2; // Skip both \r and \n
-                continue;
-            } else {
-                // CR only (old Mac format)
-                cr_only_count += 1;
+impl LineEndingCounts {
+    /// Count the line breaks in `bytes`. A `\r\n` pair counts once, as
+    /// CRLF.
+    pub fn scan(bytes: &[u8]) -> Self {
+        let mut counts = Self::default();
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\r' if bytes.get(i + 1) == Some(&b'\n') => {
+                    counts.crlf += 1;
+                    i += 1;
+                }
+                b'\r' => counts.cr += 1,
+                b'\n' => counts.lf += 1,
+                _ => {}
             }
-        } else if sample[i] == b'\n' {
-            // LF only (Unix format)
-            lf_only_count += 1;
+            i += 1;
         }
-        i += 1;
+        counts
     }
 
-    // Use majority voting to determine line ending
-    if crlf_count > lf_only_count && crlf_count > cr_only_count {
-        LineEnding::CRLF
-    } else if cr_only_count > lf_only_count && cr_only_count > crlf_count {
-        LineEnding::CR
-    } else {
-        // Default to LF if no clear winner or if LF wins
-        LineEnding::LF
+    /// Whether more than one kind of line break occurs.
+    pub fn is_mixed(&self) -> bool {
+        [self.lf, self.crlf, self.cr]
+            .iter()
+            .filter(|&&n| n > 0)
+            .count()
+            > 1
     }
+
+    /// The most common kind, by majority vote. Ties and break-less content
+    /// fall back to LF.
+    pub fn dominant(&self) -> LineEnding {
+        if self.crlf > self.lf && self.crlf > self.cr {
+            LineEnding::CRLF
+        } else if self.cr > self.lf && self.cr > self.crlf {
+            LineEnding::CR
+        } else {
+            LineEnding::LF
+        }
+    }
+}
+
+/// Detect the line ending format from a sample of bytes
+///
+/// Uses majority voting over the first 8KB (same as binary detection); see
+/// [`LineEndingCounts::dominant`].
+pub fn detect_line_ending(bytes: &[u8]) -> LineEnding {
+    let check_len = bytes.len().min(8 * 1024);
+    LineEndingCounts::scan(&bytes[..check_len]).dominant()
 }
 
 /// Detect the text encoding from a sample of bytes.
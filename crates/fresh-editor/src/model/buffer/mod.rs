@@ -25,7 +25,7 @@ pub mod persistence;
 pub mod save;
 pub mod search;
 pub use file_kind::BufferFileKind;
-pub use format::{BufferFormat, LineEnding};
+pub use format::{BufferFormat, LineEnding, LineEndingCounts};
 pub use persistence::Persistence;
 pub use save::SudoSaveRequired;
 #[cfg(test)]
@@ -390,6 +390,7 @@ impl TextBuffer {
     ) -> Self {
         // Auto-detect line ending format from content
         let line_ending = format::detect_line_ending(&utf8_content);
+        let mixed = LineEndingCounts::scan(&utf8_content).is_mixed();
 
         // Only normalize CR -> `\n` when this is an "open for editing" load
         // (`normalize_cr`) AND the content is genuinely CR-delimited. A
@@ -421,7 +422,12 @@ impl TextBuffer {
             next_buffer_id: 1,
             persistence: Persistence::new(fs, None, saved_root, Some(bytes)),
             file_kind: BufferFileKind::new(false, false),
-            format: BufferFormat::with_normalization(line_ending, encoding, normalized),
+            format: {
+                let mut format =
+                    BufferFormat::with_normalization(line_ending, encoding, normalized);
+                format.set_mixed_line_endings(mixed);
+                format
+            },
             version: 0,
             config: BufferConfig::default(),
         }
@@ -658,8 +664,10 @@ impl TextBuffer {
             return Ok(buffer);
         }
 
-        // UTF-8/ASCII files can use lazy loading
+        // UTF-8/ASCII files can use lazy loading. Mixed line endings are
+        // only noticed if they show up in the sample.
         let line_ending = format::detect_line_ending(&sample);
+        let mixed = LineEndingCounts::scan(&sample).is_mixed();
 
         // NOTE: unlike the small-file text path, lazy loading deliberately
         // does NOT normalize CR (`\r`) separators to `\n`. The large-file
@@ -710,7 +718,11 @@ impl TextBuffer {
                 Some(file_size),
             ),
             file_kind: BufferFileKind::new(true, is_binary),
-            format: BufferFormat::new(line_ending, encoding),
+            format: {
+                let mut format = BufferFormat::new(line_ending, encoding);
+                format.set_mixed_line_endings(mixed && !is_binary);
+                format
+            },
             version: 0,
             config: BufferConfig::default(),
         })
@@ -2493,6 +2505,20 @@ impl TextBuffer {
         self.format.set_default_line_ending(line_ending);
     }
 
+    /// Whether the file had more than one kind of line break when loaded
+    /// (and hasn't been converted since). `line_ending()` is then the
+    /// dominant kind.
+    pub fn has_mixed_line_endings(&self) -> bool {
+        self.format.mixed_line_endings()
+    }
+
+    /// Record that an edit rewrote every line break to `line_ending`, so
+    /// save writes the content as-is. See
+    /// [`BufferFormat::set_converted_line_ending`].
+    pub fn set_converted_line_ending(&mut self, line_ending: LineEnding) {
+        self.format.set_converted_line_ending(line_ending);
+    }
+
     /// Get the encoding format for this buffer
     pub fn encoding(&self) -> Encoding {
         self.format.encoding()
@@ -2507,6 +2533,65 @@ impl TextBuffer {
         self.mark_content_modified();
     }
 
+    /// Find the first character that `encoding` cannot represent, so a
+    /// save in that encoding can be refused instead of altering the text.
+    ///
+    /// Regions of a large file that haven't been loaded are read from disk
+    /// a chunk at a time and dropped again, like saving does, rather than
+    /// loading the whole file into memory.
+    pub fn first_unencodable_char(&self, encoding: Encoding) -> io::Result<Option<char>> {
+        // Bytes of a multi-byte character cut off at the end of a chunk.
+        let mut carry: Vec<u8> = Vec::new();
+        let mut check = |bytes: &[u8]| -> Option<char> {
+            carry.extend_from_slice(bytes);
+            let complete = carry.len() - incomplete_utf8_tail(&carry);
+            let text = String::from_utf8_lossy(&carry[..complete]);
+            let found = encoding::first_unencodable_char(&text, encoding);
+            carry.drain(..complete);
+            found
+        };
+
+        for piece_view in self
+            .piece_tree
+            .iter_pieces_in_range(0, self.piece_tree.total_bytes())
+        {
+            let Some(buffer) = self.buffers.get(piece_view.location.buffer_id()) else {
+                continue;
+            };
+            match &buffer.data {
+                BufferData::Loaded { data, .. } => {
+                    let start = piece_view.buffer_offset;
+                    if let Some(c) = check(&data[start..start + piece_view.bytes]) {
+                        return Ok(Some(c));
+                    }
+                }
+                BufferData::Unloaded {
+                    file_path,
+                    file_offset,
+                    ..
+                } => {
+                    let start = file_offset + piece_view.buffer_offset;
+                    let mut read = 0;
+                    while read < piece_view.bytes {
+                        let len = (piece_view.bytes - read).min(LOAD_CHUNK_SIZE);
+                        let chunk = self.persistence.fs().read_range(
+                            file_path,
+                            (start + read) as u64,
+                            len,
+                        )?;
+                        if let Some(c) = check(&chunk) {
+                            return Ok(Some(c));
+                        }
+                        read += len;
+                    }
+                }
+            }
+        }
+        // A truncated character at the very end decodes to U+FFFD.
+        let rest = String::from_utf8_lossy(&carry).into_owned();
+        Ok(encoding::first_unencodable_char(&rest, encoding))
+    }
+
     /// Set the default encoding format for a new/empty buffer
     ///
     /// Unlike `set_encoding`, this does NOT mark the buffer as modified.
@@ -2526,6 +2611,41 @@ impl TextBuffer {
         Some(String::from_utf8_lossy(&bytes).into_owned())
     }
 
+    /// Get the first and last few lines of the buffer as lossy UTF-8, joined
+    /// with newlines — where Vim and Emacs look for modelines. Returns `None`
+    /// for an empty buffer. Only a bounded window at each end is read, so
+    /// this is cheap on huge files.
+    pub fn modeline_region_lossy(&self) -> Option<String> {
+        const LINES: usize = 5;
+        const WINDOW: usize = 4096;
+        let total = self.total_bytes();
+        if total == 0 {
+            return None;
+        }
+        if total <= 2 * WINDOW {
+            let text = String::from_utf8_lossy(&self.get_text_range(0, total)?).into_owned();
+            let lines: Vec<&str> = text.lines().collect();
+            if lines.len() <= 2 * LINES {
+                return Some(text);
+            }
+            let tail = &lines[lines.len() - LINES..];
+            return Some([&lines[..LINES], tail].concat().join("\n"));
+        }
+        let head = self.get_text_range(0, WINDOW)?;
+        let tail = self.get_text_range(total - WINDOW, WINDOW)?;
+        let head = String::from_utf8_lossy(&head);
+        let tail = String::from_utf8_lossy(&tail);
+        // The tail window's first line is usually cut off mid-line; skip it.
+        let tail_lines: Vec<&str> = tail.lines().skip(1).collect();
+        let tail_start = tail_lines.len().saturating_sub(LINES);
+        let lines: Vec<&str> = head
+            .lines()
+            .take(LINES)
+            .chain(tail_lines[tail_start..].iter().copied())
+            .collect();
+        Some(lines.join("\n"))
+    }
+
     /// Get text for a specific line
     pub fn get_line(&self, line: usize) -> Option<Vec<u8>> {
         let (start, end) = self.piece_tree.line_range(line, &self.buffers)?;
@@ -3736,3 +3856,20 @@ impl Iterator for TextBufferLineIterator {
         }
     }
 }
+
+/// Length of a multi-byte UTF-8 sequence cut off at the end of `bytes`.
+fn incomplete_utf8_tail(bytes: &[u8]) -> usize {
+    for back in 1..=bytes.len().min(3) {
+        let b = bytes[bytes.len() - back];
+        if b & 0xC0 != 0x80 {
+            let width = match b {
+                0xF0.. => 4,
+                0xE0.. => 3,
+                0xC0.. => 2,
+                _ => 1,
+            };
+            return if width > back { back } else { 0 };
+        }
+    }
+    0
+}
@@ -501,6 +501,44 @@ mod large_file_support {
         assert_eq!(buffer.buffers[0].get_data(), None);
     }
 
+    #[test]
+    fn test_unencodable_char_found_in_unloaded_large_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("large.txt");
+        std::fs::write(&file_path, "plain text, caf\u{e9} and \u{416}uk\n").unwrap();
+
+        let buffer = TextBuffer::load_from_file(&file_path, 10, test_fs()).unwrap();
+        assert!(buffer.to_string().is_none());
+
+        assert_eq!(
+            buffer
+                .first_unencodable_char(Encoding::Windows1251)
+                .unwrap(),
+            Some('\u{e9}')
+        );
+        assert_eq!(
+            buffer
+                .first_unencodable_char(Encoding::Windows1252)
+                .unwrap(),
+            Some('\u{416}')
+        );
+        assert_eq!(
+            buffer.first_unencodable_char(Encoding::Utf16Le).unwrap(),
+            None
+        );
+        // Checking doesn't load the file.
+        assert!(!buffer.buffers[0].is_loaded());
+    }
+
+    #[test]
+    fn test_incomplete_utf8_tail() {
+        let euro = "\u{20ac}".as_bytes();
+        assert_eq!(incomplete_utf8_tail(b"abc"), 0);
+        assert_eq!(incomplete_utf8_tail(euro), 0);
+        assert_eq!(incomplete_utf8_tail(&euro[..1]), 1);
+        assert_eq!(incomplete_utf8_tail(&[b"ab", &euro[..2]].concat()), 2);
+    }
+
     /// Test that reproduces issue #657: Search on large plain text files
     ///
     /// The bug: When a large file is opened with lazy loading, buffer.to_string()
@@ -1250,6 +1288,21 @@ fn test_detect_lf() {
     );
 }
 
+#[test]
+fn test_detect_mixed_line_endings() {
+    let counts = super::format::LineEndingCounts::scan(b"a\r\nb\r\nc\nd\r\n");
+    assert_eq!((counts.lf, counts.crlf, counts.cr), (1, 3, 0));
+    assert!(counts.is_mixed());
+    assert_eq!(counts.dominant(), LineEnding::CRLF);
+    assert!(!super::format::LineEndingCounts::scan(b"a\r\nb\r\n").is_mixed());
+
+    let buffer = TextBuffer::from_bytes(b"a\r\nb\r\nc\nd\r\n".to_vec(), test_fs());
+    assert!(buffer.has_mixed_line_endings());
+    assert_eq!(buffer.line_ending(), LineEnding::CRLF);
+    let buffer = TextBuffer::from_bytes(b"a\nb\n".to_vec(), test_fs());
+    assert!(!buffer.has_mixed_line_endings());
+}
+
 #[test]
 fn test_normalize_crlf() {
     let input = b"hello\r\nworld\r\n".to_vec();
@@ -2369,8 +2422,9 @@ mod rebuild_pristine_saved_root_tests {
                 mode: u32,
                 uid: u32,
                 gid: u32,
+                elevate: &[String],
             ) -> std::io::Result<()> {
-                self.inner.sudo_write(path, data, mode, uid, gid)
+                self.inner.sudo_write(path, data, mode, uid, gid, elevate)
             }
             fn search_file(
                 &self,
@@ -5,32 +5,127 @@
 //! `input/actions.rs`. They are free functions, not methods, to avoid
 //! growing `TextBuffer`'s API surface — both callers already have a
 //! `&Buffer` in hand.
+//!
+//! Columns are *display* columns, as block selections need: each grapheme
+//! cluster takes its rendered width (two for CJK and most emoji, none of
+//! its own for a combining mark), and a tab runs to the next multiple of
+//! `tab_size`. A column never splits a grapheme.
+
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::model::buffer::Buffer;
 use crate::model::cursor::Position2D;
+use crate::primitives::display_width::str_width;
+
+/// Display width of one grapheme cluster starting at display column `col`.
+fn grapheme_width(grapheme: &str, col: usize, tab_size: usize) -> usize {
+    if grapheme == "\t" {
+        let tab_size = tab_size.max(1);
+        tab_size - col % tab_size
+    } else {
+        str_width(grapheme)
+    }
+}
+
+/// The text of `line`, without its line ending.
+pub fn line_text(buffer: &Buffer, line: usize) -> String {
+    let content = buffer.get_line(line).unwrap_or_default();
+    let text = String::from_utf8_lossy(&content);
+    text.trim_end_matches(['\n', '\r']).to_string()
+}
+
+/// Display column at byte offset `byte` of `text`.
+pub fn display_column(text: &str, byte: usize, tab_size: usize) -> usize {
+    let mut col = 0;
+    for (start, grapheme) in text.grapheme_indices(true) {
+        if start >= byte {
+            break;
+        }
+        col += grapheme_width(grapheme, col, tab_size);
+    }
+    col
+}
 
-/// Convert a byte offset into a (line, column) position.
-///
-/// The column is measured in *bytes from the start of the line*, not
-/// visual columns — this matches the cursor position model used by the
-/// editing core.
-pub fn byte_to_2d(buffer: &Buffer, byte_pos: usize) -> Position2D {
+/// Display width of all of `text`.
+pub fn display_width(text: &str, tab_size: usize) -> usize {
+    display_column(text, text.len(), tab_size)
+}
+
+/// Bytes of `text` covered by the display columns `start..end`: every
+/// grapheme cluster that overlaps them, so a double-width character half
+/// inside the range is included whole. Columns past the end of `text`
+/// clamp to its length.
+pub fn display_columns_to_bytes(
+    text: &str,
+    start: usize,
+    end: usize,
+    tab_size: usize,
+) -> Range<usize> {
+    let mut col = 0;
+    let mut range_start = None;
+    let mut range_end = text.len();
+    for (byte, grapheme) in text.grapheme_indices(true) {
+        let width = grapheme_width(grapheme, col, tab_size);
+        if range_start.is_none() && col + width > start {
+            range_start = Some(byte);
+        }
+        if col >= end {
+            range_end = byte;
+            break;
+        }
+        col += width;
+    }
+    let range_start = range_start.unwrap_or(text.len());
+    range_start..range_end.max(range_start)
+}
+
+/// Convert a byte offset into a (line, display column) position.
+pub fn byte_to_2d(buffer: &Buffer, byte_pos: usize, tab_size: usize) -> Position2D {
     let line = buffer.get_line_number(byte_pos);
     let line_start = buffer.line_start_offset(line).unwrap_or(0);
-    let column = byte_pos.saturating_sub(line_start);
+    let column = display_column(
+        &line_text(buffer, line),
+        byte_pos.saturating_sub(line_start),
+        tab_size,
+    );
     Position2D { line, column }
 }
 
-/// Convert a 2D position into a byte offset, clamping the column to the
-/// line's byte length (excluding the trailing newline, if any).
-pub fn pos_2d_to_byte(buffer: &Buffer, pos: Position2D) -> usize {
+/// Convert a (line, display column) position into a byte offset: the start
+/// of the grapheme cluster covering the column, or the line's content end
+/// for columns past it.
+pub fn pos_2d_to_byte(buffer: &Buffer, pos: Position2D, tab_size: usize) -> usize {
     let line_start = buffer.line_start_offset(pos.line).unwrap_or(0);
-    let line_content = buffer.get_line(pos.line).unwrap_or_default();
-    let line_len = if line_content.last() == Some(&b'\n') {
-        line_content.len().saturating_sub(1)
-    } else {
-        line_content.len()
-    };
-    let clamped_col = pos.column.min(line_len);
-    line_start + clamped_col
+    let text = line_text(buffer, pos.line);
+    line_start + display_columns_to_bytes(&text, pos.column, pos.column + 1, tab_size).start
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_columns_count_wide_combining_and_tabs() {
+        // "a", "你" (2 columns), "e" + combining acute (1 column), tab.
+        let text = "a你e\u{301}\tz";
+        assert_eq!(display_column(text, 1, 4), 1);
+        assert_eq!(display_column(text, 4, 4), 3);
+        assert_eq!(display_column(text, 7, 4), 4);
+        assert_eq!(display_column(text, 8, 4), 8);
+        assert_eq!(display_width(text, 4), 9);
+    }
+
+    #[test]
+    fn column_ranges_cover_whole_graphemes() {
+        let text = "a你b";
+        // Columns 1..2 are the left half of "你": it is included whole.
+        assert_eq!(display_columns_to_bytes(text, 1, 2, 4), 1..4);
+        assert_eq!(display_columns_to_bytes(text, 2, 3, 4), 1..4);
+        assert_eq!(display_columns_to_bytes(text, 3, 4, 4), 4..5);
+        assert_eq!(display_columns_to_bytes(text, 0, 9, 4), 0..5);
+        assert_eq!(display_columns_to_bytes(text, 7, 9, 4), 5..5);
+        assert_eq!(display_columns_to_bytes(text, 1, 1, 4), 1..1);
+    }
 }
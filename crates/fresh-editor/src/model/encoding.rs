@@ -661,6 +661,38 @@ pub fn convert_from_utf8(utf8_bytes: &[u8], encoding: Encoding) -> Vec<u8> {
     }
 }
 
+/// Find the first character of `text` that `encoding` cannot represent.
+///
+/// `convert_from_utf8` never fails: encoding_rs writes unmappable characters
+/// as HTML numeric references and ASCII passes UTF-8 through untouched, so
+/// callers converting on save check this first to avoid silently altering
+/// the file.
+pub fn first_unencodable_char(text: &str, encoding: Encoding) -> Option<char> {
+    match encoding {
+        Encoding::Utf8
+        | Encoding::Utf8Bom
+        | Encoding::Utf16Le
+        | Encoding::Utf16Be
+        | Encoding::Gb18030 => None,
+        Encoding::Ascii => text.chars().find(|c| !c.is_ascii()),
+        _ => {
+            let mut encoder = encoding.to_encoding_rs().new_encoder();
+            let mut out = [0u8; 1024];
+            let mut rest = text;
+            loop {
+                let (result, read, _written) =
+                    encoder.encode_from_utf8_without_replacement(rest, &mut out, true);
+                rest = &rest[read..];
+                match result {
+                    encoding_rs::EncoderResult::Unmappable(c) => return Some(c),
+                    encoding_rs::EncoderResult::InputEmpty => return None,
+                    encoding_rs::EncoderResult::OutputFull => {}
+                }
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -758,6 +790,27 @@ mod tests {
         assert_eq!(back, [b'H', 0x00, b'i', 0x00]);
     }
 
+    #[test]
+    fn test_first_unencodable_char() {
+        assert_eq!(
+            first_unencodable_char("naïve 世界", Encoding::Utf16Le),
+            None
+        );
+        assert_eq!(first_unencodable_char("naïve", Encoding::Latin1), None);
+        assert_eq!(first_unencodable_char("naïve", Encoding::Ascii), Some('ï'));
+        assert_eq!(
+            first_unencodable_char("日本語 café", Encoding::ShiftJis),
+            Some('é')
+        );
+        // Past the encoder's internal output chunk.
+        let long = format!("{}ж", "a".repeat(5000));
+        assert_eq!(
+            first_unencodable_char(&long, Encoding::Windows1252),
+            Some('ж')
+        );
+        assert_eq!(first_unencodable_char(&long, Encoding::Windows1251), None);
+    }
+
     #[test]
     fn test_encoding_resynchronizable() {
         // Self-synchronizing encodings (can find char boundaries from middle of file)
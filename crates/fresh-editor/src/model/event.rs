@@ -61,6 +61,12 @@ pub enum Event {
     /// Center the viewport on the cursor
     Recenter,
 
+    /// Scroll so the cursor's line is at the top of the viewport
+    ScrollCursorToTop,
+
+    /// Scroll so the cursor's line is at the bottom of the viewport
+    ScrollCursorToBottom,
+
     /// Set the anchor (selection start) for a cursor
     SetAnchor {
         cursor_id: CursorId,
@@ -332,6 +338,9 @@ pub struct PopupListItemData {
     pub detail: Option<String>,
     pub icon: Option<String>,
     pub data: Option<String>,
+    /// Char indices in `text` to highlight as matching the typed filter
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub match_positions: Vec<usize>,
 }
 
 /// Popup position for events
@@ -432,6 +432,16 @@ pub trait FileSystem: Send + Sync {
     /// Read a range of bytes from a file (for lazy loading large files)
     fn read_range(&self, path: &Path, offset: u64, len: usize) -> io::Result<Vec<u8>>;
 
+    /// Read at most `max_len` bytes from the start of a file.
+    ///
+    /// Unlike `read_range`, a file shorter than `max_len` is not an error:
+    /// the whole file is returned. Used by picker previews, which only show
+    /// the head of a file and must not pull large files over slow links.
+    fn read_prefix(&self, path: &Path, max_len: usize) -> io::Result<Vec<u8>> {
+        let size = self.metadata(path)?.size;
+        self.read_range(path, 0, size.min(max_len as u64) as usize)
+    }
+
     /// Count `\n` bytes in a file range without returning the data.
     ///
     /// Used by the line-feed scanner to count newlines in unloaded chunks.
@@ -722,8 +732,17 @@ pub trait FileSystem: Send + Sync {
     /// - `mode`: File permissions (e.g., 0o644)
     /// - `uid`: Owner user ID
     /// - `gid`: Owner group ID
-    fn sudo_write(&self, path: &Path, data: &[u8], mode: u32, uid: u32, gid: u32)
-        -> io::Result<()>;
+    /// - `elevate`: Elevation command and its arguments (e.g. `["sudo"]`),
+    ///   prefixed to each of the `tee`, `chmod` and `chown` steps
+    fn sudo_write(
+        &self,
+        path: &Path,
+        data: &[u8],
+        mode: u32,
+        uid: u32,
+        gid: u32,
+        elevate: &[String],
+    ) -> io::Result<()>;
 
     // ========================================================================
     // Directory Walking
@@ -1349,6 +1368,14 @@ impl FileSystem for StdFileSystem {
         Ok(buffer)
     }
 
+    fn read_prefix(&self, path: &Path, max_len: usize) -> io::Result<Vec<u8>> {
+        let file = std::fs::File::open(path)?;
+        let mut buffer = Vec::new();
+        file.take(max_len as u64).read_to_end(&mut buffer)?;
+        crate::services::counters::global().inc_disk_bytes_read(buffer.len() as u64);
+        Ok(buffer)
+    }
+
     fn write_file(&self, path: &Path, data: &[u8]) -> io::Result<()> {
         let original_metadata = self.metadata_if_exists(path);
         let temp_path = self.temp_path_for(path);
@@ -1503,19 +1530,38 @@ impl FileSystem for StdFileSystem {
         mode: u32,
         uid: u32,
         gid: u32,
+        elevate: &[String],
     ) -> io::Result<()> {
         use crate::services::process_hidden::HideWindow;
         use std::process::{Command, Stdio};
 
-        // Write data via sudo tee
-        let mut child = Command::new("sudo")
-            .args(["tee", &path.to_string_lossy()])
+        let (program, args) = match elevate.split_first() {
+            Some((program, args)) => (program.as_str(), args),
+            None => ("sudo", &[][..]),
+        };
+        let path_str = path.to_string_lossy();
+        let command = |step: &[&str]| {
+            let mut command = Command::new(program);
+            command.args(args).args(step).hide_window();
+            command
+        };
+        // Each step names itself and carries the command's stderr, so a
+        // denied password and a read-only filesystem read differently.
+        let step_failed = |step: &str, output: &std::process::Output| {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("{} {} failed: {}", program, step, stderr.trim()),
+            )
+        };
+
+        // Write data via `<elevate> tee`
+        let mut child = command(&["tee", &path_str])
             .stdin(Stdio::piped())
             .stdout(Stdio::null())
             .stderr(Stdio::piped())
-            .hide_window()
             .spawn()
-            .map_err(|e| io::Error::other(format!("failed to spawn sudo: {}", e)))?;
+            .map_err(|e| io::Error::other(format!("failed to spawn {}: {}", program, e)))?;
 
         if let Some(mut stdin) = child.stdin.take() {
             use std::io::Write;
@@ -1524,33 +1570,17 @@ impl FileSystem for StdFileSystem {
 
         let output = child.wait_with_output()?;
         if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(io::Error::new(
-                io::ErrorKind::PermissionDenied,
-                format!("sudo tee failed: {}", stderr.trim()),
-            ));
+            return Err(step_failed("tee", &output));
         }
 
-        // Set permissions via sudo chmod
-        let status = Command::new("sudo")
-            .args(["chmod", &format!("{:o}", mode), &path.to_string_lossy()])
-            .hide_window()
-            .status()?;
-        if !status.success() {
-            return Err(io::Error::other("sudo chmod failed"));
+        // Restore permissions, then ownership
+        let output = command(&["chmod", &format!("{:o}", mode), &path_str]).output()?;
+        if !output.status.success() {
+            return Err(step_failed("chmod", &output));
         }
-
-        // Set ownership via sudo chown
-        let status = Command::new("sudo")
-            .args([
-                "chown",
-                &format!("{}:{}", uid, gid),
-                &path.to_string_lossy(),
-            ])
-            .hide_window()
-            .status()?;
-        if !status.success() {
-            return Err(io::Error::other("sudo chown failed"));
+        let output = command(&["chown", &format!("{}:{}", uid, gid), &path_str]).output()?;
+        if !output.status.success() {
+            return Err(step_failed("chown", &output));
         }
 
         Ok(())
@@ -1751,6 +1781,7 @@ impl FileSystem for NoopFileSystem {
         _mode: u32,
         _uid: u32,
         _gid: u32,
+        _elevate: &[String],
     ) -> io::Result<()> {
         Self::unsupported()
     }
@@ -1884,6 +1915,32 @@ mod tests {
         assert_eq!(fs.read_file(&path).unwrap(), b"updated");
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_sudo_write_uses_elevation_command() {
+        use std::os::unix::fs::MetadataExt;
+
+        // `env` runs each step unelevated, exercising the tee/chmod/chown
+        // sequence without needing root.
+        let fs = StdFileSystem;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("elevated.txt");
+        fs.write_file(&path, b"old").unwrap();
+        let meta = std::fs::metadata(&path).unwrap();
+
+        let elevate = vec!["env".to_string()];
+        fs.sudo_write(&path, b"new", 0o600, meta.uid(), meta.gid(), &elevate)
+            .unwrap();
+        assert_eq!(fs.read_file(&path).unwrap(), b"new");
+        assert_eq!(std::fs::metadata(&path).unwrap().mode() & 0o777, 0o600);
+
+        let missing = temp_dir.path().join("no-such-dir").join("file.txt");
+        let err = fs
+            .sudo_write(&missing, b"x", 0o644, meta.uid(), meta.gid(), &elevate)
+            .unwrap_err();
+        assert!(err.to_string().starts_with("env tee failed:"), "{err}");
+    }
+
     #[test]
     fn test_write_patched_default_impl() {
         // Test that the default write_patched implementation works correctly
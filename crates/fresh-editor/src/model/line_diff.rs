@@ -82,6 +82,145 @@ pub fn diff_lines(saved: &[u8], current: &[u8]) -> LineDiff {
     }
 }
 
+/// Index pairs `(saved_idx, current_idx)` of the lines the two sequences have
+/// in common (their longest common subsequence), in increasing order.
+///
+/// The DP table is `saved.len() * current.len()` cells; callers comparing
+/// large inputs should trim the common prefix and suffix first.
+pub(crate) fn matching_lines(saved: &[&[u8]], current: &[&[u8]]) -> Vec<(usize, usize)> {
+    longest_common_subsequence(saved, current)
+        .into_iter()
+        .map(|m| (m.saved_idx, m.current_idx))
+        .collect()
+}
+
+/// Upper bound on the LCS table (in cells) for [`diff_hunks`], after the
+/// common prefix and suffix are trimmed.
+const MAX_HUNK_DIFF_CELLS: usize = 16_000_000;
+
+/// The line ranges that differ between `saved` and `current`, as
+/// `(saved_range, current_range)` pairs in order.
+///
+/// The common prefix and suffix are trimmed first; if what remains is too
+/// large to compare it is reported as a single hunk.
+pub(crate) fn diff_hunks(saved: &[&[u8]], current: &[&[u8]]) -> Vec<(Range<usize>, Range<usize>)> {
+    let prefix = saved
+        .iter()
+        .zip(current)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = saved[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let saved_end = saved.len() - suffix;
+    let current_end = current.len() - suffix;
+    let saved_mid = &saved[prefix..saved_end];
+    let current_mid = &current[prefix..current_end];
+
+    let matches = if saved_mid.len().saturating_mul(current_mid.len()) > MAX_HUNK_DIFF_CELLS {
+        Vec::new()
+    } else {
+        matching_lines(saved_mid, current_mid)
+    };
+
+    let mut hunks = Vec::new();
+    let (mut s, mut c) = (prefix, prefix);
+    for (ms, mc) in matches
+        .into_iter()
+        .map(|(ms, mc)| (prefix + ms, prefix + mc))
+        .chain(std::iter::once((saved_end, current_end)))
+    {
+        if ms > s || mc > c {
+            hunks.push((s..ms, c..mc));
+        }
+        s = ms + 1;
+        c = mc + 1;
+    }
+    hunks
+}
+
+/// Maps byte offsets in one version of a text to the matching offsets in
+/// another, through a line diff of the two.
+///
+/// Offsets on unchanged lines keep their column on the same line, wherever
+/// the diff moved it. Offsets on a changed line go to the line at the same
+/// index within the replacing hunk (column clamped to its length), or to the
+/// first line after the hunk when the hunk shrank below that index.
+#[derive(Debug, Clone)]
+pub(crate) struct OffsetMap {
+    old_line_starts: Vec<usize>,
+    new_line_starts: Vec<usize>,
+    new_len: usize,
+    hunks: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl OffsetMap {
+    pub(crate) fn new(old: &[u8], new: &[u8]) -> Self {
+        let old_lines: Vec<&[u8]> = old.split(|&b| b == b'\n').collect();
+        let new_lines: Vec<&[u8]> = new.split(|&b| b == b'\n').collect();
+        Self {
+            old_line_starts: line_starts(&old_lines),
+            new_line_starts: line_starts(&new_lines),
+            new_len: new.len(),
+            hunks: diff_hunks(&old_lines, &new_lines),
+        }
+    }
+
+    /// The offset in the new text corresponding to `offset` in the old one.
+    pub(crate) fn map(&self, offset: usize) -> usize {
+        let line = self
+            .old_line_starts
+            .partition_point(|&start| start <= offset)
+            .saturating_sub(1);
+        let column = offset - self.old_line_starts[line];
+
+        // Lines shift by the size difference of every hunk before them.
+        let mut shift = 0isize;
+        let (new_line, column) = 'found: {
+            for (old_range, new_range) in &self.hunks {
+                if line < old_range.start {
+                    break;
+                }
+                if line < old_range.end {
+                    let index = line - old_range.start;
+                    break 'found if index < new_range.len() {
+                        (new_range.start + index, column)
+                    } else {
+                        (new_range.end, 0)
+                    };
+                }
+                shift += new_range.len() as isize - old_range.len() as isize;
+            }
+            ((line as isize + shift) as usize, column)
+        };
+
+        let Some(&start) = self.new_line_starts.get(new_line) else {
+            return self.new_len;
+        };
+        let end = self
+            .new_line_starts
+            .get(new_line + 1)
+            .map_or(self.new_len, |next| next - 1);
+        (start + column).min(end)
+    }
+}
+
+/// Byte offset of the start of each line, for lines split on `\n`.
+fn line_starts(lines: &[&[u8]]) -> Vec<usize> {
+    let mut offset = 0;
+    lines
+        .iter()
+        .map(|line| {
+            let start = offset;
+            offset += line.len() + 1;
+            start
+        })
+        .collect()
+}
+
 /// Represents a match between saved and current line indices
 #[derive(Debug, Clone, Copy)]
 struct LineMatch {
@@ -476,6 +615,45 @@ mod tests {
         assert!(!diff.changed_lines.is_empty());
     }
 
+    #[test]
+    fn test_diff_hunks() {
+        let lines = |s: &'static str| s.split('\n').map(str::as_bytes).collect::<Vec<_>>();
+
+        assert!(diff_hunks(&lines("a\nb"), &lines("a\nb")).is_empty());
+        assert_eq!(
+            diff_hunks(&lines("a\nb\nc\nd"), &lines("a\nx\nc\nd\ne")),
+            vec![(1..2, 1..2), (4..4, 4..5)]
+        );
+        assert_eq!(
+            diff_hunks(&lines("a\nb\nc"), &lines("c")),
+            vec![(0..2, 0..0)]
+        );
+    }
+
+    #[test]
+    fn test_offset_map() {
+        let old = b"a\nbb\ncc\ndd\n";
+
+        // Lines inserted above: later offsets shift down with their line.
+        let map = OffsetMap::new(old, b"a\nnew\nnew\nbb\ncc\ndd\n");
+        assert_eq!(map.map(0), 0);
+        assert_eq!(map.map(3), 11); // "b|b" stays on "bb"
+        assert_eq!(map.map(9), 17); // "d|d"
+
+        // A changed line keeps the cursor on its replacement, clamped.
+        let map = OffsetMap::new(old, b"a\nx\ncc\ndd\n");
+        assert_eq!(map.map(4), 3); // end of "bb" -> end of "x"
+        assert_eq!(map.map(7), 6); // "c|c" unchanged, shifted up
+
+        // A deleted line sends the cursor to the line that follows.
+        let map = OffsetMap::new(old, b"a\ndd\n");
+        assert_eq!(map.map(6), 2);
+
+        // Past the end of the new text clamps to its length.
+        let map = OffsetMap::new(old, b"a");
+        assert_eq!(map.map(old.len()), 1);
+    }
+
     #[test]
     fn test_add_at_end_of_existing_line() {
         // Adding text to end of a line (not a newline)
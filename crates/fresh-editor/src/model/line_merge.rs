@@ -0,0 +1,159 @@
+//! Line-based three-way merge.
+//!
+//! Backs **Revert Last Transform**: the text from before a whole-buffer
+//! transform (format, trim whitespace, organize imports) is merged with the
+//! edits made since, using the transform's output as the common base. Lines
+//! only the user touched keep the user's version, lines only the transform
+//! touched go back to their original, and a region both changed is a
+//! conflict.
+
+use super::line_diff::matching_lines;
+
+/// Upper bound on the LCS table (in cells) for one pairwise comparison,
+/// after the common prefix and suffix are trimmed. Keeps a revert of a
+/// large, heavily reformatted file from allocating gigabytes.
+const MAX_DIFF_CELLS: usize = 16_000_000;
+
+/// Why a three-way merge produced no result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeError {
+    /// Both sides changed the same region of the base.
+    Conflict,
+    /// The inputs differ over too many lines to compare.
+    TooLarge,
+}
+
+/// Merge `ours` and `theirs`, two descendants of `base`, line by line.
+///
+/// Each side's changes relative to `base` are applied; where both sides
+/// changed the same lines differently the merge fails with
+/// [`MergeError::Conflict`]. Line terminators are part of each line, so the
+/// result reproduces either side exactly where it is taken from.
+pub fn merge_lines(base: &str, ours: &str, theirs: &str) -> Result<String, MergeError> {
+    let base: Vec<&str> = base.split_inclusive('\n').collect();
+    let ours: Vec<&str> = ours.split_inclusive('\n').collect();
+    let theirs: Vec<&str> = theirs.split_inclusive('\n').collect();
+
+    let ours_map = match_base_lines(&base, &ours)?;
+    let theirs_map = match_base_lines(&base, &theirs)?;
+
+    let mut out = String::new();
+    // Start of the current unstable chunk in base / ours / theirs.
+    let (mut base_start, mut ours_start, mut theirs_start) = (0, 0, 0);
+    for i in 0..=base.len() {
+        // A base line kept by both sides is a sync point; the end of input
+        // is a final one.
+        let (ours_end, theirs_end) = if i == base.len() {
+            (ours.len(), theirs.len())
+        } else {
+            match (ours_map[i], theirs_map[i]) {
+                (Some(a), Some(b)) => (a, b),
+                _ => continue,
+            }
+        };
+
+        let base_chunk = &base[base_start..i];
+        let ours_chunk = &ours[ours_start..ours_end];
+        let theirs_chunk = &theirs[theirs_start..theirs_end];
+        let merged = if ours_chunk == base_chunk || ours_chunk == theirs_chunk {
+            theirs_chunk
+        } else if theirs_chunk == base_chunk {
+            ours_chunk
+        } else {
+            return Err(MergeError::Conflict);
+        };
+        out.extend(merged.iter().copied());
+
+        if i < base.len() {
+            out.push_str(base[i]);
+            base_start = i + 1;
+            ours_start = ours_end + 1;
+            theirs_start = theirs_end + 1;
+        }
+    }
+    Ok(out)
+}
+
+/// For each line of `base`, the index of the `other` line it is matched to
+/// in their longest common subsequence, if any.
+fn match_base_lines(base: &[&str], other: &[&str]) -> Result<Vec<Option<usize>>, MergeError> {
+    let prefix = base.iter().zip(other).take_while(|(a, b)| a == b).count();
+    let suffix = base[prefix..]
+        .iter()
+        .rev()
+        .zip(other[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let base_mid: Vec<&[u8]> = base[prefix..base.len() - suffix]
+        .iter()
+        .map(|l| l.as_bytes())
+        .collect();
+    let other_mid: Vec<&[u8]> = other[prefix..other.len() - suffix]
+        .iter()
+        .map(|l| l.as_bytes())
+        .collect();
+    if base_mid.len().saturating_mul(other_mid.len()) > MAX_DIFF_CELLS {
+        return Err(MergeError::TooLarge);
+    }
+
+    let mut map = vec![None; base.len()];
+    for (i, slot) in map.iter_mut().enumerate().take(prefix) {
+        *slot = Some(i);
+    }
+    for (b, o) in matching_lines(&base_mid, &other_mid) {
+        map[prefix + b] = Some(prefix + o);
+    }
+    for k in 1..=suffix {
+        map[base.len() - k] = Some(other.len() - k);
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unchanged_ours_takes_theirs() {
+        let base = "a\nb\nc\n";
+        let theirs = "a\nB\nc\n";
+        assert_eq!(merge_lines(base, base, theirs).unwrap(), theirs);
+    }
+
+    #[test]
+    fn test_disjoint_changes_are_combined() {
+        // base is the formatted text, theirs the original before formatting,
+        // ours the formatted text plus a later edit.
+        let base = "fn a() {\n    x();\n}\n\nfn b() {\n    y();\n}\n";
+        let theirs = "fn a() {\nx();\n}\n\nfn b() {\n    y();\n}\n";
+        let ours = "fn a() {\n    x();\n}\n\nfn b() {\n    y();\n    z();\n}\n";
+        assert_eq!(
+            merge_lines(base, ours, theirs).unwrap(),
+            "fn a() {\nx();\n}\n\nfn b() {\n    y();\n    z();\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_overlapping_changes_conflict() {
+        let base = "a\nb\nc\n";
+        let ours = "a\nours\nc\n";
+        let theirs = "a\ntheirs\nc\n";
+        assert_eq!(merge_lines(base, ours, theirs), Err(MergeError::Conflict));
+    }
+
+    #[test]
+    fn test_identical_changes_merge_cleanly() {
+        let base = "a\nb\n";
+        let both = "a\nB\n";
+        assert_eq!(merge_lines(base, both, both).unwrap(), both);
+    }
+
+    #[test]
+    fn test_missing_final_newline_is_preserved() {
+        let base = "a\nb\nc";
+        let ours = "a\nb\nc!";
+        let theirs = "A\nb\nc";
+        assert_eq!(merge_lines(base, ours, theirs).unwrap(), "A\nb\nc!");
+    }
+}
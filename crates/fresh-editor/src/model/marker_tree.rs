@@ -144,11 +144,22 @@ impl Node {
         node.lazy_delta = 0;
 
         // The max_end needs to be updated after the push
-        let max_l = node.left.as_ref().map_or(0, |l| l.borrow().max_end);
-        let max_r = node.right.as_ref().map_or(0, |r| r.borrow().max_end);
+        let max_l = Self::subtree_max_end(&node.left);
+        let max_r = Self::subtree_max_end(&node.right);
         node.max_end = max(node.marker.interval.end, max(max_l, max_r));
     }
 
+    /// The max `end` in a child's subtree, in its parent's frame. A child's
+    /// `max_end` excludes its own pending `lazy_delta`, so it has to be added
+    /// back here — otherwise an insert that is still lazy makes the subtree
+    /// look like it ends too early and queries skip markers inside it.
+    fn subtree_max_end(node: &NodePtr) -> u64 {
+        node.as_ref().map_or(0, |n| {
+            let n = n.borrow();
+            (n.max_end as i64 + n.lazy_delta).max(0) as u64
+        })
+    }
+
     /// Updates a node's height and max_end based on its children.
     fn update_stats(node: &Rc<RefCell<Self>>) {
         let mut n = node.borrow_mut();
@@ -157,8 +168,8 @@ impl Node {
 
         n.height = 1 + max(height_l, height_r);
 
-        let max_l = n.left.as_ref().map_or(0, |l| l.borrow().max_end);
-        let max_r = n.right.as_ref().map_or(0, |r| r.borrow().max_end);
+        let max_l = Self::subtree_max_end(&n.left);
+        let max_r = Self::subtree_max_end(&n.right);
         n.max_end = max(n.marker.interval.end, max(max_l, max_r));
     }
 }
@@ -713,7 +724,7 @@ impl IntervalTree {
             results.push(node.marker.clone());
         }
 
-        if node.left.is_some() && node.left.as_ref().unwrap().borrow().max_end >= query_start {
+        if node.left.is_some() && Node::subtree_max_end(&node.left) >= query_start {
             Self::query_recursive(&node.left, query_start, query_end, results);
         }
 
@@ -1055,6 +1066,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_query_sees_markers_under_pending_lazy_delta() {
+        // An insert before a subtree only bumps its lazy_delta. The subtree's
+        // max_end must be read with that delta applied, or a query starting
+        // at a shifted marker prunes the subtree and misses it.
+        let mut tree = IntervalTree::new();
+        let ids: Vec<MarkerId> = (0..12)
+            .map(|i| insert_marker(&mut tree, i * 5, i * 5))
+            .collect();
+        tree.adjust_for_edit(8, 6);
+        tree.adjust_for_edit(3, 4);
+
+        for id in ids {
+            let (start, _) = get_pos(&tree, id);
+            let found = tree.query(start, start + 6);
+            assert!(
+                found.iter().any(|m| m.id == id),
+                "query({start}, {}) missed marker {id} at {start}",
+                start + 6
+            );
+        }
+    }
+
     #[test]
     fn test_problematic_lazy_delta_scenario() {
         // This test replicates the tricky tree structure to ensure the O(log n) lazy
@@ -15,8 +15,13 @@ pub mod encoding_heuristics;
 pub mod event;
 pub mod filesystem;
 pub mod line_diff;
+pub mod line_merge;
 pub mod marker;
 pub mod marker_tree;
+pub mod mojibake;
 pub mod piece_tree;
 pub mod piece_tree_diff;
+pub mod read_only_regions;
+pub mod selection_history;
+pub mod snippet_session;
 pub mod virtual_space;
@@ -0,0 +1,124 @@
+//! Repair of mojibake: UTF-8 text that was decoded as Windows-1252 or
+//! Latin-1 somewhere upstream, turning `café` into `cafÃ©`.
+//!
+//! UTF-8 encodes every non-ASCII character as bytes ≥ 0x80, so the damage is
+//! confined to runs of non-ASCII characters, one character per original
+//! byte. Each run is mapped back to the bytes Windows-1252 / Latin-1 would
+//! have decoded it from, and the parts of those bytes that are valid UTF-8
+//! replace the characters they came from. Anything that doesn't round-trip —
+//! a genuine `é`, CJK text, emoji — is left alone, so mixed text is safe to
+//! repair. Text mangled more than once is repaired by repeating the pass.
+
+use std::ops::Range;
+
+/// One selection's repair: replace `range` (currently `original`) with
+/// `repaired`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodingFix {
+    pub range: Range<usize>,
+    pub original: String,
+    pub repaired: String,
+}
+
+/// Passes to run, for text that went through a wrong decode more than once.
+const MAX_PASSES: usize = 3;
+
+/// Windows-1252 characters for bytes 0x80–0x9F. The five bytes the code page
+/// leaves undefined decode to the matching C1 control, as most decoders do.
+const CP1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+/// Repair mojibake in `text`. Returns `None` when nothing needed fixing.
+pub fn repair_mojibake(text: &str) -> Option<String> {
+    let mut repaired = repair_pass(text)?;
+    for _ in 1..MAX_PASSES {
+        match repair_pass(&repaired) {
+            Some(next) => repaired = next,
+            None => break,
+        }
+    }
+    Some(repaired)
+}
+
+/// One pass over `text`; `None` if no run decoded.
+fn repair_pass(text: &str) -> Option<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut changed = false;
+    let mut run: Vec<char> = Vec::new();
+    for ch in text.chars() {
+        if !ch.is_ascii() && single_byte(ch).is_some() {
+            run.push(ch);
+            continue;
+        }
+        changed |= flush_run(&mut run, &mut out);
+        out.push(ch);
+    }
+    changed |= flush_run(&mut run, &mut out);
+    changed.then_some(out)
+}
+
+/// Decode a run of non-ASCII characters back through their single bytes,
+/// appending the result to `out`. Returns whether any part decoded.
+fn flush_run(run: &mut Vec<char>, out: &mut String) -> bool {
+    let bytes: Vec<u8> = run.iter().filter_map(|&c| single_byte(c)).collect();
+    let mut changed = false;
+    // Characters and bytes correspond one-to-one, so a byte offset into the
+    // chunks is also an index into `run`.
+    let mut index = 0;
+    for chunk in bytes.utf8_chunks() {
+        if !chunk.valid().is_empty() {
+            out.push_str(chunk.valid());
+            index += chunk.valid().len();
+            changed = true;
+        }
+        let invalid = chunk.invalid().len();
+        out.extend(&run[index..index + invalid]);
+        index += invalid;
+    }
+    run.clear();
+    changed
+}
+
+/// The byte Windows-1252 (or Latin-1, for C1 controls) decodes to `ch`.
+fn single_byte(ch: char) -> Option<u8> {
+    match ch as u32 {
+        cp @ 0x80..=0xFF => Some(cp as u8),
+        _ => CP1252_HIGH
+            .iter()
+            .position(|&c| c == ch)
+            .map(|i| 0x80 + i as u8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repairs_utf8_read_as_windows_1252() {
+        assert_eq!(repair_mojibake("cafÃ©").as_deref(), Some("café"));
+        assert_eq!(repair_mojibake("Ã¼ber").as_deref(), Some("über"));
+        assert_eq!(
+            repair_mojibake("â€œquotedâ€\u{9D} â€” done").as_deref(),
+            Some("“quoted” — done")
+        );
+        assert_eq!(repair_mojibake("ðŸ˜€").as_deref(), Some("😀"));
+    }
+
+    #[test]
+    fn repairs_double_encoding() {
+        assert_eq!(repair_mojibake("cafÃƒÂ©").as_deref(), Some("café"));
+    }
+
+    #[test]
+    fn leaves_genuine_text_alone() {
+        assert_eq!(repair_mojibake("naïve café 日本語 😀"), None);
+        assert_eq!(repair_mojibake("plain ascii"), None);
+        // A damaged run next to a genuine character: only the damage changes.
+        assert_eq!(repair_mojibake("Ã©é").as_deref(), Some("éé"));
+    }
+}
@@ -0,0 +1,212 @@
+//! Byte ranges of a buffer that the user may not edit.
+//!
+//! A plugin marks a region read-only when it owns text that lives inside an
+//! otherwise editable buffer: prompt text embedded before an input, a
+//! generated section between markers, the file headers of an aggregated
+//! multi-file edit buffer. Regions are anchored by markers, so they follow
+//! the text as edits elsewhere shift it.
+//!
+//! The check runs where user edits are applied (`log_and_apply_event`,
+//! `apply_events_as_bulk_edit`): an event that inserts strictly inside a
+//! region, or deletes any of its bytes, is refused as a whole. Typing right
+//! before or after a region is allowed and does not grow it. Plugin edits
+//! (`insertText`, `deleteRange`) don't go through that check, so the owner
+//! can still rewrite its own text.
+
+use crate::model::event::Event;
+use crate::model::marker::{MarkerId, MarkerList};
+use fresh_core::overlay::OverlayNamespace;
+use std::ops::Range;
+
+/// One read-only byte range.
+#[derive(Debug, Clone)]
+pub struct ReadOnlyRegion {
+    /// Namespace for bulk removal (shared with the overlay namespace system)
+    pub namespace: OverlayNamespace,
+    /// Start marker (right affinity - text inserted at the start stays outside)
+    start_marker: MarkerId,
+    /// End marker (left affinity - text inserted at the end stays outside)
+    end_marker: MarkerId,
+    /// Shown in the status bar when an edit is refused; `None` uses the
+    /// generic message.
+    pub hint: Option<String>,
+}
+
+impl ReadOnlyRegion {
+    /// Get the current byte range by resolving markers
+    pub fn range(&self, marker_list: &MarkerList) -> Range<usize> {
+        let start = marker_list.get_position(self.start_marker).unwrap_or(0);
+        let end = marker_list.get_position(self.end_marker).unwrap_or(0);
+        start..end
+    }
+
+    /// Whether applying `event` would change any byte of this region.
+    fn blocks(&self, event: &Event, marker_list: &MarkerList) -> bool {
+        let region = self.range(marker_list);
+        match event {
+            Event::Insert { position, .. } => region.start < *position && *position < region.end,
+            Event::Delete { range, .. } => range.start < region.end && region.start < range.end,
+            Event::Batch { events, .. } => events.iter().any(|e| self.blocks(e, marker_list)),
+            _ => false,
+        }
+    }
+}
+
+/// Manages the read-only regions of a buffer
+#[derive(Debug, Clone, Default)]
+pub struct ReadOnlyRegions {
+    regions: Vec<ReadOnlyRegion>,
+}
+
+impl ReadOnlyRegions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark `range` read-only. Empty ranges are ignored.
+    pub fn add(
+        &mut self,
+        marker_list: &mut MarkerList,
+        namespace: OverlayNamespace,
+        range: Range<usize>,
+        hint: Option<String>,
+    ) {
+        if range.start >= range.end {
+            return;
+        }
+        let start_marker = marker_list.create(range.start, false);
+        let end_marker = marker_list.create_left_gravity(range.end);
+        self.regions.push(ReadOnlyRegion {
+            namespace,
+            start_marker,
+            end_marker,
+            hint,
+        });
+    }
+
+    /// Remove all regions in a namespace
+    pub fn clear_namespace(&mut self, namespace: &OverlayNamespace, marker_list: &mut MarkerList) {
+        self.regions.retain(|r| {
+            if &r.namespace != namespace {
+                return true;
+            }
+            marker_list.delete(r.start_marker);
+            marker_list.delete(r.end_marker);
+            false
+        });
+    }
+
+    /// The current range of the first region in `namespace`, if any.
+    pub fn range_in_namespace(
+        &self,
+        namespace: &OverlayNamespace,
+        marker_list: &MarkerList,
+    ) -> Option<Range<usize>> {
+        self.regions
+            .iter()
+            .find(|r| &r.namespace == namespace)
+            .map(|r| r.range(marker_list))
+    }
+
+    /// The first region `event` would modify, if any.
+    pub fn blocking(&self, event: &Event, marker_list: &MarkerList) -> Option<&ReadOnlyRegion> {
+        if self.regions.is_empty() {
+            return None;
+        }
+        self.regions.iter().find(|r| r.blocks(event, marker_list))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::event::CursorId;
+
+    fn insert(position: usize) -> Event {
+        Event::Insert {
+            position,
+            text: "x".to_string(),
+            cursor_id: CursorId(0),
+        }
+    }
+
+    fn delete(range: Range<usize>) -> Event {
+        Event::Delete {
+            range,
+            deleted_text: String::new(),
+            cursor_id: CursorId(0),
+        }
+    }
+
+    fn regions_over(range: Range<usize>) -> (ReadOnlyRegions, MarkerList) {
+        let mut markers = MarkerList::new();
+        markers.adjust_for_insert(0, 100);
+        let mut regions = ReadOnlyRegions::new();
+        regions.add(
+            &mut markers,
+            OverlayNamespace::from_string("test".to_string()),
+            range,
+            None,
+        );
+        (regions, markers)
+    }
+
+    #[test]
+    fn refuses_edits_inside_but_not_at_the_edges() {
+        let (regions, markers) = regions_over(10..20);
+
+        assert!(regions.blocking(&insert(15), &markers).is_some());
+        assert!(regions.blocking(&insert(10), &markers).is_none());
+        assert!(regions.blocking(&insert(20), &markers).is_none());
+
+        assert!(regions.blocking(&delete(19..25), &markers).is_some());
+        assert!(regions.blocking(&delete(5..11), &markers).is_some());
+        assert!(regions.blocking(&delete(5..10), &markers).is_none());
+        assert!(regions.blocking(&delete(20..25), &markers).is_none());
+
+        let batch = Event::Batch {
+            events: vec![insert(2), delete(12..13)],
+            description: String::new(),
+        };
+        assert!(regions.blocking(&batch, &markers).is_some());
+    }
+
+    #[test]
+    fn text_typed_at_the_edges_stays_outside() {
+        let (regions, mut markers) = regions_over(10..20);
+
+        markers.adjust_for_insert(20, 3);
+        markers.adjust_for_insert(10, 3);
+        assert_eq!(regions.regions[0].range(&markers), 13..23);
+    }
+
+    #[test]
+    fn range_in_namespace_follows_edits() {
+        let (regions, mut markers) = regions_over(10..20);
+        let test = OverlayNamespace::from_string("test".to_string());
+
+        markers.adjust_for_insert(0, 5);
+        assert_eq!(regions.range_in_namespace(&test, &markers), Some(15..25));
+        let other = OverlayNamespace::from_string("other".to_string());
+        assert_eq!(regions.range_in_namespace(&other, &markers), None);
+    }
+
+    #[test]
+    fn clear_namespace_removes_only_that_namespace() {
+        let (mut regions, mut markers) = regions_over(10..20);
+        regions.add(
+            &mut markers,
+            OverlayNamespace::from_string("other".to_string()),
+            30..40,
+            Some("generated".to_string()),
+        );
+
+        regions.clear_namespace(
+            &OverlayNamespace::from_string("test".to_string()),
+            &mut markers,
+        );
+        assert!(regions.blocking(&insert(15), &markers).is_none());
+        let hit = regions.blocking(&insert(35), &markers).unwrap();
+        assert_eq!(hit.hint.as_deref(), Some("generated"));
+    }
+}
@@ -0,0 +1,83 @@
+//! The selections Expand Selection To Node grew from.
+//!
+//! Each expansion pushes the selections it replaced, so Shrink Selection can
+//! step back through them. The history only holds while the cursors still
+//! show the last result: once the user moves, edits or selects something
+//! else, it no longer describes what is on screen and is dropped.
+
+use crate::model::event::CursorId;
+
+/// Every cursor's anchor and position.
+pub type SelectionSnapshot = Vec<(CursorId, Option<usize>, usize)>;
+
+#[derive(Debug, Default)]
+pub struct SelectionHistory {
+    /// Selections before each expansion, oldest first.
+    previous: Vec<SelectionSnapshot>,
+    /// Selections the last expansion or shrink left behind.
+    current: Option<SelectionSnapshot>,
+}
+
+impl SelectionHistory {
+    /// Record an expansion from `before` to `after`. Starts a new history
+    /// unless `before` is what the last expansion or shrink produced.
+    pub fn push(&mut self, before: SelectionSnapshot, after: SelectionSnapshot) {
+        if self.current.as_ref() != Some(&before) {
+            self.previous.clear();
+        }
+        self.previous.push(before);
+        self.current = Some(after);
+    }
+
+    /// The selections to shrink back to from `now`, if `now` is the result
+    /// of an expansion.
+    pub fn pop(&mut self, now: &SelectionSnapshot) -> Option<SelectionSnapshot> {
+        if self.current.as_ref() != Some(now) {
+            self.clear();
+            return None;
+        }
+        let previous = self.previous.pop()?;
+        self.current = Some(previous.clone());
+        Some(previous)
+    }
+
+    pub fn clear(&mut self) {
+        self.previous.clear();
+        self.current = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn selection(anchor: usize, position: usize) -> SelectionSnapshot {
+        vec![(CursorId(0), Some(anchor), position)]
+    }
+
+    #[test]
+    fn shrinks_back_through_expansions() {
+        let mut history = SelectionHistory::default();
+        let caret = vec![(CursorId(0), None, 5)];
+        history.push(caret.clone(), selection(4, 7));
+        history.push(selection(4, 7), selection(0, 10));
+
+        assert_eq!(history.pop(&selection(0, 10)), Some(selection(4, 7)));
+        assert_eq!(history.pop(&selection(4, 7)), Some(caret.clone()));
+        assert_eq!(history.pop(&caret), None);
+    }
+
+    #[test]
+    fn other_selections_drop_the_history() {
+        let mut history = SelectionHistory::default();
+        history.push(selection(4, 7), selection(0, 10));
+        // The user selected something else, then expanded from there.
+        history.push(selection(1, 2), selection(0, 3));
+        assert_eq!(history.pop(&selection(0, 3)), Some(selection(1, 2)));
+        assert_eq!(history.pop(&selection(1, 2)), None);
+
+        history.push(selection(4, 7), selection(0, 10));
+        assert_eq!(history.pop(&selection(0, 9)), None);
+        assert_eq!(history.pop(&selection(0, 10)), None);
+    }
+}
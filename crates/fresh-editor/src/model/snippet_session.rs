@@ -0,0 +1,165 @@
+//! The tab stops of a snippet that is being filled in.
+//!
+//! After a snippet with tab stops is inserted, Tab and Shift+Tab walk its
+//! placeholders. Each occurrence of a tab stop is tracked as a pair of byte
+//! markers on the shared [`MarkerList`], so typing into a placeholder (or
+//! anywhere else) keeps every range aligned with its text.
+
+use std::ops::Range;
+
+use crate::model::marker::{MarkerId, MarkerList};
+use crate::primitives::snippet::ExpandedSnippet;
+
+/// One tracked range. The start has left gravity and the end right gravity,
+/// so text typed at either edge of a placeholder becomes part of it.
+#[derive(Debug, Clone, Copy)]
+struct TrackedRange {
+    start: MarkerId,
+    end: MarkerId,
+}
+
+impl TrackedRange {
+    fn new(marker_list: &mut MarkerList, range: Range<usize>) -> Self {
+        Self {
+            start: marker_list.create_left_gravity(range.start),
+            end: marker_list.create(range.end, false),
+        }
+    }
+
+    fn get(&self, marker_list: &MarkerList) -> Option<Range<usize>> {
+        let start = marker_list.get_position(self.start)?;
+        let end = marker_list.get_position(self.end)?;
+        Some(start..end.max(start))
+    }
+
+    fn release(&self, marker_list: &mut MarkerList) {
+        marker_list.delete(self.start);
+        marker_list.delete(self.end);
+    }
+}
+
+/// An inserted snippet whose tab stops are being navigated.
+#[derive(Debug, Clone)]
+pub struct SnippetSession {
+    /// Occurrences of `$1`, `$2`, … in navigation order; all occurrences of
+    /// one tab stop are edited together.
+    tabstops: Vec<Vec<TrackedRange>>,
+    /// Where `$0` sits (or the end of the snippet).
+    final_stop: TrackedRange,
+    /// The whole inserted snippet.
+    bounds: TrackedRange,
+    /// The tab stop the cursors are on.
+    current: usize,
+}
+
+impl SnippetSession {
+    /// Track the tab stops of `expanded`, whose text was inserted at `base`.
+    /// Returns `None` if the snippet has no tab stops besides `$0`.
+    pub fn new(
+        marker_list: &mut MarkerList,
+        base: usize,
+        expanded: &ExpandedSnippet,
+    ) -> Option<Self> {
+        if expanded.tabstops.is_empty() {
+            return None;
+        }
+        let shift = |range: &Range<usize>| base + range.start..base + range.end;
+        let tabstops = expanded
+            .tabstops
+            .iter()
+            .map(|ranges| {
+                ranges
+                    .iter()
+                    .map(|range| TrackedRange::new(marker_list, shift(range)))
+                    .collect()
+            })
+            .collect();
+        let final_offset = base + expanded.cursor_offset;
+        Some(Self {
+            tabstops,
+            final_stop: TrackedRange::new(marker_list, final_offset..final_offset),
+            bounds: TrackedRange::new(marker_list, base..base + expanded.text.len()),
+            current: 0,
+        })
+    }
+
+    /// Index of the current tab stop.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Number of tab stops, not counting `$0`.
+    pub fn len(&self) -> usize {
+        self.tabstops.len()
+    }
+
+    /// Always false: a session is only created for snippets with tab stops.
+    pub fn is_empty(&self) -> bool {
+        self.tabstops.is_empty()
+    }
+
+    /// Make tab stop `index` current.
+    pub fn set_current(&mut self, index: usize) {
+        self.current = index.min(self.tabstops.len().saturating_sub(1));
+    }
+
+    /// Current byte ranges of every occurrence of tab stop `index`.
+    pub fn ranges(&self, marker_list: &MarkerList, index: usize) -> Vec<Range<usize>> {
+        self.tabstops
+            .get(index)
+            .map(|ranges| {
+                ranges
+                    .iter()
+                    .filter_map(|range| range.get(marker_list))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Current position of `$0`.
+    pub fn final_position(&self, marker_list: &MarkerList) -> Option<usize> {
+        self.final_stop.get(marker_list).map(|range| range.start)
+    }
+
+    /// Whether `position` is inside the inserted snippet (edges included).
+    pub fn contains(&self, marker_list: &MarkerList, position: usize) -> bool {
+        self.bounds
+            .get(marker_list)
+            .is_some_and(|range| range.start <= position && position <= range.end)
+    }
+
+    /// Release the session's markers.
+    pub fn release(self, marker_list: &mut MarkerList) {
+        for range in self.tabstops.iter().flatten() {
+            range.release(marker_list);
+        }
+        self.final_stop.release(marker_list);
+        self.bounds.release(marker_list);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitives::snippet::expand_snippet;
+
+    #[test]
+    fn tabstops_follow_edits() {
+        let expanded = expand_snippet("<${1:div}>$0</$1>");
+        let mut markers = MarkerList::new();
+        markers.adjust_for_insert(0, 4 + expanded.text.len());
+        let session = SnippetSession::new(&mut markers, 4, &expanded).unwrap();
+        assert_eq!(session.ranges(&markers, 0), vec![5..8, 11..14]);
+
+        // Replace the first "div" with "span", as typing over the selection does.
+        markers.adjust_for_delete(5, 3);
+        markers.adjust_for_insert(5, 4);
+        assert_eq!(session.ranges(&markers, 0), vec![5..9, 12..15]);
+        assert_eq!(session.final_position(&markers), Some(10));
+        assert!(session.contains(&markers, 4));
+        assert!(!session.contains(&markers, 3));
+
+        session.release(&mut markers);
+        assert_eq!(markers.marker_count(), 0);
+    }
+}
@@ -4,9 +4,11 @@
 //! enabling a 4-level overlay architecture (System → User → Project → Session).
 
 use crate::config::{
-    ClipboardConfig, CursorStyle, FileBrowserConfig, FileExplorerConfig, FormatterConfig,
-    IndentationGuideMode, Keybinding, KeybindingMapName, KeymapConfig, LanguageConfig,
-    LineEndingOption, OnSaveAction, PluginConfig, TerminalConfig, ThemeName, WarningsConfig,
+    ClipboardConfig, CursorStyle, EventHookConfig, FileBrowserConfig, FileExplorerConfig,
+    FormatterConfig, IndentationGuideMode, JsonSchemaConfig, Keybinding, KeybindingMapName,
+    KeymapConfig, LanguageConfig, LineEndingOption, LintSeverity, LinterConfig, OnSaveAction,
+    PluginConfig, SchemaAssociation, SecondaryCursorStyle, TerminalConfig, ThemeName,
+    WarningsConfig,
 };
 use crate::types::LspLanguageConfig;
 use serde::{Deserialize, Serialize};
@@ -77,7 +79,9 @@ pub struct PartialConfig {
     pub theme: Option<ThemeName>,
     pub locale: Option<String>,
     pub check_for_updates: Option<bool>,
+    pub telemetry: Option<bool>,
     pub self_update: Option<bool>,
+    pub offline: Option<bool>,
     pub editor: Option<PartialEditorConfig>,
     pub file_explorer: Option<PartialFileExplorerConfig>,
     pub file_browser: Option<PartialFileBrowserConfig>,
@@ -89,6 +93,7 @@ pub struct PartialConfig {
     pub languages: Option<HashMap<String, PartialLanguageConfig>>,
     pub default_language: Option<String>,
     pub lsp_enabled: Option<bool>,
+    pub lsp_shared_servers: Option<bool>,
     pub lsp: Option<HashMap<String, LspLanguageConfig>>,
     pub universal_lsp: Option<HashMap<String, LspLanguageConfig>>,
     pub warnings: Option<PartialWarningsConfig>,
@@ -97,6 +102,21 @@ pub struct PartialConfig {
     /// Environment detectors — overridden wholesale (the whole detector list
     /// is replaced if provided), not merged per-detector.
     pub env: Option<crate::config::EnvConfig>,
+    pub json_schemas: Option<PartialJsonSchemaConfig>,
+    pub linters: Option<HashMap<String, PartialLinterConfig>>,
+    pub hooks: Option<Vec<EventHookConfig>>,
+}
+
+impl PartialConfig {
+    /// Drop settings a workspace's own `.fresh/` files may not choose. The
+    /// elevation command runs with the user's privileges and telemetry
+    /// consent is the user's alone, so a cloned repository must set neither.
+    pub fn clear_user_only_settings(&mut self) {
+        self.telemetry = None;
+        if let Some(editor) = self.editor.as_mut() {
+            editor.elevation_command = None;
+        }
+    }
 }
 
 impl Merge for PartialConfig {
@@ -105,7 +125,9 @@ impl Merge for PartialConfig {
         self.theme.merge_from(&other.theme);
         self.locale.merge_from(&other.locale);
         self.check_for_updates.merge_from(&other.check_for_updates);
+        self.telemetry.merge_from(&other.telemetry);
         self.self_update.merge_from(&other.self_update);
+        self.offline.merge_from(&other.offline);
 
         // Nested structs: merge recursively
         merge_partial(&mut self.editor, &other.editor);
@@ -115,20 +137,25 @@ impl Merge for PartialConfig {
         merge_partial(&mut self.terminal, &other.terminal);
         merge_partial(&mut self.warnings, &other.warnings);
         merge_partial(&mut self.packages, &other.packages);
+        merge_partial(&mut self.json_schemas, &other.json_schemas);
         // Env detectors: higher precedence replaces the whole list.
         self.env.merge_from(&other.env);
 
         // Lists: higher precedence replaces (per design doc)
         self.keybindings.merge_from(&other.keybindings);
+        self.hooks.merge_from(&other.hooks);
 
         // HashMaps: merge entries, higher precedence wins on key collision
         merge_hashmap(&mut self.keybinding_maps, &other.keybinding_maps);
         merge_hashmap_recursive(&mut self.languages, &other.languages);
         self.default_language.merge_from(&other.default_language);
         self.lsp_enabled.merge_from(&other.lsp_enabled);
+        self.lsp_shared_servers
+            .merge_from(&other.lsp_shared_servers);
         merge_hashmap(&mut self.lsp, &other.lsp);
         merge_hashmap(&mut self.universal_lsp, &other.universal_lsp);
         merge_hashmap_recursive(&mut self.plugins, &other.plugins);
+        merge_hashmap_recursive(&mut self.linters, &other.linters);
 
         self.active_keybinding_map
             .merge_from(&other.active_keybinding_map);
@@ -154,12 +181,20 @@ pub struct PartialEditorConfig {
     pub auto_close: Option<bool>,
     pub auto_surround: Option<bool>,
     pub virtual_space: Option<crate::config::VirtualSpaceMode>,
+    pub smart_tab: Option<Vec<crate::config::SmartTabBehavior>>,
     pub animations: Option<bool>,
     pub cursor_jump_animation: Option<bool>,
     pub line_numbers: Option<bool>,
     pub relative_line_numbers: Option<bool>,
+    pub line_number_mode: Option<crate::config::LineNumberMode>,
+    pub line_number_min_width: Option<usize>,
+    pub gutter_components: Option<Vec<crate::config::GutterComponent>>,
+    pub gutter_shared_signs: Option<bool>,
     pub scroll_offset: Option<usize>,
+    pub scroll_past_end: Option<bool>,
+    pub typewriter_scrolling: Option<crate::config::TypewriterScrolling>,
     pub syntax_highlighting: Option<bool>,
+    #[serde(alias = "cursorline")]
     pub highlight_current_line: Option<bool>,
     pub highlight_occurrences: Option<bool>,
     pub hide_current_line_on_selection: Option<bool>,
@@ -168,6 +203,7 @@ pub struct PartialEditorConfig {
     pub wrap_indent: Option<bool>,
     pub wrap_column: Option<Option<usize>>,
     pub page_width: Option<Option<usize>>,
+    pub zen_mode_width: Option<usize>,
     pub highlight_timeout_ms: Option<u64>,
     pub snapshot_interval: Option<usize>,
     pub large_file_threshold_bytes: Option<u64>,
@@ -188,24 +224,47 @@ pub struct PartialEditorConfig {
     pub mouse_hover_enabled: Option<bool>,
     pub mouse_hover_delay_ms: Option<u64>,
     pub double_click_time_ms: Option<u64>,
+    pub mouse_wheel_scroll_lines: Option<usize>,
+    pub smooth_scroll: Option<bool>,
     pub auto_revert_poll_interval_ms: Option<u64>,
+    pub local_history_enabled: Option<bool>,
+    pub local_history_max_versions: Option<u32>,
     pub read_concurrency: Option<usize>,
     pub file_tree_poll_interval_ms: Option<u64>,
+    pub serve_buffer_address: Option<String>,
     pub default_line_ending: Option<LineEndingOption>,
     pub trim_trailing_whitespace_on_save: Option<bool>,
     pub ensure_final_newline_on_save: Option<bool>,
+    pub abbreviations: Option<HashMap<String, String>>,
     pub auto_read_only: Option<bool>,
+    pub elevation_command: Option<String>,
+    pub keep_search_highlights: Option<bool>,
     pub highlight_matching_brackets: Option<bool>,
     pub rainbow_brackets: Option<bool>,
     pub cursor_style: Option<CursorStyle>,
+    pub cursor_style_selection: Option<Option<CursorStyle>>,
+    pub cursor_style_normal_mode: Option<Option<CursorStyle>>,
+    pub cursor_blink: Option<bool>,
+    pub secondary_cursor_style: Option<SecondaryCursorStyle>,
     pub keyboard_disambiguate_escape_codes: Option<bool>,
     pub keyboard_report_event_types: Option<bool>,
     pub keyboard_report_alternate_keys: Option<bool>,
     pub keyboard_report_all_keys_as_escape_codes: Option<bool>,
+    pub compose_dead_keys: Option<bool>,
+    pub chord_timeout_ms: Option<u64>,
+    pub show_chord_hints: Option<bool>,
+    pub calibration_keys: Option<Vec<String>>,
     pub completion_popup_auto_show: Option<bool>,
     pub quick_suggestions: Option<bool>,
     pub quick_suggestions_delay_ms: Option<u64>,
     pub suggest_on_trigger_characters: Option<bool>,
+    pub project_word_completion: Option<bool>,
+    pub tag_word_completion: Option<bool>,
+    pub completion_matching: Option<crate::config::CompletionMatching>,
+    pub completion_sort: Option<crate::config::CompletionSort>,
+    pub completion_documentation: Option<bool>,
+    pub tags_command: Option<Vec<String>>,
+    pub tags_generate_on_save: Option<bool>,
     pub show_menu_bar: Option<bool>,
     pub screensaver_enabled: Option<bool>,
     pub screensaver_idle_minutes: Option<u32>,
@@ -232,6 +291,8 @@ pub struct PartialEditorConfig {
     pub whitespace_tabs_leading: Option<bool>,
     pub whitespace_tabs_inner: Option<bool>,
     pub whitespace_tabs_trailing: Option<bool>,
+    pub whitespace_line_endings: Option<bool>,
+    pub whitespace_highlight_trailing: Option<bool>,
 }
 
 impl Merge for PartialEditorConfig {
@@ -242,19 +303,30 @@ impl Merge for PartialEditorConfig {
         self.auto_close.merge_from(&other.auto_close);
         self.auto_surround.merge_from(&other.auto_surround);
         self.virtual_space.merge_from(&other.virtual_space);
+        self.smart_tab.merge_from(&other.smart_tab);
         self.animations.merge_from(&other.animations);
         self.cursor_jump_animation
             .merge_from(&other.cursor_jump_animation);
         self.line_numbers.merge_from(&other.line_numbers);
         self.relative_line_numbers
             .merge_from(&other.relative_line_numbers);
+        self.line_number_mode.merge_from(&other.line_number_mode);
+        self.line_number_min_width
+            .merge_from(&other.line_number_min_width);
+        self.gutter_components.merge_from(&other.gutter_components);
+        self.gutter_shared_signs
+            .merge_from(&other.gutter_shared_signs);
         self.scroll_offset.merge_from(&other.scroll_offset);
+        self.scroll_past_end.merge_from(&other.scroll_past_end);
+        self.typewriter_scrolling
+            .merge_from(&other.typewriter_scrolling);
         self.syntax_highlighting
             .merge_from(&other.syntax_highlighting);
         self.line_wrap.merge_from(&other.line_wrap);
         self.wrap_indent.merge_from(&other.wrap_indent);
         self.wrap_column.merge_from(&other.wrap_column);
         self.page_width.merge_from(&other.page_width);
+        self.zen_mode_width.merge_from(&other.zen_mode_width);
         self.highlight_timeout_ms
             .merge_from(&other.highlight_timeout_ms);
         self.snapshot_interval.merge_from(&other.snapshot_interval);
@@ -290,22 +362,42 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.mouse_hover_delay_ms);
         self.double_click_time_ms
             .merge_from(&other.double_click_time_ms);
+        self.mouse_wheel_scroll_lines
+            .merge_from(&other.mouse_wheel_scroll_lines);
+        self.smooth_scroll.merge_from(&other.smooth_scroll);
         self.auto_revert_poll_interval_ms
             .merge_from(&other.auto_revert_poll_interval_ms);
+        self.local_history_enabled
+            .merge_from(&other.local_history_enabled);
+        self.local_history_max_versions
+            .merge_from(&other.local_history_max_versions);
         self.read_concurrency.merge_from(&other.read_concurrency);
         self.file_tree_poll_interval_ms
             .merge_from(&other.file_tree_poll_interval_ms);
+        self.serve_buffer_address
+            .merge_from(&other.serve_buffer_address);
         self.default_line_ending
             .merge_from(&other.default_line_ending);
         self.trim_trailing_whitespace_on_save
             .merge_from(&other.trim_trailing_whitespace_on_save);
         self.ensure_final_newline_on_save
             .merge_from(&other.ensure_final_newline_on_save);
+        merge_hashmap(&mut self.abbreviations, &other.abbreviations);
         self.auto_read_only.merge_from(&other.auto_read_only);
+        self.elevation_command.merge_from(&other.elevation_command);
+        self.keep_search_highlights
+            .merge_from(&other.keep_search_highlights);
         self.highlight_matching_brackets
             .merge_from(&other.highlight_matching_brackets);
         self.rainbow_brackets.merge_from(&other.rainbow_brackets);
         self.cursor_style.merge_from(&other.cursor_style);
+        self.cursor_style_selection
+            .merge_from(&other.cursor_style_selection);
+        self.cursor_style_normal_mode
+            .merge_from(&other.cursor_style_normal_mode);
+        self.cursor_blink.merge_from(&other.cursor_blink);
+        self.secondary_cursor_style
+            .merge_from(&other.secondary_cursor_style);
         self.keyboard_disambiguate_escape_codes
             .merge_from(&other.keyboard_disambiguate_escape_codes);
         self.keyboard_report_event_types
@@ -314,6 +406,10 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.keyboard_report_alternate_keys);
         self.keyboard_report_all_keys_as_escape_codes
             .merge_from(&other.keyboard_report_all_keys_as_escape_codes);
+        self.compose_dead_keys.merge_from(&other.compose_dead_keys);
+        self.chord_timeout_ms.merge_from(&other.chord_timeout_ms);
+        self.show_chord_hints.merge_from(&other.show_chord_hints);
+        self.calibration_keys.merge_from(&other.calibration_keys);
         self.completion_popup_auto_show
             .merge_from(&other.completion_popup_auto_show);
         self.quick_suggestions.merge_from(&other.quick_suggestions);
@@ -321,6 +417,18 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.quick_suggestions_delay_ms);
         self.suggest_on_trigger_characters
             .merge_from(&other.suggest_on_trigger_characters);
+        self.project_word_completion
+            .merge_from(&other.project_word_completion);
+        self.tag_word_completion
+            .merge_from(&other.tag_word_completion);
+        self.completion_matching
+            .merge_from(&other.completion_matching);
+        self.completion_sort.merge_from(&other.completion_sort);
+        self.completion_documentation
+            .merge_from(&other.completion_documentation);
+        self.tags_command.merge_from(&other.tags_command);
+        self.tags_generate_on_save
+            .merge_from(&other.tags_generate_on_save);
         self.show_menu_bar.merge_from(&other.show_menu_bar);
         self.screensaver_enabled
             .merge_from(&other.screensaver_enabled);
@@ -363,6 +471,10 @@ impl Merge for PartialEditorConfig {
             .merge_from(&other.whitespace_tabs_inner);
         self.whitespace_tabs_trailing
             .merge_from(&other.whitespace_tabs_trailing);
+        self.whitespace_line_endings
+            .merge_from(&other.whitespace_line_endings);
+        self.whitespace_highlight_trailing
+            .merge_from(&other.whitespace_highlight_trailing);
     }
 }
 
@@ -430,6 +542,9 @@ impl Merge for PartialFileBrowserConfig {
 pub struct PartialClipboardConfig {
     pub use_osc52: Option<bool>,
     pub use_system_clipboard: Option<bool>,
+    pub path_line_separator: Option<String>,
+    pub path_list_separator: Option<String>,
+    pub persist_history: Option<bool>,
 }
 
 impl Merge for PartialClipboardConfig {
@@ -437,6 +552,11 @@ impl Merge for PartialClipboardConfig {
         self.use_osc52.merge_from(&other.use_osc52);
         self.use_system_clipboard
             .merge_from(&other.use_system_clipboard);
+        self.path_line_separator
+            .merge_from(&other.path_line_separator);
+        self.path_list_separator
+            .merge_from(&other.path_list_separator);
+        self.persist_history.merge_from(&other.persist_history);
     }
 }
 
@@ -450,6 +570,7 @@ pub struct PartialTerminalConfig {
     pub resume_agents: Option<bool>,
     pub mouse_drag_selects: Option<bool>,
     pub mouse_forwarding: Option<crate::config::TerminalMouseForwarding>,
+    pub paste_confirm_lines: Option<usize>,
 }
 
 impl Merge for PartialTerminalConfig {
@@ -463,6 +584,8 @@ impl Merge for PartialTerminalConfig {
         self.mouse_drag_selects
             .merge_from(&other.mouse_drag_selects);
         self.mouse_forwarding.merge_from(&other.mouse_forwarding);
+        self.paste_confirm_lines
+            .merge_from(&other.paste_confirm_lines);
     }
 }
 
@@ -493,6 +616,56 @@ impl Merge for PartialPackagesConfig {
     }
 }
 
+/// Partial JSON/YAML schema validation configuration.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PartialJsonSchemaConfig {
+    pub enabled: Option<bool>,
+    pub download: Option<bool>,
+    pub associations: Option<Vec<SchemaAssociation>>,
+}
+
+/// Partial linter configuration.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PartialLinterConfig {
+    pub enabled: Option<bool>,
+    pub languages: Option<Vec<String>>,
+    pub command: Option<String>,
+    pub args: Option<Vec<String>>,
+    pub stdin: Option<bool>,
+    pub pattern: Option<String>,
+    pub default_severity: Option<LintSeverity>,
+    pub run_on_idle: Option<bool>,
+    pub workspace: Option<bool>,
+    pub only_without_lsp: Option<bool>,
+    pub timeout_ms: Option<u64>,
+}
+
+impl Merge for PartialLinterConfig {
+    fn merge_from(&mut self, other: &Self) {
+        self.enabled.merge_from(&other.enabled);
+        self.languages.merge_from(&other.languages);
+        self.command.merge_from(&other.command);
+        self.args.merge_from(&other.args);
+        self.stdin.merge_from(&other.stdin);
+        self.pattern.merge_from(&other.pattern);
+        self.default_severity.merge_from(&other.default_severity);
+        self.run_on_idle.merge_from(&other.run_on_idle);
+        self.workspace.merge_from(&other.workspace);
+        self.only_without_lsp.merge_from(&other.only_without_lsp);
+        self.timeout_ms.merge_from(&other.timeout_ms);
+    }
+}
+
+impl Merge for PartialJsonSchemaConfig {
+    fn merge_from(&mut self, other: &Self) {
+        self.enabled.merge_from(&other.enabled);
+        self.download.merge_from(&other.download);
+        self.associations.merge_from(&other.associations);
+    }
+}
+
 /// Partial plugin configuration.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 #[serde(default)]
@@ -542,6 +715,8 @@ pub struct PartialLanguageConfig {
     pub filenames: Option<Vec<String>>,
     pub grammar: Option<String>,
     pub comment_prefix: Option<String>,
+    pub block_comment_start: Option<String>,
+    pub block_comment_end: Option<String>,
     pub auto_indent: Option<bool>,
     pub auto_close: Option<bool>,
     pub auto_surround: Option<bool>,
@@ -559,6 +734,8 @@ pub struct PartialLanguageConfig {
     pub word_characters: Option<Option<String>>,
     pub indent: Option<crate::config::IndentRulesConfig>,
     pub indentation_guide: Option<bool>,
+    pub trim_trailing_whitespace_on_save: Option<bool>,
+    pub abbreviations: Option<HashMap<String, String>>,
 }
 
 impl Merge for PartialLanguageConfig {
@@ -567,6 +744,9 @@ impl Merge for PartialLanguageConfig {
         self.filenames.merge_from(&other.filenames);
         self.grammar.merge_from(&other.grammar);
         self.comment_prefix.merge_from(&other.comment_prefix);
+        self.block_comment_start
+            .merge_from(&other.block_comment_start);
+        self.block_comment_end.merge_from(&other.block_comment_end);
         self.auto_indent.merge_from(&other.auto_indent);
         self.auto_close.merge_from(&other.auto_close);
         self.auto_surround.merge_from(&other.auto_surround);
@@ -585,6 +765,9 @@ impl Merge for PartialLanguageConfig {
         self.word_characters.merge_from(&other.word_characters);
         self.indent.merge_from(&other.indent);
         self.indentation_guide.merge_from(&other.indentation_guide);
+        self.trim_trailing_whitespace_on_save
+            .merge_from(&other.trim_trailing_whitespace_on_save);
+        merge_hashmap(&mut self.abbreviations, &other.abbreviations);
     }
 }
 
@@ -599,11 +782,18 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             auto_close: Some(cfg.auto_close),
             auto_surround: Some(cfg.auto_surround),
             virtual_space: Some(cfg.virtual_space),
+            smart_tab: Some(cfg.smart_tab.clone()),
             animations: Some(cfg.animations),
             cursor_jump_animation: Some(cfg.cursor_jump_animation),
             line_numbers: Some(cfg.line_numbers),
             relative_line_numbers: Some(cfg.relative_line_numbers),
+            line_number_mode: Some(cfg.line_number_mode),
+            line_number_min_width: Some(cfg.line_number_min_width),
+            gutter_components: Some(cfg.gutter_components.clone()),
+            gutter_shared_signs: Some(cfg.gutter_shared_signs),
             scroll_offset: Some(cfg.scroll_offset),
+            scroll_past_end: Some(cfg.scroll_past_end),
+            typewriter_scrolling: Some(cfg.typewriter_scrolling),
             syntax_highlighting: Some(cfg.syntax_highlighting),
             highlight_current_line: Some(cfg.highlight_current_line),
             highlight_occurrences: Some(cfg.highlight_occurrences),
@@ -613,6 +803,7 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             wrap_indent: Some(cfg.wrap_indent),
             wrap_column: Some(cfg.wrap_column),
             page_width: Some(cfg.page_width),
+            zen_mode_width: Some(cfg.zen_mode_width),
             highlight_timeout_ms: Some(cfg.highlight_timeout_ms),
             snapshot_interval: Some(cfg.snapshot_interval),
             large_file_threshold_bytes: Some(cfg.large_file_threshold_bytes),
@@ -637,26 +828,49 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             mouse_hover_enabled: Some(cfg.mouse_hover_enabled),
             mouse_hover_delay_ms: Some(cfg.mouse_hover_delay_ms),
             double_click_time_ms: Some(cfg.double_click_time_ms),
+            mouse_wheel_scroll_lines: Some(cfg.mouse_wheel_scroll_lines),
+            smooth_scroll: Some(cfg.smooth_scroll),
             auto_revert_poll_interval_ms: Some(cfg.auto_revert_poll_interval_ms),
+            local_history_enabled: Some(cfg.local_history_enabled),
+            local_history_max_versions: Some(cfg.local_history_max_versions),
             read_concurrency: Some(cfg.read_concurrency),
             file_tree_poll_interval_ms: Some(cfg.file_tree_poll_interval_ms),
+            serve_buffer_address: Some(cfg.serve_buffer_address.clone()),
             default_line_ending: Some(cfg.default_line_ending.clone()),
             trim_trailing_whitespace_on_save: Some(cfg.trim_trailing_whitespace_on_save),
             ensure_final_newline_on_save: Some(cfg.ensure_final_newline_on_save),
+            abbreviations: Some(cfg.abbreviations.clone()),
             auto_read_only: Some(cfg.auto_read_only),
+            elevation_command: Some(cfg.elevation_command.clone()),
+            keep_search_highlights: Some(cfg.keep_search_highlights),
             highlight_matching_brackets: Some(cfg.highlight_matching_brackets),
             rainbow_brackets: Some(cfg.rainbow_brackets),
             cursor_style: Some(cfg.cursor_style),
+            cursor_style_selection: Some(cfg.cursor_style_selection),
+            cursor_style_normal_mode: Some(cfg.cursor_style_normal_mode),
+            cursor_blink: Some(cfg.cursor_blink),
+            secondary_cursor_style: Some(cfg.secondary_cursor_style),
             keyboard_disambiguate_escape_codes: Some(cfg.keyboard_disambiguate_escape_codes),
             keyboard_report_event_types: Some(cfg.keyboard_report_event_types),
             keyboard_report_alternate_keys: Some(cfg.keyboard_report_alternate_keys),
             keyboard_report_all_keys_as_escape_codes: Some(
                 cfg.keyboard_report_all_keys_as_escape_codes,
             ),
+            compose_dead_keys: Some(cfg.compose_dead_keys),
+            chord_timeout_ms: Some(cfg.chord_timeout_ms),
+            show_chord_hints: Some(cfg.show_chord_hints),
+            calibration_keys: Some(cfg.calibration_keys.clone()),
             completion_popup_auto_show: Some(cfg.completion_popup_auto_show),
             quick_suggestions: Some(cfg.quick_suggestions),
             quick_suggestions_delay_ms: Some(cfg.quick_suggestions_delay_ms),
             suggest_on_trigger_characters: Some(cfg.suggest_on_trigger_characters),
+            project_word_completion: Some(cfg.project_word_completion),
+            tag_word_completion: Some(cfg.tag_word_completion),
+            completion_matching: Some(cfg.completion_matching),
+            completion_sort: Some(cfg.completion_sort),
+            completion_documentation: Some(cfg.completion_documentation),
+            tags_command: Some(cfg.tags_command.clone()),
+            tags_generate_on_save: Some(cfg.tags_generate_on_save),
             show_menu_bar: Some(cfg.show_menu_bar),
             screensaver_enabled: Some(cfg.screensaver_enabled),
             screensaver_idle_minutes: Some(cfg.screensaver_idle_minutes),
@@ -683,6 +897,8 @@ impl From<&crate::config::EditorConfig> for PartialEditorConfig {
             whitespace_tabs_leading: Some(cfg.whitespace_tabs_leading),
             whitespace_tabs_inner: Some(cfg.whitespace_tabs_inner),
             whitespace_tabs_trailing: Some(cfg.whitespace_tabs_trailing),
+            whitespace_line_endings: Some(cfg.whitespace_line_endings),
+            whitespace_highlight_trailing: Some(cfg.whitespace_highlight_trailing),
         }
     }
 }
@@ -697,6 +913,7 @@ impl PartialEditorConfig {
             auto_close: self.auto_close.unwrap_or(defaults.auto_close),
             auto_surround: self.auto_surround.unwrap_or(defaults.auto_surround),
             virtual_space: self.virtual_space.unwrap_or(defaults.virtual_space),
+            smart_tab: self.smart_tab.unwrap_or_else(|| defaults.smart_tab.clone()),
             animations: self.animations.unwrap_or(defaults.animations),
             cursor_jump_animation: self
                 .cursor_jump_animation
@@ -705,7 +922,21 @@ impl PartialEditorConfig {
             relative_line_numbers: self
                 .relative_line_numbers
                 .unwrap_or(defaults.relative_line_numbers),
+            line_number_mode: self.line_number_mode.unwrap_or(defaults.line_number_mode),
+            line_number_min_width: self
+                .line_number_min_width
+                .unwrap_or(defaults.line_number_min_width),
+            gutter_components: self
+                .gutter_components
+                .unwrap_or_else(|| defaults.gutter_components.clone()),
+            gutter_shared_signs: self
+                .gutter_shared_signs
+                .unwrap_or(defaults.gutter_shared_signs),
             scroll_offset: self.scroll_offset.unwrap_or(defaults.scroll_offset),
+            scroll_past_end: self.scroll_past_end.unwrap_or(defaults.scroll_past_end),
+            typewriter_scrolling: self
+                .typewriter_scrolling
+                .unwrap_or(defaults.typewriter_scrolling),
             syntax_highlighting: self
                 .syntax_highlighting
                 .unwrap_or(defaults.syntax_highlighting),
@@ -725,6 +956,7 @@ impl PartialEditorConfig {
             wrap_indent: self.wrap_indent.unwrap_or(defaults.wrap_indent),
             wrap_column: self.wrap_column.unwrap_or(defaults.wrap_column),
             page_width: self.page_width.unwrap_or(defaults.page_width),
+            zen_mode_width: self.zen_mode_width.unwrap_or(defaults.zen_mode_width),
             highlight_timeout_ms: self
                 .highlight_timeout_ms
                 .unwrap_or(defaults.highlight_timeout_ms),
@@ -775,13 +1007,26 @@ impl PartialEditorConfig {
             double_click_time_ms: self
                 .double_click_time_ms
                 .unwrap_or(defaults.double_click_time_ms),
+            mouse_wheel_scroll_lines: self
+                .mouse_wheel_scroll_lines
+                .unwrap_or(defaults.mouse_wheel_scroll_lines),
+            smooth_scroll: self.smooth_scroll.unwrap_or(defaults.smooth_scroll),
             auto_revert_poll_interval_ms: self
                 .auto_revert_poll_interval_ms
                 .unwrap_or(defaults.auto_revert_poll_interval_ms),
+            local_history_enabled: self
+                .local_history_enabled
+                .unwrap_or(defaults.local_history_enabled),
+            local_history_max_versions: self
+                .local_history_max_versions
+                .unwrap_or(defaults.local_history_max_versions),
             read_concurrency: self.read_concurrency.unwrap_or(defaults.read_concurrency),
             file_tree_poll_interval_ms: self
                 .file_tree_poll_interval_ms
                 .unwrap_or(defaults.file_tree_poll_interval_ms),
+            serve_buffer_address: self
+                .serve_buffer_address
+                .unwrap_or_else(|| defaults.serve_buffer_address.clone()),
             default_line_ending: self
                 .default_line_ending
                 .unwrap_or(defaults.default_line_ending.clone()),
@@ -791,12 +1036,31 @@ impl PartialEditorConfig {
             ensure_final_newline_on_save: self
                 .ensure_final_newline_on_save
                 .unwrap_or(defaults.ensure_final_newline_on_save),
+            abbreviations: self
+                .abbreviations
+                .unwrap_or_else(|| defaults.abbreviations.clone()),
             auto_read_only: self.auto_read_only.unwrap_or(defaults.auto_read_only),
+            elevation_command: self
+                .elevation_command
+                .unwrap_or_else(|| defaults.elevation_command.clone()),
+            keep_search_highlights: self
+                .keep_search_highlights
+                .unwrap_or(defaults.keep_search_highlights),
             highlight_matching_brackets: self
                 .highlight_matching_brackets
                 .unwrap_or(defaults.highlight_matching_brackets),
             rainbow_brackets: self.rainbow_brackets.unwrap_or(defaults.rainbow_brackets),
             cursor_style: self.cursor_style.unwrap_or(defaults.cursor_style),
+            cursor_style_selection: self
+                .cursor_style_selection
+                .unwrap_or(defaults.cursor_style_selection),
+            cursor_style_normal_mode: self
+                .cursor_style_normal_mode
+                .unwrap_or(defaults.cursor_style_normal_mode),
+            cursor_blink: self.cursor_blink.unwrap_or(defaults.cursor_blink),
+            secondary_cursor_style: self
+                .secondary_cursor_style
+                .unwrap_or(defaults.secondary_cursor_style),
             keyboard_disambiguate_escape_codes: self
                 .keyboard_disambiguate_escape_codes
                 .unwrap_or(defaults.keyboard_disambiguate_escape_codes),
@@ -809,6 +1073,12 @@ impl PartialEditorConfig {
             keyboard_report_all_keys_as_escape_codes: self
                 .keyboard_report_all_keys_as_escape_codes
                 .unwrap_or(defaults.keyboard_report_all_keys_as_escape_codes),
+            compose_dead_keys: self.compose_dead_keys.unwrap_or(defaults.compose_dead_keys),
+            chord_timeout_ms: self.chord_timeout_ms.unwrap_or(defaults.chord_timeout_ms),
+            show_chord_hints: self.show_chord_hints.unwrap_or(defaults.show_chord_hints),
+            calibration_keys: self
+                .calibration_keys
+                .unwrap_or_else(|| defaults.calibration_keys.clone()),
             completion_popup_auto_show: self
                 .completion_popup_auto_show
                 .unwrap_or(defaults.completion_popup_auto_show),
@@ -819,6 +1089,25 @@ impl PartialEditorConfig {
             suggest_on_trigger_characters: self
                 .suggest_on_trigger_characters
                 .unwrap_or(defaults.suggest_on_trigger_characters),
+            project_word_completion: self
+                .project_word_completion
+                .unwrap_or(defaults.project_word_completion),
+            tag_word_completion: self
+                .tag_word_completion
+                .unwrap_or(defaults.tag_word_completion),
+            completion_matching: self
+                .completion_matching
+                .unwrap_or(defaults.completion_matching),
+            completion_sort: self.completion_sort.unwrap_or(defaults.completion_sort),
+            completion_documentation: self
+                .completion_documentation
+                .unwrap_or(defaults.completion_documentation),
+            tags_command: self
+                .tags_command
+                .unwrap_or_else(|| defaults.tags_command.clone()),
+            tags_generate_on_save: self
+                .tags_generate_on_save
+                .unwrap_or(defaults.tags_generate_on_save),
             show_menu_bar: self.show_menu_bar.unwrap_or(defaults.show_menu_bar),
             screensaver_enabled: self
                 .screensaver_enabled
@@ -876,6 +1165,12 @@ impl PartialEditorConfig {
             whitespace_tabs_trailing: self
                 .whitespace_tabs_trailing
                 .unwrap_or(defaults.whitespace_tabs_trailing),
+            whitespace_line_endings: self
+                .whitespace_line_endings
+                .unwrap_or(defaults.whitespace_line_endings),
+            whitespace_highlight_trailing: self
+                .whitespace_highlight_trailing
+                .unwrap_or(defaults.whitespace_highlight_trailing),
         }
     }
 }
@@ -951,6 +1246,9 @@ impl From<&ClipboardConfig> for PartialClipboardConfig {
         Self {
             use_osc52: Some(cfg.use_osc52),
             use_system_clipboard: Some(cfg.use_system_clipboard),
+            path_line_separator: Some(cfg.path_line_separator.clone()),
+            path_list_separator: Some(cfg.path_list_separator.clone()),
+            persist_history: Some(cfg.persist_history),
         }
     }
 }
@@ -962,6 +1260,71 @@ impl PartialClipboardConfig {
             use_system_clipboard: self
                 .use_system_clipboard
                 .unwrap_or(defaults.use_system_clipboard),
+            path_line_separator: self
+                .path_line_separator
+                .unwrap_or_else(|| defaults.path_line_separator.clone()),
+            path_list_separator: self
+                .path_list_separator
+                .unwrap_or_else(|| defaults.path_list_separator.clone()),
+            persist_history: self.persist_history.unwrap_or(defaults.persist_history),
+        }
+    }
+}
+
+impl From<&JsonSchemaConfig> for PartialJsonSchemaConfig {
+    fn from(cfg: &JsonSchemaConfig) -> Self {
+        Self {
+            enabled: Some(cfg.enabled),
+            download: Some(cfg.download),
+            associations: Some(cfg.associations.clone()),
+        }
+    }
+}
+
+impl From<&LinterConfig> for PartialLinterConfig {
+    fn from(cfg: &LinterConfig) -> Self {
+        Self {
+            enabled: Some(cfg.enabled),
+            languages: Some(cfg.languages.clone()),
+            command: Some(cfg.command.clone()),
+            args: Some(cfg.args.clone()),
+            stdin: Some(cfg.stdin),
+            pattern: Some(cfg.pattern.clone()),
+            default_severity: Some(cfg.default_severity),
+            run_on_idle: Some(cfg.run_on_idle),
+            workspace: Some(cfg.workspace),
+            only_without_lsp: Some(cfg.only_without_lsp),
+            timeout_ms: Some(cfg.timeout_ms),
+        }
+    }
+}
+
+impl PartialLinterConfig {
+    pub fn resolve(self, defaults: &LinterConfig) -> LinterConfig {
+        LinterConfig {
+            enabled: self.enabled.unwrap_or(defaults.enabled),
+            languages: self.languages.unwrap_or_else(|| defaults.languages.clone()),
+            command: self.command.unwrap_or_else(|| defaults.command.clone()),
+            args: self.args.unwrap_or_else(|| defaults.args.clone()),
+            stdin: self.stdin.unwrap_or(defaults.stdin),
+            pattern: self.pattern.unwrap_or_else(|| defaults.pattern.clone()),
+            default_severity: self.default_severity.unwrap_or(defaults.default_severity),
+            run_on_idle: self.run_on_idle.unwrap_or(defaults.run_on_idle),
+            workspace: self.workspace.unwrap_or(defaults.workspace),
+            only_without_lsp: self.only_without_lsp.unwrap_or(defaults.only_without_lsp),
+            timeout_ms: self.timeout_ms.unwrap_or(defaults.timeout_ms),
+        }
+    }
+}
+
+impl PartialJsonSchemaConfig {
+    pub fn resolve(self, defaults: &JsonSchemaConfig) -> JsonSchemaConfig {
+        JsonSchemaConfig {
+            enabled: self.enabled.unwrap_or(defaults.enabled),
+            download: self.download.unwrap_or(defaults.download),
+            associations: self
+                .associations
+                .unwrap_or_else(|| defaults.associations.clone()),
         }
     }
 }
@@ -975,6 +1338,7 @@ impl From<&TerminalConfig> for PartialTerminalConfig {
             resume_agents: Some(cfg.resume_agents),
             mouse_drag_selects: Some(cfg.mouse_drag_selects),
             mouse_forwarding: Some(cfg.mouse_forwarding),
+            paste_confirm_lines: Some(cfg.paste_confirm_lines),
         }
     }
 }
@@ -994,6 +1358,9 @@ impl PartialTerminalConfig {
                 .mouse_drag_selects
                 .unwrap_or(defaults.mouse_drag_selects),
             mouse_forwarding: self.mouse_forwarding.unwrap_or(defaults.mouse_forwarding),
+            paste_confirm_lines: self
+                .paste_confirm_lines
+                .unwrap_or(defaults.paste_confirm_lines),
         }
     }
 }
@@ -1066,6 +1433,8 @@ impl From<&LanguageConfig> for PartialLanguageConfig {
             filenames: Some(cfg.filenames.clone()),
             grammar: Some(cfg.grammar.clone()),
             comment_prefix: cfg.comment_prefix.clone(),
+            block_comment_start: cfg.block_comment_start.clone(),
+            block_comment_end: cfg.block_comment_end.clone(),
             auto_indent: Some(cfg.auto_indent),
             auto_close: cfg.auto_close,
             auto_surround: cfg.auto_surround,
@@ -1083,6 +1452,8 @@ impl From<&LanguageConfig> for PartialLanguageConfig {
             word_characters: Some(cfg.word_characters.clone()),
             indent: cfg.indent.clone(),
             indentation_guide: cfg.indentation_guide,
+            trim_trailing_whitespace_on_save: cfg.trim_trailing_whitespace_on_save,
+            abbreviations: Some(cfg.abbreviations.clone()),
         }
     }
 }
@@ -1098,6 +1469,12 @@ impl PartialLanguageConfig {
             comment_prefix: self
                 .comment_prefix
                 .or_else(|| defaults.comment_prefix.clone()),
+            block_comment_start: self
+                .block_comment_start
+                .or_else(|| defaults.block_comment_start.clone()),
+            block_comment_end: self
+                .block_comment_end
+                .or_else(|| defaults.block_comment_end.clone()),
             auto_indent: self.auto_indent.unwrap_or(defaults.auto_indent),
             auto_close: self.auto_close.or(defaults.auto_close),
             auto_surround: self.auto_surround.or(defaults.auto_surround),
@@ -1121,6 +1498,12 @@ impl PartialLanguageConfig {
                 .unwrap_or_else(|| defaults.word_characters.clone()),
             indent: self.indent.or_else(|| defaults.indent.clone()),
             indentation_guide: self.indentation_guide.or(defaults.indentation_guide),
+            trim_trailing_whitespace_on_save: self
+                .trim_trailing_whitespace_on_save
+                .or(defaults.trim_trailing_whitespace_on_save),
+            abbreviations: self
+                .abbreviations
+                .unwrap_or_else(|| defaults.abbreviations.clone()),
         }
     }
 }
@@ -1132,7 +1515,9 @@ impl From<&crate::config::Config> for PartialConfig {
             theme: Some(cfg.theme.clone()),
             locale: cfg.locale.0.clone(),
             check_for_updates: Some(cfg.check_for_updates),
+            telemetry: cfg.telemetry,
             self_update: Some(cfg.self_update),
+            offline: Some(cfg.offline),
             editor: Some(PartialEditorConfig::from(&cfg.editor)),
             file_explorer: Some(PartialFileExplorerConfig::from(&cfg.file_explorer)),
             file_browser: Some(PartialFileBrowserConfig::from(&cfg.file_browser)),
@@ -1149,6 +1534,7 @@ impl From<&crate::config::Config> for PartialConfig {
             ),
             default_language: cfg.default_language.clone(),
             lsp_enabled: Some(cfg.lsp_enabled),
+            lsp_shared_servers: Some(cfg.lsp_shared_servers),
             lsp: Some(
                 cfg.lsp
                     .iter()
@@ -1215,6 +1601,14 @@ impl From<&crate::config::Config> for PartialConfig {
             },
             packages: Some(PartialPackagesConfig::from(&cfg.packages)),
             env: Some(cfg.env.clone()),
+            json_schemas: Some(PartialJsonSchemaConfig::from(&cfg.json_schemas)),
+            linters: Some(
+                cfg.linters
+                    .iter()
+                    .map(|(k, v)| (k.clone(), PartialLinterConfig::from(v)))
+                    .collect(),
+            ),
+            hooks: Some(cfg.hooks.clone()),
         }
     }
 }
@@ -1321,6 +1715,18 @@ impl PartialConfig {
             result
         };
 
+        // Resolve linters HashMap - merge each linter with its preset
+        let linters = {
+            let mut result = defaults.linters.clone();
+            if let Some(partial_linters) = self.linters {
+                for (key, partial_linter) in partial_linters {
+                    let default_linter = result.get(&key).cloned().unwrap_or_default();
+                    result.insert(key, partial_linter.resolve(&default_linter));
+                }
+            }
+            result
+        };
+
         let mut config = crate::config::Config {
             version: self.version.unwrap_or(defaults.version),
             theme: self.theme.unwrap_or_else(|| defaults.theme.clone()),
@@ -1328,7 +1734,9 @@ impl PartialConfig {
                 self.locale.or_else(|| defaults.locale.0.clone()),
             ),
             check_for_updates: self.check_for_updates.unwrap_or(defaults.check_for_updates),
+            telemetry: self.telemetry.or(defaults.telemetry),
             self_update: self.self_update.unwrap_or(defaults.self_update),
+            offline: self.offline.unwrap_or(defaults.offline),
             editor: self
                 .editor
                 .map(|e| e.resolve(&defaults.editor))
@@ -1361,6 +1769,9 @@ impl PartialConfig {
                 .default_language
                 .or_else(|| defaults.default_language.clone()),
             lsp_enabled: self.lsp_enabled.unwrap_or(defaults.lsp_enabled),
+            lsp_shared_servers: self
+                .lsp_shared_servers
+                .unwrap_or(defaults.lsp_shared_servers),
             lsp,
             universal_lsp,
             warnings: self
@@ -1373,6 +1784,12 @@ impl PartialConfig {
                 .map(|e| e.resolve(&defaults.packages))
                 .unwrap_or_else(|| defaults.packages.clone()),
             env: self.env.unwrap_or_else(|| defaults.env.clone()),
+            json_schemas: self
+                .json_schemas
+                .map(|e| e.resolve(&defaults.json_schemas))
+                .unwrap_or_else(|| defaults.json_schemas.clone()),
+            linters,
+            hooks: self.hooks.unwrap_or_else(|| defaults.hooks.clone()),
         };
         // Treat `0` as "not set" for numeric settings where a literal zero is
         // meaningless (wrap_column, page_width, tab_size).
@@ -1389,6 +1806,8 @@ impl Default for LanguageConfig {
             filenames: Vec::new(),
             grammar: String::new(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: true,
             auto_close: None,
             auto_surround: None,
@@ -1406,6 +1825,8 @@ impl Default for LanguageConfig {
             word_characters: None,
             indent: None,
             indentation_guide: None,
+            trim_trailing_whitespace_on_save: None,
+            abbreviations: HashMap::new(),
         }
     }
 }
@@ -1535,6 +1956,13 @@ mod tests {
         assert_eq!(higher.line_numbers, Some(true)); // Filled from lower
     }
 
+    #[test]
+    fn cursorline_is_an_alias_for_highlight_current_line() {
+        let partial: PartialEditorConfig =
+            serde_json::from_str(r#"{"cursorline": false}"#).unwrap();
+        assert_eq!(partial.highlight_current_line, Some(false));
+    }
+
     #[test]
     fn merge_partial_config_combines_languages() {
         let mut higher = PartialConfig {
@@ -0,0 +1,167 @@
+//! A 3×5 pixel bitmap font drawn with half-block characters.
+//!
+//! Used by presentation mode to show text several times larger than the
+//! terminal font. Each glyph is 3 pixels wide and 5 tall; drawn with `▀`,
+//! `▄` and `█` (two pixel rows per terminal row) it takes [`CELL_WIDTH`] ×
+//! [`CELL_HEIGHT`] terminal cells, including one column and half a row of
+//! spacing. Printable ASCII has glyphs; anything else is drawn as `?`.
+
+/// Terminal columns per glyph, including the spacing column.
+pub const CELL_WIDTH: usize = 4;
+
+/// Terminal rows per glyph, including the spacing half-row.
+pub const CELL_HEIGHT: usize = 3;
+
+/// Pixel rows of each printable ASCII character from `' '` to `'~'`, top
+/// first. Bit `0b100` is the leftmost pixel.
+const GLYPHS: [[u8; 5]; 95] = [
+    [0b000, 0b000, 0b000, 0b000, 0b000], // ' '
+    [0b010, 0b010, 0b010, 0b000, 0b010], // '!'
+    [0b101, 0b101, 0b000, 0b000, 0b000], // '"'
+    [0b101, 0b111, 0b101, 0b111, 0b101], // '#'
+    [0b011, 0b110, 0b010, 0b011, 0b110], // '$'
+    [0b101, 0b001, 0b010, 0b100, 0b101], // '%'
+    [0b010, 0b101, 0b010, 0b101, 0b011], // '&'
+    [0b010, 0b010, 0b000, 0b000, 0b000], // '\''
+    [0b001, 0b010, 0b010, 0b010, 0b001], // '('
+    [0b100, 0b010, 0b010, 0b010, 0b100], // ')'
+    [0b000, 0b101, 0b010, 0b101, 0b000], // '*'
+    [0b000, 0b010, 0b111, 0b010, 0b000], // '+'
+    [0b000, 0b000, 0b000, 0b010, 0b100], // ','
+    [0b000, 0b000, 0b111, 0b000, 0b000], // '-'
+    [0b000, 0b000, 0b000, 0b000, 0b010], // '.'
+    [0b001, 0b001, 0b010, 0b100, 0b100], // '/'
+    [0b111, 0b101, 0b101, 0b101, 0b111], // '0'
+    [0b010, 0b110, 0b010, 0b010, 0b111], // '1'
+    [0b110, 0b001, 0b010, 0b100, 0b111], // '2'
+    [0b110, 0b001, 0b010, 0b001, 0b110], // '3'
+    [0b101, 0b101, 0b111, 0b001, 0b001], // '4'
+    [0b111, 0b100, 0b110, 0b001, 0b110], // '5'
+    [0b011, 0b100, 0b111, 0b101, 0b111], // '6'
+    [0b111, 0b001, 0b010, 0b010, 0b010], // '7'
+    [0b111, 0b101, 0b111, 0b101, 0b111], // '8'
+    [0b111, 0b101, 0b111, 0b001, 0b110], // '9'
+    [0b000, 0b010, 0b000, 0b010, 0b000], // ':'
+    [0b000, 0b010, 0b000, 0b010, 0b100], // ';'
+    [0b001, 0b010, 0b100, 0b010, 0b001], // '<'
+    [0b000, 0b111, 0b000, 0b111, 0b000], // '='
+    [0b100, 0b010, 0b001, 0b010, 0b100], // '>'
+    [0b110, 0b001, 0b010, 0b000, 0b010], // '?'
+    [0b010, 0b101, 0b111, 0b100, 0b011], // '@'
+    [0b010, 0b101, 0b111, 0b101, 0b101], // 'A'
+    [0b110, 0b101, 0b110, 0b101, 0b110], // 'B'
+    [0b011, 0b100, 0b100, 0b100, 0b011], // 'C'
+    [0b110, 0b101, 0b101, 0b101, 0b110], // 'D'
+    [0b111, 0b100, 0b110, 0b100, 0b111], // 'E'
+    [0b111, 0b100, 0b110, 0b100, 0b100], // 'F'
+    [0b011, 0b100, 0b101, 0b101, 0b011], // 'G'
+    [0b101, 0b101, 0b111, 0b101, 0b101], // 'H'
+    [0b111, 0b010, 0b010, 0b010, 0b111], // 'I'
+    [0b001, 0b001, 0b001, 0b101, 0b010], // 'J'
+    [0b101, 0b101, 0b110, 0b101, 0b101], // 'K'
+    [0b100, 0b100, 0b100, 0b100, 0b111], // 'L'
+    [0b101, 0b111, 0b111, 0b101, 0b101], // 'M'
+    [0b101, 0b111, 0b111, 0b111, 0b101], // 'N'
+    [0b010, 0b101, 0b101, 0b101, 0b010], // 'O'
+    [0b110, 0b101, 0b110, 0b100, 0b100], // 'P'
+    [0b010, 0b101, 0b101, 0b111, 0b011], // 'Q'
+    [0b110, 0b101, 0b110, 0b110, 0b101], // 'R'
+    [0b011, 0b100, 0b010, 0b001, 0b110], // 'S'
+    [0b111, 0b010, 0b010, 0b010, 0b010], // 'T'
+    [0b101, 0b101, 0b101, 0b101, 0b111], // 'U'
+    [0b101, 0b101, 0b101, 0b010, 0b010], // 'V'
+    [0b101, 0b101, 0b111, 0b111, 0b101], // 'W'
+    [0b101, 0b101, 0b010, 0b101, 0b101], // 'X'
+    [0b101, 0b101, 0b010, 0b010, 0b010], // 'Y'
+    [0b111, 0b001, 0b010, 0b100, 0b111], // 'Z'
+    [0b110, 0b100, 0b100, 0b100, 0b110], // '['
+    [0b100, 0b100, 0b010, 0b001, 0b001], // '\\'
+    [0b011, 0b001, 0b001, 0b001, 0b011], // ']'
+    [0b010, 0b101, 0b000, 0b000, 0b000], // '^'
+    [0b000, 0b000, 0b000, 0b000, 0b111], // '_'
+    [0b100, 0b010, 0b000, 0b000, 0b000], // '`'
+    [0b000, 0b110, 0b011, 0b101, 0b111], // 'a'
+    [0b100, 0b110, 0b101, 0b101, 0b110], // 'b'
+    [0b000, 0b011, 0b100, 0b100, 0b011], // 'c'
+    [0b001, 0b011, 0b101, 0b101, 0b011], // 'd'
+    [0b000, 0b011, 0b101, 0b110, 0b011], // 'e'
+    [0b001, 0b010, 0b111, 0b010, 0b010], // 'f'
+    [0b000, 0b011, 0b101, 0b011, 0b110], // 'g'
+    [0b100, 0b110, 0b101, 0b101, 0b101], // 'h'
+    [0b010, 0b000, 0b010, 0b010, 0b010], // 'i'
+    [0b001, 0b000, 0b001, 0b101, 0b010], // 'j'
+    [0b100, 0b101, 0b110, 0b110, 0b101], // 'k'
+    [0b110, 0b010, 0b010, 0b010, 0b111], // 'l'
+    [0b000, 0b111, 0b111, 0b111, 0b101], // 'm'
+    [0b000, 0b110, 0b101, 0b101, 0b101], // 'n'
+    [0b000, 0b010, 0b101, 0b101, 0b010], // 'o'
+    [0b000, 0b110, 0b101, 0b110, 0b100], // 'p'
+    [0b000, 0b011, 0b101, 0b011, 0b001], // 'q'
+    [0b000, 0b011, 0b100, 0b100, 0b100], // 'r'
+    [0b000, 0b011, 0b110, 0b011, 0b110], // 's'
+    [0b010, 0b111, 0b010, 0b010, 0b011], // 't'
+    [0b000, 0b101, 0b101, 0b101, 0b011], // 'u'
+    [0b000, 0b101, 0b101, 0b111, 0b010], // 'v'
+    [0b000, 0b101, 0b111, 0b111, 0b111], // 'w'
+    [0b000, 0b101, 0b010, 0b010, 0b101], // 'x'
+    [0b000, 0b101, 0b101, 0b011, 0b110], // 'y'
+    [0b000, 0b111, 0b011, 0b110, 0b111], // 'z'
+    [0b011, 0b010, 0b110, 0b010, 0b011], // '{'
+    [0b010, 0b010, 0b010, 0b010, 0b010], // '|'
+    [0b110, 0b010, 0b011, 0b010, 0b110], // '}'
+    [0b000, 0b011, 0b110, 0b000, 0b000], // '~'
+];
+
+/// The pixel rows of `c`, or of `?` if the font has no glyph for it.
+fn glyph(c: char) -> &'static [u8; 5] {
+    let index = match c {
+        ' '..='~' => c as usize - ' ' as usize,
+        _ => '?' as usize - ' ' as usize,
+    };
+    &GLYPHS[index]
+}
+
+/// The [`CELL_HEIGHT`] rows of terminal characters that draw `c`, each
+/// [`CELL_WIDTH`] characters wide.
+pub fn glyph_rows(c: char) -> [[char; CELL_WIDTH]; CELL_HEIGHT] {
+    let pixels = glyph(c);
+    let mut rows = [[' '; CELL_WIDTH]; CELL_HEIGHT];
+    for (row, cells) in rows.iter_mut().enumerate() {
+        let top = pixels[row * 2];
+        let bottom = pixels.get(row * 2 + 1).copied().unwrap_or(0);
+        for (column, cell) in cells.iter_mut().take(3).enumerate() {
+            let bit = 0b100 >> column;
+            *cell = match (top & bit != 0, bottom & bit != 0) {
+                (true, true) => '█',
+                (true, false) => '▀',
+                (false, true) => '▄',
+                (false, false) => ' ',
+            };
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(c: char) -> Vec<String> {
+        glyph_rows(c)
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect()
+    }
+
+    #[test]
+    fn draws_two_pixel_rows_per_terminal_row() {
+        assert_eq!(draw('A'), vec!["▄▀▄ ", "█▀█ ", "▀ ▀ "]);
+        assert_eq!(draw(' '), vec!["    "; 3]);
+    }
+
+    #[test]
+    fn unknown_characters_draw_as_question_mark() {
+        assert_eq!(draw('é'), draw('?'));
+        assert_eq!(draw('\t'), draw('?'));
+    }
+}
@@ -0,0 +1,629 @@
+//! Arithmetic for the Evaluate Selection commands.
+//!
+//! Expressions use the usual operators with C precedence — `+ - * / %`,
+//! `**` for powers, and `& | ^ ~ << >>` on integers — over decimal,
+//! `0x` hex, `0b` binary and `0o` octal literals (`_` separators allowed).
+//!
+//! A number can carry a data size (`4 KiB`) or duration (`1.5h`) unit.
+//! Sizes are kept in bytes and durations in nanoseconds, so mixing units of
+//! one kind is exact; a trailing `in <unit>` (or `to <unit>`) picks the unit
+//! of the result, which otherwise is bytes or seconds.
+//!
+//! Integer arithmetic stays exact until it overflows `i128`, then falls back
+//! to floating point, as does division that doesn't come out even.
+
+use std::fmt;
+
+/// A calculation result.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Number {
+    Int(i128),
+    Float(f64),
+}
+
+impl Number {
+    fn to_f64(self) -> f64 {
+        match self {
+            Number::Int(n) => n as f64,
+            Number::Float(f) => f,
+        }
+    }
+
+    fn is_zero(self) -> bool {
+        match self {
+            Number::Int(n) => n == 0,
+            Number::Float(f) => f == 0.0,
+        }
+    }
+
+    /// An integral float within exact range becomes an `Int`.
+    fn normalize(self) -> Self {
+        match self {
+            Number::Float(f) if f.fract() == 0.0 && f.abs() < 9_007_199_254_740_992.0 => {
+                Number::Int(f as i128)
+            }
+            n => n,
+        }
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Number::Int(n) => write!(f, "{n}"),
+            // Round to 15 significant digits so `0.1 + 0.2` shows as `0.3`.
+            Number::Float(x) => {
+                let rounded: f64 = format!("{x:.14e}").parse().unwrap_or(x);
+                if rounded != 0.0 && !(1e-6..1e16).contains(&rounded.abs()) {
+                    write!(f, "{rounded:e}")
+                } else {
+                    write!(f, "{rounded}")
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dimension {
+    /// Bytes.
+    Size,
+    /// Nanoseconds.
+    Time,
+}
+
+/// `(name, dimension, size of one unit in the dimension's base unit)`.
+const UNITS: &[(&str, Dimension, i128)] = &[
+    ("B", Dimension::Size, 1),
+    ("KB", Dimension::Size, 1_000),
+    ("MB", Dimension::Size, 1_000_000),
+    ("GB", Dimension::Size, 1_000_000_000),
+    ("TB", Dimension::Size, 1_000_000_000_000),
+    ("KiB", Dimension::Size, 1 << 10),
+    ("MiB", Dimension::Size, 1 << 20),
+    ("GiB", Dimension::Size, 1 << 30),
+    ("TiB", Dimension::Size, 1 << 40),
+    ("ns", Dimension::Time, 1),
+    ("us", Dimension::Time, 1_000),
+    ("µs", Dimension::Time, 1_000),
+    ("ms", Dimension::Time, 1_000_000),
+    ("s", Dimension::Time, 1_000_000_000),
+    ("min", Dimension::Time, 60_000_000_000),
+    ("h", Dimension::Time, 3_600_000_000_000),
+    ("d", Dimension::Time, 86_400_000_000_000),
+];
+
+fn unit(name: &str) -> Option<(&'static str, Dimension, i128)> {
+    UNITS.iter().copied().find(|(n, _, _)| *n == name)
+}
+
+/// Unit a result of `dimension` is shown in when the expression names none.
+fn default_unit(dimension: Dimension) -> &'static str {
+    match dimension {
+        Dimension::Size => "B",
+        Dimension::Time => "s",
+    }
+}
+
+/// The value of an expression, with the unit it is expressed in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Evaluation {
+    pub value: Number,
+    pub unit: Option<&'static str>,
+}
+
+impl Evaluation {
+    /// `0x…` form of an integer result.
+    pub fn hex(&self) -> Option<String> {
+        match self.value {
+            Number::Int(n) if n < 0 => Some(format!("-0x{:X}", n.unsigned_abs())),
+            Number::Int(n) => Some(format!("0x{n:X}")),
+            Number::Float(_) => None,
+        }
+    }
+
+    /// `0b…` form of an integer result.
+    pub fn binary(&self) -> Option<String> {
+        match self.value {
+            Number::Int(n) if n < 0 => Some(format!("-0b{:b}", n.unsigned_abs())),
+            Number::Int(n) => Some(format!("0b{n:b}")),
+            Number::Float(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Evaluation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.unit {
+            Some(unit) => write!(f, "{} {unit}", self.value),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
+
+/// Why an expression couldn't be evaluated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CalcError {
+    Syntax(String),
+    UnknownUnit(String),
+    DivisionByZero,
+    /// A bit operation on a fraction.
+    NotInteger,
+    /// Units that don't combine, e.g. `1s + 1KB` or `2 KiB * 3 KiB`.
+    UnitMismatch,
+    /// Infinite or NaN.
+    OutOfRange,
+}
+
+impl fmt::Display for CalcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalcError::Syntax(detail) => write!(f, "{detail}"),
+            CalcError::UnknownUnit(name) => write!(f, "unknown unit '{name}'"),
+            CalcError::DivisionByZero => write!(f, "division by zero"),
+            CalcError::NotInteger => write!(f, "bit operations need integers"),
+            CalcError::UnitMismatch => write!(f, "units don't match"),
+            CalcError::OutOfRange => write!(f, "result is out of range"),
+        }
+    }
+}
+
+impl std::error::Error for CalcError {}
+
+/// Evaluate `text` as an expression.
+pub fn evaluate(text: &str) -> Result<Evaluation, CalcError> {
+    let tokens = tokenize(text)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let quantity = parser.expr()?;
+
+    let target = match parser.peek() {
+        Some(Token::Ident(word)) if word == "in" || word == "to" => {
+            parser.pos += 1;
+            match parser.next() {
+                Some(Token::Ident(name)) => Some(unit(&name).ok_or(CalcError::UnknownUnit(name))?),
+                _ => return Err(CalcError::Syntax("expected a unit".into())),
+            }
+        }
+        _ => None,
+    };
+    if let Some(token) = parser.peek() {
+        return Err(CalcError::Syntax(format!("unexpected {token}")));
+    }
+
+    let (value, shown_unit) = match (quantity.dimension, target) {
+        (None, None) => (quantity.value, None),
+        (Some(dimension), None) => {
+            let name = default_unit(dimension);
+            let (_, _, factor) = unit(name).expect("default units are in the table");
+            (div(quantity.value, Number::Int(factor))?, Some(name))
+        }
+        (Some(dimension), Some((name, target, factor))) if dimension == target => {
+            (div(quantity.value, Number::Int(factor))?, Some(name))
+        }
+        _ => return Err(CalcError::UnitMismatch),
+    };
+    if let Number::Float(f) = value {
+        if !f.is_finite() {
+            return Err(CalcError::OutOfRange);
+        }
+    }
+    Ok(Evaluation {
+        value: value.normalize(),
+        unit: shown_unit,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(Number),
+    Ident(String),
+    Op(&'static str),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(n) => write!(f, "'{n}'"),
+            Token::Ident(name) => write!(f, "'{name}'"),
+            Token::Op(op) => write!(f, "'{op}'"),
+        }
+    }
+}
+
+/// Longest first, so `**` wins over `*`.
+const OPERATORS: &[&str] = &[
+    "**", "<<", ">>", "+", "-", "*", "/", "%", "&", "|", "^", "~", "(", ")",
+];
+
+fn tokenize(text: &str) -> Result<Vec<Token>, CalcError> {
+    let mut tokens = Vec::new();
+    let mut rest = text.trim_start();
+    while let Some(ch) = rest.chars().next() {
+        let len = if ch.is_ascii_digit() || ch == '.' {
+            let (number, len) = lex_number(rest)?;
+            tokens.push(Token::Number(number));
+            len
+        } else if ch.is_alphabetic() {
+            let len = rest
+                .find(|c: char| !c.is_alphanumeric())
+                .unwrap_or(rest.len());
+            tokens.push(Token::Ident(rest[..len].to_string()));
+            len
+        } else if let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+            tokens.push(Token::Op(op));
+            op.len()
+        } else {
+            return Err(CalcError::Syntax(format!("unexpected '{ch}'")));
+        };
+        rest = rest[len..].trim_start();
+    }
+    Ok(tokens)
+}
+
+/// Lex the number at the start of `s`, returning it and its length.
+fn lex_number(s: &str) -> Result<(Number, usize), CalcError> {
+    let radix = match s.get(..2) {
+        Some("0x" | "0X") => 16,
+        Some("0b" | "0B") => 2,
+        Some("0o" | "0O") => 8,
+        _ => 10,
+    };
+    if radix != 10 {
+        let digits_len = s[2..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(s.len() - 2);
+        let digits: String = s[2..2 + digits_len].chars().filter(|&c| c != '_').collect();
+        let value = i128::from_str_radix(&digits, radix)
+            .map_err(|_| CalcError::Syntax(format!("bad number '{}'", &s[..2 + digits_len])))?;
+        return Ok((Number::Int(value), 2 + digits_len));
+    }
+
+    let bytes = s.as_bytes();
+    let digits = |mut i: usize| {
+        while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b'_') {
+            i += 1;
+        }
+        i
+    };
+    let mut end = digits(0);
+    let mut is_float = false;
+    if bytes.get(end) == Some(&b'.') {
+        end = digits(end + 1);
+        is_float = true;
+    }
+    // An exponent needs digits after it; otherwise the `e` is left alone.
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let sign = usize::from(matches!(bytes.get(end + 1), Some(b'+' | b'-')));
+        if bytes.get(end + 1 + sign).is_some_and(u8::is_ascii_digit) {
+            end = digits(end + 1 + sign);
+            is_float = true;
+        }
+    }
+
+    let text: String = s[..end].chars().filter(|&c| c != '_').collect();
+    let bad = || CalcError::Syntax(format!("bad number '{}'", &s[..end]));
+    let number = if is_float {
+        Number::Float(text.parse().map_err(|_| bad())?)
+    } else {
+        match text.parse() {
+            Ok(n) => Number::Int(n),
+            Err(_) => Number::Float(text.parse().map_err(|_| bad())?),
+        }
+    };
+    Ok((number, end))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Quantity {
+    value: Number,
+    dimension: Option<Dimension>,
+}
+
+impl Quantity {
+    fn plain(value: Number) -> Self {
+        Self {
+            value,
+            dimension: None,
+        }
+    }
+}
+
+/// Binary operators from loosest to tightest binding; `**` and the unary
+/// operators bind tighter still.
+const PRECEDENCE: &[&[&str]] = &[
+    &["|"],
+    &["^"],
+    &["&"],
+    &["<<", ">>"],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// Consume the next token if it is one of `ops`.
+    fn eat_op(&mut self, ops: &[&str]) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token::Op(op)) if ops.contains(op) => {
+                let op = *op;
+                self.pos += 1;
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+
+    fn expr(&mut self) -> Result<Quantity, CalcError> {
+        self.binary(0)
+    }
+
+    fn binary(&mut self, level: usize) -> Result<Quantity, CalcError> {
+        let Some(ops) = PRECEDENCE.get(level) else {
+            return self.unary();
+        };
+        let mut left = self.binary(level + 1)?;
+        while let Some(op) = self.eat_op(ops) {
+            let right = self.binary(level + 1)?;
+            left = apply(op, left, right)?;
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Quantity, CalcError> {
+        if let Some(op) = self.eat_op(&["-", "+", "~"]) {
+            let mut operand = self.unary()?;
+            operand.value = match (op, operand.value) {
+                ("+", value) => value,
+                ("-", Number::Int(n)) => n
+                    .checked_neg()
+                    .map_or(Number::Float(-(n as f64)), Number::Int),
+                ("-", Number::Float(f)) => Number::Float(-f),
+                (_, value) => Number::Int(!integer(value, operand.dimension)?),
+            };
+            return Ok(operand);
+        }
+        let base = self.postfix()?;
+        if self.eat_op(&["**"]).is_some() {
+            // Right-associative, and `2 ** -1` is allowed.
+            let exponent = self.unary()?;
+            return apply("**", base, exponent);
+        }
+        Ok(base)
+    }
+
+    /// A primary followed by an optional unit.
+    fn postfix(&mut self) -> Result<Quantity, CalcError> {
+        let mut quantity = self.primary()?;
+        if let Some(Token::Ident(name)) = self.peek() {
+            if name != "in" && name != "to" {
+                let name = name.clone();
+                self.pos += 1;
+                let (_, dimension, factor) = unit(&name).ok_or(CalcError::UnknownUnit(name))?;
+                if quantity.dimension.is_some() {
+                    return Err(CalcError::UnitMismatch);
+                }
+                quantity = Quantity {
+                    value: mul(quantity.value, Number::Int(factor)),
+                    dimension: Some(dimension),
+                };
+            }
+        }
+        Ok(quantity)
+    }
+
+    fn primary(&mut self) -> Result<Quantity, CalcError> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Quantity::plain(n)),
+            Some(Token::Op("(")) => {
+                let inner = self.expr()?;
+                match self.next() {
+                    Some(Token::Op(")")) => Ok(inner),
+                    _ => Err(CalcError::Syntax("missing ')'".into())),
+                }
+            }
+            Some(token) => Err(CalcError::Syntax(format!("unexpected {token}"))),
+            None => Err(CalcError::Syntax("incomplete expression".into())),
+        }
+    }
+}
+
+/// The integer value of a dimensionless operand of a bit operation.
+fn integer(value: Number, dimension: Option<Dimension>) -> Result<i128, CalcError> {
+    if dimension.is_some() {
+        return Err(CalcError::UnitMismatch);
+    }
+    match value {
+        Number::Int(n) => Ok(n),
+        Number::Float(_) => Err(CalcError::NotInteger),
+    }
+}
+
+fn apply(op: &str, left: Quantity, right: Quantity) -> Result<Quantity, CalcError> {
+    let (l, r) = (left.value, right.value);
+    let (value, dimension) = match op {
+        "+" | "-" | "%" => {
+            if left.dimension != right.dimension {
+                return Err(CalcError::UnitMismatch);
+            }
+            let dimension = left.dimension;
+            let value = match op {
+                "+" => checked(l, r, i128::checked_add, |a, b| a + b),
+                "-" => checked(l, r, i128::checked_sub, |a, b| a - b),
+                _ if r.is_zero() => return Err(CalcError::DivisionByZero),
+                _ => checked(l, r, i128::checked_rem, |a, b| a % b),
+            };
+            (value, dimension)
+        }
+        "*" => {
+            let dimension = match (left.dimension, right.dimension) {
+                (d, None) | (None, d) => d,
+                _ => return Err(CalcError::UnitMismatch),
+            };
+            (mul(l, r), dimension)
+        }
+        "/" => {
+            let dimension = match (left.dimension, right.dimension) {
+                (a, b) if a == b => None,
+                (d, None) => d,
+                _ => return Err(CalcError::UnitMismatch),
+            };
+            (div(l, r)?, dimension)
+        }
+        "**" => {
+            if left.dimension.is_some() || right.dimension.is_some() {
+                return Err(CalcError::UnitMismatch);
+            }
+            let value = match (l, r) {
+                (Number::Int(base), Number::Int(exp)) => u32::try_from(exp)
+                    .ok()
+                    .and_then(|exp| base.checked_pow(exp))
+                    .map_or_else(|| Number::Float(l.to_f64().powf(r.to_f64())), Number::Int),
+                _ => Number::Float(l.to_f64().powf(r.to_f64())),
+            };
+            (value, None)
+        }
+        _ => {
+            let a = integer(l, left.dimension)?;
+            let b = integer(r, right.dimension)?;
+            let value = match op {
+                "&" => a & b,
+                "|" => a | b,
+                "^" => a ^ b,
+                _ => {
+                    let shift = u32::try_from(b)
+                        .ok()
+                        .filter(|&s| s < 128)
+                        .ok_or(CalcError::OutOfRange)?;
+                    if op == "<<" {
+                        a.checked_mul(1 << shift).ok_or(CalcError::OutOfRange)?
+                    } else {
+                        a >> shift
+                    }
+                }
+            };
+            (Number::Int(value), None)
+        }
+    };
+    Ok(Quantity { value, dimension })
+}
+
+/// `int_op` on two integers, falling back to `float_op` on overflow or when
+/// either side is a float.
+fn checked(
+    l: Number,
+    r: Number,
+    int_op: fn(i128, i128) -> Option<i128>,
+    float_op: fn(f64, f64) -> f64,
+) -> Number {
+    if let (Number::Int(a), Number::Int(b)) = (l, r) {
+        if let Some(n) = int_op(a, b) {
+            return Number::Int(n);
+        }
+    }
+    Number::Float(float_op(l.to_f64(), r.to_f64()))
+}
+
+fn mul(l: Number, r: Number) -> Number {
+    checked(l, r, i128::checked_mul, |a, b| a * b)
+}
+
+/// Division that stays an integer when it comes out even.
+fn div(l: Number, r: Number) -> Result<Number, CalcError> {
+    if r.is_zero() {
+        return Err(CalcError::DivisionByZero);
+    }
+    if let (Number::Int(a), Number::Int(b)) = (l, r) {
+        if a.checked_rem(b) == Some(0) {
+            if let Some(n) = a.checked_div(b) {
+                return Ok(Number::Int(n));
+            }
+        }
+    }
+    Ok(Number::Float(l.to_f64() / r.to_f64()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(text: &str) -> String {
+        evaluate(text).unwrap().to_string()
+    }
+
+    #[test]
+    fn arithmetic_and_precedence() {
+        assert_eq!(eval("1 + 2 * 3"), "7");
+        assert_eq!(eval("(1 + 2) * 3"), "9");
+        assert_eq!(eval("2 ** 3 ** 2"), "512");
+        assert_eq!(eval("-2 ** 2"), "-4");
+        assert_eq!(eval("7 / 2"), "3.5");
+        assert_eq!(eval("8 / 2"), "4");
+        assert_eq!(eval("-7 % 3"), "-1");
+        assert_eq!(eval("0.1 + 0.2"), "0.3");
+        assert_eq!(eval("1.5e3"), "1500");
+        assert_eq!(eval("60 * 60 * 24 * 365"), "31536000");
+        assert_eq!(eval("2 ** 0.5"), "1.4142135623731");
+        assert_eq!(eval("2 ** 130"), "1.36112946768375e39");
+        assert_eq!(eval("1 / 2 ** 30"), "9.31322574615479e-10");
+    }
+
+    #[test]
+    fn radix_literals_and_bit_operations() {
+        assert_eq!(eval("0xFF + 0b1010 + 0o17"), "280");
+        assert_eq!(eval("1_000_000"), "1000000");
+        assert_eq!(eval("1 << 4 | 0x0F"), "31");
+        assert_eq!(eval("0xF0 & 0x3C ^ 1"), "49");
+        assert_eq!(eval("~0"), "-1");
+        assert_eq!(eval("0x100 >> 4"), "16");
+        assert_eq!(evaluate("1.5 & 1"), Err(CalcError::NotInteger));
+
+        let result = evaluate("4096").unwrap();
+        assert_eq!(result.hex().as_deref(), Some("0x1000"));
+        assert_eq!(result.binary().as_deref(), Some("0b1000000000000"));
+        assert_eq!(evaluate("-16").unwrap().hex().as_deref(), Some("-0x10"));
+        assert_eq!(evaluate("0.5").unwrap().hex(), None);
+    }
+
+    #[test]
+    fn units_convert_within_a_dimension() {
+        assert_eq!(eval("4 KiB"), "4096 B");
+        assert_eq!(eval("1 GiB in MiB"), "1024 MiB");
+        assert_eq!(eval("1.5h to min"), "90 min");
+        assert_eq!(eval("500ms + 1s"), "1.5 s");
+        assert_eq!(eval("2h in ms"), "7200000 ms");
+        assert_eq!(eval("1 MB / 1 KB"), "1000");
+        assert_eq!(eval("3 * 512 KiB in KiB"), "1536 KiB");
+        assert_eq!(eval("90s % 1min"), "30 s");
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(evaluate("1s + 1KB"), Err(CalcError::UnitMismatch));
+        assert_eq!(evaluate("2 KiB * 2 KiB"), Err(CalcError::UnitMismatch));
+        assert_eq!(evaluate("10 in ms"), Err(CalcError::UnitMismatch));
+        assert_eq!(evaluate("1 / 0"), Err(CalcError::DivisionByZero));
+        assert_eq!(
+            evaluate("5 parsecs"),
+            Err(CalcError::UnknownUnit("parsecs".into()))
+        );
+        assert!(matches!(evaluate("1 +"), Err(CalcError::Syntax(_))));
+        assert!(matches!(evaluate("(1"), Err(CalcError::Syntax(_))));
+        assert!(matches!(evaluate("1 2"), Err(CalcError::Syntax(_))));
+        assert!(matches!(evaluate("0xZZ"), Err(CalcError::Syntax(_))));
+        assert!(matches!(evaluate(""), Err(CalcError::Syntax(_))));
+    }
+}
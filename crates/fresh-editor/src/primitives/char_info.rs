@@ -0,0 +1,944 @@
+//! Character facts for the Describe Character popup.
+//!
+//! Code point, UTF-8 bytes and general category are exact for every
+//! character. Names come from a compact built-in table — ASCII, Latin-1,
+//! Latin Extended-A, general punctuation, currency, arrows, CJK punctuation,
+//! invisible format characters and the emoticons block — plus the
+//! algorithmic CJK ideograph and Hangul syllable names. Other characters
+//! have no name rather than shipping the full Unicode name list.
+
+use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
+
+/// Everything the Describe Character popup shows about one character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharInfo {
+    pub ch: char,
+    /// `U+XXXX`.
+    pub code_point: String,
+    pub name: Option<String>,
+    /// Space-separated hex bytes, e.g. `F0 9F 98 80`.
+    pub utf8: String,
+    /// Abbreviation and long form, e.g. `So (Other Symbol)`.
+    pub category: String,
+}
+
+impl CharInfo {
+    pub fn new(ch: char) -> Self {
+        let mut buf = [0u8; 4];
+        let utf8 = ch
+            .encode_utf8(&mut buf)
+            .bytes()
+            .map(|b| format!("{b:02X}"))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let (abbr, long) = category_names(ch.general_category());
+        Self {
+            ch,
+            code_point: format!("U+{:04X}", ch as u32),
+            name: char_name(ch),
+            utf8,
+            category: format!("{abbr} ({long})"),
+        }
+    }
+}
+
+/// The Unicode name of `ch`, if it's in the built-in table or has an
+/// algorithmic name.
+pub fn char_name(ch: char) -> Option<String> {
+    let cp = ch as u32;
+    if let Ok(i) = CHAR_NAMES.binary_search_by_key(&cp, |(c, _)| *c) {
+        return Some(CHAR_NAMES[i].1.to_string());
+    }
+    match cp {
+        0x3400..=0x4DBF | 0x4E00..=0x9FFF | 0x20000..=0x2A6DF => {
+            Some(format!("CJK UNIFIED IDEOGRAPH-{cp:04X}"))
+        }
+        0xAC00..=0xD7A3 => Some(hangul_syllable_name(cp - 0xAC00)),
+        _ => None,
+    }
+}
+
+/// Unicode §3.12: a precomposed syllable's name spells its jamo.
+fn hangul_syllable_name(index: u32) -> String {
+    const LEAD: [&str; 19] = [
+        "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T",
+        "P", "H",
+    ];
+    const VOWEL: [&str; 21] = [
+        "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO",
+        "WE", "WI", "YU", "EU", "YI", "I",
+    ];
+    const TRAIL: [&str; 28] = [
+        "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH",
+        "M", "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+    ];
+    let lead = (index / (21 * 28)) as usize;
+    let vowel = (index % (21 * 28) / 28) as usize;
+    let trail = (index % 28) as usize;
+    format!(
+        "HANGUL SYLLABLE {}{}{}",
+        LEAD[lead], VOWEL[vowel], TRAIL[trail]
+    )
+}
+
+fn category_names(category: GeneralCategory) -> (&'static str, &'static str) {
+    use GeneralCategory::*;
+    match category {
+        UppercaseLetter => ("Lu", "Uppercase Letter"),
+        LowercaseLetter => ("Ll", "Lowercase Letter"),
+        TitlecaseLetter => ("Lt", "Titlecase Letter"),
+        ModifierLetter => ("Lm", "Modifier Letter"),
+        OtherLetter => ("Lo", "Other Letter"),
+        NonspacingMark => ("Mn", "Nonspacing Mark"),
+        SpacingMark => ("Mc", "Spacing Mark"),
+        EnclosingMark => ("Me", "Enclosing Mark"),
+        DecimalNumber => ("Nd", "Decimal Number"),
+        LetterNumber => ("Nl", "Letter Number"),
+        OtherNumber => ("No", "Other Number"),
+        ConnectorPunctuation => ("Pc", "Connector Punctuation"),
+        DashPunctuation => ("Pd", "Dash Punctuation"),
+        OpenPunctuation => ("Ps", "Open Punctuation"),
+        ClosePunctuation => ("Pe", "Close Punctuation"),
+        InitialPunctuation => ("Pi", "Initial Punctuation"),
+        FinalPunctuation => ("Pf", "Final Punctuation"),
+        OtherPunctuation => ("Po", "Other Punctuation"),
+        MathSymbol => ("Sm", "Math Symbol"),
+        CurrencySymbol => ("Sc", "Currency Symbol"),
+        ModifierSymbol => ("Sk", "Modifier Symbol"),
+        OtherSymbol => ("So", "Other Symbol"),
+        SpaceSeparator => ("Zs", "Space Separator"),
+        LineSeparator => ("Zl", "Line Separator"),
+        ParagraphSeparator => ("Zp", "Paragraph Separator"),
+        Control => ("Cc", "Control"),
+        Format => ("Cf", "Format"),
+        Surrogate => ("Cs", "Surrogate"),
+        PrivateUse => ("Co", "Private Use"),
+        Unassigned => ("Cn", "Unassigned"),
+    }
+}
+
+/// Names from the Unicode Character Database (C0 controls use their
+/// ISO 6429 aliases), sorted by code point for binary search.
+const CHAR_NAMES: &[(u32, &str)] = &[
+    (0x0000, "NULL"),
+    (0x0001, "START OF HEADING"),
+    (0x0002, "START OF TEXT"),
+    (0x0003, "END OF TEXT"),
+    (0x0004, "END OF TRANSMISSION"),
+    (0x0005, "ENQUIRY"),
+    (0x0006, "ACKNOWLEDGE"),
+    (0x0007, "BELL"),
+    (0x0008, "BACKSPACE"),
+    (0x0009, "CHARACTER TABULATION"),
+    (0x000A, "LINE FEED"),
+    (0x000B, "LINE TABULATION"),
+    (0x000C, "FORM FEED"),
+    (0x000D, "CARRIAGE RETURN"),
+    (0x000E, "SHIFT OUT"),
+    (0x000F, "SHIFT IN"),
+    (0x0010, "DATA LINK ESCAPE"),
+    (0x0011, "DEVICE CONTROL ONE"),
+    (0x0012, "DEVICE CONTROL TWO"),
+    (0x0013, "DEVICE CONTROL THREE"),
+    (0x0014, "DEVICE CONTROL FOUR"),
+    (0x0015, "NEGATIVE ACKNOWLEDGE"),
+    (0x0016, "SYNCHRONOUS IDLE"),
+    (0x0017, "END OF TRANSMISSION BLOCK"),
+    (0x0018, "CANCEL"),
+    (0x0019, "END OF MEDIUM"),
+    (0x001A, "SUBSTITUTE"),
+    (0x001B, "ESCAPE"),
+    (0x001C, "INFORMATION SEPARATOR FOUR"),
+    (0x001D, "INFORMATION SEPARATOR THREE"),
+    (0x001E, "INFORMATION SEPARATOR TWO"),
+    (0x001F, "INFORMATION SEPARATOR ONE"),
+    (0x0020, "SPACE"),
+    (0x0021, "EXCLAMATION MARK"),
+    (0x0022, "QUOTATION MARK"),
+    (0x0023, "NUMBER SIGN"),
+    (0x0024, "DOLLAR SIGN"),
+    (0x0025, "PERCENT SIGN"),
+    (0x0026, "AMPERSAND"),
+    (0x0027, "APOSTROPHE"),
+    (0x0028, "LEFT PARENTHESIS"),
+    (0x0029, "RIGHT PARENTHESIS"),
+    (0x002A, "ASTERISK"),
+    (0x002B, "PLUS SIGN"),
+    (0x002C, "COMMA"),
+    (0x002D, "HYPHEN-MINUS"),
+    (0x002E, "FULL STOP"),
+    (0x002F, "SOLIDUS"),
+    (0x0030, "DIGIT ZERO"),
+    (0x0031, "DIGIT ONE"),
+    (0x0032, "DIGIT TWO"),
+    (0x0033, "DIGIT THREE"),
+    (0x0034, "DIGIT FOUR"),
+    (0x0035, "DIGIT FIVE"),
+    (0x0036, "DIGIT SIX"),
+    (0x0037, "DIGIT SEVEN"),
+    (0x0038, "DIGIT EIGHT"),
+    (0x0039, "DIGIT NINE"),
+    (0x003A, "COLON"),
+    (0x003B, "SEMICOLON"),
+    (0x003C, "LESS-THAN SIGN"),
+    (0x003D, "EQUALS SIGN"),
+    (0x003E, "GREATER-THAN SIGN"),
+    (0x003F, "QUESTION MARK"),
+    (0x0040, "COMMERCIAL AT"),
+    (0x0041, "LATIN CAPITAL LETTER A"),
+    (0x0042, "LATIN CAPITAL LETTER B"),
+    (0x0043, "LATIN CAPITAL LETTER C"),
+    (0x0044, "LATIN CAPITAL LETTER D"),
+    (0x0045, "LATIN CAPITAL LETTER E"),
+    (0x0046, "LATIN CAPITAL LETTER F"),
+    (0x0047, "LATIN CAPITAL LETTER G"),
+    (0x0048, "LATIN CAPITAL LETTER H"),
+    (0x0049, "LATIN CAPITAL LETTER I"),
+    (0x004A, "LATIN CAPITAL LETTER J"),
+    (0x004B, "LATIN CAPITAL LETTER K"),
+    (0x004C, "LATIN CAPITAL LETTER L"),
+    (0x004D, "LATIN CAPITAL LETTER M"),
+    (0x004E, "LATIN CAPITAL LETTER N"),
+    (0x004F, "LATIN CAPITAL LETTER O"),
+    (0x0050, "LATIN CAPITAL LETTER P"),
+    (0x0051, "LATIN CAPITAL LETTER Q"),
+    (0x0052, "LATIN CAPITAL LETTER R"),
+    (0x0053, "LATIN CAPITAL LETTER S"),
+    (0x0054, "LATIN CAPITAL LETTER T"),
+    (0x0055, "LATIN CAPITAL LETTER U"),
+    (0x0056, "LATIN CAPITAL LETTER V"),
+    (0x0057, "LATIN CAPITAL LETTER W"),
+    (0x0058, "LATIN CAPITAL LETTER X"),
+    (0x0059, "LATIN CAPITAL LETTER Y"),
+    (0x005A, "LATIN CAPITAL LETTER Z"),
+    (0x005B, "LEFT SQUARE BRACKET"),
+    (0x005C, "REVERSE SOLIDUS"),
+    (0x005D, "RIGHT SQUARE BRACKET"),
+    (0x005E, "CIRCUMFLEX ACCENT"),
+    (0x005F, "LOW LINE"),
+    (0x0060, "GRAVE ACCENT"),
+    (0x0061, "LATIN SMALL LETTER A"),
+    (0x0062, "LATIN SMALL LETTER B"),
+    (0x0063, "LATIN SMALL LETTER C"),
+    (0x0064, "LATIN SMALL LETTER D"),
+    (0x0065, "LATIN SMALL LETTER E"),
+    (0x0066, "LATIN SMALL LETTER F"),
+    (0x0067, "LATIN SMALL LETTER G"),
+    (0x0068, "LATIN SMALL LETTER H"),
+    (0x0069, "LATIN SMALL LETTER I"),
+    (0x006A, "LATIN SMALL LETTER J"),
+    (0x006B, "LATIN SMALL LETTER K"),
+    (0x006C, "LATIN SMALL LETTER L"),
+    (0x006D, "LATIN SMALL LETTER M"),
+    (0x006E, "LATIN SMALL LETTER N"),
+    (0x006F, "LATIN SMALL LETTER O"),
+    (0x0070, "LATIN SMALL LETTER P"),
+    (0x0071, "LATIN SMALL LETTER Q"),
+    (0x0072, "LATIN SMALL LETTER R"),
+    (0x0073, "LATIN SMALL LETTER S"),
+    (0x0074, "LATIN SMALL LETTER T"),
+    (0x0075, "LATIN SMALL LETTER U"),
+    (0x0076, "LATIN SMALL LETTER V"),
+    (0x0077, "LATIN SMALL LETTER W"),
+    (0x0078, "LATIN SMALL LETTER X"),
+    (0x0079, "LATIN SMALL LETTER Y"),
+    (0x007A, "LATIN SMALL LETTER Z"),
+    (0x007B, "LEFT CURLY BRACKET"),
+    (0x007C, "VERTICAL LINE"),
+    (0x007D, "RIGHT CURLY BRACKET"),
+    (0x007E, "TILDE"),
+    (0x007F, "DELETE"),
+    (0x0085, "NEXT LINE"),
+    (0x00A0, "NO-BREAK SPACE"),
+    (0x00A1, "INVERTED EXCLAMATION MARK"),
+    (0x00A2, "CENT SIGN"),
+    (0x00A3, "POUND SIGN"),
+    (0x00A4, "CURRENCY SIGN"),
+    (0x00A5, "YEN SIGN"),
+    (0x00A6, "BROKEN BAR"),
+    (0x00A7, "SECTION SIGN"),
+    (0x00A8, "DIAERESIS"),
+    (0x00A9, "COPYRIGHT SIGN"),
+    (0x00AA, "FEMININE ORDINAL INDICATOR"),
+    (0x00AB, "LEFT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+    (0x00AC, "NOT SIGN"),
+    (0x00AD, "SOFT HYPHEN"),
+    (0x00AE, "REGISTERED SIGN"),
+    (0x00AF, "MACRON"),
+    (0x00B0, "DEGREE SIGN"),
+    (0x00B1, "PLUS-MINUS SIGN"),
+    (0x00B2, "SUPERSCRIPT TWO"),
+    (0x00B3, "SUPERSCRIPT THREE"),
+    (0x00B4, "ACUTE ACCENT"),
+    (0x00B5, "MICRO SIGN"),
+    (0x00B6, "PILCROW SIGN"),
+    (0x00B7, "MIDDLE DOT"),
+    (0x00B8, "CEDILLA"),
+    (0x00B9, "SUPERSCRIPT ONE"),
+    (0x00BA, "MASCULINE ORDINAL INDICATOR"),
+    (0x00BB, "RIGHT-POINTING DOUBLE ANGLE QUOTATION MARK"),
+    (0x00BC, "VULGAR FRACTION ONE QUARTER"),
+    (0x00BD, "VULGAR FRACTION ONE HALF"),
+    (0x00BE, "VULGAR FRACTION THREE QUARTERS"),
+    (0x00BF, "INVERTED QUESTION MARK"),
+    (0x00C0, "LATIN CAPITAL LETTER A WITH GRAVE"),
+    (0x00C1, "LATIN CAPITAL LETTER A WITH ACUTE"),
+    (0x00C2, "LATIN CAPITAL LETTER A WITH CIRCUMFLEX"),
+    (0x00C3, "LATIN CAPITAL LETTER A WITH TILDE"),
+    (0x00C4, "LATIN CAPITAL LETTER A WITH DIAERESIS"),
+    (0x00C5, "LATIN CAPITAL LETTER A WITH RING ABOVE"),
+    (0x00C6, "LATIN CAPITAL LETTER AE"),
+    (0x00C7, "LATIN CAPITAL LETTER C WITH CEDILLA"),
+    (0x00C8, "LATIN CAPITAL LETTER E WITH GRAVE"),
+    (0x00C9, "LATIN CAPITAL LETTER E WITH ACUTE"),
+    (0x00CA, "LATIN CAPITAL LETTER E WITH CIRCUMFLEX"),
+    (0x00CB, "LATIN CAPITAL LETTER E WITH DIAERESIS"),
+    (0x00CC, "LATIN CAPITAL LETTER I WITH GRAVE"),
+    (0x00CD, "LATIN CAPITAL LETTER I WITH ACUTE"),
+    (0x00CE, "LATIN CAPITAL LETTER I WITH CIRCUMFLEX"),
+    (0x00CF, "LATIN CAPITAL LETTER I WITH DIAERESIS"),
+    (0x00D0, "LATIN CAPITAL LETTER ETH"),
+    (0x00D1, "LATIN CAPITAL LETTER N WITH TILDE"),
+    (0x00D2, "LATIN CAPITAL LETTER O WITH GRAVE"),
+    (0x00D3, "LATIN CAPITAL LETTER O WITH ACUTE"),
+    (0x00D4, "LATIN CAPITAL LETTER O WITH CIRCUMFLEX"),
+    (0x00D5, "LATIN CAPITAL LETTER O WITH TILDE"),
+    (0x00D6, "LATIN CAPITAL LETTER O WITH DIAERESIS"),
+    (0x00D7, "MULTIPLICATION SIGN"),
+    (0x00D8, "LATIN CAPITAL LETTER O WITH STROKE"),
+    (0x00D9, "LATIN CAPITAL LETTER U WITH GRAVE"),
+    (0x00DA, "LATIN CAPITAL LETTER U WITH ACUTE"),
+    (0x00DB, "LATIN CAPITAL LETTER U WITH CIRCUMFLEX"),
+    (0x00DC, "LATIN CAPITAL LETTER U WITH DIAERESIS"),
+    (0x00DD, "LATIN CAPITAL LETTER Y WITH ACUTE"),
+    (0x00DE, "LATIN CAPITAL LETTER THORN"),
+    (0x00DF, "LATIN SMALL LETTER SHARP S"),
+    (0x00E0, "LATIN SMALL LETTER A WITH GRAVE"),
+    (0x00E1, "LATIN SMALL LETTER A WITH ACUTE"),
+    (0x00E2, "LATIN SMALL LETTER A WITH CIRCUMFLEX"),
+    (0x00E3, "LATIN SMALL LETTER A WITH TILDE"),
+    (0x00E4, "LATIN SMALL LETTER A WITH DIAERESIS"),
+    (0x00E5, "LATIN SMALL LETTER A WITH RING ABOVE"),
+    (0x00E6, "LATIN SMALL LETTER AE"),
+    (0x00E7, "LATIN SMALL LETTER C WITH CEDILLA"),
+    (0x00E8, "LATIN SMALL LETTER E WITH GRAVE"),
+    (0x00E9, "LATIN SMALL LETTER E WITH ACUTE"),
+    (0x00EA, "LATIN SMALL LETTER E WITH CIRCUMFLEX"),
+    (0x00EB, "LATIN SMALL LETTER E WITH DIAERESIS"),
+    (0x00EC, "LATIN SMALL LETTER I WITH GRAVE"),
+    (0x00ED, "LATIN SMALL LETTER I WITH ACUTE"),
+    (0x00EE, "LATIN SMALL LETTER I WITH CIRCUMFLEX"),
+    (0x00EF, "LATIN SMALL LETTER I WITH DIAERESIS"),
+    (0x00F0, "LATIN SMALL LETTER ETH"),
+    (0x00F1, "LATIN SMALL LETTER N WITH TILDE"),
+    (0x00F2, "LATIN SMALL LETTER O WITH GRAVE"),
+    (0x00F3, "LATIN SMALL LETTER O WITH ACUTE"),
+    (0x00F4, "LATIN SMALL LETTER O WITH CIRCUMFLEX"),
+    (0x00F5, "LATIN SMALL LETTER O WITH TILDE"),
+    (0x00F6, "LATIN SMALL LETTER O WITH DIAERESIS"),
+    (0x00F7, "DIVISION SIGN"),
+    (0x00F8, "LATIN SMALL LETTER O WITH STROKE"),
+    (0x00F9, "LATIN SMALL LETTER U WITH GRAVE"),
+    (0x00FA, "LATIN SMALL LETTER U WITH ACUTE"),
+    (0x00FB, "LATIN SMALL LETTER U WITH CIRCUMFLEX"),
+    (0x00FC, "LATIN SMALL LETTER U WITH DIAERESIS"),
+    (0x00FD, "LATIN SMALL LETTER Y WITH ACUTE"),
+    (0x00FE, "LATIN SMALL LETTER THORN"),
+    (0x00FF, "LATIN SMALL LETTER Y WITH DIAERESIS"),
+    (0x0100, "LATIN CAPITAL LETTER A WITH MACRON"),
+    (0x0101, "LATIN SMALL LETTER A WITH MACRON"),
+    (0x0102, "LATIN CAPITAL LETTER A WITH BREVE"),
+    (0x0103, "LATIN SMALL LETTER A WITH BREVE"),
+    (0x0104, "LATIN CAPITAL LETTER A WITH OGONEK"),
+    (0x0105, "LATIN SMALL LETTER A WITH OGONEK"),
+    (0x0106, "LATIN CAPITAL LETTER C WITH ACUTE"),
+    (0x0107, "LATIN SMALL LETTER C WITH ACUTE"),
+    (0x0108, "LATIN CAPITAL LETTER C WITH CIRCUMFLEX"),
+    (0x0109, "LATIN SMALL LETTER C WITH CIRCUMFLEX"),
+    (0x010A, "LATIN CAPITAL LETTER C WITH DOT ABOVE"),
+    (0x010B, "LATIN SMALL LETTER C WITH DOT ABOVE"),
+    (0x010C, "LATIN CAPITAL LETTER C WITH CARON"),
+    (0x010D, "LATIN SMALL LETTER C WITH CARON"),
+    (0x010E, "LATIN CAPITAL LETTER D WITH CARON"),
+    (0x010F, "LATIN SMALL LETTER D WITH CARON"),
+    (0x0110, "LATIN CAPITAL LETTER D WITH STROKE"),
+    (0x0111, "LATIN SMALL LETTER D WITH STROKE"),
+    (0x0112, "LATIN CAPITAL LETTER E WITH MACRON"),
+    (0x0113, "LATIN SMALL LETTER E WITH MACRON"),
+    (0x0114, "LATIN CAPITAL LETTER E WITH BREVE"),
+    (0x0115, "LATIN SMALL LETTER E WITH BREVE"),
+    (0x0116, "LATIN CAPITAL LETTER E WITH DOT ABOVE"),
+    (0x0117, "LATIN SMALL LETTER E WITH DOT ABOVE"),
+    (0x0118, "LATIN CAPITAL LETTER E WITH OGONEK"),
+    (0x0119, "LATIN SMALL LETTER E WITH OGONEK"),
+    (0x011A, "LATIN CAPITAL LETTER E WITH CARON"),
+    (0x011B, "LATIN SMALL LETTER E WITH CARON"),
+    (0x011C, "LATIN CAPITAL LETTER G WITH CIRCUMFLEX"),
+    (0x011D, "LATIN SMALL LETTER G WITH CIRCUMFLEX"),
+    (0x011E, "LATIN CAPITAL LETTER G WITH BREVE"),
+    (0x011F, "LATIN SMALL LETTER G WITH BREVE"),
+    (0x0120, "LATIN CAPITAL LETTER G WITH DOT ABOVE"),
+    (0x0121, "LATIN SMALL LETTER G WITH DOT ABOVE"),
+    (0x0122, "LATIN CAPITAL LETTER G WITH CEDILLA"),
+    (0x0123, "LATIN SMALL LETTER G WITH CEDILLA"),
+    (0x0124, "LATIN CAPITAL LETTER H WITH CIRCUMFLEX"),
+    (0x0125, "LATIN SMALL LETTER H WITH CIRCUMFLEX"),
+    (0x0126, "LATIN CAPITAL LETTER H WITH STROKE"),
+    (0x0127, "LATIN SMALL LETTER H WITH STROKE"),
+    (0x0128, "LATIN CAPITAL LETTER I WITH TILDE"),
+    (0x0129, "LATIN SMALL LETTER I WITH TILDE"),
+    (0x012A, "LATIN CAPITAL LETTER I WITH MACRON"),
+    (0x012B, "LATIN SMALL LETTER I WITH MACRON"),
+    (0x012C, "LATIN CAPITAL LETTER I WITH BREVE"),
+    (0x012D, "LATIN SMALL LETTER I WITH BREVE"),
+    (0x012E, "LATIN CAPITAL LETTER I WITH OGONEK"),
+    (0x012F, "LATIN SMALL LETTER I WITH OGONEK"),
+    (0x0130, "LATIN CAPITAL LETTER I WITH DOT ABOVE"),
+    (0x0131, "LATIN SMALL LETTER DOTLESS I"),
+    (0x0132, "LATIN CAPITAL LIGATURE IJ"),
+    (0x0133, "LATIN SMALL LIGATURE IJ"),
+    (0x0134, "LATIN CAPITAL LETTER J WITH CIRCUMFLEX"),
+    (0x0135, "LATIN SMALL LETTER J WITH CIRCUMFLEX"),
+    (0x0136, "LATIN CAPITAL LETTER K WITH CEDILLA"),
+    (0x0137, "LATIN SMALL LETTER K WITH CEDILLA"),
+    (0x0138, "LATIN SMALL LETTER KRA"),
+    (0x0139, "LATIN CAPITAL LETTER L WITH ACUTE"),
+    (0x013A, "LATIN SMALL LETTER L WITH ACUTE"),
+    (0x013B, "LATIN CAPITAL LETTER L WITH CEDILLA"),
+    (0x013C, "LATIN SMALL LETTER L WITH CEDILLA"),
+    (0x013D, "LATIN CAPITAL LETTER L WITH CARON"),
+    (0x013E, "LATIN SMALL LETTER L WITH CARON"),
+    (0x013F, "LATIN CAPITAL LETTER L WITH MIDDLE DOT"),
+    (0x0140, "LATIN SMALL LETTER L WITH MIDDLE DOT"),
+    (0x0141, "LATIN CAPITAL LETTER L WITH STROKE"),
+    (0x0142, "LATIN SMALL LETTER L WITH STROKE"),
+    (0x0143, "LATIN CAPITAL LETTER N WITH ACUTE"),
+    (0x0144, "LATIN SMALL LETTER N WITH ACUTE"),
+    (0x0145, "LATIN CAPITAL LETTER N WITH CEDILLA"),
+    (0x0146, "LATIN SMALL LETTER N WITH CEDILLA"),
+    (0x0147, "LATIN CAPITAL LETTER N WITH CARON"),
+    (0x0148, "LATIN SMALL LETTER N WITH CARON"),
+    (0x0149, "LATIN SMALL LETTER N PRECEDED BY APOSTROPHE"),
+    (0x014A, "LATIN CAPITAL LETTER ENG"),
+    (0x014B, "LATIN SMALL LETTER ENG"),
+    (0x014C, "LATIN CAPITAL LETTER O WITH MACRON"),
+    (0x014D, "LATIN SMALL LETTER O WITH MACRON"),
+    (0x014E, "LATIN CAPITAL LETTER O WITH BREVE"),
+    (0x014F, "LATIN SMALL LETTER O WITH BREVE"),
+    (0x0150, "LATIN CAPITAL LETTER O WITH DOUBLE ACUTE"),
+    (0x0151, "LATIN SMALL LETTER O WITH DOUBLE ACUTE"),
+    (0x0152, "LATIN CAPITAL LIGATURE OE"),
+    (0x0153, "LATIN SMALL LIGATURE OE"),
+    (0x0154, "LATIN CAPITAL LETTER R WITH ACUTE"),
+    (0x0155, "LATIN SMALL LETTER R WITH ACUTE"),
+    (0x0156, "LATIN CAPITAL LETTER R WITH CEDILLA"),
+    (0x0157, "LATIN SMALL LETTER R WITH CEDILLA"),
+    (0x0158, "LATIN CAPITAL LETTER R WITH CARON"),
+    (0x0159, "LATIN SMALL LETTER R WITH CARON"),
+    (0x015A, "LATIN CAPITAL LETTER S WITH ACUTE"),
+    (0x015B, "LATIN SMALL LETTER S WITH ACUTE"),
+    (0x015C, "LATIN CAPITAL LETTER S WITH CIRCUMFLEX"),
+    (0x015D, "LATIN SMALL LETTER S WITH CIRCUMFLEX"),
+    (0x015E, "LATIN CAPITAL LETTER S WITH CEDILLA"),
+    (0x015F, "LATIN SMALL LETTER S WITH CEDILLA"),
+    (0x0160, "LATIN CAPITAL LETTER S WITH CARON"),
+    (0x0161, "LATIN SMALL LETTER S WITH CARON"),
+    (0x0162, "LATIN CAPITAL LETTER T WITH CEDILLA"),
+    (0x0163, "LATIN SMALL LETTER T WITH CEDILLA"),
+    (0x0164, "LATIN CAPITAL LETTER T WITH CARON"),
+    (0x0165, "LATIN SMALL LETTER T WITH CARON"),
+    (0x0166, "LATIN CAPITAL LETTER T WITH STROKE"),
+    (0x0167, "LATIN SMALL LETTER T WITH STROKE"),
+    (0x0168, "LATIN CAPITAL LETTER U WITH TILDE"),
+    (0x0169, "LATIN SMALL LETTER U WITH TILDE"),
+    (0x016A, "LATIN CAPITAL LETTER U WITH MACRON"),
+    (0x016B, "LATIN SMALL LETTER U WITH MACRON"),
+    (0x016C, "LATIN CAPITAL LETTER U WITH BREVE"),
+    (0x016D, "LATIN SMALL LETTER U WITH BREVE"),
+    (0x016E, "LATIN CAPITAL LETTER U WITH RING ABOVE"),
+    (0x016F, "LATIN SMALL LETTER U WITH RING ABOVE"),
+    (0x0170, "LATIN CAPITAL LETTER U WITH DOUBLE ACUTE"),
+    (0x0171, "LATIN SMALL LETTER U WITH DOUBLE ACUTE"),
+    (0x0172, "LATIN CAPITAL LETTER U WITH OGONEK"),
+    (0x0173, "LATIN SMALL LETTER U WITH OGONEK"),
+    (0x0174, "LATIN CAPITAL LETTER W WITH CIRCUMFLEX"),
+    (0x0175, "LATIN SMALL LETTER W WITH CIRCUMFLEX"),
+    (0x0176, "LATIN CAPITAL LETTER Y WITH CIRCUMFLEX"),
+    (0x0177, "LATIN SMALL LETTER Y WITH CIRCUMFLEX"),
+    (0x0178, "LATIN CAPITAL LETTER Y WITH DIAERESIS"),
+    (0x0179, "LATIN CAPITAL LETTER Z WITH ACUTE"),
+    (0x017A, "LATIN SMALL LETTER Z WITH ACUTE"),
+    (0x017B, "LATIN CAPITAL LETTER Z WITH DOT ABOVE"),
+    (0x017C, "LATIN SMALL LETTER Z WITH DOT ABOVE"),
+    (0x017D, "LATIN CAPITAL LETTER Z WITH CARON"),
+    (0x017E, "LATIN SMALL LETTER Z WITH CARON"),
+    (0x017F, "LATIN SMALL LETTER LONG S"),
+    (0x2000, "EN QUAD"),
+    (0x2001, "EM QUAD"),
+    (0x2002, "EN SPACE"),
+    (0x2003, "EM SPACE"),
+    (0x2004, "THREE-PER-EM SPACE"),
+    (0x2005, "FOUR-PER-EM SPACE"),
+    (0x2006, "SIX-PER-EM SPACE"),
+    (0x2007, "FIGURE SPACE"),
+    (0x2008, "PUNCTUATION SPACE"),
+    (0x2009, "THIN SPACE"),
+    (0x200A, "HAIR SPACE"),
+    (0x200B, "ZERO WIDTH SPACE"),
+    (0x200C, "ZERO WIDTH NON-JOINER"),
+    (0x200D, "ZERO WIDTH JOINER"),
+    (0x200E, "LEFT-TO-RIGHT MARK"),
+    (0x200F, "RIGHT-TO-LEFT MARK"),
+    (0x2010, "HYPHEN"),
+    (0x2011, "NON-BREAKING HYPHEN"),
+    (0x2012, "FIGURE DASH"),
+    (0x2013, "EN DASH"),
+    (0x2014, "EM DASH"),
+    (0x2015, "HORIZONTAL BAR"),
+    (0x2016, "DOUBLE VERTICAL LINE"),
+    (0x2017, "DOUBLE LOW LINE"),
+    (0x2018, "LEFT SINGLE QUOTATION MARK"),
+    (0x2019, "RIGHT SINGLE QUOTATION MARK"),
+    (0x201A, "SINGLE LOW-9 QUOTATION MARK"),
+    (0x201B, "SINGLE HIGH-REVERSED-9 QUOTATION MARK"),
+    (0x201C, "LEFT DOUBLE QUOTATION MARK"),
+    (0x201D, "RIGHT DOUBLE QUOTATION MARK"),
+    (0x201E, "DOUBLE LOW-9 QUOTATION MARK"),
+    (0x201F, "DOUBLE HIGH-REVERSED-9 QUOTATION MARK"),
+    (0x2020, "DAGGER"),
+    (0x2021, "DOUBLE DAGGER"),
+    (0x2022, "BULLET"),
+    (0x2023, "TRIANGULAR BULLET"),
+    (0x2024, "ONE DOT LEADER"),
+    (0x2025, "TWO DOT LEADER"),
+    (0x2026, "HORIZONTAL ELLIPSIS"),
+    (0x2027, "HYPHENATION POINT"),
+    (0x2028, "LINE SEPARATOR"),
+    (0x2029, "PARAGRAPH SEPARATOR"),
+    (0x202A, "LEFT-TO-RIGHT EMBEDDING"),
+    (0x202B, "RIGHT-TO-LEFT EMBEDDING"),
+    (0x202C, "POP DIRECTIONAL FORMATTING"),
+    (0x202D, "LEFT-TO-RIGHT OVERRIDE"),
+    (0x202E, "RIGHT-TO-LEFT OVERRIDE"),
+    (0x202F, "NARROW NO-BREAK SPACE"),
+    (0x2030, "PER MILLE SIGN"),
+    (0x2031, "PER TEN THOUSAND SIGN"),
+    (0x2032, "PRIME"),
+    (0x2033, "DOUBLE PRIME"),
+    (0x2034, "TRIPLE PRIME"),
+    (0x2035, "REVERSED PRIME"),
+    (0x2036, "REVERSED DOUBLE PRIME"),
+    (0x2037, "REVERSED TRIPLE PRIME"),
+    (0x2038, "CARET"),
+    (0x2039, "SINGLE LEFT-POINTING ANGLE QUOTATION MARK"),
+    (0x203A, "SINGLE RIGHT-POINTING ANGLE QUOTATION MARK"),
+    (0x203B, "REFERENCE MARK"),
+    (0x203C, "DOUBLE EXCLAMATION MARK"),
+    (0x203D, "INTERROBANG"),
+    (0x203E, "OVERLINE"),
+    (0x203F, "UNDERTIE"),
+    (0x2040, "CHARACTER TIE"),
+    (0x2041, "CARET INSERTION POINT"),
+    (0x2042, "ASTERISM"),
+    (0x2043, "HYPHEN BULLET"),
+    (0x2044, "FRACTION SLASH"),
+    (0x2045, "LEFT SQUARE BRACKET WITH QUILL"),
+    (0x2046, "RIGHT SQUARE BRACKET WITH QUILL"),
+    (0x2047, "DOUBLE QUESTION MARK"),
+    (0x2048, "QUESTION EXCLAMATION MARK"),
+    (0x2049, "EXCLAMATION QUESTION MARK"),
+    (0x204A, "TIRONIAN SIGN ET"),
+    (0x204B, "REVERSED PILCROW SIGN"),
+    (0x204C, "BLACK LEFTWARDS BULLET"),
+    (0x204D, "BLACK RIGHTWARDS BULLET"),
+    (0x204E, "LOW ASTERISK"),
+    (0x204F, "REVERSED SEMICOLON"),
+    (0x2050, "CLOSE UP"),
+    (0x2051, "TWO ASTERISKS ALIGNED VERTICALLY"),
+    (0x2052, "COMMERCIAL MINUS SIGN"),
+    (0x2053, "SWUNG DASH"),
+    (0x2054, "INVERTED UNDERTIE"),
+    (0x2055, "FLOWER PUNCTUATION MARK"),
+    (0x2056, "THREE DOT PUNCTUATION"),
+    (0x2057, "QUADRUPLE PRIME"),
+    (0x2058, "FOUR DOT PUNCTUATION"),
+    (0x2059, "FIVE DOT PUNCTUATION"),
+    (0x205A, "TWO DOT PUNCTUATION"),
+    (0x205B, "FOUR DOT MARK"),
+    (0x205C, "DOTTED CROSS"),
+    (0x205D, "TRICOLON"),
+    (0x205E, "VERTICAL FOUR DOTS"),
+    (0x205F, "MEDIUM MATHEMATICAL SPACE"),
+    (0x2060, "WORD JOINER"),
+    (0x2061, "FUNCTION APPLICATION"),
+    (0x2062, "INVISIBLE TIMES"),
+    (0x2063, "INVISIBLE SEPARATOR"),
+    (0x2064, "INVISIBLE PLUS"),
+    (0x2066, "LEFT-TO-RIGHT ISOLATE"),
+    (0x2067, "RIGHT-TO-LEFT ISOLATE"),
+    (0x2068, "FIRST STRONG ISOLATE"),
+    (0x2069, "POP DIRECTIONAL ISOLATE"),
+    (0x206A, "INHIBIT SYMMETRIC SWAPPING"),
+    (0x206B, "ACTIVATE SYMMETRIC SWAPPING"),
+    (0x206C, "INHIBIT ARABIC FORM SHAPING"),
+    (0x206D, "ACTIVATE ARABIC FORM SHAPING"),
+    (0x206E, "NATIONAL DIGIT SHAPES"),
+    (0x206F, "NOMINAL DIGIT SHAPES"),
+    (0x20A0, "EURO-CURRENCY SIGN"),
+    (0x20A1, "COLON SIGN"),
+    (0x20A2, "CRUZEIRO SIGN"),
+    (0x20A3, "FRENCH FRANC SIGN"),
+    (0x20A4, "LIRA SIGN"),
+    (0x20A5, "MILL SIGN"),
+    (0x20A6, "NAIRA SIGN"),
+    (0x20A7, "PESETA SIGN"),
+    (0x20A8, "RUPEE SIGN"),
+    (0x20A9, "WON SIGN"),
+    (0x20AA, "NEW SHEQEL SIGN"),
+    (0x20AB, "DONG SIGN"),
+    (0x20AC, "EURO SIGN"),
+    (0x20AD, "KIP SIGN"),
+    (0x20AE, "TUGRIK SIGN"),
+    (0x20AF, "DRACHMA SIGN"),
+    (0x20B0, "GERMAN PENNY SIGN"),
+    (0x20B1, "PESO SIGN"),
+    (0x20B2, "GUARANI SIGN"),
+    (0x20B3, "AUSTRAL SIGN"),
+    (0x20B4, "HRYVNIA SIGN"),
+    (0x20B5, "CEDI SIGN"),
+    (0x20B6, "LIVRE TOURNOIS SIGN"),
+    (0x20B7, "SPESMILO SIGN"),
+    (0x20B8, "TENGE SIGN"),
+    (0x20B9, "INDIAN RUPEE SIGN"),
+    (0x20BA, "TURKISH LIRA SIGN"),
+    (0x20BB, "NORDIC MARK SIGN"),
+    (0x20BC, "MANAT SIGN"),
+    (0x20BD, "RUBLE SIGN"),
+    (0x20BE, "LARI SIGN"),
+    (0x20BF, "BITCOIN SIGN"),
+    (0x20C0, "SOM SIGN"),
+    (0x2190, "LEFTWARDS ARROW"),
+    (0x2191, "UPWARDS ARROW"),
+    (0x2192, "RIGHTWARDS ARROW"),
+    (0x2193, "DOWNWARDS ARROW"),
+    (0x2194, "LEFT RIGHT ARROW"),
+    (0x2195, "UP DOWN ARROW"),
+    (0x2196, "NORTH WEST ARROW"),
+    (0x2197, "NORTH EAST ARROW"),
+    (0x2198, "SOUTH EAST ARROW"),
+    (0x2199, "SOUTH WEST ARROW"),
+    (0x219A, "LEFTWARDS ARROW WITH STROKE"),
+    (0x219B, "RIGHTWARDS ARROW WITH STROKE"),
+    (0x219C, "LEFTWARDS WAVE ARROW"),
+    (0x219D, "RIGHTWARDS WAVE ARROW"),
+    (0x219E, "LEFTWARDS TWO HEADED ARROW"),
+    (0x219F, "UPWARDS TWO HEADED ARROW"),
+    (0x21A0, "RIGHTWARDS TWO HEADED ARROW"),
+    (0x21A1, "DOWNWARDS TWO HEADED ARROW"),
+    (0x21A2, "LEFTWARDS ARROW WITH TAIL"),
+    (0x21A3, "RIGHTWARDS ARROW WITH TAIL"),
+    (0x21A4, "LEFTWARDS ARROW FROM BAR"),
+    (0x21A5, "UPWARDS ARROW FROM BAR"),
+    (0x21A6, "RIGHTWARDS ARROW FROM BAR"),
+    (0x21A7, "DOWNWARDS ARROW FROM BAR"),
+    (0x21A8, "UP DOWN ARROW WITH BASE"),
+    (0x21A9, "LEFTWARDS ARROW WITH HOOK"),
+    (0x21AA, "RIGHTWARDS ARROW WITH HOOK"),
+    (0x21AB, "LEFTWARDS ARROW WITH LOOP"),
+    (0x21AC, "RIGHTWARDS ARROW WITH LOOP"),
+    (0x21AD, "LEFT RIGHT WAVE ARROW"),
+    (0x21AE, "LEFT RIGHT ARROW WITH STROKE"),
+    (0x21AF, "DOWNWARDS ZIGZAG ARROW"),
+    (0x21B0, "UPWARDS ARROW WITH TIP LEFTWARDS"),
+    (0x21B1, "UPWARDS ARROW WITH TIP RIGHTWARDS"),
+    (0x21B2, "DOWNWARDS ARROW WITH TIP LEFTWARDS"),
+    (0x21B3, "DOWNWARDS ARROW WITH TIP RIGHTWARDS"),
+    (0x21B4, "RIGHTWARDS ARROW WITH CORNER DOWNWARDS"),
+    (0x21B5, "DOWNWARDS ARROW WITH CORNER LEFTWARDS"),
+    (0x21B6, "ANTICLOCKWISE TOP SEMICIRCLE ARROW"),
+    (0x21B7, "CLOCKWISE TOP SEMICIRCLE ARROW"),
+    (0x21B8, "NORTH WEST ARROW TO LONG BAR"),
+    (
+        0x21B9,
+        "LEFTWARDS ARROW TO BAR OVER RIGHTWARDS ARROW TO BAR",
+    ),
+    (0x21BA, "ANTICLOCKWISE OPEN CIRCLE ARROW"),
+    (0x21BB, "CLOCKWISE OPEN CIRCLE ARROW"),
+    (0x21BC, "LEFTWARDS HARPOON WITH BARB UPWARDS"),
+    (0x21BD, "LEFTWARDS HARPOON WITH BARB DOWNWARDS"),
+    (0x21BE, "UPWARDS HARPOON WITH BARB RIGHTWARDS"),
+    (0x21BF, "UPWARDS HARPOON WITH BARB LEFTWARDS"),
+    (0x21C0, "RIGHTWARDS HARPOON WITH BARB UPWARDS"),
+    (0x21C1, "RIGHTWARDS HARPOON WITH BARB DOWNWARDS"),
+    (0x21C2, "DOWNWARDS HARPOON WITH BARB RIGHTWARDS"),
+    (0x21C3, "DOWNWARDS HARPOON WITH BARB LEFTWARDS"),
+    (0x21C4, "RIGHTWARDS ARROW OVER LEFTWARDS ARROW"),
+    (0x21C5, "UPWARDS ARROW LEFTWARDS OF DOWNWARDS ARROW"),
+    (0x21C6, "LEFTWARDS ARROW OVER RIGHTWARDS ARROW"),
+    (0x21C7, "LEFTWARDS PAIRED ARROWS"),
+    (0x21C8, "UPWARDS PAIRED ARROWS"),
+    (0x21C9, "RIGHTWARDS PAIRED ARROWS"),
+    (0x21CA, "DOWNWARDS PAIRED ARROWS"),
+    (0x21CB, "LEFTWARDS HARPOON OVER RIGHTWARDS HARPOON"),
+    (0x21CC, "RIGHTWARDS HARPOON OVER LEFTWARDS HARPOON"),
+    (0x21CD, "LEFTWARDS DOUBLE ARROW WITH STROKE"),
+    (0x21CE, "LEFT RIGHT DOUBLE ARROW WITH STROKE"),
+    (0x21CF, "RIGHTWARDS DOUBLE ARROW WITH STROKE"),
+    (0x21D0, "LEFTWARDS DOUBLE ARROW"),
+    (0x21D1, "UPWARDS DOUBLE ARROW"),
+    (0x21D2, "RIGHTWARDS DOUBLE ARROW"),
+    (0x21D3, "DOWNWARDS DOUBLE ARROW"),
+    (0x21D4, "LEFT RIGHT DOUBLE ARROW"),
+    (0x21D5, "UP DOWN DOUBLE ARROW"),
+    (0x21D6, "NORTH WEST DOUBLE ARROW"),
+    (0x21D7, "NORTH EAST DOUBLE ARROW"),
+    (0x21D8, "SOUTH EAST DOUBLE ARROW"),
+    (0x21D9, "SOUTH WEST DOUBLE ARROW"),
+    (0x21DA, "LEFTWARDS TRIPLE ARROW"),
+    (0x21DB, "RIGHTWARDS TRIPLE ARROW"),
+    (0x21DC, "LEFTWARDS SQUIGGLE ARROW"),
+    (0x21DD, "RIGHTWARDS SQUIGGLE ARROW"),
+    (0x21DE, "UPWARDS ARROW WITH DOUBLE STROKE"),
+    (0x21DF, "DOWNWARDS ARROW WITH DOUBLE STROKE"),
+    (0x21E0, "LEFTWARDS DASHED ARROW"),
+    (0x21E1, "UPWARDS DASHED ARROW"),
+    (0x21E2, "RIGHTWARDS DASHED ARROW"),
+    (0x21E3, "DOWNWARDS DASHED ARROW"),
+    (0x21E4, "LEFTWARDS ARROW TO BAR"),
+    (0x21E5, "RIGHTWARDS ARROW TO BAR"),
+    (0x21E6, "LEFTWARDS WHITE ARROW"),
+    (0x21E7, "UPWARDS WHITE ARROW"),
+    (0x21E8, "RIGHTWARDS WHITE ARROW"),
+    (0x21E9, "DOWNWARDS WHITE ARROW"),
+    (0x21EA, "UPWARDS WHITE ARROW FROM BAR"),
+    (0x21EB, "UPWARDS WHITE ARROW ON PEDESTAL"),
+    (
+        0x21EC,
+        "UPWARDS WHITE ARROW ON PEDESTAL WITH HORIZONTAL BAR",
+    ),
+    (0x21ED, "UPWARDS WHITE ARROW ON PEDESTAL WITH VERTICAL BAR"),
+    (0x21EE, "UPWARDS WHITE DOUBLE ARROW"),
+    (0x21EF, "UPWARDS WHITE DOUBLE ARROW ON PEDESTAL"),
+    (0x21F0, "RIGHTWARDS WHITE ARROW FROM WALL"),
+    (0x21F1, "NORTH WEST ARROW TO CORNER"),
+    (0x21F2, "SOUTH EAST ARROW TO CORNER"),
+    (0x21F3, "UP DOWN WHITE ARROW"),
+    (0x21F4, "RIGHT ARROW WITH SMALL CIRCLE"),
+    (0x21F5, "DOWNWARDS ARROW LEFTWARDS OF UPWARDS ARROW"),
+    (0x21F6, "THREE RIGHTWARDS ARROWS"),
+    (0x21F7, "LEFTWARDS ARROW WITH VERTICAL STROKE"),
+    (0x21F8, "RIGHTWARDS ARROW WITH VERTICAL STROKE"),
+    (0x21F9, "LEFT RIGHT ARROW WITH VERTICAL STROKE"),
+    (0x21FA, "LEFTWARDS ARROW WITH DOUBLE VERTICAL STROKE"),
+    (0x21FB, "RIGHTWARDS ARROW WITH DOUBLE VERTICAL STROKE"),
+    (0x21FC, "LEFT RIGHT ARROW WITH DOUBLE VERTICAL STROKE"),
+    (0x21FD, "LEFTWARDS OPEN-HEADED ARROW"),
+    (0x21FE, "RIGHTWARDS OPEN-HEADED ARROW"),
+    (0x21FF, "LEFT RIGHT OPEN-HEADED ARROW"),
+    (0x3000, "IDEOGRAPHIC SPACE"),
+    (0x3001, "IDEOGRAPHIC COMMA"),
+    (0x3002, "IDEOGRAPHIC FULL STOP"),
+    (0x3003, "DITTO MARK"),
+    (0x3004, "JAPANESE INDUSTRIAL STANDARD SYMBOL"),
+    (0x3005, "IDEOGRAPHIC ITERATION MARK"),
+    (0x3006, "IDEOGRAPHIC CLOSING MARK"),
+    (0x3007, "IDEOGRAPHIC NUMBER ZERO"),
+    (0x3008, "LEFT ANGLE BRACKET"),
+    (0x3009, "RIGHT ANGLE BRACKET"),
+    (0x300A, "LEFT DOUBLE ANGLE BRACKET"),
+    (0x300B, "RIGHT DOUBLE ANGLE BRACKET"),
+    (0x300C, "LEFT CORNER BRACKET"),
+    (0x300D, "RIGHT CORNER BRACKET"),
+    (0x300E, "LEFT WHITE CORNER BRACKET"),
+    (0x300F, "RIGHT WHITE CORNER BRACKET"),
+    (0x3010, "LEFT BLACK LENTICULAR BRACKET"),
+    (0x3011, "RIGHT BLACK LENTICULAR BRACKET"),
+    (0x3012, "POSTAL MARK"),
+    (0x3013, "GETA MARK"),
+    (0x3014, "LEFT TORTOISE SHELL BRACKET"),
+    (0x3015, "RIGHT TORTOISE SHELL BRACKET"),
+    (0x3016, "LEFT WHITE LENTICULAR BRACKET"),
+    (0x3017, "RIGHT WHITE LENTICULAR BRACKET"),
+    (0x3018, "LEFT WHITE TORTOISE SHELL BRACKET"),
+    (0x3019, "RIGHT WHITE TORTOISE SHELL BRACKET"),
+    (0x301A, "LEFT WHITE SQUARE BRACKET"),
+    (0x301B, "RIGHT WHITE SQUARE BRACKET"),
+    (0x301C, "WAVE DASH"),
+    (0x301D, "REVERSED DOUBLE PRIME QUOTATION MARK"),
+    (0x301E, "DOUBLE PRIME QUOTATION MARK"),
+    (0x301F, "LOW DOUBLE PRIME QUOTATION MARK"),
+    (0x3020, "POSTAL MARK FACE"),
+    (0x3021, "HANGZHOU NUMERAL ONE"),
+    (0x3022, "HANGZHOU NUMERAL TWO"),
+    (0x3023, "HANGZHOU NUMERAL THREE"),
+    (0x3024, "HANGZHOU NUMERAL FOUR"),
+    (0x3025, "HANGZHOU NUMERAL FIVE"),
+    (0x3026, "HANGZHOU NUMERAL SIX"),
+    (0x3027, "HANGZHOU NUMERAL SEVEN"),
+    (0x3028, "HANGZHOU NUMERAL EIGHT"),
+    (0x3029, "HANGZHOU NUMERAL NINE"),
+    (0x302A, "IDEOGRAPHIC LEVEL TONE MARK"),
+    (0x302B, "IDEOGRAPHIC RISING TONE MARK"),
+    (0x302C, "IDEOGRAPHIC DEPARTING TONE MARK"),
+    (0x302D, "IDEOGRAPHIC ENTERING TONE MARK"),
+    (0x302E, "HANGUL SINGLE DOT TONE MARK"),
+    (0x302F, "HANGUL DOUBLE DOT TONE MARK"),
+    (0x3030, "WAVY DASH"),
+    (0x3031, "VERTICAL KANA REPEAT MARK"),
+    (0x3032, "VERTICAL KANA REPEAT WITH VOICED SOUND MARK"),
+    (0x3033, "VERTICAL KANA REPEAT MARK UPPER HALF"),
+    (
+        0x3034,
+        "VERTICAL KANA REPEAT WITH VOICED SOUND MARK UPPER HALF",
+    ),
+    (0x3035, "VERTICAL KANA REPEAT MARK LOWER HALF"),
+    (0x3036, "CIRCLED POSTAL MARK"),
+    (0x3037, "IDEOGRAPHIC TELEGRAPH LINE FEED SEPARATOR SYMBOL"),
+    (0x3038, "HANGZHOU NUMERAL TEN"),
+    (0x3039, "HANGZHOU NUMERAL TWENTY"),
+    (0x303A, "HANGZHOU NUMERAL THIRTY"),
+    (0x303B, "VERTICAL IDEOGRAPHIC ITERATION MARK"),
+    (0x303C, "MASU MARK"),
+    (0x303D, "PART ALTERNATION MARK"),
+    (0x303E, "IDEOGRAPHIC VARIATION INDICATOR"),
+    (0x303F, "IDEOGRAPHIC HALF FILL SPACE"),
+    (0xFE00, "VARIATION SELECTOR-1"),
+    (0xFE01, "VARIATION SELECTOR-2"),
+    (0xFE02, "VARIATION SELECTOR-3"),
+    (0xFE03, "VARIATION SELECTOR-4"),
+    (0xFE04, "VARIATION SELECTOR-5"),
+    (0xFE05, "VARIATION SELECTOR-6"),
+    (0xFE06, "VARIATION SELECTOR-7"),
+    (0xFE07, "VARIATION SELECTOR-8"),
+    (0xFE08, "VARIATION SELECTOR-9"),
+    (0xFE09, "VARIATION SELECTOR-10"),
+    (0xFE0A, "VARIATION SELECTOR-11"),
+    (0xFE0B, "VARIATION SELECTOR-12"),
+    (0xFE0C, "VARIATION SELECTOR-13"),
+    (0xFE0D, "VARIATION SELECTOR-14"),
+    (0xFE0E, "VARIATION SELECTOR-15"),
+    (0xFE0F, "VARIATION SELECTOR-16"),
+    (0xFEFF, "ZERO WIDTH NO-BREAK SPACE"),
+    (0xFFF9, "INTERLINEAR ANNOTATION ANCHOR"),
+    (0xFFFA, "INTERLINEAR ANNOTATION SEPARATOR"),
+    (0xFFFB, "INTERLINEAR ANNOTATION TERMINATOR"),
+    (0xFFFC, "OBJECT REPLACEMENT CHARACTER"),
+    (0xFFFD, "REPLACEMENT CHARACTER"),
+    (0x1F600, "GRINNING FACE"),
+    (0x1F601, "GRINNING FACE WITH SMILING EYES"),
+    (0x1F602, "FACE WITH TEARS OF JOY"),
+    (0x1F603, "SMILING FACE WITH OPEN MOUTH"),
+    (0x1F604, "SMILING FACE WITH OPEN MOUTH AND SMILING EYES"),
+    (0x1F605, "SMILING FACE WITH OPEN MOUTH AND COLD SWEAT"),
+    (
+        0x1F606,
+        "SMILING FACE WITH OPEN MOUTH AND TIGHTLY-CLOSED EYES",
+    ),
+    (0x1F607, "SMILING FACE WITH HALO"),
+    (0x1F608, "SMILING FACE WITH HORNS"),
+    (0x1F609, "WINKING FACE"),
+    (0x1F60A, "SMILING FACE WITH SMILING EYES"),
+    (0x1F60B, "FACE SAVOURING DELICIOUS FOOD"),
+    (0x1F60C, "RELIEVED FACE"),
+    (0x1F60D, "SMILING FACE WITH HEART-SHAPED EYES"),
+    (0x1F60E, "SMILING FACE WITH SUNGLASSES"),
+    (0x1F60F, "SMIRKING FACE"),
+    (0x1F610, "NEUTRAL FACE"),
+    (0x1F611, "EXPRESSIONLESS FACE"),
+    (0x1F612, "UNAMUSED FACE"),
+    (0x1F613, "FACE WITH COLD SWEAT"),
+    (0x1F614, "PENSIVE FACE"),
+    (0x1F615, "CONFUSED FACE"),
+    (0x1F616, "CONFOUNDED FACE"),
+    (0x1F617, "KISSING FACE"),
+    (0x1F618, "FACE THROWING A KISS"),
+    (0x1F619, "KISSING FACE WITH SMILING EYES"),
+    (0x1F61A, "KISSING FACE WITH CLOSED EYES"),
+    (0x1F61B, "FACE WITH STUCK-OUT TONGUE"),
+    (0x1F61C, "FACE WITH STUCK-OUT TONGUE AND WINKING EYE"),
+    (
+        0x1F61D,
+        "FACE WITH STUCK-OUT TONGUE AND TIGHTLY-CLOSED EYES",
+    ),
+    (0x1F61E, "DISAPPOINTED FACE"),
+    (0x1F61F, "WORRIED FACE"),
+    (0x1F620, "ANGRY FACE"),
+    (0x1F621, "POUTING FACE"),
+    (0x1F622, "CRYING FACE"),
+    (0x1F623, "PERSEVERING FACE"),
+    (0x1F624, "FACE WITH LOOK OF TRIUMPH"),
+    (0x1F625, "DISAPPOINTED BUT RELIEVED FACE"),
+    (0x1F626, "FROWNING FACE WITH OPEN MOUTH"),
+    (0x1F627, "ANGUISHED FACE"),
+    (0x1F628, "FEARFUL FACE"),
+    (0x1F629, "WEARY FACE"),
+    (0x1F62A, "SLEEPY FACE"),
+    (0x1F62B, "TIRED FACE"),
+    (0x1F62C, "GRIMACING FACE"),
+    (0x1F62D, "LOUDLY CRYING FACE"),
+    (0x1F62E, "FACE WITH OPEN MOUTH"),
+    (0x1F62F, "HUSHED FACE"),
+    (0x1F630, "FACE WITH OPEN MOUTH AND COLD SWEAT"),
+    (0x1F631, "FACE SCREAMING IN FEAR"),
+    (0x1F632, "ASTONISHED FACE"),
+    (0x1F633, "FLUSHED FACE"),
+    (0x1F634, "SLEEPING FACE"),
+    (0x1F635, "DIZZY FACE"),
+    (0x1F636, "FACE WITHOUT MOUTH"),
+    (0x1F637, "FACE WITH MEDICAL MASK"),
+    (0x1F638, "GRINNING CAT FACE WITH SMILING EYES"),
+    (0x1F639, "CAT FACE WITH TEARS OF JOY"),
+    (0x1F63A, "SMILING CAT FACE WITH OPEN MOUTH"),
+    (0x1F63B, "SMILING CAT FACE WITH HEART-SHAPED EYES"),
+    (0x1F63C, "CAT FACE WITH WRY SMILE"),
+    (0x1F63D, "KISSING CAT FACE WITH CLOSED EYES"),
+    (0x1F63E, "POUTING CAT FACE"),
+    (0x1F63F, "CRYING CAT FACE"),
+    (0x1F640, "WEARY CAT FACE"),
+    (0x1F641, "SLIGHTLY FROWNING FACE"),
+    (0x1F642, "SLIGHTLY SMILING FACE"),
+    (0x1F643, "UPSIDE-DOWN FACE"),
+    (0x1F644, "FACE WITH ROLLING EYES"),
+    (0x1F645, "FACE WITH NO GOOD GESTURE"),
+    (0x1F646, "FACE WITH OK GESTURE"),
+    (0x1F647, "PERSON BOWING DEEPLY"),
+    (0x1F648, "SEE-NO-EVIL MONKEY"),
+    (0x1F649, "HEAR-NO-EVIL MONKEY"),
+    (0x1F64A, "SPEAK-NO-EVIL MONKEY"),
+    (0x1F64B, "HAPPY PERSON RAISING ONE HAND"),
+    (0x1F64C, "PERSON RAISING BOTH HANDS IN CELEBRATION"),
+    (0x1F64D, "PERSON FROWNING"),
+    (0x1F64E, "PERSON WITH POUTING FACE"),
+    (0x1F64F, "PERSON WITH FOLDED HANDS"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_char_info_emoji() {
+        let info = CharInfo::new('😀');
+        assert_eq!(info.code_point, "U+1F600");
+        assert_eq!(info.name.as_deref(), Some("GRINNING FACE"));
+        assert_eq!(info.utf8, "F0 9F 98 80");
+        assert_eq!(info.category, "So (Other Symbol)");
+    }
+
+    #[test]
+    fn test_char_names() {
+        assert_eq!(char_name('\n').as_deref(), Some("LINE FEED"));
+        assert_eq!(char_name('\u{200B}').as_deref(), Some("ZERO WIDTH SPACE"));
+        assert_eq!(
+            char_name('中').as_deref(),
+            Some("CJK UNIFIED IDEOGRAPH-4E2D")
+        );
+        assert_eq!(char_name('한').as_deref(), Some("HANGUL SYLLABLE HAN"));
+        assert_eq!(char_name('\u{E000}'), None);
+    }
+
+    #[test]
+    fn test_char_names_sorted() {
+        assert!(CHAR_NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+}
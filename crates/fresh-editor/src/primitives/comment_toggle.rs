@@ -0,0 +1,326 @@
+//! Line and block comment toggling.
+//!
+//! These functions only plan the edits: given the lines (or the range) a
+//! toggle applies to and the language's [`CommentTokens`], they return the
+//! [`CommentEdit`]s that comment or uncomment it. The caller applies the
+//! edits and carries cursors across them with [`map_offset`].
+
+/// A language's comment delimiters.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommentTokens {
+    /// Line comment prefix, e.g. `//` or `#`.
+    pub line: Option<String>,
+    /// Block comment start and end, e.g. `/*` and `*/`.
+    pub block: Option<(String, String)>,
+}
+
+impl CommentTokens {
+    pub fn is_empty(&self) -> bool {
+        self.line.is_none() && self.block.is_none()
+    }
+}
+
+/// Replace `delete` bytes at `offset` with `insert`. Offsets refer to the
+/// text before any edit of the same plan is applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentEdit {
+    pub offset: usize,
+    pub delete: usize,
+    pub insert: String,
+}
+
+impl CommentEdit {
+    fn insert(offset: usize, text: String) -> Self {
+        Self {
+            offset,
+            delete: 0,
+            insert: text,
+        }
+    }
+
+    fn delete(offset: usize, len: usize) -> Self {
+        Self {
+            offset,
+            delete: len,
+            insert: String::new(),
+        }
+    }
+}
+
+/// Whether a toggle adds or removes comments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToggleKind {
+    Comment,
+    Uncomment,
+}
+
+/// Plan a line comment toggle over `lines`, given as `(start offset,
+/// content without the line terminator)` in ascending order.
+///
+/// Blank lines are skipped. If every other line is already commented the
+/// comments are removed; otherwise every line is commented, with the
+/// marker inserted at the smallest indentation so the block keeps its
+/// shape. Languages without a line comment wrap each line in the block
+/// comment tokens instead. Returns `None` if the language has neither.
+pub fn toggle_line_comments(
+    lines: &[(usize, &str)],
+    tokens: &CommentTokens,
+) -> Option<(ToggleKind, Vec<CommentEdit>)> {
+    let (open, close) = match (&tokens.line, &tokens.block) {
+        (Some(prefix), _) => (prefix.trim_end(), None),
+        (None, Some((open, close))) => (open.trim_end(), Some(close.trim_start())),
+        (None, None) => return None,
+    };
+
+    let mut targets: Vec<&(usize, &str)> = lines
+        .iter()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect();
+    if targets.is_empty() {
+        // Only blank lines: comment them rather than doing nothing.
+        targets = lines.iter().collect();
+    }
+
+    let is_commented = |line: &str| {
+        let content = line.trim();
+        content.starts_with(open)
+            && close.is_none_or(|close| {
+                content.len() >= open.len() + close.len() && content.ends_with(close)
+            })
+    };
+
+    let mut edits = Vec::new();
+    if targets.iter().all(|(_, line)| is_commented(line)) {
+        for &&(start, line) in &targets {
+            let indent = indent_len(line);
+            let content_end = line.trim_end().len();
+            let mut open_len = open.len();
+            let mut close_start = close.map_or(content_end, |close| content_end - close.len());
+            if line[indent + open_len..close_start].starts_with(' ') {
+                open_len += 1;
+            }
+            if close.is_some() && line[indent + open_len..close_start].ends_with(' ') {
+                close_start -= 1;
+            }
+            edits.push(CommentEdit::delete(start + indent, open_len));
+            if close.is_some() {
+                edits.push(CommentEdit::delete(
+                    start + close_start,
+                    content_end - close_start,
+                ));
+            }
+        }
+        return Some((ToggleKind::Uncomment, edits));
+    }
+
+    let indent = targets
+        .iter()
+        .map(|(_, line)| indent_len(line))
+        .min()
+        .unwrap_or(0);
+    for &&(start, line) in &targets {
+        match close {
+            None => edits.push(CommentEdit::insert(start + indent, format!("{open} "))),
+            Some(close) => {
+                let content_end = line.trim_end().len().max(indent);
+                if content_end == indent {
+                    edits.push(CommentEdit::insert(
+                        start + indent,
+                        format!("{open}  {close}"),
+                    ));
+                } else {
+                    edits.push(CommentEdit::insert(start + indent, format!("{open} ")));
+                    edits.push(CommentEdit::insert(
+                        start + content_end,
+                        format!(" {close}"),
+                    ));
+                }
+            }
+        }
+    }
+    Some((ToggleKind::Comment, edits))
+}
+
+/// Plan a block comment toggle over `text`, which starts at offset `start`.
+///
+/// Surrounding whitespace is left outside the comment. If the trimmed text
+/// is already wrapped in `open`/`close` the delimiters (and the space
+/// padding next to them) are removed; otherwise they are added.
+pub fn toggle_block_comment(
+    start: usize,
+    text: &str,
+    open: &str,
+    close: &str,
+) -> (ToggleKind, Vec<CommentEdit>) {
+    let lead = text.len() - text.trim_start().len();
+    let inner = text.trim();
+    let inner_start = start + lead;
+    let inner_end = inner_start + inner.len();
+
+    if inner.len() >= open.len() + close.len() && inner.starts_with(open) && inner.ends_with(close)
+    {
+        let body = &inner[open.len()..inner.len() - close.len()];
+        let open_len = open.len() + usize::from(body.starts_with(' '));
+        let body = &inner[open_len..inner.len() - close.len()];
+        let close_len = close.len() + usize::from(body.ends_with(' '));
+        return (
+            ToggleKind::Uncomment,
+            vec![
+                CommentEdit::delete(inner_start, open_len),
+                CommentEdit::delete(inner_end - close_len, close_len),
+            ],
+        );
+    }
+
+    let edits = if inner.is_empty() {
+        vec![CommentEdit::insert(inner_start, format!("{open}  {close}"))]
+    } else {
+        vec![
+            CommentEdit::insert(inner_start, format!("{open} ")),
+            CommentEdit::insert(inner_end, format!(" {close}")),
+        ]
+    };
+    (ToggleKind::Comment, edits)
+}
+
+/// Where `offset` ends up once `edits` (ascending, non-overlapping) are
+/// applied. An offset inside deleted text moves to where the deletion
+/// was. With `before_inserts`, an offset exactly at an insertion stays in
+/// front of the inserted text, which keeps a selection start outside a
+/// comment marker it gained; otherwise it moves past it.
+pub fn map_offset(edits: &[CommentEdit], offset: usize, before_inserts: bool) -> usize {
+    let mut mapped = offset as isize;
+    for edit in edits {
+        if offset < edit.offset || (offset == edit.offset && before_inserts) {
+            break;
+        }
+        if offset >= edit.offset + edit.delete {
+            mapped += edit.insert.len() as isize - edit.delete as isize;
+        } else {
+            mapped -= (offset - edit.offset) as isize;
+            break;
+        }
+    }
+    mapped.max(0) as usize
+}
+
+/// Byte length of the leading whitespace, by the same `char::is_whitespace`
+/// test `trim` applies when deciding whether a line is commented.
+fn indent_len(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(text: &str, edits: &[CommentEdit]) -> String {
+        let mut text = text.to_string();
+        for edit in edits.iter().rev() {
+            text.replace_range(edit.offset..edit.offset + edit.delete, &edit.insert);
+        }
+        text
+    }
+
+    fn lines(text: &str) -> Vec<(usize, &str)> {
+        let mut start = 0;
+        text.split('\n')
+            .map(|line| {
+                let entry = (start, line);
+                start += line.len() + 1;
+                entry
+            })
+            .collect()
+    }
+
+    fn slash() -> CommentTokens {
+        CommentTokens {
+            line: Some("//".into()),
+            block: Some(("/*".into(), "*/".into())),
+        }
+    }
+
+    #[test]
+    fn comments_at_the_smallest_indentation_skipping_blank_lines() {
+        let text = "    if x {\n\n        y();\n    }";
+        let (kind, edits) = toggle_line_comments(&lines(text), &slash()).unwrap();
+        assert_eq!(kind, ToggleKind::Comment);
+        let commented = apply(text, &edits);
+        assert_eq!(commented, "    // if x {\n\n    //     y();\n    // }");
+
+        let (kind, edits) = toggle_line_comments(&lines(&commented), &slash()).unwrap();
+        assert_eq!(kind, ToggleKind::Uncomment);
+        assert_eq!(apply(&commented, &edits), text);
+    }
+
+    #[test]
+    fn mixed_range_is_commented_entirely() {
+        let text = "// a\nb\n//c";
+        let (kind, edits) = toggle_line_comments(&lines(text), &slash()).unwrap();
+        assert_eq!(kind, ToggleKind::Comment);
+        assert_eq!(apply(text, &edits), "// // a\n// b\n// //c");
+
+        // Uncommenting removes the marker with or without its space.
+        let text = "// a\n//b";
+        let (_, edits) = toggle_line_comments(&lines(text), &slash()).unwrap();
+        assert_eq!(apply(text, &edits), "a\nb");
+    }
+
+    #[test]
+    fn unicode_whitespace_indent_round_trip() {
+        let text = "\u{3000}// x";
+        let (kind, edits) = toggle_line_comments(&lines(text), &slash()).unwrap();
+        assert_eq!(kind, ToggleKind::Uncomment);
+        assert_eq!(apply(text, &edits), "\u{3000}x");
+
+        let (kind, edits) = toggle_line_comments(&lines("\u{3000}x"), &slash()).unwrap();
+        assert_eq!(kind, ToggleKind::Comment);
+        assert_eq!(apply("\u{3000}x", &edits), text);
+    }
+
+    #[test]
+    fn falls_back_to_block_tokens_per_line() {
+        let tokens = CommentTokens {
+            line: None,
+            block: Some(("<!--".into(), "-->".into())),
+        };
+        let text = "  <p>\n  </p>  ";
+        let (_, edits) = toggle_line_comments(&lines(text), &tokens).unwrap();
+        let commented = apply(text, &edits);
+        assert_eq!(commented, "  <!-- <p> -->\n  <!-- </p> -->  ");
+        let (kind, edits) = toggle_line_comments(&lines(&commented), &tokens).unwrap();
+        assert_eq!(kind, ToggleKind::Uncomment);
+        assert_eq!(apply(&commented, &edits), text);
+
+        assert!(toggle_line_comments(&lines(text), &CommentTokens::default()).is_none());
+    }
+
+    #[test]
+    fn block_comment_round_trip() {
+        let text = "let a = 1 + 2;";
+        let (kind, edits) = toggle_block_comment(8, "1 + 2", "/*", "*/");
+        assert_eq!(kind, ToggleKind::Comment);
+        let commented = apply(text, &edits);
+        assert_eq!(commented, "let a = /* 1 + 2 */;");
+
+        let (kind, edits) = toggle_block_comment(8, "/* 1 + 2 */", "/*", "*/");
+        assert_eq!(kind, ToggleKind::Uncomment);
+        assert_eq!(apply(&commented, &edits), text);
+
+        let (_, edits) = toggle_block_comment(0, "/*x*/", "/*", "*/");
+        assert_eq!(apply("/*x*/", &edits), "x");
+    }
+
+    #[test]
+    fn maps_offsets_across_edits() {
+        let edits = vec![
+            CommentEdit::insert(0, "// ".into()),
+            CommentEdit::delete(10, 3),
+        ];
+        assert_eq!(map_offset(&edits, 0, true), 0);
+        assert_eq!(map_offset(&edits, 0, false), 3);
+        assert_eq!(map_offset(&edits, 5, true), 8);
+        assert_eq!(map_offset(&edits, 11, false), 13);
+        assert_eq!(map_offset(&edits, 20, false), 20);
+    }
+}
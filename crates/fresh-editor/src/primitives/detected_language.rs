@@ -46,26 +46,29 @@ impl DetectedLanguage {
     /// Detect language from a file path using user configuration.
     ///
     /// This is the primary detection path used when opening, reloading, or saving files.
-    /// Priority order matches the grammar registry:
-    /// 1. Exact filename match in user config
-    /// 2. Glob pattern match in user config
-    /// 3. Extension match in user config
-    /// 4. Built-in detection (catalog lookup)
-    /// 5. Shebang / first-line regex against `first_line` (catalog lookup)
-    /// 6. Fallback config (if set and no other match found)
+    /// Priority order:
+    /// 1. A Vim / Emacs modeline in `modeline_region` naming a known language
+    /// 2. Exact filename match in user config
+    /// 3. Glob pattern match in user config
+    /// 4. Extension match in user config
+    /// 5. Built-in detection (catalog lookup)
+    /// 6. Shebang / first-line regex against `first_line` (catalog lookup)
+    /// 7. Fallback config (if set and no other match found)
     ///
     /// `first_line` is the literal first line of the file (including any
-    /// trailing newline). The caller — which has already loaded the buffer
-    /// via the `FileSystem` trait — supplies it so the registry never does
-    /// its own I/O. Pass `None` when there is no content to inspect (e.g.,
-    /// virtual buffers, unsaved files).
+    /// trailing newline); `modeline_region` is its first and last few lines
+    /// (`Buffer::modeline_region_lossy`). The caller — which has already
+    /// loaded the buffer via the `FileSystem` trait — supplies them so the
+    /// registry never does its own I/O. Pass `None` when there is no content
+    /// to inspect (e.g., virtual buffers, unsaved files).
     pub fn from_path(
         path: &Path,
         first_line: Option<&str>,
+        modeline_region: Option<&str>,
         registry: &GrammarRegistry,
         languages: &HashMap<String, LanguageConfig>,
     ) -> Self {
-        Self::from_path_with_fallback(path, first_line, registry, languages, None)
+        Self::from_path_with_fallback(path, first_line, modeline_region, registry, languages, None)
     }
 
     /// Like `from_path`, but also accepts an optional default language name
@@ -74,10 +77,21 @@ impl DetectedLanguage {
     pub fn from_path_with_fallback(
         path: &Path,
         first_line: Option<&str>,
+        modeline_region: Option<&str>,
         registry: &GrammarRegistry,
         languages: &HashMap<String, LanguageConfig>,
         default_language: Option<&str>,
     ) -> Self {
+        // A modeline is the file's own declaration and beats every
+        // path-based rule, config included.
+        if let Some(entry) = modeline_region.and_then(|text| registry.find_by_modeline(text)) {
+            let mut detected = Self::from_entry(entry, registry);
+            if let Some(id) = resolve_language_id(&entry.display_name, registry, languages) {
+                detected.name = id;
+            }
+            return detected;
+        }
+
         // Resolve the config/LSP language id *independently* of the grammar
         // catalog. A file matching a `[languages.foo]` rule must end up with
         // `name = "foo"` so comment prefix / tab config / LSP routing all
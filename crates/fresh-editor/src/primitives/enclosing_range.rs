@@ -0,0 +1,174 @@
+//! The next larger piece of text around a selection, without a syntax tree.
+//!
+//! Expand Selection To Node walks up the tree-sitter tree when the buffer's
+//! language has a grammar. For everything else this approximates the same
+//! steps from the text alone: word, the inside of a string, the inside of a
+//! bracket pair, the brackets themselves (with a name directly before them,
+//! as in a call), the line's content, the whole lines, then the whole
+//! buffer.
+
+use std::ops::Range;
+
+/// The smallest range that strictly contains `range`, or `None` if `range`
+/// already covers all of `text`.
+pub fn enclosing_text_range(text: &str, range: Range<usize>) -> Option<Range<usize>> {
+    let mut candidates = vec![word_at(text, &range), 0..text.len()];
+    candidates.extend(line_ranges(text, &range));
+    candidates.extend(quote_ranges(text, &range));
+    candidates.extend(bracket_ranges(text, &range));
+
+    candidates
+        .into_iter()
+        .filter(|c| c.start <= range.start && c.end >= range.end && *c != range)
+        .min_by_key(|c| c.len())
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// `range` grown over the word characters on either side.
+fn word_at(text: &str, range: &Range<usize>) -> Range<usize> {
+    let start = text[..range.start]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| is_word_char(*c))
+        .last()
+        .map_or(range.start, |(i, _)| i);
+    let end = range.end
+        + text[range.end..]
+            .chars()
+            .take_while(|c| is_word_char(*c))
+            .map(char::len_utf8)
+            .sum::<usize>();
+    start..end
+}
+
+/// The lines `range` touches, without and with their indentation and line
+/// break. A selection ending just after a line break ends on that line.
+fn line_ranges(text: &str, range: &Range<usize>) -> [Range<usize>; 2] {
+    let start = text[..range.start].rfind('\n').map_or(0, |i| i + 1);
+    let last = if range.end > range.start && text[..range.end].ends_with('\n') {
+        range.end - 1
+    } else {
+        range.end
+    };
+    let end = text[last..].find('\n').map_or(text.len(), |i| last + i);
+    let full = start..(end + 1).min(text.len());
+    [trim(text, start..end), full]
+}
+
+/// `range` without leading and trailing whitespace.
+fn trim(text: &str, range: Range<usize>) -> Range<usize> {
+    let slice = &text[range.clone()];
+    let start = range.start + (slice.len() - slice.trim_start().len());
+    let end = range.end - (slice.len() - slice.trim_end().len());
+    start..end.max(start)
+}
+
+/// The inside and outside of the string literal `range` is in, if it is on
+/// one line. Quotes pair up left to right, skipping escaped ones.
+fn quote_ranges(text: &str, range: &Range<usize>) -> Vec<Range<usize>> {
+    let line_start = text[..range.start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = text[range.start..]
+        .find('\n')
+        .map_or(text.len(), |i| range.start + i);
+    let mut ranges = Vec::new();
+    let mut open: Option<(usize, char)> = None;
+    let mut escaped = false;
+    for (i, c) in text[line_start..line_end].char_indices() {
+        let i = line_start + i;
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match (open, c) {
+            (Some(_), '\\') => escaped = true,
+            (Some((start, quote)), c) if c == quote => {
+                open = None;
+                if start < range.start && i + 1 > range.end {
+                    ranges.push(start + 1..i);
+                    ranges.push(start..i + 1);
+                }
+            }
+            (None, '"' | '\'' | '`') => open = Some((i, c)),
+            _ => {}
+        }
+    }
+    ranges
+}
+
+/// The inside (trimmed) and outside of every bracket pair around `range`.
+fn bracket_ranges(text: &str, range: &Range<usize>) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut stack: Vec<(usize, char)> = Vec::new();
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' | '[' | '{' => stack.push((i, c)),
+            ')' | ']' | '}' => {
+                let open = match c {
+                    ')' => '(',
+                    ']' => '[',
+                    _ => '{',
+                };
+                // Unbalanced closers are ignored.
+                let Some(depth) = stack.iter().rposition(|(_, o)| *o == open) else {
+                    continue;
+                };
+                let (start, _) = stack[depth];
+                stack.truncate(depth);
+                if start < range.start && i >= range.end {
+                    ranges.push(trim(text, start + 1..i));
+                    ranges.push(start..i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Expand from a caret at the `|` in `marked` until the buffer is
+    /// selected, collecting each step's text.
+    fn steps(marked: &str) -> Vec<String> {
+        let caret = marked.find('|').unwrap();
+        let text = marked.replace('|', "");
+        let mut range = caret..caret;
+        let mut steps = Vec::new();
+        while let Some(next) = enclosing_text_range(&text, range.clone()) {
+            steps.push(text[next.clone()].to_string());
+            range = next;
+        }
+        steps
+    }
+
+    #[test]
+    fn grows_from_word_through_brackets_to_buffer() {
+        assert_eq!(
+            steps("fn main() {\n    call(fi|rst, \"a b\");\n}\n"),
+            vec![
+                "first",
+                "first, \"a b\"",
+                "(first, \"a b\")",
+                "call(first, \"a b\")",
+                "call(first, \"a b\");",
+                "    call(first, \"a b\");\n",
+                "{\n    call(first, \"a b\");\n}",
+                "fn main() {\n    call(first, \"a b\");\n}",
+                "fn main() {\n    call(first, \"a b\");\n}\n",
+            ]
+        );
+    }
+
+    #[test]
+    fn selects_string_contents_then_quotes() {
+        assert_eq!(
+            steps("say(\"hello, w|orld\")")[..3],
+            ["world", "hello, world", "\"hello, world\""]
+        );
+    }
+}
@@ -8,8 +8,11 @@ use std::io;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use fresh_languages::tree_sitter;
+use fresh_languages::tree_sitter_language::LanguageFn;
 use syntect::parsing::{SyntaxSet, SyntaxSetBuilder};
 
+use super::runtime_grammar::{default_symbol, RuntimeGrammar, RUNTIME_GRAMMAR_MANIFEST};
 use super::types::{GrammarInfo, GrammarRegistry, GrammarSource, GrammarSpec, PackageManifest};
 
 /// Trait for loading grammar files from various sources.
@@ -103,7 +106,8 @@ impl GrammarRegistry {
     /// This loads:
     /// 1. Built-in syntect grammars
     /// 2. Embedded grammars (TOML, Odin, etc.)
-    /// 3. User-installed grammars from ~/.config/fresh/grammars/
+    /// 3. User-installed grammars from ~/.config/fresh/grammars/, including
+    ///    compiled tree-sitter grammars (see `runtime_grammar`)
     /// 4. Language pack grammars from ~/.config/fresh/languages/packages/
     pub fn load(loader: &dyn GrammarLoader) -> Self {
         Self::load_with_additional(loader, &[])
@@ -299,6 +303,14 @@ impl GrammarRegistry {
             grammar_sources,
         );
 
+        if has_user_grammars {
+            let grammars_dir = loader.grammars_dir().unwrap();
+            let runtime_grammars = load_runtime_grammars(loader, &grammars_dir);
+            if !runtime_grammars.is_empty() {
+                registry.set_runtime_grammars(runtime_grammars);
+            }
+        }
+
         // Register short-name aliases: built-in first, then manifest-declared
         registry.populate_built_in_aliases();
         let manifest_aliases: Vec<(String, String)> = registry
@@ -345,6 +357,11 @@ fn load_user_grammars(
             continue;
         }
 
+        // Tree-sitter grammars are loaded separately (`load_runtime_grammars`).
+        if loader.exists(&path.join(RUNTIME_GRAMMAR_MANIFEST)) {
+            continue;
+        }
+
         // Check for package.json (VSCode extension format)
         let manifest_path = path.join("package.json");
         if loader.exists(&manifest_path) {
@@ -786,6 +803,141 @@ fn load_bundle_grammars(
     }
 }
 
+/// Contents of a tree-sitter grammar's `tree-sitter.json` manifest (see
+/// `runtime_grammar`).
+#[derive(Debug, serde::Deserialize)]
+struct RuntimeGrammarManifest {
+    name: String,
+    library: String,
+    #[serde(default)]
+    symbol: Option<String>,
+    #[serde(default)]
+    extensions: Vec<String>,
+    highlights: String,
+    #[serde(default)]
+    injections: Option<String>,
+    #[serde(default)]
+    locals: Option<String>,
+}
+
+/// Load every tree-sitter grammar under `grammars_dir`, skipping (and
+/// logging) the ones that fail.
+pub fn load_runtime_grammars(
+    loader: &dyn GrammarLoader,
+    grammars_dir: &Path,
+) -> Vec<RuntimeGrammar> {
+    let Ok(entries) = loader.read_dir(grammars_dir) else {
+        return Vec::new();
+    };
+    let mut grammars = Vec::new();
+    for dir in entries {
+        if !loader.is_dir(&dir) || !loader.exists(&dir.join(RUNTIME_GRAMMAR_MANIFEST)) {
+            continue;
+        }
+        match load_runtime_grammar(loader, &dir) {
+            Ok(grammar) => {
+                tracing::info!("Loaded tree-sitter grammar {} from {:?}", grammar.name, dir);
+                grammars.push(grammar);
+            }
+            Err(e) => tracing::warn!("Failed to load tree-sitter grammar {:?}: {}", dir, e),
+        }
+    }
+    grammars
+}
+
+/// Load the tree-sitter grammar described by `dir/tree-sitter.json`.
+pub fn load_runtime_grammar(
+    loader: &dyn GrammarLoader,
+    dir: &Path,
+) -> Result<RuntimeGrammar, String> {
+    let content = loader
+        .read_file(&dir.join(RUNTIME_GRAMMAR_MANIFEST))
+        .map_err(|e| format!("Failed to read {}: {}", RUNTIME_GRAMMAR_MANIFEST, e))?;
+    let manifest: RuntimeGrammarManifest = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse {}: {}", RUNTIME_GRAMMAR_MANIFEST, e))?;
+
+    let library_path = dir.join(&manifest.library);
+    if library_path.extension().is_some_and(|ext| ext == "wasm") {
+        return Err(format!(
+            "{}: WASM grammars are not supported; build the grammar as a shared library",
+            manifest.library
+        ));
+    }
+    if !loader.exists(&library_path) {
+        return Err(format!("Grammar library not found: {:?}", library_path));
+    }
+    let symbol = manifest
+        .symbol
+        .clone()
+        .unwrap_or_else(|| default_symbol(&manifest.name));
+    let language = load_tree_sitter_language(&library_path, &symbol)?;
+
+    let read_query = |file: &str| {
+        loader
+            .read_file(&dir.join(file))
+            .map_err(|e| format!("Failed to read query {}: {}", file, e))
+    };
+    let extensions = manifest
+        .extensions
+        .iter()
+        .map(|ext| ext.trim_start_matches('.').to_string())
+        .collect();
+    let mut grammar = RuntimeGrammar::new(
+        manifest.name,
+        extensions,
+        dir.to_path_buf(),
+        language,
+        read_query(&manifest.highlights)?,
+    );
+    if let Some(file) = &manifest.injections {
+        grammar.injections = read_query(file)?;
+    }
+    if let Some(file) = &manifest.locals {
+        grammar.locals = read_query(file)?;
+    }
+
+    // Compile the queries now so a broken grammar is reported once at
+    // startup rather than silently on every buffer that opens.
+    grammar.highlight_config()?;
+    Ok(grammar)
+}
+
+/// Open the shared library at `path` and build a tree-sitter language from
+/// its `symbol` export.
+fn load_tree_sitter_language(path: &Path, symbol: &str) -> Result<tree_sitter::Language, String> {
+    // SAFETY: loading a library runs its initialisers; the grammars directory
+    // is user-controlled config, like the rest of ~/.config/fresh.
+    let library = unsafe { libloading::Library::new(path) }
+        .map_err(|e| format!("Failed to load {:?}: {}", path, e))?;
+    // SAFETY: tree-sitter grammars export `const TSLanguage *symbol(void)`.
+    let language_fn = unsafe {
+        library
+            .get::<unsafe extern "C" fn() -> *const ()>(symbol.as_bytes())
+            .map(|f| LanguageFn::from_raw(*f))
+    }
+    .map_err(|e| format!("Symbol {} not found in {:?}: {}", symbol, path, e))?;
+    let language = tree_sitter::Language::new(language_fn);
+
+    let abi = language.abi_version();
+    if !(tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION..=tree_sitter::LANGUAGE_VERSION)
+        .contains(&abi)
+    {
+        return Err(format!(
+            "{:?} uses tree-sitter ABI {}, expected {} to {}",
+            path,
+            abi,
+            tree_sitter::MIN_COMPATIBLE_LANGUAGE_VERSION,
+            tree_sitter::LANGUAGE_VERSION
+        ));
+    }
+
+    // Never unloaded: the language, and every parse tree and highlight
+    // configuration built from it, point into the library's memory. Loading
+    // the same path again reuses the already-mapped library.
+    std::mem::forget(library);
+    Ok(language)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -849,6 +1001,49 @@ mod tests {
         }
     }
 
+    fn mock_with_runtime_grammar(manifest: &str) -> (MockGrammarLoader, PathBuf) {
+        let grammars_dir = PathBuf::from("/config/grammars");
+        let grammar_dir = grammars_dir.join("zig");
+        let mut loader = MockGrammarLoader::new().with_grammars_dir(grammars_dir.clone());
+        loader
+            .dirs
+            .insert(grammars_dir.clone(), vec![grammar_dir.clone()]);
+        loader.dirs.insert(grammar_dir.clone(), Vec::new());
+        loader.files.insert(
+            grammar_dir.join(RUNTIME_GRAMMAR_MANIFEST),
+            manifest.to_string(),
+        );
+        (loader, grammar_dir)
+    }
+
+    #[test]
+    fn test_runtime_grammar_rejects_wasm() {
+        let (loader, dir) = mock_with_runtime_grammar(
+            r#"{"name": "Zig", "library": "zig.wasm", "highlights": "highlights.scm"}"#,
+        );
+        let err = load_runtime_grammar(&loader, &dir).unwrap_err();
+        assert!(err.contains("WASM"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn test_runtime_grammar_missing_library_is_skipped() {
+        let (loader, dir) = mock_with_runtime_grammar(
+            r#"{"name": "Zig", "library": "libzig.so", "highlights": "highlights.scm"}"#,
+        );
+        let err = load_runtime_grammar(&loader, &dir).unwrap_err();
+        assert!(err.contains("not found"), "unexpected error: {err}");
+
+        // A broken grammar is logged and skipped; the registry still loads.
+        let registry = GrammarRegistry::load(&loader);
+        assert!(registry.find_by_name("Zig").is_some());
+        assert!(registry
+            .find_by_name("Zig")
+            .unwrap()
+            .engines
+            .runtime_grammar
+            .is_none());
+    }
+
     #[test]
     fn test_mock_loader_no_grammars() {
         let loader = MockGrammarLoader::new();
@@ -897,6 +1092,8 @@ mod tests {
                 filenames: vec!["CUSTOMBUILD".to_string()],
                 grammar: "Bourne Again Shell (bash)".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -913,6 +1110,8 @@ mod tests {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
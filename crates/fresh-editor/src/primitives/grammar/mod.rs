@@ -3,6 +3,8 @@
 //! This module is split into:
 //! - `types`: Pure data types and lookup methods (WASM-compatible, no filesystem access)
 //! - `loader`: I/O operations with `GrammarLoader` trait abstraction (runtime only)
+//! - `runtime_grammar`: tree-sitter grammars loaded from the user grammars
+//!   directory at runtime
 //!
 //! # Example
 //!
@@ -29,10 +31,13 @@
 // Loader requires filesystem access - runtime only
 #[cfg(feature = "runtime")]
 mod loader;
+mod modeline;
+mod runtime_grammar;
 mod shebang;
 mod types;
 
 // Re-export all public items for backward compatibility
 #[cfg(feature = "runtime")]
 pub use loader::*;
+pub use runtime_grammar::{RuntimeGrammar, RUNTIME_GRAMMAR_MANIFEST};
 pub use types::*;
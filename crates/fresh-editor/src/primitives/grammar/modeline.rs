@@ -0,0 +1,170 @@
+//! Vim / Emacs modeline → language detection.
+//!
+//! A file can name its own language in a comment the author's editor reads:
+//!
+//! - Emacs, first line: `# -*- mode: python -*-` or the bare `/* -*- c -*- */`
+//! - Emacs, local variables block near the end:
+//!   `# Local Variables:` … `# mode: python` … `# End:`
+//! - Vim, first or last lines: `# vim: set ft=python:` or `// vim: filetype=rust`
+//!   (also `vi:`, `ex:` and versioned `vim600:` markers)
+//!
+//! The modeline is an explicit declaration, so
+//! [`DetectedLanguage::from_path`](crate::primitives::detected_language::DetectedLanguage::from_path)
+//! consults it before the filename.
+
+/// Find the mode a modeline in `text` names. `text` is the head and tail lines
+/// of a file (see `Buffer::modeline_region_lossy`). Returns the mode
+/// normalized for catalog lookup: lowercased, with an Emacs `-mode` / `-ts`
+/// suffix and a Vim compound filetype's `.sub` part removed.
+pub(super) fn modeline_mode(text: &str) -> Option<String> {
+    let mut in_local_variables = false;
+    for (index, line) in text.lines().enumerate() {
+        // Emacs only reads the header on the first line, or the second after
+        // a shebang.
+        let header = if index < 2 {
+            emacs_header_mode(line)
+        } else {
+            None
+        };
+        if let Some(mode) = header.or_else(|| vim_filetype(line)) {
+            return Some(normalize(mode));
+        }
+        if line.contains("Local Variables:") {
+            in_local_variables = true;
+        } else if in_local_variables {
+            if line.contains("End:") {
+                in_local_variables = false;
+            } else if let Some(mode) = local_variable(line, "mode") {
+                return Some(normalize(mode));
+            }
+        }
+    }
+    None
+}
+
+/// Map Emacs mode and Vim filetype names the catalog doesn't know under that
+/// name (by language id, display name or extension) to a catalog name.
+pub(super) fn mode_alias(mode: &str) -> Option<&'static str> {
+    Some(match mode {
+        "shell-script" | "sh-script" | "zsh" | "ksh" => "bash",
+        "emacs-lisp" | "elisp" | "lisp-interaction" | "lisp-data" => "lisp",
+        "js2" | "js3" | "rjsx" => "javascript",
+        "conf" | "conf-unix" | "conf-space" | "dosini" => "ini",
+        "terraform" => "hcl",
+        "gfm" => "markdown",
+        _ => return None,
+    })
+}
+
+/// `-*- mode: python; coding: utf-8 -*-` or `-*- python -*-`.
+fn emacs_header_mode(line: &str) -> Option<&str> {
+    let start = line.find("-*-")? + 3;
+    let end = start + line[start..].find("-*-")?;
+    let vars = line[start..end].trim();
+    if !vars.contains(':') {
+        return (!vars.is_empty()).then_some(vars);
+    }
+    vars.split(';')
+        .find_map(|var| {
+            let (key, value) = var.split_once(':')?;
+            key.trim()
+                .eq_ignore_ascii_case("mode")
+                .then(|| value.trim())
+        })
+        .filter(|mode| !mode.is_empty())
+}
+
+/// A `name: value` line inside an Emacs local variables block, after any
+/// comment prefix (`# mode: python`, `;; mode: lisp`).
+fn local_variable<'a>(line: &'a str, name: &str) -> Option<&'a str> {
+    let (key, value) = line.split_once(':')?;
+    if key.split_whitespace().last()? != name {
+        return None;
+    }
+    value.split_whitespace().next()
+}
+
+/// The `ft` / `filetype` / `syntax` option of a Vim modeline. As in Vim, the
+/// marker must follow whitespace, except `vim:` which may also start the line.
+fn vim_filetype(line: &str) -> Option<&str> {
+    for (colon, _) in line.match_indices(':') {
+        let head = &line[..colon];
+        let word_start = head.rfind(char::is_whitespace).map_or(0, |p| p + 1);
+        let marker = head[word_start..]
+            .trim_end_matches(|c: char| c.is_ascii_digit() || matches!(c, '<' | '>' | '='));
+        let is_marker = match marker {
+            "vim" | "Vim" => true,
+            "vi" | "ex" => word_start > 0,
+            _ => false,
+        };
+        if !is_marker {
+            continue;
+        }
+        let options = line[colon + 1..].trim_start();
+        // `vim: set ft=python :` ends at the next colon; the bare form
+        // separates options with whitespace or colons.
+        let options = match options
+            .strip_prefix("set ")
+            .or_else(|| options.strip_prefix("se "))
+        {
+            Some(set) => set.split(':').next().unwrap_or_default(),
+            None => options,
+        };
+        return options
+            .split(|c: char| c.is_whitespace() || c == ':')
+            .filter_map(|option| option.split_once('='))
+            .find(|(key, value)| {
+                matches!(*key, "ft" | "filetype" | "syntax" | "syn") && !value.is_empty()
+            })
+            .map(|(_, value)| value);
+    }
+    None
+}
+
+fn normalize(mode: &str) -> String {
+    let mode = mode.to_ascii_lowercase();
+    let mode = mode.split('.').next().unwrap_or_default();
+    let mode = mode.strip_suffix("-mode").unwrap_or(mode);
+    mode.strip_suffix("-ts").unwrap_or(mode).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_emacs_and_vim_modelines() {
+        let mode = |text: &str| modeline_mode(text);
+        // Emacs header line, with and without the `mode:` key.
+        assert_eq!(
+            mode("#!/bin/sh\n# -*- mode: Python; coding: utf-8 -*-\n").as_deref(),
+            Some("python")
+        );
+        assert_eq!(mode("/* -*- c++ -*- */\n").as_deref(), Some("c++"));
+        assert_eq!(mode("# -*- coding: utf-8 -*-\n"), None);
+        assert_eq!(mode("a\nb\n# -*- python -*-\n"), None);
+        // Emacs local variables block.
+        assert_eq!(
+            mode("x = 1\n# Local Variables:\n# fill-column: 80\n# mode: ruby-ts\n# End:\n")
+                .as_deref(),
+            Some("ruby")
+        );
+        // Vim `set` form, bare form, versioned marker and compound filetype.
+        assert_eq!(
+            mode("# vim: set ts=4 ft=python :\n").as_deref(),
+            Some("python")
+        );
+        assert_eq!(
+            mode("// vim: filetype=rust sw=4\n").as_deref(),
+            Some("rust")
+        );
+        assert_eq!(mode("vim600: syntax=yaml\n").as_deref(), Some("yaml"));
+        assert_eq!(
+            mode("  # vi:ft=javascript.jsx\n").as_deref(),
+            Some("javascript")
+        );
+        // Not modelines: no whitespace before `vi:`, no filetype option.
+        assert_eq!(mode("//vi:ft=c\n"), None);
+        assert_eq!(mode("# vim: set ts=4 :\n"), None);
+    }
+}
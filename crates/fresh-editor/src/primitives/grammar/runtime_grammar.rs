@@ -0,0 +1,101 @@
+//! Tree-sitter grammars loaded at runtime from the user grammars directory.
+//!
+//! A subdirectory of `~/.config/fresh/grammars/` holding a `tree-sitter.json`
+//! manifest is a compiled tree-sitter grammar rather than a TextMate one:
+//!
+//! ```json
+//! {
+//!   "name": "Zig",
+//!   "library": "libtree-sitter-zig.so",
+//!   "extensions": ["zig", "zon"],
+//!   "highlights": "queries/highlights.scm"
+//! }
+//! ```
+//!
+//! `library` is a shared library exporting the grammar's language function
+//! (`symbol`, default `tree_sitter_<name>`); `highlights`, and the optional
+//! `injections` and `locals`, are query files. Paths are relative to the
+//! grammar's directory. The grammar becomes a catalog entry — or, when its
+//! name matches an existing language, upgrades that entry's highlighting —
+//! so new languages need no recompile.
+
+use std::path::PathBuf;
+
+use fresh_languages::tree_sitter;
+use fresh_languages::HighlightConfiguration;
+
+/// Manifest file that marks a grammars subdirectory as a tree-sitter grammar.
+pub const RUNTIME_GRAMMAR_MANIFEST: &str = "tree-sitter.json";
+
+/// A tree-sitter grammar loaded from the user grammars directory.
+#[derive(Clone, Debug)]
+pub struct RuntimeGrammar {
+    /// Language name, matched case-insensitively against existing catalog
+    /// entries.
+    pub name: String,
+    /// File extensions (without leading dot) this grammar claims.
+    pub extensions: Vec<String>,
+    /// Directory the grammar was loaded from.
+    pub dir: PathBuf,
+    language: tree_sitter::Language,
+    highlights: String,
+    pub(crate) injections: String,
+    pub(crate) locals: String,
+}
+
+impl RuntimeGrammar {
+    pub(crate) fn new(
+        name: String,
+        extensions: Vec<String>,
+        dir: PathBuf,
+        language: tree_sitter::Language,
+        highlights: String,
+    ) -> Self {
+        Self {
+            name,
+            extensions,
+            dir,
+            language,
+            highlights,
+            injections: String::new(),
+            locals: String::new(),
+        }
+    }
+
+    /// Build a highlight configuration from the grammar's queries.
+    pub fn highlight_config(&self) -> Result<HighlightConfiguration, String> {
+        fresh_languages::runtime_highlight_config(
+            self.language.clone(),
+            &self.name.to_lowercase(),
+            &self.highlights,
+            &self.injections,
+            &self.locals,
+        )
+    }
+}
+
+/// Default exported symbol for a grammar named `name`: `tree_sitter_` plus the
+/// lowercased name with `-` and spaces turned into `_`, as the tree-sitter CLI
+/// generates it.
+pub(crate) fn default_symbol(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| match c {
+            '-' | ' ' => '_',
+            c => c.to_ascii_lowercase(),
+        })
+        .collect();
+    format!("tree_sitter_{name}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_symbol() {
+        assert_eq!(default_symbol("Zig"), "tree_sitter_zig");
+        assert_eq!(default_symbol("common-lisp"), "tree_sitter_common_lisp");
+        assert_eq!(default_symbol("Emacs Lisp"), "tree_sitter_emacs_lisp");
+    }
+}
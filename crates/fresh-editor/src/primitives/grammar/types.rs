@@ -9,6 +9,8 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use syntect::parsing::{SyntaxDefinition, SyntaxReference, SyntaxSet, SyntaxSetBuilder};
 
+use super::runtime_grammar::RuntimeGrammar;
+
 // Re-export glob matching utilities for use by other modules
 pub use crate::primitives::glob_match::{
     filename_glob_matches, is_glob_pattern, is_path_pattern, path_glob_matches,
@@ -110,6 +112,9 @@ pub struct GrammarEngines {
     pub syntect: Option<usize>,
     /// Tree-sitter language, if one is registered for this grammar.
     pub tree_sitter: Option<fresh_languages::Language>,
+    /// Tree-sitter grammar loaded from the user grammars directory. Takes
+    /// over highlighting from the other engines when present.
+    pub runtime_grammar: Option<Arc<RuntimeGrammar>>,
 }
 
 /// A single entry in the unified grammar catalog.
@@ -326,6 +331,10 @@ pub struct GrammarRegistry {
     catalog_by_extension: HashMap<String, usize>,
     /// Index from filename to catalog index.
     catalog_by_filename: HashMap<String, usize>,
+    /// Built-in filename patterns (glob keys of `filename_scopes`, e.g.
+    /// `Dockerfile.*`) with their catalog index. Consulted after the
+    /// extension lookup so user globs and real extensions win.
+    catalog_filename_patterns: Vec<(String, usize)>,
     /// The most recent language config handed to `apply_language_config`.
     /// Retained so `rebuild_catalog` can replay it — otherwise a rebuild
     /// (triggered by e.g. `populate_built_in_aliases`) silently wipes user
@@ -335,6 +344,9 @@ pub struct GrammarRegistry {
     /// observers (plugin state snapshot) detect changes with one integer
     /// compare instead of recounting entries.
     catalog_gen: u64,
+    /// Tree-sitter grammars loaded from the user grammars directory. Folded
+    /// into the catalog by `rebuild_catalog`.
+    runtime_grammars: Vec<Arc<RuntimeGrammar>>,
 }
 
 impl GrammarRegistry {
@@ -378,8 +390,10 @@ impl GrammarRegistry {
             catalog_by_name: HashMap::new(),
             catalog_by_extension: HashMap::new(),
             catalog_by_filename: HashMap::new(),
+            catalog_filename_patterns: Vec::new(),
             applied_language_config: HashMap::new(),
             catalog_gen: 0,
+            runtime_grammars: Vec::new(),
         };
         reg.rebuild_catalog();
         reg
@@ -400,8 +414,10 @@ impl GrammarRegistry {
             catalog_by_name: HashMap::new(),
             catalog_by_extension: HashMap::new(),
             catalog_by_filename: HashMap::new(),
+            catalog_filename_patterns: Vec::new(),
             applied_language_config: HashMap::new(),
             catalog_gen: 0,
+            runtime_grammars: Vec::new(),
         };
         reg.rebuild_catalog();
         Arc::new(reg)
@@ -440,8 +456,10 @@ impl GrammarRegistry {
             catalog_by_name: HashMap::new(),
             catalog_by_extension: HashMap::new(),
             catalog_by_filename: HashMap::new(),
+            catalog_filename_patterns: Vec::new(),
             applied_language_config: HashMap::new(),
             catalog_gen: 0,
+            runtime_grammars: Vec::new(),
         };
         registry.populate_built_in_aliases();
         registry.rebuild_catalog();
@@ -522,7 +540,8 @@ impl GrammarRegistry {
         let ruby_scope = "source.ruby".to_string();
         map.insert("Brewfile".to_string(), ruby_scope);
 
-        // Dockerfile and variants (exact names; Dockerfile.* handled via prefix check)
+        // Dockerfile and variants. Exact names first; the `Dockerfile.*` glob
+        // catches the rest when the suffix isn't itself a known extension.
         let dockerfile_scope = "source.dockerfile".to_string();
         map.insert("Dockerfile".to_string(), dockerfile_scope.clone());
         map.insert("Containerfile".to_string(), dockerfile_scope.clone());
@@ -531,6 +550,18 @@ impl GrammarRegistry {
         map.insert("Dockerfile.prod".to_string(), dockerfile_scope.clone());
         map.insert("Dockerfile.test".to_string(), dockerfile_scope.clone());
         map.insert("Dockerfile.build".to_string(), dockerfile_scope.clone());
+        map.insert("Dockerfile.*".to_string(), dockerfile_scope.clone());
+        map.insert("Containerfile.*".to_string(), dockerfile_scope);
+
+        // Automake / autoconf templates (`Makefile.am`, `Makefile.in`).
+        let makefile_scope = "source.makefile".to_string();
+        map.insert("Makefile.*".to_string(), makefile_scope.clone());
+        map.insert("makefile.*".to_string(), makefile_scope);
+
+        // dotenv files are shell-style `KEY=value` assignments.
+        let dotenv_scope = "source.shell.bash".to_string();
+        map.insert(".env".to_string(), dotenv_scope.clone());
+        map.insert(".env.*".to_string(), dotenv_scope);
 
         // CMake
         let cmake_scope = "source.cmake".to_string();
@@ -1143,14 +1174,19 @@ impl GrammarRegistry {
                 engines: GrammarEngines {
                     syntect: Some(idx),
                     tree_sitter,
+                    runtime_grammar: None,
                 },
             });
         }
 
-        // Attach filename_scopes to their entries.
+        // Attach filename_scopes to their entries. Glob keys become
+        // low-priority filename patterns instead of exact names.
+        let mut filename_patterns = Vec::new();
         for (filename, scope) in &self.filename_scopes {
             if let Some(&idx) = scope_to_index.get(scope) {
-                if !catalog[idx].filenames.iter().any(|f| f == filename) {
+                if is_glob_pattern(filename) {
+                    filename_patterns.push((filename.clone(), idx));
+                } else if !catalog[idx].filenames.iter().any(|f| f == filename) {
                     catalog[idx].filenames.push(filename.clone());
                 }
             }
@@ -1195,10 +1231,43 @@ impl GrammarRegistry {
                 engines: GrammarEngines {
                     syntect: None,
                     tree_sitter: Some(*lang),
+                    runtime_grammar: None,
                 },
             });
         }
 
+        // Tree-sitter grammars from the user grammars directory. One whose
+        // name matches an existing entry takes over that entry's
+        // highlighting; otherwise it gets an entry of its own.
+        for grammar in &self.runtime_grammars {
+            let existing = catalog.iter().position(|entry| {
+                entry.display_name.eq_ignore_ascii_case(&grammar.name)
+                    || entry.language_id.eq_ignore_ascii_case(&grammar.name)
+            });
+            let idx = existing.unwrap_or_else(|| {
+                catalog.push(GrammarEntry {
+                    display_name: grammar.name.clone(),
+                    language_id: grammar.name.to_lowercase(),
+                    short_name: None,
+                    extensions: Vec::new(),
+                    filenames: Vec::new(),
+                    filename_globs: Vec::new(),
+                    source: GrammarSource::User {
+                        path: grammar.dir.clone(),
+                    },
+                    engines: GrammarEngines::default(),
+                });
+                catalog.len() - 1
+            });
+            let entry = &mut catalog[idx];
+            entry.engines.runtime_grammar = Some(Arc::clone(grammar));
+            for ext in &grammar.extensions {
+                if !entry.extensions.iter().any(|e| e == ext) {
+                    entry.extensions.push(ext.clone());
+                }
+            }
+        }
+
         // Build name / extension / filename indices.
         //
         // Every entry in `extensions` gets indexed in BOTH `by_extension`
@@ -1223,11 +1292,24 @@ impl GrammarRegistry {
                 by_filename.entry(filename.clone()).or_insert(idx);
             }
         }
+        // A runtime grammar's own extensions win over built-in mappings, the
+        // same way user `[languages]` config does.
+        for (idx, entry) in catalog.iter().enumerate() {
+            if let Some(grammar) = &entry.engines.runtime_grammar {
+                for ext in &grammar.extensions {
+                    by_extension.insert(ext.to_lowercase(), idx);
+                }
+            }
+        }
 
         self.catalog = catalog;
         self.catalog_by_name = by_name;
         self.catalog_by_extension = by_extension;
         self.catalog_by_filename = by_filename;
+        // HashMap order is arbitrary; sort so overlapping patterns resolve
+        // the same way every run.
+        filename_patterns.sort();
+        self.catalog_filename_patterns = filename_patterns;
 
         // Replay the most recent user config so a rebuild doesn't silently
         // wipe out user `[languages]` rules. `take` + restore avoids both a
@@ -1240,6 +1322,13 @@ impl GrammarRegistry {
         self.catalog_gen = self.catalog_gen.wrapping_add(1);
     }
 
+    /// Register tree-sitter grammars loaded from the user grammars directory
+    /// and rebuild the catalog around them.
+    pub(crate) fn set_runtime_grammars(&mut self, grammars: Vec<RuntimeGrammar>) {
+        self.runtime_grammars = grammars.into_iter().map(Arc::new).collect();
+        self.rebuild_catalog();
+    }
+
     /// Return the full catalog of grammar entries.
     pub fn catalog(&self) -> &[GrammarEntry] {
         &self.catalog
@@ -1270,13 +1359,15 @@ impl GrammarRegistry {
     /// 1. Exact filename (config-declared filenames and filename_scopes live here)
     /// 2. Glob patterns from user config (e.g. "*.conf", "/etc/**/rc.*")
     /// 3. File extension
-    /// 4. Shebang / first-line regex match on `first_line` if supplied
+    /// 4. Built-in filename patterns (`Dockerfile.*`, `Makefile.*`, `.env.*`)
+    /// 5. Shebang / first-line regex match on `first_line` if supplied
     ///
     /// Globs take priority over extension so a user rule like `*.conf → bash`
     /// wins over any built-in extension match on `.conf`. The first-line
-    /// fallback (#4) is last so catalog matches stay authoritative — syntect
+    /// fallback (#5) is last so catalog matches stay authoritative — syntect
     /// might otherwise misclassify `.fish` as bash via its first-line
-    /// regexes.
+    /// regexes. Built-in patterns (#4) sit below extensions so `Dockerfile.json`
+    /// stays JSON.
     ///
     /// The first-line fallback is pure: it runs syntect's
     /// `find_syntax_by_first_line` regex cache against the caller-supplied
@@ -1315,6 +1406,17 @@ impl GrammarRegistry {
             }
         }
 
+        // Built-in filename patterns (`Dockerfile.*`, `Makefile.*`, …).
+        if let Some(name) = filename {
+            if let Some((_, idx)) = self
+                .catalog_filename_patterns
+                .iter()
+                .find(|(pattern, _)| filename_glob_matches(pattern, name))
+            {
+                return Some(&self.catalog[*idx]);
+            }
+        }
+
         // Last resort: shebang / first-line regex match against the
         // caller-supplied content. Map the matched syntect grammar back to a
         // catalog entry by name — every syntect syntax has a catalog entry,
@@ -1333,6 +1435,19 @@ impl GrammarRegistry {
         self.find_by_name(lang)
     }
 
+    /// Look up the grammar a Vim or Emacs modeline in `text` names.
+    ///
+    /// `text` is the head and tail lines of a file (see
+    /// `Buffer::modeline_region_lossy`). The mode is matched as a language
+    /// name, then as an extension (`ft=py`), then through a small table of
+    /// editor-specific mode names (`shell-script`, `emacs-lisp`, …).
+    pub fn find_by_modeline(&self, text: &str) -> Option<&GrammarEntry> {
+        let mode = super::modeline::modeline_mode(text)?;
+        self.find_by_name(&mode)
+            .or_else(|| self.find_by_extension(&mode))
+            .or_else(|| self.find_by_name(super::modeline::mode_alias(&mode)?))
+    }
+
     /// Look up a grammar entry by file extension (case-insensitive, without dot).
     pub fn find_by_extension(&self, ext: &str) -> Option<&GrammarEntry> {
         self.catalog_by_extension
@@ -1612,8 +1727,10 @@ impl GrammarRegistry {
             catalog_by_name: HashMap::new(),
             catalog_by_extension: HashMap::new(),
             catalog_by_filename: HashMap::new(),
+            catalog_filename_patterns: Vec::new(),
             applied_language_config: HashMap::new(),
             catalog_gen: 0,
+            runtime_grammars: base.runtime_grammars.clone(),
         };
         reg.rebuild_catalog();
         Some(reg)
@@ -1906,6 +2023,8 @@ mod tests {
                 filenames: vec!["*.conf".to_string(), "*rc".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -1922,6 +2041,8 @@ mod tests {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -1952,6 +2073,8 @@ mod tests {
                 filenames: vec!["/etc/**/rc.*".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -1968,6 +2091,8 @@ mod tests {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -2001,6 +2126,8 @@ mod tests {
                 filenames: vec!["lfrc".to_string()],
                 grammar: "python".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -2017,6 +2144,8 @@ mod tests {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -2029,6 +2158,8 @@ mod tests {
                 filenames: vec!["*rc".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -2045,6 +2176,8 @@ mod tests {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -2440,6 +2573,8 @@ mod tests {
             filenames: filenames.iter().map(|s| s.to_string()).collect(),
             grammar: grammar.to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: true,
             auto_close: None,
             auto_surround: None,
@@ -2456,6 +2591,8 @@ mod tests {
             on_save: vec![],
             word_characters: None,
             indentation_guide: None,
+            trim_trailing_whitespace_on_save: None,
+            abbreviations: HashMap::new(),
             indent: None,
         }
     }
@@ -2821,6 +2958,84 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "tree-sitter")]
+    fn json_runtime_grammar(name: &str, extensions: &[&str]) -> RuntimeGrammar {
+        RuntimeGrammar::new(
+            name.to_string(),
+            extensions.iter().map(|ext| ext.to_string()).collect(),
+            PathBuf::from("/config/grammars").join(name),
+            fresh_languages::tree_sitter_json::LANGUAGE.into(),
+            fresh_languages::tree_sitter_json::HIGHLIGHTS_QUERY.to_string(),
+        )
+    }
+
+    #[test]
+    #[cfg(feature = "tree-sitter")]
+    fn test_runtime_grammar_adds_entry_and_claims_extensions() {
+        let mut registry = GrammarRegistry::default();
+        registry.set_runtime_grammars(vec![json_runtime_grammar("Zonk", &["zonk", "json"])]);
+
+        let entry = registry
+            .find_by_path(Path::new("a.zonk"), None)
+            .expect("a.zonk should resolve");
+        assert_eq!(entry.display_name, "Zonk");
+        assert!(entry.engines.runtime_grammar.is_some());
+        assert!(matches!(entry.source, GrammarSource::User { .. }));
+        // The runtime grammar's extension wins over the built-in JSON mapping.
+        assert_eq!(
+            registry.find_by_extension("json").unwrap().display_name,
+            "Zonk"
+        );
+        let engine =
+            crate::primitives::highlight_engine::HighlightEngine::from_entry(entry, &registry);
+        assert_eq!(engine.backend_name(), "tree-sitter");
+    }
+
+    #[test]
+    #[cfg(feature = "tree-sitter")]
+    fn test_runtime_grammar_upgrades_existing_entry() {
+        let mut registry = GrammarRegistry::default();
+        registry.set_runtime_grammars(vec![json_runtime_grammar("rust", &[])]);
+
+        let entry = registry.find_by_extension("rs").unwrap();
+        assert_eq!(entry.display_name, "Rust");
+        assert!(entry.engines.syntect.is_some());
+        assert!(entry.engines.runtime_grammar.is_some());
+    }
+
+    #[test]
+    fn test_builtin_filename_patterns() {
+        let registry = GrammarRegistry::default();
+        let id = |path: &str| {
+            registry
+                .find_by_path(Path::new(path), None)
+                .map(|e| e.language_id.clone())
+        };
+        assert_eq!(id("Dockerfile.alpine").as_deref(), Some("dockerfile"));
+        assert_eq!(id("Containerfile.dev").as_deref(), Some("dockerfile"));
+        assert_eq!(id("Makefile.am").as_deref(), Some("makefile"));
+        assert_eq!(id(".env.local").as_deref(), Some("bash"));
+        // A real extension beats the pattern.
+        assert_eq!(id("Dockerfile.json").as_deref(), Some("json"));
+    }
+
+    #[test]
+    fn test_find_by_modeline() {
+        let registry = GrammarRegistry::default();
+        let id = |text: &str| {
+            registry
+                .find_by_modeline(text)
+                .map(|e| e.language_id.clone())
+        };
+        assert_eq!(id("# -*- mode: python -*-\n").as_deref(), Some("python"));
+        // Extension-style and editor-specific mode names.
+        assert_eq!(id("# vim: set ft=sh :\n").as_deref(), Some("bash"));
+        assert_eq!(id("# vim: ft=py\n").as_deref(), Some("python"));
+        assert_eq!(id(";; -*- mode: emacs-lisp -*-\n").as_deref(), Some("lisp"));
+        assert_eq!(id("# vim: ft=no-such-language\n"), None);
+        assert_eq!(id("plain text\n"), None);
+    }
+
     /// `tree_sitter_for_syntect_name` handles the alias table + strict
     /// display-name match. The alias table catches syntect's verbose names;
     /// the strict match handles the common case.
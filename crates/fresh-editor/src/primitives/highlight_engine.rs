@@ -1603,13 +1603,20 @@ impl HighlightEngine {
     /// Build a highlighting engine for a catalog entry.
     ///
     /// Single chokepoint for the "prefer syntect, fall back to tree-sitter"
-    /// logic. Callers that start from a path or a syntax name should resolve
+    /// logic. A tree-sitter grammar loaded at runtime from the user grammars
+    /// directory beats both. Callers that start from a path or a syntax name should resolve
     /// the entry through `GrammarRegistry::find_by_path` / `find_by_name` and
     /// then call this.
     pub fn from_entry(
         entry: &crate::primitives::grammar::GrammarEntry,
         registry: &GrammarRegistry,
     ) -> Self {
+        if let Some(grammar) = &entry.engines.runtime_grammar {
+            match Highlighter::for_runtime_grammar(grammar) {
+                Ok(highlighter) => return Self::TreeSitter(Box::new(highlighter)),
+                Err(e) => tracing::warn!("Runtime grammar {} unusable: {}", grammar.name, e),
+            }
+        }
         let syntax_set = registry.syntax_set_arc();
         if let Some(index) = entry.engines.syntect {
             return Self::TextMate(Box::new(TextMateEngine::with_language(
@@ -1786,7 +1793,7 @@ impl HighlightEngine {
     /// Returns the language even when using TextMate for highlighting
     pub fn language(&self) -> Option<&Language> {
         match self {
-            Self::TreeSitter(h) => Some(h.language()),
+            Self::TreeSitter(h) => h.language(),
             Self::TextMate(h) => h.language(),
             Self::None => None,
         }
@@ -10,6 +10,7 @@
 //! This is achieved by only parsing the visible viewport (~50 lines), not the entire file.
 
 use crate::model::buffer::Buffer;
+use crate::primitives::grammar::RuntimeGrammar;
 use crate::view::theme::Theme;
 use fresh_languages::tree_sitter_highlight::{
     HighlightConfiguration, HighlightEvent, Highlighter as TSHighlighter,
@@ -105,8 +106,8 @@ struct HighlightCache {
 pub struct Highlighter {
     /// Tree-sitter highlighter instance
     ts_highlighter: TSHighlighter,
-    /// Language being highlighted
-    language: Language,
+    /// Language being highlighted; `None` for a grammar loaded at runtime
+    language: Option<Language>,
     /// Highlight configuration for the language
     config: HighlightConfiguration,
     /// Cache of highlighted spans (only for visible viewport)
@@ -119,13 +120,33 @@ impl Highlighter {
     /// Create a new highlighter for the given language
     pub fn new(language: Language) -> Result<Self, String> {
         let config = language.highlight_config()?;
-        Ok(Self {
+        Ok(Self::with_config(Some(language), config))
+    }
+
+    /// Create a highlighter for a tree-sitter grammar loaded at runtime from
+    /// the user grammars directory
+    pub fn for_runtime_grammar(grammar: &RuntimeGrammar) -> Result<Self, String> {
+        let config = grammar.highlight_config()?;
+        Ok(Self::with_config(None, config))
+    }
+
+    fn with_config(language: Option<Language>, config: HighlightConfiguration) -> Self {
+        Self {
             ts_highlighter: TSHighlighter::new(),
             language,
             config,
             cache: None,
             last_buffer_len: 0,
-        })
+        }
+    }
+
+    /// Map a tree-sitter highlight index to a category. Runtime grammars are
+    /// configured with the default capture list.
+    fn highlight_category(language: Option<Language>, index: usize) -> Option<HighlightCategory> {
+        match language {
+            Some(language) => language.highlight_category(index),
+            None => HighlightCategory::from_default_index(index),
+        }
     }
 
     /// Highlight the visible viewport range
@@ -218,7 +239,7 @@ impl Highlighter {
 
                             if let Some(&highlight_idx) = highlight_stack.last() {
                                 if let Some(category) =
-                                    self.language.highlight_category(highlight_idx)
+                                    Self::highlight_category(self.language, highlight_idx)
                                 {
                                     cached_spans.push(CachedSpan {
                                         range: span_start..span_end,
@@ -298,9 +319,9 @@ impl Highlighter {
             .map(|span| span.category)
     }
 
-    /// Get the current language
-    pub fn language(&self) -> &Language {
-        &self.language
+    /// Get the current language, if it is one of the built-in ones
+    pub fn language(&self) -> Option<&Language> {
+        self.language.as_ref()
     }
 }
 
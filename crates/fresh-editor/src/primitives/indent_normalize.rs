@@ -0,0 +1,334 @@
+//! Indentation analysis and normalization.
+//!
+//! [`analyze_indentation`] looks for the two anomalies worth warning about
+//! when a file is opened: tabs and spaces both used for indentation, and
+//! space indentation that steps by more than one width (some blocks by 2,
+//! others by 4). [`normalize_indentation`] rewrites leading whitespace to a
+//! single style while keeping every line at the same visual column.
+//!
+//! Only leading whitespace is touched, and lines that start inside a
+//! multi-line string literal are left alone so string contents keep their
+//! alignment. String detection is a lexical heuristic (quotes, triple
+//! quotes, backticks, `//`, `#` and `/* */` comments), not a parse.
+
+use std::collections::HashMap;
+
+/// Space indentation narrower than this is treated as alignment (the ` * `
+/// of a block comment), not as an indentation style.
+const MIN_SPACE_INDENT: usize = 2;
+
+/// An indentation step must occur this often to count as a second width.
+const MIN_STEP_COUNT: usize = 3;
+
+/// Indentation style to normalize to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndentStyle {
+    /// Indent with spaces only.
+    Spaces,
+    /// Indent with tabs, padding a partial level with spaces.
+    Tabs,
+}
+
+/// What [`analyze_indentation`] found.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IndentReport {
+    /// Lines indented with tabs (optionally followed by alignment spaces).
+    pub tab_lines: usize,
+    /// Lines indented with spaces only.
+    pub space_lines: usize,
+    /// Lines whose indentation has a space before a tab.
+    pub mixed_lines: usize,
+    /// Space indentation steps used at least [`MIN_STEP_COUNT`] times, most
+    /// common first. More than one means inconsistent widths.
+    pub space_steps: Vec<usize>,
+}
+
+impl IndentReport {
+    /// Tabs and spaces are both used for indentation.
+    pub fn has_mixed_indentation(&self) -> bool {
+        self.mixed_lines > 0 || (self.tab_lines > 0 && self.space_lines > 0)
+    }
+
+    /// Space-indented blocks step by more than one width.
+    pub fn has_inconsistent_widths(&self) -> bool {
+        self.space_steps.len() > 1
+    }
+
+    pub fn is_anomalous(&self) -> bool {
+        self.has_mixed_indentation() || self.has_inconsistent_widths()
+    }
+}
+
+/// Classify the indentation of every line of `text`.
+pub fn analyze_indentation(text: &str, tab_size: usize) -> IndentReport {
+    let tab_size = tab_size.max(1);
+    let mut report = IndentReport::default();
+    let mut steps: HashMap<usize, usize> = HashMap::new();
+    let mut previous_width: Option<usize> = None;
+
+    for (line, in_string) in text.split('\n').zip(lines_starting_in_string(text)) {
+        let indent = leading_whitespace(line);
+        if in_string || indent.len() == line.trim_end_matches('\r').len() {
+            continue;
+        }
+        let has_tab = indent.contains('\t');
+        let has_space = indent.contains(' ');
+        if has_tab && indent.trim_start_matches('\t').contains('\t') {
+            report.mixed_lines += 1;
+        } else if has_tab {
+            report.tab_lines += 1;
+        } else if has_space && indent.len() >= MIN_SPACE_INDENT {
+            report.space_lines += 1;
+        }
+
+        let width = indent_width(indent, tab_size);
+        if !has_tab {
+            if let Some(previous) = previous_width {
+                if width > previous && indent.len() >= MIN_SPACE_INDENT {
+                    *steps.entry(width - previous).or_default() += 1;
+                }
+            }
+            previous_width = Some(width);
+        } else {
+            previous_width = None;
+        }
+    }
+
+    let mut steps: Vec<(usize, usize)> = steps
+        .into_iter()
+        .filter(|&(_, count)| count >= MIN_STEP_COUNT)
+        .collect();
+    steps.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    // An occasional double step (a continuation line indented two levels)
+    // is the same width used twice, not a second width.
+    let mut kept: Vec<(usize, usize)> = Vec::new();
+    for (step, count) in steps {
+        if !kept
+            .iter()
+            .any(|&(k, k_count)| step % k == 0 && count * 2 < k_count)
+        {
+            kept.push((step, count));
+        }
+    }
+    report.space_steps = kept.into_iter().map(|(step, _)| step).collect();
+    report
+}
+
+/// Rewrite the leading whitespace of every line of `text` to `style`,
+/// keeping each line's visual indentation (tabs are `tab_size` columns).
+/// Blank lines and lines starting inside a string literal are unchanged.
+pub fn normalize_indentation(text: &str, style: IndentStyle, tab_size: usize) -> String {
+    let tab_size = tab_size.max(1);
+    let mut out = String::with_capacity(text.len());
+    for (index, (line, in_string)) in text
+        .split('\n')
+        .zip(lines_starting_in_string(text))
+        .enumerate()
+    {
+        if index > 0 {
+            out.push('\n');
+        }
+        let indent = leading_whitespace(line);
+        let rest = &line[indent.len()..];
+        if in_string || rest.trim_end_matches('\r').is_empty() {
+            out.push_str(line);
+            continue;
+        }
+        let width = indent_width(indent, tab_size);
+        match style {
+            IndentStyle::Spaces => out.push_str(&" ".repeat(width)),
+            IndentStyle::Tabs => {
+                out.push_str(&"\t".repeat(width / tab_size));
+                out.push_str(&" ".repeat(width % tab_size));
+            }
+        }
+        out.push_str(rest);
+    }
+    out
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    let end = line
+        .find(|c: char| c != ' ' && c != '\t')
+        .unwrap_or(line.len());
+    &line[..end]
+}
+
+/// Visual width of `indent`, with tab stops every `tab_size` columns.
+fn indent_width(indent: &str, tab_size: usize) -> usize {
+    indent.chars().fold(0, |column, c| match c {
+        '\t' => (column / tab_size + 1) * tab_size,
+        _ => column + 1,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lex {
+    Code,
+    /// Inside a string closed by the delimiter; `multiline` strings carry
+    /// over to the next line.
+    Str {
+        delimiter: &'static str,
+        multiline: bool,
+    },
+    BlockComment,
+}
+
+/// For each line of `text` (split on `\n`), whether it starts inside a
+/// string literal that began on an earlier line.
+fn lines_starting_in_string(text: &str) -> Vec<bool> {
+    let mut state = Lex::Code;
+    let mut result = Vec::new();
+    for line in text.split('\n') {
+        result.push(matches!(state, Lex::Str { .. }));
+        state = lex_line(line.as_bytes(), state);
+    }
+    result
+}
+
+/// Advance the lexer state over one line.
+fn lex_line(line: &[u8], mut state: Lex) -> Lex {
+    let mut i = 0;
+    while i < line.len() {
+        let rest = &line[i..];
+        match state {
+            Lex::Str { delimiter, .. } => {
+                if rest[0] == b'\\' {
+                    i += 2;
+                } else if rest.starts_with(delimiter.as_bytes()) {
+                    state = Lex::Code;
+                    i += delimiter.len();
+                } else {
+                    i += 1;
+                }
+            }
+            Lex::BlockComment => {
+                if rest.starts_with(b"*/") {
+                    state = Lex::Code;
+                    i += 2;
+                } else {
+                    i += 1;
+                }
+            }
+            Lex::Code => {
+                if rest.starts_with(b"//") || rest[0] == b'#' {
+                    break;
+                } else if rest.starts_with(b"/*") {
+                    state = Lex::BlockComment;
+                    i += 2;
+                } else if let Some(delimiter) = ["\"\"\"", "'''", "\"", "`"]
+                    .into_iter()
+                    .find(|d| rest.starts_with(d.as_bytes()))
+                {
+                    state = Lex::Str {
+                        delimiter,
+                        multiline: true,
+                    };
+                    i += delimiter.len();
+                } else if rest[0] == b'\'' {
+                    i += skip_single_quote(rest, &mut state);
+                } else {
+                    i += 1;
+                }
+            }
+        }
+    }
+    match state {
+        Lex::Str {
+            multiline: false, ..
+        } => Lex::Code,
+        state => state,
+    }
+}
+
+/// Handle a `'` in code: a character literal is skipped whole, a Rust
+/// lifetime or label is just the quote, and anything else opens a string
+/// that ends with the line. Returns the bytes consumed.
+fn skip_single_quote(rest: &[u8], state: &mut Lex) -> usize {
+    let close = |from: usize| rest.iter().skip(from).position(|&b| b == b'\'');
+    match rest.get(1) {
+        Some(b'\\') => {
+            if let Some(end) = close(3) {
+                if end <= 8 {
+                    return 3 + end + 1;
+                }
+            }
+        }
+        Some(&c) if rest.get(2) == Some(&b'\'') && c != b'\'' => return 3,
+        Some(&c) if c.is_ascii_alphabetic() || c == b'_' => {
+            let ident_end = rest[1..]
+                .iter()
+                .position(|&b| !(b.is_ascii_alphanumeric() || b == b'_'))
+                .map_or(rest.len(), |p| p + 1);
+            if rest.get(ident_end) != Some(&b'\'') {
+                return 1;
+            }
+        }
+        _ => {}
+    }
+    *state = Lex::Str {
+        delimiter: "'",
+        multiline: false,
+    };
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_mixed_tabs_and_spaces() {
+        let text = "fn a() {\n\tlet x = 1;\n    let y = 2;\n}\n";
+        let report = analyze_indentation(text, 4);
+        assert_eq!(report.tab_lines, 1);
+        assert_eq!(report.space_lines, 1);
+        assert!(report.has_mixed_indentation());
+
+        // Tabs followed by alignment spaces and ` * ` comment lines are fine.
+        let text = "/*\n * doc\n */\nf(a,\n\t  b);\n\tg();\n";
+        assert!(!analyze_indentation(text, 4).is_anomalous());
+    }
+
+    #[test]
+    fn reports_inconsistent_space_widths() {
+        let two = "a\n  b\nc\n  d\ne\n  f\n";
+        let four = "g\n    h\ni\n    j\nk\n    l\n";
+        let report = analyze_indentation(&format!("{two}{four}"), 4);
+        assert_eq!(report.space_steps, vec![2, 4]);
+        assert!(report.has_inconsistent_widths());
+
+        // A few double steps in a 2-space file are continuation lines.
+        let report = analyze_indentation(&format!("{two}{two}{two}{four}"), 4);
+        assert_eq!(report.space_steps, vec![2]);
+    }
+
+    #[test]
+    fn normalizes_keeping_columns() {
+        let text = "a\n\tb\n  \tc\n\t  d\n\n";
+        assert_eq!(
+            normalize_indentation(text, IndentStyle::Spaces, 4),
+            "a\n    b\n    c\n      d\n\n"
+        );
+        let text = "a\n    b\n      c\n\t\td\n";
+        assert_eq!(
+            normalize_indentation(text, IndentStyle::Tabs, 4),
+            "a\n\tb\n\t  c\n\t\td\n"
+        );
+    }
+
+    #[test]
+    fn leaves_multiline_strings_alone() {
+        let text = "x = \"\"\"\n\tkeep\n\"\"\"\n\ty = 'it''s'\n\tz = `\n\tkeep`\n";
+        assert_eq!(
+            normalize_indentation(text, IndentStyle::Spaces, 2),
+            "x = \"\"\"\n\tkeep\n\"\"\"\n  y = 'it''s'\n  z = `\n\tkeep`\n"
+        );
+        // A lifetime is not a quote; the string after it still counts.
+        let text = "let s: &'static str = \"a\n\tb\";\n\tc\n";
+        assert_eq!(
+            normalize_indentation(text, IndentStyle::Spaces, 4),
+            "let s: &'static str = \"a\n\tb\";\n    c\n"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! Join Lines and Split Line.
+//!
+//! Like [`super::comment_toggle`], these functions only plan the edits as
+//! [`CommentEdit`]s over the original text; the caller applies them and
+//! places the cursors.
+
+use super::comment_toggle::CommentEdit;
+
+const BLANKS: [char; 2] = [' ', '\t'];
+
+/// Plan joining `lines`, given as `(start offset, content without the line
+/// terminator)` in ascending order, into one line.
+///
+/// Each line's indentation and the previous line's trailing whitespace
+/// become a single space. No space is added after a blank line or in front
+/// of a closing bracket. When the first line is a line comment, the
+/// `comment_prefix` starting each following line is dropped as well.
+///
+/// Returns one edit per line after the first: `edits[i]` removes the line
+/// break between `lines[i]` and `lines[i + 1]`, and its offset is where
+/// the two meet.
+pub fn join_lines(lines: &[(usize, &str)], comment_prefix: Option<&str>) -> Vec<CommentEdit> {
+    let Some(&(first_start, first)) = lines.first() else {
+        return Vec::new();
+    };
+    let comment_prefix =
+        comment_prefix.filter(|prefix| first.trim_start_matches(BLANKS).starts_with(prefix));
+
+    let mut edits = Vec::with_capacity(lines.len().saturating_sub(1));
+    // Whether everything joined so far is whitespace; its whitespace is
+    // kept then, so joining onto an indented blank line keeps the indent.
+    let mut joined_blank = first.trim_start_matches(BLANKS).is_empty();
+    let mut join_at = if joined_blank {
+        first_start + first.len()
+    } else {
+        first_start + first.trim_end_matches(BLANKS).len()
+    };
+    for &(start, line) in &lines[1..] {
+        let mut body = line.trim_start_matches(BLANKS);
+        if let Some(rest) = comment_prefix.and_then(|prefix| body.strip_prefix(prefix)) {
+            body = rest.trim_start_matches(BLANKS);
+        }
+        let body_start = start + line.len() - body.len();
+        let space = !joined_blank && !body.is_empty() && !body.starts_with([')', ']', '}']);
+        edits.push(CommentEdit {
+            offset: join_at,
+            delete: body_start - join_at,
+            insert: if space { " " } else { "" }.to_string(),
+        });
+
+        joined_blank &= body.is_empty();
+        join_at = if joined_blank {
+            start + line.len()
+        } else {
+            body_start + body.trim_end_matches(BLANKS).len()
+        };
+    }
+    edits
+}
+
+/// Plan splitting `line` (starting at `line_start`, without its terminator)
+/// at byte `column`.
+///
+/// The whitespace around the split point is removed and the rest of the
+/// line moves below, indented like `line`. Inside a line comment the new
+/// line continues the comment with `comment_prefix`. The edit's offset is
+/// the end of the first half.
+pub fn split_line(
+    line_start: usize,
+    line: &str,
+    column: usize,
+    line_ending: &str,
+    comment_prefix: Option<&str>,
+) -> CommentEdit {
+    let (before, after) = line.split_at(column);
+    let head = before.trim_end_matches(BLANKS);
+    let tail = after.trim_start_matches(BLANKS);
+
+    let indent = &line[..line.len() - line.trim_start_matches(BLANKS).len()];
+    let mut insert = line_ending.to_string();
+    if !tail.is_empty() {
+        insert.push_str(indent);
+        // Only when the split point is past the marker; splitting in front
+        // of it just moves the comment down.
+        if let Some(prefix) = comment_prefix.filter(|prefix| {
+            head.len() >= indent.len() + prefix.len() && head[indent.len()..].starts_with(prefix)
+        }) {
+            insert.push_str(prefix);
+            insert.push(' ');
+        }
+    }
+
+    let offset = line_start + head.len();
+    CommentEdit {
+        offset,
+        delete: line_start + column + (after.len() - tail.len()) - offset,
+        insert,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(text: &str, edits: &[CommentEdit]) -> String {
+        let mut text = text.to_string();
+        for edit in edits.iter().rev() {
+            text.replace_range(edit.offset..edit.offset + edit.delete, &edit.insert);
+        }
+        text
+    }
+
+    /// `text`'s lines with their start offsets.
+    fn lines(text: &str) -> Vec<(usize, &str)> {
+        let mut start = 0;
+        text.split('\n')
+            .map(|line| {
+                let entry = (start, line);
+                start += line.len() + 1;
+                entry
+            })
+            .collect()
+    }
+
+    #[test]
+    fn join_normalizes_whitespace() {
+        let text = "    foo(a,   \n        b\n    )";
+        let edits = join_lines(&lines(text), Some("//"));
+        assert_eq!(apply(text, &edits), "    foo(a, b)");
+        assert_eq!(edits[0].offset, 10);
+    }
+
+    #[test]
+    fn join_drops_comment_markers_only_in_comments() {
+        let text = "  // first\n  // second\n  //\n  // third";
+        assert_eq!(
+            apply(text, &join_lines(&lines(text), Some("//"))),
+            "  // first second third"
+        );
+
+        let text = "let a = 1;\n// note";
+        assert_eq!(
+            apply(text, &join_lines(&lines(text), Some("//"))),
+            "let a = 1; // note"
+        );
+    }
+
+    #[test]
+    fn join_blank_lines_adds_no_space() {
+        let text = "a\n\n   \nb";
+        assert_eq!(apply(text, &join_lines(&lines(text), None)), "a b");
+
+        let text = "    \n  b";
+        assert_eq!(apply(text, &join_lines(&lines(text), None)), "    b");
+    }
+
+    #[test]
+    fn split_keeps_indent_and_comment() {
+        let line = "    foo(a, b)";
+        let edit = split_line(0, line, 11, "\n", Some("//"));
+        assert_eq!(
+            apply(line, std::slice::from_ref(&edit)),
+            "    foo(a,\n    b)"
+        );
+        assert_eq!(edit.offset, 10);
+
+        let line = "  // one two";
+        let edit = split_line(0, line, 8, "\n", Some("//"));
+        assert_eq!(apply(line, &[edit]), "  // one\n  // two");
+
+        let line = "  // one";
+        let edit = split_line(0, line, 2, "\n", Some("//"));
+        assert_eq!(apply(line, &[edit]), "\n  // one");
+
+        let edit = split_line(0, line, 8, "\r\n", Some("//"));
+        assert_eq!(apply(line, &[edit]), "  // one\r\n");
+    }
+}
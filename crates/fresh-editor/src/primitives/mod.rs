@@ -15,13 +15,22 @@
 //! | Reference highlighting | `reference_highlight_text` | `reference_highlighter` |
 
 // Pure modules - available for both runtime and WASM
+pub mod block_font;
+pub mod calc;
+pub mod char_info;
+pub mod comment_toggle;
 pub mod display_width;
+pub mod enclosing_range;
 pub mod glob_match;
 pub mod grapheme;
+pub mod indent_normalize;
+pub mod line_join;
 pub mod line_wrapping;
 pub mod path_utils;
 pub mod snippet;
+pub mod text_escape;
 pub mod text_property;
+pub mod text_transform;
 
 // Modules depending on model::buffer - available for both runtime and WASM
 pub mod line_iterator;
@@ -1,12 +1,18 @@
 //! LSP snippet parser and expander.
 //!
-//! Parses LSP snippet syntax and expands it to plain text with cursor positioning.
-//! Supports:
-//! - `$0` - final cursor position
-//! - `$n` - tabstops (expanded as empty, cursor goes to $0 or end)
-//! - `${n:text}` - tabstops with default text (uses the default)
+//! Parses LSP snippet syntax and expands it to plain text, remembering where
+//! each tab stop ended up so the editor can walk through them. Supports:
+//! - `$0` / `${0}` - final cursor position
+//! - `$n` / `${n}` - tabstops; a bare tabstop that shares its number with a
+//!   placeholder mirrors the placeholder's text
+//! - `${n:text}` - tabstops with default text (placeholders may nest)
 //! - `${n|choice1,choice2|}` - choices (uses first choice)
-//! - `\\$` - escaped dollar sign
+//! - `$NAME`, `${NAME}`, `${NAME:default}` - variables, resolved by the caller
+//! - `${n/regex/format/}` - transforms, treated as plain mirrors
+//! - `\\$`, `\\}`, `\\\\` - escapes
+
+use std::collections::HashMap;
+use std::ops::Range;
 
 /// Result of expanding a snippet
 #[derive(Debug, Clone, PartialEq)]
@@ -15,6 +21,11 @@ pub struct ExpandedSnippet {
     pub text: String,
     /// Cursor offset from start of inserted text (where $0 was, or end if no $0)
     pub cursor_offset: usize,
+    /// Tab stops `$1`, `$2`, … in navigation order. Each entry holds the byte
+    /// ranges (into `text`) of every occurrence of that tab stop: the
+    /// placeholder and all of its mirrors. `$0` is not included; it is
+    /// `cursor_offset`.
+    pub tabstops: Vec<Vec<Range<usize>>>,
 }
 
 /// Expand an LSP snippet to plain text
@@ -32,144 +43,370 @@ pub struct ExpandedSnippet {
 /// let result = expand_snippet("foo(${1:arg})");
 /// assert_eq!(result.text, "foo(arg)");
 /// assert_eq!(result.cursor_offset, 8); // cursor at end (no $0)
+/// assert_eq!(result.tabstops, vec![vec![4..7]]);
 /// ```
 pub fn expand_snippet(snippet: &str) -> ExpandedSnippet {
-    let mut result = String::new();
-    let mut cursor_offset: Option<usize> = None;
-    let mut chars = snippet.chars().peekable();
+    expand_snippet_with(snippet, |_| None)
+}
 
-    while let Some(c) = chars.next() {
-        if c == '\\' {
-            // Escape sequence
-            if let Some(&next) = chars.peek() {
-                if next == '$' || next == '\\' || next == '}' {
-                    result.push(chars.next().unwrap());
-                    continue;
-                }
-            }
-            result.push(c);
-        } else if c == '$' {
-            // Snippet placeholder
-            if let Some(&next) = chars.peek() {
-                if next == '{' {
-                    // ${...} syntax
-                    chars.next(); // consume '{'
-                    let (expanded, is_final) = parse_brace_placeholder(&mut chars);
-                    if is_final {
-                        cursor_offset = Some(result.len());
-                    }
-                    result.push_str(&expanded);
-                } else if next.is_ascii_digit() {
-                    // $n syntax
-                    let mut num = String::new();
-                    while let Some(&d) = chars.peek() {
-                        if d.is_ascii_digit() {
-                            num.push(chars.next().unwrap());
-                        } else {
-                            break;
-                        }
-                    }
-                    if num == "0" {
-                        cursor_offset = Some(result.len());
-                    }
-                    // Other tabstops expand to nothing
-                } else {
-                    // Not a valid placeholder, keep the $
-                    result.push(c);
-                }
-            } else {
-                result.push(c);
-            }
-        } else {
-            result.push(c);
+/// Expand an LSP snippet, resolving variables (`$TM_FILENAME`, …) through
+/// `variable`. A variable it doesn't know expands to its default, or to
+/// nothing.
+pub fn expand_snippet_with(
+    snippet: &str,
+    variable: impl Fn(&str) -> Option<String>,
+) -> ExpandedSnippet {
+    let chars: Vec<char> = snippet.chars().collect();
+    let mut pos = 0;
+    let nodes = parse_nodes(&chars, &mut pos, false);
+
+    // Mirrors take their text from the placeholder they mirror, which may
+    // itself contain mirrors; re-render until the texts settle. The pass
+    // limit only matters for self-referencing placeholders like `${1:a$1}`.
+    let mut mirrors: HashMap<u32, String> = HashMap::new();
+    let mut render = Render::default();
+    for _ in 0..4 {
+        render = Render::default();
+        render.nodes(&nodes, &mirrors, &variable);
+        let sources = render.sources();
+        if sources == mirrors {
+            break;
+        }
+        mirrors = sources;
+    }
+
+    let mut by_index: Vec<(u32, Vec<Range<usize>>)> = Vec::new();
+    let mut final_cursor = None;
+    for stop in &render.stops {
+        if stop.index == 0 {
+            final_cursor.get_or_insert(stop.range.start);
+            continue;
+        }
+        match by_index.iter_mut().find(|(index, _)| *index == stop.index) {
+            Some((_, ranges)) => ranges.push(stop.range.clone()),
+            None => by_index.push((stop.index, vec![stop.range.clone()])),
         }
     }
+    by_index.sort_by_key(|(index, _)| *index);
 
     ExpandedSnippet {
-        cursor_offset: cursor_offset.unwrap_or(result.len()),
-        text: result,
+        cursor_offset: final_cursor.unwrap_or(render.text.len()),
+        text: render.text,
+        tabstops: by_index.into_iter().map(|(_, ranges)| ranges).collect(),
     }
 }
 
-/// Parse a ${...} placeholder
-/// Returns (expanded_text, is_final_cursor)
-fn parse_brace_placeholder(chars: &mut std::iter::Peekable<std::str::Chars>) -> (String, bool) {
-    let mut content = String::new();
-    let mut depth = 1;
+/// A parsed piece of a snippet.
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Text(String),
+    /// `$n` (no placeholder, mirrors) or `${n:…}`
+    Tabstop {
+        index: u32,
+        placeholder: Option<Vec<Node>>,
+    },
+    /// `${n|a,b|}`
+    Choice {
+        index: u32,
+        options: Vec<String>,
+    },
+    /// `$NAME` or `${NAME:…}`
+    Variable {
+        name: String,
+        default: Option<Vec<Node>>,
+    },
+}
 
-    // Collect everything until matching '}'
-    while let Some(c) = chars.next() {
-        if c == '{' {
-            depth += 1;
-            content.push(c);
-        } else if c == '}' {
-            depth -= 1;
-            if depth == 0 {
-                break;
+/// Parse nodes up to the end of input or, inside a placeholder, up to the
+/// closing `}` (left unconsumed).
+fn parse_nodes(chars: &[char], pos: &mut usize, in_placeholder: bool) -> Vec<Node> {
+    let mut nodes = Vec::new();
+    let mut text = String::new();
+    while let Some(&c) = chars.get(*pos) {
+        match c {
+            '}' if in_placeholder => break,
+            '\\' => {
+                match chars.get(*pos + 1) {
+                    Some(&next @ ('$' | '\\' | '}')) => {
+                        text.push(next);
+                        *pos += 2;
+                    }
+                    _ => {
+                        text.push(c);
+                        *pos += 1;
+                    }
+                }
+                continue;
             }
-            content.push(c);
-        } else if c == '\\' {
-            // Handle escapes inside braces
-            if let Some(&next) = chars.peek() {
-                if next == '$' || next == '\\' || next == '}' || next == '|' {
-                    content.push(chars.next().unwrap());
+            '$' => {
+                let start = *pos;
+                *pos += 1;
+                if let Some(node) = parse_dollar(chars, pos) {
+                    if !text.is_empty() {
+                        nodes.push(Node::Text(std::mem::take(&mut text)));
+                    }
+                    nodes.push(node);
                     continue;
                 }
+                // Not a valid placeholder, keep the $
+                *pos = start + 1;
+                text.push(c);
+            }
+            _ => {
+                text.push(c);
+                *pos += 1;
+            }
+        }
+    }
+    if !text.is_empty() {
+        nodes.push(Node::Text(text));
+    }
+    nodes
+}
+
+/// Parse what follows a `$`. Returns `None` (position unspecified) if it
+/// isn't a tabstop, placeholder, choice or variable.
+fn parse_dollar(chars: &[char], pos: &mut usize) -> Option<Node> {
+    let c = *chars.get(*pos)?;
+    if c.is_ascii_digit() {
+        let index = parse_int(chars, pos)?;
+        return Some(Node::Tabstop {
+            index,
+            placeholder: None,
+        });
+    }
+    if is_var_start(c) {
+        return Some(Node::Variable {
+            name: parse_var(chars, pos),
+            default: None,
+        });
+    }
+    if c != '{' {
+        return None;
+    }
+    *pos += 1;
+    let c = *chars.get(*pos)?;
+    if c.is_ascii_digit() {
+        let index = parse_int(chars, pos)?;
+        match chars.get(*pos)? {
+            '}' => {
+                *pos += 1;
+                Some(Node::Tabstop {
+                    index,
+                    placeholder: None,
+                })
+            }
+            ':' => {
+                *pos += 1;
+                let children = parse_nodes(chars, pos, true);
+                expect(chars, pos, '}')?;
+                Some(Node::Tabstop {
+                    index,
+                    placeholder: Some(children),
+                })
+            }
+            '|' => {
+                *pos += 1;
+                let options = parse_choice(chars, pos)?;
+                Some(Node::Choice { index, options })
+            }
+            '/' => {
+                skip_transform(chars, pos)?;
+                Some(Node::Tabstop {
+                    index,
+                    placeholder: None,
+                })
+            }
+            _ => None,
+        }
+    } else if is_var_start(c) {
+        let name = parse_var(chars, pos);
+        match chars.get(*pos)? {
+            '}' => {
+                *pos += 1;
+                Some(Node::Variable {
+                    name,
+                    default: None,
+                })
+            }
+            ':' => {
+                *pos += 1;
+                let children = parse_nodes(chars, pos, true);
+                expect(chars, pos, '}')?;
+                Some(Node::Variable {
+                    name,
+                    default: Some(children),
+                })
+            }
+            '/' => {
+                skip_transform(chars, pos)?;
+                Some(Node::Variable {
+                    name,
+                    default: None,
+                })
             }
-            content.push(c);
-        } else {
-            content.push(c);
+            _ => None,
         }
+    } else {
+        None
     }
+}
 
-    // Parse the content: n or n:default or n|choices|
-    let (tabstop, default) = parse_placeholder_content(&content);
+fn parse_int(chars: &[char], pos: &mut usize) -> Option<u32> {
+    let start = *pos;
+    while chars.get(*pos).is_some_and(|c| c.is_ascii_digit()) {
+        *pos += 1;
+    }
+    chars[start..*pos].iter().collect::<String>().parse().ok()
+}
 
-    let is_final = tabstop == Some(0);
-    (default, is_final)
+fn is_var_start(c: char) -> bool {
+    c == '_' || c.is_ascii_alphabetic()
 }
 
-/// Parse placeholder content like "1", "1:default", or "1|a,b,c|"
-/// Returns (tabstop_number, default_text)
-fn parse_placeholder_content(content: &str) -> (Option<u32>, String) {
-    // Find the tabstop number
-    let mut chars = content.chars().peekable();
-    let mut num_str = String::new();
-
-    while let Some(&c) = chars.peek() {
-        if c.is_ascii_digit() {
-            num_str.push(chars.next().unwrap());
-        } else {
-            break;
+fn parse_var(chars: &[char], pos: &mut usize) -> String {
+    let start = *pos;
+    while chars
+        .get(*pos)
+        .is_some_and(|&c| c == '_' || c.is_ascii_alphanumeric())
+    {
+        *pos += 1;
+    }
+    chars[start..*pos].iter().collect()
+}
+
+fn expect(chars: &[char], pos: &mut usize, wanted: char) -> Option<()> {
+    (chars.get(*pos) == Some(&wanted)).then(|| *pos += 1)
+}
+
+/// Options of `${n|a,b|}`, after the opening `|`, consuming the closing `|}`.
+fn parse_choice(chars: &[char], pos: &mut usize) -> Option<Vec<String>> {
+    let mut options = Vec::new();
+    let mut option = String::new();
+    loop {
+        match *chars.get(*pos)? {
+            '\\' => {
+                let next = *chars.get(*pos + 1)?;
+                if matches!(next, '$' | '\\' | '}' | ',' | '|') {
+                    option.push(next);
+                    *pos += 2;
+                } else {
+                    option.push('\\');
+                    *pos += 1;
+                }
+            }
+            ',' => {
+                options.push(std::mem::take(&mut option));
+                *pos += 1;
+            }
+            '|' if chars.get(*pos + 1) == Some(&'}') => {
+                options.push(option);
+                *pos += 2;
+                return Some(options);
+            }
+            c => {
+                option.push(c);
+                *pos += 1;
+            }
         }
     }
+}
 
-    let tabstop = num_str.parse::<u32>().ok();
-
-    // Check what follows
-    match chars.peek() {
-        Some(':') => {
-            // Default text: ${n:default}
-            chars.next(); // consume ':'
-            let default: String = chars.collect();
-            // Recursively expand nested snippets in default
-            let expanded = expand_snippet(&default);
-            (tabstop, expanded.text)
+/// Skip a `/regex/format/options}` transform, consuming the closing `}`.
+fn skip_transform(chars: &[char], pos: &mut usize) -> Option<()> {
+    let mut slashes = 0;
+    loop {
+        match *chars.get(*pos)? {
+            '\\' => *pos += 2,
+            '/' => {
+                slashes += 1;
+                *pos += 1;
+            }
+            '}' if slashes >= 3 => {
+                *pos += 1;
+                return Some(());
+            }
+            _ => *pos += 1,
         }
-        Some('|') => {
-            // Choices: ${n|choice1,choice2|}
-            chars.next(); // consume '|'
-            let choices: String = chars.collect();
-            // Remove trailing '|' if present
-            let choices = choices.trim_end_matches('|');
-            // Use first choice
-            let first_choice = choices.split(',').next().unwrap_or("");
-            (tabstop, first_choice.to_string())
+    }
+}
+
+/// One occurrence of a tabstop in the rendered text.
+#[derive(Debug, Default)]
+struct Stop {
+    index: u32,
+    range: Range<usize>,
+    /// A placeholder or choice, whose text mirrors copy.
+    is_source: bool,
+}
+
+#[derive(Debug, Default)]
+struct Render {
+    text: String,
+    stops: Vec<Stop>,
+}
+
+impl Render {
+    fn nodes(
+        &mut self,
+        nodes: &[Node],
+        mirrors: &HashMap<u32, String>,
+        variable: &impl Fn(&str) -> Option<String>,
+    ) {
+        for node in nodes {
+            match node {
+                Node::Text(text) => self.text.push_str(text),
+                Node::Tabstop { index, placeholder } => {
+                    let start = self.text.len();
+                    match placeholder {
+                        Some(children) => self.nodes(children, mirrors, variable),
+                        None => {
+                            if let Some(text) = mirrors.get(index) {
+                                self.text.push_str(text);
+                            }
+                        }
+                    }
+                    self.stops.push(Stop {
+                        index: *index,
+                        range: start..self.text.len(),
+                        is_source: placeholder.is_some(),
+                    });
+                }
+                Node::Choice { index, options } => {
+                    let start = self.text.len();
+                    self.text
+                        .push_str(options.first().map(String::as_str).unwrap_or(""));
+                    self.stops.push(Stop {
+                        index: *index,
+                        range: start..self.text.len(),
+                        is_source: true,
+                    });
+                }
+                Node::Variable { name, default } => match variable(name) {
+                    Some(value) => self.text.push_str(&value),
+                    None => {
+                        if let Some(children) = default {
+                            self.nodes(children, mirrors, variable);
+                        }
+                    }
+                },
+            }
         }
-        _ => {
-            // Just a tabstop number, no default
-            (tabstop, String::new())
+    }
+
+    /// Text of each tabstop's first placeholder, for its mirrors to copy.
+    fn sources(&self) -> HashMap<u32, String> {
+        let mut sources = HashMap::new();
+        // Nested placeholders are pushed before the one enclosing them, so
+        // "first" means the one that starts first.
+        let mut stops: Vec<&Stop> = self.stops.iter().filter(|s| s.is_source).collect();
+        stops.sort_by_key(|s| s.range.start);
+        for stop in stops {
+            sources
+                .entry(stop.index)
+                .or_insert_with(|| self.text[stop.range.clone()].to_string());
         }
+        sources
     }
 }
 
@@ -231,6 +468,27 @@ mod tests {
         let result = expand_snippet("fn ${1:name}(${2:args}) { $0 }");
         assert_eq!(result.text, "fn name(args) {  }");
         assert_eq!(result.cursor_offset, 16); // where $0 was
+        assert_eq!(result.tabstops, vec![vec![3..7], vec![8..12]]);
+    }
+
+    #[test]
+    fn test_tabstop_order_follows_numbers() {
+        let result = expand_snippet("${2:b} ${1:a} $3");
+        assert_eq!(result.text, "b a ");
+        assert_eq!(result.tabstops, vec![vec![2..3], vec![0..1], vec![4..4]]);
+    }
+
+    #[test]
+    fn test_mirrored_placeholders() {
+        let result = expand_snippet("<${1:div}>$0</$1>");
+        assert_eq!(result.text, "<div></div>");
+        assert_eq!(result.cursor_offset, 5);
+        assert_eq!(result.tabstops, vec![vec![1..4, 7..10]]);
+
+        // A mirror before its placeholder, and a placeholder mirroring another.
+        let result = expand_snippet("$1 = ${1:x}; ${2:${1}2}");
+        assert_eq!(result.text, "x = x; x2");
+        assert_eq!(result.tabstops, vec![vec![0..1, 4..5, 7..8], vec![7..9]]);
     }
 
     #[test]
@@ -238,6 +496,15 @@ mod tests {
         let result = expand_snippet("${1|public,private,protected|}");
         assert_eq!(result.text, "public");
         assert_eq!(result.cursor_offset, 6);
+        assert_eq!(result.tabstops, vec![vec![0..6]]);
+    }
+
+    #[test]
+    fn test_variables() {
+        let vars = |name: &str| (name == "TM_FILENAME").then(|| "main.rs".to_string());
+        let result = expand_snippet_with("// $TM_FILENAME ${UNKNOWN:none} $HOME_DIR", vars);
+        assert_eq!(result.text, "// main.rs none ");
+        assert!(result.tabstops.is_empty());
     }
 
     #[test]
@@ -247,11 +514,19 @@ mod tests {
         assert_eq!(result.cursor_offset, 10);
     }
 
+    #[test]
+    fn test_unclosed_placeholder_is_text() {
+        let result = expand_snippet("a ${1:b");
+        assert_eq!(result.text, "a ${1:b");
+        assert!(result.tabstops.is_empty());
+    }
+
     #[test]
     fn test_nested_placeholder() {
         let result = expand_snippet("${1:foo${2:bar}}");
         assert_eq!(result.text, "foobar");
         assert_eq!(result.cursor_offset, 6);
+        assert_eq!(result.tabstops, vec![vec![0..6], vec![3..6]]);
     }
 
     #[test]
@@ -0,0 +1,384 @@
+//! Reversible text escapes: Unicode escape sequences, HTML entities and
+//! URL percent-encoding.
+//!
+//! Each pair is lenient on the decode side: sequences that don't parse are
+//! left verbatim rather than failing the whole conversion, so decoding a
+//! selection that mixes escapes with ordinary text does the obvious thing.
+
+/// Replace every non-ASCII character with a `\u{XXXX}` escape.
+pub fn escape_unicode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if ch.is_ascii() {
+            out.push(ch);
+        } else {
+            out.push_str(&format!("\\u{{{:X}}}", ch as u32));
+        }
+    }
+    out
+}
+
+/// Replace `\u{X…}`, `\uXXXX` (including UTF-16 surrogate pairs) and
+/// `\UXXXXXXXX` escapes with the characters they name.
+///
+/// An escaped backslash (`\\u0041`) is kept literal.
+pub fn unescape_unicode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find('\\') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        if let Some((ch, len)) = parse_unicode_escape(rest) {
+            out.push(ch);
+            rest = &rest[len..];
+        } else {
+            let len = if rest[1..].starts_with('\\') { 2 } else { 1 };
+            out.push_str(&rest[..len]);
+            rest = &rest[len..];
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Parse one escape at the start of `s` (which begins with `\`), returning
+/// the character and the escape's length in bytes.
+fn parse_unicode_escape(s: &str) -> Option<(char, usize)> {
+    let body = s.strip_prefix('\\')?;
+    if let Some(braced) = body.strip_prefix("u{") {
+        let end = braced.find('}')?;
+        if end > 6 {
+            return None;
+        }
+        let ch = char::from_u32(parse_hex(&braced[..end])?)?;
+        return Some((ch, 3 + end + 1));
+    }
+    if let Some(hex) = body.strip_prefix('U') {
+        let ch = char::from_u32(parse_hex(hex.get(..8)?)?)?;
+        return Some((ch, 10));
+    }
+    let unit = parse_hex(body.strip_prefix('u')?.get(..4)?)?;
+    if (0xD800..0xDC00).contains(&unit) {
+        let low = s[6..]
+            .strip_prefix("\\u")
+            .and_then(|low| low.get(..4))
+            .and_then(parse_hex)
+            .filter(|low| (0xDC00..0xE000).contains(low))?;
+        let ch = char::from_u32(0x10000 + ((unit - 0xD800) << 10) + (low - 0xDC00))?;
+        return Some((ch, 12));
+    }
+    Some((char::from_u32(unit)?, 6))
+}
+
+fn parse_hex(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
+}
+
+/// Escape HTML special characters and every non-ASCII character, using a
+/// named entity where one exists and `&#xXXXX;` otherwise.
+pub fn encode_html_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            ch if ch.is_ascii() => out.push(ch),
+            ch => match HTML_ENTITIES.iter().find(|(_, c)| *c == ch) {
+                Some((name, _)) => {
+                    out.push_str(&format!("&{name};"));
+                }
+                None => {
+                    out.push_str(&format!("&#x{:X};", ch as u32));
+                }
+            },
+        }
+    }
+    out
+}
+
+/// Replace named (`&amp;`), decimal (`&#39;`) and hex (`&#x1F600;`)
+/// entities with the characters they stand for.
+pub fn decode_html_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find('&') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        match parse_html_entity(rest) {
+            Some((ch, len)) => {
+                out.push(ch);
+                rest = &rest[len..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Parse one entity at the start of `s` (which begins with `&`), returning
+/// the character and the entity's length in bytes.
+fn parse_html_entity(s: &str) -> Option<(char, usize)> {
+    // The longest entity we understand is `&#x10FFFF;`.
+    let (semi, _) = s[1..].char_indices().take(10).find(|(_, c)| *c == ';')?;
+    let end = semi + 1;
+    let body = &s[1..end];
+    let ch = if let Some(hex) = body.strip_prefix("#x").or_else(|| body.strip_prefix("#X")) {
+        char::from_u32(parse_hex(hex)?)?
+    } else if let Some(dec) = body.strip_prefix('#') {
+        if dec.is_empty() || !dec.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        char::from_u32(dec.parse().ok()?)?
+    } else {
+        match body {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            name => HTML_ENTITIES.iter().find(|(n, _)| *n == name)?.1,
+        }
+    };
+    Some((ch, end + 1))
+}
+
+/// Percent-encode every byte outside RFC 3986's unreserved set
+/// (`A-Z a-z 0-9 - . _ ~`).
+pub fn url_encode(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    out
+}
+
+/// Decode `%XX` sequences. `+` is left alone (it only means space in form
+/// bodies). Returns `None` when the decoded bytes aren't valid UTF-8.
+pub fn url_decode(text: &str) -> Option<String> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let decoded = (bytes[i] == b'%')
+            .then(|| text.get(i + 1..i + 3))
+            .flatten()
+            .and_then(parse_hex);
+        match decoded {
+            Some(byte) => {
+                out.push(byte as u8);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8(out).ok()
+}
+
+/// Named entities beyond the five XML ones: Latin-1, the Latin Extended-A
+/// letters HTML 4 names, and common typographic punctuation.
+const HTML_ENTITIES: &[(&str, char)] = &[
+    ("nbsp", '\u{A0}'),
+    ("iexcl", '\u{A1}'),
+    ("cent", '\u{A2}'),
+    ("pound", '\u{A3}'),
+    ("curren", '\u{A4}'),
+    ("yen", '\u{A5}'),
+    ("brvbar", '\u{A6}'),
+    ("sect", '\u{A7}'),
+    ("uml", '\u{A8}'),
+    ("copy", '\u{A9}'),
+    ("ordf", '\u{AA}'),
+    ("laquo", '\u{AB}'),
+    ("not", '\u{AC}'),
+    ("shy", '\u{AD}'),
+    ("reg", '\u{AE}'),
+    ("macr", '\u{AF}'),
+    ("deg", '\u{B0}'),
+    ("plusmn", '\u{B1}'),
+    ("sup2", '\u{B2}'),
+    ("sup3", '\u{B3}'),
+    ("acute", '\u{B4}'),
+    ("micro", '\u{B5}'),
+    ("para", '\u{B6}'),
+    ("middot", '\u{B7}'),
+    ("cedil", '\u{B8}'),
+    ("sup1", '\u{B9}'),
+    ("ordm", '\u{BA}'),
+    ("raquo", '\u{BB}'),
+    ("frac14", '\u{BC}'),
+    ("frac12", '\u{BD}'),
+    ("frac34", '\u{BE}'),
+    ("iquest", '\u{BF}'),
+    ("Agrave", '\u{C0}'),
+    ("Aacute", '\u{C1}'),
+    ("Acirc", '\u{C2}'),
+    ("Atilde", '\u{C3}'),
+    ("Auml", '\u{C4}'),
+    ("Aring", '\u{C5}'),
+    ("AElig", '\u{C6}'),
+    ("Ccedil", '\u{C7}'),
+    ("Egrave", '\u{C8}'),
+    ("Eacute", '\u{C9}'),
+    ("Ecirc", '\u{CA}'),
+    ("Euml", '\u{CB}'),
+    ("Igrave", '\u{CC}'),
+    ("Iacute", '\u{CD}'),
+    ("Icirc", '\u{CE}'),
+    ("Iuml", '\u{CF}'),
+    ("ETH", '\u{D0}'),
+    ("Ntilde", '\u{D1}'),
+    ("Ograve", '\u{D2}'),
+    ("Oacute", '\u{D3}'),
+    ("Ocirc", '\u{D4}'),
+    ("Otilde", '\u{D5}'),
+    ("Ouml", '\u{D6}'),
+    ("times", '\u{D7}'),
+    ("Oslash", '\u{D8}'),
+    ("Ugrave", '\u{D9}'),
+    ("Uacute", '\u{DA}'),
+    ("Ucirc", '\u{DB}'),
+    ("Uuml", '\u{DC}'),
+    ("Yacute", '\u{DD}'),
+    ("THORN", '\u{DE}'),
+    ("szlig", '\u{DF}'),
+    ("agrave", '\u{E0}'),
+    ("aacute", '\u{E1}'),
+    ("acirc", '\u{E2}'),
+    ("atilde", '\u{E3}'),
+    ("auml", '\u{E4}'),
+    ("aring", '\u{E5}'),
+    ("aelig", '\u{E6}'),
+    ("ccedil", '\u{E7}'),
+    ("egrave", '\u{E8}'),
+    ("eacute", '\u{E9}'),
+    ("ecirc", '\u{EA}'),
+    ("euml", '\u{EB}'),
+    ("igrave", '\u{EC}'),
+    ("iacute", '\u{ED}'),
+    ("icirc", '\u{EE}'),
+    ("iuml", '\u{EF}'),
+    ("eth", '\u{F0}'),
+    ("ntilde", '\u{F1}'),
+    ("ograve", '\u{F2}'),
+    ("oacute", '\u{F3}'),
+    ("ocirc", '\u{F4}'),
+    ("otilde", '\u{F5}'),
+    ("ouml", '\u{F6}'),
+    ("divide", '\u{F7}'),
+    ("oslash", '\u{F8}'),
+    ("ugrave", '\u{F9}'),
+    ("uacute", '\u{FA}'),
+    ("ucirc", '\u{FB}'),
+    ("uuml", '\u{FC}'),
+    ("yacute", '\u{FD}'),
+    ("thorn", '\u{FE}'),
+    ("yuml", '\u{FF}'),
+    ("OElig", '\u{152}'),
+    ("oelig", '\u{153}'),
+    ("Scaron", '\u{160}'),
+    ("scaron", '\u{161}'),
+    ("Yuml", '\u{178}'),
+    ("fnof", '\u{192}'),
+    ("circ", '\u{2C6}'),
+    ("tilde", '\u{2DC}'),
+    ("ensp", '\u{2002}'),
+    ("emsp", '\u{2003}'),
+    ("thinsp", '\u{2009}'),
+    ("zwnj", '\u{200C}'),
+    ("zwj", '\u{200D}'),
+    ("lrm", '\u{200E}'),
+    ("rlm", '\u{200F}'),
+    ("ndash", '\u{2013}'),
+    ("mdash", '\u{2014}'),
+    ("lsquo", '\u{2018}'),
+    ("rsquo", '\u{2019}'),
+    ("sbquo", '\u{201A}'),
+    ("ldquo", '\u{201C}'),
+    ("rdquo", '\u{201D}'),
+    ("bdquo", '\u{201E}'),
+    ("dagger", '\u{2020}'),
+    ("Dagger", '\u{2021}'),
+    ("bull", '\u{2022}'),
+    ("hellip", '\u{2026}'),
+    ("permil", '\u{2030}'),
+    ("prime", '\u{2032}'),
+    ("Prime", '\u{2033}'),
+    ("lsaquo", '\u{2039}'),
+    ("rsaquo", '\u{203A}'),
+    ("euro", '\u{20AC}'),
+    ("trade", '\u{2122}'),
+    ("larr", '\u{2190}'),
+    ("uarr", '\u{2191}'),
+    ("rarr", '\u{2192}'),
+    ("darr", '\u{2193}'),
+    ("harr", '\u{2194}'),
+    ("minus", '\u{2212}'),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unicode_escape_round_trip() {
+        assert_eq!(escape_unicode("hi 😀 é"), "hi \\u{1F600} \\u{E9}");
+        assert_eq!(unescape_unicode("hi \\u{1F600} \\u{E9}"), "hi 😀 é");
+    }
+
+    #[test]
+    fn test_unescape_unicode_forms() {
+        assert_eq!(unescape_unicode("\\u00e9\\U0001F600"), "é😀");
+        // UTF-16 surrogate pair, as JSON and JavaScript write astral characters.
+        assert_eq!(unescape_unicode("\\uD83D\\uDE00"), "😀");
+        // Malformed, lone-surrogate and escaped-backslash sequences stay put.
+        assert_eq!(
+            unescape_unicode("\\u{zz} \\uD83D \\\\u0041 \\n"),
+            "\\u{zz} \\uD83D \\\\u0041 \\n"
+        );
+    }
+
+    #[test]
+    fn test_html_entities_round_trip() {
+        let text = "<a title=\"Tom & Jerry's\">café — 😀</a>";
+        let encoded = encode_html_entities(text);
+        assert_eq!(
+            encoded,
+            "&lt;a title=&quot;Tom &amp; Jerry&#39;s&quot;&gt;caf&eacute; &mdash; &#x1F600;&lt;/a&gt;"
+        );
+        assert_eq!(decode_html_entities(&encoded), text);
+    }
+
+    #[test]
+    fn test_decode_html_entities_leaves_unknown() {
+        assert_eq!(
+            decode_html_entities("&#65;&#x42; &bogus; a & b &"),
+            "AB &bogus; a & b &"
+        );
+    }
+
+    #[test]
+    fn test_url_encoding_round_trip() {
+        let text = "a b/c?d=é&e~";
+        assert_eq!(url_encode(text), "a%20b%2Fc%3Fd%3D%C3%A9%26e~");
+        assert_eq!(url_decode(&url_encode(text)).as_deref(), Some(text));
+        assert_eq!(url_decode("100% a+b").as_deref(), Some("100% a+b"));
+        assert_eq!(url_decode("%FF"), None);
+    }
+}
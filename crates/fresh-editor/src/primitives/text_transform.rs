@@ -0,0 +1,211 @@
+//! Case and line transforms for the selection.
+//!
+//! Case conversions work line by line: each line's leading and trailing
+//! whitespace is kept, and the text between is split into words at
+//! non-alphanumeric characters and at case changes (`fooBar`, `HTTPServer`),
+//! then rejoined in the target style. Title case is the exception: it is
+//! meant for prose, so it only recases words and keeps every separator.
+//!
+//! Line transforms take the selection's lines (without terminators) and
+//! return the new lines; the caller rejoins them.
+
+/// `Hello World-Wide web` -> `Hello World-Wide Web`.
+pub fn to_title_case(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut at_word_start = true;
+    for ch in text.chars() {
+        if ch.is_alphanumeric() {
+            if at_word_start {
+                out.extend(ch.to_uppercase());
+            } else {
+                out.extend(ch.to_lowercase());
+            }
+            at_word_start = false;
+        } else {
+            // Apostrophes stay inside words: "don't" -> "Don't".
+            at_word_start = ch != '\'';
+            out.push(ch);
+        }
+    }
+    out
+}
+
+/// `foo_bar baz` -> `fooBarBaz`.
+pub fn to_camel_case(text: &str) -> String {
+    map_lines(text, |words| {
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                if i == 0 {
+                    word.to_lowercase()
+                } else {
+                    capitalize(word)
+                }
+            })
+            .collect()
+    })
+}
+
+/// `fooBar baz` -> `foo_bar_baz`.
+pub fn to_snake_case(text: &str) -> String {
+    map_lines(text, |words| join_lowercase(words, "_"))
+}
+
+/// `fooBar baz` -> `foo-bar-baz`.
+pub fn to_kebab_case(text: &str) -> String {
+    map_lines(text, |words| join_lowercase(words, "-"))
+}
+
+/// Sort order for [`sort_lines`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+    /// Ascending, keeping one copy of each line.
+    Unique,
+}
+
+pub fn sort_lines<'a>(lines: &[&'a str], order: SortOrder) -> Vec<&'a str> {
+    let mut sorted = lines.to_vec();
+    sorted.sort_unstable();
+    match order {
+        SortOrder::Ascending => {}
+        SortOrder::Descending => sorted.reverse(),
+        SortOrder::Unique => sorted.dedup(),
+    }
+    sorted
+}
+
+pub fn reverse_lines<'a>(lines: &[&'a str]) -> Vec<&'a str> {
+    lines.iter().rev().copied().collect()
+}
+
+/// Prefix each line with `N. `, counting up from `first`. Numbers are
+/// right-aligned so the text after them lines up.
+pub fn number_lines(lines: &[&str], first: usize) -> Vec<String> {
+    let last = first + lines.len().saturating_sub(1);
+    let width = last.to_string().len();
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| format!("{:>width$}. {}", first + i, line))
+        .collect()
+}
+
+/// Apply `join` to the words of every line, keeping each line's leading and
+/// trailing whitespace and its terminator.
+fn map_lines(text: &str, join: impl Fn(&[&str]) -> String) -> String {
+    let mut out = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        let body = content.trim();
+        if body.is_empty() {
+            out.push_str(line);
+            continue;
+        }
+        let leading = content.len() - content.trim_start().len();
+        out.push_str(&content[..leading]);
+        out.push_str(&join(&split_words(body)));
+        out.push_str(&line[leading + body.len()..]);
+    }
+    out
+}
+
+/// Split an identifier or phrase into words.
+fn split_words(text: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    for part in text.split(|c: char| !c.is_alphanumeric()) {
+        let chars: Vec<(usize, char)> = part.char_indices().collect();
+        let mut start = 0;
+        for i in 1..chars.len() {
+            let (offset, ch) = chars[i];
+            let prev = chars[i - 1].1;
+            let next_is_lower = chars.get(i + 1).is_some_and(|(_, c)| c.is_lowercase());
+            // fooBar, foo1Bar | HTTPServer: the last capital starts a word.
+            let boundary = ch.is_uppercase()
+                && (prev.is_lowercase()
+                    || prev.is_numeric()
+                    || (prev.is_uppercase() && next_is_lower));
+            if boundary {
+                words.push(&part[start..offset]);
+                start = offset;
+            }
+        }
+        if start < part.len() {
+            words.push(&part[start..]);
+        }
+    }
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+fn join_lowercase(words: &[&str], separator: &str) -> String {
+    words
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_words_at_case_changes_and_separators() {
+        assert_eq!(split_words("fooBar"), vec!["foo", "Bar"]);
+        assert_eq!(split_words("HTTPServer_url"), vec!["HTTP", "Server", "url"]);
+        assert_eq!(split_words("utf8Decode"), vec!["utf8", "Decode"]);
+        assert_eq!(
+            split_words("some-kebab text"),
+            vec!["some", "kebab", "text"]
+        );
+    }
+
+    #[test]
+    fn converts_identifier_styles() {
+        assert_eq!(to_camel_case("parse_HTTP request"), "parseHttpRequest");
+        assert_eq!(to_snake_case("parseHTTPRequest"), "parse_http_request");
+        assert_eq!(to_kebab_case("ParseHttpRequest"), "parse-http-request");
+        // Lines are converted separately and keep their indentation.
+        assert_eq!(
+            to_snake_case("  fooBar\r\n\n\tbazQux "),
+            "  foo_bar\r\n\n\tbaz_qux "
+        );
+    }
+
+    #[test]
+    fn title_case_keeps_separators() {
+        assert_eq!(
+            to_title_case("the QUICK brown-fox don't"),
+            "The Quick Brown-Fox Don't"
+        );
+    }
+
+    #[test]
+    fn line_transforms() {
+        let lines = ["b", "a", "c", "a"];
+        assert_eq!(
+            sort_lines(&lines, SortOrder::Descending),
+            vec!["c", "b", "a", "a"]
+        );
+        assert_eq!(sort_lines(&lines, SortOrder::Unique), vec!["a", "b", "c"]);
+        assert_eq!(reverse_lines(&lines), vec!["a", "c", "a", "b"]);
+
+        let lines = vec!["x"; 10];
+        let numbered = number_lines(&lines, 1);
+        assert_eq!(numbered[0], " 1. x");
+        assert_eq!(numbered[9], "10. x");
+    }
+}
@@ -89,6 +89,26 @@ pub fn spawn_server_detached(session_name: Option<&str>, ssh_url: Option<&str>)
     Ok(child.id())
 }
 
+/// Spawn the headless LSP proxy (`--lsp-proxy`) as a background process.
+///
+/// It gets its own process group so that signals aimed at the relay that
+/// started it (and that relay's editor) don't take down servers other
+/// editors are using. Returns the PID of the proxy.
+pub fn spawn_lsp_proxy_detached() -> io::Result<u32> {
+    use std::os::unix::process::CommandExt;
+
+    let exe = std::env::current_exe()?;
+    let child = std::process::Command::new(&exe)
+        .arg("--lsp-proxy")
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .process_group(0)
+        .spawn()?;
+
+    Ok(child.id())
+}
+
 /// Check if a process with the given PID is still running
 pub fn is_process_running(pid: u32) -> bool {
     // Send signal 0 to check if process exists
@@ -67,6 +67,22 @@ pub fn spawn_server_detached(session_name: Option<&str>, ssh_url: Option<&str>)
     Ok(child.id())
 }
 
+/// Spawn the headless LSP proxy (`--lsp-proxy`) as a detached background
+/// process, independent of the relay that started it. Returns the PID of
+/// the proxy.
+pub fn spawn_lsp_proxy_detached() -> io::Result<u32> {
+    let exe = std::env::current_exe()?;
+
+    let child = std::process::Command::new(&exe)
+        .arg("--lsp-proxy")
+        .creation_flags(DETACHED_PROCESS | CREATE_NEW_PROCESS_GROUP)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()?;
+    Ok(child.id())
+}
+
 /// Check if a process with the given PID is still running
 pub fn is_process_running(pid: u32) -> bool {
     unsafe {
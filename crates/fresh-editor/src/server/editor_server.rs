@@ -296,8 +296,11 @@ impl EditorServer {
                     let cursor_style = self
                         .editor
                         .as_ref()
-                        .map(|e| e.config().editor.cursor_style)
-                        .unwrap_or(self.config.editor_config.editor.cursor_style);
+                        .map(|e| e.cursor_style())
+                        .unwrap_or_else(|| {
+                            let editor = &self.config.editor_config.editor;
+                            editor.cursor_style_for(crate::config::CursorContext::Editing)
+                        });
                     match self.handle_new_connection(conn, next_client_id, cursor_style) {
                         Ok(client) => {
                             tracing::info!("Client {} connected", client.id);
@@ -1244,6 +1247,7 @@ impl EditorServer {
         let Some(ref mut editor) = self.editor else {
             return Ok(false);
         };
+        editor.mark_input_received();
 
         // The interactive wave animation runs until the user does anything:
         // the first key press or mouse activity dismisses it and is consumed
@@ -1273,7 +1277,7 @@ impl EditorServer {
                 Ok(true)
             }
             Event::Paste(text) => {
-                editor.paste_text(text);
+                editor.handle_bracketed_paste(text);
                 Ok(true)
             }
             _ => Ok(false),
@@ -1305,6 +1309,9 @@ impl EditorServer {
         }
 
         // Take any pending escape sequences (e.g., cursor style changes)
+        if let Some(style) = editor.take_cursor_style_change() {
+            editor.queue_escape_sequences(style.to_escape_sequence());
+        }
         let pending_sequences = editor.take_pending_escape_sequences();
 
         // Render to capture backend
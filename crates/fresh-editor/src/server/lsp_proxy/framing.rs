@@ -0,0 +1,81 @@
+//! LSP base-protocol framing (`Content-Length` headers around JSON bodies).
+
+use serde_json::Value;
+
+/// Accumulates bytes from a stream and splits them into messages.
+#[derive(Debug, Default)]
+pub struct Framer {
+    buf: Vec<u8>,
+}
+
+impl Framer {
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// The next complete message, if one has arrived. Bodies that are not
+    /// valid JSON are logged and skipped.
+    pub fn next_message(&mut self) -> Option<Value> {
+        loop {
+            let header_end = self.buf.windows(4).position(|w| w == b"\r\n\r\n")?;
+            let headers = String::from_utf8_lossy(&self.buf[..header_end]);
+            let length = headers.lines().find_map(|line| {
+                let (name, value) = line.split_once(':')?;
+                if name.trim().eq_ignore_ascii_case("content-length") {
+                    value.trim().parse::<usize>().ok()
+                } else {
+                    None
+                }
+            });
+            let body_start = header_end + 4;
+            let Some(length) = length else {
+                tracing::warn!("LSP proxy: dropping message without Content-Length");
+                self.buf.drain(..body_start);
+                continue;
+            };
+            if self.buf.len() < body_start + length {
+                return None;
+            }
+            let body: Vec<u8> = self
+                .buf
+                .drain(..body_start + length)
+                .skip(body_start)
+                .collect();
+            match serde_json::from_slice(&body) {
+                Ok(message) => return Some(message),
+                Err(e) => tracing::warn!("LSP proxy: dropping malformed message: {}", e),
+            }
+        }
+    }
+}
+
+/// `message` with its `Content-Length` header.
+pub fn frame(message: &Value) -> Vec<u8> {
+    let body = message.to_string();
+    let mut bytes = format!("Content-Length: {}\r\n\r\n", body.len()).into_bytes();
+    bytes.extend_from_slice(body.as_bytes());
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn splits_messages_across_reads() {
+        let first = json!({"jsonrpc": "2.0", "method": "initialized", "params": {}});
+        let second = json!({"jsonrpc": "2.0", "id": 1, "result": "ü"});
+        let mut bytes = frame(&first);
+        bytes.extend(frame(&second));
+
+        let mut framer = Framer::default();
+        let (head, tail) = bytes.split_at(30);
+        framer.push(head);
+        assert_eq!(framer.next_message(), None);
+        framer.push(tail);
+        assert_eq!(framer.next_message(), Some(first));
+        assert_eq!(framer.next_message(), Some(second));
+        assert_eq!(framer.next_message(), None);
+    }
+}
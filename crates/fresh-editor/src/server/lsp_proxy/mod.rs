@@ -0,0 +1,48 @@
+//! Headless LSP proxy: one language server per workspace, shared by every
+//! Fresh instance on the machine.
+//!
+//! With `lsp_shared_servers` enabled the editor doesn't start a language
+//! server itself. It starts `fresh --lsp-proxy-client -- <command> <args>`,
+//! a relay that pipes its stdio to the proxy (`fresh --lsp-proxy`), starting
+//! the proxy first if none is running. The proxy keys servers on command,
+//! arguments and the workspace root from `initialize`, so a second editor
+//! on the same project attaches to the running rust-analyzer instead of
+//! starting another. [`mux`] describes how the editors are kept out of each
+//! other's way.
+//!
+//! A server with no editors left is kept warm for a few minutes so that
+//! reopening the project is instant; the proxy exits once it has no servers.
+
+mod framing;
+pub mod mux;
+mod relay;
+mod serve;
+
+pub use relay::run_relay;
+pub use serve::run_proxy;
+
+use crate::server::ipc::SocketPaths;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::time::Duration;
+
+/// How often the relay and the proxy poll their sockets when idle.
+const POLL_INTERVAL: Duration = Duration::from_millis(2);
+
+/// What a relay sends the proxy on connecting, as one JSON line on the
+/// control socket.
+#[derive(Debug, Serialize, Deserialize)]
+struct ProxyHello {
+    command: String,
+    args: Vec<String>,
+    /// The relay's environment. A new server is started with the
+    /// environment of the editor that needed it first.
+    env: Vec<(String, String)>,
+}
+
+/// The proxy's sockets, in their own directory so `fresh daemon list`
+/// doesn't show the proxy as an editor session.
+fn proxy_socket_paths() -> io::Result<SocketPaths> {
+    let dir = SocketPaths::socket_directory()?.join("lsp-proxy");
+    Ok(SocketPaths::for_session_name_in_dir("proxy", &dir))
+}
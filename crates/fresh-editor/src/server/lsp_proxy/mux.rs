@@ -0,0 +1,861 @@
+//! Routing between several editors and one language server.
+//!
+//! Every editor believes it has the server to itself, so the multiplexer
+//! rewrites whatever would collide:
+//!
+//! - Request ids are renumbered on the way in and restored on the way out.
+//! - Only the first `initialize` reaches the server; later editors get the
+//!   cached result. `shutdown` is answered locally and `exit` just detaches
+//!   the editor.
+//! - Notifications from the server go to every editor. Requests from the
+//!   server go to the longest-connected editor, except progress-token
+//!   creation, which is answered locally. Capability registrations are
+//!   replayed to editors that join later.
+//! - Documents are reference-counted. Each editor's copy of an open file is
+//!   mirrored here, and when a different editor starts editing or querying
+//!   a file whose text differs from what the server has, the server gets
+//!   that editor's full text first.
+//! - Document versions are the proxy's own on the server side, so
+//!   `publishDiagnostics` is translated back: each editor gets the
+//!   diagnostics only if they describe its copy, tagged with its own
+//!   version.
+
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+/// Server versions remembered per document for translating diagnostics.
+const VERSION_HISTORY: usize = 32;
+
+pub type ClientId = u64;
+
+#[derive(Debug, PartialEq)]
+pub enum Route {
+    ToServer(Value),
+    ToClient(ClientId, Value),
+}
+
+enum Init {
+    NotSent,
+    /// Forwarded under `id`; the editors waiting for the answer.
+    Pending {
+        id: i64,
+        waiting: Vec<(ClientId, Value)>,
+    },
+    Done(Value),
+}
+
+struct Document {
+    /// Each editor's copy of the text.
+    texts: BTreeMap<ClientId, String>,
+    /// Each editor's own version of its copy.
+    versions: BTreeMap<ClientId, i64>,
+    /// The editor whose copy the server has.
+    owner: ClientId,
+    /// Version of the server's copy.
+    version: i64,
+    /// Recent server versions, oldest first, with the editor and editor
+    /// version each was taken from.
+    history: VecDeque<(i64, ClientId, i64)>,
+}
+
+impl Document {
+    /// The server's copy is now `client`'s at a new version.
+    fn advance(&mut self, client: ClientId) -> i64 {
+        self.version += 1;
+        self.owner = client;
+        self.record();
+        self.version
+    }
+
+    fn record(&mut self) {
+        let client_version = self.versions.get(&self.owner).copied().unwrap_or(0);
+        if self.history.len() == VERSION_HISTORY {
+            self.history.pop_front();
+        }
+        self.history
+            .push_back((self.version, self.owner, client_version));
+    }
+}
+
+pub struct Multiplexer {
+    clients: BTreeSet<ClientId>,
+    next_id: i64,
+    /// Requests forwarded to the server, by the id they were given.
+    pending: HashMap<i64, (ClientId, Value)>,
+    /// Server requests waiting on an editor, by the request id's JSON.
+    server_requests: HashMap<String, (ClientId, Value)>,
+    init: Init,
+    initialized_sent: bool,
+    registrations: Vec<Value>,
+    documents: HashMap<String, Document>,
+}
+
+impl Default for Multiplexer {
+    fn default() -> Self {
+        Self {
+            clients: BTreeSet::new(),
+            next_id: 1,
+            pending: HashMap::new(),
+            server_requests: HashMap::new(),
+            init: Init::NotSent,
+            initialized_sent: false,
+            registrations: Vec::new(),
+            documents: HashMap::new(),
+        }
+    }
+}
+
+impl Multiplexer {
+    pub fn join(&mut self, client: ClientId) {
+        self.clients.insert(client);
+    }
+
+    pub fn has_client(&self, client: ClientId) -> bool {
+        self.clients.contains(&client)
+    }
+
+    /// Route a message an editor sent.
+    pub fn from_client(&mut self, client: ClientId, mut message: Value) -> Vec<Route> {
+        if !self.has_client(client) {
+            return Vec::new();
+        }
+        let method = message
+            .get("method")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let id = message.get("id").cloned();
+        match (method.as_deref(), id) {
+            (None, Some(id)) => self.answer_server_request(client, id, message),
+            (None, None) => Vec::new(),
+            (Some("initialize"), Some(id)) => self.initialize(client, id, message),
+            (Some("initialized"), None) => self.initialized(client, message),
+            (Some("shutdown"), Some(id)) => {
+                vec![Route::ToClient(client, response(id, Value::Null))]
+            }
+            (Some("exit"), None) => self.leave(client),
+            (Some("$/cancelRequest"), None) => {
+                let Some(original) = message.pointer("/params/id").cloned() else {
+                    return Vec::new();
+                };
+                let Some(&id) = self
+                    .pending
+                    .iter()
+                    .find(|(_, owner)| **owner == (client, original.clone()))
+                    .map(|(id, _)| id)
+                else {
+                    return Vec::new();
+                };
+                message["params"]["id"] = json!(id);
+                vec![Route::ToServer(message)]
+            }
+            (Some("textDocument/didOpen"), None) => self.did_open(client, message),
+            (Some("textDocument/didChange"), None) => self.did_change(client, message),
+            (Some("textDocument/didClose"), None) => match document_uri(&message) {
+                Some(uri) => self.close_document(client, &uri),
+                None => Vec::new(),
+            },
+            (Some(_), id) => {
+                let mut routes: Vec<Route> = document_uri(&message)
+                    .and_then(|uri| self.take_ownership(client, &uri))
+                    .into_iter()
+                    .collect();
+                if let Some(id) = id {
+                    let proxy_id = self.next_id();
+                    self.pending.insert(proxy_id, (client, id));
+                    message["id"] = json!(proxy_id);
+                }
+                routes.push(Route::ToServer(message));
+                routes
+            }
+        }
+    }
+
+    /// Route a message the server sent.
+    pub fn from_server(&mut self, mut message: Value) -> Vec<Route> {
+        let method = message
+            .get("method")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        let id = message.get("id").cloned();
+        match (method.as_deref(), id) {
+            (None, Some(id)) => {
+                let Some(proxy_id) = id.as_i64() else {
+                    return Vec::new();
+                };
+                if matches!(self.init, Init::Pending { id, .. } if id == proxy_id) {
+                    return self.initialize_answered(message);
+                }
+                let Some((client, original)) = self.pending.remove(&proxy_id) else {
+                    return Vec::new();
+                };
+                message["id"] = original;
+                vec![Route::ToClient(client, message)]
+            }
+            (Some("window/workDoneProgress/create"), Some(id)) => {
+                vec![Route::ToServer(response(id, Value::Null))]
+            }
+            (Some(method), Some(id)) => {
+                match method {
+                    "client/registerCapability" => {
+                        if let Some(Value::Array(added)) = message.pointer("/params/registrations")
+                        {
+                            self.registrations.extend(added.iter().cloned());
+                        }
+                    }
+                    "client/unregisterCapability" => {
+                        // The field name's misspelling is the spec's.
+                        if let Some(Value::Array(removed)) =
+                            message.pointer("/params/unregisterations")
+                        {
+                            let ids: Vec<&Value> = removed.iter().map(|r| &r["id"]).collect();
+                            self.registrations.retain(|r| !ids.contains(&&r["id"]));
+                        }
+                    }
+                    _ => {}
+                }
+                let Some(&primary) = self.clients.first() else {
+                    return vec![Route::ToServer(no_client_error(id))];
+                };
+                self.server_requests
+                    .insert(id.to_string(), (primary, id.clone()));
+                vec![Route::ToClient(primary, message)]
+            }
+            (Some("textDocument/publishDiagnostics"), None) => self.publish_diagnostics(message),
+            (Some(_), None) => self
+                .clients
+                .iter()
+                .map(|&client| Route::ToClient(client, message.clone()))
+                .collect(),
+            (None, None) => Vec::new(),
+        }
+    }
+
+    /// Detach an editor: give back whatever it owed the server and close
+    /// the documents only it had open.
+    pub fn leave(&mut self, client: ClientId) -> Vec<Route> {
+        if !self.clients.remove(&client) {
+            return Vec::new();
+        }
+        let mut routes = Vec::new();
+
+        let owed: Vec<String> = self
+            .server_requests
+            .iter()
+            .filter(|(_, (owner, _))| *owner == client)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in owed {
+            if let Some((_, id)) = self.server_requests.remove(&key) {
+                routes.push(Route::ToServer(no_client_error(id)));
+            }
+        }
+
+        let mut abandoned: Vec<i64> = self
+            .pending
+            .iter()
+            .filter(|(_, (owner, _))| *owner == client)
+            .map(|(id, _)| *id)
+            .collect();
+        abandoned.sort_unstable();
+        for id in abandoned {
+            self.pending.remove(&id);
+            routes.push(Route::ToServer(notification(
+                "$/cancelRequest",
+                json!({ "id": id }),
+            )));
+        }
+        if let Init::Pending { waiting, .. } = &mut self.init {
+            waiting.retain(|(owner, _)| *owner != client);
+        }
+
+        let mut open: Vec<String> = self
+            .documents
+            .iter()
+            .filter(|(_, doc)| doc.texts.contains_key(&client))
+            .map(|(uri, _)| uri.clone())
+            .collect();
+        open.sort();
+        for uri in open {
+            routes.extend(self.close_document(client, &uri));
+        }
+        routes
+    }
+
+    fn next_id(&mut self) -> i64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    fn initialize(&mut self, client: ClientId, id: Value, mut message: Value) -> Vec<Route> {
+        match &mut self.init {
+            Init::NotSent => {
+                let proxy_id = self.next_id();
+                self.init = Init::Pending {
+                    id: proxy_id,
+                    waiting: vec![(client, id)],
+                };
+                message["id"] = json!(proxy_id);
+                vec![Route::ToServer(message)]
+            }
+            Init::Pending { waiting, .. } => {
+                waiting.push((client, id));
+                Vec::new()
+            }
+            Init::Done(result) => vec![Route::ToClient(client, response(id, result.clone()))],
+        }
+    }
+
+    fn initialize_answered(&mut self, message: Value) -> Vec<Route> {
+        let Init::Pending { waiting, .. } = std::mem::replace(&mut self.init, Init::NotSent) else {
+            return Vec::new();
+        };
+        if let Some(result) = message.get("result") {
+            self.init = Init::Done(result.clone());
+        }
+        waiting
+            .into_iter()
+            .map(|(client, id)| {
+                let mut answer = message.clone();
+                answer["id"] = id;
+                Route::ToClient(client, answer)
+            })
+            .collect()
+    }
+
+    /// Forward the first `initialized`; replay the server's capability
+    /// registrations to editors that join after it.
+    fn initialized(&mut self, client: ClientId, message: Value) -> Vec<Route> {
+        if !self.initialized_sent {
+            self.initialized_sent = true;
+            return vec![Route::ToServer(message)];
+        }
+        if self.registrations.is_empty() {
+            return Vec::new();
+        }
+        // The editor's answer matches no server request and is dropped.
+        let id = format!("fresh-proxy-{}", self.next_id());
+        vec![Route::ToClient(
+            client,
+            json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "method": "client/registerCapability",
+                "params": { "registrations": self.registrations },
+            }),
+        )]
+    }
+
+    fn answer_server_request(&mut self, client: ClientId, id: Value, message: Value) -> Vec<Route> {
+        let key = id.to_string();
+        match self.server_requests.get(&key) {
+            Some((owner, _)) if *owner == client => {
+                self.server_requests.remove(&key);
+                vec![Route::ToServer(message)]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn did_open(&mut self, client: ClientId, message: Value) -> Vec<Route> {
+        let (Some(uri), Some(text)) = (
+            document_uri(&message),
+            message
+                .pointer("/params/textDocument/text")
+                .and_then(Value::as_str),
+        ) else {
+            return vec![Route::ToServer(message)];
+        };
+        let version = message_version(&message);
+        match self.documents.get_mut(&uri) {
+            Some(doc) => {
+                doc.texts.insert(client, text.to_string());
+                doc.versions.insert(client, version);
+                self.take_ownership(client, &uri).into_iter().collect()
+            }
+            None => {
+                let mut doc = Document {
+                    texts: BTreeMap::from([(client, text.to_string())]),
+                    versions: BTreeMap::from([(client, version)]),
+                    owner: client,
+                    version,
+                    history: VecDeque::new(),
+                };
+                doc.record();
+                self.documents.insert(uri, doc);
+                vec![Route::ToServer(message)]
+            }
+        }
+    }
+
+    fn did_change(&mut self, client: ClientId, mut message: Value) -> Vec<Route> {
+        let encoding = self.position_encoding();
+        let Some(uri) = document_uri(&message) else {
+            return Vec::new();
+        };
+        let Some(doc) = self.documents.get_mut(&uri) else {
+            return vec![Route::ToServer(message)];
+        };
+        let Some(text) = doc.texts.get_mut(&client) else {
+            return Vec::new();
+        };
+        if let Some(Value::Array(changes)) = message.pointer("/params/contentChanges") {
+            for change in changes {
+                apply_change(text, change, &encoding);
+            }
+        }
+        doc.versions.insert(client, message_version(&message));
+        if doc.owner != client {
+            return self.take_ownership(client, &uri).into_iter().collect();
+        }
+        let version = doc.advance(client);
+        message["params"]["textDocument"]["version"] = json!(version);
+        vec![Route::ToServer(message)]
+    }
+
+    fn close_document(&mut self, client: ClientId, uri: &str) -> Vec<Route> {
+        let Some(doc) = self.documents.get_mut(uri) else {
+            return Vec::new();
+        };
+        let Some(closed_text) = doc.texts.remove(&client) else {
+            return Vec::new();
+        };
+        doc.versions.remove(&client);
+        let Some((&next, next_text)) = doc.texts.iter().next() else {
+            self.documents.remove(uri);
+            return vec![Route::ToServer(notification(
+                "textDocument/didClose",
+                json!({ "textDocument": { "uri": uri } }),
+            ))];
+        };
+        if doc.owner != client {
+            return Vec::new();
+        }
+        let text = next_text.clone();
+        if text == closed_text {
+            // Same text, so the server's current version is `next`'s too.
+            doc.owner = next;
+            doc.record();
+            return Vec::new();
+        }
+        let version = doc.advance(next);
+        vec![full_text_change(uri, version, text)]
+    }
+
+    /// Make `client`'s copy of `uri` the server's, sending it in full if it
+    /// differs from what the server has.
+    fn take_ownership(&mut self, client: ClientId, uri: &str) -> Option<Route> {
+        let doc = self.documents.get_mut(uri)?;
+        if doc.owner == client {
+            return None;
+        }
+        let text = doc.texts.get(&client)?.clone();
+        if doc.texts.get(&doc.owner) == Some(&text) {
+            doc.owner = client;
+            doc.record();
+            return None;
+        }
+        let version = doc.advance(client);
+        Some(full_text_change(uri, version, text))
+    }
+
+    /// Send diagnostics for the server's copy of a document to the editors
+    /// whose copy it is, each under its own version. Diagnostics for an
+    /// older server version only reach the editors that version came from;
+    /// they can tell whether it is stale from the version.
+    fn publish_diagnostics(&mut self, message: Value) -> Vec<Route> {
+        let doc = message
+            .pointer("/params/uri")
+            .and_then(Value::as_str)
+            .and_then(|uri| self.documents.get(uri));
+        let Some(doc) = doc else {
+            return self
+                .clients
+                .iter()
+                .map(|&client| Route::ToClient(client, message.clone()))
+                .collect();
+        };
+        let with_version = |client: ClientId, version: Option<i64>| {
+            let mut message = message.clone();
+            match version {
+                Some(version) => message["params"]["version"] = json!(version),
+                None => {
+                    if let Some(params) = message["params"].as_object_mut() {
+                        params.remove("version");
+                    }
+                }
+            }
+            Route::ToClient(client, message)
+        };
+
+        let server_version = message.pointer("/params/version").and_then(Value::as_i64);
+        if let Some(server_version) = server_version.filter(|v| *v != doc.version) {
+            let sources: BTreeMap<ClientId, i64> = doc
+                .history
+                .iter()
+                .filter(|(v, client, _)| *v == server_version && doc.texts.contains_key(client))
+                .map(|&(_, client, version)| (client, version))
+                .collect();
+            return sources
+                .into_iter()
+                .map(|(client, version)| with_version(client, Some(version)))
+                .collect();
+        }
+        let server_text = doc.texts.get(&doc.owner);
+        doc.texts
+            .iter()
+            .filter(|(_, text)| Some(*text) == server_text)
+            .map(|(&client, _)| {
+                let version = server_version.and(doc.versions.get(&client).copied());
+                with_version(client, version)
+            })
+            .collect()
+    }
+
+    /// How the server counts columns, from its `initialize` result.
+    fn position_encoding(&self) -> String {
+        match &self.init {
+            Init::Done(result) => result
+                .pointer("/capabilities/positionEncoding")
+                .and_then(Value::as_str)
+                .unwrap_or("utf-16")
+                .to_string(),
+            _ => "utf-16".to_string(),
+        }
+    }
+}
+
+fn response(id: Value, result: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result })
+}
+
+fn no_client_error(id: Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": -32603, "message": "no editor connected to answer" },
+    })
+}
+
+fn notification(method: &str, params: Value) -> Value {
+    json!({ "jsonrpc": "2.0", "method": method, "params": params })
+}
+
+fn full_text_change(uri: &str, version: i64, text: String) -> Route {
+    Route::ToServer(notification(
+        "textDocument/didChange",
+        json!({
+            "textDocument": { "uri": uri, "version": version },
+            "contentChanges": [{ "text": text }],
+        }),
+    ))
+}
+
+/// The version an editor gave its copy in `didOpen`/`didChange`.
+fn message_version(message: &Value) -> i64 {
+    message
+        .pointer("/params/textDocument/version")
+        .and_then(Value::as_i64)
+        .unwrap_or(0)
+}
+
+fn document_uri(message: &Value) -> Option<String> {
+    message
+        .pointer("/params/textDocument/uri")
+        .and_then(Value::as_str)
+        .map(str::to_string)
+}
+
+/// Apply one `contentChanges` entry to `text`.
+fn apply_change(text: &mut String, change: &Value, encoding: &str) {
+    let Some(new_text) = change.get("text").and_then(Value::as_str) else {
+        return;
+    };
+    let Some(range) = change.get("range") else {
+        *text = new_text.to_string();
+        return;
+    };
+    let (Some(start), Some(end)) = (
+        byte_offset(text, &range["start"], encoding),
+        byte_offset(text, &range["end"], encoding),
+    ) else {
+        return;
+    };
+    if start <= end {
+        text.replace_range(start..end, new_text);
+    }
+}
+
+/// Byte offset of an LSP position, clamped to the end of its line.
+fn byte_offset(text: &str, position: &Value, encoding: &str) -> Option<usize> {
+    let line = position.get("line")?.as_u64()? as usize;
+    let character = position.get("character")?.as_u64()? as usize;
+    let line_start = if line == 0 {
+        0
+    } else {
+        text.match_indices('\n').nth(line - 1)?.0 + 1
+    };
+    let line_text = text[line_start..].split('\n').next().unwrap_or("");
+    let mut units = 0;
+    for (i, c) in line_text.char_indices() {
+        if units >= character {
+            return Some(line_start + i);
+        }
+        units += match encoding {
+            "utf-8" => c.len_utf8(),
+            "utf-32" => 1,
+            _ => c.len_utf16(),
+        };
+    }
+    Some(line_start + line_text.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(id: i64, method: &str, params: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+    }
+
+    fn open(uri: &str, text: &str) -> Value {
+        notification(
+            "textDocument/didOpen",
+            json!({ "textDocument": { "uri": uri, "languageId": "rust", "version": 1, "text": text } }),
+        )
+    }
+
+    fn change(uri: &str, line: u64, from: u64, to: u64, text: &str) -> Value {
+        notification(
+            "textDocument/didChange",
+            json!({
+                "textDocument": { "uri": uri, "version": 7 },
+                "contentChanges": [{
+                    "range": {
+                        "start": { "line": line, "character": from },
+                        "end": { "line": line, "character": to },
+                    },
+                    "text": text,
+                }],
+            }),
+        )
+    }
+
+    /// Two editors, both through `initialize`.
+    fn initialized_pair() -> Multiplexer {
+        let mut mux = Multiplexer::default();
+        mux.join(1);
+        mux.join(2);
+        mux.from_client(1, request(0, "initialize", json!({})));
+        mux.from_client(2, request(0, "initialize", json!({})));
+        mux.from_server(json!({ "jsonrpc": "2.0", "id": 1, "result": { "capabilities": {} } }));
+        mux.from_client(1, notification("initialized", json!({})));
+        mux.from_client(2, notification("initialized", json!({})));
+        mux
+    }
+
+    #[test]
+    fn one_initialize_reaches_the_server_and_ids_are_restored() {
+        let mut mux = Multiplexer::default();
+        mux.join(1);
+        mux.join(2);
+        let sent = mux.from_client(1, request(0, "initialize", json!({})));
+        assert_eq!(
+            sent,
+            vec![Route::ToServer(request(1, "initialize", json!({})))]
+        );
+        assert_eq!(
+            mux.from_client(2, request(0, "initialize", json!({}))),
+            vec![]
+        );
+
+        let result = json!({ "capabilities": { "hoverProvider": true } });
+        let answers = mux.from_server(json!({ "jsonrpc": "2.0", "id": 1, "result": result }));
+        assert_eq!(
+            answers,
+            vec![
+                Route::ToClient(1, response(json!(0), result.clone())),
+                Route::ToClient(2, response(json!(0), result.clone())),
+            ]
+        );
+
+        // A late editor is answered from the cache.
+        mux.join(3);
+        assert_eq!(
+            mux.from_client(3, request(5, "initialize", json!({}))),
+            vec![Route::ToClient(3, response(json!(5), result))]
+        );
+
+        // Both editors use id 9; the server sees distinct ids.
+        let hover = json!({ "textDocument": { "uri": "file:///a.rs" } });
+        let first = mux.from_client(1, request(9, "textDocument/hover", hover.clone()));
+        let second = mux.from_client(2, request(9, "textDocument/hover", hover.clone()));
+        assert_eq!(
+            first,
+            vec![Route::ToServer(request(
+                2,
+                "textDocument/hover",
+                hover.clone()
+            ))]
+        );
+        assert_eq!(
+            second,
+            vec![Route::ToServer(request(3, "textDocument/hover", hover))]
+        );
+        assert_eq!(
+            mux.from_server(response(json!(3), json!("docs"))),
+            vec![Route::ToClient(2, response(json!(9), json!("docs")))]
+        );
+        assert_eq!(
+            mux.from_client(1, notification("exit", Value::Null)),
+            vec![Route::ToServer(notification(
+                "$/cancelRequest",
+                json!({ "id": 2 })
+            ))]
+        );
+    }
+
+    #[test]
+    fn diverging_copies_are_resynced_and_closed_once() {
+        let mut mux = initialized_pair();
+        let uri = "file:///a.rs";
+        assert_eq!(mux.from_client(1, open(uri, "fn a() {}\n")).len(), 1);
+        // Same text: nothing for the server.
+        assert_eq!(mux.from_client(2, open(uri, "fn a() {}\n")), vec![]);
+
+        // The copies match, so either editor may own the server's; the
+        // latest to open it does, and its edits go through as-is.
+        let routes = mux.from_client(2, change(uri, 0, 3, 4, "b"));
+        assert_eq!(
+            routes,
+            vec![Route::ToServer({
+                let mut sent = change(uri, 0, 3, 4, "b");
+                sent["params"]["textDocument"]["version"] = json!(2);
+                sent
+            })]
+        );
+
+        // Editor 1 still has the old text, so its edit is sent in full.
+        let routes = mux.from_client(1, change(uri, 0, 9, 9, " // x"));
+        assert_eq!(
+            routes,
+            vec![full_text_change(uri, 3, "fn a() {} // x\n".to_string())]
+        );
+
+        // Closing in one editor keeps the file open, with the other's text;
+        // the last close closes it on the server.
+        assert_eq!(
+            mux.from_client(
+                1,
+                notification(
+                    "textDocument/didClose",
+                    json!({ "textDocument": { "uri": uri } })
+                )
+            ),
+            vec![full_text_change(uri, 4, "fn b() {}\n".to_string())]
+        );
+        assert_eq!(
+            mux.leave(2),
+            vec![Route::ToServer(notification(
+                "textDocument/didClose",
+                json!({ "textDocument": { "uri": uri } })
+            ))]
+        );
+    }
+
+    #[test]
+    fn diagnostics_carry_each_editors_own_version() {
+        let mut mux = initialized_pair();
+        let uri = "file:///a.rs";
+        let open_at = |version: i64| {
+            let mut message = open(uri, "fn a() {}\n");
+            message["params"]["textDocument"]["version"] = json!(version);
+            message
+        };
+        let diagnostics = |version: i64| {
+            notification(
+                "textDocument/publishDiagnostics",
+                json!({ "uri": uri, "version": version, "diagnostics": [] }),
+            )
+        };
+        let tagged = |version: i64| {
+            let mut message = diagnostics(0);
+            message["params"]["version"] = json!(version);
+            message
+        };
+        mux.from_client(1, open_at(1));
+        mux.from_client(2, open_at(5));
+        assert_eq!(
+            mux.from_server(diagnostics(1)),
+            vec![Route::ToClient(1, tagged(1)), Route::ToClient(2, tagged(5))]
+        );
+
+        // Editor 1's edit makes the server's copy version 2. Diagnostics for
+        // it go to editor 1 only; late ones for version 1 still describe
+        // editor 2's unchanged copy, and editor 1 sees they are stale.
+        mux.from_client(1, change(uri, 0, 3, 4, "b"));
+        assert_eq!(
+            mux.from_server(diagnostics(2)),
+            vec![Route::ToClient(1, tagged(7))]
+        );
+        assert_eq!(
+            mux.from_server(diagnostics(1)),
+            vec![Route::ToClient(1, tagged(1)), Route::ToClient(2, tagged(5))]
+        );
+    }
+
+    #[test]
+    fn server_requests_go_to_the_first_editor() {
+        let mut mux = initialized_pair();
+        let register = request(
+            40,
+            "client/registerCapability",
+            json!({ "registrations": [{ "id": "w", "method": "workspace/didChangeWatchedFiles" }] }),
+        );
+        assert_eq!(
+            mux.from_server(register.clone()),
+            vec![Route::ToClient(1, register)]
+        );
+        assert_eq!(
+            mux.from_server(request(41, "window/workDoneProgress/create", json!({}))),
+            vec![Route::ToServer(response(json!(41), Value::Null))]
+        );
+
+        // Editor 1 leaves without answering: the server gets an error, and
+        // a late editor is told about the registration.
+        assert_eq!(
+            mux.leave(1),
+            vec![Route::ToServer(no_client_error(json!(40)))]
+        );
+        mux.join(3);
+        mux.from_client(3, request(0, "initialize", json!({})));
+        let replay = mux.from_client(3, notification("initialized", json!({})));
+        let [Route::ToClient(3, replay)] = replay.as_slice() else {
+            panic!("expected a replayed registration, got {replay:?}");
+        };
+        assert_eq!(replay["method"], "client/registerCapability");
+        assert_eq!(replay["params"]["registrations"][0]["id"], "w");
+        // Its answer is swallowed.
+        assert_eq!(
+            mux.from_client(3, response(replay["id"].clone(), Value::Null)),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn positions_count_in_the_negotiated_encoding() {
+        let text = "a😀b\nxyz";
+        let at = |line, character, encoding| {
+            byte_offset(
+                text,
+                &json!({ "line": line, "character": character }),
+                encoding,
+            )
+        };
+        assert_eq!(at(0, 3, "utf-16"), Some(5));
+        assert_eq!(at(0, 2, "utf-32"), Some(5));
+        assert_eq!(at(0, 5, "utf-8"), Some(5));
+        assert_eq!(at(0, 99, "utf-16"), Some(6));
+        assert_eq!(at(1, 1, "utf-16"), Some(8));
+    }
+}
@@ -0,0 +1,101 @@
+//! `fresh --lsp-proxy-client -- <command> <args>`: what the editor runs in
+//! place of a shared language server. Pipes stdin and stdout to the proxy.
+
+use super::{proxy_socket_paths, ProxyHello, POLL_INTERVAL};
+use crate::server::daemon::spawn_lsp_proxy_detached;
+use crate::server::ipc::ClientConnection;
+use std::io::{self, Read, Write};
+use std::sync::mpsc::{self, TryRecvError};
+use std::time::{Duration, Instant};
+
+/// How long to wait for a freshly started proxy to accept connections.
+const PROXY_START_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub fn run_relay(command_line: &[String]) -> io::Result<()> {
+    let Some((command, args)) = command_line.split_first() else {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "usage: fresh --lsp-proxy-client -- <command> [args...]",
+        ));
+    };
+    let conn = connect_or_start()?;
+    let hello = ProxyHello {
+        command: command.clone(),
+        args: args.to_vec(),
+        env: std::env::vars().collect(),
+    };
+    conn.write_control(&serde_json::to_string(&hello)?)?;
+
+    // Stdin blocks, so it gets its own thread; the socket is polled here.
+    let (tx, rx) = mpsc::channel::<Vec<u8>>();
+    std::thread::spawn(move || {
+        let mut stdin = io::stdin().lock();
+        let mut buf = [0u8; 8192];
+        loop {
+            match stdin.read(&mut buf) {
+                Ok(0) | Err(_) => break,
+                Ok(n) => {
+                    if tx.send(buf[..n].to_vec()).is_err() {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+
+    let mut stdout = io::stdout().lock();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let mut busy = false;
+        loop {
+            match rx.try_recv() {
+                Ok(bytes) => {
+                    conn.write_data(&bytes)?;
+                    busy = true;
+                }
+                Err(TryRecvError::Empty) => break,
+                // The editor closed our stdin.
+                Err(TryRecvError::Disconnected) => return Ok(()),
+            }
+        }
+        match conn.read_data(&mut buf) {
+            Ok(0) => return Ok(()),
+            Ok(n) => {
+                stdout.write_all(&buf[..n])?;
+                stdout.flush()?;
+                busy = true;
+            }
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {}
+            Err(e) => return Err(e),
+        }
+        if !busy {
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// Connect to the running proxy, starting one if there is none.
+fn connect_or_start() -> io::Result<ClientConnection> {
+    let paths = proxy_socket_paths()?;
+    if paths.is_server_alive() {
+        if let Ok(conn) = ClientConnection::connect(&paths) {
+            return Ok(conn);
+        }
+    }
+    paths.cleanup_if_stale();
+    spawn_lsp_proxy_detached()?;
+
+    let deadline = Instant::now() + PROXY_START_TIMEOUT;
+    loop {
+        match ClientConnection::connect(&paths) {
+            Ok(conn) => return Ok(conn),
+            Err(e) if Instant::now() >= deadline => {
+                return Err(io::Error::new(
+                    e.kind(),
+                    format!("LSP proxy did not start: {e}"),
+                ))
+            }
+            Err(_) => std::thread::sleep(Duration::from_millis(20)),
+        }
+    }
+}
@@ -0,0 +1,490 @@
+//! `fresh --lsp-proxy`: accepts relays, starts one server per workspace
+//! and pumps messages between them.
+
+use super::framing::{frame, Framer};
+use super::mux::{ClientId, Multiplexer, Route};
+use super::{proxy_socket_paths, ProxyHello, POLL_INTERVAL};
+use crate::server::ipc::{ServerConnection, ServerListener};
+use serde_json::{json, Value};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/// How long a server with no editors attached is kept warm.
+const SERVER_LINGER: Duration = Duration::from_secs(300);
+
+/// How long the proxy stays up with no servers.
+const PROXY_IDLE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a server gets to exit after `shutdown`/`exit` before it is
+/// killed.
+const SERVER_EXIT_GRACE: Duration = Duration::from_secs(2);
+
+/// Servers are shared between editors that run the same command on the
+/// same workspace.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ServerKey {
+    command: String,
+    args: Vec<String>,
+    root: Option<String>,
+}
+
+struct Client {
+    id: ClientId,
+    conn: ServerConnection,
+    framer: Framer,
+}
+
+/// A relay that hasn't sent `initialize` yet, so its server isn't known.
+struct Joining {
+    hello: ProxyHello,
+    client: Client,
+}
+
+struct Instance {
+    key: ServerKey,
+    child: Child,
+    stdin: ChildStdin,
+    /// Output from the server's stdout reader thread.
+    stdout: mpsc::Receiver<Vec<u8>>,
+    framer: Framer,
+    mux: Multiplexer,
+    clients: Vec<Client>,
+    /// When the last editor left.
+    idle_since: Option<Instant>,
+}
+
+struct Proxy {
+    next_client: ClientId,
+    /// Handshakes read on their own threads, so a relay that connects and
+    /// stays silent doesn't stall everyone else.
+    hellos_tx: mpsc::Sender<Option<(ProxyHello, ServerConnection)>>,
+    hellos: mpsc::Receiver<Option<(ProxyHello, ServerConnection)>>,
+    /// Handshake threads still running.
+    handshakes: usize,
+    joining: Vec<Joining>,
+    instances: Vec<Instance>,
+}
+
+pub fn run_proxy() -> io::Result<()> {
+    let paths = proxy_socket_paths()?;
+    if paths.is_server_alive() {
+        tracing::info!("LSP proxy already running");
+        return Ok(());
+    }
+    let mut listener = ServerListener::bind(paths.clone())?;
+    paths.write_pid(std::process::id())?;
+    tracing::info!("LSP proxy listening on {:?}", paths.data);
+
+    let mut proxy = Proxy::new();
+    let mut idle_since = Instant::now();
+    loop {
+        let mut busy = false;
+        loop {
+            match listener.accept() {
+                Ok(Some(conn)) => {
+                    proxy.accept(conn);
+                    busy = true;
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    tracing::warn!("LSP proxy: accept failed: {}", e);
+                    break;
+                }
+            }
+        }
+        busy |= proxy.poll();
+
+        if proxy.handshakes == 0 && proxy.joining.is_empty() && proxy.instances.is_empty() {
+            if idle_since.elapsed() > PROXY_IDLE_TIMEOUT {
+                tracing::info!("LSP proxy idle, exiting");
+                return Ok(());
+            }
+        } else {
+            idle_since = Instant::now();
+        }
+        if !busy {
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+impl Proxy {
+    fn new() -> Self {
+        let (hellos_tx, hellos) = mpsc::channel();
+        Self {
+            next_client: 0,
+            hellos_tx,
+            hellos,
+            handshakes: 0,
+            joining: Vec::new(),
+            instances: Vec::new(),
+        }
+    }
+
+    /// Read the relay's hello on a thread of its own; [`Self::poll_hellos`]
+    /// picks up the result.
+    fn accept(&mut self, conn: ServerConnection) {
+        let tx = self.hellos_tx.clone();
+        let spawned = std::thread::Builder::new()
+            .name("lsp-proxy-hello".to_string())
+            .spawn(move || {
+                let joined = read_hello(&conn).map(|hello| (hello, conn));
+                // The receiver lives as long as the proxy.
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = tx.send(joined);
+            });
+        match spawned {
+            Ok(_) => self.handshakes += 1,
+            Err(e) => tracing::warn!("LSP proxy: failed to spawn handshake thread: {}", e),
+        }
+    }
+
+    /// Queue the relays whose hello has arrived.
+    fn poll_hellos(&mut self) -> bool {
+        let mut busy = false;
+        while let Ok(joined) = self.hellos.try_recv() {
+            busy = true;
+            self.handshakes -= 1;
+            let Some((hello, conn)) = joined else {
+                continue;
+            };
+            self.next_client += 1;
+            self.joining.push(Joining {
+                hello,
+                client: Client {
+                    id: self.next_client,
+                    conn,
+                    framer: Framer::default(),
+                },
+            });
+        }
+        busy
+    }
+
+    /// Move everything that is ready. Returns whether anything was.
+    fn poll(&mut self) -> bool {
+        let mut busy = self.poll_hellos();
+        busy |= self.poll_joining();
+        let mut index = 0;
+        while index < self.instances.len() {
+            let instance = &mut self.instances[index];
+            busy |= instance.poll();
+            if instance.server_exited() {
+                tracing::info!("LSP proxy: {} exited", instance.key.command);
+                self.instances.swap_remove(index);
+                continue;
+            }
+            if instance.lingered_out() {
+                tracing::info!("LSP proxy: stopping unused {}", instance.key.command);
+                let instance = self.instances.swap_remove(index);
+                instance.stop();
+                continue;
+            }
+            index += 1;
+        }
+        busy
+    }
+
+    /// Wait for each new relay's `initialize`, then attach it to the
+    /// matching server, starting one if needed.
+    fn poll_joining(&mut self) -> bool {
+        let mut busy = false;
+        let mut index = 0;
+        while index < self.joining.len() {
+            let joining = &mut self.joining[index];
+            let (open, read) = read_available(&joining.client.conn, &mut joining.client.framer);
+            busy |= read;
+            if !open {
+                self.joining.swap_remove(index);
+                continue;
+            }
+            let Some(first) = joining.client.framer.next_message() else {
+                index += 1;
+                continue;
+            };
+            let joining = self.joining.swap_remove(index);
+            self.attach(joining, first);
+        }
+        busy
+    }
+
+    fn attach(&mut self, joining: Joining, first: Value) {
+        let Joining { hello, client } = joining;
+        let key = ServerKey {
+            command: hello.command.clone(),
+            args: hello.args.clone(),
+            root: workspace_root(&first),
+        };
+        let position = match self.instances.iter().position(|i| i.key == key) {
+            Some(position) => position,
+            None => match Instance::start(key, &hello.env) {
+                Ok(instance) => {
+                    self.instances.push(instance);
+                    self.instances.len() - 1
+                }
+                Err(e) => {
+                    tracing::warn!("LSP proxy: failed to start {}: {}", hello.command, e);
+                    if let Some(id) = first.get("id") {
+                        let error = json!({
+                            "jsonrpc": "2.0",
+                            "id": id,
+                            "error": {
+                                "code": -32603,
+                                "message": format!("LSP proxy failed to start {}: {}", hello.command, e),
+                            },
+                        });
+                        // The relay is dropped either way.
+                        #[allow(clippy::let_underscore_must_use)]
+                        let _ = client.conn.write_data(&frame(&error));
+                    }
+                    return;
+                }
+            },
+        };
+        let instance = &mut self.instances[position];
+        let id = client.id;
+        instance.mux.join(id);
+        instance.clients.push(client);
+        instance.idle_since = None;
+        let routes = instance.mux.from_client(id, first);
+        instance.deliver(routes);
+        // Anything sent along with `initialize` is handled on the next poll.
+    }
+}
+
+impl Instance {
+    fn start(key: ServerKey, env: &[(String, String)]) -> io::Result<Self> {
+        let program = crate::services::remote::resolve_program(&key.command).into_owned();
+        let mut cmd = Command::new(program);
+        cmd.args(&key.args)
+            .env_clear()
+            .envs(env.iter().map(|(k, v)| (k, v)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(server_log(&key.command));
+        if let Some(dir) = key.root.as_deref().and_then(root_path) {
+            cmd.current_dir(dir);
+        }
+        let mut child = cmd.spawn()?;
+        tracing::info!(
+            "LSP proxy: started {} {:?} for {:?}",
+            key.command,
+            key.args,
+            key.root
+        );
+        let stdin = child
+            .stdin
+            .take()
+            .ok_or_else(|| io::Error::other("no stdin"))?;
+        let mut stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| io::Error::other("no stdout"))?;
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let mut buf = vec![0u8; 64 * 1024];
+            loop {
+                match stdout.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => {
+                        if tx.send(buf[..n].to_vec()).is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+        Ok(Self {
+            key,
+            child,
+            stdin,
+            stdout: rx,
+            framer: Framer::default(),
+            mux: Multiplexer::default(),
+            clients: Vec::new(),
+            idle_since: None,
+        })
+    }
+
+    fn poll(&mut self) -> bool {
+        let mut busy = false;
+
+        let mut left = Vec::new();
+        let mut routes = Vec::new();
+        for client in &mut self.clients {
+            let (open, read) = read_available(&client.conn, &mut client.framer);
+            busy |= read;
+            while let Some(message) = client.framer.next_message() {
+                routes.extend(self.mux.from_client(client.id, message));
+            }
+            if !open {
+                left.push(client.id);
+            }
+        }
+        for id in left {
+            routes.extend(self.mux.leave(id));
+        }
+        self.deliver(routes);
+
+        while let Ok(bytes) = self.stdout.try_recv() {
+            busy = true;
+            self.framer.push(&bytes);
+        }
+        while let Some(message) = self.framer.next_message() {
+            let routes = self.mux.from_server(message);
+            self.deliver(routes);
+        }
+
+        // Relays whose editor sent `exit` or went away.
+        self.clients.retain(|client| self.mux.has_client(client.id));
+        if self.clients.is_empty() && self.idle_since.is_none() {
+            self.idle_since = Some(Instant::now());
+        }
+        busy
+    }
+
+    fn deliver(&mut self, routes: Vec<Route>) {
+        let mut failed = Vec::new();
+        for route in routes {
+            match route {
+                Route::ToServer(message) => {
+                    if let Err(e) = self.stdin.write_all(&frame(&message)) {
+                        tracing::warn!("LSP proxy: writing to {} failed: {}", self.key.command, e);
+                    }
+                }
+                Route::ToClient(id, message) => {
+                    let Some(client) = self.clients.iter().find(|c| c.id == id) else {
+                        continue;
+                    };
+                    if client.conn.write_data(&frame(&message)).is_err() {
+                        failed.push(id);
+                    }
+                }
+            }
+        }
+        for id in failed {
+            let routes = self.mux.leave(id);
+            self.deliver(routes);
+        }
+    }
+
+    fn server_exited(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(Some(_)))
+    }
+
+    fn lingered_out(&self) -> bool {
+        self.idle_since
+            .is_some_and(|since| since.elapsed() > SERVER_LINGER)
+    }
+
+    /// Ask the server to exit, killing it if it takes too long.
+    fn stop(mut self) {
+        let shutdown =
+            json!({ "jsonrpc": "2.0", "id": "fresh-proxy-shutdown", "method": "shutdown" });
+        let exit = json!({ "jsonrpc": "2.0", "method": "exit" });
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = self
+            .stdin
+            .write_all(&frame(&shutdown))
+            .and_then(|()| self.stdin.write_all(&frame(&exit)));
+        drop(self.stdin);
+        let mut child = self.child;
+        std::thread::spawn(move || {
+            let deadline = Instant::now() + SERVER_EXIT_GRACE;
+            while Instant::now() < deadline {
+                if matches!(child.try_wait(), Ok(Some(_))) {
+                    return;
+                }
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = child.kill();
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = child.wait();
+        });
+    }
+}
+
+/// A relay's hello line, or `None` if it hung up or sent garbage.
+fn read_hello(conn: &ServerConnection) -> Option<ProxyHello> {
+    let line = match conn.read_control() {
+        Ok(Some(line)) => line,
+        Ok(None) => return None,
+        Err(e) => {
+            tracing::warn!("LSP proxy: reading hello failed: {}", e);
+            return None;
+        }
+    };
+    match serde_json::from_str(&line) {
+        Ok(hello) => Some(hello),
+        Err(e) => {
+            tracing::warn!("LSP proxy: malformed hello: {}", e);
+            None
+        }
+    }
+}
+
+/// Read whatever a relay has sent. Returns whether the connection is
+/// still open and whether anything arrived.
+fn read_available(conn: &ServerConnection, framer: &mut Framer) -> (bool, bool) {
+    let mut buf = [0u8; 16 * 1024];
+    let mut read = false;
+    loop {
+        match conn.read_data(&mut buf) {
+            Ok(0) => return (false, read),
+            Ok(n) => {
+                framer.push(&buf[..n]);
+                read = true;
+            }
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => return (true, read),
+            Err(_) => return (false, read),
+        }
+    }
+}
+
+/// The workspace an `initialize` request is for.
+fn workspace_root(initialize: &Value) -> Option<String> {
+    let params = initialize.get("params")?;
+    params
+        .get("rootUri")
+        .and_then(Value::as_str)
+        .or_else(|| {
+            params
+                .pointer("/workspaceFolders/0/uri")
+                .and_then(Value::as_str)
+        })
+        .or_else(|| params.get("rootPath").and_then(Value::as_str))
+        .map(str::to_string)
+}
+
+fn root_path(root: &str) -> Option<PathBuf> {
+    let path = match root.parse::<lsp_types::Uri>() {
+        Ok(uri) if root.starts_with("file:") => fresh_core::file_uri::lsp_uri_to_path(&uri)?,
+        _ => PathBuf::from(root),
+    };
+    path.is_dir().then_some(path)
+}
+
+/// Where a shared server's stderr goes.
+fn server_log(command: &str) -> Stdio {
+    let name = std::path::Path::new(command)
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| command.to_string());
+    let path = crate::services::log_dirs::lsp_log_dir().join(format!(
+        "proxy-{}-{}.log",
+        name,
+        std::process::id()
+    ));
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path);
+    match file {
+        Ok(file) => Stdio::from(file),
+        Err(_) => Stdio::null(),
+    }
+}
@@ -18,6 +18,7 @@ pub mod editor_server;
 pub mod input_parser;
 pub mod ipc;
 pub mod local_control;
+pub mod lsp_proxy;
 pub mod protocol;
 
 #[cfg(test)]
@@ -27,7 +28,8 @@ mod tests;
 
 pub use capture_backend::{terminal_setup_sequences, terminal_teardown_sequences, CaptureBackend};
 pub use daemon::{
-    daemonize, is_process_running, read_pid_file, spawn_server_detached, write_pid_file,
+    daemonize, is_process_running, read_pid_file, spawn_lsp_proxy_detached, spawn_server_detached,
+    write_pid_file,
 };
 pub use editor_server::{EditorServer, EditorServerConfig};
 pub use input_parser::InputParser;
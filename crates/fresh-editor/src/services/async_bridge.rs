@@ -120,6 +120,45 @@ pub enum AsyncMessage {
         server_name: String,
     },
 
+    /// Diagnostics from a background JSON-schema validation of a buffer.
+    /// Stored next to the LSP servers' diagnostics; an empty list clears
+    /// them. Results whose `generation` is no longer the buffer's latest
+    /// validation are dropped. Stored in `window`, the window that started
+    /// the validation, whichever window is active by then.
+    SchemaDiagnostics {
+        window: fresh_core::WindowId,
+        buffer_id: crate::model::event::BufferId,
+        generation: u64,
+        uri: String,
+        diagnostics: Vec<Diagnostic>,
+    },
+
+    /// Results of a command-line linter run (see `services::linters`), as
+    /// (file URI, diagnostics) pairs. A `workspace` run replaces all of the
+    /// linter's earlier results, so files it no longer reports are cleared.
+    /// Stored in `window`, the window that started the run.
+    LinterDiagnostics {
+        window: fresh_core::WindowId,
+        linter: String,
+        files: Vec<(String, Vec<Diagnostic>)>,
+        workspace: bool,
+    },
+
+    /// A `tags_command` run started by the window finished. `manual` runs
+    /// report success as well as failure.
+    TagsGenerated {
+        window: fresh_core::WindowId,
+        manual: bool,
+        error: Option<String>,
+    },
+
+    /// A file was opened in a window; runs the config hooks for
+    /// [`HookEvent::Open`](crate::config::HookEvent::Open), which need the
+    /// editor's job list.
+    FileOpenedForHooks {
+        buffer_id: crate::model::event::BufferId,
+    },
+
     /// LSP server initialized successfully
     LspInitialized {
         language: String,
@@ -458,6 +497,11 @@ pub enum AsyncMessage {
         complete: bool,
     },
 
+    /// A picker preview prefix finished loading on the file-preview
+    /// worker (see `services::file_preview`). Carries no content — the
+    /// preview is read back from the loader's cache on the next render.
+    FilePreviewLoaded { path: std::path::PathBuf },
+
     /// Startup-async: a single plugin directory finished loading on the
     /// plugin thread. Carries the same payload as the blocking
     /// `load_plugins_from_dir_with_config` return value.
@@ -0,0 +1,235 @@
+//! Read-only live view of a buffer over HTTP (the Serve Buffer command).
+//!
+//! A small blocking HTTP/1.1 server on its own thread. The editor renders
+//! the shared buffer to highlighted HTML (see [`super::styled_html`]) and
+//! hands it over with [`BufferShareServer::publish`]; the server only ever
+//! reads that snapshot, so browsers can't reach the editor itself.
+//!
+//! Routes:
+//!   - `GET /`        → the viewer page, with the current snapshot inlined
+//!   - `GET /version` → the snapshot's version number (plain text)
+//!   - `GET /content` → the snapshot's HTML fragment
+//!
+//! The page polls `/version` and swaps in `/content` when it changes, so
+//! there's no WebSocket or server push to keep alive.
+
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::primitives::text_escape::encode_html_entities;
+
+/// How long the accept loop sleeps when no connection is pending; also
+/// bounds how long [`Drop`] waits for the thread to notice the stop flag.
+const ACCEPT_POLL: Duration = Duration::from_millis(50);
+
+/// How often the viewer page polls `/version`.
+const VIEWER_POLL_MS: u64 = 500;
+
+#[derive(Default)]
+struct Snapshot {
+    version: u64,
+    title: String,
+    html: String,
+}
+
+/// A running Serve Buffer server. Stops when dropped.
+pub struct BufferShareServer {
+    addr: SocketAddr,
+    snapshot: Arc<Mutex<Snapshot>>,
+    stop: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl BufferShareServer {
+    /// Bind `address` (e.g. "127.0.0.1:0" for a free local port) and start
+    /// serving an empty view.
+    pub fn start(address: &str) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+        let addr = listener.local_addr()?;
+        let snapshot = Arc::new(Mutex::new(Snapshot::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread = {
+            let snapshot = Arc::clone(&snapshot);
+            let stop = Arc::clone(&stop);
+            std::thread::Builder::new()
+                .name("buffer-share".to_string())
+                .spawn(move || accept_loop(listener, &snapshot, &stop))?
+        };
+        Ok(Self {
+            addr,
+            snapshot,
+            stop,
+            thread: Some(thread),
+        })
+    }
+
+    /// The address actually bound (with the port the OS picked for port 0).
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// URL to hand to a browser. A wildcard bind is reported as loopback,
+    /// since "0.0.0.0" isn't something a browser can open.
+    pub fn url(&self) -> String {
+        let mut addr = self.addr;
+        if addr.ip().is_unspecified() {
+            addr.set_ip(if addr.is_ipv4() {
+                std::net::Ipv4Addr::LOCALHOST.into()
+            } else {
+                std::net::Ipv6Addr::LOCALHOST.into()
+            });
+        }
+        format!("http://{addr}/")
+    }
+
+    /// Replace the served view. `html` is a fragment (the highlighted
+    /// `<pre>` block); `title` is plain text.
+    pub fn publish(&self, title: &str, html: String) {
+        let mut snapshot = self.snapshot.lock().unwrap_or_else(|e| e.into_inner());
+        snapshot.version += 1;
+        snapshot.title = title.to_string();
+        snapshot.html = html;
+    }
+}
+
+impl Drop for BufferShareServer {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            drop(thread.join());
+        }
+    }
+}
+
+fn accept_loop(listener: TcpListener, snapshot: &Mutex<Snapshot>, stop: &AtomicBool) {
+    while !stop.load(Ordering::Relaxed) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                if let Err(e) = serve_connection(stream, snapshot) {
+                    tracing::debug!("buffer share: connection error: {}", e);
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => std::thread::sleep(ACCEPT_POLL),
+            Err(e) => {
+                tracing::warn!("buffer share: accept failed: {}", e);
+                std::thread::sleep(ACCEPT_POLL);
+            }
+        }
+    }
+}
+
+/// Answer one request and close the connection.
+fn serve_connection(stream: TcpStream, snapshot: &Mutex<Snapshot>) -> io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+    stream.set_write_timeout(Some(Duration::from_secs(2)))?;
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Drain the headers; nothing in them matters for a read-only view.
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("");
+    let path = path.split('?').next().unwrap_or(path);
+    let (status, content_type, body) = if method != "GET" {
+        ("405 Method Not Allowed", "text/plain", String::new())
+    } else {
+        let snapshot = snapshot.lock().unwrap_or_else(|e| e.into_inner());
+        match path {
+            "/" => ("200 OK", "text/html", viewer_page(&snapshot)),
+            "/version" => ("200 OK", "text/plain", snapshot.version.to_string()),
+            "/content" => ("200 OK", "text/html", snapshot.html.clone()),
+            _ => ("404 Not Found", "text/plain", String::new()),
+        }
+    };
+
+    let mut stream = reader.into_inner();
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}; charset=utf-8\r\n\
+         Content-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n",
+        body.len()
+    )?;
+    stream.write_all(body.as_bytes())?;
+    stream.flush()
+}
+
+fn viewer_page(snapshot: &Snapshot) -> String {
+    let title = encode_html_entities(&snapshot.title);
+    format!(
+        r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>{title}</title>
+<style>body{{margin:0;background:#111}}#view pre{{min-height:100vh;box-sizing:border-box;border-radius:0}}</style>
+</head>
+<body>
+<div id="view">{html}</div>
+<script>
+let version = "{version}";
+setInterval(async () => {{
+  try {{
+    const v = await (await fetch("/version")).text();
+    if (v !== version) {{
+      version = v;
+      document.getElementById("view").innerHTML = await (await fetch("/content")).text();
+    }}
+  }} catch (e) {{}}
+}}, {VIEWER_POLL_MS});
+</script>
+</body>
+</html>
+"#,
+        html = snapshot.html,
+        version = snapshot.version,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn get(server: &BufferShareServer, path: &str) -> String {
+        let mut stream = TcpStream::connect(server.addr()).unwrap();
+        write!(stream, "GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn serves_published_snapshot() {
+        let server = BufferShareServer::start("127.0.0.1:0").unwrap();
+        assert!(get(&server, "/version").ends_with("\r\n\r\n0"));
+
+        server.publish("a <b>.rs", "<pre>fn main() {}</pre>".to_string());
+        assert!(get(&server, "/version").ends_with("\r\n\r\n1"));
+        assert!(get(&server, "/content").ends_with("<pre>fn main() {}</pre>"));
+        let page = get(&server, "/");
+        assert!(page.starts_with("HTTP/1.1 200 OK"));
+        assert!(page.contains("<title>a &lt;b&gt;.rs</title>"));
+        assert!(page.contains("<pre>fn main() {}</pre>"));
+        assert!(get(&server, "/nope").starts_with("HTTP/1.1 404"));
+    }
+
+    #[test]
+    fn wildcard_bind_reports_loopback_url() {
+        let server = BufferShareServer::start("0.0.0.0:0").unwrap();
+        let url = server.url();
+        assert!(url.starts_with("http://127.0.0.1:"), "{url}");
+    }
+}
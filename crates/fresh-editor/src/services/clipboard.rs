@@ -7,12 +7,21 @@
 //! - Supports copying HTML-formatted text for rich text editors
 //! - Gracefully falls back to internal clipboard if system clipboard is unavailable
 //! - Respects clipboard configuration to disable problematic methods
+//! - Keeps a history ring of recent copies for paste-from-history, with
+//!   pinned entries and opt-in persistence to a file
 
 use crossterm::clipboard::CopyToClipboard;
 use crossterm::execute;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use std::io::{stdout, Write};
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
+/// Number of unpinned copies kept in the clipboard history. Pinned entries
+/// don't count towards it and are never dropped.
+pub const HISTORY_LEN: usize = 30;
+
 /// True when running inside Termux on Android.
 ///
 /// Termux exports `TERMUX_VERSION` and sets `$PREFIX` to a path under
@@ -103,6 +112,39 @@ pub fn read_system_clipboard() -> Option<String> {
     termux_clipboard_get()
 }
 
+/// Read a saved clipboard history. A missing or unreadable file yields an
+/// empty history.
+fn load_history_file(path: &Path) -> Vec<HistoryEntry> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    serde_json::from_str(&contents).unwrap_or_else(|e| {
+        tracing::warn!("Failed to parse clipboard history {:?}: {}", path, e);
+        Vec::new()
+    })
+}
+
+/// Save the clipboard history, readable only by the user since it may hold
+/// passwords and other secrets that were copied.
+///
+/// Like the Termux helpers this uses `std::fs` directly rather than the
+/// editor's filesystem authority: the clipboard belongs to the local
+/// machine, never to a remote host.
+fn save_history_file(path: &Path, history: &VecDeque<HistoryEntry>) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string(history)?;
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(json.as_bytes())
+}
+
 /// Global clipboard holder to maintain X11/Wayland clipboard ownership.
 ///
 /// On X11, the clipboard owner must stay alive to respond to paste requests.
@@ -177,6 +219,15 @@ pub struct PendingClipboard {
     pub use_system_clipboard: bool,
 }
 
+/// One copy in the clipboard history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub text: String,
+    /// Pinned entries survive the history filling up
+    #[serde(default)]
+    pub pinned: bool,
+}
+
 /// Clipboard manager that handles both internal and system clipboard
 #[derive(Debug, Clone, Default)]
 pub struct Clipboard {
@@ -193,6 +244,11 @@ pub struct Clipboard {
     session_mode: bool,
     /// Clipboard data pending delivery to clients (session mode only)
     pending_clipboard: Option<PendingClipboard>,
+    /// Recent copies, newest first, without duplicates
+    history: VecDeque<HistoryEntry>,
+    /// File the history is saved to after every change, when persistence
+    /// is enabled
+    history_file: Option<PathBuf>,
 }
 
 impl Clipboard {
@@ -205,6 +261,8 @@ impl Clipboard {
             use_system_clipboard: true,
             session_mode: false,
             pending_clipboard: None,
+            history: VecDeque::new(),
+            history_file: None,
         }
     }
 
@@ -254,6 +312,7 @@ impl Clipboard {
     /// Returns true if successful, false otherwise.
     pub fn copy_html(&mut self, html: &str, plain_text: &str) -> bool {
         self.internal = plain_text.to_string();
+        self.push_history(plain_text.to_string());
 
         if !self.use_system_clipboard {
             return false;
@@ -297,6 +356,7 @@ impl Clipboard {
     /// Methods can be disabled via clipboard configuration.
     pub fn copy(&mut self, text: String) {
         self.internal = text.clone();
+        self.push_history(text.clone());
 
         // In session mode, the server process has no terminal or display server.
         // Queue the text for delivery to clients via a control message instead.
@@ -359,6 +419,93 @@ impl Clipboard {
         }
     }
 
+    /// Recent copies, newest first
+    pub fn history(&self) -> impl Iterator<Item = &str> {
+        self.history.iter().map(|entry| entry.text.as_str())
+    }
+
+    /// The `index`-th most recent copy (0 is the newest)
+    pub fn history_entry(&self, index: usize) -> Option<&str> {
+        self.history.get(index).map(|entry| entry.text.as_str())
+    }
+
+    /// Number of entries in the history
+    pub fn history_len(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Whether history entry `index` is pinned
+    pub fn is_pinned(&self, index: usize) -> bool {
+        self.history.get(index).is_some_and(|entry| entry.pinned)
+    }
+
+    /// Pin or unpin history entry `index`. Returns the new pinned state, or
+    /// None if there is no such entry.
+    pub fn toggle_pin(&mut self, index: usize) -> Option<bool> {
+        let entry = self.history.get_mut(index)?;
+        entry.pinned = !entry.pinned;
+        let pinned = entry.pinned;
+        self.truncate_history();
+        self.save_history();
+        Some(pinned)
+    }
+
+    /// Persist the history to `path`, or stop persisting it with None.
+    ///
+    /// Switching to a new file merges the entries saved there behind the
+    /// ones copied this session, so a restart picks up where the last
+    /// session left off.
+    pub fn set_history_file(&mut self, path: Option<PathBuf>) {
+        if self.history_file == path {
+            return;
+        }
+        if let Some(path) = &path {
+            for entry in load_history_file(path) {
+                if !self.history.iter().any(|e| e.text == entry.text) {
+                    self.history.push_back(entry);
+                }
+            }
+            self.truncate_history();
+        }
+        self.history_file = path;
+    }
+
+    /// Record a copy at the front of the history. Copying text already in
+    /// the history moves it to the front instead of adding a duplicate,
+    /// keeping its pin.
+    fn push_history(&mut self, text: String) {
+        if text.is_empty() {
+            return;
+        }
+        let pinned = self.history.iter().any(|e| e.text == text && e.pinned);
+        self.history.retain(|entry| entry.text != text);
+        self.history.push_front(HistoryEntry { text, pinned });
+        self.truncate_history();
+        self.save_history();
+    }
+
+    /// Drop the oldest unpinned entries beyond [`HISTORY_LEN`].
+    fn truncate_history(&mut self) {
+        let mut unpinned = 0;
+        self.history.retain(|entry| {
+            if entry.pinned {
+                return true;
+            }
+            unpinned += 1;
+            unpinned <= HISTORY_LEN
+        });
+    }
+
+    /// Write the history to the history file, if persistence is enabled.
+    fn save_history(&self) {
+        let Some(path) = &self.history_file else {
+            return;
+        };
+        if let Err(e) = save_history_file(path, &self.history) {
+            tracing::warn!("Failed to save clipboard history to {:?}: {}", path, e);
+        }
+    }
+
     /// Check if clipboard is empty (checks both internal and system)
     pub fn is_empty(&self) -> bool {
         if !self.internal.is_empty() {
@@ -394,12 +541,74 @@ mod tests {
         assert_eq!(clipboard.get_internal(), "hello");
     }
 
+    #[test]
+    fn test_clipboard_history_newest_first_without_duplicates() {
+        let mut clipboard = Clipboard::new();
+        clipboard.set_session_mode(true);
+        clipboard.copy("one".to_string());
+        clipboard.copy("two".to_string());
+        clipboard.copy("one".to_string());
+        assert_eq!(clipboard.history().collect::<Vec<_>>(), vec!["one", "two"]);
+
+        for i in 0..HISTORY_LEN + 5 {
+            clipboard.copy(i.to_string());
+        }
+        assert_eq!(clipboard.history_len(), HISTORY_LEN);
+        assert_eq!(
+            clipboard.history_entry(0),
+            Some((HISTORY_LEN + 4).to_string().as_str())
+        );
+    }
+
+    #[test]
+    fn test_clipboard_history_keeps_pinned_entries() {
+        let mut clipboard = Clipboard::new();
+        clipboard.set_session_mode(true);
+        clipboard.copy("keep me".to_string());
+        assert_eq!(clipboard.toggle_pin(0), Some(true));
+
+        for i in 0..HISTORY_LEN + 5 {
+            clipboard.copy(i.to_string());
+        }
+        assert_eq!(clipboard.history_len(), HISTORY_LEN + 1);
+        assert_eq!(clipboard.history().last(), Some("keep me"));
+
+        // Copying a pinned entry again moves it to the front, still pinned.
+        clipboard.copy("keep me".to_string());
+        assert_eq!(clipboard.history_entry(0), Some("keep me"));
+        assert!(clipboard.is_pinned(0));
+    }
+
+    #[test]
+    fn test_clipboard_history_persists_to_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("clipboard_history.json");
+
+        let mut clipboard = Clipboard::new();
+        clipboard.set_session_mode(true);
+        clipboard.copy("before".to_string());
+        clipboard.set_history_file(Some(path.clone()));
+        clipboard.copy("saved".to_string());
+        clipboard.toggle_pin(0);
+
+        let mut restored = Clipboard::new();
+        restored.set_session_mode(true);
+        restored.copy("new session".to_string());
+        restored.set_history_file(Some(path));
+        assert_eq!(
+            restored.history().collect::<Vec<_>>(),
+            vec!["new session", "saved", "before"]
+        );
+        assert!(restored.is_pinned(1));
+    }
+
     #[test]
     fn test_clipboard_config_disables_osc52() {
         let mut clipboard = Clipboard::new();
         let config = crate::config::ClipboardConfig {
             use_osc52: false,
             use_system_clipboard: true,
+            ..Default::default()
         };
         clipboard.apply_config(&config);
         assert!(!clipboard.use_osc52);
@@ -412,6 +621,7 @@ mod tests {
         let config = crate::config::ClipboardConfig {
             use_osc52: true,
             use_system_clipboard: false,
+            ..Default::default()
         };
         clipboard.apply_config(&config);
         assert!(clipboard.use_osc52);
@@ -424,6 +634,7 @@ mod tests {
         let config = crate::config::ClipboardConfig {
             use_osc52: false,
             use_system_clipboard: false,
+            ..Default::default()
         };
         clipboard.apply_config(&config);
 
@@ -186,6 +186,35 @@ impl CompletionProvider for BufferWordProvider {
             }
         }
 
+        // Merge words from the project index (below every open buffer),
+        // nearer files first.
+        let project_dist_offset = 300_000 * (ctx.other_buffers.len() + 1);
+        for word in &ctx.project_words {
+            all_stats
+                .entry(word.text.to_lowercase())
+                .or_insert(WordStats {
+                    text: word.text.clone(),
+                    count: 1,
+                    nearest_offset: 0,
+                    nearest_dist: project_dist_offset + word.distance.min(20) * 10_000,
+                    in_viewport: false,
+                    grapheme_len: word.text.graphemes(true).count(),
+                });
+        }
+
+        // Then tag names, below every project word.
+        let tag_dist = project_dist_offset + 21 * 10_000;
+        for name in &ctx.tag_words {
+            all_stats.entry(name.to_lowercase()).or_insert(WordStats {
+                text: name.clone(),
+                count: 1,
+                nearest_offset: 0,
+                nearest_dist: tag_dist,
+                in_viewport: false,
+                grapheme_len: name.graphemes(true).count(),
+            });
+        }
+
         let mut scored: Vec<(i64, &WordStats)> = all_stats
             .values()
             .filter(|s| {
@@ -226,7 +255,7 @@ impl CompletionProvider for BufferWordProvider {
 
 #[cfg(test)]
 mod tests {
-    use super::super::provider::OtherBufferSlice;
+    use super::super::provider::{OtherBufferSlice, ProjectWord};
     use super::*;
 
     fn make_ctx(prefix: &str, cursor: usize, buf_len: usize) -> CompletionContext {
@@ -243,6 +272,8 @@ mod tests {
             word_chars_extra: String::new(),
             prefix_has_uppercase: prefix.chars().any(|c| c.is_uppercase()),
             other_buffers: Vec::new(),
+            project_words: Vec::new(),
+            tag_words: Vec::new(),
         }
     }
 
@@ -263,6 +294,8 @@ mod tests {
             word_chars_extra: String::new(),
             prefix_has_uppercase: false,
             other_buffers: Vec::new(),
+            project_words: Vec::new(),
+            tag_words: Vec::new(),
         };
         let result = provider.provide(&ctx, text);
         match result {
@@ -291,6 +324,8 @@ mod tests {
             word_chars_extra: String::new(),
             prefix_has_uppercase: false,
             other_buffers: Vec::new(),
+            project_words: Vec::new(),
+            tag_words: Vec::new(),
         };
         let result = provider.provide(&ctx, text);
         match result {
@@ -380,4 +415,56 @@ mod tests {
             _ => panic!("expected Ready"),
         }
     }
+
+    #[test]
+    fn project_words_rank_below_open_buffers_by_distance() {
+        let text = b"timeout_ms";
+        let provider = BufferWordProvider::new();
+        let mut ctx = make_ctx("time", 0, text.len());
+        ctx.project_words = vec![
+            ProjectWord {
+                text: "timestamp_far".into(),
+                distance: 4,
+            },
+            ProjectWord {
+                text: "timestamp_near".into(),
+                distance: 0,
+            },
+            ProjectWord {
+                text: "timeout_ms".into(),
+                distance: 0,
+            },
+        ];
+        let result = provider.provide(&ctx, text);
+        match result {
+            ProviderResult::Ready(candidates) => {
+                let labels: Vec<&str> = candidates.iter().map(|c| c.label.as_str()).collect();
+                assert_eq!(
+                    labels,
+                    vec!["timeout_ms", "timestamp_near", "timestamp_far"]
+                );
+            }
+            _ => panic!("expected Ready"),
+        }
+    }
+
+    #[test]
+    fn tag_words_rank_below_project_words() {
+        let text = b"";
+        let provider = BufferWordProvider::new();
+        let mut ctx = make_ctx("par", 0, text.len());
+        ctx.project_words = vec![ProjectWord {
+            text: "parse_far".into(),
+            distance: 20,
+        }];
+        ctx.tag_words = vec!["parse_config".into(), "parse_far".into()];
+        let result = provider.provide(&ctx, text);
+        match result {
+            ProviderResult::Ready(candidates) => {
+                let labels: Vec<&str> = candidates.iter().map(|c| c.label.as_str()).collect();
+                assert_eq!(labels, vec!["parse_far", "parse_config"]);
+            }
+            _ => panic!("expected Ready"),
+        }
+    }
 }
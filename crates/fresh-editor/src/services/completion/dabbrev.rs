@@ -204,6 +204,8 @@ mod tests {
             word_chars_extra: String::new(),
             prefix_has_uppercase: prefix.chars().any(|c| c.is_uppercase()),
             other_buffers: Vec::new(),
+            project_words: Vec::new(),
+            tag_words: Vec::new(),
         }
     }
 
@@ -261,6 +263,8 @@ mod tests {
             word_chars_extra: String::new(),
             prefix_has_uppercase: false,
             other_buffers: Vec::new(),
+            project_words: Vec::new(),
+            tag_words: Vec::new(),
         };
         let result = provider.provide(&ctx, text);
         match result {
@@ -0,0 +1,239 @@
+//! Filtering and ordering of LSP completion items while the popup is open.
+//!
+//! The server's items are kept for the life of the popup and re-ranked
+//! against the word before the cursor on every keystroke. Fuzzy matching
+//! uses the same scorer as the command palette, so `gtv` finds
+//! `get_text_value` and contiguous or word-boundary matches rank first.
+//! Items are matched on their label and on `filterText`; only label
+//! matches carry positions, since the label is what the popup shows.
+
+use lsp_types::CompletionItem;
+
+use crate::config::{CompletionMatching, CompletionSort};
+use crate::input::fuzzy::FuzzyMatcher;
+
+/// An LSP completion item that matched the typed text.
+#[derive(Debug)]
+pub struct RankedItem<'a> {
+    pub item: &'a CompletionItem,
+    /// Char indices in `item.label` matched by the typed text.
+    pub match_positions: Vec<usize>,
+    score: i32,
+}
+
+/// Keep the items matching `typed` and order them for the popup.
+///
+/// An empty `typed` keeps every item. Ties (and every comparison under
+/// [`CompletionSort::Server`]) fall back to the items' `sortText`; items the
+/// server sent without one keep the order they arrived in.
+pub fn rank_lsp_items<'a>(
+    items: &'a [CompletionItem],
+    typed: &str,
+    matching: CompletionMatching,
+    sort: CompletionSort,
+) -> Vec<RankedItem<'a>> {
+    let mut ranked: Vec<RankedItem<'a>> = if typed.is_empty() {
+        items
+            .iter()
+            .map(|item| RankedItem {
+                item,
+                match_positions: Vec::new(),
+                score: 0,
+            })
+            .collect()
+    } else {
+        match matching {
+            CompletionMatching::Fuzzy => fuzzy_matches(items, typed),
+            CompletionMatching::Prefix => prefix_matches(items, typed),
+        }
+    };
+
+    match sort {
+        CompletionSort::Score => {
+            ranked.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| server_order(a, b)))
+        }
+        CompletionSort::Server => ranked.sort_by(server_order),
+    }
+    ranked
+}
+
+/// The server's ordering of two items by `sortText`, with unkeyed items
+/// after keyed ones. Labels are deliberately not compared: that would put
+/// `print` ahead of a `println` the server listed first, and the sort is
+/// stable, so unkeyed items keep their arrival order.
+fn server_order(a: &RankedItem<'_>, b: &RankedItem<'_>) -> std::cmp::Ordering {
+    match (a.item.sort_text.as_deref(), b.item.sort_text.as_deref()) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    }
+}
+
+fn fuzzy_matches<'a>(items: &'a [CompletionItem], typed: &str) -> Vec<RankedItem<'a>> {
+    let mut matcher = FuzzyMatcher::new(typed);
+    items
+        .iter()
+        .filter_map(|item| {
+            let label = matcher.match_target(&item.label);
+            let filter = item
+                .filter_text
+                .as_deref()
+                .map(|text| matcher.match_target(text))
+                .filter(|m| m.matched);
+            if label.matched {
+                let score = filter.map_or(label.score, |f| f.score.max(label.score));
+                Some(RankedItem {
+                    item,
+                    match_positions: label.match_positions,
+                    score,
+                })
+            } else {
+                filter.map(|f| RankedItem {
+                    item,
+                    match_positions: Vec::new(),
+                    score: f.score,
+                })
+            }
+        })
+        .collect()
+}
+
+fn prefix_matches<'a>(items: &'a [CompletionItem], typed: &str) -> Vec<RankedItem<'a>> {
+    let typed = typed.to_lowercase();
+    let typed_len = typed.chars().count();
+    items
+        .iter()
+        .filter_map(|item| {
+            if item.label.to_lowercase().starts_with(&typed) {
+                Some(RankedItem {
+                    item,
+                    match_positions: (0..typed_len).collect(),
+                    score: 0,
+                })
+            } else if item
+                .filter_text
+                .as_ref()
+                .is_some_and(|ft| ft.to_lowercase().starts_with(&typed))
+            {
+                Some(RankedItem {
+                    item,
+                    match_positions: Vec::new(),
+                    score: 0,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(label: &str, sort_text: Option<&str>) -> CompletionItem {
+        CompletionItem {
+            label: label.to_string(),
+            sort_text: sort_text.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn labels(ranked: &[RankedItem<'_>]) -> Vec<String> {
+        ranked.iter().map(|r| r.item.label.clone()).collect()
+    }
+
+    #[test]
+    fn fuzzy_matches_subsequences_and_reports_label_positions() {
+        let items = vec![item("get_text_value", None), item("unrelated", None)];
+        let ranked = rank_lsp_items(
+            &items,
+            "gtv",
+            CompletionMatching::Fuzzy,
+            CompletionSort::Score,
+        );
+        assert_eq!(labels(&ranked), vec!["get_text_value"]);
+        assert_eq!(ranked[0].match_positions, vec![0, 4, 9]);
+    }
+
+    #[test]
+    fn prefix_rejects_subsequences() {
+        let items = vec![item("get_text_value", None), item("gtv_total", None)];
+        let ranked = rank_lsp_items(
+            &items,
+            "gtv",
+            CompletionMatching::Prefix,
+            CompletionSort::Score,
+        );
+        assert_eq!(labels(&ranked), vec!["gtv_total"]);
+        assert_eq!(ranked[0].match_positions, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn filter_text_matches_without_highlight() {
+        let mut with_filter = item("#[derive]", None);
+        with_filter.filter_text = Some("attr".to_string());
+        let items = vec![with_filter];
+        let ranked = rank_lsp_items(
+            &items,
+            "atr",
+            CompletionMatching::Fuzzy,
+            CompletionSort::Score,
+        );
+        assert_eq!(ranked.len(), 1);
+        assert!(ranked[0].match_positions.is_empty());
+    }
+
+    #[test]
+    fn score_sort_puts_better_matches_first_and_breaks_ties_by_sort_text() {
+        let items = vec![
+            item("format_bytes", Some("1")),
+            item("fmt", Some("3")),
+            item("fmt_two", Some("2")),
+        ];
+        let ranked = rank_lsp_items(
+            &items,
+            "fmt",
+            CompletionMatching::Fuzzy,
+            CompletionSort::Score,
+        );
+        assert_eq!(ranked[0].item.label, "fmt");
+        assert_eq!(ranked.last().unwrap().item.label, "format_bytes");
+    }
+
+    #[test]
+    fn server_sort_follows_sort_text() {
+        let items = vec![
+            item("fmt", Some("3")),
+            item("format_bytes", Some("1")),
+            item("fmt_two", Some("2")),
+        ];
+        let ranked = rank_lsp_items(
+            &items,
+            "fmt",
+            CompletionMatching::Fuzzy,
+            CompletionSort::Server,
+        );
+        assert_eq!(labels(&ranked), vec!["format_bytes", "fmt_two", "fmt"]);
+    }
+
+    #[test]
+    fn items_without_sort_text_keep_server_order() {
+        let items = vec![item("println", None), item("print", None)];
+        let ranked = rank_lsp_items(
+            &items,
+            "pr",
+            CompletionMatching::Fuzzy,
+            CompletionSort::Score,
+        );
+        assert_eq!(labels(&ranked), vec!["println", "print"]);
+    }
+
+    #[test]
+    fn empty_input_keeps_everything_in_sort_text_order() {
+        let items = vec![item("b", Some("2")), item("a", Some("1"))];
+        let ranked = rank_lsp_items(&items, "", CompletionMatching::Fuzzy, CompletionSort::Score);
+        assert_eq!(labels(&ranked), vec!["a", "b"]);
+    }
+}
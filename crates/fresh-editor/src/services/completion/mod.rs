@@ -30,7 +30,9 @@
 //! | dabbrev scan, buffer-word proximity scoring | Rust | Hot-path byte-level scanning must stay < 1 ms |
 //! | Fuzzy matching / Smith-Waterman scoring | Rust | O(mn) matrix work needs SIMD-friendly code |
 //! | LSP bridge (send request, receive response) | Rust | Already integrated, async I/O via tokio |
-//! | Static index (ctags-style) lookup | Rust | Sub-ms binary search on a sorted Vec |
+//! | Static index (`services::tags`) lookup | Rust | Sub-ms binary search on a sorted Vec |
+//! | Project word index (`project_words`) | Rust | Walked via the window's `FileSystem` (works over SSH), cached per project |
+//! | User snippet files (`snippets/*.json`) | Rust | Read from the config dir, cached by mtime |
 //! | Custom snippet / dictionary providers | TypeScript | Extensibility; content is small, latency tolerant |
 //! | Provider registration / lifecycle | TypeScript API | Plugins call `registerCompletionProvider()` |
 //! | Ghost-text rendering decision | Rust (view layer) | Must be frame-synchronous |
@@ -48,12 +50,15 @@
 
 pub mod buffer_words;
 pub mod dabbrev;
+pub mod lsp_ranking;
+pub mod project_words;
 pub mod provider;
 pub mod service;
+pub mod snippets;
 
 // Re-export the main types that the Editor needs.
 pub use provider::{
     CompletionCandidate, CompletionContext, CompletionProvider, CompletionSourceId,
-    OtherBufferSlice, ProviderResult,
+    OtherBufferSlice, ProjectWord, ProviderResult,
 };
 pub use service::CompletionService;
@@ -0,0 +1,630 @@
+//! Persistent project word index for buffer-word completion.
+//!
+//! Buffer-word completion only sees open buffers. This index extends it to
+//! the whole project: every text file under the project root is scanned for
+//! identifier-like words, and the result is kept as an inverted index keyed
+//! by lowercase word so a prefix lookup is a `BTreeMap` range scan.
+//!
+//! The walk goes through the window's [`FileSystem`], so SSH and container
+//! workspaces are indexed on the remote side exactly like local ones, and
+//! skips whatever the project's `.gitignore` files exclude. The index is
+//! cached as JSON in the project's state directory; on the next session only
+//! files whose size or mtime changed are read again.
+//!
+//! Building runs once per project on a worker thread, started by the first
+//! completion request. Until it finishes, lookups simply return nothing.
+//! Saved buffers are folded back in with [`ProjectWords::update_file`].
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+use std::time::UNIX_EPOCH;
+
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ignore::Match;
+use serde::{Deserialize, Serialize};
+
+use super::provider::{smart_case_matches, ProjectWord};
+use crate::model::filesystem::{FileMetadata, FileSystem};
+
+/// Directories never descended into.
+const IGNORED_DIRS: &[&str] = &[
+    ".git",
+    "node_modules",
+    "target",
+    "__pycache__",
+    ".hg",
+    ".svn",
+    ".DS_Store",
+];
+
+/// Stop indexing after this many files.
+const MAX_FILES: usize = 20_000;
+
+/// Files larger than this are skipped (generated or data files).
+pub const MAX_FILE_BYTES: u64 = 512 * 1024;
+
+/// Distinct words kept per file.
+const MAX_WORDS_PER_FILE: usize = 5_000;
+
+/// Words shorter than this (in chars) are not worth completing.
+const MIN_WORD_CHARS: usize = 3;
+
+/// Words longer than this are almost always hashes or base64 noise.
+const MAX_WORD_CHARS: usize = 64;
+
+/// Files consulted per word when computing its distance.
+const MAX_FILES_PER_WORD: usize = 256;
+
+/// Bumped whenever the cache format or word extraction changes.
+const CACHE_VERSION: u32 = 1;
+
+/// One indexed file. Paths are relative to the project root with `/`
+/// separators, as reported by `FileSystem::walk_files`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct IndexedFile {
+    path: String,
+    size: u64,
+    mtime: u64,
+    words: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheFile {
+    version: u32,
+    files: Vec<IndexedFile>,
+}
+
+/// Files a word occurs in, keyed by lowercase word in the index.
+#[derive(Debug)]
+struct WordEntry {
+    text: String,
+    files: Vec<usize>,
+}
+
+/// Inverted word → file index over one project.
+#[derive(Debug, Default)]
+pub struct ProjectWordIndex {
+    files: Vec<IndexedFile>,
+    by_path: HashMap<String, usize>,
+    words: BTreeMap<String, WordEntry>,
+}
+
+impl ProjectWordIndex {
+    /// Walk `root` through `fs` and index every text file. Entries from
+    /// `previous` whose size and mtime still match are reused without
+    /// reading the file again.
+    pub fn build(
+        fs: &dyn FileSystem,
+        root: &Path,
+        previous: Option<ProjectWordIndex>,
+        cancel: &AtomicBool,
+    ) -> Self {
+        let mut previous: HashMap<String, IndexedFile> = previous
+            .map(|p| p.files.into_iter().map(|f| (f.path.clone(), f)).collect())
+            .unwrap_or_default();
+
+        let mut gitignores = GitignoreFilter::new(root);
+        let mut files = Vec::new();
+        // Errors (e.g., root doesn't exist) leave whatever was collected.
+        drop(fs.walk_files(root, IGNORED_DIRS, cancel, &mut |path, rel| {
+            if gitignores.is_ignored(fs, path) {
+                return true;
+            }
+            if let Some(file) = index_file(fs, path, rel, previous.remove(rel)) {
+                files.push(file);
+            }
+            files.len() < MAX_FILES
+        }));
+
+        Self::from_files(files)
+    }
+
+    fn from_files(files: Vec<IndexedFile>) -> Self {
+        let mut index = Self::default();
+        for file in files {
+            index.insert_file(file);
+        }
+        index
+    }
+
+    fn insert_file(&mut self, file: IndexedFile) {
+        let id = self.files.len();
+        for word in &file.words {
+            self.words
+                .entry(word.to_lowercase())
+                .or_insert_with(|| WordEntry {
+                    text: word.clone(),
+                    files: Vec::new(),
+                })
+                .files
+                .push(id);
+        }
+        self.by_path.insert(file.path.clone(), id);
+        self.files.push(file);
+    }
+
+    /// Replace the words recorded for `rel_path` with those in `text`.
+    pub fn update_file(&mut self, rel_path: &str, text: &str, size: u64, mtime: u64) {
+        let words = extract_words(text);
+        let Some(&id) = self.by_path.get(rel_path) else {
+            if self.files.len() < MAX_FILES {
+                self.insert_file(IndexedFile {
+                    path: rel_path.to_string(),
+                    size,
+                    mtime,
+                    words,
+                });
+            }
+            return;
+        };
+
+        // The index is keyed by lowercase word, so diff on that key: a word
+        // that only changed case still belongs to this file.
+        let old: HashSet<String> = self.files[id]
+            .words
+            .iter()
+            .map(|w| w.to_lowercase())
+            .collect();
+        let new: HashMap<String, &String> = words.iter().map(|w| (w.to_lowercase(), w)).collect();
+        for key in old.iter().filter(|k| !new.contains_key(*k)) {
+            if let Some(entry) = self.words.get_mut(key) {
+                entry.files.retain(|&f| f != id);
+                if entry.files.is_empty() {
+                    self.words.remove(key);
+                }
+            }
+        }
+        for (key, word) in new.into_iter().filter(|(k, _)| !old.contains(k)) {
+            let entry = self.words.entry(key).or_insert_with(|| WordEntry {
+                text: word.clone(),
+                files: Vec::new(),
+            });
+            if !entry.files.contains(&id) {
+                entry.files.push(id);
+            }
+        }
+
+        let file = &mut self.files[id];
+        file.words = words;
+        file.size = size;
+        file.mtime = mtime;
+    }
+
+    /// Words starting with `prefix` (smart-case), nearest to `from` first.
+    ///
+    /// `from` is the edited file's path relative to the project root; `None`
+    /// measures distance from the root itself. The prefix itself is never
+    /// returned.
+    pub fn matching(
+        &self,
+        prefix: &str,
+        prefix_has_uppercase: bool,
+        from: Option<&str>,
+        limit: usize,
+    ) -> Vec<ProjectWord> {
+        let key = prefix.to_lowercase();
+        if key.is_empty() {
+            return Vec::new();
+        }
+        let from_dir = from.map(parent_dir).unwrap_or("");
+
+        let mut found: Vec<ProjectWord> = self
+            .words
+            .range(key.clone()..)
+            .take_while(|(k, _)| k.starts_with(&key))
+            .filter(|(k, e)| {
+                **k != key && smart_case_matches(&e.text, prefix, prefix_has_uppercase)
+            })
+            .map(|(_, e)| ProjectWord {
+                text: e.text.clone(),
+                distance: e
+                    .files
+                    .iter()
+                    .take(MAX_FILES_PER_WORD)
+                    .map(|&f| dir_distance(from_dir, parent_dir(&self.files[f].path)))
+                    .min()
+                    .unwrap_or(usize::MAX),
+            })
+            .collect();
+
+        found.sort_by(|a, b| a.distance.cmp(&b.distance).then(a.text.cmp(&b.text)));
+        found.truncate(limit);
+        found
+    }
+
+    /// Number of indexed files.
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Load a cache written by [`Self::save`]. Missing, unreadable or
+    /// outdated caches yield `None`.
+    pub fn load(fs: &dyn FileSystem, cache_path: &Path) -> Option<Self> {
+        let bytes = fs.read_file(cache_path).ok()?;
+        let cache: CacheFile = serde_json::from_slice(&bytes).ok()?;
+        (cache.version == CACHE_VERSION).then(|| Self::from_files(cache.files))
+    }
+
+    /// Write the index to `cache_path`, creating its directory.
+    pub fn save(&self, fs: &dyn FileSystem, cache_path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = cache_path.parent() {
+            fs.create_dir_all(parent)?;
+        }
+        let cache = CacheFile {
+            version: CACHE_VERSION,
+            files: self.files.clone(),
+        };
+        fs.write_file(cache_path, &serde_json::to_vec(&cache)?)
+    }
+}
+
+/// `.gitignore` rules of the directories between a walked file and the
+/// project root, loaded through the walking filesystem on first use.
+struct GitignoreFilter {
+    root: PathBuf,
+    /// Parsed rules per directory; `None` when it has no `.gitignore`.
+    by_dir: HashMap<PathBuf, Option<Gitignore>>,
+}
+
+impl GitignoreFilter {
+    fn new(root: &Path) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            by_dir: HashMap::new(),
+        }
+    }
+
+    /// Whether `path` is excluded. The deepest `.gitignore` with a matching
+    /// rule decides, so a nested `!pattern` re-includes what a parent
+    /// directory ignored.
+    fn is_ignored(&mut self, fs: &dyn FileSystem, path: &Path) -> bool {
+        let Some(parent) = path.parent() else {
+            return false;
+        };
+        for dir in parent.ancestors() {
+            if !dir.starts_with(&self.root) {
+                break;
+            }
+            let gitignore = self
+                .by_dir
+                .entry(dir.to_path_buf())
+                .or_insert_with(|| load_gitignore(fs, dir));
+            if let Some(gitignore) = gitignore {
+                match gitignore.matched_path_or_any_parents(path, false) {
+                    Match::Ignore(_) => return true,
+                    Match::Whitelist(_) => return false,
+                    Match::None => {}
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Parse `dir/.gitignore`, or `None` when it is missing or unreadable.
+fn load_gitignore(fs: &dyn FileSystem, dir: &Path) -> Option<Gitignore> {
+    let source = dir.join(".gitignore");
+    let contents = fs.read_file(&source).ok()?;
+    let mut builder = GitignoreBuilder::new(dir);
+    for line in contents.split(|&b| b == b'\n') {
+        let line = std::str::from_utf8(line).unwrap_or("");
+        if let Err(e) = builder.add_line(Some(source.clone()), line.trim_end_matches('\r')) {
+            tracing::debug!("Malformed .gitignore line in {:?}: {}", source, e);
+        }
+    }
+    builder.build().ok()
+}
+
+/// Index one walked file, reusing `previous` when the file is unchanged.
+fn index_file(
+    fs: &dyn FileSystem,
+    path: &Path,
+    rel: &str,
+    previous: Option<IndexedFile>,
+) -> Option<IndexedFile> {
+    let meta = fs.metadata(path).ok()?;
+    if meta.size > MAX_FILE_BYTES {
+        return None;
+    }
+    let mtime = mtime_secs(&meta);
+    if let Some(prev) = previous {
+        if prev.size == meta.size && prev.mtime == mtime {
+            return Some(prev);
+        }
+    }
+
+    let bytes = fs.read_file(path).ok()?;
+    // Binary files: a NUL byte near the start is the usual giveaway.
+    if bytes.iter().take(8192).any(|&b| b == 0) {
+        return None;
+    }
+    let text = String::from_utf8_lossy(&bytes);
+    Some(IndexedFile {
+        path: rel.to_string(),
+        size: meta.size,
+        mtime,
+        words: extract_words(&text),
+    })
+}
+
+/// Distinct identifier-like words in `text`, in first-occurrence order.
+fn extract_words(text: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut words = Vec::new();
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
+
+    for word in text.split(|c: char| !is_word_char(c)) {
+        let len = word.chars().count();
+        if !(MIN_WORD_CHARS..=MAX_WORD_CHARS).contains(&len)
+            || !word.chars().any(char::is_alphabetic)
+        {
+            continue;
+        }
+        if seen.insert(word) {
+            words.push(word.to_string());
+            if words.len() >= MAX_WORDS_PER_FILE {
+                break;
+            }
+        }
+    }
+    words
+}
+
+fn mtime_secs(meta: &FileMetadata) -> u64 {
+    meta.modified
+        .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn parent_dir(rel_path: &str) -> &str {
+    rel_path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("")
+}
+
+/// Directory hops from `a` to `b` through their common ancestor.
+fn dir_distance(a: &str, b: &str) -> usize {
+    let a: Vec<&str> = a.split('/').filter(|s| !s.is_empty()).collect();
+    let b: Vec<&str> = b.split('/').filter(|s| !s.is_empty()).collect();
+    let common = a.iter().zip(&b).take_while(|(x, y)| x == y).count();
+    (a.len() - common) + (b.len() - common)
+}
+
+#[derive(Default)]
+struct State {
+    root: Option<PathBuf>,
+    index: Option<ProjectWordIndex>,
+    building: bool,
+    /// Cancel flag of the build in progress.
+    cancel: Arc<AtomicBool>,
+}
+
+/// Shared handle to a window's project word index.
+///
+/// Cheap to clone; the worker thread that builds the index writes into the
+/// same state the editor reads from.
+#[derive(Clone, Default)]
+pub struct ProjectWords {
+    state: Arc<Mutex<State>>,
+}
+
+impl ProjectWords {
+    /// Start indexing `root` unless it is already indexed or being indexed.
+    /// A different root cancels any build in progress and discards the old
+    /// index.
+    ///
+    /// The project is walked through `fs`; the cache at `cache_path` lives
+    /// in the editor's state directory and is read and written through
+    /// `cache_fs`, the host filesystem, even when the project is remote.
+    ///
+    /// With `background == false` the index is built on the calling thread,
+    /// which keeps tests deterministic.
+    pub fn ensure_built(
+        &self,
+        fs: Arc<dyn FileSystem + Send + Sync>,
+        cache_fs: Arc<dyn FileSystem + Send + Sync>,
+        root: &Path,
+        cache_path: PathBuf,
+        background: bool,
+    ) {
+        use std::sync::atomic::Ordering;
+
+        let cancel = {
+            let mut state = self.state.lock().unwrap();
+            if state.root.as_deref() == Some(root) && (state.index.is_some() || state.building) {
+                return;
+            }
+            state.cancel.store(true, Ordering::Relaxed);
+            state.cancel = Arc::new(AtomicBool::new(false));
+            state.root = Some(root.to_path_buf());
+            state.index = None;
+            state.building = true;
+            Arc::clone(&state.cancel)
+        };
+
+        let shared = Arc::clone(&self.state);
+        let root = root.to_path_buf();
+        let job = move || {
+            let previous = ProjectWordIndex::load(cache_fs.as_ref(), &cache_path);
+            let index = ProjectWordIndex::build(fs.as_ref(), &root, previous, &cancel);
+            if cancel.load(Ordering::Relaxed) {
+                return;
+            }
+            if let Err(e) = index.save(cache_fs.as_ref(), &cache_path) {
+                tracing::debug!("Failed to save project word index: {}", e);
+            }
+            tracing::debug!(
+                "Indexed {} files for project word completion",
+                index.file_count()
+            );
+            let mut state = shared.lock().unwrap();
+            if state.root.as_deref() == Some(root.as_path()) {
+                state.index = Some(index);
+                state.building = false;
+            }
+        };
+
+        if !background {
+            job();
+            return;
+        }
+        if let Err(e) = std::thread::Builder::new()
+            .name("project-words".to_string())
+            .spawn(job)
+        {
+            tracing::warn!("Failed to spawn project word indexer: {}", e);
+            self.state.lock().unwrap().building = false;
+        }
+    }
+
+    /// Look up words in the index; empty while it is still being built.
+    pub fn matching(
+        &self,
+        prefix: &str,
+        prefix_has_uppercase: bool,
+        from: Option<&str>,
+        limit: usize,
+    ) -> Vec<ProjectWord> {
+        let state = self.state.lock().unwrap();
+        state
+            .index
+            .as_ref()
+            .map(|index| index.matching(prefix, prefix_has_uppercase, from, limit))
+            .unwrap_or_default()
+    }
+
+    /// Fold a saved file's contents into the index. `path` is stat'ed
+    /// through `fs` so the recorded size and mtime match what the next
+    /// build sees. No-op until the index has been built, and for files too
+    /// large to index.
+    pub fn update_file(&self, fs: &dyn FileSystem, path: &Path, rel_path: &str, text: &str) {
+        let Ok(meta) = fs.metadata(path) else {
+            return;
+        };
+        if meta.size > MAX_FILE_BYTES {
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        if let Some(index) = state.index.as_mut() {
+            index.update_file(rel_path, text, meta.size, mtime_secs(&meta));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::filesystem::StdFileSystem;
+
+    fn labels(words: &[ProjectWord]) -> Vec<&str> {
+        words.iter().map(|w| w.text.as_str()).collect()
+    }
+
+    #[test]
+    fn extracts_identifier_words() {
+        let words = extract_words("log_level = debug\nlog_level: 42 x1 ab");
+        assert_eq!(words, vec!["log_level", "debug"]);
+    }
+
+    #[test]
+    fn nearer_files_rank_first() {
+        let index = ProjectWordIndex::from_files(vec![
+            IndexedFile {
+                path: "deploy/prod/app.yaml".into(),
+                size: 0,
+                mtime: 0,
+                words: vec!["replicas_far".into()],
+            },
+            IndexedFile {
+                path: "config/app.toml".into(),
+                size: 0,
+                mtime: 0,
+                words: vec!["replicas_near".into(), "timeout".into()],
+            },
+        ]);
+        let found = index.matching("repl", false, Some("config/local.toml"), 10);
+        assert_eq!(labels(&found), vec!["replicas_near", "replicas_far"]);
+        assert_eq!(found[0].distance, 0);
+        assert_eq!(found[1].distance, 3);
+    }
+
+    #[test]
+    fn update_file_replaces_words() {
+        let mut index = ProjectWordIndex::from_files(vec![IndexedFile {
+            path: "notes.txt".into(),
+            size: 0,
+            mtime: 0,
+            words: vec!["obsolete_word".into()],
+        }]);
+        index.update_file("notes.txt", "fresh_word", 10, 1);
+        assert!(index.matching("obs", false, None, 10).is_empty());
+        assert_eq!(
+            labels(&index.matching("fre", false, None, 10)),
+            vec!["fresh_word"]
+        );
+    }
+
+    #[test]
+    fn update_file_keeps_words_that_only_changed_case() {
+        let mut index = ProjectWordIndex::from_files(vec![IndexedFile {
+            path: "notes.txt".into(),
+            size: 0,
+            mtime: 0,
+            words: vec!["Widget".into()],
+        }]);
+        index.update_file("notes.txt", "widget", 6, 1);
+        assert_eq!(
+            labels(&index.matching("wid", false, None, 10)),
+            vec!["Widget"]
+        );
+        index.update_file("notes.txt", "gadget", 6, 2);
+        assert!(index.matching("wid", false, None, 10).is_empty());
+    }
+
+    #[test]
+    fn build_skips_gitignored_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("gen/keep")).unwrap();
+        std::fs::write(root.join(".gitignore"), "*.log\ngen/\n").unwrap();
+        std::fs::write(root.join("gen/.gitignore"), "!keep/\n").unwrap();
+        std::fs::write(root.join("app.rs"), "let source_word = 1;").unwrap();
+        std::fs::write(root.join("debug.log"), "source_logged").unwrap();
+        std::fs::write(root.join("gen/out.rs"), "source_generated").unwrap();
+        std::fs::write(root.join("gen/keep/api.rs"), "source_kept").unwrap();
+
+        let index = ProjectWordIndex::build(&StdFileSystem, root, None, &AtomicBool::new(false));
+        assert_eq!(
+            labels(&index.matching("sour", false, None, 10)),
+            vec!["source_word", "source_kept"]
+        );
+    }
+
+    #[test]
+    fn build_reads_project_and_persists_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("project");
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::create_dir_all(root.join("target")).unwrap();
+        std::fs::write(root.join("docs/guide.md"), "Configure the frobnicator.").unwrap();
+        std::fs::write(root.join("target/out.txt"), "frobnicated_build").unwrap();
+        std::fs::write(root.join("blob.bin"), b"frob\0nicate").unwrap();
+
+        let cache = dir.path().join("state/word_index.json");
+        let words = ProjectWords::default();
+        words.ensure_built(
+            Arc::new(StdFileSystem),
+            Arc::new(StdFileSystem),
+            &root,
+            cache.clone(),
+            false,
+        );
+
+        let found = words.matching("frob", false, None, 10);
+        assert_eq!(labels(&found), vec!["frobnicator"]);
+
+        let cached = ProjectWordIndex::load(&StdFileSystem, &cache).unwrap();
+        assert_eq!(cached.file_count(), 1);
+    }
+}
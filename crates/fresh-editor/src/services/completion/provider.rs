@@ -83,6 +83,17 @@ pub struct OtherBufferSlice {
     pub label: String,
 }
 
+/// A word from the project word index, for project-wide buffer-word
+/// completion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectWord {
+    /// The word text (original casing).
+    pub text: String,
+    /// Directory hops between the file the word was found in and the file
+    /// being edited (0 = same directory).
+    pub distance: usize,
+}
+
 /// Context passed to every provider when completion is requested.
 ///
 /// All byte ranges are clamped to valid buffer positions by the service
@@ -141,6 +152,14 @@ pub struct CompletionContext {
     /// Pre-sliced byte windows from other open buffers, ordered by MRU
     /// (most recently used first). Enables multi-buffer dabbrev scanning.
     pub other_buffers: Vec<OtherBufferSlice>,
+
+    /// Words from the persistent project word index that match the prefix,
+    /// nearest file first. Ranked below words from open buffers.
+    pub project_words: Vec<ProjectWord>,
+
+    /// Symbol names from the project's tags file that match the prefix.
+    /// Ranked below project words.
+    pub tag_words: Vec<String>,
 }
 
 /// Maximum scan radius (in bytes) around the cursor for normal files.
@@ -7,6 +7,8 @@
 //! ## Provider lifecycle
 //!
 //! 1. Built-in providers (dabbrev, buffer-words) are registered at startup.
+//!    Each window also registers the user snippet provider, which needs the
+//!    config dir.
 //! 2. The LSP provider is always registered but returns `Pending` — its
 //!    results arrive asynchronously and are fed in via `supply_async_results`.
 //! 3. TypeScript plugins register providers dynamically via the plugin API.
@@ -207,6 +209,8 @@ mod tests {
             word_chars_extra: String::new(),
             prefix_has_uppercase: false,
             other_buffers: Vec::new(),
+            project_words: Vec::new(),
+            tag_words: Vec::new(),
         }
     }
 
@@ -0,0 +1,317 @@
+//! User snippet completion provider.
+//!
+//! Snippets live in the `snippets/` directory of the config dir, one file per
+//! language named after the language id (`rust.json`, `python.json`, …) plus
+//! `global.json` for snippets offered in every language. The format is the
+//! VS Code one:
+//!
+//! ```json
+//! {
+//!   "For loop": {
+//!     "prefix": "for",
+//!     "body": ["for ${1:item} in ${2:items} {", "\t$0", "}"],
+//!     "description": "Loop over an iterator"
+//!   }
+//! }
+//! ```
+//!
+//! `prefix` may be a string or a list, `body` a string or a list of lines.
+//! Bodies use LSP snippet syntax (see [`crate::primitives::snippet`]).
+//!
+//! Parsed files are cached by path and re-read only when their modification
+//! time or size changes, so edits apply without a restart and looking up
+//! snippets on every Tab press stays cheap.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use serde::Deserialize;
+
+use super::provider::{
+    case_mismatch_penalty, smart_case_matches, CompletionCandidate, CompletionContext,
+    CompletionProvider, CompletionSourceId, ProviderResult,
+};
+
+/// File holding the snippets offered in every language.
+const GLOBAL_FILE: &str = "global.json";
+
+/// Snippets rank above buffer words: the user wrote them for this prefix.
+const SNIPPET_SCORE: i64 = 1_000_000;
+
+/// A snippet from a user snippet file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserSnippet {
+    /// Key in the snippet file (e.g. "For loop").
+    pub name: String,
+    /// Words that offer the snippet in completion.
+    pub prefixes: Vec<String>,
+    /// Body in LSP snippet syntax.
+    pub body: String,
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OneOrMany {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl OneOrMany {
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            Self::One(s) => vec![s],
+            Self::Many(v) => v,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct SnippetEntry {
+    prefix: Option<OneOrMany>,
+    body: OneOrMany,
+    description: Option<String>,
+}
+
+/// Parse one snippet file, sorted by name.
+pub fn parse_snippet_file(content: &str) -> Result<Vec<UserSnippet>, serde_json::Error> {
+    let entries: HashMap<String, SnippetEntry> = serde_json::from_str(content)?;
+    let mut snippets: Vec<UserSnippet> = entries
+        .into_iter()
+        .map(|(name, entry)| UserSnippet {
+            prefixes: entry.prefix.map(OneOrMany::into_vec).unwrap_or_default(),
+            body: entry.body.into_vec().join("\n"),
+            description: entry.description,
+            name,
+        })
+        .collect();
+    snippets.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(snippets)
+}
+
+/// The snippet files that apply to `language`, most specific first.
+fn snippet_files(dir: &Path, language: &str) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    if !language.is_empty() && !language.contains(['/', '\\']) {
+        files.push(dir.join(format!("{}.json", language)));
+    }
+    files.push(dir.join(GLOBAL_FILE));
+    files
+}
+
+fn load_file(path: &Path) -> Vec<UserSnippet> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    parse_snippet_file(&content).unwrap_or_else(|e| {
+        tracing::warn!("Failed to parse snippet file {}: {}", path.display(), e);
+        Vec::new()
+    })
+}
+
+/// `(mtime, size)` of a snippet file, or `None` when it doesn't exist.
+type FileStamp = Option<(Option<SystemTime>, u64)>;
+
+/// A parsed snippet file and the stamp it was read at.
+struct CachedFile {
+    stamp: FileStamp,
+    snippets: Vec<UserSnippet>,
+}
+
+/// The user snippet files, parsed on first use and cached by path until
+/// their stamp changes. Shared by completion and the snippet commands.
+pub struct SnippetLibrary {
+    dir: PathBuf,
+    cache: Mutex<HashMap<PathBuf, CachedFile>>,
+}
+
+impl SnippetLibrary {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// The snippets available in `language`: its own file, then the global
+    /// one. Only files that changed since the last call are read again.
+    pub fn snippets(&self, language: &str) -> Vec<UserSnippet> {
+        let Ok(mut cache) = self.cache.lock() else {
+            return Vec::new();
+        };
+        let mut snippets = Vec::new();
+        for path in snippet_files(&self.dir, language) {
+            let stamp = std::fs::metadata(&path)
+                .ok()
+                .map(|m| (m.modified().ok(), m.len()));
+            if cache.get(&path).is_none_or(|cached| cached.stamp != stamp) {
+                let fresh = read_file(&path, stamp);
+                cache.insert(path.clone(), fresh);
+            }
+            if let Some(cached) = cache.get(&path) {
+                snippets.extend(cached.snippets.iter().cloned());
+            }
+        }
+        snippets
+    }
+}
+
+fn read_file(path: &Path, stamp: FileStamp) -> CachedFile {
+    CachedFile {
+        stamp,
+        snippets: if stamp.is_some() {
+            load_file(path)
+        } else {
+            Vec::new()
+        },
+    }
+}
+
+/// Offers user snippets whose prefix starts with the typed word.
+pub struct SnippetProvider {
+    library: Arc<SnippetLibrary>,
+}
+
+impl SnippetProvider {
+    pub fn new(library: Arc<SnippetLibrary>) -> Self {
+        Self { library }
+    }
+}
+
+impl CompletionProvider for SnippetProvider {
+    fn id(&self) -> CompletionSourceId {
+        CompletionSourceId("snippets".into())
+    }
+
+    fn display_name(&self) -> &str {
+        "Snippets"
+    }
+
+    fn is_enabled(&self, ctx: &CompletionContext) -> bool {
+        !ctx.prefix.is_empty() && ctx.language_id.is_some()
+    }
+
+    fn provide(&self, ctx: &CompletionContext, _buffer_window: &[u8]) -> ProviderResult {
+        let language = ctx.language_id.as_deref().unwrap_or_default();
+        let mut candidates = Vec::new();
+        for snippet in self.library.snippets(language) {
+            for prefix in &snippet.prefixes {
+                if !smart_case_matches(prefix, &ctx.prefix, ctx.prefix_has_uppercase) {
+                    continue;
+                }
+                candidates.push(CompletionCandidate {
+                    label: prefix.clone(),
+                    insert_text: Some(snippet.body.clone()),
+                    detail: Some(snippet.description.clone().unwrap_or(snippet.name.clone())),
+                    icon: Some("s".to_string()),
+                    score: SNIPPET_SCORE
+                        + case_mismatch_penalty(prefix, &ctx.prefix, ctx.prefix_has_uppercase),
+                    source: None,
+                    is_snippet: true,
+                    provider_data: None,
+                });
+            }
+        }
+        ProviderResult::Ready(candidates)
+    }
+
+    fn priority(&self) -> u32 {
+        10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_vscode_snippet_files() {
+        let snippets = parse_snippet_file(
+            r#"{
+                "For loop": {
+                    "prefix": ["for", "loop"],
+                    "body": ["for ${1:x} in ${2:xs} {", "\t$0", "}"],
+                    "description": "Loop"
+                },
+                "Todo": { "prefix": "todo", "body": "// TODO: $0" }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(snippets.len(), 2);
+        assert_eq!(snippets[0].name, "For loop");
+        assert_eq!(snippets[0].prefixes, vec!["for", "loop"]);
+        assert_eq!(snippets[0].body, "for ${1:x} in ${2:xs} {\n\t$0\n}");
+        assert_eq!(snippets[1].description, None);
+    }
+
+    #[test]
+    fn offers_language_and_global_snippets() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("rust.json"),
+            r#"{ "Print": { "prefix": "pln", "body": "println!(\"$1\");" } }"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("global.json"),
+            r#"{ "Plan": { "prefix": "plan", "body": "PLAN: $0" } }"#,
+        )
+        .unwrap();
+        let provider =
+            SnippetProvider::new(Arc::new(SnippetLibrary::new(dir.path().to_path_buf())));
+
+        let ctx = |language: &str| CompletionContext {
+            prefix: "pl".into(),
+            cursor_byte: 2,
+            word_start_byte: 0,
+            buffer_len: 2,
+            is_large_file: false,
+            scan_range: 0..2,
+            viewport_top_byte: 0,
+            viewport_bottom_byte: 2,
+            language_id: Some(language.into()),
+            word_chars_extra: String::new(),
+            prefix_has_uppercase: false,
+            other_buffers: Vec::new(),
+            project_words: Vec::new(),
+            tag_words: Vec::new(),
+        };
+        let labels = |language: &str| match provider.provide(&ctx(language), b"pl") {
+            ProviderResult::Ready(candidates) => {
+                candidates.into_iter().map(|c| c.label).collect::<Vec<_>>()
+            }
+            ProviderResult::Pending(_) => Vec::new(),
+        };
+        assert_eq!(labels("rust"), vec!["pln", "plan"]);
+        assert_eq!(labels("python"), vec!["plan"]);
+    }
+
+    #[test]
+    fn rereads_only_changed_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let global = dir.path().join("global.json");
+        std::fs::write(&global, r#"{ "A": { "prefix": "aa", "body": "a" } }"#).unwrap();
+        let library = SnippetLibrary::new(dir.path().to_path_buf());
+        let names = |library: &SnippetLibrary| -> Vec<String> {
+            library
+                .snippets("rust")
+                .into_iter()
+                .map(|s| s.name)
+                .collect()
+        };
+        assert_eq!(names(&library), vec!["A"]);
+
+        // Served from the cache while the file is unchanged.
+        let mut cache = library.cache.lock().unwrap();
+        cache.get_mut(&global).unwrap().snippets.clear();
+        drop(cache);
+        assert!(names(&library).is_empty());
+
+        // A write that changes the size is picked up even within the
+        // mtime's resolution.
+        std::fs::write(&global, r#"{ "Bee": { "prefix": "bb", "body": "b" } }"#).unwrap();
+        assert_eq!(names(&library), vec!["Bee"]);
+    }
+}
@@ -0,0 +1,293 @@
+//! Bounded file previews for pickers on remote workspaces.
+//!
+//! Previewing a result by opening the file pulls the whole file across the
+//! link on every selection change, which makes the picker crawl over SSH.
+//! Instead the picker asks for the first [`PREVIEW_BYTES`] of the file. The
+//! read runs on a worker thread and lands in a small LRU cache, so stepping
+//! back to a result already seen is free.
+//!
+//! Cancellation is "latest wins": only one request waits at a time, and a
+//! newer request replaces it before it is ever sent. A read already on the
+//! wire is bounded by the prefix size, so it is allowed to finish and its
+//! result is cached for when the user comes back to it — unless the cache
+//! was cleared meanwhile, in which case the result may predate the file's
+//! current contents and is dropped.
+
+use crate::model::filesystem::FileSystem;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Condvar, Mutex};
+
+/// How much of a file the preview fetches.
+pub const PREVIEW_BYTES: usize = 64 * 1024;
+
+/// How many previews the cache keeps.
+const CACHE_ENTRIES: usize = 64;
+
+/// The head of a file, ready to show.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilePreview {
+    pub text: String,
+    /// The file continues past `text`.
+    pub truncated: bool,
+}
+
+impl FilePreview {
+    fn from_prefix(mut bytes: Vec<u8>, max_len: usize) -> Self {
+        let truncated = bytes.len() > max_len;
+        bytes.truncate(max_len);
+        // The cut can land inside a multi-byte character; drop the partial
+        // tail rather than rendering a replacement character for it.
+        if let Err(e) = std::str::from_utf8(&bytes) {
+            if e.error_len().is_none() {
+                bytes.truncate(e.valid_up_to());
+            }
+        }
+        Self {
+            text: String::from_utf8_lossy(&bytes).into_owned(),
+            truncated,
+        }
+    }
+}
+
+/// Result of a preview fetch. Failures are cached too, so a picker that
+/// re-renders every frame doesn't hammer the link retrying a missing file.
+pub type PreviewResult = Result<Arc<FilePreview>, String>;
+
+#[derive(Default)]
+struct State {
+    /// Most recently used first.
+    cache: VecDeque<(PathBuf, PreviewResult)>,
+    pending: Option<PathBuf>,
+    /// The path being read, and the generation it was requested in.
+    in_flight: Option<(PathBuf, u64)>,
+    /// Bumped by [`FilePreviewLoader::clear`]; reads started in an older
+    /// generation are not cached.
+    generation: u64,
+    shutdown: bool,
+}
+
+struct Shared {
+    state: Mutex<State>,
+    wake: Condvar,
+}
+
+/// Fetches and caches file previews off the editor thread.
+pub struct FilePreviewLoader {
+    fs: Arc<dyn FileSystem + Send + Sync>,
+    shared: Arc<Shared>,
+}
+
+impl FilePreviewLoader {
+    /// Start a loader reading through `fs`. `on_loaded` runs on the worker
+    /// thread after each fetch so the editor can redraw.
+    pub fn new(
+        fs: Arc<dyn FileSystem + Send + Sync>,
+        on_loaded: impl Fn(PathBuf) + Send + 'static,
+    ) -> Self {
+        Self::with_limit(fs, PREVIEW_BYTES, on_loaded)
+    }
+
+    fn with_limit(
+        fs: Arc<dyn FileSystem + Send + Sync>,
+        max_len: usize,
+        on_loaded: impl Fn(PathBuf) + Send + 'static,
+    ) -> Self {
+        let shared = Arc::new(Shared {
+            state: Mutex::new(State::default()),
+            wake: Condvar::new(),
+        });
+        let worker_shared = Arc::clone(&shared);
+        let worker_fs = Arc::clone(&fs);
+        std::thread::Builder::new()
+            .name("file-preview".to_string())
+            .spawn(move || worker(&worker_shared, worker_fs.as_ref(), max_len, on_loaded))
+            .expect("failed to spawn file preview thread");
+        Self { fs, shared }
+    }
+
+    /// The filesystem this loader reads through.
+    pub fn filesystem(&self) -> &Arc<dyn FileSystem + Send + Sync> {
+        &self.fs
+    }
+
+    /// Cached preview for `path`, if it has been fetched.
+    pub fn get(&self, path: &Path) -> Option<PreviewResult> {
+        let mut state = self.shared.state.lock().unwrap();
+        let idx = state.cache.iter().position(|(p, _)| p == path)?;
+        let entry = state.cache.remove(idx)?;
+        let result = entry.1.clone();
+        state.cache.push_front(entry);
+        Some(result)
+    }
+
+    /// Ask for `path` to be fetched, replacing any request still waiting.
+    /// A no-op when the preview is cached or already being read.
+    pub fn request(&self, path: &Path) {
+        let mut state = self.shared.state.lock().unwrap();
+        let generation = state.generation;
+        if state
+            .in_flight
+            .as_ref()
+            .is_some_and(|(p, g)| p == path && *g == generation)
+            || state.pending.as_deref() == Some(path)
+            || state.cache.iter().any(|(p, _)| p == path)
+        {
+            return;
+        }
+        state.pending = Some(path.to_path_buf());
+        self.shared.wake.notify_one();
+    }
+
+    /// Forget every cached preview and any waiting request. Called when the
+    /// picker closes so the next session sees fresh file contents. A read
+    /// still in flight finishes but isn't cached.
+    pub fn clear(&self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.cache.clear();
+        state.pending = None;
+        state.generation += 1;
+    }
+}
+
+impl Drop for FilePreviewLoader {
+    fn drop(&mut self) {
+        self.shared.state.lock().unwrap().shutdown = true;
+        self.shared.wake.notify_one();
+    }
+}
+
+fn worker(
+    shared: &Shared,
+    fs: &(dyn FileSystem + Send + Sync),
+    max_len: usize,
+    on_loaded: impl Fn(PathBuf),
+) {
+    loop {
+        let (path, generation) = {
+            let mut state = shared.state.lock().unwrap();
+            loop {
+                if state.shutdown {
+                    return;
+                }
+                if let Some(path) = state.pending.take() {
+                    let generation = state.generation;
+                    state.in_flight = Some((path.clone(), generation));
+                    break (path, generation);
+                }
+                state = shared.wake.wait(state).unwrap();
+            }
+        };
+
+        // One byte past the limit tells a file that exactly fills the
+        // preview apart from one that is cut off.
+        let result = fs
+            .read_prefix(&path, max_len + 1)
+            .map(|bytes| Arc::new(FilePreview::from_prefix(bytes, max_len)))
+            .map_err(|e| e.to_string());
+
+        {
+            let mut state = shared.state.lock().unwrap();
+            state.in_flight = None;
+            if state.shutdown {
+                return;
+            }
+            if state.generation != generation {
+                continue;
+            }
+            state.cache.push_front((path.clone(), result));
+            state.cache.truncate(CACHE_ENTRIES);
+        }
+        on_loaded(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::filesystem::StdFileSystem;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    fn loader(max_len: usize) -> (FilePreviewLoader, mpsc::Receiver<PathBuf>) {
+        let (tx, rx) = mpsc::channel();
+        let loader = FilePreviewLoader::with_limit(Arc::new(StdFileSystem), max_len, move |p| {
+            drop(tx.send(p));
+        });
+        (loader, rx)
+    }
+
+    #[test]
+    fn fetches_only_the_head_of_the_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let long = dir.path().join("long.txt");
+        let short = dir.path().join("short.txt");
+        std::fs::write(&long, "0123456789").unwrap();
+        std::fs::write(&short, "0123").unwrap();
+        let (loader, rx) = loader(4);
+
+        loader.request(&long);
+        assert_eq!(rx.recv().unwrap(), long);
+        let preview = loader.get(&long).unwrap().unwrap();
+        assert_eq!(preview.text, "0123");
+        assert!(preview.truncated);
+
+        loader.request(&short);
+        rx.recv().unwrap();
+        assert!(!loader.get(&short).unwrap().unwrap().truncated);
+
+        // Cached: asking again doesn't read the file a second time.
+        loader.request(&long);
+        assert!(rx.recv_timeout(Duration::from_millis(100)).is_err());
+    }
+
+    #[test]
+    fn missing_files_are_cached_as_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = dir.path().join("missing.txt");
+        let (loader, rx) = loader(4);
+
+        loader.request(&missing);
+        rx.recv().unwrap();
+        assert!(loader.get(&missing).unwrap().is_err());
+    }
+
+    #[test]
+    fn reads_in_flight_when_cleared_are_not_cached() {
+        use crate::services::fs::{SlowFileSystem, SlowFsConfig};
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("file.txt");
+        std::fs::write(&path, "old").unwrap();
+        let slow = SlowFileSystem::new(
+            Arc::new(StdFileSystem),
+            SlowFsConfig {
+                read_file_delay: Duration::from_millis(200),
+                ..SlowFsConfig::none()
+            },
+        );
+        let (tx, rx) = mpsc::channel();
+        let loader = FilePreviewLoader::with_limit(Arc::new(slow), 16, move |p| {
+            drop(tx.send(p));
+        });
+
+        loader.request(&path);
+        while loader.shared.state.lock().unwrap().in_flight.is_none() {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        std::fs::write(&path, "new").unwrap();
+        loader.clear();
+        // Not mistaken for the read already on the wire.
+        loader.request(&path);
+
+        rx.recv().unwrap();
+        assert_eq!(loader.get(&path).unwrap().unwrap().text, "new");
+    }
+
+    #[test]
+    fn cut_never_splits_a_character() {
+        let preview = FilePreview::from_prefix("aé".as_bytes().to_vec(), 2);
+        assert_eq!(preview.text, "a");
+        assert!(preview.truncated);
+    }
+}
@@ -183,6 +183,12 @@ impl FileSystem for SlowFileSystem {
         self.inner.read_range(path, offset, len)
     }
 
+    fn read_prefix(&self, path: &Path, max_len: usize) -> io::Result<Vec<u8>> {
+        self.add_delay(self.config.read_file_delay);
+        self.metrics.read_file_calls.fetch_add(1, Ordering::SeqCst);
+        self.inner.read_prefix(path, max_len)
+    }
+
     fn write_file(&self, path: &Path, data: &[u8]) -> io::Result<()> {
         self.add_delay(self.config.write_file_delay);
         self.metrics.write_file_calls.fetch_add(1, Ordering::SeqCst);
@@ -308,10 +314,11 @@ impl FileSystem for SlowFileSystem {
         mode: u32,
         uid: u32,
         gid: u32,
+        elevate: &[String],
     ) -> io::Result<()> {
         self.add_delay(self.config.write_file_delay);
         self.metrics.write_file_calls.fetch_add(1, Ordering::SeqCst);
-        self.inner.sudo_write(path, data, mode, uid, gid)
+        self.inner.sudo_write(path, data, mode, uid, gid, elevate)
     }
 
     fn search_file(
@@ -8,8 +8,92 @@
 //! `webpki`) can be dropped from the binary by building without the
 //! `http` feature — the rest of the editor calls these functions
 //! unconditionally and gets the no-op / error stubs below.
+//!
+//! Requests go through the proxy named by the usual environment variables
+//! (see [`proxy_for`]), and the `offline` config switch ([`set_offline`])
+//! turns every call into an immediate error for air-gapped machines.
 
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set from the `offline` config. While on, nothing here touches the network.
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+const OFFLINE_ERROR: &str = "network access is disabled (offline = true)";
+
+/// Apply the `offline` config switch.
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+/// Whether network access is disabled by the `offline` config switch.
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// The proxy to use for `url`, following the usual environment conventions:
+/// `HTTPS_PROXY` for https URLs, `HTTP_PROXY` for http, `ALL_PROXY` as the
+/// fallback (each also in lower case), and no proxy for hosts listed in
+/// `NO_PROXY`. `env` looks a variable up; empty values count as unset.
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+fn proxy_for(url: &str, env: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let var = |name: &str| {
+        env(name)
+            .or_else(|| env(&name.to_ascii_lowercase()))
+            .filter(|v| !v.trim().is_empty())
+    };
+    let (scheme, rest) = url.split_once("://")?;
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or("");
+    let host_port = authority.rsplit('@').next().unwrap_or("");
+    let host = if let Some(bracketed) = host_port.strip_prefix('[') {
+        bracketed.split(']').next().unwrap_or("")
+    } else {
+        host_port.split(':').next().unwrap_or("")
+    };
+
+    if let Some(no_proxy) = var("NO_PROXY") {
+        if no_proxy
+            .split(',')
+            .any(|entry| no_proxy_matches(entry, host))
+        {
+            return None;
+        }
+    }
+    let scheme_var = match scheme.to_ascii_lowercase().as_str() {
+        "https" => var("HTTPS_PROXY"),
+        "http" => var("HTTP_PROXY"),
+        _ => None,
+    };
+    scheme_var.or_else(|| var("ALL_PROXY"))
+}
+
+/// curl-style `NO_PROXY` entry match: `*` matches everything, otherwise the
+/// host itself or any subdomain of it (a leading `.` or `*.` is ignored, as is
+/// a `:port` suffix).
+#[cfg_attr(not(feature = "http"), allow(dead_code))]
+fn no_proxy_matches(entry: &str, host: &str) -> bool {
+    let entry = entry.trim();
+    if entry == "*" {
+        return true;
+    }
+    let entry = entry.trim_start_matches('*').trim_start_matches('.');
+    // `[v6]:port` or `name:port`; a bare IPv6 address has several colons.
+    let entry = if let Some(bracketed) = entry.strip_prefix('[') {
+        bracketed.split(']').next().unwrap_or("")
+    } else if entry.matches(':').count() == 1 {
+        entry.split(':').next().unwrap_or("")
+    } else {
+        entry
+    };
+    if entry.is_empty() {
+        return false;
+    }
+    host.eq_ignore_ascii_case(entry)
+        || (host.len() > entry.len()
+            && host[host.len() - entry.len()..].eq_ignore_ascii_case(entry)
+            && host.as_bytes()[host.len() - entry.len() - 1] == b'.')
+}
 
 /// Maximum size of a body downloaded via `editor.httpFetch`. 64 MB is well
 /// above any reasonable theme/plugin asset (themes are tens of KB) while
@@ -22,11 +106,29 @@ mod imp {
     use super::*;
     use std::time::Duration;
 
+    /// The environment proxy for `url` (see [`proxy_for`]). Set explicitly on
+    /// every agent so ureq's own environment lookup, which prefers `ALL_PROXY`
+    /// and matches `NO_PROXY` differently, never applies.
+    fn env_proxy(url: &str) -> Option<ureq::Proxy> {
+        let proxy = proxy_for(url, |name| std::env::var(name).ok())?;
+        match ureq::Proxy::new(&proxy) {
+            Ok(p) => Some(p),
+            Err(e) => {
+                tracing::warn!("Ignoring invalid proxy {proxy:?}: {e}");
+                None
+            }
+        }
+    }
+
     /// GET a release-metadata JSON document (GitHub API shape) and return the
     /// raw body. Used by the update checker.
     pub fn get_release_json(url: &str) -> Result<String, String> {
+        if is_offline() {
+            return Err(OFFLINE_ERROR.to_string());
+        }
         let agent = ureq::Agent::config_builder()
             .timeout_global(Some(Duration::from_secs(15)))
+            .proxy(env_proxy(url))
             .build()
             .new_agent();
         let response = agent
@@ -48,10 +150,14 @@ mod imp {
     /// Best-effort, fire-and-forget JSON POST used for anonymous telemetry.
     /// Spawns a detached thread and never reports failures.
     pub fn post_telemetry(url: &'static str, body: String) {
+        if is_offline() {
+            return;
+        }
         #[allow(clippy::let_underscore_must_use)]
         let _ = std::thread::spawn(move || {
             let agent = ureq::Agent::config_builder()
                 .timeout_global(Some(Duration::from_secs(5)))
+                .proxy(env_proxy(url))
                 .build()
                 .new_agent();
             #[allow(clippy::let_underscore_must_use)]
@@ -68,6 +174,9 @@ mod imp {
     /// as their status code without writing to the target file. Transport
     /// errors (DNS, TLS, timeout, …) are returned as `Err`.
     pub fn download_to_file(url: &str, target: &Path) -> Result<u16, String> {
+        if is_offline() {
+            return Err(OFFLINE_ERROR.to_string());
+        }
         // Use the platform's native certificate verifier so requests work in
         // environments with TLS-intercepting proxies or custom enterprise root
         // CAs that aren't in Mozilla's bundled webpki-roots.
@@ -79,6 +188,7 @@ mod imp {
             .timeout_global(Some(Duration::from_secs(30)))
             .http_status_as_error(false)
             .tls_config(tls_config)
+            .proxy(env_proxy(url))
             .build()
             .new_agent();
 
@@ -127,3 +237,60 @@ mod imp {
 }
 
 pub use imp::{download_to_file, get_release_json, post_telemetry};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    const RELEASES: &str = "https://api.github.com/repos/sinelaw/fresh/releases/latest";
+
+    #[test]
+    fn proxy_follows_url_scheme() {
+        let vars = env(&[
+            ("HTTPS_PROXY", "http://secure:3128"),
+            ("http_proxy", "http://plain:3128"),
+            ("ALL_PROXY", "socks5://all:1080"),
+        ]);
+        assert_eq!(
+            proxy_for(RELEASES, &vars).as_deref(),
+            Some("http://secure:3128")
+        );
+        assert_eq!(
+            proxy_for("http://example.com/x", &vars).as_deref(),
+            Some("http://plain:3128")
+        );
+
+        let all_only = env(&[("all_proxy", "socks5://all:1080"), ("HTTPS_PROXY", " ")]);
+        assert_eq!(
+            proxy_for(RELEASES, &all_only).as_deref(),
+            Some("socks5://all:1080")
+        );
+        assert_eq!(proxy_for(RELEASES, env(&[])), None);
+    }
+
+    #[test]
+    fn no_proxy_bypasses_matching_hosts() {
+        let with =
+            |no_proxy: &str| env(&[("HTTPS_PROXY", "http://proxy:3128"), ("NO_PROXY", no_proxy)]);
+        assert_eq!(proxy_for(RELEASES, with("github.com")), None);
+        assert_eq!(proxy_for(RELEASES, with("localhost, .github.com")), None);
+        assert_eq!(proxy_for(RELEASES, with("*")), None);
+        assert_eq!(proxy_for("https://[::1]:8080/x", with("::1")), None);
+        assert_eq!(
+            proxy_for("https://user@internal:8443/x", with("internal:8443")),
+            None
+        );
+        // Only whole labels match: `hub.com` is not a suffix of `github.com`.
+        assert!(proxy_for(RELEASES, with("hub.com")).is_some());
+        assert!(proxy_for(RELEASES, with("example.com")).is_some());
+    }
+}
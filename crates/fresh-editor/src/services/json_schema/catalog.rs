@@ -0,0 +1,144 @@
+//! Built-in filename → schema catalog.
+//!
+//! A curated subset of the [SchemaStore](https://www.schemastore.org/) catalog
+//! covering the config files people open most. Users extend or override it
+//! with `json_schemas.associations`; entries there are consulted first.
+
+use crate::primitives::glob_match::{filename_glob_matches, is_path_pattern, path_glob_matches};
+
+/// One catalog entry: the file patterns it applies to and the schema URL.
+#[derive(Debug, Clone, Copy)]
+pub struct CatalogEntry {
+    pub file_match: &'static [&'static str],
+    pub url: &'static str,
+}
+
+/// Well-known files and their SchemaStore schemas.
+pub const BUILTIN_CATALOG: &[CatalogEntry] = &[
+    CatalogEntry {
+        file_match: &["package.json"],
+        url: "https://json.schemastore.org/package.json",
+    },
+    CatalogEntry {
+        file_match: &["tsconfig.json", "tsconfig.*.json", "jsconfig.json"],
+        url: "https://json.schemastore.org/tsconfig.json",
+    },
+    CatalogEntry {
+        file_match: &[".eslintrc", ".eslintrc.json"],
+        url: "https://json.schemastore.org/eslintrc.json",
+    },
+    CatalogEntry {
+        file_match: &[".prettierrc", ".prettierrc.json"],
+        url: "https://json.schemastore.org/prettierrc.json",
+    },
+    CatalogEntry {
+        file_match: &[".babelrc", ".babelrc.json", "babel.config.json"],
+        url: "https://json.schemastore.org/babelrc.json",
+    },
+    CatalogEntry {
+        file_match: &["composer.json"],
+        url: "https://getcomposer.org/schema.json",
+    },
+    CatalogEntry {
+        file_match: &["deno.json", "deno.jsonc"],
+        url: "https://raw.githubusercontent.com/denoland/deno/main/cli/schemas/config-file.v1.json",
+    },
+    CatalogEntry {
+        file_match: &[".devcontainer/devcontainer.json", ".devcontainer.json"],
+        url: "https://raw.githubusercontent.com/devcontainers/spec/main/schemas/devContainer.base.schema.json",
+    },
+    CatalogEntry {
+        file_match: &["**/.github/workflows/*.yml", "**/.github/workflows/*.yaml"],
+        url: "https://json.schemastore.org/github-workflow.json",
+    },
+    CatalogEntry {
+        file_match: &["action.yml", "action.yaml"],
+        url: "https://json.schemastore.org/github-action.json",
+    },
+    CatalogEntry {
+        file_match: &["**/.github/dependabot.yml", "**/.github/dependabot.yaml"],
+        url: "https://json.schemastore.org/dependabot-2.0.json",
+    },
+    CatalogEntry {
+        file_match: &[
+            "docker-compose.yml",
+            "docker-compose.yaml",
+            "docker-compose.*.yml",
+            "docker-compose.*.yaml",
+            "compose.yml",
+            "compose.yaml",
+        ],
+        url: "https://raw.githubusercontent.com/compose-spec/compose-spec/master/schema/compose-spec.json",
+    },
+    CatalogEntry {
+        file_match: &[".gitlab-ci.yml"],
+        url: "https://gitlab.com/gitlab-org/gitlab/-/raw/master/app/assets/javascripts/editor/schema/ci.json",
+    },
+    CatalogEntry {
+        file_match: &[".pre-commit-config.yaml", ".pre-commit-config.yml"],
+        url: "https://json.schemastore.org/pre-commit-config.json",
+    },
+    CatalogEntry {
+        file_match: &["Chart.yaml"],
+        url: "https://json.schemastore.org/chart.json",
+    },
+    CatalogEntry {
+        file_match: &["mkdocs.yml", "mkdocs.yaml"],
+        url: "https://json.schemastore.org/mkdocs-1.6.json",
+    },
+];
+
+/// True when `pattern` matches `path`. Patterns containing a separator are
+/// matched against the whole path (an implicit leading `**/` lets
+/// `.devcontainer/devcontainer.json` match anywhere); bare patterns against the file name.
+pub fn pattern_matches(pattern: &str, path: &str) -> bool {
+    if is_path_pattern(pattern) {
+        if path_glob_matches(pattern, path) {
+            return true;
+        }
+        return !pattern.starts_with("**") && path_glob_matches(&format!("**/{}", pattern), path);
+    }
+    let filename = path.rsplit(['/', '\\']).next().unwrap_or(path);
+    filename_glob_matches(pattern, filename)
+}
+
+/// Look up `path` in the built-in catalog.
+pub fn lookup(path: &str) -> Option<&'static str> {
+    BUILTIN_CATALOG
+        .iter()
+        .find(|entry| entry.file_match.iter().any(|p| pattern_matches(p, path)))
+        .map(|entry| entry.url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_filenames_and_nested_paths() {
+        assert_eq!(
+            lookup("/work/app/package.json"),
+            Some("https://json.schemastore.org/package.json")
+        );
+        assert_eq!(
+            lookup("/work/tsconfig.build.json"),
+            Some("https://json.schemastore.org/tsconfig.json")
+        );
+        assert_eq!(
+            lookup("/work/repo/.github/workflows/ci.yml"),
+            Some("https://json.schemastore.org/github-workflow.json")
+        );
+        assert!(lookup("/work/repo/workflows/ci.yml").is_none());
+        assert!(lookup("/work/data.json").is_none());
+    }
+
+    #[test]
+    fn relative_path_patterns_match_anywhere() {
+        let pattern = ".devcontainer/devcontainer.json";
+        assert!(pattern_matches(
+            pattern,
+            "/p/.devcontainer/devcontainer.json"
+        ));
+        assert!(!pattern_matches(pattern, "/p/devcontainer.json"));
+    }
+}
@@ -0,0 +1,536 @@
+//! Position-aware document trees for schema validation.
+//!
+//! `serde_json::Value` throws away source positions, which are exactly what a
+//! diagnostic needs. This module parses JSON/JSONC (via `jsonc-parser`'s AST)
+//! and YAML (via `yaml-rust`'s marked event stream) into a small [`Node`] tree
+//! that keeps the byte range of every value and every object key.
+
+use std::ops::Range;
+
+/// A parsed value together with the byte range it occupies in the source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    pub kind: NodeKind,
+    pub range: Range<usize>,
+}
+
+/// The value carried by a [`Node`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeKind {
+    Null,
+    Bool(bool),
+    /// The numeric value, and whether it was written as an integer literal.
+    Number(f64, bool),
+    String(String),
+    Array(Vec<Node>),
+    Object(Vec<Property>),
+}
+
+/// A single `key: value` entry of an object node.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    pub key: String,
+    pub key_range: Range<usize>,
+    pub value: Node,
+}
+
+/// One step from a node to a child: an object key or an array index.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathSegment {
+    Key(String),
+    Index(usize),
+}
+
+/// The source language of a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    /// JSON, also accepting comments and trailing commas (JSONC).
+    Json,
+    Yaml,
+}
+
+impl DocumentFormat {
+    /// Pick the format for a buffer language id, if schema validation applies.
+    pub fn for_language(language: &str) -> Option<Self> {
+        match language {
+            "json" | "jsonc" | "json5" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+}
+
+/// A syntax error that prevented building a tree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseFailure {
+    pub message: String,
+    pub range: Range<usize>,
+}
+
+impl Node {
+    /// JSON-Schema type name of this node (`"integer"` is reported as
+    /// `"number"`; integer-ness is checked separately).
+    pub fn type_name(&self) -> &'static str {
+        match self.kind {
+            NodeKind::Null => "null",
+            NodeKind::Bool(_) => "boolean",
+            NodeKind::Number(..) => "number",
+            NodeKind::String(_) => "string",
+            NodeKind::Array(_) => "array",
+            NodeKind::Object(_) => "object",
+        }
+    }
+
+    /// Look up a top-level property of an object node.
+    pub fn property(&self, key: &str) -> Option<&Property> {
+        match &self.kind {
+            NodeKind::Object(props) => props.iter().find(|p| p.key == key),
+            _ => None,
+        }
+    }
+
+    /// The path to the object key or scalar value at byte `offset`, with the
+    /// key's or value's range. `None` when `offset` falls between values.
+    pub fn path_at(&self, offset: usize) -> Option<(Vec<PathSegment>, Range<usize>)> {
+        let mut path = Vec::new();
+        let mut node = self;
+        loop {
+            match &node.kind {
+                NodeKind::Object(props) => {
+                    let prop = props.iter().find(|p| {
+                        p.key_range.contains(&offset) || p.value.range.contains(&offset)
+                    })?;
+                    path.push(PathSegment::Key(prop.key.clone()));
+                    if prop.key_range.contains(&offset) {
+                        return Some((path, prop.key_range.clone()));
+                    }
+                    node = &prop.value;
+                }
+                NodeKind::Array(items) => {
+                    let index = items.iter().position(|n| n.range.contains(&offset))?;
+                    path.push(PathSegment::Index(index));
+                    node = &items[index];
+                }
+                _ => {
+                    return node
+                        .range
+                        .contains(&offset)
+                        .then(|| (path, node.range.clone()))
+                }
+            }
+        }
+    }
+
+    /// Convert to a plain JSON value, for `enum`/`const` comparisons.
+    pub fn to_json(&self) -> serde_json::Value {
+        use serde_json::Value;
+        match &self.kind {
+            NodeKind::Null => Value::Null,
+            NodeKind::Bool(b) => Value::Bool(*b),
+            NodeKind::Number(n, is_int) => {
+                if *is_int && n.fract() == 0.0 && n.abs() < i64::MAX as f64 {
+                    Value::from(*n as i64)
+                } else {
+                    serde_json::Number::from_f64(*n)
+                        .map(Value::Number)
+                        .unwrap_or(Value::Null)
+                }
+            }
+            NodeKind::String(s) => Value::String(s.clone()),
+            NodeKind::Array(items) => Value::Array(items.iter().map(Node::to_json).collect()),
+            NodeKind::Object(props) => Value::Object(
+                props
+                    .iter()
+                    .map(|p| (p.key.clone(), p.value.to_json()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Parse `text` in the given format. Returns `Ok(None)` for an empty document.
+pub fn parse(text: &str, format: DocumentFormat) -> Result<Option<Node>, ParseFailure> {
+    match format {
+        DocumentFormat::Json => parse_json(text),
+        DocumentFormat::Yaml => parse_yaml(text),
+    }
+}
+
+fn parse_json(text: &str) -> Result<Option<Node>, ParseFailure> {
+    let result = jsonc_parser::parse_to_ast(text, &Default::default(), &Default::default())
+        .map_err(|e| {
+            let range = e.range();
+            ParseFailure {
+                message: e.kind().to_string(),
+                range: range.start..range.end,
+            }
+        })?;
+    Ok(result.value.as_ref().map(convert_json))
+}
+
+fn convert_json(value: &jsonc_parser::ast::Value<'_>) -> Node {
+    use jsonc_parser::ast::{ObjectPropName, Value};
+    use jsonc_parser::common::Ranged;
+
+    let range = value.range();
+    let kind = match value {
+        Value::NullKeyword(_) => NodeKind::Null,
+        Value::BooleanLit(b) => NodeKind::Bool(b.value),
+        Value::NumberLit(n) => number_kind(n.value),
+        Value::StringLit(s) => NodeKind::String(s.value.to_string()),
+        Value::Array(a) => NodeKind::Array(a.elements.iter().map(convert_json).collect()),
+        Value::Object(o) => NodeKind::Object(
+            o.properties
+                .iter()
+                .map(|p| {
+                    let (key, key_range) = match &p.name {
+                        ObjectPropName::String(s) => (s.value.to_string(), s.range),
+                        ObjectPropName::Word(w) => (w.value.to_string(), w.range),
+                    };
+                    Property {
+                        key,
+                        key_range: key_range.start..key_range.end,
+                        value: convert_json(&p.value),
+                    }
+                })
+                .collect(),
+        ),
+    };
+    Node {
+        kind,
+        range: range.start..range.end,
+    }
+}
+
+fn number_kind(text: &str) -> NodeKind {
+    let is_int = !text.contains(['.', 'e', 'E']);
+    let value = if let Some(hex) = text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        i64::from_str_radix(hex, 16)
+            .map(|v| v as f64)
+            .unwrap_or(f64::NAN)
+    } else {
+        text.trim_start_matches('+')
+            .parse::<f64>()
+            .unwrap_or(f64::NAN)
+    };
+    NodeKind::Number(value, is_int)
+}
+
+// ---------------------------------------------------------------------------
+// YAML
+// ---------------------------------------------------------------------------
+
+/// Builds a [`Node`] tree from `yaml-rust`'s marked events.
+///
+/// Markers are character offsets; `char_to_byte` maps them back to the byte
+/// offsets the rest of the editor works in.
+struct YamlBuilder<'a> {
+    text: &'a str,
+    char_to_byte: Vec<usize>,
+    /// Containers that have started but not yet ended.
+    stack: Vec<YamlFrame>,
+    root: Option<Node>,
+    /// Anchored nodes, so aliases can be resolved to a copy of their target.
+    anchors: std::collections::HashMap<usize, Node>,
+}
+
+enum YamlFrame {
+    Sequence {
+        start: usize,
+        anchor: usize,
+        items: Vec<Node>,
+    },
+    Mapping {
+        start: usize,
+        anchor: usize,
+        props: Vec<Property>,
+        pending_key: Option<Node>,
+    },
+}
+
+impl<'a> YamlBuilder<'a> {
+    fn new(text: &'a str) -> Self {
+        let mut char_to_byte: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+        char_to_byte.push(text.len());
+        Self {
+            text,
+            char_to_byte,
+            stack: Vec::new(),
+            root: None,
+            anchors: std::collections::HashMap::new(),
+        }
+    }
+
+    fn byte_at(&self, char_index: usize) -> usize {
+        self.char_to_byte
+            .get(char_index)
+            .copied()
+            .unwrap_or(self.text.len())
+    }
+
+    /// End of the scalar token that starts at `start`: quoted scalars run to
+    /// the closing quote, plain scalars to the end of their (trimmed) line.
+    fn scalar_end(&self, start: usize, style: yaml_rust::scanner::TScalarStyle) -> usize {
+        use yaml_rust::scanner::TScalarStyle;
+        let rest = &self.text[start..];
+        let line_end = rest
+            .find('\n')
+            .map(|i| start + i)
+            .unwrap_or(self.text.len());
+        match style {
+            TScalarStyle::SingleQuoted | TScalarStyle::DoubleQuoted => {
+                let quote = if style == TScalarStyle::SingleQuoted {
+                    '\''
+                } else {
+                    '"'
+                };
+                rest.char_indices()
+                    .skip(1)
+                    .find(|&(_, c)| c == quote)
+                    .map(|(i, _)| start + i + 1)
+                    .unwrap_or(line_end)
+            }
+            _ => {
+                let line = &self.text[start..line_end];
+                // Stop at an inline comment or a flow/mapping delimiter.
+                let cut = line
+                    .find(" #")
+                    .into_iter()
+                    .chain(line.find(": "))
+                    .chain(line.find([',', ']', '}']))
+                    .min()
+                    .unwrap_or(line.len());
+                start + line[..cut].trim_end().len()
+            }
+        }
+    }
+
+    fn push_value(&mut self, node: Node, anchor: usize) {
+        if anchor > 0 {
+            self.anchors.insert(anchor, node.clone());
+        }
+        match self.stack.last_mut() {
+            None => {
+                if self.root.is_none() {
+                    self.root = Some(node);
+                }
+            }
+            Some(YamlFrame::Sequence { items, .. }) => items.push(node),
+            Some(YamlFrame::Mapping {
+                props, pending_key, ..
+            }) => match pending_key.take() {
+                None => *pending_key = Some(node),
+                Some(key_node) => {
+                    let key = match &key_node.kind {
+                        NodeKind::String(s) => s.clone(),
+                        other => Node {
+                            kind: other.clone(),
+                            range: key_node.range.clone(),
+                        }
+                        .to_json()
+                        .to_string(),
+                    };
+                    props.push(Property {
+                        key,
+                        key_range: key_node.range,
+                        value: node,
+                    });
+                }
+            },
+        }
+    }
+}
+
+impl yaml_rust::parser::MarkedEventReceiver for YamlBuilder<'_> {
+    fn on_event(&mut self, ev: yaml_rust::Event, mark: yaml_rust::scanner::Marker) {
+        use yaml_rust::Event;
+        let pos = self.byte_at(mark.index());
+        match ev {
+            Event::Scalar(value, style, anchor, tag) => {
+                let end = self.scalar_end(pos, style).max(pos);
+                let kind = yaml_scalar_kind(value, style, tag.is_some());
+                self.push_value(
+                    Node {
+                        kind,
+                        range: pos..end,
+                    },
+                    anchor,
+                );
+            }
+            Event::SequenceStart(anchor) => self.stack.push(YamlFrame::Sequence {
+                start: pos,
+                anchor,
+                items: Vec::new(),
+            }),
+            Event::MappingStart(anchor) => self.stack.push(YamlFrame::Mapping {
+                start: pos,
+                anchor,
+                props: Vec::new(),
+                pending_key: None,
+            }),
+            Event::SequenceEnd | Event::MappingEnd => {
+                if let Some(frame) = self.stack.pop() {
+                    let (node, anchor) = match frame {
+                        YamlFrame::Sequence {
+                            start,
+                            anchor,
+                            items,
+                        } => {
+                            let end = items.last().map(|n| n.range.end).unwrap_or(pos).max(start);
+                            (
+                                Node {
+                                    kind: NodeKind::Array(items),
+                                    range: start..end,
+                                },
+                                anchor,
+                            )
+                        }
+                        YamlFrame::Mapping {
+                            start,
+                            anchor,
+                            props,
+                            ..
+                        } => {
+                            let end = props
+                                .last()
+                                .map(|p| p.value.range.end)
+                                .unwrap_or(pos)
+                                .max(start);
+                            (
+                                Node {
+                                    kind: NodeKind::Object(props),
+                                    range: start..end,
+                                },
+                                anchor,
+                            )
+                        }
+                    };
+                    self.push_value(node, anchor);
+                }
+            }
+            Event::Alias(id) => {
+                let node = self.anchors.get(&id).cloned().map(|mut n| {
+                    n.range = pos..pos;
+                    n
+                });
+                if let Some(node) = node {
+                    self.push_value(node, 0);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Resolve a YAML scalar to a typed value using the core schema rules.
+fn yaml_scalar_kind(
+    value: String,
+    style: yaml_rust::scanner::TScalarStyle,
+    tagged: bool,
+) -> NodeKind {
+    use yaml_rust::scanner::TScalarStyle;
+    if style != TScalarStyle::Plain || tagged {
+        return NodeKind::String(value);
+    }
+    match value.as_str() {
+        "" | "~" | "null" | "Null" | "NULL" => NodeKind::Null,
+        "true" | "True" | "TRUE" => NodeKind::Bool(true),
+        "false" | "False" | "FALSE" => NodeKind::Bool(false),
+        ".inf" | ".Inf" | ".INF" | "+.inf" => NodeKind::Number(f64::INFINITY, false),
+        "-.inf" | "-.Inf" | "-.INF" => NodeKind::Number(f64::NEG_INFINITY, false),
+        _ => {
+            let looks_numeric = value
+                .trim_start_matches(['-', '+'])
+                .starts_with(|c: char| c.is_ascii_digit() || c == '.');
+            match value.parse::<f64>() {
+                Ok(n) if looks_numeric => NodeKind::Number(n, !value.contains(['.', 'e', 'E'])),
+                _ => NodeKind::String(value),
+            }
+        }
+    }
+}
+
+fn parse_yaml(text: &str) -> Result<Option<Node>, ParseFailure> {
+    let mut builder = YamlBuilder::new(text);
+    let mut parser = yaml_rust::parser::Parser::new(text.chars());
+    parser.load(&mut builder, false).map_err(|e| {
+        let pos = builder.byte_at(e.marker().index());
+        ParseFailure {
+            message: e.to_string(),
+            range: pos..pos,
+        }
+    })?;
+    Ok(builder.root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slice<'a>(text: &'a str, range: &Range<usize>) -> &'a str {
+        &text[range.clone()]
+    }
+
+    #[test]
+    fn json_keeps_key_and_value_ranges() {
+        let text = r#"{ "name": "fresh", "tags": [1, 2.5] }"#;
+        let root = parse(text, DocumentFormat::Json).unwrap().unwrap();
+        let name = root.property("name").unwrap();
+        assert_eq!(slice(text, &name.key_range), "\"name\"");
+        assert_eq!(slice(text, &name.value.range), "\"fresh\"");
+        let tags = root.property("tags").unwrap();
+        match &tags.value.kind {
+            NodeKind::Array(items) => {
+                assert_eq!(items[0].kind, NodeKind::Number(1.0, true));
+                assert_eq!(items[1].kind, NodeKind::Number(2.5, false));
+            }
+            other => panic!("expected array, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn json_accepts_comments_and_reports_syntax_errors() {
+        let ok = "{\n  // comment\n  \"a\": 1,\n}";
+        assert!(parse(ok, DocumentFormat::Json).unwrap().is_some());
+        assert!(parse("{ \"a\": }", DocumentFormat::Json).is_err());
+        assert_eq!(parse("", DocumentFormat::Json).unwrap(), None);
+    }
+
+    #[test]
+    fn yaml_resolves_scalar_types_and_positions() {
+        let text = "name: fresh\ncount: 3\nenabled: true\nquoted: \"3\"\nempty:\n";
+        let root = parse(text, DocumentFormat::Yaml).unwrap().unwrap();
+        let name = root.property("name").unwrap();
+        assert_eq!(slice(text, &name.key_range), "name");
+        assert_eq!(slice(text, &name.value.range), "fresh");
+        assert_eq!(
+            root.property("count").unwrap().value.kind,
+            NodeKind::Number(3.0, true)
+        );
+        assert_eq!(
+            root.property("enabled").unwrap().value.kind,
+            NodeKind::Bool(true)
+        );
+        let quoted = root.property("quoted").unwrap();
+        assert_eq!(quoted.value.kind, NodeKind::String("3".into()));
+        assert_eq!(slice(text, &quoted.value.range), "\"3\"");
+        assert_eq!(root.property("empty").unwrap().value.kind, NodeKind::Null);
+    }
+
+    #[test]
+    fn yaml_nested_sequences_and_multibyte_offsets() {
+        let text = "título: é\nsteps:\n  - run: echo\n  - uses: x # note\n";
+        let root = parse(text, DocumentFormat::Yaml).unwrap().unwrap();
+        let title = root.property("título").unwrap();
+        assert_eq!(slice(text, &title.value.range), "é");
+        let steps = root.property("steps").unwrap();
+        match &steps.value.kind {
+            NodeKind::Array(items) => {
+                assert_eq!(items.len(), 2);
+                let uses = items[1].property("uses").unwrap();
+                assert_eq!(slice(text, &uses.value.range), "x");
+            }
+            other => panic!("expected sequence, got {:?}", other),
+        }
+    }
+}
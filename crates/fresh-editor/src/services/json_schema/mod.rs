@@ -0,0 +1,482 @@
+//! JSON Schema validation for JSON and YAML buffers.
+//!
+//! A buffer is mapped to a schema by, in order:
+//! 1. an in-document reference — a top-level `"$schema"` key, or a YAML
+//!    `# yaml-language-server: $schema=<url>` modeline,
+//! 2. the user's `json_schemas.associations`,
+//! 3. the built-in [`catalog`] of well-known files.
+//!
+//! Schemas are loaded from local paths, `file://` URLs, or (when
+//! `json_schemas.download` is on) fetched over HTTP(S) and cached under
+//! `<data_dir>/schemas`. A URL named by the document itself is only fetched
+//! in a trusted workspace, so opening a file can't make the editor contact a
+//! host of the file author's choosing. Validation runs off the UI thread; the results come
+//! back as ordinary LSP diagnostics under the [`DIAGNOSTIC_SOURCE`] source so
+//! they render, hover and navigate exactly like language-server diagnostics.
+
+pub mod catalog;
+pub mod document;
+pub mod validator;
+
+use crate::config::JsonSchemaConfig;
+use document::{DocumentFormat, Node};
+use lsp_types::{Diagnostic, DiagnosticSeverity, Position, Range};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex};
+use validator::ViolationSeverity;
+
+/// `source` stamped on every schema diagnostic, and the key the diagnostics
+/// are stored under alongside language-server results.
+pub const DIAGNOSTIC_SOURCE: &str = "json-schema";
+
+/// Parsed schemas keyed by resolved location. Shared by every window so a
+/// schema is read (or downloaded) once per process.
+static SCHEMA_CACHE: LazyLock<Mutex<HashMap<String, Arc<Value>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Locations that failed to load; not retried until restart so an offline
+/// editor doesn't hit the network on every keystroke.
+static FAILED_SCHEMAS: LazyLock<Mutex<HashSet<String>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Everything a background validation needs, snapshotted on the UI thread.
+#[derive(Debug, Clone)]
+pub struct ValidationRequest {
+    pub text: String,
+    pub path: PathBuf,
+    pub format: DocumentFormat,
+    pub config: JsonSchemaConfig,
+    pub working_dir: PathBuf,
+    /// Where downloaded schemas are cached (`DirectoryContext::schemas_dir`).
+    pub schemas_dir: PathBuf,
+    /// Whether the workspace is trusted. Untrusted workspaces only download
+    /// schemas from the built-in catalog or the user's `associations`.
+    pub trusted: bool,
+}
+
+/// Find the schema location for a document, without loading it.
+pub fn resolve_schema_location(
+    text: &str,
+    root: Option<&Node>,
+    path: &Path,
+    config: &JsonSchemaConfig,
+) -> Option<String> {
+    if let Some(location) =
+        root.and_then(|r| r.property("$schema"))
+            .and_then(|p| match &p.value.kind {
+                document::NodeKind::String(s) => Some(s.clone()),
+                _ => None,
+            })
+    {
+        return Some(location);
+    }
+    if let Some(location) = yaml_modeline_schema(text) {
+        return Some(location);
+    }
+    let path_str = path.to_string_lossy();
+    config
+        .associations
+        .iter()
+        .find(|a| {
+            a.file_match
+                .iter()
+                .any(|p| catalog::pattern_matches(p, &path_str))
+        })
+        .map(|a| a.schema.clone())
+        .or_else(|| catalog::lookup(&path_str).map(str::to_string))
+}
+
+/// `# yaml-language-server: $schema=<url>` — the de-facto YAML convention.
+fn yaml_modeline_schema(text: &str) -> Option<String> {
+    text.lines()
+        .take_while(|l| l.trim_start().starts_with('#') || l.trim().is_empty())
+        .find_map(|l| {
+            let rest = l.trim_start().strip_prefix('#')?.trim_start();
+            let rest = rest.strip_prefix("yaml-language-server:")?.trim_start();
+            let url = rest.strip_prefix("$schema=")?.trim();
+            (!url.is_empty()).then(|| url.to_string())
+        })
+}
+
+/// Load the schema at `location`. Relative paths resolve against the
+/// document's directory first, then the working directory. Remote schemas
+/// are cached for the life of the process; local ones are re-read so edits
+/// to a project's own schema take effect immediately.
+pub fn load_schema(
+    location: &str,
+    document_dir: Option<&Path>,
+    request: &ValidationRequest,
+) -> Result<Arc<Value>, String> {
+    let parse = |text: String| {
+        serde_json::from_str::<Value>(&text)
+            .map(Arc::new)
+            .map_err(|e| format!("schema {} is not valid JSON: {}", location, e))
+    };
+    if !is_remote(location) {
+        return read_local_schema(location, document_dir, &request.working_dir).and_then(parse);
+    }
+    // Checked before the failure cache so trusting the folder later still
+    // lets the schema load.
+    if !request.trusted && !is_vetted_url(location, &request.config) {
+        return Err(format!(
+            "schema {} not downloaded: workspace is not trusted",
+            location
+        ));
+    }
+
+    if let Some(schema) = SCHEMA_CACHE
+        .lock()
+        .ok()
+        .and_then(|c| c.get(location).cloned())
+    {
+        return Ok(schema);
+    }
+    if FAILED_SCHEMAS
+        .lock()
+        .map(|f| f.contains(location))
+        .unwrap_or(false)
+    {
+        return Err(format!("schema {} is unavailable", location));
+    }
+
+    match read_remote_schema(location, request).and_then(parse) {
+        Ok(schema) => {
+            if let Ok(mut cache) = SCHEMA_CACHE.lock() {
+                cache.insert(location.to_string(), schema.clone());
+            }
+            Ok(schema)
+        }
+        Err(e) => {
+            tracing::debug!("json-schema: {}", e);
+            if let Ok(mut failed) = FAILED_SCHEMAS.lock() {
+                failed.insert(location.to_string());
+            }
+            Err(e)
+        }
+    }
+}
+
+fn is_remote(location: &str) -> bool {
+    location.starts_with("http://") || location.starts_with("https://")
+}
+
+/// Whether `location` comes from the built-in catalog or the user's own
+/// associations rather than from the document being validated.
+fn is_vetted_url(location: &str, config: &JsonSchemaConfig) -> bool {
+    catalog::BUILTIN_CATALOG.iter().any(|e| e.url == location)
+        || config.associations.iter().any(|a| a.schema == location)
+}
+
+/// Download `location` into the schema cache directory (once) and read it.
+/// The download goes to a temporary file that is only renamed into place
+/// once it completes, so a failed or truncated download is never cached.
+fn read_remote_schema(location: &str, request: &ValidationRequest) -> Result<String, String> {
+    if !request.config.download {
+        return Err(format!(
+            "schema {} not downloaded (json_schemas.download is off)",
+            location
+        ));
+    }
+    let file_name = crate::workspace::encode_path_for_filename(Path::new(location));
+    let target = request.schemas_dir.join(&file_name);
+    if !target.exists() {
+        std::fs::create_dir_all(&request.schemas_dir)
+            .map_err(|e| format!("failed to create schema cache: {}", e))?;
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or(0);
+        let tmp = request.schemas_dir.join(format!(
+            "{}-{}-{}.tmp",
+            file_name,
+            std::process::id(),
+            timestamp
+        ));
+        let downloaded =
+            crate::services::http::download_to_file(location, &tmp).and_then(|status| {
+                if !(200..300).contains(&status) {
+                    return Err(format!("schema {} returned HTTP {}", location, status));
+                }
+                std::fs::rename(&tmp, &target)
+                    .map_err(|e| format!("failed to cache schema {}: {}", location, e))
+            });
+        if let Err(e) = downloaded {
+            #[allow(clippy::let_underscore_must_use)]
+            let _ = std::fs::remove_file(&tmp);
+            return Err(e);
+        }
+    }
+    std::fs::read_to_string(&target)
+        .map_err(|e| format!("failed to read {}: {}", target.display(), e))
+}
+
+fn read_local_schema(
+    location: &str,
+    document_dir: Option<&Path>,
+    working_dir: &Path,
+) -> Result<String, String> {
+    let local = location.strip_prefix("file://").unwrap_or(location);
+    let local = Path::new(local);
+    let candidates: Vec<PathBuf> = if local.is_absolute() {
+        vec![local.to_path_buf()]
+    } else {
+        document_dir
+            .map(|d| d.join(local))
+            .into_iter()
+            .chain(std::iter::once(working_dir.join(local)))
+            .collect()
+    };
+    candidates
+        .iter()
+        .find_map(|p| std::fs::read_to_string(p).ok())
+        .ok_or_else(|| format!("schema {} not found", location))
+}
+
+/// Validate a document and return its diagnostics. Documents with no
+/// associated schema produce none; an unparsable document with a schema
+/// produces a single syntax diagnostic.
+pub fn validate_document(request: &ValidationRequest) -> Vec<Diagnostic> {
+    let parsed = document::parse(&request.text, request.format);
+    let root = parsed.as_ref().ok().and_then(Option::as_ref);
+    let Some(location) =
+        resolve_schema_location(&request.text, root, &request.path, &request.config)
+    else {
+        return Vec::new();
+    };
+
+    let root = match parsed {
+        Ok(Some(root)) => root,
+        Ok(None) => return Vec::new(),
+        Err(failure) => {
+            return vec![make_diagnostic(
+                &request.text,
+                failure.range,
+                failure.message,
+                DiagnosticSeverity::ERROR,
+            )];
+        }
+    };
+
+    let schema = match load_schema(&location, request.path.parent(), request) {
+        Ok(schema) => schema,
+        Err(_) => return Vec::new(),
+    };
+
+    validator::validate(&root, &schema)
+        .into_iter()
+        .map(|v| {
+            let severity = match v.severity {
+                ViolationSeverity::Error => DiagnosticSeverity::ERROR,
+                ViolationSeverity::Warning => DiagnosticSeverity::WARNING,
+            };
+            make_diagnostic(&request.text, v.range, v.message, severity)
+        })
+        .collect()
+}
+
+/// Documentation for the key or value at byte `offset` of a document, from
+/// its schema, and the range it covers. `None` when the document has no
+/// schema or the schema doesn't describe that spot.
+pub fn hover_document(request: &ValidationRequest, offset: usize) -> Option<(String, Range)> {
+    let root = document::parse(&request.text, request.format).ok()??;
+    let location =
+        resolve_schema_location(&request.text, Some(&root), &request.path, &request.config)?;
+    let (path, range) = root.path_at(offset)?;
+    let schema = load_schema(&location, request.path.parent(), request).ok()?;
+    let description = validator::describe_path(&schema, &path)?;
+    Some((
+        description,
+        Range {
+            start: byte_to_position(&request.text, range.start),
+            end: byte_to_position(&request.text, range.end),
+        },
+    ))
+}
+
+fn make_diagnostic(
+    text: &str,
+    range: std::ops::Range<usize>,
+    message: String,
+    severity: DiagnosticSeverity,
+) -> Diagnostic {
+    Diagnostic {
+        range: Range {
+            start: byte_to_position(text, range.start),
+            end: byte_to_position(text, range.end),
+        },
+        severity: Some(severity),
+        source: Some(DIAGNOSTIC_SOURCE.to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+/// Byte offset → LSP position (UTF-16 columns, as diagnostics expect).
+fn byte_to_position(text: &str, offset: usize) -> Position {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let before = &text[..offset];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let character: usize = text[line_start..offset].chars().map(char::len_utf16).sum();
+    Position::new(line as u32, character as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SchemaAssociation;
+
+    fn request(dir: &Path, name: &str, text: &str, config: JsonSchemaConfig) -> ValidationRequest {
+        let path = dir.join(name);
+        ValidationRequest {
+            text: text.to_string(),
+            format: DocumentFormat::for_language(
+                path.extension()
+                    .and_then(|e| e.to_str())
+                    .unwrap_or_default(),
+            )
+            .unwrap(),
+            path,
+            config,
+            working_dir: dir.to_path_buf(),
+            schemas_dir: dir.join("schemas"),
+            trusted: true,
+        }
+    }
+
+    #[test]
+    fn association_maps_file_to_local_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("schema.json"),
+            r#"{"type":"object","properties":{"port":{"type":"integer"}}}"#,
+        )
+        .unwrap();
+        let config = JsonSchemaConfig {
+            associations: vec![SchemaAssociation {
+                file_match: vec!["app.yaml".to_string()],
+                schema: "schema.json".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        let diags = validate_document(&request(dir.path(), "app.yaml", "port: é\n", config));
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].source.as_deref(), Some(DIAGNOSTIC_SOURCE));
+        assert_eq!(diags[0].range.start, Position::new(0, 6));
+        assert_eq!(diags[0].range.end, Position::new(0, 7));
+    }
+
+    #[test]
+    fn hover_shows_the_schema_description() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("schema.json"),
+            r#"{"properties":{"port":{"type":"integer","description":"TCP port"}}}"#,
+        )
+        .unwrap();
+        let config = JsonSchemaConfig {
+            associations: vec![SchemaAssociation {
+                file_match: vec!["app.yaml".to_string()],
+                schema: "schema.json".to_string(),
+            }],
+            ..Default::default()
+        };
+        let request = request(dir.path(), "app.yaml", "name: x\nport: 80\n", config);
+
+        let (text, range) = hover_document(&request, 9).unwrap();
+        assert_eq!(text, "TCP port");
+        assert_eq!(range.start, Position::new(1, 0));
+        assert_eq!(range.end, Position::new(1, 4));
+        assert_eq!(hover_document(&request, 14).unwrap().0, "TCP port");
+        assert!(hover_document(&request, 1).is_none());
+    }
+
+    #[test]
+    fn schema_key_and_modeline_take_precedence() {
+        let config = JsonSchemaConfig::default();
+        let text = r#"{"$schema": "./local.json"}"#;
+        let root = document::parse(text, DocumentFormat::Json).unwrap();
+        assert_eq!(
+            resolve_schema_location(text, root.as_ref(), Path::new("/p/package.json"), &config)
+                .as_deref(),
+            Some("./local.json")
+        );
+
+        let yaml = "# yaml-language-server: $schema=https://example.com/s.json\nkey: 1\n";
+        assert_eq!(
+            resolve_schema_location(yaml, None, Path::new("/p/x.yaml"), &config).as_deref(),
+            Some("https://example.com/s.json")
+        );
+        assert!(resolve_schema_location("{}", None, Path::new("/p/x.json"), &config).is_none());
+    }
+
+    #[test]
+    fn document_schema_urls_need_a_trusted_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut request = request(dir.path(), "data.json", "{}", JsonSchemaConfig::default());
+        request.trusted = false;
+
+        let url = "http://127.0.0.1:9/untrusted.json";
+        let err = load_schema(url, None, &request).unwrap_err();
+        assert!(err.contains("not trusted"), "{}", err);
+        assert!(!FAILED_SCHEMAS.lock().unwrap().contains(url));
+
+        assert!(is_vetted_url(
+            "https://json.schemastore.org/package.json",
+            &request.config
+        ));
+        request.config.associations.push(SchemaAssociation {
+            file_match: vec!["data.json".to_string()],
+            schema: url.to_string(),
+        });
+        assert!(is_vetted_url(url, &request.config));
+    }
+
+    #[test]
+    fn unassociated_documents_are_not_checked() {
+        let dir = tempfile::tempdir().unwrap();
+        let diags = validate_document(&request(
+            dir.path(),
+            "data.json",
+            "{ not json",
+            JsonSchemaConfig::default(),
+        ));
+        assert!(diags.is_empty());
+    }
+
+    #[cfg(feature = "http")]
+    #[test]
+    fn truncated_download_leaves_nothing_in_the_cache() {
+        use std::io::{Read, Write};
+
+        // A server that promises a longer body than it sends, then hangs up.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/schema.json", listener.local_addr().unwrap());
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 1024];
+            assert!(stream.read(&mut buf).unwrap() > 0);
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 1000\r\n\r\n{\"type\": \"obj")
+                .unwrap();
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let request = request(dir.path(), "data.json", "{}", JsonSchemaConfig::default());
+        assert!(read_remote_schema(&url, &request).is_err());
+        server.join().unwrap();
+
+        let cached: Vec<_> = std::fs::read_dir(&request.schemas_dir)
+            .unwrap()
+            .flatten()
+            .map(|e| e.file_name())
+            .collect();
+        assert!(cached.is_empty(), "cache should be empty: {:?}", cached);
+    }
+}
@@ -0,0 +1,783 @@
+//! A pragmatic JSON Schema validator over position-aware [`Node`] trees.
+//!
+//! Covers the keywords real-world schemastore schemas lean on: `type`,
+//! `enum`/`const`, `properties`/`required`/`additionalProperties`/
+//! `patternProperties`, `items`/`prefixItems`, numeric and length bounds,
+//! `pattern`, `allOf`/`anyOf`/`oneOf`/`not`, `if`/`then`/`else`, and local
+//! `$ref`s (`#/definitions/...`, `#/$defs/...`). Unknown keywords are ignored
+//! rather than rejected, so newer drafts degrade gracefully instead of
+//! producing false positives.
+
+use super::document::{Node, NodeKind, PathSegment};
+use serde_json::Value;
+use std::ops::Range;
+
+/// How serious a violation is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViolationSeverity {
+    Error,
+    /// Used for `deprecated` properties.
+    Warning,
+}
+
+/// A single schema violation anchored to a byte range of the document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    pub range: Range<usize>,
+    pub message: String,
+    pub severity: ViolationSeverity,
+}
+
+/// Guard against runaway recursion through cyclic `$ref`s.
+const MAX_DEPTH: usize = 64;
+
+/// Validate `node` against `schema`. `schema` is also the root that local
+/// `$ref`s resolve against.
+pub fn validate(node: &Node, schema: &Value) -> Vec<Violation> {
+    let mut out = Vec::new();
+    Validator { root: schema }.check(node, schema, &mut out, 0);
+    out
+}
+
+/// Human-readable description of the properties an object schema expects,
+/// one `name: description` line per property. Used to enrich diagnostics
+/// about missing or unknown keys.
+pub fn describe_properties(schema: &Value, root: &Value) -> Vec<String> {
+    let schema = resolve_refs(schema, root, 0);
+    let Some(props) = schema.get("properties").and_then(Value::as_object) else {
+        return Vec::new();
+    };
+    let mut lines: Vec<String> = props
+        .iter()
+        .map(|(name, sub)| match description_of(sub, root) {
+            Some(desc) => format!("{}: {}", name, desc),
+            None => name.clone(),
+        })
+        .collect();
+    lines.sort();
+    lines
+}
+
+/// Documentation for the value at `path`: the `markdownDescription`,
+/// `description` or `title` of the schema that governs it, looking through
+/// `$ref`s and `allOf`/`anyOf`/`oneOf`/`then`/`else` branches.
+pub fn describe_path(schema: &Value, path: &[PathSegment]) -> Option<String> {
+    description_at(schema, schema, path, 0)
+}
+
+fn description_at(
+    schema: &Value,
+    root: &Value,
+    path: &[PathSegment],
+    depth: usize,
+) -> Option<String> {
+    if depth > MAX_DEPTH {
+        return None;
+    }
+    let schema = resolve_refs(schema, root, 0);
+    let direct = match path.split_first() {
+        None => full_description(schema),
+        Some((segment, rest)) => child_schemas(schema, segment)
+            .into_iter()
+            .find_map(|sub| description_at(sub, root, rest, depth + 1)),
+    };
+    direct.or_else(|| {
+        ["allOf", "anyOf", "oneOf"]
+            .iter()
+            .filter_map(|k| schema.get(*k).and_then(Value::as_array))
+            .flatten()
+            .chain(["then", "else"].iter().filter_map(|k| schema.get(*k)))
+            .find_map(|sub| description_at(sub, root, path, depth + 1))
+    })
+}
+
+/// The subschemas that apply to one child of a value governed by `schema`.
+fn child_schemas<'a>(schema: &'a Value, segment: &PathSegment) -> Vec<&'a Value> {
+    match segment {
+        PathSegment::Key(key) => {
+            let mut subs: Vec<&Value> = schema
+                .get("properties")
+                .and_then(|p| p.get(key))
+                .into_iter()
+                .collect();
+            if let Some(patterns) = schema.get("patternProperties").and_then(Value::as_object) {
+                subs.extend(
+                    patterns
+                        .iter()
+                        .filter(|(p, _)| regex::Regex::new(p).is_ok_and(|re| re.is_match(key)))
+                        .map(|(_, s)| s),
+                );
+            }
+            if subs.is_empty() {
+                subs.extend(schema.get("additionalProperties").filter(|v| v.is_object()));
+            }
+            subs
+        }
+        PathSegment::Index(index) => {
+            let tuple = schema
+                .get("prefixItems")
+                .or_else(|| schema.get("items").filter(|v| v.is_array()))
+                .and_then(Value::as_array);
+            let rest = match tuple {
+                Some(_) if schema.get("prefixItems").is_some() => schema.get("items"),
+                Some(_) => schema.get("additionalItems"),
+                None => schema.get("items"),
+            };
+            tuple
+                .and_then(|t| t.get(*index))
+                .or(rest.filter(|v| v.is_object()))
+                .into_iter()
+                .collect()
+        }
+    }
+}
+
+/// The whole documentation of one schema, unlike [`description_of`]'s
+/// first line.
+fn full_description(schema: &Value) -> Option<String> {
+    schema
+        .get("markdownDescription")
+        .or_else(|| schema.get("description"))
+        .or_else(|| schema.get("title"))
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+struct Validator<'a> {
+    root: &'a Value,
+}
+
+fn resolve_pointer<'a>(root: &'a Value, reference: &str) -> Option<&'a Value> {
+    let pointer = reference.strip_prefix('#')?;
+    if pointer.is_empty() {
+        return Some(root);
+    }
+    root.pointer(pointer)
+}
+
+/// Follow `$ref` chains so keyword lookups see the referenced schema.
+fn resolve_refs<'a>(schema: &'a Value, root: &'a Value, depth: usize) -> &'a Value {
+    match schema.get("$ref").and_then(Value::as_str) {
+        Some(r) if depth < MAX_DEPTH => match resolve_pointer(root, r) {
+            Some(target) => resolve_refs(target, root, depth + 1),
+            None => schema,
+        },
+        _ => schema,
+    }
+}
+
+fn description_of(schema: &Value, root: &Value) -> Option<String> {
+    let schema = resolve_refs(schema, root, 0);
+    schema
+        .get("description")
+        .or_else(|| schema.get("markdownDescription"))
+        .or_else(|| schema.get("title"))
+        .and_then(Value::as_str)
+        .map(|s| s.lines().next().unwrap_or_default().trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+fn type_matches(node: &Node, ty: &str) -> bool {
+    match (ty, &node.kind) {
+        ("integer", NodeKind::Number(n, _)) => n.fract() == 0.0,
+        _ => node.type_name() == ty,
+    }
+}
+
+fn json_equal(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => x.as_f64() == y.as_f64(),
+        _ => a == b,
+    }
+}
+
+fn format_values(values: &[Value]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Validator<'_> {
+    fn error(&self, out: &mut Vec<Violation>, range: Range<usize>, message: String) {
+        out.push(Violation {
+            range,
+            message,
+            severity: ViolationSeverity::Error,
+        });
+    }
+
+    /// True when `node` validates against `schema` without recording anything.
+    fn is_valid(&self, node: &Node, schema: &Value, depth: usize) -> bool {
+        let mut scratch = Vec::new();
+        self.check(node, schema, &mut scratch, depth);
+        scratch
+            .iter()
+            .all(|v| v.severity != ViolationSeverity::Error)
+    }
+
+    fn check(&self, node: &Node, schema: &Value, out: &mut Vec<Violation>, depth: usize) {
+        if depth > MAX_DEPTH {
+            return;
+        }
+        let schema = match schema {
+            Value::Bool(true) => return,
+            Value::Bool(false) => {
+                self.error(out, node.range.clone(), "Value is not allowed here".into());
+                return;
+            }
+            Value::Object(_) => schema,
+            _ => return,
+        };
+
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            if let Some(target) = resolve_pointer(self.root, reference) {
+                self.check(node, target, out, depth + 1);
+            }
+        }
+
+        if !self.check_type(node, schema, out) {
+            // Every other keyword assumes the right shape; reporting them too
+            // would just pile noise onto the one real mistake.
+            return;
+        }
+
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            let actual = node.to_json();
+            if !values.iter().any(|v| json_equal(v, &actual)) {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!(
+                        "Value is not accepted. Valid values: {}",
+                        format_values(values)
+                    ),
+                );
+            }
+        }
+        if let Some(expected) = schema.get("const") {
+            if !json_equal(expected, &node.to_json()) {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!("Value must be {}", expected),
+                );
+            }
+        }
+
+        match &node.kind {
+            NodeKind::Object(props) => self.check_object(node, props, schema, out, depth),
+            NodeKind::Array(items) => self.check_array(node, items, schema, out, depth),
+            NodeKind::String(s) => self.check_string(node, s, schema, out),
+            NodeKind::Number(n, _) => self.check_number(node, *n, schema, out),
+            NodeKind::Null | NodeKind::Bool(_) => {}
+        }
+
+        self.check_combinators(node, schema, out, depth);
+    }
+
+    /// Returns false when the node has the wrong type.
+    fn check_type(&self, node: &Node, schema: &Value, out: &mut Vec<Violation>) -> bool {
+        let allowed: Vec<&str> = match schema.get("type") {
+            Some(Value::String(t)) => vec![t.as_str()],
+            Some(Value::Array(ts)) => ts.iter().filter_map(Value::as_str).collect(),
+            _ => return true,
+        };
+        if allowed.is_empty() || allowed.iter().any(|t| type_matches(node, t)) {
+            return true;
+        }
+        let mut message = format!(
+            "Incorrect type. Expected {}, found {}",
+            allowed.join(" or "),
+            node.type_name()
+        );
+        if let Some(desc) = description_of(schema, self.root) {
+            message.push_str("\n\n");
+            message.push_str(&desc);
+        }
+        self.error(out, node.range.clone(), message);
+        false
+    }
+
+    fn check_object(
+        &self,
+        node: &Node,
+        props: &[super::document::Property],
+        schema: &Value,
+        out: &mut Vec<Violation>,
+        depth: usize,
+    ) {
+        let declared = schema.get("properties").and_then(Value::as_object);
+        let patterns: Vec<(regex::Regex, &Value)> = schema
+            .get("patternProperties")
+            .and_then(Value::as_object)
+            .map(|m| {
+                m.iter()
+                    .filter_map(|(p, s)| regex::Regex::new(p).ok().map(|re| (re, s)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let additional = schema.get("additionalProperties");
+
+        for prop in props {
+            let mut matched = false;
+            if let Some(sub) = declared.and_then(|d| d.get(&prop.key)) {
+                matched = true;
+                let sub_resolved = resolve_refs(sub, self.root, 0);
+                if sub_resolved.get("deprecated").and_then(Value::as_bool) == Some(true) {
+                    let mut message = format!("Property \"{}\" is deprecated", prop.key);
+                    if let Some(msg) = sub_resolved
+                        .get("deprecationMessage")
+                        .and_then(Value::as_str)
+                    {
+                        message = format!("{}: {}", message, msg);
+                    }
+                    out.push(Violation {
+                        range: prop.key_range.clone(),
+                        message,
+                        severity: ViolationSeverity::Warning,
+                    });
+                }
+                self.check(&prop.value, sub, out, depth + 1);
+            }
+            for (re, sub) in &patterns {
+                if re.is_match(&prop.key) {
+                    matched = true;
+                    self.check(&prop.value, sub, out, depth + 1);
+                }
+            }
+            if matched {
+                continue;
+            }
+            match additional {
+                Some(Value::Bool(false)) => {
+                    let mut message = format!("Property \"{}\" is not allowed", prop.key);
+                    let expected = describe_properties(schema, self.root);
+                    if !expected.is_empty() {
+                        message.push_str("\n\nExpected properties:\n");
+                        message.push_str(&expected.join("\n"));
+                    }
+                    self.error(out, prop.key_range.clone(), message);
+                }
+                Some(sub @ Value::Object(_)) => self.check(&prop.value, sub, out, depth + 1),
+                _ => {}
+            }
+        }
+
+        if let Some(required) = schema.get("required").and_then(Value::as_array) {
+            // Anchor on the opening brace so the squiggle stays short.
+            let anchor = node.range.start..(node.range.start + 1).min(node.range.end);
+            for name in required.iter().filter_map(Value::as_str) {
+                if props.iter().any(|p| p.key == name) {
+                    continue;
+                }
+                let mut message = format!("Missing property \"{}\"", name);
+                if let Some(desc) = declared
+                    .and_then(|d| d.get(name))
+                    .and_then(|s| description_of(s, self.root))
+                {
+                    message.push_str("\n\n");
+                    message.push_str(name);
+                    message.push_str(": ");
+                    message.push_str(&desc);
+                }
+                self.error(out, anchor.clone(), message);
+            }
+        }
+
+        let count = props.len() as u64;
+        if let Some(min) = schema.get("minProperties").and_then(Value::as_u64) {
+            if count < min {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!(
+                        "Object has fewer properties than the required number of {}",
+                        min
+                    ),
+                );
+            }
+        }
+        if let Some(max) = schema.get("maxProperties").and_then(Value::as_u64) {
+            if count > max {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!("Object has more properties than the limit of {}", max),
+                );
+            }
+        }
+    }
+
+    fn check_array(
+        &self,
+        node: &Node,
+        items: &[Node],
+        schema: &Value,
+        out: &mut Vec<Violation>,
+        depth: usize,
+    ) {
+        // Tuple form: draft 2020-12 `prefixItems` or older array-valued `items`.
+        let tuple = schema
+            .get("prefixItems")
+            .or_else(|| schema.get("items").filter(|v| v.is_array()))
+            .and_then(Value::as_array);
+        let rest = match tuple {
+            Some(_) if schema.get("prefixItems").is_some() => schema.get("items"),
+            Some(_) => schema.get("additionalItems"),
+            None => schema.get("items"),
+        };
+        for (i, item) in items.iter().enumerate() {
+            match tuple.and_then(|t| t.get(i)) {
+                Some(sub) => self.check(item, sub, out, depth + 1),
+                None => {
+                    if let Some(sub) = rest {
+                        self.check(item, sub, out, depth + 1);
+                    }
+                }
+            }
+        }
+
+        let count = items.len() as u64;
+        if let Some(min) = schema.get("minItems").and_then(Value::as_u64) {
+            if count < min {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!("Array has too few items. Expected {} or more", min),
+                );
+            }
+        }
+        if let Some(max) = schema.get("maxItems").and_then(Value::as_u64) {
+            if count > max {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!("Array has too many items. Expected {} or fewer", max),
+                );
+            }
+        }
+        if schema.get("uniqueItems").and_then(Value::as_bool) == Some(true) {
+            let values: Vec<Value> = items.iter().map(Node::to_json).collect();
+            for (i, item) in items.iter().enumerate() {
+                if values[..i].iter().any(|v| json_equal(v, &values[i])) {
+                    self.error(out, item.range.clone(), "Array has duplicate items".into());
+                }
+            }
+        }
+    }
+
+    fn check_string(&self, node: &Node, s: &str, schema: &Value, out: &mut Vec<Violation>) {
+        let len = s.chars().count() as u64;
+        if let Some(min) = schema.get("minLength").and_then(Value::as_u64) {
+            if len < min {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!("String is shorter than the minimum length of {}", min),
+                );
+            }
+        }
+        if let Some(max) = schema.get("maxLength").and_then(Value::as_u64) {
+            if len > max {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!("String is longer than the maximum length of {}", max),
+                );
+            }
+        }
+        if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+            if let Ok(re) = regex::Regex::new(pattern) {
+                if !re.is_match(s) {
+                    self.error(
+                        out,
+                        node.range.clone(),
+                        format!("String does not match the pattern \"{}\"", pattern),
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_number(&self, node: &Node, n: f64, schema: &Value, out: &mut Vec<Violation>) {
+        let bound = |key: &str| schema.get(key).and_then(Value::as_f64);
+        // Draft 4 spells exclusive bounds as booleans next to minimum/maximum.
+        let exclusive_flag = |key: &str| schema.get(key).and_then(Value::as_bool).unwrap_or(false);
+
+        if let Some(min) = bound("minimum") {
+            if n < min || (exclusive_flag("exclusiveMinimum") && n == min) {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!("Value is below the minimum of {}", min),
+                );
+            }
+        }
+        if let Some(max) = bound("maximum") {
+            if n > max || (exclusive_flag("exclusiveMaximum") && n == max) {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!("Value is above the maximum of {}", max),
+                );
+            }
+        }
+        if let Some(min) = bound("exclusiveMinimum") {
+            if n <= min {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!("Value must be greater than {}", min),
+                );
+            }
+        }
+        if let Some(max) = bound("exclusiveMaximum") {
+            if n >= max {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!("Value must be less than {}", max),
+                );
+            }
+        }
+        if let Some(step) = bound("multipleOf").filter(|s| *s > 0.0) {
+            let ratio = n / step;
+            if (ratio - ratio.round()).abs() > 1e-9 {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    format!("Value is not a multiple of {}", step),
+                );
+            }
+        }
+    }
+
+    fn check_combinators(
+        &self,
+        node: &Node,
+        schema: &Value,
+        out: &mut Vec<Violation>,
+        depth: usize,
+    ) {
+        if let Some(all) = schema.get("allOf").and_then(Value::as_array) {
+            for sub in all {
+                self.check(node, sub, out, depth + 1);
+            }
+        }
+
+        if let Some(any) = schema.get("anyOf").and_then(Value::as_array) {
+            if !any.iter().any(|sub| self.is_valid(node, sub, depth + 1)) {
+                self.report_best_branch(node, any, out, depth, "anyOf");
+            }
+        }
+
+        if let Some(one) = schema.get("oneOf").and_then(Value::as_array) {
+            let matching = one
+                .iter()
+                .filter(|sub| self.is_valid(node, sub, depth + 1))
+                .count();
+            match matching {
+                1 => {}
+                0 => self.report_best_branch(node, one, out, depth, "oneOf"),
+                _ => self.error(
+                    out,
+                    node.range.clone(),
+                    "Value matches more than one of the allowed schemas".into(),
+                ),
+            }
+        }
+
+        if let Some(not) = schema.get("not") {
+            if self.is_valid(node, not, depth + 1) {
+                self.error(
+                    out,
+                    node.range.clone(),
+                    "Value matches a schema that is not allowed".into(),
+                );
+            }
+        }
+
+        if let Some(cond) = schema.get("if") {
+            let branch = if self.is_valid(node, cond, depth + 1) {
+                schema.get("then")
+            } else {
+                schema.get("else")
+            };
+            if let Some(branch) = branch {
+                self.check(node, branch, out, depth + 1);
+            }
+        }
+    }
+
+    /// When no alternative of an `anyOf`/`oneOf` matches, report the errors of
+    /// the alternative that came closest (fewest errors) — that is almost
+    /// always the one the author meant, and its errors point at the real typo.
+    fn report_best_branch(
+        &self,
+        node: &Node,
+        branches: &[Value],
+        out: &mut Vec<Violation>,
+        depth: usize,
+        keyword: &str,
+    ) {
+        let best = branches
+            .iter()
+            .map(|sub| {
+                let mut errs = Vec::new();
+                self.check(node, sub, &mut errs, depth + 1);
+                errs
+            })
+            .filter(|errs| !errs.is_empty())
+            .min_by_key(|errs| errs.len());
+        match best {
+            Some(errs) => out.extend(errs),
+            None => self.error(
+                out,
+                node.range.clone(),
+                format!("Value does not match any schema in {}", keyword),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::json_schema::document::{parse, DocumentFormat};
+    use serde_json::json;
+
+    fn run(text: &str, schema: Value) -> Vec<(String, String)> {
+        let node = parse(text, DocumentFormat::Json).unwrap().unwrap();
+        validate(&node, &schema)
+            .into_iter()
+            .map(|v| (text[v.range].to_string(), v.message))
+            .collect()
+    }
+
+    #[test]
+    fn reports_type_mismatch_at_the_value() {
+        let schema =
+            json!({"properties": {"port": {"type": "integer", "description": "TCP port"}}});
+        let errs = run(r#"{"port": "80"}"#, schema);
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].0, "\"80\"");
+        assert!(errs[0]
+            .1
+            .starts_with("Incorrect type. Expected integer, found string"));
+        assert!(errs[0].1.contains("TCP port"));
+    }
+
+    #[test]
+    fn missing_required_property_includes_its_description() {
+        let schema = json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {"name": {"type": "string", "description": "Package name"}}
+        });
+        let errs = run("{}", schema);
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].0, "{");
+        assert!(errs[0].1.contains("Missing property \"name\""));
+        assert!(errs[0].1.contains("name: Package name"));
+    }
+
+    #[test]
+    fn unknown_property_lists_expected_ones() {
+        let schema = json!({
+            "additionalProperties": false,
+            "properties": {"a": {"description": "first"}, "b": {}}
+        });
+        let errs = run(r#"{"a": 1, "c": 2}"#, schema);
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].0, "\"c\"");
+        assert!(errs[0].1.contains("a: first"));
+        assert!(errs[0].1.contains("\nb"));
+    }
+
+    #[test]
+    fn describes_paths_through_refs_and_branches() {
+        let schema = json!({
+            "definitions": {
+                "server": {"properties": {"port": {"description": "TCP port"}}}
+            },
+            "properties": {
+                "servers": {"items": {"$ref": "#/definitions/server"}},
+                "mode": {"markdownDescription": "Run **mode**", "description": "Run mode"}
+            },
+            "allOf": [{"properties": {"extra": {"title": "Extra"}}}]
+        });
+        let key = |k: &str| PathSegment::Key(k.to_string());
+        assert_eq!(
+            describe_path(
+                &schema,
+                &[key("servers"), PathSegment::Index(2), key("port")]
+            )
+            .as_deref(),
+            Some("TCP port")
+        );
+        assert_eq!(
+            describe_path(&schema, &[key("mode")]).as_deref(),
+            Some("Run **mode**")
+        );
+        assert_eq!(
+            describe_path(&schema, &[key("extra")]).as_deref(),
+            Some("Extra")
+        );
+        assert_eq!(describe_path(&schema, &[key("missing")]), None);
+    }
+
+    #[test]
+    fn follows_local_refs_and_enums() {
+        let schema = json!({
+            "definitions": {"level": {"enum": ["low", "high"]}},
+            "properties": {"level": {"$ref": "#/definitions/level"}}
+        });
+        assert!(run(r#"{"level": "high"}"#, schema.clone()).is_empty());
+        let errs = run(r#"{"level": "mid"}"#, schema);
+        assert_eq!(errs[0].0, "\"mid\"");
+        assert!(errs[0].1.contains("\"low\", \"high\""));
+    }
+
+    #[test]
+    fn bounds_patterns_and_arrays() {
+        let schema = json!({
+            "properties": {
+                "n": {"minimum": 1, "maximum": 3},
+                "s": {"pattern": "^[a-z]+$", "maxLength": 3},
+                "xs": {"items": {"type": "string"}, "minItems": 2, "uniqueItems": true}
+            }
+        });
+        let errs = run(r#"{"n": 5, "s": "abcd", "xs": ["a", 1]}"#, schema);
+        let spans: Vec<&str> = errs.iter().map(|(s, _)| s.as_str()).collect();
+        assert_eq!(spans, vec!["5", "\"abcd\"", "1"]);
+    }
+
+    #[test]
+    fn one_of_reports_closest_branch() {
+        let schema = json!({
+            "oneOf": [
+                {"type": "object", "required": ["run"], "properties": {"run": {"type": "string"}}},
+                {"type": "string"}
+            ]
+        });
+        assert!(run(r#""x""#, schema.clone()).is_empty());
+        let errs = run(r#"{"run": 3}"#, schema);
+        assert_eq!(errs.len(), 1);
+        assert_eq!(errs[0].0, "3");
+    }
+
+    #[test]
+    fn deprecated_property_is_a_warning() {
+        let schema = json!({"properties": {"old": {"deprecated": true}}});
+        let node = parse(r#"{"old": 1}"#, DocumentFormat::Json)
+            .unwrap()
+            .unwrap();
+        let v = validate(&node, &schema);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].severity, ViolationSeverity::Warning);
+    }
+}
@@ -0,0 +1,354 @@
+//! Command-line linters reported as diagnostics.
+//!
+//! A linter is an external command (shellcheck, ruff, eslint, cargo clippy,
+//! ...) configured under `linters` in the config. It runs on save — and,
+//! for linters that read the buffer from stdin, after edits pause — and
+//! every line of its output is tried against the linter's `pattern`, a
+//! regular expression with named groups (a "problem matcher"). Matches
+//! become LSP diagnostics, so they render, hover and navigate exactly like
+//! language-server results, for languages that have no server installed.
+
+use crate::config::{LintSeverity, LinterConfig};
+use crate::services::process_hidden::HideWindow;
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Prefix of the key linter diagnostics are stored under, alongside the
+/// language servers' results: `linter:<name>`.
+pub const DIAGNOSTIC_KEY_PREFIX: &str = "linter:";
+
+/// Everything a background lint run needs, snapshotted on the UI thread.
+#[derive(Debug, Clone)]
+pub struct LintRequest {
+    /// Linter name (the key in the `linters` config map)
+    pub name: String,
+    pub config: LinterConfig,
+    /// The file being linted
+    pub path: PathBuf,
+    /// Buffer content, piped to the linter when `config.stdin` is set
+    pub text: Option<String>,
+    pub working_dir: PathBuf,
+}
+
+/// Diagnostics from one lint run, grouped by file. Per-file linters always
+/// report (possibly empty) results for the linted file.
+pub type LintResults = HashMap<PathBuf, Vec<Diagnostic>>;
+
+/// Run a linter to completion and parse its output.
+pub fn run_linter(request: &LintRequest) -> Result<LintResults, String> {
+    let pattern = regex::Regex::new(&request.config.pattern)
+        .map_err(|e| format!("invalid pattern for linter '{}': {}", request.name, e))?;
+    let output = run_command(request)?;
+    let disk_text;
+    let text = match &request.text {
+        Some(text) => Some(text.as_str()),
+        None if !request.config.workspace => {
+            disk_text = std::fs::read_to_string(&request.path).ok();
+            disk_text.as_deref()
+        }
+        None => None,
+    };
+    Ok(parse_output(&pattern, &output, request, text))
+}
+
+/// Spawn the linter, feed it the buffer if configured, and collect stdout
+/// followed by stderr. A non-zero exit status is not an error: most
+/// linters use it to signal that they found problems.
+fn run_command(request: &LintRequest) -> Result<String, String> {
+    let config = &request.config;
+    let file = request.path.display().to_string();
+    let mut cmd = Command::new(&config.command);
+    cmd.args(config.args.iter().map(|a| a.replace("$FILE", &file)))
+        .current_dir(&request.working_dir)
+        .stdin(if config.stdin {
+            Stdio::piped()
+        } else {
+            Stdio::null()
+        })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .hide_window();
+
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("failed to run '{}': {}", config.command, e))?;
+
+    // Write stdin on its own thread so a linter that streams output before
+    // reading all of its input can't deadlock against us.
+    let stdin_writer = match (config.stdin, child.stdin.take()) {
+        (true, Some(mut stdin)) => {
+            let content = request.text.clone().unwrap_or_default();
+            Some(std::thread::spawn(move || {
+                // A linter that exits early closes the pipe; that's fine.
+                #[allow(clippy::let_underscore_must_use)]
+                let _ = stdin.write_all(content.as_bytes());
+            }))
+        }
+        _ => None,
+    };
+
+    // Kill the child by PID if it overruns its timeout; `wait_with_output`
+    // would otherwise block until a stuck linter closes its pipes.
+    let timeout = Duration::from_millis(config.timeout_ms);
+    let timed_out = Arc::new(AtomicBool::new(false));
+    let finished = Arc::new(AtomicBool::new(false));
+    let child_pid = child.id();
+    let watchdog = {
+        let timed_out = Arc::clone(&timed_out);
+        let finished = Arc::clone(&finished);
+        std::thread::spawn(move || {
+            let start = Instant::now();
+            while start.elapsed() < timeout {
+                if finished.load(Ordering::SeqCst) {
+                    return;
+                }
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            timed_out.store(true, Ordering::SeqCst);
+            #[cfg(unix)]
+            {
+                // SAFETY: libc::kill is async-signal-safe and we only signal
+                // a child we spawned.
+                unsafe {
+                    libc::kill(child_pid as i32, libc::SIGKILL);
+                }
+            }
+            #[cfg(not(unix))]
+            {
+                let _ = child_pid;
+            }
+        })
+    };
+
+    let output = child.wait_with_output();
+    finished.store(true, Ordering::SeqCst);
+    if let Some(handle) = stdin_writer {
+        #[allow(clippy::let_underscore_must_use)]
+        let _ = handle.join();
+    }
+    #[allow(clippy::let_underscore_must_use)]
+    let _ = watchdog.join();
+
+    if timed_out.load(Ordering::SeqCst) {
+        return Err(format!(
+            "linter '{}' timed out after {}ms",
+            request.name, config.timeout_ms
+        ));
+    }
+    let output = output.map_err(|e| format!("failed to get output: {}", e))?;
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    text.push('\n');
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    Ok(text)
+}
+
+/// Apply the problem matcher to every output line. `text` is the linted
+/// file's content, used to convert character columns to UTF-16.
+pub fn parse_output(
+    pattern: &regex::Regex,
+    output: &str,
+    request: &LintRequest,
+    text: Option<&str>,
+) -> LintResults {
+    let mut results = LintResults::new();
+    if !request.config.workspace {
+        results.insert(request.path.clone(), Vec::new());
+    }
+    for line in output.lines() {
+        let Some(caps) = pattern.captures(line) else {
+            continue;
+        };
+        let Some(line_no) = number(&caps, "line") else {
+            continue;
+        };
+        // Per-file linters report on the file they were given, whatever
+        // name they print for it ("-" for stdin, a relative path, ...).
+        let path = if request.config.workspace {
+            let Some(file) = caps.name("file") else {
+                continue;
+            };
+            request.working_dir.join(file.as_str())
+        } else {
+            request.path.clone()
+        };
+        let file_text = if path == request.path { text } else { None };
+
+        let start_line = line_no.saturating_sub(1);
+        let start_col = number(&caps, "column").map(|c| c.saturating_sub(1));
+        let end_line = number(&caps, "end_line").map_or(start_line, |l| l.saturating_sub(1));
+        let end_col = number(&caps, "end_column").map(|c| c.saturating_sub(1));
+        let line_text = |l: u32| file_text.and_then(|t| t.lines().nth(l as usize));
+
+        let (start, end) = match (start_col, end_col) {
+            (Some(start_col), Some(end_col)) => (
+                utf16_position(line_text(start_line), start_line, start_col),
+                utf16_position(line_text(end_line), end_line, end_col),
+            ),
+            // Underline one character when the linter gives only a start.
+            (Some(start_col), None) => (
+                utf16_position(line_text(start_line), start_line, start_col),
+                utf16_position(line_text(start_line), start_line, start_col + 1),
+            ),
+            // No column: underline the whole line.
+            (None, _) => {
+                let len = line_text(start_line).map_or(0, |l| l.encode_utf16().count());
+                (
+                    Position::new(start_line, 0),
+                    Position::new(start_line, len.max(1) as u32),
+                )
+            }
+        };
+
+        let severity = caps
+            .name("severity")
+            .and_then(|s| parse_severity(s.as_str()))
+            .unwrap_or_else(|| severity_for(request.config.default_severity));
+        let message = caps
+            .name("message")
+            .map(|m| m.as_str().trim().to_string())
+            .filter(|m| !m.is_empty())
+            .unwrap_or_else(|| line.trim().to_string());
+
+        results.entry(path).or_default().push(Diagnostic {
+            range: Range { start, end },
+            severity: Some(severity),
+            code: caps
+                .name("code")
+                .map(|c| NumberOrString::String(c.as_str().to_string())),
+            source: Some(request.name.clone()),
+            message,
+            ..Default::default()
+        });
+    }
+    results
+}
+
+fn number(caps: &regex::Captures, group: &str) -> Option<u32> {
+    caps.name(group)?.as_str().parse().ok()
+}
+
+/// Character column → UTF-16 column, when the line's text is known.
+fn utf16_position(line_text: Option<&str>, line: u32, col: u32) -> Position {
+    let character = match line_text {
+        Some(text) => {
+            let within: usize = text.chars().take(col as usize).map(char::len_utf16).sum();
+            // Columns past the end of the line stay past the end.
+            within as u32 + col.saturating_sub(text.chars().count() as u32)
+        }
+        None => col,
+    };
+    Position::new(line, character)
+}
+
+/// Map the severity words linters print onto LSP severities.
+fn parse_severity(word: &str) -> Option<DiagnosticSeverity> {
+    match word.to_ascii_lowercase().as_str() {
+        "error" | "fatal" | "e" => Some(DiagnosticSeverity::ERROR),
+        "warning" | "warn" | "w" => Some(DiagnosticSeverity::WARNING),
+        "info" | "information" | "note" | "i" => Some(DiagnosticSeverity::INFORMATION),
+        "hint" | "style" | "help" => Some(DiagnosticSeverity::HINT),
+        _ => None,
+    }
+}
+
+fn severity_for(severity: LintSeverity) -> DiagnosticSeverity {
+    match severity {
+        LintSeverity::Error => DiagnosticSeverity::ERROR,
+        LintSeverity::Warning => DiagnosticSeverity::WARNING,
+        LintSeverity::Info => DiagnosticSeverity::INFORMATION,
+        LintSeverity::Hint => DiagnosticSeverity::HINT,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use std::path::Path;
+
+    fn preset(name: &str, text: Option<&str>) -> (regex::Regex, LintRequest) {
+        let config = Config::default().linters[name].clone();
+        let pattern = regex::Regex::new(&config.pattern).unwrap();
+        let request = LintRequest {
+            name: name.to_string(),
+            config,
+            path: PathBuf::from("/project/src/file"),
+            text: text.map(str::to_string),
+            working_dir: PathBuf::from("/project"),
+        };
+        (pattern, request)
+    }
+
+    #[test]
+    fn shellcheck_preset_parses_gcc_format() {
+        let (pattern, request) = preset("shellcheck", Some("#!/bin/sh\necho $1\n"));
+        let output = "-:2:6: note: Double quote to prevent globbing and word splitting. [SC2086]\n";
+        let results = parse_output(&pattern, output, &request, request.text.as_deref());
+        let diags = &results[&request.path];
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].range.start, Position::new(1, 5));
+        assert_eq!(diags[0].range.end, Position::new(1, 6));
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::INFORMATION));
+        assert_eq!(
+            diags[0].code,
+            Some(NumberOrString::String("SC2086".to_string()))
+        );
+        assert_eq!(
+            diags[0].message,
+            "Double quote to prevent globbing and word splitting."
+        );
+        assert_eq!(diags[0].source.as_deref(), Some("shellcheck"));
+    }
+
+    #[test]
+    fn ruff_and_eslint_presets_use_default_or_reported_severity() {
+        let (pattern, request) = preset("ruff", None);
+        let output = "src/file.py:1:8: F401 [*] `os` imported but unused\n";
+        let diags = &parse_output(&pattern, output, &request, None)[&request.path];
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::WARNING));
+        assert_eq!(diags[0].message, "`os` imported but unused");
+
+        let (pattern, request) = preset("eslint", None);
+        let output = "/project/src/file.js\n  3:7  error  'x' is assigned a value but never used  no-unused-vars\n\n✖ 1 problem (1 error, 0 warnings)\n";
+        let diags = &parse_output(&pattern, output, &request, None)[&request.path];
+        assert_eq!(diags.len(), 1);
+        assert_eq!(diags[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diags[0].range.start, Position::new(2, 6));
+        assert_eq!(
+            diags[0].code,
+            Some(NumberOrString::String("no-unused-vars".to_string()))
+        );
+    }
+
+    #[test]
+    fn workspace_linter_groups_results_by_reported_file() {
+        let (pattern, request) = preset("clippy", None);
+        let output = "\
+src/main.rs:2:9: warning: unused variable: `x`
+src/lib.rs:10:5: error[E0425]: cannot find value `y` in this scope
+warning: `demo` (bin \"demo\") generated 1 warning
+";
+        let results = parse_output(&pattern, output, &request, None);
+        assert_eq!(results.len(), 2);
+        let lib = &results[Path::new("/project/src/lib.rs")];
+        assert_eq!(lib[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(lib[0].code, Some(NumberOrString::String("E0425".into())));
+        assert!(results.contains_key(Path::new("/project/src/main.rs")));
+    }
+
+    #[test]
+    fn columns_are_converted_to_utf16() {
+        let (pattern, request) = preset("shellcheck", Some("echo 😀 $x\n"));
+        let output = "-:1:8: warning: x is referenced but not assigned. [SC2154]\n";
+        let diags =
+            &parse_output(&pattern, output, &request, request.text.as_deref())[&request.path];
+        assert_eq!(diags[0].range.start, Position::new(0, 8));
+    }
+}
@@ -0,0 +1,248 @@
+//! Local file history: a timestamped copy of a file at every save.
+//!
+//! With `editor.local_history_enabled` on, each save also writes what was
+//! saved into a per-file directory under the data directory. The **File
+//! History** command lists those copies, diffs one against the buffer and
+//! restores it. None of this depends on version control.
+//!
+//! ## File Layout
+//!
+//! ```text
+//! ~/.local/share/fresh/local_history/
+//! └── {encoded file path}/
+//!     ├── 2026-10-18_14-03-22.123   # the file as saved at that time
+//!     └── ...
+//! ```
+//!
+//! A save that writes the same content as the newest copy adds nothing, and
+//! only the newest `editor.local_history_max_versions` copies are kept.
+
+use chrono::{DateTime, Duration, Local, NaiveDateTime, TimeZone};
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use crate::model::line_diff::diff_hunks;
+
+/// Format of a version's file name: its local save time.
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d_%H-%M-%S%.3f";
+
+/// Unchanged lines shown around each change in a diff.
+const DIFF_CONTEXT: usize = 3;
+
+/// One saved copy of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryVersion {
+    /// Where the copy is stored.
+    pub path: PathBuf,
+    /// When the file was saved with this content.
+    pub saved_at: DateTime<Local>,
+}
+
+/// Store `content` as the version of a file saved at `now`, in its history
+/// directory `dir`, then drop the oldest versions past `max_versions`.
+///
+/// Returns false without writing anything when the newest version already
+/// holds `content`.
+pub fn record(
+    dir: &Path,
+    content: &[u8],
+    now: DateTime<Local>,
+    max_versions: usize,
+) -> io::Result<bool> {
+    let existing = versions(dir);
+    if let Some(newest) = existing.first() {
+        if fs::read(&newest.path).is_ok_and(|saved| saved == content) {
+            return Ok(false);
+        }
+    }
+
+    fs::create_dir_all(dir)?;
+    // Two saves within the same millisecond still get a file each.
+    let mut saved_at = now;
+    let mut path = dir.join(saved_at.format(TIMESTAMP_FORMAT).to_string());
+    while path.exists() {
+        saved_at += Duration::milliseconds(1);
+        path = dir.join(saved_at.format(TIMESTAMP_FORMAT).to_string());
+    }
+    fs::write(&path, content)?;
+
+    for old in versions(dir).iter().skip(max_versions.max(1)) {
+        if let Err(e) = fs::remove_file(&old.path) {
+            tracing::warn!("Failed to prune local history {:?}: {}", old.path, e);
+        }
+    }
+    Ok(true)
+}
+
+/// The versions stored in the history directory `dir`, newest first.
+pub fn versions(dir: &Path) -> Vec<HistoryVersion> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut versions: Vec<HistoryVersion> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name();
+            let time = NaiveDateTime::parse_from_str(name.to_str()?, TIMESTAMP_FORMAT).ok()?;
+            let saved_at = Local.from_local_datetime(&time).earliest()?;
+            Some(HistoryVersion {
+                path: entry.path(),
+                saved_at,
+            })
+        })
+        .collect();
+    versions.sort_by_key(|v| std::cmp::Reverse(v.saved_at));
+    versions
+}
+
+/// Lines added and removed going from `old` to `new`.
+pub fn change_counts(old: &str, new: &str) -> (usize, usize) {
+    let old_lines: Vec<&[u8]> = old.lines().map(str::as_bytes).collect();
+    let new_lines: Vec<&[u8]> = new.lines().map(str::as_bytes).collect();
+    diff_hunks(&old_lines, &new_lines).iter().fold(
+        (0, 0),
+        |(added, removed), (old_range, new_range)| {
+            (added + new_range.len(), removed + old_range.len())
+        },
+    )
+}
+
+/// A unified diff from `old` to `new`, headed with their labels. Empty when
+/// the two have the same lines.
+pub fn unified_diff(old_label: &str, new_label: &str, old: &str, new: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let old_bytes: Vec<&[u8]> = old_lines.iter().map(|line| line.as_bytes()).collect();
+    let new_bytes: Vec<&[u8]> = new_lines.iter().map(|line| line.as_bytes()).collect();
+    let hunks = diff_hunks(&old_bytes, &new_bytes);
+    if hunks.is_empty() {
+        return String::new();
+    }
+
+    let mut out = format!("--- {}\n+++ {}\n", old_label, new_label);
+    for group in group_hunks(&hunks) {
+        let (first_old, first_new) = &group[0];
+        let (last_old, last_new) = &group[group.len() - 1];
+        let before = DIFF_CONTEXT.min(first_old.start).min(first_new.start);
+        let after = DIFF_CONTEXT
+            .min(old_lines.len() - last_old.end)
+            .min(new_lines.len() - last_new.end);
+        let old_span = first_old.start - before..last_old.end + after;
+        let new_span = first_new.start - before..last_new.end + after;
+        out.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            hunk_range(&old_span),
+            hunk_range(&new_span)
+        ));
+
+        let mut line = old_span.start;
+        for (old_range, new_range) in group {
+            for text in &old_lines[line..old_range.start] {
+                out.push_str(&format!(" {}\n", text));
+            }
+            for text in &old_lines[old_range.clone()] {
+                out.push_str(&format!("-{}\n", text));
+            }
+            for text in &new_lines[new_range.clone()] {
+                out.push_str(&format!("+{}\n", text));
+            }
+            line = old_range.end;
+        }
+        for text in &old_lines[line..old_span.end] {
+            out.push_str(&format!(" {}\n", text));
+        }
+    }
+    out
+}
+
+/// Hunks split into the runs whose context would overlap, one run per
+/// `@@` block.
+fn group_hunks(hunks: &[(Range<usize>, Range<usize>)]) -> Vec<&[(Range<usize>, Range<usize>)]> {
+    let mut groups = Vec::new();
+    let mut start = 0;
+    for i in 1..=hunks.len() {
+        let split = i == hunks.len() || hunks[i].0.start - hunks[i - 1].0.end > 2 * DIFF_CONTEXT;
+        if split {
+            groups.push(&hunks[start..i]);
+            start = i;
+        }
+    }
+    groups
+}
+
+/// A `start,len` range for an `@@` header, with 1-based line numbers.
+fn hunk_range(span: &Range<usize>) -> String {
+    if span.is_empty() {
+        format!("{},0", span.start)
+    } else {
+        format!("{},{}", span.start + 1, span.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(seconds: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(2026, 10, 18, 14, 0, seconds)
+            .earliest()
+            .unwrap()
+    }
+
+    #[test]
+    fn records_versions_newest_first_and_skips_unchanged_saves() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().join("file");
+
+        assert!(record(&dir, b"one", at(1), 10).unwrap());
+        assert!(record(&dir, b"two", at(2), 10).unwrap());
+        assert!(!record(&dir, b"two", at(3), 10).unwrap());
+
+        let versions = versions(&dir);
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0].saved_at, at(2));
+        assert_eq!(fs::read(&versions[0].path).unwrap(), b"two");
+        assert_eq!(fs::read(&versions[1].path).unwrap(), b"one");
+    }
+
+    #[test]
+    fn prunes_the_oldest_versions() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().join("file");
+        for (i, content) in ["a", "b", "c", "d"].iter().enumerate() {
+            record(&dir, content.as_bytes(), at(i as u32), 2).unwrap();
+        }
+
+        let kept: Vec<Vec<u8>> = versions(&dir)
+            .iter()
+            .map(|v| fs::read(&v.path).unwrap())
+            .collect();
+        assert_eq!(kept, vec![b"d".to_vec(), b"c".to_vec()]);
+    }
+
+    #[test]
+    fn saves_in_the_same_millisecond_keep_both_versions() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path().join("file");
+        record(&dir, b"a", at(1), 10).unwrap();
+        record(&dir, b"b", at(1), 10).unwrap();
+        assert_eq!(versions(&dir).len(), 2);
+    }
+
+    #[test]
+    fn unified_diff_shows_changes_with_context() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n";
+        let new = "1\n2\nthree\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n";
+        assert_eq!(
+            unified_diff("old", "new", old, new),
+            "--- old\n+++ new\n\
+             @@ -1,6 +1,6 @@\n 1\n 2\n-3\n+three\n 4\n 5\n 6\n\
+             @@ -10,3 +10,4 @@\n 10\n 11\n 12\n+13\n"
+        );
+        assert_eq!(unified_diff("old", "new", old, old), "");
+        assert_eq!(change_counts(old, new), (2, 1));
+    }
+}
@@ -3,6 +3,7 @@
 //! This module provides functions to get log file paths following the
 //! XDG Base Directory Specification. Logs are stored in:
 //! - `$XDG_STATE_HOME/fresh/logs/` (typically `~/.local/state/fresh/logs/`)
+//! - `<portable home>/logs/` in portable mode (see `config_io::portable_home`)
 //!
 //! Each Fresh instance uses PID-based log files to support concurrent runs.
 //! On startup, stale log files from dead processes are cleaned up automatically.
@@ -38,8 +39,13 @@ pub fn log_dir() -> &'static PathBuf {
     })
 }
 
-/// Get the XDG state home log directory
+/// Get the XDG state home log directory (`<portable home>/logs` in
+/// portable mode)
 fn get_xdg_log_dir() -> Option<PathBuf> {
+    if let Some(root) = crate::config_io::portable_home() {
+        return Some(root.join("logs"));
+    }
+
     // First try XDG_STATE_HOME
     if let Ok(state_home) = std::env::var("XDG_STATE_HOME") {
         let path = PathBuf::from(state_home);
@@ -296,6 +302,11 @@ pub fn print_all_paths(dir_context: &crate::config_io::DirectoryContext) {
     writeln!(handle, "Fresh directories:").ok();
     writeln!(handle).ok();
 
+    if let Some(root) = crate::config_io::portable_home() {
+        writeln!(handle, "Portable:   {}", root.display()).ok();
+        writeln!(handle).ok();
+    }
+
     writeln!(handle, "Config:     {}", config_dir.display()).ok();
     writeln!(
         handle,
@@ -357,8 +357,8 @@ impl LspClientState {
 fn create_client_capabilities() -> ClientCapabilities {
     use lsp_types::{
         CodeActionClientCapabilities, CodeActionKindLiteralSupport, CodeActionLiteralSupport,
-        CompletionClientCapabilities, DiagnosticClientCapabilities, DiagnosticTag,
-        DiagnosticWorkspaceClientCapabilities, DocumentFormattingClientCapabilities,
+        CompletionClientCapabilities, CompletionItemCapability, DiagnosticClientCapabilities,
+        DiagnosticTag, DiagnosticWorkspaceClientCapabilities, DocumentFormattingClientCapabilities,
         DocumentHighlightClientCapabilities, DocumentRangeFormattingClientCapabilities,
         DocumentSymbolClientCapabilities, DynamicRegistrationClientCapabilities,
         FoldingRangeCapability, FoldingRangeClientCapabilities, FoldingRangeKind,
@@ -432,6 +432,10 @@ fn create_client_capabilities() -> ClientCapabilities {
             // entitled to never register the provider. See sinelaw/fresh#2195.
             completion: Some(CompletionClientCapabilities {
                 dynamic_registration: Some(true),
+                completion_item: Some(CompletionItemCapability {
+                    snippet_support: Some(true),
+                    ..Default::default()
+                }),
                 ..Default::default()
             }),
             hover: Some(HoverClientCapabilities {
@@ -756,6 +760,8 @@ enum LspCommand {
         uri: Uri,
         line: u32,
         character: u32,
+        /// The trigger character typed, or `None` when completion was invoked
+        trigger_character: Option<String>,
     },
 
     /// Request go-to-definition
@@ -1475,9 +1481,10 @@ impl LspState {
         uri: Uri,
         line: u32,
         character: u32,
+        trigger_character: Option<String>,
         pending: &PendingRequests,
     ) -> Result<(), String> {
-        use lsp_types::CompletionParams;
+        use lsp_types::{CompletionContext, CompletionParams, CompletionTriggerKind};
 
         tracing::trace!(
             "LSP: completion request at {}:{}:{}",
@@ -1490,7 +1497,16 @@ impl LspState {
             text_document_position: Self::text_document_position(uri, line, character),
             work_done_progress_params: WorkDoneProgressParams::default(),
             partial_result_params: PartialResultParams::default(),
-            context: None,
+            // Servers use the trigger kind to decide what to offer, e.g. only
+            // members after `.`, or nothing for a lone `:` that isn't `::`.
+            context: Some(CompletionContext {
+                trigger_kind: if trigger_character.is_some() {
+                    CompletionTriggerKind::TRIGGER_CHARACTER
+                } else {
+                    CompletionTriggerKind::INVOKED
+                },
+                trigger_character,
+            }),
         };
 
         // Send request and get response (tracked for cancellation)
@@ -2826,10 +2842,6 @@ struct LspTask {
     /// Next request ID
     next_id: i64,
 
-    /// Pending requests waiting for response, keyed by request id. The stored
-    /// `String` is the request method (see `PendingRequests`).
-    pending: HashMap<i64, (String, oneshot::Sender<Result<Value, String>>)>,
-
     /// Server capabilities
     capabilities: Option<ServerCapabilities>,
 
@@ -2885,6 +2897,7 @@ impl LspTask {
         language_id_overrides: HashMap<String, String>,
         document_versions: Arc<std::sync::Mutex<HashMap<PathBuf, i64>>>,
         long_running_spawner: Arc<dyn crate::services::remote::LongRunningSpawner>,
+        shared: bool,
     ) -> Result<Self, String> {
         tracing::info!("Spawning async LSP server: {} {:?}", command, args);
         tracing::info!("Process limits: {:?}", process_limits);
@@ -2909,10 +2922,19 @@ impl LspTask {
         let env_pairs: Vec<(String, String)> =
             env.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
 
-        let mut stdio_child = long_running_spawner
-            .spawn_stdio(command, args, env_pairs, None, Some(process_limits))
-            .await
-            .map_err(|e| format!("Failed to spawn LSP server '{}': {}", command, e))?;
+        // A shared server is reached through the LSP proxy's relay, which
+        // speaks the same stdio protocol.
+        let spawned = if shared {
+            long_running_spawner
+                .spawn_stdio_shared(command, args, env_pairs, None, Some(process_limits))
+                .await
+        } else {
+            long_running_spawner
+                .spawn_stdio(command, args, env_pairs, None, Some(process_limits))
+                .await
+        };
+        let mut stdio_child =
+            spawned.map_err(|e| format!("Failed to spawn LSP server '{}': {}", command, e))?;
 
         let stdin = stdio_child
             .take_stdin()
@@ -2970,7 +2992,6 @@ impl LspTask {
             stdin,
             stdout,
             next_id: 0,
-            pending: HashMap::new(),
             capabilities: None,
             document_versions,
             pending_opens: HashMap::new(),
@@ -3081,7 +3102,9 @@ impl LspTask {
     // Channel sends and handler results are best-effort: errors are already logged
     // within handler methods, and channel send failures mean the editor is shutting down.
     #[allow(clippy::let_underscore_must_use)]
-    async fn run(self, mut command_rx: mpsc::Receiver<LspCommand>) {
+    /// Process commands until the channel closes. `pending` is shared with
+    /// the `LspHandle` so the editor can count in-flight requests.
+    async fn run(self, mut command_rx: mpsc::Receiver<LspCommand>, pending: PendingRequests) {
         tracing::info!("LspTask::run() started for language: {}", self.language);
 
         // Create shared stdin writer so both command processing and stdout reader can write
@@ -3102,7 +3125,6 @@ impl LspTask {
             language_id_overrides: Arc::new(self.language_id_overrides.clone()),
         };
 
-        let pending = Arc::new(Mutex::new(self.pending));
         let async_tx = state.async_tx.clone();
         let language_clone: String = (*state.language).clone();
         let server_name: String = (*state.server_name).clone();
@@ -3346,11 +3368,19 @@ impl LspTask {
                     uri,
                     line,
                     character,
+                    trigger_character,
                 } => {
                     if initialized {
                         tracing::info!("Processing Completion request for {}", uri.as_str());
                         spawn_request!(state, pending, |s, p| s
-                            .handle_completion(request_id, uri, line, character, &p)
+                            .handle_completion(
+                                request_id,
+                                uri,
+                                line,
+                                character,
+                                trigger_character,
+                                &p
+                            )
                             .await);
                     } else {
                         tracing::trace!("LSP not initialized, sending empty completion");
@@ -4490,6 +4520,9 @@ pub struct LspHandle {
     /// Document version tracking (shared with the async LSP task).
     /// Used to check document versions in workspace/applyEdit.
     document_versions: Arc<std::sync::Mutex<HashMap<PathBuf, i64>>>,
+
+    /// Requests awaiting a response (shared with the async LSP task).
+    pending: PendingRequests,
 }
 
 // Channel sends and state transitions in LspHandle are best-effort: async_tx.send()
@@ -4517,6 +4550,7 @@ impl LspHandle {
         process_limits: ProcessLimits,
         language_id_overrides: std::collections::HashMap<String, String>,
         long_running_spawner: Arc<dyn crate::services::remote::LongRunningSpawner>,
+        shared: bool,
     ) -> Result<Self, String> {
         let (command_tx, command_rx) = mpsc::channel(100); // Buffer up to 100 commands
         let async_tx = async_bridge.sender();
@@ -4544,6 +4578,8 @@ impl LspHandle {
         let document_versions: Arc<std::sync::Mutex<HashMap<PathBuf, i64>>> =
             Arc::new(std::sync::Mutex::new(HashMap::new()));
         let document_versions_for_task = document_versions.clone();
+        let pending: PendingRequests = Arc::new(Mutex::new(HashMap::new()));
+        let pending_for_task = pending.clone();
 
         let state_clone = state.clone();
         let stderr_log_path_clone = stderr_log_path.clone();
@@ -4560,11 +4596,12 @@ impl LspHandle {
                 language_id_overrides,
                 document_versions_for_task,
                 long_running_spawner,
+                shared,
             )
             .await
             {
                 Ok(task) => {
-                    task.run(command_rx).await;
+                    task.run(command_rx, pending_for_task).await;
                 }
                 Err(e) => {
                     tracing::error!("Failed to spawn LSP task: {}", e);
@@ -4630,6 +4667,7 @@ impl LspHandle {
             state,
             runtime: runtime.clone(),
             document_versions,
+            pending,
         })
     }
 
@@ -4643,6 +4681,15 @@ impl LspHandle {
         &self.scope
     }
 
+    /// Number of requests sent to the server that are still awaiting a
+    /// response.
+    pub fn in_flight_requests(&self) -> usize {
+        self.pending
+            .lock()
+            .map(|pending| pending.len())
+            .unwrap_or(0)
+    }
+
     /// Get the document version for a file path, as last sent via didOpen/didChange.
     /// Returns None if the document hasn't been opened with this server.
     pub fn document_version(&self, path: &std::path::Path) -> Option<i64> {
@@ -4805,13 +4852,15 @@ impl LspHandle {
             .map_err(|_| "Failed to send workspace folder change".to_string())
     }
 
-    /// Request completion at position
+    /// Request completion at position. `trigger_character` is the
+    /// server-advertised trigger character that was typed, if any.
     pub fn completion(
         &self,
         request_id: u64,
         uri: Uri,
         line: u32,
         character: u32,
+        trigger_character: Option<char>,
     ) -> Result<(), String> {
         self.command_tx
             .try_send(LspCommand::Completion {
@@ -4819,6 +4868,7 @@ impl LspHandle {
                 uri,
                 line,
                 character,
+                trigger_character: trigger_character.map(String::from),
             })
             .map_err(|_| "Failed to send completion command".to_string())
     }
@@ -5918,6 +5968,7 @@ mod tests {
             ProcessLimits::unlimited(),
             Default::default(),
             local_spawner(),
+            false,
         );
 
         // Should succeed in spawning
@@ -5948,6 +5999,7 @@ mod tests {
             ProcessLimits::unlimited(),
             Default::default(),
             local_spawner(),
+            false,
         )
         .unwrap();
 
@@ -5978,6 +6030,7 @@ mod tests {
             ProcessLimits::unlimited(),
             Default::default(),
             local_spawner(),
+            false,
         )
         .unwrap();
 
@@ -6014,6 +6067,7 @@ mod tests {
             ProcessLimits::unlimited(),
             Default::default(),
             local_spawner(),
+            false,
         )
         .unwrap();
 
@@ -6051,6 +6105,7 @@ mod tests {
             ProcessLimits::unlimited(),
             Default::default(),
             local_spawner(),
+            false,
         );
 
         // Should succeed in creating handle (error happens asynchronously)
@@ -6092,6 +6147,7 @@ mod tests {
                     ProcessLimits::unlimited(),
                     Default::default(),
                     local_spawner(),
+                    false,
                 )
                 .unwrap()
             });
@@ -6165,6 +6221,7 @@ mod tests {
             ProcessLimits::unlimited(),
             Default::default(),
             local_spawner(),
+            false,
         )
         .unwrap();
 
@@ -6218,6 +6275,7 @@ mod tests {
             ProcessLimits::unlimited(),
             Default::default(),
             local_spawner(),
+            false,
         )
         .unwrap();
 
@@ -6289,6 +6347,7 @@ mod tests {
             ProcessLimits::unlimited(),
             Default::default(),
             local_spawner(),
+            false,
         )
         .unwrap();
 
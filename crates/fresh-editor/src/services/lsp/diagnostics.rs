@@ -1,11 +1,14 @@
 //! LSP diagnostics display
 //!
 //! This module handles converting LSP diagnostics to visual overlays in the editor.
-//! Diagnostics are displayed as colored underlines (red for errors, yellow for warnings, etc.)
+//! Diagnostics are displayed as colored underlines (red for errors, yellow for warnings, etc.),
+//! styled per severity by the theme, or as background highlights on terminals that can't draw
+//! styled underlines.
 use crate::model::buffer::Buffer;
 use crate::state::EditorState;
-use crate::view::overlay::{Overlay, OverlayFace, OverlayNamespace};
+use crate::view::overlay::{Overlay, OverlayFace, OverlayNamespace, UnderlineStyle};
 use lsp_types::{Diagnostic, DiagnosticSeverity, Position};
+use ratatui::style::{Modifier, Style};
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
@@ -98,12 +101,23 @@ pub fn apply_diagnostics_to_state_cached(
     state: &mut EditorState,
     diagnostics: &[Diagnostic],
     theme: &crate::view::theme::Theme,
+) -> bool {
+    apply_diagnostics_to_state_cached_with_style(state, diagnostics, theme, false)
+}
+
+/// [`apply_diagnostics_to_state_cached`] for a terminal that may draw styled
+/// underlines; see [`diagnostic_to_overlay_with_style`].
+pub fn apply_diagnostics_to_state_cached_with_style(
+    state: &mut EditorState,
+    diagnostics: &[Diagnostic],
+    theme: &crate::view::theme::Theme,
+    styled_underlines: bool,
 ) -> bool {
     // Get cache key from buffer's file path
     let cache_key = match state.buffer.file_path() {
         Some(path) => path.to_string_lossy().to_string(),
         None => {
-            apply_diagnostics_to_state(state, diagnostics, theme);
+            apply_diagnostics_to_state_with_style(state, diagnostics, theme, styled_underlines);
             return true;
         }
     };
@@ -135,7 +149,7 @@ pub fn apply_diagnostics_to_state_cached(
     );
 
     // Diagnostics have changed, do the expensive update
-    apply_diagnostics_to_state(state, diagnostics, theme);
+    apply_diagnostics_to_state_with_style(state, diagnostics, theme, styled_underlines);
 
     // Update cache for this buffer
     if let Ok(mut cache) = DIAGNOSTIC_CACHE.lock() {
@@ -151,6 +165,19 @@ pub fn diagnostic_to_overlay(
     diagnostic: &Diagnostic,
     buffer: &Buffer,
     theme: &crate::view::theme::Theme,
+) -> Option<(Range<usize>, OverlayFace, i32, &'static str)> {
+    diagnostic_to_overlay_with_style(diagnostic, buffer, theme, false)
+}
+
+/// [`diagnostic_to_overlay`] that honors the theme's per-severity style.
+///
+/// `styled_underlines` says whether the terminal renders colored and curly
+/// underlines; without them every severity falls back to its background.
+pub fn diagnostic_to_overlay_with_style(
+    diagnostic: &Diagnostic,
+    buffer: &Buffer,
+    theme: &crate::view::theme::Theme,
+    styled_underlines: bool,
 ) -> Option<(Range<usize>, OverlayFace, i32, &'static str)> {
     // Convert LSP positions (line/character) to byte offsets
     // LSP uses 0-indexed lines and characters (UTF-16 code units)
@@ -179,38 +206,63 @@ pub fn diagnostic_to_overlay(
     );
 
     // Determine overlay face based on diagnostic severity using theme colors
-    let (face, priority, theme_key) = match diagnostic.severity {
+    use crate::view::theme::DiagnosticStyle;
+    let (style, fg, bg, priority, fg_key, bg_key) = match diagnostic.severity {
         Some(DiagnosticSeverity::ERROR) => (
-            OverlayFace::Background {
-                color: theme.diagnostic_error_bg,
-            },
+            theme.diagnostic_error_style,
+            theme.diagnostic_error_fg,
+            theme.diagnostic_error_bg,
             100, // Highest priority
+            "diagnostic.error_fg",
             "diagnostic.error_bg",
         ),
         Some(DiagnosticSeverity::WARNING) => (
-            OverlayFace::Background {
-                color: theme.diagnostic_warning_bg,
-            },
+            theme.diagnostic_warning_style,
+            theme.diagnostic_warning_fg,
+            theme.diagnostic_warning_bg,
             50, // Medium priority
+            "diagnostic.warning_fg",
             "diagnostic.warning_bg",
         ),
         Some(DiagnosticSeverity::INFORMATION) => (
-            OverlayFace::Background {
-                color: theme.diagnostic_info_bg,
-            },
+            theme.diagnostic_info_style,
+            theme.diagnostic_info_fg,
+            theme.diagnostic_info_bg,
             30, // Lower priority
+            "diagnostic.info_fg",
             "diagnostic.info_bg",
         ),
         Some(DiagnosticSeverity::HINT) | None => (
-            OverlayFace::Background {
-                color: theme.diagnostic_hint_bg,
-            },
+            theme.diagnostic_hint_style,
+            theme.diagnostic_hint_fg,
+            theme.diagnostic_hint_bg,
             10, // Lowest priority
+            "diagnostic.hint_fg",
             "diagnostic.hint_bg",
         ),
         _ => return None, // Unknown severity
     };
 
+    // Underlines in the severity color, unless the terminal can't draw
+    // them: then the background highlight stands in.
+    let underline = match style {
+        DiagnosticStyle::Undercurl => Some(UnderlineStyle::Wavy),
+        DiagnosticStyle::Underline => Some(UnderlineStyle::Straight),
+        DiagnosticStyle::Dotted => Some(UnderlineStyle::Dotted),
+        DiagnosticStyle::Background => None,
+    };
+    let (face, theme_key) = match underline {
+        Some(underline) if styled_underlines => (
+            OverlayFace::Style {
+                style: Style::default()
+                    .add_modifier(Modifier::UNDERLINED | underline.modifier())
+                    .underline_color(fg),
+            },
+            fg_key,
+        ),
+        _ => (OverlayFace::Background { color: bg }, bg_key),
+    };
+
     Some((start_byte..end_byte, face, priority, theme_key))
 }
 
@@ -223,6 +275,17 @@ pub fn apply_diagnostics_to_state(
     state: &mut EditorState,
     diagnostics: &[Diagnostic],
     theme: &crate::view::theme::Theme,
+) {
+    apply_diagnostics_to_state_with_style(state, diagnostics, theme, false);
+}
+
+/// [`apply_diagnostics_to_state`] for a terminal that may draw styled
+/// underlines; see [`diagnostic_to_overlay_with_style`].
+pub fn apply_diagnostics_to_state_with_style(
+    state: &mut EditorState,
+    diagnostics: &[Diagnostic],
+    theme: &crate::view::theme::Theme,
+    styled_underlines: bool,
 ) {
     let ns = lsp_diagnostic_namespace();
 
@@ -233,7 +296,7 @@ pub fn apply_diagnostics_to_state(
     let mut added_count = 0;
     for diagnostic in diagnostics {
         if let Some((range, face, priority, theme_key)) =
-            diagnostic_to_overlay(diagnostic, &state.buffer, theme)
+            diagnostic_to_overlay_with_style(diagnostic, &state.buffer, theme, styled_underlines)
         {
             let message = diagnostic.message.clone();
 
@@ -430,6 +493,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_diagnostic_to_overlay_styled_underline() {
+        let buffer = Buffer::from_str_test("hello world");
+
+        let diagnostic = Diagnostic {
+            range: Range {
+                start: Position {
+                    line: 0,
+                    character: 0,
+                },
+                end: Position {
+                    line: 0,
+                    character: 5,
+                },
+            },
+            severity: Some(DiagnosticSeverity::ERROR),
+            message: "Test error".to_string(),
+            ..Default::default()
+        };
+
+        let mut theme = crate::view::theme::Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let (_, face, _, theme_key) =
+            diagnostic_to_overlay_with_style(&diagnostic, &buffer, &theme, true).unwrap();
+        assert_eq!(theme_key, "diagnostic.error_fg");
+        match face {
+            OverlayFace::Style { style } => {
+                assert!(style.add_modifier.contains(Modifier::UNDERLINED));
+                assert_eq!(
+                    UnderlineStyle::from_modifier(style.add_modifier),
+                    Some(UnderlineStyle::Wavy)
+                );
+                assert_eq!(style.underline_color, Some(theme.diagnostic_error_fg));
+                assert_eq!(style.bg, None);
+            }
+            _ => panic!("Expected Style face"),
+        }
+
+        // A severity styled as a background keeps its highlight.
+        theme.diagnostic_error_style = crate::view::theme::DiagnosticStyle::Background;
+        let (_, face, _, _) =
+            diagnostic_to_overlay_with_style(&diagnostic, &buffer, &theme, true).unwrap();
+        assert!(matches!(face, OverlayFace::Background { .. }));
+    }
+
     #[test]
     fn test_diagnostic_to_overlay_multiline() {
         let buffer = Buffer::from_str_test("line1\nline2\nline3");
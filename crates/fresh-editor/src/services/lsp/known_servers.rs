@@ -0,0 +1,228 @@
+//! Catalog of well-known language servers.
+//!
+//! Used to detect servers that are installed but not configured (so the
+//! editor can offer to enable them) and to suggest an install command for
+//! the current platform when none is present.
+
+use crate::types::{LspServerConfig, ProcessLimits};
+
+/// A language server the editor knows how to set up without user config.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnownServer {
+    /// Language id the server handles (e.g. "python").
+    pub language: &'static str,
+    /// Executable looked up on PATH.
+    pub command: &'static str,
+    /// Arguments needed to run the server over stdio.
+    pub args: &'static [&'static str],
+    /// Files that mark the workspace root for this server.
+    pub root_markers: &'static [&'static str],
+    /// Install command on Linux.
+    pub install_linux: &'static str,
+    /// Install command on macOS.
+    pub install_macos: &'static str,
+    /// Install command on Windows.
+    pub install_windows: &'static str,
+}
+
+impl KnownServer {
+    /// The install command for the platform the editor is running on.
+    pub fn install_command(&self) -> &'static str {
+        if cfg!(target_os = "macos") {
+            self.install_macos
+        } else if cfg!(windows) {
+            self.install_windows
+        } else {
+            self.install_linux
+        }
+    }
+
+    /// Whether the install command can be run unattended in the task runner.
+    /// Commands that need elevated privileges are only shown, never run.
+    pub fn install_is_runnable(&self) -> bool {
+        let cmd = self.install_command();
+        !cmd.is_empty() && !cmd.starts_with("sudo ")
+    }
+
+    /// Server config used when the user enables this server.
+    pub fn to_config(&self) -> LspServerConfig {
+        LspServerConfig {
+            command: self.command.to_string(),
+            args: Some(self.args.iter().map(|a| a.to_string()).collect()),
+            enabled: true,
+            auto_start: true,
+            process_limits: ProcessLimits::default(),
+            root_markers: self.root_markers.iter().map(|m| m.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+const PYTHON_ROOT_MARKERS: &[&str] = &[
+    "pyproject.toml",
+    "setup.py",
+    "setup.cfg",
+    "pyrightconfig.json",
+    ".git",
+];
+const C_ROOT_MARKERS: &[&str] = &["compile_commands.json", "CMakeLists.txt", ".git"];
+
+/// Known servers, in order of preference within each language.
+pub const KNOWN_SERVERS: &[KnownServer] = &[
+    KnownServer {
+        language: "rust",
+        command: "rust-analyzer",
+        args: &[],
+        root_markers: &["Cargo.toml", "rust-project.json", ".git"],
+        install_linux: "rustup component add rust-analyzer",
+        install_macos: "rustup component add rust-analyzer",
+        install_windows: "rustup component add rust-analyzer",
+    },
+    KnownServer {
+        language: "python",
+        command: "pyright-langserver",
+        args: &["--stdio"],
+        root_markers: PYTHON_ROOT_MARKERS,
+        install_linux: "npm install -g pyright",
+        install_macos: "npm install -g pyright",
+        install_windows: "npm install -g pyright",
+    },
+    KnownServer {
+        language: "python",
+        command: "pylsp",
+        args: &[],
+        root_markers: PYTHON_ROOT_MARKERS,
+        install_linux: "pip install python-lsp-server",
+        install_macos: "pip install python-lsp-server",
+        install_windows: "pip install python-lsp-server",
+    },
+    KnownServer {
+        language: "go",
+        command: "gopls",
+        args: &[],
+        root_markers: &["go.mod", "go.work", ".git"],
+        install_linux: "go install golang.org/x/tools/gopls@latest",
+        install_macos: "go install golang.org/x/tools/gopls@latest",
+        install_windows: "go install golang.org/x/tools/gopls@latest",
+    },
+    KnownServer {
+        language: "c",
+        command: "clangd",
+        args: &[],
+        root_markers: C_ROOT_MARKERS,
+        install_linux: "sudo apt install clangd",
+        install_macos: "brew install llvm",
+        install_windows: "winget install LLVM.LLVM",
+    },
+    KnownServer {
+        language: "cpp",
+        command: "clangd",
+        args: &[],
+        root_markers: C_ROOT_MARKERS,
+        install_linux: "sudo apt install clangd",
+        install_macos: "brew install llvm",
+        install_windows: "winget install LLVM.LLVM",
+    },
+];
+
+/// Known servers for `language`, most preferred first.
+pub fn known_servers(language: &str) -> impl Iterator<Item = &'static KnownServer> + '_ {
+    KNOWN_SERVERS.iter().filter(move |s| s.language == language)
+}
+
+/// Look up a known server by language and command.
+pub fn find_known_server(language: &str, command: &str) -> Option<&'static KnownServer> {
+    known_servers(language).find(|s| s.command == command)
+}
+
+/// Result of probing the known servers for a language that has no usable
+/// configured server.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ServerDetection {
+    /// Known servers found on PATH that aren't configured yet.
+    pub found: Vec<&'static KnownServer>,
+    /// Known servers that aren't installed.
+    pub missing: Vec<&'static KnownServer>,
+}
+
+impl ServerDetection {
+    pub fn is_empty(&self) -> bool {
+        self.found.is_empty() && self.missing.is_empty()
+    }
+}
+
+/// Probe the known servers for `language`.
+///
+/// Returns an empty detection when one of the `configured` servers is
+/// `usable` (enabled and resolvable) — there is nothing to suggest then.
+/// Servers already present in `configured` are never offered again.
+pub fn detect_servers(
+    language: &str,
+    configured: &[LspServerConfig],
+    exists: impl Fn(&str) -> bool,
+) -> ServerDetection {
+    if configured
+        .iter()
+        .any(|c| c.enabled && !c.command.is_empty() && exists(&c.command))
+    {
+        return ServerDetection::default();
+    }
+    let mut detection = ServerDetection::default();
+    for server in known_servers(language) {
+        if exists(server.command) {
+            if !configured.iter().any(|c| c.command == server.command) {
+                detection.found.push(server);
+            }
+        } else {
+            detection.missing.push(server);
+        }
+    }
+    detection
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(command: &str, enabled: bool) -> LspServerConfig {
+        LspServerConfig {
+            command: command.to_string(),
+            enabled,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn detects_unconfigured_server_on_path() {
+        let configured = [config("pylsp", true)];
+        let detection = detect_servers("python", &configured, |cmd| cmd == "pyright-langserver");
+        assert_eq!(detection.found.len(), 1);
+        assert_eq!(detection.found[0].command, "pyright-langserver");
+        assert_eq!(detection.missing.len(), 1);
+        assert_eq!(detection.missing[0].command, "pylsp");
+    }
+
+    #[test]
+    fn nothing_to_suggest_when_configured_server_is_usable() {
+        let configured = [config("gopls", true)];
+        assert!(detect_servers("go", &configured, |_| true).is_empty());
+        // A disabled server doesn't count as usable.
+        let configured = [config("gopls", false)];
+        let detection = detect_servers("go", &configured, |_| true);
+        assert!(detection.found.is_empty());
+        assert!(detection.missing.is_empty());
+        assert!(detect_servers("cobol", &[], |_| false).is_empty());
+    }
+
+    #[test]
+    fn privileged_install_commands_are_not_runnable() {
+        let clangd = find_known_server("c", "clangd").unwrap();
+        assert_eq!(
+            clangd.install_is_runnable(),
+            !clangd.install_command().starts_with("sudo ")
+        );
+        assert!(find_known_server("go", "gopls")
+            .unwrap()
+            .install_is_runnable());
+    }
+}
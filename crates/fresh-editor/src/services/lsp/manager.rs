@@ -467,6 +467,11 @@ pub struct LspManager {
     /// still work — an explicit user action overrides the global opt-out,
     /// matching how manual start already overrides per-server `enabled=false`.
     globally_enabled: bool,
+
+    /// Mirrors the top-level `lsp_shared_servers` config field: start
+    /// servers through the headless LSP proxy so other Fresh instances on
+    /// the same workspace reuse them.
+    shared_servers: bool,
 }
 
 impl LspManager {
@@ -495,6 +500,7 @@ impl LspManager {
             allowed_languages: HashSet::new(),
             disabled_languages: HashSet::new(),
             globally_enabled: true,
+            shared_servers: false,
         }
     }
 
@@ -504,6 +510,12 @@ impl LspManager {
         self.globally_enabled = enabled;
     }
 
+    /// Mirror the top-level `lsp_shared_servers` config field. Applies to
+    /// servers started after the call.
+    pub fn set_shared_servers(&mut self, shared: bool) {
+        self.shared_servers = shared;
+    }
+
     /// Wire the long-running spawner from the active `Authority`.
     ///
     /// Called from `Editor::set_boot_authority` so every LSP server
@@ -1303,6 +1315,7 @@ impl LspManager {
                 config.process_limits.clone(),
                 config.language_id_overrides.clone(),
                 long_running_spawner.clone(),
+                self.shared_servers,
             ) {
                 Ok(handle) => {
                     let effective_root = self.resolve_root_uri(language, file_path);
@@ -1419,6 +1432,7 @@ impl LspManager {
                 config.process_limits.clone(),
                 config.language_id_overrides.clone(),
                 long_running_spawner.clone(),
+                self.shared_servers,
             ) {
                 Ok(handle) => {
                     let effective_root = file_path
@@ -1746,6 +1760,7 @@ impl LspManager {
             config.process_limits.clone(),
             config.language_id_overrides.clone(),
             long_running_spawner,
+            self.shared_servers,
         ) {
             Ok(handle) => {
                 let effective_root = if is_universal {
@@ -1820,6 +1835,16 @@ impl LspManager {
         labels
     }
 
+    /// Requests awaiting a response, per running server name, for servers
+    /// with any outstanding.
+    pub fn in_flight_requests(&self) -> Vec<(String, usize)> {
+        self.handles
+            .iter()
+            .map(|sh| (sh.name.clone(), sh.handle.in_flight_requests()))
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+
     /// Get the names of all running servers for a given language
     pub fn server_names_for_language(&self, language: &str) -> Vec<String> {
         self.handles
@@ -2326,6 +2351,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "rust".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -2342,6 +2369,8 @@ mod tests {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -2352,6 +2381,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "javascript".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -2368,6 +2399,8 @@ mod tests {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -2378,6 +2411,8 @@ mod tests {
                 filenames: vec![],
                 grammar: "c_sharp".to_string(),
                 comment_prefix: Some("//".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -2394,6 +2429,8 @@ mod tests {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -2445,6 +2482,8 @@ mod tests {
                 filenames: vec!["/etc/**/rc.*".to_string(), "*rc".to_string()],
                 grammar: "bash".to_string(),
                 comment_prefix: Some("#".to_string()),
+                block_comment_start: None,
+                block_comment_end: None,
                 auto_indent: true,
                 auto_close: None,
                 auto_surround: None,
@@ -2461,6 +2500,8 @@ mod tests {
                 on_save: vec![],
                 word_characters: None,
                 indentation_guide: None,
+                trim_trailing_whitespace_on_save: None,
+                abbreviations: HashMap::new(),
                 indent: None,
             },
         );
@@ -2562,6 +2603,8 @@ mod tests {
             filenames: vec![],
             grammar: String::new(),
             comment_prefix: Some("//".to_string()),
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: true,
             auto_close: None,
             auto_surround: None,
@@ -2578,6 +2621,8 @@ mod tests {
             on_save: vec![],
             word_characters: None,
             indentation_guide: None,
+            trim_trailing_whitespace_on_save: None,
+            abbreviations: HashMap::new(),
             indent: None,
         };
         languages.insert(
@@ -5,18 +5,23 @@
 
 pub mod async_bridge;
 pub mod authority;
+pub mod buffer_share;
 pub mod clipboard;
 pub mod completion;
 pub mod counters;
 pub mod editorconfig;
 pub mod env_provider;
+pub mod file_preview;
 pub mod file_watcher;
 pub mod fs;
 #[cfg(target_os = "linux")]
 pub mod gpm;
 /// Outbound HTTP(S); the only place `ureq`/TLS is used (gated by `http`).
 pub mod http;
+pub mod json_schema;
+pub mod linters;
 pub mod live_grep_state;
+pub mod local_history;
 pub mod log_dirs;
 pub mod lsp;
 pub mod packages;
@@ -26,12 +31,19 @@ pub mod process_limits;
 pub mod recovery;
 pub mod release_checker;
 pub mod remote;
+/// User scripts in `~/.config/fresh/scripts/` (gated by `scripting`).
+#[cfg(feature = "scripting")]
+pub mod scripting;
 pub mod signal_handler;
 pub mod status_log;
 pub mod styled_html;
+pub mod tags;
 pub mod telemetry;
 pub mod terminal;
+pub mod terminal_backend;
+pub mod terminal_graphics;
 pub mod terminal_modes;
+pub mod terminal_probe;
 /// In-place self-update engine for self-contained installs (`fresh update`).
 #[cfg(feature = "self-update")]
 pub mod updater;
@@ -290,6 +290,8 @@ impl LanguageManifestConfig {
     pub fn to_language_config(&self) -> LanguageConfig {
         LanguageConfig {
             comment_prefix: self.comment_prefix.clone(),
+            block_comment_start: self.block_comment_start.clone(),
+            block_comment_end: self.block_comment_end.clone(),
             auto_indent: self.auto_indent.unwrap_or(true),
             show_whitespace_tabs: self.show_whitespace_tabs.unwrap_or(true),
             use_tabs: self.use_tabs,
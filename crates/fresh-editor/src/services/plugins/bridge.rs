@@ -293,6 +293,7 @@ impl PluginServiceBridge for EditorServiceBridge {
             custom_contexts: command.custom_contexts,
             source: CommandSource::Plugin(command.plugin_name),
             terminal_bypass: command.terminal_bypass,
+            context_menu: command.context_menu,
         };
         self.command_registry
             .read()
@@ -53,12 +53,9 @@ impl PluginDevWorkspace {
         content: &str,
         fresh_dts_source: &Path,
     ) -> Result<Self, String> {
-        let cache_dir =
-            dirs::cache_dir().ok_or_else(|| "Could not determine cache directory".to_string())?;
-        let dir = cache_dir
-            .join("fresh")
-            .join("plugin-dev")
-            .join(buffer_id.to_string());
+        let cache_dir = crate::config_io::cache_dir()
+            .ok_or_else(|| "Could not determine cache directory".to_string())?;
+        let dir = cache_dir.join("plugin-dev").join(buffer_id.to_string());
 
         // Create directory
         std::fs::create_dir_all(&dir)
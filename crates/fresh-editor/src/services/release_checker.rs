@@ -159,10 +159,12 @@ impl UpdateChecker {
 /// Start an update checker that runs once at startup.
 ///
 /// The check respects daily debouncing via the stamp file - if already
-/// checked today, no network request is made.
+/// checked today, no network request is made. The daily telemetry ping goes
+/// out alongside it when `send_telemetry` is set.
 /// Results are available via `poll_result()` on the returned handle.
 pub fn start_periodic_update_check(
     releases_url: &str,
+    send_telemetry: bool,
     time_source: SharedTimeSource,
     data_dir: PathBuf,
 ) -> UpdateChecker {
@@ -174,7 +176,9 @@ pub fn start_periodic_update_check(
         if let Some(unique_id) =
             super::telemetry::should_run_daily_check(time_source.as_ref(), &data_dir)
         {
-            super::telemetry::track_open(&unique_id);
+            if send_telemetry {
+                super::telemetry::track_open(&unique_id);
+            }
             let result = check_for_update(&url);
             // Receiver may be dropped if checker is dropped before result arrives.
             #[allow(clippy::let_underscore_must_use)]
@@ -189,7 +193,8 @@ pub fn start_periodic_update_check(
     }
 }
 
-/// Start an update checker (for testing with custom parameters).
+/// Start an update checker (for testing with custom parameters). Never sends
+/// telemetry.
 #[doc(hidden)]
 pub fn start_periodic_update_check_with_interval(
     releases_url: &str,
@@ -198,7 +203,7 @@ pub fn start_periodic_update_check_with_interval(
     data_dir: PathBuf,
 ) -> UpdateChecker {
     // check_interval is ignored - debouncing is handled by stamp file
-    start_periodic_update_check(releases_url, time_source, data_dir)
+    start_periodic_update_check(releases_url, false, time_source, data_dir)
 }
 
 /// Start a background update check
@@ -208,6 +213,7 @@ pub fn start_periodic_update_check_with_interval(
 /// Respects daily debouncing - if already checked today, no result will be sent.
 pub fn start_update_check(
     releases_url: &str,
+    send_telemetry: bool,
     time_source: SharedTimeSource,
     data_dir: PathBuf,
 ) -> UpdateCheckHandle {
@@ -219,7 +225,9 @@ pub fn start_update_check(
         if let Some(unique_id) =
             super::telemetry::should_run_daily_check(time_source.as_ref(), &data_dir)
         {
-            super::telemetry::track_open(&unique_id);
+            if send_telemetry {
+                super::telemetry::track_open(&unique_id);
+            }
             let result = check_for_update(&url);
             // Receiver may be dropped if handle is dropped before result arrives.
             #[allow(clippy::let_underscore_must_use)]
@@ -413,7 +421,7 @@ mod tests {
         let temp_dir = tempfile::tempdir().unwrap();
 
         let mut checker =
-            start_periodic_update_check(&url, time_source, temp_dir.path().to_path_buf());
+            start_periodic_update_check(&url, false, time_source, temp_dir.path().to_path_buf());
 
         // Wait for result
         let start = std::time::Instant::now();
@@ -437,7 +445,7 @@ mod tests {
         let temp_dir = tempfile::tempdir().unwrap();
 
         let mut checker =
-            start_periodic_update_check(&url, time_source, temp_dir.path().to_path_buf());
+            start_periodic_update_check(&url, false, time_source, temp_dir.path().to_path_buf());
 
         // Wait for result
         let start = std::time::Instant::now();
@@ -461,7 +469,8 @@ mod tests {
         let time_source = super::super::time_source::TestTimeSource::shared();
         let temp_dir = tempfile::tempdir().unwrap();
 
-        let checker = start_periodic_update_check(&url, time_source, temp_dir.path().to_path_buf());
+        let checker =
+            start_periodic_update_check(&url, false, time_source, temp_dir.path().to_path_buf());
 
         // Immediately check (before result arrives)
         assert!(!checker.is_update_available());
@@ -54,6 +54,23 @@ def validate_path(p):
     return os.path.realpath(expanded)
 
 
+def validate_elevate(elevate):
+    """Validate the elevation command sent with a sudo write.
+
+    The program must be a bare name looked up on PATH or an absolute path;
+    a relative path would resolve against whatever directory the agent was
+    started in.
+    """
+    if not isinstance(elevate, list) or not elevate:
+        raise ValueError("elevate must be a non-empty list")
+    if not all(isinstance(arg, str) and arg for arg in elevate):
+        raise ValueError("elevate arguments must be non-empty strings")
+    program = elevate[0]
+    if os.sep in program and not os.path.isabs(program):
+        raise ValueError(f"elevate program must be on PATH or absolute: {program}")
+    return elevate
+
+
 # === File Operations ===
 
 
@@ -114,35 +131,38 @@ def cmd_write(id, p):
 def cmd_sudo_write(id, p):
     """Write file contents using sudo (for root-owned files).
 
-    Uses sudo tee to write the file. Preserves original permissions and ownership.
+    Uses `<elevate> tee` to write the file, where `elevate` is the configured
+    elevation command (default sudo). Preserves original permissions and
+    ownership.
     """
     path = validate_path(p["path"])
     data = unb64(p["data"])
+    elevate = validate_elevate(p.get("elevate") or ["sudo"])
 
     # Get original metadata to preserve permissions
     mode = p.get("mode")
     uid = p.get("uid")
     gid = p.get("gid")
 
-    # Use sudo tee to write the file
-    proc = subprocess.Popen(
-        ["sudo", "tee", path],
-        stdin=subprocess.PIPE,
-        stdout=subprocess.DEVNULL,
-        stderr=subprocess.PIPE,
-    )
-    _, stderr = proc.communicate(data)
+    def run_step(step, stdin=None):
+        proc = subprocess.Popen(
+            elevate + step,
+            stdin=subprocess.PIPE,
+            stdout=subprocess.DEVNULL,
+            stderr=subprocess.PIPE,
+        )
+        _, stderr = proc.communicate(stdin)
+        if proc.returncode != 0:
+            raise RuntimeError(
+                f"{elevate[0]} {step[0]} failed: {stderr.decode().strip()}")
 
-    if proc.returncode != 0:
-        raise RuntimeError(f"sudo tee failed: {stderr.decode().strip()}")
+    run_step(["tee", path], data)
 
     # Restore permissions and ownership if provided
     if mode is not None:
-        subprocess.run(["sudo", "chmod", f"{mode:o}", path], check=True,
-                       capture_output=True)
+        run_step(["chmod", f"{mode:o}", path])
     if uid is not None and gid is not None:
-        subprocess.run(["sudo", "chown", f"{uid}:{gid}", path], check=True,
-                       capture_output=True)
+        run_step(["chown", f"{uid}:{gid}", path])
 
     send(id, r={"size": len(data)})
 
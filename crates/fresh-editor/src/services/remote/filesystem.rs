@@ -226,6 +226,19 @@ impl RemoteFileSystem {
     }
 }
 
+/// Concatenate the base64 `data` payloads of a streamed `read` response.
+fn decode_data_chunks(data_chunks: Vec<serde_json::Value>) -> io::Result<Vec<u8>> {
+    let mut content = Vec::new();
+    for chunk in data_chunks {
+        if let Some(b64) = chunk.get("data").and_then(|v| v.as_str()) {
+            let decoded =
+                decode_base64(b64).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            content.extend(decoded);
+        }
+    }
+    Ok(content)
+}
+
 impl FileSystem for RemoteFileSystem {
     fn read_file(&self, path: &Path) -> io::Result<Vec<u8>> {
         let path_str = path.to_string_lossy();
@@ -234,15 +247,7 @@ impl FileSystem for RemoteFileSystem {
             .request_with_data_blocking("read", read_params(&path_str, None, None))
             .map_err(Self::to_io_error)?;
 
-        // Collect all streaming data chunks
-        let mut content = Vec::new();
-        for chunk in data_chunks {
-            if let Some(b64) = chunk.get("data").and_then(|v| v.as_str()) {
-                let decoded = decode_base64(b64)
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-                content.extend(decoded);
-            }
-        }
+        let content = decode_data_chunks(data_chunks)?;
 
         Ok(content)
     }
@@ -254,15 +259,7 @@ impl FileSystem for RemoteFileSystem {
             .request_with_data_blocking("read", read_params(&path_str, Some(offset), Some(len)))
             .map_err(Self::to_io_error)?;
 
-        // Collect all streaming data chunks
-        let mut content = Vec::new();
-        for chunk in data_chunks {
-            if let Some(b64) = chunk.get("data").and_then(|v| v.as_str()) {
-                let decoded = decode_base64(b64)
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-                content.extend(decoded);
-            }
-        }
+        let content = decode_data_chunks(data_chunks)?;
 
         // Get the size reported by the agent (how many bytes it actually read from the file)
         let agent_reported_size = result
@@ -290,6 +287,20 @@ impl FileSystem for RemoteFileSystem {
         Ok(content)
     }
 
+    fn read_prefix(&self, path: &Path, max_len: usize) -> io::Result<Vec<u8>> {
+        // One bounded `read`: the agent stops at EOF, so a short file comes
+        // back whole instead of failing like `read_range` would. No `stat`
+        // round trip first — on a slow link that would double the latency.
+        let path_str = path.to_string_lossy();
+        let (data_chunks, _result) = self
+            .channel
+            .request_with_data_blocking("read", read_params(&path_str, Some(0), Some(max_len)))
+            .map_err(Self::to_io_error)?;
+        let mut content = decode_data_chunks(data_chunks)?;
+        content.truncate(max_len);
+        Ok(content)
+    }
+
     fn count_line_feeds_in_range(&self, path: &Path, offset: u64, len: usize) -> io::Result<usize> {
         let path_str = path.to_string_lossy();
         let result = self
@@ -668,12 +679,13 @@ impl FileSystem for RemoteFileSystem {
         mode: u32,
         uid: u32,
         gid: u32,
+        elevate: &[String],
     ) -> io::Result<()> {
         let path_str = path.to_string_lossy();
         self.channel
             .request_blocking(
                 "sudo_write",
-                sudo_write_params(&path_str, data, mode, uid, gid),
+                sudo_write_params(&path_str, data, mode, uid, gid, elevate),
             )
             .map_err(Self::to_io_error)?;
         Ok(())
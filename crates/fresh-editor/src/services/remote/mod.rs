@@ -34,6 +34,9 @@ pub use protocol::{
     decode_base64, encode_base64, ls_params, read_params, stat_params, sudo_write_params,
     write_params, AgentRequest, AgentResponse,
 };
+/// Windows `PATHEXT` resolution for spawned programs, shared with the LSP
+/// proxy. Crate-internal.
+pub(crate) use spawner::resolve_program;
 pub use spawner::{
     build_kube_agent_terminal_args, build_kube_terminal_args, build_ssh_agent_terminal_args,
     build_ssh_terminal_args, ssh_remote_env_launcher, LocalLongRunningSpawner, LocalProcessSpawner,
@@ -169,13 +169,15 @@ pub fn sudo_write_params(
     mode: u32,
     uid: u32,
     gid: u32,
+    elevate: &[String],
 ) -> serde_json::Value {
     serde_json::json!({
         "path": path,
         "data": encode_base64(data),
         "mode": mode,
         "uid": uid,
-        "gid": gid
+        "gid": gid,
+        "elevate": elevate
     })
 }
 
@@ -70,7 +70,7 @@ use tokio::process::{ChildStderr, ChildStdin, ChildStdout};
 /// On other platforms it is a pass-through: a plain `PATH` lookup already
 /// does the right thing and pre-resolving would differ only cosmetically.
 #[cfg(windows)]
-fn resolve_program(command: &str) -> Cow<'_, str> {
+pub(crate) fn resolve_program(command: &str) -> Cow<'_, str> {
     match which::which(command) {
         Ok(path) => Cow::Owned(path.to_string_lossy().into_owned()),
         Err(_) => Cow::Borrowed(command),
@@ -79,7 +79,7 @@ fn resolve_program(command: &str) -> Cow<'_, str> {
 
 /// Non-Windows pass-through — see the Windows variant for rationale.
 #[cfg(not(windows))]
-fn resolve_program(command: &str) -> Cow<'_, str> {
+pub(crate) fn resolve_program(command: &str) -> Cow<'_, str> {
     Cow::Borrowed(command)
 }
 
@@ -714,6 +714,21 @@ pub trait LongRunningSpawner: Send + Sync {
         limits: Option<&ProcessLimits>,
     ) -> Result<StdioChild, SpawnError>;
 
+    /// Like [`Self::spawn_stdio`], but for a language server that may be
+    /// shared with other Fresh instances through the headless LSP proxy
+    /// (`lsp_shared_servers`). Only the local spawner can reach the proxy;
+    /// everything else spawns a private server.
+    async fn spawn_stdio_shared(
+        &self,
+        command: &str,
+        args: &[String],
+        env: Vec<(String, String)>,
+        cwd: Option<&Path>,
+        limits: Option<&ProcessLimits>,
+    ) -> Result<StdioChild, SpawnError> {
+        self.spawn_stdio(command, args, env, cwd, limits).await
+    }
+
     /// Check whether `command` resolves to an executable under this
     /// authority. Routed through the same spawner so an SSH authority
     /// probes the remote `$PATH` and a container authority probes the
@@ -789,6 +804,32 @@ impl LongRunningSpawner for LocalLongRunningSpawner {
         Ok(StdioChild::from_local_tokio_child(child, post_spawn))
     }
 
+    async fn spawn_stdio_shared(
+        &self,
+        command: &str,
+        args: &[String],
+        env: Vec<(String, String)>,
+        cwd: Option<&Path>,
+        _limits: Option<&ProcessLimits>,
+    ) -> Result<StdioChild, SpawnError> {
+        // Trust is judged on the server, not on the relay standing in for
+        // it. Limits are not applied: the server is the proxy's child and
+        // outlives this editor.
+        gate(
+            &self.trust,
+            command,
+            cwd.map(|p| p.to_string_lossy()).as_deref(),
+        )?;
+        let exe = std::env::current_exe().map_err(|e| SpawnError::Process(e.to_string()))?;
+        let relay_args: Vec<String> = ["--lsp-proxy-client", "--", command]
+            .into_iter()
+            .map(str::to_string)
+            .chain(args.iter().cloned())
+            .collect();
+        self.spawn_stdio(&exe.to_string_lossy(), &relay_args, env, cwd, None)
+            .await
+    }
+
     async fn command_exists(&self, command: &str) -> bool {
         // Honor the active env's PATH (e.g. a venv's `bin/`) so the existence
         // probe searches the same place `spawn_stdio` will — otherwise a
@@ -0,0 +1,576 @@
+//! User scripts: small [Rhai](https://rhai.rs) files in
+//! `~/.config/fresh/scripts/`.
+//!
+//! Each `*.rhai` file is evaluated once when it loads. Its top level
+//! registers palette commands with `register_command`; functions named after
+//! an editor event (`on_save`, `on_open`, ...) run when that event fires.
+//!
+//! Scripts never touch the editor directly. A call runs against a
+//! [`ScriptBuffer`] snapshot of one buffer and edits the snapshot; once it
+//! returns, the editor applies the difference as a single edit (see
+//! `app::scripts`), so a command undoes in one step. Status messages and
+//! actions are collected as [`ScriptRequest`]s and handled after the edit.
+//!
+//! Rhai is sandboxed: scripts have no file, process or network access, and a
+//! call is stopped after [`MAX_OPERATIONS`] steps so a runaway loop can't
+//! hang the editor.
+
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use rhai::{CallFnOptions, Dynamic, Engine, EvalAltResult, Scope, AST, INT};
+
+/// Directory under the config dir that holds the scripts.
+pub const SCRIPTS_DIR: &str = "scripts";
+
+/// Prefix of the action names script commands are registered under.
+const ACTION_PREFIX: &str = "script:";
+
+/// Step limit for a single evaluation or call.
+pub const MAX_OPERATIONS: u64 = 10_000_000;
+
+/// Editor event a script reacts to by defining a function of the same name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptHook {
+    /// `on_open(path)`: a file was opened
+    Open,
+    /// `on_save(path)`: a file was saved
+    Save,
+    /// `on_buffer_switch(path)`: a different buffer became active
+    BufferSwitch,
+    /// `on_mode_change(mode)`: the editor mode changed (`""` when none)
+    ModeChange,
+}
+
+impl ScriptHook {
+    /// Name of the script function that handles this event.
+    pub fn function_name(self) -> &'static str {
+        match self {
+            Self::Open => "on_open",
+            Self::Save => "on_save",
+            Self::BufferSwitch => "on_buffer_switch",
+            Self::ModeChange => "on_mode_change",
+        }
+    }
+}
+
+/// The buffer a call runs against.
+#[derive(Debug, Clone, Default)]
+pub struct ScriptBuffer {
+    pub text: String,
+    /// File path, empty for buffers without a file
+    pub path: String,
+    /// Byte offset of the primary cursor
+    pub cursor: usize,
+    /// Current editor mode, empty when none
+    pub mode: String,
+}
+
+/// Something besides an edit that a script asked the editor to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScriptRequest {
+    SetStatus(String),
+    RunAction(String),
+}
+
+/// What a call left for the editor to apply.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptEffects {
+    /// The buffer text after the call, if it edited the buffer
+    pub text: Option<String>,
+    /// Where the call put the cursor, if it moved it
+    pub cursor: Option<usize>,
+    pub requests: Vec<ScriptRequest>,
+}
+
+/// The single edit that turns `old` into `new`: the byte range of `old` to
+/// replace, and its replacement. `None` when they're equal.
+pub fn replacement<'a>(old: &str, new: &'a str) -> Option<(std::ops::Range<usize>, &'a str)> {
+    if old == new {
+        return None;
+    }
+    let mut prefix = old
+        .bytes()
+        .zip(new.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !old.is_char_boundary(prefix) || !new.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+    let max_suffix = old.len().min(new.len()) - prefix;
+    let mut suffix = old
+        .bytes()
+        .rev()
+        .zip(new.bytes().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !old.is_char_boundary(old.len() - suffix) || !new.is_char_boundary(new.len() - suffix) {
+        suffix -= 1;
+    }
+    Some((prefix..old.len() - suffix, &new[prefix..new.len() - suffix]))
+}
+
+/// A palette command registered by a script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptCommand {
+    pub name: String,
+    pub description: String,
+    /// File name of the script that registered it (`title_case.rhai`)
+    pub script: String,
+    /// Script function the command calls
+    pub function: String,
+}
+
+impl ScriptCommand {
+    /// Action name the command is registered under, so it can also be
+    /// bound to a key: `script:<file stem>.<function>`.
+    pub fn action_name(&self) -> String {
+        let stem = self.script.strip_suffix(".rhai").unwrap_or(&self.script);
+        format!("{ACTION_PREFIX}{stem}.{}", self.function)
+    }
+}
+
+/// State shared between the host and the functions registered on the engine.
+#[derive(Default)]
+struct Shared {
+    buffer: ScriptBuffer,
+    edited: bool,
+    cursor_moved: bool,
+    requests: Vec<ScriptRequest>,
+    /// `(name, description, function)` registered by the script being loaded
+    registered: Vec<(String, String, String)>,
+}
+
+struct Script {
+    /// File name, e.g. `title_case.rhai`
+    name: String,
+    ast: AST,
+}
+
+/// Loads scripts and runs their commands and event handlers.
+pub struct ScriptHost {
+    engine: Engine,
+    shared: Arc<Mutex<Shared>>,
+    scripts: Vec<Script>,
+    commands: Vec<ScriptCommand>,
+}
+
+impl Default for ScriptHost {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+type ScriptResult<T> = Result<T, Box<EvalAltResult>>;
+
+/// Convert a script-supplied offset, rejecting negatives.
+fn offset(value: INT) -> ScriptResult<usize> {
+    usize::try_from(value).map_err(|_| format!("negative position {value}").into())
+}
+
+/// Check that `position` is a valid byte offset into `text`.
+fn check_position(text: &str, position: usize) -> ScriptResult<()> {
+    if position > text.len() {
+        return Err(format!("position {position} is past the end of the buffer").into());
+    }
+    if !text.is_char_boundary(position) {
+        return Err(format!("position {position} is inside a character").into());
+    }
+    Ok(())
+}
+
+impl ScriptHost {
+    pub fn new() -> Self {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        // Rhai halves its nesting limits in debug builds; pin the release
+        // ones so a script that loads in one build loads in the other.
+        engine.set_max_expr_depths(64, 32);
+        engine.on_print(|text| tracing::info!("script: {text}"));
+        engine.on_debug(|text, source, pos| {
+            tracing::debug!("script {}:{pos}: {text}", source.unwrap_or("?"));
+        });
+        register_api(&mut engine, &shared);
+        Self {
+            engine,
+            shared,
+            scripts: Vec::new(),
+            commands: Vec::new(),
+        }
+    }
+
+    /// Load every `*.rhai` file in `dir`, in name order. A file that fails
+    /// to compile or run is skipped; its error is returned as
+    /// `(file name, message)`.
+    pub fn load_dir(&mut self, dir: &Path) -> Vec<(String, String)> {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        let mut paths: Vec<_> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "rhai"))
+            .collect();
+        paths.sort();
+
+        let mut errors = Vec::new();
+        for path in paths {
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let result = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|source| self.load(&name, &source));
+            if let Err(e) = result {
+                errors.push((name, e));
+            }
+        }
+        errors
+    }
+
+    /// Compile `source` and run its top level, keeping the commands it
+    /// registers.
+    pub fn load(&mut self, name: &str, source: &str) -> Result<(), String> {
+        let ast = self.engine.compile(source).map_err(|e| e.to_string())?;
+        self.shared.lock().unwrap().registered.clear();
+        let run = self.engine.run_ast_with_scope(&mut Scope::new(), &ast);
+        let registered = std::mem::take(&mut self.shared.lock().unwrap().registered);
+        run.map_err(|e| e.to_string())?;
+
+        let mut commands = Vec::new();
+        for (command_name, description, function) in registered {
+            if !ast.iter_functions().any(|f| f.name == function) {
+                return Err(format!(
+                    "command '{command_name}' calls '{function}', which isn't defined"
+                ));
+            }
+            commands.push(ScriptCommand {
+                name: command_name,
+                description,
+                script: name.to_string(),
+                function,
+            });
+        }
+        self.commands.extend(commands);
+        self.scripts.push(Script {
+            name: name.to_string(),
+            ast,
+        });
+        Ok(())
+    }
+
+    /// File names of the loaded scripts.
+    pub fn script_names(&self) -> impl Iterator<Item = &str> {
+        self.scripts.iter().map(|s| s.name.as_str())
+    }
+
+    pub fn commands(&self) -> &[ScriptCommand] {
+        &self.commands
+    }
+
+    /// The command registered under `action`, if it's a script action.
+    pub fn command_for_action(&self, action: &str) -> Option<&ScriptCommand> {
+        if !action.starts_with(ACTION_PREFIX) {
+            return None;
+        }
+        self.commands.iter().find(|c| c.action_name() == action)
+    }
+
+    /// Whether any script handles `hook`.
+    pub fn has_hook(&self, hook: ScriptHook) -> bool {
+        let function = hook.function_name();
+        self.scripts
+            .iter()
+            .any(|s| s.ast.iter_functions().any(|f| f.name == function))
+    }
+
+    /// Run `command` against `buffer` and return what it asked for.
+    pub fn run_command(
+        &self,
+        command: &ScriptCommand,
+        buffer: ScriptBuffer,
+    ) -> Result<ScriptEffects, String> {
+        let script = self
+            .scripts
+            .iter()
+            .find(|s| s.name == command.script)
+            .ok_or_else(|| format!("script '{}' is not loaded", command.script))?;
+        self.call(script, &command.function, (), buffer)
+    }
+
+    /// Call every script's handler for `hook` with `arg`, one after another.
+    /// The buffer each handler sees includes the edits of the ones before
+    /// it. Returns their combined effects, and the errors as
+    /// `(file name, message)`.
+    pub fn run_hook(
+        &self,
+        hook: ScriptHook,
+        arg: &str,
+        mut buffer: ScriptBuffer,
+    ) -> (ScriptEffects, Vec<(String, String)>) {
+        let function = hook.function_name();
+        let mut effects = ScriptEffects::default();
+        let mut errors = Vec::new();
+        for script in &self.scripts {
+            if !script
+                .ast
+                .iter_functions()
+                .any(|f| f.name == function && f.params.len() == 1)
+            {
+                continue;
+            }
+            match self.call(script, function, (arg.to_string(),), buffer.clone()) {
+                Ok(script_effects) => {
+                    if let Some(text) = &script_effects.text {
+                        buffer.text = text.clone();
+                        effects.text = script_effects.text;
+                    }
+                    if let Some(cursor) = script_effects.cursor {
+                        buffer.cursor = cursor;
+                        effects.cursor = Some(cursor);
+                    }
+                    effects.requests.extend(script_effects.requests);
+                }
+                Err(e) => errors.push((script.name.clone(), e)),
+            }
+        }
+        (effects, errors)
+    }
+
+    fn call(
+        &self,
+        script: &Script,
+        function: &str,
+        args: impl rhai::FuncArgs,
+        buffer: ScriptBuffer,
+    ) -> Result<ScriptEffects, String> {
+        *self.shared.lock().unwrap() = Shared {
+            buffer,
+            ..Default::default()
+        };
+        let result = self.engine.call_fn_with_options::<Dynamic>(
+            CallFnOptions::new().eval_ast(false),
+            &mut Scope::new(),
+            &script.ast,
+            function,
+            args,
+        );
+        // A failed call changes nothing.
+        if let Err(e) = result {
+            return Err(e.to_string());
+        }
+        let shared = std::mem::take(&mut *self.shared.lock().unwrap());
+        Ok(ScriptEffects {
+            text: shared.edited.then_some(shared.buffer.text),
+            cursor: shared.cursor_moved.then_some(shared.buffer.cursor),
+            requests: shared.requests,
+        })
+    }
+}
+
+/// Register the functions scripts call, all operating on `shared`.
+fn register_api(engine: &mut Engine, shared: &Arc<Mutex<Shared>>) {
+    let s = shared.clone();
+    engine.register_fn("register_command", move |name: &str, function: &str| {
+        s.lock()
+            .unwrap()
+            .registered
+            .push((name.to_string(), String::new(), function.to_string()));
+    });
+    let s = shared.clone();
+    engine.register_fn(
+        "register_command",
+        move |name: &str, description: &str, function: &str| {
+            s.lock().unwrap().registered.push((
+                name.to_string(),
+                description.to_string(),
+                function.to_string(),
+            ));
+        },
+    );
+
+    let s = shared.clone();
+    engine.register_fn("buffer_text", move || s.lock().unwrap().buffer.text.clone());
+    let s = shared.clone();
+    engine.register_fn("buffer_path", move || s.lock().unwrap().buffer.path.clone());
+    let s = shared.clone();
+    engine.register_fn("buffer_len", move || {
+        s.lock().unwrap().buffer.text.len() as INT
+    });
+    let s = shared.clone();
+    engine.register_fn("cursor", move || s.lock().unwrap().buffer.cursor as INT);
+    let s = shared.clone();
+    engine.register_fn("editor_mode", move || s.lock().unwrap().buffer.mode.clone());
+
+    let s = shared.clone();
+    engine.register_fn(
+        "insert",
+        move |position: INT, text: &str| -> ScriptResult<()> {
+            let position = offset(position)?;
+            let mut shared = s.lock().unwrap();
+            check_position(&shared.buffer.text, position)?;
+            shared.buffer.text.insert_str(position, text);
+            if shared.buffer.cursor >= position {
+                shared.buffer.cursor += text.len();
+            }
+            shared.edited = true;
+            Ok(())
+        },
+    );
+    let s = shared.clone();
+    engine.register_fn("delete", move |start: INT, end: INT| -> ScriptResult<()> {
+        let (start, end) = (offset(start)?, offset(end)?);
+        if start > end {
+            return Err(format!("delete range {start}..{end} is reversed").into());
+        }
+        let mut shared = s.lock().unwrap();
+        check_position(&shared.buffer.text, start)?;
+        check_position(&shared.buffer.text, end)?;
+        shared.buffer.text.replace_range(start..end, "");
+        let cursor = shared.buffer.cursor;
+        if cursor > start {
+            shared.buffer.cursor = start + cursor.saturating_sub(end);
+        }
+        shared.edited = true;
+        Ok(())
+    });
+    let s = shared.clone();
+    engine.register_fn("set_cursor", move |position: INT| -> ScriptResult<()> {
+        let position = offset(position)?;
+        let mut shared = s.lock().unwrap();
+        check_position(&shared.buffer.text, position)?;
+        shared.buffer.cursor = position;
+        shared.cursor_moved = true;
+        Ok(())
+    });
+    let s = shared.clone();
+    engine.register_fn("set_status", move |message: &str| {
+        s.lock()
+            .unwrap()
+            .requests
+            .push(ScriptRequest::SetStatus(message.to_string()));
+    });
+    let s = shared.clone();
+    engine.register_fn("run_action", move |action: &str| {
+        s.lock()
+            .unwrap()
+            .requests
+            .push(ScriptRequest::RunAction(action.to_string()));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn buffer(text: &str, cursor: usize) -> ScriptBuffer {
+        ScriptBuffer {
+            text: text.to_string(),
+            cursor,
+            ..Default::default()
+        }
+    }
+
+    fn host(source: &str) -> ScriptHost {
+        let mut host = ScriptHost::new();
+        host.load("test.rhai", source).unwrap();
+        host
+    }
+
+    #[test]
+    fn command_edits_are_collected_and_visible_to_the_script() {
+        let host = host(
+            r#"
+            register_command("Shout", "Upper-case the buffer", "shout");
+            fn shout() {
+                let text = buffer_text();
+                delete(0, buffer_len());
+                insert(0, text.to_upper());
+                set_status(`now ${buffer_len()} bytes`);
+            }
+            "#,
+        );
+        let command = &host.commands()[0];
+        assert_eq!(command.name, "Shout");
+        assert_eq!(command.action_name(), "script:test.shout");
+        assert_eq!(host.command_for_action("script:test.shout"), Some(command));
+
+        let effects = host.run_command(command, buffer("héllo", 0)).unwrap();
+        assert_eq!(
+            effects,
+            ScriptEffects {
+                text: Some("HÉLLO".to_string()),
+                cursor: None,
+                requests: vec![ScriptRequest::SetStatus("now 6 bytes".to_string())],
+            }
+        );
+    }
+
+    #[test]
+    fn positions_inside_a_character_are_rejected() {
+        let host = host(
+            r#"
+            register_command("Bad", "bad");
+            fn bad() { insert(2, "x"); }
+            "#,
+        );
+        let err = host
+            .run_command(&host.commands()[0], buffer("héllo", 0))
+            .unwrap_err();
+        assert!(err.contains("inside a character"), "{err}");
+    }
+
+    #[test]
+    fn hooks_run_in_every_script_that_defines_them() {
+        let mut host = ScriptHost::new();
+        host.load("a.rhai", r#"fn on_save(path) { insert(0, "a"); }"#)
+            .unwrap();
+        host.load(
+            "b.rhai",
+            r#"fn on_save(path) { insert(buffer_len(), path); }"#,
+        )
+        .unwrap();
+        assert!(host.has_hook(ScriptHook::Save));
+        assert!(!host.has_hook(ScriptHook::Open));
+
+        let (effects, errors) = host.run_hook(ScriptHook::Save, "/x", buffer("-", 0));
+        assert!(errors.is_empty());
+        assert_eq!(effects.text.as_deref(), Some("a-/x"));
+    }
+
+    #[test]
+    fn replacement_spans_only_the_changed_characters() {
+        assert_eq!(replacement("same", "same"), None);
+        assert_eq!(replacement("hello world", "hello World"), Some((6..7, "W")));
+        assert_eq!(replacement("aaa", "aaaa"), Some((3..3, "a")));
+        assert_eq!(replacement("abc", "c"), Some((0..2, "")));
+        // "é" and "è" share their first byte; the edit covers the whole character.
+        assert_eq!(replacement("café", "cafè"), Some((3..5, "è")));
+    }
+
+    #[test]
+    fn command_calling_an_undefined_function_fails_to_load() {
+        let mut host = ScriptHost::new();
+        let err = host
+            .load("test.rhai", r#"register_command("Nope", "missing");"#)
+            .unwrap_err();
+        assert!(err.contains("missing"), "{err}");
+        assert!(host.commands().is_empty());
+    }
+
+    #[test]
+    fn runaway_loops_are_stopped() {
+        let host = host(
+            r#"
+            register_command("Spin", "spin");
+            fn spin() { loop {} }
+            "#,
+        );
+        assert!(host
+            .run_command(&host.commands()[0], buffer("", 0))
+            .is_err());
+    }
+}
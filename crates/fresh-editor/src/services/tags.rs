@@ -0,0 +1,310 @@
+//! ctags `tags` files.
+//!
+//! Projects without a language server often still have a `tags` file made
+//! by Universal or Exuberant Ctags. Each line names a symbol, the file that
+//! defines it and an address (a line number or a search pattern), followed
+//! by optional extension fields:
+//!
+//! ```text
+//! parse_config<TAB>src/config.rs<TAB>/^pub fn parse_config(/;"<TAB>f
+//! ```
+//!
+//! Lines starting with `!_TAG_` are headers and are skipped. The file is
+//! read through the window's [`FileSystem`], so remote projects work the
+//! same as local ones, and is read again only when its mtime changes.
+//!
+//! The index backs tag-word completion, go-to-definition in buffers no
+//! language server handles, and the Go to Tag prompt. The file itself is
+//! made by an external command (`tags_command`, `ctags -R .` by default),
+//! which the editor runs on request or after each save.
+
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::model::filesystem::FileSystem;
+use crate::services::completion::provider::smart_case_matches;
+
+/// Name of the tags file looked for at the project root.
+pub const TAGS_FILE_NAME: &str = "tags";
+
+/// Tags files larger than this are not loaded.
+const MAX_TAGS_FILE_BYTES: u64 = 64 * 1024 * 1024;
+
+/// One symbol from a tags file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    pub name: String,
+    /// Defining file, as written in the tags file (usually relative to it).
+    pub file: String,
+    /// Ex command locating the symbol: a line number or a `/pattern/`.
+    pub address: String,
+    /// Single-letter or long kind (`f`, `function`, …), if present.
+    pub kind: Option<String>,
+}
+
+/// Parse the lines of a tags file. Malformed lines are skipped.
+pub fn parse_tags(text: &str) -> Vec<Tag> {
+    text.lines()
+        .filter(|line| !line.starts_with("!_TAG_"))
+        .filter_map(parse_tag_line)
+        .collect()
+}
+
+fn parse_tag_line(line: &str) -> Option<Tag> {
+    let mut fields = line.splitn(3, '\t');
+    let name = fields.next().filter(|n| !n.is_empty())?;
+    let file = fields.next().filter(|f| !f.is_empty())?;
+    let rest = fields.next()?;
+    // The address ends at `;"`, after which come the extension fields.
+    let (address, extensions) = match rest.find(";\"") {
+        Some(end) => (&rest[..end], &rest[end + 2..]),
+        None => (rest, ""),
+    };
+    let kind = extensions
+        .split('\t')
+        .map(str::trim)
+        .find(|field| !field.is_empty() && (!field.contains(':') || field.starts_with("kind:")))
+        .map(|field| field.trim_start_matches("kind:").to_string());
+    Some(Tag {
+        name: name.to_string(),
+        file: file.to_string(),
+        address: address.to_string(),
+        kind,
+    })
+}
+
+/// The tags of one project, kept sorted by lowercase name.
+#[derive(Debug, Default)]
+pub struct TagIndex {
+    tags: Vec<Tag>,
+    /// `tags[i].name.to_lowercase()`, for prefix lookups.
+    keys: Vec<String>,
+}
+
+impl TagIndex {
+    pub fn new(mut tags: Vec<Tag>) -> Self {
+        tags.sort_by_cached_key(|tag| tag.name.to_lowercase());
+        let keys = tags.iter().map(|tag| tag.name.to_lowercase()).collect();
+        Self { tags, keys }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+    }
+
+    /// Every tag named exactly `name`.
+    pub fn definitions(&self, name: &str) -> Vec<&Tag> {
+        let key = name.to_lowercase();
+        let start = self.keys.partition_point(|k| k.as_str() < key.as_str());
+        self.tags[start..]
+            .iter()
+            .zip(&self.keys[start..])
+            .take_while(|(_, k)| **k == key)
+            .map(|(tag, _)| tag)
+            .filter(|tag| tag.name == name)
+            .collect()
+    }
+
+    /// Tags whose name contains `query` (ignoring case), exact matches
+    /// first, then prefix matches, then the rest; at most `limit` of them.
+    pub fn search(&self, query: &str, limit: usize) -> Vec<&Tag> {
+        let key = query.to_lowercase();
+        let mut ranked: Vec<(u8, &Tag)> = self
+            .tags
+            .iter()
+            .zip(&self.keys)
+            .filter_map(|(tag, k)| {
+                let rank = if *k == key {
+                    0
+                } else if k.starts_with(&key) {
+                    1
+                } else if k.contains(&key) {
+                    2
+                } else {
+                    return None;
+                };
+                Some((rank, tag))
+            })
+            .collect();
+        // Stable, so each group stays in name order.
+        ranked.sort_by_key(|(rank, _)| *rank);
+        ranked.into_iter().take(limit).map(|(_, tag)| tag).collect()
+    }
+
+    /// Distinct tag names starting with `prefix` (smart-case), excluding
+    /// the prefix itself, at most `limit` of them.
+    pub fn names_matching(
+        &self,
+        prefix: &str,
+        prefix_has_uppercase: bool,
+        limit: usize,
+    ) -> Vec<String> {
+        let key = prefix.to_lowercase();
+        if key.is_empty() {
+            return Vec::new();
+        }
+        let start = self.keys.partition_point(|k| k.as_str() < key.as_str());
+        let mut names: Vec<String> = Vec::new();
+        for (tag, tag_key) in self.tags[start..].iter().zip(&self.keys[start..]) {
+            if !tag_key.starts_with(&key) || names.len() >= limit {
+                break;
+            }
+            if *tag_key != key
+                && smart_case_matches(&tag.name, prefix, prefix_has_uppercase)
+                && names.last() != Some(&tag.name)
+            {
+                names.push(tag.name.clone());
+            }
+        }
+        names
+    }
+}
+
+/// The 0-based line a tag address points at in `text`: either a line
+/// number, or the first line matching a `/pattern/` or `?pattern?` search.
+/// Patterns are literal apart from the `^` and `$` anchors.
+pub fn resolve_address(address: &str, text: &str) -> Option<usize> {
+    let address = address.trim();
+    if let Ok(line) = address.parse::<usize>() {
+        return Some(line.saturating_sub(1));
+    }
+    let delimiter = address.chars().next().filter(|c| *c == '/' || *c == '?')?;
+    let body = address[1..]
+        .strip_suffix(delimiter)
+        .unwrap_or(&address[1..]);
+    let (body, anchored_start) = match body.strip_prefix('^') {
+        Some(rest) => (rest, true),
+        None => (body, false),
+    };
+    let (body, anchored_end) = match body.strip_suffix('$') {
+        Some(rest) if !rest.ends_with('\\') => (rest, true),
+        _ => (body, false),
+    };
+    let mut pattern = String::with_capacity(body.len());
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => pattern.extend(chars.next()),
+            c => pattern.push(c),
+        }
+    }
+    text.lines()
+        .position(|line| match (anchored_start, anchored_end) {
+            (true, true) => line == pattern,
+            (true, false) => line.starts_with(&pattern),
+            (false, true) => line.ends_with(&pattern),
+            (false, false) => line.contains(&pattern),
+        })
+}
+
+/// A project's tags file, loaded on demand and reloaded when it changes.
+#[derive(Debug, Default)]
+pub struct TagFile {
+    path: Option<PathBuf>,
+    modified: Option<SystemTime>,
+    index: TagIndex,
+}
+
+impl TagFile {
+    /// The index for the tags file at `path`, re-read if the file changed
+    /// since the last call. A missing or unreadable file gives an empty
+    /// index.
+    pub fn load(&mut self, fs: &dyn FileSystem, path: &Path) -> &TagIndex {
+        let metadata = fs.metadata_if_exists(path);
+        let modified = metadata.as_ref().and_then(|m| m.modified);
+        let unchanged = self.path.as_deref() == Some(path) && self.modified == modified;
+        if !unchanged {
+            self.path = Some(path.to_path_buf());
+            self.modified = modified;
+            self.index = match metadata {
+                Some(m) if m.size <= MAX_TAGS_FILE_BYTES => fs
+                    .read_file(path)
+                    .map(|bytes| TagIndex::new(parse_tags(&String::from_utf8_lossy(&bytes))))
+                    .unwrap_or_default(),
+                _ => TagIndex::default(),
+            };
+        }
+        &self.index
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "!_TAG_FILE_FORMAT\t2\t/extended format/\n\
+        parse_config\tsrc/config.rs\t/^pub fn parse_config(/;\"\tf\n\
+        ParseError\tsrc/error.rs\t12;\"\tkind:struct\tline:12\n\
+        parse_args\tsrc/main.rs\t/^fn parse_args() {$/;\"\tf\n\
+        parse_config\tsrc/legacy.rs\t40;\"\tf\n";
+
+    #[test]
+    fn parses_names_files_addresses_and_kinds() {
+        let tags = parse_tags(SAMPLE);
+        assert_eq!(tags.len(), 4);
+        assert_eq!(
+            tags[0],
+            Tag {
+                name: "parse_config".to_string(),
+                file: "src/config.rs".to_string(),
+                address: "/^pub fn parse_config(/".to_string(),
+                kind: Some("f".to_string()),
+            }
+        );
+        assert_eq!(tags[1].address, "12");
+        assert_eq!(tags[1].kind.as_deref(), Some("struct"));
+    }
+
+    #[test]
+    fn definitions_and_search() {
+        let index = TagIndex::new(parse_tags(SAMPLE));
+        let files: Vec<&str> = index
+            .definitions("parse_config")
+            .iter()
+            .map(|tag| tag.file.as_str())
+            .collect();
+        assert_eq!(files.len(), 2);
+        assert!(files.contains(&"src/config.rs") && files.contains(&"src/legacy.rs"));
+        assert!(index.definitions("parseerror").is_empty());
+
+        let names: Vec<&str> = index
+            .search("error", 10)
+            .iter()
+            .map(|tag| tag.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["ParseError"]);
+        let names: Vec<&str> = index
+            .search("parse_args", 10)
+            .iter()
+            .map(|tag| tag.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["parse_args"]);
+        assert_eq!(index.search("config", 10).len(), 2);
+    }
+
+    #[test]
+    fn resolves_line_numbers_and_search_patterns() {
+        let text = "use std::io;\n\nfn parse_args() {\n}\npub fn parse_config(path: &str) {\n}\n";
+        assert_eq!(resolve_address("3", text), Some(2));
+        assert_eq!(resolve_address("/^fn parse_args() {$/", text), Some(2));
+        assert_eq!(resolve_address("/^pub fn parse_config(/", text), Some(4));
+        assert_eq!(resolve_address("?parse_config(path: &str)?", text), Some(4));
+        assert_eq!(resolve_address("/^a\\/b$/", "x\na/b\n"), Some(1));
+        assert_eq!(resolve_address("/^missing$/", text), None);
+    }
+
+    #[test]
+    fn names_matching_is_smart_case_and_deduplicated() {
+        let index = TagIndex::new(parse_tags(SAMPLE));
+        assert_eq!(
+            index.names_matching("parse", false, 10),
+            vec!["parse_args", "parse_config", "ParseError"]
+        );
+        assert_eq!(index.names_matching("Parse", true, 10), vec!["ParseError"]);
+        assert_eq!(
+            index.names_matching("parse_args", false, 10),
+            Vec::<String>::new()
+        );
+        assert_eq!(index.names_matching("parse", false, 1), vec!["parse_args"]);
+    }
+}
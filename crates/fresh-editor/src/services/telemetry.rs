@@ -1,4 +1,4 @@
-use super::time_source::TimeSource;
+use super::time_source::{SharedTimeSource, TimeSource};
 use chrono::NaiveDate;
 use serde::Serialize;
 use std::collections::hash_map::RandomState;
@@ -127,16 +127,43 @@ pub fn should_run_daily_check(
     }
 }
 
-/// Track app open with unique ID
-pub fn track_open(unique_id: &str) {
-    let event = Event {
+/// The app-open event for `unique_id`
+fn open_event(unique_id: &str) -> Event {
+    Event {
         version: Some(env!("CARGO_PKG_VERSION")),
         os: Some(format!("{}-{}", OS, ARCH)),
         command: Some("fresh"),
         value: std::env::var("TERM").ok(),
         uid: Some(unique_id.to_string()),
-    };
-    send(event);
+    }
+}
+
+/// Track app open with unique ID
+pub fn track_open(unique_id: &str) {
+    send(open_event(unique_id));
+}
+
+/// Send the daily app-open event on a background thread, without an update
+/// check (used when update checking is off but telemetry is on). Shares the
+/// daily debounce with the update checker.
+pub fn start_daily_ping(time_source: SharedTimeSource, data_dir: PathBuf) {
+    #[allow(clippy::let_underscore_must_use)]
+    let _ = std::thread::spawn(move || {
+        if let Some(unique_id) = should_run_daily_check(time_source.as_ref(), &data_dir) {
+            track_open(&unique_id);
+        }
+    });
+}
+
+/// What the daily telemetry ping would send right now: the endpoint and the
+/// JSON body. The unique ID comes from the stamp file; before the first
+/// daily check there is none yet and `placeholder_id` stands in for it.
+pub fn preview(data_dir: &std::path::Path, placeholder_id: &str) -> (&'static str, String) {
+    let unique_id = read_stamp_file(data_dir)
+        .map(|data| data.unique_id)
+        .unwrap_or_else(|| placeholder_id.to_string());
+    let body = serde_json::to_string_pretty(&open_event(&unique_id)).unwrap_or_default();
+    (TELEMETRY_URL, body)
 }
 
 fn send(event: Event) {
@@ -187,6 +214,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn preview_uses_stamp_id_once_there_is_one() {
+        let time_source = TestTimeSource::new();
+        let temp_dir = tempfile::tempdir().unwrap();
+
+        let (url, body) = preview(temp_dir.path(), "<new id>");
+        assert_eq!(url, TELEMETRY_URL);
+        assert!(body.contains("\"uid\": \"<new id>\""), "{body}");
+        assert!(body.contains(env!("CARGO_PKG_VERSION")), "{body}");
+
+        let id = should_run_daily_check(&time_source, temp_dir.path()).unwrap();
+        let (_, body) = preview(temp_dir.path(), "<new id>");
+        assert!(body.contains(&format!("\"uid\": \"{id}\"")), "{body}");
+    }
+
     #[test]
     fn should_run_daily_check_debounces_by_day() {
         let time_source = TestTimeSource::new();
@@ -886,6 +886,14 @@ impl TerminalState {
         self.term.mode().contains(TermMode::APP_CURSOR)
     }
 
+    /// Check if bracketed paste mode (DECSET 2004) is enabled.
+    /// Shells with line editing (bash, zsh, fish) and editors set this
+    /// so a paste arrives wrapped in `ESC[200~` … `ESC[201~` and isn't
+    /// run line by line.
+    pub fn is_bracketed_paste(&self) -> bool {
+        self.term.mode().contains(TermMode::BRACKETED_PASTE)
+    }
+
     // =========================================================================
     // Incremental scrollback streaming
     // =========================================================================
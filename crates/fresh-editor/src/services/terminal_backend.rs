@@ -0,0 +1,172 @@
+//! Terminal output backend
+//!
+//! Ratatui's crossterm backend only knows the plain underline attribute.
+//! [`TerminalBackend`] wraps it and adds curly, dotted and dashed
+//! underlines, which cells carry as the modifier bits of
+//! [`UnderlineStyle::modifier`].
+//!
+//! A run of cells sharing a styled underline is drawn with the underline
+//! turned on (`SGR 4:n`) up front and removed from the cells themselves, so
+//! the inner backend never overrides it; the reset the inner backend writes
+//! after each draw turns it off again. Underline colors pass through
+//! untouched.
+
+use crate::view::overlay::UnderlineStyle;
+use ratatui::backend::{Backend, ClearType, CrosstermBackend, WindowSize};
+use ratatui::buffer::Cell;
+use ratatui::layout::{Position, Size};
+use ratatui::style::Modifier;
+use std::io::{self, Write};
+
+/// A crossterm backend that also draws styled underlines.
+pub struct TerminalBackend<W: Write> {
+    inner: CrosstermBackend<W>,
+}
+
+impl<W: Write> TerminalBackend<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            inner: CrosstermBackend::new(writer),
+        }
+    }
+}
+
+impl<W: Write> Backend for TerminalBackend<W> {
+    type Error = io::Error;
+
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let cells: Vec<(u16, u16, &Cell)> = content.collect();
+        if cells
+            .iter()
+            .all(|(_, _, cell)| UnderlineStyle::from_modifier(cell.modifier).is_none())
+        {
+            return self.inner.draw(cells.into_iter());
+        }
+
+        let style_of = |cell: &Cell| UnderlineStyle::from_modifier(cell.modifier);
+        for run in cells.chunk_by(|a, b| style_of(a.2) == style_of(b.2)) {
+            let Some(style) = style_of(run[0].2) else {
+                self.inner.draw(run.iter().copied())?;
+                continue;
+            };
+            let plain: Vec<(u16, u16, Cell)> = run
+                .iter()
+                .map(|&(x, y, cell)| {
+                    let mut cell = cell.clone();
+                    cell.modifier
+                        .remove(Modifier::UNDERLINED | UnderlineStyle::MODIFIER_MASK);
+                    (x, y, cell)
+                })
+                .collect();
+            write!(self.inner, "\x1b[4:{}m", style.sgr_subparam())?;
+            self.inner
+                .draw(plain.iter().map(|(x, y, cell)| (*x, *y, cell)))?;
+        }
+        Ok(())
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        self.inner.append_lines(n)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.inner.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.inner.show_cursor()
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        self.inner.get_cursor_position()
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        self.inner.set_cursor_position(position)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.inner.clear()
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.inner.clear_region(clear_type)
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        self.inner.size()
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.inner.window_size()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Backend::flush(&mut self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Sink(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Sink {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn cell(symbol: &'static str, modifier: Modifier) -> Cell {
+        let mut cell = Cell::new(symbol);
+        cell.modifier = modifier;
+        cell.underline_color = Color::Red;
+        cell
+    }
+
+    fn draw(cells: &[(u16, u16, Cell)]) -> String {
+        let sink = Sink::default();
+        let mut backend = TerminalBackend::new(sink.clone());
+        backend
+            .draw(cells.iter().map(|(x, y, cell)| (*x, *y, cell)))
+            .unwrap();
+        let out = sink.0.lock().unwrap().clone();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn curly_runs_get_sgr_4_3() {
+        let curly = Modifier::UNDERLINED | UnderlineStyle::Wavy.modifier();
+        let out = draw(&[
+            (0, 0, cell("a", Modifier::empty())),
+            (1, 0, cell("b", curly)),
+            (2, 0, cell("c", curly)),
+            (3, 0, cell("d", Modifier::empty())),
+        ]);
+        assert_eq!(out.matches("\x1b[4:3m").count(), 1);
+        let curl = out.find("\x1b[4:3m").unwrap();
+        assert!(out[..curl].contains('a'));
+        assert!(out[curl..].starts_with("\x1b[4:3m"));
+        // The inner backend's plain underline never overrides the curl.
+        assert!(!out.contains("\x1b[4m"));
+    }
+
+    #[test]
+    fn plain_underlines_pass_through() {
+        let out = draw(&[(0, 0, cell("a", Modifier::UNDERLINED))]);
+        assert!(out.contains("\x1b[4m"));
+        assert!(!out.contains("\x1b[4:"));
+    }
+}
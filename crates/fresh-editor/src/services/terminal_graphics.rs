@@ -0,0 +1,498 @@
+//! Inline images in the terminal
+//!
+//! Image previews are drawn with whichever graphics protocol the host
+//! terminal speaks: kitty's, iTerm2's inline images, or sixel. Ratatui only
+//! knows text cells, so the editor reserves the image's cells in the frame
+//! (blank popup rows) and the escape sequence built here is written after
+//! the frame, with the cursor moved to the reserved area.
+//!
+//! kitty and iTerm2 take the file as is and scale it to the cell area
+//! themselves. Sixel is a bitmap, so PNG files are decoded, scaled and
+//! reduced to a 216-color palette here; other formats stay undrawable on
+//! sixel-only terminals.
+
+use base64::Engine;
+use ratatui::layout::Rect;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Cell size assumed when the terminal doesn't report its pixel size.
+const FALLBACK_CELL_PIXELS: (u16, u16) = (8, 16);
+/// Base64 payload bytes per kitty graphics chunk.
+const KITTY_CHUNK: usize = 4096;
+
+/// A terminal graphics protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Iterm2,
+    Sixel,
+}
+
+impl GraphicsProtocol {
+    /// Name shown in the capability report.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Kitty => "kitty",
+            Self::Iterm2 => "iTerm2",
+            Self::Sixel => "sixel",
+        }
+    }
+
+    /// Guess the protocol from the environment. Sixel support is only
+    /// known from the terminal's Device Attributes, so it isn't guessed.
+    pub fn detect(var: impl Fn(&str) -> Option<String>) -> Option<Self> {
+        let term = var("TERM").unwrap_or_default().to_lowercase();
+        let program = var("TERM_PROGRAM").unwrap_or_default().to_lowercase();
+        // Multiplexers swallow graphics unless wrapped in passthrough.
+        if term.starts_with("tmux") || term.starts_with("screen") || program == "tmux" {
+            return None;
+        }
+        if term.contains("kitty") || program == "ghostty" || var("KITTY_WINDOW_ID").is_some() {
+            Some(Self::Kitty)
+        } else if program == "iterm.app" || program == "wezterm" {
+            Some(Self::Iterm2)
+        } else {
+            None
+        }
+    }
+
+    /// Whether images of `format` can be drawn with this protocol.
+    pub fn draws(self, format: ImageFormat) -> bool {
+        match self {
+            Self::Iterm2 => true,
+            Self::Kitty | Self::Sixel => format == ImageFormat::Png,
+        }
+    }
+}
+
+/// An image file format Fresh can preview.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    Gif,
+}
+
+impl ImageFormat {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Png => "PNG",
+            Self::Jpeg => "JPEG",
+            Self::Gif => "GIF",
+        }
+    }
+}
+
+/// An image file loaded for preview.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreviewImage {
+    pub path: PathBuf,
+    pub data: Arc<[u8]>,
+    pub format: ImageFormat,
+    /// Size in pixels
+    pub width: u32,
+    pub height: u32,
+}
+
+impl PreviewImage {
+    /// Wrap the contents of the image file at `path`, recognising its format
+    /// and size from the header.
+    pub fn from_data(path: &Path, data: Vec<u8>) -> io::Result<Self> {
+        let (format, width, height) = sniff(&data).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "not a PNG, JPEG or GIF image")
+        })?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            data: data.into(),
+            format,
+            width,
+            height,
+        })
+    }
+
+    /// The largest cell area within `max_cols` × `max_rows` that keeps the
+    /// image's aspect ratio, never scaling it up.
+    pub fn fit(&self, max_cols: u16, max_rows: u16, cell: (u16, u16)) -> (u16, u16) {
+        let (cell_w, cell_h) = (cell.0.max(1) as f64, cell.1.max(1) as f64);
+        let (w, h) = (self.width.max(1) as f64, self.height.max(1) as f64);
+        let scale = (max_cols as f64 * cell_w / w)
+            .min(max_rows as f64 * cell_h / h)
+            .min(1.0);
+        let cols = ((w * scale) / cell_w)
+            .ceil()
+            .clamp(1.0, max_cols.max(1) as f64);
+        let rows = ((h * scale) / cell_h)
+            .ceil()
+            .clamp(1.0, max_rows.max(1) as f64);
+        (cols as u16, rows as u16)
+    }
+}
+
+/// Recognise an image from its header: format, width and height.
+pub fn sniff(data: &[u8]) -> Option<(ImageFormat, u32, u32)> {
+    let be32 = |at: usize| Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?));
+    let be16 = |at: usize| Some(u16::from_be_bytes(data.get(at..at + 2)?.try_into().ok()?) as u32);
+    let le16 = |at: usize| Some(u16::from_le_bytes(data.get(at..at + 2)?.try_into().ok()?) as u32);
+
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") && data.get(12..16) == Some(b"IHDR") {
+        return Some((ImageFormat::Png, be32(16)?, be32(20)?));
+    }
+    if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+        return Some((ImageFormat::Gif, le16(6)?, le16(8)?));
+    }
+    if data.starts_with(&[0xFF, 0xD8]) {
+        // Walk the segments to the first start-of-frame marker.
+        let mut at = 2;
+        while data.get(at) == Some(&0xFF) {
+            let marker = *data.get(at + 1)?;
+            let len = be16(at + 2)? as usize;
+            let is_sof = matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC);
+            if is_sof {
+                return Some((ImageFormat::Jpeg, be16(at + 7)?, be16(at + 5)?));
+            }
+            at += 2 + len;
+        }
+    }
+    None
+}
+
+/// Pixel size of one terminal cell, from the terminal's reported window
+/// size when it has one.
+pub fn cell_pixel_size() -> (u16, u16) {
+    crossterm::terminal::window_size()
+        .ok()
+        .filter(|s| s.width > 0 && s.height > 0 && s.columns > 0 && s.rows > 0)
+        .map(|s| (s.width / s.columns, s.height / s.rows))
+        .filter(|&(w, h)| w > 0 && h > 0)
+        .unwrap_or(FALLBACK_CELL_PIXELS)
+}
+
+/// Escape sequence drawing `image` over `area`, leaving the cursor where it
+/// was. `None` when the protocol can't draw the image's format or the
+/// image fails to decode.
+pub fn encode(
+    protocol: GraphicsProtocol,
+    image: &PreviewImage,
+    area: Rect,
+    cell: (u16, u16),
+) -> Option<Vec<u8>> {
+    if !protocol.draws(image.format) || area.width == 0 || area.height == 0 {
+        return None;
+    }
+    let payload = match protocol {
+        GraphicsProtocol::Kitty => kitty(image, area),
+        GraphicsProtocol::Iterm2 => iterm2(image, area),
+        GraphicsProtocol::Sixel => sixel(image, area, cell)?,
+    };
+    let mut out = format!("\x1b7\x1b[{};{}H", area.y + 1, area.x + 1).into_bytes();
+    out.extend_from_slice(&payload);
+    out.extend_from_slice(b"\x1b8");
+    Some(out)
+}
+
+/// Escape sequence removing images drawn earlier. Only kitty keeps images
+/// apart from the text cells; the others are painted over by the next
+/// frame.
+pub fn clear(protocol: GraphicsProtocol) -> Vec<u8> {
+    match protocol {
+        GraphicsProtocol::Kitty => b"\x1b_Ga=d,q=2\x1b\\".to_vec(),
+        GraphicsProtocol::Iterm2 | GraphicsProtocol::Sixel => Vec::new(),
+    }
+}
+
+fn kitty(image: &PreviewImage, area: Rect) -> Vec<u8> {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(&image.data);
+    let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(KITTY_CHUNK).collect();
+    let mut out = Vec::with_capacity(encoded.len() + chunks.len() * 32);
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 < chunks.len());
+        let header = if i == 0 {
+            format!(
+                "\x1b_Ga=T,f=100,q=2,C=1,c={},r={},m={};",
+                area.width, area.height, more
+            )
+        } else {
+            format!("\x1b_Gm={};", more)
+        };
+        out.extend_from_slice(header.as_bytes());
+        out.extend_from_slice(chunk);
+        out.extend_from_slice(b"\x1b\\");
+    }
+    out
+}
+
+fn iterm2(image: &PreviewImage, area: Rect) -> Vec<u8> {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(&image.data);
+    format!(
+        "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=1:{}\x07",
+        image.data.len(),
+        area.width,
+        area.height,
+        encoded
+    )
+    .into_bytes()
+}
+
+/// Decode a PNG into 8-bit RGBA.
+fn decode_png(data: &[u8]) -> Option<(Vec<u8>, u32, u32)> {
+    let mut decoder = png::Decoder::new(io::Cursor::new(data));
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().ok()?;
+    let mut buf = vec![0; reader.output_buffer_size()?];
+    let info = reader.next_frame(&mut buf).ok()?;
+    buf.truncate(info.buffer_size());
+    let rgba: Vec<u8> = match info.color_type {
+        png::ColorType::Rgba => buf,
+        png::ColorType::Rgb => buf
+            .chunks_exact(3)
+            .flat_map(|p| [p[0], p[1], p[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buf
+            .chunks_exact(2)
+            .flat_map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        png::ColorType::Grayscale => buf.iter().flat_map(|&g| [g, g, g, 255]).collect(),
+        png::ColorType::Indexed => return None,
+    };
+    Some((rgba, info.width, info.height))
+}
+
+fn sixel(image: &PreviewImage, area: Rect, cell: (u16, u16)) -> Option<Vec<u8>> {
+    let (rgba, src_w, src_h) = decode_png(&image.data)?;
+    let max_w = area.width as f64 * cell.0 as f64;
+    let max_h = area.height as f64 * cell.1 as f64;
+    let scale = (max_w / src_w as f64).min(max_h / src_h as f64).min(1.0);
+    let width = ((src_w as f64 * scale) as usize).max(1);
+    let height = ((src_h as f64 * scale) as usize).max(1);
+
+    // Nearest-neighbour scale onto a 6×6×6 color cube; `None` is transparent.
+    let level = |c: u8| (c as usize * 5 + 127) / 255;
+    let pixels: Vec<Option<usize>> = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let sx = (x * src_w as usize / width).min(src_w as usize - 1);
+            let sy = (y * src_h as usize / height).min(src_h as usize - 1);
+            let p = &rgba[(sy * src_w as usize + sx) * 4..][..4];
+            (p[3] >= 128).then(|| level(p[0]) * 36 + level(p[1]) * 6 + level(p[2]))
+        })
+        .collect();
+
+    let mut out = format!("\x1bP0;1;0q\"1;1;{};{}", width, height);
+    let mut used = [false; 216];
+    for &color in pixels.iter().flatten() {
+        used[color] = true;
+    }
+    for (color, _) in used.iter().enumerate().filter(|(_, used)| **used) {
+        let pct = |l: usize| l * 20;
+        out.push_str(&format!(
+            "#{};2;{};{};{}",
+            color,
+            pct(color / 36),
+            pct(color / 6 % 6),
+            pct(color % 6)
+        ));
+    }
+
+    for band in (0..height).step_by(6) {
+        let rows = band..(band + 6).min(height);
+        let mut colors: Vec<usize> = rows
+            .clone()
+            .flat_map(|y| pixels[y * width..(y + 1) * width].iter().flatten().copied())
+            .collect();
+        colors.sort_unstable();
+        colors.dedup();
+        for color in colors {
+            out.push_str(&format!("#{}", color));
+            let sixels = (0..width).map(|x| {
+                rows.clone().fold(0u8, |bits, y| {
+                    if pixels[y * width + x] == Some(color) {
+                        bits | 1 << (y - band)
+                    } else {
+                        bits
+                    }
+                })
+            });
+            push_run_length(&mut out, sixels);
+            out.push('$');
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    Some(out.into_bytes())
+}
+
+/// Append sixel data characters, collapsing repeats into `!<n><char>`.
+fn push_run_length(out: &mut String, sixels: impl Iterator<Item = u8>) {
+    let mut run: Option<(u8, usize)> = None;
+    let flush = |out: &mut String, bits: u8, count: usize| {
+        let ch = (b'?' + bits) as char;
+        if count > 3 {
+            out.push_str(&format!("!{}{}", count, ch));
+        } else {
+            out.extend(std::iter::repeat_n(ch, count));
+        }
+    };
+    for bits in sixels {
+        run = match run {
+            Some((prev, count)) if prev == bits => Some((prev, count + 1)),
+            Some((prev, count)) => {
+                flush(out, prev, count);
+                Some((bits, 1))
+            }
+            None => Some((bits, 1)),
+        };
+    }
+    if let Some((bits, count)) = run {
+        flush(out, bits, count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 2×2 RGBA PNG: red, green / blue, transparent.
+    fn tiny_png() -> Vec<u8> {
+        let mut data = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut data, 2, 2);
+            encoder.set_color(png::ColorType::Rgba);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header().unwrap();
+            writer
+                .write_image_data(&[
+                    255, 0, 0, 255, 0, 255, 0, 255, //
+                    0, 0, 255, 255, 0, 0, 0, 0,
+                ])
+                .unwrap();
+        }
+        data
+    }
+
+    fn image(data: Vec<u8>) -> PreviewImage {
+        let (format, width, height) = sniff(&data).unwrap();
+        PreviewImage {
+            path: PathBuf::from("tiny.png"),
+            data: data.into(),
+            format,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn headers_give_format_and_size() {
+        assert_eq!(sniff(&tiny_png()), Some((ImageFormat::Png, 2, 2)));
+        assert_eq!(
+            sniff(b"GIF89a\x40\x01\xf0\x00"),
+            Some((ImageFormat::Gif, 320, 240))
+        );
+        // SOI, an APP0 segment, then SOF0 with height 480 and width 640.
+        let jpeg = [
+            0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, 0xFF, 0xC0, 0x00, 0x11, 0x08, 0x01,
+            0xE0, 0x02, 0x80,
+        ];
+        assert_eq!(sniff(&jpeg), Some((ImageFormat::Jpeg, 640, 480)));
+        assert_eq!(sniff(b"plain text"), None);
+    }
+
+    #[test]
+    fn fit_keeps_the_aspect_ratio_without_upscaling() {
+        let mut img = image(tiny_png());
+        (img.width, img.height) = (800, 400);
+        assert_eq!(img.fit(50, 50, (8, 16)), (50, 13));
+        (img.width, img.height) = (16, 16);
+        assert_eq!(img.fit(50, 50, (8, 16)), (2, 1));
+    }
+
+    #[test]
+    fn protocols_detected_from_the_environment() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            GraphicsProtocol::detect(env(&[("TERM", "xterm-kitty")])),
+            Some(GraphicsProtocol::Kitty)
+        );
+        assert_eq!(
+            GraphicsProtocol::detect(env(&[("TERM_PROGRAM", "iTerm.app")])),
+            Some(GraphicsProtocol::Iterm2)
+        );
+        assert_eq!(
+            GraphicsProtocol::detect(env(&[("TERM", "tmux-256color"), ("KITTY_WINDOW_ID", "1")])),
+            None
+        );
+        assert_eq!(
+            GraphicsProtocol::detect(env(&[("TERM", "xterm-256color")])),
+            None
+        );
+    }
+
+    #[test]
+    fn each_protocol_draws_at_the_area() {
+        let img = image(tiny_png());
+        let area = Rect::new(4, 2, 10, 5);
+        for protocol in [
+            GraphicsProtocol::Kitty,
+            GraphicsProtocol::Iterm2,
+            GraphicsProtocol::Sixel,
+        ] {
+            let out = String::from_utf8(encode(protocol, &img, area, (8, 16)).unwrap()).unwrap();
+            assert!(out.starts_with("\x1b7\x1b[3;5H"), "{protocol:?}: {out:?}");
+            assert!(out.ends_with("\x1b8"), "{protocol:?}");
+        }
+
+        let kitty = encode(GraphicsProtocol::Kitty, &img, area, (8, 16)).unwrap();
+        assert!(String::from_utf8_lossy(&kitty).contains("a=T,f=100,q=2,C=1,c=10,r=5,m=0;"));
+    }
+
+    #[test]
+    fn sixel_encodes_the_used_palette_and_transparency() {
+        let img = image(tiny_png());
+        let out = String::from_utf8(sixel(&img, Rect::new(0, 0, 1, 1), (8, 16)).unwrap()).unwrap();
+        assert!(out.starts_with("\x1bP0;1;0q\"1;1;2;2"));
+        // Red, green and blue of the 6-level cube, and nothing for the
+        // transparent pixel.
+        assert!(out.contains("#180;2;100;0;0"));
+        assert!(out.contains("#30;2;0;100;0"));
+        assert!(out.contains("#5;2;0;0;100"));
+        let header = "\x1bP0;1;0q\"1;1;2;2".len();
+        assert_eq!(out[header..].matches(";2;").count(), 3);
+        // Red is the top-left pixel: bit 0 of column 0, nothing in column 1.
+        assert!(out.contains("#180@?$"));
+        assert!(out.ends_with("-\x1b\\"));
+    }
+
+    #[test]
+    fn kitty_only_draws_png() {
+        let mut img = image(tiny_png());
+        img.format = ImageFormat::Jpeg;
+        assert!(encode(
+            GraphicsProtocol::Kitty,
+            &img,
+            Rect::new(0, 0, 4, 4),
+            (8, 16)
+        )
+        .is_none());
+        assert!(encode(
+            GraphicsProtocol::Iterm2,
+            &img,
+            Rect::new(0, 0, 4, 4),
+            (8, 16)
+        )
+        .is_some());
+    }
+
+    #[test]
+    fn long_runs_are_compressed() {
+        let mut out = String::new();
+        push_run_length(&mut out, [1, 1, 1, 1, 1, 0, 0].into_iter());
+        assert_eq!(out, "!5@??");
+    }
+}
@@ -60,6 +60,67 @@ pub mod sequences {
 
     // Attributes
     pub const RESET_ATTRIBUTES: &[u8] = b"\x1b[0m";
+
+    // Capability query: the kitty flags in effect (`CSI ? u`), the state of
+    // mouse reporting and bracketed paste (DECRQM, `CSI ? n $ p`), then
+    // Primary Device Attributes (`CSI c`). Every terminal answers DA, so a DA
+    // reply with no flags reply before it means "not supported".
+    pub const QUERY_CAPABILITIES: &[u8] = b"\x1b[?u\x1b[?1000$p\x1b[?2004$p\x1b[c";
+}
+
+/// What the terminal said about the kitty keyboard protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyboardProtocol {
+    /// Queried, no answer yet (or never queried)
+    #[default]
+    Unknown,
+    /// The terminal answered Device Attributes but not the flags query
+    Unsupported,
+    /// The terminal reported these enhancement flags in effect
+    Active(KeyboardEnhancementFlags),
+}
+
+impl KeyboardProtocol {
+    /// Fold a query reply into the current state.
+    pub fn apply_reply(self, reply: fresh_input_parser::TerminalReply) -> Self {
+        use fresh_input_parser::TerminalReply;
+        match reply {
+            TerminalReply::KeyboardFlags(bits) => {
+                Self::Active(KeyboardEnhancementFlags::from_bits_truncate(bits))
+            }
+            TerminalReply::DeviceAttributes { .. } if self == Self::Unknown => Self::Unsupported,
+            TerminalReply::DeviceAttributes { .. } | TerminalReply::Mode { .. } => self,
+        }
+    }
+}
+
+/// Query replies read from the host terminal, waiting for the event loop.
+///
+/// The input readers live inside the event loop's poll closure, so they hand
+/// replies over through here rather than through the event stream.
+static TERMINAL_REPLIES: std::sync::Mutex<Vec<fresh_input_parser::TerminalReply>> =
+    std::sync::Mutex::new(Vec::new());
+
+/// Queue replies an input reader parsed from the host terminal.
+pub fn push_terminal_replies(replies: Vec<fresh_input_parser::TerminalReply>) {
+    if !replies.is_empty() {
+        TERMINAL_REPLIES.lock().unwrap().extend(replies);
+    }
+}
+
+/// Take the queued host-terminal replies, oldest first.
+pub fn take_terminal_replies() -> Vec<fresh_input_parser::TerminalReply> {
+    std::mem::take(&mut *TERMINAL_REPLIES.lock().unwrap())
+}
+
+/// Ask the host terminal what it supports. The answers arrive later as
+/// input; see [`crate::services::terminal_probe::TerminalCapabilities`].
+#[allow(clippy::let_underscore_must_use)]
+pub fn query_capabilities() {
+    // Best-effort: without an answer the capabilities just stay unknown.
+    let mut out = stdout();
+    let _ = out.write_all(sequences::QUERY_CAPABILITIES);
+    let _ = out.flush();
 }
 
 /// Configuration for keyboard enhancement flags.
@@ -87,6 +148,23 @@ impl Default for KeyboardConfig {
 }
 
 impl KeyboardConfig {
+    /// The flags the editor config asks for. Key event types are also
+    /// requested whenever a binding fires on key release.
+    pub fn from_config(config: &crate::config::Config) -> Self {
+        let editor = &config.editor;
+        let has_release_bindings = config.keybindings.iter().any(|kb| kb.release)
+            || config
+                .resolve_keymap(&config.active_keybinding_map)
+                .iter()
+                .any(|kb| kb.release);
+        Self {
+            disambiguate_escape_codes: editor.keyboard_disambiguate_escape_codes,
+            report_event_types: editor.keyboard_report_event_types || has_release_bindings,
+            report_alternate_keys: editor.keyboard_report_alternate_keys,
+            report_all_keys_as_escape_codes: editor.keyboard_report_all_keys_as_escape_codes,
+        }
+    }
+
     /// Build crossterm KeyboardEnhancementFlags from this config.
     pub fn to_flags(&self) -> KeyboardEnhancementFlags {
         let mut flags = KeyboardEnhancementFlags::empty();
@@ -0,0 +1,359 @@
+//! Terminal capability probe
+//!
+//! At startup Fresh asks the host terminal what it supports: DECRQM for
+//! mouse reporting and bracketed paste, the kitty keyboard flags, and then
+//! Primary Device Attributes, which every terminal answers and which
+//! therefore marks the end of the replies. The answers are combined with
+//! what the environment says about colors, underline styles and inline
+//! images.
+//!
+//! Features the terminal says it can't handle are turned off instead of
+//! being left to misbehave: mouse capture is released, and underline colors
+//! are stripped from the frame unless the terminal is known to render them.
+//! The findings are shown by the "Terminal Capabilities" command and by
+//! `fresh --doctor`.
+
+use crate::input::key_translator::current_terminal;
+use crate::services::terminal_graphics::GraphicsProtocol;
+use crate::services::terminal_modes::KeyboardProtocol;
+use crate::view::color_support::ColorCapability;
+use fresh_input_parser::TerminalReply;
+use rust_i18n::t;
+
+/// DECSET mode number for mouse click reporting.
+const MODE_MOUSE: u16 = 1000;
+/// DECSET mode number for bracketed paste.
+const MODE_BRACKETED_PASTE: u16 = 2004;
+
+/// Whether the terminal supports a feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Support {
+    /// No answer (yet), or nothing to go on
+    #[default]
+    Unknown,
+    Yes,
+    No,
+}
+
+impl Support {
+    /// Interpret a DECRPM status: 0 is "mode not recognised" and 4 is
+    /// "permanently reset"; anything else means the mode works.
+    fn from_mode_status(status: u8) -> Self {
+        match status {
+            0 | 4 => Self::No,
+            _ => Self::Yes,
+        }
+    }
+
+    fn label(self) -> String {
+        match self {
+            Self::Unknown => t!("probe.unknown"),
+            Self::Yes => t!("probe.yes"),
+            Self::No => t!("probe.no"),
+        }
+        .to_string()
+    }
+}
+
+/// What the host terminal can do, as far as Fresh could find out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalCapabilities {
+    /// `TERM_PROGRAM` or `TERM`, when set
+    pub terminal: Option<String>,
+    pub color: ColorCapability,
+    /// Mouse click reporting (DECSET 1000)
+    pub mouse: Support,
+    /// Bracketed paste (DECSET 2004)
+    pub bracketed_paste: Support,
+    /// Colored and curly underlines (SGR 58 / `4:3`), judged from the
+    /// environment since terminals can't be asked
+    pub styled_underlines: Support,
+    pub keyboard: KeyboardProtocol,
+    /// How inline images can be drawn, if at all
+    pub graphics: Option<GraphicsProtocol>,
+    /// Whether the terminal has answered the query. Features still
+    /// [`Support::Unknown`] after this weren't reported either way.
+    pub settled: bool,
+}
+
+impl TerminalCapabilities {
+    /// Nothing known beyond the color depth.
+    pub fn unprobed(color: ColorCapability) -> Self {
+        Self {
+            terminal: None,
+            color,
+            mouse: Support::Unknown,
+            bracketed_paste: Support::Unknown,
+            styled_underlines: Support::Unknown,
+            keyboard: KeyboardProtocol::Unknown,
+            graphics: None,
+            settled: false,
+        }
+    }
+
+    /// Capabilities known from the environment alone, before any reply.
+    pub fn detect(color: ColorCapability) -> Self {
+        Self {
+            terminal: current_terminal(),
+            styled_underlines: styled_underline_support(|name| std::env::var(name).ok()),
+            graphics: GraphicsProtocol::detect(|name| std::env::var(name).ok()),
+            ..Self::unprobed(color)
+        }
+    }
+
+    /// Fold a query reply into the capabilities. Returns whether anything
+    /// changed.
+    pub fn apply_reply(&mut self, reply: TerminalReply) -> bool {
+        let before = self.clone();
+        match reply {
+            TerminalReply::Mode { mode, status } => match mode {
+                MODE_MOUSE => self.mouse = Support::from_mode_status(status),
+                MODE_BRACKETED_PASTE => self.bracketed_paste = Support::from_mode_status(status),
+                _ => {}
+            },
+            TerminalReply::KeyboardFlags(_) => self.keyboard = self.keyboard.apply_reply(reply),
+            TerminalReply::DeviceAttributes { sixel } => {
+                self.keyboard = self.keyboard.apply_reply(reply);
+                if sixel && self.graphics.is_none() {
+                    self.graphics = Some(GraphicsProtocol::Sixel);
+                }
+                self.settled = true;
+            }
+        }
+        *self != before
+    }
+
+    /// Whether underline colors and styles should reach the terminal.
+    pub fn renders_styled_underlines(&self) -> bool {
+        self.styled_underlines == Support::Yes
+    }
+
+    /// Features Fresh turns off for this terminal, for display.
+    pub fn disabled_features(&self) -> Vec<String> {
+        let mut disabled = Vec::new();
+        if self.mouse == Support::No {
+            disabled.push(t!("probe.disabled_mouse").to_string());
+        }
+        if !self.renders_styled_underlines() {
+            disabled.push(t!("probe.disabled_underlines").to_string());
+        }
+        disabled
+    }
+
+    /// One line per finding, as shown in the popup and by `fresh --doctor`.
+    pub fn report_lines(&self) -> Vec<String> {
+        let color = match self.color {
+            ColorCapability::TrueColor => t!("probe.color_true"),
+            ColorCapability::Color256 => t!("probe.color_256"),
+            ColorCapability::Color16 => t!("probe.color_16"),
+        };
+        let keyboard = match self.keyboard {
+            KeyboardProtocol::Active(flags) => {
+                t!("probe.keyboard_active", flags = flags.bits())
+            }
+            KeyboardProtocol::Unsupported => t!("probe.no"),
+            KeyboardProtocol::Unknown => t!("probe.unknown"),
+        };
+        let terminal = self
+            .terminal
+            .clone()
+            .unwrap_or_else(|| t!("probe.unknown").to_string());
+        let mut lines = vec![
+            t!("probe.terminal", value = terminal).to_string(),
+            t!("probe.colors", value = color).to_string(),
+            t!("probe.mouse", value = self.mouse.label()).to_string(),
+            t!(
+                "probe.bracketed_paste",
+                value = self.bracketed_paste.label()
+            )
+            .to_string(),
+            t!("probe.underlines", value = self.styled_underlines.label()).to_string(),
+            t!("probe.keyboard", value = keyboard).to_string(),
+            t!(
+                "probe.images",
+                value = self
+                    .graphics
+                    .map(|g| g.label().to_string())
+                    .unwrap_or_else(|| t!("probe.no").to_string())
+            )
+            .to_string(),
+        ];
+        if !self.settled {
+            lines.push(String::new());
+            lines.push(t!("probe.no_answer").to_string());
+        }
+        let disabled = self.disabled_features();
+        if !disabled.is_empty() {
+            lines.push(String::new());
+            lines.push(t!("probe.disabled", features = disabled.join(", ")).to_string());
+        }
+        lines
+    }
+}
+
+/// Judge underline style support from the environment. Terminals that
+/// don't implement SGR 58 can misread its parameters as other attributes,
+/// so only terminals known to handle it get [`Support::Yes`].
+fn styled_underline_support(var: impl Fn(&str) -> Option<String>) -> Support {
+    let term = var("TERM").unwrap_or_default().to_lowercase();
+    let program = var("TERM_PROGRAM").unwrap_or_default().to_lowercase();
+
+    if term == "linux" || term.starts_with("screen") || program == "apple_terminal" {
+        return Support::No;
+    }
+    // Multiplexers pass styles through only when configured to.
+    if term.starts_with("tmux") || program == "tmux" {
+        return Support::Unknown;
+    }
+    let known_term = [
+        "kitty",
+        "wezterm",
+        "foot",
+        "ghostty",
+        "alacritty",
+        "contour",
+    ]
+    .iter()
+    .any(|name| term.contains(name));
+    let known_program = ["wezterm", "ghostty", "iterm.app", "vscode"].contains(&program.as_str());
+    // VTE gained styled underlines in 0.52.
+    let vte = var("VTE_VERSION")
+        .and_then(|v| v.parse::<u32>().ok())
+        .is_some_and(|v| v >= 5200);
+    if known_term
+        || known_program
+        || vte
+        || var("KITTY_WINDOW_ID").is_some()
+        || var("WT_SESSION").is_some()
+    {
+        Support::Yes
+    } else {
+        Support::Unknown
+    }
+}
+
+/// `fresh --doctor`: probe the terminal and print what was found.
+pub fn run_doctor() -> anyhow::Result<()> {
+    let mut capabilities = TerminalCapabilities::detect(ColorCapability::detect());
+    #[cfg(unix)]
+    {
+        use std::io::IsTerminal;
+        if std::io::stdin().is_terminal() && std::io::stdout().is_terminal() {
+            query_terminal(&mut capabilities)?;
+        }
+    }
+    println!("{}", t!("probe.title"));
+    for line in capabilities.report_lines() {
+        if line.is_empty() {
+            println!();
+        } else {
+            println!("  {}", line);
+        }
+    }
+    Ok(())
+}
+
+/// Send the capability query and read replies until the terminal has
+/// answered or a second has passed.
+#[cfg(unix)]
+fn query_terminal(capabilities: &mut TerminalCapabilities) -> anyhow::Result<()> {
+    use crate::services::terminal_modes::{query_capabilities, take_terminal_replies};
+    use std::time::{Duration, Instant};
+
+    crossterm::terminal::enable_raw_mode()?;
+    query_capabilities();
+    let mut reader = crate::services::tty_input::TtyReader::new();
+    let deadline = Instant::now() + Duration::from_secs(1);
+    while !capabilities.settled {
+        let Some(remaining) = deadline.checked_duration_since(Instant::now()) else {
+            break;
+        };
+        // Key presses typed meanwhile are dropped; only replies matter.
+        let polled = reader.poll(remaining);
+        for reply in take_terminal_replies() {
+            capabilities.apply_reply(reply);
+        }
+        if polled.is_err() {
+            break;
+        }
+    }
+    crossterm::terminal::disable_raw_mode()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    fn unprobed() -> TerminalCapabilities {
+        TerminalCapabilities::unprobed(ColorCapability::Color256)
+    }
+
+    #[test]
+    fn replies_fill_in_capabilities() {
+        let mut caps = unprobed();
+        assert!(caps.apply_reply(TerminalReply::Mode {
+            mode: 1000,
+            status: 1
+        }));
+        assert!(caps.apply_reply(TerminalReply::Mode {
+            mode: 2004,
+            status: 0
+        }));
+        assert!(!caps.apply_reply(TerminalReply::Mode { mode: 9, status: 2 }));
+        assert!(caps.apply_reply(TerminalReply::DeviceAttributes { sixel: true }));
+
+        assert_eq!(caps.mouse, Support::Yes);
+        assert_eq!(caps.bracketed_paste, Support::No);
+        assert_eq!(caps.keyboard, KeyboardProtocol::Unsupported);
+        assert_eq!(caps.graphics, Some(GraphicsProtocol::Sixel));
+        assert!(caps.settled);
+    }
+
+    #[test]
+    fn unsupported_features_are_reported_as_disabled() {
+        let mut caps = unprobed();
+        caps.apply_reply(TerminalReply::Mode {
+            mode: 1000,
+            status: 0,
+        });
+        caps.apply_reply(TerminalReply::DeviceAttributes { sixel: false });
+        assert_eq!(caps.disabled_features().len(), 2);
+
+        caps.mouse = Support::Yes;
+        caps.styled_underlines = Support::Yes;
+        assert!(caps.disabled_features().is_empty());
+    }
+
+    #[test]
+    fn underline_support_follows_the_environment() {
+        assert_eq!(
+            styled_underline_support(env(&[("TERM", "xterm-kitty")])),
+            Support::Yes
+        );
+        assert_eq!(
+            styled_underline_support(env(&[("TERM", "xterm-256color"), ("VTE_VERSION", "7600")])),
+            Support::Yes
+        );
+        assert_eq!(
+            styled_underline_support(env(&[("TERM", "linux")])),
+            Support::No
+        );
+        assert_eq!(
+            styled_underline_support(env(&[("TERM", "tmux-256color"), ("WT_SESSION", "x")])),
+            Support::Unknown
+        );
+        assert_eq!(
+            styled_underline_support(env(&[("TERM", "xterm-256color")])),
+            Support::Unknown
+        );
+    }
+}
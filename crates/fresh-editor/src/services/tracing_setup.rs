@@ -16,6 +16,8 @@ use super::warning_log::{WarningLogHandle, WarningLogLayer};
 pub struct TracingHandles {
     pub warning: WarningLogHandle,
     pub status: StatusLogHandle,
+    /// The main log file everything is written to.
+    pub log_path: std::path::PathBuf,
 }
 
 /// Initialize the global tracing subscriber with file logging and warning/status capture.
@@ -38,6 +40,7 @@ pub fn init_global(log_file_path: &Path) -> Option<TracingHandles> {
     Some(TracingHandles {
         warning: warning_handle,
         status: status_handle,
+        log_path: log_file_path.to_path_buf(),
     })
 }
 
@@ -160,6 +160,7 @@ impl TtyReader {
         for ev in events {
             self.push_coalesced(ev);
         }
+        crate::services::terminal_modes::push_terminal_replies(self.parser.take_replies());
         true
     }
 
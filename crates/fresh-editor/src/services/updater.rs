@@ -126,14 +126,21 @@ pub fn run(opts: &UpdateOptions) -> Result<UpdateStatus, String> {
                 println!("A new version of Fresh is available: {CURRENT_VERSION} → {latest}");
                 println!("Download it from: {url}");
                 Ok(UpdateStatus::Done)
+            } else if prov.channel == Channel::Unknown && !is_cargo_build_output() {
+                // A binary placed on PATH by hand: fetch the release build for
+                // this platform and swap it in, keeping the old binary until
+                // the new one starts.
+                standalone_update(&latest, opts)
+                    .map(|()| UpdateStatus::Done)
+                    .map_err(|e| format!("{e}\nDownload the release manually from {url}"))
             } else {
-                // Unknown / source install: no in-place mechanism. Friendly
+                // Source install or a cargo build: no in-place mechanism. Friendly
                 // guidance — deliberately not phrased as an error — but the
                 // caller still exits non-zero so the editor's update indicator
                 // doesn't report a phantom "Updated" that never happened.
                 println!("A new version of Fresh is available: {CURRENT_VERSION} → {latest}");
                 println!();
-                println!("This copy of Fresh was installed manually, so it can't update itself.");
+                println!("This copy of Fresh was built from source, so it can't update itself.");
                 println!("To update, download the latest release:");
                 println!();
                 println!("    {url}");
@@ -174,16 +181,7 @@ fn self_contained_update(
 
     // Tarball / prebuilt: download the archive, verify, extract the inner
     // binary, and atomically swap the running executable.
-    let ext = if cfg!(windows) { "zip" } else { "tar.xz" };
-    let asset = prov
-        .hints
-        .asset
-        .clone()
-        .unwrap_or_else(|| format!("fresh-editor-{target}.{ext}"));
-    let url = format!("{}/v{latest}/{asset}", opts.download_base);
-
-    let bin_name = if cfg!(windows) { "fresh.exe" } else { "fresh" };
-    let binary = fetch_and_extract_binary(&url, bin_name)?;
+    let binary = fetch_release_binary(prov.hints.asset.clone(), latest, opts)?;
 
     let exe = std::env::current_exe().map_err(|e| format!("cannot find current exe: {e}"))?;
     println!("Installing to {} ...", exe.display());
@@ -193,6 +191,78 @@ fn self_contained_update(
     Ok(())
 }
 
+/// In-place update for a standalone binary of unknown provenance. Same
+/// download and verification as a tarball install, but there is no installer
+/// to fall back on, so the previous binary is kept until the new one answers
+/// `--version` with the expected release and restored otherwise.
+fn standalone_update(latest: &str, opts: &UpdateOptions) -> Result<(), String> {
+    let binary = fetch_release_binary(None, latest, opts)?;
+
+    let exe = std::env::current_exe().map_err(|e| format!("cannot find current exe: {e}"))?;
+    println!("Installing to {} ...", exe.display());
+    self_update::replace_with_rollback(&exe, &binary, |path| reports_version(path, latest))
+        .map_err(|e| e.to_string())?;
+
+    println!("Updated to {latest}. Restart fresh to use the new version.");
+    Ok(())
+}
+
+/// Download, verify and extract the release binary for this platform.
+/// `asset` overrides the archive name (from the install receipt).
+fn fetch_release_binary(
+    asset: Option<String>,
+    latest: &str,
+    opts: &UpdateOptions,
+) -> Result<Vec<u8>, String> {
+    let target = fresh_update::TARGET_TRIPLE;
+    let ext = if cfg!(windows) { "zip" } else { "tar.xz" };
+    let asset = asset.unwrap_or_else(|| format!("fresh-editor-{target}.{ext}"));
+    let url = format!("{}/v{latest}/{asset}", opts.download_base);
+
+    let bin_name = if cfg!(windows) { "fresh.exe" } else { "fresh" };
+    fetch_and_extract_binary(&url, bin_name)
+}
+
+/// Health check for a freshly installed binary: it runs and reports `version`.
+fn reports_version(exe: &Path, version: &str) -> bool {
+    std::process::Command::new(exe)
+        .arg("--version")
+        .output()
+        .map(|out| {
+            out.status.success()
+                && version_output_matches(&String::from_utf8_lossy(&out.stdout), version)
+        })
+        .unwrap_or(false)
+}
+
+/// Whether `--version` output (`fresh 0.4.5`) names exactly `version`.
+fn version_output_matches(output: &str, version: &str) -> bool {
+    output
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().last())
+        .is_some_and(|reported| reported == version)
+}
+
+/// Whether the running binary sits in a cargo `target/{debug,release}`
+/// directory. Such a build has no provenance either, but replacing it with a
+/// release download would be wrong: it's a source checkout.
+fn is_cargo_build_output() -> bool {
+    std::env::current_exe()
+        .map(|exe| exe_in_cargo_target(&exe))
+        .unwrap_or(false)
+}
+
+fn exe_in_cargo_target(exe: &Path) -> bool {
+    let mut dirs = exe.ancestors().skip(1).filter_map(|d| d.file_name());
+    match (dirs.next(), dirs.next()) {
+        (Some(profile), Some(target)) => {
+            target == "target" && (profile == "debug" || profile == "release")
+        }
+        _ => false,
+    }
+}
+
 /// AppImage self-update: download the new AppImage, verify, extract its
 /// squashfs, and atomically replace the install root created by install.sh.
 /// Unix-only (AppImages don't exist on Windows/macOS).
@@ -370,6 +440,14 @@ mod tests {
     use super::*;
     use std::io::Write;
 
+    #[test]
+    fn version_output_must_name_the_exact_version() {
+        assert!(version_output_matches("fresh 0.4.5\n", "0.4.5"));
+        assert!(!version_output_matches("fresh 0.4.51\n", "0.4.5"));
+        assert!(!version_output_matches("fresh 0.4.5-rc1\n", "0.4.5"));
+        assert!(!version_output_matches("", "0.4.5"));
+    }
+
     /// Build a `.tar.xz` in memory containing a single `fresh` entry.
     fn make_tar_xz(bin: &[u8]) -> Vec<u8> {
         let mut tar_bytes = Vec::new();
@@ -406,6 +484,18 @@ mod tests {
         assert!(extract_from_tar_xz(&archive, "nope").is_err());
     }
 
+    #[test]
+    fn cargo_build_output_is_not_self_updated() {
+        assert!(exe_in_cargo_target(Path::new(
+            "/src/fresh/target/debug/fresh"
+        )));
+        assert!(exe_in_cargo_target(Path::new(
+            "/src/fresh/target/release/fresh"
+        )));
+        assert!(!exe_in_cargo_target(Path::new("/home/u/bin/fresh")));
+        assert!(!exe_in_cargo_target(Path::new("/opt/release/fresh")));
+    }
+
     #[test]
     fn extract_zip_finds_binary() {
         let mut buf = Vec::new();
@@ -247,6 +247,10 @@ pub struct EditorState {
     /// but navigation, selection, and copy are still allowed
     pub editing_disabled: bool,
 
+    /// Byte ranges plugins marked read-only; user edits touching them are
+    /// refused (see `crate::model::read_only_regions`)
+    pub read_only_regions: crate::model::read_only_regions::ReadOnlyRegions,
+
     /// Whether this buffer can be scrolled (default true). Fixed buffer-group
     /// panels (toolbars, headers, footers) set this to false so the mouse
     /// wheel is ignored and no scrollbar is drawn.
@@ -297,6 +301,13 @@ pub struct EditorState {
     /// (issue #1571).
     pub folding_ranges: LspFoldRanges,
 
+    /// The inserted snippet whose tab stops Tab / Shift+Tab are walking,
+    /// if any.
+    pub snippet_session: Option<crate::model::snippet_session::SnippetSession>,
+
+    /// Selections Expand Selection To Node grew from, for Shrink Selection.
+    pub selection_history: crate::model::selection_history::SelectionHistory,
+
     /// The detected language ID for this buffer (e.g., "rust", "csharp", "text").
     /// Used for LSP config lookup and internal identification.
     pub language: String,
@@ -402,6 +413,7 @@ impl EditorState {
             pending_virtual_lines: Vec::new(),
             cursor_visibility_locked: false,
             editing_disabled: false,
+            read_only_regions: crate::model::read_only_regions::ReadOnlyRegions::new(),
             scrollable: true,
             indentation_guide_override: None,
             interactive_widget_panel: false,
@@ -413,6 +425,8 @@ impl EditorState {
             bracket_highlight_overlay: BracketHighlightOverlay::new(),
             semantic_tokens: None,
             folding_ranges: LspFoldRanges::new(),
+            snippet_session: None,
+            selection_history: Default::default(),
             language: "text".to_string(),
             display_name: "Text".to_string(),
             line_wrap_cache: crate::view::line_wrap_cache::LineWrapCache::default(),
@@ -495,8 +509,14 @@ impl EditorState {
     ) -> anyhow::Result<Self> {
         let buffer = Buffer::load_from_file(path, large_file_threshold, fs)?;
         let first_line = buffer.first_line_lossy();
-        let detected =
-            DetectedLanguage::from_path(path, first_line.as_deref(), registry, languages);
+        let modeline_region = buffer.modeline_region_lossy();
+        let detected = DetectedLanguage::from_path(
+            path,
+            first_line.as_deref(),
+            modeline_region.as_deref(),
+            registry,
+            languages,
+        );
         let mut state = Self::new_from_buffer(buffer);
         state.apply_language(detected);
         Ok(state)
@@ -520,8 +540,14 @@ impl EditorState {
     ) -> anyhow::Result<Self> {
         let buffer = Buffer::load_from_file_force_text(path, large_file_threshold, fs)?;
         let first_line = buffer.first_line_lossy();
-        let detected =
-            DetectedLanguage::from_path(path, first_line.as_deref(), registry, languages);
+        let modeline_region = buffer.modeline_region_lossy();
+        let detected = DetectedLanguage::from_path(
+            path,
+            first_line.as_deref(),
+            modeline_region.as_deref(),
+            registry,
+            languages,
+        );
         let mut state = Self::new_from_buffer(buffer);
         state.apply_language(detected);
         Ok(state)
@@ -753,7 +779,11 @@ impl EditorState {
 
             // View events (Scroll, SetViewport, Recenter) are now handled at Editor level
             // via SplitViewState. They should not reach EditorState.apply().
-            Event::Scroll { .. } | Event::SetViewport { .. } | Event::Recenter => {
+            Event::Scroll { .. }
+            | Event::SetViewport { .. }
+            | Event::Recenter
+            | Event::ScrollCursorToTop
+            | Event::ScrollCursorToBottom => {
                 // These events are intercepted in Editor::apply_event_to_active_buffer
                 // and routed to SplitViewState. If we get here, something is wrong.
                 tracing::warn!("View event {:?} reached EditorState.apply() - should be handled by SplitViewState", event);
@@ -1188,7 +1218,9 @@ impl EditorState {
         }
     }
 
-    /// Capture positions of markers strictly inside a deleted range.
+    /// Capture positions of markers inside a deleted range, including its
+    /// start: re-inserting the text on undo pushes a marker sitting at the
+    /// start to the end of the insert, so it has to be put back as well.
     /// Call this BEFORE applying the delete. Returns encoded displaced markers.
     pub fn capture_displaced_markers(&self, range: &Range<usize>) -> Vec<(u64, usize)> {
         let mut displaced = Vec::new();
@@ -1196,7 +1228,7 @@ impl EditorState {
             return displaced;
         }
         for (marker_id, start, _end) in self.marker_list.query_range(range.start, range.end) {
-            if start > range.start && start < range.end {
+            if range.contains(&start) {
                 displaced.push(
                     DisplacedMarker::Main {
                         id: marker_id.0,
@@ -1207,7 +1239,7 @@ impl EditorState {
             }
         }
         for (marker_id, start, _end) in self.margins.query_indicator_range(range.start, range.end) {
-            if start > range.start && start < range.end {
+            if range.contains(&start) {
                 displaced.push(
                     DisplacedMarker::Margin {
                         id: marker_id.0,
@@ -1385,6 +1417,7 @@ pub(crate) fn convert_popup_data_to_popup(
                     icon: item.icon.clone(),
                     data: item.data.clone(),
                     disabled: false,
+                    match_positions: item.match_positions.clone(),
                 })
                 .collect(),
             selected: *selected,
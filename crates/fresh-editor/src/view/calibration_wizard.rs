@@ -3,8 +3,9 @@
 //! Renders the input calibration wizard modal overlay.
 
 use crate::app::calibration_wizard::{
-    CalibrationStep, CalibrationWizard, KeyStatus, PendingConfirmation,
+    CalibrationStep, CalibrationWizard, DiagnosticLog, KeyStatus, PendingConfirmation,
 };
+use crate::services::terminal_modes::KeyboardProtocol;
 use crate::view::theme::Theme;
 use ratatui::{
     layout::{Constraint, Layout, Rect},
@@ -54,6 +55,11 @@ pub fn render_calibration_wizard(
         return;
     }
 
+    if let Some(log) = &wizard.diagnostic {
+        render_diagnostic(frame, dialog_area, wizard, log, theme);
+        return;
+    }
+
     // Create the outer block
     let title = match &wizard.step {
         CalibrationStep::Capture { .. } => t!("calibration.title_capture").to_string(),
@@ -62,6 +68,7 @@ pub fn render_calibration_wizard(
 
     let block = Block::default()
         .title(title)
+        .title_bottom(keyboard_protocol_line(wizard, theme))
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.editor_fg))
         .style(Style::default().bg(theme.editor_bg).fg(theme.editor_fg));
@@ -88,6 +95,26 @@ pub fn render_calibration_wizard(
     }
 }
 
+/// Whether the terminal answered the kitty keyboard protocol query. Without
+/// it, Ctrl+Shift+letters, Super and key release can't be told apart.
+fn keyboard_protocol_line(wizard: &CalibrationWizard, theme: &Theme) -> Line<'static> {
+    let (text, fg) = match wizard.keyboard_protocol {
+        KeyboardProtocol::Active(flags) => (
+            t!("calibration.protocol_active", flags = flags.bits()).to_string(),
+            theme.diagnostic_info_fg,
+        ),
+        KeyboardProtocol::Unsupported => (
+            t!("calibration.protocol_unsupported").to_string(),
+            theme.diagnostic_warning_fg,
+        ),
+        KeyboardProtocol::Unknown => (
+            t!("calibration.protocol_unknown").to_string(),
+            theme.line_number_fg,
+        ),
+    };
+    Line::from(Span::styled(text, Style::default().fg(fg)))
+}
+
 /// Render confirmation dialog for destructive actions
 fn render_confirmation_dialog(
     frame: &mut Frame,
@@ -148,6 +175,75 @@ fn render_confirmation_dialog(
     frame.render_widget(para, inner_area);
 }
 
+/// Render the event inspector: the most recent raw events, newest last
+fn render_diagnostic(
+    frame: &mut Frame,
+    area: Rect,
+    wizard: &CalibrationWizard,
+    log: &DiagnosticLog,
+    theme: &Theme,
+) {
+    let block = Block::default()
+        .title(t!("calibration.title_diagnostic").to_string())
+        .title_bottom(keyboard_protocol_line(wizard, theme))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.editor_fg))
+        .style(Style::default().bg(theme.editor_bg).fg(theme.editor_fg));
+
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let chunks = Layout::vertical([
+        Constraint::Length(3), // Instructions
+        Constraint::Min(4),    // Event log
+        Constraint::Length(3), // Controls/status
+    ])
+    .split(inner_area);
+
+    let instructions = Paragraph::new(t!("calibration.diagnostic_instructions").to_string())
+        .style(Style::default().fg(theme.editor_fg))
+        .wrap(Wrap { trim: true });
+    frame.render_widget(instructions, chunks[0]);
+
+    let mut event_lines: Vec<Line> = Vec::new();
+    for entry in &log.entries {
+        event_lines.push(Line::from(Span::styled(
+            entry.event.clone(),
+            Style::default().fg(theme.help_key_fg),
+        )));
+        if let Some(effect) = &entry.effect {
+            event_lines.push(Line::from(Span::styled(
+                format!("  → {}", effect),
+                Style::default().fg(theme.diagnostic_info_fg),
+            )));
+        }
+    }
+    if event_lines.is_empty() {
+        event_lines.push(Line::from(Span::styled(
+            t!("calibration.diagnostic_empty").to_string(),
+            Style::default().fg(theme.line_number_fg),
+        )));
+    }
+    // Keep the newest events in view
+    let visible = chunks[1].height as usize;
+    let skip = event_lines.len().saturating_sub(visible);
+    let log_para =
+        Paragraph::new(event_lines.split_off(skip)).style(Style::default().fg(theme.editor_fg));
+    frame.render_widget(log_para, chunks[1]);
+
+    let controls = vec![
+        Line::from(vec![
+            Span::styled("[Esc Esc]", Style::default().fg(theme.help_key_fg)),
+            Span::raw(format!(" {}", t!("calibration.diagnostic_exit"))),
+        ]),
+        Line::from(""),
+        Line::from(wizard.status_message.as_deref().unwrap_or("")),
+    ];
+
+    let controls_para = Paragraph::new(controls).style(Style::default().fg(theme.editor_fg));
+    frame.render_widget(controls_para, chunks[2]);
+}
+
 /// Render the capture phase UI
 fn render_capture_phase(
     frame: &mut Frame,
@@ -166,7 +262,7 @@ fn render_capture_phase(
     let instructions = vec![
         Line::from(vec![
             Span::raw(format!("{}: ", t!("calibration.group"))),
-            Span::styled(group.name, Style::default().fg(theme.help_key_fg)),
+            Span::styled(group.name.as_ref(), Style::default().fg(theme.help_key_fg)),
         ]),
         Line::from(""),
         Line::from(vec![Span::styled(
@@ -218,7 +314,7 @@ fn render_capture_phase(
 
         progress_lines.push(Line::from(vec![
             Span::styled(format!(" {} ", status_char), style),
-            Span::styled(t.name, style),
+            Span::styled(t.name.as_ref(), style),
         ]));
     }
 
@@ -252,6 +348,8 @@ fn render_capture_phase(
             Span::raw(format!(" {} ", t!("calibration.back"))),
             Span::styled("[g]", Style::default().fg(theme.help_key_fg)),
             Span::raw(format!(" {} ", t!("calibration.skip_group"))),
+            Span::styled("[i]", Style::default().fg(theme.help_key_fg)),
+            Span::raw(format!(" {} ", t!("calibration.inspect"))),
             Span::styled("[a]", Style::default().fg(theme.diagnostic_error_fg)),
             Span::raw(format!(" {}", t!("calibration.abort"))),
         ]),
@@ -323,7 +421,7 @@ fn render_verify_phase(
             };
             status_lines.push(Line::from(vec![
                 Span::styled(format!("[{}] ", status_char), style),
-                Span::styled(target.name, style),
+                Span::styled(target.name.as_ref(), style),
             ]));
         }
     }
@@ -340,6 +438,8 @@ fn render_verify_phase(
             Span::raw(format!(" {} ", t!("calibration.back"))),
             Span::styled("[r]", Style::default().fg(theme.diagnostic_warning_fg)),
             Span::raw(format!(" {} ", t!("calibration.restart"))),
+            Span::styled("[i]", Style::default().fg(theme.help_key_fg)),
+            Span::raw(format!(" {} ", t!("calibration.inspect"))),
             Span::styled("[a]", Style::default().fg(theme.diagnostic_error_fg)),
             Span::raw(format!(" {}", t!("calibration.abort"))),
         ]),
@@ -387,6 +487,8 @@ fn render_all_keys_ok(
         Line::from(vec![
             Span::styled("[y]", Style::default().fg(theme.diagnostic_info_fg)),
             Span::raw(format!(" {} ", t!("calibration.save"))),
+            Span::styled("[i]", Style::default().fg(theme.help_key_fg)),
+            Span::raw(format!(" {} ", t!("calibration.inspect"))),
             Span::styled("[a]", Style::default().fg(theme.diagnostic_error_fg)),
             Span::raw(format!(" {}", t!("calibration.abort"))),
         ]),
@@ -0,0 +1,144 @@
+//! Chord hint popup rendering.
+//!
+//! While a multi-key chord is pending, a bordered panel along the bottom of
+//! the editor area lists the keys that can come next and what each one
+//! runs, laid out in as many columns as fit (which-key style).
+
+use crate::primitives::display_width::{char_width, str_width};
+use crate::view::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use rust_i18n::t;
+
+/// One continuation of the pending chord.
+pub struct ChordHint {
+    /// The keys still to press, e.g. "Ctrl+S".
+    pub keys: String,
+    /// What those keys run.
+    pub description: String,
+}
+
+/// Gap between a hint's keys and its description.
+const KEY_GAP: &str = " → ";
+/// Blank columns between two hint columns.
+const COLUMN_GAP: usize = 3;
+
+/// Render the hints for the chord `prefix` at the bottom of `area`, using at
+/// most half of its height. Hints that don't fit are summed up in a last
+/// "+N more" cell.
+pub fn render_chord_hints(
+    frame: &mut Frame,
+    area: Rect,
+    prefix: &str,
+    hints: &[ChordHint],
+    theme: &Theme,
+) {
+    if hints.is_empty() || area.width < 10 || area.height < 4 {
+        return;
+    }
+
+    let inner_width = area.width.saturating_sub(2) as usize;
+    let key_width = hints.iter().map(|h| str_width(&h.keys)).max().unwrap_or(0);
+    let cell_width = hints
+        .iter()
+        .map(|h| key_width + str_width(KEY_GAP) + str_width(&h.description))
+        .max()
+        .unwrap_or(0)
+        .min(inner_width);
+    let columns = ((inner_width + COLUMN_GAP) / (cell_width + COLUMN_GAP)).max(1);
+    let max_rows = (area.height / 2).saturating_sub(2).max(1) as usize;
+    let needed_rows = hints.len().div_ceil(columns);
+    let rows = needed_rows.min(max_rows);
+    // Keep one cell for the overflow note when not everything fits.
+    let shown = if needed_rows > rows {
+        (rows * columns).saturating_sub(1)
+    } else {
+        hints.len()
+    };
+
+    let text_style = Style::default().fg(theme.popup_text_fg).bg(theme.popup_bg);
+    let key_style = Style::default().fg(theme.help_key_fg).bg(theme.popup_bg);
+    let gap_style = Style::default()
+        .fg(theme.help_separator_fg)
+        .bg(theme.popup_bg);
+
+    // Fill column-major, so the sorted hints read top to bottom.
+    let mut lines: Vec<Vec<Span>> = vec![Vec::new(); rows];
+    for (i, hint) in hints.iter().take(shown).enumerate() {
+        let (column, row) = (i / rows, i % rows);
+        let line = &mut lines[row];
+        if column > 0 {
+            line.push(Span::styled(" ".repeat(COLUMN_GAP), text_style));
+        }
+        let key_pad = key_width.saturating_sub(str_width(&hint.keys));
+        let description = truncate(
+            &hint.description,
+            cell_width.saturating_sub(key_width + str_width(KEY_GAP)),
+        );
+        let pad =
+            cell_width.saturating_sub(key_width + str_width(KEY_GAP) + str_width(&description));
+        line.push(Span::styled(
+            format!("{}{}", " ".repeat(key_pad), hint.keys),
+            key_style,
+        ));
+        line.push(Span::styled(KEY_GAP, gap_style));
+        line.push(Span::styled(
+            format!("{}{}", description, " ".repeat(pad)),
+            text_style,
+        ));
+    }
+    if shown < hints.len() {
+        let more = t!("chord.more", count = hints.len() - shown).to_string();
+        let (column, row) = (shown / rows, shown % rows);
+        if column > 0 {
+            lines[row].push(Span::styled(" ".repeat(COLUMN_GAP), text_style));
+        }
+        lines[row].push(Span::styled(more, gap_style));
+    }
+
+    let height = rows as u16 + 2;
+    let rect = Rect {
+        x: area.x,
+        y: area.y + area.height - height,
+        width: area.width,
+        height,
+    };
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(
+            Style::default()
+                .fg(theme.popup_border_fg)
+                .bg(theme.popup_bg),
+        )
+        .style(text_style)
+        .title(format!(" {} ", prefix));
+    frame.render_widget(Clear, rect);
+    frame.render_widget(
+        Paragraph::new(lines.into_iter().map(Line::from).collect::<Vec<_>>()).block(block),
+        rect,
+    );
+}
+
+/// Cut `text` to `width` columns, marking the cut with an ellipsis.
+fn truncate(text: &str, width: usize) -> String {
+    if str_width(text) <= width {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    let mut used = 0;
+    for c in text.chars() {
+        let w = char_width(c);
+        if used + w + 1 > width {
+            break;
+        }
+        out.push(c);
+        used += w;
+    }
+    out.push('…');
+    out
+}
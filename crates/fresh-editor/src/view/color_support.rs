@@ -500,6 +500,7 @@ pub fn convert_buffer_colors(buffer: &mut ratatui::buffer::Buffer, capability: C
     for cell in buffer.content.iter_mut() {
         cell.fg = convert_color(cell.fg, capability);
         cell.bg = convert_color(cell.bg, capability);
+        cell.underline_color = convert_color(cell.underline_color, capability);
     }
 
     // Enforce minimum contrast for 256-color mode
@@ -508,6 +509,17 @@ pub fn convert_buffer_colors(buffer: &mut ratatui::buffer::Buffer, capability: C
     }
 }
 
+/// Reduce styled underlines to plain ones for terminals that can't render
+/// them, so SGR 58 and `4:n` never reach a terminal that might misread
+/// their parameters
+pub fn strip_styled_underlines(buffer: &mut ratatui::buffer::Buffer) {
+    let styles = crate::view::overlay::UnderlineStyle::MODIFIER_MASK;
+    for cell in buffer.content.iter_mut() {
+        cell.underline_color = Color::Reset;
+        cell.modifier.remove(styles);
+    }
+}
+
 /// Post-conversion pass: ensure every fg/bg pair in the buffer has sufficient
 /// WCAG contrast ratio. Adjusts fg colors when contrast is too low.
 fn enforce_minimum_contrast(buffer: &mut ratatui::buffer::Buffer) {
@@ -9,6 +9,9 @@ use crate::model::marker::{MarkerId, MarkerList};
 /// A collapsed fold range tracked by markers.
 #[derive(Debug, Clone)]
 pub struct FoldRange {
+    /// Marker on the header line, used to notice when an edit deletes the
+    /// header or splits it from the hidden lines so the fold can be expanded.
+    header_marker: MarkerId,
     /// Marker at the first hidden byte (start of line after header)
     start_marker: MarkerId,
     /// Marker at the end of the hidden range (start of line after fold end)
@@ -17,6 +20,55 @@ pub struct FoldRange {
     placeholder: Option<String>,
 }
 
+impl FoldRange {
+    fn delete_markers(&self, marker_list: &mut MarkerList) {
+        marker_list.delete(self.header_marker);
+        marker_list.delete(self.start_marker);
+        marker_list.delete(self.end_marker);
+    }
+
+    /// Resolve to line/byte info, or `None` if edits have invalidated the
+    /// range (see [`FoldManager::prune_stale`]).
+    fn resolve(&self, buffer: &Buffer, marker_list: &MarkerList) -> Option<ResolvedFoldRange> {
+        let header_marker_byte = marker_list.get_position(self.header_marker)?;
+        let start_byte = marker_list.get_position(self.start_marker)?;
+        let end_byte = marker_list.get_position(self.end_marker)?;
+        if end_byte <= start_byte || start_byte == 0 {
+            return None;
+        }
+
+        // The hidden range must start a line, and that line must sit directly
+        // below the header line holding the header marker. Compared by bytes
+        // because line numbers are only estimates in large-file mode.
+        let header_byte = indent_folding::find_line_start_byte(buffer, start_byte - 1);
+        if header_marker_byte >= start_byte
+            || indent_folding::find_line_start_byte(buffer, start_byte) != start_byte
+            || indent_folding::find_line_start_byte(buffer, header_marker_byte) != header_byte
+        {
+            return None;
+        }
+
+        let start_line = buffer.get_line_number(start_byte);
+        if start_line == 0 {
+            return None;
+        }
+        let end_line = buffer.get_line_number(end_byte.saturating_sub(1));
+        if end_line < start_line {
+            return None;
+        }
+
+        Some(ResolvedFoldRange {
+            header_line: start_line - 1,
+            start_line,
+            end_line,
+            start_byte,
+            end_byte,
+            header_byte,
+            placeholder: self.placeholder.clone(),
+        })
+    }
+}
+
 /// A resolved fold range with computed line/byte info.
 #[derive(Debug, Clone)]
 pub struct ResolvedFoldRange {
@@ -68,21 +120,30 @@ impl FoldManager {
     }
 
     /// Add a collapsed fold range.
+    ///
+    /// The hidden-range markers are left-gravity: text inserted at the first
+    /// hidden byte stays hidden, while text inserted at the start of the line
+    /// after the fold stays visible. The header marker moves with text typed
+    /// at the start of the header line.
     pub fn add(
         &mut self,
+        buffer: &Buffer,
         marker_list: &mut MarkerList,
         start: usize,
         end: usize,
         placeholder: Option<String>,
     ) {
-        if end <= start {
+        if end <= start || start == 0 {
             return;
         }
 
-        let start_marker = marker_list.create(start, true); // left affinity
-        let end_marker = marker_list.create(end, false); // right affinity
+        let header_byte = indent_folding::find_line_start_byte(buffer, start - 1);
+        let header_marker = marker_list.create(header_byte, false);
+        let start_marker = marker_list.create_left_gravity(start);
+        let end_marker = marker_list.create_left_gravity(end);
 
         self.ranges.push(FoldRange {
+            header_marker,
             start_marker,
             end_marker,
             placeholder,
@@ -92,12 +153,68 @@ impl FoldManager {
     /// Remove all fold ranges and their markers.
     pub fn clear(&mut self, marker_list: &mut MarkerList) {
         for range in &self.ranges {
-            marker_list.delete(range.start_marker);
-            marker_list.delete(range.end_marker);
+            range.delete_markers(marker_list);
         }
         self.ranges.clear();
     }
 
+    /// Expand (remove) every fold that an edit of `start..end` would change
+    /// without removing it outright. An insertion is `start == end`.
+    ///
+    /// Called for the view that originates an edit, before the edit is
+    /// applied, so hidden text is never modified out of sight. An edit that
+    /// covers the whole hidden range (e.g. deleting a selection that spans
+    /// the fold) leaves the fold alone; [`Self::prune_stale`] drops it once
+    /// its range has collapsed. Returns true if a fold was removed.
+    pub fn expand_touched_by_edit(
+        &mut self,
+        marker_list: &mut MarkerList,
+        start: usize,
+        end: usize,
+    ) -> bool {
+        let mut removed = false;
+        self.ranges.retain(|range| {
+            let (Some(fold_start), Some(fold_end)) = (
+                marker_list.get_position(range.start_marker),
+                marker_list.get_position(range.end_marker),
+            ) else {
+                return true;
+            };
+            let touches = if start == end {
+                fold_start <= start && start < fold_end
+            } else {
+                start < fold_end && end > fold_start
+            };
+            let covers = start < end && start <= fold_start && end >= fold_end;
+            if touches && !covers {
+                range.delete_markers(marker_list);
+                removed = true;
+                false
+            } else {
+                true
+            }
+        });
+        removed
+    }
+
+    /// Drop folds that edits have left inconsistent: the hidden range
+    /// collapsed, or the header line was deleted, joined with a neighbour or
+    /// split so it no longer sits directly above the hidden lines.
+    /// Returns true if a fold was removed.
+    pub fn prune_stale(&mut self, buffer: &Buffer, marker_list: &mut MarkerList) -> bool {
+        let mut removed = false;
+        self.ranges.retain(|range| {
+            if range.resolve(buffer, marker_list).is_some() {
+                true
+            } else {
+                range.delete_markers(marker_list);
+                removed = true;
+                false
+            }
+        });
+        removed
+    }
+
     /// Remove any fold that contains the given byte position.
     /// Returns true if a fold was removed.
     pub fn remove_if_contains_byte(&mut self, marker_list: &mut MarkerList, byte: usize) -> bool {
@@ -111,16 +228,15 @@ impl FoldManager {
                 return true;
             };
             if start_byte <= byte && byte < end_byte {
-                to_delete.push((range.start_marker, range.end_marker));
+                to_delete.push(range.clone());
                 false
             } else {
                 true
             }
         });
 
-        for (start, end) in &to_delete {
-            marker_list.delete(*start);
-            marker_list.delete(*end);
+        for range in &to_delete {
+            range.delete_markers(marker_list);
         }
 
         !to_delete.is_empty()
@@ -132,43 +248,10 @@ impl FoldManager {
         buffer: &Buffer,
         marker_list: &MarkerList,
     ) -> Vec<ResolvedFoldRange> {
-        let mut ranges = Vec::new();
-
-        for range in &self.ranges {
-            let Some(start_byte) = marker_list.get_position(range.start_marker) else {
-                continue;
-            };
-            let Some(end_byte) = marker_list.get_position(range.end_marker) else {
-                continue;
-            };
-            if end_byte <= start_byte {
-                continue;
-            }
-
-            let start_line = buffer.get_line_number(start_byte);
-            if start_line == 0 {
-                continue;
-            }
-            let end_line = buffer.get_line_number(end_byte.saturating_sub(1));
-            if end_line < start_line {
-                continue;
-            }
-
-            let header_byte =
-                indent_folding::find_line_start_byte(buffer, start_byte.saturating_sub(1));
-
-            ranges.push(ResolvedFoldRange {
-                header_line: start_line - 1,
-                start_line,
-                end_line,
-                start_byte,
-                end_byte,
-                header_byte,
-                placeholder: range.placeholder.clone(),
-            });
-        }
-
-        ranges
+        self.ranges
+            .iter()
+            .filter_map(|range| range.resolve(buffer, marker_list))
+            .collect()
     }
 
     /// Return a map of header_byte -> placeholder for collapsed folds.
@@ -201,16 +284,15 @@ impl FoldManager {
             let current_header =
                 indent_folding::find_line_start_byte(buffer, start_byte.saturating_sub(1));
             if current_header == target_header_byte {
-                to_delete.push((range.start_marker, range.end_marker));
+                to_delete.push(range.clone());
                 false
             } else {
                 true
             }
         });
 
-        for (start, end) in &to_delete {
-            marker_list.delete(*start);
-            marker_list.delete(*end);
+        for range in &to_delete {
+            range.delete_markers(marker_list);
         }
 
         !to_delete.is_empty()
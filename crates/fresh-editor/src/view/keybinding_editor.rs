@@ -3,10 +3,11 @@
 //! Renders the keybinding editor modal and handles input events.
 
 use crate::app::keybinding_editor::{
-    BindingSource, ContextFilter, DeleteResult, DisplayRow, EditMode, KeybindingEditor, SearchMode,
-    SourceFilter,
+    BindingSource, ContextFilter, DeleteResult, DisplayRow, EditMode, KeybindingEditor,
+    KeymapDiffKind, KeymapFileAction, SearchMode, SourceFilter,
 };
 use crate::input::keybindings::{format_keybinding, normalize_key, KeybindingResolver};
+use crate::primitives::display_width::str_width;
 use crate::view::dimming::apply_dimming;
 use crate::view::theme::Theme;
 use crate::view::ui::scrollbar::{render_scrollbar, ScrollbarColors};
@@ -94,6 +95,7 @@ pub fn render_keybinding_editor(
     });
     // Reset dialog layouts (will be set if dialogs are rendered)
     editor.layout.dialog_buttons = None;
+    editor.layout.dialog_conflict_buttons = None;
     editor.layout.dialog_key_field = None;
     editor.layout.dialog_action_field = None;
     editor.layout.dialog_context_field = None;
@@ -116,6 +118,16 @@ pub fn render_keybinding_editor(
         editor.edit_dialog = Some(dialog);
     }
 
+    if editor.showing_diff {
+        apply_dimming(frame, modal_area);
+        render_diff_overlay(frame, inner, editor, theme);
+    }
+
+    if editor.file_dialog.is_some() {
+        apply_dimming(frame, modal_area);
+        render_file_dialog(frame, inner, editor, theme);
+    }
+
     if editor.showing_confirm_dialog {
         apply_dimming(frame, modal_area);
         render_confirm_dialog(frame, inner, editor, theme);
@@ -504,8 +516,7 @@ fn render_table(frame: &mut Frame, area: Rect, editor: &mut KeybindingEditor, th
                                     t!("keybinding_editor.source_keymap").to_string()
                                 }
                                 BindingSource::Plugin => {
-                                    t!("keybinding_editor.source_plugin", default = "Plugin")
-                                        .to_string()
+                                    t!("keybinding_editor.source_plugin").to_string()
                                 }
                                 BindingSource::Unbound => String::new(),
                             },
@@ -620,7 +631,7 @@ fn render_footer(frame: &mut Frame, area: Rect, editor: &KeybindingEditor, theme
 /// Render the help overlay
 fn render_help_overlay(frame: &mut Frame, area: Rect, theme: &Theme) {
     let width = 52u16.min(area.width.saturating_sub(4));
-    let height = 22u16.min(area.height.saturating_sub(4));
+    let height = 25u16.min(area.height.saturating_sub(4));
     let x = area.x + (area.width.saturating_sub(width)) / 2;
     let y = area.y + (area.height.saturating_sub(height)) / 2;
 
@@ -653,6 +664,9 @@ fn render_help_overlay(frame: &mut Frame, area: Rect, theme: &Theme) {
     let h_edit = t!("keybinding_editor.help_edit_binding").to_string();
     let h_add = t!("keybinding_editor.help_add_binding").to_string();
     let h_del = t!("keybinding_editor.help_delete_binding").to_string();
+    let h_diff = t!("keybinding_editor.help_view_diff").to_string();
+    let h_export = t!("keybinding_editor.help_export").to_string();
+    let h_import = t!("keybinding_editor.help_import").to_string();
     let h_filters = t!("keybinding_editor.help_filters").to_string();
     let h_ctx = t!("keybinding_editor.help_cycle_context").to_string();
     let h_src = t!("keybinding_editor.help_cycle_source").to_string();
@@ -675,6 +689,9 @@ fn render_help_overlay(frame: &mut Frame, area: Rect, theme: &Theme) {
         help_line("  Enter", &h_edit, theme, false),
         help_line("  a", &h_add, theme, false),
         help_line("  d / Delete", &h_del, theme, false),
+        help_line("  v", &h_diff, theme, false),
+        help_line("  x", &h_export, theme, false),
+        help_line("  i", &h_import, theme, false),
         help_line("", "", theme, false),
         help_line(&h_filters, "", theme, true),
         help_line("  c", &h_ctx, theme, false),
@@ -1003,31 +1020,51 @@ fn render_edit_dialog(
     let save_text = format!(" {} ", t!("keybinding_editor.btn_save"));
     let cancel_text = format!(" {} ", t!("keybinding_editor.btn_cancel"));
     let save_x = chunks[8].x + 3;
-    let cancel_x = save_x + save_text.len() as u16 + 2;
+    let cancel_x = save_x + str_width(&save_text) as u16 + 2;
+    let button_rect = |x: u16, text: &str| Rect {
+        x,
+        y: chunks[8].y,
+        width: str_width(text) as u16,
+        height: 1,
+    };
     editor.layout.dialog_buttons = Some((
-        Rect {
-            x: save_x,
-            y: chunks[8].y,
-            width: save_text.len() as u16,
-            height: 1,
-        },
-        Rect {
-            x: cancel_x,
-            y: chunks[8].y,
-            width: cancel_text.len() as u16,
-            height: 1,
-        },
+        button_rect(save_x, &save_text),
+        button_rect(cancel_x, &cancel_text),
     ));
 
-    frame.render_widget(
-        Paragraph::new(Line::from(vec![
-            Span::raw("   "),
-            Span::styled(save_text, save_style),
-            Span::raw("  "),
-            Span::styled(cancel_text, cancel_style),
-        ])),
-        chunks[8],
-    );
+    let mut button_spans = vec![
+        Span::raw("   "),
+        Span::styled(save_text.clone(), save_style),
+        Span::raw("  "),
+        Span::styled(cancel_text.clone(), cancel_style),
+    ];
+    if dialog.button_count() > 2 {
+        // Conflict resolution buttons, only offered while there are conflicts
+        let button_style = |index: usize| {
+            if btn_focused && dialog.selected_button == index {
+                Style::default()
+                    .fg(theme.popup_bg)
+                    .bg(theme.diagnostic_warning_fg)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.diagnostic_warning_fg)
+            }
+        };
+        let unbind_text = format!(" {} ", t!("keybinding_editor.btn_unbind_others"));
+        let rebind_text = format!(" {} ", t!("keybinding_editor.btn_rebind_other"));
+        let unbind_x = cancel_x + str_width(&cancel_text) as u16 + 2;
+        let rebind_x = unbind_x + str_width(&unbind_text) as u16 + 2;
+        editor.layout.dialog_conflict_buttons = Some((
+            button_rect(unbind_x, &unbind_text),
+            button_rect(rebind_x, &rebind_text),
+        ));
+        button_spans.push(Span::raw("  "));
+        button_spans.push(Span::styled(unbind_text, button_style(2)));
+        button_spans.push(Span::raw("  "));
+        button_spans.push(Span::styled(rebind_text, button_style(3)));
+    }
+
+    frame.render_widget(Paragraph::new(Line::from(button_spans)), chunks[8]);
 
     // Render autocomplete popup on top of everything if visible
     if dialog.autocomplete_visible && !dialog.autocomplete_suggestions.is_empty() {
@@ -1108,6 +1145,179 @@ fn render_autocomplete_popup(
     frame.render_widget(Paragraph::new(lines), inner);
 }
 
+/// Render the diff between the current bindings and the built-in keymap
+fn render_diff_overlay(
+    frame: &mut Frame,
+    area: Rect,
+    editor: &mut KeybindingEditor,
+    theme: &Theme,
+) {
+    let width = 76u16.min(area.width.saturating_sub(4));
+    let height = area.height.saturating_sub(4);
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+
+    let dialog_area = Rect {
+        x,
+        y,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, dialog_area);
+
+    let block = Block::default()
+        .title(format!(
+            " {} ",
+            t!(
+                "keybinding_editor.diff_title",
+                keymap = &editor.active_keymap
+            )
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.popup_border_fg))
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_text_fg));
+    let inner = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let entries = editor.keymap_diff();
+    if entries.is_empty() {
+        frame.render_widget(
+            Paragraph::new(Line::from(Span::styled(
+                format!(" {}", t!("keybinding_editor.diff_empty")),
+                Style::default().fg(theme.popup_text_fg),
+            ))),
+            inner,
+        );
+        return;
+    }
+
+    let visible = inner.height as usize;
+    editor.diff_scroll = editor
+        .diff_scroll
+        .min(entries.len().saturating_sub(visible));
+    let was = t!("keybinding_editor.diff_was").to_string();
+    let lines: Vec<Line> = entries
+        .iter()
+        .skip(editor.diff_scroll)
+        .take(visible)
+        .map(|entry| {
+            let (marker, color) = match entry.kind {
+                KeymapDiffKind::Added => ("+", theme.help_key_fg),
+                KeymapDiffKind::Changed => ("~", theme.diagnostic_warning_fg),
+                KeymapDiffKind::Removed => ("-", theme.diagnostic_error_fg),
+            };
+            let mut spans = vec![
+                Span::styled(
+                    format!(" {} ", marker),
+                    Style::default().fg(color).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    pad_right(&entry.key_display, 18),
+                    Style::default().fg(theme.help_key_fg),
+                ),
+                Span::styled(
+                    pad_right(&entry.context, 14),
+                    Style::default().fg(theme.popup_text_fg),
+                ),
+                Span::styled(
+                    entry.action_display.clone(),
+                    Style::default().fg(theme.popup_text_fg),
+                ),
+            ];
+            if let Some(ref default) = entry.default_action_display {
+                spans.push(Span::styled(
+                    format!(
+                        "{}({} {})",
+                        if entry.action_display.is_empty() {
+                            ""
+                        } else {
+                            "  "
+                        },
+                        was,
+                        default
+                    ),
+                    Style::default()
+                        .fg(theme.popup_text_fg)
+                        .add_modifier(Modifier::ITALIC),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Render the export/import path dialog
+fn render_file_dialog(frame: &mut Frame, area: Rect, editor: &KeybindingEditor, theme: &Theme) {
+    let Some(ref dialog) = editor.file_dialog else {
+        return;
+    };
+    let width = 64u16.min(area.width.saturating_sub(4));
+    let height = 6u16.min(area.height.saturating_sub(4));
+    let x = area.x + (area.width.saturating_sub(width)) / 2;
+    let y = area.y + (area.height.saturating_sub(height)) / 2;
+
+    let dialog_area = Rect {
+        x,
+        y,
+        width,
+        height,
+    };
+    frame.render_widget(Clear, dialog_area);
+
+    let (title, hint) = match dialog.action {
+        KeymapFileAction::Export => (
+            t!("keybinding_editor.export_title"),
+            t!("keybinding_editor.export_hint"),
+        ),
+        KeymapFileAction::Import => (
+            t!("keybinding_editor.import_title"),
+            t!("keybinding_editor.import_hint"),
+        ),
+    };
+    let block = Block::default()
+        .title(format!(" {} ", title))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.popup_border_fg))
+        .style(Style::default().bg(theme.popup_bg).fg(theme.popup_text_fg));
+    let inner = block.inner(dialog_area);
+    frame.render_widget(block, dialog_area);
+
+    let label = format!(" {} ", t!("keybinding_editor.label_path"));
+    // Keep the end of a long path (and the cursor) in view
+    let room = (inner.width as usize).saturating_sub(str_width(&label) + 1);
+    let path_chars: Vec<char> = dialog.path.chars().collect();
+    let shown: String = path_chars[path_chars.len().saturating_sub(room)..]
+        .iter()
+        .collect();
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                label,
+                Style::default()
+                    .fg(theme.help_key_fg)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(shown, Style::default().fg(theme.popup_text_fg)),
+            Span::styled("_", Style::default().fg(theme.cursor)),
+        ]),
+        Line::from(""),
+    ];
+    if let Some(ref err) = dialog.error {
+        lines.push(Line::from(Span::styled(
+            format!(" \u{2717} {}", err),
+            Style::default().fg(theme.diagnostic_error_fg),
+        )));
+    } else {
+        lines.push(Line::from(Span::styled(
+            format!(" {}", hint),
+            Style::default().fg(theme.popup_text_fg),
+        )));
+    }
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
 /// Render the unsaved changes confirm dialog
 fn render_confirm_dialog(
     frame: &mut Frame,
@@ -1222,6 +1432,16 @@ pub fn handle_keybinding_editor_input(
         return handle_confirm_input(editor, event);
     }
 
+    // Diff against the built-in keymap
+    if editor.showing_diff {
+        return handle_diff_input(editor, event);
+    }
+
+    // Export/import path dialog
+    if editor.file_dialog.is_some() {
+        return handle_file_dialog_input(editor, event);
+    }
+
     // Edit dialog
     if editor.edit_dialog.is_some() {
         return handle_edit_dialog_input(editor, event);
@@ -1349,6 +1569,23 @@ fn handle_main_input(editor: &mut KeybindingEditor, event: &KeyEvent) -> Keybind
             }
         }
 
+        // Diff against the built-in keymap
+        (KeyCode::Char('v'), KeyModifiers::NONE) => {
+            editor.showing_diff = true;
+            editor.diff_scroll = 0;
+            KeybindingEditorAction::Consumed
+        }
+
+        // Export / import
+        (KeyCode::Char('x'), KeyModifiers::NONE) => {
+            editor.open_file_dialog(KeymapFileAction::Export);
+            KeybindingEditorAction::Consumed
+        }
+        (KeyCode::Char('i'), KeyModifiers::NONE) => {
+            editor.open_file_dialog(KeymapFileAction::Import);
+            KeybindingEditorAction::Consumed
+        }
+
         // Context filter
         (KeyCode::Char('c'), KeyModifiers::NONE) => {
             editor.cycle_context_filter();
@@ -1453,7 +1690,7 @@ fn handle_edit_dialog_input(
                 dialog.key_code = Some(norm_code);
                 dialog.modifiers = norm_mods;
                 dialog.key_display = format_keybinding(&norm_code, &norm_mods);
-                dialog.conflicts = editor.find_conflicts(norm_code, norm_mods, &dialog.context);
+                editor.refresh_conflicts(&mut dialog);
                 dialog.capturing_special = false;
             }
         }
@@ -1603,21 +1840,14 @@ fn handle_edit_dialog_input(
                 (KeyCode::Left, _) if dialog.context_option_index > 0 => {
                     dialog.context_option_index -= 1;
                     dialog.context = dialog.context_options[dialog.context_option_index].clone();
-                    // Update conflicts
-                    if let Some(key_code) = dialog.key_code {
-                        dialog.conflicts =
-                            editor.find_conflicts(key_code, dialog.modifiers, &dialog.context);
-                    }
+                    editor.refresh_conflicts(&mut dialog);
                 }
                 (KeyCode::Right, _)
                     if dialog.context_option_index + 1 < dialog.context_options.len() =>
                 {
                     dialog.context_option_index += 1;
                     dialog.context = dialog.context_options[dialog.context_option_index].clone();
-                    if let Some(key_code) = dialog.key_code {
-                        dialog.conflicts =
-                            editor.find_conflicts(key_code, dialog.modifiers, &dialog.context);
-                    }
+                    editor.refresh_conflicts(&mut dialog);
                 }
                 (KeyCode::Enter, _) => {
                     dialog.focus_area = 3;
@@ -1630,19 +1860,19 @@ fn handle_edit_dialog_input(
             // Buttons area
             match (event.code, event.modifiers) {
                 (KeyCode::Tab, KeyModifiers::NONE) => {
-                    if dialog.selected_button < 1 {
-                        // Move from Save to Cancel
-                        dialog.selected_button = 1;
+                    if dialog.selected_button + 1 < dialog.button_count() {
+                        // Move to the next button
+                        dialog.selected_button += 1;
                     } else {
-                        // Wrap from Cancel to Key field
+                        // Wrap from the last button to Key field
                         dialog.focus_area = 0;
                         dialog.mode = EditMode::RecordingKey;
                     }
                 }
                 (KeyCode::BackTab, _) => {
                     if dialog.selected_button > 0 {
-                        // Move from Cancel to Save
-                        dialog.selected_button = 0;
+                        // Move to the previous button
+                        dialog.selected_button -= 1;
                     } else {
                         // Wrap from Save to Context field
                         dialog.focus_area = 2;
@@ -1656,22 +1886,34 @@ fn handle_edit_dialog_input(
                 (KeyCode::Left, _) if dialog.selected_button > 0 => {
                     dialog.selected_button -= 1;
                 }
-                (KeyCode::Right, _) if dialog.selected_button < 1 => {
+                (KeyCode::Right, _) if dialog.selected_button + 1 < dialog.button_count() => {
                     dialog.selected_button += 1;
                 }
                 (KeyCode::Enter, _) => {
-                    if dialog.selected_button == 0 {
-                        // Save - put the dialog back first so apply_edit_dialog can take it
-                        editor.edit_dialog = Some(dialog);
-                        if let Some(err) = editor.apply_edit_dialog() {
-                            // Validation failed - dialog is still open with error
-                            return KeybindingEditorAction::StatusMessage(err);
-                        }
-                        return KeybindingEditorAction::Consumed;
-                    } else {
+                    let button = dialog.selected_button;
+                    if button == 1 {
                         // Cancel - don't put dialog back
                         return KeybindingEditorAction::Consumed;
                     }
+                    // Save (optionally resolving conflicts) - put the dialog
+                    // back first so apply_edit_dialog can take it
+                    editor.edit_dialog = Some(dialog);
+                    let result = match button {
+                        2 => editor.apply_edit_dialog_unbinding_conflicts(),
+                        3 => editor.apply_edit_dialog_rebinding_conflict(),
+                        _ => editor.apply_edit_dialog(),
+                    };
+                    return match result {
+                        // Validation failed - dialog is still open with error
+                        Some(err) => KeybindingEditorAction::StatusMessage(err),
+                        None if button == 2 => KeybindingEditorAction::StatusMessage(
+                            t!("keybinding_editor.status_conflicts_unbound").to_string(),
+                        ),
+                        None if button == 3 => KeybindingEditorAction::StatusMessage(
+                            t!("keybinding_editor.status_rebind_conflict").to_string(),
+                        ),
+                        None => KeybindingEditorAction::Consumed,
+                    };
                 }
                 _ => {}
             }
@@ -1684,6 +1926,95 @@ fn handle_edit_dialog_input(
     KeybindingEditorAction::Consumed
 }
 
+fn handle_diff_input(editor: &mut KeybindingEditor, event: &KeyEvent) -> KeybindingEditorAction {
+    // Rendering clamps the scroll offset to the diff length.
+    match event.code {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('v') | KeyCode::Char('q') => {
+            editor.showing_diff = false;
+        }
+        KeyCode::Up | KeyCode::Char('k') => {
+            editor.diff_scroll = editor.diff_scroll.saturating_sub(1);
+        }
+        KeyCode::Down | KeyCode::Char('j') => {
+            editor.diff_scroll += 1;
+        }
+        KeyCode::PageUp => {
+            editor.diff_scroll = editor
+                .diff_scroll
+                .saturating_sub(editor.scroll.viewport as usize);
+        }
+        KeyCode::PageDown => {
+            editor.diff_scroll += editor.scroll.viewport as usize;
+        }
+        _ => {}
+    }
+    KeybindingEditorAction::Consumed
+}
+
+fn handle_file_dialog_input(
+    editor: &mut KeybindingEditor,
+    event: &KeyEvent,
+) -> KeybindingEditorAction {
+    let Some(dialog) = editor.file_dialog.as_mut() else {
+        return KeybindingEditorAction::Consumed;
+    };
+    match (event.code, event.modifiers) {
+        (KeyCode::Esc, _) => {
+            editor.file_dialog = None;
+        }
+        (KeyCode::Backspace, _) => {
+            dialog.path.pop();
+            dialog.error = None;
+        }
+        (KeyCode::Char(c), m) if !m.contains(KeyModifiers::CONTROL) => {
+            dialog.path.push(c);
+            dialog.error = None;
+        }
+        (KeyCode::Enter, _) => {
+            let action = dialog.action;
+            let path_text = dialog.path.clone();
+            let path = editor.resolve_keymap_file_path(&path_text);
+            let result =
+                match action {
+                    KeymapFileAction::Export => editor.export_keymap(&path).map(|count| {
+                        t!(
+                            "keybinding_editor.status_exported",
+                            count = count,
+                            path = path.display().to_string()
+                        )
+                        .to_string()
+                    }),
+                    KeymapFileAction::Import => editor.import_keymap(&path).map(
+                        |(count, other_keymap)| match other_keymap {
+                            Some(keymap) => t!(
+                                "keybinding_editor.status_imported_other_keymap",
+                                count = count,
+                                keymap = keymap
+                            )
+                            .to_string(),
+                            None => {
+                                t!("keybinding_editor.status_imported", count = count).to_string()
+                            }
+                        },
+                    ),
+                };
+            match result {
+                Ok(msg) => {
+                    editor.file_dialog = None;
+                    return KeybindingEditorAction::StatusMessage(msg);
+                }
+                Err(err) => {
+                    if let Some(dialog) = editor.file_dialog.as_mut() {
+                        dialog.error = Some(err);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+    KeybindingEditorAction::Consumed
+}
+
 fn handle_confirm_input(editor: &mut KeybindingEditor, event: &KeyEvent) -> KeybindingEditorAction {
     match (event.code, event.modifiers) {
         (KeyCode::Left, _) => {
@@ -1,3 +1,4 @@
+use crate::config::{EditorConfig, GutterComponent, LineNumberMode, DEFAULT_GUTTER_COMPONENTS};
 use crate::model::marker::{MarkerId, MarkerList};
 use ratatui::style::{Color, Style};
 use std::collections::BTreeMap;
@@ -9,6 +10,79 @@ use std::collections::BTreeMap;
 /// number column that feels cramped next to the indicator and separator.
 pub const MIN_LINE_NUMBER_DIGITS: usize = 2;
 
+/// Margin namespace of the gutter indicators that anchor line bookmarks.
+/// The gutter draws these in the `bookmarks` component rather than `git`.
+pub const LINE_BOOKMARK_NAMESPACE: &str = "bookmark";
+
+/// Width of the separator drawn between the gutter and the text.
+const SEPARATOR_WIDTH: usize = 3;
+
+/// The gutter settings in effect for one frame, applied to each buffer's
+/// left margin as it is laid out.
+#[derive(Debug, Clone, Copy)]
+pub struct GutterSettings<'a> {
+    pub components: &'a [GutterComponent],
+    pub shared_signs: bool,
+    pub min_number_width: usize,
+    pub line_number_mode: LineNumberMode,
+}
+
+impl<'a> GutterSettings<'a> {
+    pub fn from_config(editor: &'a EditorConfig) -> Self {
+        Self {
+            components: &editor.gutter_components,
+            shared_signs: editor.gutter_shared_signs,
+            min_number_width: editor.line_number_min_width,
+            line_number_mode: editor.effective_line_number_mode(),
+        }
+    }
+}
+
+impl Default for GutterSettings<'_> {
+    fn default() -> Self {
+        Self {
+            components: DEFAULT_GUTTER_COMPONENTS,
+            shared_signs: true,
+            min_number_width: MIN_LINE_NUMBER_DIGITS,
+            line_number_mode: LineNumberMode::Absolute,
+        }
+    }
+}
+
+/// The parts of the gutter width that don't depend on the buffer: how many
+/// sign columns there are, and whether and how wide line numbers are.
+/// Width estimates made outside the render pass use it to agree with the
+/// rendered gutter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GutterShape {
+    pub sign_columns: usize,
+    pub line_numbers: bool,
+    pub min_number_width: usize,
+}
+
+impl Default for GutterShape {
+    fn default() -> Self {
+        Self {
+            sign_columns: 1,
+            line_numbers: true,
+            min_number_width: MIN_LINE_NUMBER_DIGITS,
+        }
+    }
+}
+
+impl GutterShape {
+    /// Gutter width when the line-number column needs `digits` digits,
+    /// including the separator.
+    pub fn width(&self, digits: usize) -> usize {
+        let numbers = if self.line_numbers {
+            digits.max(self.min_number_width)
+        } else {
+            0
+        };
+        self.sign_columns + numbers + SEPARATOR_WIDTH
+    }
+}
+
 /// Position of a margin in the editor
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MarginPosition {
@@ -152,6 +226,15 @@ pub struct MarginConfig {
 
     /// Default separator style
     pub separator_style: Style,
+
+    /// Gutter columns left to right, from `editor.gutter_components`
+    pub components: Vec<GutterComponent>,
+
+    /// Whether the sign components share one column
+    pub shared_signs: bool,
+
+    /// Fewest digits the line-number column shrinks to
+    pub min_number_width: usize,
 }
 
 impl MarginConfig {
@@ -165,6 +248,9 @@ impl MarginConfig {
             separator: " │ ".to_string(), // Separator with spaces: " │ " (space before for indicators, space after for readability)
             style: Style::default().fg(Color::DarkGray),
             separator_style: Style::default().fg(Color::DarkGray),
+            components: DEFAULT_GUTTER_COMPONENTS.to_vec(),
+            shared_signs: true,
+            min_number_width: MIN_LINE_NUMBER_DIGITS,
         }
     }
 
@@ -178,15 +264,43 @@ impl MarginConfig {
             separator: String::new(),
             style: Style::default(),
             separator_style: Style::default(),
+            components: Vec::new(),
+            shared_signs: true,
+            min_number_width: 0,
+        }
+    }
+
+    /// Number of one-cell sign columns: one when the signs share a column,
+    /// otherwise one per sign component.
+    pub fn sign_columns(&self) -> usize {
+        let signs = self.components.iter().filter(|c| c.is_sign()).count();
+        if self.shared_signs {
+            signs.min(1)
+        } else {
+            signs
         }
     }
 
-    /// Calculate the total width including indicator column and separator
-    /// Format: [indicator (1 char)][line_number (N chars)][separator (3 chars)]
+    /// Whether the components include the line-number column.
+    pub fn has_line_numbers(&self) -> bool {
+        self.components.contains(&GutterComponent::LineNumbers)
+    }
+
+    /// Buffer-independent shape of this margin, for width estimates.
+    pub fn shape(&self) -> GutterShape {
+        GutterShape {
+            sign_columns: self.sign_columns(),
+            line_numbers: self.has_line_numbers(),
+            min_number_width: self.min_number_width,
+        }
+    }
+
+    /// Calculate the total width including sign columns and separator
+    /// Format: [signs (1 char each)][line_number (N chars)][separator (3 chars)]
     pub fn total_width(&self) -> usize {
         if self.enabled {
-            // 1 char for indicator column + line number width + separator
-            1 + self.width
+            self.sign_columns()
+                + self.width
                 + if self.show_separator {
                     self.separator.chars().count()
                 } else {
@@ -444,13 +558,15 @@ impl MarginManager {
     /// Only queries markers within `viewport_start..viewport_end`, avoiding
     /// iteration over the entire indicator set.
     ///
-    /// Returns a map of line_number -> highest priority indicator for that line.
+    /// Returns a map of line_number -> highest priority indicator for that line,
+    /// among indicators whose namespace passes `include_namespace`.
     /// The `get_line_fn` converts byte offsets to line numbers.
     pub fn get_indicators_for_viewport(
         &self,
         viewport_start: usize,
         viewport_end: usize,
         get_line_fn: impl Fn(usize) -> usize,
+        include_namespace: impl Fn(&str) -> bool,
     ) -> BTreeMap<usize, LineIndicator> {
         let mut by_line: BTreeMap<usize, LineIndicator> = BTreeMap::new();
 
@@ -464,7 +580,12 @@ impl MarginManager {
                 let line = get_line_fn(byte_pos);
 
                 // Get highest priority indicator for this marker
-                if let Some(indicator) = indicators.values().max_by_key(|ind| ind.priority) {
+                if let Some(indicator) = indicators
+                    .iter()
+                    .filter(|(namespace, _)| include_namespace(namespace))
+                    .map(|(_, ind)| ind)
+                    .max_by_key(|ind| ind.priority)
+                {
                     // Check if this is higher priority than existing indicator on this line
                     if let Some(existing) = by_line.get(&line) {
                         if indicator.priority > existing.priority {
@@ -621,16 +742,29 @@ impl MarginManager {
         }
     }
 
+    /// Apply the user's gutter components and minimum line-number width to
+    /// the left margin. Cheap when nothing changed, so the render pass can
+    /// call it every frame.
+    pub fn set_gutter_layout(&mut self, settings: &GutterSettings) {
+        let config = &mut self.left_config;
+        if config.components != settings.components {
+            config.components = settings.components.to_vec();
+        }
+        config.shared_signs = settings.shared_signs;
+        config.min_number_width = settings.min_number_width;
+    }
+
     /// Update the left margin width based on buffer size.
-    /// Only adjusts width when `show_line_numbers` is true.
+    /// Only adjusts width when `show_line_numbers` is true and the gutter
+    /// has a line-number column.
     pub fn update_width_for_buffer(&mut self, buffer_total_lines: usize, show_line_numbers: bool) {
-        if show_line_numbers {
+        if show_line_numbers && self.left_config.has_line_numbers() {
             let digits = if buffer_total_lines == 0 {
                 1
             } else {
                 ((buffer_total_lines as f64).log10().floor() as usize) + 1
             };
-            self.left_config.width = digits.max(MIN_LINE_NUMBER_DIGITS);
+            self.left_config.width = digits.max(self.left_config.min_number_width);
         }
     }
 
@@ -665,8 +799,10 @@ impl MarginManager {
         } else {
             self.left_config.enabled = true;
             self.left_config.show_separator = true;
-            if self.left_config.width == 0 {
-                self.left_config.width = MIN_LINE_NUMBER_DIGITS;
+            if !self.left_config.has_line_numbers() {
+                self.left_config.width = 0;
+            } else if self.left_config.width == 0 {
+                self.left_config.width = self.left_config.min_number_width;
             }
         }
     }
@@ -828,6 +964,73 @@ mod tests {
         assert_eq!(manager.left_total_width(), 8);
     }
 
+    #[test]
+    fn test_gutter_layout_separate_signs_and_min_width() {
+        let mut manager = MarginManager::new();
+        let components = [
+            GutterComponent::Git,
+            GutterComponent::LineNumbers,
+            GutterComponent::Diagnostics,
+        ];
+        manager.set_gutter_layout(&GutterSettings {
+            components: &components,
+            shared_signs: false,
+            min_number_width: 4,
+            line_number_mode: LineNumberMode::Absolute,
+        });
+        manager.configure_for_line_numbers(true);
+        manager.update_width_for_buffer(10, true);
+
+        // 2 sign columns + 4 digits + 3 (" │ ")
+        assert_eq!(manager.left_config.sign_columns(), 2);
+        assert_eq!(manager.left_total_width(), 9);
+        assert_eq!(
+            manager.left_total_width(),
+            manager.left_config.shape().width(2)
+        );
+
+        // Shared signs collapse to one column.
+        manager.left_config.shared_signs = true;
+        assert_eq!(manager.left_total_width(), 8);
+    }
+
+    #[test]
+    fn test_gutter_layout_without_line_number_component() {
+        let mut manager = MarginManager::new();
+        manager.set_gutter_layout(&GutterSettings {
+            components: &[GutterComponent::Diagnostics],
+            ..GutterSettings::default()
+        });
+        manager.configure_for_line_numbers(true);
+        manager.update_width_for_buffer(5000, true);
+
+        // 1 sign column + 3 (" │ "), no number column
+        assert_eq!(manager.left_total_width(), 4);
+        assert_eq!(manager.left_config.shape().width(4), 4);
+    }
+
+    #[test]
+    fn test_indicators_for_viewport_filter_namespaces() {
+        let mut manager = MarginManager::new();
+        manager.set_line_indicator(
+            0,
+            LINE_BOOKMARK_NAMESPACE.to_string(),
+            LineIndicator::new("*", Color::Yellow, 20),
+        );
+        manager.set_line_indicator(
+            0,
+            "git-gutter".to_string(),
+            LineIndicator::new("│", Color::Green, 10),
+        );
+
+        let bookmarks =
+            manager.get_indicators_for_viewport(0, 10, |b| b, |ns| ns == LINE_BOOKMARK_NAMESPACE);
+        let changes =
+            manager.get_indicators_for_viewport(0, 10, |b| b, |ns| ns != LINE_BOOKMARK_NAMESPACE);
+        assert_eq!(bookmarks.get(&0).unwrap().symbol, "*");
+        assert_eq!(changes.get(&0).unwrap().symbol, "│");
+    }
+
     #[test]
     fn test_margin_manager_without_line_numbers() {
         let manager = MarginManager::without_line_numbers();
@@ -427,11 +427,13 @@ fn add_text_to_lines(
 /// with non-breaking spaces. Markdown parsers strip leading spaces from
 /// paragraphs, but LSP documentation (e.g. Python docstrings) uses indentation
 /// for structure. Non-breaking spaces survive markdown parsing.
+///
+/// Indented list items are left alone so nested lists still parse.
 fn preserve_leading_whitespace(text: &str) -> String {
     text.lines()
         .map(|line| {
             let indent = line.len() - line.trim_start_matches(' ').len();
-            if indent > 0 {
+            if indent > 0 && !starts_with_list_marker(&line[indent..]) {
                 format!("{}{}", "\u{00A0}".repeat(indent), &line[indent..])
             } else {
                 line.to_string()
@@ -441,6 +443,18 @@ fn preserve_leading_whitespace(text: &str) -> String {
         .join("\n")
 }
 
+/// Whether a line opens a list item: `-`, `*` or `+`, or a number followed
+/// by `.` or `)`, then a space.
+fn starts_with_list_marker(line: &str) -> bool {
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let rest = if digits > 0 {
+        line[digits..].strip_prefix(['.', ')'])
+    } else {
+        line.strip_prefix(['-', '*', '+'])
+    };
+    rest.is_some_and(|rest| rest.starts_with(' '))
+}
+
 /// Parse markdown text into styled lines for terminal rendering
 ///
 /// If `registry` is provided, uses syntect for syntax highlighting in code blocks,
@@ -470,6 +484,11 @@ pub fn parse_markdown(
     let mut code_block_lang = String::new();
     // Track current link URL (if inside a link)
     let mut current_link_url: Option<String> = None;
+    // Open lists, innermost last: the next number for ordered lists
+    let mut list_stack: Vec<Option<u64>> = Vec::new();
+    // Set from a list item's marker until its first text, so a loose
+    // item's paragraph stays on the marker's line
+    let mut at_item_marker = false;
 
     for event in parser {
         match event {
@@ -515,17 +534,36 @@ pub fn parse_markdown(
                         style_stack
                             .push(current.add_modifier(Modifier::UNDERLINED).fg(Color::Cyan));
                     }
-                    // Start list items on a new line
-                    Tag::List(_) | Tag::Item
-                        if !lines.last().map(|l| l.spans.is_empty()).unwrap_or(true) =>
-                    {
-                        lines.push(StyledLine::new());
+                    // Start lists and list items on a new line
+                    Tag::List(start) => {
+                        if !lines.last().map(|l| l.spans.is_empty()).unwrap_or(true) {
+                            lines.push(StyledLine::new());
+                        }
+                        list_stack.push(start);
+                    }
+                    Tag::Item => {
+                        if !lines.last().map(|l| l.spans.is_empty()).unwrap_or(true) {
+                            lines.push(StyledLine::new());
+                        }
+                        let indent = "  ".repeat(list_stack.len().saturating_sub(1));
+                        let marker = match list_stack.last_mut() {
+                            Some(Some(number)) => {
+                                *number += 1;
+                                format!("{}{}. ", indent, *number - 1)
+                            }
+                            _ => format!("{}• ", indent),
+                        };
+                        let current = *style_stack.last().unwrap_or(&Style::default());
+                        if let Some(line) = lines.last_mut() {
+                            line.push(marker, current);
+                        }
+                        at_item_marker = true;
                     }
                     Tag::Paragraph => {
                         // Start paragraphs on new line if we have any prior content.
                         // This preserves blank lines from previous paragraph ends.
                         let has_prior_content = lines.iter().any(|l| !l.spans.is_empty());
-                        if has_prior_content {
+                        if has_prior_content && !at_item_marker {
                             lines.push(StyledLine::new());
                         }
                     }
@@ -558,11 +596,16 @@ pub fn parse_markdown(
                     }
                     TagEnd::Item => {
                         // Items end naturally
+                        at_item_marker = false;
+                    }
+                    TagEnd::List(_) => {
+                        list_stack.pop();
                     }
                     _ => {}
                 }
             }
             Event::Text(text) => {
+                at_item_marker = false;
                 if in_code_block {
                     // Try syntax highlighting for code blocks using syntect
                     let spans = if let Some(reg) = registry {
@@ -616,6 +659,7 @@ pub fn parse_markdown(
                 }
             }
             Event::Code(code) => {
+                at_item_marker = false;
                 // Inline code - render with background styling (no backticks needed).
                 // Uses `popup_text_fg` (popup body) rather than `help_key_fg`
                 // (key/heading accent) — see issue #2033.
@@ -1020,6 +1064,26 @@ mod tests {
         assert!(all_text.contains("Item 1"), "Should contain Item 1");
         assert!(all_text.contains("Item 2"), "Should contain Item 2");
         assert!(all_text.contains("Item 3"), "Should contain Item 3");
+        assert_eq!(lines[0].plain_text(), "• Item 1");
+    }
+
+    #[test]
+    fn test_list_markers() {
+        let theme = Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let lines = parse_markdown(
+            "3. Third\n4. Fourth\n   - nested\n\n- loose\n\n- items",
+            &theme,
+            None,
+        );
+        let text: Vec<String> = lines
+            .iter()
+            .map(|l| l.plain_text())
+            .filter(|l| !l.is_empty())
+            .collect();
+        assert_eq!(
+            text,
+            vec!["3. Third", "4. Fourth", "  • nested", "• loose", "• items"]
+        );
     }
 
     #[test]
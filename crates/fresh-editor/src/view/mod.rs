@@ -35,6 +35,8 @@ pub mod margin;
 #[cfg(any(feature = "runtime", feature = "wasm"))]
 pub mod overlay;
 #[cfg(any(feature = "runtime", feature = "wasm"))]
+pub mod screen_dump;
+#[cfg(any(feature = "runtime", feature = "wasm"))]
 pub mod scroll_sync;
 #[cfg(any(feature = "runtime", feature = "wasm"))]
 pub mod soft_break;
@@ -58,6 +60,8 @@ pub mod bracket_highlight_overlay;
 #[cfg(feature = "runtime")]
 pub mod calibration_wizard;
 #[cfg(feature = "runtime")]
+pub mod chord_hints;
+#[cfg(feature = "runtime")]
 pub mod event_debug;
 #[cfg(feature = "runtime")]
 pub mod file_browser_input;
@@ -68,12 +72,16 @@ pub mod keybinding_editor;
 #[cfg(feature = "runtime")]
 pub mod markdown;
 #[cfg(feature = "runtime")]
+pub mod perf_hud;
+#[cfg(feature = "runtime")]
 pub mod popup;
 #[cfg(feature = "runtime")]
 pub mod popup_input;
 #[cfg(feature = "runtime")]
 pub mod popup_mouse;
 #[cfg(feature = "runtime")]
+pub mod presentation;
+#[cfg(feature = "runtime")]
 pub mod prompt;
 #[cfg(feature = "runtime")]
 pub mod prompt_input;
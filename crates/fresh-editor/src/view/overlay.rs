@@ -1,5 +1,5 @@
 use crate::model::marker::{MarkerId, MarkerList};
-use ratatui::style::{Color, Style};
+use ratatui::style::{Color, Modifier, Style};
 use std::collections::HashMap;
 use std::ops::Range;
 
@@ -124,6 +124,45 @@ pub enum UnderlineStyle {
     Dashed,
 }
 
+impl UnderlineStyle {
+    /// Modifier bits marking an underlined cell's line style.
+    ///
+    /// Ratatui has no attribute for underline styles, so they ride on
+    /// modifier bits it leaves unused; the terminal backend turns them into
+    /// SGR `4:3`/`4:4`/`4:5`. A straight underline is plain `UNDERLINED`.
+    pub const fn modifier(self) -> Modifier {
+        match self {
+            Self::Straight => Modifier::empty(),
+            Self::Wavy => Modifier::from_bits_retain(1 << 12),
+            Self::Dotted => Modifier::from_bits_retain(1 << 13),
+            Self::Dashed => Modifier::from_bits_retain(1 << 14),
+        }
+    }
+
+    /// All the bits [`UnderlineStyle::modifier`] uses.
+    pub const MODIFIER_MASK: Modifier = Modifier::from_bits_retain(0b111 << 12);
+
+    /// The styled underline a cell's modifiers ask for, if any.
+    pub fn from_modifier(modifier: Modifier) -> Option<Self> {
+        if !modifier.contains(Modifier::UNDERLINED) {
+            return None;
+        }
+        [Self::Wavy, Self::Dotted, Self::Dashed]
+            .into_iter()
+            .find(|style| modifier.contains(style.modifier()))
+    }
+
+    /// The SGR 4 sub-parameter for this style.
+    pub fn sgr_subparam(self) -> u8 {
+        match self {
+            Self::Straight => 1,
+            Self::Wavy => 3,
+            Self::Dotted => 4,
+            Self::Dashed => 5,
+        }
+    }
+}
+
 /// Priority for overlay z-ordering
 /// Higher priority overlays are rendered on top of lower priority ones
 pub type Priority = i32;
@@ -0,0 +1,71 @@
+//! Performance HUD rendering.
+//!
+//! A small bordered panel in the top-right corner of the editor listing
+//! frame timing and resource counters, one labelled row each.
+
+use crate::primitives::display_width::str_width;
+use crate::view::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use rust_i18n::t;
+
+/// One HUD row: a label and its current value.
+pub struct PerfHudRow {
+    pub label: String,
+    pub value: String,
+}
+
+/// Render `rows` in a panel at the top-right of `area`, one row below its
+/// top edge so the menu bar stays readable. Nothing is drawn when the area
+/// is too small to hold the panel's border.
+pub fn render_perf_hud(frame: &mut Frame, area: Rect, rows: &[PerfHudRow], theme: &Theme) {
+    let label_width = rows.iter().map(|r| str_width(&r.label)).max().unwrap_or(0);
+    let content_width = rows
+        .iter()
+        .map(|r| label_width + 1 + str_width(&r.value))
+        .max()
+        .unwrap_or(0);
+    let width = (content_width as u16 + 4).min(area.width);
+    let height = (rows.len() as u16 + 2).min(area.height.saturating_sub(1));
+    if width < 4 || height < 3 {
+        return;
+    }
+    let hud_area = Rect {
+        x: area.x + area.width - width,
+        y: area.y + 1,
+        width,
+        height,
+    };
+
+    let text_style = Style::default().fg(theme.popup_text_fg).bg(theme.popup_bg);
+    let label_style = Style::default().fg(theme.help_key_fg).bg(theme.popup_bg);
+    let lines: Vec<Line> = rows
+        .iter()
+        .map(|row| {
+            let pad = label_width.saturating_sub(str_width(&row.label));
+            Line::from(vec![
+                Span::styled(format!("{}{} ", row.label, " ".repeat(pad)), label_style),
+                Span::styled(row.value.clone(), text_style),
+            ])
+        })
+        .collect();
+
+    let block = Block::default()
+        .title(t!("perf_hud.title").to_string())
+        .borders(Borders::ALL)
+        .border_style(
+            Style::default()
+                .fg(theme.popup_border_fg)
+                .bg(theme.popup_bg),
+        )
+        .style(text_style);
+    let inner = block.inner(hud_area);
+    frame.render_widget(Clear, hud_area);
+    frame.render_widget(block, hud_area);
+    frame.render_widget(Paragraph::new(lines).style(text_style), inner);
+}
@@ -128,12 +128,31 @@ pub enum PopupResolver {
     /// through `handle_update_menu_action`; "update" opens a local terminal that
     /// runs the update.
     Update,
+    /// Running jobs menu (anchored to the status bar's `{jobs}` segment).
+    /// Confirm dispatches the selected row's `data` (a job id, or "close")
+    /// through `handle_jobs_menu_action`, cancelling that job.
+    Jobs,
     /// "Couldn't save settings" error popup. Acknowledging it (confirm or
     /// cancel) opens the offending config file for `layer` in a buffer so the
     /// user can fix the syntax error that blocked the save.
     SettingsSaveError {
         layer: crate::config_io::ConfigLayer,
     },
+    /// Mixed / inconsistent indentation notice shown when a file opens.
+    /// Confirm dispatches the selected row's `data` ("spaces" / "tabs" /
+    /// "keep") through `handle_indentation_fix_action`.
+    IndentationFix,
+    /// Navigation history picker. Confirm jumps to the position history
+    /// entry whose index is the selected row's `data` through
+    /// `navigate_to_history_entry`.
+    NavigationHistory,
+    /// Bookmarks panel. Confirm jumps to the line bookmark named by the
+    /// selected row's `"<buffer_id>:<marker_id>"` data through
+    /// `jump_to_picked_line_bookmark`.
+    LineBookmarks,
+    /// Named marks panel. Confirm jumps to the mark named by the selected
+    /// row's `"<buffer_id>:<marker_id>"` data through `jump_to_picked_mark`.
+    NamedMarks,
 }
 
 /// Content of a popup window
@@ -150,6 +169,13 @@ pub enum PopupContent {
     },
     /// Custom rendered content (just store strings for now)
     Custom(Vec<String>),
+    /// An image the terminal draws over the content area with its graphics
+    /// protocol; the popup only reserves `cols` × `rows` blank cells for it
+    Image {
+        image: std::sync::Arc<crate::services::terminal_graphics::PreviewImage>,
+        cols: u16,
+        rows: u16,
+    },
 }
 
 /// Text selection within a popup (line, column positions)
@@ -202,6 +228,8 @@ pub struct PopupListItem {
     pub data: Option<String>,
     /// If true, item is rendered grayed-out and not selectable.
     pub disabled: bool,
+    /// Char indices in `text` highlighted as matching the typed filter
+    pub match_positions: Vec<usize>,
 }
 
 impl PopupListItem {
@@ -212,6 +240,7 @@ impl PopupListItem {
             icon: None,
             data: None,
             disabled: false,
+            match_positions: Vec::new(),
         }
     }
 
@@ -621,6 +650,7 @@ impl Popup {
             PopupContent::Markdown(lines) => lines.len(),
             PopupContent::List { items, .. } => items.len(),
             PopupContent::Custom(lines) => lines.len(),
+            PopupContent::Image { rows, .. } => *rows as usize,
         }
     }
 
@@ -648,6 +678,7 @@ impl Popup {
             // Lists and custom content don't wrap
             PopupContent::List { items, .. } => items.len(),
             PopupContent::Custom(lines) => lines.len(),
+            PopupContent::Image { rows, .. } => *rows as usize,
         }
     }
 
@@ -737,6 +768,7 @@ impl Popup {
             }
             PopupContent::List { items, .. } => items.iter().map(|i| i.text.clone()).collect(),
             PopupContent::Custom(lines) => lines.clone(),
+            PopupContent::Image { .. } => Vec::new(),
         }
     }
 
@@ -875,6 +907,7 @@ impl Popup {
             }
             PopupContent::List { items, .. } => items.len() as u16,
             PopupContent::Custom(lines) => lines.len() as u16,
+            PopupContent::Image { rows, .. } => *rows,
         };
 
         // Add border lines if bordered
@@ -1055,6 +1088,41 @@ impl Popup {
         }
     }
 
+    /// Calculate the area for this popup shown as a side panel of another
+    /// popup at `anchor` (e.g. completion documentation): top-aligned with
+    /// it, to its right if the full width fits there, else to its left,
+    /// else on whichever side has more room. `None` when neither side is
+    /// wide enough to be readable.
+    pub fn calculate_area_beside(&self, anchor: Rect, bounds: Rect) -> Option<Rect> {
+        const MIN_WIDTH: u16 = 20;
+        let right_space = (bounds.x + bounds.width).saturating_sub(anchor.x + anchor.width);
+        let left_space = anchor.x.saturating_sub(bounds.x);
+        let (x, width) = if right_space >= self.width {
+            (anchor.x + anchor.width, self.width)
+        } else if left_space >= self.width {
+            (anchor.x - self.width, self.width)
+        } else if right_space >= left_space && right_space >= MIN_WIDTH {
+            (anchor.x + anchor.width, right_space)
+        } else if left_space >= MIN_WIDTH {
+            (bounds.x, left_space)
+        } else {
+            return None;
+        };
+        let height = self
+            .content_height_for_width(width)
+            .min(self.max_height)
+            .min((bounds.y + bounds.height).saturating_sub(anchor.y));
+        if height < 3 {
+            return None;
+        }
+        Some(Rect {
+            x,
+            y: anchor.y,
+            width,
+            height,
+        })
+    }
+
     /// Render the popup to the frame
     pub fn render(&self, frame: &mut Frame, area: Rect, theme: &crate::view::theme::Theme) {
         self.render_with_hover(frame, area, theme, None);
@@ -1195,6 +1263,8 @@ impl Popup {
                     count > visible_lines_count && inner_area.width > scrollbar_reserved_width,
                 )
             }
+            // Sized to fit, and drawn by the terminal rather than scrolled.
+            PopupContent::Image { rows, .. } => (*rows as usize, false),
         };
 
         // Adjust content area to leave room for scrollbar if needed
@@ -1365,7 +1435,29 @@ impl Popup {
                                 .fg(theme.help_separator_fg)
                                 .add_modifier(Modifier::DIM);
                         }
-                        spans.push(Span::styled(trimmed, text_style));
+                        if item.match_positions.is_empty() {
+                            spans.push(Span::styled(trimmed, text_style));
+                        } else {
+                            // Highlight the characters matched by the typed
+                            // filter, same colors as the file explorer search.
+                            let indent_chars = text[..indent_len].chars().count();
+                            let match_style = text_style
+                                .fg(theme.search_match_fg)
+                                .bg(theme.search_match_bg);
+                            let mut run = String::new();
+                            let mut run_is_match = false;
+                            for (i, c) in trimmed.chars().enumerate() {
+                                let is_match = item.match_positions.contains(&(indent_chars + i));
+                                if is_match != run_is_match && !run.is_empty() {
+                                    let style = if run_is_match { match_style } else { text_style };
+                                    spans.push(Span::styled(std::mem::take(&mut run), style));
+                                }
+                                run_is_match = is_match;
+                                run.push(c);
+                            }
+                            let style = if run_is_match { match_style } else { text_style };
+                            spans.push(Span::styled(run, style));
+                        }
 
                         // Add detail if present
                         if let Some(detail) = &item.detail {
@@ -1443,6 +1535,8 @@ impl Popup {
                 let paragraph = Paragraph::new(visible_lines);
                 frame.render_widget(paragraph, content_area);
             }
+            // The cells stay blank for the image written after the frame.
+            PopupContent::Image { .. } => {}
         }
 
         // Render scrollbar if needed
@@ -1776,6 +1870,38 @@ mod tests {
         assert!(below.x + below.width <= chrome.x + chrome.width);
     }
 
+    #[test]
+    fn test_popup_area_beside_anchor() {
+        let theme = crate::view::theme::Theme::load_builtin(theme::THEME_DARK).unwrap();
+        let bounds = Rect::new(0, 0, 100, 30);
+        let panel = Popup::text(vec!["docs".to_string()], &theme)
+            .with_width(30)
+            .with_max_height(10);
+
+        // Room on the right: placed there, top-aligned with the anchor.
+        let area = panel
+            .calculate_area_beside(Rect::new(10, 5, 40, 8), bounds)
+            .unwrap();
+        assert_eq!((area.x, area.y, area.width, area.height), (50, 5, 30, 3));
+
+        // Anchor near the right edge: flips to the left side.
+        let area = panel
+            .calculate_area_beside(Rect::new(60, 5, 35, 8), bounds)
+            .unwrap();
+        assert_eq!((area.x, area.width), (30, 30));
+
+        // Neither side fully fits: the wider side is used, shrunk.
+        let area = panel
+            .calculate_area_beside(Rect::new(28, 5, 50, 8), bounds)
+            .unwrap();
+        assert_eq!((area.x, area.width), (0, 28));
+
+        // No readable room anywhere.
+        assert!(panel
+            .calculate_area_beside(Rect::new(10, 5, 80, 8), bounds)
+            .is_none());
+    }
+
     #[test]
     fn test_popup_fixed_position_clamping() {
         let theme = crate::view::theme::Theme::load_builtin(theme::THEME_DARK).unwrap();
@@ -0,0 +1,104 @@
+//! Presentation mode rendering
+//!
+//! Draws the current page of a [`Presentation`] over the whole area, each
+//! character scaled up per [`PresentationScale`], with a one-line footer.
+
+use crate::app::presentation::{Presentation, PresentationScale};
+use crate::primitives::block_font;
+use crate::view::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    widgets::{Block, Clear, Paragraph},
+    Frame,
+};
+use rust_i18n::t;
+
+/// Render presentation mode into `area`, updating the presentation's page
+/// size to what fits.
+pub fn render_presentation(
+    frame: &mut Frame,
+    area: Rect,
+    presentation: &mut Presentation,
+    theme: &Theme,
+) {
+    let background = Style::default().fg(theme.editor_fg).bg(theme.editor_bg);
+    frame.render_widget(Clear, area);
+    frame.render_widget(Block::default().style(background), area);
+    if area.height < 2 {
+        return;
+    }
+
+    // The last row is the footer.
+    let body = Rect {
+        height: area.height - 1,
+        ..area
+    };
+    let (glyph_width, glyph_height) = match presentation.scale {
+        PresentationScale::Wide => (2, 1),
+        PresentationScale::Block => (block_font::CELL_WIDTH, block_font::CELL_HEIGHT),
+    };
+    presentation.set_lines_per_page(body.height as usize / glyph_height);
+    let columns = body.width as usize / glyph_width;
+
+    let buf = frame.buffer_mut();
+    for (row, line) in presentation.page_lines().iter().enumerate() {
+        let y = body.y + (row * glyph_height) as u16;
+        let chars = line
+            .iter()
+            .flat_map(|(text, color)| text.chars().map(move |c| (c, *color)))
+            .take(columns);
+        for (column, (c, color)) in chars.enumerate() {
+            let x = body.x + (column * glyph_width) as u16;
+            let style = background.fg(color);
+            match presentation.scale {
+                PresentationScale::Wide => {
+                    buf.set_string(x, y, fullwidth(c), style);
+                }
+                PresentationScale::Block => {
+                    for (dy, cells) in block_font::glyph_rows(c).iter().enumerate() {
+                        let cell_y = y + dy as u16;
+                        if cell_y >= body.bottom() {
+                            break;
+                        }
+                        for (dx, symbol) in cells.iter().enumerate() {
+                            if let Some(cell) = buf.cell_mut((x + dx as u16, cell_y)) {
+                                cell.set_char(*symbol).set_style(style);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let footer = t!(
+        "presentation.footer",
+        name = presentation.title,
+        page = presentation.page() + 1,
+        pages = presentation.page_count()
+    );
+    let footer_area = Rect {
+        y: body.bottom(),
+        height: 1,
+        ..area
+    };
+    frame.render_widget(
+        Paragraph::new(format!(" {footer}")).style(
+            Style::default()
+                .fg(theme.status_bar_fg)
+                .bg(theme.status_bar_bg),
+        ),
+        footer_area,
+    );
+}
+
+/// `c` drawn two columns wide: printable ASCII as its fullwidth form, a
+/// space as two spaces. Other characters are returned unchanged.
+fn fullwidth(c: char) -> String {
+    match c {
+        ' ' => "  ".to_string(),
+        '!'..='~' => char::from_u32(c as u32 + 0xFEE0).unwrap_or(c).to_string(),
+        c => c.to_string(),
+    }
+}
@@ -51,6 +51,8 @@ pub enum PromptType {
     GotoLineScanConfirm,
     /// Choose an ANSI background file
     SetBackgroundFile,
+    /// Choose the file to dump the screen to (Dump Screen To File)
+    DumpScreen,
     /// Set background blend ratio (0-1)
     SetBackgroundBlend,
     /// Plugin-controlled prompt with custom type identifier
@@ -76,6 +78,12 @@ pub enum PromptType {
     SetBookmark,
     /// Jump to a bookmark - prompts for register (0-9)
     JumpToBookmark,
+    /// Label the line bookmark on the cursor line (empty clears the label)
+    LineBookmarkLabel,
+    /// Set a named mark - prompts for a letter (a-z local, A-Z global)
+    SetNamedMark,
+    /// Go to a named mark - prompts for a letter
+    GotoNamedMark,
     /// Set page width (empty clears to viewport)
     SetPageWidth,
     /// Add a vertical ruler at a column position
@@ -88,27 +96,50 @@ pub enum PromptType {
     SetLineEnding,
     /// Set text encoding format for current buffer
     SetEncoding,
+    /// Convert the current buffer to an encoding and save it
+    SaveWithEncoding,
     /// Set language/syntax highlighting for current buffer
     SetLanguage,
+    /// Pick the language of a new scratch buffer
+    NewScratchBuffer,
     /// Stop a running LSP server (select from list)
     StopLspServer,
     /// Restart LSP server(s) (select from list)
     RestartLspServer,
     /// Select a theme (select from list)
-    /// Stores the original theme name for restoration on cancel
-    SelectTheme { original_theme: String },
+    /// Stores the original theme name for restoration on cancel, and
+    /// whether confirming persists the choice to config (Select Theme) or
+    /// only applies it for this session (Preview Themes)
+    SelectTheme {
+        original_theme: String,
+        persist: bool,
+    },
     /// Select a keybinding map (select from list)
     SelectKeybindingMap,
     /// Select a cursor style (select from list)
     SelectCursorStyle,
     /// Select a UI locale/language (select from list)
     SelectLocale,
+    /// Select the key calibration profile for this terminal (select from list)
+    SelectCalibrationProfile,
     /// Select a theme for copy with formatting
     CopyWithFormattingTheme,
+    /// Pick a user snippet to insert (select from list); suggestion values
+    /// index into `snippets`
+    InsertSnippet {
+        snippets: Vec<crate::services::completion::snippets::UserSnippet>,
+    },
+    /// Search the project's tags file and jump to a symbol; suggestion
+    /// values hold the tag's name, file and address, tab-separated
+    GotoTag,
     /// Confirm reverting a modified file
     ConfirmRevert,
     /// Confirm saving over a file that changed on disk
     ConfirmSaveConflict,
+    /// Confirm the repairs found by Fix Encoding Artifacts
+    ConfirmFixEncoding {
+        fixes: Vec<crate::model::mojibake::EncodingFix>,
+    },
     /// Confirm saving with sudo after permission denied
     ConfirmSudoSave {
         info: crate::model::buffer::SudoSaveRequired,
@@ -128,6 +159,11 @@ pub enum PromptType {
     /// Issued only when no buffer is modified; otherwise
     /// `ConfirmQuitWithModified` runs instead.
     ConfirmQuit,
+    /// First-launch question: send anonymous telemetry? (`telemetry` config)
+    TelemetryConsent,
+    /// Confirm pasting a large multi-line text into a live terminal
+    /// (`terminal.paste_confirm_lines`)
+    ConfirmTerminalPaste { text: String },
     /// File Explorer rename operation
     /// Stores the original path and name for the file/directory being renamed
     FileExplorerRename {
@@ -170,6 +206,18 @@ pub enum PromptType {
     ConfirmLargeFileEncoding { path: std::path::PathBuf },
     /// Switch to a tab by name (from the current split's open buffers)
     SwitchToTab,
+    /// Buffer picker: every open buffer, most recently used first, with a
+    /// live preview of the selection in the other split
+    SwitchBuffer,
+    /// Pick a clipboard history entry to paste; suggestion values index
+    /// into the history
+    ClipboardHistory,
+    /// Pick a saved version of the active file to restore; suggestion
+    /// values are the paths of the stored copies
+    FileHistory,
+    /// Filter the *Editor Log* panel to one module; suggestions are the
+    /// modules seen in the log
+    FilterEditorLog,
     /// Run shell command on buffer/selection
     /// If replace is true, replace the input with the output
     /// If replace is false, output goes to a new buffer
@@ -187,7 +235,10 @@ impl PromptType {
     /// expensive or destructive action — there, click should preview the
     /// selection and Enter should commit (issue #1660).
     pub fn click_confirms(&self) -> bool {
-        !matches!(self, PromptType::ReloadWithEncoding)
+        !matches!(
+            self,
+            PromptType::ReloadWithEncoding | PromptType::SaveWithEncoding
+        )
     }
 
     /// Whether this prompt is one of the search/replace prompts that exposes
@@ -149,6 +149,7 @@ impl InputHandler for Prompt {
                                 crate::view::prompt::PromptType::Plugin { .. }
                                     | crate::view::prompt::PromptType::QuickOpen
                                     | crate::view::prompt::PromptType::LiveGrep
+                                    | crate::view::prompt::PromptType::SwitchBuffer
                             );
                         if should_sync {
                             if let Some(suggestion) = self.suggestions.get(new_selected) {
@@ -164,6 +165,13 @@ impl InputHandler for Prompt {
                         ) {
                             ctx.defer(DeferredAction::PreviewThemeFromPrompt);
                         }
+                        // For the buffer picker, preview the buffer in the other split
+                        if matches!(
+                            self.prompt_type,
+                            crate::view::prompt::PromptType::SwitchBuffer
+                        ) {
+                            ctx.defer(DeferredAction::PreviewBufferFromPrompt);
+                        }
                         // For plugin prompts, notify about selection change (for live preview)
                         if matches!(
                             self.prompt_type,
@@ -195,6 +203,7 @@ impl InputHandler for Prompt {
                                 crate::view::prompt::PromptType::Plugin { .. }
                                     | crate::view::prompt::PromptType::QuickOpen
                                     | crate::view::prompt::PromptType::LiveGrep
+                                    | crate::view::prompt::PromptType::SwitchBuffer
                             );
                         if should_sync {
                             if let Some(suggestion) = self.suggestions.get(new_selected) {
@@ -210,6 +219,13 @@ impl InputHandler for Prompt {
                         ) {
                             ctx.defer(DeferredAction::PreviewThemeFromPrompt);
                         }
+                        // For the buffer picker, preview the buffer in the other split
+                        if matches!(
+                            self.prompt_type,
+                            crate::view::prompt::PromptType::SwitchBuffer
+                        ) {
+                            ctx.defer(DeferredAction::PreviewBufferFromPrompt);
+                        }
                         // For plugin prompts, notify about selection change (for live preview)
                         if matches!(
                             self.prompt_type,
@@ -357,6 +373,15 @@ impl Prompt {
                 }
                 InputResult::Consumed
             }
+            'd' if matches!(
+                self.prompt_type,
+                crate::view::prompt::PromptType::SwitchBuffer
+            ) =>
+            {
+                // Close the highlighted buffer; the picker stays open
+                ctx.defer(DeferredAction::CloseBufferFromPicker);
+                InputResult::Consumed
+            }
             // Pass through other Ctrl+key combinations to global keybindings (e.g., Ctrl+P to toggle Quick Open)
             _ => InputResult::Ignored,
         }
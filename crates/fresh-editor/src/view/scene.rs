@@ -539,6 +539,13 @@ impl Editor {
                         win.search_confirm_each,
                         shortcut(&Action::ToggleSearchConfirmEach),
                     ),
+                    opt(
+                        lo.multiline,
+                        "multiline",
+                        t!("search.multiline").to_string(),
+                        win.search_multiline,
+                        shortcut(&Action::ToggleSearchMultiline),
+                    ),
                 ]
                 .into_iter()
                 .flatten()
@@ -659,6 +666,9 @@ fn project_popup(
         PopupContent::Text(lines) | PopupContent::Custom(lines) => PopupContentView::Lines {
             lines: lines.clone(),
         },
+        PopupContent::Image { image, .. } => PopupContentView::Lines {
+            lines: vec![image.path.display().to_string()],
+        },
         PopupContent::Markdown(styled) => PopupContentView::Lines {
             lines: styled
                 .iter()
@@ -1085,6 +1095,7 @@ impl Editor {
             ContextMenuKind::NewTab => "newTab",
             ContextMenuKind::Tab => "tab",
             ContextMenuKind::CloseSplit => "closeSplit",
+            ContextMenuKind::Buffer => "buffer",
         };
         Some(ContextMenuView {
             kind,
@@ -0,0 +1,214 @@
+//! Plain-text and ANSI snapshots of a rendered frame.
+//!
+//! Backs the **Dump Screen To File** command and `--screenshot-on-exit`:
+//! the finished frame buffer is flattened into text a user can attach to a
+//! bug report, which helps on terminals where selecting and copying the
+//! screen doesn't work.
+
+use std::path::Path;
+
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier, Style};
+use unicode_width::UnicodeWidthStr;
+
+/// Output format of a screen dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenDumpFormat {
+    /// Characters only; trailing blanks on each row are trimmed.
+    Text,
+    /// Characters plus SGR color/attribute escapes, full row width.
+    Ansi,
+}
+
+impl ScreenDumpFormat {
+    /// Pick the format from the target file name: `.ans` (the usual ANSI-art
+    /// extension) gets escapes, anything else plain text.
+    pub fn from_path(path: &Path) -> Self {
+        if path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("ans"))
+        {
+            Self::Ansi
+        } else {
+            Self::Text
+        }
+    }
+}
+
+/// Flatten `buffer` into one line per screen row in the given format.
+pub fn dump_buffer(buffer: &Buffer, format: ScreenDumpFormat) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        let mut current: Option<Style> = None;
+        // Cells hidden behind the previous wide symbol (a CJK character, or
+        // the second half of an OSC 8 hyperlink chunk).
+        let mut to_skip = 0usize;
+        for x in area.left()..area.right() {
+            let cell = &buffer[(x, y)];
+            if to_skip > 0 {
+                to_skip -= 1;
+                continue;
+            }
+            let symbol = strip_osc8(cell.symbol());
+            to_skip = symbol.width().saturating_sub(1);
+            if format == ScreenDumpFormat::Ansi && current != Some(cell.style()) {
+                push_sgr(&mut line, cell.style());
+                current = Some(cell.style());
+            }
+            line.push_str(&symbol);
+        }
+        match format {
+            ScreenDumpFormat::Text => out.push_str(line.trim_end()),
+            ScreenDumpFormat::Ansi => {
+                out.push_str(&line);
+                out.push_str("\x1b[0m");
+            }
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Remove OSC 8 hyperlink escapes (`ESC ] 8 ; params ; url BEL`), keeping
+/// the link text.
+fn strip_osc8(symbol: &str) -> String {
+    if !symbol.contains('\x1b') {
+        return symbol.to_string();
+    }
+    let mut out = String::with_capacity(symbol.len());
+    let mut rest = symbol;
+    while let Some(start) = rest.find("\x1b]8;") {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match rest.find('\x07') {
+            Some(end) => rest = &rest[end + 1..],
+            None => rest = "",
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Append an SGR sequence that resets and then sets exactly `style`.
+fn push_sgr(out: &mut String, style: Style) {
+    let mut codes = vec!["0".to_string()];
+    const MODIFIER_CODES: [(Modifier, &str); 9] = [
+        (Modifier::BOLD, "1"),
+        (Modifier::DIM, "2"),
+        (Modifier::ITALIC, "3"),
+        (Modifier::UNDERLINED, "4"),
+        (Modifier::SLOW_BLINK, "5"),
+        (Modifier::RAPID_BLINK, "6"),
+        (Modifier::REVERSED, "7"),
+        (Modifier::HIDDEN, "8"),
+        (Modifier::CROSSED_OUT, "9"),
+    ];
+    for (modifier, code) in MODIFIER_CODES {
+        if style.add_modifier.contains(modifier) {
+            codes.push(code.to_string());
+        }
+    }
+    if let Some(fg) = style.fg.and_then(|c| color_code(c, false)) {
+        codes.push(fg);
+    }
+    if let Some(bg) = style.bg.and_then(|c| color_code(c, true)) {
+        codes.push(bg);
+    }
+    out.push_str("\x1b[");
+    out.push_str(&codes.join(";"));
+    out.push('m');
+}
+
+/// SGR parameter for a foreground (or, with `background`, background)
+/// color. `Reset` needs none because every sequence starts with `0`.
+fn color_code(color: Color, background: bool) -> Option<String> {
+    // Standard colors are 30–37 / 90–97 (foreground); backgrounds add 10.
+    let base = |code: u8| Some((code + if background { 10 } else { 0 }).to_string());
+    match color {
+        Color::Reset => None,
+        Color::Black => base(30),
+        Color::Red => base(31),
+        Color::Green => base(32),
+        Color::Yellow => base(33),
+        Color::Blue => base(34),
+        Color::Magenta => base(35),
+        Color::Cyan => base(36),
+        Color::Gray => base(37),
+        Color::DarkGray => base(90),
+        Color::LightRed => base(91),
+        Color::LightGreen => base(92),
+        Color::LightYellow => base(93),
+        Color::LightBlue => base(94),
+        Color::LightMagenta => base(95),
+        Color::LightCyan => base(96),
+        Color::White => base(97),
+        Color::Indexed(i) => Some(format!("{};5;{}", if background { 48 } else { 38 }, i)),
+        Color::Rgb(r, g, b) => Some(format!(
+            "{};2;{};{};{}",
+            if background { 48 } else { 38 },
+            r,
+            g,
+            b
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            ScreenDumpFormat::from_path(Path::new("screen.ans")),
+            ScreenDumpFormat::Ansi
+        );
+        assert_eq!(
+            ScreenDumpFormat::from_path(Path::new("screen.ANS")),
+            ScreenDumpFormat::Ansi
+        );
+        assert_eq!(
+            ScreenDumpFormat::from_path(Path::new("screen.txt")),
+            ScreenDumpFormat::Text
+        );
+        assert_eq!(
+            ScreenDumpFormat::from_path(Path::new("screen")),
+            ScreenDumpFormat::Text
+        );
+    }
+
+    #[test]
+    fn test_text_dump_trims_rows_and_keeps_wide_chars_aligned() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 2));
+        buffer.set_string(0, 0, "ab", Style::default());
+        buffer.set_string(0, 1, "日本x", Style::default());
+        assert_eq!(dump_buffer(&buffer, ScreenDumpFormat::Text), "ab\n日本x\n");
+    }
+
+    #[test]
+    fn test_text_dump_strips_hyperlinks() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buffer.set_string(0, 0, "link", Style::default());
+        buffer[(0, 0)].set_symbol("\x1b]8;;https://example.com\x07li\x1b]8;;\x07");
+        buffer[(1, 0)].set_symbol("");
+        assert_eq!(dump_buffer(&buffer, ScreenDumpFormat::Text), "link\n");
+    }
+
+    #[test]
+    fn test_ansi_dump_emits_sgr_on_style_change() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        let red_bold = Style::default()
+            .fg(Color::Rgb(255, 0, 0))
+            .bg(Color::Indexed(17))
+            .add_modifier(Modifier::BOLD);
+        buffer.set_string(0, 0, "ab", red_bold);
+        buffer.set_string(2, 0, "c", Style::default().fg(Color::Green));
+        assert_eq!(
+            dump_buffer(&buffer, ScreenDumpFormat::Ansi),
+            "\x1b[0;1;38;2;255;0;0;48;5;17mab\x1b[0;32mc\x1b[0m\n"
+        );
+    }
+}
@@ -9,6 +9,7 @@ use crate::view::controls::{
     TextInputState, TextListState, ToggleState,
 };
 use crate::view::ui::{FocusRegion, ScrollItem, TextEdit};
+use rust_i18n::t;
 use std::collections::{HashMap, HashSet};
 
 /// State for multiline JSON editing
@@ -630,7 +631,53 @@ impl SettingItem {
         if inner_width == 0 {
             return 1;
         }
-        desc.len().div_ceil(inner_width as usize) as u16
+        let len = desc.len() + self.annotation().map_or(0, |a| a.len() + 1);
+        len.div_ceil(inner_width as usize) as u16
+    }
+
+    /// Note shown after the description: the layer the value comes from
+    /// and, when the value overrides it, the default (`(user) default: 4`).
+    /// `None` while the value is the schema default.
+    pub fn annotation(&self) -> Option<String> {
+        let layer = match self.layer_source {
+            ConfigLayer::System => return None,
+            ConfigLayer::User => "user",
+            ConfigLayer::Project => "project",
+            ConfigLayer::Session => "session",
+        };
+        Some(match self.default_hint() {
+            Some(default) => format!(
+                "({}) {}",
+                layer,
+                t!("settings.default_hint", value = default)
+            ),
+            None => format!("({})", layer),
+        })
+    }
+
+    /// The schema default as text, when it is a scalar the current value
+    /// differs from. Lists, maps and objects don't fit on one line and are
+    /// left to the Reset button.
+    fn default_hint(&self) -> Option<String> {
+        if self.is_null {
+            return None;
+        }
+        let default = self.default.as_ref()?;
+        let current = control_to_value(&self.control);
+        let same = match (default.as_f64(), current.as_f64()) {
+            (Some(a), Some(b)) => a == b,
+            _ => *default == current,
+        };
+        if same {
+            return None;
+        }
+        match default {
+            serde_json::Value::Bool(b) => Some(b.to_string()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            serde_json::Value::String(s) if s.is_empty() => Some("\"\"".to_string()),
+            serde_json::Value::String(s) => Some(s.clone()),
+            _ => None,
+        }
     }
 }
 
@@ -1799,6 +1846,49 @@ mod tests {
         assert_eq!(item.layer_source, ConfigLayer::User);
     }
 
+    #[test]
+    fn test_annotation_shows_overridden_default() {
+        let schema = SettingSchema {
+            path: "/editor/tab_size".to_string(),
+            name: "Tab Size".to_string(),
+            description: None,
+            setting_type: SettingType::Integer {
+                minimum: Some(1),
+                maximum: Some(16),
+            },
+            default: Some(serde_json::Value::Number(4.into())),
+            read_only: false,
+            section: None,
+            order: None,
+            nullable: false,
+            enum_from: None,
+            dual_list_sibling: None,
+            dynamically_extendable_status_bar_elements: false,
+        };
+
+        // Schema default: no note at all.
+        let config = sample_config();
+        assert_eq!(
+            build_item(&schema, &test_context(&config)).annotation(),
+            None
+        );
+
+        // Set to 2 in the user layer: both the layer and the default show.
+        let mut layer_sources = HashMap::new();
+        layer_sources.insert("/editor/tab_size".to_string(), ConfigLayer::User);
+        let ctx = test_context_with_sources(&config, &layer_sources, ConfigLayer::User);
+        let item = build_item(&schema, &ctx);
+        assert_eq!(item.annotation().as_deref(), Some("(user) default: 4"));
+
+        // Set in the layer but equal to the default: just the layer.
+        let config = serde_json::json!({ "editor": { "tab_size": 4 } });
+        let ctx = test_context_with_sources(&config, &layer_sources, ConfigLayer::User);
+        assert_eq!(
+            build_item(&schema, &ctx).annotation().as_deref(),
+            Some("(user)")
+        );
+    }
+
     #[test]
     fn test_build_number_item() {
         let schema = SettingSchema {
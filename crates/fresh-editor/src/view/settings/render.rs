@@ -1093,12 +1093,7 @@ fn render_description_band(
     item: &SettingItem,
     theme: &Theme,
 ) {
-    let layer_label = match item.layer_source {
-        crate::config_io::ConfigLayer::System => None,
-        crate::config_io::ConfigLayer::User => Some("user"),
-        crate::config_io::ConfigLayer::Project => Some("project"),
-        crate::config_io::ConfigLayer::Session => Some("session"),
-    };
+    let annotation = item.annotation();
 
     if plan.description_rows > 0 {
         let Some(desc_rect) = vp
@@ -1116,11 +1111,13 @@ fn render_description_band(
             Some(d) if !d.is_empty() => wrap_text(d, max_text_width),
             _ => Vec::new(),
         };
-        if let Some(layer) = layer_label {
-            if let Some(last) = lines.last_mut() {
-                last.push_str(&format!(" ({})", layer));
-            } else {
-                lines.push(format!("({})", layer));
+        if let Some(note) = annotation {
+            match lines.last_mut() {
+                Some(last) if last.len() + note.len() < max_text_width => {
+                    last.push(' ');
+                    last.push_str(&note);
+                }
+                _ => lines.push(note),
             }
         }
         let desc_style = Style::default().fg(theme.line_number_fg);
@@ -1131,8 +1128,8 @@ fn render_description_band(
                 Rect::new(desc_rect.x, desc_rect.y + i as u16, desc_rect.width, 1),
             );
         }
-    } else if let Some(layer) = layer_label {
-        // No description, just a layer label on the row immediately below the control.
+    } else if let Some(note) = annotation {
+        // No description, just the layer label on the row immediately below the control.
         let Some(layer_rect) = vp
             .band_rect(plan.description_y(), 1)
             .map(|r| inset_by_chrome(r, style))
@@ -1140,7 +1137,7 @@ fn render_description_band(
             return;
         };
         frame.render_widget(
-            Paragraph::new(format!("({})", layer)).style(Style::default().fg(theme.line_number_fg)),
+            Paragraph::new(note).style(Style::default().fg(theme.line_number_fg)),
             layer_rect,
         );
     }
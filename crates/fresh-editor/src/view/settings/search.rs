@@ -108,10 +108,15 @@ pub fn search_settings(pages: &[SettingsPage], query: &str) -> Vec<SearchResult>
         }
     }
 
-    // Sort by score (descending), then by name (ascending)
+    // Sort by score (descending), then by name (ascending). Values nested
+    // inside map entries (a linter's `check` argument) come after settings
+    // and map keys, however well they match.
+    let is_nested_value =
+        |r: &SearchResult| matches!(r.deep_match, Some(DeepMatch::MapValue { .. }));
     results.sort_by(|a, b| {
-        b.score
-            .cmp(&a.score)
+        is_nested_value(a)
+            .cmp(&is_nested_value(b))
+            .then_with(|| b.score.cmp(&a.score))
             .then_with(|| a.item.name.cmp(&b.item.name))
     });
 
@@ -659,6 +664,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_search_nested_value_ranks_below_settings() {
+        let pages = vec![make_page(
+            "General",
+            vec![
+                make_item("Check For Updates", None, "/check_for_updates"),
+                make_map_item(
+                    "Linters",
+                    "/linters",
+                    vec![(
+                        "ruff".to_string(),
+                        serde_json::json!({"args": ["check", "-"]}),
+                    )],
+                ),
+            ],
+        )];
+
+        // The exact "check" argument must not outrank the setting itself.
+        let results = search_settings(&pages, "check");
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].item.name, "Check For Updates");
+        assert!(matches!(
+            &results[1].deep_match,
+            Some(DeepMatch::MapValue { key, .. }) if key == "ruff"
+        ));
+    }
+
     #[test]
     fn test_search_text_list_item() {
         let pages = vec![make_page(
@@ -208,6 +208,8 @@ pub struct ViewConfigDefaults {
     pub wrap_column: Option<usize>,
     pub rulers: Vec<usize>,
     pub scroll_offset: usize,
+    pub scroll_past_end: bool,
+    pub typewriter_scrolling: crate::config::TypewriterScrolling,
 }
 
 impl BufferViewState {
@@ -262,6 +264,8 @@ impl BufferViewState {
             wrap_column,
             rulers,
             scroll_offset,
+            scroll_past_end,
+            typewriter_scrolling,
         } = defaults;
         self.show_line_numbers = line_numbers;
         self.highlight_current_line = highlight_current_line;
@@ -270,6 +274,8 @@ impl BufferViewState {
         self.viewport.wrap_column = wrap_column;
         self.rulers = rulers;
         self.viewport.set_scroll_offset(scroll_offset);
+        self.viewport.scroll_past_end = scroll_past_end;
+        self.viewport.typewriter_scrolling = typewriter_scrolling;
     }
 
     /// Activate page view (compose mode) with an optional page width.
@@ -2464,6 +2470,8 @@ mod tests {
             wrap_column: None,
             rulers: vec![],
             scroll_offset: 7,
+            scroll_past_end: false,
+            typewriter_scrolling: crate::config::TypewriterScrolling::Off,
         });
         assert_eq!(
             view_state.viewport.scroll_offset, 7,
@@ -18,6 +18,12 @@ pub fn normalize_theme_name(name: &str) -> String {
     name.to_lowercase().replace(['_', ' '], "-")
 }
 
+/// Whether `path` looks like a theme file (`.json` or `.toml`).
+pub(crate) fn is_theme_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext == "json" || ext == "toml")
+}
+
 /// Expand `~`, `$VAR`, and `${VAR}` references in a config-supplied path.
 ///
 /// Minimal shell-like expansion (no crate dependency). Unknown variables are
@@ -128,7 +134,7 @@ impl ThemeRegistry {
     /// | `builtin://NAME` | `builtin://dark` | built-in theme by name |
     /// | `file://PATH` (env-expanded) | `file://${HOME}/.config/fresh/themes/x.json` | exact user-theme key |
     /// | `http(s)://...` | `https://github.com/...#dark` | URL-packaged theme |
-    /// | relative `.json` path | `s-dark.json`, `packages/nord/dark.json` | user theme under themes dir |
+    /// | relative `.json`/`.toml` path | `s-dark.json`, `packages/nord/dark.toml` | user theme under themes dir |
     /// | bare name (legacy) | `dark` | exact key, else normalized-name match |
     ///
     /// `$HOME`, `${HOME}`, `${XDG_CONFIG_HOME}` and a leading `~` are
@@ -173,11 +179,12 @@ impl ThemeRegistry {
             return None;
         }
 
-        // 5. Relative path (ends with `.json`) — resolve against themes dir.
-        //    This is the portable form for user themes (`s-dark.json` or
-        //    `packages/nord/dark.json`). Env-var expansion is applied for
-        //    the rare case of a hand-edited path like `${HOME}/foo.json`.
-        if value.ends_with(".json") {
+        // 5. Relative path (ends with `.json` / `.toml`) — resolve against
+        //    themes dir. This is the portable form for user themes
+        //    (`s-dark.json` or `packages/nord/dark.toml`). Env-var expansion
+        //    is applied for the rare case of a hand-edited path like
+        //    `${HOME}/foo.json`.
+        if is_theme_file(Path::new(value)) {
             if let Some(themes_dir) = self.themes_dir.as_deref() {
                 let expanded = expand_env_vars(value);
                 // Registry keys are built from `entry.path().display()`,
@@ -465,7 +472,8 @@ impl ThemeLoader {
                         let theme_path = pkg_dir.join(file);
                         if theme_path.exists() {
                             if let Ok(content) = std::fs::read_to_string(&theme_path) {
-                                if let Ok(theme) = Theme::from_json(&content) {
+                                if let Ok(theme) = Theme::from_file_contents(&theme_path, &content)
+                                {
                                     let normalized_name = normalize_theme_name(name);
                                     let info = if let Some(ref repo) = repository {
                                         ThemeInfo::with_key(
@@ -489,7 +497,7 @@ impl ThemeLoader {
             }
         }
 
-        // Fallback: if no fresh.themes, scan for JSON files
+        // Fallback: if no fresh.themes, scan for theme files
         self.scan_directory(
             pkg_dir,
             &pack_name,
@@ -534,40 +542,73 @@ impl ThemeLoader {
                     format!("{}/{}", pack, subdir_name)
                 };
                 self.scan_directory(&path, &new_pack, repository, themes, theme_list);
-            } else if path.extension().is_some_and(|ext| ext == "json") {
-                if let Ok(content) = std::fs::read_to_string(&path) {
-                    if let Ok(theme) = Theme::from_json(&content) {
-                        let name = normalize_theme_name(&theme.name);
-                        let info = if let Some(repo) = repository {
-                            ThemeInfo::with_key(&name, pack, format!("{}#{}", repo, name))
-                        } else if pack.starts_with("user") {
-                            // User-saved themes: use file:// URL as key
-                            ThemeInfo::with_key(&name, pack, format!("file://{}", path.display()))
-                        } else {
-                            ThemeInfo::new(&name, pack)
-                        };
-
-                        // Only skip exact key duplicates
-                        if themes.contains_key(&info.key) {
-                            continue;
-                        }
-
-                        themes.insert(info.key.clone(), theme);
-                        theme_list.push(info);
+            } else if is_theme_file(&path) {
+                let Ok(content) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+                let theme = match Theme::from_file_contents(&path, &content) {
+                    Ok(theme) => theme,
+                    Err(e) => {
+                        tracing::warn!("Skipping theme file {}: {}", path.display(), e);
+                        continue;
                     }
+                };
+                let name = normalize_theme_name(&theme.name);
+                let info = if let Some(repo) = repository {
+                    ThemeInfo::with_key(&name, pack, format!("{}#{}", repo, name))
+                } else if pack.starts_with("user") {
+                    // User-saved themes: use file:// URL as key
+                    ThemeInfo::with_key(&name, pack, format!("file://{}", path.display()))
+                } else {
+                    ThemeInfo::new(&name, pack)
+                };
+
+                // Only skip exact key duplicates
+                if themes.contains_key(&info.key) {
+                    continue;
                 }
+
+                themes.insert(info.key.clone(), theme);
+                theme_list.push(info);
             }
         }
     }
 }
 
+impl Theme {
+    /// Parse theme from a TOML string (no I/O).
+    ///
+    /// Same schema as [`Theme::from_json`]: top-level `name` / `extends`,
+    /// and `[editor]`, `[ui]`, `[search]`, `[diagnostic]`, `[syntax]` tables
+    /// whose values are `[r, g, b]` arrays or named colors.
+    pub fn from_toml(toml_str: &str) -> Result<Self, String> {
+        let raw: toml::Value =
+            toml::from_str(toml_str).map_err(|e| format!("Failed to parse theme TOML: {}", e))?;
+        let raw = serde_json::to_value(raw).map_err(|e| format!("Failed to parse theme: {}", e))?;
+        Self::from_value(raw)
+    }
+
+    /// Parse a theme file's contents, choosing TOML or JSON by extension.
+    pub fn from_file_contents(path: &Path, content: &str) -> Result<Self, String> {
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            Self::from_toml(content)
+        } else {
+            Self::from_json(content)
+        }
+    }
+}
+
 // Cursor color methods on Theme (no I/O for theme loading)
 impl Theme {
     /// Set the terminal cursor color using OSC 12 escape sequence.
     /// This makes the hardware cursor visible on any background.
+    /// Skipped when stdout isn't a terminal (e.g. `fresh --bench | jq`).
     pub fn set_terminal_cursor_color(&self) {
         use super::types::color_to_rgb;
-        use std::io::Write;
+        use std::io::{IsTerminal, Write};
+        if !std::io::stdout().is_terminal() {
+            return;
+        }
         if let Some((r, g, b)) = color_to_rgb(self.cursor) {
             // OSC 12 sets cursor color: \x1b]12;#RRGGBB\x07
             // Best-effort terminal escape writes
@@ -586,7 +627,10 @@ impl Theme {
 
     /// Reset the terminal cursor color to default.
     pub fn reset_terminal_cursor_color() {
-        use std::io::Write;
+        use std::io::{IsTerminal, Write};
+        if !std::io::stdout().is_terminal() {
+            return;
+        }
         // OSC 112 resets cursor color to default
         // Best-effort terminal escape writes
         #[allow(clippy::let_underscore_must_use)]
@@ -788,6 +832,74 @@ mod tests {
         }
     }
 
+    /// TOML theme files load alongside JSON ones, resolve by relative path,
+    /// and a malformed file is skipped without hiding the rest.
+    #[test]
+    fn test_toml_theme_loading_from_user_dir() {
+        use ratatui::style::Color;
+
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let themes_dir = temp_dir.path().to_path_buf();
+
+        let theme_toml = r#"
+            name = "Solar Flare"
+            extends = "builtin://light"
+
+            [editor]
+            bg = [16, 32, 48]
+
+            [syntax]
+            keyword = [200, 100, 50]
+        "#;
+        std::fs::write(themes_dir.join("solar.toml"), theme_toml)
+            .expect("Failed to write theme file");
+        std::fs::write(themes_dir.join("broken.toml"), "name = ")
+            .expect("Failed to write theme file");
+
+        let registry = ThemeLoader::new(themes_dir.clone()).load_all(&[]);
+
+        let theme = registry
+            .get("solar.toml")
+            .expect("TOML theme should resolve by relative path");
+        assert_eq!(theme.name, "Solar Flare");
+        assert_eq!(theme.editor_bg, Color::Rgb(0x10, 0x20, 0x30));
+        assert_eq!(theme.syntax_keyword, Color::Rgb(200, 100, 50));
+        // Unspecified fields come from the `extends` base.
+        let light = Theme::load_builtin("light").unwrap();
+        assert_eq!(theme.editor_fg, light.editor_fg);
+
+        // Also reachable by name, and persisted as the portable path.
+        assert!(registry.contains("solar-flare"));
+        let key = registry.resolve_key("solar-flare").unwrap();
+        assert_eq!(registry.portable_form(&key).as_deref(), Some("solar.toml"));
+
+        assert!(
+            !registry
+                .list()
+                .iter()
+                .any(|t| t.key.ends_with("broken.toml")),
+            "Malformed theme file should be skipped"
+        );
+    }
+
+    #[test]
+    fn test_from_toml_matches_from_json() {
+        let from_toml = Theme::from_toml(
+            r#"
+            name = "same"
+            [ui]
+            status_bar_bg = [17, 34, 51]
+            "#,
+        )
+        .unwrap();
+        let from_json =
+            Theme::from_json(r#"{"name": "same", "ui": {"status_bar_bg": [17, 34, 51]}}"#).unwrap();
+        assert_eq!(from_toml.name, from_json.name);
+        assert_eq!(from_toml.status_bar_bg, from_json.status_bar_bg);
+        assert_eq!(from_toml.editor_bg, from_json.editor_bg);
+        assert!(Theme::from_toml("name = [").is_err());
+    }
+
     /// Test that custom themes in a package directory (with package.json) are loaded.
     #[test]
     fn test_custom_theme_package_loading() {
@@ -619,6 +619,10 @@ pub struct EditorColors {
     /// Whitespace indicator foreground color (for tab arrows and space dots)
     #[serde(default = "default_whitespace_indicator_fg")]
     pub whitespace_indicator_fg: ColorDef,
+    /// Trailing whitespace highlight background (`whitespace_highlight_trailing`).
+    /// When omitted, inherits the diagnostic error background.
+    #[serde(default)]
+    pub trailing_whitespace_bg: Option<ColorDef>,
     /// Bracket match highlight color (used when rainbow is disabled)
     #[serde(default = "default_bracket_match_fg")]
     pub bracket_match_fg: ColorDef,
@@ -1285,6 +1289,36 @@ pub struct DiagnosticColors {
     /// Hint highlight background
     #[serde(default = "default_diagnostic_hint_bg")]
     pub hint_bg: ColorDef,
+    /// How errors are marked in the text
+    #[serde(default = "default_diagnostic_style")]
+    pub error_style: DiagnosticStyle,
+    /// How warnings are marked in the text
+    #[serde(default = "default_diagnostic_style")]
+    pub warning_style: DiagnosticStyle,
+    /// How info diagnostics are marked in the text
+    #[serde(default = "default_diagnostic_style")]
+    pub info_style: DiagnosticStyle,
+    /// How hints are marked in the text
+    #[serde(default = "default_diagnostic_hint_style")]
+    pub hint_style: DiagnosticStyle,
+}
+
+/// How a diagnostic's range is marked in the text.
+///
+/// The underline styles are drawn in the severity's `*_fg` color. Terminals
+/// that can't render colored or curly underlines get the `*_bg` background
+/// instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DiagnosticStyle {
+    /// Curly underline
+    Undercurl,
+    /// Straight underline
+    Underline,
+    /// Dotted underline
+    Dotted,
+    /// Background highlight
+    Background,
 }
 
 // Default diagnostic colors
@@ -1312,6 +1346,12 @@ fn default_diagnostic_hint_fg() -> ColorDef {
 fn default_diagnostic_hint_bg() -> ColorDef {
     ColorDef::Rgb(30, 30, 30)
 }
+fn default_diagnostic_style() -> DiagnosticStyle {
+    DiagnosticStyle::Undercurl
+}
+fn default_diagnostic_hint_style() -> DiagnosticStyle {
+    DiagnosticStyle::Dotted
+}
 
 /// Syntax highlighting colors.
 ///
@@ -1431,6 +1471,7 @@ pub struct Theme {
 
     // Whitespace indicator color (tab arrows, space dots)
     pub whitespace_indicator_fg: Color,
+    pub trailing_whitespace_bg: Color,
 
     // Bracket matching colors
     pub bracket_match_fg: Color,
@@ -1591,6 +1632,10 @@ pub struct Theme {
     pub diagnostic_info_bg: Color,
     pub diagnostic_hint_fg: Color,
     pub diagnostic_hint_bg: Color,
+    pub diagnostic_error_style: DiagnosticStyle,
+    pub diagnostic_warning_style: DiagnosticStyle,
+    pub diagnostic_info_style: DiagnosticStyle,
+    pub diagnostic_hint_style: DiagnosticStyle,
 
     // Syntax highlighting colors
     pub syntax_keyword: Color,
@@ -1657,6 +1702,12 @@ impl From<ThemeFile> for Theme {
             indent_rainbow_5: file.editor.indent_rainbow_5.into(),
             indent_rainbow_6: file.editor.indent_rainbow_6.into(),
             whitespace_indicator_fg: file.editor.whitespace_indicator_fg.into(),
+            trailing_whitespace_bg: file
+                .editor
+                .trailing_whitespace_bg
+                .clone()
+                .unwrap_or_else(|| file.diagnostic.error_bg.clone())
+                .into(),
             bracket_match_fg: file.editor.bracket_match_fg.into(),
             bracket_rainbow_1: file.editor.bracket_rainbow_1.into(),
             bracket_rainbow_2: file.editor.bracket_rainbow_2.into(),
@@ -1855,6 +1906,10 @@ impl From<ThemeFile> for Theme {
             diagnostic_info_bg: file.diagnostic.info_bg.into(),
             diagnostic_hint_fg: file.diagnostic.hint_fg.into(),
             diagnostic_hint_bg: file.diagnostic.hint_bg.into(),
+            diagnostic_error_style: file.diagnostic.error_style,
+            diagnostic_warning_style: file.diagnostic.warning_style,
+            diagnostic_info_style: file.diagnostic.info_style,
+            diagnostic_hint_style: file.diagnostic.hint_style,
             syntax_keyword: file.syntax.keyword.color().clone().into(),
             syntax_keyword_modifier: file.syntax.keyword.modifier(),
             syntax_string: file.syntax.string.color().clone().into(),
@@ -1919,6 +1974,7 @@ impl From<Theme> for ThemeFile {
                 indent_rainbow_5: theme.indent_rainbow_5.into(),
                 indent_rainbow_6: theme.indent_rainbow_6.into(),
                 whitespace_indicator_fg: theme.whitespace_indicator_fg.into(),
+                trailing_whitespace_bg: Some(theme.trailing_whitespace_bg.into()),
                 bracket_match_fg: theme.bracket_match_fg.into(),
                 bracket_rainbow_1: theme.bracket_rainbow_1.into(),
                 bracket_rainbow_2: theme.bracket_rainbow_2.into(),
@@ -2029,6 +2085,10 @@ impl From<Theme> for ThemeFile {
                 info_bg: theme.diagnostic_info_bg.into(),
                 hint_fg: theme.diagnostic_hint_fg.into(),
                 hint_bg: theme.diagnostic_hint_bg.into(),
+                error_style: theme.diagnostic_error_style,
+                warning_style: theme.diagnostic_warning_style,
+                info_style: theme.diagnostic_info_style,
+                hint_style: theme.diagnostic_hint_style,
             },
             syntax: SyntaxColors {
                 keyword: StyledColorDef::from_parts(
@@ -2214,6 +2274,14 @@ impl Theme {
         // struct because every field has a serde default.
         let raw: serde_json::Value =
             serde_json::from_str(json).map_err(|e| format!("Failed to parse theme JSON: {}", e))?;
+        Self::from_value(raw)
+    }
+
+    /// Build a theme from an already-parsed theme document (no I/O).
+    ///
+    /// Shared by [`Theme::from_json`] and the TOML theme loader, which
+    /// converts its table into the same `serde_json::Value` shape first.
+    pub(crate) fn from_value(raw: serde_json::Value) -> Result<Self, String> {
         let theme_file: ThemeFile = serde_json::from_value(raw.clone())
             .map_err(|e| format!("Failed to parse theme: {}", e))?;
 
@@ -2382,6 +2450,7 @@ theme_color_keys! {
         "ruler_bg" => color ruler_bg,
         "selection_bg" => color selection_bg,
         "whitespace_indicator_fg" => color whitespace_indicator_fg,
+        "trailing_whitespace_bg" => color trailing_whitespace_bg,
         "bracket_match_fg" => color bracket_match_fg,
         "bracket_rainbow_1" => color bracket_rainbow_1,
         "bracket_rainbow_2" => color bracket_rainbow_2,
@@ -2927,6 +2996,23 @@ mod tests {
         assert_eq!(theme.indentation_guide_fg, Color::Rgb(12, 34, 56));
     }
 
+    #[test]
+    fn test_trailing_whitespace_bg_falls_back_to_diagnostic_error_bg() {
+        let json = r#"{
+            "name": "x",
+            "diagnostic": { "error_bg": [70, 10, 10] }
+        }"#;
+        let theme = Theme::from_json(json).expect("should parse");
+        assert_eq!(theme.trailing_whitespace_bg, Color::Rgb(70, 10, 10));
+
+        let json = r#"{
+            "name": "x",
+            "editor": { "trailing_whitespace_bg": [1, 2, 3] }
+        }"#;
+        let theme = Theme::from_json(json).expect("should parse");
+        assert_eq!(theme.trailing_whitespace_bg, Color::Rgb(1, 2, 3));
+    }
+
     #[test]
     fn test_indent_rainbow_colors_are_independent_from_bracket_colors() {
         let json = r#"{
@@ -3160,7 +3246,8 @@ mod tests {
                 .and_then(|v| v.as_object())
                 .unwrap_or_else(|| panic!("section `{section}` missing from serialized ThemeFile"));
             for (field, val) in fields {
-                if is_color_leaf(val) {
+                // Diagnostic `*_style` choices are strings too, but not colors.
+                if is_color_leaf(val) && !field.ends_with("_style") {
                     keys.push((section.to_string(), field.clone()));
                 }
             }
@@ -6,6 +6,7 @@
 //! inspector. The input and output structs are private to this module so they
 //! never leak to callers outside `split_rendering`.
 
+use crate::config::SecondaryCursorStyle;
 use crate::view::overlay::{Overlay, OverlayFace};
 use crate::view::theme::{Theme, TokenColorExt};
 use fresh_core::api::ViewTokenStyle;
@@ -40,6 +41,8 @@ pub(super) struct CharStyleContext<'a> {
     /// Skip REVERSED style on the primary cursor cell. True when a hardware
     /// cursor is available (not software_cursor_only), or in session mode.
     pub skip_primary_cursor_reverse: bool,
+    /// How secondary cursor cells are drawn.
+    pub secondary_cursor_style: crate::config::SecondaryCursorStyle,
     /// Whether this character is on the cursor line and current-line
     /// highlighting is enabled.
     pub is_cursor_line_highlighted: bool,
@@ -47,6 +50,14 @@ pub(super) struct CharStyleContext<'a> {
     pub current_line_bg: Color,
 }
 
+/// Text modifier that draws a secondary cursor in an active split.
+pub(super) fn secondary_cursor_modifier(style: SecondaryCursorStyle) -> Modifier {
+    match style {
+        SecondaryCursorStyle::Block => Modifier::REVERSED,
+        SecondaryCursorStyle::Underline => Modifier::UNDERLINED,
+    }
+}
+
 /// Output from [`compute_char_style`].
 pub(super) struct CharStyleOutput {
     pub style: Style,
@@ -169,9 +180,11 @@ pub(super) fn compute_char_style(ctx: &CharStyleContext) -> CharStyleOutput {
         match &overlay.face {
             OverlayFace::Underline {
                 color,
-                style: _underline_style,
+                style: underline_style,
             } => {
-                style = style.add_modifier(Modifier::UNDERLINED).fg(*color);
+                style = style
+                    .add_modifier(Modifier::UNDERLINED | underline_style.modifier())
+                    .fg(*color);
                 if let Some(key) = overlay.theme_key {
                     fg_theme_key = Some(key);
                 }
@@ -265,11 +278,9 @@ pub(super) fn compute_char_style(ctx: &CharStyleContext) -> CharStyleOutput {
     let is_secondary_cursor = ctx.is_cursor && ctx.byte_pos != Some(ctx.primary_cursor_position);
     if ctx.is_active {
         if ctx.is_cursor {
-            if ctx.skip_primary_cursor_reverse {
-                if is_secondary_cursor {
-                    style = style.add_modifier(Modifier::REVERSED);
-                }
-            } else {
+            if is_secondary_cursor {
+                style = style.add_modifier(secondary_cursor_modifier(ctx.secondary_cursor_style));
+            } else if !ctx.skip_primary_cursor_reverse {
                 style = style.add_modifier(Modifier::REVERSED);
             }
             region = "Cursor";
@@ -334,6 +345,7 @@ mod tests {
             primary_cursor_position: 0,
             is_active: true,
             skip_primary_cursor_reverse: true,
+            secondary_cursor_style: crate::config::SecondaryCursorStyle::Block,
             is_cursor_line_highlighted: false,
             current_line_bg: theme.current_line_bg,
         })
@@ -368,6 +380,7 @@ mod tests {
             primary_cursor_position: 0,
             is_active: true,
             skip_primary_cursor_reverse: true,
+            secondary_cursor_style: crate::config::SecondaryCursorStyle::Block,
             is_cursor_line_highlighted: false,
             current_line_bg: theme.current_line_bg,
         });
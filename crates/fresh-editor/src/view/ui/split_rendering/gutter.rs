@@ -8,6 +8,7 @@
 use super::folding::FoldIndicator;
 use super::layout::ComposeLayout;
 use super::spans::push_span_with_map;
+use crate::config::{GutterComponent, LineNumberMode};
 use crate::state::{EditorState, ViewMode};
 use crate::view::margin::{LineIndicator, MarginPosition};
 use crate::view::theme::Theme;
@@ -32,143 +33,172 @@ pub(super) struct LeftMarginContext<'a> {
     pub gutter_num: usize,
     pub estimated_lines: usize,
     pub diagnostic_lines: &'a HashSet<usize>,
-    pub line_indicators: &'a BTreeMap<usize, LineIndicator>,
+    pub change_indicators: &'a BTreeMap<usize, LineIndicator>,
+    pub bookmark_indicators: &'a BTreeMap<usize, LineIndicator>,
     pub fold_indicators: &'a BTreeMap<usize, FoldIndicator>,
     pub cursor_line_start_byte: usize,
     pub cursor_line_number: usize,
-    pub relative_line_numbers: bool,
+    pub line_number_mode: LineNumberMode,
     pub show_line_numbers: bool,
     pub byte_offset_mode: bool,
     pub highlight_current_line: bool,
     pub is_active: bool,
-    /// Optional glyph + color rendered into the indicator slot for
+    /// Optional glyph + color rendered into the first sign column for
     /// virtual lines (`ViewLine::virtual_gutter_glyph`). Source lines
-    /// pass `None` and fall back to the existing
-    /// diagnostic/fold/line-indicator lookup chain.
+    /// pass `None` and fall back to the per-component sign lookups.
     pub virtual_gutter_glyph: Option<&'a (String, Color)>,
 }
 
-/// Render the left margin (indicators + line numbers + separator) to the
-/// provided span / column-map buffers.
+/// The sign a gutter component shows on a line, if any.
+fn component_sign(
+    ctx: &LeftMarginContext,
+    component: GutterComponent,
+    line_start: usize,
+) -> Option<(String, Color)> {
+    match component {
+        GutterComponent::Diagnostics => ctx
+            .diagnostic_lines
+            .contains(&line_start)
+            .then(|| ("●".to_string(), Color::Red)),
+        GutterComponent::Bookmarks => ctx
+            .bookmark_indicators
+            .get(&line_start)
+            .map(|ind| (ind.symbol.clone(), ind.color)),
+        GutterComponent::Git => ctx
+            .change_indicators
+            .get(&line_start)
+            .map(|ind| (ind.symbol.clone(), ind.color)),
+        GutterComponent::Fold => ctx.fold_indicators.get(&line_start).map(|fold| {
+            let symbol = if fold.collapsed { "▸" } else { "▾" };
+            (symbol.to_string(), ctx.theme.line_number_fg)
+        }),
+        GutterComponent::LineNumbers => None,
+    }
+}
+
+/// Render the left margin (sign columns + line numbers + separator) to the
+/// provided span / column-map buffers, in the order of the configured
+/// gutter components.
 pub(super) fn render_left_margin(
     ctx: &LeftMarginContext,
     line_spans: &mut Vec<Span<'static>>,
     line_view_map: &mut Vec<Option<usize>>,
 ) {
-    if !ctx.state.margins.left_config.enabled {
+    let config = &ctx.state.margins.left_config;
+    if !config.enabled {
         return;
     }
 
     let lookup_key = ctx.line_start_byte;
-    // Pre-compute indicator bg for cursor line highlighting
-    let indicator_is_cursor_line = lookup_key.is_some_and(|k| k == ctx.cursor_line_start_byte);
-    let indicator_bg = if indicator_is_cursor_line && ctx.highlight_current_line && ctx.is_active {
-        Some(ctx.theme.current_line_bg)
-    } else {
-        None
+    let is_cursor_line = lookup_key.is_some_and(|k| k == ctx.cursor_line_start_byte);
+    let use_cursor_line_bg = is_cursor_line && ctx.highlight_current_line && ctx.is_active;
+    let with_line_bg = |style: Style| {
+        if use_cursor_line_bg {
+            style.bg(ctx.theme.current_line_bg)
+        } else {
+            style
+        }
     };
 
-    // Virtual lines whose source `VirtualText` carries a `gutter_glyph`
-    // get their own indicator (e.g. live-diff's "-" on a deletion
-    // line). This wins over the "continuation = blank" rule below so
-    // the glyph actually appears in the gutter; subsequent wrapped
-    // rows of the same virtual line set `virtual_gutter_glyph` to
-    // `None`, so they keep the blank fallback.
-    if let Some((glyph, color)) = ctx.virtual_gutter_glyph {
-        let mut style = Style::default().fg(*color);
-        if let Some(bg) = indicator_bg {
-            style = style.bg(bg);
-        }
-        push_span_with_map(line_spans, line_view_map, glyph.clone(), style, None);
-    } else if ctx.is_continuation {
-        // For continuation lines, don't show any indicators
-        let mut style = Style::default();
-        if let Some(bg) = indicator_bg {
-            style = style.bg(bg);
-        }
-        push_span_with_map(line_spans, line_view_map, " ".to_string(), style, None);
-    } else if lookup_key.is_some_and(|k| ctx.diagnostic_lines.contains(&k)) {
-        // Diagnostic indicators have highest priority
-        let mut style = Style::default().fg(Color::Red);
-        if let Some(bg) = indicator_bg {
-            style = style.bg(bg);
+    // Shared signs: one column at the first sign component's position,
+    // showing the sign of the first listed component that has one (so the
+    // default order gives diagnostics > bookmarks > git > fold).
+    let mut shared_sign_drawn = false;
+    let mut first_sign_column = true;
+    for &component in &config.components {
+        if component == GutterComponent::LineNumbers {
+            render_line_number(
+                ctx,
+                is_cursor_line,
+                use_cursor_line_bg,
+                line_spans,
+                line_view_map,
+            );
+            continue;
         }
-        push_span_with_map(line_spans, line_view_map, "●".to_string(), style, None);
-    } else if lookup_key.is_some_and(|k| {
-        ctx.fold_indicators.contains_key(&k) && !ctx.line_indicators.contains_key(&k)
-    }) {
-        let fold = ctx.fold_indicators.get(&lookup_key.unwrap()).unwrap();
-        let symbol = if fold.collapsed { "▸" } else { "▾" };
-        let mut style = Style::default().fg(ctx.theme.line_number_fg);
-        if let Some(bg) = indicator_bg {
-            style = style.bg(bg);
-        }
-        push_span_with_map(line_spans, line_view_map, symbol.to_string(), style, None);
-    } else if let Some(indicator) = lookup_key.and_then(|k| ctx.line_indicators.get(&k)) {
-        let mut style = Style::default().fg(indicator.color);
-        if let Some(bg) = indicator_bg {
-            style = style.bg(bg);
+        if config.shared_signs && shared_sign_drawn {
+            continue;
         }
+        shared_sign_drawn = true;
+
+        // Virtual lines whose source `VirtualText` carries a `gutter_glyph`
+        // get their own indicator (e.g. live-diff's "-" on a deletion
+        // line), in the first sign column. This wins over the
+        // "continuation = blank" rule so the glyph actually appears;
+        // subsequent wrapped rows of the same virtual line set
+        // `virtual_gutter_glyph` to `None`, so they stay blank.
+        let sign = if let Some(glyph) = ctx.virtual_gutter_glyph {
+            first_sign_column.then(|| glyph.clone())
+        } else if ctx.is_continuation {
+            None
+        } else if config.shared_signs {
+            lookup_key.and_then(|k| {
+                config
+                    .components
+                    .iter()
+                    .find_map(|&c| component_sign(ctx, c, k))
+            })
+        } else {
+            lookup_key.and_then(|k| component_sign(ctx, component, k))
+        };
+        first_sign_column = false;
+
+        let (text, style) = match sign {
+            Some((symbol, color)) => (symbol, Style::default().fg(color)),
+            None => (" ".to_string(), Style::default()),
+        };
+        push_span_with_map(line_spans, line_view_map, text, with_line_bg(style), None);
+    }
+
+    if config.show_separator {
+        let separator_style = with_line_bg(Style::default().fg(ctx.theme.line_number_fg));
         push_span_with_map(
             line_spans,
             line_view_map,
-            indicator.symbol.clone(),
-            style,
+            config.separator.clone(),
+            separator_style,
             None,
         );
-    } else {
-        let mut style = Style::default();
-        if let Some(bg) = indicator_bg {
-            style = style.bg(bg);
-        }
-        push_span_with_map(line_spans, line_view_map, " ".to_string(), style, None);
     }
+}
 
-    let is_cursor_line = lookup_key.is_some_and(|k| k == ctx.cursor_line_start_byte);
-    let use_cursor_line_bg = is_cursor_line && ctx.highlight_current_line && ctx.is_active;
+/// Render the line-number column: a byte offset in byte-offset mode,
+/// otherwise a number counted per `line_number_mode`.
+fn render_line_number(
+    ctx: &LeftMarginContext,
+    is_cursor_line: bool,
+    use_cursor_line_bg: bool,
+    line_spans: &mut Vec<Span<'static>>,
+    line_view_map: &mut Vec<Option<usize>>,
+) {
+    let width = ctx.state.margins.left_config.width;
+    if width == 0 {
+        return;
+    }
+    let number_style = if is_cursor_line {
+        Style::default().fg(ctx.theme.editor_fg)
+    } else {
+        Style::default().fg(ctx.theme.line_number_fg)
+    };
 
-    if ctx.is_continuation {
-        let blank = " ".repeat(ctx.state.margins.left_config.width);
-        let mut style = Style::default().fg(ctx.theme.line_number_fg);
-        if use_cursor_line_bg {
-            style = style.bg(ctx.theme.current_line_bg);
-        }
-        push_span_with_map(line_spans, line_view_map, blank, style, None);
+    let (rendered_text, mut margin_style) = if ctx.is_continuation {
+        (
+            " ".repeat(width),
+            Style::default().fg(ctx.theme.line_number_fg),
+        )
     } else if ctx.byte_offset_mode && ctx.show_line_numbers {
-        let rendered_text = format!(
-            "{:>width$}",
-            ctx.gutter_num,
-            width = ctx.state.margins.left_config.width
-        );
-        let mut margin_style = if is_cursor_line {
-            Style::default().fg(ctx.theme.editor_fg)
-        } else {
-            Style::default().fg(ctx.theme.line_number_fg)
-        };
-        if use_cursor_line_bg {
-            margin_style = margin_style.bg(ctx.theme.current_line_bg);
-        }
-        push_span_with_map(line_spans, line_view_map, rendered_text, margin_style, None);
-    } else if ctx.relative_line_numbers {
-        let display_num = if is_cursor_line {
-            ctx.gutter_num + 1
-        } else {
+        (format!("{:>width$}", ctx.gutter_num), number_style)
+    } else if ctx.line_number_mode != LineNumberMode::Absolute {
+        // Relative counts 0 on the cursor line; hybrid shows the cursor
+        // line's own number there instead.
+        let display_num = if !is_cursor_line {
             ctx.gutter_num.abs_diff(ctx.cursor_line_number)
-        };
-        let rendered_text = format!(
-            "{:>width$}",
-            display_num,
-            width = ctx.state.margins.left_config.width
-        );
-        let mut margin_style = if is_cursor_line {
-            Style::default().fg(ctx.theme.editor_fg)
+        } else if ctx.line_number_mode == LineNumberMode::Hybrid {
+            ctx.gutter_num + 1
         } else {
-            Style::default().fg(ctx.theme.line_number_fg)
+            0
         };
-        if use_cursor_line_bg {
-            margin_style = margin_style.bg(ctx.theme.current_line_bg);
-        }
-        push_span_with_map(line_spans, line_view_map, rendered_text, margin_style, None);
+        (format!("{:>width$}", display_num), number_style)
     } else {
         let margin_content = ctx.state.margins.render_line(
             ctx.gutter_num,
@@ -176,30 +206,16 @@ pub(super) fn render_left_margin(
             ctx.estimated_lines,
             ctx.show_line_numbers,
         );
-        let (rendered_text, style_opt) = margin_content.render(ctx.state.margins.left_config.width);
-
-        let mut margin_style =
-            style_opt.unwrap_or_else(|| Style::default().fg(ctx.theme.line_number_fg));
-        if use_cursor_line_bg {
-            margin_style = margin_style.bg(ctx.theme.current_line_bg);
-        }
-
-        push_span_with_map(line_spans, line_view_map, rendered_text, margin_style, None);
-    }
-
-    if ctx.state.margins.left_config.show_separator {
-        let mut separator_style = Style::default().fg(ctx.theme.line_number_fg);
-        if use_cursor_line_bg {
-            separator_style = separator_style.bg(ctx.theme.current_line_bg);
-        }
-        push_span_with_map(
-            line_spans,
-            line_view_map,
-            ctx.state.margins.left_config.separator.clone(),
-            separator_style,
-            None,
-        );
+        let (rendered_text, style_opt) = margin_content.render(width);
+        (
+            rendered_text,
+            style_opt.unwrap_or_else(|| Style::default().fg(ctx.theme.line_number_fg)),
+        )
+    };
+    if use_cursor_line_bg {
+        margin_style = margin_style.bg(ctx.theme.current_line_bg);
     }
+    push_span_with_map(line_spans, line_view_map, rendered_text, margin_style, None);
 }
 
 /// Paper-on-desk compose-mode margins flanking the content area.
@@ -56,7 +56,7 @@ pub struct EditorRenderConfig<'a> {
     pub line_wrap: bool,
     pub estimated_line_length: usize,
     pub highlight_context_bytes: usize,
-    pub relative_line_numbers: bool,
+    pub gutter: crate::view::margin::GutterSettings<'a>,
     pub use_terminal_bg: bool,
     pub show_vertical_scrollbar: bool,
     pub show_horizontal_scrollbar: bool,
@@ -69,6 +69,7 @@ pub struct EditorRenderConfig<'a> {
     pub hide_current_line_on_selection: bool,
     pub background_fade: f32,
     pub software_cursor_only: bool,
+    pub secondary_cursor_style: crate::config::SecondaryCursorStyle,
 }
 
 impl<'a> EditorRenderConfig<'a> {
@@ -86,7 +87,7 @@ impl<'a> EditorRenderConfig<'a> {
             line_wrap: editor.line_wrap,
             estimated_line_length: editor.estimated_line_length,
             highlight_context_bytes: editor.highlight_context_bytes,
-            relative_line_numbers: editor.relative_line_numbers,
+            gutter: crate::view::margin::GutterSettings::from_config(editor),
             use_terminal_bg: editor.use_terminal_bg,
             show_vertical_scrollbar: editor.show_vertical_scrollbar,
             show_horizontal_scrollbar: editor.show_horizontal_scrollbar,
@@ -99,6 +100,7 @@ impl<'a> EditorRenderConfig<'a> {
             hide_current_line_on_selection: editor.hide_current_line_on_selection,
             background_fade,
             software_cursor_only,
+            secondary_cursor_style: editor.secondary_cursor_style,
         }
     }
 }
@@ -214,7 +216,7 @@ impl SplitRenderer {
         lsp_waiting: bool,
         estimated_line_length: usize,
         highlight_context_bytes: usize,
-        relative_line_numbers: bool,
+        gutter: crate::view::margin::GutterSettings<'_>,
         use_terminal_bg: bool,
         session_mode: bool,
         software_cursor_only: bool,
@@ -233,7 +235,7 @@ impl SplitRenderer {
             lsp_waiting,
             estimated_line_length,
             highlight_context_bytes,
-            relative_line_numbers,
+            gutter,
             use_terminal_bg,
             session_mode,
             software_cursor_only,
@@ -530,9 +532,10 @@ mod tests {
             line_wrap: viewport.line_wrap_enabled,
             estimated_lines,
             left_column: viewport.left_column,
-            relative_line_numbers: false,
+            line_number_mode: crate::config::LineNumberMode::Absolute,
             session_mode: false,
             software_cursor_only: false,
+            secondary_cursor_style: crate::config::SecondaryCursorStyle::Block,
             show_line_numbers: true, // Tests show line numbers
             byte_offset_mode: false, // Tests use exact line numbers
             show_tilde: true,
@@ -636,9 +639,10 @@ mod tests {
             line_wrap: viewport.line_wrap_enabled,
             estimated_lines,
             left_column: viewport.left_column,
-            relative_line_numbers: false,
+            line_number_mode: crate::config::LineNumberMode::Absolute,
             session_mode: false,
             software_cursor_only: false,
+            secondary_cursor_style: crate::config::SecondaryCursorStyle::Block,
             show_line_numbers: true,
             byte_offset_mode: false,
             show_tilde: true,
@@ -1241,7 +1245,13 @@ mod tests {
         let start = state.buffer.line_start_offset(1).unwrap();
         let end = state.buffer.line_start_offset(3).unwrap();
         let mut folds = FoldManager::new();
-        folds.add(&mut state.marker_list, start, end, Some("...".to_string()));
+        folds.add(
+            &state.buffer,
+            &mut state.marker_list,
+            start,
+            end,
+            Some("...".to_string()),
+        );
 
         let viewport = Viewport::new(40, 6);
         let gutter_width = state.margins.left_total_width();
@@ -1345,7 +1355,7 @@ mod tests {
         let start = state.buffer.line_start_offset(1).unwrap();
         let end = state.buffer.line_start_offset(2).unwrap();
         let mut folds = FoldManager::new();
-        folds.add(&mut state.marker_list, start, end, None);
+        folds.add(&state.buffer, &mut state.marker_list, start, end, None);
 
         let line1_byte = state.buffer.line_start_offset(1).unwrap();
         let view_lines = vec![ViewLine {
@@ -3186,9 +3196,10 @@ mod tests {
             line_wrap: viewport.line_wrap_enabled,
             estimated_lines,
             left_column: viewport.left_column,
-            relative_line_numbers: false,
+            line_number_mode: crate::config::LineNumberMode::Absolute,
             session_mode: false,
             software_cursor_only: false,
+            secondary_cursor_style: crate::config::SecondaryCursorStyle::Block,
             show_line_numbers: false,
             byte_offset_mode: false,
             show_tilde: true,
@@ -49,8 +49,10 @@ pub(crate) struct DecorationContext {
     /// Inline diagnostic text per line. Derived from viewport overlays;
     /// highest severity wins per line.
     pub diagnostic_inline_texts: HashMap<usize, (String, Style)>,
-    /// Line indicators indexed by line-start byte offset.
-    pub line_indicators: BTreeMap<usize, LineIndicator>,
+    /// Git, diff and plugin line indicators indexed by line-start byte offset.
+    pub change_indicators: BTreeMap<usize, LineIndicator>,
+    /// Bookmark indicators indexed by line-start byte offset.
+    pub bookmark_indicators: BTreeMap<usize, LineIndicator>,
     /// Fold indicators indexed by line-start byte offset.
     pub fold_indicators: BTreeMap<usize, FoldIndicator>,
 }
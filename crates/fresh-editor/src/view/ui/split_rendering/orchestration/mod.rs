@@ -1104,7 +1104,7 @@ pub(crate) fn compute_content_layout(
     lsp_waiting: bool,
     estimated_line_length: usize,
     highlight_context_bytes: usize,
-    relative_line_numbers: bool,
+    gutter: crate::view::margin::GutterSettings<'_>,
     use_terminal_bg: bool,
     session_mode: bool,
     software_cursor_only: bool,
@@ -1216,10 +1216,11 @@ pub(crate) fn compute_content_layout(
             view_prefs.view_transform,
             estimated_line_length,
             highlight_context_bytes,
-            relative_line_numbers,
+            gutter,
             use_terminal_bg,
             session_mode,
             software_cursor_only,
+            crate::config::SecondaryCursorStyle::default(),
             view_prefs.show_line_numbers,
             effective_highlight_current_line,
             diagnostics_inline_text,
@@ -6,9 +6,11 @@
 use super::super::folding::{diff_indicators_for_viewport, fold_indicators_for_viewport};
 use super::super::style::inline_diagnostic_style;
 use super::contexts::{DecorationContext, SelectionContext};
+use crate::model::buffer_position::byte_to_2d;
 use crate::model::cursor::{Cursors, SelectionMode};
 use crate::state::{EditorState, ViewMode};
 use crate::view::folding::FoldManager;
+use crate::view::margin::LINE_BOOKMARK_NAMESPACE;
 use crate::view::theme::Theme;
 use crate::view::ui::view_pipeline::ViewLine;
 use ratatui::style::Style;
@@ -48,16 +50,17 @@ pub(crate) fn selection_context(state: &EditorState, cursors: &Cursors) -> Selec
     ranges.sort_by_key(|r| r.start);
 
     let block_virtual = state.buffer_settings.virtual_space.block_beyond_eol();
+    let tab_size = state.buffer_settings.tab_size;
     let mut block_rects: Vec<(usize, usize, usize, usize)> = cursors
         .iter()
         .filter_map(|(_, cursor)| {
             if cursor.selection_mode == SelectionMode::Block {
                 if let Some(anchor) = cursor.block_anchor {
-                    let cur_line = state.buffer.get_line_number(cursor.position);
-                    let cur_line_start = state.buffer.line_start_offset(cur_line).unwrap_or(0);
-                    let mut cur_col = cursor.position.saturating_sub(cur_line_start);
+                    let cur = byte_to_2d(&state.buffer, cursor.position, tab_size);
+                    let cur_line = cur.line;
+                    let mut cur_col = cur.column;
                     // With virtual space, the block column (carried by the
-                    // sticky column) may extend past the clipped byte column.
+                    // sticky column) may extend past the line's content.
                     if block_virtual {
                         if let Some(sticky) = cursor.sticky_column {
                             cur_col = cur_col.max(sticky);
@@ -289,19 +292,27 @@ pub(crate) fn decoration_context(
         HashMap::new()
     };
 
-    // Pre-compute line indicators for the viewport.
-    let mut line_indicators =
-        state
-            .margins
-            .get_indicators_for_viewport(viewport_start, viewport_end, |byte_offset| {
-                indent_folding::find_line_start_byte(&state.buffer, byte_offset)
-            });
+    // Pre-compute line indicators for the viewport. Bookmarks and change
+    // markers are separate gutter components, so they are looked up apart.
+    let line_start = |byte_offset| indent_folding::find_line_start_byte(&state.buffer, byte_offset);
+    let bookmark_indicators = state.margins.get_indicators_for_viewport(
+        viewport_start,
+        viewport_end,
+        line_start,
+        |namespace| namespace == LINE_BOOKMARK_NAMESPACE,
+    );
+    let mut change_indicators = state.margins.get_indicators_for_viewport(
+        viewport_start,
+        viewport_end,
+        line_start,
+        |namespace| namespace != LINE_BOOKMARK_NAMESPACE,
+    );
 
     // Merge native diff-since-saved indicators (cornflower blue │ for unsaved edits).
     // These have priority 5, lower than git gutter (10).
     let diff_indicators = diff_indicators_for_viewport(state, viewport_start, viewport_end);
     for (key, diff_ind) in diff_indicators {
-        line_indicators.entry(key).or_insert(diff_ind);
+        change_indicators.entry(key).or_insert(diff_ind);
     }
 
     let fold_indicators = fold_indicators_for_viewport(state, folds, view_lines);
@@ -313,7 +324,8 @@ pub(crate) fn decoration_context(
         overlay_position_index,
         diagnostic_lines,
         diagnostic_inline_texts,
-        line_indicators,
+        change_indicators,
+        bookmark_indicators,
         fold_indicators,
     }
 }
@@ -103,10 +103,11 @@ pub(crate) fn compute_buffer_layout(
     view_transform: Option<ViewTransformPayload>,
     estimated_line_length: usize,
     highlight_context_bytes: usize,
-    relative_line_numbers: bool,
+    gutter: crate::view::margin::GutterSettings<'_>,
     use_terminal_bg: bool,
     session_mode: bool,
     software_cursor_only: bool,
+    secondary_cursor_style: crate::config::SecondaryCursorStyle,
     show_line_numbers: bool,
     highlight_current_line: bool,
     diagnostics_inline_text: bool,
@@ -118,7 +119,9 @@ pub(crate) fn compute_buffer_layout(
 ) -> BufferLayoutOutput {
     let _span = tracing::trace_span!("compute_buffer_layout").entered();
 
-    // Configure shared margin layout for this split's line number setting.
+    // Configure shared margin layout for the gutter settings and this
+    // split's line number setting.
+    state.margins.set_gutter_layout(&gutter);
     state.margins.configure_for_line_numbers(show_line_numbers);
 
     // Compute effective editor background: terminal default or theme-defined
@@ -159,6 +162,7 @@ pub(crate) fn compute_buffer_layout(
         state.margins.left_config.width = 0;
     }
     let mut gutter_width = state.margins.left_total_width();
+    viewport.gutter_shape = state.margins.left_config.shape();
 
     let mut compose_layout = calculate_compose_layout(area, &view_mode, compose_width);
     // In compose mode the gutter (diagnostic / indicator slot) is drawn in the
@@ -386,9 +390,10 @@ pub(crate) fn compute_buffer_layout(
         line_wrap,
         estimated_lines,
         left_column: viewport.left_column,
-        relative_line_numbers,
+        line_number_mode: gutter.line_number_mode,
         session_mode,
         software_cursor_only,
+        secondary_cursor_style,
         show_line_numbers,
         byte_offset_mode,
         show_tilde,
@@ -652,9 +657,10 @@ pub(crate) fn render_buffer_in_split(
         background_fade,
         estimated_line_length,
         highlight_context_bytes,
-        relative_line_numbers,
+        gutter,
         use_terminal_bg,
         software_cursor_only,
+        secondary_cursor_style,
         diagnostics_inline_text,
         indentation_guide,
         indentation_guide_glyph,
@@ -675,10 +681,11 @@ pub(crate) fn render_buffer_in_split(
         view_transform,
         estimated_line_length,
         highlight_context_bytes,
-        relative_line_numbers,
+        gutter,
         use_terminal_bg,
         session_mode,
         software_cursor_only,
+        secondary_cursor_style,
         show_line_numbers,
         highlight_current_line,
         diagnostics_inline_text,
@@ -11,7 +11,9 @@
 //! Each per-cell concern (cursor detection, style resolution, display
 //! character, span emission, position bookkeeping) is its own method.
 
-use super::super::super::char_style::{compute_char_style, CharStyleContext, CharStyleOutput};
+use super::super::super::char_style::{
+    compute_char_style, secondary_cursor_modifier, CharStyleContext, CharStyleOutput,
+};
 use super::super::super::spans::{
     push_debug_tag, push_span_with_map, span_bg_info_at, span_color_at, span_info_at,
     DebugSpanTracker, SpanAccumulator,
@@ -57,6 +59,8 @@ pub(super) struct CellPassInput<'a, 'c> {
     /// Skip REVERSED style on the primary cursor (session mode or
     /// non-block cursor style).
     pub session_mode: bool,
+    /// How secondary cursors are drawn.
+    pub secondary_cursor_style: crate::config::SecondaryCursorStyle,
     pub is_on_cursor_line: bool,
     pub highlight_current_line: bool,
     pub indentation_guide: IndentationGuideMode,
@@ -283,8 +287,10 @@ impl CellPass<'_, '_, '_> {
         let is_primary_cursor =
             is_cursor && byte_pos == Some(self.input.selection.primary_cursor_position);
         let exclude_from_selection = is_cursor && !(self.input.is_active && is_primary_cursor);
-        let is_selected =
-            !exclude_from_selection && self.selection_sweep.contains(byte_pos, self.byte_index);
+        let is_selected = !exclude_from_selection
+            && self
+                .selection_sweep
+                .contains(byte_pos, self.col_offset, char_width(ch));
 
         let resolved = self.resolve_cell_style(byte_pos, ansi_style, is_cursor, is_selected);
         self.record_cell_theme(&resolved);
@@ -325,6 +331,9 @@ impl CellPass<'_, '_, '_> {
         } else if is_whitespace_indicator && !is_cursor && !is_selected {
             style = style.fg(self.input.theme.whitespace_indicator_fg);
         }
+        if !is_cursor && !is_selected && self.is_trailing_whitespace_highlight(ch, byte_pos) {
+            style = style.bg(self.input.theme.trailing_whitespace_bg);
+        }
 
         if !display_char.is_empty() {
             self.emit_cell(display_char, style, byte_pos, ch);
@@ -396,6 +405,39 @@ impl CellPass<'_, '_, '_> {
             .indent_rainbow_color(self.col_offset / tab_size)
     }
 
+    /// Whether this cell is source whitespace at the end of a logical line
+    /// and `whitespace_highlight_trailing` is on. Soft-wrapped rows only
+    /// count on the row that actually ends the line, so spaces before a wrap
+    /// point are not mistaken for trailing whitespace.
+    fn is_trailing_whitespace_highlight(&self, ch: char, byte_pos: Option<usize>) -> bool {
+        if !self
+            .input
+            .state
+            .buffer_settings
+            .whitespace
+            .highlight_trailing
+            || !matches!(ch, ' ' | '\t')
+            || byte_pos.is_none()
+        {
+            return false;
+        }
+        let view_line = self.input.view_line;
+        let ends_logical_line = view_line.ends_with_newline
+            || view_line
+                .char_source_bytes
+                .iter()
+                .rev()
+                .find_map(|b| *b)
+                .is_some_and(|last| last + 1 >= self.input.state.buffer.len());
+        if !ends_logical_line {
+            return false;
+        }
+        match self.non_ws {
+            (_, Some(last)) => self.display_char_idx > last,
+            _ => true,
+        }
+    }
+
     fn is_leading_indent_cell(&self) -> bool {
         match self.non_ws {
             (Some(first), _) => self.display_char_idx < first,
@@ -509,6 +551,7 @@ impl CellPass<'_, '_, '_> {
             primary_cursor_position: input.selection.primary_cursor_position,
             is_active: input.is_active,
             skip_primary_cursor_reverse: input.session_mode,
+            secondary_cursor_style: input.secondary_cursor_style,
             is_cursor_line_highlighted: input.is_on_cursor_line
                 && input.highlight_current_line
                 && input.is_active,
@@ -589,6 +632,9 @@ impl CellPass<'_, '_, '_> {
         } else if self.debug_tracker.is_some() && ch == '\n' {
             // Debug mode: show LF explicitly
             ("\\n", false)
+        } else if ch == '\n' && ws.line_endings {
+            // Visual indicator for the line ending
+            ('↵'.encode_utf8(indicator_buf), true)
         } else if ch == '\n' {
             ("", false)
         } else if ws_show_tab {
@@ -686,7 +732,11 @@ impl CellPass<'_, '_, '_> {
                     self.line_spans,
                     self.line_view_map,
                     " ".to_string(),
-                    cursor_indicator_style(self.input.theme, self.input.is_active),
+                    cursor_indicator_style(
+                        self.input.theme,
+                        self.input.is_active,
+                        secondary_cursor_modifier(self.input.secondary_cursor_style),
+                    ),
                     byte_pos,
                 );
             }
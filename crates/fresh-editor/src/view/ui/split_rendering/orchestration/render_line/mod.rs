@@ -11,6 +11,7 @@
 //! The post-loop work (implicit trailing line, EOF tildes) lives in
 //! `trailing`. Everything here is quarantined to `orchestration/`.
 
+use super::super::char_style::secondary_cursor_modifier;
 use super::super::gutter::{render_left_margin, LeftMarginContext};
 use super::super::layout::ViewAnchor;
 use super::super::spans::push_span_with_map;
@@ -65,12 +66,14 @@ pub(crate) struct LineRenderInput<'a> {
     pub estimated_lines: usize,
     /// Left column offset for horizontal scrolling
     pub left_column: usize,
-    /// Whether to show relative line numbers (distance from cursor)
-    pub relative_line_numbers: bool,
+    /// How line numbers count (absolute, or distance from the cursor)
+    pub line_number_mode: crate::config::LineNumberMode,
     /// Skip REVERSED style on the primary cursor (session mode or non-block cursor style)
     pub session_mode: bool,
     /// No hardware cursor: always render software cursor indicators
     pub software_cursor_only: bool,
+    /// How secondary cursors are drawn
+    pub secondary_cursor_style: crate::config::SecondaryCursorStyle,
     /// Whether to show line numbers in the gutter
     pub show_line_numbers: bool,
     /// Whether the gutter shows byte offsets instead of line numbers
@@ -480,9 +483,10 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
         line_wrap,
         estimated_lines,
         left_column,
-        relative_line_numbers,
+        line_number_mode,
         session_mode,
         software_cursor_only,
+        secondary_cursor_style,
         show_line_numbers,
         byte_offset_mode,
         show_tilde,
@@ -559,7 +563,7 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
     // Cursors for O(1) amortized span lookups (spans are sorted by byte range)
     let mut span_cursors = SpanCursors::default();
     // Linear-range + block-rect selection sweep. The cell loop just
-    // asks `contains(byte_pos, byte_index)` — see SelectionActiveSet.
+    // asks `contains(byte_pos, col, width)` — see SelectionActiveSet.
     let mut selection_sweep = SelectionActiveSet::new(&selection.ranges, &selection.block_rects);
 
     // Overlay sweep: O(1) amortised per cell, zero allocation per cell.
@@ -715,11 +719,12 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                 gutter_num,
                 estimated_lines,
                 diagnostic_lines: &decorations.diagnostic_lines,
-                line_indicators: &decorations.line_indicators,
+                change_indicators: &decorations.change_indicators,
+                bookmark_indicators: &decorations.bookmark_indicators,
                 fold_indicators: &decorations.fold_indicators,
                 cursor_line_start_byte,
                 cursor_line_number: state.primary_cursor_line_number.value(),
-                relative_line_numbers,
+                line_number_mode,
                 show_line_numbers,
                 byte_offset_mode,
                 highlight_current_line,
@@ -799,6 +804,7 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                 lsp_waiting,
                 is_active,
                 session_mode,
+                secondary_cursor_style,
                 is_on_cursor_line,
                 highlight_current_line,
                 indentation_guide,
@@ -830,7 +836,14 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                 LineStart::AfterBreak | LineStart::AfterInjectedNewline
             )
         {
-            let row_len = line_content.trim_end_matches(['\r', '\n']).len();
+            // Display width of the row's content, in the same columns as
+            // the block rects.
+            let content_chars = line_content.trim_end_matches(['\r', '\n']).chars().count();
+            let row_len = if content_chars < current_view_line.char_visual_cols.len() {
+                current_view_line.visual_col_at_char(content_chars)
+            } else {
+                current_view_line.visual_width()
+            };
             let overlap = selection
                 .block_rects
                 .iter()
@@ -885,6 +898,7 @@ pub(crate) fn render_view_lines(input: LineRenderInput<'_>) -> LineRenderOutput
                     theme,
                     is_active,
                     software_cursor_only,
+                    secondary_cursor_style,
                     gutter_width,
                     left_col,
                     col_offset: cells.col_offset,
@@ -1159,13 +1173,15 @@ fn prefill_cell_theme_map(
     }
 }
 
-/// Style for the software cursor indicator cell.
-fn cursor_indicator_style(theme: &Theme, is_active: bool) -> Style {
+/// Style for the software cursor indicator cell. `cursor_modifier` draws
+/// the cursor in an active split: reverse video for the primary cursor,
+/// or the secondary cursor style.
+fn cursor_indicator_style(theme: &Theme, is_active: bool, cursor_modifier: Modifier) -> Style {
     if is_active {
         Style::default()
             .fg(theme.editor_fg)
             .bg(theme.editor_bg)
-            .add_modifier(Modifier::REVERSED)
+            .add_modifier(cursor_modifier)
     } else {
         Style::default()
             .fg(theme.editor_fg)
@@ -1181,6 +1197,7 @@ struct LineEndCursorInput<'a> {
     theme: &'a Theme,
     is_active: bool,
     software_cursor_only: bool,
+    secondary_cursor_style: crate::config::SecondaryCursorStyle,
     gutter_width: usize,
     left_col: usize,
     /// Visual column after the last processed character.
@@ -1282,11 +1299,18 @@ fn place_line_end_cursor(
                 None,
             );
         }
+        // Unless only software cursors are drawn, an indicator here
+        // belongs to a secondary cursor.
+        let cursor_modifier = if is_primary_at_end {
+            Modifier::REVERSED
+        } else {
+            secondary_cursor_modifier(input.secondary_cursor_style)
+        };
         push_span_with_map(
             line_spans,
             line_view_map,
             " ".to_string(),
-            cursor_indicator_style(input.theme, input.is_active),
+            cursor_indicator_style(input.theme, input.is_active, cursor_modifier),
             None,
         );
     }
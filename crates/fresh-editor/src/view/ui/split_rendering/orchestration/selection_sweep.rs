@@ -10,13 +10,13 @@
 //!   anything from there with `start <= bp` is a candidate.
 //! * **Block (rectangular) selections** (`SelectionContext::block_rects`)
 //!   — entries are `(start_line, start_col, end_line, end_col)` with
-//!   columns in per-line byte indices. Sorted by `start_line`. We
+//!   columns in per-line display columns. Sorted by `start_line`. We
 //!   maintain an active set of rects whose `[start_line, end_line]`
 //!   includes the current visible line, refreshed once per row as
-//!   `gutter_num` advances; each cell tests `byte_index` against
-//!   the rect's column span.
+//!   `gutter_num` advances; each cell tests its visual column span
+//!   against the rect's column span.
 //!
-//! Net: the cell loop just calls `contains(byte_pos, byte_index)`.
+//! Net: the cell loop just calls `contains(byte_pos, col, width)`.
 
 use std::ops::Range;
 
@@ -83,10 +83,16 @@ impl<'a> SelectionActiveSet<'a> {
     /// (ANSI / virtual cells) — those still get block-rect checks
     /// but no linear-range coverage, matching the existing logic.
     ///
-    /// `cell_byte_index` is the cell's per-line byte index (used by
-    /// the block-rect column-span check, matching how
-    /// `block_rects` stores its column bounds).
-    pub(super) fn contains(&mut self, buffer_byte: Option<usize>, cell_byte_index: usize) -> bool {
+    /// `cell_col` and `cell_width` are the cell's visual column and
+    /// display width (used by the block-rect column-span check, matching
+    /// how `block_rects` stores its column bounds). A double-width
+    /// character is selected when either of its halves is inside a rect.
+    pub(super) fn contains(
+        &mut self,
+        buffer_byte: Option<usize>,
+        cell_col: usize,
+        cell_width: usize,
+    ) -> bool {
         let linear = buffer_byte.is_some_and(|bp| {
             while self.range_cursor < self.ranges.len() && self.ranges[self.range_cursor].end <= bp
             {
@@ -99,7 +105,7 @@ impl<'a> SelectionActiveSet<'a> {
         });
         let block = self.active_block.iter().any(|&i| {
             let (_, start_col, _, end_col) = self.blocks[i];
-            cell_byte_index >= start_col && cell_byte_index <= end_col
+            cell_col + cell_width.max(1) > start_col && cell_col <= end_col
         });
         linear || block
     }
@@ -5,13 +5,13 @@ use std::path::Path;
 
 use crate::app::types::CellThemeRecorder;
 use crate::app::WarningLevel;
-use crate::config::{StatusBarConfig, StatusBarElement, VirtualSpaceMode};
+use crate::config::{StatusBarConfig, StatusBarElement, StatusBarSegment, VirtualSpaceMode};
 use crate::primitives::display_width::{char_width, str_width};
 use crate::state::EditorState;
 use crate::view::prompt::Prompt;
 use chrono::Timelike;
 use ratatui::layout::Rect;
-use ratatui::style::{Modifier, Style};
+use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
 use ratatui::Frame;
@@ -41,6 +41,8 @@ pub enum StatusBarClickable {
     LineEnding,
     Encoding,
     Language,
+    /// The `E:n W:n` diagnostic counts — click to open the diagnostics panel.
+    Diagnostics,
     Lsp,
     Warnings,
     Messages,
@@ -49,6 +51,8 @@ pub enum StatusBarClickable {
     ReadOnly,
     /// The "Update: vX.Y.Z" indicator — click to offer an in-editor update.
     Update,
+    /// The running-jobs indicator — click to open the jobs menu.
+    Jobs,
 }
 
 /// Categorization of how a rendered element should be styled and tracked for click detection.
@@ -62,8 +66,12 @@ enum ElementKind {
     Encoding,
     /// Language indicator (clickable)
     Language,
+    /// Diagnostic counts (clickable, opens the diagnostics panel)
+    Diagnostics,
     /// LSP status indicator (colored by warning level, clickable)
     Lsp,
+    /// Running jobs indicator (clickable, opens the jobs menu)
+    Jobs,
     /// Warning badge (colored, clickable)
     WarningBadge,
     /// Update available indicator (highlighted)
@@ -201,6 +209,16 @@ impl RemoteIndicatorOverride {
     }
 }
 
+/// One element of a rendered side: spans, width, kind, plugin token key
+/// and `status_bar.segments` entry (see `StatusBarRenderer::render_side`).
+type SideItem<'c> = (
+    Vec<Span<'static>>,
+    usize,
+    ElementKind,
+    Option<String>,
+    Option<&'c StatusBarSegment>,
+);
+
 /// A single rendered status bar element with its text and styling info.
 struct RenderedElement {
     text: String,
@@ -248,6 +266,9 @@ pub struct StatusBarContext<'a> {
     /// latter still scopes whether a warning badge is shown on the right
     /// side of the status bar).
     pub lsp_indicator_state: LspIndicatorState,
+    /// Text of the `{jobs}` element (the running job's progress, or how
+    /// many run); empty hides it.
+    pub jobs_status: &'a str,
     pub theme: &'a crate::view::theme::Theme,
     pub display_name: &'a str,
     pub keybindings: &'a crate::input::keybindings::KeybindingResolver,
@@ -266,6 +287,11 @@ pub struct StatusBarContext<'a> {
     pub remote_connection: Option<&'a str>,
     pub session_name: Option<&'a str>,
     pub read_only: bool,
+    /// Source lines of the active buffer when it is narrowed; `None` hides
+    /// the `{narrowed}` element.
+    pub narrowed: Option<&'a str>,
+    /// Editor mode set by a modal-editing plugin; `None` hides `{mode}`.
+    pub editor_mode: Option<&'a str>,
     /// Plugin-supplied override for the `{remote}` indicator. When
     /// `Some`, its state+label are rendered instead of the one
     /// derived from `remote_connection`. Set via the
@@ -331,6 +357,10 @@ pub struct StatusBarLayout {
     /// `docs/internal/trust-env-devcontainer-ux-plan.md`
     /// §"Path from here to the North Star".
     pub plugin_token_areas: std::collections::HashMap<String, (u16, u16, u16)>,
+    /// Areas of elements given an `on_click` action in
+    /// `status_bar.segments`, as `(action, row, start_col, end_col)`. A hit
+    /// here runs the action instead of the element's own click behavior.
+    pub segment_actions: Vec<(String, u16, u16, u16)>,
     /// Every rendered element, in screen order, with its semantic name, text and
     /// cell position. This is the status bar's semantic model: a frontend renders
     /// it directly (web) instead of scraping the drawn cells, and the TUI cell
@@ -343,7 +373,8 @@ pub struct StatusBarLayout {
 #[derive(Debug, Clone)]
 pub struct StatusSegmentInfo {
     /// Semantic kind: "lsp" | "warning" | "language" | "encoding" |
-    /// "lineEnding" | "remote" | "trust" | "message" | "plugin" | "text".
+    /// "diagnostics" | "lineEnding" | "remote" | "trust" | "message" |
+    /// "plugin" | "text".
     pub name: &'static str,
     /// Plugin token key for `name == "plugin"`.
     pub key: Option<String>,
@@ -364,6 +395,7 @@ fn element_kind_name(kind: ElementKind) -> &'static str {
         ElementKind::WarningBadge => "warning",
         ElementKind::Language => "language",
         ElementKind::Encoding => "encoding",
+        ElementKind::Diagnostics => "diagnostics",
         ElementKind::LineEnding => "lineEnding",
         ElementKind::RemoteIndicator(_) => "remote",
         ElementKind::WorkspaceTrust(_) => "trust",
@@ -382,6 +414,7 @@ pub enum SearchOptionsHover {
     WholeWord,
     Regex,
     ConfirmEach,
+    Multiline,
 }
 
 /// Layout information for search options bar hit testing
@@ -397,6 +430,8 @@ pub struct SearchOptionsLayout {
     pub regex: Option<(u16, u16)>,
     /// Confirm Each checkbox area (start_col, end_col) - only present in replace mode
     pub confirm_each: Option<(u16, u16)>,
+    /// Multiline checkbox area (start_col, end_col)
+    pub multiline: Option<(u16, u16)>,
 }
 
 impl SearchOptionsLayout {
@@ -426,6 +461,11 @@ impl SearchOptionsLayout {
                 return Some(SearchOptionsHover::ConfirmEach);
             }
         }
+        if let Some((start, end)) = self.multiline {
+            if x >= start && x < end {
+                return Some(SearchOptionsHover::Multiline);
+            }
+        }
         None
     }
 }
@@ -771,13 +811,13 @@ impl StatusBarRenderer {
         Self::render_status(frame, area, ctx, config, rec, draw)
     }
 
-    /// Render the prompt/minibuffer
+    /// Render the prompt/minibuffer, returning the caret cell if it fits.
     pub fn render_prompt(
         frame: &mut Frame,
         area: Rect,
         prompt: &Prompt,
         theme: &crate::view::theme::Theme,
-    ) {
+    ) -> Option<(u16, u16)> {
         let base_style = Style::default().fg(theme.prompt_fg).bg(theme.prompt_bg);
 
         // Create spans for the prompt
@@ -827,19 +867,22 @@ impl StatusBarRenderer {
         let cursor_x = (message_width + input_width_before_cursor) as u16;
         if cursor_x < area.width {
             frame.set_cursor_position((area.x + cursor_x, area.y));
+            return Some((area.x + cursor_x, area.y));
         }
+        None
     }
 
     /// Render the file open prompt with colorized path
     /// Shows: "Open: /path/to/current/dir/filename" where the directory part is dimmed
     /// Long paths are truncated: "/private/[...]/project/" with [...] styled differently
+    /// Returns the caret cell if it fits.
     pub fn render_file_open_prompt(
         frame: &mut Frame,
         area: Rect,
         prompt: &Prompt,
         file_open_state: &crate::app::file_open::FileOpenState,
         theme: &crate::view::theme::Theme,
-    ) {
+    ) -> Option<(u16, u16)> {
         let base_style = Style::default().fg(theme.prompt_fg).bg(theme.prompt_bg);
         let dir_style = Style::default()
             .fg(theme.help_separator_fg)
@@ -934,7 +977,9 @@ impl StatusBarRenderer {
         let cursor_x = (prefix_width + dir_display_width + input_width_before_cursor) as u16;
         if cursor_x < area.width {
             frame.set_cursor_position((area.x + cursor_x, area.y));
+            return Some((area.x + cursor_x, area.y));
         }
+        None
     }
 
     /// Render a single element to its text representation.
@@ -1028,6 +1073,16 @@ impl StatusBarRenderer {
                     token_key: None,
                 })
             }
+            StatusBarElement::Mode => ctx.editor_mode.map(|mode| RenderedElement {
+                text: mode.to_string(),
+                kind: ElementKind::Normal,
+                token_key: None,
+            }),
+            StatusBarElement::Narrowed => ctx.narrowed.map(|text| RenderedElement {
+                text: text.to_string(),
+                kind: ElementKind::Normal,
+                token_key: None,
+            }),
             StatusBarElement::Cursor => {
                 if !ctx.state.show_cursors {
                     return None;
@@ -1112,7 +1167,7 @@ impl StatusBarRenderer {
                 }
                 Some(RenderedElement {
                     text: parts.join(" "),
-                    kind: ElementKind::Normal,
+                    kind: ElementKind::Diagnostics,
                     token_key: None,
                 })
             }
@@ -1165,11 +1220,19 @@ impl StatusBarRenderer {
                     token_key: None,
                 })
             }
-            StatusBarElement::LineEnding => Some(RenderedElement {
-                text: ctx.state.buffer.line_ending().display_name().to_string(),
-                kind: ElementKind::LineEnding,
-                token_key: None,
-            }),
+            StatusBarElement::LineEnding => {
+                let name = ctx.state.buffer.line_ending().display_name();
+                let text = if ctx.state.buffer.has_mixed_line_endings() {
+                    t!("status.line_ending_mixed", ending = name).to_string()
+                } else {
+                    name.to_string()
+                };
+                Some(RenderedElement {
+                    text,
+                    kind: ElementKind::LineEnding,
+                    token_key: None,
+                })
+            }
             StatusBarElement::Encoding => Some(RenderedElement {
                 text: ctx.state.buffer.encoding().display_name().to_string(),
                 kind: ElementKind::Encoding,
@@ -1201,6 +1264,16 @@ impl StatusBarRenderer {
                     token_key: None,
                 })
             }
+            StatusBarElement::Jobs => {
+                if ctx.jobs_status.is_empty() {
+                    return None;
+                }
+                Some(RenderedElement {
+                    text: ctx.jobs_status.to_string(),
+                    kind: ElementKind::Jobs,
+                    token_key: None,
+                })
+            }
             StatusBarElement::Warnings => {
                 if ctx.general_warning_count == 0 {
                     return None;
@@ -1374,7 +1447,7 @@ impl StatusBarRenderer {
                 }
                 style
             }
-            ElementKind::Language => {
+            ElementKind::Language | ElementKind::Diagnostics | ElementKind::Jobs => {
                 let (fg, bg) = if is_hovering {
                     (theme.menu_hover_fg, theme.menu_hover_bg)
                 } else {
@@ -1533,7 +1606,9 @@ impl StatusBarRenderer {
             | ElementKind::LineEnding
             | ElementKind::Encoding
             | ElementKind::ReadOnly
-            | ElementKind::Language => ("ui.status_bar_fg", "ui.status_bar_bg"),
+            | ElementKind::Language
+            | ElementKind::Diagnostics
+            | ElementKind::Jobs => ("ui.status_bar_fg", "ui.status_bar_bg"),
             ElementKind::RemoteDisconnected => (
                 "ui.status_error_indicator_fg",
                 "ui.status_error_indicator_bg",
@@ -1587,6 +1662,7 @@ impl StatusBarRenderer {
             ElementKind::LineEnding => Some(StatusBarClickable::LineEnding),
             ElementKind::Encoding => Some(StatusBarClickable::Encoding),
             ElementKind::Language => Some(StatusBarClickable::Language),
+            ElementKind::Diagnostics => Some(StatusBarClickable::Diagnostics),
             ElementKind::Lsp => Some(StatusBarClickable::Lsp),
             ElementKind::WarningBadge => Some(StatusBarClickable::Warnings),
             ElementKind::Messages => Some(StatusBarClickable::Messages),
@@ -1594,6 +1670,7 @@ impl StatusBarRenderer {
             ElementKind::WorkspaceTrust(_) => Some(StatusBarClickable::WorkspaceTrust),
             ElementKind::ReadOnly => Some(StatusBarClickable::ReadOnly),
             ElementKind::Update => Some(StatusBarClickable::Update),
+            ElementKind::Jobs => Some(StatusBarClickable::Jobs),
             ElementKind::Normal
             | ElementKind::RemoteDisconnected
             | ElementKind::Palette
@@ -1610,10 +1687,17 @@ impl StatusBarRenderer {
         layout: &mut StatusBarLayout,
         kind: ElementKind,
         token_key: Option<&str>,
+        segment: Option<&StatusBarSegment>,
         row: u16,
         start_col: u16,
         end_col: u16,
     ) {
+        if let Some(action) = segment.and_then(|s| s.on_click.as_ref()) {
+            layout
+                .segment_actions
+                .push((action.clone(), row, start_col, end_col));
+            return;
+        }
         if let Some(id) = Self::clickable_for_kind(kind) {
             layout.clickable.push((id, row, start_col, end_col));
         }
@@ -1697,18 +1781,20 @@ impl StatusBarRenderer {
 
     /// Render a configured side (left/right) into styled per-element groups.
     /// Each tuple carries the rendered spans, total width, the kind tag
-    /// (for layout/click-area routing of built-ins), and the plugin
+    /// (for layout/click-area routing of built-ins), the plugin
     /// token key (`Some` only for `ElementKind::Custom`) so the
     /// placement loops can record the screen area under the same key
-    /// the plugin registered.
-    fn render_side(
-        config_side: &[StatusBarElement],
+    /// the plugin registered, and the element's `status_bar.segments`
+    /// entry, whose style is already applied to the spans.
+    fn render_side<'c>(
+        config_side: &'c [StatusBarElement],
+        config: &'c StatusBarConfig,
         ctx: &mut StatusBarContext<'_>,
-    ) -> Vec<(Vec<Span<'static>>, usize, ElementKind, Option<String>)> {
-        let rendered: Vec<RenderedElement> = config_side
+    ) -> Vec<SideItem<'c>> {
+        let rendered: Vec<(RenderedElement, Option<&StatusBarSegment>)> = config_side
             .iter()
-            .filter_map(|elem| Self::render_element(elem, ctx))
-            .filter(|e| !e.text.is_empty())
+            .filter_map(|elem| Self::render_element(elem, ctx).map(|r| (r, config.segment(elem))))
+            .filter(|(e, _)| !e.text.is_empty())
             .collect();
 
         let theme = ctx.theme;
@@ -1717,16 +1803,58 @@ impl StatusBarRenderer {
         let lsp_state = ctx.lsp_indicator_state;
         rendered
             .into_iter()
-            .map(|r| {
+            .map(|(r, segment)| {
                 let kind = r.kind;
                 let token_key = r.token_key.clone();
-                let (spans, width) =
+                let (mut spans, width) =
                     Self::element_spans(&r, theme, hovered, warning_level, lsp_state);
-                (spans, width, kind, token_key)
+                if segment.is_some() {
+                    let style = Self::segment_style(segment, theme);
+                    for span in &mut spans {
+                        span.style = span.style.patch(style);
+                    }
+                }
+                (spans, width, kind, token_key, segment)
             })
             .collect()
     }
 
+    /// The style a `status_bar.segments` entry lays over its element.
+    /// Colors that are neither a theme key nor `#rrggbb` are ignored.
+    fn segment_style(
+        segment: Option<&StatusBarSegment>,
+        theme: &crate::view::theme::Theme,
+    ) -> Style {
+        let Some(segment) = segment else {
+            return Style::default();
+        };
+        let color = |spec: &Option<String>| {
+            let spec = spec.as_deref()?;
+            match spec.strip_prefix('#') {
+                Some(hex) if hex.len() == 6 => {
+                    let value = u32::from_str_radix(hex, 16).ok()?;
+                    Some(Color::Rgb(
+                        (value >> 16) as u8,
+                        (value >> 8) as u8,
+                        value as u8,
+                    ))
+                }
+                _ => theme.resolve_theme_key(spec),
+            }
+        };
+        let mut style = Style::default();
+        if let Some(fg) = color(&segment.fg) {
+            style = style.fg(fg);
+        }
+        if let Some(bg) = color(&segment.bg) {
+            style = style.bg(bg);
+        }
+        if segment.bold {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        style
+    }
+
     /// Render the normal status bar (config-driven).
     fn render_status(
         frame: &mut Frame,
@@ -1771,8 +1899,8 @@ impl StatusBarRenderer {
             .chain(config.right.iter())
             .any(|e| matches!(e, StatusBarElement::RemoteIndicator));
 
-        let left_items = Self::render_side(&config.left, ctx);
-        let mut right_items = Self::render_side(&config.right, ctx);
+        let left_items = Self::render_side(&config.left, config, ctx);
+        let mut right_items = Self::render_side(&config.right, config, ctx);
 
         // Separator drawn between elements, used verbatim from config.
         // An empty value disables separators and consumes no width.
@@ -1792,7 +1920,7 @@ impl StatusBarRenderer {
         // alongside that minimum left budget.  We never drop the *first*
         // right element so the user keeps at least one piece of right-side
         // status if any was configured.
-        let total_right_width: usize = right_items.iter().map(|(_, w, _, _)| *w).sum::<usize>()
+        let total_right_width: usize = right_items.iter().map(|(_, w, ..)| *w).sum::<usize>()
             + separator_width * right_items.len().saturating_sub(1);
         let left_min_target = available_width
             .saturating_mul(2)
@@ -1813,7 +1941,7 @@ impl StatusBarRenderer {
             }
         }
 
-        let right_width: usize = right_items.iter().map(|(_, w, _, _)| *w).sum::<usize>()
+        let right_width: usize = right_items.iter().map(|(_, w, ..)| *w).sum::<usize>()
             + separator_width * right_items.len().saturating_sub(1);
 
         let narrow = available_width < 15;
@@ -1831,7 +1959,9 @@ impl StatusBarRenderer {
         let mut spans: Vec<Span<'static>> = Vec::new();
         let mut used_left: usize = 0;
 
-        for (idx, (item_spans, width, kind, token_key)) in left_items.into_iter().enumerate() {
+        for (idx, (item_spans, width, kind, token_key, segment)) in
+            left_items.into_iter().enumerate()
+        {
             let sep_width = if idx == 0 { 0 } else { separator_width };
             if used_left + sep_width >= left_max_width {
                 break;
@@ -1883,6 +2013,7 @@ impl StatusBarRenderer {
                     &mut layout,
                     kind,
                     token_key.as_deref(),
+                    segment,
                     area.y,
                     area.x + start_col as u16,
                     area.x + (start_col + width) as u16,
@@ -1912,7 +2043,8 @@ impl StatusBarRenderer {
                     overflow_is_hovering,
                     ctx.warning_level,
                     ctx.lsp_indicator_state,
-                );
+                )
+                .patch(Self::segment_style(segment, ctx.theme));
                 let seg_text = (!draw).then(|| truncated.clone());
                 spans.push(Span::styled(truncated, overflow_style));
 
@@ -1933,6 +2065,7 @@ impl StatusBarRenderer {
                     &mut layout,
                     kind,
                     token_key.as_deref(),
+                    segment,
                     area.y,
                     area.x + start_col as u16,
                     area.x + (start_col + truncated_width) as u16,
@@ -1975,7 +2108,9 @@ impl StatusBarRenderer {
         }
 
         let mut current_col = area.x + col_offset as u16;
-        for (idx, (item_spans, width, kind, token_key)) in right_items.into_iter().enumerate() {
+        for (idx, (item_spans, width, kind, token_key, segment)) in
+            right_items.into_iter().enumerate()
+        {
             if idx > 0 && separator_width > 0 {
                 if let Some(r) = rec.as_deref_mut() {
                     r.run(
@@ -2005,6 +2140,7 @@ impl StatusBarRenderer {
                 &mut layout,
                 kind,
                 token_key.as_deref(),
+                segment,
                 area.y,
                 current_col,
                 current_col + width as u16,
@@ -2038,6 +2174,7 @@ impl StatusBarRenderer {
     /// - Whole Word (Alt+W)
     /// - Regex (Alt+R)
     /// - Confirm Each (Alt+I) - only shown in replace mode
+    /// - Multiline (Alt+N)
     ///
     /// # Returns
     /// Layout information for hit testing mouse clicks on checkboxes
@@ -2048,6 +2185,7 @@ impl StatusBarRenderer {
         case_sensitive: bool,
         whole_word: bool,
         use_regex: bool,
+        multiline: bool,
         confirm_each: Option<bool>, // None = don't show, Some(value) = show with this state
         theme: &crate::view::theme::Theme,
         keybindings: &crate::input::keybindings::KeybindingResolver,
@@ -2279,6 +2417,43 @@ impl StatusBarRenderer {
             layout.confirm_each = Some((confirm_start, current_col));
         }
 
+        // Multiline option, last so the replace-mode options keep their
+        // place on narrow terminals
+        let multiline_shortcut =
+            get_shortcut(&crate::input::keybindings::Action::ToggleSearchMultiline);
+        let multiline_checkbox = if multiline { "[x]" } else { "[ ]" };
+
+        // Separator
+        spans.push(Span::styled("   ", base_style));
+        current_col += 3;
+
+        let multiline_hovered = hover == SearchOptionsHover::Multiline;
+        let multiline_start = current_col;
+        let multiline_label = format!("{} {}", multiline_checkbox, t!("search.multiline"));
+        let multiline_shortcut_text = multiline_shortcut
+            .as_ref()
+            .map(|s| format!(" ({})", s))
+            .unwrap_or_default();
+        let multiline_full_width =
+            str_width(&multiline_label) + str_width(&multiline_shortcut_text);
+
+        spans.push(Span::styled(
+            multiline_label,
+            get_checkbox_style(multiline_hovered, multiline),
+        ));
+        if !multiline_shortcut_text.is_empty() {
+            spans.push(Span::styled(
+                multiline_shortcut_text,
+                if multiline_hovered {
+                    hover_shortcut_style
+                } else {
+                    shortcut_style
+                },
+            ));
+        }
+        current_col += multiline_full_width as u16;
+        layout.multiline = Some((multiline_start, current_col));
+
         // Fill remaining space
         let current_width = (current_col - area.x) as usize;
         let available_width = area.width as usize;
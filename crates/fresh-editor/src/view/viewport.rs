@@ -1,3 +1,4 @@
+use crate::config::TypewriterScrolling;
 use crate::model::buffer::Buffer;
 use crate::model::cursor::Cursor;
 use crate::primitives::line_wrapping::WrapConfig;
@@ -71,6 +72,19 @@ pub struct Viewport {
     /// `SplitViewState::show_line_numbers`.
     pub show_line_numbers: bool,
 
+    /// Whether scrolling may continue past the last line until it reaches
+    /// the top row. When false, the last line stops at the bottom row.
+    /// Mirrors the `scroll_past_end` editor setting.
+    pub scroll_past_end: bool,
+
+    /// Screen row the cursor line is pinned to while typing and
+    /// navigating. Mirrors the `typewriter_scrolling` editor setting.
+    pub typewriter_scrolling: TypewriterScrolling,
+
+    /// Sign columns and line-number settings of the gutter, copied from the
+    /// buffer's margins each frame so `gutter_width` matches the renderer.
+    pub gutter_shape: crate::view::margin::GutterShape,
+
     /// Whether viewport needs synchronization with cursor positions
     /// When true, ensure_visible needs to be called before rendering
     /// This allows batching multiple cursor movements into a single viewport update
@@ -143,6 +157,9 @@ impl Viewport {
             wrap_column: None,
             compose_width: None,
             show_line_numbers: true,
+            scroll_past_end: false,
+            typewriter_scrolling: TypewriterScrolling::Off,
+            gutter_shape: crate::view::margin::GutterShape::default(),
             needs_sync: false,
             skip_resize_sync: false,
             skip_ensure_visible: false,
@@ -230,15 +247,55 @@ impl Viewport {
         self.height as usize
     }
 
+    /// Rows that must stay filled with content below the top of the
+    /// viewport: all of them, or just one when scrolling past the end.
+    fn rows_to_fill(&self, viewport_height: usize) -> usize {
+        if self.scroll_past_end {
+            viewport_height.min(1)
+        } else {
+            self.rows_through_pinned_row(viewport_height)
+        }
+    }
+
+    /// Rows from the viewport top through the typewriter row, so the last
+    /// line can still scroll up to that row. The whole viewport when
+    /// typewriter scrolling is off.
+    fn rows_through_pinned_row(&self, viewport_height: usize) -> usize {
+        match self.typewriter_scrolling {
+            TypewriterScrolling::Off => viewport_height,
+            _ => self.scroll_margins(viewport_height).0 + 1,
+        }
+    }
+
+    /// Rows to keep between the cursor and the `(top, bottom)` edges of a
+    /// viewport `viewport_height` rows tall.
+    ///
+    /// Normally both are `scroll_offset`, capped at half the height. With
+    /// typewriter scrolling the two margins add up to `viewport_height - 1`,
+    /// which leaves exactly one row where the cursor may sit without
+    /// scrolling.
+    pub(crate) fn scroll_margins(&self, viewport_height: usize) -> (usize, usize) {
+        let last_row = viewport_height.saturating_sub(1);
+        let top = match self.typewriter_scrolling {
+            TypewriterScrolling::Off => {
+                let margin = self.scroll_offset.min(viewport_height / 2);
+                return (margin, margin);
+            }
+            TypewriterScrolling::Center => last_row / 2,
+            TypewriterScrolling::Offset => self.scroll_offset.min(last_row),
+        };
+        (top, last_row - top)
+    }
+
     /// Calculate the gutter width based on buffer length
-    /// Format: "[indicator]{:>N} │ " where N is the number of digits for line numbers
-    /// - Indicator column: 1 char (space, or symbols like ●/✗/⚠)
-    /// - Line numbers: N digits (min 2), right-aligned
+    /// Format: "[signs]{:>N} │ " where N is the number of digits for line numbers
+    /// - Sign columns: 1 char each (space, or symbols like ●/✗/⚠); one by default
+    /// - Line numbers: N digits (min 2 by default), right-aligned
     /// - Separator: " │ " = 3 chars (space, box char, space)
     ///
-    /// Total width = 1 + N + 3 = N + 4 (where N >= 2 minimum, so min 6 total).
+    /// With the default gutter, total width = 1 + N + 3 = N + 4 (min 6).
     /// The width adapts to the buffer's line count — small files don't waste
-    /// space on a 4-digit-wide column. `MIN_LINE_NUMBER_DIGITS` keeps it from
+    /// space on a 4-digit-wide column. The minimum digit count keeps it from
     /// shrinking so much that a 1-line buffer feels cramped.
     pub fn gutter_width(&self, buffer: &Buffer) -> usize {
         let byte_offset_mode = buffer.line_count().is_none();
@@ -253,7 +310,7 @@ impl Viewport {
         } else {
             ((gutter_estimate as f64).log10().floor() as usize) + 1
         };
-        1 + digits.max(crate::view::margin::MIN_LINE_NUMBER_DIGITS) + 3
+        self.gutter_shape.width(digits)
     }
 
     /// Count visual rows for a single logical line, accounting for plugin soft
@@ -615,12 +672,32 @@ impl Viewport {
     /// is passed to the visual-row scroll.
     pub fn center_on_position(&mut self, buffer: &mut Buffer, position: usize) {
         let half = self.visible_line_count() / 2;
+        self.place_position_at_row(buffer, position, half);
+    }
+
+    /// Scroll so the row containing `position` is the top row, below the
+    /// `scroll_offset` margin (vim's `zt`).
+    pub fn top_on_position(&mut self, buffer: &mut Buffer, position: usize) {
+        let margin = self.scroll_offset.min(self.visible_line_count() / 2);
+        self.place_position_at_row(buffer, position, margin);
+    }
+
+    /// Scroll so the row containing `position` is the bottom row, above
+    /// the `scroll_offset` margin (vim's `zb`).
+    pub fn bottom_on_position(&mut self, buffer: &mut Buffer, position: usize) {
+        let height = self.visible_line_count();
+        let margin = self.scroll_offset.min(height / 2);
+        self.place_position_at_row(buffer, position, height.saturating_sub(margin + 1));
+    }
 
+    /// Scroll so the visual row containing `position` lands `row` rows
+    /// below the top of the viewport (fewer near the start of the buffer).
+    fn place_position_at_row(&mut self, buffer: &mut Buffer, position: usize, row: usize) {
         if !self.line_wrap_enabled {
             // Unwrapped: one visual row per logical line, so walk back
-            // `half` logical lines from the target.
+            // `row` logical lines from the target.
             let mut iter = buffer.line_iterator(position, 80);
-            for _ in 0..half {
+            for _ in 0..row {
                 if iter.prev().is_none() {
                     break;
                 }
@@ -632,7 +709,7 @@ impl Viewport {
 
         // Wrapped: find which visual row inside its logical line the
         // target sits on, anchor the viewport top to that row, then
-        // scroll up `half` real visual rows (which walks back through any
+        // scroll up `row` real visual rows (which walks back through any
         // wrapped lines above).
         let line = buffer.get_line_number(position);
         let line_start = buffer.line_start_offset(line).unwrap_or(position);
@@ -661,7 +738,7 @@ impl Viewport {
 
         self.top_byte = line_start;
         self.top_view_line_offset = match_row_in_line;
-        self.scroll_up(buffer, &[], &[], half);
+        self.scroll_up(buffer, &[], &[], row);
     }
 
     /// Scroll down by N lines (byte-based)
@@ -901,7 +978,7 @@ impl Viewport {
         virtual_lines: &[usize],
         wrap_config: &WrapConfig,
     ) {
-        let viewport_height = self.visible_line_count();
+        let viewport_height = self.rows_to_fill(self.visible_line_count());
         if viewport_height == 0 {
             return;
         }
@@ -1290,10 +1367,12 @@ impl Viewport {
             return false;
         }
 
-        let desired_offset = self.scroll_offset.min(viewport_height / 2);
+        let (desired_offset, _) = self.scroll_margins(viewport_height);
         // Use a clamped shift so we never push the viewport past
         // buffer boundaries (handled by max_top below via saturating).
-        let max_top_candidate = view_lines.len().saturating_sub(viewport_height);
+        let max_top_candidate = view_lines
+            .len()
+            .saturating_sub(self.rows_through_pinned_row(viewport_height));
         let target_top = cursor_view_line.saturating_sub(desired_offset);
         let new_offset = target_top.min(max_top_candidate);
         if new_offset == self.top_view_line_offset {
@@ -1345,30 +1424,33 @@ impl Viewport {
         let effective_bottom = effective_top + viewport_height;
 
         let apply_margin = self.line_wrap_enabled || self.top_view_line_offset > 0;
-        let effective_offset = if apply_margin {
-            self.scroll_offset.min(viewport_height / 2)
+        let (top_margin, bottom_margin) = if apply_margin {
+            self.scroll_margins(viewport_height)
         } else {
-            0
+            (0, 0)
         };
-        let max_top = view_lines.len().saturating_sub(viewport_height);
+        let max_top = view_lines
+            .len()
+            .saturating_sub(self.rows_through_pinned_row(viewport_height));
 
         // Cursor is in the top margin zone when it sits within
-        // `effective_offset` rows of the top of the viewport.  When
-        // `effective_offset == 0` (non-wrapped fallback) this degrades to
+        // `top_margin` rows of the top of the viewport.  When
+        // `top_margin == 0` (non-wrapped fallback) this degrades to
         // the pre-existing "cursor above the viewport" check.
-        let in_top_margin = cursor_view_line < effective_top + effective_offset;
+        let in_top_margin = cursor_view_line < effective_top + top_margin;
         // Cursor is in the bottom margin zone when it sits within
-        // `effective_offset` rows of the bottom. `+1` because margin is
-        // *within* the last `effective_offset` rows (inclusive).  With
-        // `effective_offset == 0` this degrades to "cursor at or below
+        // `bottom_margin` rows of the bottom. `+1` because margin is
+        // *within* the last `bottom_margin` rows (inclusive).  With
+        // `bottom_margin == 0` this degrades to "cursor at or below
         // the last visible row".
-        let in_bottom_margin = cursor_view_line + effective_offset + 1 > effective_bottom;
+        let in_bottom_margin = cursor_view_line + bottom_margin + 1 > effective_bottom;
 
         tracing::trace!(
-            "ensure_visible_in_layout: margins effective_top={} effective_bottom={} effective_offset={} apply_margin={} in_top_margin={} in_bottom_margin={} max_top={}",
+            "ensure_visible_in_layout: margins effective_top={} effective_bottom={} top_margin={} bottom_margin={} apply_margin={} in_top_margin={} in_bottom_margin={} max_top={}",
             effective_top,
             effective_bottom,
-            effective_offset,
+            top_margin,
+            bottom_margin,
             apply_margin,
             in_top_margin,
             in_bottom_margin,
@@ -1382,13 +1464,13 @@ impl Viewport {
         // Compute the ideal top_view_line_offset that puts the cursor
         // just inside the safe zone (margin away from the nearer edge).
         let target_top = if in_top_margin {
-            // Put cursor at `effective_offset` rows from the top.
-            cursor_view_line.saturating_sub(effective_offset)
+            // Put cursor at `top_margin` rows from the top.
+            cursor_view_line.saturating_sub(top_margin)
         } else {
-            // Put cursor at `effective_offset` rows from the bottom,
-            // i.e. row `viewport_height - 1 - effective_offset` from the
+            // Put cursor at `bottom_margin` rows from the bottom,
+            // i.e. row `viewport_height - 1 - bottom_margin` from the
             // new top.
-            (cursor_view_line + effective_offset + 1).saturating_sub(viewport_height)
+            (cursor_view_line + bottom_margin + 1).saturating_sub(viewport_height)
         };
 
         // Clamp to valid range. `max_top` is the largest top that still
@@ -1614,7 +1696,7 @@ impl Viewport {
             proposed_top_byte
         );
 
-        let viewport_height = self.visible_line_count();
+        let viewport_height = self.rows_to_fill(self.visible_line_count());
         if viewport_height == 0 {
             self.top_byte = proposed_top_byte;
             return;
@@ -1925,7 +2007,7 @@ impl Viewport {
         self.load_data_around_cursor(buffer, cursor.position, viewport_lines);
 
         let cursor_line_start = buffer.line_iterator(cursor.position, 80).current_position();
-        let effective_offset = self.scroll_offset.min(viewport_lines / 2);
+        let margins = self.scroll_margins(viewport_lines);
 
         let (cursor_is_visible, cursor_near_top) = if cursor_line_start < self.top_byte {
             (false, true)
@@ -1935,7 +2017,7 @@ impl Viewport {
                 cursor,
                 cursor_line_start,
                 viewport_lines,
-                effective_offset,
+                margins,
                 hidden_ranges,
             )
         } else {
@@ -1943,7 +2025,7 @@ impl Viewport {
                 buffer,
                 cursor_line_start,
                 viewport_lines,
-                effective_offset,
+                margins,
                 hidden_ranges,
             )
         };
@@ -1971,15 +2053,16 @@ impl Viewport {
                     buffer,
                     cursor,
                     cursor_line_start,
-                    effective_offset,
+                    margins,
                     cursor_near_top,
                     hidden_ranges,
                 );
             } else {
+                let (top_margin, bottom_margin) = margins;
                 let target_rows_from_top = if cursor_near_top {
-                    effective_offset
+                    top_margin
                 } else {
-                    viewport_lines.saturating_sub(effective_offset + 1)
+                    viewport_lines.saturating_sub(bottom_margin + 1)
                 };
                 self.scroll_to_cursor_nowrap(
                     buffer,
@@ -2081,7 +2164,7 @@ impl Viewport {
         cursor: &Cursor,
         cursor_line_start: usize,
         viewport_lines: usize,
-        effective_offset: usize,
+        (top_margin, bottom_margin): (usize, usize),
         hidden_ranges: &[(usize, usize)],
     ) -> (bool, bool) {
         let wrap_config = self.make_wrap_config(buffer);
@@ -2108,11 +2191,11 @@ impl Viewport {
                     crate::view::line_wrap_cache::char_position_in_layout(&layout, cursor_column);
                 visual_rows += cursor_segment_idx.min(segments_count - 1) + 1;
 
-                // visual_rows is 1-based here; > effective_offset gives the same
-                // margin as lines_from_top >= effective_offset in no-wrap mode.
-                let vis = visual_rows > effective_offset
-                    && visual_rows <= viewport_lines.saturating_sub(effective_offset);
-                if !vis && visual_rows <= effective_offset {
+                // visual_rows is 1-based here; > top_margin gives the same
+                // margin as lines_from_top >= top_margin in no-wrap mode.
+                let vis = visual_rows > top_margin
+                    && visual_rows <= viewport_lines.saturating_sub(bottom_margin);
+                if !vis && visual_rows <= top_margin {
                     cursor_near_top = true;
                 }
                 return (vis, cursor_near_top);
@@ -2147,7 +2230,7 @@ impl Viewport {
         buffer: &mut Buffer,
         cursor_line_start: usize,
         viewport_lines: usize,
-        effective_offset: usize,
+        (top_margin, bottom_margin): (usize, usize),
         hidden_ranges: &[(usize, usize)],
     ) -> (bool, bool) {
         let mut iter = buffer.line_iterator(self.top_byte, 80);
@@ -2169,34 +2252,36 @@ impl Viewport {
             lines_from_top += 1;
         }
 
-        let cursor_near_top = lines_from_top < effective_offset;
-        let visible = lines_from_top >= effective_offset
-            && lines_from_top < viewport_lines.saturating_sub(effective_offset);
+        let cursor_near_top = lines_from_top < top_margin;
+        let visible = lines_from_top >= top_margin
+            && lines_from_top < viewport_lines.saturating_sub(bottom_margin);
         tracing::trace!(
-            "ensure_visible (no wrap): lines_from_top={}, effective_offset={}, visible={}",
+            "ensure_visible (no wrap): lines_from_top={}, top_margin={}, bottom_margin={}, visible={}",
             lines_from_top,
-            effective_offset,
+            top_margin,
+            bottom_margin,
             visible
         );
         (visible, cursor_near_top)
     }
 
     /// Scroll `top_byte` / `top_view_line_offset` so the cursor lands inside
-    /// the scroll margin (wrap mode). `effective_offset` is the margin depth.
+    /// the scroll margins (wrap mode). `(top_margin, bottom_margin)` are the
+    /// margin depths at each edge.
     fn scroll_to_cursor_wrapped(
         &mut self,
         buffer: &mut Buffer,
         cursor: &Cursor,
         cursor_line_start: usize,
-        effective_offset: usize,
+        (top_margin, bottom_margin): (usize, usize),
         cursor_near_top: bool,
         hidden_ranges: &[(usize, usize)],
     ) {
         let viewport_lines = self.visible_line_count().max(1);
         let target_visual_rows = if cursor_near_top {
-            effective_offset + 1
+            top_margin + 1
         } else {
-            viewport_lines.saturating_sub(effective_offset)
+            viewport_lines.saturating_sub(bottom_margin)
         };
         let wrap_config = self.make_wrap_config(buffer);
         let mut iter = buffer.line_iterator(cursor_line_start, 80);
@@ -2224,7 +2309,7 @@ impl Viewport {
         // teleport the cursor many rows down (issue #1574, step 16).
         if cursor_near_top && visual_rows_counted >= target_visual_rows {
             self.set_top_byte_with_limit(buffer, &[], &[], cursor_line_start);
-            self.top_view_line_offset = cursor_segment_idx_in_line.saturating_sub(effective_offset);
+            self.top_view_line_offset = cursor_segment_idx_in_line.saturating_sub(top_margin);
             self.scrolled_up_in_wrap = true;
             return;
         }
@@ -2232,7 +2317,7 @@ impl Viewport {
         // Walk backward counting visual rows until we accumulate target_visual_rows.
         // When scrolling UP and the walk overshoots, set `top_view_line_offset`
         // within the landing line so the cursor ends up at exactly
-        // `effective_offset` rows from the new top (issue #1574, step 16).
+        // `top_margin` rows from the new top (issue #1574, step 16).
         // This is intentionally not done for scroll-DOWN — that path relies on
         // landing at line start (`top_view_line_offset = 0`).
         iter = buffer.line_iterator(cursor_line_start, 80);
@@ -2352,8 +2437,7 @@ impl Viewport {
 
         // If not visible, scroll to show it with scroll offset
         if !target_is_visible {
-            let effective_offset = self.scroll_offset.min(visible_count / 2);
-            let target_line_from_top = effective_offset;
+            let (target_line_from_top, _) = self.scroll_margins(visible_count);
 
             // Move backwards from target to find new top_byte
             let mut iter = buffer.line_iterator(target_line_byte, 80);
@@ -2613,6 +2697,20 @@ mod tests {
         assert_eq!(vp.top_byte, 0); // Can't scroll past 0
     }
 
+    #[test]
+    fn test_scroll_past_end_stops_with_last_line_at_top() {
+        let content: Vec<String> = (1..=50).map(|i| format!("line{i}")).collect();
+        let mut buffer = Buffer::from_str_test(&content.join("\n"));
+        let mut vp = Viewport::new(80, 24);
+
+        vp.scroll_down(&mut buffer, &[], &[], 100);
+        assert_eq!(buffer.get_line_number(vp.top_byte), 50 - 24);
+
+        vp.scroll_past_end = true;
+        vp.scroll_down(&mut buffer, &[], &[], 100);
+        assert_eq!(buffer.get_line_number(vp.top_byte), 49);
+    }
+
     #[test]
     fn center_on_position_unwrapped_centers_logical_line() {
         // 50 single-row lines, height 24 → half = 12. Centering on line
@@ -2631,6 +2729,24 @@ mod tests {
         assert_eq!(vp.top_view_line_offset, 0);
     }
 
+    #[test]
+    fn top_and_bottom_on_position_keep_the_scroll_offset_margin() {
+        let mut content = String::new();
+        for i in 0..50 {
+            content.push_str(&format!("line{i}\n"));
+        }
+        let mut buffer = Buffer::from_str_test(&content);
+        let mut vp = Viewport::new(80, 24);
+        vp.scroll_offset = 3;
+        let pos = buffer.line_start_offset(29).unwrap();
+
+        vp.top_on_position(&mut buffer, pos);
+        assert_eq!(buffer.get_line_number(vp.top_byte), 29 - 3);
+
+        vp.bottom_on_position(&mut buffer, pos);
+        assert_eq!(buffer.get_line_number(vp.top_byte), 29 - 20);
+    }
+
     #[test]
     fn center_on_position_wrapped_counts_visual_rows() {
         // A long line that wraps into many visual rows sits directly above
@@ -3091,4 +3207,99 @@ mod tests {
             lines_from_top
         );
     }
+
+    /// Buffer of `count` short numbered lines.
+    fn numbered_lines(count: usize) -> Buffer {
+        let content: String = (0..count).map(|i| format!("line{i}\n")).collect();
+        Buffer::from_str_test(&content)
+    }
+
+    /// Screen row of `line` below `vp.top_byte` when `hidden` lines collapse.
+    fn screen_row(vp: &Viewport, buffer: &Buffer, line: usize, hidden: &[(usize, usize)]) -> usize {
+        let top_line = buffer.get_line_number(vp.top_byte);
+        (top_line..line)
+            .filter(|&l| {
+                let start = buffer.line_start_offset(l).unwrap();
+                !hidden.iter().any(|&(s, e)| start >= s && start < e)
+            })
+            .count()
+    }
+
+    #[test]
+    fn typewriter_center_pins_cursor_row_while_moving() {
+        let mut buffer = numbered_lines(100);
+        let mut vp = Viewport::new(80, 11);
+        vp.typewriter_scrolling = TypewriterScrolling::Center;
+
+        for line in [40, 41, 42, 30, 29, 99] {
+            let cursor = Cursor::new(buffer.line_start_offset(line).unwrap());
+            vp.ensure_visible(&mut buffer, &cursor, &[]);
+            assert_eq!(
+                screen_row(&vp, &buffer, line, &[]),
+                5,
+                "cursor on line {line} should stay on the middle row"
+            );
+        }
+
+        // Near the top there is nothing above to scroll into view.
+        let cursor = Cursor::new(buffer.line_start_offset(2).unwrap());
+        vp.ensure_visible(&mut buffer, &cursor, &[]);
+        assert_eq!(vp.top_byte, 0);
+    }
+
+    #[test]
+    fn typewriter_offset_pins_cursor_at_scroll_offset_row() {
+        let mut buffer = numbered_lines(100);
+        let mut vp = Viewport::new(80, 12);
+        vp.scroll_offset = 2;
+        vp.typewriter_scrolling = TypewriterScrolling::Offset;
+
+        for line in [50, 51, 49, 99] {
+            let cursor = Cursor::new(buffer.line_start_offset(line).unwrap());
+            vp.ensure_visible(&mut buffer, &cursor, &[]);
+            assert_eq!(screen_row(&vp, &buffer, line, &[]), 2, "line {line}");
+        }
+        assert_eq!(vp.scroll_margins(12), (2, 9));
+    }
+
+    #[test]
+    fn typewriter_center_skips_folded_lines() {
+        let mut buffer = numbered_lines(100);
+        let mut vp = Viewport::new(80, 11);
+        vp.typewriter_scrolling = TypewriterScrolling::Center;
+        // Lines 41..=47 are folded away under line 40.
+        let hidden = [(
+            buffer.line_start_offset(41).unwrap(),
+            buffer.line_start_offset(48).unwrap(),
+        )];
+
+        for line in [50, 48, 40, 39] {
+            let cursor = Cursor::new(buffer.line_start_offset(line).unwrap());
+            vp.ensure_visible(&mut buffer, &cursor, &hidden);
+            assert_eq!(screen_row(&vp, &buffer, line, &hidden), 5, "line {line}");
+        }
+    }
+
+    #[test]
+    fn typewriter_center_counts_wrapped_rows_when_scrolling_up() {
+        // Every line wraps into two rows at this width.
+        let content: String = (0..60)
+            .map(|i| format!("{i:02}{}\n", "x".repeat(40)))
+            .collect();
+        let mut buffer = Buffer::from_str_test(&content);
+        let mut vp = Viewport::new(30, 11);
+        vp.line_wrap_enabled = true;
+        vp.typewriter_scrolling = TypewriterScrolling::Center;
+
+        let cursor = Cursor::new(buffer.line_start_offset(30).unwrap());
+        vp.ensure_visible(&mut buffer, &cursor, &[]);
+        let cursor = Cursor::new(buffer.line_start_offset(28).unwrap());
+        vp.ensure_visible(&mut buffer, &cursor, &[]);
+
+        // Two wrapped lines plus the tail segment of a third sit above the
+        // cursor: five rows, leaving it on the middle row.
+        let top_line = buffer.get_line_number(vp.top_byte);
+        let rows_above = (28 - top_line) * 2 - vp.top_view_line_offset;
+        assert_eq!(rows_above, 5);
+    }
 }
@@ -28,7 +28,7 @@
 //! `"regions.panes.len"` when the pane count changes (panes carry the bulk of
 //! the bytes; typing resends only the changed pane). Client→server input is
 //! JSON text frames, tagged `{"type":"key"|"mouse"|"action"|"widget"|
-//! "settings"|"kbedit"|"paste"|"resize"}` — each carrying the same fields as
+//! "settings"|"kbedit"|"paste"|"ime"|"resize"}` — each carrying the same fields as
 //! the HTTP POST bodies below.
 //!
 //! Session model: MANY WebSocket clients, ALL mirroring the one single-threaded
@@ -72,6 +72,7 @@
 //!   - `GET /state`   → `{ w, h, regions, theme, clipboard }` from the real render
 //!   - `POST /key`    → runs the real `Editor::handle_key`, returns `/state`
 //!   - `POST /paste`  → `{text}` → the editor's bracketed-paste path, returns `/state`
+//!   - `POST /ime`    → `{preedit}` or `{commit}` → IME composition, returns `/state`
 //!   - `POST /resize` → `{cols, rows}` → `Editor::resize`, returns `/state`
 //!   - `POST /mouse` `/action` `/widget` `/settings` `/kbedit` → same pattern
 //!   - `POST /step` `/reset` → parity-harness routes (no clipboard attach)
@@ -206,6 +207,7 @@ pub fn build_editor(cols: u16, rows: u16, files: &[PathBuf]) -> Result<Editor> {
     // it into the cells — the cell buffer carries pane interiors only, with no
     // chrome to hide. See docs/internal/web-ui.md.
     editor.suppress_chrome_cells = true;
+    editor.set_styled_underlines_supported();
     for f in files {
         if let Err(e) = editor.open_file(f) {
             eprintln!("open_file {f:?} failed: {e}");
@@ -795,8 +797,8 @@ fn handle_http(
         // the same loop pass (the `true` below counts as input).
         (
             "POST",
-            p @ ("/key" | "/paste" | "/mouse" | "/action" | "/widget" | "/settings" | "/kbedit"
-            | "/resize"),
+            p @ ("/key" | "/paste" | "/ime" | "/mouse" | "/action" | "/widget" | "/settings"
+            | "/kbedit" | "/resize"),
         ) => {
             apply_message(editor, &p[1..], &body_json(), cols, rows);
             let s = tick_scene(editor, *cols, *rows, clip).to_string();
@@ -850,6 +852,7 @@ fn apply_message(
         "mouse" => apply_mouse(editor, v),
         "action" => apply_action(editor, v),
         "paste" => apply_paste(editor, v),
+        "ime" => apply_ime(editor, v),
         "widget" => apply_widget(editor, v),
         "settings" => apply_settings(editor, v),
         "kbedit" => apply_kbedit(editor, v),
@@ -883,9 +886,20 @@ fn apply_action(editor: &mut Editor, v: &Value) {
 /// terminal paste in `paste_text`.
 fn apply_paste(editor: &mut Editor, v: &Value) {
     if let Some(text) = v.get("text").and_then(|t| t.as_str()) {
-        if !editor.paste_bracketed_into_focused_panel(text) {
-            editor.paste_text(text.to_string());
-        }
+        editor.handle_bracketed_paste(text.to_string());
+    }
+}
+
+/// IME composition from the hidden text sink (docs/internal/web-ui.md §3.6).
+/// `compositionupdate` sends the uncommitted `{preedit}`, which the editor
+/// paints at the caret (so it reaches the page as ordinary pane cells plus
+/// the moved caret); `compositionend` sends `{commit}`, which clears the
+/// pre-edit and types the text in as key presses.
+fn apply_ime(editor: &mut Editor, v: &Value) {
+    if let Some(text) = v.get("commit").and_then(|t| t.as_str()) {
+        editor.commit_ime_text(text);
+    } else if let Some(text) = v.get("preedit").and_then(|t| t.as_str()) {
+        editor.set_ime_preedit(text);
     }
 }
 
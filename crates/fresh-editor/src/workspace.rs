@@ -74,6 +74,10 @@ pub struct Workspace {
     #[serde(default)]
     pub bookmarks: HashMap<char, SerializedBookmark>,
 
+    /// Line bookmarks (gutter-marked lines, optionally labelled)
+    #[serde(default)]
+    pub line_bookmarks: Vec<SerializedLineBookmark>,
+
     /// Open terminal workspaces (for restoration)
     #[serde(default)]
     pub terminals: Vec<SerializedTerminalWorkspace>,
@@ -92,6 +96,11 @@ pub struct Workspace {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub unnamed_buffers: Vec<UnnamedBufferRef>,
 
+    /// Scratch buffers, with their content stored inline (they never have a
+    /// path until the user saves them)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scratch_buffers: Vec<ScratchBufferRef>,
+
     /// Plugin-managed global state, isolated per plugin name.
     /// Persisted across sessions so plugins can store non-buffer-specific state.
     /// TODO: Need to think about plugin isolation / namespacing strategy for these APIs.
@@ -173,6 +182,20 @@ pub struct UnnamedBufferRef {
     pub display_name: String,
 }
 
+/// A persisted scratch buffer. Tabs and split leaves refer to it by
+/// `recovery_id`, the same way they refer to unnamed buffers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScratchBufferRef {
+    /// Stable ID the layout uses to reference this buffer
+    pub recovery_id: String,
+    /// Display name shown in tabs (e.g., "scratch-1")
+    pub display_name: String,
+    /// Syntax the buffer is highlighted as, by name (e.g., "Markdown")
+    pub language: String,
+    /// Full buffer text
+    pub content: String,
+}
+
 /// Serializable split layout (mirrors SplitNode but with file paths instead of buffer IDs)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SerializedSplitNode {
@@ -447,6 +470,8 @@ pub struct SearchOptions {
     pub use_regex: bool,
     #[serde(default)]
     pub confirm_each: bool,
+    #[serde(default)]
+    pub multiline: bool,
 }
 
 /// Serialized bookmark (file path + byte offset)
@@ -458,6 +483,17 @@ pub struct SerializedBookmark {
     pub position: usize,
 }
 
+/// Serialized line bookmark (file path + byte offset of the line + label)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializedLineBookmark {
+    /// File path (relative to working_dir)
+    pub file_path: PathBuf,
+    /// Byte offset of the bookmarked line's start
+    pub position: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
 /// Reference to an open tab (file path, terminal index, or unnamed buffer)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SerializedTabRef {
@@ -1099,6 +1135,7 @@ impl Workspace {
         self.terminals.is_empty()
             && self.external_files.is_empty()
             && self.unnamed_buffers.is_empty()
+            && self.scratch_buffers.is_empty()
             && self.split_states.values().all(|s| s.open_tabs.is_empty())
     }
 
@@ -1111,6 +1148,7 @@ impl Workspace {
     pub fn has_no_preservable_content(&self) -> bool {
         self.external_files.is_empty()
             && self.unnamed_buffers.is_empty()
+            && self.scratch_buffers.is_empty()
             && self.split_states.values().all(|s| {
                 s.open_tabs
                     .iter()
@@ -1317,10 +1355,12 @@ impl Workspace {
             histories: WorkspaceHistories::default(),
             search_options: SearchOptions::default(),
             bookmarks: HashMap::new(),
+            line_bookmarks: Vec::new(),
             terminals: Vec::new(),
             external_files: Vec::new(),
             read_only_files: Vec::new(),
             unnamed_buffers: Vec::new(),
+            scratch_buffers: Vec::new(),
             plugin_global_state: HashMap::new(),
             saved_at: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
@@ -1542,6 +1582,7 @@ mod tests {
             whole_word: true,
             use_regex: false,
             confirm_each: true,
+            multiline: true,
         };
 
         let json = serde_json::to_string(&options).unwrap();
@@ -1551,6 +1592,7 @@ mod tests {
         assert!(restored.whole_word);
         assert!(!restored.use_regex);
         assert!(restored.confirm_each);
+        assert!(restored.multiline);
     }
 
     #[test]
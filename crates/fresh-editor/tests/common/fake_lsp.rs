@@ -1277,6 +1277,9 @@ case "$method" in
         send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"contents":{"kind":"markdown","value":"Test hover content"},"range":{"start":{"line":'$line',"character":'$char'},"end":{"line":'$line',"character":'$end_char'}}}}'
         ;;
     "textDocument/completion")
+        # Log how the request was triggered (CompletionContext)
+        echo "$msg" | grep -o '"triggerKind":[0-9]*' >> "$LOG_FILE"
+        echo "$msg" | grep -o '"triggerCharacter":"[^"]*"' >> "$LOG_FILE"
         send_message '{"jsonrpc":"2.0","id":'$msg_id',"result":{"items":[{"label":"test_function","kind":3,"detail":"fn test_function()","insertText":"test_function"}]}}'
         ;;
     "textDocument/definition")
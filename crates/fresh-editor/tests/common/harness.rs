@@ -17,7 +17,7 @@ fn init_test_environment() {
     fresh_plugin_runtime::backend::set_panic_on_js_errors(true);
 }
 
-use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
 /// Terminal layout constants
 /// The editor uses a fixed layout with reserved rows for UI elements
@@ -1406,6 +1406,21 @@ impl EditorTestHarness {
         Ok(())
     }
 
+    /// Deliver a key press the way the terminal event loop does, through
+    /// `handle_input_event`, so key translation and dead-key composition
+    /// apply (`send_key` goes straight to `handle_key`).
+    pub fn send_terminal_key(
+        &mut self,
+        code: KeyCode,
+        modifiers: KeyModifiers,
+    ) -> anyhow::Result<()> {
+        self.editor
+            .handle_input_event(crossterm::event::Event::Key(KeyEvent::new(code, modifiers)))?;
+        self.drain_async_work();
+        self.render()?;
+        Ok(())
+    }
+
     /// Force a render cycle and capture output
     pub fn render(&mut self) -> anyhow::Result<()> {
         self.terminal.draw(|frame| {
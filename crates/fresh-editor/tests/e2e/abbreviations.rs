@@ -0,0 +1,94 @@
+//! Tests for abbreviations expanded as you type.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+
+fn harness_with(config: Config, file_name: &str) -> (EditorTestHarness, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let file = temp_dir.path().join(file_name);
+    std::fs::write(&file, "").unwrap();
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    harness.open_file(&file).unwrap();
+    harness.render().unwrap();
+    (harness, temp_dir)
+}
+
+fn undo(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+}
+
+#[test]
+fn test_abbreviation_expands_on_word_boundary() {
+    let mut config = Config::default();
+    config
+        .editor
+        .abbreviations
+        .insert("teh".to_string(), "the".to_string());
+    let (mut harness, _temp) = harness_with(config, "notes.txt");
+
+    harness.type_text("teh cat, xteh teh.").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "the cat, xteh the.");
+}
+
+#[test]
+fn test_undo_reverts_just_the_expansion() {
+    let mut config = Config::default();
+    config
+        .editor
+        .abbreviations
+        .insert("teh".to_string(), "the".to_string());
+    let (mut harness, _temp) = harness_with(config, "notes.txt");
+
+    harness.type_text("teh ").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "the ");
+
+    undo(&mut harness);
+    assert_eq!(harness.get_buffer_content().unwrap(), "teh ");
+    assert_eq!(harness.cursor_position(), 4);
+
+    // Typing on after the undo doesn't expand it again.
+    harness.type_text("x").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "teh x");
+}
+
+#[test]
+fn test_language_abbreviations_apply_to_their_language_only() {
+    let mut config = Config::default();
+    config
+        .languages
+        .get_mut("rust")
+        .unwrap()
+        .abbreviations
+        .insert("pl".to_string(), "println!".to_string());
+
+    let (mut harness, _temp) = harness_with(config.clone(), "main.rs");
+    harness.type_text("pl ").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "println! ");
+
+    let (mut harness, _temp) = harness_with(config, "notes.txt");
+    harness.type_text("pl ").unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "pl ");
+}
+
+#[test]
+fn test_template_abbreviation_starts_a_snippet() {
+    let mut config = Config::default();
+    config
+        .editor
+        .abbreviations
+        .insert(";fn".to_string(), "fn ${1:name}() {\n    $0\n}".to_string());
+    let (mut harness, _temp) = harness_with(config, "main.rs");
+
+    harness.type_text(";fn ").unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "fn name() {\n    \n}"
+    );
+    assert_eq!(harness.get_selected_text(), "name");
+
+    undo(&mut harness);
+    assert_eq!(harness.get_buffer_content().unwrap(), ";fn");
+}
@@ -1,7 +1,8 @@
 // E2E tests for the `editor.auto_read_only` config option (issue #2048).
 //
 // By default, files that are not writable on disk and files in library/vendor
-// directories (node_modules, rustup toolchains, ...) open in read-only mode.
+// directories (node_modules, rustup toolchains, ...) open in read-only mode,
+// as do generated files (an `@generated` / `DO NOT EDIT` header).
 // Setting `auto_read_only: false` disables that automatic detection so such
 // files always open editable. Binary files open read-only regardless.
 //
@@ -271,6 +272,49 @@ fn test_library_file_opens_read_only_by_default() {
     );
 }
 
+/// A generated file (Go-style `DO NOT EDIT` header) opens read-only by default.
+#[test]
+fn test_generated_file_opens_read_only_by_default() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("api.pb.go");
+    std::fs::write(
+        &file_path,
+        "// Code generated by protoc-gen-go. DO NOT EDIT.\n\npackage api\n",
+    )
+    .unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        160,
+        24,
+        Config::default(),
+        temp_dir.path().to_path_buf(),
+    )
+    .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("package api");
+
+    harness.type_text("ZZTYPEDZZ").unwrap();
+    harness.render().unwrap();
+    let screen = harness.screen_to_string();
+    assert!(
+        !screen.contains("ZZTYPEDZZ"),
+        "Editing should be disabled for generated files by default. Screen:\n{}",
+        screen
+    );
+    assert!(
+        screen.contains(EDITING_DISABLED_MSG),
+        "Blocked keystroke should surface the editing-disabled message. Screen:\n{}",
+        screen
+    );
+    assert!(
+        screen.contains(READ_ONLY_INDICATOR),
+        "Generated file should show the read-only indicator. Screen:\n{}",
+        screen
+    );
+}
+
 /// With `auto_read_only: false`, a file in a library directory opens editable.
 #[test]
 fn test_auto_read_only_disabled_opens_library_file_editable() {
@@ -307,6 +307,72 @@ fn test_auto_revert_preserves_cursor_position() {
     );
 }
 
+/// Test that lines inserted above the cursor by an external change move the
+/// cursor, its selection and the scroll position down with the text they
+/// were on, instead of leaving them at the same byte offsets.
+#[test]
+fn test_auto_revert_remaps_cursor_selection_and_scroll() {
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file_path = project_dir.join("remap.txt");
+
+    let lines: Vec<String> = (1..=100).map(|i| format!("Line number {}", i)).collect();
+    let content = lines.join("\n");
+    write_and_sync(&file_path, &content);
+
+    harness.open_file(&file_path).unwrap();
+
+    // Scroll down, then select "number" on the cursor line.
+    use crossterm::event::{KeyCode, KeyModifiers};
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::PageDown, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    for _ in 0.."Line ".len() {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    for _ in 0.."number".len() {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness.render().unwrap();
+
+    let top_line_before = harness.top_line_number();
+    let selection_before = harness.get_selection_range().unwrap();
+    assert_eq!(&content[selection_before.clone()], "number");
+    let cursor_line = content[..selection_before.start].matches('\n').count();
+
+    // Insert three lines at the top of the file.
+    harness.sleep(FILE_CHANGE_DELAY);
+    let modified_content = format!("Header A\nHeader B\nHeader C\n{}", content);
+    write_and_sync(&file_path, &modified_content);
+
+    let expected = modified_content.clone();
+    harness
+        .wait_until(|h| h.get_buffer_content().unwrap() == expected)
+        .expect("Auto-revert should update buffer");
+    harness.render().unwrap();
+
+    let shift = "Header A\nHeader B\nHeader C\n".len();
+    let selection_after = harness.get_selection_range();
+    assert_eq!(
+        selection_after,
+        Some(selection_before.start + shift..selection_before.end + shift),
+        "Selection should follow its text through the inserted lines"
+    );
+    assert_eq!(
+        harness.top_line_number(),
+        top_line_before + 3,
+        "Scroll position should follow its text through the inserted lines"
+    );
+    harness.assert_screen_contains(&lines[cursor_line]);
+}
+
 /// Test that auto-revert is not disabled by a single save operation
 /// Previously, saving the file would immediately trigger auto-revert disable
 /// because the file change event would come too quickly after the previous event
@@ -534,3 +600,63 @@ fn test_auto_revert_forced_off_for_terminal_buffers() {
         "terminal-backed buffers must have auto-revert forced off"
     );
 }
+
+/// A branch switch reloads the open files the checkout rewrote in one
+/// refresh pass, without waiting for the regular auto-revert poll, and
+/// reports it in the status bar.
+#[test]
+#[cfg_attr(windows, ignore)]
+fn test_branch_switch_reloads_open_files() {
+    use crate::common::git_test_helper::{git_command, GitTestRepo};
+
+    let repo = GitTestRepo::new();
+    let file_path = repo.create_file("notes.txt", "main version\n");
+    repo.git_add_all();
+    repo.git_commit("Initial commit");
+    let checkout = |args: &[&str]| {
+        let status = git_command(&repo.path)
+            .arg("checkout")
+            .args(args)
+            .status()
+            .unwrap();
+        assert!(status.success(), "git checkout {:?} failed", args);
+    };
+    checkout(&["-q", "-b", "feature"]);
+    repo.modify_file("notes.txt", "feature version\n");
+    repo.git_add_all();
+    repo.git_commit("Feature change");
+    checkout(&["-q", "-"]);
+
+    // Only the branch switch may reload the file: the regular poll is
+    // pushed far beyond the test.
+    let mut config = fresh::config::Config::default();
+    config.editor.auto_revert_poll_interval_ms = 3_600_000;
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, repo.path.clone()).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.assert_screen_contains("main version");
+
+    // Wait for the file-tree poller to record the current HEAD.
+    harness
+        .wait_until(|h| !h.editor().active_window().git_heads.is_empty())
+        .unwrap();
+
+    let mtime_before = fs::metadata(&file_path).unwrap().modified().unwrap();
+    checkout(&["-q", "feature"]);
+    // Make the rewrite visible regardless of the filesystem's mtime
+    // granularity.
+    File::options()
+        .write(true)
+        .open(&file_path)
+        .unwrap()
+        .set_modified(mtime_before + Duration::from_secs(10))
+        .unwrap();
+
+    harness
+        .wait_until(|h| h.get_buffer_content().unwrap() == "feature version\n")
+        .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Switched to feature"))
+        .unwrap();
+    harness.assert_screen_contains("1 open file(s) changed");
+}
@@ -542,3 +542,39 @@ fn test_block_selection_copy_copies_rectangular_region() {
         "Block selection copy should produce exactly the rectangular region"
     );
 }
+
+/// Block selection columns are display columns: a double-width character
+/// spans two of them, a combining mark none, and the copied rectangle
+/// always holds whole graphemes.
+#[test]
+fn test_block_selection_copy_uses_display_columns() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.editor_mut().set_clipboard_for_test("".to_string());
+
+    harness
+        .type_text("你好 world\nabcdef\ne\u{301}😀zz")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+
+    // Each Right steps over one CJK character: two columns.
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::ALT | KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    for _ in 0..2 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::ALT | KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        .unwrap();
+
+    assert_eq!(
+        harness.editor_mut().clipboard_content_for_test(),
+        "你好\nabcd\ne\u{301}😀z"
+    );
+}
@@ -0,0 +1,108 @@
+//! E2E tests for terminal-initiated (bracketed) paste
+//!
+//! Tests that:
+//! - A bracketed paste into a buffer is one undo step and isn't auto-indented
+//! - Pasting many lines into a live terminal asks for confirmation first
+//! - A short paste goes straight to the terminal
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use portable_pty::{native_pty_system, PtySize};
+
+fn harness_or_skip(width: u16, height: u16) -> Option<EditorTestHarness> {
+    if native_pty_system()
+        .openpty(PtySize {
+            rows: 1,
+            cols: 1,
+            pixel_width: 0,
+            pixel_height: 0,
+        })
+        .is_err()
+    {
+        eprintln!("Skipping terminal test: PTY not available in this environment");
+        return None;
+    }
+
+    EditorTestHarness::new(width, height).ok()
+}
+
+macro_rules! harness_or_return {
+    ($w:expr, $h:expr) => {
+        match harness_or_skip($w, $h) {
+            Some(h) => h,
+            None => return,
+        }
+    };
+}
+
+/// Twelve `echo` lines — above the default `terminal.paste_confirm_lines`.
+fn many_lines(marker: &str) -> String {
+    (1..=12)
+        .map(|i| format!("echo {marker}{i}\n"))
+        .collect::<String>()
+}
+
+#[test]
+fn test_paste_into_buffer_is_one_undo_step_without_auto_indent() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.load_buffer_from_text("    x").unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    harness.send_paste("\nfoo\nbar").unwrap();
+    // Typed, each newline would have copied the four-space indent.
+    harness.assert_buffer_content("    x\nfoo\nbar");
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("    x");
+}
+
+#[test]
+fn test_large_terminal_paste_asks_and_can_be_cancelled() {
+    let mut harness = harness_or_return!(100, 30);
+    harness.editor_mut().open_terminal();
+    harness.render().unwrap();
+
+    harness.send_paste(&many_lines("CANCELLED_")).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Paste 12 lines into the terminal");
+    harness.assert_screen_contains("echo CANCELLED_1");
+
+    harness.type_text("n").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Paste cancelled");
+    harness.assert_screen_not_contains("CANCELLED_12");
+}
+
+#[test]
+fn test_large_terminal_paste_is_sent_after_confirming() {
+    let mut harness = harness_or_return!(100, 30);
+    harness.editor_mut().open_terminal();
+    harness.render().unwrap();
+
+    harness.send_paste(&many_lines("CONFIRMED_")).unwrap();
+    harness.render().unwrap();
+    harness.type_text("y").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.wait_for_screen_contains("CONFIRMED_12").unwrap();
+}
+
+#[test]
+fn test_short_terminal_paste_is_sent_without_asking() {
+    let mut harness = harness_or_return!(100, 30);
+    harness.editor_mut().open_terminal();
+    harness.render().unwrap();
+
+    harness.send_paste("echo SHORT_PASTE\n").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("into the terminal");
+
+    harness.wait_for_screen_contains("SHORT_PASTE").unwrap();
+}
@@ -0,0 +1,112 @@
+//! Tests for the editor content context menu
+//!
+//! Tests that:
+//! - Right-clicking in a buffer lists the clipboard entries
+//! - A right-click inside the selection keeps it, so Copy acts on it
+//! - A right-click elsewhere moves the cursor to the clicked position
+//! - The Menu key opens the same menu at the cursor, navigable by keyboard
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+fn open_file(content: &str) -> (TempDir, EditorTestHarness) {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("a.txt");
+    std::fs::write(&file_path, content).unwrap();
+    let mut harness =
+        EditorTestHarness::create(80, 24, HarnessOptions::new().with_config(Config::default()))
+            .unwrap();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    (temp_dir, harness)
+}
+
+/// Click the menu entry labelled `label`.
+fn click_item(harness: &mut EditorTestHarness, label: &str) {
+    let (col, row) = harness
+        .find_text_on_screen(label)
+        .unwrap_or_else(|| panic!("menu item {label:?} should be on screen"));
+    harness.mouse_click(col, row).unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_right_click_in_buffer_lists_clipboard_items() {
+    let (_dir, mut harness) = open_file("alpha beta\n");
+    let (col, row) = harness.find_text_on_screen("beta").unwrap();
+    harness.mouse_right_click(col, row).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Cut");
+    harness.assert_screen_contains("Copy");
+    harness.assert_screen_contains("Paste");
+    harness.assert_screen_contains("Select All");
+    // No language server for plain text, so no LSP entries.
+    harness.assert_screen_not_contains("Go to Definition");
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Select All");
+}
+
+#[test]
+fn test_right_click_copy_keeps_selection() {
+    let (_dir, mut harness) = open_file("alpha beta\n");
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+    harness.render().unwrap();
+
+    let (col, row) = harness.find_text_on_screen("beta").unwrap();
+    harness.mouse_right_click(col, row).unwrap();
+    harness.render().unwrap();
+    click_item(&mut harness, "Copy");
+
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("alpha beta\nalpha beta");
+}
+
+#[test]
+fn test_right_click_outside_selection_moves_cursor() {
+    let (_dir, mut harness) = open_file("first\nsecond\n");
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+    harness.render().unwrap();
+
+    let (col, row) = harness.find_text_on_screen("second").unwrap();
+    harness.mouse_right_click(col, row).unwrap();
+    harness.render().unwrap();
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    // The selection on the first line is gone and typing lands at the click.
+    harness.type_text("X").unwrap();
+    harness.assert_buffer_content("first\nXsecond\n");
+}
+
+#[test]
+fn test_menu_key_opens_menu_at_cursor() {
+    let (_dir, mut harness) = open_file("word\n");
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+
+    harness.send_key(KeyCode::Menu, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Select All");
+
+    // Cut, Copy, Paste: two steps down, then Enter.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("wordword\n");
+    harness.assert_screen_not_contains("Select All");
+}
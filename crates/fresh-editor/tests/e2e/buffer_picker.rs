@@ -0,0 +1,129 @@
+//! E2E tests for the buffer picker ("Switch Buffer"): most-recently-used
+//! order, preview in the other split, and closing buffers from the list.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Harness with alpha.txt, bravo.txt and charlie.txt opened in that order.
+fn harness_with_three_files() -> EditorTestHarness {
+    let mut harness = EditorTestHarness::with_temp_project(120, 30).unwrap();
+    let dir = harness.project_dir().unwrap();
+    for name in ["alpha", "bravo", "charlie"] {
+        let path = dir.join(format!("{name}.txt"));
+        std::fs::write(&path, format!("{name} content\n")).unwrap();
+        harness.open_file(&path).unwrap();
+    }
+    harness.render().unwrap();
+    harness
+}
+
+/// The previous buffer is preselected, so opening the picker and pressing
+/// Enter toggles between the two most recent buffers; the list follows
+/// use, not opening order.
+#[test]
+fn test_buffer_picker_mru_order() {
+    let mut harness = harness_with_three_files();
+
+    run_command(&mut harness, "Switch Buffer");
+    harness.assert_screen_contains("Switch buffer:");
+    let screen = harness.screen_to_string();
+    // Rows of the suggestion list, below the tab bar's file names.
+    let list = &screen[screen.rfind('┌').unwrap()..];
+    let charlie = list.find("charlie.txt").unwrap();
+    let bravo = list.find("bravo.txt").unwrap();
+    let alpha = list.find("alpha.txt").unwrap();
+    assert!(
+        charlie < bravo && bravo < alpha,
+        "not in MRU order:\n{screen}"
+    );
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "bravo content\n");
+
+    // Fuzzy filtering picks the oldest buffer; afterwards the picker's
+    // default goes back to bravo.
+    run_command(&mut harness, "Switch Buffer");
+    harness.type_text("alp").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "alpha content\n");
+
+    run_command(&mut harness, "Switch Buffer");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "bravo content\n");
+}
+
+/// With two splits, the highlighted buffer shows in the other split while
+/// the picker is open and is put back on Escape.
+#[test]
+fn test_buffer_picker_previews_in_other_split() {
+    let mut harness = harness_with_three_files();
+    run_command(&mut harness, "Split Vertical");
+    harness.assert_screen_not_contains("bravo content");
+
+    // bravo is preselected: the other split previews it.
+    run_command(&mut harness, "Switch Buffer");
+    harness.assert_screen_contains("bravo content");
+    harness.assert_screen_not_contains("alpha content");
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("alpha content");
+    harness.assert_screen_not_contains("bravo content");
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("alpha content");
+    assert_eq!(harness.get_buffer_content().unwrap(), "charlie content\n");
+}
+
+/// Ctrl+D closes the highlighted buffer and keeps the picker open; a
+/// buffer with unsaved changes asks to save or discard first.
+#[test]
+fn test_buffer_picker_close_buffer() {
+    let mut harness = harness_with_three_files();
+    harness.type_text("edited ").unwrap();
+
+    run_command(&mut harness, "Switch Buffer");
+    harness
+        .send_key(KeyCode::Char('d'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Switch buffer:");
+    assert!(!harness.screen_to_string().contains("bravo.txt"));
+
+    // The highlight moved onto alpha; move up to the modified charlie.
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Char('d'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("'charlie.txt' modified");
+    harness.type_text("d").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "alpha content\n");
+    harness.assert_screen_not_contains("charlie.txt");
+}
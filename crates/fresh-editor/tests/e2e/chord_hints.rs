@@ -0,0 +1,79 @@
+//! E2E tests for the chord hint popup and `editor.chord_timeout_ms`.
+//!
+//! The Emacs keymap binds a family of `Ctrl+X …` chords, so pressing
+//! `Ctrl+X` leaves a chord pending.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+
+fn emacs_harness(config: Config) -> EditorTestHarness {
+    let config = Config {
+        active_keybinding_map: "emacs".into(),
+        ..config
+    };
+    EditorTestHarness::create(
+        100,
+        30,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_preserved_keybinding_map(),
+    )
+    .unwrap()
+}
+
+/// The first chord key lists its continuations; a key that ends the chord
+/// closes the list.
+#[test]
+fn test_chord_prefix_shows_continuations() {
+    let mut harness = emacs_harness(Config::default());
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Ctrl+S → Save file");
+
+    harness
+        .send_key(KeyCode::Char('x'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Ctrl+S → Save file");
+    harness.assert_screen_contains(" Ctrl+X ");
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Ctrl+S → Save file");
+}
+
+/// With hints turned off a pending chord shows nothing.
+#[test]
+fn test_chord_hints_can_be_disabled() {
+    let mut config = Config::default();
+    config.editor.show_chord_hints = false;
+    let mut harness = emacs_harness(config);
+
+    harness
+        .send_key(KeyCode::Char('x'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Ctrl+S → Save file");
+}
+
+/// A chord left waiting longer than `chord_timeout_ms` is abandoned.
+#[test]
+fn test_chord_times_out() {
+    let mut config = Config::default();
+    config.editor.chord_timeout_ms = 500;
+    let mut harness = emacs_harness(config);
+
+    harness
+        .send_key(KeyCode::Char('x'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.tick_and_render().unwrap();
+    harness.assert_screen_contains("Ctrl+S → Save file");
+
+    harness.advance_time(std::time::Duration::from_millis(300));
+    harness.tick_and_render().unwrap();
+    harness.assert_screen_contains("Ctrl+S → Save file");
+
+    harness.advance_time(std::time::Duration::from_millis(300));
+    harness.tick_and_render().unwrap();
+    harness.assert_screen_not_contains("Ctrl+S → Save file");
+}
@@ -0,0 +1,59 @@
+//! Launch options handled by the editor itself: `fresh --wait` and
+//! `fresh --diff OLD NEW`.
+
+use crate::common::harness::EditorTestHarness;
+use fresh::app::CLI_WAIT_ID;
+
+/// A file opened with the `--wait` id quits the editor once its buffer is
+/// closed, and not before.
+#[test]
+fn test_wait_quits_when_buffer_closed() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let file = temp_dir.path().join("COMMIT_EDITMSG");
+    std::fs::write(&file, "fix things\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .editor_mut()
+        .queue_file_open(file, None, None, None, None, None, Some(CLI_WAIT_ID));
+    harness.tick_and_render().unwrap();
+    harness.assert_screen_contains("fix things");
+    assert!(!harness.editor().should_quit());
+
+    let buffer_id = harness.editor().active_buffer();
+    harness.editor_mut().close_buffer(buffer_id).unwrap();
+    // The wait belongs to the CLI, not to a nested client.
+    assert!(harness.editor_mut().take_completed_waits().is_empty());
+    harness.tick_and_render().unwrap();
+    assert!(harness.editor().should_quit());
+}
+
+/// `--diff` shows both files side by side, aligned on their common lines.
+#[test]
+fn test_diff_view_opens_both_files() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let old = temp_dir.path().join("old.txt");
+    let new = temp_dir.path().join("new.txt");
+    std::fs::write(&old, "alpha\nbravo\ncharlie\n").unwrap();
+    std::fs::write(&new, "alpha\nbeta\ncharlie\ndelta\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    let buffer_id = harness.editor_mut().open_diff_view(&old, &new).unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.editor().active_buffer(), buffer_id);
+    assert!(harness
+        .editor()
+        .active_window()
+        .is_composite_buffer(buffer_id));
+    harness.assert_screen_contains("old.txt ↔ new.txt");
+    let screen = harness.screen_to_string();
+    let row = |text: &str| {
+        screen
+            .lines()
+            .position(|line| line.contains(text))
+            .unwrap_or_else(|| panic!("{text} not on screen:\n{screen}"))
+    };
+    assert_eq!(row("bravo"), row("beta"));
+    assert!(row("delta") > row("charlie"));
+}
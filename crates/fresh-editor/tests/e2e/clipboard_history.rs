@@ -0,0 +1,173 @@
+//! Tests for the clipboard history
+//!
+//! Tests that:
+//! - Paste from History lists recent copies and pastes the picked one
+//! - Typing in the picker filters it, Alt+K pins and Alt+I pastes re-indented
+//! - Cycle Paste Through History swaps the text just pasted for older copies
+//! - Cycling refuses to touch the buffer once something else was edited
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(command_name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Open `one\ntwo\nthree\n` and copy each line in turn, leaving the cursor
+/// at the end of the buffer. History is then `three`, `two`, `one`.
+fn open_with_history() -> (TempDir, EditorTestHarness) {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("a.txt");
+    std::fs::write(&file_path, "one\ntwo\nthree\n").unwrap();
+    let mut harness =
+        EditorTestHarness::create(80, 24, HarnessOptions::new().with_config(Config::default()))
+            .unwrap();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    harness.open_file(&file_path).unwrap();
+    for _ in 0..3 {
+        harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+        harness
+            .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    }
+    harness.render().unwrap();
+    (temp_dir, harness)
+}
+
+#[test]
+fn test_paste_from_history_pastes_picked_entry() {
+    let (_dir, mut harness) = open_with_history();
+    run_command(&mut harness, "Paste from History");
+    harness.assert_screen_contains("Clipboard History");
+
+    // Newest first: three, two, one.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("one\ntwo\nthree\ntwo");
+
+    // The picked entry is now the clipboard, so a plain paste repeats it.
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("one\ntwo\nthree\ntwotwo");
+}
+
+#[test]
+fn test_cycle_paste_history_replaces_last_paste() {
+    let (_dir, mut harness) = open_with_history();
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("one\ntwo\nthree\nthree");
+
+    run_command(&mut harness, "Cycle Paste Through History");
+    harness.assert_buffer_content("one\ntwo\nthree\ntwo");
+    run_command(&mut harness, "Cycle Paste Through History");
+    harness.assert_buffer_content("one\ntwo\nthree\none");
+    // Past the oldest entry it wraps back to the newest.
+    run_command(&mut harness, "Cycle Paste Through History");
+    harness.assert_buffer_content("one\ntwo\nthree\nthree");
+
+    // One undo takes the cycled paste back out.
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("one\ntwo\nthree\n");
+}
+
+#[test]
+fn test_cycle_paste_history_needs_a_fresh_paste() {
+    let (_dir, mut harness) = open_with_history();
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("!").unwrap();
+
+    run_command(&mut harness, "Cycle Paste Through History");
+    harness.assert_buffer_content("one\ntwo\nthree\nthree!");
+    harness.assert_screen_contains("Nothing wa");
+}
+
+#[test]
+fn test_paste_from_history_filters_and_pins() {
+    let (_dir, mut harness) = open_with_history();
+    run_command(&mut harness, "Paste from History");
+
+    // Pin "one", the oldest entry: it moves to the top of the list.
+    harness.type_text("one").unwrap();
+    harness
+        .send_key(KeyCode::Char('k'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("pinned");
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("one\ntwo\nthree\none");
+
+    // A query with no match pastes nothing.
+    run_command(&mut harness, "Paste from History");
+    harness.type_text("zzz").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_buffer_content("one\ntwo\nthree\none");
+}
+
+#[test]
+fn test_paste_from_history_reindents_to_cursor_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("a.txt");
+    std::fs::write(
+        &file_path,
+        "        if a {\n            b();\n        }\n    ",
+    )
+    .unwrap();
+    let mut harness =
+        EditorTestHarness::create(80, 24, HarnessOptions::new().with_config(Config::default()))
+            .unwrap();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    harness.open_file(&file_path).unwrap();
+
+    // Copy the three indented lines, then paste them on the last line,
+    // which is indented by four spaces.
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+
+    run_command(&mut harness, "Paste from History");
+    harness.type_text("if a").unwrap();
+    harness
+        .send_key(KeyCode::Char('i'), KeyModifiers::ALT)
+        .unwrap();
+    harness.assert_buffer_content(
+        "        if a {\n            b();\n        }\n    if a {\n        b();\n    }\n",
+    );
+}
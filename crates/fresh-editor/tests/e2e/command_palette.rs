@@ -1917,3 +1917,78 @@ fn test_command_palette_description_search() {
     harness.assert_screen_contains("Toggle Page View");
     harness.assert_screen_contains("Set Page Width");
 }
+
+/// Text from the primary cursor to the end of its line.
+fn rest_of_cursor_line(harness: &EditorTestHarness) -> String {
+    let text = harness.get_buffer_content().unwrap();
+    let rest = &text[harness.cursor_position()..];
+    rest.split('\n').next().unwrap_or_default().to_string()
+}
+
+/// The Goto Line prompt accepts `:+N`/`:-N`, `N%` and `LINE:COL` targets.
+#[test]
+fn test_goto_line_prompt_relative_percent_and_column() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(100, 24, Default::default()).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    let jump_path = project_root.join("jump.txt");
+    write_numbered_lines(&jump_path, 100);
+    harness.open_file(&jump_path).unwrap();
+    harness.render().unwrap();
+
+    let goto = |harness: &mut EditorTestHarness, input: &str| {
+        harness
+            .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+            .unwrap();
+        harness.type_text(input).unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.render().unwrap();
+    };
+
+    goto(&mut harness, "50%");
+    assert_eq!(rest_of_cursor_line(&harness), "LINE50");
+
+    goto(&mut harness, ":+10");
+    assert_eq!(rest_of_cursor_line(&harness), "LINE60");
+
+    goto(&mut harness, ":-5");
+    assert_eq!(rest_of_cursor_line(&harness), "LINE55");
+
+    goto(&mut harness, "12:4");
+    assert_eq!(rest_of_cursor_line(&harness), "E12");
+}
+
+/// `file:line:col` in the Goto Line prompt opens that file at the position.
+#[test]
+fn test_goto_line_prompt_file_line_col() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut harness =
+        EditorTestHarness::with_temp_project_and_config(100, 24, Default::default()).unwrap();
+    let project_root = harness.project_dir().unwrap();
+    let first = project_root.join("first.txt");
+    let second = project_root.join("second.txt");
+    write_numbered_lines(&first, 10);
+    fs::write(&second, "alpha\nbeta\ngamma\n").unwrap();
+    harness.open_file(&first).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('g'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("second.txt:3:2").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "alpha\nbeta\ngamma\n"
+    );
+    assert_eq!(rest_of_cursor_line(&harness), "amma");
+}
@@ -0,0 +1,59 @@
+//! Keybindings whose `when` clause depends on the active buffer
+//! (`language:…`, `filetype:…`) or combines conditions (`&&`, `||`, `!`).
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, Keybinding};
+use std::collections::HashMap;
+
+fn config_with_binding(when: &str) -> Config {
+    let mut config = Config::default();
+    config.keybindings.push(Keybinding {
+        key: "j".to_string(),
+        modifiers: vec!["alt".to_string()],
+        keys: vec![],
+        action: "move_document_end".to_string(),
+        args: HashMap::new(),
+        when: Some(when.to_string()),
+        release: false,
+    });
+    config
+}
+
+/// Open `name` with three lines, press Alt+J and report whether the cursor
+/// jumped to the end of the document.
+fn alt_j_jumps_to_end(harness: &mut EditorTestHarness, name: &str) -> bool {
+    let path = harness.project_dir().unwrap().join(name);
+    std::fs::write(&path, "one\ntwo\nthree\n").unwrap();
+    harness.open_file(&path).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Char('j'), KeyModifiers::ALT)
+        .unwrap();
+    harness.cursor_position() == "one\ntwo\nthree\n".len()
+}
+
+#[test]
+fn language_binding_fires_only_in_that_language() {
+    let config = config_with_binding("language:markdown");
+    let mut harness = EditorTestHarness::with_temp_project_and_config(100, 30, config).unwrap();
+
+    assert!(
+        alt_j_jumps_to_end(&mut harness, "notes.md"),
+        "language:markdown binding must fire in a markdown buffer"
+    );
+    assert!(
+        !alt_j_jumps_to_end(&mut harness, "main.rs"),
+        "language:markdown binding must not fire in a rust buffer"
+    );
+}
+
+#[test]
+fn combined_clause_checks_every_condition() {
+    let config = config_with_binding("normal && (filetype:txt || language:rust)");
+    let mut harness = EditorTestHarness::with_temp_project_and_config(100, 30, config).unwrap();
+
+    assert!(alt_j_jumps_to_end(&mut harness, "notes.txt"));
+    assert!(alt_j_jumps_to_end(&mut harness, "main.rs"));
+    assert!(!alt_j_jumps_to_end(&mut harness, "notes.md"));
+}
@@ -0,0 +1,70 @@
+//! E2E tests for config hot reload: writing a config file applies it
+//! without running Reload Config, and a broken one is reported in a popup.
+
+use crate::common::harness::EditorTestHarness;
+use ratatui::style::Color;
+
+/// The default ruler background color.
+const RULER_BG: Color = Color::Rgb(50, 50, 50);
+
+fn user_config_path(harness: &EditorTestHarness) -> std::path::PathBuf {
+    let config_dir = harness
+        .project_dir()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("config");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    config_dir.join("config.json")
+}
+
+/// Editing the user config on disk applies the new settings, including
+/// rulers of buffers that are already open.
+#[test]
+fn test_config_file_change_reloads() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 30).unwrap();
+    let config_path = user_config_path(&harness);
+    // First tick records the config files as they are now.
+    let _fixture = harness.load_buffer_from_text(&"X".repeat(60)).unwrap();
+    harness.tick_and_render().unwrap();
+
+    std::fs::write(
+        &config_path,
+        "{\n  \"editor\": {\n    \"tab_size\": 7,\n    \"rulers\": [40]\n  }\n}\n",
+    )
+    .unwrap();
+    harness
+        .wait_until(|h| h.config().editor.tab_size == 7)
+        .unwrap();
+    harness.assert_screen_contains("Config reloaded");
+    let gutter_width = harness.editor().active_state().margins.left_total_width() as u16;
+    let (row, _) = harness.content_area_rows();
+    assert_eq!(
+        harness
+            .get_cell_style(gutter_width + 40, row as u16)
+            .and_then(|s| s.bg),
+        Some(RULER_BG)
+    );
+}
+
+/// A config file that stops loading keeps the current settings and says
+/// why in a popup.
+#[test]
+fn test_broken_config_change_shows_popup() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 30).unwrap();
+    let config_path = user_config_path(&harness);
+    harness.tick_and_render().unwrap();
+    let tab_size = harness.config().editor.tab_size;
+
+    std::fs::write(
+        &config_path,
+        "{\n  \"editor\": {\n    \"tab_size\": ,\n  }\n}\n",
+    )
+    .unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Config Not Reloaded"))
+        .unwrap();
+    harness.assert_screen_contains("current settings were kept");
+    assert_eq!(harness.config().editor.tab_size, tab_size);
+    assert!(!harness.editor().in_config_rescue());
+}
@@ -0,0 +1,63 @@
+//! E2E tests for config rescue mode: a config file that doesn't load is
+//! opened with its error, and Reload Config applies it once fixed.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// A broken config keeps the current settings, opens the file with the
+/// error and clears up after the file is fixed, saved and reloaded.
+#[test]
+fn test_broken_config_rescue_and_reload() {
+    let mut harness = EditorTestHarness::with_temp_project(160, 30).unwrap();
+    let config_dir = harness
+        .project_dir()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("config");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    let config_path = config_dir.join("config.json");
+    std::fs::write(
+        &config_path,
+        "{\n  \"editor\": {\n    \"tab_size\": 7,\n    \"line_numbers\": fals\n  }\n}\n",
+    )
+    .unwrap();
+    let tab_size = harness.config().editor.tab_size;
+
+    run_command(&mut harness, "Reload Config");
+    assert!(harness.editor().in_config_rescue());
+    assert_eq!(harness.config().editor.tab_size, tab_size);
+    harness.assert_screen_contains("\"line_numbers\": fals");
+    harness.assert_screen_contains("Config error in");
+
+    // Fix the typo in the opened config file and save it.
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .type_text("{\n\"editor\": {\n\"tab_size\": 7,\n\"line_numbers\": false\n}\n}\n")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Config file is valid again");
+    assert!(harness.editor().in_config_rescue());
+
+    run_command(&mut harness, "Reload Config");
+    assert!(!harness.editor().in_config_rescue());
+    assert_eq!(harness.config().editor.tab_size, 7);
+    harness.assert_screen_contains("Config reloaded");
+}
@@ -1,6 +1,7 @@
 //! E2E coverage for the "Copy File Path" / "Copy Relative File Path" commands
-//! (issue #1752): the user-visible status message confirms what was copied,
-//! plus the matching items on the tab right-click context menu.
+//! (issue #1752) and "Copy Path:Line": the user-visible status message
+//! confirms what was copied, plus the matching items on the tab right-click
+//! context menu.
 //!
 //! These tests follow CONTRIBUTING.md's "observe, not inspect" rule: they
 //! assert only on rendered output. The status bar surfaces the copied path
@@ -205,3 +206,48 @@ fn tab_right_click_copy_full_path_copies_absolute() {
          got screen:\n{screen}"
     );
 }
+
+// ── Copy Path:Line ───────────────────────────────────────────────────────────
+
+#[test]
+fn copy_path_line_uses_cursor_line_and_configured_separator() {
+    let mut config = fresh::config::Config::default();
+    config.clipboard.path_line_separator = "#L".to_string();
+    let mut harness = EditorTestHarness::with_temp_project_and_config(220, 30, config).unwrap();
+    let project_root = harness.project_dir().unwrap();
+
+    let file_path = project_root.join("lines.txt");
+    fs::write(&file_path, "one\ntwo\nthree\n").unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    run_command_palette(&mut harness, "Copy Path:Line");
+
+    harness.assert_screen_contains("Copied path: lines.txt#L3");
+}
+
+#[test]
+fn tab_right_click_copy_path_line_uses_default_separator() {
+    let mut harness = wide_temp_project_harness();
+    let project_root = harness.project_dir().unwrap();
+
+    let file_path = project_root.join("tab.txt");
+    fs::write(&file_path, "one\ntwo\n").unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    let (col, row) = active_tab_position(&harness);
+    harness.mouse_right_click(col, row).unwrap();
+    harness.render().unwrap();
+
+    let (item_col, item_row) = harness
+        .find_text_on_screen("Copy Path:Line")
+        .expect("'Copy Path:Line' should be visible after tab right-click");
+    harness.mouse_click(item_col, item_row).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Copied path: tab.txt:2");
+}
@@ -107,3 +107,72 @@ fn test_steady_block_skips_reversed_with_hardware_cursor() {
          when hardware cursor is available, but style was {style:?}"
     );
 }
+
+/// With `secondary_cursor_style = "underline"`, secondary cursors are
+/// underlined rather than reverse-video, so they read as distinct from the
+/// primary cursor.
+#[test]
+fn test_underline_secondary_cursor_style() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+    use fresh::config::SecondaryCursorStyle;
+
+    let mut config = Config::default();
+    config.editor.secondary_cursor_style = SecondaryCursorStyle::Underline;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.type_text("abc\nabc\nabc").unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.editor_mut().add_cursor_below();
+    harness.render().unwrap();
+
+    // The secondary cursor sits in the same column one row above or below
+    // the primary.
+    let (cx, cy) = harness.screen_cursor_position();
+    let secondary = [cy - 1, cy + 1]
+        .into_iter()
+        .map(|y| harness.get_cell_style(cx, y).unwrap())
+        .find(|style| style.add_modifier.contains(Modifier::UNDERLINED))
+        .expect("secondary cursor cell should be underlined");
+    assert!(
+        !secondary.add_modifier.contains(Modifier::REVERSED),
+        "underline secondary cursor should not be REVERSED, got {:?}",
+        secondary
+    );
+}
+
+/// The terminal cursor style follows the context: `cursor_style` while
+/// editing, `cursor_style_selection` with a selection, and the steady
+/// variant of either when `cursor_blink` is off.
+#[test]
+fn test_cursor_style_follows_selection_and_blink() {
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    let mut config = Config::default();
+    config.editor.cursor_style = CursorStyle::BlinkingBar;
+    config.editor.cursor_style_selection = Some(CursorStyle::BlinkingBlock);
+    config.editor.cursor_blink = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.type_text("Hello").unwrap();
+
+    assert_eq!(harness.editor().cursor_style(), CursorStyle::SteadyBar);
+    assert_eq!(
+        harness.editor_mut().take_cursor_style_change(),
+        Some(CursorStyle::SteadyBar)
+    );
+    assert_eq!(harness.editor_mut().take_cursor_style_change(), None);
+
+    harness
+        .send_key(KeyCode::Left, KeyModifiers::SHIFT)
+        .unwrap();
+    assert_eq!(
+        harness.editor_mut().take_cursor_style_change(),
+        Some(CursorStyle::SteadyBlock)
+    );
+
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(harness.editor().cursor_style(), CursorStyle::SteadyBar);
+}
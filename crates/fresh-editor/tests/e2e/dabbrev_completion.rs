@@ -19,6 +19,7 @@ fn dabbrev_harness(width: u16, height: u16) -> EditorTestHarness {
         action: "dabbrev_expand".to_string(),
         args: std::collections::HashMap::new(),
         when: None,
+        release: false,
     });
     EditorTestHarness::create(width, height, HarnessOptions::new().with_config(config)).unwrap()
 }
@@ -219,6 +220,7 @@ fn popup_completion_harness(width: u16, height: u16) -> EditorTestHarness {
         action: "lsp_completion".to_string(),
         args: std::collections::HashMap::new(),
         when: None,
+        release: false,
     });
     EditorTestHarness::create(width, height, HarnessOptions::new().with_config(config)).unwrap()
 }
@@ -310,3 +312,60 @@ fn test_popup_buffer_word_dismiss() {
         content
     );
 }
+
+/// Words from project files that aren't open are offered once the project
+/// word index has been built in the background.
+#[test]
+fn test_popup_offers_words_from_unopened_project_files() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project = temp_dir.path().join("project");
+    std::fs::create_dir_all(project.join("config")).unwrap();
+    std::fs::write(
+        project.join("config/settings.ini"),
+        "retention_period_days = 30\n",
+    )
+    .unwrap();
+
+    let mut config = Config::default();
+    config.keybindings.push(fresh::config::Keybinding {
+        key: " ".to_string(),
+        modifiers: vec!["ctrl".to_string()],
+        keys: vec![],
+        action: "lsp_completion".to_string(),
+        args: std::collections::HashMap::new(),
+        when: None,
+        release: false,
+    });
+    let mut harness = EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(project),
+    )
+    .unwrap();
+
+    harness.type_text("retent").unwrap();
+    harness.render().unwrap();
+
+    // The first request starts indexing; re-trigger until it has finished.
+    let mut found = false;
+    for _ in 0..200 {
+        send_ctrl_space(&mut harness);
+        if harness.screen_to_string().contains("retention_period_days") {
+            found = true;
+            break;
+        }
+        harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+    assert!(
+        found,
+        "Popup should offer a word from an unopened project file, screen:\n{}",
+        harness.screen_to_string()
+    );
+
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("retention_period_days");
+}
@@ -0,0 +1,105 @@
+//! E2E tests for dead-key composition on terminals that pass dead keys through
+//!
+//! Tests that:
+//! - An accent followed by a letter types the composed letter only
+//! - The held accent is shown underlined at the caret, not in the buffer
+//! - The `compose_dead_keys` setting turns composition off
+//! - The calibration wizard's event inspector lists the raw events
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use ratatui::style::Modifier;
+
+fn press(harness: &mut EditorTestHarness, c: char) {
+    harness
+        .send_terminal_key(KeyCode::Char(c), KeyModifiers::NONE)
+        .unwrap();
+}
+
+#[test]
+fn test_accent_then_letter_types_composed_letter() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    for c in ['c', 'a', 'f', '´', 'e', ' ', '¨', 'u'] {
+        press(&mut harness, c);
+    }
+
+    harness.assert_buffer_content("café ü");
+    // Undo takes back only the last letter, not a stray accent.
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("café ");
+}
+
+#[test]
+fn test_held_accent_is_shown_at_caret() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    press(&mut harness, 'a');
+    let (caret_x, caret_y) = harness.screen_cursor_position();
+
+    press(&mut harness, '´');
+
+    harness.assert_buffer_content("a");
+    assert_eq!(harness.get_cell(caret_x, caret_y).as_deref(), Some("´"));
+    let style = harness.get_cell_style(caret_x, caret_y).unwrap();
+    assert!(style.add_modifier.contains(Modifier::UNDERLINED));
+
+    // Space types the accent itself.
+    press(&mut harness, ' ');
+    harness.assert_buffer_content("a´");
+    assert_eq!(harness.editor().ime_preedit(), None);
+}
+
+#[test]
+fn test_escape_drops_held_accent() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+
+    press(&mut harness, 'ˇ');
+    harness
+        .send_terminal_key(KeyCode::Esc, KeyModifiers::NONE)
+        .unwrap();
+    press(&mut harness, 's');
+
+    harness.assert_buffer_content("s");
+}
+
+#[test]
+fn test_composition_can_be_disabled() {
+    let mut config = Config::default();
+    config.editor.compose_dead_keys = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+
+    press(&mut harness, '´');
+    press(&mut harness, 'e');
+
+    harness.assert_buffer_content("´e");
+}
+
+#[test]
+fn test_calibration_inspector_lists_raw_events() {
+    let mut harness = EditorTestHarness::new(100, 30).unwrap();
+    harness.editor_mut().open_calibration_wizard();
+    harness.render().unwrap();
+
+    press(&mut harness, 'i');
+    harness.assert_screen_contains("Event Inspector");
+    harness.assert_screen_contains("Waiting for input");
+
+    press(&mut harness, '´');
+    press(&mut harness, 'e');
+    harness.assert_screen_contains("U+00B4");
+    harness.assert_screen_contains("becomes 'é'");
+    // Nothing was typed into the buffer.
+    harness.assert_buffer_content("");
+
+    harness
+        .send_terminal_key(KeyCode::Esc, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_terminal_key(KeyCode::Esc, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_screen_not_contains("Event Inspector");
+    assert!(harness.editor().is_calibration_active());
+}
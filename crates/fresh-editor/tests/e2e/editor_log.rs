@@ -0,0 +1,91 @@
+//! Tests for the *Editor Log* panel (Show Editor Log).
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::io::Write;
+
+const LOG: &str = "\
+2026-01-02T03:04:05.000001Z  INFO fresh::app: started
+2026-01-02T03:04:06.000001Z  WARN fresh::services::lsp::manager: rust-analyzer exited
+2026-01-02T03:04:07.000001Z DEBUG fresh::services::terminal: resized to 80x24
+";
+
+fn show_editor_log(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Show Editor Log").unwrap();
+    harness.wait_for_screen_contains("Show Editor Log").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_editor_log_follows_new_lines() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("fresh-test.log");
+    std::fs::write(&path, LOG).unwrap();
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness.editor_mut().set_editor_log_path(path.clone());
+
+    show_editor_log(&mut harness);
+    harness.assert_screen_contains("fresh-test.log");
+    harness.assert_screen_contains("rust-analyzer exited");
+    harness.assert_screen_contains("resized to 80x24");
+
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .unwrap();
+    writeln!(
+        file,
+        "2026-01-02T03:04:08.000001Z ERROR fresh::services::lsp::manager: restart failed"
+    )
+    .unwrap();
+    harness.wait_for_screen_contains("restart failed").unwrap();
+}
+
+#[test]
+fn test_editor_log_module_filter() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("fresh-test.log");
+    std::fs::write(&path, LOG).unwrap();
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness.editor_mut().set_editor_log_path(path);
+
+    show_editor_log(&mut harness);
+    harness
+        .send_key(KeyCode::Char('m'), KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("lsp").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("module lsp");
+    harness.assert_screen_contains("rust-analyzer exited");
+    harness.assert_screen_not_contains("resized to 80x24");
+
+    // An empty filter shows everything again.
+    harness
+        .send_key(KeyCode::Char('m'), KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    for _ in 0.."lsp".len() {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("resized to 80x24");
+}
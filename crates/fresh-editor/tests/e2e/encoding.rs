@@ -1020,8 +1020,23 @@ fn test_large_utf16_file_navigation() {
 // Status Bar Encoding Indicator Click Tests
 // ============================================================================
 
-/// Test that clicking on the encoding indicator in the status bar opens the encoding selector.
-/// This test will fail if the click handler is not implemented.
+/// Open the Set Encoding prompt (convert the buffer on save) from the
+/// command palette. Clicking the status bar indicator of a clean file
+/// reopens it with an encoding instead.
+fn open_set_encoding_prompt(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Set Encoding").unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Test that clicking on the encoding indicator in the status bar opens the
+/// Reload with Encoding selector for an unmodified file.
 #[test]
 fn test_encoding_indicator_click_opens_selector() {
     let temp_dir = TempDir::new().unwrap();
@@ -1044,11 +1059,34 @@ fn test_encoding_indicator_click_opens_selector() {
     // Click on the encoding indicator
     harness.mouse_click(col, row).unwrap();
 
-    // After clicking, the encoding selector prompt should open
-    // The prompt should show encoding options (UTF-16 is one of the available encodings)
+    // A clean file is reopened with the picked encoding, so the click opens
+    // the reload prompt with the encoding options (UTF-16 is one of them)
+    harness.assert_screen_contains("Reload with encoding:");
     harness.assert_screen_contains("UTF-16");
 }
 
+/// A modified buffer can't be reloaded, so clicking the encoding indicator
+/// falls back to the Set Encoding prompt.
+#[test]
+fn test_encoding_indicator_click_on_modified_buffer_sets_encoding() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "Hello, World! こんにちは").unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.type_text("edit ").unwrap();
+    harness.render().unwrap();
+
+    let (col, row) = harness
+        .find_text_on_screen("UTF-8")
+        .expect("UTF-8 encoding indicator should be visible in status bar");
+    harness.mouse_click(col, row).unwrap();
+
+    harness.assert_screen_contains("Encoding:");
+    harness.assert_screen_not_contains("Reload with encoding:");
+}
+
 /// Test that the encoding indicator is displayed for all files (ASCII and UTF-8).
 #[test]
 fn test_encoding_indicator_always_visible() {
@@ -1065,8 +1103,8 @@ fn test_encoding_indicator_always_visible() {
     harness.assert_screen_contains("ASCII");
 }
 
-/// Test that clicking on UTF-16 encoding indicator opens selector and can change encoding.
-/// This is a complete flow test: load UTF-16 file, change encoding to UTF-8 via click, save.
+/// Test that Set Encoding can convert a UTF-16 file.
+/// This is a complete flow test: load UTF-16 file, change encoding to UTF-8, save.
 #[test]
 fn test_utf16_encoding_indicator_click_and_change() {
     let temp_dir = TempDir::new().unwrap();
@@ -1087,15 +1125,7 @@ fn test_utf16_encoding_indicator_click_and_change() {
     // Verify UTF-16 LE encoding is shown in status bar
     harness.assert_screen_contains("UTF-16 LE");
 
-    // Find the position of "UTF-16 LE" on screen
-    let (col, row) = harness
-        .find_text_on_screen("UTF-16 LE")
-        .expect("UTF-16 LE encoding indicator should be visible in status bar");
-
-    // Click on the encoding indicator to open encoding selector
-    harness.mouse_click(col, row).unwrap();
-
-    // After clicking, the encoding selector prompt should open
+    open_set_encoding_prompt(&mut harness);
     harness.assert_screen_contains("Encoding:");
 
     // Type "UTF-8" to filter and select UTF-8 encoding
@@ -1145,7 +1175,7 @@ fn test_utf16_encoding_indicator_click_and_change() {
     );
 }
 
-/// Test changing encoding from UTF-8 to UTF-16 LE via click on status bar indicator.
+/// Test changing encoding from UTF-8 to UTF-16 LE via Set Encoding.
 /// This tests the opposite direction: UTF-8 → UTF-16 LE.
 #[test]
 fn test_utf8_to_utf16_encoding_change() {
@@ -1164,7 +1194,7 @@ fn test_utf8_to_utf16_encoding_change() {
     harness.assert_screen_contains("UTF-8");
 
     // Find the position of "UTF-8" on screen - should be in the status bar (last lines)
-    let (col, row) = harness
+    let (_, row) = harness
         .find_text_on_screen("UTF-8")
         .expect("UTF-8 encoding indicator should be visible in status bar");
 
@@ -1175,14 +1205,12 @@ fn test_utf8_to_utf16_encoding_change() {
         row
     );
 
-    // Click on the encoding indicator to open encoding selector
-    harness.mouse_click(col, row).unwrap();
+    open_set_encoding_prompt(&mut harness);
 
-    // After clicking, the encoding selector prompt should open
     let screen = harness.screen_to_string();
     assert!(
         screen.contains("Encoding:"),
-        "Encoding selector should open after clicking. Screen:\n{}",
+        "Encoding selector should open. Screen:\n{}",
         screen
     );
 
@@ -1295,11 +1323,8 @@ fn test_windows1250_display_and_selector() {
     harness.open_file(&utf8_file).unwrap();
     harness.render().unwrap();
 
-    // Click on encoding indicator to open selector
-    let (col, row) = harness
-        .find_text_on_screen("ASCII")
-        .expect("Encoding indicator should be visible");
-    harness.mouse_click(col, row).unwrap();
+    harness.assert_screen_contains("ASCII");
+    open_set_encoding_prompt(&mut harness);
     harness.assert_screen_contains("Encoding:");
 
     // Filter for Windows-1250 and verify it shows with description
@@ -1340,10 +1365,8 @@ fn test_windows1250_encoding_conversions() {
     harness.render().unwrap();
 
     // Part 1: UTF-8 → Windows-1250 → save
-    let (col, row) = harness
-        .find_text_on_screen("UTF-8")
-        .expect("UTF-8 indicator should be visible");
-    harness.mouse_click(col, row).unwrap();
+    harness.assert_screen_contains("UTF-8");
+    open_set_encoding_prompt(&mut harness);
     harness
         .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
         .unwrap();
@@ -1376,10 +1399,8 @@ fn test_windows1250_encoding_conversions() {
 
     // File may be detected as Windows-1252 (chardetng can't distinguish 1250 vs 1252)
     // Find encoding indicator and change to UTF-8
-    let (col, row) = harness
-        .find_text_on_screen("Windows")
-        .expect("Windows encoding indicator should be visible");
-    harness.mouse_click(col, row).unwrap();
+    harness.assert_screen_contains("Windows");
+    open_set_encoding_prompt(&mut harness);
     harness
         .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
         .unwrap();
@@ -1536,10 +1557,8 @@ fn test_windows1251_cyrillic_display_and_selector() {
     assert!(screen.contains('м'), "Screen should contain 'м'");
 
     // Verify the encoding selector lists Windows-1251 with the Cyrillic description
-    let (col, row) = harness
-        .find_text_on_screen("Windows-1251")
-        .expect("Encoding indicator should be visible");
-    harness.mouse_click(col, row).unwrap();
+    harness.assert_screen_contains("Windows-1251");
+    open_set_encoding_prompt(&mut harness);
     harness.assert_screen_contains("Encoding:");
 
     harness
@@ -1574,10 +1593,8 @@ fn test_windows1251_encoding_conversions() {
     harness.render().unwrap();
 
     // Change encoding to Windows-1251 and save
-    let (col, row) = harness
-        .find_text_on_screen("UTF-8")
-        .expect("UTF-8 indicator should be visible");
-    harness.mouse_click(col, row).unwrap();
+    harness.assert_screen_contains("UTF-8");
+    open_set_encoding_prompt(&mut harness);
     harness
         .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
         .unwrap();
@@ -1614,10 +1631,8 @@ fn test_windows1251_encoding_conversions() {
     harness.open_file(&file_path).unwrap();
     harness.render().unwrap();
 
-    let (col, row) = harness
-        .find_text_on_screen("Windows-1251")
-        .expect("Windows-1251 indicator should be visible");
-    harness.mouse_click(col, row).unwrap();
+    harness.assert_screen_contains("Windows-1251");
+    open_set_encoding_prompt(&mut harness);
     harness
         .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
         .unwrap();
@@ -1731,14 +1746,14 @@ fn test_all_encodings_load_and_save_as_utf8() {
             .or_else(|| harness.find_text_on_screen("EUC"))
             .or_else(|| harness.find_text_on_screen("ASCII"));
 
-        let (col, row) = encoding_pos.unwrap_or_else(|| {
-            panic!(
-                "Test {} ({}): Could not find encoding indicator",
-                i, tc.desc
-            )
-        });
+        assert!(
+            encoding_pos.is_some(),
+            "Test {} ({}): Could not find encoding indicator",
+            i,
+            tc.desc
+        );
 
-        harness.mouse_click(col, row).unwrap();
+        open_set_encoding_prompt(&mut harness);
         harness
             .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
             .unwrap();
@@ -2388,6 +2403,71 @@ fn test_reload_with_encoding_menu_item() {
     harness.assert_screen_contains("Reload with Encoding...");
 }
 
+/// Run "Save with Encoding..." from the command palette and confirm `encoding`.
+fn save_with_encoding(harness: &mut EditorTestHarness, encoding: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Save with Encoding").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Save with Encoding");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Save with encoding:");
+
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(encoding).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// "Save with Encoding..." converts a UTF-8 buffer and writes it in one step.
+#[test]
+fn test_save_with_encoding_converts_file() {
+    let mut harness = EditorTestHarness::with_temp_project(100, 24).unwrap();
+    let file_path = harness.project_dir().unwrap().join("save_as_latin1.txt");
+    std::fs::write(&file_path, "café\n").unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("UTF-8");
+
+    save_with_encoding(&mut harness, "Latin-1");
+
+    assert_eq!(
+        std::fs::read(&file_path).unwrap(),
+        vec![0x63, 0x61, 0x66, 0xE9, 0x0A]
+    );
+    harness.assert_screen_contains("Latin-1");
+}
+
+/// Characters the target encoding cannot represent block the save, leaving
+/// the file and the buffer's encoding untouched.
+#[test]
+fn test_save_with_encoding_refuses_unencodable_text() {
+    let mut harness = EditorTestHarness::with_temp_project(100, 24).unwrap();
+    let file_path = harness.project_dir().unwrap().join("save_cjk.txt");
+    std::fs::write(&file_path, "Hello 世界\n").unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    save_with_encoding(&mut harness, "Latin-1");
+
+    harness.assert_screen_contains("Cannot save as Latin-1");
+    assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "Hello 世界\n");
+    assert_eq!(
+        harness.editor().active_state().buffer.encoding(),
+        fresh::model::buffer::Encoding::Utf8
+    );
+}
+
 /// Helper: open the "Reload with encoding" prompt for `file_path`, with the
 /// buffer cursor parked at line 4 column 3 so we can detect any stray
 /// editor-cursor placement caused by clicks on the prompt popup.
@@ -0,0 +1,69 @@
+//! Tests for the Evaluate Selection commands.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness.wait_for_screen_contains(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+}
+
+fn select_all(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+}
+
+#[test]
+fn test_evaluate_selection_popup() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("4 KiB").unwrap();
+
+    select_all(&mut harness);
+    run_command(&mut harness, "Evaluate Selection");
+    harness.render().unwrap();
+    harness.assert_screen_contains("4096 B");
+    harness.assert_screen_contains("0x1000");
+    harness.assert_screen_contains("0b1000000000000");
+    assert_eq!(harness.get_buffer_content().unwrap(), "4 KiB");
+}
+
+#[test]
+fn test_evaluate_selection_replace_and_append() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("60 * 60 * 24").unwrap();
+
+    select_all(&mut harness);
+    run_command(&mut harness, "Evaluate Selection: Append Result");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "60 * 60 * 24 = 86400"
+    );
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    select_all(&mut harness);
+    run_command(&mut harness, "Evaluate Selection: Replace");
+    assert_eq!(harness.get_buffer_content().unwrap(), "86400");
+}
+
+#[test]
+fn test_evaluate_selection_error_leaves_buffer_alone() {
+    let mut harness = EditorTestHarness::new(160, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("1 / 0").unwrap();
+
+    select_all(&mut harness);
+    run_command(&mut harness, "Evaluate Selection: Replace");
+    harness.render().unwrap();
+    harness.assert_screen_contains("division by zero");
+    assert_eq!(harness.get_buffer_content().unwrap(), "1 / 0");
+}
@@ -0,0 +1,227 @@
+//! E2E tests for the `hooks` config: commands run on editor events.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, EventHookConfig, HookEvent, LinterConfig};
+use std::path::Path;
+use tempfile::TempDir;
+
+fn harness_with_hooks(dir: &Path, hooks: Vec<EventHookConfig>) -> EditorTestHarness {
+    let config = Config {
+        hooks,
+        ..Default::default()
+    };
+    EditorTestHarness::with_config_and_working_dir(160, 24, config, dir.to_path_buf()).unwrap()
+}
+
+/// A hook that appends `<event>:<file name>` to `events.log`.
+fn logging_hook(event: HookEvent, name: &str) -> EventHookConfig {
+    EventHookConfig {
+        event,
+        command: "sh".to_string(),
+        args: vec![
+            "-c".to_string(),
+            format!("echo {}:$(basename \"$0\") >> events.log", name),
+            "$FILE".to_string(),
+        ],
+        ..Default::default()
+    }
+}
+
+fn read_log(dir: &Path, name: &str) -> String {
+    std::fs::read_to_string(dir.join(name)).unwrap_or_default()
+}
+
+/// A save hook runs only for files matching its globs.
+#[test]
+#[cfg_attr(not(unix), ignore = "Hooks run their commands under sh")]
+fn test_save_hook_runs_for_matching_file() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    std::fs::write(dir.join("notes.txt"), "notes\n").unwrap();
+    std::fs::write(dir.join("schema.graphql"), "type Query\n").unwrap();
+
+    let mut harness = harness_with_hooks(
+        dir,
+        vec![EventHookConfig {
+            file_match: vec!["schema.graphql".to_string()],
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), "echo saved >> hook.log".to_string()],
+            ..Default::default()
+        }],
+    );
+
+    harness.open_file(&dir.join("notes.txt")).unwrap();
+    harness.type_text("more ").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_screen_contains("Saved").unwrap();
+
+    harness.open_file(&dir.join("schema.graphql")).unwrap();
+    harness.type_text("extend ").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|_| read_log(dir, "hook.log") == "saved\n")
+        .unwrap();
+}
+
+/// A hook command that exits non-zero is reported under its title.
+#[test]
+#[cfg_attr(not(unix), ignore = "Hooks run their commands under sh")]
+fn test_failing_hook_is_reported() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    std::fs::write(dir.join("schema.graphql"), "type Query\n").unwrap();
+
+    let mut harness = harness_with_hooks(
+        dir,
+        vec![EventHookConfig {
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), "exit 3".to_string()],
+            title: Some("codegen".to_string()),
+            ..Default::default()
+        }],
+    );
+
+    harness.open_file(&dir.join("schema.graphql")).unwrap();
+    harness.type_text("extend ").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("Job 'codegen' failed (exit code 3)")
+        .unwrap();
+}
+
+/// Open, buffer-switch and idle hooks each run with `$FILE` set to the
+/// buffer they fired for.
+#[test]
+#[cfg_attr(not(unix), ignore = "Hooks run their commands under sh")]
+fn test_open_switch_and_idle_hooks() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    std::fs::write(dir.join("a.txt"), "a\n").unwrap();
+    std::fs::write(dir.join("b.txt"), "b\n").unwrap();
+
+    let mut harness = harness_with_hooks(
+        dir,
+        vec![
+            logging_hook(HookEvent::Open, "open"),
+            logging_hook(HookEvent::BufferSwitch, "switch"),
+            logging_hook(HookEvent::Idle, "idle"),
+        ],
+    );
+
+    harness.open_file(&dir.join("a.txt")).unwrap();
+    harness
+        .wait_until(|_| read_log(dir, "events.log").contains("open:a.txt"))
+        .unwrap();
+
+    harness.open_file(&dir.join("b.txt")).unwrap();
+    harness
+        .wait_until(|_| {
+            let log = read_log(dir, "events.log");
+            log.contains("open:b.txt") && log.contains("switch:b.txt")
+        })
+        .unwrap();
+    assert!(!read_log(dir, "events.log").contains("idle:"));
+
+    harness.type_text("edit ").unwrap();
+    harness
+        .wait_until(|_| read_log(dir, "events.log").contains("idle:b.txt"))
+        .unwrap();
+}
+
+/// A diagnostics hook runs when a linter reports problems for the file,
+/// with `%file` and `%line` filled in.
+#[test]
+#[cfg_attr(not(unix), ignore = "Hooks run their commands under sh")]
+fn test_diagnostics_hook_runs_when_linter_reports() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    let file = dir.join("script.sh");
+    std::fs::write(&file, "echo $1\n").unwrap();
+
+    let mut config = Config {
+        hooks: vec![EventHookConfig {
+            event: HookEvent::Diagnostics,
+            command: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                "echo diag:$(basename \"$0\"):$1 >> events.log".to_string(),
+                "%file".to_string(),
+                "%line".to_string(),
+            ],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    // The presets would run real tools if they happen to be installed.
+    config.linters.clear();
+    config.linters.insert(
+        "fake-lint".to_string(),
+        LinterConfig {
+            languages: vec!["bash".to_string()],
+            command: "sh".to_string(),
+            args: vec![
+                "-c".to_string(),
+                "echo \"$0:1:6: warning: unquoted\"".to_string(),
+                "$FILE".to_string(),
+            ],
+            pattern: r"^[^:]+:(?P<line>\d+):(?P<column>\d+): (?P<severity>\w+): (?P<message>.*)$"
+                .to_string(),
+            only_without_lsp: false,
+            ..Default::default()
+        },
+    );
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(160, 24, config, dir.to_path_buf()).unwrap();
+
+    harness.open_file(&file).unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|_| read_log(dir, "events.log") == "diag:script.sh:1\n")
+        .unwrap();
+}
+
+/// A save hook firing for a file saved in the background by Save All runs
+/// its command but not its action, which would otherwise hit the buffer in
+/// focus.
+#[test]
+#[cfg_attr(not(unix), ignore = "Hooks run their commands under sh")]
+fn test_background_save_hook_skips_action_on_focused_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir = temp_dir.path();
+    std::fs::write(dir.join("a.txt"), "alpha\n").unwrap();
+    std::fs::write(dir.join("b.txt"), "beta\n").unwrap();
+
+    let mut harness = harness_with_hooks(
+        dir,
+        vec![EventHookConfig {
+            file_match: vec!["a.txt".to_string()],
+            command: "sh".to_string(),
+            args: vec!["-c".to_string(), "echo saved >> hook.log".to_string()],
+            action: Some("delete_line".to_string()),
+            ..Default::default()
+        }],
+    );
+
+    harness.open_file(&dir.join("a.txt")).unwrap();
+    harness.type_text("x").unwrap();
+    harness.open_file(&dir.join("b.txt")).unwrap();
+    harness.editor_mut().save_all().unwrap();
+    harness
+        .wait_until(|_| read_log(dir, "hook.log") == "saved\n")
+        .unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "beta\n");
+    assert_eq!(
+        std::fs::read_to_string(dir.join("a.txt")).unwrap(),
+        "xalpha\n"
+    );
+}
@@ -0,0 +1,84 @@
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+const GROW_SHRINK: KeyModifiers = KeyModifiers::CONTROL
+    .union(KeyModifiers::ALT)
+    .union(KeyModifiers::SHIFT);
+
+/// Put the cursor on line `line`, column `column` (both 0-based).
+fn move_to(harness: &mut EditorTestHarness, line: usize, column: usize) {
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..line {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    for _ in 0..column {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+}
+
+fn expand(harness: &mut EditorTestHarness) -> String {
+    harness.send_key(KeyCode::Right, GROW_SHRINK).unwrap();
+    harness.get_selected_text()
+}
+
+fn shrink(harness: &mut EditorTestHarness) -> String {
+    harness.send_key(KeyCode::Left, GROW_SHRINK).unwrap();
+    harness.get_selected_text()
+}
+
+/// With a tree-sitter grammar, each press selects the next enclosing node,
+/// and shrinking retraces the same steps.
+#[test]
+fn test_expand_and_shrink_follow_syntax_tree() {
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text_named(
+            "math.js",
+            "function add(a, b) {\n  return total(a + b);\n}\n",
+        )
+        .unwrap();
+    move_to(&mut harness, 1, 15);
+
+    assert_eq!(expand(&mut harness), "a");
+    assert_eq!(expand(&mut harness), "a + b");
+    assert_eq!(expand(&mut harness), "(a + b)");
+    assert_eq!(expand(&mut harness), "total(a + b)");
+    assert_eq!(expand(&mut harness), "return total(a + b);");
+
+    assert_eq!(shrink(&mut harness), "total(a + b)");
+    assert_eq!(shrink(&mut harness), "(a + b)");
+    assert_eq!(shrink(&mut harness), "a + b");
+    assert_eq!(shrink(&mut harness), "a");
+    shrink(&mut harness);
+    harness.assert_no_selection();
+    assert_eq!(
+        harness.cursor_position(),
+        "function add(a, b) {\n".len() + 15
+    );
+}
+
+/// Without a grammar the text fallback steps out through brackets, and
+/// moving the cursor forgets the history.
+#[test]
+fn test_expand_without_grammar_and_history_reset() {
+    let mut harness = EditorTestHarness::with_temp_project(80, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("note: call(first, second) done\n")
+        .unwrap();
+    move_to(&mut harness, 0, 12);
+
+    assert_eq!(expand(&mut harness), "first");
+    assert_eq!(expand(&mut harness), "first, second");
+    assert_eq!(expand(&mut harness), "(first, second)");
+
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_no_selection();
+    shrink(&mut harness);
+    harness.assert_no_selection();
+}
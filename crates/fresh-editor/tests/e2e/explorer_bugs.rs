@@ -202,8 +202,9 @@ impl FileSystem for FaultInjectingFileSystem {
         mode: u32,
         uid: u32,
         gid: u32,
+        elevate: &[String],
     ) -> io::Result<()> {
-        self.inner.sudo_write(path, data, mode, uid, gid)
+        self.inner.sudo_write(path, data, mode, uid, gid, elevate)
     }
     fn search_file(
         &self,
@@ -26,6 +26,13 @@ fn context_menu_visible(h: &EditorTestHarness) -> bool {
     h.screen_to_string().contains(" Paste ")
 }
 
+// The editor content area has its own context menu (which also lists
+// "Paste"), so tests that right-click there look for an explorer-only entry
+// instead. "New Directory" is shown for a single non-root selection.
+fn explorer_menu_visible(h: &EditorTestHarness) -> bool {
+    h.screen_to_string().contains("New Directory")
+}
+
 // ── open helper ──────────────────────────────────────────────────────────────
 
 fn harness_with_explorer() -> EditorTestHarness {
@@ -73,13 +80,13 @@ fn test_context_menu_shows_all_items() {
 fn test_right_click_outside_closes_menu() {
     let mut h = harness_with_explorer();
     h.mouse_right_click(EXPLORER_COL, EXPLORER_ROW).unwrap();
-    assert!(context_menu_visible(&h));
+    assert!(explorer_menu_visible(&h));
 
     // Right-click in the editor area (right of the explorer)
     h.mouse_right_click(60, 10).unwrap();
 
     assert!(
-        !context_menu_visible(&h),
+        !explorer_menu_visible(&h),
         "Context menu should be closed after right-click outside the explorer"
     );
 }
@@ -124,7 +131,7 @@ fn test_no_menu_when_explorer_closed() {
     h.mouse_right_click(EXPLORER_COL, EXPLORER_ROW).unwrap();
 
     assert!(
-        !context_menu_visible(&h),
+        !explorer_menu_visible(&h),
         "Context menu must not open when file explorer is not visible"
     );
 }
@@ -0,0 +1,116 @@
+//! Tests for local file history: copies kept at each save, listed, diffed
+//! and restored by **File History**.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use std::fs;
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness.wait_for_screen_contains(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+fn save(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+}
+
+fn status(harness: &EditorTestHarness) -> String {
+    harness
+        .editor()
+        .get_status_message()
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// A file saved as "one", then again as "one two".
+fn harness_with_two_saves(temp: &tempfile::TempDir) -> EditorTestHarness {
+    let mut config = Config::default();
+    config.editor.local_history_enabled = true;
+    let path = temp.path().join("notes.txt");
+    fs::write(&path, "").unwrap();
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    harness.open_file(&path).unwrap();
+    harness.render().unwrap();
+
+    harness.type_text("one").unwrap();
+    save(&mut harness);
+    harness.type_text(" two").unwrap();
+    save(&mut harness);
+    assert_eq!(fs::read_to_string(&path).unwrap(), "one two");
+    harness
+}
+
+#[test]
+fn test_file_history_restores_an_earlier_save() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut harness = harness_with_two_saves(&temp);
+    harness.type_text(" three").unwrap();
+
+    run_command(&mut harness, "File History");
+    harness.wait_for_prompt().unwrap();
+    harness.render().unwrap();
+    // Both saves differ from the buffer by the one changed line.
+    harness.assert_screen_contains("+1 -1");
+
+    // Newest first: the second entry is the first save.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "one");
+    assert!(status(&harness).contains("Restored the version saved at"));
+
+    // The restore is a single undo step.
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "one two three");
+}
+
+#[test]
+fn test_file_history_diff_against_the_buffer() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut harness = harness_with_two_saves(&temp);
+
+    run_command(&mut harness, "File History");
+    harness.wait_for_prompt().unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::ALT)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+
+    let diff = harness.get_buffer_content().unwrap();
+    assert!(diff.contains("+++ notes.txt (buffer)"), "diff: {diff:?}");
+    assert!(diff.contains("\n-one\n+one two\n"), "diff: {diff:?}");
+}
+
+#[test]
+fn test_file_history_is_empty_when_disabled() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join("notes.txt");
+    fs::write(&path, "").unwrap();
+    let mut harness = EditorTestHarness::with_config(100, 24, Config::default()).unwrap();
+    harness.open_file(&path).unwrap();
+    harness.type_text("one").unwrap();
+    save(&mut harness);
+
+    run_command(&mut harness, "File History");
+    harness.wait_for_prompt_closed().unwrap();
+    assert!(
+        status(&harness).contains("enable editor.local_history_enabled"),
+        "status: {:?}",
+        status(&harness)
+    );
+}
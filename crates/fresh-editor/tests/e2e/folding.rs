@@ -3,6 +3,7 @@
 use crate::common::fixtures::TestFixture;
 use crate::common::harness::{layout, EditorTestHarness};
 use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
 use lsp_types::FoldingRange;
 
 fn set_fold_range(harness: &mut EditorTestHarness, start_line: usize, end_line: usize) {
@@ -1598,3 +1599,218 @@ fn test_fold_unfold_at_end_of_large_file_gutter_click() {
     harness.assert_screen_contains("b_body_1");
     harness.assert_screen_contains("b_body_3");
 }
+
+// ---------------------------------------------------------------------------
+// Editing around folds
+//
+// Semantics under test:
+// - A selection that spans a collapsed fold covers the hidden text, so copy
+//   and delete act on it.
+// - An edit from the folding view that deletes or detaches the fold header,
+//   or reaches into the hidden lines, expands the fold first.
+// - Edits made in another view of the same buffer move the fold with the
+//   text and keep it collapsed, unless they delete its header.
+// ---------------------------------------------------------------------------
+
+const FOLD_EDIT_CONTENT: &str = "\
+fn alpha() {
+    alpha_body_1
+    alpha_body_2
+}
+fn beta() {
+    beta_body_1
+    beta_body_2
+}
+after_line
+";
+
+/// Open [`FOLD_EDIT_CONTENT`] and collapse the `fn beta()` block.
+fn open_with_beta_folded() -> (TestFixture, EditorTestHarness) {
+    let fixture = TestFixture::new("fold_edit.rs", FOLD_EDIT_CONTENT).unwrap();
+    let mut harness = EditorTestHarness::new(80, 30).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+    fold_beta(&mut harness);
+    harness.assert_screen_not_contains("beta_body_1");
+    (fixture, harness)
+}
+
+fn fold_beta(harness: &mut EditorTestHarness) {
+    set_cursor_line(harness, 4);
+    harness
+        .editor_mut()
+        .active_window_mut()
+        .toggle_fold_at_cursor();
+    harness.render().unwrap();
+    harness.assert_screen_contains("fn beta() { ...");
+}
+
+fn buffer_text(harness: &EditorTestHarness) -> String {
+    harness.editor().active_state().buffer.to_string().unwrap()
+}
+
+fn run_palette_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_selection_across_fold_copies_and_deletes_hidden_text() {
+    let (_fixture, mut harness) = open_with_beta_folded();
+
+    // Shift+Down from the header jumps over the hidden lines; the selection
+    // still covers them.
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness.editor_mut().set_clipboard_for_test(String::new());
+    harness
+        .send_key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+        .unwrap();
+    let copied = harness.editor_mut().clipboard_content_for_test();
+    assert!(
+        copied.contains("fn beta() {")
+            && copied.contains("beta_body_1")
+            && copied.contains("beta_body_2"),
+        "copy should include hidden lines, got {copied:?}"
+    );
+
+    harness
+        .send_key(KeyCode::Delete, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    let text = buffer_text(&harness);
+    assert!(!text.contains("fn beta()") && !text.contains("beta_body"));
+    assert!(text.contains("}\n}\nafter_line"), "got {text:?}");
+    harness.assert_screen_not_contains("{ ...");
+    harness.assert_screen_contains("after_line");
+}
+
+#[test]
+fn test_deleting_fold_header_line_expands_fold() {
+    let (_fixture, mut harness) = open_with_beta_folded();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::DeleteLine);
+    harness.render().unwrap();
+
+    assert!(!buffer_text(&harness).contains("fn beta()"));
+    // The hidden lines are shown again rather than re-attaching to `}`.
+    harness.assert_screen_contains("beta_body_1");
+    harness.assert_screen_contains("beta_body_2");
+    harness.assert_screen_not_contains("{ ...");
+    harness.assert_screen_not_contains("} ...");
+}
+
+#[test]
+fn test_joining_fold_header_with_hidden_line_expands_fold() {
+    let (_fixture, mut harness) = open_with_beta_folded();
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Delete, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("fn beta() {    beta_body_1");
+    harness.assert_screen_contains("beta_body_2");
+    harness.assert_screen_not_contains("{ ...");
+}
+
+#[test]
+fn test_backspace_after_fold_expands_fold() {
+    let (_fixture, mut harness) = open_with_beta_folded();
+
+    // Down from the header lands on the first line after the fold.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    let after_fold = harness.cursor_position();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    // The joined line was hidden text, so the fold opened to show it.
+    assert_eq!(harness.cursor_position(), after_fold - 1);
+    harness.assert_screen_contains("beta_body_1");
+    harness.assert_screen_not_contains("{ ...");
+}
+
+#[test]
+fn test_typing_next_to_fold_keeps_it_collapsed() {
+    let (_fixture, mut harness) = open_with_beta_folded();
+
+    // Text typed at the start of the header and of the line after the fold
+    // stays visible and leaves the fold alone.
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness.type_text("pub ").unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness.type_text("// ").unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("pub fn beta() { ...");
+    harness.assert_screen_not_contains("beta_body_1");
+    let text = buffer_text(&harness);
+    let after_fold_line = text.lines().find(|line| line.starts_with("// ")).unwrap();
+    let (start_row, end_row) = harness.content_area_rows();
+    assert!(
+        (start_row..=end_row).any(|r| harness.get_row_text(r as u16).contains(after_fold_line)),
+        "`{after_fold_line}` should be visible:\n{}",
+        harness.screen_to_string()
+    );
+}
+
+#[test]
+fn test_edits_in_other_view_keep_fold_consistent() {
+    let fixture = TestFixture::new("fold_edit.rs", FOLD_EDIT_CONTENT).unwrap();
+    let mut harness = EditorTestHarness::new(80, 40).unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    run_palette_command(&mut harness, "split horiz");
+    fold_beta(&mut harness);
+    run_palette_command(&mut harness, "prev split");
+
+    // Edit inside the hidden lines from the unfolded view: the folded view
+    // keeps the fold collapsed, so the edit shows up only once on screen.
+    set_cursor_line(&mut harness, 5);
+    harness.type_text("EDITED_").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("fn beta() { ...");
+    assert_eq!(
+        harness.screen_to_string().matches("EDITED_").count(),
+        1,
+        "hidden edit should not leak into the folded view:\n{}",
+        harness.screen_to_string()
+    );
+
+    // Lines inserted above the fold shift it without detaching it.
+    set_cursor_line(&mut harness, 0);
+    harness.type_text("// top\n").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("fn beta() { ...");
+    assert_eq!(harness.screen_to_string().matches("beta_body_2").count(), 1);
+
+    // Deleting the header from the other view expands the fold.
+    set_cursor_line(&mut harness, 5);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::DeleteLine);
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("{ ...");
+    assert_eq!(
+        harness.screen_to_string().matches("beta_body_2").count(),
+        2,
+        "both views should show the formerly hidden lines:\n{}",
+        harness.screen_to_string()
+    );
+}
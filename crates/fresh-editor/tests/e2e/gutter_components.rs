@@ -0,0 +1,106 @@
+//! E2E tests for the configurable gutter: line number modes, the minimum
+//! number width, hiding components and separate sign columns.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, GutterComponent, LineNumberMode};
+
+const CTRL_ALT: KeyModifiers = KeyModifiers::CONTROL.union(KeyModifiers::ALT);
+
+fn open_lines(config: Config) -> EditorTestHarness {
+    let mut harness = EditorTestHarness::with_temp_project_and_config(80, 24, config).unwrap();
+    let file_path = harness.project_dir().unwrap().join("test.txt");
+    std::fs::write(&file_path, "line1\nline2\nline3\nline4\nline5\n").unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+/// Assert the screen row showing `text` starts with `gutter` then `text`.
+fn assert_gutter(harness: &EditorTestHarness, text: &str, gutter: &str) {
+    let (_, row) = harness
+        .find_text_on_screen(text)
+        .unwrap_or_else(|| panic!("{text} not on screen:\n{}", harness.screen_to_string()));
+    let row_text = harness.get_row_text(row);
+    assert!(
+        row_text.starts_with(&format!("{gutter}{text}")),
+        "expected gutter {gutter:?} before {text:?}, got row {row_text:?}"
+    );
+}
+
+fn cursor_to_line3(harness: &mut EditorTestHarness) {
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+}
+
+/// Relative mode counts from the cursor line, which shows 0; hybrid shows
+/// the cursor line's own number instead.
+#[test]
+fn test_relative_and_hybrid_line_numbers() {
+    let mut config = Config::default();
+    config.editor.line_number_mode = LineNumberMode::Relative;
+    let mut harness = open_lines(config);
+    cursor_to_line3(&mut harness);
+
+    assert_gutter(&harness, "line1", "  2 │ ");
+    assert_gutter(&harness, "line3", "  0 │ ");
+    assert_gutter(&harness, "line5", "  2 │ ");
+
+    let mut config = Config::default();
+    config.editor.line_number_mode = LineNumberMode::Hybrid;
+    let mut harness = open_lines(config);
+    cursor_to_line3(&mut harness);
+
+    assert_gutter(&harness, "line2", "  1 │ ");
+    assert_gutter(&harness, "line3", "  3 │ ");
+}
+
+/// `line_number_min_width` pads short buffers' numbers; leaving
+/// `line_numbers` out of the components drops the number column.
+#[test]
+fn test_min_width_and_hidden_line_numbers() {
+    let mut config = Config::default();
+    config.editor.line_number_min_width = 4;
+    let harness = open_lines(config);
+    assert_gutter(&harness, "line1", "    1 │ ");
+
+    let mut config = Config::default();
+    config.editor.gutter_components = vec![GutterComponent::Diagnostics, GutterComponent::Git];
+    let harness = open_lines(config);
+    assert_gutter(&harness, "line1", "  │ ");
+}
+
+/// With separate sign columns, a bookmark and an unsaved-change marker on
+/// the same line both show, each in its own column.
+#[test]
+fn test_separate_sign_columns() {
+    let mut config = Config::default();
+    config.editor.gutter_components = vec![
+        GutterComponent::Bookmarks,
+        GutterComponent::LineNumbers,
+        GutterComponent::Git,
+    ];
+    config.editor.gutter_shared_signs = false;
+    let mut harness = open_lines(config);
+
+    harness.send_key(KeyCode::Char('k'), CTRL_ALT).unwrap();
+    harness.type_text("X").unwrap();
+    harness.render().unwrap();
+
+    assert_gutter(&harness, "Xline1", "◆ 1│ │ ");
+    assert_gutter(&harness, "line2", "  2  │ ");
+}
+
+/// Shared signs (the default) keep one column, where the bookmark wins
+/// over the change marker.
+#[test]
+fn test_shared_sign_column_prefers_earlier_component() {
+    let mut harness = open_lines(Config::default());
+
+    harness.send_key(KeyCode::Char('k'), CTRL_ALT).unwrap();
+    harness.type_text("X").unwrap();
+    harness.render().unwrap();
+
+    assert_gutter(&harness, "Xline1", "◆ 1 │ ");
+}
@@ -0,0 +1,99 @@
+//! E2E tests for input-method (IME) composition
+//!
+//! Tests that:
+//! - Pre-edit text is drawn underlined at the caret without touching the buffer
+//! - The caret sits at the end of the pre-edit text
+//! - Committing inserts the text as one undo step; an empty pre-edit cancels
+//! - Prompts show the pre-edit at their caret and receive the commit
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::style::Modifier;
+
+fn harness_at_end_of(content: &str) -> EditorTestHarness {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.load_buffer_from_text(content).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+#[test]
+fn test_preedit_is_drawn_at_caret_without_editing_buffer() {
+    let mut harness = harness_at_end_of("hello ");
+    let (caret_x, caret_y) = harness.screen_cursor_position();
+
+    harness.editor_mut().set_ime_preedit("にほん");
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("hello ");
+    assert_eq!(harness.get_cell(caret_x, caret_y).as_deref(), Some("に"));
+    assert_eq!(
+        harness.get_cell(caret_x + 4, caret_y).as_deref(),
+        Some("ん")
+    );
+    let style = harness.get_cell_style(caret_x, caret_y).unwrap();
+    assert!(style.add_modifier.contains(Modifier::UNDERLINED));
+    // Three double-width characters: the caret moves six cells.
+    assert_eq!(harness.screen_cursor_position(), (caret_x + 6, caret_y));
+}
+
+#[test]
+fn test_commit_types_text_and_clears_preedit() {
+    let mut harness = harness_at_end_of("hello ");
+
+    harness.editor_mut().set_ime_preedit("にほん");
+    harness.render().unwrap();
+    harness.editor_mut().commit_ime_text("日本");
+    harness.render().unwrap();
+
+    harness.assert_buffer_content("hello 日本");
+    harness.assert_screen_contains("日");
+    harness.assert_screen_not_contains("に");
+    assert_eq!(harness.editor().ime_preedit(), None);
+
+    // The whole commit is one undo step.
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("hello ");
+}
+
+#[test]
+fn test_empty_preedit_cancels_composition() {
+    let mut harness = harness_at_end_of("hello ");
+    let caret = harness.screen_cursor_position();
+
+    harness.editor_mut().set_ime_preedit("ni");
+    harness.render().unwrap();
+    harness.editor_mut().set_ime_preedit("");
+    harness.render().unwrap();
+
+    harness.assert_screen_not_contains("hello ni");
+    harness.assert_buffer_content("hello ");
+    assert_eq!(harness.screen_cursor_position(), caret);
+}
+
+#[test]
+fn test_preedit_is_drawn_in_prompt() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    let (caret_x, caret_y) = harness.screen_cursor_position();
+
+    harness.editor_mut().set_ime_preedit("にほん");
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_cell(caret_x, caret_y).as_deref(), Some("に"));
+    let style = harness.get_cell_style(caret_x, caret_y).unwrap();
+    assert!(style.add_modifier.contains(Modifier::UNDERLINED));
+    assert_eq!(harness.screen_cursor_position(), (caret_x + 6, caret_y));
+
+    // The commit is typed into the palette query, not the buffer behind it.
+    harness.editor_mut().commit_ime_text("日本");
+    harness.render().unwrap();
+    harness.assert_buffer_content("");
+    assert_eq!(harness.editor().prompt_input().as_deref(), Some(">日本"));
+}
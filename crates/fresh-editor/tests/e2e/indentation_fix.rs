@@ -0,0 +1,66 @@
+//! Tests for the mixed-indentation notice and Convert Indentation commands.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn open_fixture(harness: &mut EditorTestHarness, content: &str) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("mixed.rs");
+    std::fs::write(&path, content).unwrap();
+    harness.open_file(&path).unwrap();
+    harness.render().unwrap();
+    dir
+}
+
+#[test]
+fn test_mixed_indentation_offers_conversion() {
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    let _dir = open_fixture(
+        &mut harness,
+        "fn main() {\n\tlet a = 1;\n    let b = \"x\n\tkeep\";\n}\n",
+    );
+
+    harness
+        .wait_for_screen_contains("Mixed indentation: 1 line(s) use tabs, 1 use spaces.")
+        .unwrap();
+    harness.assert_screen_contains("Convert To Spaces (4)");
+    harness.assert_screen_contains("L2 →let a =… ⇒ ····let a =…");
+
+    // The notice doesn't take the keyboard until focused.
+    harness
+        .send_key(KeyCode::Char('t'), KeyModifiers::ALT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    // The tab inside the string literal is left alone.
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "fn main() {\n    let a = 1;\n    let b = \"x\n\tkeep\";\n}\n"
+    );
+    harness.assert_screen_not_contains("Mixed indentation");
+}
+
+#[test]
+fn test_convert_indentation_to_tabs_command() {
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    let _dir = open_fixture(&mut harness, "a\n    b\n      c\n");
+    harness.assert_screen_not_contains("Indentation");
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Convert Indentation to Tabs").unwrap();
+    harness
+        .wait_for_screen_contains("Convert Indentation to Tabs")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "a\n\tb\n\t  c\n");
+}
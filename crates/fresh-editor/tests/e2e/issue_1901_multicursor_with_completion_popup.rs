@@ -38,6 +38,7 @@ fn show_completion_popup(harness: &mut EditorTestHarness) -> anyhow::Result<()>
                     detail: None,
                     icon: Some("v".to_string()),
                     data: Some("value".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -145,6 +146,7 @@ fn show_hello_completion_popup(harness: &mut EditorTestHarness) -> anyhow::Resul
                     detail: None,
                     icon: Some("v".to_string()),
                     data: Some("hello".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -103,6 +103,7 @@ fn custom_noop_keybinding_disables_ctrl_q() {
         action: "noop".to_string(),
         args: HashMap::new(),
         when: None,
+        release: false,
     });
 
     let mut harness = EditorTestHarness::with_temp_project_and_config(120, 40, config).unwrap();
@@ -130,6 +131,7 @@ fn custom_none_keybinding_disables_ctrl_q() {
         action: "none".to_string(),
         args: HashMap::new(),
         when: None,
+        release: false,
     });
 
     let mut harness = EditorTestHarness::with_temp_project_and_config(120, 40, config).unwrap();
@@ -162,6 +164,7 @@ fn keybinding_editor_delete_of_default_ctrl_q_disables_quit() {
         action: "noop".to_string(),
         args: HashMap::new(),
         when: Some("normal".to_string()),
+        release: false,
     });
 
     let mut harness = EditorTestHarness::with_temp_project_and_config(120, 40, config).unwrap();
@@ -73,7 +73,8 @@ fn row_with(harness: &EditorTestHarness, needle: &str) -> String {
 
 /// From a freshly opened Settings panel, focus the right-hand list and walk
 /// down to the "Languages" map, then open the (already-focused) `html` entry's
-/// Edit Value dialog. Leaves the dialog open with focus on its first field.
+/// Edit Value dialog. Leaves the dialog open with focus on its first
+/// checkbox field, Auto Close (just past the Abbreviations map).
 fn open_html_language_dialog(harness: &mut EditorTestHarness) {
     harness.open_settings().unwrap();
     harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
@@ -101,6 +102,9 @@ fn open_html_language_dialog(harness: &mut EditorTestHarness) {
         harness.screen_to_string().contains("Auto Surround"),
         "language entry dialog should show the Auto Surround field"
     );
+    // Step past the Abbreviations map's "[+] Add new" row.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
 }
 
 /// Issue #2345 (1): editing a language's settings must not silently disable
@@ -186,8 +190,8 @@ fn issue_2345_inherited_auto_surround_shows_neutral_chip() {
 /// that one field to inherited, without touching its siblings.
 #[test]
 fn issue_2345_per_field_inherit_button_reverts_single_field() {
-    // 32 rows: the dialog lists fields alphabetically and the assertion below
-    // needs the "Line Wrap" row on screen ("Indentation Guide" sits above it).
+    // 32 rows: the dialog lists fields alphabetically, so the "Line Wrap" row
+    // checked below starts under the fold and is scrolled into view.
     let mut harness = EditorTestHarness::with_config(120, 32, html_only_config()).unwrap();
     harness.render().unwrap();
 
@@ -213,12 +217,27 @@ fn issue_2345_per_field_inherit_button_reverts_single_field() {
         "an overriding field shows its value plus a clickable [Inherit] button; row: {:?}",
         row
     );
-    // A sibling the user never touched stays inherited.
+    // A sibling the user never touched stays inherited. Walk focus down until
+    // its row scrolls into view, then back up to the overriding field.
+    for _ in 0..30 {
+        if harness.screen_to_string().contains("Line Wrap") {
+            break;
+        }
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        harness.render().unwrap();
+    }
     assert!(
         row_with(&harness, "Line Wrap").contains("(Inherited)"),
         "untouched sibling must stay inherited; row: {:?}",
         row_with(&harness, "Line Wrap")
     );
+    for _ in 0..30 {
+        if harness.screen_to_string().contains("[Inherit]") {
+            break;
+        }
+        harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+        harness.render().unwrap();
+    }
 
     // Click the [Inherit] button — only Auto Surround is overriding, so it's the
     // only [Inherit] on screen.
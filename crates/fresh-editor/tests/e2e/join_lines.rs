@@ -0,0 +1,95 @@
+//! Tests for Join Lines and Split Line
+//!
+//! Tests that:
+//! - Joining collapses indentation into one space and drops comment markers
+//! - Every cursor joins or splits its own line, undone as one step
+//! - Splitting keeps the indentation and the cursor on the first half
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+/// Helper to run a command from the command palette
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(command_name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn open(content: &str, name: &str) -> (TempDir, EditorTestHarness) {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join(name);
+    std::fs::write(&file_path, content).unwrap();
+    let mut harness =
+        EditorTestHarness::create(80, 24, HarnessOptions::new().with_config(Config::default()))
+            .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    (temp_dir, harness)
+}
+
+/// Joining a selection merges its lines, dropping the comment markers of
+/// the continuation lines.
+#[test]
+fn test_join_lines_selection_drops_comment_markers() {
+    let (_dir, mut harness) = open("    // one\n    // two\n    // three\nfn a() {}\n", "a.rs");
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+    run_command(&mut harness, "Join Lines");
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "    // one two three\nfn a() {}\n"
+    );
+}
+
+/// Cursors on adjacent lines join one run of lines, and one undo restores
+/// the original text.
+#[test]
+fn test_join_lines_multi_cursor_undo() {
+    let original = "one\n  two\nthree\n  four\nfive\n";
+    let (_dir, mut harness) = open(original, "a.txt");
+    harness.editor_mut().add_cursor_below();
+    harness.editor_mut().add_cursor_below();
+    run_command(&mut harness, "Join Lines");
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "one two three four\nfive\n"
+    );
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), original);
+}
+
+/// Split Line moves the rest of the line down at the same indentation and
+/// leaves the cursor where it was.
+#[test]
+fn test_split_line_keeps_indent_and_cursor() {
+    let (_dir, mut harness) = open("    call(a, b);\n", "a.rs");
+    for _ in 0.."    call(a,".len() {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    run_command(&mut harness, "Split Line");
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "    call(a,\n    b);\n"
+    );
+    assert_eq!(harness.cursor_position(), "    call(a,".len());
+}
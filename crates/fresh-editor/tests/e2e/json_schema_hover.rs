@@ -0,0 +1,48 @@
+//! E2E tests for hover documentation from JSON/YAML schemas.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+/// Hovering a key shows its description from the document's schema, with no
+/// language server involved.
+#[test]
+fn test_hover_shows_schema_description() {
+    let temp_dir = TempDir::new().unwrap();
+    std::fs::write(
+        temp_dir.path().join("schema.json"),
+        r#"{"properties": {"port": {"type": "integer", "description": "Port the server listens on"}}}"#,
+    )
+    .unwrap();
+    let file_path = temp_dir.path().join("app.json");
+    std::fs::write(
+        &file_path,
+        "{\"$schema\": \"./schema.json\",\n  \"port\": 80\n}\n",
+    )
+    .unwrap();
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        24,
+        Config::default(),
+        temp_dir.path().to_path_buf(),
+    )
+    .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // Onto the `port` key.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Char('k'), KeyModifiers::ALT)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("Port the server listens on")
+        .unwrap();
+}
@@ -0,0 +1,55 @@
+//! Keybindings with `release = true`, which fire when the key comes back up.
+//! Only terminals speaking the kitty keyboard protocol report releases.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use fresh::config::{Config, Keybinding};
+use std::collections::HashMap;
+
+const CONTENT: &str = "one\ntwo\nthree\n";
+
+fn ctrl_shift_j(kind: KeyEventKind) -> Event {
+    Event::Key(KeyEvent::new_with_kind(
+        KeyCode::Char('J'),
+        KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        kind,
+    ))
+}
+
+#[test]
+fn release_binding_fires_on_release_only() {
+    let mut config = Config::default();
+    config.keybindings.push(Keybinding {
+        key: "j".to_string(),
+        modifiers: vec!["ctrl".to_string(), "shift".to_string()],
+        keys: vec![],
+        action: "move_document_end".to_string(),
+        args: HashMap::new(),
+        when: None,
+        release: true,
+    });
+    let mut harness = EditorTestHarness::with_temp_project_and_config(100, 30, config).unwrap();
+    let path = harness.project_dir().unwrap().join("notes.txt");
+    std::fs::write(&path, CONTENT).unwrap();
+    harness.open_file(&path).unwrap();
+    harness.render().unwrap();
+
+    for kind in [KeyEventKind::Press, KeyEventKind::Repeat] {
+        harness
+            .editor_mut()
+            .handle_input_event(ctrl_shift_j(kind))
+            .unwrap();
+        assert_eq!(
+            harness.cursor_position(),
+            0,
+            "{:?} must not fire a release binding",
+            kind
+        );
+    }
+
+    harness
+        .editor_mut()
+        .handle_input_event(ctrl_shift_j(KeyEventKind::Release))
+        .unwrap();
+    assert_eq!(harness.cursor_position(), CONTENT.len());
+}
@@ -534,6 +534,227 @@ fn test_add_new_binding() {
     harness.assert_screen_contains("modified");
 }
 
+/// Record Ctrl+S for `quit` in the add dialog (clashing with the keymap's
+/// save) and move focus to the Save button.
+fn add_conflicting_ctrl_s_binding(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    for ch in "quit".chars() {
+        harness
+            .send_key(KeyCode::Char(ch), KeyModifiers::NONE)
+            .unwrap();
+    }
+    // Accept autocomplete, then Tab past the context to the buttons
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+}
+
+/// Test resolving a conflict from the edit dialog by unbinding the other
+/// binding, then checking the change in the diff against the keymap
+#[test]
+fn test_unbind_conflict_from_edit_dialog() {
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+    open_keybinding_editor(&mut harness);
+
+    add_conflicting_ctrl_s_binding(&mut harness);
+    harness.assert_screen_contains("Conflicts:");
+    harness.assert_screen_contains("Unbind Others");
+    harness.assert_screen_contains("Rebind Other");
+
+    // Save -> Cancel -> Unbind Others
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Add Keybinding");
+    harness.assert_screen_contains("conflicting bindings unbound");
+
+    // The diff lists Ctrl+S as changed from the keymap's save
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Changes vs. default keymap");
+    let screen = harness.screen_to_string();
+    assert!(
+        screen
+            .lines()
+            .any(|l| l.contains("~ Ctrl+S") && l.contains("was: Save")),
+        "Diff should show Ctrl+S as changed from Save.\nScreen:\n{}",
+        screen,
+    );
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Changes vs. default keymap");
+
+    // Save and close: Ctrl+S now quits instead of saving
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Keybinding Editor");
+    let kb = harness.editor().keybindings_for_tests();
+    let event = crossterm::event::KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL);
+    let resolved = kb
+        .read()
+        .unwrap()
+        .resolve(&event, fresh::input::keybindings::KeyContext::Normal);
+    assert_eq!(resolved, fresh::input::keybindings::Action::Quit);
+}
+
+/// Test that Rebind Other saves the binding and reopens the dialog for the
+/// displaced action, waiting for its new key
+#[test]
+fn test_rebind_conflict_from_edit_dialog() {
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+    open_keybinding_editor(&mut harness);
+
+    add_conflicting_ctrl_s_binding(&mut harness);
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Add Keybinding");
+    harness.assert_screen_contains("(press any key...)");
+
+    // Move save to Ctrl+Alt+S
+    harness
+        .send_key(
+            KeyCode::Char('s'),
+            KeyModifiers::CONTROL | KeyModifiers::ALT,
+        )
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Ctrl+Alt+S");
+    harness.assert_screen_not_contains("Conflicts:");
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Add Keybinding");
+
+    harness
+        .send_key(KeyCode::Char('v'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    let screen = harness.screen_to_string();
+    assert!(
+        screen
+            .lines()
+            .any(|l| l.contains("+ Ctrl+Alt+S") && l.contains("Save")),
+        "Diff should list the new Ctrl+Alt+S binding.\nScreen:\n{}",
+        screen,
+    );
+}
+
+/// Test exporting the custom bindings to a file and importing them again
+#[test]
+fn test_export_and_import_keymap() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("shared-keymap.json");
+    let path_str = path.display().to_string();
+
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+    open_keybinding_editor(&mut harness);
+    add_conflicting_ctrl_s_binding(&mut harness);
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    // Export: replace the suggested path with ours
+    harness
+        .send_key(KeyCode::Char('x'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Export Keymap");
+    harness.assert_screen_contains("keymap.json");
+    for _ in 0..512 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text(&path_str).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Export Keymap");
+    harness.assert_screen_contains("Exported 1 custom bindings");
+
+    let exported: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    assert_eq!(exported["inherits"], "default");
+    assert_eq!(exported["bindings"][0]["action"], "quit");
+
+    // Discard the change, then import it into a fresh editor session
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Keybinding Editor");
+
+    open_keybinding_editor(&mut harness);
+    harness.assert_screen_not_contains("modified");
+    harness
+        .send_key(KeyCode::Char('i'), KeyModifiers::NONE)
+        .unwrap();
+    for _ in 0..512 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text(&path_str).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Imported 1 bindings");
+    harness.assert_screen_contains("modified");
+
+    // A missing file keeps the dialog open with the error
+    harness
+        .send_key(KeyCode::Char('i'), KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("-missing").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Import Keymap");
+    harness.assert_screen_contains("\u{2717}");
+}
+
 // ========================
 // Delete binding
 // ========================
@@ -152,8 +152,9 @@ impl FileSystem for NotOwnerFileSystem {
         mode: u32,
         uid: u32,
         gid: u32,
+        elevate: &[String],
     ) -> io::Result<()> {
-        self.inner.sudo_write(path, data, mode, uid, gid)
+        self.inner.sudo_write(path, data, mode, uid, gid, elevate)
     }
 
     fn search_file(
@@ -507,8 +508,9 @@ impl FileSystem for CrashDuringStreamFileSystem {
         mode: u32,
         uid: u32,
         gid: u32,
+        elevate: &[String],
     ) -> io::Result<()> {
-        self.inner.sudo_write(path, data, mode, uid, gid)
+        self.inner.sudo_write(path, data, mode, uid, gid, elevate)
     }
 
     fn search_file(
@@ -0,0 +1,190 @@
+//! E2E tests for line bookmarks: toggling with a gutter marker, stepping
+//! between them, labelling, the bookmarks panel, and session persistence.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use tempfile::TempDir;
+
+const CTRL_ALT: KeyModifiers = KeyModifiers::CONTROL.union(KeyModifiers::ALT);
+
+/// Config binding F5 to `label_bookmark` and F6 to `show_bookmarks`, which
+/// have no default keys.
+fn bookmark_config() -> Config {
+    let mut config = Config::default();
+    for (key, action) in [("F5", "label_bookmark"), ("F6", "show_bookmarks")] {
+        config.keybindings.push(fresh::config::Keybinding {
+            key: key.to_string(),
+            modifiers: vec![],
+            keys: vec![],
+            action: action.to_string(),
+            args: std::collections::HashMap::new(),
+            when: None,
+            release: false,
+        });
+    }
+    config
+}
+
+fn project_with_file(content: &str) -> (TempDir, std::path::PathBuf, std::path::PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+    let file = project_dir.join("notes.txt");
+    std::fs::write(&file, content).unwrap();
+    (temp_dir, project_dir, file)
+}
+
+fn harness_in(project_dir: &std::path::Path) -> EditorTestHarness {
+    EditorTestHarness::create(
+        80,
+        24,
+        HarnessOptions::new()
+            .with_config(bookmark_config())
+            .with_working_dir(project_dir.to_path_buf()),
+    )
+    .unwrap()
+}
+
+fn cursor_line(harness: &EditorTestHarness) -> usize {
+    let pos = harness.cursor_position();
+    harness.editor().active_state().buffer.get_line_number(pos)
+}
+
+fn goto_line(harness: &mut EditorTestHarness, line: usize) {
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..line {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+}
+
+/// Toggling marks the line in the gutter; next/previous step between marked
+/// lines and wrap around; toggling again removes the mark.
+#[test]
+fn test_toggle_and_step_between_bookmarks() {
+    let (_temp, project_dir, file) = project_with_file("zero\none\ntwo\nthree\nfour\n");
+    let mut harness = harness_in(&project_dir);
+    harness.open_file(&file).unwrap();
+
+    goto_line(&mut harness, 1);
+    harness.send_key(KeyCode::Char('k'), CTRL_ALT).unwrap();
+    goto_line(&mut harness, 3);
+    harness.send_key(KeyCode::Char('k'), CTRL_ALT).unwrap();
+    harness.render().unwrap();
+
+    let screen = harness.screen_to_string();
+    let marked: Vec<&str> = screen.lines().filter(|l| l.contains('◆')).collect();
+    assert_eq!(marked.len(), 2, "two gutter markers expected:\n{screen}");
+    assert!(marked[0].contains("one") && marked[1].contains("three"));
+
+    goto_line(&mut harness, 0);
+    harness.send_key(KeyCode::Char('l'), CTRL_ALT).unwrap();
+    assert_eq!(cursor_line(&harness), 1);
+    harness.send_key(KeyCode::Char('l'), CTRL_ALT).unwrap();
+    assert_eq!(cursor_line(&harness), 3);
+    harness.send_key(KeyCode::Char('l'), CTRL_ALT).unwrap();
+    assert_eq!(cursor_line(&harness), 1, "next wraps to the first bookmark");
+    harness.send_key(KeyCode::Char('j'), CTRL_ALT).unwrap();
+    assert_eq!(
+        cursor_line(&harness),
+        3,
+        "previous wraps to the last bookmark"
+    );
+
+    // Toggling on a bookmarked line removes it.
+    harness.send_key(KeyCode::Char('k'), CTRL_ALT).unwrap();
+    harness.render().unwrap();
+    let screen = harness.screen_to_string();
+    assert_eq!(screen.lines().filter(|l| l.contains('◆')).count(), 1);
+}
+
+/// A bookmark stays on its line when text is inserted above it.
+#[test]
+fn test_bookmark_follows_edits() {
+    let (_temp, project_dir, file) = project_with_file("alpha\nbeta\ngamma\n");
+    let mut harness = harness_in(&project_dir);
+    harness.open_file(&file).unwrap();
+
+    goto_line(&mut harness, 2);
+    harness.send_key(KeyCode::Char('k'), CTRL_ALT).unwrap();
+    goto_line(&mut harness, 0);
+    harness.type_text("inserted\n").unwrap();
+
+    harness.send_key(KeyCode::Char('l'), CTRL_ALT).unwrap();
+    assert_eq!(cursor_line(&harness), 3);
+    harness.render().unwrap();
+    let screen = harness.screen_to_string();
+    let marked: Vec<&str> = screen.lines().filter(|l| l.contains('◆')).collect();
+    assert_eq!(marked.len(), 1);
+    assert!(
+        marked[0].contains("gamma"),
+        "marker should stay on gamma:\n{screen}"
+    );
+}
+
+/// The panel lists bookmarks with their labels; Enter jumps to the pick.
+#[test]
+fn test_bookmarks_panel_lists_labels_and_jumps() {
+    let (_temp, project_dir, file) = project_with_file("first\nsecond\nthird\nfourth\n");
+    let mut harness = harness_in(&project_dir);
+    harness.open_file(&file).unwrap();
+
+    // Labelling an unmarked line bookmarks it.
+    goto_line(&mut harness, 2);
+    harness.send_key(KeyCode::F(5), KeyModifiers::NONE).unwrap();
+    harness.type_text("entry point").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    goto_line(&mut harness, 0);
+    harness.send_key(KeyCode::Char('k'), CTRL_ALT).unwrap();
+
+    harness.send_key(KeyCode::F(6), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    let screen = harness.screen_to_string();
+    assert!(screen.contains("Bookmarks"), "panel title:\n{screen}");
+    assert!(screen.contains("notes.txt:1"), "first bookmark:\n{screen}");
+    assert!(
+        screen.contains("notes.txt:3") && screen.contains("entry point — third"),
+        "labelled bookmark:\n{screen}"
+    );
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(cursor_line(&harness), 2);
+    assert!(!harness.editor().active_state().popups.is_visible());
+}
+
+/// Bookmarks and their labels survive a session save/restore.
+#[test]
+fn test_bookmarks_persist_in_session() {
+    let (_temp, project_dir, file) = project_with_file("a\nb\nc\nd\n");
+
+    {
+        let mut harness = harness_in(&project_dir);
+        harness.open_file(&file).unwrap();
+        goto_line(&mut harness, 2);
+        harness.send_key(KeyCode::F(5), KeyModifiers::NONE).unwrap();
+        harness.type_text("todo").unwrap();
+        harness
+            .send_key(KeyCode::Enter, KeyModifiers::NONE)
+            .unwrap();
+        harness.editor_mut().save_workspace().unwrap();
+    }
+
+    let mut harness = harness_in(&project_dir);
+    harness.editor_mut().try_restore_workspace().unwrap();
+    harness.render().unwrap();
+
+    goto_line(&mut harness, 0);
+    harness.send_key(KeyCode::Char('l'), CTRL_ALT).unwrap();
+    assert_eq!(cursor_line(&harness), 2);
+
+    harness.send_key(KeyCode::F(6), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("todo — c");
+}
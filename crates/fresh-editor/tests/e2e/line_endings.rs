@@ -0,0 +1,96 @@
+//! E2E tests for mixed line-ending detection and the Convert Line Endings
+//! commands.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+const MIXED: &[u8] = b"one\r\ntwo\r\nthree\nfour\r\n";
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(name).unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Opening a file with mixed endings warns and marks the dominant ending
+/// in the status bar.
+#[test]
+fn test_mixed_line_endings_are_reported() {
+    let mut harness = EditorTestHarness::with_temp_project(180, 24).unwrap();
+    let file_path = harness.project_dir().unwrap().join("mixed.txt");
+    std::fs::write(&file_path, MIXED).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("mixed line endings");
+    harness.assert_screen_contains("CRLF (mixed)");
+}
+
+/// Converting to LF rewrites the buffer as one edit: save writes pure LF,
+/// and a single undo brings the original bytes back.
+#[test]
+fn test_convert_line_endings_to_lf_is_one_undoable_edit() {
+    let mut harness = EditorTestHarness::with_temp_project(100, 24).unwrap();
+    let file_path = harness.project_dir().unwrap().join("mixed.txt");
+    std::fs::write(&file_path, MIXED).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Convert Line Endings to LF");
+    harness.assert_screen_contains("Converted 3 line endings to LF");
+    harness.assert_screen_not_contains("(mixed)");
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        std::fs::read(&file_path).unwrap(),
+        b"one\ntwo\nthree\nfour\n"
+    );
+
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(std::fs::read(&file_path).unwrap(), MIXED);
+}
+
+/// Converting to CRLF adds the missing carriage returns, and text typed
+/// afterwards uses CRLF too.
+#[test]
+fn test_convert_line_endings_to_crlf() {
+    let mut harness = EditorTestHarness::with_temp_project(100, 24).unwrap();
+    let file_path = harness.project_dir().unwrap().join("unix.txt");
+    std::fs::write(&file_path, "one\ntwo\n").unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Convert Line Endings to CRLF");
+    harness.assert_screen_contains("CRLF");
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(std::fs::read(&file_path).unwrap(), b"one\r\ntwo\r\n");
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    assert_eq!(std::fs::read(&file_path).unwrap(), b"\r\none\r\ntwo\r\n");
+}
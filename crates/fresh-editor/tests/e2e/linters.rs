@@ -0,0 +1,185 @@
+//! E2E tests for command-line linters reported as diagnostics.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, LinterConfig};
+use std::path::Path;
+use tempfile::TempDir;
+
+/// Write an executable shell script standing in for a real linter.
+fn fake_linter(dir: &Path, body: &str) -> String {
+    let path = dir.join("fake-lint");
+    std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+    path.display().to_string()
+}
+
+fn config_with_linter(linter: LinterConfig) -> Config {
+    let mut config = Config::default();
+    // The presets would run real tools if they happen to be installed.
+    config.linters.clear();
+    config.linters.insert("fake-lint".to_string(), linter);
+    config
+}
+
+fn linter_messages(harness: &EditorTestHarness) -> Vec<String> {
+    harness
+        .editor()
+        .get_stored_diagnostics()
+        .values()
+        .flatten()
+        .filter(|d| d.source.as_deref() == Some("fake-lint"))
+        .map(|d| d.message.clone())
+        .collect()
+}
+
+/// A file-based linter runs on save and its matches become diagnostics.
+#[test]
+#[cfg_attr(not(unix), ignore = "Linters require Unix-like environment")]
+fn test_linter_runs_on_save() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("script.sh");
+    std::fs::write(&file_path, "echo $1\n").unwrap();
+    let command = fake_linter(
+        temp_dir.path(),
+        r#"grep -q 'echo \$1' "$1" && echo "$1:1:6: warning: Double quote to prevent globbing. [SC2086]""#,
+    );
+
+    let config = config_with_linter(LinterConfig {
+        languages: vec!["bash".to_string()],
+        command,
+        args: vec!["$FILE".to_string()],
+        pattern: r"^[^:]+:(?P<line>\d+):(?P<column>\d+): (?P<severity>\w+): (?P<message>.*?)(?: \[(?P<code>\w+)\])?$".to_string(),
+        only_without_lsp: false,
+        ..Default::default()
+    });
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        24,
+        config,
+        temp_dir.path().to_path_buf(),
+    )
+    .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    assert!(linter_messages(&harness).is_empty());
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| !linter_messages(h).is_empty())
+        .unwrap();
+    assert_eq!(
+        linter_messages(&harness),
+        vec!["Double quote to prevent globbing.".to_string()]
+    );
+}
+
+/// A stdin linter with `run_on_idle` lints unsaved edits, and clears its
+/// diagnostics once the problem is gone.
+#[test]
+#[cfg_attr(not(unix), ignore = "Linters require Unix-like environment")]
+fn test_idle_linter_checks_unsaved_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("script.sh");
+    std::fs::write(&file_path, "").unwrap();
+    let command = fake_linter(
+        temp_dir.path(),
+        "grep -n TODO | sed 's/:.*/: found a TODO/'",
+    );
+
+    let config = config_with_linter(LinterConfig {
+        languages: vec!["bash".to_string()],
+        command,
+        stdin: true,
+        run_on_idle: true,
+        pattern: r"^(?P<line>\d+): (?P<message>.*)$".to_string(),
+        only_without_lsp: false,
+        ..Default::default()
+    });
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        24,
+        config,
+        temp_dir.path().to_path_buf(),
+    )
+    .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.type_text("# TODO tidy up").unwrap();
+    harness
+        .wait_until(|h| linter_messages(h) == vec!["found a TODO".to_string()])
+        .unwrap();
+
+    harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+    harness.type_text("# done").unwrap();
+    harness
+        .wait_until(|h| linter_messages(h).is_empty())
+        .unwrap();
+}
+
+/// Linters may load repo-controlled config (eslint's `eslint.config.js`),
+/// so they only run once the workspace is trusted.
+#[test]
+#[cfg_attr(not(unix), ignore = "Linters require Unix-like environment")]
+fn test_linter_skipped_in_untrusted_workspace() {
+    use fresh::services::workspace_trust::TrustLevel;
+
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("script.sh");
+    std::fs::write(&file_path, "echo hi\n").unwrap();
+    let runs = temp_dir.path().join("runs.log");
+    let command = fake_linter(
+        temp_dir.path(),
+        &format!(
+            r#"echo run >> "{}"; echo "$1:1:1: warning: linted""#,
+            runs.display()
+        ),
+    );
+
+    let config = config_with_linter(LinterConfig {
+        languages: vec!["bash".to_string()],
+        command,
+        args: vec!["$FILE".to_string()],
+        pattern: r"^[^:]+:(?P<line>\d+):(?P<column>\d+): (?P<severity>\w+): (?P<message>.*)$"
+            .to_string(),
+        only_without_lsp: false,
+        ..Default::default()
+    });
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        100,
+        24,
+        config,
+        temp_dir.path().to_path_buf(),
+    )
+    .unwrap();
+    harness
+        .editor()
+        .authority()
+        .workspace_trust
+        .set_level(TrustLevel::Restricted);
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    // Trusting the folder lets the next save lint; only that save ran it.
+    harness
+        .editor()
+        .authority()
+        .workspace_trust
+        .set_level(TrustLevel::Trusted);
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|h| !linter_messages(h).is_empty())
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(&runs).unwrap(), "run\n");
+}
@@ -30,12 +30,14 @@ fn test_lsp_completion_popup_text_not_mangled() -> anyhow::Result<()> {
                         detail: Some("fn test_function() -> i32".to_string()),
                         icon: Some("λ".to_string()),
                         data: Some("test_function".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "test_variable".to_string(),
                         detail: Some("let test_variable: String".to_string()),
                         icon: Some("v".to_string()),
                         data: Some("test_variable".to_string()),
+                        match_positions: Vec::new(),
                     },
                 ],
                 selected: 0,
@@ -114,6 +116,7 @@ fn test_lsp_completion_replaces_word() -> anyhow::Result<()> {
                     detail: Some("fn test_function()".to_string()),
                     icon: Some("λ".to_string()),
                     data: Some("test_function".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -205,12 +208,14 @@ fn test_lsp_completion_popup() -> anyhow::Result<()> {
                         detail: Some("fn test_function()".to_string()),
                         icon: Some("λ".to_string()),
                         data: Some("test_function".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "test_variable".to_string(),
                         detail: Some("let test_variable".to_string()),
                         icon: Some("v".to_string()),
                         data: Some("test_variable".to_string()),
+                        match_positions: Vec::new(),
                     },
                 ],
                 selected: 0,
@@ -376,18 +381,21 @@ fn test_lsp_completion_navigation() -> anyhow::Result<()> {
                         detail: None,
                         icon: None,
                         data: Some("item1".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "item2".to_string(),
                         detail: None,
                         icon: None,
                         data: Some("item2".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "item3".to_string(),
                         detail: None,
                         icon: None,
                         data: Some("item3".to_string()),
+                        match_positions: Vec::new(),
                     },
                 ],
                 selected: 0,
@@ -446,6 +454,7 @@ fn test_lsp_completion_cancel() -> anyhow::Result<()> {
                     detail: None,
                     icon: None,
                     data: Some("completion_item".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -503,12 +512,14 @@ fn test_lsp_completion_after_dot() -> anyhow::Result<()> {
                         detail: Some("fn len(&self) -> usize".to_string()),
                         icon: Some("λ".to_string()),
                         data: Some("len".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "is_empty".to_string(),
                         detail: Some("fn is_empty(&self) -> bool".to_string()),
                         icon: Some("λ".to_string()),
                         data: Some("is_empty".to_string()),
+                        match_positions: Vec::new(),
                     },
                 ],
                 selected: 0,
@@ -566,6 +577,7 @@ fn test_lsp_completion_after_dot_with_partial() -> anyhow::Result<()> {
                     detail: Some("fn length(&self) -> usize".to_string()),
                     icon: Some("λ".to_string()),
                     data: Some("length".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -622,12 +634,14 @@ fn test_lsp_completion_filtering() -> anyhow::Result<()> {
                         detail: Some("fn test_function()".to_string()),
                         icon: Some("λ".to_string()),
                         data: Some("test_function".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "test_variable".to_string(),
                         detail: Some("let test_variable".to_string()),
                         icon: Some("v".to_string()),
                         data: Some("test_variable".to_string()),
+                        match_positions: Vec::new(),
                     },
                     // These should NOT appear (different prefix):
                     // - "Self" (doesn't start with "test_")
@@ -714,12 +728,14 @@ fn test_lsp_completion_popup_size() -> anyhow::Result<()> {
                         detail: Some("fn test_function()".to_string()),
                         icon: Some("λ".to_string()),
                         data: Some("test_function".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "test_variable".to_string(),
                         detail: Some("let test_variable".to_string()),
                         icon: Some("v".to_string()),
                         data: Some("test_variable".to_string()),
+                        match_positions: Vec::new(),
                     },
                 ],
                 selected: 0,
@@ -1358,12 +1374,14 @@ fn test_lsp_completion_popup_hides_background() -> anyhow::Result<()> {
                         detail: Some("Args".to_string()),
                         icon: Some("v".to_string()),
                         data: Some("args".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "Args".to_string(),
                         detail: Some("Args".to_string()),
                         icon: Some("S".to_string()),
                         data: Some("Args".to_string()),
+                        match_positions: Vec::new(),
                     },
                 ],
                 selected: 0,
@@ -4833,6 +4851,7 @@ fn test_popup_home_key_selects_first_item() -> anyhow::Result<()> {
             detail: None,
             icon: None,
             data: Some(format!("item_{}", i)),
+            match_positions: Vec::new(),
         })
         .collect();
 
@@ -4892,6 +4911,7 @@ fn test_popup_end_key_selects_last_item() -> anyhow::Result<()> {
             detail: None,
             icon: None,
             data: Some(format!("item_{}", i)),
+            match_positions: Vec::new(),
         })
         .collect();
 
@@ -4946,6 +4966,7 @@ fn test_popup_mouse_wheel_scrolls() -> anyhow::Result<()> {
             detail: None,
             icon: None,
             data: Some(format!("completion_item_{}", i)),
+            match_positions: Vec::new(),
         })
         .collect();
 
@@ -5031,6 +5052,7 @@ fn test_popup_scrollbar_visible_for_long_list() -> anyhow::Result<()> {
             detail: None,
             icon: None,
             data: Some(format!("item_{}", i)),
+            match_positions: Vec::new(),
         })
         .collect();
 
@@ -5096,6 +5118,7 @@ fn test_popup_no_scrollbar_for_short_list() -> anyhow::Result<()> {
             detail: None,
             icon: None,
             data: Some(format!("item_{}", i)),
+            match_positions: Vec::new(),
         })
         .collect();
 
@@ -5149,6 +5172,7 @@ fn test_popup_mouse_wheel_scroll_up() -> anyhow::Result<()> {
             detail: None,
             icon: None,
             data: Some(format!("item_{}", i)),
+            match_positions: Vec::new(),
         })
         .collect();
 
@@ -5278,18 +5302,21 @@ fn test_completion_type_to_filter_basic() -> anyhow::Result<()> {
                         detail: Some("fn test_function()".to_string()),
                         icon: Some("λ".to_string()),
                         data: Some("test_function".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "test_variable".to_string(),
                         detail: Some("let test_variable".to_string()),
                         icon: Some("v".to_string()),
                         data: Some("test_variable".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "temp_file".to_string(),
                         detail: Some("let temp_file".to_string()),
                         icon: Some("v".to_string()),
                         data: Some("temp_file".to_string()),
+                        match_positions: Vec::new(),
                     },
                 ],
                 selected: 0,
@@ -5406,18 +5433,21 @@ fn test_completion_type_to_filter_uppercase() -> anyhow::Result<()> {
                         detail: Some("void Console.WriteLine()".to_string()),
                         icon: Some("λ".to_string()),
                         data: Some("WriteLine".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "Write".to_string(),
                         detail: Some("void Console.Write()".to_string()),
                         icon: Some("λ".to_string()),
                         data: Some("Write".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "ReadLine".to_string(),
                         detail: Some("string Console.ReadLine()".to_string()),
                         icon: Some("λ".to_string()),
                         data: Some("ReadLine".to_string()),
+                        match_positions: Vec::new(),
                     },
                 ],
                 selected: 0,
@@ -5528,6 +5558,7 @@ fn test_completion_type_to_filter_closes_on_no_match() -> anyhow::Result<()> {
                     detail: None,
                     icon: None,
                     data: Some("test_function".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -5607,6 +5638,7 @@ fn test_completion_backspace_refilters() -> anyhow::Result<()> {
                     detail: None,
                     icon: None,
                     data: Some("test_function".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -5709,18 +5741,21 @@ fn test_completion_type_to_filter_preserves_selection() -> anyhow::Result<()> {
                         detail: None,
                         icon: None,
                         data: Some("test_alpha".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "test_beta".to_string(),
                         detail: None,
                         icon: None,
                         data: Some("test_beta".to_string()),
+                        match_positions: Vec::new(),
                     },
                     PopupListItemData {
                         text: "test_gamma".to_string(),
                         detail: None,
                         icon: None,
                         data: Some("test_gamma".to_string()),
+                        match_positions: Vec::new(),
                     },
                 ],
                 selected: 0,
@@ -5809,6 +5844,7 @@ fn test_completion_enter_dismisses_and_inserts_newline() -> anyhow::Result<()> {
                     detail: None,
                     icon: Some("λ".to_string()),
                     data: Some("test_function".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -5889,6 +5925,7 @@ fn test_completion_tab_accepts() -> anyhow::Result<()> {
                     detail: None,
                     icon: Some("λ".to_string()),
                     data: Some("test_function".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -5955,6 +5992,7 @@ fn test_completion_snippet_cursor_position() -> anyhow::Result<()> {
                     icon: Some("m".to_string()),
                     // Snippet with $0 inside parens
                     data: Some("println!($0)".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -6009,6 +6047,7 @@ fn test_completion_snippet_with_default() -> anyhow::Result<()> {
                     icon: Some("k".to_string()),
                     // Snippet with default text
                     data: Some("fn ${1:name}($2) {\n    $0\n}".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -6032,7 +6071,13 @@ fn test_completion_snippet_with_default() -> anyhow::Result<()> {
         "Snippet should expand with default text"
     );
 
-    // Verify cursor is at $0 position (after the 4 spaces on line 2)
+    // The first tab stop is selected; Tab walks $2 and then lands on $0
+    // (after the 4 spaces on line 2)
+    assert_eq!(harness.get_selected_text(), "name");
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE)?;
+    let cursor_pos = harness.editor().active_cursors().primary().position;
+    assert_eq!(cursor_pos, 8, "Cursor should be at $2 position");
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE)?;
     let cursor_pos = harness.editor().active_cursors().primary().position;
     assert_eq!(cursor_pos, 16, "Cursor should be at $0 position");
 
@@ -6066,6 +6111,7 @@ fn test_completion_plain_text_no_snippet() -> anyhow::Result<()> {
                     icon: Some("v".to_string()),
                     // Plain text, no snippet syntax
                     data: Some("my_variable".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
@@ -6338,10 +6384,10 @@ fn test_completion_triggered_on_trigger_character() -> anyhow::Result<()> {
     harness.send_key(KeyCode::Char('.'), KeyModifiers::NONE)?;
     harness.render()?;
 
-    // Wait for completion request to be logged
+    // Wait for completion request (and its trigger context) to be logged
     harness.wait_until(|_| {
         let log_content = std::fs::read_to_string(&log_file).unwrap_or_default();
-        log_content.contains("textDocument/completion")
+        log_content.contains("textDocument/completion") && log_content.contains("triggerKind")
     })?;
 
     // Verify completion was triggered
@@ -6351,6 +6397,13 @@ fn test_completion_triggered_on_trigger_character() -> anyhow::Result<()> {
         "Expected completion request to be triggered by '.' character. Log: {}",
         log_content
     );
+    // ...and that the server was told which trigger character was typed
+    assert!(
+        log_content.contains("\"triggerKind\":2")
+            && log_content.contains("\"triggerCharacter\":\".\""),
+        "Expected a TriggerCharacter context for '.'. Log: {}",
+        log_content
+    );
 
     Ok(())
 }
@@ -6425,10 +6478,10 @@ fn test_completion_triggered_on_word_char_with_quick_suggestions() -> anyhow::Re
     harness.send_key(KeyCode::Char('p'), KeyModifiers::NONE)?;
     harness.render()?;
 
-    // Wait for completion request
+    // Wait for completion request (and its trigger context)
     harness.wait_until(|_| {
         let log_content = std::fs::read_to_string(&log_file).unwrap_or_default();
-        log_content.contains("textDocument/completion")
+        log_content.contains("textDocument/completion") && log_content.contains("triggerKind")
     })?;
 
     let log_content = std::fs::read_to_string(&log_file)?;
@@ -6437,6 +6490,12 @@ fn test_completion_triggered_on_word_char_with_quick_suggestions() -> anyhow::Re
         "Expected completion to be triggered by word character 'p' when quick_suggestions=true. Log: {}",
         log_content
     );
+    // Completion while typing a word is an ordinary invocation
+    assert!(
+        log_content.contains("\"triggerKind\":1") && !log_content.contains("triggerCharacter"),
+        "Expected an Invoked context for a word character. Log: {}",
+        log_content
+    );
 
     Ok(())
 }
@@ -6641,6 +6700,7 @@ fn test_completion_ctrl_space_toggles_popup_off() -> anyhow::Result<()> {
                     detail: None,
                     icon: Some("λ".to_string()),
                     data: Some("test_function".to_string()),
+                    match_positions: Vec::new(),
                 }],
                 selected: 0,
             },
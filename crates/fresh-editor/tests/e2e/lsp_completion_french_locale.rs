@@ -85,18 +85,21 @@ fn setup_french_completion_popup(prefix: &str) -> anyhow::Result<(EditorTestHarn
                             detail: Some("fn test_function()".to_string()),
                             icon: Some("λ".to_string()),
                             data: Some("test_function".to_string()),
+                            match_positions: Vec::new(),
                         },
                         PopupListItemData {
                             text: "test_variable".to_string(),
                             detail: Some("let test_variable".to_string()),
                             icon: Some("v".to_string()),
                             data: Some("test_variable".to_string()),
+                            match_positions: Vec::new(),
                         },
                         PopupListItemData {
                             text: "test_struct".to_string(),
                             detail: Some("struct TestStruct".to_string()),
                             icon: Some("S".to_string()),
                             data: Some("test_struct".to_string()),
+                            match_positions: Vec::new(),
                         },
                     ],
                     selected: 0,
@@ -65,18 +65,21 @@ fn setup_completion_popup(prefix: &str) -> anyhow::Result<EditorTestHarness> {
                             ),
                             icon: Some("λ".to_string()),
                             data: Some("calculate_difference".to_string()),
+                            match_positions: Vec::new(),
                         },
                         PopupListItemData {
                             text: "calculate_product".to_string(),
                             detail: Some("fn calculate_product(a: i32, b: i32) -> i32".to_string()),
                             icon: Some("λ".to_string()),
                             data: Some("calculate_product".to_string()),
+                            match_positions: Vec::new(),
                         },
                         PopupListItemData {
                             text: "calculate_sum".to_string(),
                             detail: Some("fn calculate_sum(a: i32, b: i32) -> i32".to_string()),
                             icon: Some("λ".to_string()),
                             data: Some("calculate_sum".to_string()),
+                            match_positions: Vec::new(),
                         },
                     ],
                     selected: 0,
@@ -595,12 +598,14 @@ fn test_completion_underscore_filters() -> anyhow::Result<()> {
                             detail: None,
                             icon: None,
                             data: Some("calculate_sum".to_string()),
+                            match_positions: Vec::new(),
                         },
                         PopupListItemData {
                             text: "calculated".to_string(),
                             detail: None,
                             icon: None,
                             data: Some("calculated".to_string()),
+                            match_positions: Vec::new(),
                         },
                     ],
                     selected: 0,
@@ -692,18 +697,21 @@ fn setup_frameless_completion_popup(prefix: &str) -> anyhow::Result<EditorTestHa
                             detail: Some("fn(a: i32, b: i32) -> i32".to_string()),
                             icon: Some("λ".to_string()),
                             data: Some("calculate_difference".to_string()),
+                            match_positions: Vec::new(),
                         },
                         PopupListItemData {
                             text: "calculate_product".to_string(),
                             detail: Some("fn(a: i32, b: i32) -> i32".to_string()),
                             icon: Some("λ".to_string()),
                             data: Some("calculate_product".to_string()),
+                            match_positions: Vec::new(),
                         },
                         PopupListItemData {
                             text: "calculate_sum".to_string(),
                             detail: Some("fn(a: i32, b: i32) -> i32".to_string()),
                             icon: Some("λ".to_string()),
                             data: Some("calculate_sum".to_string()),
+                            match_positions: Vec::new(),
                         },
                     ],
                     selected: 0,
@@ -825,12 +833,14 @@ fn test_completion_popup_aligns_with_word_start() -> anyhow::Result<()> {
                             detail: None,
                             icon: Some("λ".to_string()),
                             data: Some("calculate".to_string()),
+                            match_positions: Vec::new(),
                         },
                         PopupListItemData {
                             text: "calibrate".to_string(),
                             detail: None,
                             icon: Some("λ".to_string()),
                             data: Some("calibrate".to_string()),
+                            match_positions: Vec::new(),
                         },
                     ],
                     selected: 0,
@@ -879,3 +889,241 @@ fn test_completion_popup_aligns_with_word_start() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// Typing keeps items whose label contains the typed characters in order,
+/// not only those starting with them, and highlights the matched characters.
+#[test]
+fn test_completion_fuzzy_filter_highlights_matches() -> anyhow::Result<()> {
+    let mut harness = setup_completion_popup("c")?;
+
+    harness.send_key(KeyCode::Char('s'), KeyModifiers::NONE)?;
+    harness.send_key(KeyCode::Char('m'), KeyModifiers::NONE)?;
+    harness.render()?;
+
+    assert!(
+        harness.editor().active_state().popups.is_visible(),
+        "\"csm\" should still match calculate_sum"
+    );
+    harness.assert_screen_contains("calculate_sum");
+    harness.assert_screen_not_contains("calculate_product");
+    harness.assert_screen_not_contains("calculate_difference");
+
+    // The 's' of "sum" is one of the matched characters.
+    let (x, y) = harness
+        .find_text_on_screen("calculate_sum")
+        .expect("item should be on screen");
+    let match_bg = harness.editor().theme().search_match_bg;
+    let style = harness.get_cell_style(x + 10, y).unwrap();
+    assert_eq!(
+        style.bg,
+        Some(match_bg),
+        "matched char should be highlighted"
+    );
+    let style = harness.get_cell_style(x + 1, y).unwrap();
+    assert_ne!(style.bg, Some(match_bg), "unmatched char should not be");
+
+    Ok(())
+}
+
+/// With `completion_matching` set to "prefix", subsequence matches are
+/// dropped and the popup closes once nothing starts with the typed text.
+#[test]
+fn test_completion_prefix_matching_rejects_subsequences() -> anyhow::Result<()> {
+    let mut harness = setup_completion_popup("c")?;
+    harness.editor_mut().config_mut().editor.completion_matching =
+        fresh::config::CompletionMatching::Prefix;
+
+    harness.send_key(KeyCode::Char('s'), KeyModifiers::NONE)?;
+    harness.render()?;
+
+    assert!(
+        !harness.editor().active_state().popups.is_visible(),
+        "no item starts with \"cs\", so the popup should close"
+    );
+
+    Ok(())
+}
+
+/// Selecting an item shows its documentation in a panel beside the popup,
+/// and the panel follows the selection.
+#[test]
+fn test_completion_docs_panel_follows_selection() -> anyhow::Result<()> {
+    let mut harness = setup_completion_popup("calc")?;
+    let documented = |label: &str, doc: &str| lsp_types::CompletionItem {
+        label: label.to_string(),
+        kind: Some(lsp_types::CompletionItemKind::FUNCTION),
+        documentation: Some(lsp_types::Documentation::String(doc.to_string())),
+        ..Default::default()
+    };
+    harness.editor_mut().set_completion_items(vec![
+        documented("calculate_difference", "Subtracts b from a."),
+        documented("calculate_product", "Multiplies a by b."),
+        documented("calculate_sum", "Adds a and b."),
+    ]);
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.render()?;
+    harness.assert_screen_contains("Multiplies a by b.");
+    harness.assert_screen_contains("calculate_sum");
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.render()?;
+    harness.assert_screen_contains("Adds a and b.");
+    harness.assert_screen_not_contains("Multiplies a by b.");
+
+    // Closing the popup closes the panel with it.
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE)?;
+    harness.render()?;
+    harness.assert_screen_not_contains("Adds a and b.");
+
+    Ok(())
+}
+
+/// With `completion_documentation` off, no panel is shown.
+#[test]
+fn test_completion_docs_panel_can_be_disabled() -> anyhow::Result<()> {
+    let mut harness = setup_completion_popup("calc")?;
+    harness
+        .editor_mut()
+        .config_mut()
+        .editor
+        .completion_documentation = false;
+    harness
+        .editor_mut()
+        .set_completion_items(vec![lsp_types::CompletionItem {
+            label: "calculate_product".to_string(),
+            documentation: Some(lsp_types::Documentation::String(
+                "Multiplies a by b.".to_string(),
+            )),
+            ..Default::default()
+        }]);
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE)?;
+    harness.render()?;
+    harness.assert_screen_contains("calculate_product");
+    harness.assert_screen_not_contains("Multiplies a by b.");
+
+    Ok(())
+}
+
+/// Show a completion popup for `items`, as if the server had sent them.
+fn show_lsp_completion(harness: &mut EditorTestHarness, items: Vec<lsp_types::CompletionItem>) {
+    let popup_items = items
+        .iter()
+        .map(|item| PopupListItemData {
+            text: item.label.clone(),
+            detail: None,
+            icon: None,
+            data: Some(match &item.text_edit {
+                Some(lsp_types::CompletionTextEdit::Edit(edit)) => edit.new_text.clone(),
+                _ => item.label.clone(),
+            }),
+            match_positions: Vec::new(),
+        })
+        .collect();
+    harness.editor_mut().set_completion_items(items);
+    harness
+        .apply_event(Event::ShowPopup {
+            popup: PopupData {
+                kind: PopupKindHint::Completion,
+                title: None,
+                description: None,
+                transient: false,
+                content: PopupContentData::List {
+                    items: popup_items,
+                    selected: 0,
+                },
+                position: PopupPositionData::BelowCursor,
+                width: 50,
+                max_height: 15,
+                bordered: true,
+            },
+        })
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn lsp_range(start: (u32, u32), end: (u32, u32)) -> lsp_types::Range {
+    lsp_types::Range {
+        start: lsp_types::Position::new(start.0, start.1),
+        end: lsp_types::Position::new(end.0, end.1),
+    }
+}
+
+/// Accepting an item replaces its `textEdit` range (not just the word
+/// before the cursor), applies its `additionalTextEdits`, lands on the
+/// snippet's tab stop, and undoes as one step.
+#[test]
+fn test_completion_accept_honors_text_edit_and_additional_edits() -> anyhow::Result<()> {
+    let mut harness = EditorTestHarness::new(80, 24)?;
+    harness.type_text("p.le")?;
+
+    // Typed after the request: the range still ends where the cursor was.
+    show_lsp_completion(
+        &mut harness,
+        vec![lsp_types::CompletionItem {
+            label: "len".to_string(),
+            insert_text_format: Some(lsp_types::InsertTextFormat::SNIPPET),
+            text_edit: Some(lsp_types::CompletionTextEdit::Edit(lsp_types::TextEdit {
+                range: lsp_range((0, 1), (0, 3)),
+                new_text: "->len($1)".to_string(),
+            })),
+            additional_text_edits: Some(vec![lsp_types::TextEdit {
+                range: lsp_range((0, 0), (0, 0)),
+                new_text: "use std::len;\n".to_string(),
+            }]),
+            ..Default::default()
+        }],
+    );
+
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE)?;
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "use std::len;\np->len()"
+    );
+
+    // The cursor sits on the snippet's tab stop, past the auto-import.
+    harness.type_text("x")?;
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "use std::len;\np->len(x)"
+    );
+
+    harness.send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)?;
+    harness.send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)?;
+    assert_eq!(harness.get_buffer_content().unwrap(), "p.le");
+
+    Ok(())
+}
+
+/// An item's `command` runs after it is accepted; `triggerSuggest` opens
+/// completion again.
+#[test]
+fn test_completion_accept_runs_trigger_suggest_command() -> anyhow::Result<()> {
+    let mut harness = EditorTestHarness::new(80, 24)?;
+    // A word for the re-opened (buffer-word) completion to offer.
+    harness.type_text("calc_total\ncalc")?;
+    show_lsp_completion(
+        &mut harness,
+        vec![lsp_types::CompletionItem {
+            label: "calc_".to_string(),
+            command: Some(lsp_types::Command {
+                title: "Suggest".to_string(),
+                command: "editor.action.triggerSuggest".to_string(),
+                arguments: None,
+            }),
+            ..Default::default()
+        }],
+    );
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE)?;
+    harness.render()?;
+
+    assert_eq!(harness.get_buffer_content().unwrap(), "calc_total\ncalc_");
+    assert!(
+        harness.editor().active_state().popups.is_visible(),
+        "triggerSuggest should open completion again"
+    );
+    harness.assert_screen_contains("calc_total");
+
+    Ok(())
+}
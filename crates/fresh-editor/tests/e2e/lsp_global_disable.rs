@@ -255,6 +255,7 @@ fn test_lsp_enabled_false_still_allows_manual_start() -> anyhow::Result<()> {
         action: "lsp_restart".to_string(),
         args: std::collections::HashMap::new(),
         when: None,
+        release: false,
     });
 
     let mut harness = EditorTestHarness::with_config_and_working_dir(
@@ -279,6 +279,7 @@ fn issue_1_click_stacks_plugin_popup_and_lsp_servers_popup() -> anyhow::Result<(
                 detail: None,
                 icon: None,
                 data: Some("disable".to_string()),
+                match_positions: Vec::new(),
             }],
             selected: 0,
         },
@@ -362,6 +363,7 @@ fn issue_2_show_popup_ignores_theme_popup_bg() -> anyhow::Result<()> {
                 detail: None,
                 icon: None,
                 data: Some("noop".to_string()),
+                match_positions: Vec::new(),
             }],
             selected: 0,
         },
@@ -311,3 +311,54 @@ fn test_popup_offers_enable_when_config_already_disabled() -> anyhow::Result<()>
 
     Ok(())
 }
+
+/// When the configured server can't run, the popup falls back to the
+/// well-known server for the language: a "Use gopls" row when it is on
+/// PATH, otherwise its install command as a row that runs it as a job.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_missing_binary_popup_offers_known_server() -> anyhow::Result<()> {
+    let temp = tempfile::tempdir()?;
+    let file = temp.path().join("main.go");
+    std::fs::write(&file, "package main\n")?;
+
+    let mut config = make_config_with_missing_rust_lsp();
+    let rust = config.lsp.remove("rust").unwrap();
+    config.lsp.insert("go".to_string(), rust);
+
+    let mut harness = EditorTestHarness::create(
+        120,
+        30,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(temp.path().to_path_buf()),
+    )?;
+    harness.open_file(&file)?;
+    harness.editor_mut().show_lsp_status_popup();
+    harness.render()?;
+
+    let items = popup_items(&harness);
+    if fresh::services::lsp::command_exists("gopls") {
+        assert!(
+            items
+                .iter()
+                .any(|(_, data, _)| data.as_deref() == Some("use:go/gopls")),
+            "expected a 'Use gopls' row. Items: {:#?}",
+            items
+        );
+    } else {
+        let install_row = items
+            .iter()
+            .find(|(_, data, _)| data.as_deref() == Some("install:go/gopls"))
+            .unwrap_or_else(|| panic!("expected an install row. Items: {:#?}", items));
+        assert!(
+            install_row
+                .0
+                .contains("go install golang.org/x/tools/gopls@latest"),
+            "install row must show the command. Row: {:?}",
+            install_row
+        );
+    }
+
+    Ok(())
+}
@@ -196,6 +196,7 @@ fn test_lsp_toggle_off_edit_toggle_on_causes_desync() -> anyhow::Result<()> {
         action: "lsp_toggle_for_buffer".to_string(),
         args: std::collections::HashMap::new(),
         when: None,
+        release: false,
     });
 
     // Create harness with empty plugins dir to prevent loading embedded
@@ -323,6 +324,7 @@ fn test_lsp_toggle_off_sends_did_close() -> anyhow::Result<()> {
         action: "lsp_toggle_for_buffer".to_string(),
         args: std::collections::HashMap::new(),
         when: None,
+        release: false,
     });
 
     // Create harness with empty plugins dir to prevent loading embedded
@@ -41,6 +41,7 @@ const FILE_MENU_GOLDEN: &[&str] = &[
     "│ Save All                             │",
     "│ Revert                               │",
     "│ Reload with Encoding...              │",
+    "│ Save with Encoding...                │",
     "│ ─────────────────────────────────────│",
     "│ Close Buffer                         │",
     "│ ─────────────────────────────────────│",
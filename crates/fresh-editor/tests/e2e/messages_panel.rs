@@ -0,0 +1,73 @@
+//! Tests for the *Messages* panel (Show Messages).
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn show_messages(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Show Messages").unwrap();
+    harness.wait_for_screen_contains("Show Messages").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_messages_panel_keeps_replaced_messages() {
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness
+        .editor_mut()
+        .set_status_message("first message".to_string());
+    harness
+        .editor_mut()
+        .set_status_error("second went wrong".to_string());
+
+    show_messages(&mut harness);
+    harness.assert_screen_contains("INFO   editor: first message");
+    harness.assert_screen_contains("ERROR  editor: second went wrong");
+
+    // Messages arriving while the panel is open show up in it.
+    harness
+        .editor_mut()
+        .set_status_message("third message".to_string());
+    harness.render().unwrap();
+    harness.assert_screen_contains("editor: third message");
+}
+
+#[test]
+fn test_messages_panel_severity_filter() {
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness
+        .editor_mut()
+        .set_status_message("just info".to_string());
+    harness
+        .editor_mut()
+        .set_status_error("a failure".to_string());
+
+    show_messages(&mut harness);
+    harness.assert_screen_contains("Showing all messages");
+
+    // f: warnings and errors, then errors only.
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Showing errors");
+    harness.assert_screen_contains("a failure");
+    harness.assert_screen_not_contains("just info");
+
+    // q closes the panel.
+    harness
+        .send_key(KeyCode::Char('q'), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Showing errors");
+}
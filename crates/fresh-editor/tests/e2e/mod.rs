@@ -1,3 +1,4 @@
+pub mod abbreviations;
 #[cfg(feature = "plugins")]
 pub mod action_popup_global;
 pub mod altgr_shift;
@@ -10,26 +11,40 @@ pub mod basic;
 pub mod binary_file;
 pub mod block_selection;
 pub mod blog_showcases;
+pub mod bracketed_paste;
+pub mod buffer_context_menu;
 pub mod buffer_groups;
 #[cfg(feature = "plugins")]
 pub mod buffer_lifecycle;
+pub mod buffer_picker;
 pub mod buffer_settings_commands;
 pub mod capslock_shortcuts;
 pub mod cargo_config_editing;
+pub mod chord_hints;
+pub mod cli_launch;
+pub mod clipboard_history;
 pub mod command_palette;
+pub mod conditional_keybindings;
+pub mod config_hot_reload;
 pub mod config_language_selector;
+pub mod config_rescue;
 pub mod copy_buffer_path;
 pub mod crash_repro;
 pub mod csharp_language_coherence;
 pub mod csi_u_session_input;
 pub mod cursor_style_rendering;
 pub mod dabbrev_completion;
+pub mod dead_keys;
 #[cfg(feature = "plugins")]
 pub mod dock_focus_stuck_born_attached;
 pub mod dock_panel_routing;
 pub mod document_model;
+pub mod editor_log;
 pub mod emacs_actions;
 pub mod encoding;
+pub mod evaluate_selection;
+pub mod event_hooks;
+pub mod expand_selection_to_node;
 pub mod explorer_bugs;
 pub mod explorer_context_menu;
 pub mod explorer_menu;
@@ -40,6 +55,7 @@ pub mod file_explorer;
 pub mod file_explorer_compact_chain;
 pub mod file_explorer_open_focus;
 pub mod file_explorer_session_persist;
+pub mod file_history;
 pub mod file_permissions;
 pub mod flash;
 #[cfg(feature = "plugins")]
@@ -48,9 +64,12 @@ pub mod folding;
 pub mod glob_language_detection;
 #[cfg(feature = "gui")]
 pub mod gui;
+pub mod gutter_components;
 pub mod hot_exit_flows;
 pub mod hot_exit_recovery_lsp_sync;
+pub mod ime_composition;
 pub mod indent_dedent;
+pub mod indentation_fix;
 pub mod indentation_guide;
 pub mod inlay_hints_wrap_scroll;
 pub mod inline_diagnostics;
@@ -90,20 +109,32 @@ pub mod issue_2572_hover_through_panel;
 pub mod issue_2605_format_selection_range;
 pub mod issue_779_after_eof_shade;
 pub mod issue_close_file_in_split_hides_buffer_group;
+pub mod join_lines;
 pub mod language_dialog_esc_cancels_edit;
 pub mod language_dialog_tab_size;
+pub mod line_bookmarks;
+pub mod messages_panel;
+pub mod modeline_detection;
+pub mod mojibake_fix;
 pub mod mouse_session_input;
+pub mod named_marks;
+pub mod narrowing;
+pub mod screen_dump;
 pub mod suspend_process;
 
 pub mod close_buffer_shared_split_cursor;
+pub mod json_schema_hover;
+pub mod key_release_bindings;
 pub mod keybinding_editor;
 pub mod language_features_e2e;
 pub mod large_file_inplace_write_bug;
 pub mod large_file_mode;
 pub mod lifecycle;
+pub mod line_endings;
 pub mod line_number_bugs;
 pub mod line_wrap_cache_consistency;
 pub mod line_wrapping;
+pub mod linters;
 pub mod list_clamp_no_spurious_select;
 pub mod live_grep;
 pub mod locale;
@@ -175,14 +206,19 @@ pub mod panel_mode_window_switch_leak;
 pub mod paste;
 pub mod per_buffer_view_settings;
 pub mod per_session_authority;
+pub mod perf_hud;
 #[cfg(feature = "plugins")]
 pub mod plugins;
 pub mod popup_selection;
+pub mod portable_mode;
 pub mod position_history;
 pub mod position_history_bugs;
 pub mod position_history_debug;
 pub mod position_history_truncate_debug;
+pub mod presentation;
+pub mod preview_images;
 pub mod preview_lsp_popup_focus;
+pub mod preview_markdown;
 pub mod preview_tabs;
 pub mod prompt;
 pub mod prompt_editing;
@@ -197,9 +233,12 @@ pub mod rendering;
 pub mod restored_agent_terminal;
 pub mod restored_terminal_dock_activation;
 pub mod restored_terminal_focus;
+pub mod revert_last_transform;
 pub mod save_as_language_detection;
 pub mod save_nonexistent_directory;
+pub mod scratch_buffers;
 pub mod scroll_clearing;
+pub mod scroll_options;
 pub mod scrolling;
 pub mod search;
 pub mod search_center_on_scroll;
@@ -209,6 +248,7 @@ pub mod search_replace;
 pub mod search_selection_on_punctuation;
 pub mod search_viewport_stall_after_wrap;
 pub mod selection;
+pub mod serve_buffer;
 pub mod server_session_lifecycle;
 pub mod session_hot_exit;
 #[cfg(feature = "plugins")]
@@ -234,6 +274,7 @@ pub mod shift_backspace;
 pub mod slow_filesystem;
 pub mod smart_editing;
 pub mod smart_home;
+pub mod snippets;
 pub mod split_close_confirm;
 pub mod split_focus_tab_click;
 pub mod split_tabs;
@@ -252,10 +293,14 @@ pub mod syntax_highlighting_coverage;
 pub mod syntax_highlighting_embedded_offset;
 pub mod syntax_language_case;
 pub mod syntax_variable_builtin;
+pub mod tab_close_commands;
 pub mod tab_config;
 pub mod tab_drag;
 pub mod tab_new_button;
+pub mod tags;
+pub mod telemetry;
 pub mod terminal;
+pub mod terminal_capabilities;
 pub mod terminal_close;
 pub mod terminal_link;
 pub mod terminal_pane_navigation_live;
@@ -263,6 +308,8 @@ pub mod terminal_resize;
 pub mod terminal_resume_mode_after_close;
 pub mod terminal_split_focus_live;
 pub mod test_scrollbar_keybinds_cursor;
+pub mod text_drag_and_drop;
+pub mod text_escapes;
 pub mod theme;
 pub mod theme_screenshots;
 pub mod toggle_bars;
@@ -275,6 +322,8 @@ pub mod unicode_prompt_bugs;
 pub mod universal_lsp;
 pub mod unnamed_buffer_persistence;
 pub mod update_notification;
+#[cfg(feature = "scripting")]
+pub mod user_scripts;
 pub mod vertical_rulers;
 #[cfg(feature = "plugins")]
 pub mod vi_mode;
@@ -283,8 +332,10 @@ pub mod vi_mode_bugs;
 pub mod virtual_space;
 pub mod visual_regression;
 pub mod warning_indicators;
+pub mod whitespace_rendering;
 #[cfg(feature = "plugins")]
 pub mod widget_panel_ownership;
 pub mod window_switch_explorer_first_frame;
 #[cfg(feature = "plugins")]
 pub mod workspace;
+pub mod zen_mode;
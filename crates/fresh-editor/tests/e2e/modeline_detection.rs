@@ -0,0 +1,52 @@
+//! Language detection from Vim / Emacs modelines, which beat the file's
+//! extension.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use std::fs;
+use tempfile::TempDir;
+
+fn detect_language(filename: &str, contents: &str) -> String {
+    let temp_dir = TempDir::new().unwrap();
+    let working_dir = temp_dir.path().to_path_buf();
+    let test_file = working_dir.join(filename);
+    fs::write(&test_file, contents).unwrap();
+
+    let mut harness = EditorTestHarness::create(
+        100,
+        30,
+        HarnessOptions::new()
+            .without_empty_plugins_dir()
+            .with_full_grammar_registry()
+            .with_working_dir(working_dir.clone()),
+    )
+    .unwrap();
+
+    harness.open_file(&test_file).unwrap();
+    harness.editor().active_state().language.clone()
+}
+
+#[test]
+fn test_emacs_header_overrides_extension() {
+    assert_eq!(
+        detect_language("build.txt", "# -*- mode: python -*-\nprint('hi')\n"),
+        "python"
+    );
+}
+
+#[test]
+fn test_vim_modeline_at_end_of_file() {
+    let mut contents = String::from("echo hi\n");
+    for _ in 0..20 {
+        contents.push_str("echo more\n");
+    }
+    contents.push_str("# vim: set ft=sh :\n");
+    assert_eq!(detect_language("script.conf", &contents), "bash");
+}
+
+#[test]
+fn test_unknown_modeline_falls_back_to_extension() {
+    assert_eq!(
+        detect_language("main.rs", "// vim: ft=no-such-language\nfn main() {}\n"),
+        "rust"
+    );
+}
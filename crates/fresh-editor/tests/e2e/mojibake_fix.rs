@@ -0,0 +1,69 @@
+//! Tests for Fix Encoding Artifacts.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn start_fix(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Fix Encoding Artifacts").unwrap();
+    harness
+        .wait_for_screen_contains("Fix Encoding Artifacts")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+fn answer(harness: &mut EditorTestHarness, reply: &str) {
+    harness.type_text(reply).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+}
+
+#[test]
+fn test_fix_encoding_artifacts_previews_and_applies() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("cafÃ© â€œmenuâ€\u{9D} — 日本")
+        .unwrap();
+
+    start_fix(&mut harness);
+    harness.wait_for_screen_contains("→ 'café").unwrap();
+    answer(&mut harness, "y");
+    assert_eq!(harness.get_buffer_content().unwrap(), "café “menu” — 日本");
+
+    // The whole repair undoes in one step.
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "cafÃ© â€œmenuâ€\u{9D} — 日本"
+    );
+}
+
+#[test]
+fn test_fix_encoding_artifacts_cancel_and_clean_text() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("Ã¼ber").unwrap();
+
+    start_fix(&mut harness);
+    harness.wait_for_prompt().unwrap();
+    answer(&mut harness, "n");
+    assert_eq!(harness.get_buffer_content().unwrap(), "Ã¼ber");
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("über").unwrap();
+    start_fix(&mut harness);
+    harness.wait_for_prompt_closed().unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("No encoding artifacts found");
+}
@@ -1959,6 +1959,9 @@ fn test_mouse_select_never_creates_invalid_utf8() {
             }
             prev_start_col = Some(*start_col);
 
+            // Collapse the previous selection: a drag starting inside it
+            // would move the selected text instead of selecting.
+            harness.send_key(KeyCode::Home, KeyModifiers::NONE).unwrap();
             harness
                 .mouse_drag(gutter_x + start_col, row, gutter_x + end_col, row)
                 .unwrap();
@@ -0,0 +1,155 @@
+//! E2E tests for named marks: `a`–`z` local to a buffer, `A`–`Z` global,
+//! following edits, and the marks panel.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::input::keybindings::Action;
+use tempfile::TempDir;
+
+fn run_command(harness: &mut EditorTestHarness, command_name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(command_name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn cursor_line(harness: &EditorTestHarness) -> usize {
+    let pos = harness.cursor_position();
+    harness.editor().active_state().buffer.get_line_number(pos)
+}
+
+fn goto_line(harness: &mut EditorTestHarness, line: usize) {
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..line {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+}
+
+fn write_files(dir: &TempDir) -> (std::path::PathBuf, std::path::PathBuf) {
+    let first = dir.path().join("first.txt");
+    let second = dir.path().join("second.txt");
+    std::fs::write(&first, "alpha\nbeta\ngamma\ndelta\n").unwrap();
+    std::fs::write(&second, "one\ntwo\nthree\n").unwrap();
+    (first, second)
+}
+
+/// A mark stays on its text when lines are inserted above it.
+#[test]
+fn test_mark_follows_edits() {
+    let dir = TempDir::new().unwrap();
+    let (first, _) = write_files(&dir);
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&first).unwrap();
+
+    goto_line(&mut harness, 2);
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SetNamedMark('a'));
+    goto_line(&mut harness, 0);
+    harness.type_text("new\nlines\n").unwrap();
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::GotoNamedMark('a'));
+    assert_eq!(cursor_line(&harness), 4);
+    let text = harness.editor().active_state().buffer.to_string().unwrap();
+    assert_eq!(&text[harness.cursor_position()..], "amma\ndelta\n");
+}
+
+/// Lowercase marks belong to their buffer; uppercase marks jump across
+/// buffers.
+#[test]
+fn test_lowercase_marks_are_local_and_uppercase_are_global() {
+    let dir = TempDir::new().unwrap();
+    let (first, second) = write_files(&dir);
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&first).unwrap();
+
+    goto_line(&mut harness, 1);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SetNamedMark('a'));
+    goto_line(&mut harness, 3);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::SetNamedMark('Q'));
+
+    harness.open_file(&second).unwrap();
+    goto_line(&mut harness, 2);
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::GotoNamedMark('a'));
+    assert_eq!(cursor_line(&harness), 2, "`a` is not set in second.txt");
+    assert_eq!(
+        harness.editor().active_state().buffer.to_string().unwrap(),
+        "one\ntwo\nthree\n"
+    );
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::GotoNamedMark('Q'));
+    assert_eq!(
+        harness.editor().active_state().buffer.to_string().unwrap(),
+        "alpha\nbeta\ngamma\ndelta\n"
+    );
+    assert_eq!(cursor_line(&harness), 3);
+
+    harness
+        .editor_mut()
+        .dispatch_action_for_tests(Action::GotoNamedMark('a'));
+    assert_eq!(cursor_line(&harness), 1);
+}
+
+/// The palette commands prompt for the mark letter; the marks panel lists
+/// marks and jumps to the pick.
+#[test]
+fn test_mark_commands_and_panel() {
+    let dir = TempDir::new().unwrap();
+    let (first, _) = write_files(&dir);
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&first).unwrap();
+
+    goto_line(&mut harness, 1);
+    run_command(&mut harness, "Set Named Mark");
+    harness.type_text("b").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    goto_line(&mut harness, 3);
+    run_command(&mut harness, "Set Named Mark");
+    harness.type_text("c").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    run_command(&mut harness, "Go to Named Mark");
+    harness.type_text("b").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(cursor_line(&harness), 1);
+
+    run_command(&mut harness, "List Named Marks");
+    let screen = harness.screen_to_string();
+    assert!(screen.contains("Marks"), "panel title:\n{screen}");
+    assert!(
+        screen.contains("first.txt:2") && screen.contains("first.txt:4"),
+        "both marks listed:\n{screen}"
+    );
+
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    assert_eq!(cursor_line(&harness), 3);
+    assert!(!harness.editor().active_state().popups.is_visible());
+}
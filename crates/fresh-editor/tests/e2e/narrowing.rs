@@ -0,0 +1,102 @@
+//! Tests for Narrow to Selection / Widen.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness.wait_for_screen_contains(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+}
+
+/// Narrow to lines 2-3 of a four-line buffer.
+fn narrow_to_middle(harness: &mut EditorTestHarness) {
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Down, KeyModifiers::SHIFT)
+        .unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::SHIFT).unwrap();
+    run_command(harness, "Narrow to Selection");
+}
+
+#[test]
+fn test_narrow_edit_and_widen() {
+    let mut harness = EditorTestHarness::new(160, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("one\ntwo\nthree\nfour\n")
+        .unwrap();
+
+    narrow_to_middle(&mut harness);
+    assert_eq!(harness.get_buffer_content().unwrap(), "two\nthree\n");
+    harness.render().unwrap();
+    harness.assert_screen_contains("Narrowed 2-3");
+
+    harness.type_text("X").unwrap();
+    run_command(&mut harness, "Widen");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "one\nXtwo\nthree\nfour\n"
+    );
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Narrowed 2-3");
+
+    // The merge undoes in one step.
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "one\ntwo\nthree\nfour\n"
+    );
+}
+
+#[test]
+fn test_narrowed_region_is_protected_in_source() {
+    let mut harness = EditorTestHarness::new(160, 24).unwrap();
+    let _fixture = harness
+        .load_buffer_from_text("one\ntwo\nthree\nfour\n")
+        .unwrap();
+
+    narrow_to_middle(&mut harness);
+    run_command(&mut harness, "Previous Buffer");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "one\ntwo\nthree\nfour\n"
+    );
+
+    // Lines 2-3 refuse edits; line 1 doesn't.
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("Y").unwrap();
+    harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+    harness.type_text("Z").unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "oZne\ntwo\nthree\nfour\n"
+    );
+
+    // Closing the narrowed buffer gives the region back.
+    run_command(&mut harness, "Next Buffer");
+    run_command(&mut harness, "Close Buffer");
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.type_text("Y").unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "oZne\nYtwo\nthree\nfour\n"
+    );
+}
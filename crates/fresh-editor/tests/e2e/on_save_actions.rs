@@ -41,6 +41,8 @@ fn test_format_on_save() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -57,6 +59,8 @@ fn test_format_on_save() {
             on_save: vec![],
             word_characters: None,
             indentation_guide: None,
+            trim_trailing_whitespace_on_save: None,
+            abbreviations: Default::default(),
             indent: None,
         },
     );
@@ -112,6 +116,8 @@ fn test_on_save_linter_style() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -128,6 +134,8 @@ fn test_on_save_linter_style() {
             on_save: vec![action],
             word_characters: None,
             indentation_guide: None,
+            trim_trailing_whitespace_on_save: None,
+            abbreviations: Default::default(),
             indent: None,
         },
     );
@@ -183,6 +191,8 @@ fn test_on_save_action_failure() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -199,6 +209,8 @@ fn test_on_save_action_failure() {
             on_save: vec![action],
             word_characters: None,
             indentation_guide: None,
+            trim_trailing_whitespace_on_save: None,
+            abbreviations: Default::default(),
             indent: None,
         },
     );
@@ -263,6 +275,8 @@ fn test_on_save_file_placeholder() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -279,6 +293,8 @@ fn test_on_save_file_placeholder() {
             on_save: vec![action],
             word_characters: None,
             indentation_guide: None,
+            trim_trailing_whitespace_on_save: None,
+            abbreviations: Default::default(),
             indent: None,
         },
     );
@@ -337,6 +353,8 @@ fn test_formatter_stdin_mode() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -353,6 +371,8 @@ fn test_formatter_stdin_mode() {
             on_save: vec![],
             word_characters: None,
             indentation_guide: None,
+            trim_trailing_whitespace_on_save: None,
+            abbreviations: Default::default(),
             indent: None,
         },
     );
@@ -418,6 +438,8 @@ fn test_on_save_stops_on_failure() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -434,6 +456,8 @@ fn test_on_save_stops_on_failure() {
             on_save: vec![action1, action2],
             word_characters: None,
             indentation_guide: None,
+            trim_trailing_whitespace_on_save: None,
+            abbreviations: Default::default(),
             indent: None,
         },
     );
@@ -520,6 +544,8 @@ fn test_formatter_not_found_shows_message() {
             filenames: vec![],
             grammar: "plaintext".to_string(),
             comment_prefix: None,
+            block_comment_start: None,
+            block_comment_end: None,
             auto_indent: false,
             auto_close: None,
             auto_surround: None,
@@ -536,6 +562,8 @@ fn test_formatter_not_found_shows_message() {
             on_save: vec![],
             word_characters: None,
             indentation_guide: None,
+            trim_trailing_whitespace_on_save: None,
+            abbreviations: Default::default(),
             indent: None,
         },
     );
@@ -597,6 +625,40 @@ fn test_trim_trailing_whitespace_on_save() {
     assert_eq!(disk_content, "line 1\nline 2\nline 3\n");
 }
 
+/// A language-level `trim_trailing_whitespace_on_save` overrides the global
+/// setting: Markdown keeps its trailing-space line breaks while the global
+/// option stays on.
+#[test]
+fn test_trim_trailing_whitespace_language_override() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+
+    let file_path = project_dir.join("notes.md");
+    std::fs::write(&file_path, "hard  \nbreak\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.trim_trailing_whitespace_on_save = true;
+    config
+        .languages
+        .get_mut("markdown")
+        .expect("markdown language config")
+        .trim_trailing_whitespace_on_save = Some(false);
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 24, config, project_dir).unwrap();
+
+    harness.open_file(&file_path).unwrap();
+    harness.type_text("x").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+
+    let disk_content = std::fs::read_to_string(&file_path).unwrap();
+    assert_eq!(disk_content, "xhard  \nbreak\n");
+}
+
 /// Test ensure_final_newline_on_save adds newline at end
 #[test]
 fn test_ensure_final_newline_on_save() {
@@ -0,0 +1,51 @@
+//! Tests for the performance HUD: toggled from the command palette, it
+//! lists frame timing and resource counters in the top-right corner.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn toggle_perf_hud(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Toggle Performance HUD").unwrap();
+    harness
+        .wait_for_screen_contains("Toggle Performance HUD")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_perf_hud_shows_timings_and_buffer_size() {
+    let mut harness = EditorTestHarness::with_temp_project(100, 24).unwrap();
+    let file_path = harness.project_dir().unwrap().join("test.txt");
+    std::fs::write(&file_path, "alpha\nbeta\n").unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Input latency");
+
+    toggle_perf_hud(&mut harness);
+    assert!(harness.editor().is_perf_hud_visible());
+
+    let (_, title_row) = harness
+        .find_text_on_screen("Performance")
+        .expect("HUD title should be on screen");
+    let render_row = harness.get_row_text(title_row + 1);
+    assert!(
+        render_row.contains("Render") && render_row.contains(" ms (avg "),
+        "render timing row, got {render_row:?}"
+    );
+    harness.assert_screen_contains("Input latency");
+    harness.assert_screen_contains("11 B, 3 lines");
+    harness.assert_screen_contains("LSP requests");
+    harness.assert_screen_contains("Memory");
+
+    toggle_perf_hud(&mut harness);
+    assert!(!harness.editor().is_perf_hud_visible());
+    harness.assert_screen_not_contains("Input latency");
+}
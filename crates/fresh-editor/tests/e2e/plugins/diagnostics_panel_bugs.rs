@@ -336,3 +336,30 @@ fn test_diagnostics_panel_a_toggles_filter() {
         after
     );
 }
+
+// ─── Status bar: clicking the diagnostic counts opens the panel ─────────────
+
+/// The `E:n` segment in the status bar is clickable and opens the
+/// diagnostics panel, like the "Show Diagnostics Panel" command.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_status_bar_diagnostics_click_opens_panel() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let _fake_server = FakeLspServer::spawn_many_diagnostics(temp_dir.path(), 3).unwrap();
+    let (mut harness, _test_file) = setup_harness(&temp_dir);
+
+    harness
+        .wait_until(|h| h.get_status_bar().contains("E:3"))
+        .unwrap();
+    let (col, row) = harness
+        .find_text_on_screen("E:3")
+        .expect("diagnostic counts should be in the status bar");
+    harness.mouse_click(col, row).unwrap();
+
+    harness
+        .wait_until(|h| {
+            let screen = h.screen_to_string();
+            screen.contains("Diagnostics (") && screen.contains("[E]")
+        })
+        .unwrap();
+}
@@ -0,0 +1,129 @@
+//! E2E tests for the external_plugins plugin
+//!
+//! Tests that a plugin running as an external process, speaking JSON-RPC
+//! over stdio (`tests/fixtures/rpc_plugin.py`):
+//! - Registers commands and keybindings that edit the active buffer
+//! - Shows popups, gets the picked action back, and opens panels
+//! - Can be stopped and restarted from "External Plugins: Manage", and its
+//!   commands go away while it is stopped
+
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::config_io::DirectoryContext;
+use std::fs;
+
+fn harness_with_rpc_plugin() -> (tempfile::TempDir, EditorTestHarness) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    copy_plugin(&plugins_dir, "external_plugins");
+
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+    let manifest_dir = dir_context.config_dir.join("external-plugins");
+    fs::create_dir_all(&manifest_dir).unwrap();
+    let fixture =
+        std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/rpc_plugin.py");
+    let manifest = serde_json::json!({
+        "name": "rpc",
+        "command": "python3",
+        "args": [fixture.to_string_lossy()],
+    });
+    fs::write(manifest_dir.join("rpc.json"), manifest.to_string()).unwrap();
+
+    let file = project_root.join("notes.txt");
+    fs::write(&file, "Hello World\n").unwrap();
+
+    let mut harness = EditorTestHarness::create(
+        120,
+        30,
+        HarnessOptions::new()
+            .with_config(Config::default())
+            .with_working_dir(project_root)
+            .with_shared_dir_context(dir_context)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+    harness.open_file(&file).unwrap();
+    harness
+        .wait_for_screen_contains("rpc ready (unknown method")
+        .unwrap();
+    (temp_dir, harness)
+}
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_external_plugin_commands_and_keys_edit_the_buffer() {
+    let (_temp, mut harness) = harness_with_rpc_plugin();
+
+    run_command(&mut harness, "Ext: Shout");
+    harness.wait_for_screen_contains("HELLO WORLD").unwrap();
+
+    // The keys are a layer of their own, not the global editor mode
+    assert_eq!(harness.editor().editor_mode(), None);
+    harness
+        .send_key(KeyCode::Char('l'), KeyModifiers::ALT)
+        .unwrap();
+    harness.wait_for_screen_contains("hello world").unwrap();
+}
+
+#[test]
+fn test_external_plugin_popup_and_panel() {
+    let (_temp, mut harness) = harness_with_rpc_plugin();
+
+    run_command(&mut harness, "Ext: Ask");
+    harness.wait_for_screen_contains("Proceed?").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("picked yes").unwrap();
+
+    run_command(&mut harness, "Ext: Panel");
+    harness.wait_for_screen_contains("panel line two").unwrap();
+    harness.assert_screen_contains("Hello World");
+}
+
+#[test]
+fn test_plugin_manager_stops_and_restarts_a_plugin() {
+    let (_temp, mut harness) = harness_with_rpc_plugin();
+
+    run_command(&mut harness, "External Plugins: Manage");
+    harness.wait_for_screen_contains("running").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("External plugin 'rpc' stopped")
+        .unwrap();
+
+    // The plugin's commands left the palette with it.
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Ext: Shout").unwrap();
+    harness.render().unwrap();
+    let screen = harness.screen_to_string();
+    assert_eq!(screen.matches("Ext: Shout").count(), 1, "{screen}");
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    run_command(&mut harness, "External Plugins: Manage");
+    harness.wait_for_screen_contains("stopped").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("rpc ready (unknown method")
+        .unwrap();
+}
@@ -477,6 +477,36 @@ fn test_git_find_file_interactive_filtering() {
     );
 }
 
+/// Test git find file previews the selected file beside the results
+#[test]
+fn test_git_find_file_previews_selected_file() {
+    let repo = GitTestRepo::new();
+    repo.setup_typical_project();
+    repo.setup_git_plugins();
+
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    // Wide enough for the overlay to show its preview pane.
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        180,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+
+    trigger_git_find_file(&mut harness);
+    harness
+        .wait_until(|h| contains_src_path(&h.screen_to_string()))
+        .unwrap();
+
+    harness.type_text("lib.rs").unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("process_request"))
+        .unwrap();
+}
+
 /// Test git find file selection and navigation
 #[test]
 fn test_git_find_file_selection_navigation() {
@@ -1,8 +1,10 @@
 //! E2E tests for the git_statusbar plugin
 //!
 //! These tests verify that the status bar can be configured to show the
-//! git branch element, which is registered by the git_statusbar plugin.
+//! git branch element, which is registered by the git_statusbar plugin, and
+//! that clicking it opens the branch switcher.
 
+use crate::common::git_test_helper::{git_command, GitTestRepo};
 use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness, HarnessOptions};
 use crossterm::event::{KeyCode, KeyModifiers};
 use fresh::config::{Config, StatusBarConfig, StatusBarElement};
@@ -114,3 +116,75 @@ fn test_status_bar_shows_custom_branch_token() {
         .wait_until(|h| h.get_status_bar().contains("Not in git"))
         .unwrap();
 }
+
+#[test]
+fn test_clicking_branch_token_switches_branch() {
+    let repo = GitTestRepo::new();
+    repo.create_file("test.txt", "test content\n");
+    repo.git_add_all();
+    repo.git_commit("initial");
+    let status = git_command(&repo.path)
+        .args(["branch", "feature-x"])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let plugins_dir = repo.path.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin(&plugins_dir, "git_statusbar");
+    copy_plugin_lib(&plugins_dir);
+
+    let mut config = Config::default();
+    config.editor.status_bar = StatusBarConfig {
+        left: vec![
+            StatusBarElement::Filename,
+            StatusBarElement::CustomToken("git_statusbar:branch".to_string()),
+        ],
+        right: vec![StatusBarElement::Encoding, StatusBarElement::Language],
+        ..StatusBarConfig::default()
+    };
+    let mut harness = EditorTestHarness::create(
+        100,
+        24,
+        HarnessOptions::new()
+            .with_working_dir(repo.path.clone())
+            .with_config(config),
+    )
+    .unwrap();
+    harness.open_file(&repo.path.join("test.txt")).unwrap();
+
+    let initial = String::from_utf8(
+        git_command(&repo.path)
+            .args(["rev-parse", "--abbrev-ref", "HEAD"])
+            .output()
+            .unwrap()
+            .stdout,
+    )
+    .unwrap()
+    .trim()
+    .to_string();
+    harness
+        .wait_until(|h| h.get_status_bar().contains(&initial))
+        .unwrap();
+
+    let (col, row) = harness
+        .find_text_on_screen(&initial)
+        .expect("branch label should be in the status bar");
+    harness.mouse_click(col, row).unwrap();
+    harness.wait_for_screen_contains("Switch branch:").unwrap();
+    harness.wait_for_screen_contains("feature-x").unwrap();
+
+    harness.type_text("feature-x").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_until(|h| h.get_status_bar().contains("feature-x"))
+        .unwrap();
+
+    let head = git_command(&repo.path)
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&head.stdout).trim(), "feature-x");
+}
@@ -0,0 +1,89 @@
+//! E2E tests for the issue_links plugin
+//!
+//! Tests that `#N` and `org/repo#N` references in comments:
+//! - Are underlined once the repository's `origin` remote is resolved
+//! - Show their GitHub URL in the status bar when hovered with the mouse
+//!   or when the cursor moves onto them
+//! - Are ignored outside comments
+
+use crate::common::git_test_helper::{git_command, DirGuard, GitTestRepo};
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use ratatui::style::Modifier;
+use std::fs;
+
+fn harness_with_issue_links(content: &str) -> (GitTestRepo, DirGuard, EditorTestHarness) {
+    let repo = GitTestRepo::new();
+    let file = repo.create_file("src/lib.rs", content);
+    let output = git_command(&repo.path)
+        .args(["remote", "add", "origin", "git@github.com:acme/widgets.git"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let plugins_dir = repo.path.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    copy_plugin(&plugins_dir, "issue_links");
+
+    let original_dir = repo.change_to_repo_dir();
+    let guard = DirGuard::new(original_dir);
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        160,
+        24,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+    harness.open_file(&file).unwrap();
+    (repo, guard, harness)
+}
+
+/// Wait until the reference starting with `text` is underlined, i.e. the
+/// plugin resolved the remote and decorated the line. Returns its position.
+fn wait_for_link(harness: &mut EditorTestHarness, text: &str) -> (u16, u16) {
+    let (col, row) = harness.find_text_on_screen(text).unwrap();
+    harness
+        .wait_until(|h| {
+            h.get_cell_style(col, row)
+                .is_some_and(|s| s.add_modifier.contains(Modifier::UNDERLINED))
+        })
+        .unwrap();
+    (col, row)
+}
+
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_issue_reference_in_comment_links_to_remote() {
+    let (_repo, _guard, mut harness) =
+        harness_with_issue_links("// Fixes #12, see other/lib#7\nlet s = \"#5\";\n");
+
+    let (col, row) = wait_for_link(&mut harness, "#12");
+    harness.mouse_move(col + 1, row).unwrap();
+    harness
+        .wait_for_screen_contains("#12 → https://github.com/acme/widgets/issues/12")
+        .unwrap();
+
+    // Outside the comment, `#5` is left alone.
+    let (col, row) = harness.find_text_on_screen("#5").unwrap();
+    let style = harness.get_cell_style(col, row).unwrap();
+    assert!(!style.add_modifier.contains(Modifier::UNDERLINED));
+}
+
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_cursor_on_cross_repo_reference_shows_its_url() {
+    let (_repo, _guard, mut harness) = harness_with_issue_links("// see other/lib#7 too\n");
+
+    wait_for_link(&mut harness, "other/lib#7");
+    for _ in 0.."// see other".len() {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness
+        .wait_for_screen_contains("other/lib#7 → https://github.com/other/lib/issues/7")
+        .unwrap();
+}
@@ -0,0 +1,118 @@
+//! E2E tests for the `editor.spawnJob` plugin API
+//!
+//! Tests that:
+//! - Job output reaches `onStdout` line by line, stdin can be written and
+//!   closed, and the handle resolves with the exit code
+//! - A running job and its progress show in the `{jobs}` status-bar element
+//! - Cancelling a job from the jobs menu kills it and resolves its handle
+//!   with -1
+//! - A job that exits non-zero is reported in the status bar
+
+use crate::common::harness::{copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use std::fs;
+
+const JOBS_PLUGIN: &str = r#"
+const editor = getEditor();
+
+registerHandler("job_echo", function(): void {
+    const lines: string[] = [];
+    const job = editor.spawnJob(
+        "sh",
+        ["-c", "echo ready; read name; echo hello-$name"],
+        { title: "echo-job", stdin: true },
+    );
+    job.onStdout((line) => {
+        lines.push(line);
+        if (line === "ready") {
+            job.write("fresh\n");
+            job.closeStdin();
+        }
+    });
+    job.then((r) => editor.setStatus(`echo-exit:${r.exit_code}:${lines.join(",")}`));
+});
+
+registerHandler("job_slow", function(): void {
+    const job = editor.spawnJob("sh", ["-c", "sleep 30"], { title: "slow-job" });
+    job.progress("warming up", 25);
+    job.then((r) => editor.setStatus(`slow-exit:${r.exit_code}`));
+});
+
+registerHandler("job_fail", function(): void {
+    editor.spawnJob("sh", ["-c", "exit 3"], { title: "failing-job" });
+});
+
+editor.registerCommand("Test: Echo Job", "Run a job that reads stdin", "job_echo", null);
+editor.registerCommand("Test: Slow Job", "Run a job that sleeps", "job_slow", null);
+editor.registerCommand("Test: Failing Job", "Run a job that fails", "job_fail", null);
+editor.setStatus("jobs plugin loaded");
+"#;
+
+fn harness_with_jobs_plugin() -> (tempfile::TempDir, EditorTestHarness) {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    fs::write(plugins_dir.join("test_jobs.ts"), JOBS_PLUGIN).unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 24, Default::default(), project_root)
+            .unwrap();
+    harness
+        .wait_for_screen_contains("jobs plugin loaded")
+        .unwrap();
+    (temp_dir, harness)
+}
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_job_streams_output_and_takes_stdin() {
+    let (_temp, mut harness) = harness_with_jobs_plugin();
+
+    run_command(&mut harness, "Test: Echo Job");
+    harness
+        .wait_for_screen_contains("echo-exit:0:ready,hello-fresh")
+        .unwrap();
+    harness.assert_screen_not_contains("echo-job");
+}
+
+#[test]
+fn test_running_job_shows_progress_and_can_be_cancelled() {
+    let (_temp, mut harness) = harness_with_jobs_plugin();
+
+    run_command(&mut harness, "Test: Slow Job");
+    harness
+        .wait_for_screen_contains("slow-job: warming up 25%")
+        .unwrap();
+
+    run_command(&mut harness, "Show Running Jobs");
+    harness.assert_screen_contains("Running Jobs");
+    harness.assert_screen_contains("Cancel slow-job: warming up 25%");
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+
+    harness.wait_for_screen_contains("slow-exit:-1").unwrap();
+    harness.assert_screen_not_contains("slow-job");
+}
+
+#[test]
+fn test_failed_job_is_reported() {
+    let (_temp, mut harness) = harness_with_jobs_plugin();
+
+    run_command(&mut harness, "Test: Failing Job");
+    harness
+        .wait_for_screen_contains("Job 'failing-job' failed (exit code 3)")
+        .unwrap();
+}
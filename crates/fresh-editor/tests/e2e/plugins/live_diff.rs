@@ -1040,6 +1040,74 @@ fn test_live_diff_word_highlight_on_low_similarity_removed_added_pair() {
     );
 }
 
+/// `Live Diff: Highlight Unsaved Words` diffs the buffer against the file
+/// on disk, not HEAD, and shows only word emphasis: the typed word is
+/// bold + underlined, the untouched tokens on its line are not, and the
+/// line deleted since HEAD never shows up as an old-text virtual line.
+#[test]
+#[cfg_attr(target_os = "windows", ignore)]
+fn test_live_diff_soft_highlights_unsaved_words() {
+    let repo = GitTestRepo::new();
+    repo.setup_live_diff_plugin();
+
+    repo.create_file(
+        "note.txt",
+        "SHARED_HEAD_TOKEN SHARED_TAIL_TOKEN\nHEAD_ONLY_LINE_GONE\n",
+    );
+    repo.git_add(&["note.txt"]);
+    repo.git_commit("baseline");
+
+    let original_dir = repo.change_to_repo_dir();
+    let _guard = DirGuard::new(original_dir);
+
+    // Saved (on-disk) change vs HEAD: not an unsaved edit, so not shown.
+    repo.modify_file("note.txt", "SHARED_HEAD_TOKEN SHARED_TAIL_TOKEN\n");
+
+    let mut harness = EditorTestHarness::with_config_and_working_dir(
+        120,
+        40,
+        Config::default(),
+        repo.path.clone(),
+    )
+    .unwrap();
+
+    open_file(&mut harness, &repo.path, "note.txt");
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness
+        .type_text("Live Diff: Highlight Unsaved Words")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.type_text("TYPED_SOFT_WORD ").unwrap();
+    harness
+        .wait_until(|h| {
+            let buf = h.buffer();
+            find_text_cell(buf, "TYPED_SOFT_WORD")
+                .is_some_and(|(y, x)| is_word_diff_emphasized(buf, x, y))
+        })
+        .unwrap();
+
+    let buf = harness.buffer();
+    let (y, _) = find_text_cell(buf, "TYPED_SOFT_WORD").unwrap();
+    let (_, head_x) = find_text_cell(buf, "SHARED_HEAD_TOKEN").unwrap();
+    assert!(
+        !is_word_diff_emphasized(buf, head_x, y),
+        "untouched token on the edited line should NOT be bold + underlined",
+    );
+    let screen = harness.screen_to_string();
+    assert!(
+        !has_text(&screen, "HEAD_ONLY_LINE_GONE"),
+        "soft view must diff against disk and show no old-text lines. \
+         Screen:\n{screen}"
+    );
+}
+
 /// Regression (#2503): in `vs HEAD` mode, committing the buffer's changes to
 /// git must clear the diff automatically — *without* a manual
 /// `Live Diff: Refresh`. Before the fix the plugin cached the HEAD reference
@@ -31,6 +31,9 @@ pub mod diagnostics_panel_bugs;
 pub mod diagnostics_panel_jump;
 pub mod diff_cursor;
 pub mod env_manager;
+// Runs its fixture plugin under `python3`.
+#[cfg(unix)]
+pub mod external_plugins;
 pub mod file_explorer_slots;
 pub mod find_file;
 pub mod git;
@@ -41,12 +44,17 @@ pub mod git_statusbar;
 pub mod goto_with_selection;
 pub mod gutter;
 pub mod init_script;
+pub mod issue_links;
+// Runs its jobs under `sh`.
+#[cfg(unix)]
+pub mod jobs;
 pub mod language_pack;
 pub mod live_diff;
 pub mod load_from_buffer;
 pub mod lsp_find_references;
 pub mod lsp_navigation;
 pub mod markdown_source;
+pub mod multi_file_edit;
 pub mod orchestrator_attach_worktree;
 pub mod orchestrator_new_dialog;
 pub mod orchestrator_new_session_renders;
@@ -60,10 +68,13 @@ pub mod review_diff_hunk_parity;
 pub mod review_diff_line_staging;
 pub mod review_diff_ux_bugs;
 pub mod set_split_ratio_leaf;
+#[cfg(unix)]
+pub mod show_assembly;
 pub mod tab_actions;
 pub mod terminal_hooks;
 pub mod theme_editor;
 pub mod trust_lockdown;
+pub mod tutor;
 pub mod unified_keybindings;
 pub mod vi_mode_autostart;
 pub mod watch_path;
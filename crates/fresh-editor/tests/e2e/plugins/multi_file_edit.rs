@@ -0,0 +1,67 @@
+//! E2E tests for the multi-file edit plugin.
+//!
+//! The search results buffer is editable only inside the line texts: file
+//! headers and line-number gutters are read-only regions. Applying writes
+//! the changed lines back to their files.
+
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use std::fs;
+
+#[test]
+fn test_edits_limited_to_line_text_and_written_back() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let working_dir = temp.path().join("work");
+    let plugins_dir = working_dir.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    copy_plugin(&plugins_dir, "multi_file_edit");
+    let a = working_dir.join("a.txt");
+    fs::write(&a, "one needle\ntwo\n").unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(100, 30, Config::default(), working_dir)
+            .unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Multi-File Edit: Search").unwrap();
+    harness
+        .wait_for_screen_contains("Multi-File Edit: Search")
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("needle").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("1│ one needle").unwrap();
+
+    // The cursor starts on the file header, which is protected.
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("x").unwrap();
+    harness
+        .wait_for_screen_contains("Only the line text can be edit")
+        .unwrap();
+    harness.assert_screen_not_contains("ax.txt");
+
+    // The line text itself is editable.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text("s").unwrap();
+    harness.wait_for_screen_contains("1│ one needles").unwrap();
+
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .wait_until(|_| fs::read_to_string(&a).unwrap() == "one needles\ntwo\n")
+        .unwrap();
+}
@@ -1592,3 +1592,86 @@ editor.registerCommand(
         .wait_until(|h| h.screen_to_string().contains("[SearchActive=false]"))
         .expect("Command 3");
 }
+
+/// Test plugin read-only regions - typing or deleting inside a protected
+/// range is refused with the plugin's hint, while edits right after it
+/// still go through.
+#[test]
+fn test_plugin_read_only_region_refuses_edits() {
+    init_tracing_from_env();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir(&project_root).unwrap();
+
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+
+    let test_plugin = r#"
+const editor = getEditor();
+
+editor.registerCommand(
+    "Test: Protect Header",
+    "Make the header read-only",
+    "test_protect_header",
+    null
+);
+
+globalThis.test_protect_header = function(): void {
+    const bufferId = editor.getActiveBufferId();
+    editor.addReadOnlyRegion(bufferId, "test-ro", 0, 6, "Generated");
+    editor.setStatus("Protected");
+};
+"#;
+
+    fs::write(plugins_dir.join("test_read_only.ts"), test_plugin).unwrap();
+
+    let fixture = TestFixture::new("test.txt", "HEADER body\n").unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_config_and_working_dir(120, 24, Default::default(), project_root)
+            .unwrap();
+    harness.open_file(&fixture.path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Test: Protect Header").unwrap();
+    harness
+        .wait_until(|h| h.screen_to_string().contains("Protect Header"))
+        .unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("Protected").unwrap();
+
+    // Inside the header: typing and backspace are both refused.
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text("x").unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("HEADER body\n");
+    assert!(harness.screen_to_string().contains("Generated"));
+
+    // Right after the header: editing works and the region doesn't grow.
+    for _ in 0..3 {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text(":").unwrap();
+    harness
+        .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+        .unwrap();
+    harness.type_text("!").unwrap();
+    harness.render().unwrap();
+    harness.assert_buffer_content("HEADER! body\n");
+}
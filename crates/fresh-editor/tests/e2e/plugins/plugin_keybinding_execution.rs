@@ -27,6 +27,7 @@ fn test_plugin_command_executes_via_keybinding() {
         action: "marker_insert_action".to_string(),
         args: std::collections::HashMap::new(),
         when: None,
+        release: false,
     });
 
     let mut harness = EditorTestHarness::with_temp_project_and_config(120, 40, config).unwrap();
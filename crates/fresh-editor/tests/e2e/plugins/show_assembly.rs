@@ -0,0 +1,127 @@
+//! E2E tests for the show_assembly plugin.
+//!
+//! The compiler is replaced by a shell script that prints canned rustc
+//! output, so the tests cover filtering, demangling and source-line
+//! navigation without depending on the installed toolchain.
+
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, PluginConfig};
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use tempfile::TempDir;
+
+const SOURCE: &str = "pub fn add(a: u32, b: u32) -> u32 {\n    a + b\n}\n";
+
+const RUSTC_OUTPUT: &str = r#"	.file	"demo.cgu.0"
+	.section	.text._ZN4demo3add17h0123456789abcdefE,"ax",@progbits
+	.globl	_ZN4demo3add17h0123456789abcdefE
+	.p2align	4
+_ZN4demo3add17h0123456789abcdefE:
+.Lfunc_begin0:
+	.cfi_startproc
+	.file	1 "demo.rs"
+	.loc	1 2 5 prologue_end
+	leal	(%rdi,%rsi), %eax
+	.loc	1 3 2
+	retq
+.Lfunc_end0:
+	.cfi_endproc
+	.section	.debug_abbrev,"",@progbits
+	.byte	1
+"#;
+
+fn setup() -> (EditorTestHarness, TempDir, std::path::PathBuf) {
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project");
+    let plugins_dir = project_root.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    copy_plugin(&plugins_dir, "show_assembly");
+
+    let source_path = project_root.join("demo.rs");
+    fs::write(&source_path, SOURCE).unwrap();
+    fs::write(temp_dir.path().join("demo.s"), RUSTC_OUTPUT).unwrap();
+    let compiler = temp_dir.path().join("fake-rustc");
+    fs::write(
+        &compiler,
+        format!(
+            "#!/bin/sh\ncat '{}'\n",
+            temp_dir.path().join("demo.s").display()
+        ),
+    )
+    .unwrap();
+    fs::set_permissions(&compiler, fs::Permissions::from_mode(0o755)).unwrap();
+
+    let mut config = Config::default();
+    config.plugins.insert(
+        "show_assembly".to_string(),
+        PluginConfig {
+            enabled: true,
+            path: None,
+            settings: serde_json::json!({ "rustCompiler": compiler.display().to_string() }),
+        },
+    );
+    let harness =
+        EditorTestHarness::with_config_and_working_dir(120, 30, config, project_root).unwrap();
+    (harness, temp_dir, source_path)
+}
+
+fn show_assembly(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Show Assembly").unwrap();
+    harness.wait_for_screen_contains("Show Assembly").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("demo::add:").unwrap();
+}
+
+/// The listing keeps labels and instructions, demangles symbols and drops
+/// directives and debug sections.
+#[test]
+fn test_show_assembly_filters_and_demangles() {
+    let (mut harness, _temp_dir, source_path) = setup();
+    harness.open_file(&source_path).unwrap();
+    harness.render().unwrap();
+
+    show_assembly(&mut harness);
+
+    let screen = harness.screen_to_string();
+    assert!(screen.contains("leal    (%rdi,%rsi), %eax"), "{screen}");
+    assert!(screen.contains("retq"), "{screen}");
+    assert!(!screen.contains("_ZN4demo3add"), "{screen}");
+    assert!(!screen.contains(".cfi_startproc"), "{screen}");
+    assert!(!screen.contains("Lfunc_begin0"), "{screen}");
+    assert!(!screen.contains(".byte"), "{screen}");
+}
+
+/// Enter on an instruction jumps to the source line it was generated from.
+#[test]
+fn test_show_assembly_enter_jumps_to_source_line() {
+    let (mut harness, _temp_dir, source_path) = setup();
+    harness.open_file(&source_path).unwrap();
+    harness.render().unwrap();
+
+    show_assembly(&mut harness);
+
+    // Line 1 is the function label; line 2 is `leal`, from source line 2.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    let line_two = SOURCE.find('\n').unwrap() + 1;
+    harness
+        .wait_until(|h| {
+            h.editor()
+                .active_state()
+                .buffer
+                .file_path()
+                .is_some_and(|p| p == source_path.as_path())
+                && h.cursor_position() == line_two
+        })
+        .unwrap();
+}
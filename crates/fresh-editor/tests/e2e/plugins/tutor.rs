@@ -0,0 +1,115 @@
+//! E2E tests for the tutor plugin (interactive tutorial).
+//!
+//! Each lesson's exercise is solved with the keys the lesson teaches, and
+//! the tutorial must advance on its own once the buffer and cursors match.
+
+use crate::common::harness::{copy_plugin, copy_plugin_lib, EditorTestHarness};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use std::fs;
+
+fn harness_with_tutor() -> (tempfile::TempDir, EditorTestHarness) {
+    let temp = tempfile::TempDir::new().unwrap();
+    let working_dir = temp.path().join("work");
+    let plugins_dir = working_dir.join("plugins");
+    fs::create_dir_all(&plugins_dir).unwrap();
+    copy_plugin_lib(&plugins_dir);
+    copy_plugin(&plugins_dir, "tutor");
+
+    let harness =
+        EditorTestHarness::with_config_and_working_dir(100, 40, Config::default(), working_dir)
+            .unwrap();
+    (temp, harness)
+}
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness.wait_for_screen_contains(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+}
+
+fn press(harness: &mut EditorTestHarness, code: KeyCode, modifiers: KeyModifiers, times: usize) {
+    for _ in 0..times {
+        harness.send_key(code, modifiers).unwrap();
+    }
+}
+
+/// The cursor starts on the first exercise line; TARGET is twelve lines down.
+fn solve_navigation(harness: &mut EditorTestHarness) {
+    press(harness, KeyCode::Down, KeyModifiers::NONE, 12);
+    harness.wait_for_screen_contains("Lesson 2 of 4").unwrap();
+}
+
+#[test]
+fn test_tutorial_advances_through_every_lesson() {
+    let (_temp, mut harness) = harness_with_tutor();
+
+    run_command(&mut harness, "Tutor: Start Tutorial");
+    harness.wait_for_screen_contains("Lesson 1 of 4").unwrap();
+    harness.assert_screen_contains("TARGET");
+
+    solve_navigation(&mut harness);
+
+    // Multiple cursors: select the first `red`, add the other two, type.
+    press(&mut harness, KeyCode::End, KeyModifiers::NONE, 1);
+    press(
+        &mut harness,
+        KeyCode::Left,
+        KeyModifiers::CONTROL | KeyModifiers::SHIFT,
+        1,
+    );
+    press(&mut harness, KeyCode::Char('d'), KeyModifiers::CONTROL, 2);
+    harness.type_text("blue").unwrap();
+    harness.wait_for_screen_contains("Lesson 3 of 4").unwrap();
+
+    // Search for the needle.
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("needle").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("Lesson 4 of 4").unwrap();
+    // Leave the search prompt, if it stayed open, for the palette.
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+
+    // Select the fruit lines and sort them from the palette.
+    press(&mut harness, KeyCode::Down, KeyModifiers::SHIFT, 4);
+    run_command(&mut harness, "Sort Lines");
+    harness
+        .wait_for_screen_contains("Fresh Tutor — Finished")
+        .unwrap();
+    harness.assert_no_plugin_errors();
+}
+
+#[test]
+fn test_tutorial_resumes_at_checkpoint() {
+    let (_temp, mut harness) = harness_with_tutor();
+
+    run_command(&mut harness, "Tutor: Start Tutorial");
+    harness.wait_for_screen_contains("Lesson 1 of 4").unwrap();
+    solve_navigation(&mut harness);
+
+    // Close the tutor and start again: it picks up at lesson 2.
+    harness
+        .send_key(KeyCode::Char('w'), KeyModifiers::ALT)
+        .unwrap();
+    harness
+        .wait_until(|h| !h.screen_to_string().contains("*Tutor*"))
+        .unwrap();
+    run_command(&mut harness, "Tutor: Start Tutorial");
+    harness.wait_for_screen_contains("Lesson 2 of 4").unwrap();
+
+    // Restart goes back to the beginning.
+    run_command(&mut harness, "Tutor: Restart Tutorial");
+    harness.wait_for_screen_contains("Lesson 1 of 4").unwrap();
+}
@@ -0,0 +1,42 @@
+//! E2E test for portable mode (`--portable <dir>` / `FRESH_HOME`): the
+//! binary resolves every directory it owns under the portable root and
+//! leaves the home directory alone.
+
+use std::process::Command;
+use tempfile::TempDir;
+
+#[test]
+fn test_portable_mode_keeps_everything_under_root() {
+    let temp_dir = TempDir::new().unwrap();
+    let home = temp_dir.path().join("home");
+    std::fs::create_dir_all(&home).unwrap();
+    let root = temp_dir.path().join("stick");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_fresh"))
+        .arg("--portable")
+        .arg(&root)
+        .args(["--cmd", "config", "paths"])
+        .env("HOME", &home)
+        .env_remove("XDG_CONFIG_HOME")
+        .env_remove("XDG_DATA_HOME")
+        .env_remove("XDG_STATE_HOME")
+        .env_remove("XDG_CACHE_HOME")
+        .env_remove("FRESH_HOME")
+        .output()
+        .expect("failed to run fresh");
+    assert!(output.status.success(), "{:?}", output);
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let root = root.display().to_string();
+    for label in ["Portable:", "Config:", "Data:", "Logs:"] {
+        let line = stdout
+            .lines()
+            .find(|line| line.starts_with(label))
+            .unwrap_or_else(|| panic!("no {label} line in:\n{stdout}"));
+        assert!(line.contains(&root), "{label} not under {root}:\n{stdout}");
+    }
+    assert!(
+        std::fs::read_dir(&home).unwrap().next().is_none(),
+        "home directory was written to"
+    );
+}
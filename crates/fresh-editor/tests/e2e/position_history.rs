@@ -250,3 +250,54 @@ fn test_position_history_with_next_prev_buffer() {
     harness.send_key(KeyCode::Left, KeyModifiers::ALT).unwrap();
     harness.assert_buffer_content("Third");
 }
+
+/// Test that the navigation history popup lists visited locations with
+/// their line and text, and that picking one jumps there
+#[test]
+fn test_navigation_history_popup_jumps_to_picked_location() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let notes = temp_dir.path().join("notes.txt");
+    let todo = temp_dir.path().join("todo.txt");
+    let filler = "filler line\n".repeat(10);
+    std::fs::write(&notes, format!("notes start\n{filler}notes end")).unwrap();
+    std::fs::write(&todo, "buy milk").unwrap();
+
+    let mut harness =
+        EditorTestHarness::with_working_dir(100, 24, temp_dir.path().to_path_buf()).unwrap();
+    harness.open_file(&notes).unwrap();
+    // A large jump within the buffer becomes its own history entry
+    harness
+        .send_key(KeyCode::End, KeyModifiers::CONTROL)
+        .unwrap();
+    let notes_end = harness.cursor_position();
+    harness.open_file(&todo).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Show Navigation History").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Navigation History");
+    harness.assert_screen_contains("todo.txt:1 buy milk");
+    harness.assert_screen_contains("notes.txt:12 notes end");
+
+    // Newest first, with the current location selected: one row down is
+    // the jump to the end of notes.txt
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.assert_screen_not_contains("Navigation History");
+    assert!(harness
+        .get_buffer_content()
+        .unwrap()
+        .starts_with("notes start"));
+    assert_eq!(harness.cursor_position(), notes_end);
+
+    // Forward history survives the pick
+    harness.send_key(KeyCode::Right, KeyModifiers::ALT).unwrap();
+    harness.assert_buffer_content("buy milk");
+}
@@ -0,0 +1,87 @@
+//! Tests for presentation mode
+//!
+//! Tests that:
+//! - Presentation: Start draws the buffer in large type, page by page
+//! - Space and Backspace move between pages, Esc leaves the mode
+//! - The mode is read-only
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use tempfile::TempDir;
+
+fn start_presentation(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Presentation: Start").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+fn press(harness: &mut EditorTestHarness, code: KeyCode) {
+    harness.send_key(code, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_presentation_pages_through_buffer() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("slides.txt");
+    let content: String = (1..=12).map(|i| format!("line{i:02}\n")).collect();
+    std::fs::write(&file_path, &content).unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("line01");
+
+    // 23 rows above the footer fit 7 lines of 3-row glyphs: two pages.
+    start_presentation(&mut harness);
+    harness.assert_screen_contains("slides.txt — 1/2");
+    harness.assert_screen_contains("█");
+    harness.assert_screen_not_contains("line01");
+
+    press(&mut harness, KeyCode::Char(' '));
+    harness.assert_screen_contains("slides.txt — 2/2");
+    press(&mut harness, KeyCode::Char(' '));
+    harness.assert_screen_contains("slides.txt — 2/2");
+    press(&mut harness, KeyCode::Backspace);
+    harness.assert_screen_contains("slides.txt — 1/2");
+
+    // Keys that would edit the buffer do nothing while presenting.
+    press(&mut harness, KeyCode::Char('x'));
+    press(&mut harness, KeyCode::Delete);
+
+    press(&mut harness, KeyCode::Esc);
+    harness.assert_screen_not_contains("1/2");
+    harness.assert_screen_contains("line01");
+    assert_eq!(harness.get_buffer_content().unwrap(), content);
+}
+
+#[test]
+fn test_presentation_wide_scale_fits_more_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("slides.txt");
+    let content: String = (1..=12).map(|i| format!("line{i:02}\n")).collect();
+    std::fs::write(&file_path, &content).unwrap();
+
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    // At double width every line fits on one page, drawn in fullwidth
+    // characters. Read the glyph cells directly: the cell after each wide
+    // character is never redrawn by the test backend.
+    start_presentation(&mut harness);
+    press(&mut harness, KeyCode::Char('-'));
+    harness.assert_screen_contains("slides.txt — 1/1");
+    let last_line: String = (0..6)
+        .map(|i| harness.get_cell(i * 2, 11).unwrap())
+        .collect();
+    assert_eq!(last_line, "ｌｉｎｅ１２");
+
+    press(&mut harness, KeyCode::Char('+'));
+    harness.assert_screen_contains("slides.txt — 1/2");
+}
@@ -0,0 +1,85 @@
+//! "Preview Image" shows the image under the cursor, drawn with the
+//! terminal's graphics protocol, or a description where there is none.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::services::terminal_graphics::GraphicsProtocol;
+use fresh::services::terminal_probe::TerminalCapabilities;
+use fresh::view::color_support::ColorCapability;
+
+/// The start of a 64×32 PNG: enough for the header to be recognised.
+fn png_header() -> Vec<u8> {
+    let mut data = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR".to_vec();
+    data.extend_from_slice(&64u32.to_be_bytes());
+    data.extend_from_slice(&32u32.to_be_bytes());
+    data.extend_from_slice(&[8, 6, 0, 0, 0]);
+    data
+}
+
+fn harness_with_image_link() -> EditorTestHarness {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let dir = harness.project_dir().unwrap();
+    std::fs::write(dir.join("logo.png"), png_header()).unwrap();
+    let path = dir.join("README.md");
+    std::fs::write(&path, "![logo](logo.png)\n").unwrap();
+    harness.open_file(&path).unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+fn preview_image(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text("Preview Image").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn image_is_described_without_a_graphics_protocol() {
+    let mut harness = harness_with_image_link();
+    preview_image(&mut harness);
+
+    harness.assert_screen_contains("logo.png — 64×32 PNG");
+    harness.assert_screen_contains("This terminal can't display images.");
+    assert_eq!(harness.editor_mut().take_graphics_output(), None);
+}
+
+#[test]
+fn image_is_drawn_and_removed_with_the_kitty_protocol() {
+    let mut harness = harness_with_image_link();
+    let mut capabilities = TerminalCapabilities::unprobed(ColorCapability::TrueColor);
+    capabilities.graphics = Some(GraphicsProtocol::Kitty);
+    harness.editor_mut().set_terminal_capabilities(capabilities);
+
+    preview_image(&mut harness);
+    harness.assert_screen_contains("logo.png — 64×32 PNG");
+    let drawn = harness.editor_mut().take_graphics_output().unwrap();
+    let drawn = String::from_utf8_lossy(&drawn);
+    assert!(drawn.contains("\x1b_Ga=T,f=100"), "{drawn:?}");
+
+    // Nothing more to write while the popup stays put.
+    harness.render().unwrap();
+    assert_eq!(harness.editor_mut().take_graphics_output(), None);
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    let removed = harness.editor_mut().take_graphics_output().unwrap();
+    assert_eq!(removed, b"\x1b_Ga=d,q=2\x1b\\");
+}
+
+#[test]
+fn cursor_off_an_image_reports_nothing_to_preview() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let path = harness.project_dir().unwrap().join("notes.txt");
+    std::fs::write(&path, "no pictures here\n").unwrap();
+    harness.open_file(&path).unwrap();
+    harness.render().unwrap();
+
+    preview_image(&mut harness);
+    harness.assert_screen_contains("No image under the cursor");
+}
@@ -116,6 +116,7 @@ fn show_lsp_popup(harness: &mut EditorTestHarness) {
                         detail: Some("fn calculate_difference(a: i32, b: i32) -> i32".to_string()),
                         icon: Some("λ".to_string()),
                         data: Some(POPUP_MARKER.to_string()),
+                        match_positions: Vec::new(),
                     }],
                     selected: 0,
                 },
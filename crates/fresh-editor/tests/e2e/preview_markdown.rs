@@ -0,0 +1,44 @@
+//! "Markdown Preview" renders the active buffer in a popup.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn markdown_preview_renders_the_buffer() {
+    let mut harness = EditorTestHarness::with_temp_project(120, 40).unwrap();
+    let dir = harness.project_dir().unwrap();
+    let path = dir.join("notes.md");
+    std::fs::write(
+        &path,
+        "# Shopping\n\nSome **bold** words.\n\n1. apples\n2. pears\n   - ripe ones\n\n```rust\nfn main() {}\n```\n",
+    )
+    .unwrap();
+    harness.open_file(&path).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("**bold**");
+
+    run_command(&mut harness, "Markdown Preview");
+
+    harness.assert_screen_contains("notes.md");
+    harness.assert_screen_contains("Some bold words.");
+    harness.assert_screen_contains("1. apples");
+    harness.assert_screen_contains("2. pears");
+    harness.assert_screen_contains("• ripe ones");
+    harness.assert_screen_contains("fn main() {}");
+
+    harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("• ripe ones");
+}
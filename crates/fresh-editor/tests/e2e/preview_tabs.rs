@@ -20,6 +20,7 @@
 //! - With `file_explorer.preview_tabs = false`, single-click produces a
 //!   permanent tab.
 //! - Splitting the layout promotes the current preview.
+//! - The "Keep Tab Open" command promotes the current preview.
 
 use crate::common::harness::EditorTestHarness;
 use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
@@ -318,6 +319,38 @@ fn config_disabled_falls_back_to_permanent_open() {
     );
 }
 
+#[test]
+fn keep_tab_open_command_promotes_preview() {
+    let mut harness = setup_with_explorer(&["alpha.txt", "beta.txt"]);
+
+    single_click_file(&mut harness, "alpha.txt");
+    assert!(tab_bar(&harness).contains("(preview)"));
+
+    focus_editor(&mut harness);
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Keep Tab Open").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    let row = tab_bar(&harness);
+    assert!(
+        row.contains("alpha.txt") && !row.contains("(preview)"),
+        "Keep Tab Open must promote the preview to permanent; got:\n{row}"
+    );
+
+    // The kept tab survives the next preview open.
+    single_click_file(&mut harness, "beta.txt");
+    let row = tab_bar(&harness);
+    assert!(
+        row.contains("alpha.txt") && row.contains("beta.txt"),
+        "kept tab must coexist with the new preview; got:\n{row}"
+    );
+}
+
 #[test]
 fn splitting_the_layout_promotes_preview() {
     let mut harness = setup_with_explorer(&["alpha.txt"]);
@@ -143,8 +143,9 @@ impl FileSystem for ToggleRemoteFs {
         mode: u32,
         uid: u32,
         gid: u32,
+        elevate: &[String],
     ) -> io::Result<()> {
-        self.inner.sudo_write(path, data, mode, uid, gid)
+        self.inner.sudo_write(path, data, mode, uid, gid, elevate)
     }
     fn walk_files(
         &self,
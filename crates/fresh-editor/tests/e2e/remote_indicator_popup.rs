@@ -128,8 +128,9 @@ impl FileSystem for DisconnectedRemoteFs {
         mode: u32,
         uid: u32,
         gid: u32,
+        elevate: &[String],
     ) -> io::Result<()> {
-        self.inner.sudo_write(path, data, mode, uid, gid)
+        self.inner.sudo_write(path, data, mode, uid, gid, elevate)
     }
     fn walk_files(
         &self,
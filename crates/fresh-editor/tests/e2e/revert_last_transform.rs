@@ -0,0 +1,112 @@
+//! Tests for **Revert Last Transform**: backing out a format while keeping
+//! the edits made after it.
+//!
+//! The formatter is `sed`, which is POSIX-only — gated to Unix targets.
+
+#![cfg(unix)]
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, FormatterConfig, LanguageConfig};
+use std::fs;
+
+/// A "formatter" that puts spaces around every `=`.
+fn config_with_spacing_formatter() -> Config {
+    let mut config = Config::default();
+    let entry = config
+        .languages
+        .entry("rust".to_string())
+        .or_insert_with(LanguageConfig::default);
+    entry.formatter = Some(FormatterConfig {
+        command: "sed".to_string(),
+        // Arguments are joined into a `sh -c` command line, so quote the script.
+        args: vec!["'s/=/ = /g'".to_string()],
+        stdin: true,
+        timeout_ms: 10_000,
+    });
+    config
+}
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness.wait_for_screen_contains(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+}
+
+/// Status messages are truncated in the status bar at 80 columns, so check
+/// the raw message instead of the screen.
+fn assert_status_contains(harness: &EditorTestHarness, text: &str) {
+    let msg = harness
+        .editor()
+        .get_status_message()
+        .cloned()
+        .unwrap_or_default();
+    assert!(msg.contains(text), "expected status {text:?}, got: {msg:?}");
+}
+
+fn open_formatted(harness: &mut EditorTestHarness, tmp: &tempfile::TempDir) {
+    let path = tmp.path().join("sample.rs");
+    fs::write(&path, "x=1\n\n// note\n\ny=2\n").unwrap();
+    harness.open_file(&path).unwrap();
+    harness.render().unwrap();
+
+    run_command(harness, "Format Buffer");
+    harness
+        .wait_until(|h| h.get_buffer_content().as_deref() == Some("x = 1\n\n// note\n\ny = 2\n"))
+        .unwrap();
+}
+
+#[test]
+fn test_revert_last_transform_keeps_later_edits() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let mut harness =
+        EditorTestHarness::with_config(80, 24, config_with_spacing_formatter()).unwrap();
+    open_formatted(&mut harness, &tmp);
+
+    // Edit a line the formatter didn't touch.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text("!").unwrap();
+
+    run_command(&mut harness, "Revert Last Transform");
+
+    assert_eq!(
+        harness.get_buffer_content().as_deref(),
+        Some("x=1\n\n// note!\n\ny=2\n"),
+        "formatting should be reverted and the later edit kept"
+    );
+    assert_status_contains(&harness, "Reverted Format");
+
+    // The snapshot is consumed by the revert.
+    run_command(&mut harness, "Revert Last Transform");
+    assert_status_contains(&harness, "No transform to revert");
+}
+
+#[test]
+fn test_revert_last_transform_refuses_overlapping_edit() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let mut harness =
+        EditorTestHarness::with_config(80, 24, config_with_spacing_formatter()).unwrap();
+    open_formatted(&mut harness, &tmp);
+
+    // Edit a line the formatter rewrote.
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.type_text("0").unwrap();
+
+    run_command(&mut harness, "Revert Last Transform");
+
+    assert_eq!(
+        harness.get_buffer_content().as_deref(),
+        Some("x = 10\n\n// note\n\ny = 2\n"),
+        "a conflicting revert must leave the buffer untouched"
+    );
+    assert_status_contains(&harness, "Cannot revert Format");
+}
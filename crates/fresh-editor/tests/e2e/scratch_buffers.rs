@@ -0,0 +1,81 @@
+//! E2E tests for scratch buffers: created from the palette with a chosen
+//! language, never prompting for a path on quit, and kept in the session.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::config_io::DirectoryContext;
+use tempfile::TempDir;
+
+/// "New Scratch Buffer" from the palette asks for a language and opens a
+/// named buffer highlighted as that language.
+#[test]
+fn test_new_scratch_buffer_from_palette() {
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("New Scratch Buffer").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("New scratch buffer:");
+
+    harness.type_text("markdown").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("scratch-1");
+    assert_eq!(harness.editor().active_state().language, "markdown");
+
+    // A second scratch buffer gets the next free name.
+    harness.editor_mut().new_scratch_buffer("Plain Text");
+    harness.render().unwrap();
+    harness.assert_screen_contains("scratch-2");
+}
+
+/// Quitting with an edited scratch buffer doesn't prompt (not even with
+/// hot exit off), and the buffer comes back with its content and language
+/// on the next start.
+#[test]
+fn test_scratch_buffer_survives_restart_without_prompt() {
+    let temp_dir = TempDir::new().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir(&project_dir).unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+
+    let options = || {
+        let mut config = Config::default();
+        config.editor.hot_exit = false;
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(project_dir.clone())
+            .with_shared_dir_context(dir_context.clone())
+            .without_empty_plugins_dir()
+    };
+
+    {
+        let mut harness = EditorTestHarness::create(100, 24, options()).unwrap();
+        harness.editor_mut().new_scratch_buffer("Markdown");
+        harness.type_text("# scratch notes").unwrap();
+        harness.render().unwrap();
+
+        harness.editor_mut().quit();
+        assert!(harness.should_quit(), "quit should not prompt for a path");
+        harness.shutdown(true).unwrap();
+    }
+
+    {
+        let mut harness = EditorTestHarness::create(100, 24, options()).unwrap();
+        let restored = harness.startup(true, &[]).unwrap();
+        assert!(restored, "Session should have been restored");
+        harness.render().unwrap();
+        harness.assert_screen_contains("scratch-1");
+        harness.assert_screen_contains("# scratch notes");
+        assert_eq!(harness.editor().active_state().language, "markdown");
+    }
+}
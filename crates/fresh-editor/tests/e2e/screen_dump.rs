@@ -0,0 +1,72 @@
+//! E2E tests for the Dump Screen To File command.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn start_dump_screen(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("Dump Screen To File").unwrap();
+    harness.render().unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("Dump screen to").unwrap();
+}
+
+/// Accepting the pre-filled name writes a plain-text dump of the screen
+/// into the working directory.
+#[test]
+fn test_dump_screen_writes_plain_text() {
+    let mut harness = EditorTestHarness::with_temp_project(100, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file_path = project_dir.join("notes.txt");
+    std::fs::write(&file_path, "screen dump marker\n").unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    start_dump_screen(&mut harness);
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("Screen dumped to")
+        .unwrap();
+
+    let dump = std::fs::read_to_string(project_dir.join("fresh-screen.txt")).unwrap();
+    assert!(dump.contains("screen dump marker"), "dump was:\n{dump}");
+    assert!(!dump.contains('\x1b'), "plain-text dump has escapes");
+    assert_eq!(dump.lines().count(), 24);
+}
+
+/// A `.ans` target gets SGR color escapes alongside the text.
+#[test]
+fn test_dump_screen_ans_extension_writes_ansi() {
+    let mut harness = EditorTestHarness::with_temp_project(100, 24).unwrap();
+    let project_dir = harness.project_dir().unwrap();
+    let file_path = project_dir.join("notes.txt");
+    std::fs::write(&file_path, "screen dump marker\n").unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    start_dump_screen(&mut harness);
+    for _ in 0.."fresh-screen.txt".len() {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text("screen.ans").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("Screen dumped to")
+        .unwrap();
+
+    let dump = std::fs::read_to_string(project_dir.join("screen.ans")).unwrap();
+    assert!(dump.contains("\x1b[0;"), "dump has no SGR escapes");
+    // The word under the cursor is highlighted, so only the rest of the
+    // line is a single styled run.
+    assert!(dump.contains("dump marker"), "dump was:\n{dump}");
+}
@@ -0,0 +1,233 @@
+//! E2E tests for the scroll settings
+//!
+//! Tests that:
+//! - `mouse_wheel_scroll_lines` sets how far one wheel notch scrolls
+//! - `scroll_past_end` lets the last line scroll up to the top row
+//! - `typewriter_scrolling` keeps the cursor line on one screen row, also
+//!   when lines wrap
+//! - `smooth_scroll` plays a wheel scroll out over several frames, and a key
+//!   press stops it
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, TypewriterScrolling};
+use std::time::Duration;
+
+fn harness_with(config: Config) -> EditorTestHarness {
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    let content: Vec<String> = (0..100).map(|i| format!("line {i:02}")).collect();
+    harness.load_buffer_from_text(&content.join("\n")).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness
+}
+
+/// Text of the first content row on screen.
+fn top_row(harness: &EditorTestHarness) -> String {
+    harness.get_screen_row(harness.content_area_rows().0)
+}
+
+fn wheel_down(harness: &mut EditorTestHarness, notches: usize) {
+    for _ in 0..notches {
+        harness.mouse_scroll_down(40, 10).unwrap();
+    }
+}
+
+#[test]
+fn test_wheel_scroll_lines_is_configurable() {
+    let mut config = Config::default();
+    config.editor.mouse_wheel_scroll_lines = 5;
+    let mut harness = harness_with(config);
+
+    wheel_down(&mut harness, 1);
+    assert!(
+        top_row(&harness).contains("line 05"),
+        "{}",
+        top_row(&harness)
+    );
+}
+
+#[test]
+fn test_last_line_stops_at_bottom_by_default() {
+    let mut config = Config::default();
+    config.editor.line_wrap = false;
+    let mut harness = harness_with(config);
+
+    wheel_down(&mut harness, 50);
+    harness.assert_screen_contains("line 99");
+    harness.assert_screen_not_contains("~");
+}
+
+#[test]
+fn test_scroll_past_end_brings_last_line_to_top() {
+    for line_wrap in [false, true] {
+        let mut config = Config::default();
+        config.editor.scroll_past_end = true;
+        config.editor.line_wrap = line_wrap;
+        let mut harness = harness_with(config);
+
+        wheel_down(&mut harness, 50);
+        assert!(
+            top_row(&harness).contains("line 99"),
+            "line_wrap={line_wrap}: {}",
+            top_row(&harness)
+        );
+        harness.assert_screen_not_contains("line 98");
+    }
+}
+
+/// Screen row of the hardware cursor, relative to the first content row.
+fn cursor_content_row(harness: &mut EditorTestHarness) -> usize {
+    harness.render().unwrap();
+    harness.screen_cursor_position().1 as usize - harness.content_area_rows().0
+}
+
+/// Middle row of the content area, relative to its first row.
+fn middle_content_row(harness: &EditorTestHarness) -> usize {
+    let (first, last) = harness.content_area_rows();
+    (last - first) / 2
+}
+
+#[test]
+fn test_typewriter_scrolling_keeps_cursor_on_middle_row() {
+    for line_wrap in [false, true] {
+        let mut config = Config::default();
+        config.editor.line_wrap = line_wrap;
+        config.editor.typewriter_scrolling = TypewriterScrolling::Center;
+        let mut harness = harness_with(config);
+        let middle = middle_content_row(&harness);
+
+        for _ in 0..middle {
+            harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        }
+        for step in 0..30 {
+            harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+            assert_eq!(
+                cursor_content_row(&mut harness),
+                middle,
+                "line_wrap={line_wrap}, down step {step}"
+            );
+        }
+        harness.type_text("typed").unwrap();
+        assert_eq!(cursor_content_row(&mut harness), middle);
+        for step in 0..10 {
+            harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+            assert_eq!(
+                cursor_content_row(&mut harness),
+                middle,
+                "line_wrap={line_wrap}, up step {step}"
+            );
+        }
+
+        // The last line still reaches the middle row, leaving the rows
+        // below it empty.
+        harness
+            .send_key(KeyCode::End, KeyModifiers::CONTROL)
+            .unwrap();
+        assert_eq!(
+            cursor_content_row(&mut harness),
+            middle,
+            "line_wrap={line_wrap}"
+        );
+        assert!(
+            top_row(&harness).contains(&format!("line {:02}", 99 - middle)),
+            "line_wrap={line_wrap}: {}",
+            top_row(&harness)
+        );
+    }
+}
+
+#[test]
+fn test_typewriter_scrolling_counts_wrapped_rows() {
+    let mut config = Config::default();
+    config.editor.line_wrap = true;
+    config.editor.typewriter_scrolling = TypewriterScrolling::Center;
+    let mut harness = EditorTestHarness::with_config(40, 24, config).unwrap();
+    // Every line wraps into two screen rows.
+    let content: Vec<String> = (0..60)
+        .map(|i| format!("line {i:02} {}", "word ".repeat(8)))
+        .collect();
+    harness.load_buffer_from_text(&content.join("\n")).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    let middle = middle_content_row(&harness);
+
+    for _ in 0..middle {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    for step in 0..20 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+        assert_eq!(cursor_content_row(&mut harness), middle, "down step {step}");
+    }
+    for step in 0..10 {
+        harness.send_key(KeyCode::Up, KeyModifiers::NONE).unwrap();
+        assert_eq!(cursor_content_row(&mut harness), middle, "up step {step}");
+    }
+}
+
+#[test]
+fn test_typewriter_scrolling_offset_uses_scroll_offset_row() {
+    let mut config = Config::default();
+    config.editor.line_wrap = false;
+    config.editor.scroll_offset = 4;
+    config.editor.typewriter_scrolling = TypewriterScrolling::Offset;
+    let mut harness = harness_with(config);
+
+    for _ in 0..20 {
+        harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    }
+    assert_eq!(cursor_content_row(&mut harness), 4);
+    assert!(
+        top_row(&harness).contains("line 16"),
+        "{}",
+        top_row(&harness)
+    );
+}
+
+#[test]
+fn test_smooth_scroll_eases_over_several_frames() {
+    let mut config = Config::default();
+    config.editor.smooth_scroll = true;
+    config.editor.mouse_wheel_scroll_lines = 8;
+    let mut harness = harness_with(config);
+
+    // Each frame covers half of what is left: 4, 2, 1, 1.
+    wheel_down(&mut harness, 1);
+    assert!(
+        top_row(&harness).contains("line 04"),
+        "{}",
+        top_row(&harness)
+    );
+    for expected in ["line 06", "line 07", "line 08"] {
+        assert!(harness.editor().next_periodic_redraw_deadline().is_some());
+        harness.advance_time(Duration::from_millis(16));
+        harness.tick_and_render().unwrap();
+        assert!(
+            top_row(&harness).contains(expected),
+            "{}",
+            top_row(&harness)
+        );
+    }
+    assert!(harness.editor().next_periodic_redraw_deadline().is_none());
+}
+
+#[test]
+fn test_key_press_stops_smooth_scroll() {
+    let mut config = Config::default();
+    config.editor.smooth_scroll = true;
+    config.editor.mouse_wheel_scroll_lines = 8;
+    let mut harness = harness_with(config);
+
+    wheel_down(&mut harness, 1);
+    harness
+        .send_key(KeyCode::Right, KeyModifiers::NONE)
+        .unwrap();
+    let top = top_row(&harness);
+
+    harness.advance_time(Duration::from_millis(16));
+    harness.tick_and_render().unwrap();
+    assert_eq!(top_row(&harness), top);
+}
@@ -3111,3 +3111,111 @@ fn test_clear_search_action() {
         .wait_until(|h| h.count_search_highlights() == 0)
         .expect("Search should be cleared");
 }
+
+/// While typing the query, the status bar counts the matches and says which
+/// one is at or after the cursor.
+#[test]
+fn test_search_prompt_shows_match_count_while_typing() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "foo one\nfoo two\nbar\nfoo three\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    // Put the cursor on the second line, past the first match.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("foo").unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        harness.editor().get_status_message().map(String::as_str),
+        Some("Match 2 of 3")
+    );
+    assert!(harness.count_search_highlights() > 0);
+
+    harness.type_text("x").unwrap();
+    assert_eq!(
+        harness.editor().get_status_message().map(String::as_str),
+        Some("No occurrences of 'foox' found.")
+    );
+}
+
+/// With `keep_search_highlights` off, confirming a search drops the
+/// highlights, but Find Next still steps through the matches.
+#[test]
+fn test_search_highlights_not_kept_after_prompt_closes() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "foo one\nfoo two\nfoo three\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.keep_search_highlights = false;
+    let mut harness = EditorTestHarness::with_config(100, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("foo").unwrap();
+    harness.render().unwrap();
+    // Highlights still show while typing.
+    assert!(harness.count_search_highlights() > 0);
+
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.process_async_and_render().unwrap();
+    assert_eq!(harness.count_search_highlights(), 0);
+
+    harness.send_key(KeyCode::F(3), KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+    assert_eq!(harness.cursor_position(), 8);
+    assert_eq!(harness.count_search_highlights(), 0);
+}
+
+/// With Multiline on, a plain-text query reads `\n` as a newline, so a
+/// match can span lines.
+#[test]
+fn test_multiline_plain_text_search_spans_lines() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.txt");
+    std::fs::write(&file_path, "fn a()\n{\n}\nfn b() {}\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("[ ] Multiline");
+    harness
+        .send_key(KeyCode::Char('n'), KeyModifiers::ALT)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("[x] Multiline");
+
+    harness.type_text(r"()\n{").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.process_async_and_render().unwrap();
+    assert_eq!(
+        harness.editor().get_status_message().map(String::as_str),
+        Some(r"Found 1 match for '()\n{'")
+    );
+    assert_eq!(harness.cursor_position(), 4);
+
+    // The mode sticks for the next search.
+    harness
+        .send_key(KeyCode::Char('f'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("[x] Multiline");
+}
@@ -0,0 +1,49 @@
+//! E2E tests for the Serve Buffer command (read-only live view over HTTP).
+
+use crate::common::harness::EditorTestHarness;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+
+/// GET `path` from the share server at `url` and return the response body.
+fn http_get(url: &str, path: &str) -> String {
+    let host = url
+        .trim_start_matches("http://")
+        .trim_end_matches('/')
+        .to_string();
+    let mut stream = TcpStream::connect(&host).unwrap();
+    write!(stream, "GET {path} HTTP/1.1\r\nHost: {host}\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+        .split_once("\r\n\r\n")
+        .map(|(_, body)| body.to_string())
+        .unwrap_or_default()
+}
+
+/// The served view shows the buffer and follows edits to it.
+#[test]
+fn test_serve_buffer_follows_edits() {
+    let mut harness = EditorTestHarness::with_temp_project(160, 24).unwrap();
+    let file_path = harness.project_dir().unwrap().join("notes.txt");
+    std::fs::write(&file_path, "shared line\n").unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.editor_mut().serve_buffer();
+    harness.render().unwrap();
+
+    let url = harness
+        .editor()
+        .buffer_share_url()
+        .expect("server should be running");
+    harness.assert_screen_contains(&url);
+    assert!(http_get(&url, "/content").contains("shared line"));
+    assert!(http_get(&url, "/").contains("<title>notes.txt</title>"));
+
+    harness.type_text("typed & live").unwrap();
+    harness
+        .wait_until(|_| http_get(&url, "/content").contains("typed &amp; live"))
+        .unwrap();
+
+    harness.editor_mut().stop_serving_buffer();
+    assert!(harness.editor().buffer_share_url().is_none());
+    assert!(TcpStream::connect(url.trim_start_matches("http://").trim_end_matches('/')).is_err());
+}
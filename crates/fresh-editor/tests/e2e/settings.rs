@@ -1093,10 +1093,10 @@ fn test_settings_from_terminal_mode_captures_input() {
     harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
     harness.render().unwrap();
 
-    // Editor category has settings organized by sections - Completion section comes first
+    // Editor category has settings organized by sections - Bracket Matching comes first
     // If Down key worked in Settings, we should now be viewing Editor settings
-    // Check for a setting in the visible Completion section
-    harness.assert_screen_contains("Quick Suggestions");
+    // Check for a setting in the visible Bracket Matching section
+    harness.assert_screen_contains("Highlight Matching Brackets");
 
     // Clean up - close settings
     harness.send_key(KeyCode::Esc, KeyModifiers::NONE).unwrap();
@@ -2281,8 +2281,21 @@ fn test_entry_dialog_focus_indicator() {
     harness.assert_screen_contains("Key:");
 
     // The focused field should have a ">" indicator
-    // First editable field (Auto Close) should be focused by default
+    // First editable field (the Abbreviations map) should be focused by
+    // default, on its "[+] Add new" row
     // (fields are sorted alphabetically within a language entry dialog)
+    let screen = harness.screen_to_string();
+    assert!(
+        screen.contains(">    [+] Add new"),
+        "Focus indicator '>' should appear before the Abbreviations add row. Screen:\n{}",
+        screen
+    );
+
+    // Navigate down to next editable field
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.render().unwrap();
+
+    // Now "Auto Close" should be focused with ">" indicator
     // Format: ">  " or ">● " (3-char indicator area: focus, modified, space)
     let screen = harness.screen_to_string();
     assert!(
@@ -2331,8 +2344,8 @@ fn test_entry_dialog_add_new_textlist_item() {
     harness.assert_screen_contains("Edit Value");
 
     // Navigate to Extensions section which has "[+] Add new"
-    // Fields in order: Key, Auto Indent, Comment Prefix, Extensions (3 downs)
-    for _ in 0..3 {
+    // Fields in order: Key, Abbreviations, Auto Indent, Comment Prefix, Extensions (4 downs)
+    for _ in 0..4 {
         harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
     }
     harness.render().unwrap();
@@ -109,11 +109,13 @@ fn test_settings_text_field_click_positions_cursor() {
     );
 }
 
-/// Screen (col, row) of the first `[+] Add new` affordance, scanning by
-/// character to step over multibyte box-drawing glyphs.
-fn find_add_new(harness: &EditorTestHarness) -> Option<(u16, u16)> {
+/// Screen (col, row) of the first `[+] Add new` affordance below the row
+/// showing `label`, scanning by character to step over multibyte
+/// box-drawing glyphs.
+fn find_add_new(harness: &EditorTestHarness, label: &str) -> Option<(u16, u16)> {
     let height = harness.buffer().area.height;
-    for y in 0..height {
+    let start = (0..height).find(|&y| harness.get_row_text(y).contains(label))?;
+    for y in start..height {
         let chars: Vec<char> = harness.get_row_text(y).chars().collect();
         let needle: Vec<char> = "[+] Add new".chars().collect();
         if let Some(c) = (0..chars.len().saturating_sub(needle.len().saturating_sub(1)))
@@ -146,7 +148,7 @@ fn test_settings_entry_dialog_text_click_positions_cursor() {
         .unwrap();
     harness.render().unwrap();
 
-    let (ac, ar) = find_add_new(&harness).unwrap_or_else(|| {
+    let (ac, ar) = find_add_new(&harness, "Keybinding Maps:").unwrap_or_else(|| {
         panic!(
             "no '[+] Add new' on the Keybindings page.\nScreen:\n{}",
             harness.screen_to_string()
@@ -0,0 +1,200 @@
+//! Tests for user snippets and tab-stop navigation.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::{Config, SmartTabBehavior};
+use fresh::config_io::DirectoryContext;
+
+/// A harness whose config dir holds `rust.json` with `snippets`, editing an
+/// empty Rust file.
+fn harness_with_rust_snippets(snippets: &str) -> (EditorTestHarness, tempfile::TempDir) {
+    harness_with_rust_snippets_and_config(snippets, Config::default())
+}
+
+fn harness_with_rust_snippets_and_config(
+    snippets: &str,
+    config: Config,
+) -> (EditorTestHarness, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+    let snippets_dir = dir_context.snippets_dir();
+    std::fs::create_dir_all(&snippets_dir).unwrap();
+    std::fs::write(snippets_dir.join("rust.json"), snippets).unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir_all(&project_dir).unwrap();
+    let file = project_dir.join("main.rs");
+    std::fs::write(&file, "").unwrap();
+
+    let mut harness = EditorTestHarness::create(
+        160,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(project_dir)
+            .with_shared_dir_context(dir_context)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+    harness.open_file(&file).unwrap();
+    harness.render().unwrap();
+    (harness, temp_dir)
+}
+
+fn insert_snippet(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Insert Snippet").unwrap();
+    harness.wait_for_screen_contains("Insert Snippet").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("Insert snippet:").unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+}
+
+#[test]
+fn test_snippet_tab_stops_and_mirrors() {
+    let (mut harness, _temp) = harness_with_rust_snippets(
+        r#"{
+            "Function": {
+                "prefix": "fn",
+                "body": ["fn ${1:name}() -> ${2:u32} {", "    $0", "}", "// $1"]
+            }
+        }"#,
+    );
+
+    insert_snippet(&mut harness, "Function");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "fn name() -> u32 {\n    \n}\n// name"
+    );
+    assert_eq!(harness.get_selected_text(), "name");
+
+    // Typing fills the placeholder and its mirror.
+    harness.type_text("run").unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "fn run() -> u32 {\n    \n}\n// run"
+    );
+
+    // Tab selects the next tab stop; Shift+Tab goes back.
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.get_selected_text(), "u32");
+    harness
+        .send_key(KeyCode::BackTab, KeyModifiers::SHIFT)
+        .unwrap();
+    assert_eq!(harness.get_selected_text(), "run");
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.type_text("i64").unwrap();
+
+    // Tab past the last stop lands on $0 and ends the snippet.
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    harness.type_text("0").unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "fn run() -> i64 {\n    0\n}\n// run"
+    );
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "fn run() -> i64 {\n    0    \n}\n// run"
+    );
+}
+
+#[test]
+fn test_insert_snippet_without_snippets_reports_language() {
+    let (mut harness, _temp) = harness_with_rust_snippets("{}");
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Insert Snippet").unwrap();
+    harness.wait_for_screen_contains("Insert Snippet").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness
+        .wait_for_screen_contains("No snippets defined for rust")
+        .unwrap();
+}
+
+const FOR_SNIPPET: &str = r#"{
+    "For loop": { "prefix": "for", "body": ["for ${1:item} in ${2:iter} {", "    $0", "}"] }
+}"#;
+
+fn smart_tab_config(behaviors: &[SmartTabBehavior]) -> Config {
+    let mut config = Config::default();
+    config.editor.smart_tab = behaviors.to_vec();
+    config
+}
+
+/// Tab right after a snippet prefix expands the snippet, and the next Tab
+/// moves through its tab stops.
+#[test]
+fn test_smart_tab_expands_snippet_prefix() {
+    let (mut harness, _temp) = harness_with_rust_snippets_and_config(
+        FOR_SNIPPET,
+        smart_tab_config(&[
+            SmartTabBehavior::ExpandSnippet,
+            SmartTabBehavior::NextTabStop,
+            SmartTabBehavior::InsertIndent,
+        ]),
+    );
+
+    harness.type_text("for").unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "for item in iter {\n    \n}"
+    );
+    assert_eq!(harness.get_selected_text(), "item");
+
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.get_selected_text(), "iter");
+}
+
+/// Behaviors left out of `editor.smart_tab` never run: with only
+/// `insert_indent`, Tab after a prefix just indents.
+#[test]
+fn test_smart_tab_respects_configured_behaviors() {
+    let (mut harness, _temp) = harness_with_rust_snippets_and_config(
+        FOR_SNIPPET,
+        smart_tab_config(&[SmartTabBehavior::InsertIndent]),
+    );
+
+    harness.type_text("for").unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "for    ");
+
+    // Without `indent_selection`, Tab over a selection does nothing.
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::SHIFT)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "for    ");
+}
+
+/// With the default behaviors, Tab indents a selection and inserts
+/// indentation elsewhere.
+#[test]
+fn test_smart_tab_indents_selection_by_default() {
+    let (mut harness, _temp) = harness_with_rust_snippets(FOR_SNIPPET);
+
+    harness.type_text("a\nb").unwrap();
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "    a\n    b");
+
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Tab, KeyModifiers::NONE).unwrap();
+    assert_eq!(harness.get_buffer_content().unwrap(), "    a\n    b    ");
+}
@@ -5,7 +5,8 @@
 
 use crate::common::harness::{EditorTestHarness, HarnessOptions};
 use crossterm::event::{KeyCode, KeyModifiers};
-use fresh::config::{Config, StatusBarConfig, StatusBarElement};
+use fresh::config::{Config, StatusBarConfig, StatusBarElement, StatusBarSegment};
+use ratatui::style::{Color, Modifier};
 use std::fs;
 
 /// Helper: create a config with the given status bar elements.
@@ -403,3 +404,47 @@ fn test_both_sides_empty() {
         "No cursor info expected.\nStatus bar: {status}"
     );
 }
+
+/// `{mode}` shows the editor mode a modal-editing plugin sets, and nothing
+/// when no mode is set.
+#[test]
+fn test_mode_element_shows_editor_mode() {
+    let config = config_with_status_bar(vec![StatusBarElement::Mode], vec![]);
+    let mut harness = EditorTestHarness::with_temp_project_and_config(120, 30, config).unwrap();
+    harness.render().unwrap();
+    assert!(!harness.get_status_bar().contains("vi-normal"));
+
+    harness.editor_mut().active_window_mut().editor_mode = Some("vi-normal".to_string());
+    harness.render().unwrap();
+    let status = harness.get_status_bar();
+    assert!(
+        status.contains("vi-normal"),
+        "Mode element should show the editor mode.\nStatus bar: {status}"
+    );
+}
+
+/// A `segments` entry restyles its element and replaces its click action.
+#[test]
+fn test_segment_style_and_click_action() {
+    let mut config = config_with_status_bar(vec![StatusBarElement::Cursor], vec![]);
+    config.editor.status_bar.segments.insert(
+        "{cursor}".to_string(),
+        StatusBarSegment {
+            fg: Some("#102030".to_string()),
+            bold: true,
+            on_click: Some("show_messages".to_string()),
+            ..Default::default()
+        },
+    );
+    let mut harness = EditorTestHarness::with_temp_project_and_config(120, 30, config).unwrap();
+    harness.render().unwrap();
+
+    let (x, y) = harness.find_text_on_screen("Ln 1, Col 1").unwrap();
+    let style = harness.get_cell_style(x, y).unwrap();
+    assert_eq!(style.fg, Some(Color::Rgb(0x10, 0x20, 0x30)));
+    assert!(style.add_modifier.contains(Modifier::BOLD));
+
+    harness.mouse_click(x, y).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("*Messages*");
+}
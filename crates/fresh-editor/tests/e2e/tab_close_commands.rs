@@ -0,0 +1,91 @@
+//! E2E tests for the tab close commands (Close Other Tabs, Close Tabs to
+//! the Right, Close Saved Tabs) and Reopen Closed Tab.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn run_command(harness: &mut EditorTestHarness, command: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text(command).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+/// Harness with one file per name opened in order, the last one active.
+fn harness_with_files(names: &[&str]) -> EditorTestHarness {
+    let mut harness = EditorTestHarness::with_temp_project(120, 30).unwrap();
+    let dir = harness.project_dir().unwrap();
+    for name in names {
+        let path = dir.join(format!("{name}.txt"));
+        std::fs::write(&path, format!("{name} content\n")).unwrap();
+        harness.open_file(&path).unwrap();
+    }
+    harness.render().unwrap();
+    harness
+}
+
+#[test]
+fn test_close_other_tabs() {
+    let mut harness = harness_with_files(&["alpha", "bravo", "charlie"]);
+
+    run_command(&mut harness, "Close Other Tabs");
+    harness.assert_screen_contains("charlie.txt");
+    harness.assert_screen_not_contains("alpha.txt");
+    harness.assert_screen_not_contains("bravo.txt");
+    assert_eq!(harness.get_buffer_content().unwrap(), "charlie content\n");
+}
+
+/// Closing to the right and closing saved tabs both leave tabs with unsaved
+/// changes open.
+#[test]
+fn test_close_tabs_to_right_and_saved_tabs_keep_modified() {
+    let mut harness = harness_with_files(&["alpha", "bravo", "charlie", "delta"]);
+    let dir = harness.project_dir().unwrap();
+    harness.open_file(&dir.join("charlie.txt")).unwrap();
+    harness.type_text("edited ").unwrap();
+    harness.open_file(&dir.join("bravo.txt")).unwrap();
+    harness.render().unwrap();
+
+    run_command(&mut harness, "Close Tabs to the Right");
+    harness.assert_screen_not_contains("delta.txt");
+    harness.assert_screen_contains("charlie.txt");
+    harness.assert_screen_contains("alpha.txt");
+
+    run_command(&mut harness, "Close Saved Tabs");
+    harness.assert_screen_not_contains("alpha.txt");
+    harness.assert_screen_not_contains("bravo.txt");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "edited charlie content\n"
+    );
+}
+
+/// Reopen Closed Tab brings files back newest first, each with the cursor
+/// where it was.
+#[test]
+fn test_reopen_closed_tab_restores_cursor() {
+    let mut harness = harness_with_files(&["alpha", "bravo", "charlie"]);
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    let charlie_cursor = harness.cursor_position();
+    assert_ne!(charlie_cursor, 0);
+
+    run_command(&mut harness, "Close Tab");
+    run_command(&mut harness, "Close Tab");
+    harness.assert_screen_not_contains("charlie.txt");
+    assert_eq!(harness.get_buffer_content().unwrap(), "alpha content\n");
+
+    run_command(&mut harness, "Reopen Closed Tab");
+    assert_eq!(harness.get_buffer_content().unwrap(), "bravo content\n");
+
+    run_command(&mut harness, "Reopen Closed Tab");
+    assert_eq!(harness.get_buffer_content().unwrap(), "charlie content\n");
+    assert_eq!(harness.cursor_position(), charlie_cursor);
+
+    run_command(&mut harness, "Reopen Closed Tab");
+    harness.assert_screen_contains("No closed tabs to reopen");
+}
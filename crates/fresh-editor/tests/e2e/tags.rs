@@ -0,0 +1,124 @@
+//! Tests for navigation with a ctags `tags` file.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+
+const LIB: &str = "// helpers\n\npub fn helper() -> u32 {\n    1\n}\n";
+const MAIN: &str = "fn main() {\n    helper();\n}\n";
+
+/// A project with `main.rs` calling `helper` from `lib.rs`, and `tags`
+/// (if given) at its root. `main.rs` is open.
+fn harness_with_project(
+    tags: Option<&str>,
+    config: Config,
+) -> (EditorTestHarness, tempfile::TempDir) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let project_dir = temp_dir.path().join("project");
+    std::fs::create_dir_all(&project_dir).unwrap();
+    std::fs::write(project_dir.join("lib.rs"), LIB).unwrap();
+    let main = project_dir.join("main.rs");
+    std::fs::write(&main, MAIN).unwrap();
+    if let Some(tags) = tags {
+        std::fs::write(project_dir.join("tags"), tags).unwrap();
+    }
+
+    let mut harness = EditorTestHarness::create(
+        120,
+        24,
+        HarnessOptions::new()
+            .with_config(config)
+            .with_working_dir(project_dir),
+    )
+    .unwrap();
+    harness.open_file(&main).unwrap();
+    harness.render().unwrap();
+    (harness, temp_dir)
+}
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness.wait_for_screen_contains(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+}
+
+#[test]
+fn test_goto_definition_uses_tags_file_without_lsp() {
+    let (mut harness, _temp) = harness_with_project(
+        Some("helper\tlib.rs\t/^pub fn helper() -> u32 {$/;\"\tf\n"),
+        Config::default(),
+    );
+
+    // Cursor onto `helper` in `main.rs`.
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::End, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness.send_key(KeyCode::Left, KeyModifiers::NONE).unwrap();
+    harness
+        .send_key(KeyCode::F(12), KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), LIB);
+    assert_eq!(
+        harness.cursor_position(),
+        LIB.find("fn helper").unwrap() + 3
+    );
+}
+
+#[test]
+fn test_goto_tag_prompt_searches_tags() {
+    let (mut harness, _temp) = harness_with_project(
+        Some("helper\tlib.rs\t3;\"\tf\nmain\tmain.rs\t1;\"\tf\n"),
+        Config::default(),
+    );
+
+    run_command(&mut harness, "Go to Tag");
+    harness.wait_for_screen_contains("Go to tag:").unwrap();
+    harness.type_text("help").unwrap();
+    harness.wait_for_screen_contains("lib.rs").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+
+    assert_eq!(harness.get_buffer_content().unwrap(), LIB);
+    assert_eq!(
+        harness.cursor_position(),
+        LIB.find("fn helper").unwrap() + 3
+    );
+}
+
+#[test]
+fn test_goto_tag_without_tags_file_reports_it() {
+    let (mut harness, _temp) = harness_with_project(None, Config::default());
+
+    run_command(&mut harness, "Go to Tag");
+    harness
+        .wait_for_screen_contains("No tags file in the project root")
+        .unwrap();
+}
+
+#[test]
+fn test_generate_tags_runs_tags_command() {
+    let mut config = Config::default();
+    config.editor.tags_command = vec![
+        "sh".to_string(),
+        "-c".to_string(),
+        "printf 'helper\\tlib.rs\\t3;\"\\tf\\n' > tags".to_string(),
+    ];
+    let (mut harness, temp) = harness_with_project(None, config);
+
+    run_command(&mut harness, "Generate Tags");
+    harness.wait_for_screen_contains("Tags generated").unwrap();
+    let tags = std::fs::read_to_string(temp.path().join("project").join("tags")).unwrap();
+    assert!(tags.starts_with("helper\tlib.rs\t3;\""));
+}
@@ -0,0 +1,80 @@
+//! Tests for telemetry consent and the privacy commands.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness.wait_for_screen_contains(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_consent_prompt_records_answer() {
+    let mut harness = EditorTestHarness::new(160, 24).unwrap();
+    assert_eq!(harness.editor().config().telemetry, None);
+
+    harness.editor_mut().maybe_prompt_telemetry_consent();
+    harness.render().unwrap();
+    harness.assert_screen_contains("Send anonymous usage data");
+    harness.type_text("n").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    assert_eq!(harness.editor().config().telemetry, Some(false));
+
+    // Answered: not asked again.
+    harness.editor_mut().maybe_prompt_telemetry_consent();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Send anonymous usage data");
+}
+
+#[test]
+fn test_toggle_commands_flip_settings() {
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+
+    run_command(&mut harness, "Toggle Telemetry");
+    assert_eq!(harness.editor().config().telemetry, Some(true));
+    harness.assert_screen_contains("Telemetry on");
+    run_command(&mut harness, "Toggle Telemetry");
+    assert_eq!(harness.editor().config().telemetry, Some(false));
+
+    let checks = harness.editor().config().check_for_updates;
+    run_command(&mut harness, "Toggle Update Check");
+    assert_eq!(harness.editor().config().check_for_updates, !checks);
+}
+
+#[test]
+fn test_show_telemetry_data_lists_payload() {
+    let mut harness = EditorTestHarness::new(120, 40).unwrap();
+
+    run_command(&mut harness, "Show Telemetry Data");
+    harness.assert_screen_contains("*Telemetry Data*");
+    harness.assert_screen_contains("not decided yet");
+    harness.assert_screen_contains("\"version\"");
+    harness.assert_screen_contains("<random ID");
+}
+
+#[test]
+fn test_no_upgrade_check_survives_config_reload() {
+    let mut harness = EditorTestHarness::new(120, 24).unwrap();
+    harness.editor_mut().set_no_upgrade_check(true);
+
+    harness.editor_mut().reload_config();
+    assert_eq!(harness.editor().config().telemetry, Some(false));
+    assert!(!harness.editor().config().check_for_updates);
+
+    // The override counts as an answer, so consent is not asked for.
+    harness.editor_mut().maybe_prompt_telemetry_consent();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("Send anonymous usage data");
+}
@@ -0,0 +1,52 @@
+//! The startup terminal capability probe: replies to the query turn off
+//! features the terminal lacks, and "Terminal Capabilities" shows the findings.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh_input_parser::TerminalReply;
+
+#[test]
+fn unsupported_mouse_is_turned_off_once_the_terminal_answers() {
+    let mut harness = EditorTestHarness::new(180, 30).unwrap();
+    harness.render().unwrap();
+    assert!(harness.editor().is_mouse_enabled());
+
+    harness
+        .editor_mut()
+        .apply_terminal_reply(TerminalReply::Mode {
+            mode: 1000,
+            status: 0,
+        });
+    harness
+        .editor_mut()
+        .apply_terminal_reply(TerminalReply::Mode {
+            mode: 2004,
+            status: 1,
+        });
+    assert!(
+        harness.editor().is_mouse_enabled(),
+        "nothing is turned off before the terminal has answered in full"
+    );
+
+    harness
+        .editor_mut()
+        .apply_terminal_reply(TerminalReply::DeviceAttributes { sixel: false });
+    harness.render().unwrap();
+    assert!(!harness.editor().is_mouse_enabled());
+    harness.assert_screen_contains("mouse support turned off");
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.type_text("Terminal Capabilities").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+
+    harness.assert_screen_contains("Mouse reporting: no");
+    harness.assert_screen_contains("Bracketed paste: yes");
+    harness.assert_screen_contains("Kitty keyboard protocol: no");
+    harness.assert_screen_contains("mouse support");
+}
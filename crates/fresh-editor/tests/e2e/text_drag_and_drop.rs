@@ -0,0 +1,159 @@
+//! Tests for dragging a selection with the mouse
+//!
+//! Tests that:
+//! - Dragging a selection moves it, as one undo step
+//! - Holding Ctrl at the drop copies instead of moving
+//! - A plain click inside the selection still just places the cursor
+//! - Text can be dragged from one split's buffer into another's
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use tempfile::TempDir;
+
+fn mouse(
+    harness: &mut EditorTestHarness,
+    kind: MouseEventKind,
+    (column, row): (u16, u16),
+    modifiers: KeyModifiers,
+) {
+    harness
+        .send_mouse(MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers,
+        })
+        .unwrap();
+}
+
+/// Press at `from`, drag to `to`, release there with `modifiers` held.
+fn drag_and_drop(
+    harness: &mut EditorTestHarness,
+    from: (u16, u16),
+    to: (u16, u16),
+    modifiers: KeyModifiers,
+) {
+    mouse(
+        harness,
+        MouseEventKind::Down(MouseButton::Left),
+        from,
+        KeyModifiers::NONE,
+    );
+    mouse(
+        harness,
+        MouseEventKind::Drag(MouseButton::Left),
+        to,
+        KeyModifiers::NONE,
+    );
+    mouse(
+        harness,
+        MouseEventKind::Up(MouseButton::Left),
+        to,
+        modifiers,
+    );
+    harness.render().unwrap();
+}
+
+/// Load `content` and select its first `len` characters.
+fn harness_with_selection(content: &str, len: usize) -> EditorTestHarness {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    harness.load_buffer_from_text(content).unwrap();
+    harness
+        .send_key(KeyCode::Home, KeyModifiers::CONTROL)
+        .unwrap();
+    for _ in 0..len {
+        harness
+            .send_key(KeyCode::Right, KeyModifiers::SHIFT)
+            .unwrap();
+    }
+    harness.render().unwrap();
+    harness
+}
+
+/// Screen cell just past the end of `text`.
+fn end_of(harness: &EditorTestHarness, text: &str) -> (u16, u16) {
+    let (col, row) = harness.find_text_on_screen(text).unwrap();
+    (col + text.len() as u16, row)
+}
+
+#[test]
+fn test_drag_selection_moves_it_as_one_undo_step() {
+    let mut harness = harness_with_selection("one two three", 3);
+    let from = harness.find_text_on_screen("one").unwrap();
+    let to = end_of(&harness, "three");
+
+    drag_and_drop(&mut harness, (from.0 + 1, from.1), to, KeyModifiers::NONE);
+    harness.assert_buffer_content(" two threeone");
+
+    // The dropped text is selected: typing replaces it.
+    harness.type_text("1").unwrap();
+    harness.assert_buffer_content(" two three1");
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.assert_buffer_content("one two three");
+}
+
+#[test]
+fn test_drag_selection_with_ctrl_copies_it() {
+    let mut harness = harness_with_selection("one two three", 3);
+    let from = harness.find_text_on_screen("one").unwrap();
+    let to = end_of(&harness, "three");
+
+    drag_and_drop(&mut harness, from, to, KeyModifiers::CONTROL);
+    harness.assert_buffer_content("one two threeone");
+}
+
+#[test]
+fn test_drop_inside_selection_changes_nothing() {
+    let mut harness = harness_with_selection("one two three", 3);
+    let (col, row) = harness.find_text_on_screen("one").unwrap();
+
+    drag_and_drop(&mut harness, (col, row), (col + 2, row), KeyModifiers::NONE);
+    harness.assert_buffer_content("one two three");
+}
+
+#[test]
+fn test_click_inside_selection_places_cursor() {
+    let mut harness = harness_with_selection("one two three", 3);
+    let (col, row) = harness.find_text_on_screen("one").unwrap();
+
+    harness.mouse_click(col + 1, row).unwrap();
+    harness.type_text("X").unwrap();
+    harness.assert_buffer_content("oXne two three");
+}
+
+#[test]
+fn test_drag_selection_into_another_split() {
+    let temp_dir = TempDir::new().unwrap();
+    let source = temp_dir.path().join("source.txt");
+    let target = temp_dir.path().join("target.txt");
+    std::fs::write(&source, "moved text\n").unwrap();
+    std::fs::write(&target, "dropzone\n").unwrap();
+
+    let mut harness = EditorTestHarness::new(100, 24).unwrap();
+    harness.open_file(&source).unwrap();
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text("split vert").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.open_file(&target).unwrap();
+    harness.render().unwrap();
+
+    // Select "moved" in the left split.
+    let (col, row) = harness.find_text_on_screen("moved").unwrap();
+    harness.mouse_drag(col, row, col + 5, row).unwrap();
+
+    let to = end_of(&harness, "dropzone");
+    drag_and_drop(&mut harness, (col + 1, row), to, KeyModifiers::NONE);
+    harness.assert_buffer_content("dropzonemoved\n");
+
+    harness.mouse_click(col, row).unwrap();
+    harness.assert_buffer_content(" text\n");
+}
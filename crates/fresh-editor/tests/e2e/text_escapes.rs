@@ -0,0 +1,64 @@
+//! Tests for the escape conversion commands and Describe Character.
+
+use crate::common::harness::EditorTestHarness;
+use crossterm::event::{KeyCode, KeyModifiers};
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text(name).unwrap();
+    harness.wait_for_screen_contains(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+}
+
+fn select_all(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+}
+
+#[test]
+fn test_unicode_escapes_round_trip_through_selection() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("smile \\u{1F600}").unwrap();
+
+    select_all(&mut harness);
+    run_command(&mut harness, "Unescape Unicode");
+    assert_eq!(harness.get_buffer_content().unwrap(), "smile 😀");
+
+    select_all(&mut harness);
+    run_command(&mut harness, "Escape Unicode");
+    assert_eq!(harness.get_buffer_content().unwrap(), "smile \\u{1F600}");
+}
+
+#[test]
+fn test_conversions_ignore_cursors_without_selection() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("a%20b &amp;").unwrap();
+
+    run_command(&mut harness, "URL-Decode");
+    run_command(&mut harness, "Decode HTML Entities");
+    assert_eq!(harness.get_buffer_content().unwrap(), "a%20b &amp;");
+
+    select_all(&mut harness);
+    run_command(&mut harness, "URL-Decode");
+    assert_eq!(harness.get_buffer_content().unwrap(), "a b &amp;");
+}
+
+#[test]
+fn test_describe_character_popup() {
+    let mut harness = EditorTestHarness::new(80, 24).unwrap();
+    let _fixture = harness.load_buffer_from_text("😀").unwrap();
+
+    run_command(&mut harness, "Describe Character Under Cursor");
+    harness.render().unwrap();
+    harness.assert_screen_contains("U+1F600");
+    harness.assert_screen_contains("GRINNING FACE");
+    harness.assert_screen_contains("F0 9F 98 80");
+    harness.assert_screen_contains("So (Other Symbol)");
+}
@@ -919,3 +919,117 @@ fn test_theme_selector_strips_url_scheme_from_display() {
 
     drop(temp_dir);
 }
+
+fn ember_theme_toml(bg: [u8; 3]) -> String {
+    format!(
+        "name = \"Ember\"\n\n[editor]\nbg = [{}, {}, {}]\n",
+        bg[0], bg[1], bg[2]
+    )
+}
+
+/// Preview Themes live-applies the chosen theme for the session but leaves
+/// the persisted config alone.
+#[test]
+fn test_preview_themes_applies_for_session_without_persisting() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+
+    let themes_dir = temp_dir.path().join("config").join("themes");
+    fs::create_dir_all(&themes_dir).unwrap();
+    fs::write(
+        themes_dir.join("ember.toml"),
+        ember_theme_toml([60, 20, 10]),
+    )
+    .unwrap();
+
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir_all(project_root.join("plugins")).unwrap();
+
+    let mut harness = EditorTestHarness::create(
+        120,
+        40,
+        HarnessOptions::new()
+            .with_working_dir(project_root)
+            .with_shared_dir_context(dir_context)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+    harness.render().unwrap();
+
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Preview Themes").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_screen_contains("Preview theme").unwrap();
+
+    for _ in 0..20 {
+        harness
+            .send_key(KeyCode::Backspace, KeyModifiers::NONE)
+            .unwrap();
+    }
+    harness.type_text("ember").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    harness.render().unwrap();
+
+    let bg = harness.get_cell_style(5, 2).and_then(|s| s.bg);
+    assert_eq!(bg, Some(Color::Rgb(60, 20, 10)));
+    harness.assert_screen_contains("Previewing theme 'Ember'");
+
+    let config_path = temp_dir.path().join("config").join("config.json");
+    let saved = fs::read_to_string(&config_path).unwrap_or_default();
+    assert!(
+        !saved.contains("ember"),
+        "Preview Themes must not persist the theme. Config: {}",
+        saved
+    );
+}
+
+/// Editing the active user theme file on disk re-applies it without a
+/// restart.
+#[test]
+fn test_user_theme_file_hot_reloads() {
+    let temp_dir = TempDir::new().unwrap();
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+
+    let themes_dir = temp_dir.path().join("config").join("themes");
+    fs::create_dir_all(&themes_dir).unwrap();
+    let theme_path = themes_dir.join("ember.toml");
+    fs::write(&theme_path, ember_theme_toml([60, 20, 10])).unwrap();
+
+    let project_root = temp_dir.path().join("project_root");
+    fs::create_dir_all(project_root.join("plugins")).unwrap();
+
+    let mut harness = EditorTestHarness::create(
+        120,
+        40,
+        HarnessOptions::new()
+            .with_config(Config {
+                theme: "ember.toml".into(),
+                ..Default::default()
+            })
+            .with_working_dir(project_root)
+            .with_shared_dir_context(dir_context)
+            .without_empty_plugins_dir(),
+    )
+    .unwrap();
+    harness.render().unwrap();
+    assert_eq!(
+        harness.get_cell_style(5, 2).and_then(|s| s.bg),
+        Some(Color::Rgb(60, 20, 10))
+    );
+
+    // Different length as well as contents, so the change is visible even on
+    // filesystems with coarse mtimes.
+    fs::write(&theme_path, ember_theme_toml([100, 60, 20])).unwrap();
+    harness
+        .wait_until(|h| h.get_cell_style(5, 2).and_then(|s| s.bg) == Some(Color::Rgb(100, 60, 20)))
+        .unwrap();
+    harness.assert_screen_contains("Themes reloaded");
+}
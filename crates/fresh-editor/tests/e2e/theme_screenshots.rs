@@ -638,6 +638,7 @@ fn scene_completion_popup(h: &mut EditorTestHarness, s: &mut BlogShowcase) {
         detail: Some(detail.to_string()),
         icon: Some(icon.to_string()),
         data: Some(text.to_string()),
+        match_positions: Vec::new(),
     };
     h.apply_event(Event::ShowPopup {
         popup: PopupData {
@@ -752,6 +752,7 @@ fn test_switch_keybinding_map_via_action_persists() {
                 serde_json::Value::String("vscode".to_string()),
             )]),
             when: None,
+            release: false,
         });
 
         let mut harness = EditorTestHarness::create(
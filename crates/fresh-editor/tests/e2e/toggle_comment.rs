@@ -3,6 +3,8 @@
 //! Tests that:
 //! - Toggle comment uses language-specific comment prefixes from config
 //! - Selection is preserved after commenting/uncommenting
+//! - Indented ranges keep their shape and mixed ranges are commented whole
+//! - Block comments wrap a selection or line, and stand in for line comments
 
 use crate::common::harness::{EditorTestHarness, HarnessOptions};
 use crossterm::event::{KeyCode, KeyModifiers};
@@ -390,3 +392,103 @@ fn test_toggle_comment_selection_at_buffer_end() {
         content
     );
 }
+
+/// Comments go at the block's smallest indentation, blank lines are skipped,
+/// and a partly commented range is commented as a whole.
+#[test]
+fn test_toggle_comment_indented_mixed_range() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.rs");
+    let original = "    // a();
+
+        b();
+";
+    std::fs::write(&file_path, original).unwrap();
+
+    let mut harness =
+        EditorTestHarness::create(80, 24, HarnessOptions::new().with_config(Config::default()))
+            .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    run_command(&mut harness, "Toggle Comment");
+
+    let content = harness.get_buffer_content().unwrap();
+    assert_eq!(
+        content,
+        "    // // a();
+
+    //     b();
+"
+    );
+
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    run_command(&mut harness, "Toggle Comment");
+    assert_eq!(harness.get_buffer_content().unwrap(), original);
+}
+
+/// Toggle Block Comment wraps the current line's content and unwraps it again.
+#[test]
+fn test_toggle_block_comment_current_line() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.c");
+    std::fs::write(
+        &file_path,
+        "int a;
+    call(a);
+",
+    )
+    .unwrap();
+
+    let mut harness =
+        EditorTestHarness::create(80, 24, HarnessOptions::new().with_config(Config::default()))
+            .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.send_key(KeyCode::Down, KeyModifiers::NONE).unwrap();
+    run_command(&mut harness, "Toggle Block Comment");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "int a;
+    /* call(a); */
+"
+    );
+
+    run_command(&mut harness, "Toggle Block Comment");
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "int a;
+    call(a);
+"
+    );
+}
+
+/// Languages without a line comment wrap each line in block comment tokens.
+#[test]
+fn test_toggle_comment_html_uses_block_tokens() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("test.html");
+    std::fs::write(
+        &file_path,
+        "<p>
+  hi
+</p>",
+    )
+    .unwrap();
+
+    let mut harness =
+        EditorTestHarness::create(80, 24, HarnessOptions::new().with_config(Config::default()))
+            .unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness
+        .send_key(KeyCode::Char('a'), KeyModifiers::CONTROL)
+        .unwrap();
+    run_command(&mut harness, "Toggle Comment");
+
+    assert_eq!(
+        harness.get_buffer_content().unwrap(),
+        "<!-- <p> -->\n<!--   hi -->\n<!-- </p> -->"
+    );
+}
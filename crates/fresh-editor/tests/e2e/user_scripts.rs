@@ -0,0 +1,127 @@
+//! E2E tests for user scripts: `.rhai` files in the config dir's `scripts/`.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+use fresh::config_io::DirectoryContext;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+/// Harness whose config dir holds `scripts`, with `notes.txt` open.
+fn harness_with_scripts(scripts: &[(&str, &str)]) -> (TempDir, PathBuf, EditorTestHarness) {
+    let temp_dir = TempDir::new().unwrap();
+    let project_root = temp_dir.path().join("project");
+    fs::create_dir_all(&project_root).unwrap();
+
+    let dir_context = DirectoryContext::for_testing(temp_dir.path());
+    let scripts_dir = dir_context.config_dir.join("scripts");
+    fs::create_dir_all(&scripts_dir).unwrap();
+    for (name, source) in scripts {
+        fs::write(scripts_dir.join(name), source).unwrap();
+    }
+
+    let file = project_root.join("notes.txt");
+    fs::write(&file, "hello world\n").unwrap();
+
+    let mut harness = EditorTestHarness::create(
+        160,
+        24,
+        HarnessOptions::new()
+            .with_config(Config::default())
+            .with_working_dir(project_root)
+            .with_shared_dir_context(dir_context),
+    )
+    .unwrap();
+    harness.open_file(&file).unwrap();
+    (temp_dir, scripts_dir, harness)
+}
+
+fn run_command(harness: &mut EditorTestHarness, name: &str) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.type_text(name).unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.render().unwrap();
+}
+
+const TITLE_CASE: &str = r#"
+register_command("Title Case", "Capitalize every word", "title_case");
+
+fn title_case() {
+    let text = buffer_text();
+    let start = 0;
+    let at_word_start = true;
+    for ch in text.chars() {
+        let len = ch.to_string().bytes;
+        if at_word_start && ch >= 'a' && ch <= 'z' {
+            delete(start, start + len);
+            insert(start, ch.to_string().to_upper());
+        }
+        at_word_start = ch == ' ' || ch == '\n';
+        start += len;
+    }
+    set_status("title-cased");
+}
+"#;
+
+/// A command a script registers shows in the palette and edits the buffer.
+#[test]
+fn test_script_command_edits_active_buffer() {
+    let (_temp, _scripts, mut harness) = harness_with_scripts(&[("title_case.rhai", TITLE_CASE)]);
+
+    run_command(&mut harness, "Title Case");
+    harness.wait_for_screen_contains("title-cased").unwrap();
+    harness.assert_screen_contains("Hello World");
+
+    // All of the command's edits undo in one step.
+    harness
+        .send_key(KeyCode::Char('z'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("hello world");
+}
+
+/// `on_save` runs with the saved file's path and can edit its buffer.
+#[test]
+fn test_script_on_save_hook() {
+    let (_temp, _scripts, mut harness) = harness_with_scripts(&[(
+        "stamp.rhai",
+        r#"
+        fn on_save(path) {
+            if path.ends_with("notes.txt") {
+                insert(buffer_len(), "-- saved\n");
+            }
+        }
+        "#,
+    )]);
+
+    harness.type_text("more ").unwrap();
+    harness
+        .send_key(KeyCode::Char('s'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_screen_contains("-- saved").unwrap();
+}
+
+/// "Scripts: Reload" picks up new scripts and reports ones that fail.
+#[test]
+fn test_reload_scripts_reports_errors() {
+    let (_temp, scripts_dir, mut harness) = harness_with_scripts(&[]);
+
+    fs::write(scripts_dir.join("title_case.rhai"), TITLE_CASE).unwrap();
+    run_command(&mut harness, "Scripts: Reload");
+    harness
+        .wait_for_screen_contains("Loaded 1 script(s)")
+        .unwrap();
+    run_command(&mut harness, "Title Case");
+    harness.wait_for_screen_contains("Hello World").unwrap();
+
+    fs::write(scripts_dir.join("broken.rhai"), "fn oops( {").unwrap();
+    run_command(&mut harness, "Scripts: Reload");
+    harness
+        .wait_for_screen_contains("Script broken.rhai failed to load")
+        .unwrap();
+}
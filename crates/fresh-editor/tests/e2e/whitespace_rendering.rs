@@ -0,0 +1,74 @@
+//! Tests for line-ending indicators and trailing-whitespace highlighting.
+
+use crate::common::harness::EditorTestHarness;
+use fresh::config::Config;
+use tempfile::TempDir;
+
+/// `whitespace_line_endings` draws a ↵ at the end of each line; the master
+/// `whitespace_show` switch hides it along with the other indicators.
+#[test]
+fn test_line_ending_indicators_follow_master_toggle() {
+    let mut config = Config::default();
+    config.editor.whitespace_line_endings = true;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config.clone()).unwrap();
+    harness.new_buffer().unwrap();
+    harness.type_text("alpha\nbeta").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("alpha↵");
+
+    config.editor.whitespace_show = false;
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.new_buffer().unwrap();
+    harness.type_text("alpha\nbeta").unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_not_contains("↵");
+}
+
+/// Trailing spaces get the theme's `trailing_whitespace_bg`; spaces between
+/// words do not.
+#[test]
+fn test_trailing_whitespace_is_highlighted() {
+    let mut config = Config::default();
+    config.editor.whitespace_highlight_trailing = true;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.new_buffer().unwrap();
+    harness.type_text("one two  \nnext").unwrap();
+    harness.render().unwrap();
+
+    let expected = harness.editor().theme().trailing_whitespace_bg;
+    let (col, row) = harness.find_text_on_screen("one two").unwrap();
+    let inner = harness.get_cell_style(col + 3, row).unwrap();
+    assert_ne!(
+        inner.bg,
+        Some(expected),
+        "inner space must not be highlighted"
+    );
+    for offset in [7, 8] {
+        let style = harness.get_cell_style(col + offset, row).unwrap();
+        assert_eq!(style.bg, Some(expected), "trailing space at +{offset}");
+    }
+}
+
+/// A trailing tab is highlighted across every column it expands to.
+#[test]
+fn test_trailing_tab_is_highlighted() {
+    let temp_dir = TempDir::new().unwrap();
+    let file_path = temp_dir.path().join("tabs.txt");
+    std::fs::write(&file_path, "word\t\nnext\n").unwrap();
+
+    let mut config = Config::default();
+    config.editor.whitespace_highlight_trailing = true;
+
+    let mut harness = EditorTestHarness::with_config(80, 24, config).unwrap();
+    harness.open_file(&file_path).unwrap();
+    harness.render().unwrap();
+
+    let expected = harness.editor().theme().trailing_whitespace_bg;
+    let (col, row) = harness.find_text_on_screen("word").unwrap();
+    for offset in 4..8 {
+        let style = harness.get_cell_style(col + offset, row).unwrap();
+        assert_eq!(style.bg, Some(expected), "trailing tab column at +{offset}");
+    }
+}
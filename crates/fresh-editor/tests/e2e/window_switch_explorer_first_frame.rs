@@ -44,6 +44,7 @@ fn launch_harness(
         action: "next_window".to_string(),
         args: std::collections::HashMap::new(),
         when: None,
+        release: false,
     });
     EditorTestHarness::create(
         120,
@@ -0,0 +1,78 @@
+//! Tests for Zen mode: no chrome, centered soft-wrapped text, and the
+//! prior layout restored when it is turned off.
+
+use crate::common::harness::{EditorTestHarness, HarnessOptions};
+use crossterm::event::{KeyCode, KeyModifiers};
+use fresh::config::Config;
+
+const LONG_LINE: &str = "one two three four five six seven eight nine ten eleven twelve \
+                         thirteen fourteen fifteen sixteen";
+
+fn toggle_zen_mode(harness: &mut EditorTestHarness) {
+    harness
+        .send_key(KeyCode::Char('p'), KeyModifiers::CONTROL)
+        .unwrap();
+    harness.wait_for_prompt().unwrap();
+    harness.type_text("Toggle Zen Mode").unwrap();
+    harness.wait_for_screen_contains("Toggle Zen Mode").unwrap();
+    harness
+        .send_key(KeyCode::Enter, KeyModifiers::NONE)
+        .unwrap();
+    harness.wait_for_prompt_closed().unwrap();
+    harness.render().unwrap();
+}
+
+#[test]
+fn test_zen_mode_centers_wrapped_text_and_restores_layout() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let file = temp_dir.path().join("notes.txt");
+    std::fs::write(&file, format!("Title\n{}\n", LONG_LINE)).unwrap();
+
+    let mut config = Config::default();
+    config.editor.line_wrap = false;
+    config.editor.zen_mode_width = 40;
+    let mut harness =
+        EditorTestHarness::create(100, 24, HarnessOptions::new().with_config(config)).unwrap();
+    harness.open_file(&file).unwrap();
+    harness.render().unwrap();
+    harness.assert_screen_contains("notes.txt");
+
+    toggle_zen_mode(&mut harness);
+
+    // No menu, tab or status bar: the text starts on the first row,
+    // centered in a 40-column column with no gutter. The vertical
+    // scrollbar keeps the last screen column, leaving 99 to center in.
+    harness.assert_screen_not_contains("notes.txt");
+    harness.assert_screen_not_contains("File");
+    let first_row = harness.get_row_text(0);
+    assert_eq!(
+        first_row.find("Title"),
+        Some(29),
+        "text should be centered: {:?}",
+        first_row
+    );
+    // The long line soft-wraps inside the column.
+    let screen = harness.screen_to_string();
+    assert!(
+        screen.contains("sixteen"),
+        "wrapped text visible:\n{}",
+        screen
+    );
+    for row in 1..4 {
+        let text = harness.get_row_text(row);
+        assert!(
+            text.trim_end().len() <= 69,
+            "row {} should stay within the column: {:?}",
+            row,
+            text
+        );
+    }
+
+    toggle_zen_mode(&mut harness);
+
+    harness.assert_screen_contains("notes.txt");
+    harness.assert_screen_contains("File");
+    harness.assert_screen_contains("Zen mode off");
+    // Line wrap is back off: the long line runs off the right edge.
+    harness.assert_screen_not_contains("sixteen");
+}
@@ -0,0 +1,157 @@
+//! Tests for the embedding API (`fresh::embed`): an editor drawn into an
+//! area of a host application's frame and driven by the host's events.
+
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use fresh::config::Config;
+use fresh::config_io::DirectoryContext;
+use fresh::embed::{EmbedOptions, EmbeddedEditor};
+use ratatui::backend::TestBackend;
+use ratatui::layout::Rect;
+use ratatui::widgets::Paragraph;
+use ratatui::Terminal;
+
+/// The host's frame, the editor's area inside it, and a file to edit.
+struct Host {
+    _sandbox: tempfile::TempDir,
+    terminal: Terminal<TestBackend>,
+    editor: EmbeddedEditor,
+    file: std::path::PathBuf,
+}
+
+const AREA: Rect = Rect {
+    x: 10,
+    y: 2,
+    width: 60,
+    height: 15,
+};
+
+impl Host {
+    fn new() -> Self {
+        let sandbox = tempfile::tempdir().unwrap();
+        let project = sandbox.path().join("project");
+        std::fs::create_dir_all(&project).unwrap();
+        let file = project.join("notes.txt");
+        std::fs::write(&file, "first line\nsecond line\n").unwrap();
+
+        let config = Config {
+            check_for_updates: false,
+            ..Config::default()
+        };
+        let editor = EmbeddedEditor::new(
+            EmbedOptions::new()
+                .with_config(config)
+                .with_working_dir(project)
+                .with_dir_context(DirectoryContext::for_testing(&sandbox.path().join("state"))),
+        )
+        .unwrap();
+        Self {
+            _sandbox: sandbox,
+            terminal: Terminal::new(TestBackend::new(80, 20)).unwrap(),
+            editor,
+            file,
+        }
+    }
+
+    /// Draw a host frame: a title row, then the editor in `area`.
+    fn draw(&mut self, area: Rect) {
+        let editor = &mut self.editor;
+        self.terminal
+            .draw(|frame| {
+                frame.render_widget(Paragraph::new("HOST APP"), Rect::new(0, 0, 80, 1));
+                editor.render(frame, area);
+            })
+            .unwrap();
+    }
+
+    fn row(&self, y: u16) -> String {
+        let buffer = self.terminal.backend().buffer();
+        (0..buffer.area.width)
+            .map(|x| buffer[(x, y)].symbol())
+            .collect()
+    }
+
+    fn key(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        self.editor
+            .handle_event(Event::Key(KeyEvent::new(code, modifiers)))
+            .unwrap();
+    }
+}
+
+#[test]
+fn renders_inside_the_given_area_only() {
+    let mut host = Host::new();
+    let file = host.file.clone();
+    host.editor.open_file(&file).unwrap();
+    host.draw(AREA);
+
+    assert!(host.row(0).starts_with("HOST APP"));
+    let rows: Vec<String> = (0..20).map(|y| host.row(y)).collect();
+    let line = rows
+        .iter()
+        .position(|row| row.contains("first line"))
+        .expect("buffer text is drawn");
+    assert!(line >= AREA.y as usize && line < AREA.bottom() as usize);
+    let column = rows[line][..rows[line].find("first line").unwrap()]
+        .chars()
+        .count();
+    assert!(column >= AREA.x as usize);
+    // Nothing is drawn outside the area.
+    for row in rows.iter().skip(1) {
+        assert!(row.chars().take(AREA.x as usize).all(|c| c == ' '));
+    }
+    assert_eq!(host.editor.area(), AREA);
+}
+
+#[test]
+fn keys_edit_and_render_follows_area_size() {
+    let mut host = Host::new();
+    let file = host.file.clone();
+    host.editor.open_file(&file).unwrap();
+    host.draw(AREA);
+
+    for c in "typed ".chars() {
+        host.key(KeyCode::Char(c), KeyModifiers::NONE);
+    }
+    host.editor.tick().unwrap();
+    let wide = Rect::new(0, 1, 80, 19);
+    host.draw(wide);
+    assert!((0..20).any(|y| host.row(y).contains("typed first line")));
+
+    let state = host.editor.editor().active_state();
+    assert_eq!(
+        state.buffer.to_string().unwrap(),
+        "typed first line\nsecond line\n"
+    );
+    assert!(!host.editor.should_quit());
+}
+
+#[test]
+fn mouse_events_are_translated_into_the_area() {
+    let mut host = Host::new();
+    let file = host.file.clone();
+    host.editor.open_file(&file).unwrap();
+    host.draw(AREA);
+
+    let click = |column, row| {
+        Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        })
+    };
+    // Outside the area: ignored.
+    assert!(!host.editor.handle_event(click(2, 5)).unwrap());
+
+    // On the second buffer line, in host coordinates.
+    let y = (0..20)
+        .find(|&y| host.row(y).contains("second line"))
+        .unwrap();
+    let row = host.row(y);
+    let x = row[..row.find("second").unwrap()].chars().count() as u16;
+    host.editor.handle_event(click(x + 3, y)).unwrap();
+    let cursor = host.editor.editor().active_cursors().primary().position;
+    assert_eq!(cursor, "first line\n".len() + 3);
+}
@@ -0,0 +1,91 @@
+#!/usr/bin/env python3
+"""A minimal external plugin for the `external_plugins` e2e tests.
+
+It speaks the editor's JSON-RPC protocol over stdio: on `initialize` it
+registers a few commands and a keybinding, then answers `command/execute`
+and `popup/result` notifications by calling back into the editor.
+"""
+import json
+import sys
+
+next_id = 1
+queued = []
+
+
+def send(message):
+    message["jsonrpc"] = "2.0"
+    sys.stdout.write(json.dumps(message) + "\n")
+    sys.stdout.flush()
+
+
+def call(method, **params):
+    """Send a request and wait for its response, queueing anything else."""
+    global next_id
+    request_id = next_id
+    next_id += 1
+    send({"id": request_id, "method": method, "params": params})
+    while True:
+        line = sys.stdin.readline()
+        if not line:
+            sys.exit(0)
+        message = json.loads(line)
+        if message.get("id") == request_id and "method" not in message:
+            if "error" in message:
+                raise RuntimeError(message["error"]["message"])
+            return message.get("result")
+        queued.append(message)
+
+
+def rewrite(transform):
+    text = call("buffer/getText")
+    call("buffer/delete", start=0, end=len(text.encode()))
+    call("buffer/insert", position=0, text=transform(text))
+
+
+def on_command(command):
+    if command == "shout":
+        rewrite(str.upper)
+    elif command == "lower":
+        rewrite(str.lower)
+    elif command == "ask":
+        call(
+            "ui/showPopup",
+            id="question",
+            title="Ext question",
+            message="Proceed?",
+            actions=[{"id": "yes", "label": "Yes"}, {"id": "no", "label": "No"}],
+        )
+    elif command == "panel":
+        call("ui/showPanel", id="info", title="Ext Info",
+             lines=["panel line one", "panel line two"])
+
+
+def handle(message):
+    method = message.get("method")
+    params = message.get("params", {})
+    if method == "initialize":
+        send({"id": message["id"], "result": {}})
+        call("commands/register", id="shout", name="Ext: Shout")
+        call("commands/register", id="lower", name="Ext: Lower")
+        call("commands/register", id="ask", name="Ext: Ask")
+        call("commands/register", id="panel", name="Ext: Panel")
+        call("keybindings/register", key="M-l", command="lower")
+        try:
+            call("no/such/method")
+        except RuntimeError as e:
+            call("editor/setStatus", message=f"rpc ready ({e})")
+    elif method == "command/execute":
+        on_command(params["command"])
+    elif method == "popup/result":
+        call("editor/setStatus", message=f"picked {params['action']}")
+    elif method == "shutdown":
+        sys.exit(0)
+
+
+while True:
+    while queued:
+        handle(queued.pop(0))
+    line = sys.stdin.readline()
+    if not line:
+        break
+    handle(json.loads(line))
@@ -505,18 +505,21 @@ fn test_popup_events() {
                     detail: Some("First item".to_string()),
                     icon: Some("📄".to_string()),
                     data: None,
+                    match_positions: Vec::new(),
                 },
                 PopupListItemData {
                     text: "Item 2".to_string(),
                     detail: Some("Second item".to_string()),
                     icon: Some("📄".to_string()),
                     data: None,
+                    match_positions: Vec::new(),
                 },
                 PopupListItemData {
                     text: "Item 3".to_string(),
                     detail: Some("Third item".to_string()),
                     icon: Some("📄".to_string()),
                     data: None,
+                    match_positions: Vec::new(),
                 },
             ],
             selected: 0,
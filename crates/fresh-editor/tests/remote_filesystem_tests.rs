@@ -299,6 +299,22 @@ fn test_read_range_on_large_file() {
     );
 }
 
+#[test]
+fn test_read_prefix_stops_at_limit_or_eof() {
+    let Some((fs, temp_dir, _rt)) = create_test_filesystem() else {
+        eprintln!("Skipping test: could not create test filesystem");
+        return;
+    };
+
+    let test_path = temp_dir.path().join("prefix.txt");
+    std::fs::write(&test_path, b"0123456789").unwrap();
+
+    // A limit inside the file returns just the head...
+    assert_eq!(fs.read_prefix(&test_path, 4).unwrap(), b"0123");
+    // ...and one past the end returns the whole file instead of failing.
+    assert_eq!(fs.read_prefix(&test_path, 64).unwrap(), b"0123456789");
+}
+
 // =============================================================================
 // Tests for optimized remote operations (Phase 1 & 2 optimizations)
 // =============================================================================
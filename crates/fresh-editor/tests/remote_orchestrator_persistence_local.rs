@@ -175,9 +175,10 @@ impl FileSystem for RecordingRemoteFs {
         mode: u32,
         uid: u32,
         gid: u32,
+        elevate: &[String],
     ) -> io::Result<()> {
         self.record(path);
-        self.inner.sudo_write(path, data, mode, uid, gid)
+        self.inner.sudo_write(path, data, mode, uid, gid, elevate)
     }
     fn walk_files(
         &self,
@@ -165,9 +165,10 @@ impl FileSystem for DroppableFileSystem {
         mode: u32,
         uid: u32,
         gid: u32,
+        elevate: &[String],
     ) -> io::Result<()> {
         self.check_disconnected()?;
-        self.inner.sudo_write(path, data, mode, uid, gid)
+        self.inner.sudo_write(path, data, mode, uid, gid, elevate)
     }
     fn walk_files(
         &self,
@@ -96,12 +96,14 @@ pub mod regressions;
 pub mod reset_isolation;
 pub mod save_state;
 pub mod scenario_shapes;
+pub mod scroll_cursor_to_edges;
 pub mod select_to_paragraph;
 pub mod selection;
 pub mod selftest;
 pub mod shadow_corpus;
 pub mod smart_home;
 pub mod sort_lines;
+pub mod text_transforms;
 pub mod toggle_comment;
 pub mod undo_redo;
 pub mod unicode_cursor;
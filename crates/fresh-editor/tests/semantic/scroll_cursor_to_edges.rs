@@ -0,0 +1,48 @@
+//! `Action::ScrollCursorToTop` / `Action::ScrollCursorToBottom` (vim's
+//! `zt` / `zb`): the cursor's line lands at the top or bottom of the
+//! viewport, inside the `scroll_offset` margin (3 rows by default), and
+//! the cursor itself doesn't move.
+
+use crate::common::scenario::layout_scenario::{assert_layout_scenario, LayoutScenario};
+use fresh::test_api::Action;
+
+/// 50 lines of 8 bytes each, so line `n` starts at byte `8 * n`.
+fn long_buffer() -> String {
+    (0..50)
+        .map(|i| format!("line {i:02}\n"))
+        .collect::<String>()
+}
+
+fn move_down_25_then(action: Action) -> Vec<Action> {
+    let mut actions: Vec<Action> = (0..25).map(|_| Action::MoveDown).collect();
+    actions.push(action);
+    actions
+}
+
+#[test]
+fn scroll_cursor_to_top_puts_cursor_line_below_the_margin() {
+    assert_layout_scenario(LayoutScenario {
+        description: "zt puts line 25 three rows below the top".into(),
+        initial_text: long_buffer(),
+        width: 40,
+        height: 20,
+        actions: move_down_25_then(Action::ScrollCursorToTop),
+        expected_top_byte: Some(8 * (25 - 3)),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn scroll_cursor_to_bottom_puts_cursor_line_above_the_margin() {
+    assert_layout_scenario(LayoutScenario {
+        description: "zb puts line 25 three rows above the bottom".into(),
+        initial_text: long_buffer(),
+        width: 40,
+        height: 20,
+        actions: move_down_25_then(Action::ScrollCursorToBottom),
+        // A 20-row terminal leaves 16 content rows once the editor chrome
+        // is drawn; line 25 sits on row 16 - 3 - 1 = 12.
+        expected_top_byte: Some(8 * (25 - 12)),
+        ..Default::default()
+    });
+}
@@ -0,0 +1,95 @@
+//! Selection transforms beyond upper/lowercase: identifier case styles,
+//! title case, descending/unique sort, reversing and numbering lines.
+
+use crate::common::scenario::buffer_scenario::{
+    assert_buffer_scenario, BufferScenario, CursorExpect,
+};
+use fresh::test_api::Action;
+
+#[test]
+fn theorem_snake_case_converts_word_under_cursor() {
+    assert_buffer_scenario(BufferScenario {
+        description: "ToSnakeCase without a selection converts the word at the cursor".into(),
+        initial_text: "parseHTTPRequest(body)".into(),
+        actions: vec![Action::ToSnakeCase],
+        expected_text: "parse_http_request(body)".into(),
+        expected_primary: CursorExpect::at(18),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn theorem_camel_and_title_case_on_selection() {
+    assert_buffer_scenario(BufferScenario {
+        description: "ToCamelCase joins the selected words".into(),
+        initial_text: "user-account id".into(),
+        actions: vec![Action::SelectAll, Action::ToCamelCase],
+        expected_text: "userAccountId".into(),
+        expected_primary: CursorExpect::at(13),
+        ..Default::default()
+    });
+    assert_buffer_scenario(BufferScenario {
+        description: "ToTitleCase recases words and keeps separators".into(),
+        initial_text: "a TALE of two-cities".into(),
+        actions: vec![Action::SelectAll, Action::ToTitleCase],
+        expected_text: "A Tale Of Two-Cities".into(),
+        expected_primary: CursorExpect::at(20),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn theorem_sort_lines_descending_and_unique() {
+    assert_buffer_scenario(BufferScenario {
+        description: "SortLinesDescending orders lines in reverse".into(),
+        initial_text: "banana\ncherry\napple\n".into(),
+        actions: vec![Action::SelectAll, Action::SortLinesDescending],
+        expected_text: "cherry\nbanana\napple\n".into(),
+        expected_primary: CursorExpect::at(20),
+        ..Default::default()
+    });
+    assert_buffer_scenario(BufferScenario {
+        description: "SortLinesUnique sorts and drops repeated lines".into(),
+        initial_text: "b\na\nb\na".into(),
+        actions: vec![Action::SelectAll, Action::SortLinesUnique],
+        expected_text: "a\nb".into(),
+        expected_primary: CursorExpect::at(3),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn theorem_reverse_lines() {
+    assert_buffer_scenario(BufferScenario {
+        description: "ReverseLines flips the selected lines".into(),
+        initial_text: "one\ntwo\nthree".into(),
+        actions: vec![Action::SelectAll, Action::ReverseLines],
+        expected_text: "three\ntwo\none".into(),
+        expected_primary: CursorExpect::at(13),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn theorem_number_lines_selection_and_cursors() {
+    assert_buffer_scenario(BufferScenario {
+        description: "NumberLines prefixes each selected line".into(),
+        initial_text: "milk\neggs\n".into(),
+        actions: vec![Action::SelectAll, Action::NumberLines],
+        expected_text: "1. milk\n2. eggs\n".into(),
+        expected_primary: CursorExpect::at(16),
+        ..Default::default()
+    });
+    assert_buffer_scenario(BufferScenario {
+        description: "NumberLines inserts a sequence at bare cursors, top to bottom".into(),
+        initial_text: "a\nb\nc".into(),
+        actions: vec![
+            Action::AddCursorBelow,
+            Action::AddCursorBelow,
+            Action::NumberLines,
+        ],
+        expected_text: "1a\n2b\n3c".into(),
+        skip_cursor_check: true,
+        ..Default::default()
+    });
+}
@@ -26,7 +26,8 @@ use ratatui::style::Color;
 use ratatui::Terminal;
 use ratatui_wgpu::{Builder, Dimensions, Font, WgpuBackend};
 use winit::application::ApplicationHandler;
-use winit::event::{ElementState, MouseButton, MouseScrollDelta, WindowEvent};
+use winit::dpi::{PhysicalPosition, PhysicalSize};
+use winit::event::{ElementState, Ime, MouseButton, MouseScrollDelta, WindowEvent};
 use winit::event_loop::{ActiveEventLoop, EventLoop};
 use winit::keyboard::KeyLocation;
 use winit::keyboard::{Key, NamedKey};
@@ -74,6 +75,17 @@ pub trait GuiApplication {
     /// Render the application into a ratatui frame.
     fn render(&mut self, frame: &mut ratatui::Frame);
 
+    /// Update the input method's uncommitted pre-edit (composition) text;
+    /// an empty string means the composition ended or was cancelled.
+    ///
+    /// Default implementation does nothing.
+    fn on_ime_preedit(&mut self, _text: &str) {}
+
+    /// Handle text committed by the input method.
+    ///
+    /// Default implementation does nothing.
+    fn on_ime_commit(&mut self, _text: &str) {}
+
     /// Per-tick housekeeping (called every frame).
     /// Returns `true` if a re-render is needed.
     fn tick(&mut self) -> AnyhowResult<bool>;
@@ -308,6 +320,9 @@ struct RunnerState<A: GuiApplication> {
     alt_location: Option<KeyLocation>,
     /// Platform-native menu bar (macOS: real AppKit menus; other: no-op stub).
     native_menu: NativeMenuBar,
+    /// Whether an IME composition is in progress. Its keys belong to the
+    /// input method, so key presses aren't forwarded until it commits.
+    ime_composing: bool,
 }
 
 impl<A: GuiApplication + 'static> ApplicationHandler for WgpuRunner<A> {
@@ -378,7 +393,7 @@ impl<A: GuiApplication + 'static> ApplicationHandler for WgpuRunner<A> {
                     }
                 }
 
-                if event.state == ElementState::Released {
+                if event.state == ElementState::Released || state.ime_composing {
                     return;
                 }
                 if let Some(key_event) =
@@ -391,6 +406,26 @@ impl<A: GuiApplication + 'static> ApplicationHandler for WgpuRunner<A> {
                 }
             }
 
+            WindowEvent::Ime(ime) => {
+                match ime {
+                    Ime::Preedit(text, _) => {
+                        state.ime_composing = !text.is_empty();
+                        state.app.on_ime_preedit(&text);
+                    }
+                    Ime::Commit(text) => {
+                        state.ime_composing = false;
+                        state.app.on_ime_preedit("");
+                        state.app.on_ime_commit(&text);
+                    }
+                    Ime::Enabled => {}
+                    Ime::Disabled => {
+                        state.ime_composing = false;
+                        state.app.on_ime_preedit("");
+                    }
+                }
+                state.needs_render = true;
+            }
+
             WindowEvent::MouseInput {
                 state: btn_state,
                 button,
@@ -498,6 +533,14 @@ impl<A: GuiApplication + 'static> ApplicationHandler for WgpuRunner<A> {
                     if let Err(e) = state.terminal.draw(|frame| state.app.render(frame)) {
                         tracing::error!("Render error: {}", e);
                     }
+                    // Keep the IME candidate window next to the caret.
+                    if let Ok(pos) = state.terminal.get_cursor_position() {
+                        let (cw, ch) = state.cell_size;
+                        state.window.set_ime_cursor_area(
+                            PhysicalPosition::new(pos.x as f64 * cw, pos.y as f64 * ch),
+                            PhysicalSize::new(cw, ch),
+                        );
+                    }
                     state.last_render = Instant::now();
                     state.needs_render = false;
                 }
@@ -596,6 +639,10 @@ impl<A: GuiApplication> WgpuRunner<A> {
         // what Explorer / Properties / Alt-Tab show.  No-op elsewhere.
         platform::set_window_icon(&window);
 
+        // Receive `WindowEvent::Ime` so input methods (CJK, dead keys) can
+        // compose in place.
+        window.set_ime_allowed(true);
+
         let size = window.inner_size();
 
         // Build the wgpu backend (async adapter/device request — block on it).
@@ -652,6 +699,7 @@ impl<A: GuiApplication> WgpuRunner<A> {
             cell_size,
             alt_location: None,
             native_menu,
+            ime_composing: false,
         })
     }
 }
@@ -95,6 +95,26 @@ enum State {
     StringSeq { saw_esc: bool },
 }
 
+/// A terminal's answer to a query, recognised in the input stream.
+///
+/// Replies never surface as events; the parser keeps them for
+/// [`InputParser::take_replies`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalReply {
+    /// `CSI ? <flags> u`: the kitty keyboard protocol flags in effect
+    KeyboardFlags(u8),
+    /// `CSI ? … c`: a Primary Device Attributes response. Terminals answer
+    /// queries in order, so this arriving without a preceding
+    /// [`TerminalReply::KeyboardFlags`] means the keyboard query went
+    /// unanswered. `sixel` is set when the attributes list sixel graphics
+    /// (attribute 4).
+    DeviceAttributes { sixel: bool },
+    /// `CSI ? <mode> ; <status> $ y`: a DECRPM answer to a private mode
+    /// query. `status` is 0 for an unrecognised mode, 1/2 for set/reset and
+    /// 3/4 for permanently set/reset.
+    Mode { mode: u16, status: u8 },
+}
+
 /// Incremental terminal-input parser.
 ///
 /// Feed it raw bytes with [`InputParser::parse`]; it returns whatever complete
@@ -108,6 +128,8 @@ pub struct InputParser {
     /// Accumulated bracketed-paste content (including the trailing end marker
     /// until it is recognised and stripped).
     paste: Vec<u8>,
+    /// Query replies seen since the last [`InputParser::take_replies`].
+    replies: Vec<TerminalReply>,
 }
 
 impl Default for InputParser {
@@ -122,9 +144,15 @@ impl InputParser {
             state: State::Ground,
             buffer: Vec::with_capacity(32),
             paste: Vec::new(),
+            replies: Vec::new(),
         }
     }
 
+    /// Take the query replies parsed so far, oldest first.
+    pub fn take_replies(&mut self) -> Vec<TerminalReply> {
+        std::mem::take(&mut self.replies)
+    }
+
     /// Parse a chunk of raw bytes into complete events.
     ///
     /// Partial sequences are retained across calls, so splitting a sequence
@@ -542,6 +570,29 @@ impl InputParser {
                 "InputParser: discarding CSI reply, final {:#04x}",
                 final_byte
             );
+            if params[0] == b'?' {
+                let body = std::str::from_utf8(&params[1..]).unwrap_or("");
+                match final_byte {
+                    b'u' => {
+                        if let Ok(flags) = body.parse::<u8>() {
+                            self.replies.push(TerminalReply::KeyboardFlags(flags));
+                        }
+                    }
+                    b'c' => self.replies.push(TerminalReply::DeviceAttributes {
+                        sixel: body.split(';').skip(1).any(|attr| attr == "4"),
+                    }),
+                    b'y' => {
+                        let parsed = body.strip_suffix('$').and_then(|body| {
+                            let (mode, status) = body.split_once(';')?;
+                            Some((mode.parse().ok()?, status.parse().ok()?))
+                        });
+                        if let Some((mode, status)) = parsed {
+                            self.replies.push(TerminalReply::Mode { mode, status });
+                        }
+                    }
+                    _ => {}
+                }
+            }
             return;
         }
 
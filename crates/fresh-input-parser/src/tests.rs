@@ -1027,6 +1027,65 @@ fn csi_reply_lists_are_discarded() {
     assert!(p.parse(b"\x1b[>1;95;0c").is_empty(), "DA2 reply leaked");
 }
 
+#[test]
+fn keyboard_protocol_replies_are_recorded() {
+    let mut p = InputParser::new();
+    assert!(p.parse(b"\x1b[?15u\x1b[?62;22c").is_empty());
+    assert_eq!(
+        p.take_replies(),
+        vec![
+            TerminalReply::KeyboardFlags(15),
+            TerminalReply::DeviceAttributes { sixel: false }
+        ]
+    );
+    assert!(p.take_replies().is_empty());
+
+    // Split across reads, and with keys around it.
+    assert_eq!(
+        keys(&p.parse(b"a\x1b[?")),
+        vec![(KeyCode::Char('a'), KeyModifiers::NONE)]
+    );
+    assert_eq!(
+        keys(&p.parse(b"1ub")),
+        vec![(KeyCode::Char('b'), KeyModifiers::NONE)]
+    );
+    assert_eq!(p.take_replies(), vec![TerminalReply::KeyboardFlags(1)]);
+
+    // Attribute 4 after the device class announces sixel graphics.
+    p.parse(b"\x1b[?62;4;22c");
+    assert_eq!(
+        p.take_replies(),
+        vec![TerminalReply::DeviceAttributes { sixel: true }]
+    );
+
+    // DA2 (`CSI > … c`) is not the primary attributes reply.
+    p.parse(b"\x1b[>1;95;0c");
+    assert!(p.take_replies().is_empty());
+}
+
+#[test]
+fn mode_reports_are_recorded() {
+    let mut p = InputParser::new();
+    assert!(p.parse(b"\x1b[?1000;1$y\x1b[?2004;0$y").is_empty());
+    assert_eq!(
+        p.take_replies(),
+        vec![
+            TerminalReply::Mode {
+                mode: 1000,
+                status: 1
+            },
+            TerminalReply::Mode {
+                mode: 2004,
+                status: 0
+            }
+        ]
+    );
+
+    // A malformed report is dropped without producing keys.
+    assert!(p.parse(b"\x1b[?1000$y").is_empty());
+    assert!(p.take_replies().is_empty());
+}
+
 // ---- §5.3 Robustness ----
 
 #[test]
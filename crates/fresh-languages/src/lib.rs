@@ -4,6 +4,7 @@ use std::path::Path;
 pub use tree_sitter;
 pub use tree_sitter_highlight;
 pub use tree_sitter_highlight::HighlightConfiguration;
+pub use tree_sitter_language;
 
 // Re-export the bundled language grammar crates (gated by features). Only the
 // languages that must use tree-sitter because syntect ships no highlighting
@@ -618,10 +619,27 @@ impl std::fmt::Display for Language {
     }
 }
 
-// Used by every per-language `highlight_config` arm; each arm is gated by its
-// own grammar feature, so with zero grammars enabled this is (legitimately)
-// unused.
-#[allow(dead_code)]
+/// Build a highlight configuration for a grammar that isn't compiled into
+/// Fresh — one loaded from the user grammars directory at runtime.
+///
+/// Captures are configured like the bundled grammars', so highlight indices
+/// resolve through [`HighlightCategory::from_default_index`]. `injections` and
+/// `locals` may be empty.
+pub fn runtime_highlight_config(
+    language: tree_sitter::Language,
+    name: &str,
+    highlights: &str,
+    injections: &str,
+    locals: &str,
+) -> Result<HighlightConfiguration, String> {
+    let mut config = HighlightConfiguration::new(language, name, highlights, injections, locals)
+        .map_err(|e| format!("Failed to create {name} highlight config: {e}"))?;
+    config.configure(DEFAULT_HIGHLIGHT_CAPTURES);
+    Ok(config)
+}
+
+// Used by every per-language `highlight_config` arm and by
+// `runtime_highlight_config`.
 const DEFAULT_HIGHLIGHT_CAPTURES: &[&str] = &[
     "attribute",
     "comment",
@@ -755,6 +773,35 @@ mod tests {
             .expect("Templ highlight config should build");
     }
 
+    #[test]
+    #[cfg(feature = "tree-sitter-json")]
+    fn test_runtime_highlight_config_builds() {
+        let config = runtime_highlight_config(
+            tree_sitter_json::LANGUAGE.into(),
+            "json",
+            tree_sitter_json::HIGHLIGHTS_QUERY,
+            "",
+            "",
+        );
+        assert!(config.is_ok(), "runtime highlight config should build");
+    }
+
+    #[test]
+    #[cfg(feature = "tree-sitter-json")]
+    fn test_runtime_highlight_config_rejects_bad_query() {
+        let result = runtime_highlight_config(
+            tree_sitter_json::LANGUAGE.into(),
+            "json",
+            "(no_such_node) @string",
+            "",
+            "",
+        );
+        let Err(err) = result else {
+            panic!("a query naming an unknown node should be rejected");
+        };
+        assert!(err.contains("json"), "unexpected error: {err}");
+    }
+
     /// Guard: `from_path` and `extensions()` must stay in sync — they used to
     /// be two hand-maintained tables with a "keep in sync" comment, which
     /// silently drifted when either was edited in isolation.
@@ -1332,6 +1332,9 @@ impl JsEditorApi {
     /// virtual mode (from `defineMode()`) matches. This is for plugin-defined
     /// contexts only (e.g. `"tour-active"`, `"review-mode"`), not built-in
     /// editor modes.
+    ///
+    /// Pass `{ contextMenu: true }` in `options` to also list the command in
+    /// the editor's right-click context menu.
     pub fn register_command<'js>(
         &self,
         ctx: rquickjs::Ctx<'js>,
@@ -1341,7 +1344,7 @@ impl JsEditorApi {
         #[plugin_api(ts_type = "string | null")] context: rquickjs::function::Opt<
             rquickjs::Value<'js>,
         >,
-        #[plugin_api(ts_type = "{ terminalBypass?: boolean } | null")]
+        #[plugin_api(ts_type = "{ terminalBypass?: boolean; contextMenu?: boolean } | null")]
         options: rquickjs::function::Opt<rquickjs::Value<'js>>,
     ) -> rquickjs::Result<bool> {
         // Use stored plugin name instead of global lookup
@@ -1401,20 +1404,25 @@ impl JsEditorApi {
             },
         );
 
-        // Extract `options.terminalBypass`. JS shape: `{ terminalBypass: true }`
-        // or omitted/null. Anything else is ignored — wrong shape stays at the
-        // safe default of `false`.
-        let terminal_bypass: bool = options
-            .0
-            .and_then(|v| {
-                if v.is_null() || v.is_undefined() {
-                    None
-                } else {
-                    v.into_object()
-                        .and_then(|obj| obj.get::<&str, bool>("terminalBypass").ok())
-                }
-            })
-            .unwrap_or(false);
+        // Extract `options.terminalBypass` / `options.contextMenu`. JS shape:
+        // `{ terminalBypass: true, contextMenu: true }` or omitted/null.
+        // Anything else is ignored — wrong shape stays at the safe default
+        // of `false`.
+        let options = options.0.and_then(|v| {
+            if v.is_null() || v.is_undefined() {
+                None
+            } else {
+                v.into_object()
+            }
+        });
+        let option_flag = |key: &str| {
+            options
+                .as_ref()
+                .and_then(|obj| obj.get::<&str, bool>(key).ok())
+                .unwrap_or(false)
+        };
+        let terminal_bypass = option_flag("terminalBypass");
+        let context_menu = option_flag("contextMenu");
 
         // Register with editor
         let command = Command {
@@ -1424,6 +1432,7 @@ impl JsEditorApi {
             plugin_name,
             custom_contexts: context_str.into_iter().collect(),
             terminal_bypass,
+            context_menu,
         };
 
         Ok(self
@@ -1450,6 +1459,10 @@ impl JsEditorApi {
     }
 
     /// Set a context (for keybinding conditions)
+    ///
+    /// A context named after a mode from `defineMode()` also acts as a key
+    /// layer: its bindings apply alongside the active editor mode, which
+    /// keeps any key it binds itself.
     pub fn set_context(&self, name: String, active: bool) -> bool {
         // Track context name for cleanup on unload
         if active {
@@ -1919,6 +1932,16 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    /// Switch the active window to the project rooted at `path`, the
+    /// same as confirming the Switch Project prompt with that directory.
+    pub fn switch_project(&self, path: String) -> bool {
+        self.command_sender
+            .send(PluginCommand::SwitchProject {
+                path: PathBuf::from(path),
+            })
+            .is_ok()
+    }
+
     /// Open a file in a specific split
     pub fn open_file_in_split(&self, split_id: u32, path: String, line: u32, column: u32) -> bool {
         self.command_sender
@@ -2184,6 +2207,15 @@ impl JsEditorApi {
             .unwrap_or_default()
     }
 
+    /// Newer Fresh release reported by the update checker (e.g.
+    /// `"0.3.1"`), or `null` when there is none to offer.
+    pub fn get_update_available(&self) -> Option<String> {
+        self.state_snapshot
+            .read()
+            .ok()
+            .and_then(|s| s.update_available.clone())
+    }
+
     /// Current Workspace Trust level for the active project: `"restricted"`,
     /// `"trusted"`, or `"blocked"` (empty when unavailable). Exposed to JS as
     /// `editor.workspaceTrustLevel()`. Plugins that run repo-controlled work
@@ -3761,6 +3793,50 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    // === Read-only Regions ===
+
+    /// Mark bytes [start, end) read-only: user edits that would change them
+    /// are refused, showing `hint` (or a generic message) in the status bar.
+    /// Edits made through `insertText` / `deleteRange` still apply, so the
+    /// plugin can keep rewriting the text it protects.
+    pub fn add_read_only_region(
+        &self,
+        buffer_id: u32,
+        namespace: String,
+        start: u32,
+        end: u32,
+        hint: rquickjs::function::Opt<String>,
+    ) -> bool {
+        // Track namespace for cleanup on unload
+        self.plugin_tracked_state
+            .borrow_mut()
+            .entry(self.plugin_name.clone())
+            .or_default()
+            .overlay_namespaces
+            .push((BufferId(buffer_id as usize), namespace.clone()));
+
+        self.command_sender
+            .send(PluginCommand::AddReadOnlyRegion {
+                buffer_id: BufferId(buffer_id as usize),
+                namespace: OverlayNamespace::from_string(namespace),
+                start: start as usize,
+                end: end as usize,
+                hint: hint.0,
+                epoch: self.hook_epoch_for(buffer_id),
+            })
+            .is_ok()
+    }
+
+    /// Clear all read-only regions in a namespace
+    pub fn clear_read_only_regions(&self, buffer_id: u32, namespace: String) -> bool {
+        self.command_sender
+            .send(PluginCommand::ClearReadOnlyRegions {
+                buffer_id: BufferId(buffer_id as usize),
+                namespace: OverlayNamespace::from_string(namespace),
+            })
+            .is_ok()
+    }
+
     // === Folds ===
 
     /// Add a collapsed fold range. Hides bytes [start, end) from
@@ -6762,6 +6838,80 @@ impl JsEditorApi {
             .is_ok()
     }
 
+    // === Jobs ===
+
+    /// Start a job (async, returns request_id which is also the job id).
+    /// Wrapped by `editor.spawnJob`, declared in `ts_export.rs`.
+    #[qjs(rename = "_spawnJobStart")]
+    pub fn spawn_job_start(
+        &self,
+        _ctx: rquickjs::Ctx<'_>,
+        command: String,
+        args: Vec<String>,
+        options: rquickjs::function::Opt<fresh_core::api::JobOptions>,
+    ) -> u64 {
+        let id = self.alloc_request_id();
+        let options = options.0.unwrap_or_default();
+        // Killed with the plugin's other background processes on unload.
+        self.plugin_tracked_state
+            .borrow_mut()
+            .entry(self.plugin_name.clone())
+            .or_default()
+            .background_process_ids
+            .push(id);
+        let title = options.title.filter(|t| !t.is_empty()).unwrap_or_else(|| {
+            std::iter::once(&command)
+                .chain(&args)
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+        let _ = self.command_sender.send(PluginCommand::SpawnJob {
+            plugin: self.plugin_name.clone(),
+            job_id: id,
+            command,
+            args,
+            cwd: options.cwd.filter(|s| !s.is_empty()),
+            title,
+            stdin: options.stdin.unwrap_or(false),
+            callback_id: JsCallbackId::new(id),
+        });
+        id
+    }
+
+    /// Write text to a job's stdin. Wrapped by `JobHandle.write`.
+    #[qjs(rename = "_writeJobStdin")]
+    pub fn write_job_stdin(&self, job_id: u64, data: String) -> bool {
+        self.command_sender
+            .send(PluginCommand::WriteJobStdin { job_id, data })
+            .is_ok()
+    }
+
+    /// Close a job's stdin. Wrapped by `JobHandle.closeStdin`.
+    #[qjs(rename = "_closeJobStdin")]
+    pub fn close_job_stdin(&self, job_id: u64) -> bool {
+        self.command_sender
+            .send(PluginCommand::CloseJobStdin { job_id })
+            .is_ok()
+    }
+
+    /// Update the progress shown for a running job in the running jobs
+    /// list: a short message and an optional completion percentage (0-100)
+    pub fn set_job_progress(
+        &self,
+        job_id: u64,
+        message: String,
+        percent: rquickjs::function::Opt<f64>,
+    ) -> bool {
+        self.command_sender
+            .send(PluginCommand::SetJobProgress {
+                job_id,
+                message: (!message.is_empty()).then_some(message),
+                percent: percent.0.map(|p| p.clamp(0.0, 100.0) as u8),
+            })
+            .is_ok()
+    }
+
     // === Terminal ===
 
     /// Create a new terminal in a split (async, returns TerminalResult)
@@ -7401,6 +7551,61 @@ const EDITOR_PROMISE_BOOTSTRAP: &str = r#"
                         }
                     };
                 };
+                // spawnJob returns a `JobHandle`: a ProcessHandle whose
+                // output callbacks are fed from the process output hooks,
+                // filtered to this plugin's running jobs by id.
+                globalThis._jobs = new Map();
+                const _dispatchJobOutput = function(stream) {
+                    return function(args) {
+                        const job = globalThis._jobs.get(args.process_id);
+                        if (job) {
+                            const line = args.data.endsWith("\n") ? args.data.slice(0, -1) : args.data;
+                            for (const handler of job[stream]) handler(line);
+                        }
+                    };
+                };
+                let _jobHooksInstalled = false;
+                editor.spawnJob = function(command, args, options) {
+                    if (!_jobHooksInstalled) {
+                        _jobHooksInstalled = true;
+                        editor.on("onProcessStdout", _dispatchJobOutput("stdout"));
+                        editor.on("onProcessStderr", _dispatchJobOutput("stderr"));
+                    }
+                    let jobId;
+                    if (options && typeof options === "object") {
+                        jobId = editor._spawnJobStart(command, args || [], options);
+                    } else {
+                        jobId = editor._spawnJobStart(command, args || []);
+                    }
+                    const job = { stdout: [], stderr: [] };
+                    globalThis._jobs.set(jobId, job);
+                    const resultPromise = new Promise(function(resolve, reject) {
+                        globalThis._pendingCallbacks.set(jobId, {
+                            resolve: function(r) { globalThis._jobs.delete(jobId); resolve(r); },
+                            reject: function(e) { globalThis._jobs.delete(jobId); reject(e); }
+                        });
+                    });
+                    const handle = {
+                        jobId: jobId,
+                        get result() { return resultPromise; },
+                        then: function(f, r) { return resultPromise.then(f, r); },
+                        catch: function(r) { return resultPromise.catch(r); },
+                        onStdout: function(handler) { job.stdout.push(handler); return handle; },
+                        onStderr: function(handler) { job.stderr.push(handler); return handle; },
+                        write: function(data) { return editor._writeJobStdin(jobId, data); },
+                        closeStdin: function() { return editor._closeJobStdin(jobId); },
+                        progress: function(message, percent) {
+                            if (typeof percent === "number") {
+                                return editor.setJobProgress(jobId, message, percent);
+                            }
+                            return editor.setJobProgress(jobId, message);
+                        },
+                        kill: function() {
+                            return Promise.resolve(editor.killBackgroundProcess(jobId));
+                        }
+                    };
+                    return handle;
+                };
                 editor.delay = _wrapAsync("_delayStart", "delay");
                 editor.createVirtualBuffer = _wrapAsync("_createVirtualBufferStart", "createVirtualBuffer");
                 editor.createVirtualBufferInSplit = _wrapAsync("_createVirtualBufferInSplitStart", "createVirtualBufferInSplit");
@@ -7900,7 +8105,8 @@ impl QuickJsBackend {
                         buffer_id: *buf_id,
                         namespace: OverlayNamespace::from_string(ns.clone()),
                     });
-                    // Also clear conceals and soft breaks (same namespace system)
+                    // Also clear conceals, soft breaks and read-only regions
+                    // (same namespace system)
                     let _ = self
                         .command_sender
                         .send(PluginCommand::ClearConcealNamespace {
@@ -7913,6 +8119,12 @@ impl QuickJsBackend {
                             buffer_id: *buf_id,
                             namespace: OverlayNamespace::from_string(ns.clone()),
                         });
+                    let _ = self
+                        .command_sender
+                        .send(PluginCommand::ClearReadOnlyRegions {
+                            buffer_id: *buf_id,
+                            namespace: OverlayNamespace::from_string(ns.clone()),
+                        });
                 }
             }
 
@@ -10097,6 +10309,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_api_add_read_only_region() {
+        let (mut backend, rx) = create_test_backend();
+
+        backend
+            .execute_js(
+                r#"
+            const editor = getEditor();
+            editor.addReadOnlyRegion(1, "prompt", 0, 8, "Prompt text is fixed");
+            editor.addReadOnlyRegion(1, "prompt", 20, 30);
+        "#,
+                "test.js",
+            )
+            .unwrap();
+
+        match rx.try_recv().unwrap() {
+            PluginCommand::AddReadOnlyRegion {
+                buffer_id,
+                namespace,
+                start,
+                end,
+                hint,
+                ..
+            } => {
+                assert_eq!(buffer_id.0, 1);
+                assert_eq!(namespace.as_str(), "prompt");
+                assert_eq!((start, end), (0, 8));
+                assert_eq!(hint.as_deref(), Some("Prompt text is fixed"));
+            }
+            cmd => panic!("Expected AddReadOnlyRegion, got {:?}", cmd),
+        }
+        match rx.try_recv().unwrap() {
+            PluginCommand::AddReadOnlyRegion { hint, .. } => assert_eq!(hint, None),
+            cmd => panic!("Expected AddReadOnlyRegion, got {:?}", cmd),
+        }
+    }
+
     // ==================== Theme Tests ====================
 
     #[test]
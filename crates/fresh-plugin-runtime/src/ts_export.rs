@@ -20,7 +20,7 @@ use fresh_core::api::{
     CompositePaneStyle, CompositeSourceConfig, CreateCompositeBufferOptions, CreateTerminalOptions,
     CreateVirtualBufferInExistingSplitOptions, CreateVirtualBufferInSplitOptions,
     CreateVirtualBufferOptions, CursorInfo, DirEntry, FormatterPackConfig, GrammarInfoSnapshot,
-    GrepMatch, JsDiagnostic, JsPosition, JsRange, JsTextPropertyEntry, KeyEventPayload,
+    GrepMatch, JobOptions, JsDiagnostic, JsPosition, JsRange, JsTextPropertyEntry, KeyEventPayload,
     LanguagePackConfig, LayoutHints, LspServerPackConfig, OverlayColorSpec, OverlayOptions,
     PluginAnimationEdge, PluginAnimationKind, ProcessLimitsPackConfig, RemoteBackendInfo,
     ReplaceResult, ScreenSize, SearchTakeResult, SpawnResult, SplitSnapshot, TerminalResult,
@@ -64,6 +64,7 @@ fn get_type_decl(type_name: &str) -> Option<String> {
         // Process types
         "SpawnResult" => Some(SpawnResult::decl(&cfg)),
         "BackgroundProcessResult" => Some(BackgroundProcessResult::decl(&cfg)),
+        "JobOptions" => Some(JobOptions::decl(&cfg)),
 
         // Grep/Replace types
         "GrepMatch" => Some(GrepMatch::decl(&cfg)),
@@ -360,6 +361,7 @@ const DEPENDENCY_TYPES: &[&str] = &[
     "CreateWindowWithTerminalOptions", // Used by createWindowWithTerminal opts
     "SessionWithTerminalResult",       // Used by createWindowWithTerminal return type
     "CreateTerminalOptions",           // Used by createTerminal opts parameter
+    "JobOptions",                      // Used by spawnJob opts parameter
     "CursorInfo",                      // Used by getPrimaryCursor, getAllCursors
     "OverlayOptions",                  // Used by TextPropertyEntry.style and InlineOverlay
     "OverlayColorSpec",                // Used by OverlayOptions.fg/bg
@@ -558,6 +560,12 @@ interface HookEventMap {
    * explorer-driven changes too.
    */
   after_file_explorer_change: { path: string };
+  /**
+   * Fired when a watched repo's checked-out branch changes, after open
+   * buffers have been reloaded for it. `branch` is the new branch name,
+   * or the short commit id for a detached HEAD.
+   */
+  git_head_changed: { branch: string };
 
   // ── text edits ───────────────────────────────────────────────────────────
   before_insert: { buffer_id: number; position: number; text: string };
@@ -641,7 +649,18 @@ interface HookEventMap {
 
   // ── mouse ────────────────────────────────────────────────────────────────
   mouse_click: MouseClickHookArgs;
-  mouse_move: { column: number; row: number; content_x: number; content_y: number };
+  mouse_move: {
+    column: number;
+    row: number;
+    content_x: number;
+    content_y: number;
+    /** Buffer under the mouse, or `null` outside any buffer panel. */
+    buffer_id: number | null;
+    /** 0-indexed buffer row under the mouse, accounting for scroll. */
+    buffer_row: number | null;
+    /** 0-indexed byte column inside the buffer row. */
+    buffer_col: number | null;
+  };
   mouse_scroll: { buffer_id: number; delta: number; col: number; row: number };
 
   // ── LSP ──────────────────────────────────────────────────────────────────
@@ -769,6 +788,36 @@ interface EditorAPI {
     mode: string,
     layout: unknown,
   ): Promise<BufferGroupResult>;
+  /**
+   * Start a job: a background process listed in the editor's running
+   * jobs (the `{jobs}` status-bar element) until it exits. Output lines
+   * arrive through `onStdout` / `onStderr`; the handle resolves with the
+   * exit code once the process exits or is killed (`-1`). A non-zero exit
+   * is reported in the status bar.
+   *
+   * ```ts
+   * const job = editor.spawnJob("cargo", ["clippy"], { title: "clippy" });
+   * job.onStderr((line) => editor.setJobProgress(job.jobId, line.trim()));
+   * const { exit_code } = await job;
+   * ```
+   */
+  spawnJob(command: string, args: string[], options?: JobOptions): JobHandle;
+}
+
+/** Handle for a job started with `editor.spawnJob` */
+interface JobHandle extends ProcessHandle<BackgroundProcessResult> {
+  /** Job ID, also the process ID passed to `onProcessStdout` hooks */
+  readonly jobId: number;
+  /** Call `handler` with each line the job writes to stdout */
+  onStdout(handler: (line: string) => void): JobHandle;
+  /** Call `handler` with each line the job writes to stderr */
+  onStderr(handler: (line: string) => void): JobHandle;
+  /** Write to the job's stdin (needs `stdin: true`) */
+  write(data: string): boolean;
+  /** Close the job's stdin, signalling end of input */
+  closeStdin(): boolean;
+  /** Shorthand for `editor.setJobProgress(jobId, message, percent)` */
+  progress(message: string, percent?: number): boolean;
 }
 "#;
 
@@ -897,6 +946,7 @@ mod tests {
             "LayoutHints",
             "SpawnResult",
             "BackgroundProcessResult",
+            "JobOptions",
             "TerminalResult",
             "CreateTerminalOptions",
             "CreateWindowWithTerminalOptions",
@@ -1313,6 +1363,7 @@ mod tests {
             "insertAtCursor",
             "openFile",
             "openFileInSplit",
+            "switchProject",
             "showBuffer",
             "closeBuffer",
             "animateArea",
@@ -1322,6 +1373,7 @@ mod tests {
             "off",
             "getEnv",
             "getCwd",
+            "getUpdateAvailable",
             "pathJoin",
             "pathDirname",
             "pathBasename",
@@ -1391,6 +1443,8 @@ mod tests {
             "charWidth",
             "stringWidth",
             "clearConcealsInRangeForNamespace",
+            "addReadOnlyRegion",
+            "clearReadOnlyRegions",
             "addSoftBreak",
             "clearSoftBreakNamespace",
             "clearSoftBreaksInRange",
@@ -26,6 +26,9 @@ pub enum UpdateError {
     ChecksumMismatch { expected: String, actual: String },
     /// The resolved provenance is not eligible for an in-place swap.
     NotSelfUpdatable,
+    /// The new binary failed its health check and the previous one was put
+    /// back.
+    RolledBack,
 }
 
 impl fmt::Display for UpdateError {
@@ -41,6 +44,12 @@ impl fmt::Display for UpdateError {
                     "this install cannot self-update; use the package manager"
                 )
             }
+            UpdateError::RolledBack => {
+                write!(
+                    f,
+                    "the new binary failed to start; the previous version was restored"
+                )
+            }
         }
     }
 }
@@ -120,6 +129,17 @@ fn backup_path(target: &Path) -> PathBuf {
     dir.join(format!(".{name}.old"))
 }
 
+/// Where [`replace_with_rollback`] keeps the previous binary until the new one
+/// has passed its health check.
+fn rollback_path(target: &Path) -> PathBuf {
+    let name = target
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "fresh".to_string());
+    let dir = target.parent().unwrap_or_else(|| Path::new("."));
+    dir.join(format!(".{name}.rollback"))
+}
+
 /// Atomically replace the executable at `target` with `new_bytes`.
 ///
 /// The new bytes are written to a sibling temp file (same directory, so the
@@ -142,6 +162,31 @@ pub fn atomic_replace(target: &Path, new_bytes: &[u8]) -> Result<(), UpdateError
     result
 }
 
+/// [`atomic_replace`], keeping a copy of the previous binary until `healthy`
+/// accepts the new one (typically by running it). If the check fails the copy
+/// is moved back over `target` and [`UpdateError::RolledBack`] is returned.
+/// Used for installs with no other way back, such as a hand-placed binary.
+pub fn replace_with_rollback(
+    target: &Path,
+    new_bytes: &[u8],
+    healthy: impl FnOnce(&Path) -> bool,
+) -> Result<(), UpdateError> {
+    let rollback = rollback_path(target);
+    std::fs::copy(target, &rollback)?;
+
+    if let Err(e) = atomic_replace(target, new_bytes) {
+        let _ = std::fs::remove_file(&rollback);
+        return Err(e);
+    }
+    if healthy(target) {
+        let _ = std::fs::remove_file(&rollback);
+        return Ok(());
+    }
+    // Same directory, so this rename is as atomic as the swap was.
+    std::fs::rename(&rollback, target)?;
+    Err(UpdateError::RolledBack)
+}
+
 #[cfg(not(windows))]
 fn do_swap(target: &Path, staging: &Path) -> Result<(), UpdateError> {
     std::fs::rename(staging, target)?;
@@ -255,6 +300,38 @@ mod tests {
         assert_eq!(mode & 0o111, 0o111, "executable bits not set: {mode:o}");
     }
 
+    #[test]
+    fn replace_with_rollback_keeps_healthy_binary() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("fresh");
+        std::fs::write(&target, b"old version").unwrap();
+
+        replace_with_rollback(&target, b"new version", |p| {
+            std::fs::read(p).unwrap() == b"new version"
+        })
+        .unwrap();
+        assert_eq!(std::fs::read(&target).unwrap(), b"new version");
+        assert!(
+            !rollback_path(&target).exists(),
+            "rollback copy left behind"
+        );
+    }
+
+    #[test]
+    fn replace_with_rollback_restores_on_failed_check() {
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("fresh");
+        std::fs::write(&target, b"old version").unwrap();
+
+        let err = replace_with_rollback(&target, b"broken", |_| false).unwrap_err();
+        assert!(matches!(err, UpdateError::RolledBack));
+        assert_eq!(std::fs::read(&target).unwrap(), b"old version");
+        assert!(
+            !rollback_path(&target).exists(),
+            "rollback copy left behind"
+        );
+    }
+
     #[test]
     fn can_self_update_requires_capability_and_confidence() {
         // Tarball at authoritative confidence: yes.
@@ -92,6 +92,7 @@ export default defineConfig({
         items: [
           { text: "Overview", link: "/configuration/" },
           { text: "Startup Script (init.ts)", link: "/configuration/init" },
+          { text: "User Scripts", link: "/configuration/scripts" },
           { text: "Keyboard", link: "/configuration/keyboard" },
         ],
       },
@@ -108,6 +109,7 @@ export default defineConfig({
               { text: "Common Patterns", link: "/plugins/development/patterns" },
               { text: "Utilities Library", link: "/plugins/development/utilities" },
               { text: "Internationalization", link: "/plugins/development/i18n" },
+              { text: "External Plugins", link: "/plugins/development/external-plugins" },
             ],
           },
           {
@@ -130,6 +132,7 @@ export default defineConfig({
         items: [
           { text: "Architecture", link: "/architecture" },
           { text: "Adding a Language", link: "/development/adding-languages" },
+          { text: "Embedding the Editor", link: "/development/embedding" },
           { text: "WASM Compatibility", link: "/wasm" },
           { text: "QuickJS Migration", link: "/quickjs" },
           {
@@ -57,13 +57,15 @@ function ensureKbInput(){
     if(handled) ev.preventDefault();
     i.value="";
   });
-  // Committed IME composition (CJK candidate commit, dead-key accents): the
-  // uncommitted preedit lives in this hidden input (inline preedit rendering
-  // at the caret is a scene-level design item — see web-ui.md §3.6); on
-  // commit, forward the composed text to the editor and clear the sink.
+  // IME composition (CJK candidate selection, dead-key accents): each update
+  // sends the uncommitted preedit, which the editor paints underlined at the
+  // caret (it arrives back as ordinary pane cells — see web-ui.md §3.6); the
+  // commit inserts the composed text and clears the preedit. A cancelled
+  // composition commits "" — that only clears it. Then clear the sink.
+  i.addEventListener("compositionupdate",ev=>{ wsSend({type:"ime",preedit:ev.data||""}); });
   i.addEventListener("compositionend",ev=>{
     i.value="";
-    if(ev.data) for(const ch of ev.data) mobileSendKey({key:ch});
+    wsSend({type:"ime",commit:ev.data||""});
   });
   // Never keep typed text — but don't clear mid-composition, which would
   // cancel the IME's preedit state.